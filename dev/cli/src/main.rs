@@ -65,6 +65,24 @@ enum Commands {
         filter: Option<String>,
     },
 
+    /// Run the API smoke test (Neo4j + Postgres testcontainers, needs Docker)
+    TestApi {
+        /// Extra arguments for the test binary, e.g. `--nocapture`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Check extraction against approved golden outputs (live LLM + embeddings)
+    TestGolden {
+        /// Only cases whose name contains this
+        #[arg(short, long)]
+        filter: Option<String>,
+
+        /// Approve pending outputs instead of checking: case names, or `all`
+        #[arg(long, value_delimiter = ',', num_args = 0.., default_missing_value = "all")]
+        approve: Option<Vec<String>>,
+    },
+
     /// Run improvement loop (blind spot analysis, ~$5-10)
     Improve,
 
@@ -79,6 +97,20 @@ enum Commands {
         mutations: u32,
     },
 
+    /// Load test a running API (run, seed, purge; see modules/rootsignal-loadtest)
+    Loadtest {
+        /// Arguments for the loadtest binary, e.g. `run --concurrency 32`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Seed a synthetic demo region (see modules/rootsignal-seed)
+    Seed {
+        /// Arguments for the seed binary, e.g. `--wipe --signals 500`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
     /// Docker service management
     #[command(subcommand)]
     Docker(cmd::docker::DockerCommand),
@@ -105,8 +137,14 @@ fn run() -> Result<()> {
         Some(Commands::Scout) => cmd_scout(&ctx),
         Some(Commands::TestScout { filter }) => cmd_test_scout(&ctx, filter.as_deref()),
         Some(Commands::TestSim { filter }) => cmd_test_sim(&ctx, filter.as_deref()),
+        Some(Commands::TestApi { args }) => cmd_test_api(&ctx, &args),
+        Some(Commands::TestGolden { filter, approve }) => {
+            cmd_test_golden(&ctx, filter.as_deref(), approve.as_deref())
+        }
         Some(Commands::Improve) => cmd_improve(&ctx),
         Some(Commands::Evolve { generations, mutations }) => cmd_evolve(&ctx, generations, mutations),
+        Some(Commands::Loadtest { args }) => cmd_loadtest(&ctx, &args),
+        Some(Commands::Seed { args }) => cmd_seed(&ctx, &args),
         Some(Commands::Status) => cmd_status(&ctx),
         Some(Commands::Doctor) => cmd_doctor(&ctx),
         Some(Commands::Docker(cmd)) => cmd::docker::run(&ctx, cmd),
@@ -192,6 +230,59 @@ fn cmd_test_scout(ctx: &AppContext, filter: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+fn cmd_test_api(ctx: &AppContext, args: &[String]) -> Result<()> {
+    ctx.print_header("API Smoke Test");
+    println!();
+    ctx.print_info("Serves the full API against seeded Neo4j + Postgres containers and checks every route");
+    println!();
+
+    let status = std::process::Command::new("cargo")
+        .args(["test", "-p", "rootsignal-api", "smoke_tests", "--", "--ignored"])
+        .args(args)
+        .current_dir(&ctx.repo)
+        .status()?;
+
+    println!();
+    if status.success() {
+        ctx.print_success("API smoke test passed!");
+    } else {
+        ctx.print_warning("API smoke test failed — check output above (changed shapes: `cargo insta review`)");
+    }
+    Ok(())
+}
+
+fn cmd_test_golden(ctx: &AppContext, filter: Option<&str>, approve: Option<&[String]>) -> Result<()> {
+    ctx.print_header("Extraction Golden Outputs");
+    println!();
+
+    let mut command = std::process::Command::new("cargo");
+    command
+        .args(["test", "-p", "rootsignal-scout", "--test", "golden_test", "--", "--ignored", "--nocapture"])
+        .current_dir(&ctx.repo);
+    if let Some(names) = approve {
+        ctx.print_info("Approving pending outputs — they become the new goldens");
+        command.env("GOLDEN_APPROVE", names.join(","));
+    } else {
+        ctx.print_info("Extracts each golden input and compares it with the approved output (~$0.20)");
+    }
+    if let Some(f) = filter {
+        command.env("GOLDEN_FILTER", f);
+    }
+    println!();
+
+    let status = command.status()?;
+
+    println!();
+    if !status.success() {
+        ctx.print_warning("Golden outputs changed — review tests/golden/pending, then `dev test-golden --approve`");
+    } else if approve.is_some() {
+        ctx.print_success("Pending outputs approved — commit tests/golden/approved");
+    } else {
+        ctx.print_success("Extraction matches the approved goldens!");
+    }
+    Ok(())
+}
+
 fn cmd_test_sim(ctx: &AppContext, filter: Option<&str>) -> Result<()> {
     ctx.print_header("Sim Integration Tests (LLM-generated worlds)");
     println!();
@@ -304,6 +395,49 @@ fn cmd_evolve(ctx: &AppContext, generations: u32, mutations: u32) -> Result<()>
     Ok(())
 }
 
+fn cmd_loadtest(ctx: &AppContext, args: &[String]) -> Result<()> {
+    ctx.print_header("API Load Test");
+    println!();
+
+    let args: Vec<&str> = if args.is_empty() {
+        vec!["run"]
+    } else {
+        args.iter().map(String::as_str).collect()
+    };
+    let status = std::process::Command::new("cargo")
+        .args(["run", "--release", "-q", "-p", "rootsignal-loadtest", "--"])
+        .args(&args)
+        .current_dir(&ctx.repo)
+        .status()?;
+
+    println!();
+    if status.success() {
+        ctx.print_success("Load test complete!");
+    } else {
+        ctx.print_warning("Load test failed or went over the perf budget — check output above");
+    }
+    Ok(())
+}
+
+fn cmd_seed(ctx: &AppContext, args: &[String]) -> Result<()> {
+    ctx.print_header("Seed Demo Region");
+    println!();
+
+    let status = std::process::Command::new("cargo")
+        .args(["run", "--release", "-q", "-p", "rootsignal-seed", "--"])
+        .args(args)
+        .current_dir(&ctx.repo)
+        .status()?;
+
+    println!();
+    if status.success() {
+        ctx.print_success("Demo region seeded!");
+    } else {
+        ctx.print_warning("Seeding failed — check output above");
+    }
+    Ok(())
+}
+
 fn cmd_doctor(ctx: &AppContext) -> Result<()> {
     ctx.print_header("System Health Check");
     println!();
@@ -74,15 +74,46 @@ export const CREATE_SCOUT_TASK = gql`
     $location: String!
     $radiusKm: Float
     $priority: Float
+    $useBoundary: Boolean
   ) {
     createScoutTask(
       location: $location
       radiusKm: $radiusKm
       priority: $priority
+      useBoundary: $useBoundary
     )
   }
 `;
 
+export const CREATE_REGION = gql`
+  mutation CreateRegion(
+    $location: String!
+    $radiusKm: Float
+    $useBoundary: Boolean
+    $timezone: String!
+  ) {
+    createRegion(
+      location: $location
+      radiusKm: $radiusKm
+      useBoundary: $useBoundary
+      timezone: $timezone
+    ) {
+      success
+      message
+    }
+  }
+`;
+
+export const RECHECK_REGION_READINESS = gql`
+  mutation RecheckRegionReadiness($region: String!) {
+    recheckRegionReadiness(region: $region) {
+      region
+      stage
+      progress
+    }
+  }
+`;
+
 export const CANCEL_SCOUT_TASK = gql`
   mutation CancelScoutTask($id: String!) {
     cancelScoutTask(id: $id)
@@ -127,3 +158,21 @@ export const DISMISS_FINDING = gql`
     dismissFinding(id: $id)
   }
 `;
+
+export const REQUEST_SOURCE_BACKFILL = gql`
+  mutation RequestSourceBackfill($region: String!, $canonicalKey: String!, $maxItems: Int, $maxAgeDays: Int) {
+    requestSourceBackfill(region: $region, canonicalKey: $canonicalKey, maxItems: $maxItems, maxAgeDays: $maxAgeDays) {
+      success
+      message
+    }
+  }
+`;
+
+export const REVIEW_BOOTSTRAP_CANDIDATES = gql`
+  mutation ReviewBootstrapCandidates($region: String!, $canonicalKeys: [String!], $approve: Boolean!) {
+    reviewBootstrapCandidates(region: $region, canonicalKeys: $canonicalKeys, approve: $approve) {
+      success
+      message
+    }
+  }
+`;
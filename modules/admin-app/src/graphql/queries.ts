@@ -88,6 +88,22 @@ export const ADMIN_DASHBOARD = gql`
         severity
         category
         whatWouldHelp
+        coverage
+        escalated
+      }
+      coverageByDay {
+        day
+        tensions
+        avgCoverage
+        unmet
+        escalated
+      }
+      auditByDay {
+        day
+        audited
+        meanScore
+        critical
+        suppressed
       }
       topSources {
         name
@@ -118,11 +134,31 @@ export const ADMIN_DASHBOARD = gql`
   }
 `;
 
+export const TRENDS = gql`
+  query Trends($region: String!, $window: GqlTrendWindow!) {
+    trends(region: $region, window: $window, limit: 10) {
+      id
+      category
+      neighborhood
+      direction
+      observed
+      expected
+      changeRatio
+      weeklyCounts
+      headline
+      signals {
+        ${SIGNAL_FIELDS}
+      }
+    }
+  }
+`;
+
 export const ADMIN_REGION_SOURCES = gql`
   query AdminRegionSources($regionSlug: String!) {
     adminRegionSources(regionSlug: $regionSlug) {
       id
       url
+      canonicalKey
       canonicalValue
       sourceLabel
       weight
@@ -131,8 +167,59 @@ export const ADMIN_REGION_SOURCES = gql`
       discoveryMethod
       lastScraped
       cadenceHours
+      nextRunAt
       signalsProduced
       active
+      trialState
+    }
+  }
+`;
+
+export const ADMIN_SOURCE_BACKFILLS = gql`
+  query AdminSourceBackfills($region: String!, $status: BackfillStatus) {
+    adminSourceBackfills(region: $region, status: $status) {
+      canonicalKey
+      maxItems
+      maxAgeDays
+      status
+      requestedAt
+      completedAt
+      signalsStored
+      error
+    }
+  }
+`;
+
+export const ADMIN_INFORMATION_REQUESTS = gql`
+  query AdminInformationRequests($region: String!, $status: InformationRequestStatus, $limit: Int) {
+    adminInformationRequests(region: $region, status: $status, limit: $limit) {
+      id
+      situationId
+      situationHeadline
+      temperature
+      question
+      status
+      attempts
+      createdAt
+      answeredAt
+      evidence {
+        id
+        url
+        snippet
+        retrievedAt
+      }
+    }
+  }
+`;
+
+export const ADMIN_SOURCE_TRIAL_TRANSITIONS = gql`
+  query AdminSourceTrialTransitions($regionSlug: String, $limit: Int) {
+    adminSourceTrialTransitions(regionSlug: $regionSlug, limit: $limit) {
+      canonicalKey
+      from
+      to
+      reason
+      at
     }
   }
 `;
@@ -212,29 +299,34 @@ export const SIGNAL_DETAIL = gql`
     signal(id: $id) {
       ${SIGNAL_FIELDS}
       ... on GqlGatheringSignal {
-        evidence { id sourceUrl snippet relevance }
+        evidence { id sourceUrl snippet relevance freshness checkedAt driftDiff }
         actors { id name actorType }
         story { id headline arc }
+        investigations { id question method sourcesConsulted confidenceBefore confidenceAfter costCents createdAt findings { sourceUrl relevance confidence snippet } }
       }
       ... on GqlAidSignal {
-        evidence { id sourceUrl snippet relevance }
+        evidence { id sourceUrl snippet relevance freshness checkedAt driftDiff }
         actors { id name actorType }
         story { id headline arc }
+        investigations { id question method sourcesConsulted confidenceBefore confidenceAfter costCents createdAt findings { sourceUrl relevance confidence snippet } }
       }
       ... on GqlNeedSignal {
-        evidence { id sourceUrl snippet relevance }
+        evidence { id sourceUrl snippet relevance freshness checkedAt driftDiff }
         actors { id name actorType }
         story { id headline arc }
+        investigations { id question method sourcesConsulted confidenceBefore confidenceAfter costCents createdAt findings { sourceUrl relevance confidence snippet } }
       }
       ... on GqlNoticeSignal {
-        evidence { id sourceUrl snippet relevance }
+        evidence { id sourceUrl snippet relevance freshness checkedAt driftDiff }
         actors { id name actorType }
         story { id headline arc }
+        investigations { id question method sourcesConsulted confidenceBefore confidenceAfter costCents createdAt findings { sourceUrl relevance confidence snippet } }
       }
       ... on GqlTensionSignal {
-        evidence { id sourceUrl snippet relevance }
+        evidence { id sourceUrl snippet relevance freshness checkedAt driftDiff }
         actors { id name actorType }
         story { id headline arc }
+        investigations { id question method sourcesConsulted confidenceBefore confidenceAfter costCents createdAt findings { sourceUrl relevance confidence snippet } }
       }
     }
   }
@@ -281,6 +373,12 @@ export const STORY_DETAIL = gql`
       arc
       category
       energy
+      energyBreakdown {
+        activity
+        severity
+        corroboration
+        heat
+      }
       signalCount
       firstSeen
       lastUpdated
@@ -356,6 +454,10 @@ export const ADMIN_SCOUT_RUN = gql`
       region
       startedAt
       finishedAt
+      flags {
+        flag
+        enabled
+      }
       stats {
         urlsScraped
         urlsUnchanged
@@ -403,6 +505,8 @@ export const ADMIN_SCOUT_RUN = gql`
         remainingCents
         topics
         postsFound
+        score
+        suppressed
       }
     }
   }
@@ -656,3 +760,44 @@ export const SITUATION_DETAIL = gql`
     }
   }
 `;
+
+export const ADMIN_REGION_ONBOARDINGS = gql`
+  query AdminRegionOnboardings {
+    adminRegionOnboardings {
+      region
+      name
+      taskId
+      timezone
+      stage
+      progress
+      dryRunFetched
+      dryRunFailed
+      checklist {
+        name
+        passed
+        detail
+      }
+      error
+      createdAt
+      updatedAt
+    }
+  }
+`;
+
+export const ADMIN_BOOTSTRAP_CANDIDATES = gql`
+  query AdminBootstrapCandidates($region: String!, $status: BootstrapReviewStatus) {
+    adminBootstrapCandidates(region: $region, status: $status) {
+      canonicalKey
+      canonicalValue
+      url
+      sourceRole
+      score
+      domainReputation
+      locality
+      content
+      status
+      sampleExcerpt
+      createdAt
+    }
+  }
+`;
@@ -1,5 +1,6 @@
+import { useState } from "react";
 import { useQuery } from "@apollo/client";
-import { ADMIN_DASHBOARD } from "@/graphql/queries";
+import { ADMIN_DASHBOARD, TRENDS } from "@/graphql/queries";
 import {
   BarChart,
   Bar,
@@ -21,6 +22,10 @@ export function DashboardPage() {
   const { data, loading } = useQuery(ADMIN_DASHBOARD, {
     variables: { region },
   });
+  const [trendWindow, setTrendWindow] = useState<"WEEK" | "MONTH">("MONTH");
+  const { data: trendData } = useQuery(TRENDS, {
+    variables: { region, window: trendWindow },
+  });
 
   if (loading) return <p className="text-muted-foreground">Loading dashboard...</p>;
 
@@ -110,6 +115,93 @@ export function DashboardPage() {
         </div>
       </div>
 
+      {/* Extraction audit */}
+      <div className="rounded-lg border border-border p-4">
+        <div className="flex items-center justify-between mb-4">
+          <h2 className="text-sm font-medium">Extraction Audit (30 day)</h2>
+          <p className="text-xs text-muted-foreground">
+            {d.auditByDay.reduce((n: number, a: { audited: number }) => n + a.audited, 0)} audited,{" "}
+            {d.auditByDay.reduce((n: number, a: { suppressed: number }) => n + a.suppressed, 0)} suppressed
+          </p>
+        </div>
+        {d.auditByDay.length > 0 ? (
+          <ResponsiveContainer width="100%" height={160}>
+            <AreaChart data={d.auditByDay}>
+              <XAxis dataKey="day" tick={{ fontSize: 11 }} />
+              <YAxis domain={[0, 1]} tick={{ fontSize: 11 }} />
+              <Tooltip />
+              <Area type="monotone" dataKey="meanScore" name="Mean judge score" fill="#10b981" stroke="#10b981" />
+            </AreaChart>
+          </ResponsiveContainer>
+        ) : (
+          <p className="text-sm text-muted-foreground">No audited signals yet</p>
+        )}
+      </div>
+
+      {/* Trends */}
+      <div className="rounded-lg border border-border p-4">
+        <div className="flex items-center justify-between mb-4">
+          <h2 className="text-sm font-medium">Trends</h2>
+          <div className="flex gap-1">
+            {(["WEEK", "MONTH"] as const).map((w) => (
+              <button
+                key={w}
+                onClick={() => setTrendWindow(w)}
+                className={`px-2 py-0.5 rounded text-xs ${
+                  trendWindow === w ? "bg-accent text-foreground" : "text-muted-foreground"
+                }`}
+              >
+                {w === "WEEK" ? "Week" : "Month"}
+              </button>
+            ))}
+          </div>
+        </div>
+        {trendData?.trends?.length ? (
+          <table className="w-full text-sm">
+            <thead>
+              <tr className="border-b border-border text-left text-muted-foreground">
+                <th className="pb-2 font-medium">Category</th>
+                <th className="pb-2 font-medium">Neighborhood</th>
+                <th className="pb-2 font-medium">Change</th>
+                <th className="pb-2 font-medium">Signals</th>
+                <th className="pb-2 font-medium">Supporting</th>
+              </tr>
+            </thead>
+            <tbody>
+              {trendData.trends.map(
+                (t: {
+                  id: string;
+                  category: string;
+                  neighborhood: string | null;
+                  direction: string;
+                  observed: number;
+                  expected: number;
+                  changeRatio: number | null;
+                  signals: { title: string }[];
+                }) => (
+                  <tr key={t.id} className="border-b border-border/50">
+                    <td className="py-1.5">{t.category}</td>
+                    <td className="py-1.5">{t.neighborhood ?? "All"}</td>
+                    <td className={`py-1.5 ${t.direction === "RISING" ? "text-red-400" : "text-green-400"}`}>
+                      {t.direction === "RISING" ? "▲" : "▼"}{" "}
+                      {t.changeRatio != null ? `${t.changeRatio.toFixed(1)}×` : "new"}
+                    </td>
+                    <td className="py-1.5">
+                      {t.observed} <span className="text-muted-foreground">/ {t.expected.toFixed(0)} expected</span>
+                    </td>
+                    <td className="py-1.5 text-muted-foreground truncate max-w-[300px]">
+                      {t.signals.map((s) => s.title).slice(0, 3).join(" · ")}
+                    </td>
+                  </tr>
+                ),
+              )}
+            </tbody>
+          </table>
+        ) : (
+          <p className="text-sm text-muted-foreground">No significant trends</p>
+        )}
+      </div>
+
       {/* Unmet tensions table */}
       <div className="rounded-lg border border-border p-4">
         <h2 className="text-sm font-medium mb-4">Unmet Tensions</h2>
@@ -120,16 +212,35 @@ export function DashboardPage() {
                 <th className="pb-2 font-medium">Title</th>
                 <th className="pb-2 font-medium">Severity</th>
                 <th className="pb-2 font-medium">Category</th>
+                <th className="pb-2 font-medium">Coverage</th>
                 <th className="pb-2 font-medium">What Would Help</th>
               </tr>
             </thead>
             <tbody>
               {d.unmetTensions.map(
-                (t: { title: string; severity: string; category: string; whatWouldHelp: string }, i: number) => (
+                (
+                  t: {
+                    title: string;
+                    severity: string;
+                    category: string;
+                    whatWouldHelp: string;
+                    coverage: number | null;
+                    escalated: boolean;
+                  },
+                  i: number,
+                ) => (
                   <tr key={i} className="border-b border-border/50">
-                    <td className="py-2">{t.title}</td>
+                    <td className="py-2">
+                      {t.title}
+                      {t.escalated && (
+                        <span className="ml-2 text-xs text-red-400">escalated</span>
+                      )}
+                    </td>
                     <td className="py-2">{t.severity}</td>
                     <td className="py-2">{t.category}</td>
+                    <td className="py-2">
+                      {t.coverage == null ? "—" : `${Math.round(t.coverage * 100)}%`}
+                    </td>
                     <td className="py-2 text-muted-foreground">{t.whatWouldHelp}</td>
                   </tr>
                 ),
@@ -4,7 +4,11 @@ import { useQuery, useMutation } from "@apollo/client";
 import {
   ADMIN_SCOUT_RUNS,
   ADMIN_REGION_SOURCES,
+  ADMIN_SOURCE_TRIAL_TRANSITIONS,
+  ADMIN_SOURCE_BACKFILLS,
   ADMIN_SCOUT_TASKS,
+  ADMIN_BOOTSTRAP_CANDIDATES,
+  ADMIN_REGION_ONBOARDINGS,
   SUPERVISOR_FINDINGS,
   SUPERVISOR_SUMMARY,
 } from "@/graphql/queries";
@@ -16,16 +20,53 @@ import {
   CANCEL_SCOUT_TASK,
   DISMISS_FINDING,
   RESET_SCOUT_STATUS,
+  REVIEW_BOOTSTRAP_CANDIDATES,
+  REQUEST_SOURCE_BACKFILL,
+  CREATE_REGION,
+  RECHECK_REGION_READINESS,
 } from "@/graphql/mutations";
 
-type Tab = "tasks" | "runs" | "sources" | "findings";
+type Tab = "tasks" | "regions" | "runs" | "sources" | "review" | "findings";
 const TABS: { key: Tab; label: string }[] = [
   { key: "tasks", label: "Tasks" },
+  { key: "regions", label: "Regions" },
   { key: "runs", label: "Runs" },
   { key: "sources", label: "Sources" },
+  { key: "review", label: "Bootstrap Review" },
   { key: "findings", label: "Findings" },
 ];
 
+type BootstrapCandidate = {
+  canonicalKey: string;
+  canonicalValue: string;
+  url: string | null;
+  sourceRole: string;
+  score: number;
+  domainReputation: number;
+  locality: number;
+  content: number | null;
+  sampleExcerpt: string | null;
+};
+
+type RegionOnboarding = {
+  region: string;
+  name: string;
+  timezone: string;
+  stage: string;
+  progress: string;
+  dryRunFetched: number | null;
+  dryRunFailed: number | null;
+  checklist: { name: string; passed: boolean; detail: string }[];
+  error: string | null;
+  updatedAt: string;
+};
+
+const ONBOARDING_STAGE_STYLES: Record<string, string> = {
+  active: "bg-green-900 text-green-300",
+  awaiting_review: "bg-amber-500/10 text-amber-400",
+  failed: "bg-red-500/10 text-red-400",
+};
+
 type ScoutRunStats = {
   urlsScraped: number;
   signalsExtracted: number;
@@ -390,6 +431,32 @@ export function ScoutPage() {
     skip: tab !== "sources",
   });
   const sources = sourcesData?.adminRegionSources ?? [];
+  const { data: trialsData } = useQuery(ADMIN_SOURCE_TRIAL_TRANSITIONS, {
+    variables: { limit: 20 },
+    skip: tab !== "sources",
+  });
+  const trialTransitions: {
+    canonicalKey: string;
+    from: string;
+    to: string;
+    reason: string;
+    at: string;
+  }[] = trialsData?.adminSourceTrialTransitions ?? [];
+  const [backfillRegion, setBackfillRegion] = useState("twincities");
+  const { data: backfillsData, refetch: refetchBackfills } = useQuery(ADMIN_SOURCE_BACKFILLS, {
+    variables: { region: backfillRegion },
+    skip: tab !== "sources" || !backfillRegion,
+  });
+  const backfillStatus = new Map<string, { status: string; signalsStored: number; error: string | null }>(
+    (backfillsData?.adminSourceBackfills ?? []).map(
+      (b: { canonicalKey: string; status: string; signalsStored: number; error: string | null }) => [b.canonicalKey, b],
+    ),
+  );
+  const [requestBackfill] = useMutation(REQUEST_SOURCE_BACKFILL);
+  const handleBackfill = async (canonicalKey: string) => {
+    await requestBackfill({ variables: { region: backfillRegion, canonicalKey } });
+    refetchBackfills();
+  };
   const [addSource] = useMutation(ADD_SOURCE);
   const [showAddSource, setShowAddSource] = useState(false);
   const [sourceUrl, setSourceUrl] = useState("");
@@ -406,6 +473,32 @@ export function ScoutPage() {
     refetchSources();
   };
 
+  // --- Bootstrap review ---
+  const [reviewRegion, setReviewRegion] = useState("twincities");
+  const { data: candidatesData, loading: candidatesLoading, refetch: refetchCandidates } = useQuery(
+    ADMIN_BOOTSTRAP_CANDIDATES,
+    { variables: { region: reviewRegion, status: "PENDING" }, skip: tab !== "review" || !reviewRegion },
+  );
+  const candidates: BootstrapCandidate[] = candidatesData?.adminBootstrapCandidates ?? [];
+  const [reviewCandidates] = useMutation(REVIEW_BOOTSTRAP_CANDIDATES);
+  const [selectedCandidates, setSelectedCandidates] = useState<Set<string>>(new Set());
+
+  const toggleCandidate = (key: string) => {
+    setSelectedCandidates((prev) => {
+      const next = new Set(prev);
+      if (next.has(key)) next.delete(key);
+      else next.add(key);
+      return next;
+    });
+  };
+
+  // `keys` undefined reviews every pending candidate.
+  const handleReview = async (approve: boolean, keys?: string[]) => {
+    await reviewCandidates({ variables: { region: reviewRegion, canonicalKeys: keys, approve } });
+    setSelectedCandidates(new Set());
+    refetchCandidates();
+  };
+
   // --- Tasks ---
   const { data: tasksData, loading: tasksLoading, refetch: refetchTasks } = useQuery(
     ADMIN_SCOUT_TASKS,
@@ -415,6 +508,7 @@ export function ScoutPage() {
   const [createTask] = useMutation(CREATE_SCOUT_TASK);
   const [cancelTask] = useMutation(CANCEL_SCOUT_TASK);
   const [taskLocation, setTaskLocation] = useState("");
+  const [taskUseBoundary, setTaskUseBoundary] = useState(false);
   const [taskCreating, setTaskCreating] = useState(false);
   const [taskError, setTaskError] = useState<string | null>(null);
 
@@ -425,7 +519,7 @@ export function ScoutPage() {
     setTaskError(null);
     try {
       await createTask({
-        variables: { location: taskLocation.trim() },
+        variables: { location: taskLocation.trim(), useBoundary: taskUseBoundary },
       });
       setTaskLocation("");
       refetchTasks();
@@ -441,6 +535,54 @@ export function ScoutPage() {
     refetchTasks();
   };
 
+  // --- Regions ---
+  const { data: onboardingsData, loading: onboardingsLoading, refetch: refetchOnboardings } = useQuery(
+    ADMIN_REGION_ONBOARDINGS,
+    { skip: tab !== "regions", pollInterval: tab === "regions" ? 10000 : 0 },
+  );
+  const onboardings: RegionOnboarding[] = onboardingsData?.adminRegionOnboardings ?? [];
+  const [createRegion] = useMutation(CREATE_REGION);
+  const [recheckReadiness] = useMutation(RECHECK_REGION_READINESS);
+  const [regionLocation, setRegionLocation] = useState("");
+  const [regionUseBoundary, setRegionUseBoundary] = useState(false);
+  const [regionTimezone, setRegionTimezone] = useState(
+    Intl.DateTimeFormat().resolvedOptions().timeZone,
+  );
+  const [regionCreating, setRegionCreating] = useState(false);
+  const [regionError, setRegionError] = useState<string | null>(null);
+
+  const handleCreateRegion = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!regionLocation.trim()) return;
+    setRegionCreating(true);
+    setRegionError(null);
+    try {
+      await createRegion({
+        variables: {
+          location: regionLocation.trim(),
+          useBoundary: regionUseBoundary,
+          timezone: regionTimezone.trim(),
+        },
+      });
+      setRegionLocation("");
+      refetchOnboardings();
+    } catch (err: unknown) {
+      setRegionError(err instanceof Error ? err.message : "Failed to create region");
+    } finally {
+      setRegionCreating(false);
+    }
+  };
+
+  const handleRecheck = async (region: string) => {
+    setRegionError(null);
+    try {
+      await recheckReadiness({ variables: { region } });
+      refetchOnboardings();
+    } catch (err: unknown) {
+      setRegionError(err instanceof Error ? err.message : "Failed to recheck readiness");
+    }
+  };
+
   // --- Task actions ---
   const [runScout] = useMutation(RUN_SCOUT);
   const [runScoutPhase] = useMutation(RUN_SCOUT_PHASE);
@@ -558,6 +700,13 @@ export function ScoutPage() {
         <div>
           <div className="flex items-center justify-between mb-4">
             <h2 className="text-sm font-medium">Sources ({sources.length})</h2>
+            <input
+              type="text"
+              value={backfillRegion}
+              onChange={(e) => setBackfillRegion(e.target.value)}
+              placeholder="Backfill region"
+              className="ml-auto mr-2 px-3 py-1.5 rounded-md border border-input bg-background text-sm"
+            />
             <button
               onClick={() => setShowAddSource(!showAddSource)}
               className="px-3 py-1.5 rounded-md bg-primary text-primary-foreground text-sm hover:bg-primary/90"
@@ -598,38 +747,170 @@ export function ScoutPage() {
                 <tr className="border-b border-border text-left text-muted-foreground">
                   <th className="pb-2 font-medium">Source</th>
                   <th className="pb-2 font-medium">Type</th>
+                  <th className="pb-2 font-medium">Trial</th>
                   <th className="pb-2 font-medium">Weight</th>
                   <th className="pb-2 font-medium">Signals</th>
                   <th className="pb-2 font-medium">Cadence</th>
                   <th className="pb-2 font-medium">Last Scraped</th>
+                  <th className="pb-2 font-medium">Next Run</th>
+                  <th className="pb-2 font-medium">Backfill</th>
                 </tr>
               </thead>
               <tbody>
                 {sources.map(
                   (s: {
                     id: string;
+                    canonicalKey: string;
                     canonicalValue: string;
                     sourceLabel: string;
+                    trialState: string;
                     effectiveWeight: number;
                     signalsProduced: number;
                     cadenceHours: number;
                     lastScraped: string | null;
+                    nextRunAt: string | null;
                   }) => (
                     <tr key={s.id} className="border-b border-border/50">
                       <td className="py-2 truncate max-w-[200px]">{s.canonicalValue}</td>
                       <td className="py-2">{s.sourceLabel}</td>
+                      <td className="py-2 text-muted-foreground">
+                        {s.trialState === "established" ? "" : s.trialState}
+                      </td>
                       <td className="py-2">{s.effectiveWeight.toFixed(2)}</td>
                       <td className="py-2">{s.signalsProduced}</td>
                       <td className="py-2">{s.cadenceHours}h</td>
                       <td className="py-2 text-muted-foreground">
                         {s.lastScraped ? new Date(s.lastScraped).toLocaleDateString() : "Never"}
                       </td>
+                      <td className="py-2 text-muted-foreground">
+                        {s.nextRunAt ? new Date(s.nextRunAt).toLocaleString() : "Dormant"}
+                      </td>
+                      <td className="py-2">
+                        {(() => {
+                          const b = backfillStatus.get(s.canonicalKey);
+                          if (b?.status === "REQUESTED") return <span className="text-muted-foreground">Requested</span>;
+                          if (b?.status === "COMPLETED")
+                            return <span className="text-muted-foreground">{b.signalsStored} historical</span>;
+                          return (
+                            <button
+                              onClick={() => handleBackfill(s.canonicalKey)}
+                              disabled={!backfillRegion}
+                              title={b?.error ?? undefined}
+                              className="px-2 py-0.5 rounded-md border border-input text-xs hover:bg-accent disabled:opacity-50"
+                            >
+                              {b?.status === "FAILED" ? "Retry" : "Backfill"}
+                            </button>
+                          );
+                        })()}
+                      </td>
                     </tr>
                   ),
                 )}
               </tbody>
             </table>
           </div>
+
+          {trialTransitions.length > 0 && (
+            <div className="mt-6">
+              <h2 className="text-sm font-medium mb-2">Recent trial outcomes</h2>
+              <ul className="space-y-1 text-sm">
+                {trialTransitions.map((t) => (
+                  <li key={`${t.canonicalKey}-${t.at}`} className="flex gap-3">
+                    <span className="text-muted-foreground">{new Date(t.at).toLocaleString()}</span>
+                    <span className="truncate max-w-[300px]">{t.canonicalKey}</span>
+                    <span>
+                      {t.from} → {t.to}
+                    </span>
+                    <span className="text-muted-foreground">{t.reason}</span>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
+        </div>
+      )}
+
+      {/* Bootstrap review tab */}
+      {tab === "review" && (
+        <div className="space-y-4">
+          <div className="flex gap-2 items-center">
+            <input
+              type="text"
+              value={reviewRegion}
+              onChange={(e) => setReviewRegion(e.target.value)}
+              placeholder="Region"
+              className="px-3 py-1.5 rounded-md border border-input bg-background text-sm"
+            />
+            <button
+              onClick={() => handleReview(true, [...selectedCandidates])}
+              disabled={selectedCandidates.size === 0}
+              className="px-3 py-1.5 rounded-md bg-primary text-primary-foreground text-sm hover:bg-primary/90 disabled:opacity-50"
+            >
+              Approve selected ({selectedCandidates.size})
+            </button>
+            <button
+              onClick={() => handleReview(false, [...selectedCandidates])}
+              disabled={selectedCandidates.size === 0}
+              className="px-3 py-1.5 rounded-md border border-input text-sm hover:bg-accent disabled:opacity-50"
+            >
+              Reject selected
+            </button>
+            <button
+              onClick={() => handleReview(true)}
+              disabled={candidates.length === 0}
+              className="px-3 py-1.5 rounded-md border border-input text-sm hover:bg-accent disabled:opacity-50"
+            >
+              Approve all
+            </button>
+          </div>
+
+          {candidatesLoading ? (
+            <p className="text-muted-foreground">Loading candidates...</p>
+          ) : candidates.length === 0 ? (
+            <p className="text-muted-foreground">No bootstrap sources awaiting review.</p>
+          ) : (
+            <div className="rounded-lg border border-border overflow-hidden">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="border-b border-border bg-muted/50">
+                    <th className="px-4 py-2" />
+                    <th className="text-left px-4 py-2 font-medium">Source</th>
+                    <th className="text-left px-4 py-2 font-medium">Role</th>
+                    <th className="text-left px-4 py-2 font-medium">Score</th>
+                    <th className="text-left px-4 py-2 font-medium">Domain</th>
+                    <th className="text-left px-4 py-2 font-medium">Locality</th>
+                    <th className="text-left px-4 py-2 font-medium">Content</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {candidates.map((c) => (
+                    <tr key={c.canonicalKey} className="border-b border-border/50 align-top">
+                      <td className="px-4 py-2">
+                        <input
+                          type="checkbox"
+                          checked={selectedCandidates.has(c.canonicalKey)}
+                          onChange={() => toggleCandidate(c.canonicalKey)}
+                        />
+                      </td>
+                      <td className="px-4 py-2 max-w-[360px]">
+                        <p className="truncate">{c.url ?? c.canonicalValue}</p>
+                        {c.sampleExcerpt && (
+                          <p className="text-xs text-muted-foreground line-clamp-2">{c.sampleExcerpt}</p>
+                        )}
+                      </td>
+                      <td className="px-4 py-2">{c.sourceRole}</td>
+                      <td className="px-4 py-2 font-medium">{c.score.toFixed(2)}</td>
+                      <td className="px-4 py-2">{c.domainReputation.toFixed(2)}</td>
+                      <td className="px-4 py-2">{c.locality.toFixed(2)}</td>
+                      <td className="px-4 py-2 text-muted-foreground">
+                        {c.content != null ? c.content.toFixed(2) : "Not sampled"}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
         </div>
       )}
 
@@ -645,6 +926,14 @@ export function ScoutPage() {
               className="flex-1 max-w-xs px-3 py-1.5 rounded-md border border-input bg-background text-sm"
               required
             />
+            <label className="flex items-center gap-1.5 text-sm text-muted-foreground">
+              <input
+                type="checkbox"
+                checked={taskUseBoundary}
+                onChange={(e) => setTaskUseBoundary(e.target.checked)}
+              />
+              Use boundary
+            </label>
             <button
               type="submit"
               disabled={taskCreating || !taskLocation.trim()}
@@ -695,6 +984,114 @@ export function ScoutPage() {
         </div>
       )}
 
+      {/* Regions tab */}
+      {tab === "regions" && (
+        <div>
+          <form onSubmit={handleCreateRegion} className="mb-4 flex gap-2 items-center">
+            <input
+              type="text"
+              value={regionLocation}
+              onChange={(e) => { setRegionLocation(e.target.value); setRegionError(null); }}
+              placeholder="Location (e.g. Northfield, MN)"
+              className="flex-1 max-w-xs px-3 py-1.5 rounded-md border border-input bg-background text-sm"
+              required
+            />
+            <input
+              type="text"
+              value={regionTimezone}
+              onChange={(e) => { setRegionTimezone(e.target.value); setRegionError(null); }}
+              placeholder="Timezone (e.g. America/Chicago)"
+              className="w-48 px-3 py-1.5 rounded-md border border-input bg-background text-sm"
+              required
+            />
+            <label className="flex items-center gap-1.5 text-sm text-muted-foreground">
+              <input
+                type="checkbox"
+                checked={regionUseBoundary}
+                onChange={(e) => setRegionUseBoundary(e.target.checked)}
+              />
+              Use boundary
+            </label>
+            <button
+              type="submit"
+              disabled={regionCreating || !regionLocation.trim() || !regionTimezone.trim()}
+              className="px-3 py-1.5 rounded-md bg-primary text-primary-foreground text-sm hover:bg-primary/90 disabled:opacity-50"
+            >
+              {regionCreating ? "Creating..." : "Create Region"}
+            </button>
+            {regionError && (
+              <span className="text-sm text-red-400">{regionError}</span>
+            )}
+          </form>
+
+          {onboardingsLoading ? (
+            <p className="text-muted-foreground">Loading regions...</p>
+          ) : onboardings.length === 0 ? (
+            <p className="text-muted-foreground">No regions onboarded yet.</p>
+          ) : (
+            <div className="rounded-lg border border-border overflow-hidden">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="border-b border-border bg-muted/50">
+                    <th className="text-left px-4 py-2 font-medium">Region</th>
+                    <th className="text-left px-4 py-2 font-medium">Stage</th>
+                    <th className="text-left px-4 py-2 font-medium">Progress</th>
+                    <th className="text-left px-4 py-2 font-medium">Readiness</th>
+                    <th className="text-left px-4 py-2 font-medium">Updated</th>
+                    <th className="text-right px-4 py-2 font-medium"></th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {onboardings.map((o) => (
+                    <tr key={o.region} className="border-b border-border last:border-0 align-top">
+                      <td className="px-4 py-2">
+                        <div>{o.name}</div>
+                        <div className="text-xs text-muted-foreground">{o.region} · {o.timezone}</div>
+                      </td>
+                      <td className="px-4 py-2">
+                        <span
+                          className={`text-xs px-2 py-0.5 rounded-full ${
+                            ONBOARDING_STAGE_STYLES[o.stage] ?? "bg-blue-500/10 text-blue-400"
+                          }`}
+                        >
+                          {o.stage.replace(/_/g, " ")}
+                        </span>
+                      </td>
+                      <td className="px-4 py-2 text-muted-foreground">
+                        {o.progress}
+                        {o.error && <p className="text-xs text-red-400 mt-1">{o.error}</p>}
+                      </td>
+                      <td className="px-4 py-2">
+                        <ul className="space-y-0.5 text-xs">
+                          {o.checklist.map((c) => (
+                            <li key={c.name} className={c.passed ? "text-green-400" : "text-muted-foreground"}>
+                              {c.passed ? "✓" : "○"} {c.detail}
+                            </li>
+                          ))}
+                        </ul>
+                      </td>
+                      <td className="px-4 py-2 text-muted-foreground whitespace-nowrap">
+                        {formatDate(o.updatedAt)}
+                      </td>
+                      <td className="px-4 py-2 text-right">
+                        {o.stage === "awaiting_review" && (
+                          <button
+                            onClick={() => handleRecheck(o.region)}
+                            className="text-xs px-2 py-1 rounded border border-border text-muted-foreground hover:text-foreground hover:bg-accent/50 transition-colors"
+                          >
+                            Recheck
+                          </button>
+                        )}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </div>
+      )}
+
       {/* Findings tab */}
       {tab === "findings" && (
         <div className="space-y-4">
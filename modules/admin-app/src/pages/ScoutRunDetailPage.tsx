@@ -14,6 +14,7 @@ const EVENT_COLORS: Record<string, string> = {
   llm_extraction: "bg-amber-500/10 text-amber-400 border-amber-500/20",
   signal_created: "bg-green-500/10 text-green-400 border-green-500/20",
   signal_deduplicated: "bg-orange-500/10 text-orange-400 border-orange-500/20",
+  signal_audited: "bg-teal-500/10 text-teal-400 border-teal-500/20",
   signal_corroborated:
     "bg-emerald-500/10 text-emerald-400 border-emerald-500/20",
   expansion_query_collected:
@@ -59,6 +60,8 @@ type ScoutRunEvent = {
   remainingCents?: number;
   topics?: string[];
   postsFound?: number;
+  score?: number;
+  suppressed?: boolean;
 };
 
 /** Build a human-readable detail string for an event. */
@@ -84,6 +87,8 @@ function eventDetail(e: ScoutRunEvent): string {
       return `${e.signalType}: "${truncate(e.title ?? "", 40)}" (${(e.confidence ?? 0).toFixed(2)})`;
     case "signal_deduplicated":
       return `${e.signalType}: "${truncate(e.title ?? "", 30)}" → ${e.action} (sim=${(e.similarity ?? 0).toFixed(3)})`;
+    case "signal_audited":
+      return `${e.signalType}: "${truncate(e.title ?? "", 40)}" score=${(e.score ?? 0).toFixed(2)}${e.suppressed ? " (suppressed)" : ""}`;
     case "signal_corroborated":
       return `${e.signalType}: ${e.existingId?.slice(0, 8)} ← ${truncate(e.newSourceUrl ?? "", 30)} (sim=${(e.similarity ?? 0).toFixed(3)})`;
     case "expansion_query_collected":
@@ -180,7 +185,26 @@ export function ScoutRunDetailPage() {
         ))}
       </div>
 
-      {/* Filter */}
+      {/* Feature flags the run resolved */}
+      {run.flags?.length > 0 && (
+        <div className="flex flex-wrap gap-2 items-center">
+          <span className="text-xs text-muted-foreground">Flags</span>
+          {run.flags.map((f: { flag: string; enabled: boolean }) => (
+            <span
+              key={f.flag}
+              className={`px-2 py-0.5 rounded text-xs font-mono ${
+                f.enabled
+                  ? "bg-green-500/10 text-green-400"
+                  : "bg-muted text-muted-foreground line-through"
+              }`}
+            >
+              {f.flag}
+            </span>
+          ))}
+        </div>
+      )}
+
+            {/* Filter */}
       <div className="flex gap-3 items-center">
         <select
           value={typeFilter ?? ""}
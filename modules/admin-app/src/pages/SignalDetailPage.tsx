@@ -2,6 +2,32 @@ import { useParams, Link } from "react-router";
 import { useQuery } from "@apollo/client";
 import { SIGNAL_DETAIL } from "@/graphql/queries";
 
+type InvestigationFinding = {
+  sourceUrl: string;
+  relevance: string;
+  confidence: number;
+  snippet: string;
+};
+
+type Investigation = {
+  id: string;
+  question: string;
+  method: string;
+  sourcesConsulted: string[];
+  confidenceBefore: number;
+  confidenceAfter: number;
+  costCents: number;
+  createdAt: string;
+  findings: InvestigationFinding[];
+};
+
+const FRESHNESS_COLORS: Record<string, string> = {
+  CURRENT: "bg-green-500/20 text-green-300",
+  CHANGED: "bg-yellow-500/20 text-yellow-300",
+  REMOVED: "bg-red-500/20 text-red-300",
+  STALE: "bg-gray-500/20 text-gray-400",
+};
+
 export function SignalDetailPage() {
   const { id } = useParams<{ id: string }>();
   const { data, loading } = useQuery(SIGNAL_DETAIL, { variables: { id } });
@@ -40,7 +66,14 @@ export function SignalDetailPage() {
           <h2 className="text-sm font-medium mb-3">Evidence ({signal.evidence.length})</h2>
           <div className="space-y-3">
             {signal.evidence.map(
-              (ev: { id: string; sourceUrl: string; snippet: string | null }) => (
+              (ev: {
+                id: string;
+                sourceUrl: string;
+                snippet: string | null;
+                freshness: string | null;
+                checkedAt: string | null;
+                driftDiff: string | null;
+              }) => (
                 <div key={ev.id} className="text-sm">
                   <a
                     href={ev.sourceUrl}
@@ -50,9 +83,23 @@ export function SignalDetailPage() {
                   >
                     {ev.sourceUrl}
                   </a>
+                  {ev.freshness && (
+                    <span
+                      className={`ml-2 px-2 py-0.5 rounded-full text-xs ${FRESHNESS_COLORS[ev.freshness] ?? "bg-secondary"}`}
+                      title={ev.checkedAt ? `Checked ${new Date(ev.checkedAt).toLocaleString()}` : undefined}
+                    >
+                      {ev.freshness.toLowerCase()}
+                    </span>
+                  )}
                   {ev.snippet && (
                     <p className="mt-1 text-muted-foreground">{ev.snippet}</p>
                   )}
+                  {ev.driftDiff && (
+                    <details className="mt-1">
+                      <summary className="cursor-pointer text-xs text-muted-foreground">What changed</summary>
+                      <pre className="mt-1 whitespace-pre-wrap text-xs text-muted-foreground">{ev.driftDiff}</pre>
+                    </details>
+                  )}
                 </div>
               ),
             )}
@@ -60,6 +107,60 @@ export function SignalDetailPage() {
         </div>
       )}
 
+      {signal.investigations?.length > 0 && (
+        <div className="rounded-lg border border-border p-4">
+          <h2 className="text-sm font-medium mb-3">
+            Investigations ({signal.investigations.length})
+          </h2>
+          <div className="space-y-4">
+            {signal.investigations.map((inv: Investigation) => (
+              <div key={inv.id} className="text-sm space-y-1">
+                <p className="font-medium">{inv.question}</p>
+                <p className="text-xs text-muted-foreground">
+                  {new Date(inv.createdAt).toLocaleString()}
+                  {" "}&middot; confidence {(inv.confidenceBefore * 100).toFixed(0)}%
+                  {" "}&rarr; {(inv.confidenceAfter * 100).toFixed(0)}%
+                  {" "}&middot; {inv.costCents}&cent;
+                </p>
+                <p className="text-muted-foreground">{inv.method}</p>
+                {inv.findings.length > 0 && (
+                  <ul className="mt-1 space-y-1">
+                    {inv.findings.map((f) => (
+                      <li key={f.sourceUrl}>
+                        <span className="px-1.5 py-0.5 rounded bg-secondary text-xs mr-2">
+                          {f.relevance} {(f.confidence * 100).toFixed(0)}%
+                        </span>
+                        <a
+                          href={f.sourceUrl}
+                          target="_blank"
+                          rel="noopener noreferrer"
+                          className="text-blue-400 hover:underline break-all"
+                        >
+                          {f.sourceUrl}
+                        </a>
+                        {f.snippet && <p className="mt-0.5 text-muted-foreground">{f.snippet}</p>}
+                      </li>
+                    ))}
+                  </ul>
+                )}
+                {inv.sourcesConsulted.length > 0 && (
+                  <details className="text-xs text-muted-foreground">
+                    <summary className="cursor-pointer">
+                      {inv.sourcesConsulted.length} sources consulted
+                    </summary>
+                    <ul className="mt-1 space-y-0.5">
+                      {inv.sourcesConsulted.map((url) => (
+                        <li key={url} className="break-all">{url}</li>
+                      ))}
+                    </ul>
+                  </details>
+                )}
+              </div>
+            ))}
+          </div>
+        </div>
+      )}
+
       {signal.actors?.length > 0 && (
         <div className="rounded-lg border border-border p-4">
           <h2 className="text-sm font-medium mb-3">Actors ({signal.actors.length})</h2>
@@ -1,6 +1,7 @@
+import { useState } from "react";
 import { Link } from "react-router";
 import { useQuery } from "@apollo/client";
-import { SITUATIONS } from "@/graphql/queries";
+import { ADMIN_INFORMATION_REQUESTS, SITUATIONS } from "@/graphql/queries";
 
 const ARC_COLORS: Record<string, string> = {
   EMERGING: "bg-blue-500/20 text-blue-300",
@@ -70,6 +71,69 @@ export function SituationsPage() {
           </tbody>
         </table>
       </div>
+      <InformationRequests />
+    </div>
+  );
+}
+
+type PulledEvidence = { id: string; url: string; snippet: string };
+
+type InformationRequest = {
+  id: string;
+  situationId: string;
+  situationHeadline: string;
+  question: string;
+  status: string;
+  attempts: number;
+  evidence: PulledEvidence[];
+};
+
+function InformationRequests() {
+  const [region, setRegion] = useState("");
+  const { data } = useQuery(ADMIN_INFORMATION_REQUESTS, {
+    variables: { region, limit: 50 },
+    skip: !region,
+  });
+  const requests: InformationRequest[] = data?.adminInformationRequests ?? [];
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center gap-3">
+        <h2 className="text-lg font-semibold">Information requests</h2>
+        <input
+          value={region}
+          onChange={(e) => setRegion(e.target.value)}
+          placeholder="Region"
+          className="px-2 py-1 rounded-md border border-input bg-background text-sm"
+        />
+      </div>
+      {region && requests.length === 0 && (
+        <p className="text-sm text-muted-foreground">No information requests.</p>
+      )}
+      <ul className="space-y-2 text-sm">
+        {requests.map((r) => (
+          <li key={r.id} className="border-b border-border/50 pb-2">
+            <div className="flex items-center gap-2">
+              <span className="px-2 py-0.5 rounded-full text-xs bg-secondary">{r.status}</span>
+              <span>{r.question}</span>
+              <span className="text-xs text-muted-foreground">
+                {r.attempts} attempt{r.attempts === 1 ? "" : "s"}
+              </span>
+            </div>
+            <Link to={`/situations/${r.situationId}`} className="text-xs text-muted-foreground hover:underline">
+              {r.situationHeadline}
+            </Link>
+            {r.evidence.map((e) => (
+              <blockquote key={e.id} className="mt-1 pl-3 border-l border-border text-muted-foreground">
+                {e.snippet}{" "}
+                <a href={e.url} target="_blank" rel="noreferrer" className="text-xs hover:underline">
+                  {new URL(e.url).hostname}
+                </a>
+              </blockquote>
+            ))}
+          </li>
+        ))}
+      </ul>
     </div>
   );
 }
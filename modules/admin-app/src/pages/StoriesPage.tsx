@@ -49,7 +49,7 @@ export function StoriesPage() {
                     )}
                   </td>
                   <td className="py-2 text-muted-foreground">{s.category}</td>
-                  <td className="py-2">{s.energy.toFixed(1)}</td>
+                  <td className="py-2">{s.energy.toFixed(2)}</td>
                   <td className="py-2">{s.signalCount}</td>
                 </tr>
               ),
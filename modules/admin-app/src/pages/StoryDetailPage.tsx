@@ -75,7 +75,19 @@ export function StoryDetailPage() {
             <span className="px-2 py-0.5 rounded-full bg-secondary">{story.arc}</span>
           )}
           {story.category && <>{" "}&middot; {story.category}</>}
-          {" "}&middot; Energy {story.energy.toFixed(1)}
+          {" "}&middot;{" "}
+          <span
+            title={
+              story.energyBreakdown
+                ? `activity ${story.energyBreakdown.activity.toFixed(2)}, ` +
+                  `severity ${story.energyBreakdown.severity.toFixed(2)}, ` +
+                  `corroboration ${story.energyBreakdown.corroboration.toFixed(2)}, ` +
+                  `heat ${story.energyBreakdown.heat.toFixed(2)}`
+                : undefined
+            }
+          >
+            Energy {story.energy.toFixed(2)}
+          </span>
           {" "}&middot; {story.signalCount} signals
         </p>
         <h1 className="text-xl font-semibold">{story.headline}</h1>
@@ -1,8 +1,14 @@
+use std::sync::Arc;
+
 use anyhow::{anyhow, Result};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use tracing::debug;
 
+use super::stream::{SseParser, StreamState};
 use super::types::*;
+use crate::error::AiError;
+use crate::traits::EarlyAbort;
+use crate::usage::{TokenUsage, UsageTracker};
 
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1";
 const ANTHROPIC_VERSION: &str = "2023-06-01";
@@ -11,6 +17,7 @@ pub(crate) struct ClaudeClient {
     api_key: String,
     http: reqwest::Client,
     base_url: String,
+    usage: Option<Arc<UsageTracker>>,
 }
 
 impl ClaudeClient {
@@ -19,6 +26,7 @@ impl ClaudeClient {
             api_key: api_key.to_string(),
             http: reqwest::Client::new(),
             base_url: ANTHROPIC_API_URL.to_string(),
+            usage: None,
         }
     }
 
@@ -27,6 +35,11 @@ impl ClaudeClient {
         self
     }
 
+    pub fn with_usage_tracker(mut self, tracker: Arc<UsageTracker>) -> Self {
+        self.usage = Some(tracker);
+        self
+    }
+
     fn headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
         headers.insert("x-api-key", HeaderValue::from_str(&self.api_key)?);
@@ -57,6 +70,74 @@ impl ClaudeClient {
             return Err(anyhow!("Claude API error ({}): {}", status, error_text));
         }
 
-        Ok(response.json().await?)
+        let response: ChatResponse = response.json().await?;
+        if let Some(ref usage) = response.usage {
+            self.record_usage(&request.model, usage);
+        }
+        Ok(response)
+    }
+
+    /// Stream a response, passing the output so far to `abort` after each
+    /// chunk. Dropping the connection on abort stops generation.
+    pub async fn chat_stream(&self, request: &ChatRequest, abort: &dyn EarlyAbort) -> Result<StreamState> {
+        let url = format!("{}/messages", self.base_url);
+
+        debug!(model = %request.model, "Claude streaming request");
+
+        let mut request_body = serde_json::to_value(request)?;
+        request_body["stream"] = serde_json::Value::Bool(true);
+
+        let mut response = self
+            .http
+            .post(&url)
+            .headers(self.headers()?)
+            .json(&request_body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await?;
+            return Err(anyhow!("Claude API error ({}): {}", status, error_text));
+        }
+
+        let mut parser = SseParser::default();
+        let mut state = StreamState::default();
+        while let Some(chunk) = response.chunk().await? {
+            let mut done = false;
+            for payload in parser.push(&chunk) {
+                done |= state.apply(&payload)?;
+            }
+            if let Some(reason) = abort.check(state.output()) {
+                debug!(model = %request.model, %reason, "Claude stream aborted");
+                if let Some(ref usage) = state.usage {
+                    self.record_usage(&request.model, usage);
+                }
+                return Err(AiError::Aborted { reason, partial: state.output().to_string() }.into());
+            }
+            if done {
+                break;
+            }
+        }
+
+        if let Some(ref usage) = state.usage {
+            self.record_usage(&request.model, usage);
+        }
+        Ok(state)
+    }
+
+    fn record_usage(&self, model: &str, usage: &Usage) {
+        let usage = TokenUsage::from(usage);
+        debug!(
+            model,
+            input_tokens = usage.input_tokens,
+            output_tokens = usage.output_tokens,
+            cache_write_tokens = usage.cache_write_tokens,
+            cache_read_tokens = usage.cache_read_tokens,
+            "Claude usage"
+        );
+        if let Some(ref tracker) = self.usage {
+            tracker.record(usage);
+        }
     }
 }
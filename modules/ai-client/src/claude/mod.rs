@@ -1,13 +1,16 @@
 mod client;
 pub mod prompt_builder;
+mod stream;
 pub(crate) mod types;
 
 pub use prompt_builder::{ClaudeOutputBuilder, ClaudePromptBuilder};
 
 use crate::openai::StructuredOutput;
-use crate::tool::{DynTool, Tool, ToolWrapper};
-use crate::traits::Agent;
+use crate::tool::{DynTool, Tool, ToolCallMetrics, ToolWrapper};
+use crate::traits::{Agent, OutputBuilder, PromptBuilder, VisionAgent};
+use crate::usage::UsageTracker;
 use anyhow::{anyhow, Result};
+use async_trait::async_trait;
 use std::sync::Arc;
 
 use client::ClaudeClient;
@@ -22,7 +25,9 @@ pub struct Claude {
     api_key: String,
     pub(crate) model: String,
     pub(crate) tools: Vec<Arc<dyn DynTool>>,
+    pub(crate) tool_metrics: Option<Arc<ToolCallMetrics>>,
     base_url: Option<String>,
+    usage: Option<Arc<UsageTracker>>,
 }
 
 impl Claude {
@@ -31,7 +36,9 @@ impl Claude {
             api_key: api_key.into(),
             model: model.into(),
             tools: Vec::new(),
+            tool_metrics: None,
             base_url: None,
+            usage: None,
         }
     }
 
@@ -46,6 +53,19 @@ impl Claude {
         self
     }
 
+    /// Report every call's token usage, including prompt cache reads and
+    /// writes, into `tracker`.
+    pub fn with_usage_tracker(mut self, tracker: Arc<UsageTracker>) -> Self {
+        self.usage = Some(tracker);
+        self
+    }
+
+    /// Count tool calls, malformed calls and repairs per tool into `metrics`.
+    pub fn with_tool_metrics(mut self, metrics: Arc<ToolCallMetrics>) -> Self {
+        self.tool_metrics = Some(metrics);
+        self
+    }
+
     pub fn model(&self) -> &str {
         &self.model
     }
@@ -55,50 +75,36 @@ impl Claude {
     }
 
     pub(crate) fn client(&self) -> ClaudeClient {
-        let client = ClaudeClient::new(&self.api_key);
+        let mut client = ClaudeClient::new(&self.api_key);
         if let Some(ref url) = self.base_url {
-            client.with_base_url(url)
-        } else {
-            client
+            client = client.with_base_url(url);
         }
+        if let Some(ref tracker) = self.usage {
+            client = client.with_usage_tracker(tracker.clone());
+        }
+        client
     }
 
     // =========================================================================
     // Convenience methods
     // =========================================================================
 
-    pub async fn extract<T: StructuredOutput>(
+    pub async fn extract<T: StructuredOutput + Send + 'static>(
         &self,
         model: &str,
         system_prompt: impl Into<String>,
         user_prompt: impl Into<String>,
     ) -> Result<T> {
-        let schema = T::openai_schema();
-
-        let tool_name = "structured_response";
-        let mut request = ChatRequest::new(model)
-            .system(system_prompt)
-            .message(WireMessage::user(user_prompt))
-            .tool(ToolDefinitionWire {
-                name: tool_name.to_string(),
-                description: "Extract structured data from the input.".to_string(),
-                input_schema: schema,
-            });
-        request.tool_choice = Some(serde_json::json!({
-            "type": "tool",
-            "name": tool_name,
-        }));
-
-        let response = self.client().chat(&request).await?;
-
-        for block in &response.content {
-            if let ContentBlock::ToolUse { input, .. } = block {
-                return serde_json::from_value(input.clone())
-                    .map_err(|e| anyhow!("Failed to deserialize response: {}", e));
-            }
-        }
-
-        Err(anyhow!("No structured output in Claude response"))
+        let agent = Claude {
+            model: model.to_string(),
+            ..self.clone()
+        };
+        agent
+            .prompt(user_prompt)
+            .preamble(system_prompt)
+            .output::<T>()
+            .send()
+            .await
     }
 
     pub async fn chat_completion(
@@ -175,6 +181,17 @@ impl Agent for Claude {
     }
 }
 
+// =============================================================================
+// VisionAgent Implementation
+// =============================================================================
+
+#[async_trait]
+impl VisionAgent for Claude {
+    async fn describe_image(&self, bytes: &[u8], mime_type: &str, prompt: &str) -> Result<String> {
+        Claude::describe_image(self, bytes, mime_type, prompt).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +209,26 @@ mod tests {
             .with_base_url("https://custom.api.com");
         assert_eq!(ai.base_url, Some("https://custom.api.com".to_string()));
     }
+
+    #[test]
+    fn cacheable_prefix_is_sent_as_a_cached_system_block_ahead_of_the_preamble() {
+        use crate::traits::PromptBuilder;
+
+        let builder = Claude::new("sk-ant-test", "claude-haiku-4-5-20251001")
+            .prompt("Extract this page")
+            .cacheable_prefix("Long extraction rules")
+            .preamble("Region notes");
+
+        let request = builder.apply_prompt(ChatRequest::new("claude-haiku-4-5-20251001"));
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json["system"],
+            serde_json::json!([
+                {"type": "text", "text": "Long extraction rules", "cache_control": {"type": "ephemeral"}},
+                {"type": "text", "text": "Region notes"},
+            ])
+        );
+        assert_eq!(json["messages"][0]["content"], "Extract this page");
+    }
 }
@@ -4,10 +4,12 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::openai::StructuredOutput;
-use crate::traits::{Message, MessageRole, OutputBuilder, PromptBuilder};
+use crate::structured::{parse_structured, PARSE_RETRIES};
+use crate::tool::ToolRunner;
+use crate::traits::{EarlyAbort, Message, MessageRole, OutputBuilder, PromptBuilder};
 
 use super::types::*;
 use super::Claude;
@@ -16,6 +18,7 @@ pub struct ClaudePromptBuilder {
     agent: Claude,
     input: String,
     preamble: Option<String>,
+    cacheable_prefix: Option<String>,
     temperature: Option<f32>,
     max_turns: usize,
     messages: Vec<Message>,
@@ -27,12 +30,41 @@ impl ClaudePromptBuilder {
             agent,
             input,
             preamble: None,
+            cacheable_prefix: None,
             temperature: None,
             max_turns: 1,
             messages: Vec::new(),
         }
     }
 
+    /// Set the system prompt and messages. Claude takes one top-level system
+    /// prompt: the cacheable prefix as its own cached block, then the preamble
+    /// and any system messages merged into a second block.
+    pub(crate) fn apply_prompt(&self, mut request: ChatRequest) -> ChatRequest {
+        if let Some(ref prefix) = self.cacheable_prefix {
+            request = request.cached_system(prefix);
+        }
+
+        let mut system: Vec<&str> = self.preamble.as_deref().into_iter().collect();
+        let mut messages = Vec::new();
+        for msg in &self.messages {
+            match msg.role {
+                MessageRole::System => system.push(&msg.content),
+                MessageRole::User => messages.push(WireMessage::user(&msg.content)),
+                MessageRole::Assistant => messages.push(WireMessage::assistant(&msg.content)),
+            }
+        }
+        if !system.is_empty() {
+            request = request.system(system.join("\n\n"));
+        }
+
+        if !self.input.is_empty() {
+            messages.push(WireMessage::user(&self.input));
+        }
+
+        request.messages(messages)
+    }
+
     pub fn output<T: DeserializeOwned + JsonSchema + Send + 'static>(
         self,
     ) -> ClaudeOutputBuilder<T> {
@@ -50,6 +82,11 @@ impl PromptBuilder for ClaudePromptBuilder {
         self
     }
 
+    fn cacheable_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.cacheable_prefix = Some(prefix.into());
+        self
+    }
+
     fn temperature(mut self, temperature: f32) -> Self {
         self.temperature = Some(temperature);
         self
@@ -74,34 +111,7 @@ impl PromptBuilder for ClaudePromptBuilder {
             request = request.temperature(temp);
         }
 
-        if let Some(ref preamble) = self.preamble {
-            request = request.system(preamble);
-        }
-
-        let mut messages = Vec::new();
-
-        for msg in &self.messages {
-            match msg.role {
-                MessageRole::System => {
-                    // Claude uses top-level system field, merge into it
-                    let existing = request.system.take().unwrap_or_default();
-                    let combined = if existing.is_empty() {
-                        msg.content.clone()
-                    } else {
-                        format!("{}\n\n{}", existing, msg.content)
-                    };
-                    request = request.system(combined);
-                }
-                MessageRole::User => messages.push(WireMessage::user(&msg.content)),
-                MessageRole::Assistant => messages.push(WireMessage::assistant(&msg.content)),
-            }
-        }
-
-        if !self.input.is_empty() {
-            messages.push(WireMessage::user(&self.input));
-        }
-
-        request = request.messages(messages);
+        request = self.apply_prompt(request);
 
         // Add tools
         for tool in &self.agent.tools {
@@ -118,6 +128,7 @@ impl PromptBuilder for ClaudePromptBuilder {
         }
 
         // Multi-turn tool loop
+        let mut runner = ToolRunner::new(&self.agent.tools, &self.agent.model, self.agent.tool_metrics.as_deref());
         let mut turn = 0;
         loop {
             turn += 1;
@@ -138,26 +149,17 @@ impl PromptBuilder for ClaudePromptBuilder {
                 let mut results = Vec::new();
                 for block in &tool_uses {
                     if let ContentBlock::ToolUse { id, name, input } = block {
-                        let tool = self
-                            .agent
-                            .tools
-                            .iter()
-                            .find(|t| t.name() == name.as_str())
-                            .ok_or_else(|| anyhow!("Tool not found: {}", name))?;
-
-                        debug!(tool = %name, "Executing tool call");
-
-                        let result = match tool.call_json(input.clone()).await {
-                            Ok(v) => serde_json::to_string(&v)?,
-                            Err(e) => format!("Error: {}", e),
-                        };
-
+                        let reply = runner.call(name, Ok(input.clone())).await;
                         results.push(ContentBlock::ToolResult {
                             tool_use_id: id.clone(),
-                            content: result,
+                            content: reply.content,
+                            is_error: reply.is_error,
                         });
                     }
                 }
+                if runner.take_repair_turn() {
+                    turn -= 1;
+                }
 
                 request.messages.push(WireMessage::tool_results(results));
                 continue;
@@ -166,6 +168,17 @@ impl PromptBuilder for ClaudePromptBuilder {
             return Ok(response.text().unwrap_or_default());
         }
     }
+
+    async fn send_streaming(self, abort: &dyn EarlyAbort) -> Result<String> {
+        let mut request = ChatRequest::new(&self.agent.model);
+        if let Some(temp) = self.temperature {
+            request = request.temperature(temp);
+        }
+        request = self.apply_prompt(request);
+
+        let state = self.agent.client().chat_stream(&request, abort).await?;
+        Ok(state.text)
+    }
 }
 
 // =============================================================================
@@ -182,6 +195,52 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
     for ClaudeOutputBuilder<T>
 {
     async fn send(self) -> Result<T> {
+        let mut request = self.request();
+        let client = self.builder.agent.client();
+
+        let mut retries = PARSE_RETRIES;
+        loop {
+            let response = client.chat(&request).await?;
+
+            // Extract the tool use input as our structured output
+            let Some((id, input)) = response.content.iter().find_map(|block| match block {
+                ContentBlock::ToolUse { id, input, .. } => Some((id.clone(), input.to_string())),
+                _ => None,
+            }) else {
+                return Err(anyhow!("No structured output in Claude response"));
+            };
+
+            match parse_structured::<T>(&input) {
+                Ok(output) => return Ok(output),
+                Err(e) if retries > 0 => {
+                    retries -= 1;
+                    warn!(error = %e, "Structured output failed to deserialize, retrying with feedback");
+                    request.messages.push(WireMessage::assistant_blocks(response.content.clone()));
+                    request.messages.push(WireMessage::tool_results(vec![ContentBlock::ToolResult {
+                        tool_use_id: id,
+                        content: e.feedback(),
+                        is_error: true,
+                    }]));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    async fn send_streaming(self, abort: &dyn EarlyAbort) -> Result<T> {
+        let request = self.request();
+        let state = self.builder.agent.client().chat_stream(&request, abort).await?;
+
+        if state.tool_name.is_none() {
+            return Err(anyhow!("No structured output in Claude response"));
+        }
+        Ok(parse_structured(&state.tool_json)?)
+    }
+}
+
+impl<T: DeserializeOwned + JsonSchema + Send + 'static> ClaudeOutputBuilder<T> {
+    /// A request that forces a tool call whose input is `T`.
+    fn request(&self) -> ChatRequest {
         let schema = T::openai_schema();
 
         debug!(
@@ -189,38 +248,10 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
             "Claude structured output extraction"
         );
 
-        let client = self.builder.agent.client();
-
         let mut request = ChatRequest::new(&self.builder.agent.model)
             .temperature(0.0); // Structured extraction must be deterministic
 
-        if let Some(ref preamble) = self.builder.preamble {
-            request = request.system(preamble);
-        }
-
-        let mut messages = Vec::new();
-
-        for msg in &self.builder.messages {
-            match msg.role {
-                MessageRole::System => {
-                    let existing = request.system.take().unwrap_or_default();
-                    let combined = if existing.is_empty() {
-                        msg.content.clone()
-                    } else {
-                        format!("{}\n\n{}", existing, msg.content)
-                    };
-                    request = request.system(combined);
-                }
-                MessageRole::User => messages.push(WireMessage::user(&msg.content)),
-                MessageRole::Assistant => messages.push(WireMessage::assistant(&msg.content)),
-            }
-        }
-
-        if !self.builder.input.is_empty() {
-            messages.push(WireMessage::user(&self.builder.input));
-        }
-
-        request = request.messages(messages);
+        request = self.builder.apply_prompt(request);
 
         // Use forced tool use for structured output
         let tool_name = "structured_response";
@@ -233,17 +264,6 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
             "type": "tool",
             "name": tool_name,
         }));
-
-        let response = client.chat(&request).await?;
-
-        // Extract the tool use input as our structured output
-        for block in &response.content {
-            if let ContentBlock::ToolUse { input, .. } = block {
-                return serde_json::from_value(input.clone())
-                    .map_err(|e| anyhow!("Failed to deserialize response: {}", e));
-            }
-        }
-
-        Err(anyhow!("No structured output in Claude response"))
+        request
     }
 }
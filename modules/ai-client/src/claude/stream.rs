@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use super::types::Usage;
+
+/// Splits a Messages API event stream into the JSON payloads of its events.
+#[derive(Default)]
+pub(crate) struct SseParser {
+    buffer: String,
+}
+
+impl SseParser {
+    /// Feed bytes as they arrive; returns the `data:` payloads of every event
+    /// completed by this chunk.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer
+            .push_str(&String::from_utf8_lossy(chunk).replace("\r\n", "\n"));
+        let mut payloads = Vec::new();
+        while let Some(end) = self.buffer.find("\n\n") {
+            let event: String = self.buffer.drain(..end + 2).collect();
+            let data: Vec<&str> = event
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(str::trim_start)
+                .collect();
+            if !data.is_empty() {
+                payloads.push(data.join("\n"));
+            }
+        }
+        payloads
+    }
+}
+
+/// A streamed response assembled so far.
+#[derive(Debug, Default)]
+pub(crate) struct StreamState {
+    /// Text blocks, concatenated.
+    pub text: String,
+    /// The input of the first tool call, as partial JSON.
+    pub tool_json: String,
+    pub tool_name: Option<String>,
+    pub stop_reason: Option<String>,
+    pub usage: Option<Usage>,
+}
+
+impl StreamState {
+    /// The output a caller asked for: tool input when a tool is being called,
+    /// text otherwise.
+    pub fn output(&self) -> &str {
+        if self.tool_name.is_some() {
+            &self.tool_json
+        } else {
+            &self.text
+        }
+    }
+
+    /// Apply one event. Returns `true` once the message is complete.
+    pub fn apply(&mut self, payload: &str) -> Result<bool> {
+        let event: Value = serde_json::from_str(payload)?;
+        match event["type"].as_str() {
+            Some("message_start") => {
+                self.usage = serde_json::from_value(event["message"]["usage"].clone()).ok();
+            }
+            Some("content_block_start") => {
+                let block = &event["content_block"];
+                match block["type"].as_str() {
+                    Some("tool_use") if self.tool_name.is_none() => {
+                        self.tool_name = block["name"].as_str().map(str::to_string);
+                    }
+                    Some("text") => self.text.push_str(block["text"].as_str().unwrap_or_default()),
+                    _ => {}
+                }
+            }
+            Some("content_block_delta") => {
+                let delta = &event["delta"];
+                match delta["type"].as_str() {
+                    Some("text_delta") => self.text.push_str(delta["text"].as_str().unwrap_or_default()),
+                    Some("input_json_delta") => {
+                        self.tool_json.push_str(delta["partial_json"].as_str().unwrap_or_default())
+                    }
+                    _ => {}
+                }
+            }
+            Some("message_delta") => {
+                self.stop_reason = event["delta"]["stop_reason"].as_str().map(str::to_string);
+                if let (Some(usage), Some(output)) = (self.usage.as_mut(), event["usage"]["output_tokens"].as_u64()) {
+                    usage.output_tokens = output as u32;
+                }
+            }
+            Some("message_stop") => return Ok(true),
+            Some("error") => {
+                return Err(anyhow!(
+                    "Claude stream error: {}",
+                    event["error"]["message"].as_str().unwrap_or("unknown")
+                ));
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tool_input_is_assembled_across_split_chunks() {
+        let events = concat!(
+            "event: message_start\ndata: {\"type\":\"message_start\",\"message\":{\"usage\":{\"input_tokens\":900,\"output_tokens\":1}}}\n\n",
+            "event: content_block_start\ndata: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"tool_use\",\"id\":\"t1\",\"name\":\"structured_response\",\"input\":{}}}\n\n",
+            "event: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"signals\\\": \"}}\n\n",
+            "event: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"[]}\"}}\n\n",
+            "event: message_delta\ndata: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"tool_use\"},\"usage\":{\"output_tokens\":12}}\n\n",
+            "event: message_stop\ndata: {\"type\":\"message_stop\"}\n\n",
+        );
+        let mut parser = SseParser::default();
+        let mut state = StreamState::default();
+
+        let mut done = false;
+        for chunk in events.as_bytes().chunks(37) {
+            for payload in parser.push(chunk) {
+                done = state.apply(&payload).unwrap();
+            }
+        }
+
+        assert!(done);
+        assert_eq!(state.output(), "{\"signals\": []}");
+        assert_eq!(state.stop_reason.as_deref(), Some("tool_use"));
+        let usage = state.usage.unwrap();
+        assert_eq!((usage.input_tokens, usage.output_tokens), (900, 12));
+    }
+}
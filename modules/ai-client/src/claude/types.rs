@@ -41,6 +41,8 @@ pub(crate) enum ContentBlock {
     ToolResult {
         tool_use_id: String,
         content: String,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        is_error: bool,
     },
 }
 
@@ -92,6 +94,37 @@ impl WireMessage {
     }
 }
 
+// =============================================================================
+// System Prompt
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct SystemBlock {
+    #[serde(rename = "type")]
+    pub block_type: &'static str,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+/// Marks the end of a prompt prefix to cache. Everything up to and including
+/// the marked block (tools, then system) is cached for about five minutes.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CacheControl {
+    #[serde(rename = "type")]
+    pub cache_type: &'static str,
+}
+
+impl SystemBlock {
+    fn text(text: String, cached: bool) -> Self {
+        Self {
+            block_type: "text",
+            text,
+            cache_control: cached.then_some(CacheControl { cache_type: "ephemeral" }),
+        }
+    }
+}
+
 // =============================================================================
 // Tool Definition
 // =============================================================================
@@ -112,8 +145,8 @@ pub(crate) struct ChatRequest {
     pub model: String,
     pub max_tokens: u32,
     pub messages: Vec<WireMessage>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub system: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub system: Vec<SystemBlock>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -128,15 +161,22 @@ impl ChatRequest {
             model: model.into(),
             max_tokens: 4096,
             messages: Vec::new(),
-            system: None,
+            system: Vec::new(),
             temperature: None,
             tools: None,
             tool_choice: None,
         }
     }
 
+    /// Append a system prompt block.
     pub fn system(mut self, system: impl Into<String>) -> Self {
-        self.system = Some(system.into());
+        self.system.push(SystemBlock::text(system.into(), false));
+        self
+    }
+
+    /// Append a system prompt block that ends a cached prefix.
+    pub fn cached_system(mut self, system: impl Into<String>) -> Self {
+        self.system.push(SystemBlock::text(system.into(), true));
         self
     }
 
@@ -175,7 +215,6 @@ pub(crate) struct ChatResponse {
     pub content: Vec<ContentBlock>,
     pub stop_reason: Option<String>,
     #[serde(default)]
-    #[allow(dead_code)]
     pub usage: Option<Usage>,
 }
 
@@ -202,4 +241,19 @@ impl ChatResponse {
 pub(crate) struct Usage {
     pub input_tokens: u32,
     pub output_tokens: u32,
+    #[serde(default)]
+    pub cache_creation_input_tokens: u32,
+    #[serde(default)]
+    pub cache_read_input_tokens: u32,
+}
+
+impl From<&Usage> for crate::usage::TokenUsage {
+    fn from(usage: &Usage) -> Self {
+        Self {
+            input_tokens: usage.input_tokens.into(),
+            output_tokens: usage.output_tokens.into(),
+            cache_write_tokens: usage.cache_creation_input_tokens.into(),
+            cache_read_tokens: usage.cache_read_input_tokens.into(),
+        }
+    }
 }
@@ -19,6 +19,11 @@ pub enum AiError {
 
     #[error("Max turns exceeded: {0}")]
     MaxTurns(usize),
+
+    /// A streamed response was cut off by an [`EarlyAbort`](crate::EarlyAbort)
+    /// predicate. `partial` is the output received up to that point.
+    #[error("Generation aborted early: {reason}")]
+    Aborted { reason: String, partial: String },
 }
 
 impl From<reqwest::Error> for AiError {
@@ -92,6 +92,29 @@ impl OpenAiClient {
     }
 
     pub async fn transcribe(&self, bytes: Vec<u8>, mime_type: &str) -> Result<String> {
+        let response = self.transcription_request(bytes, mime_type, "text").await?;
+        Ok(response.text().await?)
+    }
+
+    /// Whisper transcription with segment timestamps (`verbose_json`).
+    pub async fn transcribe_segments(
+        &self,
+        bytes: Vec<u8>,
+        mime_type: &str,
+    ) -> Result<Vec<TranscriptionSegment>> {
+        let response = self
+            .transcription_request(bytes, mime_type, "verbose_json")
+            .await?;
+        let body: VerboseTranscription = response.json().await?;
+        Ok(body.segments)
+    }
+
+    async fn transcription_request(
+        &self,
+        bytes: Vec<u8>,
+        mime_type: &str,
+        response_format: &str,
+    ) -> Result<reqwest::Response> {
         let url = format!("{}/audio/transcriptions", self.base_url);
 
         // Derive file extension from mime type for the form filename
@@ -111,7 +134,7 @@ impl OpenAiClient {
 
         let form = reqwest::multipart::Form::new()
             .text("model", "whisper-1")
-            .text("response_format", "text")
+            .text("response_format", response_format.to_string())
             .part("file", part);
 
         debug!("OpenAI Whisper transcription request");
@@ -130,7 +153,7 @@ impl OpenAiClient {
             return Err(anyhow!("Whisper API error ({}): {}", status, error_text));
         }
 
-        Ok(response.text().await?)
+        Ok(response)
     }
 
     pub async fn embed(&self, model: &str, text: &str) -> Result<Vec<f32>> {
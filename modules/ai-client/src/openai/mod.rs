@@ -5,9 +5,10 @@ pub(crate) mod types;
 
 pub use prompt_builder::{OpenAiOutputBuilder, OpenAiPromptBuilder};
 pub use schema::StructuredOutput;
+pub use types::TranscriptionSegment;
 
-use crate::tool::{DynTool, Tool, ToolWrapper};
-use crate::traits::{Agent, EmbedAgent};
+use crate::tool::{DynTool, Tool, ToolCallMetrics, ToolWrapper};
+use crate::traits::{Agent, EmbedAgent, OutputBuilder, PromptBuilder};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -24,6 +25,7 @@ pub struct OpenAi {
     pub(crate) model: String,
     embedding_model: String,
     pub(crate) tools: Vec<Arc<dyn DynTool>>,
+    pub(crate) tool_metrics: Option<Arc<ToolCallMetrics>>,
     base_url: Option<String>,
 }
 
@@ -34,6 +36,7 @@ impl OpenAi {
             model: model.into(),
             embedding_model: "text-embedding-3-small".to_string(),
             tools: Vec::new(),
+            tool_metrics: None,
             base_url: None,
         }
     }
@@ -49,6 +52,12 @@ impl OpenAi {
         self
     }
 
+    /// Count tool calls, malformed calls and repairs per tool into `metrics`.
+    pub fn with_tool_metrics(mut self, metrics: Arc<ToolCallMetrics>) -> Self {
+        self.tool_metrics = Some(metrics);
+        self
+    }
+
     pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
         self.base_url = Some(url.into());
         self
@@ -78,39 +87,22 @@ impl OpenAi {
     // =========================================================================
 
     /// Type-safe structured output extraction (convenience method).
-    pub async fn extract<T: StructuredOutput>(
+    pub async fn extract<T: StructuredOutput + Send + 'static>(
         &self,
         model: &str,
         system_prompt: impl Into<String>,
         user_prompt: impl Into<String>,
     ) -> Result<T> {
-        let schema = T::openai_schema();
-
-        let request = types::StructuredRequest {
+        let agent = OpenAi {
             model: model.to_string(),
-            messages: vec![
-                types::WireMessage::system(system_prompt),
-                types::WireMessage::user(user_prompt),
-            ],
-            temperature: if model.starts_with("gpt-5") {
-                None
-            } else {
-                Some(0.0)
-            },
-            response_format: types::ResponseFormat {
-                format_type: "json_schema".to_string(),
-                json_schema: types::JsonSchemaFormat {
-                    name: "structured_response".to_string(),
-                    strict: true,
-                    schema,
-                },
-            },
+            ..self.clone()
         };
-
-        let json_str = self.client().structured_output(&request).await?;
-
-        serde_json::from_str(&json_str)
-            .map_err(|e| anyhow!("Failed to deserialize response: {}", e))
+        agent
+            .prompt(user_prompt)
+            .preamble(system_prompt)
+            .output::<T>()
+            .send()
+            .await
     }
 
     /// Simple chat completion (convenience method).
@@ -151,6 +143,15 @@ impl OpenAi {
         self.client().transcribe(bytes, mime_type).await
     }
 
+    /// Transcribe audio/video via Whisper, keeping segment timestamps.
+    pub async fn transcribe_segments(
+        &self,
+        bytes: Vec<u8>,
+        mime_type: &str,
+    ) -> Result<Vec<TranscriptionSegment>> {
+        self.client().transcribe_segments(bytes, mime_type).await
+    }
+
     /// Create embedding for text.
     pub async fn create_embedding(&self, text: &str, model: &str) -> Result<Vec<f32>> {
         self.client().embed(model, text).await
@@ -4,8 +4,10 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
-use tracing::debug;
+use tracing::{debug, warn};
 
+use crate::structured::{parse_structured, PARSE_RETRIES};
+use crate::tool::ToolRunner;
 use crate::traits::{Message, MessageRole, OutputBuilder, PromptBuilder};
 
 use super::schema::StructuredOutput;
@@ -16,6 +18,7 @@ pub struct OpenAiPromptBuilder {
     agent: OpenAi,
     input: String,
     preamble: Option<String>,
+    cacheable_prefix: Option<String>,
     temperature: Option<f32>,
     max_turns: usize,
     messages: Vec<Message>,
@@ -27,6 +30,7 @@ impl OpenAiPromptBuilder {
             agent,
             input,
             preamble: None,
+            cacheable_prefix: None,
             temperature: None,
             max_turns: 1,
             messages: Vec::new(),
@@ -51,6 +55,11 @@ impl PromptBuilder for OpenAiPromptBuilder {
         self
     }
 
+    fn cacheable_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.cacheable_prefix = Some(prefix.into());
+        self
+    }
+
     fn temperature(mut self, temperature: f32) -> Self {
         self.temperature = Some(temperature);
         self
@@ -71,6 +80,11 @@ impl PromptBuilder for OpenAiPromptBuilder {
 
         let mut messages = Vec::new();
 
+        // Sent first and unchanged so the provider's automatic prefix cache applies
+        if let Some(ref prefix) = self.cacheable_prefix {
+            messages.push(WireMessage::system(prefix));
+        }
+
         if let Some(ref preamble) = self.preamble {
             messages.push(WireMessage::system(preamble));
         }
@@ -108,6 +122,7 @@ impl PromptBuilder for OpenAiPromptBuilder {
         }
 
         // Multi-turn tool loop
+        let mut runner = ToolRunner::new(&self.agent.tools, &self.agent.model, self.agent.tool_metrics.as_deref());
         let mut turn = 0;
         loop {
             turn += 1;
@@ -134,24 +149,12 @@ impl PromptBuilder for OpenAiPromptBuilder {
 
                     // Execute tools and add results
                     for tc in tool_calls {
-                        let tool = self
-                            .agent
-                            .tools
-                            .iter()
-                            .find(|t| t.name() == tc.function.name)
-                            .ok_or_else(|| anyhow!("Tool not found: {}", tc.function.name))?;
-
-                        let args: serde_json::Value =
-                            serde_json::from_str(&tc.function.arguments).unwrap_or_default();
-
-                        debug!(tool = %tc.function.name, "Executing tool call");
-
-                        let result = match tool.call_json(args).await {
-                            Ok(v) => serde_json::to_string(&v)?,
-                            Err(e) => format!("Error: {}", e),
-                        };
-
-                        request.messages.push(WireMessage::tool(&tc.id, &result));
+                        let args = serde_json::from_str(&tc.function.arguments).map_err(|e| e.to_string());
+                        let reply = runner.call(&tc.function.name, args).await;
+                        request.messages.push(WireMessage::tool(&tc.id, &reply.content));
+                    }
+                    if runner.take_repair_turn() {
+                        turn -= 1;
                     }
 
                     continue;
@@ -188,6 +191,11 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
 
         let mut messages = Vec::new();
 
+        // Sent first and unchanged so the provider's automatic prefix cache applies
+        if let Some(ref prefix) = self.builder.cacheable_prefix {
+            messages.push(WireMessage::system(prefix));
+        }
+
         if let Some(ref preamble) = self.builder.preamble {
             messages.push(WireMessage::system(preamble));
         }
@@ -211,7 +219,7 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
             Some(0.0)
         };
 
-        let request = StructuredRequest {
+        let mut request = StructuredRequest {
             model: model.clone(),
             messages,
             temperature,
@@ -225,9 +233,19 @@ impl<T: DeserializeOwned + JsonSchema + Send + 'static> OutputBuilder<T>
             },
         };
 
-        let json_str = client.structured_output(&request).await?;
-
-        serde_json::from_str(&json_str)
-            .map_err(|e| anyhow!("Failed to deserialize response: {}", e))
+        let mut retries = PARSE_RETRIES;
+        loop {
+            let json_str = client.structured_output(&request).await?;
+            match parse_structured::<T>(&json_str) {
+                Ok(output) => return Ok(output),
+                Err(e) if retries > 0 => {
+                    retries -= 1;
+                    warn!(error = %e, "Structured output failed to deserialize, retrying with feedback");
+                    request.messages.push(WireMessage::assistant(&json_str));
+                    request.messages.push(WireMessage::user(e.feedback()));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
     }
 }
@@ -262,3 +262,22 @@ pub(crate) fn uses_max_completion_tokens(model: &str) -> bool {
         || model.contains("-o1")
         || model.contains("-o3")
 }
+
+// =============================================================================
+// Transcription
+// =============================================================================
+
+/// One timed segment of a Whisper `verbose_json` transcription.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptionSegment {
+    /// Seconds from the start of the audio.
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct VerboseTranscription {
+    #[serde(default)]
+    pub segments: Vec<TranscriptionSegment>,
+}
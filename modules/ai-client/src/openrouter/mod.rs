@@ -4,8 +4,8 @@ pub(crate) mod types;
 
 pub use prompt_builder::{OpenRouterOutputBuilder, OpenRouterPromptBuilder};
 
-use crate::tool::{DynTool, Tool, ToolWrapper};
-use crate::traits::{Agent, EmbedAgent};
+use crate::tool::{DynTool, Tool, ToolCallMetrics, ToolWrapper};
+use crate::traits::{Agent, EmbedAgent, OutputBuilder, PromptBuilder};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -23,6 +23,7 @@ pub struct OpenRouter {
     app_name: Option<String>,
     site_url: Option<String>,
     pub(crate) tools: Vec<Arc<dyn DynTool>>,
+    pub(crate) tool_metrics: Option<Arc<ToolCallMetrics>>,
 }
 
 impl OpenRouter {
@@ -33,6 +34,7 @@ impl OpenRouter {
             app_name: None,
             site_url: None,
             tools: Vec::new(),
+            tool_metrics: None,
         }
     }
 
@@ -52,6 +54,12 @@ impl OpenRouter {
         self
     }
 
+    /// Count tool calls, malformed calls and repairs per tool into `metrics`.
+    pub fn with_tool_metrics(mut self, metrics: Arc<ToolCallMetrics>) -> Self {
+        self.tool_metrics = Some(metrics);
+        self
+    }
+
     /// Get the model name.
     pub fn model(&self) -> &str {
         &self.model
@@ -73,32 +81,22 @@ impl OpenRouter {
     // =========================================================================
 
     /// Type-safe structured output extraction.
-    pub async fn extract<T: crate::openai::StructuredOutput>(
+    pub async fn extract<T: crate::openai::StructuredOutput + Send + 'static>(
         &self,
         model: &str,
         system_prompt: impl Into<String>,
         user_prompt: impl Into<String>,
     ) -> Result<T> {
-        let schema = T::openai_schema();
-
-        let mut request = types::ChatRequest::new(model).messages(vec![
-            types::WireMessage::system(system_prompt),
-            types::WireMessage::user(user_prompt),
-        ]);
-        request.temperature = Some(0.0);
-        request.response_format = Some(serde_json::json!({
-            "type": "json_schema",
-            "json_schema": {
-                "name": "structured_response",
-                "strict": true,
-                "schema": schema,
-            }
-        }));
-
-        let json_str = self.client().structured_output(&request).await?;
-
-        serde_json::from_str(&json_str)
-            .map_err(|e| anyhow!("Failed to deserialize response: {}", e))
+        let agent = OpenRouter {
+            model: model.to_string(),
+            ..self.clone()
+        };
+        agent
+            .prompt(user_prompt)
+            .preamble(system_prompt)
+            .output::<T>()
+            .send()
+            .await
     }
 
     /// Simple chat completion.
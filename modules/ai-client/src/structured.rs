@@ -0,0 +1,97 @@
+//! Structured output: schema-constrained generation (forced tool use for
+//! Claude, `response_format` for OpenAI and OpenRouter), typed deserialization
+//! with error context, and a retry that feeds the error back to the model.
+
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::util::strip_code_blocks;
+
+/// Retries after an output fails to deserialize. Each retry shows the model
+/// its previous output and what was wrong with it.
+pub const PARSE_RETRIES: usize = 1;
+
+/// Model output that didn't deserialize into the requested type.
+#[derive(Debug, Error)]
+#[error("{type_name} did not deserialize at {path}: {message} (near `{excerpt}`)")]
+pub struct StructuredOutputError {
+    pub type_name: String,
+    /// JSON path of the failing value, e.g. `signals[2].urgency`.
+    pub path: String,
+    pub message: String,
+    /// The output around the failure.
+    pub excerpt: String,
+    /// The full output, for diagnostics.
+    pub raw: String,
+}
+
+impl StructuredOutputError {
+    /// What the model is told on a retry.
+    pub fn feedback(&self) -> String {
+        format!(
+            "Your previous output did not match the required schema: at `{}`, {}. \
+             Return the complete output again, corrected, matching the schema exactly.",
+            self.path, self.message
+        )
+    }
+}
+
+/// Deserialize model output into `T`. A markdown code fence around the JSON
+/// is tolerated.
+pub fn parse_structured<T: DeserializeOwned>(raw: &str) -> Result<T, StructuredOutputError> {
+    let json = strip_code_blocks(raw);
+    let de = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize(de).map_err(|e| {
+        let path = e.path().to_string();
+        let inner = e.into_inner();
+        StructuredOutputError {
+            type_name: std::any::type_name::<T>().rsplit("::").next().unwrap_or_default().to_string(),
+            path,
+            excerpt: excerpt(json, inner.line(), inner.column()),
+            message: inner.to_string(),
+            raw: raw.to_string(),
+        }
+    })
+}
+
+/// Up to 40 characters either side of a 1-based line and column.
+fn excerpt(json: &str, line: usize, column: usize) -> String {
+    let Some(text) = json.lines().nth(line.saturating_sub(1)) else {
+        return String::new();
+    };
+    let chars: Vec<char> = text.chars().collect();
+    let at = column.saturating_sub(1).min(chars.len());
+    chars[at.saturating_sub(40)..(at + 40).min(chars.len())].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Plan {
+        queries: Vec<Query>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Query {
+        text: String,
+        priority: u8,
+    }
+
+    #[test]
+    fn failure_names_the_path_and_shows_where() {
+        let raw = "```json\n{\"queries\": [{\"text\": \"food shelf\", \"priority\": 1}, {\"text\": \"tenant union\", \"priority\": \"high\"}]}\n```";
+
+        let err = parse_structured::<Plan>(raw).unwrap_err();
+
+        assert_eq!(err.type_name, "Plan");
+        assert_eq!(err.path, "queries[1].priority");
+        assert!(err.excerpt.contains("\"high\""));
+        assert!(err.feedback().contains("queries[1].priority"));
+        assert!(parse_structured::<Plan>("{\"queries\": []}").is_ok());
+    }
+}
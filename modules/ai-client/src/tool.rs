@@ -2,7 +2,10 @@ use async_trait::async_trait;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ToolDefinition {
@@ -55,6 +58,220 @@ impl<T: Tool> DynTool for ToolWrapper<T> {
     }
 }
 
+// =============================================================================
+// Argument Validation
+// =============================================================================
+
+/// Check `args` against a tool's JSON Schema. Covers the keywords tool
+/// definitions use: `type`, `properties`, `required`, `items`, `enum` and
+/// `additionalProperties: false`. Returns every violation, by JSON path.
+pub fn validate_args(schema: &Value, args: &Value) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    validate_at(schema, args, "$", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let types: Vec<&str> = match &schema["type"] {
+        Value::String(t) => vec![t.as_str()],
+        Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| type_matches(t, value)) {
+        errors.push(format!("{path}: expected {}, got {value}", types.join(" or ")));
+        return;
+    }
+
+    if let Some(allowed) = schema["enum"].as_array() {
+        if !allowed.contains(value) {
+            errors.push(format!("{path}: {value} is not one of {}", Value::Array(allowed.clone())));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema["properties"].as_object();
+        for required in schema["required"].as_array().into_iter().flatten().filter_map(Value::as_str) {
+            if !object.contains_key(required) {
+                errors.push(format!("{path}: missing required field `{required}`"));
+            }
+        }
+        for (key, field) in object {
+            match properties.and_then(|p| p.get(key)) {
+                Some(field_schema) => validate_at(field_schema, field, &format!("{path}.{key}"), errors),
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    errors.push(format!("{path}: unexpected field `{key}`"));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in array.iter().enumerate() {
+            validate_at(items, item, &format!("{path}[{i}]"), errors);
+        }
+    }
+}
+
+// =============================================================================
+// Tool Call Metrics
+// =============================================================================
+
+/// Call counts for one tool under one model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ToolCallStats {
+    pub calls: u64,
+    /// Calls with an unknown tool name or arguments that failed validation.
+    pub malformed: u64,
+    /// Malformed calls the model got right on its repair round-trip.
+    pub repaired: u64,
+}
+
+impl ToolCallStats {
+    pub fn malformed_rate(&self) -> f64 {
+        match self.calls {
+            0 => 0.0,
+            calls => self.malformed as f64 / calls as f64,
+        }
+    }
+}
+
+/// Tool call counts keyed by (model, tool), shared across agents.
+#[derive(Debug, Default)]
+pub struct ToolCallMetrics {
+    stats: Mutex<BTreeMap<(String, String), ToolCallStats>>,
+}
+
+impl ToolCallMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, model: &str, tool: &str, malformed: bool, repaired: bool) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = stats.entry((model.to_string(), tool.to_string())).or_default();
+        entry.calls += 1;
+        entry.malformed += u64::from(malformed);
+        entry.repaired += u64::from(repaired);
+    }
+
+    /// Every (model, tool) pair seen so far.
+    pub fn snapshot(&self) -> BTreeMap<(String, String), ToolCallStats> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+// =============================================================================
+// Tool Runner
+// =============================================================================
+
+/// A tool result to send back to the model.
+pub(crate) struct ToolReply {
+    pub content: String,
+    pub is_error: bool,
+}
+
+/// Runs an agent loop's tool calls. Arguments are validated against the
+/// tool's schema first; the first malformed call in a loop gets a repair
+/// round-trip that explains the problem and doesn't count toward max turns.
+pub(crate) struct ToolRunner<'a> {
+    tools: &'a [Arc<dyn DynTool>],
+    model: &'a str,
+    metrics: Option<&'a ToolCallMetrics>,
+    repair_used: bool,
+    repair_turn: bool,
+    repairing: Option<String>,
+}
+
+impl<'a> ToolRunner<'a> {
+    pub fn new(tools: &'a [Arc<dyn DynTool>], model: &'a str, metrics: Option<&'a ToolCallMetrics>) -> Self {
+        Self {
+            tools,
+            model,
+            metrics,
+            repair_used: false,
+            repair_turn: false,
+            repairing: None,
+        }
+    }
+
+    /// Run one call. `args` is `Err` when the arguments weren't valid JSON.
+    pub async fn call(&mut self, name: &str, args: Result<Value, String>) -> ToolReply {
+        let tool = self.tools.iter().find(|t| t.name() == name);
+        let checked = match (tool, args) {
+            (None, _) => Err(format!("unknown tool `{name}`")),
+            (Some(_), Err(e)) => Err(format!("arguments are not valid JSON: {e}")),
+            (Some(tool), Ok(args)) => match validate_args(&tool.definition().await.parameters, &args) {
+                Ok(()) => Ok((tool, args)),
+                Err(errors) => Err(errors.join("; ")),
+            },
+        };
+
+        let (tool, args) = match checked {
+            Ok(call) => call,
+            Err(error) => {
+                warn!(model = self.model, tool = name, %error, "Malformed tool call");
+                self.record(name, true, false);
+                if self.repair_used {
+                    return ToolReply { content: format!("Error: {error}"), is_error: true };
+                }
+                self.repair_used = true;
+                self.repair_turn = true;
+                self.repairing = Some(name.to_string());
+                return ToolReply {
+                    content: format!(
+                        "Invalid call to `{name}`: {error}. Call it again with arguments that match its schema."
+                    ),
+                    is_error: true,
+                };
+            }
+        };
+
+        let repaired = self.repairing.as_deref() == Some(name);
+        if repaired {
+            self.repairing = None;
+        }
+        self.record(name, false, repaired);
+
+        debug!(tool = %name, "Executing tool call");
+        match tool.call_json(args).await {
+            Ok(v) => ToolReply {
+                content: serde_json::to_string(&v).unwrap_or_default(),
+                is_error: false,
+            },
+            Err(e) => ToolReply { content: format!("Error: {}", e), is_error: true },
+        }
+    }
+
+    /// Whether the turn just run asked for a repair, so the next turn is free.
+    pub fn take_repair_turn(&mut self) -> bool {
+        std::mem::take(&mut self.repair_turn)
+    }
+
+    fn record(&self, tool: &str, malformed: bool, repaired: bool) {
+        if let Some(metrics) = self.metrics {
+            metrics.record(self.model, tool, malformed, repaired);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +330,51 @@ mod tests {
             .unwrap();
         assert_eq!(result, serde_json::json!("hello"));
     }
+
+    #[test]
+    fn validation_reports_every_violation_by_path() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string" },
+                "limit": { "type": "integer" },
+                "tags": { "type": "array", "items": { "type": "string", "enum": ["a", "b"] } }
+            },
+            "required": ["query"],
+            "additionalProperties": false
+        });
+
+        let errors = validate_args(&schema, &serde_json::json!({"limit": "10", "tags": ["a", "c"], "x": 1})).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                "$: missing required field `query`",
+                "$.limit: expected integer, got \"10\"",
+                "$.tags[1]: \"c\" is not one of [\"a\",\"b\"]",
+                "$: unexpected field `x`",
+            ]
+        );
+        assert!(validate_args(&schema, &serde_json::json!({"query": "food shelf"})).is_ok());
+    }
+
+    #[tokio::test]
+    async fn malformed_call_gets_one_repair_round_trip_and_is_counted() {
+        let tools: Vec<Arc<dyn DynTool>> = vec![Arc::new(ToolWrapper(EchoTool))];
+        let metrics = ToolCallMetrics::new();
+        let mut runner = ToolRunner::new(&tools, "haiku", Some(&metrics));
+
+        let first = runner.call("echo", Ok(serde_json::json!({"msg": "hi"}))).await;
+        let repair_turn = runner.take_repair_turn();
+        let repaired = runner.call("echo", Ok(serde_json::json!({"message": "hi"}))).await;
+        let second_bad = runner.call("echo", Ok(serde_json::json!({}))).await;
+
+        assert!(first.is_error && first.content.starts_with("Invalid call to `echo`"));
+        assert!(repair_turn);
+        assert_eq!(repaired.content, "\"hi\"");
+        assert!(second_bad.content.starts_with("Error:"));
+        assert!(!runner.take_repair_turn());
+        let stats = metrics.snapshot()[&("haiku".to_string(), "echo".to_string())];
+        assert_eq!(stats, ToolCallStats { calls: 3, malformed: 2, repaired: 1 });
+    }
 }
@@ -1,3 +1,4 @@
+use crate::error::AiError;
 use crate::tool::{DynTool, Tool};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -62,10 +63,53 @@ pub trait Agent: Clone + Send + Sync {
 #[async_trait]
 pub trait PromptBuilder: Send + Sized {
     fn preamble(self, preamble: impl Into<String>) -> Self;
+    /// A stable system prompt prefix, identical across calls, that the
+    /// provider may cache. Sent ahead of the preamble.
+    fn cacheable_prefix(self, prefix: impl Into<String>) -> Self;
     fn temperature(self, temperature: f32) -> Self;
     fn multi_turn(self, max_turns: usize) -> Self;
     fn messages(self, messages: Vec<Message>) -> Self;
     async fn send(self) -> Result<String>;
+
+    /// Stream the response, checking `abort` against the output so far and
+    /// stopping generation as soon as it objects. An abort is returned as
+    /// [`AiError::Aborted`] carrying the partial output. Tools are not run.
+    ///
+    /// Providers without streaming send normally and check the full output.
+    async fn send_streaming(self, abort: &dyn EarlyAbort) -> Result<String> {
+        let output = self.send().await?;
+        match abort.check(&output) {
+            Some(reason) => Err(AiError::Aborted { reason, partial: output }.into()),
+            None => Ok(output),
+        }
+    }
+}
+
+// =============================================================================
+// Streaming
+// =============================================================================
+
+/// Decides from a partial response whether to stop generating. Returns the
+/// reason to abort, or `None` to keep going.
+pub trait EarlyAbort: Send + Sync {
+    fn check(&self, partial: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync> EarlyAbort for F {
+    fn check(&self, partial: &str) -> Option<String> {
+        self(partial)
+    }
+}
+
+/// Aborts when the response starts in prose rather than JSON. A leading
+/// markdown code fence is allowed.
+pub fn json_only(partial: &str) -> Option<String> {
+    let start = partial.trim_start();
+    let first = start.chars().next()?;
+    if matches!(first, '{' | '[') || start.starts_with("```") || "```".starts_with(start) {
+        return None;
+    }
+    Some("answered in prose instead of JSON".to_string())
 }
 
 // =============================================================================
@@ -75,6 +119,16 @@ pub trait PromptBuilder: Send + Sized {
 #[async_trait]
 pub trait OutputBuilder<T>: Send {
     async fn send(self) -> Result<T>;
+
+    /// Like [`send`](OutputBuilder::send), but streams the structured output
+    /// as raw JSON through `abort`. Providers without streaming ignore `abort`.
+    async fn send_streaming(self, abort: &dyn EarlyAbort) -> Result<T>
+    where
+        Self: Sized,
+    {
+        let _ = abort;
+        self.send().await
+    }
 }
 
 // =============================================================================
@@ -86,3 +140,27 @@ pub trait EmbedAgent: Send + Sync {
     async fn embed(&self, text: impl Into<String> + Send) -> Result<Vec<f32>>;
     async fn embed_batch(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>>;
 }
+
+// =============================================================================
+// VisionAgent Trait
+// =============================================================================
+
+#[async_trait]
+pub trait VisionAgent: Send + Sync {
+    /// Answer `prompt` about an image, returning the model's text response.
+    async fn describe_image(&self, bytes: &[u8], mime_type: &str, prompt: &str) -> Result<String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_only_aborts_on_prose_but_waits_out_a_code_fence() {
+        assert_eq!(json_only("  "), None);
+        assert_eq!(json_only("``"), None);
+        assert_eq!(json_only("```json\n{\"a\""), None);
+        assert_eq!(json_only("[{\"title\""), None);
+        assert!(json_only("I'm sorry, but").is_some());
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Token counts for one or more calls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    /// Input tokens billed at the full rate.
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Input tokens written to the prompt cache (billed at 1.25x).
+    pub cache_write_tokens: u64,
+    /// Input tokens read from the prompt cache (billed at 0.1x).
+    pub cache_read_tokens: u64,
+}
+
+impl TokenUsage {
+    /// All input tokens, cached or not.
+    pub fn total_input_tokens(&self) -> u64 {
+        self.input_tokens + self.cache_write_tokens + self.cache_read_tokens
+    }
+
+    /// Share of input tokens served from the cache.
+    pub fn cache_hit_rate(&self) -> f64 {
+        match self.total_input_tokens() {
+            0 => 0.0,
+            total => self.cache_read_tokens as f64 / total as f64,
+        }
+    }
+
+    /// Full-rate input tokens saved by caching, net of the cache write
+    /// surcharge. Negative while the cache hasn't paid for itself yet.
+    pub fn saved_input_tokens(&self) -> f64 {
+        self.cache_read_tokens as f64 * 0.9 - self.cache_write_tokens as f64 * 0.25
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_write_tokens += other.cache_write_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+    }
+}
+
+/// Running token totals, shared across the clients that report into it.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    calls: AtomicU64,
+    input_tokens: AtomicU64,
+    output_tokens: AtomicU64,
+    cache_write_tokens: AtomicU64,
+    cache_read_tokens: AtomicU64,
+}
+
+impl UsageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, usage: TokenUsage) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.input_tokens.fetch_add(usage.input_tokens, Ordering::Relaxed);
+        self.output_tokens.fetch_add(usage.output_tokens, Ordering::Relaxed);
+        self.cache_write_tokens.fetch_add(usage.cache_write_tokens, Ordering::Relaxed);
+        self.cache_read_tokens.fetch_add(usage.cache_read_tokens, Ordering::Relaxed);
+    }
+
+    pub fn calls(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    pub fn totals(&self) -> TokenUsage {
+        TokenUsage {
+            input_tokens: self.input_tokens.load(Ordering::Relaxed),
+            output_tokens: self.output_tokens.load(Ordering::Relaxed),
+            cache_write_tokens: self.cache_write_tokens.load(Ordering::Relaxed),
+            cache_read_tokens: self.cache_read_tokens.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_reads_pay_back_the_write_surcharge() {
+        let tracker = UsageTracker::new();
+        let prefix = 4000;
+        tracker.record(TokenUsage { input_tokens: 500, output_tokens: 200, cache_write_tokens: prefix, cache_read_tokens: 0 });
+        for _ in 0..9 {
+            tracker.record(TokenUsage { input_tokens: 500, output_tokens: 200, cache_write_tokens: 0, cache_read_tokens: prefix });
+        }
+
+        let totals = tracker.totals();
+
+        assert_eq!(tracker.calls(), 10);
+        assert_eq!(totals.total_input_tokens(), 45_000);
+        assert!((totals.cache_hit_rate() - 0.8).abs() < 1e-9);
+        assert!((totals.saved_input_tokens() - (36_000.0 * 0.9 - 4000.0 * 0.25)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,221 @@
+//! Typed Apify actors.
+//!
+//! Each actor run is described by a unit struct: the actor's ID, the input
+//! it takes and the dataset items it returns. `ApifyClient::run` starts,
+//! waits for and fetches any of them, so a new platform needs only its
+//! input and output types in `types.rs` and an impl here.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::types::{
+    DiscoveredPost, FacebookComment, FacebookCommentsInput, FacebookEvent, FacebookEventsScraperInput,
+    FacebookPost, FacebookScraperInput, InstagramComment, InstagramCommentsInput, InstagramHashtagInput,
+    InstagramPost, InstagramScraperInput, RedditCommentsInput, RedditPost, RedditScraperInput,
+    TikTokComment, TikTokCommentsInput, TikTokPost, TikTokScraperInput, TikTokSearchInput, Tweet,
+    TweetScraperInput, TweetSearchInput,
+};
+
+/// An Apify actor run with a known input and output.
+pub trait ApifyActor {
+    /// Actor ID, or `user~name` slug.
+    const ID: &'static str;
+    type Input: Serialize + Sync;
+    type Output: DeserializeOwned;
+
+    /// The item as a platform-agnostic post. `None` for items that aren't
+    /// posts, or lack the content or author a post needs.
+    fn discover(_item: Self::Output) -> Option<DiscoveredPost> {
+        None
+    }
+}
+
+/// apify/instagram-post-scraper: a profile's posts.
+pub struct InstagramPosts;
+
+impl ApifyActor for InstagramPosts {
+    const ID: &'static str = "nH2AHrwxeTRJoN5hX";
+    type Input = InstagramScraperInput;
+    type Output = InstagramPost;
+
+    fn discover(item: InstagramPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// apify/instagram-hashtag-scraper: recent posts under hashtags.
+pub struct InstagramHashtags;
+
+impl ApifyActor for InstagramHashtags {
+    const ID: &'static str = "apify~instagram-hashtag-scraper";
+    type Input = InstagramHashtagInput;
+    type Output = InstagramPost;
+
+    fn discover(item: InstagramPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// apify/instagram-comment-scraper.
+pub struct InstagramComments;
+
+impl ApifyActor for InstagramComments {
+    const ID: &'static str = "apify~instagram-comment-scraper";
+    type Input = InstagramCommentsInput;
+    type Output = InstagramComment;
+}
+
+/// apify/facebook-posts-scraper: a page's posts.
+pub struct FacebookPosts;
+
+impl ApifyActor for FacebookPosts {
+    const ID: &'static str = "KoJrdxJCTtpon81KY";
+    type Input = FacebookScraperInput;
+    type Output = FacebookPost;
+
+    fn discover(item: FacebookPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// apify/facebook-events-scraper: a page's upcoming events.
+pub struct FacebookEvents;
+
+impl ApifyActor for FacebookEvents {
+    const ID: &'static str = "apify~facebook-events-scraper";
+    type Input = FacebookEventsScraperInput;
+    type Output = FacebookEvent;
+}
+
+/// apify/facebook-comments-scraper.
+pub struct FacebookComments;
+
+impl ApifyActor for FacebookComments {
+    const ID: &'static str = "apify~facebook-comments-scraper";
+    type Input = FacebookCommentsInput;
+    type Output = FacebookComment;
+}
+
+/// apidojo/tweet-scraper: a handle's tweets.
+pub struct XPosts;
+
+impl ApifyActor for XPosts {
+    const ID: &'static str = "61RPP7dywgiy0JPD0";
+    type Input = TweetScraperInput;
+    type Output = Tweet;
+
+    fn discover(item: Tweet) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// apidojo/tweet-scraper with search terms instead of handles.
+pub struct XSearch;
+
+impl ApifyActor for XSearch {
+    const ID: &'static str = XPosts::ID;
+    type Input = TweetSearchInput;
+    type Output = Tweet;
+
+    fn discover(item: Tweet) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// clockworks/tiktok-scraper: a profile's videos.
+pub struct TikTokPosts;
+
+impl ApifyActor for TikTokPosts {
+    const ID: &'static str = "GdWCkxBtKWOsKjdch";
+    type Input = TikTokScraperInput;
+    type Output = TikTokPost;
+
+    fn discover(item: TikTokPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// clockworks/tiktok-scraper with search queries instead of profiles.
+pub struct TikTokSearch;
+
+impl ApifyActor for TikTokSearch {
+    const ID: &'static str = TikTokPosts::ID;
+    type Input = TikTokSearchInput;
+    type Output = TikTokPost;
+
+    fn discover(item: TikTokPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// clockworks/tiktok-comments-scraper.
+pub struct TikTokComments;
+
+impl ApifyActor for TikTokComments {
+    const ID: &'static str = "clockworks~tiktok-comments-scraper";
+    type Input = TikTokCommentsInput;
+    type Output = TikTokComment;
+}
+
+/// trudax/reddit-scraper: posts from subreddit or search URLs.
+pub struct RedditPosts;
+
+impl ApifyActor for RedditPosts {
+    const ID: &'static str = "FgJtjDwJCLhRH9saM";
+    type Input = RedditScraperInput;
+    type Output = RedditPost;
+
+    fn discover(item: RedditPost) -> Option<DiscoveredPost> {
+        item.into_discovered()
+    }
+}
+
+/// trudax/reddit-scraper on one post, returning the post and its comments.
+pub struct RedditComments;
+
+impl ApifyActor for RedditComments {
+    const ID: &'static str = RedditPosts::ID;
+    type Input = RedditCommentsInput;
+    type Output = RedditPost;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item<T: DeserializeOwned>(json: &str) -> T {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn reddit_comments_and_communities_are_not_discovered_as_posts() {
+        let post = item(r#"{"url":"https://reddit.com/r/x/1","title":"Shelter full","body":"Need cots",
+                            "username":"a","dataType":"post","createdAt":"2026-01-02T03:04:05Z"}"#);
+        let comment = item(r#"{"url":"https://reddit.com/r/x/1/c","body":"+1","username":"b","dataType":"comment"}"#);
+
+        let discovered = RedditPosts::discover(post).unwrap();
+
+        assert_eq!(discovered.content, "Shelter full\n\nNeed cots");
+        assert!(discovered.timestamp.is_some());
+        assert!(RedditPosts::discover(comment).is_none());
+    }
+
+    #[test]
+    fn tweets_are_discovered_with_twitter_timestamps() {
+        let tweet = item(r#"{"full_text":"Road closed","url":"https://x.com/a/status/1",
+                             "created_at":"Wed Oct 10 20:19:24 +0000 2018","author":{"userName":"a","name":"A"}}"#);
+
+        let discovered = XSearch::discover(tweet).unwrap();
+
+        assert_eq!(discovered.author_username, "a");
+        assert_eq!(discovered.platform, "x");
+        assert_eq!(discovered.timestamp.unwrap().to_rfc3339(), "2018-10-10T20:19:24+00:00");
+    }
+
+    #[test]
+    fn actors_without_post_items_discover_nothing() {
+        let event = item(r#"{"name":"Food drive"}"#);
+
+        assert!(FacebookEvents::discover(event).is_none());
+    }
+}
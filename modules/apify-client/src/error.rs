@@ -15,6 +15,9 @@ pub enum ApifyError {
 
     #[error("Run failed with status: {0}")]
     RunFailed(String),
+
+    #[error("Run {0} still unfinished after {1}s")]
+    Timeout(String, u64),
 }
 
 impl From<reqwest::Error> for ApifyError {
@@ -1,40 +1,42 @@
+pub mod actor;
 pub mod error;
 pub mod types;
+pub mod webhook;
 
+pub use actor::ApifyActor;
 pub use error::{ApifyError, Result};
 pub use types::{
-    DiscoveredPost, FacebookPost, FacebookScraperInput, InstagramHashtagInput, InstagramPost,
-    InstagramScraperInput, RedditPost, RedditScraperInput,
-    RunData, StartUrl, TikTokPost, TikTokScraperInput, TikTokSearchInput, Tweet, TweetAuthor,
+    DiscoveredPost, FacebookComment, FacebookCommentsInput, FacebookEvent, FacebookEventLocation,
+    FacebookEventsScraperInput, FacebookPost, FacebookScraperInput, InstagramComment,
+    InstagramCommentsInput, InstagramHashtagInput, InstagramPost, InstagramScraperInput,
+    RedditCommentsInput, RedditPost, RedditScraperInput, RunData, StartUrl, TikTokComment,
+    TikTokCommentsInput, TikTokPost, TikTokScraperInput, TikTokSearchInput, Tweet, TweetAuthor,
     TweetScraperInput, TweetSearchInput,
 };
+pub use webhook::{RunCompletions, RunWebhook, WebhookPayload};
+
+use std::time::{Duration, Instant};
 
 use serde::de::DeserializeOwned;
 use types::ApiResponse;
 
 const BASE_URL: &str = "https://api.apify.com/v2";
 
-/// Actor ID for apify/instagram-post-scraper.
-const INSTAGRAM_POST_SCRAPER: &str = "nH2AHrwxeTRJoN5hX";
-
-/// Actor slug for apify/instagram-hashtag-scraper.
-const INSTAGRAM_HASHTAG_SCRAPER: &str = "apify~instagram-hashtag-scraper";
-
-/// Actor ID for apify/facebook-posts-scraper.
-const FACEBOOK_POSTS_SCRAPER: &str = "KoJrdxJCTtpon81KY";
-
-/// Actor ID for apidojo/tweet-scraper.
-const TWEET_SCRAPER: &str = "61RPP7dywgiy0JPD0";
+/// How long to wait for a completion webhook before checking the run
+/// directly, in case the notification was lost.
+const WEBHOOK_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
-/// Actor ID for clockworks/tiktok-scraper.
-const TIKTOK_SCRAPER: &str = "GdWCkxBtKWOsKjdch";
+/// Shortest gap between polls of an unfinished run. `waitForFinish`
+/// sometimes returns at once, which would otherwise spin.
+const MIN_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
-/// Actor ID for trudax/reddit-scraper.
-const REDDIT_SCRAPER: &str = "FgJtjDwJCLhRH9saM";
+/// Longest a run is waited for.
+const MAX_RUN_WAIT: Duration = Duration::from_secs(30 * 60);
 
 pub struct ApifyClient {
     client: reqwest::Client,
     token: String,
+    webhook: Option<RunWebhook>,
 }
 
 impl ApifyClient {
@@ -42,22 +44,81 @@ impl ApifyClient {
         Self {
             client: reqwest::Client::new(),
             token,
+            webhook: None,
         }
     }
 
-    /// Start an Instagram profile scrape run. Returns immediately with run metadata.
-    pub async fn start_instagram_scrape(&self, username: &str, limit: u32) -> Result<RunData> {
-        let input = InstagramScraperInput {
-            username: vec![username.to_string()],
-            results_limit: limit,
+    /// Register `webhook` on every run started, and wait for its
+    /// notifications instead of long-polling.
+    pub fn with_webhook(mut self, webhook: RunWebhook) -> Self {
+        self.webhook = Some(webhook);
+        self
+    }
+
+    /// URL that starts a run of `actor`.
+    fn runs_url(&self, actor: &str) -> String {
+        let url = format!("{}/acts/{}/runs", BASE_URL, actor);
+        match &self.webhook {
+            Some(webhook) => reqwest::Url::parse_with_params(&url, &[("webhooks", webhook.query_param())])
+                .map(|u| u.to_string())
+                .unwrap_or(url),
+            None => url,
+        }
+    }
+
+    /// Wait until a run completes. With a webhook, waits for its
+    /// notification and checks the run directly every
+    /// `WEBHOOK_CHECK_INTERVAL` in case it was lost; without one, long-polls.
+    pub async fn wait_for_run(&self, run_id: &str) -> Result<RunData> {
+        let Some(webhook) = &self.webhook else {
+            return self.poll_run(run_id).await;
         };
 
-        let url = format!("{}/acts/{}/runs", BASE_URL, INSTAGRAM_POST_SCRAPER);
+        let started = Instant::now();
+        loop {
+            let notified = webhook.completions.wait_for(run_id, WEBHOOK_CHECK_INTERVAL).await;
+            if let Some(run) = finished(self.get_run(run_id, 0).await?)? {
+                if notified.is_none() {
+                    tracing::warn!(run_id, "Run finished without a completion webhook");
+                }
+                return Ok(run);
+            }
+            if started.elapsed() >= MAX_RUN_WAIT {
+                return Err(ApifyError::Timeout(run_id.to_string(), MAX_RUN_WAIT.as_secs()));
+            }
+            if notified.is_some() {
+                // Notified but not finished yet as far as the API says.
+                tokio::time::sleep(MIN_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    /// Long-poll with `waitForFinish=60` until a run completes.
+    async fn poll_run(&self, run_id: &str) -> Result<RunData> {
+        let started = Instant::now();
+        loop {
+            let polled = Instant::now();
+            let run = self.get_run(run_id, 60).await?;
+            tracing::debug!(run_id, status = %run.status, "Polled run");
+            if let Some(run) = finished(run)? {
+                return Ok(run);
+            }
+            if started.elapsed() >= MAX_RUN_WAIT {
+                return Err(ApifyError::Timeout(run_id.to_string(), MAX_RUN_WAIT.as_secs()));
+            }
+            if let Some(pause) = MIN_POLL_INTERVAL.checked_sub(polled.elapsed()) {
+                tokio::time::sleep(pause).await;
+            }
+        }
+    }
+
+    /// Fetch a run, waiting up to `wait_secs` for it to finish.
+    async fn get_run(&self, run_id: &str, wait_secs: u32) -> Result<RunData> {
+        let url = format!("{}/actor-runs/{}?waitForFinish={}", BASE_URL, run_id, wait_secs);
         let resp = self
             .client
-            .post(&url)
+            .get(&url)
             .bearer_auth(&self.token)
-            .json(&input)
             .send()
             .await?;
 
@@ -74,40 +135,6 @@ impl ApifyClient {
         Ok(api_resp.data)
     }
 
-    /// Poll until a run completes. Uses `waitForFinish=60` for efficient long-polling.
-    pub async fn wait_for_run(&self, run_id: &str) -> Result<RunData> {
-        loop {
-            let url = format!("{}/actor-runs/{}?waitForFinish=60", BASE_URL, run_id);
-            let resp = self
-                .client
-                .get(&url)
-                .bearer_auth(&self.token)
-                .send()
-                .await?;
-
-            let status = resp.status();
-            if !status.is_success() {
-                let body = resp.text().await.unwrap_or_default();
-                return Err(ApifyError::Api {
-                    status: status.as_u16(),
-                    message: body,
-                });
-            }
-
-            let api_resp: ApiResponse<RunData> = resp.json().await?;
-            match api_resp.data.status.as_str() {
-                "SUCCEEDED" => return Ok(api_resp.data),
-                "FAILED" | "ABORTED" | "TIMED-OUT" => {
-                    return Err(ApifyError::RunFailed(api_resp.data.status));
-                }
-                _ => {
-                    tracing::debug!(run_id, status = %api_resp.data.status, "Run still in progress");
-                    continue;
-                }
-            }
-        }
-    }
-
     /// Fetch dataset items from a completed run.
     pub async fn get_dataset_items<T: DeserializeOwned>(&self, dataset_id: &str) -> Result<Vec<T>> {
         let url = format!("{}/datasets/{}/items?format=json", BASE_URL, dataset_id);
@@ -131,52 +158,14 @@ impl ApifyClient {
         Ok(items)
     }
 
-    /// Scrape Instagram profile posts end-to-end: start run, poll, fetch results.
-    pub async fn scrape_instagram_posts(
-        &self,
-        username: &str,
-        limit: u32,
-    ) -> Result<Vec<InstagramPost>> {
-        tracing::info!(username, limit, "Starting Instagram profile scrape");
-
-        let run = self.start_instagram_scrape(username, limit).await?;
-        tracing::info!(run_id = %run.id, "Apify run started, polling for completion");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let posts: Vec<InstagramPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = posts.len(), "Fetched Instagram posts");
-
-        Ok(posts)
-    }
-
-    /// Search Instagram hashtags and return normalized DiscoveredPosts.
-    /// Uses the apify/instagram-hashtag-scraper actor.
-    pub async fn search_instagram_hashtags(
-        &self,
-        hashtags: &[&str],
-        limit: u32,
-    ) -> Result<Vec<DiscoveredPost>> {
-        tracing::info!(?hashtags, limit, "Starting Instagram hashtag search");
-
-        let input = InstagramHashtagInput {
-            hashtags: hashtags.iter().map(|h| h.to_string()).collect(),
-            results_limit: limit,
-        };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, INSTAGRAM_HASHTAG_SCRAPER);
+    /// Start a run of `A`. Returns immediately with run metadata.
+    pub async fn start<A: ApifyActor>(&self, input: &A::Input) -> Result<RunData> {
+        let url = self.runs_url(A::ID);
         let resp = self
             .client
             .post(&url)
             .bearer_auth(&self.token)
-            .json(&input)
+            .json(input)
             .send()
             .await?;
 
@@ -190,375 +179,196 @@ impl ApifyClient {
         }
 
         let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Hashtag scrape started, polling for completion");
+        Ok(api_resp.data)
+    }
+
+    /// Run `A` end-to-end: start the run, wait for it, fetch its dataset.
+    pub async fn run<A: ApifyActor>(&self, input: &A::Input) -> Result<Vec<A::Output>> {
+        let run = self.start::<A>(input).await?;
+        tracing::info!(actor = A::ID, run_id = %run.id, "Apify run started, waiting for completion");
 
         let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let posts: Vec<InstagramPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
+        let items: Vec<A::Output> = self.get_dataset_items(&completed.default_dataset_id).await?;
+        tracing::info!(actor = A::ID, run_id = %completed.id, count = items.len(), "Fetched actor results");
+        Ok(items)
+    }
 
-        let discovered: Vec<DiscoveredPost> = posts
-            .into_iter()
-            .filter_map(|p| p.into_discovered())
-            .collect();
+    /// Run `A` and keep the items that make a post.
+    pub async fn discover<A: ApifyActor>(&self, input: &A::Input) -> Result<Vec<DiscoveredPost>> {
+        let items = self.run::<A>(input).await?;
+        Ok(items.into_iter().filter_map(A::discover).collect())
+    }
 
-        tracing::info!(count = discovered.len(), "Fetched Instagram hashtag posts");
-        Ok(discovered)
+    /// Scrape an Instagram profile's posts.
+    pub async fn scrape_instagram_posts(&self, username: &str, limit: u32) -> Result<Vec<InstagramPost>> {
+        tracing::info!(username, limit, "Starting Instagram profile scrape");
+        let input = InstagramScraperInput {
+            username: vec![username.to_string()],
+            results_limit: limit,
+        };
+        self.run::<actor::InstagramPosts>(&input).await
     }
 
-    /// Scrape Facebook page posts end-to-end: start run, poll, fetch results.
-    pub async fn scrape_facebook_posts(
-        &self,
-        page_url: &str,
-        limit: u32,
-    ) -> Result<Vec<FacebookPost>> {
-        tracing::info!(page_url, limit, "Starting Facebook page scrape");
+    /// Search Instagram hashtags.
+    pub async fn search_instagram_hashtags(&self, hashtags: &[&str], limit: u32) -> Result<Vec<DiscoveredPost>> {
+        tracing::info!(?hashtags, limit, "Starting Instagram hashtag search");
+        let input = InstagramHashtagInput {
+            hashtags: hashtags.iter().map(|h| h.to_string()).collect(),
+            results_limit: limit,
+        };
+        self.discover::<actor::InstagramHashtags>(&input).await
+    }
 
+    /// Scrape a Facebook page's posts.
+    pub async fn scrape_facebook_posts(&self, page_url: &str, limit: u32) -> Result<Vec<FacebookPost>> {
+        tracing::info!(page_url, limit, "Starting Facebook page scrape");
         let input = FacebookScraperInput {
             start_urls: vec![StartUrl {
                 url: page_url.to_string(),
             }],
             results_limit: limit,
         };
+        self.run::<actor::FacebookPosts>(&input).await
+    }
 
-        let url = format!("{}/acts/{}/runs", BASE_URL, FACEBOOK_POSTS_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Apify run started, polling for completion");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let posts: Vec<FacebookPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = posts.len(), "Fetched Facebook posts");
-
-        Ok(posts)
+    /// Scrape a Facebook page's upcoming events.
+    pub async fn scrape_facebook_events(&self, events_url: &str, limit: u32) -> Result<Vec<FacebookEvent>> {
+        tracing::info!(events_url, limit, "Starting Facebook events scrape");
+        let input = FacebookEventsScraperInput {
+            start_urls: vec![StartUrl {
+                url: events_url.to_string(),
+            }],
+            max_events: limit,
+        };
+        self.run::<actor::FacebookEvents>(&input).await
     }
 
-    /// Scrape TikTok profile posts end-to-end: start run, poll, fetch results.
+    /// Scrape a TikTok profile's videos.
     pub async fn scrape_tiktok_posts(&self, username: &str, limit: u32) -> Result<Vec<TikTokPost>> {
         tracing::info!(username, limit, "Starting TikTok scrape");
-
         let input = TikTokScraperInput {
             profiles: vec![username.to_string()],
             results_per_page: limit,
         };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, TIKTOK_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Apify run started, polling for completion");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let posts: Vec<TikTokPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = posts.len(), "Fetched TikTok posts");
-
-        Ok(posts)
+        self.run::<actor::TikTokPosts>(&input).await
     }
 
-    /// Search Reddit by keywords. Uses the same trudax/reddit-scraper actor
-    /// with Reddit search URLs as startUrls.
-    pub async fn search_reddit_keywords(
-        &self,
-        keywords: &[&str],
-        limit: u32,
-    ) -> Result<Vec<RedditPost>> {
+    /// Search Reddit by keywords, through Reddit search URLs.
+    pub async fn search_reddit_keywords(&self, keywords: &[&str], limit: u32) -> Result<Vec<RedditPost>> {
         tracing::info!(?keywords, limit, "Starting Reddit keyword search");
-
-        let start_urls: Vec<StartUrl> = keywords
-            .iter()
-            .map(|k| StartUrl {
-                url: format!(
-                    "https://www.reddit.com/search/?q={}&sort=new",
-                    k.replace(' ', "+")
-                ),
-            })
-            .collect();
-
         let input = RedditScraperInput {
-            start_urls,
+            start_urls: keywords
+                .iter()
+                .map(|k| StartUrl {
+                    url: format!("https://www.reddit.com/search/?q={}&sort=new", k.replace(' ', "+")),
+                })
+                .collect(),
             max_items: limit,
             sort: "new".to_string(),
         };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, REDDIT_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Reddit keyword search started, polling");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        let posts: Vec<RedditPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = posts.len(), "Fetched Reddit posts from keyword search");
-
-        Ok(posts)
+        self.run::<actor::RedditPosts>(&input).await
     }
 
-    /// Scrape Reddit subreddit posts end-to-end: start run, poll, fetch results.
-    pub async fn scrape_reddit_posts(
-        &self,
-        subreddit_url: &str,
-        limit: u32,
-    ) -> Result<Vec<RedditPost>> {
+    /// Scrape a subreddit's posts. Takes a bare name ("TwinCities") or a URL.
+    pub async fn scrape_reddit_posts(&self, subreddit_url: &str, limit: u32) -> Result<Vec<RedditPost>> {
         tracing::info!(subreddit_url, limit, "Starting Reddit scrape");
-
-        // Accept both bare identifiers ("TwinCities") and full URLs
         let full_url = if subreddit_url.starts_with("http") {
             subreddit_url.to_string()
         } else {
             format!("https://www.reddit.com/r/{}", subreddit_url)
         };
-
         let input = RedditScraperInput {
-            start_urls: vec![StartUrl {
-                url: full_url,
-            }],
+            start_urls: vec![StartUrl { url: full_url }],
             max_items: limit,
             sort: "new".to_string(),
         };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, REDDIT_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Apify run started, polling for completion");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let posts: Vec<RedditPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = posts.len(), "Fetched Reddit posts");
-
-        Ok(posts)
+        self.run::<actor::RedditPosts>(&input).await
     }
 
-    /// Search X/Twitter by keywords. Uses the same apidojo/tweet-scraper actor
-    /// with searchTerms instead of twitterHandles.
+    /// Search X/Twitter by keywords.
     pub async fn search_x_keywords(&self, keywords: &[&str], limit: u32) -> Result<Vec<Tweet>> {
         tracing::info!(?keywords, limit, "Starting X/Twitter keyword search");
-
         let input = TweetSearchInput {
             search_terms: keywords.iter().map(|k| k.to_string()).collect(),
             max_items: limit,
         };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, TWEET_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "X/Twitter keyword search started, polling");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        let tweets: Vec<Tweet> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = tweets.len(), "Fetched tweets from keyword search");
-
-        Ok(tweets)
+        self.run::<actor::XSearch>(&input).await
     }
 
-    /// Search TikTok by keywords. Uses the clockworks/tiktok-scraper actor
-    /// with searchQueries instead of profiles.
-    pub async fn search_tiktok_keywords(
-        &self,
-        keywords: &[&str],
-        limit: u32,
-    ) -> Result<Vec<TikTokPost>> {
+    /// Search TikTok by keywords.
+    pub async fn search_tiktok_keywords(&self, keywords: &[&str], limit: u32) -> Result<Vec<TikTokPost>> {
         tracing::info!(?keywords, limit, "Starting TikTok keyword search");
-
         let input = TikTokSearchInput {
             search_queries: keywords.iter().map(|k| k.to_string()).collect(),
             results_per_page: limit,
         };
-
-        let url = format!("{}/acts/{}/runs", BASE_URL, TIKTOK_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
-
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
-
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "TikTok keyword search started, polling");
-
-        let completed = self.wait_for_run(&run.id).await?;
-        let posts: Vec<TikTokPost> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(
-            count = posts.len(),
-            "Fetched TikTok posts from keyword search"
-        );
-
-        Ok(posts)
+        self.run::<actor::TikTokSearch>(&input).await
     }
 
-    /// Scrape X/Twitter posts end-to-end: start run, poll, fetch results.
+    /// Scrape an X/Twitter handle's tweets.
     pub async fn scrape_x_posts(&self, handle: &str, limit: u32) -> Result<Vec<Tweet>> {
         tracing::info!(handle, limit, "Starting X/Twitter scrape");
-
         let input = TweetScraperInput {
             twitter_handles: vec![handle.to_string()],
             max_items: limit,
         };
+        self.run::<actor::XPosts>(&input).await
+    }
 
-        let url = format!("{}/acts/{}/runs", BASE_URL, TWEET_SCRAPER);
-        let resp = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&input)
-            .send()
-            .await?;
+    /// Scrape the comments on one Instagram post.
+    pub async fn scrape_instagram_comments(&self, post_url: &str, limit: u32) -> Result<Vec<InstagramComment>> {
+        tracing::info!(post_url, limit, "Starting Instagram comment scrape");
+        let input = InstagramCommentsInput {
+            direct_urls: vec![post_url.to_string()],
+            results_limit: limit,
+        };
+        self.run::<actor::InstagramComments>(&input).await
+    }
 
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApifyError::Api {
-                status: status.as_u16(),
-                message: body,
-            });
-        }
+    /// Scrape the top-level comments on one Facebook post.
+    pub async fn scrape_facebook_comments(&self, post_url: &str, limit: u32) -> Result<Vec<FacebookComment>> {
+        tracing::info!(post_url, limit, "Starting Facebook comment scrape");
+        let input = FacebookCommentsInput {
+            start_urls: vec![StartUrl {
+                url: post_url.to_string(),
+            }],
+            results_limit: limit,
+            include_nested_comments: false,
+        };
+        self.run::<actor::FacebookComments>(&input).await
+    }
 
-        let api_resp: ApiResponse<RunData> = resp.json().await?;
-        let run = api_resp.data;
-        tracing::info!(run_id = %run.id, "Apify run started, polling for completion");
+    /// Scrape a Reddit post and its comments. Comments come back with
+    /// `data_type` "comment"; top-level ones have a `t3_` parent.
+    pub async fn scrape_reddit_comments(&self, post_url: &str, limit: u32) -> Result<Vec<RedditPost>> {
+        tracing::info!(post_url, limit, "Starting Reddit comment scrape");
+        let input = RedditCommentsInput {
+            start_urls: vec![StartUrl {
+                url: post_url.to_string(),
+            }],
+            max_items: limit + 1,
+            max_comments: limit,
+            skip_comments: false,
+        };
+        self.run::<actor::RedditComments>(&input).await
+    }
 
-        let completed = self.wait_for_run(&run.id).await?;
-        tracing::info!(
-            run_id = %completed.id,
-            dataset_id = %completed.default_dataset_id,
-            "Run completed, fetching results"
-        );
-
-        let tweets: Vec<Tweet> = self
-            .get_dataset_items(&completed.default_dataset_id)
-            .await?;
-        tracing::info!(count = tweets.len(), "Fetched tweets");
+    /// Scrape the comments on one TikTok video.
+    pub async fn scrape_tiktok_comments(&self, post_url: &str, limit: u32) -> Result<Vec<TikTokComment>> {
+        tracing::info!(post_url, limit, "Starting TikTok comment scrape");
+        let input = TikTokCommentsInput {
+            post_urls: vec![post_url.to_string()],
+            comments_per_post: limit,
+        };
+        self.run::<actor::TikTokComments>(&input).await
+    }
+}
 
-        Ok(tweets)
+/// The run if it succeeded, `None` while it's still going, or its failure.
+fn finished(run: RunData) -> Result<Option<RunData>> {
+    match run.status.as_str() {
+        "SUCCEEDED" => Ok(Some(run)),
+        "FAILED" | "ABORTED" | "TIMED-OUT" => Err(ApifyError::RunFailed(run.status)),
+        _ => Ok(None),
     }
 }
@@ -75,6 +75,24 @@ impl InstagramPost {
     }
 }
 
+/// Input for the apify/instagram-comment-scraper actor.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstagramCommentsInput {
+    #[serde(rename = "directUrls")]
+    pub direct_urls: Vec<String>,
+    #[serde(rename = "resultsLimit")]
+    pub results_limit: u32,
+}
+
+/// A single Instagram comment from the Apify dataset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstagramComment {
+    pub text: Option<String>,
+    #[serde(rename = "ownerUsername")]
+    pub owner_username: Option<String>,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
 /// Wrapper for Apify API responses.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApiResponse<T> {
@@ -109,6 +127,82 @@ pub struct FacebookPost {
     pub shares: Option<i64>,
 }
 
+impl FacebookPost {
+    /// Convert to a platform-agnostic DiscoveredPost for the discovery pipeline.
+    pub fn into_discovered(self) -> Option<DiscoveredPost> {
+        Some(DiscoveredPost {
+            content: self.text?,
+            author_username: self.page_name?,
+            author_display_name: None,
+            post_url: self.url?,
+            timestamp: self.time.as_deref().and_then(parse_rfc3339),
+            platform: "facebook".to_string(),
+        })
+    }
+}
+
+/// Input for the apify/facebook-comments-scraper actor.
+#[derive(Debug, Clone, Serialize)]
+pub struct FacebookCommentsInput {
+    #[serde(rename = "startUrls")]
+    pub start_urls: Vec<StartUrl>,
+    #[serde(rename = "resultsLimit")]
+    pub results_limit: u32,
+    #[serde(rename = "includeNestedComments")]
+    pub include_nested_comments: bool,
+}
+
+/// A single Facebook comment from the Apify dataset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FacebookComment {
+    pub text: Option<String>,
+    #[serde(rename = "profileName")]
+    pub profile_name: Option<String>,
+    pub date: Option<String>,
+}
+
+/// Input for the apify/facebook-events-scraper actor.
+#[derive(Debug, Clone, Serialize)]
+pub struct FacebookEventsScraperInput {
+    #[serde(rename = "startUrls")]
+    pub start_urls: Vec<StartUrl>,
+    #[serde(rename = "maxEvents")]
+    pub max_events: u32,
+}
+
+/// A single Facebook event from the Apify dataset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FacebookEvent {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "utcStartDate")]
+    pub utc_start_date: Option<DateTime<Utc>>,
+    #[serde(rename = "utcEndDate")]
+    pub utc_end_date: Option<DateTime<Utc>>,
+    pub location: Option<FacebookEventLocation>,
+    #[serde(rename = "isOnline", default)]
+    pub is_online: bool,
+    #[serde(rename = "organizedBy")]
+    pub organized_by: Option<String>,
+    #[serde(rename = "organizerUrl")]
+    pub organizer_url: Option<String>,
+    #[serde(rename = "ticketUrl")]
+    pub ticket_url: Option<String>,
+}
+
+/// Venue of a Facebook event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FacebookEventLocation {
+    pub name: Option<String>,
+    #[serde(rename = "streetAddress")]
+    pub street_address: Option<String>,
+    pub city: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
 /// Input for the apidojo/tweet-scraper actor.
 #[derive(Debug, Clone, Serialize)]
 pub struct TweetScraperInput {
@@ -152,6 +246,9 @@ pub struct Tweet {
     pub retweet_count: Option<i64>,
     #[serde(rename = "replyCount")]
     pub reply_count: Option<i64>,
+    /// Set on replies: the tweet this one answers.
+    #[serde(rename = "inReplyToId")]
+    pub in_reply_to_id: Option<String>,
 }
 
 impl Tweet {
@@ -159,6 +256,25 @@ impl Tweet {
     pub fn content(&self) -> Option<&str> {
         self.full_text.as_deref().or(self.text.as_deref())
     }
+
+    /// Convert to a platform-agnostic DiscoveredPost for the discovery pipeline.
+    pub fn into_discovered(self) -> Option<DiscoveredPost> {
+        let content = self.content()?.to_string();
+        let author = self.author?;
+        Some(DiscoveredPost {
+            content,
+            author_username: author.user_name?,
+            author_display_name: author.name,
+            post_url: self.url?,
+            // e.g. "Wed Oct 10 20:19:24 +0000 2018"
+            timestamp: self.created_at.as_deref().and_then(|s| {
+                DateTime::parse_from_str(s, "%a %b %d %H:%M:%S %z %Y")
+                    .ok()
+                    .map(|t| t.with_timezone(&Utc))
+            }),
+            platform: "x".to_string(),
+        })
+    }
 }
 
 /// Input for the clockworks/tiktok-scraper actor (profile-based).
@@ -200,6 +316,21 @@ pub struct TikTokPost {
     pub hashtags: Option<Vec<TikTokHashtag>>,
 }
 
+impl TikTokPost {
+    /// Convert to a platform-agnostic DiscoveredPost for the discovery pipeline.
+    pub fn into_discovered(self) -> Option<DiscoveredPost> {
+        let author = self.author_meta?;
+        Some(DiscoveredPost {
+            content: self.text?,
+            author_username: author.name?,
+            author_display_name: author.nick_name,
+            post_url: self.web_video_url?,
+            timestamp: self.create_time_iso.as_deref().and_then(parse_rfc3339),
+            platform: "tiktok".to_string(),
+        })
+    }
+}
+
 /// Author metadata from a TikTok post.
 #[derive(Debug, Clone, Deserialize)]
 pub struct TikTokAuthor {
@@ -214,6 +345,28 @@ pub struct TikTokHashtag {
     pub name: Option<String>,
 }
 
+/// Input for clockworks/tiktok-comments-scraper.
+#[derive(Debug, Clone, Serialize)]
+pub struct TikTokCommentsInput {
+    #[serde(rename = "postURLs")]
+    pub post_urls: Vec<String>,
+    #[serde(rename = "commentsPerPost")]
+    pub comments_per_post: u32,
+}
+
+/// A single TikTok comment from the Apify dataset.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TikTokComment {
+    pub text: Option<String>,
+    #[serde(rename = "uniqueId")]
+    pub unique_id: Option<String>,
+    #[serde(rename = "createTimeISO")]
+    pub create_time_iso: Option<String>,
+    /// Set on replies to another comment.
+    #[serde(rename = "repliesToId")]
+    pub replies_to_id: Option<String>,
+}
+
 /// Input for the trudax/reddit-scraper actor.
 #[derive(Debug, Clone, Serialize)]
 pub struct RedditScraperInput {
@@ -224,6 +377,19 @@ pub struct RedditScraperInput {
     pub sort: String,
 }
 
+/// Input for the trudax/reddit-scraper actor when scraping one post's comments.
+#[derive(Debug, Clone, Serialize)]
+pub struct RedditCommentsInput {
+    #[serde(rename = "startUrls")]
+    pub start_urls: Vec<StartUrl>,
+    #[serde(rename = "maxItems")]
+    pub max_items: u32,
+    #[serde(rename = "maxComments")]
+    pub max_comments: u32,
+    #[serde(rename = "skipComments")]
+    pub skip_comments: bool,
+}
+
 /// A single Reddit post from the Apify dataset.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RedditPost {
@@ -237,11 +403,38 @@ pub struct RedditPost {
     pub number_of_comments: Option<i64>,
     #[serde(rename = "createdAt")]
     pub created_at: Option<String>,
+    pub username: Option<String>,
+    /// On comments: `t3_...` for a reply to the post, `t1_...` for a reply to a comment.
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
     /// Apify returns "community", "post", or "comment". Used to filter out non-posts.
     #[serde(rename = "dataType")]
     pub data_type: Option<String>,
 }
 
+impl RedditPost {
+    /// Convert to a platform-agnostic DiscoveredPost for the discovery
+    /// pipeline. Communities and comments aren't posts.
+    pub fn into_discovered(self) -> Option<DiscoveredPost> {
+        if self.data_type.as_deref().is_some_and(|t| t != "post") {
+            return None;
+        }
+        let content = match (self.title, self.body) {
+            (Some(title), Some(body)) if !body.is_empty() => format!("{title}\n\n{body}"),
+            (Some(title), _) => title,
+            (None, body) => body?,
+        };
+        Some(DiscoveredPost {
+            content,
+            author_username: self.username?,
+            author_display_name: None,
+            post_url: self.url?,
+            timestamp: self.created_at.as_deref().and_then(parse_rfc3339),
+            platform: "reddit".to_string(),
+        })
+    }
+}
+
 /// Apify actor run metadata.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RunData {
@@ -254,3 +447,7 @@ pub struct RunData {
     #[serde(rename = "finishedAt")]
     pub finished_at: Option<DateTime<Utc>>,
 }
+
+fn parse_rfc3339(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s).ok().map(|t| t.with_timezone(&Utc))
+}
@@ -0,0 +1,145 @@
+//! Run completion webhooks.
+//!
+//! With a webhook configured, each run is started with an ad-hoc Apify
+//! webhook that posts to our receiver when the run finishes, and
+//! `wait_for_run` waits for that notification instead of long-polling.
+//! Where the notification lands (and how a waiter hears about it) is up to
+//! the `RunCompletions` implementation.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::Engine;
+use serde::Deserialize;
+
+use crate::error::Result;
+use crate::types::RunData;
+
+/// Events that end a run. Note `TIMED_OUT` here vs the `TIMED-OUT` run status.
+const TERMINAL_EVENTS: [&str; 4] = [
+    "ACTOR.RUN.SUCCEEDED",
+    "ACTOR.RUN.FAILED",
+    "ACTOR.RUN.ABORTED",
+    "ACTOR.RUN.TIMED_OUT",
+];
+
+/// Where completion notifications are awaited.
+#[async_trait]
+pub trait RunCompletions: Send + Sync {
+    /// The run's terminal status once its webhook has been received, or
+    /// `None` if nothing arrives within `timeout`.
+    async fn wait_for(&self, run_id: &str, timeout: Duration) -> Option<String>;
+}
+
+#[derive(Clone)]
+pub struct RunWebhook {
+    /// Receiver URL, including whatever authenticates it (e.g. `?key=`).
+    pub request_url: String,
+    pub completions: Arc<dyn RunCompletions>,
+}
+
+impl RunWebhook {
+    pub fn new(request_url: impl Into<String>, completions: Arc<dyn RunCompletions>) -> Self {
+        Self {
+            request_url: request_url.into(),
+            completions,
+        }
+    }
+
+    /// The `webhooks` query parameter for starting a run: a base64 JSON
+    /// array of ad-hoc webhook definitions.
+    pub(crate) fn query_param(&self) -> String {
+        let webhooks = serde_json::json!([{
+            "eventTypes": TERMINAL_EVENTS,
+            "requestUrl": self.request_url,
+        }]);
+        base64::engine::general_purpose::STANDARD.encode(webhooks.to_string())
+    }
+}
+
+/// The body Apify posts to the receiver (its default payload template).
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookPayload {
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    #[serde(rename = "eventData")]
+    pub event_data: WebhookEventData,
+    /// The run as of the event.
+    pub resource: Option<RunData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookEventData {
+    #[serde(rename = "actorRunId")]
+    pub actor_run_id: String,
+}
+
+impl WebhookPayload {
+    pub fn parse(body: &str) -> Result<Self> {
+        Ok(serde_json::from_str(body)?)
+    }
+
+    /// Whether the event ends the run.
+    pub fn is_terminal(&self) -> bool {
+        TERMINAL_EVENTS.contains(&self.event_type.as_str())
+    }
+
+    /// The run's status, from the run itself or else from the event type.
+    pub fn status(&self) -> String {
+        match &self.resource {
+            Some(run) => run.status.clone(),
+            None => self
+                .event_type
+                .trim_start_matches("ACTOR.RUN.")
+                .replace('_', "-"),
+        }
+    }
+
+    pub fn dataset_id(&self) -> Option<&str> {
+        self.resource.as_ref().map(|run| run.default_dataset_id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NeverCompletes;
+
+    #[async_trait]
+    impl RunCompletions for NeverCompletes {
+        async fn wait_for(&self, _run_id: &str, _timeout: Duration) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn query_param_registers_every_terminal_event_at_the_receiver() {
+        let webhook = RunWebhook::new("https://api.example.org/webhooks/apify?key=s3cret", Arc::new(NeverCompletes));
+
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(webhook.query_param())
+            .unwrap();
+        let webhooks: serde_json::Value = serde_json::from_slice(&decoded).unwrap();
+
+        assert_eq!(webhooks[0]["requestUrl"], "https://api.example.org/webhooks/apify?key=s3cret");
+        assert_eq!(webhooks[0]["eventTypes"].as_array().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn payload_status_falls_back_to_the_event_type() {
+        let with_run = WebhookPayload::parse(
+            r#"{"eventType":"ACTOR.RUN.SUCCEEDED","eventData":{"actorRunId":"r1"},
+                "resource":{"id":"r1","status":"SUCCEEDED","defaultDatasetId":"d1"}}"#,
+        )
+        .unwrap();
+        let without_run =
+            WebhookPayload::parse(r#"{"eventType":"ACTOR.RUN.TIMED_OUT","eventData":{"actorRunId":"r2"}}"#).unwrap();
+
+        assert_eq!(with_run.status(), "SUCCEEDED");
+        assert_eq!(with_run.dataset_id(), Some("d1"));
+        assert_eq!(without_run.status(), "TIMED-OUT");
+        assert!(without_run.is_terminal());
+    }
+}
@@ -0,0 +1,20 @@
+CREATE TABLE dead_letters (
+    id          UUID        PRIMARY KEY,
+    workflow    TEXT        NOT NULL,
+    item_key    TEXT        NOT NULL,
+    payload     JSONB       NOT NULL,
+    error       TEXT        NOT NULL,
+    error_class TEXT        NOT NULL,
+    attempts    JSONB       NOT NULL DEFAULT '[]',
+    status      TEXT        NOT NULL DEFAULT 'pending',
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- One open entry per work item; repeat failures append to its attempt history.
+CREATE UNIQUE INDEX idx_dead_letters_open_item
+    ON dead_letters (workflow, item_key)
+    WHERE status <> 'discarded';
+
+CREATE INDEX idx_dead_letters_status_updated
+    ON dead_letters (status, updated_at DESC);
@@ -0,0 +1,17 @@
+-- Per-source credentials for gated content, keyed by source canonical_key.
+-- Values are AES-256-GCM encrypted by the application (SOURCE_CREDENTIALS_KEY);
+-- the database never sees plaintext.
+CREATE TABLE source_credentials (
+    canonical_key        TEXT        PRIMARY KEY,
+    kind                 TEXT        NOT NULL,
+    header_name          TEXT,
+    nonce                BYTEA       NOT NULL,
+    ciphertext           BYTEA       NOT NULL,
+    health               TEXT        NOT NULL DEFAULT 'healthy',
+    consecutive_failures INT         NOT NULL DEFAULT 0,
+    last_success_at      TIMESTAMPTZ,
+    last_failure_at      TIMESTAMPTZ,
+    last_error           TEXT,
+    rotated_at           TIMESTAMPTZ NOT NULL DEFAULT now(),
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,8 @@
+-- Video transcripts: timed captions (or speech-to-text) for long videos,
+-- so signals can link to the minute they were said.
+
+ALTER TABLE long_videos ADD COLUMN title TEXT;
+ALTER TABLE long_videos ADD COLUMN platform_id TEXT;
+ALTER TABLE long_videos ADD COLUMN transcript JSONB NOT NULL DEFAULT '[]';
+
+CREATE INDEX idx_long_videos_platform_id ON long_videos(source_id, platform_id);
@@ -0,0 +1,19 @@
+-- Podcast episodes: RSS audio enclosures with speech-to-text transcripts.
+
+CREATE TABLE podcast_episodes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    source_id UUID NOT NULL REFERENCES sources(id),
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    content_hash TEXT NOT NULL,
+    guid TEXT NOT NULL,
+    title TEXT,
+    description TEXT,
+    published_at TIMESTAMPTZ,
+    permalink TEXT NOT NULL,
+    audio_url TEXT NOT NULL,
+    duration_secs DOUBLE PRECISION,
+    transcript JSONB NOT NULL DEFAULT '[]',
+    UNIQUE(source_id, guid)
+);
+CREATE INDEX idx_podcast_episodes_source ON podcast_episodes(source_id);
+CREATE INDEX idx_podcast_episodes_fetched ON podcast_episodes(fetched_at);
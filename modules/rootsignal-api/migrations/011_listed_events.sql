@@ -0,0 +1,26 @@
+-- Structured events from event platforms (Eventbrite, Facebook Events).
+-- Re-listing an event updates it in place: times and venues change.
+
+CREATE TABLE listed_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    source_id UUID NOT NULL REFERENCES sources(id),
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    content_hash TEXT NOT NULL,
+    platform_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    venue_name TEXT,
+    venue_address TEXT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    is_online BOOLEAN NOT NULL DEFAULT false,
+    organizer_name TEXT,
+    organizer_url TEXT,
+    ticket_url TEXT,
+    permalink TEXT NOT NULL,
+    UNIQUE(source_id, platform_id)
+);
+CREATE INDEX idx_listed_events_source ON listed_events(source_id);
+CREATE INDEX idx_listed_events_starts ON listed_events(starts_at);
@@ -0,0 +1,10 @@
+-- Retention: the purge deletes archive content by fetch time and run logs
+-- by finish time, so every table it scans needs an index on that column.
+
+CREATE INDEX IF NOT EXISTS idx_long_videos_fetched ON long_videos(fetched_at);
+CREATE INDEX IF NOT EXISTS idx_feeds_fetched ON feeds(fetched_at);
+CREATE INDEX IF NOT EXISTS idx_search_results_fetched ON search_results(fetched_at);
+CREATE INDEX IF NOT EXISTS idx_listed_events_fetched ON listed_events(fetched_at);
+CREATE INDEX IF NOT EXISTS idx_files_fetched ON files(fetched_at);
+CREATE INDEX IF NOT EXISTS idx_attachments_parent_id ON attachments(parent_id);
+CREATE INDEX IF NOT EXISTS idx_scout_runs_finished ON scout_runs(finished_at);
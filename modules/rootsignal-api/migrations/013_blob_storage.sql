@@ -0,0 +1,25 @@
+-- Blob storage: page HTML, long document text and long transcripts can live
+-- in a filesystem or S3 blob store under a content-addressed key, with the
+-- row keeping only the key. `blobs` registers every stored blob for the
+-- lifecycle (cold storage after N days, deletion once unreferenced).
+
+CREATE TABLE blobs (
+    key TEXT PRIMARY KEY,
+    backend TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    content_type TEXT NOT NULL,
+    storage_class TEXT NOT NULL DEFAULT 'standard',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_written_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX idx_blobs_lifecycle ON blobs(backend, storage_class, last_written_at);
+
+ALTER TABLE files ADD COLUMN text_blob_key TEXT;
+ALTER TABLE pages ADD COLUMN raw_html_blob_key TEXT;
+ALTER TABLE long_videos ADD COLUMN transcript_blob_key TEXT;
+ALTER TABLE podcast_episodes ADD COLUMN transcript_blob_key TEXT;
+
+CREATE INDEX idx_files_text_blob_key ON files(text_blob_key) WHERE text_blob_key IS NOT NULL;
+CREATE INDEX idx_pages_raw_html_blob_key ON pages(raw_html_blob_key) WHERE raw_html_blob_key IS NOT NULL;
+CREATE INDEX idx_long_videos_transcript_blob_key ON long_videos(transcript_blob_key) WHERE transcript_blob_key IS NOT NULL;
+CREATE INDEX idx_podcast_episodes_transcript_blob_key ON podcast_episodes(transcript_blob_key) WHERE transcript_blob_key IS NOT NULL;
@@ -0,0 +1,17 @@
+-- Deduplicated, compressed blobs: `ref_count` is the number of rows pointing
+-- at a blob, so repeated fetches of identical content share one copy, and
+-- `stored_bytes`/`encoding` record what the blob store actually holds after
+-- zstd compression. `size_bytes` stays the uncompressed size.
+
+ALTER TABLE blobs ADD COLUMN ref_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE blobs ADD COLUMN stored_bytes BIGINT;
+ALTER TABLE blobs ADD COLUMN encoding TEXT NOT NULL DEFAULT 'identity';
+
+UPDATE blobs SET stored_bytes = size_bytes;
+ALTER TABLE blobs ALTER COLUMN stored_bytes SET NOT NULL;
+
+UPDATE blobs b SET ref_count =
+    (SELECT count(*) FROM files WHERE text_blob_key = b.key)
+  + (SELECT count(*) FROM pages WHERE raw_html_blob_key = b.key)
+  + (SELECT count(*) FROM long_videos WHERE transcript_blob_key = b.key)
+  + (SELECT count(*) FROM podcast_episodes WHERE transcript_blob_key = b.key);
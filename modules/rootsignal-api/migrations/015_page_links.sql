@@ -0,0 +1,16 @@
+-- Link graph: one row per outbound link on an archived page. URLs are
+-- normalized the way `sources.url` is (no scheme, no www., no trailing
+-- slash) and each end carries its domain, so the graph can be walked page to
+-- page or rolled up domain to domain. Rows go with their page.
+
+CREATE TABLE page_links (
+    page_id UUID NOT NULL REFERENCES pages(id) ON DELETE CASCADE,
+    from_url TEXT NOT NULL,
+    from_domain TEXT NOT NULL,
+    to_url TEXT NOT NULL,
+    to_domain TEXT NOT NULL,
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (page_id, to_url)
+);
+CREATE INDEX idx_page_links_from_url ON page_links(from_url);
+CREATE INDEX idx_page_links_domains ON page_links(fetched_at, from_domain, to_domain);
@@ -0,0 +1,15 @@
+-- Resumable site crawls: a running crawl checkpoints its frontier (queue,
+-- seen set, stats) after every page. A crawl of the same seed with the same
+-- rules resumes the latest unfinished one instead of starting over.
+
+CREATE TABLE crawls (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    seed_url TEXT NOT NULL,
+    rules JSONB NOT NULL,
+    frontier JSONB NOT NULL,
+    stats JSONB NOT NULL,
+    completed_at TIMESTAMPTZ,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX idx_crawls_unfinished ON crawls(seed_url, updated_at) WHERE completed_at IS NULL;
@@ -0,0 +1,6 @@
+-- Web search runs on several providers with fallback. Record which one
+-- answered each query so per-provider daily limits can be enforced.
+
+ALTER TABLE search_results ADD COLUMN provider TEXT;
+
+CREATE INDEX idx_search_results_provider_fetched ON search_results(provider, fetched_at);
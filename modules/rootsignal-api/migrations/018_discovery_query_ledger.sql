@@ -0,0 +1,34 @@
+-- Discovery query performance ledger: per region, every generated web query
+-- with the pattern that produced it (e.g. curiosity:unmet_tension,
+-- template:resources_services) and the signals it got accepted into the graph.
+
+CREATE TABLE discovery_queries (
+    region           TEXT        NOT NULL,
+    canonical_key    TEXT        NOT NULL,
+    query            TEXT        NOT NULL,
+    pattern          TEXT        NOT NULL,
+    -- The winning query this one was seeded by, for refinements.
+    refined_from     TEXT,
+    runs             INTEGER     NOT NULL DEFAULT 0,
+    accepted_signals INTEGER     NOT NULL DEFAULT 0,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_run_at      TIMESTAMPTZ,
+    PRIMARY KEY (region, canonical_key)
+);
+
+CREATE INDEX idx_discovery_queries_pattern ON discovery_queries (region, pattern);
+
+-- Every optimizer decision: pattern retirements, refinements of past
+-- winners, and queries skipped because their pattern is retired.
+CREATE TABLE query_decisions (
+    id         BIGSERIAL   PRIMARY KEY,
+    region     TEXT        NOT NULL,
+    run_id     TEXT,
+    decided_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    decision   TEXT        NOT NULL,
+    pattern    TEXT        NOT NULL,
+    query      TEXT,
+    reason     TEXT        NOT NULL
+);
+
+CREATE INDEX idx_query_decisions_region ON query_decisions (region, decision, decided_at DESC);
@@ -0,0 +1,11 @@
+-- Region memory: one size-bounded memo per region (key actors, recurring
+-- venues, active situations, known hoaxes, style notes) that the scout reads
+-- at the start of a run and rewrites at the end.
+
+CREATE TABLE region_memos (
+    region     TEXT        PRIMARY KEY,
+    memo       JSONB       NOT NULL,
+    -- The run that last rewrote the memo.
+    run_id     TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,22 @@
+-- Negative knowledge: debunked rumors, spam and known duplicates the scout
+-- must not store again, fingerprinted by canonical text and embedding.
+
+CREATE TABLE negative_knowledge (
+    id             UUID        PRIMARY KEY,
+    -- NULL applies in every region.
+    region         TEXT,
+    kind           TEXT        NOT NULL,
+    text           TEXT        NOT NULL,
+    canonical_text TEXT        NOT NULL,
+    -- Filled in by the scout the first time it loads the entry.
+    embedding      REAL[],
+    reason         TEXT        NOT NULL,
+    added_by       TEXT        NOT NULL,
+    hit_count      INTEGER     NOT NULL DEFAULT 0,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at     TIMESTAMPTZ,
+    last_hit_at    TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX idx_negative_knowledge_text ON negative_knowledge ((COALESCE(region, '')), canonical_text);
+CREATE INDEX idx_negative_knowledge_region ON negative_knowledge (region, expires_at);
@@ -0,0 +1,15 @@
+-- Feature flag overrides for risky scout behaviors. Flags not listed here
+-- use their built-in default. Each run records the flags it resolved.
+
+CREATE TABLE feature_flags (
+    flag       TEXT        NOT NULL,
+    -- NULL applies in every region; a region row beats it.
+    region     TEXT,
+    enabled    BOOLEAN     NOT NULL,
+    updated_by TEXT        NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX idx_feature_flags_flag_region ON feature_flags (flag, (COALESCE(region, '')));
+
+ALTER TABLE scout_runs ADD COLUMN flags JSONB NOT NULL DEFAULT '{}';
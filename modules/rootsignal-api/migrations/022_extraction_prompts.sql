@@ -0,0 +1,38 @@
+-- Extraction prompt registry. A candidate runs in shadow beside production on
+-- sampled content until its comparison report passes, then goes live for its
+-- region (or globally). Regions without a live prompt use the built-in one.
+
+CREATE TABLE extraction_prompts (
+    id          UUID        PRIMARY KEY,
+    -- NULL applies in every region without its own prompt.
+    region      TEXT,
+    name        TEXT        NOT NULL,
+    template    TEXT        NOT NULL,
+    status      TEXT        NOT NULL CHECK (status IN ('shadow', 'live', 'retired')),
+    sample_rate DOUBLE PRECISION NOT NULL CHECK (sample_rate > 0 AND sample_rate <= 1),
+    created_by  TEXT        NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    promoted_at TIMESTAMPTZ,
+    report      JSONB
+);
+
+-- At most one live and one shadow prompt per scope.
+CREATE UNIQUE INDEX idx_extraction_prompts_live ON extraction_prompts ((COALESCE(region, ''))) WHERE status = 'live';
+CREATE UNIQUE INDEX idx_extraction_prompts_shadow ON extraction_prompts ((COALESCE(region, ''))) WHERE status = 'shadow';
+
+-- Both prompts' output for one piece of sampled content. Never written to the graph.
+CREATE TABLE shadow_extractions (
+    id               UUID        PRIMARY KEY,
+    prompt_id        UUID        NOT NULL REFERENCES extraction_prompts(id) ON DELETE CASCADE,
+    run_id           TEXT        NOT NULL,
+    region           TEXT        NOT NULL,
+    source_url       TEXT        NOT NULL,
+    content          TEXT        NOT NULL,
+    production       JSONB       NOT NULL,
+    candidate        JSONB       NOT NULL,
+    production_score DOUBLE PRECISION,
+    candidate_score  DOUBLE PRECISION,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_shadow_extractions_prompt ON shadow_extractions (prompt_id, created_at);
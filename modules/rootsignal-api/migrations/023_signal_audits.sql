@@ -0,0 +1,19 @@
+-- Judge verdicts on a sample of each run's new signals, trended per region
+-- on the admin dashboard. Signals with a critical issue are suppressed.
+
+CREATE TABLE signal_audits (
+    id          UUID             PRIMARY KEY,
+    run_id      TEXT             NOT NULL,
+    region      TEXT             NOT NULL,
+    signal_id   UUID             NOT NULL,
+    signal_type TEXT             NOT NULL,
+    source_url  TEXT             NOT NULL,
+    -- 0 (unusable) to 1 (faithful and complete).
+    score       DOUBLE PRECISION NOT NULL,
+    critical    BOOLEAN          NOT NULL,
+    suppressed  BOOLEAN          NOT NULL,
+    issues      JSONB            NOT NULL DEFAULT '[]',
+    created_at  TIMESTAMPTZ      NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_signal_audits_region_created ON signal_audits (region, created_at);
@@ -0,0 +1,6 @@
+-- Engagement velocity (weighted interactions per hour) of each post snapshot.
+-- Computed at fetch time against the previous snapshot of the same post.
+
+ALTER TABLE posts ADD COLUMN engagement_velocity DOUBLE PRECISION;
+
+CREATE INDEX idx_posts_permalink ON posts(permalink, fetched_at DESC) WHERE permalink IS NOT NULL;
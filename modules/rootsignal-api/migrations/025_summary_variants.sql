@@ -0,0 +1,17 @@
+-- Plain-language and translated summaries of high-visibility signals and
+-- situations. `source_hash` is the hash of the English summary each variant
+-- was generated from; a variant whose hash no longer matches is stale and is
+-- neither served nor kept past its next refresh.
+
+CREATE TABLE summary_variants (
+    subject_id    UUID        NOT NULL,
+    -- 'signal' or 'situation'
+    subject_kind  TEXT        NOT NULL,
+    -- Primary language subtag, e.g. 'es'. 'en' holds a plain-language English summary.
+    language      TEXT        NOT NULL,
+    summary       TEXT        NOT NULL,
+    plain_summary TEXT,
+    source_hash   TEXT        NOT NULL,
+    generated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (subject_id, language)
+);
@@ -0,0 +1,19 @@
+-- Outbound room feed messages (Discord/Matrix), queued on cache reload and
+-- posted by the API's delivery worker with per-feed rate limits and retries.
+
+CREATE TABLE room_deliveries (
+    id UUID PRIMARY KEY,
+    feed_id UUID NOT NULL,
+    signal_id UUID NOT NULL,
+    body TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',  -- pending, delivered, failed
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (feed_id, signal_id)
+);
+
+CREATE INDEX idx_room_deliveries_due ON room_deliveries(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_room_deliveries_feed ON room_deliveries(feed_id, delivered_at);
@@ -0,0 +1,16 @@
+-- Budget policy for synthesis phases: the allocation mode and per-phase
+-- priorities. Settings not stored here use their built-in default; each
+-- synthesis run records the allocation it made on its run log.
+
+CREATE TABLE synthesis_budget_policies (
+    -- NULL applies in every region; a region row beats it setting by setting.
+    region     TEXT,
+    -- 'rank' or 'proportional'; NULL falls through.
+    mode       TEXT,
+    -- Phase name -> priority (0 disables the phase).
+    priorities JSONB       NOT NULL DEFAULT '{}',
+    updated_by TEXT        NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX idx_synthesis_budget_policies_region ON synthesis_budget_policies ((COALESCE(region, '')));
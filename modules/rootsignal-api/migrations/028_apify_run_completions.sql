@@ -0,0 +1,13 @@
+-- Apify run completions reported by webhook. The receiver records each
+-- terminal event here and sends NOTIFY apify_run_completed with the run id;
+-- a scout waiting on that run wakes up and fetches its results.
+
+CREATE TABLE apify_run_completions (
+    run_id      TEXT PRIMARY KEY,
+    -- The run's terminal status: SUCCEEDED, FAILED, ABORTED or TIMED-OUT.
+    status      TEXT        NOT NULL,
+    dataset_id  TEXT,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_apify_run_completions_received_at ON apify_run_completions (received_at);
@@ -0,0 +1,27 @@
+-- Per-run failure tallies for error budgets. Each scout run records, per
+-- operation, its attempts, failures and a few sample failures; the
+-- supervisor sums them over a rolling window and alerts when an
+-- operation's failure rate goes over its budget.
+
+CREATE TABLE operation_failures (
+    id          BIGSERIAL PRIMARY KEY,
+    run_id      TEXT        NOT NULL,
+    region      TEXT        NOT NULL,
+    -- 'scrape' or a discovery finder ('response_finder', ...).
+    operation   TEXT        NOT NULL,
+    attempts    INT         NOT NULL,
+    failures    INT         NOT NULL,
+    -- Up to three {reason, error} objects.
+    samples     JSONB       NOT NULL DEFAULT '[]',
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_operation_failures_region_recorded ON operation_failures (region, recorded_at);
+
+-- When each burned budget last alerted, so it alerts once per window.
+CREATE TABLE error_budget_alerts (
+    region     TEXT        NOT NULL,
+    operation  TEXT        NOT NULL,
+    alerted_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (region, operation)
+);
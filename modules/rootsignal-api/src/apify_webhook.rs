@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::{debug, warn};
+
+use rootsignal_archive::{record_apify_completion, ApifyWebhookPayload};
+use rootsignal_common::log_events::WebhookRejected;
+use rootsignal_common::Secret;
+
+use crate::inbound_email::constant_time_eq;
+
+pub struct ApifyWebhookState {
+    pub pool: PgPool,
+    pub secret: Secret,
+}
+
+#[derive(Deserialize)]
+pub struct ApifyWebhookQuery {
+    key: Option<String>,
+}
+
+/// Apify run completion webhook. Scouts register it on every actor run
+/// with `?key=<APIFY_WEBHOOK_SECRET>`; each terminal event is recorded in
+/// Postgres, which wakes the scout waiting on the run.
+///
+/// Storage failures get 500 so Apify redelivers.
+pub async fn apify_webhook_handler(
+    State(state): State<Option<Arc<ApifyWebhookState>>>,
+    Query(params): Query<ApifyWebhookQuery>,
+    body: String,
+) -> impl IntoResponse {
+    let Some(state) = state else {
+        return (StatusCode::SERVICE_UNAVAILABLE, "Apify webhook not configured").into_response();
+    };
+    let authorized = params
+        .key
+        .as_deref()
+        .is_some_and(|k| constant_time_eq(k.as_bytes(), state.secret.expose().as_bytes()));
+    if !authorized {
+        WebhookRejected { webhook: "apify", reason: "unauthorized", detail: String::new() }.emit();
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let payload = match ApifyWebhookPayload::parse(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            WebhookRejected { webhook: "apify", reason: "malformed", detail: e.to_string() }.emit();
+            return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+        }
+    };
+
+    match record_apify_completion(&state.pool, &payload).await {
+        Ok(recorded) => {
+            debug!(
+                run_id = payload.event_data.actor_run_id.as_str(),
+                event = payload.event_type.as_str(),
+                recorded,
+                "Apify webhook received"
+            );
+            StatusCode::OK.into_response()
+        }
+        Err(e) => {
+            warn!(error = %e, run_id = payload.event_data.actor_run_id.as_str(), "Failed to record Apify run completion");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod models;
+pub mod pools;
 
 pub use models::archive;
 pub use models::scout_run;
+pub use pools::PgPools;
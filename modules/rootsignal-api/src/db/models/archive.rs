@@ -352,6 +352,25 @@ pub async fn recent_pages(pool: &PgPool, limit: u32) -> Result<Vec<ArchivePageRo
         .collect())
 }
 
+/// When the archive last fetched `url`, as a page, a source or a post.
+pub async fn last_fetched(pool: &PgPool, url: &str) -> Result<Option<DateTime<Utc>>> {
+    let (at,) = sqlx::query_as::<_, (Option<DateTime<Utc>>,)>(
+        r#"
+        SELECT GREATEST(
+            (SELECT max(pg.fetched_at) FROM pages pg JOIN sources s ON s.id = pg.source_id WHERE s.url = $1),
+            (SELECT max(sct.last_scraped_at) FROM source_content_types sct
+               JOIN sources s ON s.id = sct.source_id WHERE s.url = $1),
+            (SELECT max(p.fetched_at) FROM posts p WHERE p.permalink = $1)
+        )
+        "#,
+    )
+    .bind(url)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(at)
+}
+
 pub async fn recent_feeds(pool: &PgPool, limit: u32) -> Result<Vec<ArchiveFeedRow>> {
     let limit = limit.min(100) as i64;
 
@@ -1,2 +1,3 @@
 pub mod archive;
+pub mod room_delivery;
 pub mod scout_run;
@@ -0,0 +1,218 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Attempts before a delivery is given up on.
+pub const MAX_ATTEMPTS: i32 = 6;
+/// First retry delay; doubles with each further attempt.
+const RETRY_BASE_SECS: i64 = 60;
+
+// ---------------------------------------------------------------------------
+// Row types
+// ---------------------------------------------------------------------------
+
+pub struct RoomDeliveryRow {
+    pub id: Uuid,
+    pub feed_id: Uuid,
+    pub signal_id: Uuid,
+    pub body: String,
+    pub attempts: i32,
+}
+
+/// Delivery counts for one feed.
+#[derive(Default)]
+pub struct RoomDeliveryStats {
+    pub pending: i64,
+    pub delivered_last_hour: i64,
+    pub failed: i64,
+    pub last_error: Option<String>,
+}
+
+/// When to try a delivery again after its `attempts`th failure, or `None`
+/// once it has used all its attempts. `retry_after` (a platform rate-limit
+/// hint) overrides the backoff when longer.
+pub fn next_attempt_at(
+    attempts: i32,
+    retry_after: Option<std::time::Duration>,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    if attempts >= MAX_ATTEMPTS {
+        return None;
+    }
+    let backoff = Duration::seconds(RETRY_BASE_SECS << (attempts - 1).clamp(0, 10));
+    let hint = retry_after
+        .and_then(|d| Duration::from_std(d).ok())
+        .unwrap_or_else(Duration::zero);
+    Some(now + backoff.max(hint))
+}
+
+// ---------------------------------------------------------------------------
+// Queries
+// ---------------------------------------------------------------------------
+
+/// Queue a message for a feed. A signal is only ever queued once per feed.
+pub async fn enqueue(pool: &PgPool, feed_id: Uuid, signal_id: Uuid, body: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO room_deliveries (id, feed_id, signal_id, body)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (feed_id, signal_id) DO NOTHING
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(feed_id)
+    .bind(signal_id)
+    .bind(body)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Pending deliveries due by `now`, oldest first.
+pub async fn due(pool: &PgPool, now: DateTime<Utc>, limit: u32) -> Result<Vec<RoomDeliveryRow>> {
+    let rows = sqlx::query_as::<_, (Uuid, Uuid, Uuid, String, i32)>(
+        r#"
+        SELECT id, feed_id, signal_id, body, attempts
+        FROM room_deliveries
+        WHERE status = 'pending' AND next_attempt_at <= $1
+        ORDER BY created_at
+        LIMIT $2
+        "#,
+    )
+    .bind(now)
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(id, feed_id, signal_id, body, attempts)| RoomDeliveryRow {
+            id,
+            feed_id,
+            signal_id,
+            body,
+            attempts,
+        })
+        .collect())
+}
+
+/// Messages a feed has posted since `since`.
+pub async fn delivered_since(pool: &PgPool, feed_id: Uuid, since: DateTime<Utc>) -> Result<i64> {
+    let count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM room_deliveries WHERE feed_id = $1 AND status = 'delivered' AND delivered_at > $2",
+    )
+    .bind(feed_id)
+    .bind(since)
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn mark_delivered(pool: &PgPool, id: Uuid, now: DateTime<Utc>) -> Result<()> {
+    sqlx::query(
+        "UPDATE room_deliveries SET status = 'delivered', attempts = attempts + 1, delivered_at = $2, last_error = NULL WHERE id = $1",
+    )
+    .bind(id)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record a failed attempt: retry at `next_attempt_at`, or mark the delivery
+/// failed when there is none.
+pub async fn mark_attempt_failed(
+    pool: &PgPool,
+    id: Uuid,
+    error: &str,
+    next_attempt_at: Option<DateTime<Utc>>,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE room_deliveries
+        SET attempts = attempts + 1,
+            last_error = $2,
+            status = CASE WHEN $3::timestamptz IS NULL THEN 'failed' ELSE 'pending' END,
+            next_attempt_at = COALESCE($3, next_attempt_at)
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(error)
+    .bind(next_attempt_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Push a feed's due deliveries back to `until`, without counting an attempt.
+pub async fn defer_feed(pool: &PgPool, feed_id: Uuid, until: DateTime<Utc>) -> Result<()> {
+    sqlx::query(
+        "UPDATE room_deliveries SET next_attempt_at = $2 WHERE feed_id = $1 AND status = 'pending' AND next_attempt_at < $2",
+    )
+    .bind(feed_id)
+    .bind(until)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Drop a deleted feed's queue and history.
+pub async fn delete_feed(pool: &PgPool, feed_id: Uuid) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM room_deliveries WHERE feed_id = $1")
+        .bind(feed_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn stats(pool: &PgPool, feed_id: Uuid, now: DateTime<Utc>) -> Result<RoomDeliveryStats> {
+    let row = sqlx::query_as::<_, (i64, i64, i64, Option<String>)>(
+        r#"
+        SELECT
+            count(*) FILTER (WHERE status = 'pending'),
+            count(*) FILTER (WHERE status = 'delivered' AND delivered_at > $2),
+            count(*) FILTER (WHERE status = 'failed'),
+            (SELECT last_error FROM room_deliveries
+             WHERE feed_id = $1 AND last_error IS NOT NULL
+             ORDER BY next_attempt_at DESC LIMIT 1)
+        FROM room_deliveries
+        WHERE feed_id = $1
+        "#,
+    )
+    .bind(feed_id)
+    .bind(now - Duration::hours(1))
+    .fetch_one(pool)
+    .await?;
+
+    Ok(RoomDeliveryStats {
+        pending: row.0,
+        delivered_last_hour: row.1,
+        failed: row.2,
+        last_error: row.3,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retries_back_off_exponentially_then_give_up() {
+        let now = Utc::now();
+
+        assert_eq!(next_attempt_at(1, None, now), Some(now + Duration::seconds(60)));
+        assert_eq!(next_attempt_at(3, None, now), Some(now + Duration::seconds(240)));
+        assert_eq!(next_attempt_at(MAX_ATTEMPTS, None, now), None);
+    }
+
+    #[test]
+    fn a_longer_rate_limit_hint_wins_over_backoff() {
+        let now = Utc::now();
+
+        let hinted = next_attempt_at(1, Some(std::time::Duration::from_secs(600)), now);
+
+        assert_eq!(hinted, Some(now + Duration::seconds(600)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
@@ -13,6 +15,7 @@ pub struct StatsJson {
     pub urls_failed: Option<u32>,
     pub signals_extracted: Option<u32>,
     pub signals_deduplicated: Option<u32>,
+    pub signals_suppressed: Option<u32>,
     pub signals_stored: Option<u32>,
     pub social_media_posts: Option<u32>,
     pub expansion_queries_collected: Option<u32>,
@@ -57,6 +60,10 @@ pub struct EventJson {
     pub remaining_cents: Option<u64>,
     pub topics: Option<Vec<String>>,
     pub posts_found: Option<u32>,
+    pub score: Option<f64>,
+    pub suppressed: Option<bool>,
+    pub kind: Option<String>,
+    pub error_class: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -70,8 +77,20 @@ pub struct ScoutRunRow {
     pub finished_at: DateTime<Utc>,
     pub stats: StatsJson,
     pub events: Vec<EventJson>,
+    /// Feature flags by name.
+    pub flags: BTreeMap<String, bool>,
 }
 
+type ScoutRunTuple = (
+    String,
+    String,
+    DateTime<Utc>,
+    DateTime<Utc>,
+    serde_json::Value,
+    serde_json::Value,
+    serde_json::Value,
+);
+
 // ---------------------------------------------------------------------------
 // Queries
 // ---------------------------------------------------------------------------
@@ -79,9 +98,9 @@ pub struct ScoutRunRow {
 pub async fn list_by_region(pool: &PgPool, region: &str, limit: u32) -> Result<Vec<ScoutRunRow>> {
     let limit = limit.min(100) as i64;
 
-    let rows = sqlx::query_as::<_, (String, String, DateTime<Utc>, DateTime<Utc>, serde_json::Value, serde_json::Value)>(
+    let rows = sqlx::query_as::<_, ScoutRunTuple>(
         r#"
-        SELECT run_id, region, started_at, finished_at, stats, events
+        SELECT run_id, region, started_at, finished_at, stats, events, flags
         FROM scout_runs
         WHERE region = $1
         ORDER BY finished_at DESC
@@ -97,9 +116,9 @@ pub async fn list_by_region(pool: &PgPool, region: &str, limit: u32) -> Result<V
 }
 
 pub async fn find_by_id(pool: &PgPool, run_id: &str) -> Result<Option<ScoutRunRow>> {
-    let row = sqlx::query_as::<_, (String, String, DateTime<Utc>, DateTime<Utc>, serde_json::Value, serde_json::Value)>(
+    let row = sqlx::query_as::<_, ScoutRunTuple>(
         r#"
-        SELECT run_id, region, started_at, finished_at, stats, events
+        SELECT run_id, region, started_at, finished_at, stats, events, flags
         FROM scout_runs
         WHERE run_id = $1
         "#,
@@ -111,13 +130,49 @@ pub async fn find_by_id(pool: &PgPool, run_id: &str) -> Result<Option<ScoutRunRo
     Ok(row.map(row_to_scout_run))
 }
 
+/// Events in a region's runs that name `url` or whose title contains
+/// `title`, newest first, each with its run id.
+pub async fn events_mentioning(
+    pool: &PgPool,
+    region: &str,
+    url: Option<&str>,
+    title: Option<&str>,
+    limit: u32,
+) -> Result<Vec<(String, EventJson)>> {
+    let limit = limit.min(500) as i64;
+
+    let rows = sqlx::query_as::<_, (String, serde_json::Value)>(
+        r#"
+        SELECT r.run_id, e.event
+        FROM scout_runs r, jsonb_array_elements(r.events) AS e(event)
+        WHERE r.region = $1
+          AND ($2::text IS NULL
+               OR e.event->>'url' = $2
+               OR e.event->>'source_url' = $2
+               OR e.event->>'new_source_url' = $2)
+          AND ($3::text IS NULL OR strpos(lower(e.event->>'title'), lower($3)) > 0)
+        ORDER BY r.finished_at DESC, (e.event->>'seq')::int DESC
+        LIMIT $4
+        "#,
+    )
+    .bind(region)
+    .bind(url)
+    .bind(title)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(run_id, event)| Some((run_id, serde_json::from_value(event).ok()?)))
+        .collect())
+}
+
 // ---------------------------------------------------------------------------
 // Internal
 // ---------------------------------------------------------------------------
 
-fn row_to_scout_run(
-    r: (String, String, DateTime<Utc>, DateTime<Utc>, serde_json::Value, serde_json::Value),
-) -> ScoutRunRow {
+fn row_to_scout_run(r: ScoutRunTuple) -> ScoutRunRow {
     ScoutRunRow {
         run_id: r.0,
         region: r.1,
@@ -125,5 +180,6 @@ fn row_to_scout_run(
         finished_at: r.3,
         stats: serde_json::from_value(r.4).unwrap_or_default(),
         events: serde_json::from_value(r.5).unwrap_or_default(),
+        flags: serde_json::from_value(r.6).unwrap_or_default(),
     }
 }
@@ -0,0 +1,222 @@
+//! Read/write splitting across a Postgres primary and an optional replica.
+//!
+//! Writes always go to the primary. Read-only queries (listings, run history,
+//! archive reads) go to the replica while it keeps up; once replication lag
+//! passes the threshold, or the lag hasn't been checked recently, reads fall
+//! back to the primary until the replica catches up again.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// Lag past which reads stop going to the replica, unless
+/// `DATABASE_REPLICA_MAX_LAG_SECS` says otherwise.
+pub const DEFAULT_MAX_LAG: Duration = Duration::from_secs(5);
+
+/// How often the replica's lag is measured.
+pub const LAG_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The last replica lag measurement.
+#[derive(Debug, Clone, Default)]
+pub struct ReplicaLag {
+    /// Seconds the replica trails the primary; None until first measured.
+    pub seconds: Option<f64>,
+    pub checked_at: Option<DateTime<Utc>>,
+    /// Why the last check failed, if it did.
+    pub error: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct PgPools {
+    primary: PgPool,
+    replica: Option<PgPool>,
+    max_lag: Duration,
+    lag: Arc<RwLock<ReplicaLag>>,
+}
+
+impl PgPools {
+    pub fn new(primary: PgPool, replica: Option<PgPool>, max_lag: Duration) -> Self {
+        Self {
+            primary,
+            replica,
+            max_lag,
+            lag: Arc::new(RwLock::new(ReplicaLag::default())),
+        }
+    }
+
+    /// Connect the replica named by `DATABASE_REPLICA_URL`, if any. A replica
+    /// that can't be reached is left out rather than failing startup.
+    pub async fn from_env(primary: PgPool) -> Self {
+        let max_lag = std::env::var("DATABASE_REPLICA_MAX_LAG_SECS")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|secs| *secs > 0.0)
+            .map(Duration::from_secs_f64)
+            .unwrap_or(DEFAULT_MAX_LAG);
+        let replica = match std::env::var("DATABASE_REPLICA_URL").ok().filter(|s| !s.is_empty()) {
+            Some(url) => match PgPoolOptions::new().max_connections(5).connect(&url).await {
+                Ok(pool) => {
+                    info!(max_lag_secs = max_lag.as_secs_f64(), "Postgres read replica connected");
+                    Some(pool)
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to connect to Postgres read replica — reads use the primary");
+                    None
+                }
+            },
+            None => None,
+        };
+        Self::new(primary, replica, max_lag)
+    }
+
+    /// The pool for writes and anything that must see them immediately.
+    pub fn primary(&self) -> &PgPool {
+        &self.primary
+    }
+
+    /// The pool for read-only queries: the replica while it's fresh enough.
+    pub async fn read(&self) -> &PgPool {
+        match &self.replica {
+            Some(replica) if self.replica_fresh(&*self.lag.read().await, Utc::now()) => replica,
+            _ => &self.primary,
+        }
+    }
+
+    pub fn has_replica(&self) -> bool {
+        self.replica.is_some()
+    }
+
+    pub fn max_lag(&self) -> Duration {
+        self.max_lag
+    }
+
+    pub async fn lag(&self) -> ReplicaLag {
+        self.lag.read().await.clone()
+    }
+
+    /// Whether reads currently go to the replica.
+    pub async fn reading_from_replica(&self) -> bool {
+        self.replica.is_some() && self.replica_fresh(&*self.lag.read().await, Utc::now())
+    }
+
+    /// A measurement is only trusted for a few check intervals; a monitor that
+    /// has stopped reporting counts as lagging.
+    fn replica_fresh(&self, lag: &ReplicaLag, now: DateTime<Utc>) -> bool {
+        let (Some(seconds), Some(checked_at)) = (lag.seconds, lag.checked_at) else {
+            return false;
+        };
+        let stale_after = chrono::Duration::from_std(LAG_CHECK_INTERVAL * 3).unwrap_or_default();
+        seconds <= self.max_lag.as_secs_f64() && now - checked_at <= stale_after
+    }
+
+    /// Measure the replica's lag once.
+    pub async fn check_lag(&self) {
+        let Some(replica) = &self.replica else {
+            return;
+        };
+        let was_fresh = self.reading_from_replica().await;
+        let measured: Result<Option<f64>, sqlx::Error> = sqlx::query_scalar(
+            // An idle primary sends nothing to replay, so a replica that has
+            // replayed everything it received is caught up.
+            "SELECT CASE
+                 WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0
+                 ELSE EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp())::float8
+             END",
+        )
+        .fetch_one(replica)
+        .await;
+
+        let mut lag = self.lag.write().await;
+        lag.checked_at = Some(Utc::now());
+        match measured {
+            Ok(seconds) => {
+                // NULL means the server isn't replicating at all
+                lag.seconds = Some(seconds.unwrap_or(0.0).max(0.0));
+                lag.error = None;
+            }
+            Err(e) => {
+                lag.seconds = None;
+                lag.error = Some(e.to_string());
+            }
+        }
+        let now_fresh = self.replica_fresh(&lag, Utc::now());
+        if was_fresh && !now_fresh {
+            warn!(
+                lag_secs = lag.seconds,
+                max_lag_secs = self.max_lag.as_secs_f64(),
+                error = lag.error.as_deref(),
+                "Postgres replica lagging — reads fall back to the primary"
+            );
+        } else if !was_fresh && now_fresh {
+            info!(lag_secs = lag.seconds, "Postgres replica caught up — reads use the replica");
+        }
+    }
+
+    /// Measure replica lag in the background for as long as the process runs.
+    pub fn spawn_lag_monitor(&self) {
+        if self.replica.is_none() {
+            return;
+        }
+        let pools = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(LAG_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                pools.check_lag().await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pools(max_lag_secs: u64) -> PgPools {
+        let lazy = || PgPoolOptions::new().connect_lazy("postgres://localhost/rootsignal").unwrap();
+        PgPools::new(lazy(), Some(lazy()), Duration::from_secs(max_lag_secs))
+    }
+
+    fn measured(seconds: f64, ago_secs: i64) -> ReplicaLag {
+        ReplicaLag {
+            seconds: Some(seconds),
+            checked_at: Some(Utc::now() - chrono::Duration::seconds(ago_secs)),
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn replica_within_max_lag_is_fresh() {
+        let pools = pools(5);
+
+        assert!(pools.replica_fresh(&measured(1.5, 1), Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn replica_beyond_max_lag_is_not_fresh() {
+        let pools = pools(5);
+
+        assert!(!pools.replica_fresh(&measured(12.0, 1), Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn stale_lag_measurement_is_not_trusted() {
+        let pools = pools(5);
+
+        assert!(!pools.replica_fresh(&measured(0.0, 60), Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn reads_fall_back_to_primary_until_lag_is_measured() {
+        let pools = pools(5);
+
+        assert!(!pools.reading_from_replica().await);
+        *pools.lag.write().await = measured(0.5, 0);
+        assert!(pools.reading_from_replica().await);
+    }
+}
@@ -1,4 +1,9 @@
+use std::sync::Arc;
+
 use async_graphql::{Context, ErrorExtensions, Guard, Result};
+use rootsignal_common::Visibility;
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
 
 use crate::jwt::Claims;
 
@@ -6,6 +11,10 @@ use crate::jwt::Claims;
 /// None if no valid JWT cookie was present.
 pub struct AuthContext(pub Option<Claims>);
 
+/// The request's `Accept-Language` header, for fields that serve a summary
+/// in the reader's language when the query doesn't name one.
+pub struct AcceptLanguage(pub String);
+
 /// Guard that requires a valid admin JWT.
 /// Use with `#[graphql(guard = "AdminGuard")]` on admin queries/mutations.
 pub struct AdminGuard;
@@ -21,3 +30,70 @@ impl Guard for AdminGuard {
         }
     }
 }
+
+/// The graph holding a region's data, which may be a database of its own.
+pub async fn region_graph(ctx: &Context<'_>, region: &str) -> Result<rootsignal_graph::GraphClient> {
+    ctx.data_unchecked::<rootsignal_graph::GraphRouter>()
+        .for_region(region)
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to connect to region database: {e}")))
+}
+
+/// A writer over a region's data; see [`region_graph`].
+pub async fn region_writer(ctx: &Context<'_>, region: &str) -> Result<rootsignal_graph::GraphWriter> {
+    Ok(rootsignal_graph::GraphWriter::new(region_graph(ctx, region).await?))
+}
+
+/// The Postgres pool for a read-only query: a read replica while it keeps
+/// up with the primary, otherwise the primary.
+pub async fn read_pool<'a>(ctx: &'a Context<'_>) -> Result<&'a sqlx::PgPool> {
+    match ctx.data_unchecked::<Option<crate::db::PgPools>>() {
+        Some(pools) => Ok(pools.read().await),
+        None => Err(async_graphql::Error::new("Postgres not configured")),
+    }
+}
+
+/// The signed-in member's id (the token's `sub`).
+pub fn member_id(ctx: &Context<'_>) -> Result<String> {
+    match &ctx.data_unchecked::<AuthContext>().0 {
+        Some(claims) => Ok(claims.sub.clone()),
+        None => Err(async_graphql::Error::new("Unauthenticated")
+            .extend_with(|_, e| e.set("code", "UNAUTHENTICATED"))),
+    }
+}
+
+/// The caller's visibility tier, resolved once per request.
+#[derive(Default)]
+pub struct ViewerTier(pub OnceCell<Visibility>);
+
+/// Which signal fields the caller may see: admins everything, verified
+/// contributors the contributor tier, everyone else the public tier.
+pub async fn viewer_tier(ctx: &Context<'_>) -> Visibility {
+    let resolve = async {
+        match ctx.data_opt::<AuthContext>().and_then(|a| a.0.as_ref()) {
+            Some(claims) if claims.is_admin => Visibility::Admin,
+            Some(claims) => {
+                let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+                match rootsignal_graph::annotations::contributor(client, &claims.sub).await {
+                    Ok(Some(_)) => Visibility::Contributor,
+                    _ => Visibility::Public,
+                }
+            }
+            None => Visibility::Public,
+        }
+    };
+    match ctx.data_opt::<ViewerTier>() {
+        Some(tier) => *tier.0.get_or_init(|| resolve).await,
+        None => resolve.await,
+    }
+}
+
+/// Storage key for an anonymous read token: a hash, so the graph never holds
+/// the token itself.
+pub fn read_token_key(token: &str) -> Result<String> {
+    rootsignal_common::read_state::validate_read_token(token)?;
+    let mut hasher = Sha256::new();
+    hasher.update(b"rootsignal-read-state:");
+    hasher.update(token.as_bytes());
+    Ok(hex::encode(hasher.finalize()))
+}
@@ -4,8 +4,9 @@ use std::sync::Arc;
 use async_graphql::dataloader::Loader;
 use uuid::Uuid;
 
-use rootsignal_common::{ActorNode, EvidenceNode, SituationNode, StoryNode, TagNode};
-use rootsignal_graph::CachedReader;
+use rootsignal_common::{ActorNode, Annotation, CorrectionRequest, EvidenceNode, Investigation, SituationNode, StoryNode, TagNode};
+use rootsignal_common::summary_variants::SummaryVariant;
+use rootsignal_graph::{CachedReader, GraphClient};
 
 // --- EvidenceBySignalLoader ---
 
@@ -114,3 +115,77 @@ impl Loader<Uuid> for TagsBySituationLoader {
             .map_err(Arc::new)
     }
 }
+
+// --- AnnotationsBySignalLoader ---
+
+/// Approved contributor annotations. Not cached: they change between reloads.
+pub struct AnnotationsBySignalLoader {
+    pub client: Arc<GraphClient>,
+}
+
+impl Loader<Uuid> for AnnotationsBySignalLoader {
+    type Value = Vec<Annotation>;
+    type Error = Arc<anyhow::Error>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        rootsignal_graph::annotations::approved_annotations(&self.client, keys)
+            .await
+            .map_err(|e| Arc::new(anyhow::anyhow!(e)))
+    }
+}
+
+// --- CorrectionsBySignalLoader ---
+
+pub struct CorrectionsBySignalLoader {
+    pub client: Arc<GraphClient>,
+}
+
+impl Loader<Uuid> for CorrectionsBySignalLoader {
+    type Value = Vec<CorrectionRequest>;
+    type Error = Arc<anyhow::Error>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        rootsignal_graph::corrections::corrections_for_signals(&self.client, keys)
+            .await
+            .map_err(|e| Arc::new(anyhow::anyhow!(e)))
+    }
+}
+
+// --- InvestigationsBySignalLoader ---
+
+pub struct InvestigationsBySignalLoader {
+    pub client: Arc<GraphClient>,
+}
+
+impl Loader<Uuid> for InvestigationsBySignalLoader {
+    type Value = Vec<Investigation>;
+    type Error = Arc<anyhow::Error>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        rootsignal_graph::investigations::investigations_for_signals(&self.client, keys)
+            .await
+            .map_err(|e| Arc::new(anyhow::anyhow!(e)))
+    }
+}
+
+// --- SummaryVariantsLoader ---
+
+/// Cached summary variants of signals and situations. Without Postgres
+/// there are none, and every summary is served in English.
+pub struct SummaryVariantsLoader {
+    pub pools: Option<crate::db::PgPools>,
+}
+
+impl Loader<Uuid> for SummaryVariantsLoader {
+    type Value = Vec<SummaryVariant>;
+    type Error = Arc<anyhow::Error>;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let Some(pools) = &self.pools else {
+            return Ok(HashMap::new());
+        };
+        rootsignal_common::summary_variants::load(pools.read().await, keys)
+            .await
+            .map_err(|e| Arc::new(anyhow::anyhow!(e)))
+    }
+}
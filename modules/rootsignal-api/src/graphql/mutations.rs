@@ -2,21 +2,37 @@ use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::Instant;
 
-use async_graphql::{Context, Object, Result, SimpleObject};
+use async_graphql::{Context, InputObject, Object, Result, SimpleObject};
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 use uuid::Uuid;
 
 use rootsignal_common::{
-    Config, DemandSignal, DiscoveryMethod, ScoutScope, SourceNode, SourceRole,
+    AgendaBody, AggregateGuard, Annotation, AnnotationStatus, BackfillDepth, BoundingBox, BurstWindow, CalendarWindow, CategoryRule, Config,
+    Contributor, CorrectionKind, CorrectionRequest, CorrectionStatus, DemandSignal, DiscoveryMethod, EntityGeometry, OpenDataDataset, OpenDataFields, QuietHours,
+    HoldTarget, LegalHold, NotifyChannel, RegionAgendas, RetentionPolicy, RegionCalendar, RegionOpenData, RegionRegistry, RegionSchedule, RegionServiceAlerts, RegistryEntity,
+    RegionOnboarding, RoomFeed, RoomTarget, SavedSearch, SchoolDistrict, ScoutScope, SituationSubscription, SourceNode, SourceRole, SignalField, StoryEnergyWeights, SubjectRequest, SubjectRequestStatus,
+    TopicCategory, TransitAgency, TrialState, Visibility,
 };
-use rootsignal_graph::GraphWriter;
+use rootsignal_common::extraction_prompts::{self, NewPrompt, PromptStatus, ShadowThresholds};
+use rootsignal_common::feature_flags::{self, Flag};
+use rootsignal_common::synthesis_budget::{self, AllocationMode, SynthesisPhase};
+use rootsignal_common::negative_knowledge::{self, NegativeKind, NewNegativeEntry};
+use rootsignal_common::onboarding::DEFAULT_SCHEDULE_CRON;
+use rootsignal_archive::{CredentialKind, CredentialStore, SourceCredential};
+use rootsignal_graph::taxonomy::load_taxonomy;
+use rootsignal_graph::{CachedReader, GraphRouter, GraphWriter};
 use rootsignal_scout::pipeline::traits::SignalStore;
 
 use crate::jwt::{self, JwtService};
 use crate::restate_client::RestateClient;
 
-use super::context::AdminGuard;
+use super::context::{member_id, read_token_key, region_graph, region_writer, AdminGuard};
+use super::schema::AdminRegionOnboarding;
+use super::types::{
+    GqlAnnotation, GqlCorrection, GqlCorrectionKind, GqlDataClass, GqlNotifyChannelKind, GqlOpenDataSignal, GqlPortalKind, GqlRegistryKind, GqlRoomFeed, GqlRoomPlatform,
+    GqlSavedSearch, GqlSeverity, GqlSignalField, GqlSubjectRequest, GqlVisibility, SignalType,
+};
 
 /// Rate limiter state shared via GraphQL context.
 pub struct RateLimiter(pub Mutex<std::collections::HashMap<IpAddr, Vec<Instant>>>);
@@ -66,6 +82,364 @@ struct SubmitSourceResult {
     source_id: Option<String>,
 }
 
+// --- Saved search inputs ---
+
+#[derive(InputObject)]
+struct SavedSearchInput {
+    /// Omit to create; pass an existing id to replace that search.
+    id: Option<Uuid>,
+    name: String,
+    bbox: Option<BoundingBoxInput>,
+    #[graphql(default)]
+    types: Vec<SignalType>,
+    /// Topic categories; each includes its subcategories.
+    #[graphql(default)]
+    categories: Vec<String>,
+    #[graphql(default)]
+    keywords: Vec<String>,
+    #[graphql(default)]
+    notify: Vec<NotifyChannelInput>,
+}
+
+#[derive(InputObject)]
+struct BoundingBoxInput {
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+}
+
+#[derive(InputObject)]
+struct NotifyChannelInput {
+    channel: GqlNotifyChannelKind,
+    /// Email address, E.164 phone number or https URL.
+    target: String,
+}
+
+// --- Room feed inputs ---
+
+#[derive(InputObject)]
+struct RoomFeedInput {
+    /// Omit to create; pass an existing id to replace that feed.
+    id: Option<Uuid>,
+    name: String,
+    platform: GqlRoomPlatform,
+    /// Discord only. May be omitted when editing to keep the current webhook.
+    webhook_url: Option<String>,
+    /// Matrix only, e.g. "https://matrix.org".
+    homeserver: Option<String>,
+    /// Matrix only, e.g. "!abc123:matrix.org".
+    room_id: Option<String>,
+    bbox: Option<BoundingBoxInput>,
+    #[graphql(default)]
+    types: Vec<SignalType>,
+    min_severity: Option<GqlSeverity>,
+    /// Placeholders: {type} {title} {summary} {location} {category} {url}.
+    /// Omit for the default template.
+    template: Option<String>,
+    max_per_hour: Option<u32>,
+    #[graphql(default)]
+    paused: bool,
+}
+
+// --- Story energy inputs ---
+
+#[derive(InputObject)]
+struct StoryEnergyWeightsInput {
+    activity: f64,
+    severity: f64,
+    corroboration: f64,
+    heat: f64,
+}
+
+impl From<StoryEnergyWeightsInput> for StoryEnergyWeights {
+    fn from(input: StoryEnergyWeightsInput) -> Self {
+        StoryEnergyWeights {
+            activity: input.activity,
+            severity: input.severity,
+            corroboration: input.corroboration,
+            heat: input.heat,
+        }
+    }
+}
+
+// --- Region calendar inputs ---
+
+#[derive(InputObject)]
+struct RegionCalendarInput {
+    /// IANA timezone the quiet hours are expressed in (e.g. "America/Chicago").
+    timezone: String,
+    quiet_start_hour: Option<u32>,
+    quiet_end_hour: Option<u32>,
+    #[graphql(default)]
+    blackouts: Vec<CalendarWindowInput>,
+    #[graphql(default)]
+    bursts: Vec<BurstWindowInput>,
+}
+
+#[derive(InputObject)]
+struct CalendarWindowInput {
+    starts_at: chrono::DateTime<chrono::Utc>,
+    ends_at: chrono::DateTime<chrono::Utc>,
+    reason: String,
+}
+
+#[derive(InputObject)]
+struct BurstWindowInput {
+    starts_at: chrono::DateTime<chrono::Utc>,
+    ends_at: chrono::DateTime<chrono::Utc>,
+    reason: String,
+    /// Cadence multiplier while active, in (0, 1]; 0.25 scrapes 4x as often.
+    cadence_factor: f64,
+}
+
+// --- Region open-data inputs ---
+
+#[derive(InputObject)]
+struct OpenDataDatasetInput {
+    /// Human-readable name, used as the source authority ("Minneapolis 311").
+    name: String,
+    portal: GqlPortalKind,
+    /// Portal root, e.g. "https://opendata.minneapolismn.gov".
+    base_url: String,
+    /// Socrata four-by-four ID or CKAN resource ID.
+    dataset_id: String,
+    fields: OpenDataFieldsInput,
+    categories: Vec<CategoryRuleInput>,
+    #[graphql(default = 50)]
+    max_records: u32,
+    #[graphql(default = 7)]
+    lookback_days: u32,
+}
+
+/// Column names holding each record field.
+#[derive(InputObject)]
+struct OpenDataFieldsInput {
+    id: String,
+    category: String,
+    opened_at: String,
+    description: Option<String>,
+    latitude: Option<String>,
+    longitude: Option<String>,
+    address: Option<String>,
+}
+
+#[derive(InputObject)]
+struct CategoryRuleInput {
+    /// Matched case-insensitively against the record's category.
+    contains: String,
+    signal: GqlOpenDataSignal,
+    severity: GqlSeverity,
+}
+
+impl From<OpenDataDatasetInput> for OpenDataDataset {
+    fn from(input: OpenDataDatasetInput) -> Self {
+        OpenDataDataset {
+            name: input.name,
+            portal: input.portal.into(),
+            base_url: input.base_url,
+            dataset_id: input.dataset_id,
+            fields: OpenDataFields {
+                id: input.fields.id,
+                category: input.fields.category,
+                opened_at: input.fields.opened_at,
+                description: input.fields.description,
+                latitude: input.fields.latitude,
+                longitude: input.fields.longitude,
+                address: input.fields.address,
+            },
+            categories: input
+                .categories
+                .into_iter()
+                .map(|r| CategoryRule {
+                    contains: r.contains,
+                    signal: r.signal.into(),
+                    severity: r.severity.into(),
+                })
+                .collect(),
+            max_records: input.max_records,
+            lookback_days: input.lookback_days,
+        }
+    }
+}
+
+// --- Region agenda inputs ---
+
+#[derive(InputObject)]
+struct AgendaBodyInput {
+    /// Human-readable name, used as the source authority ("Minneapolis City Council").
+    name: String,
+    /// Legistar client, as in webapi.legistar.com/v1/{client}.
+    legistar_client: String,
+    /// Legistar body name to follow; omit to follow every body.
+    body_name: Option<String>,
+    /// IANA timezone meeting times are published in.
+    timezone: String,
+    location_name: String,
+    latitude: f64,
+    longitude: f64,
+    #[graphql(default = 14)]
+    lookahead_days: u32,
+    #[graphql(default = 14)]
+    lookback_days: u32,
+}
+
+impl From<AgendaBodyInput> for AgendaBody {
+    fn from(input: AgendaBodyInput) -> Self {
+        AgendaBody {
+            name: input.name,
+            legistar_client: input.legistar_client,
+            body_name: input.body_name,
+            timezone: input.timezone,
+            location_name: input.location_name,
+            latitude: input.latitude,
+            longitude: input.longitude,
+            lookahead_days: input.lookahead_days,
+            lookback_days: input.lookback_days,
+        }
+    }
+}
+
+// --- Region service alert inputs ---
+
+#[derive(InputObject)]
+struct TransitAgencyInput {
+    /// Human-readable name, used as the source authority ("Metro Transit").
+    name: String,
+    /// GTFS-realtime service alerts feed; put any API key in the query string.
+    alerts_url: String,
+    /// Route ids to follow; omit to follow every route.
+    #[graphql(default)]
+    routes: Vec<String>,
+    location_name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl From<TransitAgencyInput> for TransitAgency {
+    fn from(input: TransitAgencyInput) -> Self {
+        TransitAgency {
+            name: input.name,
+            alerts_url: input.alerts_url,
+            routes: input.routes,
+            location_name: input.location_name,
+            latitude: input.latitude,
+            longitude: input.longitude,
+        }
+    }
+}
+
+#[derive(InputObject)]
+struct SchoolDistrictInput {
+    /// Human-readable name ("Minneapolis Public Schools").
+    name: String,
+    /// RSS or Atom feed of district announcements.
+    feed_url: String,
+    /// School names to look for in announcements.
+    #[graphql(default)]
+    schools: Vec<String>,
+    /// Words that make an announcement an alert; omit for the defaults
+    /// (closed, late start, e-learning, ...).
+    alert_keywords: Option<Vec<String>>,
+    location_name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl From<SchoolDistrictInput> for SchoolDistrict {
+    fn from(input: SchoolDistrictInput) -> Self {
+        SchoolDistrict {
+            name: input.name,
+            feed_url: input.feed_url,
+            schools: input.schools,
+            alert_keywords: input
+                .alert_keywords
+                .unwrap_or_else(rootsignal_common::service_alerts::default_school_alert_keywords),
+            location_name: input.location_name,
+            latitude: input.latitude,
+            longitude: input.longitude,
+        }
+    }
+}
+
+// --- Region registry inputs ---
+
+#[derive(InputObject)]
+struct RegistryEntityInput {
+    kind: GqlRegistryKind,
+    /// Route and ward numbers ("21", "9"); full names for schools and parks.
+    name: String,
+    /// Other ways signals refer to it ("21 bus").
+    #[graphql(default)]
+    aliases: Vec<String>,
+    /// Location of a school or park.
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    /// Boundary ring of a ward or park as [lat, lng] pairs; takes precedence
+    /// over a point.
+    boundary: Option<Vec<Vec<f64>>>,
+}
+
+impl TryFrom<RegistryEntityInput> for RegistryEntity {
+    type Error = String;
+
+    fn try_from(input: RegistryEntityInput) -> std::result::Result<Self, String> {
+        let geometry = match (input.boundary, input.latitude.zip(input.longitude)) {
+            (Some(boundary), _) => Some(EntityGeometry::Polygon {
+                ring: boundary
+                    .into_iter()
+                    .map(|pair| match pair[..] {
+                        [lat, lng] => Ok([lat, lng]),
+                        _ => Err(format!("Boundary of '{}' has a point that isn't [lat, lng]", input.name)),
+                    })
+                    .collect::<std::result::Result<_, _>>()?,
+            }),
+            (None, Some((lat, lng))) => Some(EntityGeometry::Point { lat, lng }),
+            (None, None) => None,
+        };
+        Ok(RegistryEntity {
+            kind: input.kind.into(),
+            name: input.name,
+            aliases: input.aliases,
+            geometry,
+        })
+    }
+}
+
+impl From<RegionCalendarInput> for RegionCalendar {
+    fn from(input: RegionCalendarInput) -> Self {
+        RegionCalendar {
+            timezone: input.timezone,
+            quiet_hours: input
+                .quiet_start_hour
+                .zip(input.quiet_end_hour)
+                .map(|(start, end)| QuietHours {
+                    start_hour: start.min(u32::from(u8::MAX)) as u8,
+                    end_hour: end.min(u32::from(u8::MAX)) as u8,
+                }),
+            blackouts: input
+                .blackouts
+                .into_iter()
+                .map(|w| CalendarWindow {
+                    starts_at: w.starts_at,
+                    ends_at: w.ends_at,
+                    reason: w.reason,
+                })
+                .collect(),
+            bursts: input
+                .bursts
+                .into_iter()
+                .map(|b| BurstWindow {
+                    starts_at: b.starts_at,
+                    ends_at: b.ends_at,
+                    reason: b.reason,
+                    cadence_factor: b.cadence_factor,
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Test phone number — only available in debug builds.
 #[cfg(debug_assertions)]
 const TEST_PHONE: Option<&str> = Some("+1234567890");
@@ -75,6 +449,8 @@ const TEST_PHONE: Option<&str> = None;
 const AUTH_RATE_LIMIT_PER_HOUR: usize = 10;
 const SUBMIT_RATE_LIMIT_PER_HOUR: usize = 10;
 const DEMAND_RATE_LIMIT_PER_HOUR: usize = 10;
+const CORRECTION_RATE_LIMIT_PER_HOUR: usize = 10;
+const SUBSCRIBE_RATE_LIMIT_PER_HOUR: usize = 10;
 
 #[Object]
 impl MutationRoot {
@@ -83,13 +459,12 @@ impl MutationRoot {
     /// Send an OTP code to the given phone number.
     async fn send_otp(&self, ctx: &Context<'_>, phone: String) -> Result<SendOtpResult> {
         let phone = phone.trim().to_string();
-        let config = ctx.data_unchecked::<Arc<Config>>();
 
         // Rate limit
         rate_limit_check(ctx, AUTH_RATE_LIMIT_PER_HOUR)?;
 
-        // Check allowlist
-        if !config.admin_numbers.contains(&phone) {
+        // Check allowlist (admins and verified contributors)
+        if !may_sign_in(ctx, &phone).await {
             return Ok(SendOtpResult { success: false });
         }
 
@@ -131,8 +506,8 @@ impl MutationRoot {
         // Rate limit
         rate_limit_check(ctx, AUTH_RATE_LIMIT_PER_HOUR)?;
 
-        // Check allowlist
-        if !config.admin_numbers.contains(&phone) {
+        // Check allowlist (admins and verified contributors)
+        if !may_sign_in(ctx, &phone).await {
             return Ok(VerifyOtpResult { success: false });
         }
 
@@ -173,6 +548,140 @@ impl MutationRoot {
         Ok(LogoutResult { success: true })
     }
 
+    // ========== Read state (anonymous token) ==========
+
+    /// Record that this visitor has caught up in `region` as of `asOf` (from
+    /// `newSince`; defaults to now). The watermark never moves backwards.
+    async fn mark_read(
+        &self,
+        ctx: &Context<'_>,
+        token: String,
+        region: String,
+        as_of: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<ScoutResult> {
+        let key = read_token_key(&token)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let now = chrono::Utc::now();
+        let seen_at = as_of.map_or(now, |t| t.min(now));
+        rootsignal_graph::read_state::mark_read(client, &key, &rootsignal_common::slugify(&region), seen_at).await?;
+        Ok(ScoutResult { success: true, message: None })
+    }
+
+    // ========== Member mutations (signed in) ==========
+
+    /// Create or replace one of your saved searches. New signals matching it
+    /// show up in `myWatchlist` and are sent to its notify channels.
+    async fn save_search(&self, ctx: &Context<'_>, input: SavedSearchInput) -> Result<GqlSavedSearch> {
+        let owner = member_id(ctx)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let existing = rootsignal_graph::watchlist::saved_searches(client, Some(&owner)).await?;
+
+        let now = chrono::Utc::now();
+        let (id, created_at, last_seen_at) = match input.id {
+            Some(id) => {
+                let prior = existing
+                    .iter()
+                    .find(|s| s.id == id)
+                    .ok_or_else(|| async_graphql::Error::new(format!("No saved search {id}")))?;
+                (id, prior.created_at, prior.last_seen_at)
+            }
+            None if existing.len() >= rootsignal_common::watchlist::MAX_SAVED_SEARCHES => {
+                return Err(async_graphql::Error::new(format!(
+                    "At most {} saved searches",
+                    rootsignal_common::watchlist::MAX_SAVED_SEARCHES
+                )));
+            }
+            None => (Uuid::new_v4(), now, now),
+        };
+
+        let clean = |values: Vec<String>| -> Vec<String> {
+            values.into_iter().map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect()
+        };
+        let search = SavedSearch {
+            id,
+            owner,
+            name: input.name.trim().to_string(),
+            bbox: input.bbox.map(|b| BoundingBox {
+                min_lat: b.min_lat,
+                max_lat: b.max_lat,
+                min_lng: b.min_lng,
+                max_lng: b.max_lng,
+            }),
+            types: input.types.into_iter().map(SignalType::to_node_type).collect(),
+            categories: clean(input.categories),
+            keywords: clean(input.keywords),
+            notify: input
+                .notify
+                .into_iter()
+                .map(|c| c.channel.to_channel(c.target.trim().to_string()))
+                .collect(),
+            created_at,
+            last_seen_at,
+        };
+        search.validate().map_err(async_graphql::Error::new)?;
+
+        rootsignal_graph::watchlist::save_search(client, &search)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save search: {e}")))?;
+        info!(search = %search.id, "Saved search stored");
+        Ok(search.into())
+    }
+
+    /// Delete one of your saved searches.
+    async fn delete_saved_search(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let owner = member_id(ctx)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let deleted = rootsignal_graph::watchlist::delete_search(client, &owner, id).await?;
+        Ok(ScoutResult {
+            success: deleted,
+            message: (!deleted).then(|| format!("No saved search {id}")),
+        })
+    }
+
+    /// Mark your watchlist as seen, clearing its new matches. With `searchId`,
+    /// only that search.
+    async fn mark_watchlist_seen(&self, ctx: &Context<'_>, search_id: Option<Uuid>) -> Result<ScoutResult> {
+        let owner = member_id(ctx)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        rootsignal_graph::watchlist::mark_seen(client, &owner, search_id, chrono::Utc::now()).await?;
+        Ok(ScoutResult { success: true, message: None })
+    }
+
+    /// Annotate a signal with local context (verified contributors only).
+    /// The note is held for moderation and shown once approved.
+    async fn annotate_signal(&self, ctx: &Context<'_>, signal_id: Uuid, body: String) -> Result<GqlAnnotation> {
+        let member = member_id(ctx)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let contributor = rootsignal_graph::annotations::contributor(client, &member)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new("Only verified contributors can annotate signals"))?;
+        let body = rootsignal_common::annotations::check_annotation_body(&body).map_err(async_graphql::Error::new)?;
+
+        let pending = rootsignal_graph::annotations::pending_count(client, &member).await?;
+        if pending >= rootsignal_common::annotations::MAX_PENDING_PER_CONTRIBUTOR {
+            return Err(async_graphql::Error::new(format!(
+                "You have {pending} annotations awaiting review; try again once they're reviewed"
+            )));
+        }
+
+        let annotation = Annotation {
+            id: Uuid::new_v4(),
+            signal_id,
+            author_id: contributor.member_id,
+            author_name: contributor.display_name,
+            body,
+            status: AnnotationStatus::Pending,
+            created_at: chrono::Utc::now(),
+            reviewed_at: None,
+            review_note: None,
+        };
+        if !rootsignal_graph::annotations::add_annotation(client, &annotation).await? {
+            return Err(async_graphql::Error::new(format!("No signal {signal_id}")));
+        }
+        info!(annotation = %annotation.id, signal = %signal_id, "Annotation queued for review");
+        Ok(annotation.into())
+    }
+
     // ========== Admin mutations (AdminGuard) ==========
 
     /// Add a source.
@@ -220,6 +729,7 @@ impl MutationRoot {
             quality_penalty: 1.0,
             source_role: SourceRole::default(),
             scrape_count: 0,
+            trial: TrialState::Probation,
         };
 
         writer
@@ -235,6 +745,60 @@ impl MutationRoot {
         })
     }
 
+    /// Track a newsletter by its sender address (admin only). Subscribe the
+    /// `adminNewsletterAddress` to the newsletter first; issues from this
+    /// sender are then extracted on each scout run.
+    #[graphql(guard = "AdminGuard")]
+    async fn add_newsletter_source(
+        &self,
+        ctx: &Context<'_>,
+        sender: String,
+        reason: Option<String>,
+    ) -> Result<AddSourceResult> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let sender = sender.trim();
+        if sender.len() > 320 || sender.contains(char::is_whitespace) || !sender.contains('@') {
+            return Err("Sender must be an email address".into());
+        }
+
+        let cv = rootsignal_common::newsletter_source_value(sender);
+        let source_id = Uuid::new_v4();
+        let source = SourceNode {
+            id: source_id,
+            canonical_key: cv.clone(),
+            canonical_value: cv.clone(),
+            url: None,
+            discovery_method: DiscoveryMethod::HumanSubmission,
+            created_at: chrono::Utc::now(),
+            last_scraped: None,
+            last_produced_signal: None,
+            signals_produced: 0,
+            signals_corroborated: 0,
+            consecutive_empty_runs: 0,
+            active: true,
+            gap_context: reason.as_ref().map(|r| format!("Admin: {r}")),
+            weight: 0.5,
+            cadence_hours: None,
+            avg_signals_per_scrape: 0.0,
+            quality_penalty: 1.0,
+            source_role: SourceRole::default(),
+            scrape_count: 0,
+            trial: TrialState::Probation,
+        };
+
+        writer
+            .upsert_source(&source)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to create source: {e}")))?;
+
+        info!(source = cv.as_str(), "Newsletter source added by admin");
+
+        Ok(AddSourceResult {
+            success: true,
+            source_id: Some(source_id.to_string()),
+        })
+    }
+
     /// Run scout for a task. Loads task by ID, derives scope, dispatches via Restate.
     #[graphql(guard = "AdminGuard")]
     async fn run_scout(&self, ctx: &Context<'_>, task_id: String) -> Result<ScoutResult> {
@@ -261,28 +825,29 @@ impl MutationRoot {
             .map_err(|e| async_graphql::Error::new(format!("Failed to load task: {e}")))?
             .ok_or_else(|| async_graphql::Error::new(format!("Scout task {task_id} not found")))?;
 
-        // Concurrency guard: reject if another task for the same region is already running
+        // Concurrency guard: a run already in flight for this region is the one
+        // the caller gets — starting another would double-spend budget.
         let running = writer
-            .is_region_task_running(&task.context)
+            .running_region_task_id(&task.context)
             .await
             .map_err(|e| async_graphql::Error::new(format!("Failed to check running status: {e}")))?;
-        if running {
+        if let Some(running_id) = running {
             return Ok(ScoutResult {
-                success: false,
-                message: Some("Another task for this region is already running".to_string()),
+                success: true,
+                message: Some(format!("Already running, attached to existing run {running_id}")),
             });
         }
 
         let scope = ScoutScope::from(&task);
 
-        restate
+        let dispatch = restate
             .run_scout(&task_id, &scope)
             .await
             .map_err(|e| async_graphql::Error::new(e.to_string()))?;
 
         Ok(ScoutResult {
             success: true,
-            message: Some(format!("Scout started via Restate for {}", task.context)),
+            message: Some(dispatch_message(&dispatch, &format!("Scout started via Restate for {}", task.context))),
         })
     }
 
@@ -331,14 +896,14 @@ impl MutationRoot {
 
         let scope = ScoutScope::from(&task);
 
-        restate
+        let dispatch = restate
             .run_phase(restate_phase, &task_id, &scope)
             .await
             .map_err(|e| async_graphql::Error::new(e.to_string()))?;
 
         Ok(ScoutResult {
             success: true,
-            message: Some(format!("{:?} started via Restate for {}", phase, task.context)),
+            message: Some(dispatch_message(&dispatch, &format!("{:?} started via Restate for {}", phase, task.context))),
         })
     }
 
@@ -431,6 +996,7 @@ impl MutationRoot {
             quality_penalty: 1.0,
             source_role: SourceRole::default(),
             scrape_count: 0,
+            trial: TrialState::Probation,
         };
 
         store
@@ -523,6 +1089,8 @@ impl MutationRoot {
     }
 
     /// Create a new scout task (manual demand signal). Geocodes the location server-side.
+    /// With `use_boundary`, an area (a county, a city) is scoped to its boundary
+    /// rather than a radius, and its geo terms name the places inside it.
     #[graphql(guard = "AdminGuard")]
     async fn create_scout_task(
         &self,
@@ -530,33 +1098,10 @@ impl MutationRoot {
         location: String,
         radius_km: Option<f64>,
         priority: Option<f64>,
+        use_boundary: Option<bool>,
     ) -> Result<String> {
-        let (lat, lng, display_name) = geocode_location(&location)
-            .await
-            .map_err(|e| async_graphql::Error::new(format!("Geocoding failed: {e}")))?;
-
-        // Extract geo_terms from the display_name (comma-separated parts)
-        let geo_terms: Vec<String> = display_name
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-
         let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
-        let task = rootsignal_common::ScoutTask {
-            id: Uuid::new_v4(),
-            center_lat: lat,
-            center_lng: lng,
-            radius_km: radius_km.unwrap_or(30.0),
-            context: display_name,
-            geo_terms,
-            priority: priority.unwrap_or(1.0),
-            source: rootsignal_common::ScoutTaskSource::Manual,
-            status: rootsignal_common::ScoutTaskStatus::Pending,
-            phase_status: "idle".to_string(),
-            created_at: chrono::Utc::now(),
-            completed_at: None,
-        };
+        let task = geocoded_scout_task(&location, radius_km, priority, use_boundary.unwrap_or(false)).await?;
         let id = task.id.to_string();
         writer
             .upsert_scout_task(&task)
@@ -565,6 +1110,72 @@ impl MutationRoot {
         Ok(id)
     }
 
+    /// Create a region and start onboarding it (admin only). Geocodes the
+    /// location, creates the region's scout task and runs the onboarding
+    /// workflow: bootstrap, a paused daily schedule in `timezone` and the
+    /// synthesis budget, then a dry run. The region goes active once its
+    /// readiness checklist passes; see `adminRegionOnboardings`.
+    #[graphql(guard = "AdminGuard")]
+    async fn create_region(
+        &self,
+        ctx: &Context<'_>,
+        location: String,
+        radius_km: Option<f64>,
+        use_boundary: Option<bool>,
+        timezone: String,
+    ) -> Result<ScoutResult> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let restate = require_restate(ctx)?;
+        rootsignal_common::preview_schedule(DEFAULT_SCHEDULE_CRON, &timezone, chrono::Utc::now(), 1)
+            .map_err(async_graphql::Error::new)?;
+
+        let task = geocoded_scout_task(&location, radius_km, None, use_boundary.unwrap_or(false)).await?;
+        let slug = rootsignal_common::slugify(&task.context);
+        let exists = rootsignal_graph::onboarding::onboarding(client, &slug).await?.is_some()
+            || writer.get_region_schedule(&slug).await?.is_some();
+        if exists {
+            return Err(async_graphql::Error::new(format!("Region {} already exists", task.context)));
+        }
+
+        let task_id = task.id.to_string();
+        writer
+            .upsert_scout_task(&task)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to create scout task: {e}")))?;
+        let onboarding = RegionOnboarding::new(&slug, &task.context, &task_id, &timezone);
+        rootsignal_graph::onboarding::save_onboarding(client, &onboarding).await?;
+
+        let dispatch = restate
+            .run_onboarding(&task_id, &ScoutScope::from(&task), &timezone)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to dispatch onboarding: {e}")))?;
+        Ok(ScoutResult {
+            success: true,
+            message: Some(dispatch_message(
+                &dispatch,
+                &format!("Onboarding dispatched via Restate for {}", task.context),
+            )),
+        })
+    }
+
+    /// Re-run a region's readiness checklist, e.g. after reviewing its
+    /// bootstrap sources, activating it if it now passes (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn recheck_region_readiness(&self, ctx: &Context<'_>, region: String) -> Result<AdminRegionOnboarding> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let graph = region_graph(ctx, &region).await?;
+        let onboarding = rootsignal_scout::workflows::onboarding::recheck_readiness(
+            client,
+            &graph,
+            &rootsignal_common::slugify(&region),
+        )
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to check readiness: {e}")))?
+        .ok_or_else(|| async_graphql::Error::new(format!("Region {region} wasn't onboarded with createRegion")))?;
+        Ok(onboarding.into())
+    }
+
     /// Cancel a scout task.
     #[graphql(guard = "AdminGuard")]
     async fn cancel_scout_task(&self, ctx: &Context<'_>, id: String) -> Result<bool> {
@@ -621,6 +1232,99 @@ impl MutationRoot {
         Ok(true)
     }
 
+    /// Report a problem with a signal (public, rate-limited). `details` is
+    /// seen only by admins; `duplicateOf` is required for duplicates.
+    async fn request_correction(
+        &self,
+        ctx: &Context<'_>,
+        signal_id: Uuid,
+        kind: GqlCorrectionKind,
+        details: Option<String>,
+        duplicate_of: Option<Uuid>,
+    ) -> Result<GqlCorrection> {
+        rate_limit_check(ctx, CORRECTION_RATE_LIMIT_PER_HOUR)?;
+
+        let kind = kind.into();
+        let details = rootsignal_common::corrections::check_correction(kind, details.as_deref(), duplicate_of)
+            .map_err(async_graphql::Error::new)?;
+        if duplicate_of == Some(signal_id) {
+            return Err("A signal can't duplicate itself".into());
+        }
+
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let correction = CorrectionRequest {
+            id: Uuid::new_v4(),
+            signal_id,
+            kind,
+            details,
+            duplicate_of,
+            status: CorrectionStatus::Open,
+            created_at: chrono::Utc::now(),
+            resolved_at: None,
+            resolution_note: None,
+        };
+        let stored = rootsignal_graph::corrections::submit_correction(client, &correction)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to file correction: {e}")))?;
+        if !stored {
+            return Err(async_graphql::Error::new(format!("No signal {signal_id}")));
+        }
+
+        info!(signal = %signal_id, kind = kind.as_str(), "Correction requested");
+        Ok(correction.into())
+    }
+
+    /// Email `email` when a situation escalates or goes cold (public,
+    /// rate-limited). Nothing but a confirmation email is sent until its
+    /// link is followed; every later email carries an unsubscribe link.
+    async fn subscribe_to_situation(
+        &self,
+        ctx: &Context<'_>,
+        situation_id: Uuid,
+        email: String,
+    ) -> Result<ScoutResult> {
+        rate_limit_check(ctx, SUBSCRIBE_RATE_LIMIT_PER_HOUR)?;
+
+        let email = email.trim().to_lowercase();
+        NotifyChannel::Email(email.clone())
+            .validate()
+            .map_err(async_graphql::Error::new)?;
+
+        let Some(notifier) = ctx.data_unchecked::<Arc<rootsignal_graph::CacheStore>>().situation_notifier() else {
+            return Err(async_graphql::Error::new("Situation emails aren't configured"));
+        };
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let subscription = SituationSubscription::pending(situation_id, email);
+        let outcome = rootsignal_graph::situation_feed::subscribe(client, &subscription)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to subscribe: {e}")))?;
+
+        use rootsignal_graph::situation_feed::SubscribeOutcome;
+        let (success, message) = match outcome {
+            SubscribeOutcome::Pending { subscription, headline } => {
+                notifier
+                    .confirm(&headline, &subscription)
+                    .await
+                    .map_err(|e| async_graphql::Error::new(format!("Failed to send confirmation: {e}")))?;
+                info!(situation = %situation_id, "Situation subscription awaiting confirmation");
+                (true, "Confirmation sent".to_string())
+            }
+            SubscribeOutcome::AlreadySubscribed => (true, "Already subscribed".to_string()),
+            SubscribeOutcome::NoSuchSituation => (false, format!("No situation {situation_id}")),
+            SubscribeOutcome::TooMany => (
+                false,
+                format!(
+                    "This address already follows {} situations",
+                    rootsignal_common::situation_feed::MAX_SUBSCRIPTIONS_PER_EMAIL
+                ),
+            ),
+        };
+        Ok(ScoutResult {
+            success,
+            message: Some(message),
+        })
+    }
+
     /// Manually trigger a news scan (admin only).
     #[graphql(guard = "AdminGuard")]
     async fn run_news_scan(&self, ctx: &Context<'_>) -> Result<ScoutResult> {
@@ -634,49 +1338,1466 @@ impl MutationRoot {
         }
 
         let restate = require_restate(ctx)?;
-        restate
+        let dispatch = restate
             .run_news_scan()
             .await
             .map_err(|e| async_graphql::Error::new(format!("Failed to dispatch news scan: {e}")))?;
 
         Ok(ScoutResult {
             success: true,
-            message: Some("News scan dispatched via Restate".into()),
+            message: Some(dispatch_message(&dispatch, "News scan dispatched via Restate")),
         })
     }
 
-}
+    /// Re-verify aging signals for a task's region against their source pages (admin only).
+    /// `repeat_every_hours` keeps the sweep running on that interval.
+    #[graphql(guard = "AdminGuard")]
+    async fn run_verification(
+        &self,
+        ctx: &Context<'_>,
+        task_id: String,
+        repeat_every_hours: Option<u32>,
+    ) -> Result<ScoutResult> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let restate = require_restate(ctx)?;
 
-fn rate_limit_check(ctx: &Context<'_>, max_per_hour: usize) -> Result<()> {
-    let client_ip = ctx.data_unchecked::<ClientIp>();
-    let limiter = ctx.data_unchecked::<RateLimiter>();
+        let task = writer
+            .get_scout_task(&task_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load task: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("Scout task {task_id} not found")))?;
+        let scope = ScoutScope::from(&task);
 
-    // We need to block on the mutex - use try_lock to avoid async issues
-    // In practice, contention is very low
-    let mut guard = limiter
-        .0
-        .try_lock()
-        .map_err(|_| async_graphql::Error::new("Rate limiter busy, try again"))?;
+        let dispatch = restate
+            .run_verification(&scope, repeat_every_hours)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to dispatch verification: {e}")))?;
 
-    let entries = guard.entry(client_ip.0).or_default();
-    if !check_rate_limit_window(entries, Instant::now(), max_per_hour) {
-        return Err(async_graphql::Error::new("Rate limit exceeded"));
+        Ok(ScoutResult {
+            success: true,
+            message: Some(dispatch_message(
+                &dispatch,
+                &format!("Verification sweep dispatched via Restate for {}", task.context),
+            )),
+        })
     }
 
-    Ok(())
-}
+    /// Set the scheduling calendar for a region (admin only). Replaces any
+    /// existing calendar; the scheduler and supervisor pick it up on their next run.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_calendar(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        calendar: RegionCalendarInput,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let calendar = RegionCalendar::from(calendar);
+        calendar.validate().map_err(async_graphql::Error::new)?;
 
-fn check_rate_limit_window(entries: &mut Vec<Instant>, now: Instant, max_per_hour: usize) -> bool {
-    let cutoff = now - std::time::Duration::from_secs(3600);
-    entries.retain(|t| *t > cutoff);
-    if entries.len() >= max_per_hour {
-        return false;
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_calendar(&slug, &calendar)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save calendar: {e}")))?;
+
+        let message = match calendar.blocked_at(chrono::Utc::now()) {
+            Some(block) => format!("Calendar saved for {region}; scouting currently blocked ({block})"),
+            None => format!("Calendar saved for {region}"),
+        };
+        Ok(ScoutResult {
+            success: true,
+            message: Some(message),
+        })
     }
-    entries.push(now);
-    true
-}
 
-/// Extract the Restate client from GraphQL context, returning a clear error if not configured.
+    /// Schedule recurring scout runs for a task's region (admin only).
+    /// `cron` is a five-field expression read in `timezone`. Replaces any
+    /// existing schedule for the region and keeps its paused state.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_schedule(
+        &self,
+        ctx: &Context<'_>,
+        task_id: String,
+        cron: String,
+        timezone: String,
+    ) -> Result<ScoutResult> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let task = writer
+            .get_scout_task(&task_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load task: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("Scout task {task_id} not found")))?;
+        let slug = rootsignal_common::slugify(&task.context);
+        let paused = writer
+            .get_region_schedule(&slug)
+            .await?
+            .is_some_and(|existing| existing.paused);
+
+        let schedule = RegionSchedule {
+            task_id,
+            cron: cron.trim().to_string(),
+            timezone,
+            paused,
+            last_run_at: None,
+            updated_at: chrono::Utc::now(),
+        };
+        schedule.validate().map_err(async_graphql::Error::new)?;
+        writer
+            .set_region_schedule(&slug, &schedule)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save schedule: {e}")))?;
+
+        let message = match schedule.next_runs(chrono::Utc::now(), 1).first() {
+            Some(next) if !paused => format!("Schedule saved for {}; next run {next}", task.context),
+            _ => format!("Schedule saved for {} (paused)", task.context),
+        };
+        Ok(ScoutResult {
+            success: true,
+            message: Some(message),
+        })
+    }
+
+    /// Pause a region's scheduled runs and its supervisor (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn pause_region_schedule(&self, ctx: &Context<'_>, region: String) -> Result<ScoutResult> {
+        set_schedule_paused(ctx, &region, true).await
+    }
+
+    /// Resume a region's scheduled runs (admin only). Runs missed while
+    /// paused are not made up.
+    #[graphql(guard = "AdminGuard")]
+    async fn resume_region_schedule(&self, ctx: &Context<'_>, region: String) -> Result<ScoutResult> {
+        set_schedule_paused(ctx, &region, false).await
+    }
+
+    /// Set the story energy weights for a region (admin only). Takes effect
+    /// on the region's next supervisor run, or immediately via `backfillStoryEnergy`.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_energy_weights(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        weights: StoryEnergyWeightsInput,
+    ) -> Result<ScoutResult> {
+        let client = &region_graph(ctx, &region).await?;
+        let weights = StoryEnergyWeights::from(weights);
+        weights.validate().map_err(async_graphql::Error::new)?;
+
+        rootsignal_graph::story_energy::set_region_energy_weights(
+            client,
+            &rootsignal_common::slugify(&region),
+            &weights,
+        )
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to save energy weights: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Energy weights saved for {region}")),
+        })
+    }
+
+    /// Recompute story energy now (admin only). With a task, only that
+    /// task's region is recomputed, using the region's weights; without one,
+    /// every story is recomputed with the default weights.
+    #[graphql(guard = "AdminGuard")]
+    async fn backfill_story_energy(&self, ctx: &Context<'_>, task_id: Option<String>) -> Result<ScoutResult> {
+        let router = ctx.data_unchecked::<GraphRouter>();
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+
+        let (targets, label) = match task_id {
+            Some(task_id) => {
+                let task = writer
+                    .get_scout_task(&task_id)
+                    .await
+                    .map_err(|e| async_graphql::Error::new(format!("Failed to load task: {e}")))?
+                    .ok_or_else(|| async_graphql::Error::new(format!("Scout task {task_id} not found")))?;
+                let scope = ScoutScope::from(&task);
+                let client = region_graph(ctx, &scope.name).await?;
+                let weights = rootsignal_graph::story_energy::region_energy_weights(
+                    &client,
+                    &rootsignal_common::slugify(&scope.name),
+                )
+                .await?
+                .unwrap_or_default();
+                let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+                let bbox = BoundingBox { min_lat, max_lat, min_lng, max_lng };
+                (vec![(client, weights, Some(bbox))], scope.name)
+            }
+            None => {
+                let clients = router
+                    .clients()
+                    .await
+                    .map_err(|e| async_graphql::Error::new(format!("Failed to connect to region databases: {e}")))?;
+                let targets = clients.into_iter().map(|c| (c, StoryEnergyWeights::default(), None)).collect();
+                (targets, "all regions".to_string())
+            }
+        };
+
+        let mut stories = 0;
+        for (client, weights, bbox) in &targets {
+            stories += rootsignal_graph::story_energy::recompute_story_energy(client, weights, bbox.as_ref())
+                .await
+                .map_err(|e| async_graphql::Error::new(format!("Failed to recompute story energy: {e}")))?;
+        }
+
+        info!(stories, region = label.as_str(), "Story energy backfilled");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Recomputed energy for {stories} stories in {label}")),
+        })
+    }
+
+    /// Set the smallest group of signals a region's published counts and
+    /// trends may describe (admin only). Smaller groups are folded or withheld.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_aggregate_privacy(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        min_group_size: u32,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let guard = AggregateGuard { min_group_size };
+        guard.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_aggregate_guard(&slug, &guard)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save aggregate privacy: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Aggregates for {region} now need at least {min_group_size} signals")),
+        })
+    }
+
+    /// Set the open-data (311) datasets a region ingests (admin only).
+    /// Replaces the existing list; an empty list turns ingestion off.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_open_data(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        datasets: Vec<OpenDataDatasetInput>,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let open_data = RegionOpenData {
+            datasets: datasets.into_iter().map(OpenDataDataset::from).collect(),
+        };
+        open_data.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_open_data(&slug, &open_data)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save open-data datasets: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "{} open-data dataset(s) saved for {region}",
+                open_data.datasets.len()
+            )),
+        })
+    }
+
+    /// Set the meeting bodies whose agendas a region follows (admin only).
+    /// Replaces the existing list; omitting `high_impact_topics` keeps the
+    /// defaults (budget, zoning, ...).
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_agendas(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        bodies: Vec<AgendaBodyInput>,
+        high_impact_topics: Option<Vec<String>>,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let mut agendas = RegionAgendas {
+            bodies: bodies.into_iter().map(AgendaBody::from).collect(),
+            ..Default::default()
+        };
+        if let Some(topics) = high_impact_topics {
+            agendas.high_impact_topics = topics;
+        }
+        agendas.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_agendas(&slug, &agendas)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save agendas: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} meeting body(ies) saved for {region}", agendas.bodies.len())),
+        })
+    }
+
+    /// Set the transit agencies and school districts whose alerts a region
+    /// follows (admin only). Replaces the existing lists.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_service_alerts(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        #[graphql(default)] transit_agencies: Vec<TransitAgencyInput>,
+        #[graphql(default)] school_districts: Vec<SchoolDistrictInput>,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let alerts = RegionServiceAlerts {
+            transit_agencies: transit_agencies.into_iter().map(TransitAgency::from).collect(),
+            school_districts: school_districts.into_iter().map(SchoolDistrict::from).collect(),
+        };
+        alerts.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_service_alerts(&slug, &alerts)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save service alerts: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "{} transit agency(ies) and {} school district(s) saved for {region}",
+                alerts.transit_agencies.len(),
+                alerts.school_districts.len()
+            )),
+        })
+    }
+
+    /// Set the census counties whose ACS tract estimates give a region's
+    /// situations demographic context (admin only). `acs_year` defaults to the
+    /// latest 5-year release.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_demographics(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        state_fips: String,
+        county_fips: Vec<String>,
+        acs_year: Option<u16>,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let demographics = rootsignal_common::RegionDemographics {
+            state_fips,
+            county_fips,
+            acs_year: acs_year.unwrap_or_else(rootsignal_common::demographics::default_acs_year),
+        };
+        demographics.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_demographics(&slug, &demographics)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save demographics: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "ACS {} data for {} county(ies) saved for {region}",
+                demographics.acs_year,
+                demographics.county_fips.len()
+            )),
+        })
+    }
+
+    /// Set the schools, routes, wards and parks a region's signals are linked
+    /// to (admin only). Replaces the existing registry; links to entities
+    /// still listed are kept.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_region_registry(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        entities: Vec<RegistryEntityInput>,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        let registry = RegionRegistry {
+            entities: entities
+                .into_iter()
+                .map(RegistryEntity::try_from)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(async_graphql::Error::new)?,
+        };
+        registry.validate().map_err(async_graphql::Error::new)?;
+
+        let slug = rootsignal_common::slugify(&region);
+        writer
+            .set_region_registry(&slug, &registry)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save registry: {e}")))?;
+        if let Err(e) = writer.link_located_in(&slug, &registry).await {
+            warn!(error = %e, region = slug.as_str(), "Failed to link existing signals to registry boundaries");
+        }
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} registry entity(ies) saved for {region}", registry.entities.len())),
+        })
+    }
+
+    /// Import ward or district boundaries from a GeoJSON FeatureCollection
+    /// (admin only), naming each by `name_property`. Replaces the region's
+    /// existing boundaries of that kind and tags existing signals and
+    /// situations with the districts they fall in.
+    #[graphql(guard = "AdminGuard")]
+    async fn import_region_districts(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        #[graphql(default_with = "GqlRegistryKind::Ward")] kind: GqlRegistryKind,
+        geojson: String,
+        name_property: String,
+    ) -> Result<ScoutResult> {
+        let writer = region_writer(ctx, &region).await?;
+        if !matches!(kind, GqlRegistryKind::Ward | GqlRegistryKind::District) {
+            return Err(async_graphql::Error::new("Only wards and districts can be imported"));
+        }
+        let districts = rootsignal_common::districts_from_geojson(&geojson, kind.into(), &name_property)
+            .map_err(async_graphql::Error::new)?;
+        let count = districts.len();
+
+        let slug = rootsignal_common::slugify(&region);
+        let mut registry = writer
+            .get_region_registry(&slug)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load registry: {e}")))?;
+        registry.replace_kind(kind.into(), districts);
+        registry.validate().map_err(async_graphql::Error::new)?;
+
+        writer
+            .set_region_registry(&slug, &registry)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save registry: {e}")))?;
+        let linked = writer
+            .link_located_in(&slug, &registry)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to tag signals with districts: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "{count} {} boundary(ies) imported for {region}; {linked} signal(s) and situation(s) tagged",
+                rootsignal_common::RegistryKind::from(kind)
+            )),
+        })
+    }
+
+    /// Create or update a topic category (admin only). The slug follows
+    /// `name` for new categories; use `renameTopicCategory` to rename one.
+    #[graphql(guard = "AdminGuard")]
+    async fn upsert_topic_category(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        parent: Option<String>,
+        #[graphql(default)] aliases: Vec<String>,
+    ) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let mut taxonomy = load_taxonomy(client).await?;
+        let category = TopicCategory {
+            aliases,
+            ..TopicCategory::new(&name, parent.as_deref(), &[])
+        };
+        taxonomy.categories.retain(|c| c.slug != category.slug);
+        taxonomy.categories.push(category.clone());
+        taxonomy.validate().map_err(async_graphql::Error::new)?;
+
+        rootsignal_graph::taxonomy::upsert_category(client, &category)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save category: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Category {} saved", category.slug)),
+        })
+    }
+
+    /// Rename a topic category (admin only), re-tagging everything filed
+    /// under it. The old name keeps mapping to it as an alias.
+    #[graphql(guard = "AdminGuard")]
+    async fn rename_topic_category(&self, ctx: &Context<'_>, slug: String, name: String) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let taxonomy = load_taxonomy(client).await?;
+        let category = taxonomy
+            .get(&slug)
+            .ok_or_else(|| async_graphql::Error::new(format!("Unknown category: {slug}")))?;
+        let new_slug = rootsignal_common::slugify(&name);
+        if new_slug.is_empty() {
+            return Err(async_graphql::Error::new("Category name is empty"));
+        }
+        if new_slug != slug && taxonomy.get(&new_slug).is_some() {
+            return Err(async_graphql::Error::new(format!(
+                "Category {new_slug} already exists; merge into it instead"
+            )));
+        }
+
+        let retagged = rootsignal_graph::taxonomy::rename_category(client, category, &name)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to rename category: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Category {slug} renamed to {new_slug}; {retagged} item(s) re-tagged")),
+        })
+    }
+
+    /// Merge a category — or a free-text label no category matched — into
+    /// another category (admin only). Everything tagged `from` is re-tagged,
+    /// and `from` maps to `into` from then on.
+    #[graphql(guard = "AdminGuard")]
+    async fn merge_topic_category(&self, ctx: &Context<'_>, from: String, into: String) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let taxonomy = load_taxonomy(client).await?;
+        let target = taxonomy
+            .get(&into)
+            .ok_or_else(|| async_graphql::Error::new(format!("Unknown category: {into}")))?;
+        if from == into {
+            return Err(async_graphql::Error::new("Cannot merge a category into itself"));
+        }
+        if taxonomy.path(&into).iter().any(|c| c.slug == from) {
+            return Err(async_graphql::Error::new(format!("{into} is under {from}; merge the other way")));
+        }
+
+        let retagged = rootsignal_graph::taxonomy::merge_category(client, &taxonomy, &from, target)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to merge category: {e}")))?;
+
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{from} merged into {into}; {retagged} item(s) re-tagged")),
+        })
+    }
+
+    /// Verify a member as a contributor by phone number, or update their
+    /// display name (admin only). Contributors can sign in and annotate.
+    #[graphql(guard = "AdminGuard")]
+    async fn verify_contributor(&self, ctx: &Context<'_>, phone: String, display_name: String) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let display_name = display_name.trim().to_string();
+        if display_name.is_empty() {
+            return Err(async_graphql::Error::new("Contributor needs a display name"));
+        }
+        let contributor = Contributor {
+            member_id: jwt::phone_to_uuid(phone.trim()).to_string(),
+            display_name,
+            reputation: rootsignal_common::annotations::INITIAL_REPUTATION,
+            verified_at: chrono::Utc::now(),
+        };
+        rootsignal_graph::annotations::upsert_contributor(client, &contributor)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to verify contributor: {e}")))?;
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} verified", contributor.display_name)),
+        })
+    }
+
+    /// Revoke a contributor (admin only). Their pending annotations are rejected.
+    #[graphql(guard = "AdminGuard")]
+    async fn revoke_contributor(&self, ctx: &Context<'_>, phone: String) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let member = jwt::phone_to_uuid(phone.trim()).to_string();
+        let revoked = rootsignal_graph::annotations::revoke_contributor(client, &member).await?;
+        Ok(ScoutResult {
+            success: revoked,
+            message: (!revoked).then(|| "Not a contributor".to_string()),
+        })
+    }
+
+    /// Approve or reject a pending annotation (admin only). `note` is shown
+    /// to the author.
+    #[graphql(guard = "AdminGuard")]
+    async fn review_annotation(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        approve: bool,
+        note: Option<String>,
+    ) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        let outcome = rootsignal_graph::annotations::review_annotation(client, id, approve, note.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to review annotation: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("No pending annotation {id}")))?;
+
+        let mut message = format!("Annotation {}", outcome.annotation.status.as_str());
+        if outcome.reverification_requested {
+            message.push_str("; signal queued for re-verification");
+        }
+        Ok(ScoutResult {
+            success: true,
+            message: Some(message),
+        })
+    }
+
+    /// Approve or reject a region's pending bootstrap sources (admin only):
+    /// those in `canonical_keys`, or all of them when omitted. Approved
+    /// sources become active.
+    #[graphql(guard = "AdminGuard")]
+    async fn review_bootstrap_candidates(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        canonical_keys: Option<Vec<String>>,
+        approve: bool,
+    ) -> Result<ScoutResult> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let reviewed =
+            rootsignal_graph::bootstrap_review::review_candidates(&graph, &slug, canonical_keys.as_deref(), approve)
+                .await
+                .map_err(|e| async_graphql::Error::new(format!("Failed to review bootstrap sources: {e}")))?;
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "{reviewed} source(s) {}",
+                if approve { "approved" } else { "rejected" }
+            )),
+        })
+    }
+
+    /// Request a one-time backfill of a source's history (admin only). The
+    /// region's next scout run with budget for it reads up to `max_items`
+    /// items no older than `max_age_days`; the signals it stores are marked
+    /// historical.
+    #[graphql(guard = "AdminGuard")]
+    async fn request_source_backfill(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        canonical_key: String,
+        max_items: Option<u32>,
+        max_age_days: Option<u32>,
+    ) -> Result<ScoutResult> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let default = BackfillDepth::default();
+        let depth = BackfillDepth::new(
+            max_items.unwrap_or(default.max_items),
+            max_age_days.unwrap_or(default.max_age_days),
+        );
+        let requested = rootsignal_graph::backfill::request_backfill(&graph, &slug, &canonical_key, depth)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to request backfill: {e}")))?;
+        Ok(ScoutResult {
+            success: requested,
+            message: Some(if requested {
+                format!("Backfill of up to {} items requested", depth.max_items)
+            } else {
+                "Source not found, or already backfilled".to_string()
+            }),
+        })
+    }
+
+    /// Accept or dismiss a correction request (admin only). `note` is shown
+    /// publicly with the status. Accepting a "not true" or "duplicate"
+    /// correction also registers the signal as negative knowledge, so the
+    /// scout doesn't extract it again.
+    #[graphql(guard = "AdminGuard")]
+    async fn resolve_correction(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        accept: bool,
+        note: Option<String>,
+    ) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        let resolved = rootsignal_graph::corrections::resolve_correction(client, id, accept, note.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to resolve correction: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("No open correction {id}")))?;
+        if accept {
+            register_corrected_signal(ctx, &resolved, note.as_deref()).await;
+        }
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Correction {}", resolved.status.as_str())),
+        })
+    }
+
+    /// Register content the scout should suppress instead of storing (admin
+    /// only). `kind` is `debunked`, `spam`, `duplicate` or `rejected`; without
+    /// `region` the entry applies everywhere. Registering the same text again
+    /// replaces its kind, reason and expiry.
+    #[graphql(guard = "AdminGuard")]
+    async fn add_negative_knowledge(
+        &self,
+        ctx: &Context<'_>,
+        kind: String,
+        text: String,
+        reason: String,
+        region: Option<String>,
+        expires_in_days: Option<u32>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let kind = NegativeKind::parse(&kind).ok_or_else(|| {
+            async_graphql::Error::new(format!("Unknown kind: {kind} (debunked, spam, duplicate, rejected)"))
+        })?;
+        let now = chrono::Utc::now();
+        let entry = NewNegativeEntry {
+            region: region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+            kind,
+            text,
+            reason: reason.trim().to_string(),
+            added_by: member_id(ctx)?,
+            expires_at: expires_in_days.map(|days| now + chrono::Duration::days(days as i64)),
+        };
+        entry.validate(now).map_err(async_graphql::Error::new)?;
+
+        let id = negative_knowledge::add(pool, &entry)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save negative knowledge: {e}")))?;
+        info!(id = %id, kind = kind.as_str(), region = ?entry.region, "Negative knowledge entry saved");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Negative knowledge entry {id} saved")),
+        })
+    }
+
+    /// Remove a negative knowledge entry; matching content is stored again
+    /// from the next run (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn remove_negative_knowledge(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let removed = negative_knowledge::remove(pool, id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to remove negative knowledge: {e}")))?;
+        if !removed {
+            return Err(async_graphql::Error::new(format!("No negative knowledge entry {id}")));
+        }
+        info!(id = %id, "Negative knowledge entry removed");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Negative knowledge entry {id} removed")),
+        })
+    }
+
+    /// Turn a scout feature flag on or off (admin only). Without `region`
+    /// the override applies everywhere a region override doesn't. Takes
+    /// effect from each region's next run.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_feature_flag(
+        &self,
+        ctx: &Context<'_>,
+        flag: String,
+        enabled: bool,
+        region: Option<String>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let flag = parse_flag(&flag)?;
+        let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        feature_flags::set_override(pool, flag, region.as_deref(), enabled, &member_id(ctx)?)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save feature flag: {e}")))?;
+        info!(flag = flag.as_str(), enabled, region = ?region, "Feature flag set");
+        let scope = region.as_deref().unwrap_or("all regions");
+        let state = if enabled { "enabled" } else { "disabled" };
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{flag} {state} for {scope}")),
+        })
+    }
+
+    /// Remove a feature flag override so the global value or the default
+    /// applies again (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn clear_feature_flag(
+        &self,
+        ctx: &Context<'_>,
+        flag: String,
+        region: Option<String>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let flag = parse_flag(&flag)?;
+        let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        let cleared = feature_flags::clear_override(pool, flag, region.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to clear feature flag: {e}")))?;
+        let scope = region.as_deref().unwrap_or("all regions");
+        if !cleared {
+            return Err(async_graphql::Error::new(format!("No {flag} override for {scope}")));
+        }
+        info!(flag = flag.as_str(), region = ?region, "Feature flag override cleared");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{flag} override for {scope} cleared")),
+        })
+    }
+
+    /// Pin a queued investigation to the front of the queue, or unpin it
+    /// (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn pin_investigation(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        signal_id: Uuid,
+        pinned: bool,
+    ) -> Result<ScoutResult> {
+        let client = region_graph(ctx, &region).await?;
+        let updated = rootsignal_graph::investigation_queue::set_pinned(&client, signal_id, pinned)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to update queue entry: {e}")))?;
+        if !updated {
+            return Err(async_graphql::Error::new(format!("No queued investigation for {signal_id}")));
+        }
+        info!(%signal_id, pinned, "Investigation queue entry pinned");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(if pinned { "Pinned" } else { "Unpinned" }.to_string()),
+        })
+    }
+
+    /// Skip a pending or blocked investigation, or return a skipped or
+    /// blocked one to pending with its attempts reset (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn skip_investigation(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        signal_id: Uuid,
+        skipped: bool,
+    ) -> Result<ScoutResult> {
+        let client = region_graph(ctx, &region).await?;
+        let updated = rootsignal_graph::investigation_queue::set_skipped(&client, signal_id, skipped)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to update queue entry: {e}")))?;
+        if !updated {
+            let expected = if skipped { "pending or blocked" } else { "skipped or blocked" };
+            return Err(async_graphql::Error::new(format!("No {expected} investigation for {signal_id}")));
+        }
+        info!(%signal_id, skipped, "Investigation queue entry updated");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(if skipped { "Skipped" } else { "Requeued" }.to_string()),
+        })
+    }
+
+    /// Set a synthesis phase's budget priority (admin only); 0 disables the
+    /// phase. Without `region` it applies everywhere a region policy doesn't
+    /// set it. Takes effect from each region's next synthesis.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_synthesis_phase_priority(
+        &self,
+        ctx: &Context<'_>,
+        phase: String,
+        priority: u32,
+        region: Option<String>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let phase = SynthesisPhase::parse(&phase).ok_or_else(|| {
+            let known: Vec<&str> = SynthesisPhase::ALL.iter().map(SynthesisPhase::as_str).collect();
+            async_graphql::Error::new(format!("Unknown phase: {phase} ({})", known.join(", ")))
+        })?;
+        let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        synthesis_budget::set_priority(pool, phase, region.as_deref(), priority, &member_id(ctx)?)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save phase priority: {e}")))?;
+        info!(phase = phase.as_str(), priority, region = ?region, "Synthesis phase priority set");
+        let scope = region.as_deref().unwrap_or("all regions");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{phase} priority {priority} for {scope}")),
+        })
+    }
+
+    /// Set how synthesis splits its budget, `rank` or `proportional`
+    /// (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn set_synthesis_allocation_mode(
+        &self,
+        ctx: &Context<'_>,
+        mode: String,
+        region: Option<String>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let mode = AllocationMode::parse(&mode)
+            .ok_or_else(|| async_graphql::Error::new(format!("Unknown mode: {mode} (rank, proportional)")))?;
+        let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        synthesis_budget::set_mode(pool, mode, region.as_deref(), &member_id(ctx)?)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save allocation mode: {e}")))?;
+        info!(mode = mode.as_str(), region = ?region, "Synthesis allocation mode set");
+        let scope = region.as_deref().unwrap_or("all regions");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} allocation for {scope}", mode.as_str())),
+        })
+    }
+
+    /// Remove a synthesis budget policy so the global policy or the
+    /// defaults apply again (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn clear_synthesis_budget_policy(&self, ctx: &Context<'_>, region: Option<String>) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        let cleared = synthesis_budget::clear_policy(pool, region.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to clear synthesis budget policy: {e}")))?;
+        let scope = region.as_deref().unwrap_or("all regions");
+        if !cleared {
+            return Err(async_graphql::Error::new(format!("No synthesis budget policy for {scope}")));
+        }
+        info!(region = ?region, "Synthesis budget policy cleared");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Synthesis budget policy for {scope} cleared")),
+        })
+    }
+
+    /// Register a candidate extraction prompt in shadow (admin only). The
+    /// scout runs it beside production on `sample_rate` of extracted content
+    /// and records both outputs without writing the candidate's to the graph.
+    /// Replaces any candidate already in shadow for the same scope.
+    #[graphql(guard = "AdminGuard")]
+    async fn register_extraction_prompt(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        template: String,
+        region: Option<String>,
+        sample_rate: Option<f64>,
+    ) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let prompt = NewPrompt {
+            region: region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+            name,
+            template,
+            sample_rate: sample_rate.unwrap_or(0.1),
+            created_by: member_id(ctx)?,
+        };
+        prompt.validate().map_err(async_graphql::Error::new)?;
+        let id = extraction_prompts::register(pool, &prompt)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to register extraction prompt: {e}")))?;
+        info!(id = %id, name = prompt.name.as_str(), region = ?prompt.region, "Extraction prompt registered in shadow");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Extraction prompt {id} registered in shadow")),
+        })
+    }
+
+    /// Judge a shadow candidate's new samples and save its comparison report
+    /// (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn evaluate_extraction_prompt(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let config = ctx.data_unchecked::<Arc<Config>>();
+        if config.anthropic_api_key.is_empty() {
+            return Ok(ScoutResult {
+                success: false,
+                message: Some("Anthropic API key not configured".to_string()),
+            });
+        }
+        let prompt = extraction_prompts::get(pool, id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load extraction prompt: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("Extraction prompt {id} not found")))?;
+        if prompt.status != PromptStatus::Shadow {
+            return Err(async_graphql::Error::new(format!("Extraction prompt {id} is not in shadow")));
+        }
+        let report = rootsignal_scout::pipeline::shadow::evaluate(pool, &config.anthropic_api_key.expose(), id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to evaluate extraction prompt: {e}")))?;
+        let failures = report.failures(&ShadowThresholds::default());
+        let verdict = if failures.is_empty() {
+            "ready to promote".to_string()
+        } else {
+            format!("not ready: {}", failures.join("; "))
+        };
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} samples, {} judged — {verdict}", report.samples, report.judged)),
+        })
+    }
+
+    /// Make a shadow candidate the live extraction prompt for its scope
+    /// (admin only). Refused unless its latest report passes the thresholds.
+    #[graphql(guard = "AdminGuard")]
+    async fn promote_extraction_prompt(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let prompt = extraction_prompts::promote(pool, id, &ShadowThresholds::default())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to promote extraction prompt: {e}")))?;
+        info!(id = %id, name = prompt.name.as_str(), region = ?prompt.region, "Extraction prompt promoted");
+        let scope = prompt.region.as_deref().unwrap_or("all regions");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} is live for {scope}", prompt.name)),
+        })
+    }
+
+    /// Retire an extraction prompt (admin only). Retiring a live prompt hands
+    /// its scope back to the global prompt or the built-in one.
+    #[graphql(guard = "AdminGuard")]
+    async fn retire_extraction_prompt(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let retired = extraction_prompts::retire(pool, id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to retire extraction prompt: {e}")))?;
+        if !retired {
+            return Err(async_graphql::Error::new(format!("Extraction prompt {id} not found")));
+        }
+        info!(id = %id, "Extraction prompt retired");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Extraction prompt {id} retired")),
+        })
+    }
+
+    /// Set or rotate the credential used to fetch a gated source (admin only).
+    /// `kind` is `cookie`, `bearer`, or `header` (with `header_name`). The
+    /// value is encrypted at rest and never returned by any query.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_source_credential(
+        &self,
+        ctx: &Context<'_>,
+        canonical_key: String,
+        kind: String,
+        value: String,
+        header_name: Option<String>,
+    ) -> Result<ScoutResult> {
+        let store = require_credential_store(ctx)?;
+        let kind = CredentialKind::parse(&kind).ok_or_else(|| {
+            async_graphql::Error::new(format!("Unknown credential kind: {kind} (cookie, bearer, header)"))
+        })?;
+        if value.trim().is_empty() {
+            return Err(async_graphql::Error::new("Credential value is empty"));
+        }
+
+        let credential = SourceCredential { kind, header_name, value };
+        let rotated = store
+            .set(&canonical_key, &credential)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to store credential: {e}")))?;
+
+        info!(canonical_key = canonical_key.as_str(), kind = kind.as_str(), rotated, "Source credential saved");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(if rotated {
+                format!("Credential rotated for {canonical_key}")
+            } else {
+                format!("Credential set for {canonical_key}")
+            }),
+        })
+    }
+
+    /// Remove a source's credential; it will be fetched unauthenticated (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn delete_source_credential(&self, ctx: &Context<'_>, canonical_key: String) -> Result<ScoutResult> {
+        let store = require_credential_store(ctx)?;
+        let deleted = store
+            .delete(&canonical_key)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to delete credential: {e}")))?;
+
+        Ok(ScoutResult {
+            success: deleted,
+            message: Some(if deleted {
+                format!("Credential removed for {canonical_key}")
+            } else {
+                format!("No credential stored for {canonical_key}")
+            }),
+        })
+    }
+
+    /// Replay dead-lettered items into the workflows they failed in (admin only).
+    /// Each id is replayed independently; failures are reported per id.
+    #[graphql(guard = "AdminGuard")]
+    async fn replay_dead_letters(&self, ctx: &Context<'_>, ids: Vec<String>) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let restate = require_restate(ctx)?;
+
+        let mut replayed = 0;
+        let mut errors = Vec::new();
+        for id in &ids {
+            let uuid = Uuid::parse_str(id)
+                .map_err(|_| async_graphql::Error::new(format!("Invalid dead letter id: {id}")))?;
+            match restate.replay_dead_letter(pool, uuid).await {
+                Ok(key) => {
+                    info!(id = id.as_str(), key = key.as_str(), "Dead letter replayed");
+                    replayed += 1;
+                }
+                Err(e) => {
+                    warn!(id = id.as_str(), error = %e, "Dead letter replay failed");
+                    errors.push(format!("{id}: {e}"));
+                }
+            }
+        }
+
+        let mut message = format!("Replayed {replayed} of {} dead letters", ids.len());
+        if !errors.is_empty() {
+            message.push_str(&format!(" ({})", errors.join("; ")));
+        }
+        Ok(ScoutResult {
+            success: errors.is_empty(),
+            message: Some(message),
+        })
+    }
+
+    /// Discard a dead-lettered item without replaying it (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn discard_dead_letter(&self, ctx: &Context<'_>, id: String) -> Result<ScoutResult> {
+        let pool = require_pool(ctx)?;
+        let uuid = Uuid::parse_str(&id)
+            .map_err(|_| async_graphql::Error::new(format!("Invalid dead letter id: {id}")))?;
+
+        let found = rootsignal_common::dead_letter::set_status(
+            pool,
+            uuid,
+            rootsignal_common::dead_letter::DeadLetterStatus::Discarded,
+        )
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to discard dead letter: {e}")))?;
+        if !found {
+            return Err(async_graphql::Error::new(format!("Dead letter {id} not found")));
+        }
+
+        info!(id = id.as_str(), "Dead letter discarded");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Dead letter {id} discarded")),
+        })
+    }
+
+    /// Set how long a class of data is kept (admin only). Takes effect at the
+    /// next purge.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_retention_policy(
+        &self,
+        ctx: &Context<'_>,
+        class: GqlDataClass,
+        retain_days: u32,
+    ) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let policy = RetentionPolicy {
+            class: class.into(),
+            retain_days,
+        };
+        policy.validate().map_err(async_graphql::Error::new)?;
+        rootsignal_graph::retention::set_retention_policy(client, &policy)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save retention policy: {e}")))?;
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("{} kept for {retain_days} days", policy.class.as_str())),
+        })
+    }
+
+    /// Exempt a situation or signal (`node_id`) or everything from a source
+    /// (`source_url`) from retention purges until released (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn place_legal_hold(
+        &self,
+        ctx: &Context<'_>,
+        node_id: Option<Uuid>,
+        source_url: Option<String>,
+        reason: String,
+    ) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let target = match (node_id, source_url) {
+            (Some(id), None) => HoldTarget::Node(id),
+            (None, Some(url)) => HoldTarget::SourceUrl(url.trim().to_string()),
+            _ => return Err(async_graphql::Error::new("Give exactly one of nodeId and sourceUrl")),
+        };
+        let hold = LegalHold {
+            id: Uuid::new_v4(),
+            target,
+            reason: reason.trim().to_string(),
+            created_at: chrono::Utc::now(),
+        };
+        hold.validate().map_err(async_graphql::Error::new)?;
+        rootsignal_graph::retention::place_legal_hold(client, &hold)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to place legal hold: {e}")))?;
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Legal hold {} placed", hold.id)),
+        })
+    }
+
+    /// Release a legal hold (admin only). Held data becomes eligible at the
+    /// next purge.
+    #[graphql(guard = "AdminGuard")]
+    async fn release_legal_hold(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let released = rootsignal_graph::retention::release_legal_hold(client, id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to release legal hold: {e}")))?;
+        if !released {
+            return Err(async_graphql::Error::new(format!("No active legal hold {id}")));
+        }
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Legal hold {id} released")),
+        })
+    }
+
+    /// Run the retention purge now (admin only). With `dry_run`, reports what
+    /// would be purged without deleting anything.
+    #[graphql(guard = "AdminGuard")]
+    async fn run_retention_purge(&self, ctx: &Context<'_>, dry_run: bool) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let pool = require_pool(ctx)?;
+        let report = rootsignal_scout::infra::retention::run_purge(client, pool, dry_run)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Retention purge failed: {e}")))?;
+        Ok(ScoutResult {
+            success: report.errors.is_empty(),
+            message: Some(report.to_string()),
+        })
+    }
+
+    /// Show one field of a signal to a narrower audience, or with no
+    /// `visibility`, return it to its default tier (admin only). Public
+    /// readers see the change when the cache next reloads.
+    #[graphql(guard = "AdminGuard")]
+    async fn set_signal_field_visibility(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        field: GqlSignalField,
+        visibility: Option<GqlVisibility>,
+    ) -> Result<ScoutResult> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let field = SignalField::from(field);
+        let mut demotions = writer
+            .field_visibility(id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to read signal: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("No signal {id}")))?;
+        let tier = visibility.map(Visibility::from);
+        demotions.set(field, tier);
+        writer
+            .set_field_visibility(id, &demotions)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save field visibility: {e}")))?;
+        info!(%id, field = field.as_str(), tier = tier.map(|t| t.as_str()), "Signal field visibility set");
+        Ok(ScoutResult {
+            success: true,
+            message: Some(match tier {
+                Some(tier) => format!("{} shown to {} and above", field.as_str(), tier.as_str()),
+                None => format!("{} back to its default visibility", field.as_str()),
+            }),
+        })
+    }
+
+    /// Open a data-subject request for a person's name and handles (admin
+    /// only). Review its matches with `adminSubjectRequestReview` before
+    /// executing it.
+    #[graphql(guard = "AdminGuard")]
+    async fn open_subject_request(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        #[graphql(default)] handles: Vec<String>,
+        note: Option<String>,
+    ) -> Result<GqlSubjectRequest> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let terms = rootsignal_common::subject_requests::search_terms(&name, &handles)
+            .map_err(async_graphql::Error::new)?;
+        let request = SubjectRequest {
+            id: Uuid::new_v4(),
+            terms,
+            note: note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()),
+            status: SubjectRequestStatus::Open,
+            opened_by: member_id(ctx)?,
+            created_at: chrono::Utc::now(),
+            closed_by: None,
+            closed_at: None,
+            redaction: None,
+        };
+        rootsignal_graph::subject_requests::open_request(client, &request)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to open subject request: {e}")))?;
+        Ok(request.into())
+    }
+
+    /// Execute an open data-subject request (admin only): remove its terms
+    /// from signals and evidence, anonymize matching actors, and keep the
+    /// terms suppressed for future extraction. Not reversible.
+    #[graphql(guard = "AdminGuard")]
+    async fn execute_subject_request(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let executed_by = member_id(ctx)?;
+        let summary = rootsignal_graph::subject_requests::execute_request(client, id, &executed_by)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to execute subject request: {e}")))?
+            .ok_or_else(|| async_graphql::Error::new(format!("No open subject request {id}")))?;
+        // Translations of the redacted summaries would otherwise outlive them
+        if let Some(pool) = ctx.data_unchecked::<Option<sqlx::PgPool>>().as_ref() {
+            if let Err(e) = rootsignal_common::summary_variants::delete(pool, &summary.node_ids).await {
+                tracing::warn!(error = %e, "Failed to drop summary variants of redacted nodes");
+            }
+        }
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!(
+                "Redacted {} signals and {} evidence snippets, anonymized {} actors",
+                summary.signals, summary.evidence, summary.actors
+            )),
+        })
+    }
+
+    /// Close an open data-subject request without redacting anything (admin
+    /// only).
+    #[graphql(guard = "AdminGuard")]
+    async fn dismiss_subject_request(&self, ctx: &Context<'_>, id: Uuid, note: Option<String>) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let dismissed_by = member_id(ctx)?;
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        let dismissed =
+            rootsignal_graph::subject_requests::dismiss_request(client, id, &dismissed_by, note.as_deref())
+                .await
+                .map_err(|e| async_graphql::Error::new(format!("Failed to dismiss subject request: {e}")))?;
+        if !dismissed {
+            return Err(async_graphql::Error::new(format!("No open subject request {id}")));
+        }
+        Ok(ScoutResult {
+            success: true,
+            message: Some(format!("Subject request {id} dismissed")),
+        })
+    }
+
+    /// Create or replace a room feed posting new signals to a Discord channel
+    /// or Matrix room (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn save_room_feed(&self, ctx: &Context<'_>, input: RoomFeedInput) -> Result<GqlRoomFeed> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let prior = match input.id {
+            Some(id) => Some(
+                rootsignal_graph::room_feeds::room_feed(client, id)
+                    .await?
+                    .ok_or_else(|| async_graphql::Error::new(format!("No room feed {id}")))?,
+            ),
+            None => None,
+        };
+
+        let trimmed = |v: Option<String>| v.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+        let target = match input.platform {
+            GqlRoomPlatform::Discord => {
+                let webhook_url = match (trimmed(input.webhook_url), prior.as_ref().map(|p| &p.target)) {
+                    (Some(url), _) => url,
+                    (None, Some(RoomTarget::Discord { webhook_url })) => webhook_url.clone(),
+                    (None, _) => return Err(async_graphql::Error::new("Discord feeds need a webhookUrl")),
+                };
+                RoomTarget::Discord { webhook_url }
+            }
+            GqlRoomPlatform::Matrix => match (trimmed(input.homeserver), trimmed(input.room_id)) {
+                (Some(homeserver), Some(room_id)) => RoomTarget::Matrix { homeserver, room_id },
+                _ => return Err(async_graphql::Error::new("Matrix feeds need a homeserver and roomId")),
+            },
+        };
+
+        let feed = RoomFeed {
+            id: prior.as_ref().map_or_else(Uuid::new_v4, |p| p.id),
+            name: input.name.trim().to_string(),
+            target,
+            bbox: input.bbox.map(|b| BoundingBox {
+                min_lat: b.min_lat,
+                max_lat: b.max_lat,
+                min_lng: b.min_lng,
+                max_lng: b.max_lng,
+            }),
+            types: input.types.into_iter().map(SignalType::to_node_type).collect(),
+            min_severity: input.min_severity.map(Into::into),
+            template: trimmed(input.template),
+            max_per_hour: input
+                .max_per_hour
+                .unwrap_or(rootsignal_common::room_feeds::DEFAULT_MESSAGES_PER_HOUR),
+            paused: input.paused,
+            created_by: match &prior {
+                Some(p) => p.created_by.clone(),
+                None => member_id(ctx)?,
+            },
+            created_at: prior.as_ref().map_or_else(chrono::Utc::now, |p| p.created_at),
+        };
+        feed.validate().map_err(async_graphql::Error::new)?;
+
+        rootsignal_graph::room_feeds::save_feed(client, &feed)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to save room feed: {e}")))?;
+        info!(feed = %feed.id, "Room feed stored");
+        Ok(feed.into())
+    }
+
+    /// Delete a room feed and its delivery queue (admin only).
+    #[graphql(guard = "AdminGuard")]
+    async fn delete_room_feed(&self, ctx: &Context<'_>, id: Uuid) -> Result<ScoutResult> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let deleted = rootsignal_graph::room_feeds::delete_feed(client, id).await?;
+        if let Some(pool) = ctx.data_unchecked::<Option<sqlx::PgPool>>().as_ref() {
+            if let Err(e) = crate::db::models::room_delivery::delete_feed(pool, id).await {
+                warn!(feed = %id, error = %e, "Failed to drop room feed deliveries");
+            }
+        }
+        Ok(ScoutResult {
+            success: deleted,
+            message: (!deleted).then(|| format!("No room feed {id}")),
+        })
+    }
+}
+
+/// User-facing message for a dispatch: `started` for new runs, or which
+/// existing run a duplicate submission attached to.
+fn dispatch_message(dispatch: &crate::restate_client::Dispatch, started: &str) -> String {
+    if dispatch.is_attached() {
+        let handle = dispatch.handle();
+        format!("Already running, attached to existing run {}", handle.key)
+    } else {
+        started.to_string()
+    }
+}
+
+/// Admin numbers, plus members an admin has verified as contributors.
+async fn may_sign_in(ctx: &Context<'_>, phone: &str) -> bool {
+    let config = ctx.data_unchecked::<Arc<Config>>();
+    if config.admin_numbers.iter().any(|n| n == phone) {
+        return true;
+    }
+    let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+    let member_id = jwt::phone_to_uuid(phone).to_string();
+    matches!(rootsignal_graph::annotations::contributor(client, &member_id).await, Ok(Some(_)))
+}
+
+fn rate_limit_check(ctx: &Context<'_>, max_per_hour: usize) -> Result<()> {
+    let client_ip = ctx.data_unchecked::<ClientIp>();
+    let limiter = ctx.data_unchecked::<RateLimiter>();
+
+    // We need to block on the mutex - use try_lock to avoid async issues
+    // In practice, contention is very low
+    let mut guard = limiter
+        .0
+        .try_lock()
+        .map_err(|_| async_graphql::Error::new("Rate limiter busy, try again"))?;
+
+    let entries = guard.entry(client_ip.0).or_default();
+    if !check_rate_limit_window(entries, Instant::now(), max_per_hour) {
+        return Err(async_graphql::Error::new("Rate limit exceeded"));
+    }
+
+    Ok(())
+}
+
+fn check_rate_limit_window(entries: &mut Vec<Instant>, now: Instant, max_per_hour: usize) -> bool {
+    let cutoff = now - std::time::Duration::from_secs(3600);
+    entries.retain(|t| *t > cutoff);
+    if entries.len() >= max_per_hour {
+        return false;
+    }
+    entries.push(now);
+    true
+}
+
+/// Extract the Restate client from GraphQL context, returning a clear error if not configured.
 fn require_restate<'a>(ctx: &'a Context<'_>) -> Result<&'a RestateClient> {
     ctx.data_unchecked::<Option<RestateClient>>()
         .as_ref()
@@ -685,22 +2806,173 @@ fn require_restate<'a>(ctx: &'a Context<'_>) -> Result<&'a RestateClient> {
         })
 }
 
+/// Pause or resume a region's schedule, erroring if it has none.
+async fn set_schedule_paused(ctx: &Context<'_>, region: &str, paused: bool) -> Result<ScoutResult> {
+    let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+    let found = writer
+        .set_region_schedule_paused(region, paused)
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to update schedule: {e}")))?;
+    if !found {
+        return Err(async_graphql::Error::new(format!("No schedule for region {region}")));
+    }
+    let state = if paused { "paused" } else { "resumed" };
+    info!(region, state, "Region schedule updated");
+    Ok(ScoutResult {
+        success: true,
+        message: Some(format!("Schedule {state} for {region}")),
+    })
+}
+
+fn require_credential_store<'a>(ctx: &'a Context<'_>) -> Result<&'a CredentialStore> {
+    ctx.data_unchecked::<Option<Arc<CredentialStore>>>()
+        .as_deref()
+        .ok_or_else(|| {
+            async_graphql::Error::new("Source credentials not configured (set SOURCE_CREDENTIALS_KEY)")
+        })
+}
+
+/// Register the signal behind an accepted "not true" or "duplicate"
+/// correction as negative knowledge. Non-fatal: the correction is resolved
+/// either way.
+async fn register_corrected_signal(ctx: &Context<'_>, correction: &CorrectionRequest, note: Option<&str>) {
+    let kind = match correction.kind {
+        CorrectionKind::NotTrue => NegativeKind::Debunked,
+        CorrectionKind::Duplicate => NegativeKind::Duplicate,
+        _ => return,
+    };
+    let Some(pool) = ctx.data_unchecked::<Option<sqlx::PgPool>>().as_ref() else {
+        return;
+    };
+    let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+    let signal = match reader.get_signal_by_id(correction.signal_id).await {
+        Ok(Some(signal)) => signal,
+        Ok(None) => return,
+        Err(e) => {
+            warn!(correction = %correction.id, error = %e, "Failed to load corrected signal for negative knowledge");
+            return;
+        }
+    };
+
+    let mut reason = format!("Accepted correction: {}", correction.kind.as_str());
+    if let Some(note) = note {
+        reason.push_str(&format!(" ({note})"));
+    }
+    let entry = NewNegativeEntry {
+        region: None,
+        kind,
+        text: signal.title().to_string(),
+        reason,
+        added_by: format!("correction:{}", correction.id),
+        expires_at: None,
+    };
+    match negative_knowledge::add(pool, &entry).await {
+        Ok(id) => info!(correction = %correction.id, entry = %id, "Corrected signal registered as negative knowledge"),
+        Err(e) => warn!(correction = %correction.id, error = %e, "Failed to register corrected signal as negative knowledge"),
+    }
+}
+
+fn parse_flag(flag: &str) -> Result<Flag> {
+    Flag::parse(flag).ok_or_else(|| {
+        let known: Vec<&str> = Flag::ALL.iter().map(Flag::as_str).collect();
+        async_graphql::Error::new(format!("Unknown flag: {flag} ({})", known.join(", ")))
+    })
+}
+
+fn require_pool<'a>(ctx: &'a Context<'_>) -> Result<&'a sqlx::PgPool> {
+    ctx.data_unchecked::<Option<sqlx::PgPool>>()
+        .as_ref()
+        .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))
+}
+
 #[derive(serde::Deserialize)]
 struct NominatimResult {
     lat: String,
     lon: String,
     display_name: String,
+    /// Present when `polygon_geojson` is requested; a point for places without an area.
+    #[serde(default)]
+    geojson: Option<serde_json::Value>,
+}
+
+struct GeocodedPlace {
+    lat: f64,
+    lng: f64,
+    display_name: String,
+    boundary: Option<EntityGeometry>,
+}
+
+/// A pending manual scout task for a geocoded location. An area geocoded with
+/// its boundary is scoped to the boundary, and its geo terms name the places
+/// inside it; anything else gets a radius (default 30km).
+async fn geocoded_scout_task(
+    location: &str,
+    radius_km: Option<f64>,
+    priority: Option<f64>,
+    use_boundary: bool,
+) -> Result<rootsignal_common::ScoutTask> {
+    let place = geocode_location(location, use_boundary)
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Geocoding failed: {e}")))?;
+
+    // Extract geo_terms from the display_name (comma-separated parts)
+    let mut geo_terms: Vec<String> = place
+        .display_name
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let scope = match place.boundary {
+        Some(boundary) => {
+            for name in boundary_place_names(&boundary).await {
+                if !geo_terms.contains(&name) {
+                    geo_terms.push(name);
+                }
+            }
+            ScoutScope::from_boundary(place.display_name, boundary)
+        }
+        None => ScoutScope {
+            center_lat: place.lat,
+            center_lng: place.lng,
+            radius_km: radius_km.unwrap_or(30.0),
+            name: place.display_name,
+            boundary: None,
+        },
+    };
+
+    Ok(rootsignal_common::ScoutTask {
+        id: Uuid::new_v4(),
+        center_lat: scope.center_lat,
+        center_lng: scope.center_lng,
+        radius_km: scope.radius_km,
+        context: scope.name,
+        geo_terms,
+        priority: priority.unwrap_or(1.0),
+        source: rootsignal_common::ScoutTaskSource::Manual,
+        status: rootsignal_common::ScoutTaskStatus::Pending,
+        phase_status: "idle".to_string(),
+        created_at: chrono::Utc::now(),
+        completed_at: None,
+        boundary: scope.boundary,
+    })
 }
 
-/// Geocode a location string to (lat, lng, display_name) using Nominatim.
-async fn geocode_location(location: &str) -> anyhow::Result<(f64, f64, String)> {
+/// Geocode a location string using Nominatim, with its boundary if asked for
+/// and the place has one.
+async fn geocode_location(location: &str, with_boundary: bool) -> anyhow::Result<GeocodedPlace> {
     if location.len() > 200 {
         anyhow::bail!("Location input too long (max 200 chars)");
     }
+    let mut params = vec![("q", location), ("format", "json"), ("limit", "1")];
+    if with_boundary {
+        // Simplified to ~100 m so county boundaries stay a manageable size.
+        params.extend([("polygon_geojson", "1"), ("polygon_threshold", "0.001")]);
+    }
     let client = reqwest::Client::new();
     let resp = client
         .get("https://nominatim.openstreetmap.org/search")
-        .query(&[("q", location), ("format", "json"), ("limit", "1")])
+        .query(&params)
         .header("User-Agent", "rootsignal/1.0")
         .send()
         .await?;
@@ -711,9 +2983,52 @@ async fn geocode_location(location: &str) -> anyhow::Result<(f64, f64, String)>
         .next()
         .ok_or_else(|| anyhow::anyhow!("No geocoding results for '{}'", location))?;
 
-    let lat: f64 = first.lat.parse()?;
-    let lon: f64 = first.lon.parse()?;
-    Ok((lat, lon, first.display_name))
+    Ok(GeocodedPlace {
+        lat: first.lat.parse()?,
+        lng: first.lon.parse()?,
+        boundary: first.geojson.and_then(|g| EntityGeometry::from_geojson(&g).ok()),
+        display_name: first.display_name,
+    })
+}
+
+/// Most places named inside a boundary; each is a Nominatim reverse lookup.
+const MAX_BOUNDARY_SAMPLES: usize = 6;
+
+/// Names of the cities and towns a boundary covers, found by reverse
+/// geocoding points spread across it. Lookups that fail are skipped.
+async fn boundary_place_names(boundary: &EntityGeometry) -> Vec<String> {
+    #[derive(serde::Deserialize)]
+    struct ReverseResult {
+        #[serde(default)]
+        name: String,
+    }
+
+    let client = reqwest::Client::new();
+    let mut names: Vec<String> = Vec::new();
+    for (i, (lat, lng)) in boundary.sample_points(2).into_iter().take(MAX_BOUNDARY_SAMPLES).enumerate() {
+        if i > 0 {
+            // Nominatim's usage policy allows one request per second.
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        }
+        let (lat, lng) = (lat.to_string(), lng.to_string());
+        let result = client
+            .get("https://nominatim.openstreetmap.org/reverse")
+            .query(&[("lat", lat.as_str()), ("lon", lng.as_str()), ("format", "json"), ("zoom", "10")])
+            .header("User-Agent", "rootsignal/1.0")
+            .send()
+            .await;
+        let name = match result {
+            Ok(resp) => resp.json::<ReverseResult>().await.map(|r| r.name).unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, "Reverse geocoding failed");
+                continue;
+            }
+        };
+        if !name.is_empty() && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
 }
 
 #[cfg(test)]
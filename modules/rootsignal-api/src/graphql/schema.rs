@@ -5,13 +5,13 @@ use async_graphql::{Context, EmptySubscription, Object, Result, Schema, SimpleOb
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use rootsignal_common::{Node, NodeType};
-use rootsignal_graph::{CachedReader, GraphWriter};
+use rootsignal_common::{BoundingBox, NodeType};
+use rootsignal_graph::{CachedReader, GraphRouter, GraphWriter};
 
-use super::context::{AdminGuard, AuthContext};
+use super::context::{member_id, read_pool, read_token_key, region_graph, region_writer, viewer_tier, AdminGuard, AuthContext};
 use super::loaders::{
-    ActorsBySignalLoader, EvidenceBySignalLoader, SituationsBySignalLoader, StoryBySignalLoader,
-    TagsBySituationLoader, TagsByStoryLoader,
+    ActorsBySignalLoader, AnnotationsBySignalLoader, CorrectionsBySignalLoader, EvidenceBySignalLoader, InvestigationsBySignalLoader,
+    SituationsBySignalLoader, StoryBySignalLoader, SummaryVariantsLoader, TagsBySituationLoader, TagsByStoryLoader,
 };
 use super::mutations::MutationRoot;
 use super::types::*;
@@ -34,6 +34,72 @@ impl QueryRoot {
         })
     }
 
+    /// The signed-in member's saved searches, each with the signals it has
+    /// matched since they last marked it seen.
+    async fn my_watchlist(&self, ctx: &Context<'_>) -> Result<Vec<WatchlistEntry>> {
+        let owner = member_id(ctx)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
+
+        let mut entries = Vec::new();
+        for search in rootsignal_graph::watchlist::saved_searches(client, Some(&owner)).await? {
+            let mut new_matches = Vec::new();
+            for id in rootsignal_graph::watchlist::new_matches(client, &search).await? {
+                if let Some(node) = reader.get_signal_by_id(id).await? {
+                    new_matches.push(GqlSignal::for_viewer(node, viewer));
+                }
+            }
+            entries.push(WatchlistEntry {
+                search: search.into(),
+                new_match_count: new_matches.len() as u32,
+                new_matches,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// What's new in the viewed area since this visitor last caught up in
+    /// `region`. `token` is a random id the client generates and keeps; only
+    /// its hash is stored. Call `markRead` with `asOf` once shown.
+    async fn new_since(
+        &self,
+        ctx: &Context<'_>,
+        token: String,
+        region: String,
+        min_lat: f64,
+        max_lat: f64,
+        min_lng: f64,
+        max_lng: f64,
+        limit: Option<u32>,
+    ) -> Result<NewSinceResult> {
+        let key = read_token_key(&token)?;
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
+        let now = Utc::now();
+        let region_slug = rootsignal_common::slugify(&region);
+        let watermark = rootsignal_graph::read_state::watermark(client, &key, &region_slug).await?;
+        let since = rootsignal_common::read_state::new_since_start(watermark, now);
+
+        let limit = limit.unwrap_or(5).min(20);
+        let bounds = BoundingBox { min_lat, max_lat, min_lng, max_lng };
+        let new = reader.new_signals_in_bounds(&region_slug, bounds, since, limit).await?;
+        Ok(NewSinceResult {
+            since: new.since,
+            as_of: now,
+            total: new.counts.total,
+            other_count: new.counts.other,
+            counts: new
+                .counts
+                .cells
+                .into_iter()
+                .map(|(nt, count)| SignalTypeCount { signal_type: nt.into(), count })
+                .collect(),
+            top: new.top.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect(),
+        })
+    }
+
     /// Find signals near a geographic point.
     async fn signals_near(
         &self,
@@ -44,41 +110,54 @@ impl QueryRoot {
         types: Option<Vec<SignalType>>,
     ) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let node_types: Option<Vec<NodeType>> =
             types.map(|t| t.into_iter().map(|st| st.to_node_type()).collect());
         let radius = radius_km.min(50.0);
         let nodes = reader
             .find_nodes_near(lat, lng, radius, node_types.as_deref())
             .await?;
-        Ok(nodes.into_iter().map(GqlSignal::from).collect())
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
     }
 
 
-    /// List recent signals, ordered by triangulation quality.
+    /// List recent signals, ordered by triangulation quality. With `district`,
+    /// only signals located in that ward or district, newest first.
     async fn signals_recent(
         &self,
         ctx: &Context<'_>,
         limit: Option<u32>,
         types: Option<Vec<SignalType>>,
+        district: Option<DistrictFilter>,
     ) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let node_types: Option<Vec<NodeType>> =
             types.map(|t| t.into_iter().map(|st| st.to_node_type()).collect());
         let limit = limit.unwrap_or(50).min(200);
-        let nodes = reader.list_recent(limit, node_types.as_deref()).await?;
-        Ok(nodes.into_iter().map(GqlSignal::from).collect())
+        let nodes = match district {
+            Some(district) => {
+                reader
+                    .signals_in_district(&district.entity_id()?, node_types.as_deref(), limit)
+                    .await?
+            }
+            None => reader.list_recent(limit, node_types.as_deref()).await?,
+        };
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
     }
 
     /// Get a single signal by ID.
     async fn signal(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let node = reader.get_signal_by_id(id).await?;
-        Ok(node.map(GqlSignal::from))
+        Ok(node.map(|n| GqlSignal::for_viewer(n, viewer)))
     }
 
     // ========== Search app queries (public, no auth) ==========
 
     /// Find signals within a bounding box, sorted by heat. For viewport-driven browsing.
+    /// `category` includes its subcategories.
     async fn signals_in_bounds(
         &self,
         ctx: &Context<'_>,
@@ -86,18 +165,46 @@ impl QueryRoot {
         max_lat: f64,
         min_lng: f64,
         max_lng: f64,
+        category: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlSignal>> {
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
+        let limit = limit.unwrap_or(50).min(200);
+        let categories = category_filter(ctx, category).await?;
+        let nodes = reader
+            .signals_in_bounds(min_lat, max_lat, min_lng, max_lng, categories.as_deref(), limit)
+            .await?;
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
+    }
+
+    /// Signals linked to a registry entity — everything about Route 21, all
+    /// signals touching Ward 9 — newest first.
+    async fn signals_for_entity(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        kind: GqlRegistryKind,
+        name: String,
         limit: Option<u32>,
     ) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let limit = limit.unwrap_or(50).min(200);
+        let entity = rootsignal_common::RegistryEntity {
+            kind: kind.into(),
+            name,
+            aliases: Vec::new(),
+            geometry: None,
+        };
         let nodes = reader
-            .signals_in_bounds(min_lat, max_lat, min_lng, max_lng, limit)
+            .signals_for_entity(&entity.node_id(&rootsignal_common::slugify(&region)), limit)
             .await?;
-        Ok(nodes.into_iter().map(GqlSignal::from).collect())
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
     }
 
     /// Find stories within a bounding box (by centroid), sorted by energy.
-    /// Optionally filter by tag slug.
+    /// Optionally filter by tag slug and by category (with subcategories).
     async fn stories_in_bounds(
         &self,
         ctx: &Context<'_>,
@@ -106,10 +213,12 @@ impl QueryRoot {
         min_lng: f64,
         max_lng: f64,
         tag: Option<String>,
+        category: Option<String>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlStory>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
         let limit = limit.unwrap_or(20).min(100);
+        let categories = category_filter(ctx, category).await?;
         let stories = reader
             .stories_in_bounds_filtered(
                 min_lat,
@@ -117,6 +226,7 @@ impl QueryRoot {
                 min_lng,
                 max_lng,
                 tag.as_deref(),
+                categories.as_deref(),
                 limit,
             )
             .await?;
@@ -136,6 +246,7 @@ impl QueryRoot {
         limit: Option<u32>,
     ) -> Result<Vec<GqlSearchResult>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let embedder = ctx.data_unchecked::<Arc<rootsignal_scout::infra::embedder::Embedder>>();
         let limit = limit.unwrap_or(50).min(200);
 
@@ -152,7 +263,7 @@ impl QueryRoot {
         Ok(results
             .into_iter()
             .map(|(node, score)| GqlSearchResult {
-                signal: GqlSignal::from(node),
+                signal: GqlSignal::for_viewer(node, viewer),
                 score,
             })
             .collect())
@@ -198,21 +309,52 @@ impl QueryRoot {
             .collect())
     }
 
-    /// List stories ordered by energy.
+    /// List stories ordered by energy. With `district`, only stories with a
+    /// signal located in that ward or district.
     async fn stories(
         &self,
         ctx: &Context<'_>,
         limit: Option<u32>,
         status: Option<String>,
+        district: Option<DistrictFilter>,
     ) -> Result<Vec<GqlStory>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
         let limit = limit.unwrap_or(20).min(100);
-        let stories = reader
-            .top_stories_by_energy(limit, status.as_deref())
-            .await?;
+        let stories = match district {
+            Some(district) => {
+                reader
+                    .stories_in_district(&district.entity_id()?, status.as_deref(), limit)
+                    .await?
+            }
+            None => reader.top_stories_by_energy(limit, status.as_deref()).await?,
+        };
         Ok(stories.into_iter().map(GqlStory).collect())
     }
 
+    /// One ward or district at a glance: signal counts by type, its most
+    /// severe tensions, and the stories and situations touching it.
+    async fn district_summary(&self, ctx: &Context<'_>, district: DistrictFilter) -> Result<DistrictSummary> {
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
+        let summary = reader.district_summary(&district.entity_id()?).await?;
+        Ok(DistrictSummary {
+            region: district.region,
+            kind: district.kind,
+            name: district.name,
+            signal_count: summary.signal_counts.total,
+            other_count: summary.signal_counts.other,
+            signal_counts: summary
+                .signal_counts
+                .cells
+                .into_iter()
+                .map(|(nt, count)| SignalTypeCount { signal_type: nt.into(), count })
+                .collect(),
+            top_tensions: summary.top_tensions.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect(),
+            stories: summary.stories.into_iter().map(GqlStory).collect(),
+            situations: summary.situations.into_iter().map(GqlSituation).collect(),
+        })
+    }
+
     /// Get a single story by ID.
     async fn story(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<GqlStory>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
@@ -220,7 +362,8 @@ impl QueryRoot {
         Ok(story.map(GqlStory))
     }
 
-    /// List stories by category.
+    /// List stories by category, including its subcategories. Accepts a
+    /// category slug, name or alias.
     async fn stories_by_category(
         &self,
         ctx: &Context<'_>,
@@ -229,7 +372,8 @@ impl QueryRoot {
     ) -> Result<Vec<GqlStory>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
         let limit = limit.unwrap_or(20).min(100);
-        let stories = reader.stories_by_category(&category, limit).await?;
+        let categories = category_filter(ctx, Some(category)).await?.unwrap_or_default();
+        let stories = reader.stories_by_category(&categories, limit).await?;
         Ok(stories.into_iter().map(GqlStory).collect())
     }
 
@@ -278,10 +422,146 @@ impl QueryRoot {
 
     }
 
+    /// Significant rises and falls in signal categories across a region's
+    /// neighborhoods over the last week or month, strongest first.
+    async fn trends(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        #[graphql(default_with = "GqlTrendWindow::Month")] window: GqlTrendWindow,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlTrend>> {
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let limit = limit.unwrap_or(20).min(100);
+        let trends = reader
+            .trends(&rootsignal_common::slugify(&region), window.into(), limit)
+            .await?;
+        Ok(trends.into_iter().map(GqlTrend).collect())
+    }
+
+    /// Region-wide signal and story counts from the region's materialized
+    /// stats; null until they are first computed. Signal counts too small to
+    /// publish are folded into `otherCount`.
+    async fn region_stats(&self, ctx: &Context<'_>, region: String) -> Result<Option<PublicRegionStats>> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let Some(stats) = rootsignal_graph::region_stats::region_stats(&graph, &slug).await? else {
+            return Ok(None);
+        };
+        let guard = ctx.data_unchecked::<Arc<CachedReader>>().aggregate_guard(&slug).await?;
+        let cells = stats
+            .count_by_type
+            .into_iter()
+            .map(|c| (c.label, c.count.min(u32::MAX as u64) as u32, false))
+            .collect();
+        let signals = guard.breakdown(cells);
+        Ok(Some(PublicRegionStats {
+            computed_at: stats.computed_at,
+            signal_count: signals.total,
+            other_count: signals.other,
+            signal_counts: signals
+                .cells
+                .into_iter()
+                .map(|(label, count)| LabelCount { label, count: count as u64 })
+                .collect(),
+            story_count: stats.total_stories,
+            story_count_by_category: stats
+                .story_count_by_category
+                .into_iter()
+                .map(|c| LabelCount { label: c.label, count: c.count })
+                .collect(),
+        }))
+    }
+
+    /// How the region's signals were gathered and vetted: source mix,
+    /// extraction acceptance, corrections, safety suppressions, and what the
+    /// latest run spent with which extraction prompt. From the materialized
+    /// stats; null until they are first computed. Suppression categories too
+    /// small to publish are folded into `suppressedOther`.
+    async fn region_transparency(&self, ctx: &Context<'_>, region: String) -> Result<Option<RegionTransparency>> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let Some(stats) = rootsignal_graph::region_stats::region_stats(&graph, &slug).await? else {
+            return Ok(None);
+        };
+        let last_run = rootsignal_graph::region_stats::run_methodology(&graph, &slug).await?;
+        let guard = ctx.data_unchecked::<Arc<CachedReader>>().aggregate_guard(&slug).await?;
+        let label_counts = |counts: Vec<rootsignal_common::region_stats::LabelCount>| {
+            counts
+                .into_iter()
+                .map(|c| LabelCount { label: c.label, count: c.count })
+                .collect::<Vec<_>>()
+        };
+        let extraction_acceptance_rate = stats.acceptance_rate();
+        let suppressions = guard.breakdown(
+            stats
+                .suppression_counts
+                .into_iter()
+                .map(|c| (c.label, c.count.min(u32::MAX as u64) as u32, false))
+                .collect(),
+        );
+        Ok(Some(RegionTransparency {
+            computed_at: stats.computed_at,
+            active_sources: stats.active_sources,
+            source_composition: label_counts(stats.source_composition),
+            extraction_acceptance_rate,
+            correction_counts: label_counts(stats.correction_counts),
+            suppressed_total: suppressions.total,
+            suppressed_other: suppressions.other,
+            suppression_counts: suppressions
+                .cells
+                .into_iter()
+                .map(|(label, count)| LabelCount { label, count: count as u64 })
+                .collect(),
+            last_run: last_run.map(|run| PublicRunMethodology {
+                finished_at: run.finished_at,
+                spent_cents: run.spent_cents,
+                budget_cents: run.budget_cents,
+                prompt_version: run.prompt_version,
+                prompt_live_since: run.prompt_live_since,
+            }),
+        }))
+    }
+
+    /// The canonical topic categories, parents before children. Category
+    /// filters accept any slug, name or alias listed here.
+    async fn topic_categories(&self, ctx: &Context<'_>) -> Result<Vec<TopicCategory>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let taxonomy = rootsignal_graph::taxonomy::load_taxonomy(client).await?;
+        let mut categories: Vec<TopicCategory> = taxonomy
+            .categories
+            .iter()
+            .map(|c| TopicCategory {
+                slug: c.slug.clone(),
+                name: c.name.clone(),
+                parent: c.parent.clone(),
+                aliases: c.aliases.clone(),
+                path: taxonomy.path(&c.slug).iter().map(|p| p.name.clone()).collect(),
+            })
+            .collect();
+        categories.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(categories)
+    }
+
     // ========== Situation queries ==========
 
-    /// Top situations by temperature.
+    /// Top situations by temperature, optionally in a category (with subcategories).
     async fn situations(
+        &self,
+        ctx: &Context<'_>,
+        category: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlSituation>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let reader = rootsignal_graph::PublicGraphReader::new(client.as_ref().clone());
+        let limit = limit.unwrap_or(20).min(100);
+        let categories = category_filter(ctx, category).await?;
+        let situations = reader.situations(limit, categories.as_deref()).await?;
+        Ok(situations.into_iter().map(GqlSituation).collect())
+    }
+
+    /// Situations most likely to escalate, so organizers can get ahead of them.
+    async fn situations_by_escalation_risk(
         &self,
         ctx: &Context<'_>,
         limit: Option<u32>,
@@ -289,7 +569,7 @@ impl QueryRoot {
         let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
         let reader = rootsignal_graph::PublicGraphReader::new(client.as_ref().clone());
         let limit = limit.unwrap_or(20).min(100);
-        let situations = reader.situations(limit).await?;
+        let situations = reader.situations_by_escalation_risk(limit).await?;
         Ok(situations.into_iter().map(GqlSituation).collect())
     }
 
@@ -301,7 +581,8 @@ impl QueryRoot {
         Ok(situation.map(GqlSituation))
     }
 
-    /// Situations within a geographic bounding box.
+    /// Situations within a geographic bounding box, optionally by arc and
+    /// category (with subcategories).
     async fn situations_in_bounds(
         &self,
         ctx: &Context<'_>,
@@ -310,13 +591,15 @@ impl QueryRoot {
         min_lng: f64,
         max_lng: f64,
         arc: Option<String>,
+        category: Option<String>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlSituation>> {
         let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
         let reader = rootsignal_graph::PublicGraphReader::new(client.as_ref().clone());
         let limit = limit.unwrap_or(20).min(100);
+        let categories = category_filter(ctx, category).await?;
         let situations = reader
-            .situations_in_bounds(min_lat, max_lat, min_lng, max_lng, limit, arc.as_deref())
+            .situations_in_bounds(min_lat, max_lat, min_lng, max_lng, limit, arc.as_deref(), categories.as_deref())
             .await?;
         Ok(situations.into_iter().map(GqlSituation).collect())
     }
@@ -346,11 +629,12 @@ impl QueryRoot {
         limit: Option<u32>,
     ) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
         let limit = limit.unwrap_or(20).min(100);
         let nodes = reader
             .unresponded_tensions_in_bounds(min_lat, max_lat, min_lng, max_lng, limit)
             .await?;
-        Ok(nodes.into_iter().map(GqlSignal::from).collect())
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
     }
 
     /// Find actors within a bounding box, sorted by last_active.
@@ -378,50 +662,31 @@ impl QueryRoot {
 
     // ========== Admin queries (AdminGuard) ==========
 
-    /// Dashboard data for a region.
+    /// Dashboard data for a region. Aggregates come from the region's
+    /// materialized stats, recomputed here only when missing or stale.
     #[graphql(guard = "AdminGuard")]
     async fn admin_dashboard(&self, ctx: &Context<'_>, region: String) -> Result<AdminDashboardData> {
-        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
-        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let graph = region_graph(ctx, &region).await?;
+        let writer = GraphWriter::new(graph.clone());
+
+        let stats = match rootsignal_graph::region_stats::region_stats(&graph, &region).await? {
+            Some(stats) if !stats.is_stale(Utc::now()) => stats,
+            _ => rootsignal_graph::region_stats::refresh_region_stats(&graph, &region).await?,
+        };
 
-        let (
-            total_signals,
-            story_count,
-            actor_count,
-            by_type,
-            freshness,
-            confidence,
-            signal_volume,
-            story_arcs,
-            story_categories,
-            tensions,
-            discovery,
-            yield_data,
-            gap_stats,
-            sources,
-            due_sources,
-            region_running,
-        ) = tokio::join!(
-            reader.total_count(),
-            reader.story_count(),
-            reader.actor_count(),
-            reader.count_by_type(),
-            reader.freshness_distribution(),
-            reader.confidence_distribution(),
-            reader.signal_volume_by_day(),
-            reader.story_count_by_arc(),
-            reader.story_count_by_category(),
+        let (tensions, due_sources, region_running, coverage_history) = tokio::join!(
             writer.get_unmet_tensions(20),
-            writer.get_discovery_performance(),
-            writer.get_extraction_yield(),
-            writer.get_gap_type_stats(),
-            writer.get_active_sources(),
             writer.count_due_sources(),
             writer.is_region_task_running(&region),
+            writer.get_coverage_history(30),
         );
 
-        let sources = sources.unwrap_or_default();
-        let (top_sources, bottom_sources) = discovery.unwrap_or_default();
+        let audit_history = match read_pool(ctx).await {
+            Ok(pool) => rootsignal_common::signal_audit::daily_trend(pool, &region, 30)
+                .await
+                .unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
 
         let scout_statuses = vec![RegionScoutStatus {
             region_name: region.clone(),
@@ -429,69 +694,56 @@ impl QueryRoot {
             last_scouted: None,
             sources_due: due_sources.unwrap_or(0),
             running: region_running.unwrap_or(false),
+            calendar_block: None,
         }];
 
-        let by_type = by_type.unwrap_or_default();
-        let signal_volume = signal_volume.unwrap_or_default();
+        let label_counts = |rows: Vec<rootsignal_common::region_stats::LabelCount>| -> Vec<LabelCount> {
+            rows.into_iter().map(|r| LabelCount { label: r.label, count: r.count }).collect()
+        };
+        let source_rows = |rows: Vec<rootsignal_common::region_stats::SourceStat>| -> Vec<AdminSourceRow> {
+            rows.into_iter()
+                .map(|s| AdminSourceRow {
+                    name: s.name,
+                    signals: s.signals,
+                    weight: s.weight,
+                    empty_runs: s.empty_runs,
+                })
+                .collect()
+        };
 
         Ok(AdminDashboardData {
-            total_signals: total_signals.unwrap_or(0),
-            total_stories: story_count.unwrap_or(0),
-            total_actors: actor_count.unwrap_or(0),
-            total_sources: sources.len() as u64,
-            active_sources: sources.iter().filter(|s| s.active).count() as u64,
+            stats_computed_at: stats.computed_at,
+            total_signals: stats.total_signals,
+            total_stories: stats.total_stories,
+            total_actors: stats.total_actors,
+            total_sources: stats.total_sources,
+            active_sources: stats.active_sources,
             total_tensions: tensions.as_ref().map(|t| t.len() as u64).unwrap_or(0),
             scout_statuses,
-            signal_volume_by_day: signal_volume
-                .iter()
-                .map(|(day, ev, gi, need, not, ten)| DayVolume {
-                    day: day.clone(),
-                    gatherings: *ev,
-                    aids: *gi,
-                    needs: *need,
-                    notices: *not,
-                    tensions: *ten,
-                })
-                .collect(),
-            count_by_type: by_type
-                .iter()
-                .map(|(t, c)| TypeCount {
-                    signal_type: format!("{t}"),
-                    count: *c,
-                })
-                .collect(),
-            story_count_by_arc: story_arcs
-                .unwrap_or_default()
-                .iter()
-                .map(|(arc, c)| LabelCount {
-                    label: arc.clone(),
-                    count: *c,
-                })
-                .collect(),
-            story_count_by_category: story_categories
-                .unwrap_or_default()
-                .iter()
-                .map(|(cat, c)| LabelCount {
-                    label: cat.clone(),
-                    count: *c,
-                })
-                .collect(),
-            freshness_distribution: freshness
-                .unwrap_or_default()
-                .iter()
-                .map(|(bucket, c)| LabelCount {
-                    label: bucket.clone(),
-                    count: *c,
+            signal_volume_by_day: stats
+                .signal_volume_by_day
+                .into_iter()
+                .map(|d| DayVolume {
+                    day: d.day,
+                    gatherings: d.gatherings,
+                    aids: d.aids,
+                    needs: d.needs,
+                    notices: d.notices,
+                    tensions: d.tensions,
                 })
                 .collect(),
-            confidence_distribution: confidence
-                .unwrap_or_default()
-                .iter()
-                .map(|(bucket, c)| LabelCount {
-                    label: bucket.clone(),
-                    count: *c,
+            count_by_type: stats
+                .count_by_type
+                .into_iter()
+                .map(|c| TypeCount {
+                    signal_type: c.label,
+                    count: c.count,
                 })
                 .collect(),
+            story_count_by_arc: label_counts(stats.story_count_by_arc),
+            story_count_by_category: label_counts(stats.story_count_by_category),
+            freshness_distribution: label_counts(stats.freshness_distribution),
+            confidence_distribution: label_counts(stats.confidence_distribution),
             unmet_tensions: tensions
                 .unwrap_or_default()
                 .iter()
@@ -501,71 +753,91 @@ impl QueryRoot {
                     severity: t.severity.clone(),
                     category: t.category.clone(),
                     what_would_help: t.what_would_help.clone(),
+                    coverage: t.coverage,
+                    escalated: t.escalated,
                 })
                 .collect(),
-            top_sources: top_sources
-                .iter()
-                .take(10)
-                .map(|s| AdminSourceRow {
-                    name: s.canonical_value.clone(),
-                    signals: s.signals_produced,
-                    weight: s.weight,
-                    empty_runs: s.consecutive_empty_runs,
+            coverage_by_day: coverage_history
+                .unwrap_or_default()
+                .into_iter()
+                .map(|d| AdminCoverageDay {
+                    day: d.day,
+                    tensions: d.tensions,
+                    avg_coverage: d.avg_coverage,
+                    unmet: d.unmet,
+                    escalated: d.escalated,
                 })
                 .collect(),
-            bottom_sources: bottom_sources
-                .iter()
-                .take(10)
-                .map(|s| AdminSourceRow {
-                    name: s.canonical_value.clone(),
-                    signals: s.signals_produced,
-                    weight: s.weight,
-                    empty_runs: s.consecutive_empty_runs,
+            audit_by_day: audit_history
+                .into_iter()
+                .map(|d| AdminAuditDay {
+                    day: d.day.to_string(),
+                    audited: d.audited,
+                    mean_score: d.mean_score,
+                    critical: d.critical,
+                    suppressed: d.suppressed,
                 })
                 .collect(),
-            extraction_yield: yield_data
-                .unwrap_or_default()
-                .iter()
+            top_sources: source_rows(stats.top_sources),
+            bottom_sources: source_rows(stats.bottom_sources),
+            extraction_yield: stats
+                .extraction_yield
+                .into_iter()
                 .map(|y| AdminYieldRow {
-                    source_label: y.source_label.clone(),
+                    source_label: y.source_label,
                     extracted: y.extracted,
                     survived: y.survived,
                     corroborated: y.corroborated,
                     contradicted: y.contradicted,
                 })
                 .collect(),
-            gap_stats: gap_stats
-                .unwrap_or_default()
-                .iter()
+            gap_stats: stats
+                .gap_stats
+                .into_iter()
                 .map(|g| AdminGapRow {
-                    gap_type: g.gap_type.clone(),
-                    total: g.total_sources,
-                    successful: g.successful_sources,
+                    gap_type: g.gap_type,
+                    total: g.total,
+                    successful: g.successful,
                     avg_weight: g.avg_weight,
                 })
                 .collect(),
         })
     }
 
-    /// List active sources with schedule preview.
+    /// List active sources with schedule preview. With `region_slug`, the
+    /// next-run preview reflects that region's calendar (quiet hours, blackouts, bursts).
     #[graphql(guard = "AdminGuard")]
     async fn admin_region_sources(
         &self,
         ctx: &Context<'_>,
+        region_slug: Option<String>,
     ) -> Result<Vec<AdminSource>> {
-        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let writer = match region_slug.as_deref() {
+            Some(r) => region_writer(ctx, r).await?,
+            None => GraphWriter::new(ctx.data_unchecked::<GraphRouter>().default_client().clone()),
+        };
         let sources = writer.get_active_sources().await?;
+        let calendar = match region_slug.as_deref() {
+            Some(r) => writer.get_region_calendar(&rootsignal_common::slugify(r)).await?,
+            None => None,
+        };
+        let scheduler = rootsignal_scout::scheduling::scheduler::SourceScheduler::new().with_calendar(calendar);
+        let now = Utc::now();
         Ok(sources
             .iter()
             .map(|s| {
                 let effective_weight = s.weight * s.quality_penalty;
-                let cadence = s.cadence_hours.unwrap_or_else(|| {
+                let mut cadence = s.cadence_hours.unwrap_or_else(|| {
                     rootsignal_scout::scheduling::scheduler::cadence_hours_for_weight(effective_weight)
                 });
+                if s.trial == rootsignal_common::TrialState::Probation {
+                    cadence = cadence.max(rootsignal_common::source_trial::TRIAL_MIN_CADENCE_HOURS);
+                }
                 let source_label = source_label_from_value(s.value());
                 AdminSource {
                     id: s.id,
                     url: s.url.clone().unwrap_or_default(),
+                    canonical_key: s.canonical_key.clone(),
                     canonical_value: s.canonical_value.clone(),
                     source_label,
                     weight: s.weight,
@@ -574,13 +846,41 @@ impl QueryRoot {
                     discovery_method: format!("{:?}", s.discovery_method),
                     last_scraped: s.last_scraped,
                     cadence_hours: cadence as f64,
+                    next_run_at: scheduler.next_run_at(s, now),
                     signals_produced: s.signals_produced,
                     active: s.active,
+                    trial_state: s.trial.as_str().to_string(),
                 }
             })
             .collect())
     }
 
+    /// Sources that recently left their trial period (graduated or
+    /// deactivated), newest first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_source_trial_transitions(
+        &self,
+        ctx: &Context<'_>,
+        region_slug: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<AdminTrialTransition>> {
+        let writer = match region_slug.as_deref() {
+            Some(r) => region_writer(ctx, r).await?,
+            None => GraphWriter::new(ctx.data_unchecked::<GraphRouter>().default_client().clone()),
+        };
+        let transitions = writer.recent_trial_transitions(limit.unwrap_or(20).min(100)).await?;
+        Ok(transitions
+            .into_iter()
+            .map(|t| AdminTrialTransition {
+                canonical_key: t.canonical_key,
+                from: t.from.as_str().to_string(),
+                to: t.to.as_str().to_string(),
+                reason: t.reason,
+                at: t.at,
+            })
+            .collect())
+    }
+
     /// Scout status for a specific region.
     #[graphql(guard = "AdminGuard")]
     async fn admin_scout_status(
@@ -589,10 +889,17 @@ impl QueryRoot {
         region_slug: String,
     ) -> Result<RegionScoutStatus> {
         let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
-        let (running, due) = tokio::join!(
+        let calendar_key = rootsignal_common::slugify(&region_slug);
+        let (running, due, calendar) = tokio::join!(
             writer.is_region_task_running(&region_slug),
             writer.count_due_sources(),
+            writer.get_region_calendar(&calendar_key),
         );
+        let calendar_block = calendar
+            .ok()
+            .flatten()
+            .and_then(|c| c.blocked_at(Utc::now()))
+            .map(|b| b.to_string());
 
         Ok(RegionScoutStatus {
             region_name: region_slug.clone(),
@@ -600,9 +907,174 @@ impl QueryRoot {
             last_scouted: None,
             sources_due: due.unwrap_or(0),
             running: running.unwrap_or(false),
+            calendar_block,
+        })
+    }
+
+    /// Scheduling calendar for a region, if one is set.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_calendar(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<Option<RegionCalendar>> {
+        let writer = region_writer(ctx, &region).await?;
+        let calendar = writer
+            .get_region_calendar(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(calendar.map(RegionCalendar::from))
+    }
+
+    /// Every region's scout run schedule with its next `preview` runs (default 5).
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_schedules(
+        &self,
+        ctx: &Context<'_>,
+        preview: Option<u32>,
+    ) -> Result<Vec<RegionSchedule>> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let now = Utc::now();
+        let n = preview.unwrap_or(5) as usize;
+        let schedules = writer.list_region_schedules().await?;
+        Ok(schedules
+            .into_iter()
+            .map(|(region, s)| RegionSchedule {
+                next_runs: if s.paused { Vec::new() } else { s.next_runs(now, n) },
+                region,
+                task_id: s.task_id,
+                cron: s.cron,
+                timezone: s.timezone,
+                paused: s.paused,
+                last_run_at: s.last_run_at,
+                updated_at: s.updated_at,
+            })
+            .collect())
+    }
+
+    /// Regions created through `createRegion`, newest first, with their
+    /// onboarding progress and readiness checklist.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_onboardings(&self, ctx: &Context<'_>) -> Result<Vec<AdminRegionOnboarding>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let onboardings = rootsignal_graph::onboarding::onboardings(client).await?;
+        Ok(onboardings.into_iter().map(AdminRegionOnboarding::from).collect())
+    }
+
+    /// Validate a cron expression and preview its next `count` runs (default
+    /// 10, at most 50). Errors explain what is wrong with the expression.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_preview_schedule(
+        &self,
+        cron: String,
+        timezone: String,
+        count: Option<u32>,
+    ) -> Result<Vec<DateTime<Utc>>> {
+        rootsignal_common::preview_schedule(&cron, &timezone, Utc::now(), count.unwrap_or(10) as usize)
+            .map_err(async_graphql::Error::new)
+    }
+
+    /// Story energy weights for a region; the defaults when none are set.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_energy_weights(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<RegionEnergyWeights> {
+        let client = &region_graph(ctx, &region).await?;
+        let weights =
+            rootsignal_graph::story_energy::region_energy_weights(client, &rootsignal_common::slugify(&region))
+                .await?;
+        Ok(RegionEnergyWeights {
+            is_default: weights.is_none(),
+            weights: weights.unwrap_or_default().into(),
         })
     }
 
+    /// Open-data (311) datasets a region ingests.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_open_data(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<Vec<OpenDataDataset>> {
+        let writer = region_writer(ctx, &region).await?;
+        let open_data = writer
+            .get_region_open_data(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(open_data
+            .map(|o| o.datasets.into_iter().map(OpenDataDataset::from).collect())
+            .unwrap_or_default())
+    }
+
+    /// Meeting bodies whose agendas a region follows.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_agendas(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<RegionAgendas> {
+        let writer = region_writer(ctx, &region).await?;
+        let agendas = writer
+            .get_region_agendas(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(RegionAgendas::from(agendas.unwrap_or_default()))
+    }
+
+    /// Transit agencies and school districts whose alerts a region follows.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_service_alerts(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<RegionServiceAlerts> {
+        let writer = region_writer(ctx, &region).await?;
+        let alerts = writer
+            .get_region_service_alerts(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(RegionServiceAlerts::from(alerts.unwrap_or_default()))
+    }
+
+    /// Census counties a region's situation context comes from, if configured.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_demographics(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<Option<RegionDemographics>> {
+        let writer = region_writer(ctx, &region).await?;
+        let demographics = writer
+            .get_region_demographics(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(demographics.map(|d| RegionDemographics {
+            state_fips: d.state_fips,
+            county_fips: d.county_fips,
+            acs_year: d.acs_year,
+        }))
+    }
+
+    /// Schools, routes, wards and parks a region's signals are linked to.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_region_registry(&self, ctx: &Context<'_>, region: String) -> Result<Vec<RegistryEntity>> {
+        let writer = region_writer(ctx, &region).await?;
+        let registry = writer
+            .get_region_registry(&rootsignal_common::slugify(&region))
+            .await?;
+        Ok(registry.entities.into_iter().map(RegistryEntity::from).collect())
+    }
+
+    /// Category labels no topic category matched, most used first; merge
+    /// them into a category with `mergeTopicCategory`.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_unmapped_categories(&self, ctx: &Context<'_>) -> Result<Vec<LabelCount>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let taxonomy = rootsignal_graph::taxonomy::load_taxonomy(client).await?;
+        let labels = rootsignal_graph::taxonomy::unmapped_labels(client, &taxonomy).await?;
+        Ok(labels
+            .into_iter()
+            .map(|(label, count)| LabelCount { label, count: count as u64 })
+            .collect())
+    }
+
     /// List supervisor validation findings for a region.
     #[graphql(guard = "AdminGuard")]
     async fn supervisor_findings(
@@ -643,10 +1115,7 @@ impl QueryRoot {
         region: String,
         limit: Option<u32>,
     ) -> Result<Vec<ScoutRun>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool.as_ref().ok_or_else(|| {
-            async_graphql::Error::new("Postgres not configured")
-        })?;
+        let pool = read_pool(ctx).await?;
         let limit = limit.unwrap_or(20).min(100);
 
         let rows = crate::db::scout_run::list_by_region(pool, &region, limit)
@@ -663,10 +1132,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         run_id: String,
     ) -> Result<Option<ScoutRun>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool.as_ref().ok_or_else(|| {
-            async_graphql::Error::new("Postgres not configured")
-        })?;
+        let pool = read_pool(ctx).await?;
 
         let row = crate::db::scout_run::find_by_id(pool, &run_id)
             .await
@@ -675,69 +1141,468 @@ impl QueryRoot {
         Ok(row.map(ScoutRun::from))
     }
 
-    /// Aggregate summary of supervisor findings for a region.
+    /// Postgres read replica status: its last measured lag and whether
+    /// read-only queries currently go to it. Null when Postgres isn't configured.
     #[graphql(guard = "AdminGuard")]
-    async fn supervisor_summary(
-        &self,
-        ctx: &Context<'_>,
-        region: String,
-    ) -> Result<SupervisorSummary> {
-        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
-        let summary = reader.validation_issue_summary(&region).await?;
-
-        Ok(SupervisorSummary {
-            total_open: summary.total_open,
-            total_resolved: summary.total_resolved,
-            total_dismissed: summary.total_dismissed,
-            count_by_type: summary
-                .count_by_type
-                .into_iter()
-                .map(|(label, count)| FindingCount { label, count })
-                .collect(),
-            count_by_severity: summary
-                .count_by_severity
-                .into_iter()
-                .map(|(label, count)| FindingCount { label, count })
-                .collect(),
+    async fn admin_database_replication(&self, ctx: &Context<'_>) -> Option<DatabaseReplication> {
+        let pools = ctx.data_unchecked::<Option<crate::db::PgPools>>().as_ref()?;
+        let lag = pools.lag().await;
+        Some(DatabaseReplication {
+            replica_configured: pools.has_replica(),
+            reading_from_replica: pools.reading_from_replica().await,
+            lag_seconds: lag.seconds,
+            max_lag_seconds: pools.max_lag().as_secs_f64(),
+            checked_at: lag.checked_at,
+            error: lag.error,
         })
     }
 
-    /// List scout tasks, optionally filtered by status.
+    /// Address to subscribe to a newsletter so its issues reach the scout.
+    /// Null when newsletter ingestion isn't configured.
     #[graphql(guard = "AdminGuard")]
-    async fn admin_scout_tasks(
-        &self,
-        ctx: &Context<'_>,
-        status: Option<String>,
-        limit: Option<i32>,
-    ) -> Result<Vec<GqlScoutTask>> {
-        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
-        let lim = limit.unwrap_or(50).min(200) as u32;
-        let tasks = writer
-            .list_scout_tasks(status.as_deref(), lim)
-            .await
-            .map_err(|e| async_graphql::Error::new(format!("Failed to list scout tasks: {e}")))?;
-
-        Ok(tasks
-            .into_iter()
-            .map(GqlScoutTask::from_task)
-            .collect())
+    async fn admin_newsletter_address(&self, ctx: &Context<'_>) -> Option<String> {
+        let config = ctx.data_unchecked::<Arc<Config>>();
+        config
+            .inbound_email_address
+            .clone()
+            .filter(|_| config.inbound_email_secret.is_some())
     }
 
-    // ========== Archive queries ==========
+    /// Discord and Matrix room feeds, oldest first, with their delivery
+    /// queue when Postgres is configured.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_room_feeds(&self, ctx: &Context<'_>) -> Result<Vec<GqlRoomFeed>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let pool = read_pool(ctx).await.ok();
+        let now = Utc::now();
+        let mut feeds = Vec::new();
+        for feed in rootsignal_graph::room_feeds::room_feeds(client).await? {
+            let id = feed.id;
+            let mut gql = GqlRoomFeed::from(feed);
+            if let Some(pool) = pool {
+                match crate::db::models::room_delivery::stats(pool, id, now).await {
+                    Ok(stats) => gql.delivery = Some(stats.into()),
+                    Err(e) => tracing::warn!(feed = %id, error = %e, "Failed to load room feed delivery stats"),
+                }
+            }
+            feeds.push(gql);
+        }
+        Ok(feeds)
+    }
 
-    /// Total row counts for all archive content types.
+    /// Stored source credentials and their health. Never includes the values.
     #[graphql(guard = "AdminGuard")]
-    async fn admin_archive_counts(&self, ctx: &Context<'_>) -> Result<GqlArchiveCounts> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+    async fn admin_source_credentials(&self, ctx: &Context<'_>) -> Result<Vec<SourceCredentialInfo>> {
+        let store = ctx.data_unchecked::<Option<Arc<rootsignal_archive::CredentialStore>>>();
+        let store = store.as_ref().ok_or_else(|| {
+            async_graphql::Error::new("Source credentials not configured (set SOURCE_CREDENTIALS_KEY)")
+        })?;
 
-        let counts = crate::db::archive::count_all(pool)
+        let rows = store
+            .list()
             .await
-            .map_err(|e| async_graphql::Error::new(format!("Failed to query archive counts: {e}")))?;
+            .map_err(|e| async_graphql::Error::new(format!("Failed to list credentials: {e}")))?;
 
-        Ok(GqlArchiveCounts {
+        Ok(rows.into_iter().map(SourceCredentialInfo::from).collect())
+    }
+
+    /// List dead-lettered workflow items, newest first. Filter by status
+    /// (`pending`, `replayed`, `discarded`); defaults to all.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_dead_letters(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<DeadLetter>> {
+        let pool = read_pool(ctx).await?;
+        let status = match status.as_deref() {
+            None => None,
+            Some(s) => Some(rootsignal_common::dead_letter::DeadLetterStatus::parse(s).ok_or_else(|| {
+                async_graphql::Error::new(format!("Unknown dead letter status: {s}"))
+            })?),
+        };
+        let limit = limit.unwrap_or(50).min(200);
+
+        let rows = rootsignal_common::dead_letter::list(pool, status, limit)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to query dead letters: {e}")))?;
+
+        Ok(rows.into_iter().map(DeadLetter::from).collect())
+    }
+
+    /// List negative knowledge entries (debunked, spam, duplicate, rejected
+    /// content the scout suppresses), newest first. With a region, only
+    /// entries that apply there; expired entries only when asked for.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_negative_knowledge(
+        &self,
+        ctx: &Context<'_>,
+        region: Option<String>,
+        include_expired: Option<bool>,
+        limit: Option<u32>,
+    ) -> Result<Vec<NegativeKnowledgeEntry>> {
+        let pool = read_pool(ctx).await?;
+        let rows = rootsignal_common::negative_knowledge::list(
+            pool,
+            region.as_deref(),
+            include_expired.unwrap_or(false),
+            limit.unwrap_or(100),
+        )
+        .await
+        .map_err(|e| async_graphql::Error::new(format!("Failed to query negative knowledge: {e}")))?;
+
+        Ok(rows.into_iter().map(NegativeKnowledgeEntry::from).collect())
+    }
+
+    /// Scout feature flags with their effective value and the layer it came
+    /// from: `default`, `global` or `region`. Without a region, global
+    /// overrides only.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_feature_flags(&self, ctx: &Context<'_>, region: Option<String>) -> Result<Vec<FeatureFlagState>> {
+        let pool = read_pool(ctx).await?;
+        let overrides = rootsignal_common::feature_flags::list_overrides(pool, region.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to query feature flags: {e}")))?;
+
+        Ok(rootsignal_common::feature_flags::resolve_states(&overrides, region.as_deref())
+            .into_iter()
+            .map(|state| FeatureFlagState {
+                flag: state.flag.as_str().to_string(),
+                description: state.flag.description().to_string(),
+                enabled: state.enabled,
+                default_enabled: state.flag.default_enabled(),
+                source: state.source.as_str().to_string(),
+            })
+            .collect())
+    }
+
+    /// How synthesis splits its remaining budget: the allocation mode and
+    /// each phase's priority, with the layer each came from. Without a
+    /// region, the global policy only.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_synthesis_budget(&self, ctx: &Context<'_>, region: Option<String>) -> Result<SynthesisBudgetState> {
+        let pool = read_pool(ctx).await?;
+        let overrides = rootsignal_common::synthesis_budget::list_overrides(pool, region.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to query synthesis budget policy: {e}")))?;
+
+        let policy = rootsignal_common::synthesis_budget::SynthesisBudgetPolicy::resolve(&overrides, region.as_deref());
+        Ok(SynthesisBudgetState {
+            mode: policy.mode.as_str().to_string(),
+            mode_source: policy.mode_source.as_str().to_string(),
+            phases: policy
+                .priorities
+                .into_iter()
+                .map(|(phase, priority, source)| SynthesisPhasePriority {
+                    phase: phase.as_str().to_string(),
+                    priority,
+                    default_priority: phase.default_priority(),
+                    source: source.as_str().to_string(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Extraction prompts in the registry, newest first, with their shadow
+    /// reports. With a region, only prompts that apply there.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_extraction_prompts(
+        &self,
+        ctx: &Context<'_>,
+        region: Option<String>,
+        include_retired: Option<bool>,
+    ) -> Result<Vec<ExtractionPromptEntry>> {
+        let pool = read_pool(ctx).await?;
+        let prompts = rootsignal_common::extraction_prompts::list(pool, region.as_deref(), include_retired.unwrap_or(false))
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to query extraction prompts: {e}")))?;
+
+        Ok(prompts.into_iter().map(ExtractionPromptEntry::from).collect())
+    }
+
+    /// Aggregate summary of supervisor findings for a region.
+    #[graphql(guard = "AdminGuard")]
+    async fn supervisor_summary(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+    ) -> Result<SupervisorSummary> {
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let summary = reader.validation_issue_summary(&region).await?;
+
+        Ok(SupervisorSummary {
+            total_open: summary.total_open,
+            total_resolved: summary.total_resolved,
+            total_dismissed: summary.total_dismissed,
+            count_by_type: summary
+                .count_by_type
+                .into_iter()
+                .map(|(label, count)| FindingCount { label, count })
+                .collect(),
+            count_by_severity: summary
+                .count_by_severity
+                .into_iter()
+                .map(|(label, count)| FindingCount { label, count })
+                .collect(),
+        })
+    }
+
+    /// List scout tasks, optionally filtered by status.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_scout_tasks(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<Vec<GqlScoutTask>> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let lim = limit.unwrap_or(50).min(200) as u32;
+        let tasks = writer
+            .list_scout_tasks(status.as_deref(), lim)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to list scout tasks: {e}")))?;
+
+        Ok(tasks
+            .into_iter()
+            .map(GqlScoutTask::from_task)
+            .collect())
+    }
+
+    /// Annotations awaiting moderation, oldest first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_annotation_queue(&self, ctx: &Context<'_>, limit: Option<u32>) -> Result<Vec<GqlAnnotation>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let queue = rootsignal_graph::annotations::pending_annotations(client, limit).await?;
+        Ok(queue.into_iter().map(GqlAnnotation::from).collect())
+    }
+
+    /// Sources a region's bootstrap proposed, best-scoring first; only those
+    /// with `status` when given.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_bootstrap_candidates(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        status: Option<GqlReviewStatus>,
+    ) -> Result<Vec<GqlBootstrapCandidate>> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let candidates =
+            rootsignal_graph::bootstrap_review::bootstrap_candidates(&graph, &slug, status.map(Into::into)).await?;
+        Ok(candidates.into_iter().map(GqlBootstrapCandidate::from).collect())
+    }
+
+    /// A region's source backfills, newest request first; only those with
+    /// `status` when given.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_source_backfills(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        status: Option<GqlBackfillStatus>,
+    ) -> Result<Vec<GqlBackfill>> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let backfills = rootsignal_graph::backfill::backfills(&graph, &slug, status.map(Into::into)).await?;
+        Ok(backfills.into_iter().map(GqlBackfill::from).collect())
+    }
+
+    /// A region's situation information requests with their pulled
+    /// evidence, newest first; only those with `status` when given.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_information_requests(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        status: Option<GqlRequestStatus>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlInformationRequest>> {
+        let slug = rootsignal_common::slugify(&region);
+        let graph = region_graph(ctx, &slug).await?;
+        let requests = rootsignal_graph::information_requests::information_requests(
+            &graph,
+            status.map(Into::into),
+            limit.unwrap_or(50).min(200),
+        )
+        .await?;
+        Ok(requests.into_iter().map(GqlInformationRequest::from).collect())
+    }
+
+    /// Most recent investigation reports across all signals, newest first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_investigations(&self, ctx: &Context<'_>, limit: Option<u32>) -> Result<Vec<GqlInvestigation>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let reports = rootsignal_graph::investigations::recent_investigations(client, limit).await?;
+        Ok(reports.into_iter().map(GqlInvestigation::from).collect())
+    }
+
+    /// Automated decisions about a signal, newest first. Kept after the
+    /// signal is merged away or expired.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_signal_decisions(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlDecision>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let decisions = rootsignal_graph::decisions::decisions_for_signal(client, id, limit).await?;
+        Ok(decisions.into_iter().map(GqlDecision::from).collect())
+    }
+
+    /// A region's investigation queue in the order the next run would take
+    /// it (pinned first, then by priority score), optionally one state only.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_investigation_queue(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        state: Option<GqlQueueState>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlQueueEntry>> {
+        let client = region_graph(ctx, &region).await?;
+        let limit = limit.unwrap_or(50).min(500);
+        let states: Vec<_> = state.into_iter().map(Into::into).collect();
+        let mut entries = rootsignal_graph::investigation_queue::entries(&client, &region, &states, 1000).await?;
+        let now = Utc::now();
+        entries.sort_by(|a, b| rootsignal_common::investigation_queue::by_priority(a, b, now));
+        Ok(entries.into_iter().take(limit as usize).map(GqlQueueEntry::from).collect())
+    }
+
+    /// Situations by clustering quality, least cohesive first. With
+    /// `breached_only`, just those the weaver will re-weave.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_situation_quality(
+        &self,
+        ctx: &Context<'_>,
+        breached_only: Option<bool>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlSituationQuality>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let report =
+            rootsignal_graph::situation_quality::quality_report(client, breached_only.unwrap_or(false), limit).await?;
+        Ok(report.into_iter().map(GqlSituationQuality::from).collect())
+    }
+
+    /// Why a URL or description isn't on the map (or confirmation that it
+    /// is): traces it through discovery, fetching, extraction and the
+    /// publication gates using the run logs, archive, graph and negative
+    /// knowledge.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_why_not(
+        &self,
+        ctx: &Context<'_>,
+        region: String,
+        url: Option<String>,
+        description: Option<String>,
+    ) -> Result<GqlWhyNot> {
+        let url = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+        let description = description.map(|d| d.trim().to_string()).filter(|d| !d.is_empty());
+        if url.is_none() && description.is_none() {
+            return Err(async_graphql::Error::new("Provide a url or a description"));
+        }
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let pool = read_pool(ctx).await?;
+        let facts = crate::why_not::gather_facts(pool, client, &region, url.as_deref(), description.as_deref())
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to trace: {e}")))?;
+        let why = rootsignal_common::why_not::explain(&facts);
+        Ok(GqlWhyNot::new(why, facts))
+    }
+
+    /// Open correction requests, harmful reports first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_correction_queue(&self, ctx: &Context<'_>, limit: Option<u32>) -> Result<Vec<GqlCorrectionRequest>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let queue = rootsignal_graph::corrections::open_corrections(client, limit).await?;
+        Ok(queue.into_iter().map(GqlCorrectionRequest::from).collect())
+    }
+
+    /// Retention policy for every data class.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_retention_policies(&self, ctx: &Context<'_>) -> Result<Vec<GqlRetentionPolicy>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let policies = rootsignal_graph::retention::retention_policies(client).await?;
+        Ok(policies.into_iter().map(GqlRetentionPolicy::from).collect())
+    }
+
+    /// Legal holds in force, newest first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_legal_holds(&self, ctx: &Context<'_>) -> Result<Vec<GqlLegalHold>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let holds = rootsignal_graph::retention::active_legal_holds(client).await?;
+        Ok(holds.into_iter().map(GqlLegalHold::from).collect())
+    }
+
+    /// Recent retention purges and dry runs with their volumes, newest first.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_retention_runs(&self, ctx: &Context<'_>, limit: Option<u32>) -> Result<Vec<GqlRetentionRun>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(20).min(100);
+        let runs = rootsignal_graph::retention::recent_purges(client, limit).await?;
+        Ok(runs.into_iter().map(GqlRetentionRun::from).collect())
+    }
+
+    /// Fields a signal shows to a narrower audience than its default, set by
+    /// the content policy or an admin.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_signal_field_demotions(&self, ctx: &Context<'_>, id: Uuid) -> Result<Vec<GqlFieldDemotion>> {
+        let writer = ctx.data_unchecked::<Arc<GraphWriter>>();
+        let visibility = writer.field_visibility(id).await?.unwrap_or_default();
+        Ok(visibility
+            .demotions()
+            .map(|(field, tier)| GqlFieldDemotion { field: field.into(), visibility: tier.into() })
+            .collect())
+    }
+
+    /// Data-subject requests, newest first, optionally filtered by status.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_subject_requests(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<GqlSubjectRequestStatus>,
+        limit: Option<u32>,
+    ) -> Result<Vec<GqlSubjectRequest>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let limit = limit.unwrap_or(50).min(200);
+        let requests =
+            rootsignal_graph::subject_requests::list_requests(client, status.map(Into::into), limit).await?;
+        Ok(requests.into_iter().map(GqlSubjectRequest::from).collect())
+    }
+
+    /// A request with every signal, actor and evidence snippet that mentions
+    /// its terms, for review before it is executed.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_subject_request_review(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<GqlSubjectReview>> {
+        let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+        let Some(request) = rootsignal_graph::subject_requests::get_request(client, id).await? else {
+            return Ok(None);
+        };
+        let matches = rootsignal_graph::subject_requests::find_matches(client, &request.terms).await?;
+        Ok(Some(GqlSubjectReview {
+            request: request.into(),
+            matches: matches.into_iter().map(GqlSubjectMatch::from).collect(),
+        }))
+    }
+
+    // ========== Archive queries ==========
+
+    /// Total row counts for all archive content types.
+    #[graphql(guard = "AdminGuard")]
+    async fn admin_archive_counts(&self, ctx: &Context<'_>) -> Result<GqlArchiveCounts> {
+        let pool = read_pool(ctx).await?;
+
+        let counts = crate::db::archive::count_all(pool)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to query archive counts: {e}")))?;
+
+        Ok(GqlArchiveCounts {
             posts: counts.posts,
             short_videos: counts.short_videos,
             stories: counts.stories,
@@ -756,10 +1621,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         days: Option<u32>,
     ) -> Result<Vec<GqlArchiveVolumeDay>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let days = days.unwrap_or(7);
         let rows = crate::db::archive::volume_by_day(pool, days)
@@ -789,10 +1651,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchivePost>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_posts(pool, limit)
@@ -822,10 +1681,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveShortVideo>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_short_videos(pool, limit)
@@ -852,10 +1708,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveStory>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_stories(pool, limit)
@@ -883,10 +1736,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveLongVideo>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_long_videos(pool, limit)
@@ -913,10 +1763,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchivePage>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_pages(pool, limit)
@@ -941,10 +1788,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveFeed>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_feeds(pool, limit)
@@ -970,10 +1814,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveSearchResult>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_search_results(pool, limit)
@@ -998,10 +1839,7 @@ impl QueryRoot {
         ctx: &Context<'_>,
         limit: Option<u32>,
     ) -> Result<Vec<GqlArchiveFile>> {
-        let pool = ctx.data_unchecked::<Option<sqlx::PgPool>>();
-        let pool = pool
-            .as_ref()
-            .ok_or_else(|| async_graphql::Error::new("Postgres not configured"))?;
+        let pool = read_pool(ctx).await?;
 
         let limit = limit.unwrap_or(50);
         let rows = crate::db::archive::recent_files(pool, limit)
@@ -1033,6 +1871,8 @@ pub struct MeResult {
 
 #[derive(SimpleObject)]
 pub struct AdminDashboardData {
+    /// When the aggregates below were materialized.
+    pub stats_computed_at: DateTime<Utc>,
     pub total_signals: u64,
     pub total_stories: u64,
     pub total_actors: u64,
@@ -1047,6 +1887,10 @@ pub struct AdminDashboardData {
     pub freshness_distribution: Vec<LabelCount>,
     pub confidence_distribution: Vec<LabelCount>,
     pub unmet_tensions: Vec<AdminTensionRow>,
+    /// Daily tension response coverage, oldest first.
+    pub coverage_by_day: Vec<AdminCoverageDay>,
+    /// Daily judge audit results for new signals, oldest first.
+    pub audit_by_day: Vec<AdminAuditDay>,
     pub top_sources: Vec<AdminSourceRow>,
     pub bottom_sources: Vec<AdminSourceRow>,
     pub extraction_yield: Vec<AdminYieldRow>,
@@ -1060,6 +1904,8 @@ pub struct RegionScoutStatus {
     pub last_scouted: Option<DateTime<Utc>>,
     pub sources_due: u32,
     pub running: bool,
+    /// Why the region calendar is blocking scouting right now, if it is.
+    pub calendar_block: Option<String>,
 }
 
 #[derive(SimpleObject)]
@@ -1090,6 +1936,28 @@ pub struct AdminTensionRow {
     pub severity: String,
     pub category: Option<String>,
     pub what_would_help: Option<String>,
+    /// Response coverage, 0.0–1.0; None until the supervisor first scores it.
+    pub coverage: Option<f64>,
+    pub escalated: bool,
+}
+
+#[derive(SimpleObject)]
+pub struct AdminCoverageDay {
+    pub day: String,
+    pub tensions: u32,
+    pub avg_coverage: f64,
+    pub unmet: u32,
+    pub escalated: u32,
+}
+
+#[derive(SimpleObject)]
+pub struct AdminAuditDay {
+    pub day: String,
+    pub audited: u32,
+    /// Mean judge score, 0.0–1.0.
+    pub mean_score: f64,
+    pub critical: u32,
+    pub suppressed: u32,
 }
 
 #[derive(SimpleObject)]
@@ -1121,6 +1989,7 @@ pub struct AdminGapRow {
 pub struct AdminSource {
     pub id: Uuid,
     pub url: String,
+    pub canonical_key: String,
     pub canonical_value: String,
     pub source_label: String,
     pub weight: f64,
@@ -1129,8 +1998,502 @@ pub struct AdminSource {
     pub discovery_method: String,
     pub last_scraped: Option<DateTime<Utc>>,
     pub cadence_hours: f64,
+    /// When the scheduler will next pick this source up; None if dormant.
+    pub next_run_at: Option<DateTime<Utc>>,
     pub signals_produced: u32,
     pub active: bool,
+    /// "established", "probation", "graduated" or "failed".
+    pub trial_state: String,
+}
+
+/// A source leaving its trial period.
+#[derive(SimpleObject)]
+pub struct AdminTrialTransition {
+    pub canonical_key: String,
+    pub from: String,
+    pub to: String,
+    pub reason: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A region's scout run schedule.
+#[derive(SimpleObject)]
+pub struct RegionSchedule {
+    pub region: String,
+    pub task_id: String,
+    /// Five-field cron expression, read in `timezone`.
+    pub cron: String,
+    pub timezone: String,
+    pub paused: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+    /// Upcoming runs; empty while paused.
+    pub next_runs: Vec<DateTime<Utc>>,
+}
+
+/// A region's self-serve onboarding.
+#[derive(SimpleObject)]
+pub struct AdminRegionOnboarding {
+    pub region: String,
+    pub name: String,
+    pub task_id: String,
+    pub timezone: String,
+    /// bootstrapping, seeding, dry_run, awaiting_review, active or failed.
+    pub stage: String,
+    pub progress: String,
+    pub dry_run_fetched: Option<u32>,
+    pub dry_run_failed: Option<u32>,
+    pub checklist: Vec<AdminReadinessCheck>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::RegionOnboarding> for AdminRegionOnboarding {
+    fn from(o: rootsignal_common::RegionOnboarding) -> Self {
+        Self {
+            region: o.region,
+            name: o.name,
+            task_id: o.task_id,
+            timezone: o.timezone,
+            stage: o.stage.as_str().to_string(),
+            progress: o.progress,
+            dry_run_fetched: o.dry_run.map(|r| r.fetched),
+            dry_run_failed: o.dry_run.map(|r| r.failed),
+            checklist: o
+                .checklist
+                .into_iter()
+                .map(|c| AdminReadinessCheck {
+                    name: c.name,
+                    passed: c.passed,
+                    detail: c.detail,
+                })
+                .collect(),
+            error: o.error,
+            created_at: o.created_at,
+            updated_at: o.updated_at,
+        }
+    }
+}
+
+/// One item of a region's readiness checklist.
+#[derive(SimpleObject)]
+pub struct AdminReadinessCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Postgres read replica status.
+#[derive(SimpleObject)]
+pub struct DatabaseReplication {
+    pub replica_configured: bool,
+    /// False while the replica lags past `max_lag_seconds`; reads then use the primary.
+    pub reading_from_replica: bool,
+    /// Seconds the replica trails the primary at the last check.
+    pub lag_seconds: Option<f64>,
+    pub max_lag_seconds: f64,
+    pub checked_at: Option<DateTime<Utc>>,
+    /// Why the last lag check failed, if it did.
+    pub error: Option<String>,
+}
+
+/// Relative weight of each story energy component; only ratios matter.
+#[derive(SimpleObject)]
+pub struct StoryEnergyWeights {
+    pub activity: f64,
+    pub severity: f64,
+    pub corroboration: f64,
+    pub heat: f64,
+}
+
+impl From<rootsignal_common::StoryEnergyWeights> for StoryEnergyWeights {
+    fn from(w: rootsignal_common::StoryEnergyWeights) -> Self {
+        Self {
+            activity: w.activity,
+            severity: w.severity,
+            corroboration: w.corroboration,
+            heat: w.heat,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct RegionEnergyWeights {
+    pub weights: StoryEnergyWeights,
+    /// True when the region has no weights of its own.
+    pub is_default: bool,
+}
+
+/// Region scheduling calendar: quiet hours (local time), blackouts, bursts.
+#[derive(SimpleObject)]
+pub struct RegionCalendar {
+    pub timezone: String,
+    pub quiet_start_hour: Option<u32>,
+    pub quiet_end_hour: Option<u32>,
+    pub blackouts: Vec<CalendarWindow>,
+    pub bursts: Vec<BurstWindow>,
+    /// Why scouting is blocked right now, if it is.
+    pub blocked_now: Option<String>,
+    /// Earliest moment scouting is allowed (now, when not blocked).
+    pub next_allowed: DateTime<Utc>,
+}
+
+#[derive(SimpleObject)]
+pub struct CalendarWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub reason: String,
+}
+
+#[derive(SimpleObject)]
+pub struct BurstWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub reason: String,
+    pub cadence_factor: f64,
+}
+
+impl From<rootsignal_common::RegionCalendar> for RegionCalendar {
+    fn from(c: rootsignal_common::RegionCalendar) -> Self {
+        let now = Utc::now();
+        Self {
+            blocked_now: c.blocked_at(now).map(|b| b.to_string()),
+            next_allowed: c.next_allowed(now),
+            timezone: c.timezone,
+            quiet_start_hour: c.quiet_hours.map(|q| u32::from(q.start_hour)),
+            quiet_end_hour: c.quiet_hours.map(|q| u32::from(q.end_hour)),
+            blackouts: c
+                .blackouts
+                .into_iter()
+                .map(|w| CalendarWindow {
+                    starts_at: w.starts_at,
+                    ends_at: w.ends_at,
+                    reason: w.reason,
+                })
+                .collect(),
+            bursts: c
+                .bursts
+                .into_iter()
+                .map(|b| BurstWindow {
+                    starts_at: b.starts_at,
+                    ends_at: b.ends_at,
+                    reason: b.reason,
+                    cadence_factor: b.cadence_factor,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An open-data dataset a region ingests, and how its categories map to signals.
+#[derive(SimpleObject)]
+pub struct OpenDataDataset {
+    pub name: String,
+    pub portal: GqlPortalKind,
+    pub base_url: String,
+    pub dataset_id: String,
+    pub id_field: String,
+    pub category_field: String,
+    pub opened_at_field: String,
+    pub categories: Vec<OpenDataCategoryRule>,
+    pub max_records: u32,
+    pub lookback_days: u32,
+}
+
+#[derive(SimpleObject)]
+pub struct OpenDataCategoryRule {
+    pub contains: String,
+    pub signal: GqlOpenDataSignal,
+    pub severity: GqlSeverity,
+}
+
+impl From<rootsignal_common::OpenDataDataset> for OpenDataDataset {
+    fn from(d: rootsignal_common::OpenDataDataset) -> Self {
+        Self {
+            name: d.name,
+            portal: d.portal.into(),
+            base_url: d.base_url,
+            dataset_id: d.dataset_id,
+            id_field: d.fields.id,
+            category_field: d.fields.category,
+            opened_at_field: d.fields.opened_at,
+            categories: d
+                .categories
+                .into_iter()
+                .map(|r| OpenDataCategoryRule {
+                    contains: r.contains,
+                    signal: r.signal.into(),
+                    severity: r.severity.into(),
+                })
+                .collect(),
+            max_records: d.max_records,
+            lookback_days: d.lookback_days,
+        }
+    }
+}
+
+/// Meeting bodies a region follows and the topics that flag agenda items.
+#[derive(SimpleObject)]
+pub struct RegionAgendas {
+    pub bodies: Vec<AgendaBody>,
+    pub high_impact_topics: Vec<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct AgendaBody {
+    pub name: String,
+    pub legistar_client: String,
+    pub body_name: Option<String>,
+    pub timezone: String,
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub lookahead_days: u32,
+    pub lookback_days: u32,
+}
+
+impl From<rootsignal_common::RegionAgendas> for RegionAgendas {
+    fn from(a: rootsignal_common::RegionAgendas) -> Self {
+        Self {
+            bodies: a
+                .bodies
+                .into_iter()
+                .map(|b| AgendaBody {
+                    name: b.name,
+                    legistar_client: b.legistar_client,
+                    body_name: b.body_name,
+                    timezone: b.timezone,
+                    location_name: b.location_name,
+                    latitude: b.latitude,
+                    longitude: b.longitude,
+                    lookahead_days: b.lookahead_days,
+                    lookback_days: b.lookback_days,
+                })
+                .collect(),
+            high_impact_topics: a.high_impact_topics,
+        }
+    }
+}
+
+/// Transit agencies and school districts a region follows for service alerts.
+#[derive(SimpleObject)]
+pub struct RegionServiceAlerts {
+    pub transit_agencies: Vec<TransitAgency>,
+    pub school_districts: Vec<SchoolDistrict>,
+}
+
+#[derive(SimpleObject)]
+pub struct TransitAgency {
+    pub name: String,
+    pub alerts_url: String,
+    pub routes: Vec<String>,
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(SimpleObject)]
+pub struct SchoolDistrict {
+    pub name: String,
+    pub feed_url: String,
+    pub schools: Vec<String>,
+    pub alert_keywords: Vec<String>,
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl From<rootsignal_common::RegionServiceAlerts> for RegionServiceAlerts {
+    fn from(a: rootsignal_common::RegionServiceAlerts) -> Self {
+        Self {
+            transit_agencies: a
+                .transit_agencies
+                .into_iter()
+                .map(|t| TransitAgency {
+                    name: t.name,
+                    alerts_url: t.alerts_url,
+                    routes: t.routes,
+                    location_name: t.location_name,
+                    latitude: t.latitude,
+                    longitude: t.longitude,
+                })
+                .collect(),
+            school_districts: a
+                .school_districts
+                .into_iter()
+                .map(|d| SchoolDistrict {
+                    name: d.name,
+                    feed_url: d.feed_url,
+                    schools: d.schools,
+                    alert_keywords: d.alert_keywords,
+                    location_name: d.location_name,
+                    latitude: d.latitude,
+                    longitude: d.longitude,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct RegionDemographics {
+    pub state_fips: String,
+    pub county_fips: Vec<String>,
+    pub acs_year: u16,
+}
+
+/// An entity in a region's registry.
+#[derive(SimpleObject)]
+pub struct RegistryEntity {
+    pub kind: GqlRegistryKind,
+    pub name: String,
+    pub aliases: Vec<String>,
+    /// Key within the region ("route-21").
+    pub key: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Boundary ring as [lat, lng] pairs.
+    pub boundary: Option<Vec<Vec<f64>>>,
+    /// Boundary rings of a ward or district in several pieces.
+    pub boundary_parts: Option<Vec<Vec<Vec<f64>>>>,
+}
+
+impl From<rootsignal_common::RegistryEntity> for RegistryEntity {
+    fn from(e: rootsignal_common::RegistryEntity) -> Self {
+        let key = e.key();
+        let ring_to_vec = |ring: Vec<[f64; 2]>| ring.iter().map(|p| p.to_vec()).collect::<Vec<_>>();
+        let (point, boundary, boundary_parts) = match e.geometry {
+            Some(rootsignal_common::EntityGeometry::Point { lat, lng }) => (Some((lat, lng)), None, None),
+            Some(rootsignal_common::EntityGeometry::Polygon { ring }) => (None, Some(ring_to_vec(ring)), None),
+            Some(rootsignal_common::EntityGeometry::MultiPolygon { rings }) => {
+                (None, None, Some(rings.into_iter().map(ring_to_vec).collect()))
+            }
+            None => (None, None, None),
+        };
+        Self {
+            kind: e.kind.into(),
+            name: e.name,
+            aliases: e.aliases,
+            key,
+            latitude: point.map(|(lat, _)| lat),
+            longitude: point.map(|(_, lng)| lng),
+            boundary,
+            boundary_parts,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DistrictSummary {
+    pub region: String,
+    pub kind: GqlRegistryKind,
+    pub name: String,
+    /// None when the district has too few signals to publish a count.
+    pub signal_count: Option<u32>,
+    /// Signals folded out of `signal_counts`: types too small to show alone
+    /// or including sensitive signals.
+    pub other_count: u32,
+    pub signal_counts: Vec<SignalTypeCount>,
+    pub top_tensions: Vec<GqlSignal>,
+    pub stories: Vec<GqlStory>,
+    pub situations: Vec<GqlSituation>,
+}
+
+/// Published region-wide counts.
+#[derive(SimpleObject)]
+pub struct PublicRegionStats {
+    pub computed_at: DateTime<Utc>,
+    /// None when the region has too few signals to publish a count.
+    pub signal_count: Option<u32>,
+    pub other_count: u32,
+    /// By signal type.
+    pub signal_counts: Vec<LabelCount>,
+    /// Live stories.
+    pub story_count: u64,
+    /// Live stories updated in the last 30 days.
+    pub story_count_by_category: Vec<LabelCount>,
+}
+
+/// Published methodology stats for a region.
+#[derive(SimpleObject)]
+pub struct RegionTransparency {
+    pub computed_at: DateTime<Utc>,
+    pub active_sources: u64,
+    /// Active sources by channel: web, search, social, feed, ...
+    pub source_composition: Vec<LabelCount>,
+    /// Share of extracted signals kept rather than merged away or removed;
+    /// None before anything was extracted.
+    pub extraction_acceptance_rate: Option<f64>,
+    /// Correction requests by status.
+    pub correction_counts: Vec<LabelCount>,
+    /// Signals held back for safety, by category.
+    pub suppression_counts: Vec<LabelCount>,
+    pub suppressed_other: u32,
+    /// None when too few signals were held back to publish a count.
+    pub suppressed_total: Option<u32>,
+    pub last_run: Option<PublicRunMethodology>,
+}
+
+/// What the region's latest scout run spent and ran with.
+#[derive(SimpleObject)]
+pub struct PublicRunMethodology {
+    pub finished_at: DateTime<Utc>,
+    pub spent_cents: u64,
+    /// 0 when the budget is unlimited.
+    pub budget_cents: u64,
+    /// The extraction prompt in use: a registry prompt's name or "built-in".
+    pub prompt_version: String,
+    pub prompt_live_since: Option<DateTime<Utc>>,
+}
+
+#[derive(SimpleObject)]
+pub struct SignalTypeCount {
+    pub signal_type: SignalType,
+    pub count: u32,
+}
+
+#[derive(SimpleObject)]
+pub struct TopicCategory {
+    pub slug: String,
+    pub name: String,
+    pub parent: Option<String>,
+    pub aliases: Vec<String>,
+    /// Names from the root down to this category.
+    pub path: Vec<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct WatchlistEntry {
+    pub search: GqlSavedSearch,
+    pub new_match_count: u32,
+    /// Newest first. Signals since hidden or expired are left out.
+    pub new_matches: Vec<GqlSignal>,
+}
+
+#[derive(SimpleObject)]
+pub struct NewSinceResult {
+    /// Where "new" starts: the visitor's last catch-up, or a short lookback.
+    pub since: DateTime<Utc>,
+    /// Pass to `markRead` so signals arriving after this stay new.
+    pub as_of: DateTime<Utc>,
+    /// None when too few signals are new to publish a count.
+    pub total: Option<u32>,
+    /// New signals folded out of `counts`.
+    pub other_count: u32,
+    pub counts: Vec<SignalTypeCount>,
+    pub top: Vec<GqlSignal>,
+}
+
+/// Canonical slugs a `category` filter covers: the category (by slug, name
+/// or alias) and its descendants.
+async fn category_filter(ctx: &Context<'_>, category: Option<String>) -> Result<Option<Vec<String>>> {
+    let Some(category) = category else {
+        return Ok(None);
+    };
+    let client = ctx.data_unchecked::<Arc<rootsignal_graph::GraphClient>>();
+    let taxonomy = rootsignal_graph::taxonomy::load_taxonomy(client).await?;
+    Ok(Some(taxonomy.expand(&category)))
 }
 
 // ========== Archive GQL Types ==========
@@ -1253,6 +2616,14 @@ struct ScoutRun {
     finished_at: DateTime<Utc>,
     stats: ScoutRunStats,
     events: Vec<ScoutRunEvent>,
+    /// The feature flags the run resolved. Empty for runs logged before flags.
+    flags: Vec<ScoutRunFlag>,
+}
+
+#[derive(SimpleObject)]
+struct ScoutRunFlag {
+    flag: String,
+    enabled: bool,
 }
 
 #[derive(SimpleObject)]
@@ -1262,6 +2633,7 @@ struct ScoutRunStats {
     urls_failed: u32,
     signals_extracted: u32,
     signals_deduplicated: u32,
+    signals_suppressed: u32,
     signals_stored: u32,
     social_media_posts: u32,
     expansion_queries_collected: u32,
@@ -1306,6 +2678,9 @@ struct ScoutRunEvent {
     remaining_cents: Option<u64>,
     topics: Option<Vec<String>>,
     posts_found: Option<u32>,
+    /// Judge audit score, 0 to 1.
+    score: Option<f64>,
+    suppressed: Option<bool>,
 }
 
 impl From<ScoutRunRow> for ScoutRun {
@@ -1317,6 +2692,7 @@ impl From<ScoutRunRow> for ScoutRun {
             finished_at: r.finished_at,
             stats: ScoutRunStats::from(r.stats),
             events: r.events.into_iter().map(ScoutRunEvent::from).collect(),
+            flags: r.flags.into_iter().map(|(flag, enabled)| ScoutRunFlag { flag, enabled }).collect(),
         }
     }
 }
@@ -1329,6 +2705,7 @@ impl From<StatsJson> for ScoutRunStats {
             urls_failed: s.urls_failed.unwrap_or(0),
             signals_extracted: s.signals_extracted.unwrap_or(0),
             signals_deduplicated: s.signals_deduplicated.unwrap_or(0),
+            signals_suppressed: s.signals_suppressed.unwrap_or(0),
             signals_stored: s.signals_stored.unwrap_or(0),
             social_media_posts: s.social_media_posts.unwrap_or(0),
             expansion_queries_collected: s.expansion_queries_collected.unwrap_or(0),
@@ -1375,6 +2752,239 @@ impl From<EventJson> for ScoutRunEvent {
             remaining_cents: j.remaining_cents,
             topics: j.topics,
             posts_found: j.posts_found,
+            score: j.score,
+            suppressed: j.suppressed,
+        }
+    }
+}
+
+// ========== Source Credential Types ==========
+
+/// Metadata about a stored source credential. The value is write-only.
+#[derive(SimpleObject)]
+struct SourceCredentialInfo {
+    canonical_key: String,
+    kind: String,
+    header_name: Option<String>,
+    /// `healthy` or `auth_failed` (source rejected it; rotate it).
+    health: String,
+    consecutive_failures: u32,
+    last_success_at: Option<DateTime<Utc>>,
+    last_failure_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+    rotated_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_archive::CredentialMeta> for SourceCredentialInfo {
+    fn from(m: rootsignal_archive::CredentialMeta) -> Self {
+        Self {
+            canonical_key: m.canonical_key,
+            kind: m.kind.as_str().to_string(),
+            header_name: m.header_name,
+            health: m.health.as_str().to_string(),
+            consecutive_failures: m.consecutive_failures,
+            last_success_at: m.last_success_at,
+            last_failure_at: m.last_failure_at,
+            last_error: m.last_error,
+            rotated_at: m.rotated_at,
+            created_at: m.created_at,
+        }
+    }
+}
+
+// ========== Dead Letter Types ==========
+
+use rootsignal_common::dead_letter::{Attempt, DeadLetter as DeadLetterEntry};
+
+/// A workflow item that failed terminally and is waiting for replay or discard.
+#[derive(SimpleObject)]
+struct DeadLetter {
+    id: String,
+    workflow: String,
+    item_key: String,
+    error: String,
+    error_class: String,
+    status: String,
+    attempt_count: u32,
+    attempts: Vec<DeadLetterAttempt>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(SimpleObject)]
+struct DeadLetterAttempt {
+    at: DateTime<Utc>,
+    error: String,
+    error_class: String,
+}
+
+impl From<DeadLetterEntry> for DeadLetter {
+    fn from(d: DeadLetterEntry) -> Self {
+        Self {
+            id: d.id.to_string(),
+            workflow: d.workflow,
+            item_key: d.item_key,
+            error: d.error,
+            error_class: d.error_class.as_str().to_string(),
+            status: d.status.as_str().to_string(),
+            attempt_count: d.attempts.len() as u32,
+            attempts: d.attempts.into_iter().map(DeadLetterAttempt::from).collect(),
+            created_at: d.created_at,
+            updated_at: d.updated_at,
+        }
+    }
+}
+
+impl From<Attempt> for DeadLetterAttempt {
+    fn from(a: Attempt) -> Self {
+        Self {
+            at: a.at,
+            error: a.error,
+            error_class: a.error_class.as_str().to_string(),
+        }
+    }
+}
+
+// ========== Negative Knowledge Types ==========
+
+use rootsignal_common::negative_knowledge::NegativeEntry;
+
+/// Content the scout suppresses instead of storing, and why.
+#[derive(SimpleObject)]
+struct NegativeKnowledgeEntry {
+    id: String,
+    /// Null applies in every region.
+    region: Option<String>,
+    kind: String,
+    text: String,
+    reason: String,
+    added_by: String,
+    /// Extractions suppressed by this entry.
+    hit_count: u32,
+    /// Whether the scout has embedded it yet; until then it matches titles only.
+    embedded: bool,
+    created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+    last_hit_at: Option<DateTime<Utc>>,
+}
+
+impl From<NegativeEntry> for NegativeKnowledgeEntry {
+    fn from(e: NegativeEntry) -> Self {
+        Self {
+            id: e.id.to_string(),
+            region: e.region,
+            kind: e.kind.as_str().to_string(),
+            text: e.text,
+            reason: e.reason,
+            added_by: e.added_by,
+            hit_count: e.hit_count,
+            embedded: e.embedding.is_some(),
+            created_at: e.created_at,
+            expires_at: e.expires_at,
+            last_hit_at: e.last_hit_at,
+        }
+    }
+}
+
+// ========== Feature Flag Types ==========
+
+#[derive(SimpleObject)]
+struct FeatureFlagState {
+    flag: String,
+    description: String,
+    enabled: bool,
+    default_enabled: bool,
+    /// `default`, `global` or `region`.
+    source: String,
+}
+
+// ========== Synthesis Budget Types ==========
+
+#[derive(SimpleObject)]
+struct SynthesisPhasePriority {
+    phase: String,
+    /// 0 disables the phase.
+    priority: u32,
+    default_priority: u32,
+    /// `default`, `global` or `region`.
+    source: String,
+}
+
+#[derive(SimpleObject)]
+struct SynthesisBudgetState {
+    /// `rank` or `proportional`.
+    mode: String,
+    mode_source: String,
+    phases: Vec<SynthesisPhasePriority>,
+}
+
+// ========== Extraction Prompt Types ==========
+
+use rootsignal_common::extraction_prompts::{ExtractionPrompt, ShadowReport, ShadowThresholds};
+
+#[derive(SimpleObject)]
+struct ExtractionPromptEntry {
+    id: String,
+    /// Null applies in every region without its own prompt.
+    region: Option<String>,
+    name: String,
+    template: String,
+    /// `shadow`, `live` or `retired`.
+    status: String,
+    sample_rate: f64,
+    created_by: String,
+    created_at: DateTime<Utc>,
+    promoted_at: Option<DateTime<Utc>>,
+    report: Option<ShadowReportView>,
+}
+
+#[derive(SimpleObject)]
+struct ShadowReportView {
+    samples: u32,
+    production_signals: u32,
+    candidate_signals: u32,
+    /// Candidate signals per production signal; null when only the candidate found any.
+    yield_ratio: Option<f64>,
+    agreement: f64,
+    judged: u32,
+    production_score: Option<f64>,
+    candidate_score: Option<f64>,
+    generated_at: DateTime<Utc>,
+    /// Why the candidate can't be promoted yet; empty when it can.
+    failures: Vec<String>,
+}
+
+impl From<ShadowReport> for ShadowReportView {
+    fn from(r: ShadowReport) -> Self {
+        Self {
+            failures: r.failures(&ShadowThresholds::default()),
+            samples: r.samples,
+            production_signals: r.production_signals,
+            candidate_signals: r.candidate_signals,
+            yield_ratio: r.yield_ratio,
+            agreement: r.agreement,
+            judged: r.judged,
+            production_score: r.production_score,
+            candidate_score: r.candidate_score,
+            generated_at: r.generated_at,
+        }
+    }
+}
+
+impl From<ExtractionPrompt> for ExtractionPromptEntry {
+    fn from(p: ExtractionPrompt) -> Self {
+        Self {
+            id: p.id.to_string(),
+            region: p.region,
+            name: p.name,
+            template: p.template,
+            status: p.status.as_str().to_string(),
+            sample_rate: p.sample_rate,
+            created_by: p.created_by,
+            created_at: p.created_at,
+            promoted_at: p.promoted_at,
+            report: p.report.map(ShadowReportView::from),
         }
     }
 }
@@ -1394,18 +3004,40 @@ fn source_label_from_value(value: &str) -> String {
     domain.strip_prefix("www.").unwrap_or(domain).to_string()
 }
 
-pub fn build_schema(
-    reader: Arc<CachedReader>,
-    writer: Arc<GraphWriter>,
-    jwt_service: JwtService,
-    config: Arc<Config>,
-    twilio: Option<Arc<twilio::TwilioService>>,
-    rate_limiter: super::mutations::RateLimiter,
-    graph_client: Arc<rootsignal_graph::GraphClient>,
-    cache_store: Arc<rootsignal_graph::CacheStore>,
-    restate_client: Option<RestateClient>,
-    pg_pool: Option<sqlx::PgPool>,
-) -> ApiSchema {
+/// The services the schema hands to its resolvers.
+pub struct SchemaDeps {
+    pub reader: Arc<CachedReader>,
+    pub writer: Arc<GraphWriter>,
+    pub jwt_service: JwtService,
+    pub config: Arc<Config>,
+    pub twilio: Option<Arc<twilio::TwilioService>>,
+    pub rate_limiter: super::mutations::RateLimiter,
+    pub graph_client: Arc<rootsignal_graph::GraphClient>,
+    pub graph_router: GraphRouter,
+    pub cache_store: Arc<rootsignal_graph::CacheStore>,
+    pub restate_client: Option<RestateClient>,
+    pub pg_pools: Option<crate::db::PgPools>,
+}
+
+pub fn build_schema(deps: SchemaDeps) -> ApiSchema {
+    let SchemaDeps {
+        reader,
+        writer,
+        jwt_service,
+        config,
+        twilio,
+        rate_limiter,
+        graph_client,
+        graph_router,
+        cache_store,
+        restate_client,
+        pg_pools,
+    } = deps;
+
+    // Writes and read-your-write paths take the primary; read-only queries
+    // go through `read_pool`.
+    let pg_pool = pg_pools.as_ref().map(|p| p.primary().clone());
+
     let evidence_loader = DataLoader::new(
         EvidenceBySignalLoader {
             reader: reader.clone(),
@@ -1443,15 +3075,55 @@ pub fn build_schema(
         tokio::spawn,
     );
 
+    let annotations_loader = DataLoader::new(
+        AnnotationsBySignalLoader {
+            client: graph_client.clone(),
+        },
+        tokio::spawn,
+    );
+
+    let corrections_loader = DataLoader::new(
+        CorrectionsBySignalLoader {
+            client: graph_client.clone(),
+        },
+        tokio::spawn,
+    );
+
+    let investigations_loader = DataLoader::new(
+        InvestigationsBySignalLoader {
+            client: graph_client.clone(),
+        },
+        tokio::spawn,
+    );
+
+    let summary_variants_loader = DataLoader::new(
+        SummaryVariantsLoader {
+            pools: pg_pools.clone(),
+        },
+        tokio::spawn,
+    );
+
     // Create Voyage AI embedder for semantic search (if API key is available)
     let embedder = {
-        let voyage_key = &config.voyage_api_key;
+        let voyage_key = &config.voyage_api_key.expose();
         if voyage_key.is_empty() {
             tracing::warn!("VOYAGE_API_KEY not set — semantic search queries will fail");
         }
         Arc::new(rootsignal_scout::infra::embedder::Embedder::new(voyage_key))
     };
 
+    // Per-source credential store (needs Postgres + SOURCE_CREDENTIALS_KEY)
+    let credential_store = match (&pg_pool, &config.source_credentials_key) {
+        (Some(pool), Some(key)) => match rootsignal_archive::CredentialCipher::from_base64_key(&key.expose()) {
+            Ok(cipher) => Some(Arc::new(rootsignal_archive::CredentialStore::new(pool.clone(), cipher))),
+            Err(e) => {
+                tracing::warn!(error = %e, "Source credentials disabled");
+                None
+            }
+        },
+        _ => None,
+    };
+
     Schema::build(QueryRoot, MutationRoot, EmptySubscription)
         .data(reader)
         .data(writer.clone() as Arc<dyn rootsignal_scout::pipeline::traits::SignalStore>)
@@ -1461,6 +3133,7 @@ pub fn build_schema(
         .data(twilio)
         .data(rate_limiter)
         .data(graph_client)
+        .data(graph_router)
         .data(cache_store)
         .data(evidence_loader)
         .data(actors_loader)
@@ -1468,11 +3141,63 @@ pub fn build_schema(
         .data(situations_loader)
         .data(tags_loader)
         .data(situation_tags_loader)
+        .data(annotations_loader)
+        .data(corrections_loader)
+        .data(investigations_loader)
+        .data(summary_variants_loader)
         .data(embedder)
         .data(restate_client)
         .data(pg_pool)
+        .data(pg_pools)
+        .data(credential_store)
         .finish()
 }
 
 use crate::jwt::JwtService;
 use rootsignal_common::Config;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute};
+    use async_graphql::{EmptySubscription, Response, Schema};
+
+    use super::super::mutations::MutationRoot;
+    use super::QueryRoot;
+
+    /// Stops a request after validation, so documents are checked against the
+    /// schema without any resolver running.
+    struct ValidateOnly;
+
+    impl ExtensionFactory for ValidateOnly {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(ValidateOnly)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Extension for ValidateOnly {
+        async fn execute(
+            &self,
+            _ctx: &ExtensionContext<'_>,
+            _operation_name: Option<&str>,
+            _next: NextExecute<'_>,
+        ) -> Response {
+            Response::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn client_operations_validate_against_the_schema() {
+        let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+            .extension(ValidateOnly)
+            .finish();
+
+        for op in rootsignal_client::schema::OPERATIONS {
+            let response = schema.execute(op.document).await;
+
+            assert!(response.errors.is_empty(), "{} is out of sync with the API: {:?}", op.name, response.errors);
+        }
+    }
+}
@@ -9,15 +9,20 @@ use async_graphql::{Context, Object, Result, SimpleObject, Union};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use rootsignal_common::accessibility;
+use rootsignal_common::summary_variants;
+use rootsignal_common::why_not;
 use rootsignal_common::{
-    ActorNode, AidNode, EvidenceNode, GatheringNode, NeedNode, Node, NodeMeta, NoticeNode,
-    StoryNode, TagNode, TensionNode,
+    redact_node, ActorNode, AidNode, EvidenceNode, GatheringNode, NeedNode, Node, NodeMeta, NoticeNode,
+    StoryNode, TagNode, TensionNode, Visibility,
 };
 use rootsignal_graph::CachedReader;
 
+use super::context::{viewer_tier, AcceptLanguage};
 use super::loaders::{
-    ActorsBySignalLoader, EvidenceBySignalLoader, SituationsBySignalLoader, StoryBySignalLoader,
-    TagsBySituationLoader, TagsByStoryLoader,
+    ActorsBySignalLoader, AnnotationsBySignalLoader, CorrectionsBySignalLoader, EvidenceBySignalLoader,
+    InvestigationsBySignalLoader, StoryBySignalLoader, SummaryVariantsLoader, TagsBySituationLoader,
+    TagsByStoryLoader,
 };
 
 // --- GraphQL Enums ---
@@ -115,6 +120,125 @@ impl From<rootsignal_common::Severity> for GqlSeverity {
     }
 }
 
+impl From<GqlSeverity> for rootsignal_common::Severity {
+    fn from(s: GqlSeverity) -> Self {
+        match s {
+            GqlSeverity::Low => rootsignal_common::Severity::Low,
+            GqlSeverity::Medium => rootsignal_common::Severity::Medium,
+            GqlSeverity::High => rootsignal_common::Severity::High,
+            GqlSeverity::Critical => rootsignal_common::Severity::Critical,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum GqlPortalKind {
+    Socrata,
+    Ckan,
+}
+
+impl From<rootsignal_common::PortalKind> for GqlPortalKind {
+    fn from(p: rootsignal_common::PortalKind) -> Self {
+        match p {
+            rootsignal_common::PortalKind::Socrata => GqlPortalKind::Socrata,
+            rootsignal_common::PortalKind::Ckan => GqlPortalKind::Ckan,
+        }
+    }
+}
+
+impl From<GqlPortalKind> for rootsignal_common::PortalKind {
+    fn from(p: GqlPortalKind) -> Self {
+        match p {
+            GqlPortalKind::Socrata => rootsignal_common::PortalKind::Socrata,
+            GqlPortalKind::Ckan => rootsignal_common::PortalKind::Ckan,
+        }
+    }
+}
+
+/// The signal an open-data record becomes.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum GqlOpenDataSignal {
+    Tension,
+    Notice,
+}
+
+impl From<rootsignal_common::OpenDataSignal> for GqlOpenDataSignal {
+    fn from(s: rootsignal_common::OpenDataSignal) -> Self {
+        match s {
+            rootsignal_common::OpenDataSignal::Tension => GqlOpenDataSignal::Tension,
+            rootsignal_common::OpenDataSignal::Notice => GqlOpenDataSignal::Notice,
+        }
+    }
+}
+
+impl From<GqlOpenDataSignal> for rootsignal_common::OpenDataSignal {
+    fn from(s: GqlOpenDataSignal) -> Self {
+        match s {
+            GqlOpenDataSignal::Tension => rootsignal_common::OpenDataSignal::Tension,
+            GqlOpenDataSignal::Notice => rootsignal_common::OpenDataSignal::Notice,
+        }
+    }
+}
+
+/// Kind of registry entity signals are linked to.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum GqlRegistryKind {
+    School,
+    Route,
+    Ward,
+    District,
+    Park,
+}
+
+impl From<rootsignal_common::RegistryKind> for GqlRegistryKind {
+    fn from(k: rootsignal_common::RegistryKind) -> Self {
+        match k {
+            rootsignal_common::RegistryKind::School => GqlRegistryKind::School,
+            rootsignal_common::RegistryKind::Route => GqlRegistryKind::Route,
+            rootsignal_common::RegistryKind::Ward => GqlRegistryKind::Ward,
+            rootsignal_common::RegistryKind::District => GqlRegistryKind::District,
+            rootsignal_common::RegistryKind::Park => GqlRegistryKind::Park,
+        }
+    }
+}
+
+impl From<GqlRegistryKind> for rootsignal_common::RegistryKind {
+    fn from(k: GqlRegistryKind) -> Self {
+        match k {
+            GqlRegistryKind::School => rootsignal_common::RegistryKind::School,
+            GqlRegistryKind::Route => rootsignal_common::RegistryKind::Route,
+            GqlRegistryKind::Ward => rootsignal_common::RegistryKind::Ward,
+            GqlRegistryKind::District => rootsignal_common::RegistryKind::District,
+            GqlRegistryKind::Park => rootsignal_common::RegistryKind::Park,
+        }
+    }
+}
+
+/// A ward or district of a region, e.g. `{region: "Minneapolis", name: "9"}`.
+#[derive(async_graphql::InputObject)]
+pub struct DistrictFilter {
+    pub region: String,
+    #[graphql(default_with = "GqlRegistryKind::Ward")]
+    pub kind: GqlRegistryKind,
+    pub name: String,
+}
+
+impl DistrictFilter {
+    /// Graph id of the district's registry entity.
+    pub fn entity_id(&self) -> async_graphql::Result<String> {
+        if !matches!(self.kind, GqlRegistryKind::Ward | GqlRegistryKind::District) {
+            return Err(async_graphql::Error::new("Only wards and districts can filter by district"));
+        }
+        let entity = rootsignal_common::RegistryEntity {
+            kind: self.kind.into(),
+            name: self.name.clone(),
+            aliases: Vec::new(),
+            geometry: None,
+        };
+        Ok(entity.node_id(&rootsignal_common::slugify(&self.region)))
+    }
+}
+
 #[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
 pub enum GqlSensitivityLevel {
     General,
@@ -132,6 +256,73 @@ impl From<rootsignal_common::SensitivityLevel> for GqlSensitivityLevel {
     }
 }
 
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "Visibility")]
+pub enum GqlVisibility {
+    Public,
+    Contributor,
+    Admin,
+}
+
+impl From<Visibility> for GqlVisibility {
+    fn from(v: Visibility) -> Self {
+        match v {
+            Visibility::Public => GqlVisibility::Public,
+            Visibility::Contributor => GqlVisibility::Contributor,
+            Visibility::Admin => GqlVisibility::Admin,
+        }
+    }
+}
+
+impl From<GqlVisibility> for Visibility {
+    fn from(v: GqlVisibility) -> Self {
+        match v {
+            GqlVisibility::Public => Visibility::Public,
+            GqlVisibility::Contributor => Visibility::Contributor,
+            GqlVisibility::Admin => Visibility::Admin,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "SignalField")]
+pub enum GqlSignalField {
+    Location,
+    LocationName,
+    ActionUrl,
+    Organizer,
+}
+
+impl From<rootsignal_common::SignalField> for GqlSignalField {
+    fn from(f: rootsignal_common::SignalField) -> Self {
+        match f {
+            rootsignal_common::SignalField::Location => GqlSignalField::Location,
+            rootsignal_common::SignalField::LocationName => GqlSignalField::LocationName,
+            rootsignal_common::SignalField::ActionUrl => GqlSignalField::ActionUrl,
+            rootsignal_common::SignalField::Organizer => GqlSignalField::Organizer,
+        }
+    }
+}
+
+impl From<GqlSignalField> for rootsignal_common::SignalField {
+    fn from(f: GqlSignalField) -> Self {
+        match f {
+            GqlSignalField::Location => rootsignal_common::SignalField::Location,
+            GqlSignalField::LocationName => rootsignal_common::SignalField::LocationName,
+            GqlSignalField::ActionUrl => rootsignal_common::SignalField::ActionUrl,
+            GqlSignalField::Organizer => rootsignal_common::SignalField::Organizer,
+        }
+    }
+}
+
+/// A field raised above its default tier on one signal.
+#[derive(SimpleObject)]
+#[graphql(name = "FieldDemotion")]
+pub struct GqlFieldDemotion {
+    pub field: GqlSignalField,
+    pub visibility: GqlVisibility,
+}
+
 #[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
 pub enum GqlGeoPrecision {
     Exact,
@@ -214,6 +405,135 @@ impl GqlEvidence {
     async fn evidence_confidence(&self) -> Option<f32> {
         self.0.evidence_confidence
     }
+    /// Weighted interactions per hour of the social post this came from.
+    async fn engagement_velocity(&self) -> Option<f64> {
+        self.0.engagement_velocity
+    }
+    /// Whether the source page still says this; None until first re-checked.
+    async fn freshness(&self) -> Option<GqlEvidenceFreshness> {
+        self.0.verification.as_ref().map(|v| v.freshness.into())
+    }
+    async fn checked_at(&self) -> Option<DateTime<Utc>> {
+        self.0.verification.as_ref().map(|v| v.checked_at)
+    }
+    /// What changed on the source page, when it changed.
+    async fn drift_diff(&self) -> Option<&str> {
+        self.0.verification.as_ref().and_then(|v| v.drift_diff.as_deref())
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "EvidenceFreshness")]
+pub enum GqlEvidenceFreshness {
+    Current,
+    Changed,
+    Removed,
+    Stale,
+}
+
+impl From<rootsignal_common::EvidenceFreshness> for GqlEvidenceFreshness {
+    fn from(f: rootsignal_common::EvidenceFreshness) -> Self {
+        match f {
+            rootsignal_common::EvidenceFreshness::Current => GqlEvidenceFreshness::Current,
+            rootsignal_common::EvidenceFreshness::Changed => GqlEvidenceFreshness::Changed,
+            rootsignal_common::EvidenceFreshness::Removed => GqlEvidenceFreshness::Removed,
+            rootsignal_common::EvidenceFreshness::Stale => GqlEvidenceFreshness::Stale,
+        }
+    }
+}
+
+// --- Accessibility ---
+
+#[derive(SimpleObject)]
+#[graphql(name = "Accessibility")]
+pub struct GqlAccessibility {
+    /// Short label to announce on focus (`aria-label`).
+    pub aria_label: String,
+    /// Alt text for the marker or card image, generated when the node was written.
+    pub alt_text: String,
+    /// The summary at a reading level of grade 8 or below, when one was written.
+    pub plain_summary: Option<String>,
+    /// Flesch-Kincaid grade level of `plain_summary`.
+    pub reading_grade: Option<f64>,
+}
+
+impl GqlAccessibility {
+    fn new(aria_label: String, alt_text: Option<&str>, plain_summary: Option<&str>) -> Self {
+        Self {
+            // Nodes written before alt text existed fall back to their label.
+            alt_text: alt_text.map(str::to_string).unwrap_or_else(|| aria_label.clone()),
+            aria_label,
+            reading_grade: plain_summary.map(accessibility::reading_grade),
+            plain_summary: plain_summary.map(str::to_string),
+        }
+    }
+
+    pub fn for_signal(node: &Node) -> Self {
+        let meta = node.meta();
+        Self::new(
+            accessibility::signal_aria_label(node),
+            meta.and_then(|m| m.alt_text.as_deref()),
+            meta.and_then(|m| m.plain_summary.as_deref()),
+        )
+    }
+
+    pub fn for_situation(situation: &rootsignal_common::SituationNode) -> Self {
+        Self::new(
+            accessibility::situation_aria_label(situation),
+            situation.alt_text.as_deref(),
+            situation.plain_summary.as_deref(),
+        )
+    }
+}
+
+// --- Localized summaries ---
+
+#[derive(SimpleObject)]
+#[graphql(name = "LocalizedSummary")]
+pub struct GqlLocalizedSummary {
+    /// Language of `summary` and `plain_summary`, e.g. "es".
+    pub language: String,
+    pub summary: String,
+    pub plain_summary: Option<String>,
+}
+
+impl GqlLocalizedSummary {
+    /// The summary in the first of the reader's languages it has a current
+    /// translation in, else the English original. `language` is a list like
+    /// "so,es" or an `Accept-Language` value; unset = the request's
+    /// `Accept-Language` header.
+    async fn resolve(
+        ctx: &Context<'_>,
+        id: Uuid,
+        summary: &str,
+        plain_summary: Option<&str>,
+        language: Option<String>,
+    ) -> Result<Self> {
+        let requested = language
+            .or_else(|| ctx.data_opt::<AcceptLanguage>().map(|a| a.0.clone()))
+            .unwrap_or_default();
+        let preferred = summary_variants::preferred_languages(&requested);
+        let loader = ctx.data_unchecked::<DataLoader<SummaryVariantsLoader>>();
+        let variants = loader.load_one(id).await?.unwrap_or_default();
+
+        if let Some(v) = summary_variants::best_variant(&variants, &preferred, summary)
+            .filter(|v| v.language != summary_variants::SOURCE_LANGUAGE)
+        {
+            return Ok(Self {
+                language: v.language.clone(),
+                summary: v.summary.clone(),
+                plain_summary: v.plain_summary.clone(),
+            });
+        }
+        let english = [summary_variants::SOURCE_LANGUAGE.to_string()];
+        Ok(Self {
+            language: summary_variants::SOURCE_LANGUAGE.to_string(),
+            summary: summary.to_string(),
+            plain_summary: plain_summary.map(str::to_string).or_else(|| {
+                summary_variants::best_variant(&variants, &english, summary).and_then(|v| v.plain_summary.clone())
+            }),
+        })
+    }
 }
 
 // --- Signal Union ---
@@ -227,9 +547,11 @@ pub enum GqlSignal {
     Tension(GqlTensionSignal),
 }
 
-impl From<Node> for GqlSignal {
-    fn from(node: Node) -> Self {
-        match node {
+impl GqlSignal {
+    /// The only way a signal enters the schema: fields above the viewer's
+    /// tier are stripped here, whichever query or loader produced it.
+    pub fn for_viewer(node: Node, viewer: Visibility) -> Self {
+        match redact_node(node, viewer) {
             Node::Gathering(n) => GqlSignal::Gathering(GqlGatheringSignal(n)),
             Node::Aid(n) => GqlSignal::Aid(GqlAidSignal(n)),
             Node::Need(n) => GqlSignal::Need(GqlNeedSignal(n)),
@@ -321,6 +643,41 @@ macro_rules! signal_meta_resolvers {
                 .map(GqlActor)
                 .collect())
         }
+        /// Contributor annotations — local context from people, not extracted
+        /// from sources.
+        async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+            let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+            Ok(loader
+                .load_one(self.meta().id)
+                .await?
+                .unwrap_or_default()
+                .into_iter()
+                .map(GqlAnnotation::from)
+                .collect())
+        }
+        /// Correction requests residents have filed, with their status.
+        async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+            let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+            Ok(loader
+                .load_one(self.meta().id)
+                .await?
+                .unwrap_or_default()
+                .into_iter()
+                .map(GqlCorrection::from)
+                .collect())
+        }
+        /// Investigation reports — how the scout checked this signal and what
+        /// moved its confidence.
+        async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+            let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+            Ok(loader
+                .load_one(self.meta().id)
+                .await?
+                .unwrap_or_default()
+                .into_iter()
+                .map(GqlInvestigation::from)
+                .collect())
+        }
     };
 }
 
@@ -350,6 +707,13 @@ impl GqlGatheringSignal {
     async fn cause_heat(&self) -> f64 { self.meta().cause_heat }
     async fn channel_diversity(&self) -> u32 { self.meta().channel_diversity }
     async fn mentioned_actors(&self) -> &[String] { &self.meta().mentioned_actors }
+    /// Screen-reader text for this signal's map marker and list item.
+    async fn accessibility(&self) -> GqlAccessibility { GqlAccessibility::for_signal(&Node::Gathering(self.0.clone())) }
+    /// The summary in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        let m = self.meta();
+        GqlLocalizedSummary::resolve(ctx, m.id, &m.summary, m.plain_summary.as_deref(), language).await
+    }
     async fn evidence(&self, ctx: &Context<'_>) -> Result<Vec<GqlEvidence>> {
         let loader = ctx.data_unchecked::<DataLoader<EvidenceBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlEvidence).collect())
@@ -362,6 +726,21 @@ impl GqlGatheringSignal {
         let loader = ctx.data_unchecked::<DataLoader<ActorsBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlActor).collect())
     }
+    /// Contributor annotations — local context from people, not extracted from sources.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+        let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlAnnotation::from).collect())
+    }
+    /// Correction requests residents have filed, with their status.
+    async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+        let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlCorrection::from).collect())
+    }
+    /// Investigation reports — how the scout checked this signal and what moved its confidence.
+    async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+        let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlInvestigation::from).collect())
+    }
 
     async fn starts_at(&self) -> Option<DateTime<Utc>> {
         self.0.starts_at
@@ -406,6 +785,13 @@ impl GqlAidSignal {
     async fn cause_heat(&self) -> f64 { self.meta().cause_heat }
     async fn channel_diversity(&self) -> u32 { self.meta().channel_diversity }
     async fn mentioned_actors(&self) -> &[String] { &self.meta().mentioned_actors }
+    /// Screen-reader text for this signal's map marker and list item.
+    async fn accessibility(&self) -> GqlAccessibility { GqlAccessibility::for_signal(&Node::Aid(self.0.clone())) }
+    /// The summary in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        let m = self.meta();
+        GqlLocalizedSummary::resolve(ctx, m.id, &m.summary, m.plain_summary.as_deref(), language).await
+    }
     async fn evidence(&self, ctx: &Context<'_>) -> Result<Vec<GqlEvidence>> {
         let loader = ctx.data_unchecked::<DataLoader<EvidenceBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlEvidence).collect())
@@ -418,6 +804,21 @@ impl GqlAidSignal {
         let loader = ctx.data_unchecked::<DataLoader<ActorsBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlActor).collect())
     }
+    /// Contributor annotations — local context from people, not extracted from sources.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+        let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlAnnotation::from).collect())
+    }
+    /// Correction requests residents have filed, with their status.
+    async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+        let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlCorrection::from).collect())
+    }
+    /// Investigation reports — how the scout checked this signal and what moved its confidence.
+    async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+        let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlInvestigation::from).collect())
+    }
 
     async fn action_url(&self) -> &str {
         &self.0.action_url
@@ -456,6 +857,13 @@ impl GqlNeedSignal {
     async fn cause_heat(&self) -> f64 { self.meta().cause_heat }
     async fn channel_diversity(&self) -> u32 { self.meta().channel_diversity }
     async fn mentioned_actors(&self) -> &[String] { &self.meta().mentioned_actors }
+    /// Screen-reader text for this signal's map marker and list item.
+    async fn accessibility(&self) -> GqlAccessibility { GqlAccessibility::for_signal(&Node::Need(self.0.clone())) }
+    /// The summary in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        let m = self.meta();
+        GqlLocalizedSummary::resolve(ctx, m.id, &m.summary, m.plain_summary.as_deref(), language).await
+    }
     async fn evidence(&self, ctx: &Context<'_>) -> Result<Vec<GqlEvidence>> {
         let loader = ctx.data_unchecked::<DataLoader<EvidenceBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlEvidence).collect())
@@ -468,6 +876,21 @@ impl GqlNeedSignal {
         let loader = ctx.data_unchecked::<DataLoader<ActorsBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlActor).collect())
     }
+    /// Contributor annotations — local context from people, not extracted from sources.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+        let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlAnnotation::from).collect())
+    }
+    /// Correction requests residents have filed, with their status.
+    async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+        let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlCorrection::from).collect())
+    }
+    /// Investigation reports — how the scout checked this signal and what moved its confidence.
+    async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+        let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlInvestigation::from).collect())
+    }
 
     async fn urgency(&self) -> GqlUrgency {
         self.0.urgency.into()
@@ -481,6 +904,49 @@ impl GqlNeedSignal {
     async fn goal(&self) -> Option<&str> {
         self.0.goal.as_deref()
     }
+    /// Crowdfunding progress, when the Need is backed by a fundraiser page.
+    async fn fundraiser(&self) -> Option<GqlFundraiser> {
+        self.0.fundraiser.clone().map(GqlFundraiser)
+    }
+}
+
+// --- Fundraiser ---
+
+pub struct GqlFundraiser(pub rootsignal_common::Fundraiser);
+
+#[Object]
+impl GqlFundraiser {
+    async fn url(&self) -> &str {
+        &self.0.url
+    }
+    async fn platform(&self) -> &str {
+        &self.0.platform
+    }
+    async fn goal(&self) -> Option<f64> {
+        self.0.goal
+    }
+    async fn raised(&self) -> f64 {
+        self.0.raised
+    }
+    async fn currency(&self) -> &str {
+        &self.0.currency
+    }
+    /// Raised as a percentage of the goal (e.g. 73.0). Null when there's no goal.
+    async fn percent_funded(&self) -> Option<f64> {
+        self.0.percent_funded()
+    }
+    async fn donor_count(&self) -> Option<u32> {
+        self.0.donor_count
+    }
+    async fn organizer(&self) -> Option<&str> {
+        self.0.organizer.as_deref()
+    }
+    async fn beneficiary(&self) -> Option<&str> {
+        self.0.beneficiary.as_deref()
+    }
+    async fn checked_at(&self) -> DateTime<Utc> {
+        self.0.checked_at
+    }
 }
 
 // --- NoticeSignal ---
@@ -509,6 +975,13 @@ impl GqlNoticeSignal {
     async fn cause_heat(&self) -> f64 { self.meta().cause_heat }
     async fn channel_diversity(&self) -> u32 { self.meta().channel_diversity }
     async fn mentioned_actors(&self) -> &[String] { &self.meta().mentioned_actors }
+    /// Screen-reader text for this signal's map marker and list item.
+    async fn accessibility(&self) -> GqlAccessibility { GqlAccessibility::for_signal(&Node::Notice(self.0.clone())) }
+    /// The summary in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        let m = self.meta();
+        GqlLocalizedSummary::resolve(ctx, m.id, &m.summary, m.plain_summary.as_deref(), language).await
+    }
     async fn evidence(&self, ctx: &Context<'_>) -> Result<Vec<GqlEvidence>> {
         let loader = ctx.data_unchecked::<DataLoader<EvidenceBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlEvidence).collect())
@@ -521,6 +994,21 @@ impl GqlNoticeSignal {
         let loader = ctx.data_unchecked::<DataLoader<ActorsBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlActor).collect())
     }
+    /// Contributor annotations — local context from people, not extracted from sources.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+        let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlAnnotation::from).collect())
+    }
+    /// Correction requests residents have filed, with their status.
+    async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+        let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlCorrection::from).collect())
+    }
+    /// Investigation reports — how the scout checked this signal and what moved its confidence.
+    async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+        let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlInvestigation::from).collect())
+    }
 
     async fn severity(&self) -> GqlSeverity {
         self.0.severity.into()
@@ -562,6 +1050,13 @@ impl GqlTensionSignal {
     async fn cause_heat(&self) -> f64 { self.meta().cause_heat }
     async fn channel_diversity(&self) -> u32 { self.meta().channel_diversity }
     async fn mentioned_actors(&self) -> &[String] { &self.meta().mentioned_actors }
+    /// Screen-reader text for this signal's map marker and list item.
+    async fn accessibility(&self) -> GqlAccessibility { GqlAccessibility::for_signal(&Node::Tension(self.0.clone())) }
+    /// The summary in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        let m = self.meta();
+        GqlLocalizedSummary::resolve(ctx, m.id, &m.summary, m.plain_summary.as_deref(), language).await
+    }
     async fn evidence(&self, ctx: &Context<'_>) -> Result<Vec<GqlEvidence>> {
         let loader = ctx.data_unchecked::<DataLoader<EvidenceBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlEvidence).collect())
@@ -574,6 +1069,21 @@ impl GqlTensionSignal {
         let loader = ctx.data_unchecked::<DataLoader<ActorsBySignalLoader>>();
         Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlActor).collect())
     }
+    /// Contributor annotations — local context from people, not extracted from sources.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<GqlAnnotation>> {
+        let loader = ctx.data_unchecked::<DataLoader<AnnotationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlAnnotation::from).collect())
+    }
+    /// Correction requests residents have filed, with their status.
+    async fn corrections(&self, ctx: &Context<'_>) -> Result<Vec<GqlCorrection>> {
+        let loader = ctx.data_unchecked::<DataLoader<CorrectionsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlCorrection::from).collect())
+    }
+    /// Investigation reports — how the scout checked this signal and what moved its confidence.
+    async fn investigations(&self, ctx: &Context<'_>) -> Result<Vec<GqlInvestigation>> {
+        let loader = ctx.data_unchecked::<DataLoader<InvestigationsBySignalLoader>>();
+        Ok(loader.load_one(self.meta().id).await?.unwrap_or_default().into_iter().map(GqlInvestigation::from).collect())
+    }
 
     async fn severity(&self) -> GqlSeverity {
         self.0.severity.into()
@@ -587,9 +1097,10 @@ impl GqlTensionSignal {
     async fn responses(&self, ctx: &Context<'_>) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
         let nodes = reader.tension_responses(self.0.meta.id).await?;
+        let viewer = viewer_tier(ctx).await;
         Ok(nodes
             .into_iter()
-            .map(|tr| GqlSignal::from(tr.node))
+            .map(|tr| GqlSignal::for_viewer(tr.node, viewer))
             .collect())
     }
 }
@@ -624,6 +1135,10 @@ impl GqlStory {
     async fn energy(&self) -> f64 {
         self.0.energy
     }
+    /// The components `energy` is computed from, each 0.0–1.0.
+    async fn energy_breakdown(&self) -> Option<GqlEnergyBreakdown> {
+        self.0.energy_components.map(GqlEnergyBreakdown)
+    }
     async fn centroid_lat(&self) -> Option<f64> {
         self.0.centroid_lat
     }
@@ -695,7 +1210,8 @@ impl GqlStory {
     async fn signals(&self, ctx: &Context<'_>) -> Result<Vec<GqlSignal>> {
         let reader = ctx.data_unchecked::<Arc<CachedReader>>();
         let nodes = reader.get_story_signals(self.0.id).await?;
-        Ok(nodes.into_iter().map(GqlSignal::from).collect())
+        let viewer = viewer_tier(ctx).await;
+        Ok(nodes.into_iter().map(|n| GqlSignal::for_viewer(n, viewer)).collect())
     }
 
     async fn actors(&self, ctx: &Context<'_>) -> Result<Vec<GqlActor>> {
@@ -840,6 +1356,28 @@ pub struct FindingCount {
 }
 
 /// A story matched via its constituent signals' semantic similarity.
+pub struct GqlEnergyBreakdown(pub rootsignal_common::EnergyComponents);
+
+#[Object]
+impl GqlEnergyBreakdown {
+    /// Recency- and confidence-weighted signal count.
+    async fn activity(&self) -> f64 {
+        self.0.activity
+    }
+    /// Severity of the story's most severe tension.
+    async fn severity(&self) -> f64 {
+        self.0.severity
+    }
+    /// How independently corroborated the signals are.
+    async fn corroboration(&self) -> f64 {
+        self.0.corroboration
+    }
+    /// Hottest cause heat among the signals.
+    async fn heat(&self) -> f64 {
+        self.0.heat
+    }
+}
+
 pub struct GqlStorySearchResult {
     pub story: GqlStory,
     pub score: f64,
@@ -859,6 +1397,97 @@ impl GqlStorySearchResult {
     }
 }
 
+// --- Trend types ---
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum GqlTrendWindow {
+    Week,
+    Month,
+}
+
+impl From<GqlTrendWindow> for rootsignal_common::TrendWindow {
+    fn from(w: GqlTrendWindow) -> Self {
+        match w {
+            GqlTrendWindow::Week => rootsignal_common::TrendWindow::Week,
+            GqlTrendWindow::Month => rootsignal_common::TrendWindow::Month,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum GqlTrendDirection {
+    Rising,
+    Falling,
+}
+
+impl From<rootsignal_common::TrendDirection> for GqlTrendDirection {
+    fn from(d: rootsignal_common::TrendDirection) -> Self {
+        match d {
+            rootsignal_common::TrendDirection::Rising => GqlTrendDirection::Rising,
+            rootsignal_common::TrendDirection::Falling => GqlTrendDirection::Falling,
+        }
+    }
+}
+
+pub struct GqlTrend(pub rootsignal_common::Trend);
+
+#[Object]
+impl GqlTrend {
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+    async fn category(&self) -> &str {
+        &self.0.category
+    }
+    /// Neighborhood the trend is in; null for the whole region.
+    async fn neighborhood(&self) -> Option<&str> {
+        self.0.neighborhood.as_deref()
+    }
+    async fn direction(&self) -> GqlTrendDirection {
+        self.0.direction.into()
+    }
+    /// Signals in the window.
+    async fn observed(&self) -> u32 {
+        self.0.observed
+    }
+    /// Signals the baseline rate predicts for the window.
+    async fn expected(&self) -> f64 {
+        self.0.expected
+    }
+    async fn change_ratio(&self) -> Option<f64> {
+        self.0.change_ratio()
+    }
+    async fn z_score(&self) -> f64 {
+        self.0.z_score
+    }
+    /// Baseline then window weeks, oldest first.
+    async fn weekly_counts(&self) -> &[u32] {
+        &self.0.weekly_counts
+    }
+    async fn window_start(&self) -> String {
+        self.0.window_start.to_string()
+    }
+    async fn detected_at(&self) -> DateTime<Utc> {
+        self.0.detected_at
+    }
+    async fn headline(&self) -> String {
+        self.0.headline()
+    }
+
+    /// Signals from the window behind the change, newest first.
+    async fn signals(&self, ctx: &Context<'_>) -> Result<Vec<GqlSignal>> {
+        let reader = ctx.data_unchecked::<Arc<CachedReader>>();
+        let viewer = viewer_tier(ctx).await;
+        let mut signals = Vec::new();
+        for id in &self.0.signal_ids {
+            if let Some(node) = reader.get_signal_by_id(*id).await? {
+                signals.push(GqlSignal::for_viewer(node, viewer));
+            }
+        }
+        Ok(signals)
+    }
+}
+
 // --- Situation types ---
 
 #[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
@@ -990,6 +1619,29 @@ impl GqlSituation {
         self.0.category.as_deref()
     }
 
+    /// Likelihood (0.0–1.0) of escalating soon, with the reasons; null
+    /// until the first run after the situation appeared.
+    async fn escalation(&self) -> Option<EscalationRisk> {
+        self.0.escalation.clone().map(EscalationRisk::from)
+    }
+
+    /// Screen-reader text for this situation's card.
+    async fn accessibility(&self) -> GqlAccessibility {
+        GqlAccessibility::for_situation(&self.0)
+    }
+
+    /// The lede in the reader's language, with a plain-language version when there is one.
+    async fn localized_summary(&self, ctx: &Context<'_>, language: Option<String>) -> Result<GqlLocalizedSummary> {
+        GqlLocalizedSummary::resolve(ctx, self.0.id, &self.0.lede, self.0.plain_summary.as_deref(), language).await
+    }
+
+    /// Background on where the situation is unfolding.
+    async fn context(&self) -> SituationContext {
+        SituationContext {
+            demographics: self.0.demographics.clone().map(DemographicContext::from),
+        }
+    }
+
     async fn tags(&self, ctx: &Context<'_>) -> Result<Vec<GqlTag>> {
         let loader = ctx.data_unchecked::<DataLoader<TagsBySituationLoader>>();
         let tags = loader.load_one(self.0.id).await?.unwrap_or_default();
@@ -1012,24 +1664,830 @@ impl GqlSituation {
     }
 }
 
-pub struct GqlDispatch(pub rootsignal_common::DispatchNode);
+#[derive(SimpleObject)]
+pub struct EscalationRisk {
+    pub risk: f64,
+    pub rationale: String,
+    pub computed_at: DateTime<Utc>,
+}
 
-#[Object]
-impl GqlDispatch {
-    async fn id(&self) -> Uuid {
-        self.0.id
-    }
-    async fn situation_id(&self) -> Uuid {
-        self.0.situation_id
+impl From<rootsignal_common::EscalationRisk> for EscalationRisk {
+    fn from(e: rootsignal_common::EscalationRisk) -> Self {
+        Self {
+            risk: e.risk,
+            rationale: e.rationale,
+            computed_at: e.computed_at,
+        }
     }
-    async fn body(&self) -> &str {
-        &self.0.body
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "AnnotationStatus")]
+pub enum GqlAnnotationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl From<rootsignal_common::AnnotationStatus> for GqlAnnotationStatus {
+    fn from(s: rootsignal_common::AnnotationStatus) -> Self {
+        match s {
+            rootsignal_common::AnnotationStatus::Pending => GqlAnnotationStatus::Pending,
+            rootsignal_common::AnnotationStatus::Approved => GqlAnnotationStatus::Approved,
+            rootsignal_common::AnnotationStatus::Rejected => GqlAnnotationStatus::Rejected,
+        }
     }
-    async fn signal_ids(&self) -> Vec<String> {
-        self.0.signal_ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "BootstrapReviewStatus")]
+pub enum GqlReviewStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl From<rootsignal_common::ReviewStatus> for GqlReviewStatus {
+    fn from(s: rootsignal_common::ReviewStatus) -> Self {
+        match s {
+            rootsignal_common::ReviewStatus::Pending => GqlReviewStatus::Pending,
+            rootsignal_common::ReviewStatus::Approved => GqlReviewStatus::Approved,
+            rootsignal_common::ReviewStatus::Rejected => GqlReviewStatus::Rejected,
+        }
     }
-    async fn created_at(&self) -> DateTime<Utc> {
-        self.0.created_at
+}
+
+impl From<GqlReviewStatus> for rootsignal_common::ReviewStatus {
+    fn from(s: GqlReviewStatus) -> Self {
+        match s {
+            GqlReviewStatus::Pending => rootsignal_common::ReviewStatus::Pending,
+            GqlReviewStatus::Approved => rootsignal_common::ReviewStatus::Approved,
+            GqlReviewStatus::Rejected => rootsignal_common::ReviewStatus::Rejected,
+        }
+    }
+}
+
+/// A source a new region's bootstrap proposed, with how it scored.
+#[derive(SimpleObject)]
+#[graphql(name = "BootstrapCandidate")]
+pub struct GqlBootstrapCandidate {
+    pub canonical_key: String,
+    pub canonical_value: String,
+    pub url: Option<String>,
+    pub source_role: String,
+    pub score: f64,
+    pub domain_reputation: f64,
+    pub locality: f64,
+    /// Null when the source couldn't be sampled.
+    pub content: Option<f64>,
+    pub status: GqlReviewStatus,
+    pub sample_excerpt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<rootsignal_common::BootstrapCandidate> for GqlBootstrapCandidate {
+    fn from(c: rootsignal_common::BootstrapCandidate) -> Self {
+        Self {
+            canonical_key: c.canonical_key,
+            canonical_value: c.canonical_value,
+            url: c.url,
+            source_role: c.source_role.to_string(),
+            score: c.score.total(),
+            domain_reputation: c.score.domain_reputation,
+            locality: c.score.locality,
+            content: c.score.content,
+            status: c.status.into(),
+            sample_excerpt: c.sample_excerpt,
+            created_at: c.created_at,
+            reviewed_at: c.reviewed_at,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "BackfillStatus")]
+pub enum GqlBackfillStatus {
+    Requested,
+    Completed,
+    Failed,
+}
+
+impl From<rootsignal_common::BackfillStatus> for GqlBackfillStatus {
+    fn from(s: rootsignal_common::BackfillStatus) -> Self {
+        match s {
+            rootsignal_common::BackfillStatus::Requested => GqlBackfillStatus::Requested,
+            rootsignal_common::BackfillStatus::Completed => GqlBackfillStatus::Completed,
+            rootsignal_common::BackfillStatus::Failed => GqlBackfillStatus::Failed,
+        }
+    }
+}
+
+impl From<GqlBackfillStatus> for rootsignal_common::BackfillStatus {
+    fn from(s: GqlBackfillStatus) -> Self {
+        match s {
+            GqlBackfillStatus::Requested => rootsignal_common::BackfillStatus::Requested,
+            GqlBackfillStatus::Completed => rootsignal_common::BackfillStatus::Completed,
+            GqlBackfillStatus::Failed => rootsignal_common::BackfillStatus::Failed,
+        }
+    }
+}
+
+/// A one-time deep scrape of a source's history.
+#[derive(SimpleObject)]
+#[graphql(name = "SourceBackfill")]
+pub struct GqlBackfill {
+    pub canonical_key: String,
+    pub max_items: u32,
+    pub max_age_days: u32,
+    pub status: GqlBackfillStatus,
+    pub requested_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Signals stored and marked historical.
+    pub signals_stored: u32,
+    pub error: Option<String>,
+}
+
+impl From<rootsignal_common::Backfill> for GqlBackfill {
+    fn from(b: rootsignal_common::Backfill) -> Self {
+        Self {
+            canonical_key: b.canonical_key,
+            max_items: b.depth.max_items,
+            max_age_days: b.depth.max_age_days,
+            status: b.status.into(),
+            requested_at: b.requested_at,
+            completed_at: b.completed_at,
+            signals_stored: b.signals_stored,
+            error: b.error,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "InformationRequestStatus")]
+pub enum GqlRequestStatus {
+    Open,
+    Answered,
+    Unanswered,
+}
+
+impl From<rootsignal_common::RequestStatus> for GqlRequestStatus {
+    fn from(s: rootsignal_common::RequestStatus) -> Self {
+        match s {
+            rootsignal_common::RequestStatus::Open => GqlRequestStatus::Open,
+            rootsignal_common::RequestStatus::Answered => GqlRequestStatus::Answered,
+            rootsignal_common::RequestStatus::Unanswered => GqlRequestStatus::Unanswered,
+        }
+    }
+}
+
+impl From<GqlRequestStatus> for rootsignal_common::RequestStatus {
+    fn from(s: GqlRequestStatus) -> Self {
+        match s {
+            GqlRequestStatus::Open => rootsignal_common::RequestStatus::Open,
+            GqlRequestStatus::Answered => rootsignal_common::RequestStatus::Answered,
+            GqlRequestStatus::Unanswered => rootsignal_common::RequestStatus::Unanswered,
+        }
+    }
+}
+
+/// A page excerpt pulled to answer an information request.
+#[derive(SimpleObject)]
+#[graphql(name = "PulledEvidence")]
+pub struct GqlPulledEvidence {
+    pub id: Uuid,
+    pub url: String,
+    pub snippet: String,
+    pub retrieved_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::PulledEvidence> for GqlPulledEvidence {
+    fn from(e: rootsignal_common::PulledEvidence) -> Self {
+        Self {
+            id: e.id,
+            url: e.url,
+            snippet: e.snippet,
+            retrieved_at: e.retrieved_at,
+        }
+    }
+}
+
+/// Something a situation asked to find out, with what was pulled to answer it.
+#[derive(SimpleObject)]
+#[graphql(name = "InformationRequest")]
+pub struct GqlInformationRequest {
+    pub id: Uuid,
+    pub situation_id: Uuid,
+    pub situation_headline: String,
+    pub temperature: f64,
+    pub question: String,
+    pub status: GqlRequestStatus,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub answered_at: Option<DateTime<Utc>>,
+    pub evidence: Vec<GqlPulledEvidence>,
+}
+
+impl From<(rootsignal_common::InformationRequest, Vec<rootsignal_common::PulledEvidence>)> for GqlInformationRequest {
+    fn from((r, evidence): (rootsignal_common::InformationRequest, Vec<rootsignal_common::PulledEvidence>)) -> Self {
+        Self {
+            id: r.id,
+            situation_id: r.situation_id,
+            situation_headline: r.situation_headline,
+            temperature: r.temperature,
+            question: r.question,
+            status: r.status.into(),
+            attempts: r.attempts,
+            created_at: r.created_at,
+            answered_at: r.answered_at,
+            evidence: evidence.into_iter().map(GqlPulledEvidence::from).collect(),
+        }
+    }
+}
+
+/// A verified contributor's note on a signal. Written by a person, so it's
+/// kept apart from the signal's extracted fields.
+#[derive(SimpleObject)]
+#[graphql(name = "Annotation")]
+pub struct GqlAnnotation {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub body: String,
+    pub author_name: String,
+    pub status: GqlAnnotationStatus,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+}
+
+impl From<rootsignal_common::Annotation> for GqlAnnotation {
+    fn from(a: rootsignal_common::Annotation) -> Self {
+        Self {
+            id: a.id,
+            signal_id: a.signal_id,
+            body: a.body,
+            author_name: a.author_name,
+            status: a.status.into(),
+            created_at: a.created_at,
+            reviewed_at: a.reviewed_at,
+            review_note: a.review_note,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "CorrectionKind")]
+pub enum GqlCorrectionKind {
+    WrongLocation,
+    Outdated,
+    Duplicate,
+    NotTrue,
+    Harmful,
+}
+
+impl From<rootsignal_common::CorrectionKind> for GqlCorrectionKind {
+    fn from(k: rootsignal_common::CorrectionKind) -> Self {
+        match k {
+            rootsignal_common::CorrectionKind::WrongLocation => GqlCorrectionKind::WrongLocation,
+            rootsignal_common::CorrectionKind::Outdated => GqlCorrectionKind::Outdated,
+            rootsignal_common::CorrectionKind::Duplicate => GqlCorrectionKind::Duplicate,
+            rootsignal_common::CorrectionKind::NotTrue => GqlCorrectionKind::NotTrue,
+            rootsignal_common::CorrectionKind::Harmful => GqlCorrectionKind::Harmful,
+        }
+    }
+}
+
+impl From<GqlCorrectionKind> for rootsignal_common::CorrectionKind {
+    fn from(k: GqlCorrectionKind) -> Self {
+        match k {
+            GqlCorrectionKind::WrongLocation => rootsignal_common::CorrectionKind::WrongLocation,
+            GqlCorrectionKind::Outdated => rootsignal_common::CorrectionKind::Outdated,
+            GqlCorrectionKind::Duplicate => rootsignal_common::CorrectionKind::Duplicate,
+            GqlCorrectionKind::NotTrue => rootsignal_common::CorrectionKind::NotTrue,
+            GqlCorrectionKind::Harmful => rootsignal_common::CorrectionKind::Harmful,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "CorrectionStatus")]
+pub enum GqlCorrectionStatus {
+    Open,
+    Accepted,
+    Dismissed,
+}
+
+impl From<rootsignal_common::CorrectionStatus> for GqlCorrectionStatus {
+    fn from(s: rootsignal_common::CorrectionStatus) -> Self {
+        match s {
+            rootsignal_common::CorrectionStatus::Open => GqlCorrectionStatus::Open,
+            rootsignal_common::CorrectionStatus::Accepted => GqlCorrectionStatus::Accepted,
+            rootsignal_common::CorrectionStatus::Dismissed => GqlCorrectionStatus::Dismissed,
+        }
+    }
+}
+
+/// A correction request as shown publicly: the reporter's details are left out.
+#[derive(SimpleObject)]
+#[graphql(name = "Correction")]
+pub struct GqlCorrection {
+    pub id: Uuid,
+    pub kind: GqlCorrectionKind,
+    pub status: GqlCorrectionStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolution_note: Option<String>,
+}
+
+impl From<rootsignal_common::CorrectionRequest> for GqlCorrection {
+    fn from(c: rootsignal_common::CorrectionRequest) -> Self {
+        Self {
+            id: c.id,
+            kind: c.kind.into(),
+            status: c.status.into(),
+            created_at: c.created_at,
+            resolved_at: c.resolved_at,
+            resolution_note: c.resolution_note,
+        }
+    }
+}
+
+/// One piece of evidence an investigation turned up.
+#[derive(SimpleObject)]
+#[graphql(name = "InvestigationFinding")]
+pub struct GqlInvestigationFinding {
+    pub source_url: String,
+    pub relevance: String,
+    pub confidence: f32,
+    pub snippet: String,
+}
+
+/// An investigation of a signal: the question, the method, what was consulted
+/// and found, and how the signal's confidence moved as a result.
+#[derive(SimpleObject)]
+#[graphql(name = "Investigation")]
+pub struct GqlInvestigation {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub question: String,
+    pub method: String,
+    pub sources_consulted: Vec<String>,
+    pub findings: Vec<GqlInvestigationFinding>,
+    pub confidence_before: f32,
+    pub confidence_after: f32,
+    pub cost_cents: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::Investigation> for GqlInvestigation {
+    fn from(i: rootsignal_common::Investigation) -> Self {
+        Self {
+            id: i.id,
+            signal_id: i.signal_id,
+            question: i.question,
+            method: i.method,
+            sources_consulted: i.sources_consulted,
+            findings: i
+                .findings
+                .into_iter()
+                .map(|f| GqlInvestigationFinding {
+                    source_url: f.source_url,
+                    relevance: f.relevance,
+                    confidence: f.confidence,
+                    snippet: f.snippet,
+                })
+                .collect(),
+            confidence_before: i.confidence_before,
+            confidence_after: i.confidence_after,
+            cost_cents: i.cost_cents,
+            created_at: i.created_at,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "WhyNotStage")]
+pub enum GqlWhyNotStage {
+    NeverDiscovered,
+    Discovered,
+    FetchFailed,
+    Fetched,
+    Extracted,
+    Rejected,
+    Published,
+}
+
+impl From<why_not::Stage> for GqlWhyNotStage {
+    fn from(s: why_not::Stage) -> Self {
+        match s {
+            why_not::Stage::NeverDiscovered => GqlWhyNotStage::NeverDiscovered,
+            why_not::Stage::Discovered => GqlWhyNotStage::Discovered,
+            why_not::Stage::FetchFailed => GqlWhyNotStage::FetchFailed,
+            why_not::Stage::Fetched => GqlWhyNotStage::Fetched,
+            why_not::Stage::Extracted => GqlWhyNotStage::Extracted,
+            why_not::Stage::Rejected => GqlWhyNotStage::Rejected,
+            why_not::Stage::Published => GqlWhyNotStage::Published,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "WhyNotGate")]
+pub enum GqlWhyNotGate {
+    Safety,
+    Dedup,
+    Confidence,
+    Geography,
+    NegativeKnowledge,
+    Expired,
+}
+
+impl From<why_not::Gate> for GqlWhyNotGate {
+    fn from(g: why_not::Gate) -> Self {
+        match g {
+            why_not::Gate::Safety => GqlWhyNotGate::Safety,
+            why_not::Gate::Dedup => GqlWhyNotGate::Dedup,
+            why_not::Gate::Confidence => GqlWhyNotGate::Confidence,
+            why_not::Gate::Geography => GqlWhyNotGate::Geography,
+            why_not::Gate::NegativeKnowledge => GqlWhyNotGate::NegativeKnowledge,
+            why_not::Gate::Expired => GqlWhyNotGate::Expired,
+        }
+    }
+}
+
+/// A stored signal found for a "why not" lookup, visible or not.
+#[derive(SimpleObject)]
+#[graphql(name = "WhyNotSignal")]
+pub struct GqlWhyNotSignal {
+    pub id: Uuid,
+    pub signal_type: String,
+    pub title: String,
+    pub confidence: f32,
+    pub review_status: String,
+    /// What keeps it off the map; null when it is visible.
+    pub hidden_by: Option<GqlWhyNotGate>,
+}
+
+/// Why a URL or description is or isn't on the map: the furthest pipeline
+/// stage it reached, the gate that stopped it, and the evidence.
+#[derive(SimpleObject)]
+#[graphql(name = "WhyNot")]
+pub struct GqlWhyNot {
+    pub stage: GqlWhyNotStage,
+    pub gate: Option<GqlWhyNotGate>,
+    pub explanation: String,
+    pub source_known: bool,
+    pub last_archived: Option<DateTime<Utc>>,
+    pub fetch_attempts: u32,
+    pub extractions: u32,
+    /// Scout runs whose logs mention it, newest first.
+    pub run_ids: Vec<String>,
+    pub signals: Vec<GqlWhyNotSignal>,
+}
+
+impl GqlWhyNot {
+    pub fn new(why: why_not::WhyNot, facts: why_not::WhyNotFacts) -> Self {
+        let mut runs: Vec<(DateTime<Utc>, String)> = facts
+            .fetches
+            .iter()
+            .map(|f| (f.at, f.run_id.clone()))
+            .chain(facts.extractions.iter().map(|e| (e.at, e.run_id.clone())))
+            .chain(facts.outcomes.iter().map(|o| (o.at, o.run_id.clone())))
+            .collect();
+        runs.sort_by_key(|(at, _)| std::cmp::Reverse(*at));
+        let mut run_ids: Vec<String> = Vec::new();
+        for (_, run_id) in runs {
+            if !run_ids.contains(&run_id) {
+                run_ids.push(run_id);
+            }
+        }
+
+        Self {
+            stage: why.stage.into(),
+            gate: why.gate.map(Into::into),
+            explanation: why.explanation,
+            source_known: facts.source_known,
+            last_archived: facts.last_archived,
+            fetch_attempts: facts.fetches.len() as u32,
+            extractions: facts.extractions.len() as u32,
+            run_ids,
+            signals: facts
+                .signals
+                .into_iter()
+                .map(|s| GqlWhyNotSignal {
+                    hidden_by: s.hidden_by().map(Into::into),
+                    id: s.id,
+                    signal_type: s.node_type.to_string(),
+                    title: s.title,
+                    confidence: s.confidence,
+                    review_status: s.review_status,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "DecisionKind")]
+pub enum GqlDecisionKind {
+    ConfidenceChange,
+    Merge,
+    Suppression,
+    Boost,
+}
+
+impl From<rootsignal_common::DecisionKind> for GqlDecisionKind {
+    fn from(k: rootsignal_common::DecisionKind) -> Self {
+        match k {
+            rootsignal_common::DecisionKind::ConfidenceChange => GqlDecisionKind::ConfidenceChange,
+            rootsignal_common::DecisionKind::Merge => GqlDecisionKind::Merge,
+            rootsignal_common::DecisionKind::Suppression => GqlDecisionKind::Suppression,
+            rootsignal_common::DecisionKind::Boost => GqlDecisionKind::Boost,
+        }
+    }
+}
+
+/// An automated change to a signal: which component made it, on what
+/// inputs, with what outcome and why.
+#[derive(SimpleObject)]
+#[graphql(name = "Decision")]
+pub struct GqlDecision {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub signal_type: String,
+    pub kind: GqlDecisionKind,
+    pub component: String,
+    pub inputs_hash: String,
+    pub outcome: String,
+    pub rationale: String,
+    pub run_id: Option<String>,
+    pub decided_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::Decision> for GqlDecision {
+    fn from(d: rootsignal_common::Decision) -> Self {
+        Self {
+            id: d.id,
+            signal_id: d.subject_id,
+            signal_type: d.subject_type.to_string(),
+            kind: d.kind.into(),
+            component: d.component,
+            inputs_hash: d.inputs_hash,
+            outcome: d.outcome,
+            rationale: d.rationale,
+            run_id: d.run_id,
+            decided_at: d.decided_at,
+        }
+    }
+}
+
+/// A correction request in the admin triage queue.
+#[derive(SimpleObject)]
+#[graphql(name = "CorrectionRequest")]
+pub struct GqlCorrectionRequest {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub kind: GqlCorrectionKind,
+    pub details: Option<String>,
+    pub duplicate_of: Option<Uuid>,
+    pub status: GqlCorrectionStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::CorrectionRequest> for GqlCorrectionRequest {
+    fn from(c: rootsignal_common::CorrectionRequest) -> Self {
+        Self {
+            id: c.id,
+            signal_id: c.signal_id,
+            kind: c.kind.into(),
+            details: c.details,
+            duplicate_of: c.duplicate_of,
+            status: c.status.into(),
+            created_at: c.created_at,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "SavedSearch")]
+pub struct GqlSavedSearch {
+    pub id: Uuid,
+    pub name: String,
+    pub bbox: Option<GqlBoundingBox>,
+    pub types: Vec<SignalType>,
+    pub categories: Vec<String>,
+    pub keywords: Vec<String>,
+    pub notify: Vec<GqlNotifyChannel>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "BoundingBox")]
+pub struct GqlBoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "NotifyChannel")]
+pub struct GqlNotifyChannel {
+    pub channel: GqlNotifyChannelKind,
+    pub target: String,
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "NotifyChannelKind")]
+pub enum GqlNotifyChannelKind {
+    Email,
+    Sms,
+    Webhook,
+}
+
+impl GqlNotifyChannelKind {
+    pub fn to_channel(self, target: String) -> rootsignal_common::NotifyChannel {
+        match self {
+            GqlNotifyChannelKind::Email => rootsignal_common::NotifyChannel::Email(target),
+            GqlNotifyChannelKind::Sms => rootsignal_common::NotifyChannel::Sms(target),
+            GqlNotifyChannelKind::Webhook => rootsignal_common::NotifyChannel::Webhook(target),
+        }
+    }
+}
+
+impl From<rootsignal_common::SavedSearch> for GqlSavedSearch {
+    fn from(s: rootsignal_common::SavedSearch) -> Self {
+        Self {
+            id: s.id,
+            name: s.name,
+            bbox: s.bbox.map(|b| GqlBoundingBox {
+                min_lat: b.min_lat,
+                max_lat: b.max_lat,
+                min_lng: b.min_lng,
+                max_lng: b.max_lng,
+            }),
+            types: s.types.into_iter().map(SignalType::from).collect(),
+            categories: s.categories,
+            keywords: s.keywords,
+            notify: s
+                .notify
+                .into_iter()
+                .map(|c| {
+                    let (channel, target) = match c {
+                        rootsignal_common::NotifyChannel::Email(t) => (GqlNotifyChannelKind::Email, t),
+                        rootsignal_common::NotifyChannel::Sms(t) => (GqlNotifyChannelKind::Sms, t),
+                        rootsignal_common::NotifyChannel::Webhook(t) => (GqlNotifyChannelKind::Webhook, t),
+                    };
+                    GqlNotifyChannel { channel, target }
+                })
+                .collect(),
+            created_at: s.created_at,
+            last_seen_at: s.last_seen_at,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "RoomPlatform")]
+pub enum GqlRoomPlatform {
+    Discord,
+    Matrix,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "RoomFeed")]
+pub struct GqlRoomFeed {
+    pub id: Uuid,
+    pub name: String,
+    pub platform: GqlRoomPlatform,
+    /// The room, for display: a Discord webhook with its token masked, or a
+    /// Matrix room id and homeserver.
+    pub destination: String,
+    pub bbox: Option<GqlBoundingBox>,
+    pub types: Vec<SignalType>,
+    pub min_severity: Option<GqlSeverity>,
+    /// Null when the feed uses the default template.
+    pub template: Option<String>,
+    pub max_per_hour: u32,
+    pub paused: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    /// Null when Postgres isn't configured.
+    pub delivery: Option<GqlRoomDeliveryStats>,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "RoomDeliveryStats")]
+pub struct GqlRoomDeliveryStats {
+    pub pending: i64,
+    pub delivered_last_hour: i64,
+    pub failed: i64,
+    pub last_error: Option<String>,
+}
+
+impl From<crate::db::models::room_delivery::RoomDeliveryStats> for GqlRoomDeliveryStats {
+    fn from(s: crate::db::models::room_delivery::RoomDeliveryStats) -> Self {
+        Self {
+            pending: s.pending,
+            delivered_last_hour: s.delivered_last_hour,
+            failed: s.failed,
+            last_error: s.last_error,
+        }
+    }
+}
+
+impl From<rootsignal_common::RoomFeed> for GqlRoomFeed {
+    fn from(f: rootsignal_common::RoomFeed) -> Self {
+        let (platform, destination) = match &f.target {
+            rootsignal_common::RoomTarget::Discord { webhook_url } => {
+                // The token after the webhook id lets anyone post to the channel.
+                let masked = match webhook_url.rsplit_once('/') {
+                    Some((base, _token)) => format!("{base}/…"),
+                    None => webhook_url.clone(),
+                };
+                (GqlRoomPlatform::Discord, masked)
+            }
+            rootsignal_common::RoomTarget::Matrix { homeserver, room_id } => {
+                (GqlRoomPlatform::Matrix, format!("{room_id} on {homeserver}"))
+            }
+        };
+        Self {
+            id: f.id,
+            name: f.name,
+            platform,
+            destination,
+            bbox: f.bbox.map(|b| GqlBoundingBox {
+                min_lat: b.min_lat,
+                max_lat: b.max_lat,
+                min_lng: b.min_lng,
+                max_lng: b.max_lng,
+            }),
+            types: f.types.into_iter().map(SignalType::from).collect(),
+            min_severity: f.min_severity.map(Into::into),
+            template: f.template,
+            max_per_hour: f.max_per_hour,
+            paused: f.paused,
+            created_by: f.created_by,
+            created_at: f.created_at,
+            delivery: None,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SituationContext {
+    /// Census estimates for the tracts the situation's signals are in;
+    /// null when the region has no census data configured.
+    pub demographics: Option<DemographicContext>,
+}
+
+/// ACS 5-year estimates aggregated over the tracts a situation covers.
+/// Rates are fractions (0.0–1.0).
+#[derive(SimpleObject)]
+pub struct DemographicContext {
+    pub acs_year: u16,
+    pub tracts: u32,
+    pub population: u64,
+    pub poverty_rate: Option<f64>,
+    pub renter_share: Option<f64>,
+    pub limited_english_share: Option<f64>,
+    /// One-line summary for display.
+    pub summary: String,
+}
+
+impl From<rootsignal_common::DemographicContext> for DemographicContext {
+    fn from(c: rootsignal_common::DemographicContext) -> Self {
+        Self {
+            summary: c.describe(),
+            acs_year: c.acs_year,
+            tracts: c.tracts,
+            population: c.population,
+            poverty_rate: c.poverty_rate,
+            renter_share: c.renter_share,
+            limited_english_share: c.limited_english_share,
+        }
+    }
+}
+
+pub struct GqlDispatch(pub rootsignal_common::DispatchNode);
+
+#[Object]
+impl GqlDispatch {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+    async fn situation_id(&self) -> Uuid {
+        self.0.situation_id
+    }
+    async fn body(&self) -> &str {
+        &self.0.body
+    }
+    async fn signal_ids(&self) -> Vec<String> {
+        self.0.signal_ids.iter().map(|id| id.to_string()).collect()
+    }
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.0.created_at
     }
     async fn dispatch_type(&self) -> GqlDispatchType {
         self.0.dispatch_type.into()
@@ -1065,6 +2523,8 @@ pub struct GqlScoutTask {
     pub completed_at: Option<String>,
     /// Current workflow phase status for this task's region (e.g. "complete", "idle", "running_scrape").
     pub phase_status: String,
+    /// Region boundary as GeoJSON, for tasks covering an irregular area.
+    pub boundary_geojson: Option<String>,
 }
 
 impl GqlScoutTask {
@@ -1082,6 +2542,386 @@ impl GqlScoutTask {
             created_at: t.created_at.to_rfc3339(),
             completed_at: t.completed_at.map(|dt| dt.to_rfc3339()),
             phase_status: t.phase_status,
+            boundary_geojson: t.boundary.map(|b| b.to_geojson().to_string()),
+        }
+    }
+}
+
+// --- Retention types ---
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "DataClass")]
+pub enum GqlDataClass {
+    ArchiveContent,
+    EvidenceSnippets,
+    ResolvedSituations,
+    ScoutRunLogs,
+    DecisionRecords,
+}
+
+impl From<rootsignal_common::DataClass> for GqlDataClass {
+    fn from(c: rootsignal_common::DataClass) -> Self {
+        match c {
+            rootsignal_common::DataClass::ArchiveContent => GqlDataClass::ArchiveContent,
+            rootsignal_common::DataClass::EvidenceSnippets => GqlDataClass::EvidenceSnippets,
+            rootsignal_common::DataClass::ResolvedSituations => GqlDataClass::ResolvedSituations,
+            rootsignal_common::DataClass::ScoutRunLogs => GqlDataClass::ScoutRunLogs,
+            rootsignal_common::DataClass::DecisionRecords => GqlDataClass::DecisionRecords,
+        }
+    }
+}
+
+impl From<GqlDataClass> for rootsignal_common::DataClass {
+    fn from(c: GqlDataClass) -> Self {
+        match c {
+            GqlDataClass::ArchiveContent => rootsignal_common::DataClass::ArchiveContent,
+            GqlDataClass::EvidenceSnippets => rootsignal_common::DataClass::EvidenceSnippets,
+            GqlDataClass::ResolvedSituations => rootsignal_common::DataClass::ResolvedSituations,
+            GqlDataClass::ScoutRunLogs => rootsignal_common::DataClass::ScoutRunLogs,
+            GqlDataClass::DecisionRecords => rootsignal_common::DataClass::DecisionRecords,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "RetentionPolicy")]
+pub struct GqlRetentionPolicy {
+    pub class: GqlDataClass,
+    pub retain_days: u32,
+    pub default_retain_days: u32,
+}
+
+impl From<rootsignal_common::RetentionPolicy> for GqlRetentionPolicy {
+    fn from(p: rootsignal_common::RetentionPolicy) -> Self {
+        Self {
+            class: p.class.into(),
+            retain_days: p.retain_days,
+            default_retain_days: p.class.default_retain_days(),
+        }
+    }
+}
+
+/// An active legal hold. Exactly one of `node_id` and `source_url` is set.
+#[derive(SimpleObject)]
+#[graphql(name = "LegalHold")]
+pub struct GqlLegalHold {
+    pub id: Uuid,
+    pub node_id: Option<Uuid>,
+    pub source_url: Option<String>,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::LegalHold> for GqlLegalHold {
+    fn from(h: rootsignal_common::LegalHold) -> Self {
+        let (node_id, source_url) = match h.target {
+            rootsignal_common::HoldTarget::Node(id) => (Some(id), None),
+            rootsignal_common::HoldTarget::SourceUrl(url) => (None, Some(url)),
+        };
+        Self {
+            id: h.id,
+            node_id,
+            source_url,
+            reason: h.reason,
+            created_at: h.created_at,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "PurgeCount")]
+pub struct GqlPurgeCount {
+    pub class: GqlDataClass,
+    pub retain_days: u32,
+    /// Deleted, or in a dry run, would be deleted.
+    pub purged: u64,
+    /// Past retention but kept by a legal hold.
+    pub held: u64,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "RetentionRun")]
+pub struct GqlRetentionRun {
+    pub id: Uuid,
+    pub dry_run: bool,
+    pub ran_at: DateTime<Utc>,
+    pub total_purged: u64,
+    pub counts: Vec<GqlPurgeCount>,
+    pub errors: Vec<String>,
+}
+
+impl From<rootsignal_common::PurgeReport> for GqlRetentionRun {
+    fn from(r: rootsignal_common::PurgeReport) -> Self {
+        Self {
+            id: r.id,
+            dry_run: r.dry_run,
+            ran_at: r.ran_at,
+            total_purged: r.total_purged(),
+            counts: r
+                .counts
+                .into_iter()
+                .map(|c| GqlPurgeCount {
+                    class: c.class.into(),
+                    retain_days: c.retain_days,
+                    purged: c.purged,
+                    held: c.held,
+                })
+                .collect(),
+            errors: r.errors.into_iter().map(|(class, e)| format!("{}: {e}", class.as_str())).collect(),
+        }
+    }
+}
+
+// --- Data-subject request types ---
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "SubjectRequestStatus")]
+pub enum GqlSubjectRequestStatus {
+    Open,
+    Executed,
+    Dismissed,
+}
+
+impl From<rootsignal_common::SubjectRequestStatus> for GqlSubjectRequestStatus {
+    fn from(s: rootsignal_common::SubjectRequestStatus) -> Self {
+        match s {
+            rootsignal_common::SubjectRequestStatus::Open => GqlSubjectRequestStatus::Open,
+            rootsignal_common::SubjectRequestStatus::Executed => GqlSubjectRequestStatus::Executed,
+            rootsignal_common::SubjectRequestStatus::Dismissed => GqlSubjectRequestStatus::Dismissed,
+        }
+    }
+}
+
+impl From<GqlSubjectRequestStatus> for rootsignal_common::SubjectRequestStatus {
+    fn from(s: GqlSubjectRequestStatus) -> Self {
+        match s {
+            GqlSubjectRequestStatus::Open => rootsignal_common::SubjectRequestStatus::Open,
+            GqlSubjectRequestStatus::Executed => rootsignal_common::SubjectRequestStatus::Executed,
+            GqlSubjectRequestStatus::Dismissed => rootsignal_common::SubjectRequestStatus::Dismissed,
+        }
+    }
+}
+
+/// A data-subject request and, once executed, what it changed.
+#[derive(SimpleObject)]
+#[graphql(name = "SubjectRequest")]
+pub struct GqlSubjectRequest {
+    pub id: Uuid,
+    pub terms: Vec<String>,
+    pub note: Option<String>,
+    pub status: GqlSubjectRequestStatus,
+    pub opened_by: String,
+    pub created_at: DateTime<Utc>,
+    pub closed_by: Option<String>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub redacted_signals: Option<u32>,
+    pub anonymized_actors: Option<u32>,
+    pub redacted_evidence: Option<u32>,
+    /// Every node the execution changed.
+    pub redacted_node_ids: Vec<Uuid>,
+}
+
+impl From<rootsignal_common::SubjectRequest> for GqlSubjectRequest {
+    fn from(r: rootsignal_common::SubjectRequest) -> Self {
+        let redaction = r.redaction.as_ref();
+        Self {
+            id: r.id,
+            status: r.status.into(),
+            opened_by: r.opened_by,
+            created_at: r.created_at,
+            closed_by: r.closed_by,
+            closed_at: r.closed_at,
+            redacted_signals: redaction.map(|s| s.signals),
+            anonymized_actors: redaction.map(|s| s.actors),
+            redacted_evidence: redaction.map(|s| s.evidence),
+            redacted_node_ids: redaction.map(|s| s.node_ids.clone()).unwrap_or_default(),
+            terms: r.terms,
+            note: r.note,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "SubjectMatchKind")]
+pub enum GqlSubjectMatchKind {
+    Signal,
+    Actor,
+    Evidence,
+}
+
+impl From<rootsignal_common::SubjectMatchKind> for GqlSubjectMatchKind {
+    fn from(k: rootsignal_common::SubjectMatchKind) -> Self {
+        match k {
+            rootsignal_common::SubjectMatchKind::Signal => GqlSubjectMatchKind::Signal,
+            rootsignal_common::SubjectMatchKind::Actor => GqlSubjectMatchKind::Actor,
+            rootsignal_common::SubjectMatchKind::Evidence => GqlSubjectMatchKind::Evidence,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "SubjectMatch")]
+pub struct GqlSubjectMatch {
+    pub kind: GqlSubjectMatchKind,
+    pub id: Uuid,
+    pub label: String,
+    pub field: String,
+    pub excerpt: String,
+}
+
+impl From<rootsignal_common::SubjectMatch> for GqlSubjectMatch {
+    fn from(m: rootsignal_common::SubjectMatch) -> Self {
+        Self {
+            kind: m.kind.into(),
+            id: m.id,
+            label: m.label,
+            field: m.field,
+            excerpt: m.excerpt,
+        }
+    }
+}
+
+/// Everything an open request would touch, for review before execution.
+#[derive(SimpleObject)]
+#[graphql(name = "SubjectReview")]
+pub struct GqlSubjectReview {
+    pub request: GqlSubjectRequest,
+    pub matches: Vec<GqlSubjectMatch>,
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "QualityBreach")]
+pub enum GqlQualityBreach {
+    LowCohesion,
+    HighOverlap,
+}
+
+impl From<rootsignal_common::situation_quality::QualityBreach> for GqlQualityBreach {
+    fn from(b: rootsignal_common::situation_quality::QualityBreach) -> Self {
+        use rootsignal_common::situation_quality::QualityBreach;
+        match b {
+            QualityBreach::LowCohesion => GqlQualityBreach::LowCohesion,
+            QualityBreach::HighOverlap => GqlQualityBreach::HighOverlap,
+        }
+    }
+}
+
+/// A situation's clustering quality as of the weaver's last check.
+#[derive(SimpleObject)]
+#[graphql(name = "SituationQuality")]
+pub struct GqlSituationQuality {
+    pub situation_id: Uuid,
+    pub headline: String,
+    pub signal_count: u32,
+    /// Mean pairwise similarity of its signals.
+    pub cohesion: f64,
+    /// Mean silhouette of its signals, -1 to 1.
+    pub silhouette: f64,
+    pub nearest_situation_id: Option<Uuid>,
+    /// Centroid similarity to the nearest situation.
+    pub centroid_overlap: f64,
+    pub misplaced_signals: u32,
+    pub breach: Option<GqlQualityBreach>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub reweaved_at: Option<DateTime<Utc>>,
+    /// Re-woven recently enough that the weaver won't re-weave it yet.
+    pub cooling_down: bool,
+}
+
+impl From<rootsignal_graph::situation_quality::StoredQuality> for GqlSituationQuality {
+    fn from(q: rootsignal_graph::situation_quality::StoredQuality) -> Self {
+        Self {
+            cooling_down: q.cooling_down(Utc::now()),
+            situation_id: q.situation_id,
+            headline: q.headline,
+            signal_count: q.signal_count,
+            cohesion: q.cohesion,
+            silhouette: q.silhouette,
+            nearest_situation_id: q.nearest_situation_id,
+            centroid_overlap: q.centroid_overlap,
+            misplaced_signals: q.misplaced_signals,
+            breach: q.breach.map(Into::into),
+            checked_at: q.checked_at,
+            reweaved_at: q.reweaved_at,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(name = "InvestigationQueueState")]
+pub enum GqlQueueState {
+    Pending,
+    InProgress,
+    Blocked,
+    Done,
+    Skipped,
+}
+
+impl From<rootsignal_common::investigation_queue::QueueState> for GqlQueueState {
+    fn from(s: rootsignal_common::investigation_queue::QueueState) -> Self {
+        use rootsignal_common::investigation_queue::QueueState;
+        match s {
+            QueueState::Pending => GqlQueueState::Pending,
+            QueueState::InProgress => GqlQueueState::InProgress,
+            QueueState::Blocked => GqlQueueState::Blocked,
+            QueueState::Done => GqlQueueState::Done,
+            QueueState::Skipped => GqlQueueState::Skipped,
+        }
+    }
+}
+
+impl From<GqlQueueState> for rootsignal_common::investigation_queue::QueueState {
+    fn from(s: GqlQueueState) -> Self {
+        use rootsignal_common::investigation_queue::QueueState;
+        match s {
+            GqlQueueState::Pending => QueueState::Pending,
+            GqlQueueState::InProgress => QueueState::InProgress,
+            GqlQueueState::Blocked => QueueState::Blocked,
+            GqlQueueState::Done => QueueState::Done,
+            GqlQueueState::Skipped => QueueState::Skipped,
+        }
+    }
+}
+
+/// A signal in the investigation queue.
+#[derive(SimpleObject)]
+#[graphql(name = "InvestigationQueueEntry")]
+pub struct GqlQueueEntry {
+    pub signal_id: Uuid,
+    pub signal_type: String,
+    pub title: String,
+    pub source_url: String,
+    pub severity: Option<String>,
+    pub cause_heat: f64,
+    /// Open correction requests on the signal.
+    pub user_flags: u32,
+    pub pinned: bool,
+    pub state: GqlQueueState,
+    /// 0–1; pinned entries go first regardless.
+    pub priority_score: f64,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<rootsignal_common::investigation_queue::QueueEntry> for GqlQueueEntry {
+    fn from(e: rootsignal_common::investigation_queue::QueueEntry) -> Self {
+        Self {
+            priority_score: e.priority_score(Utc::now()),
+            signal_id: e.signal_id,
+            signal_type: e.node_type.to_string(),
+            title: e.title,
+            source_url: e.source_url,
+            severity: e.severity,
+            cause_heat: e.cause_heat,
+            user_flags: e.user_flags,
+            pinned: e.pinned,
+            state: e.state.into(),
+            attempts: e.attempts,
+            last_error: e.last_error,
+            queued_at: e.queued_at,
+            updated_at: e.updated_at,
         }
     }
 }
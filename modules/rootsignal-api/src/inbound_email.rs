@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{FromRequest, Multipart, Query, Request, State};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::Form;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use rootsignal_archive::{parse_ses_notification, InboundEmail, NewsletterInbox, SesNotification};
+use rootsignal_common::log_events::WebhookRejected;
+use rootsignal_common::Secret;
+
+/// Newsletters with attachments routinely exceed axum's 2MB default.
+pub const MAX_INBOUND_BYTES: usize = 25 * 1024 * 1024;
+
+pub struct InboundEmailState {
+    pub inbox: NewsletterInbox,
+    pub secret: Secret,
+}
+
+#[derive(Deserialize)]
+pub struct InboundEmailQuery {
+    key: Option<String>,
+}
+
+/// Email provider webhook. Mailgun routes post form data; SES receipt rules
+/// post SNS notifications (JSON sent as text/plain). Both authenticate with
+/// `?key=<INBOUND_EMAIL_SECRET>` in the configured webhook URL.
+///
+/// Unparseable mail gets 406, which tells Mailgun not to retry; storage
+/// failures get 500 so the provider redelivers.
+pub async fn inbound_email_handler(
+    State(state): State<Option<Arc<InboundEmailState>>>,
+    Query(params): Query<InboundEmailQuery>,
+    request: Request,
+) -> impl IntoResponse {
+    let Some(state) = state else {
+        return (StatusCode::SERVICE_UNAVAILABLE, "Inbound email not configured").into_response();
+    };
+    let authorized = params
+        .key
+        .as_deref()
+        .is_some_and(|k| constant_time_eq(k.as_bytes(), state.secret.expose().as_bytes()));
+    if !authorized {
+        WebhookRejected { webhook: "inbound_email", reason: "unauthorized", detail: String::new() }.emit();
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let content_type = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let parsed = if content_type.starts_with("multipart/form-data") {
+        match Multipart::from_request(request, &()).await {
+            Ok(multipart) => multipart_fields(multipart)
+                .await
+                .and_then(|f| InboundEmail::from_mailgun_fields(&f).map_err(|e| e.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    } else if content_type.starts_with("application/x-www-form-urlencoded") {
+        match Form::<HashMap<String, String>>::from_request(request, &()).await {
+            Ok(Form(fields)) => InboundEmail::from_mailgun_fields(&fields).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    } else {
+        let body = match String::from_request(request, &()).await {
+            Ok(b) => b,
+            Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        };
+        match parse_ses_notification(&body) {
+            Ok(SesNotification::Email(email)) => Ok(*email),
+            Ok(SesNotification::SubscriptionConfirmation { subscribe_url }) => {
+                return confirm_sns_subscription(&subscribe_url).await.into_response();
+            }
+            Ok(SesNotification::Ignored(kind)) => {
+                info!(kind, "Ignoring SNS notification");
+                return StatusCode::OK.into_response();
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    };
+
+    let email = match parsed {
+        Ok(email) => email,
+        Err(e) => {
+            WebhookRejected { webhook: "inbound_email", reason: "malformed", detail: e.clone() }.emit();
+            return (StatusCode::NOT_ACCEPTABLE, e).into_response();
+        }
+    };
+
+    match state.inbox.ingest(&email).await {
+        Ok(Some(issue)) => {
+            info!(source = issue.source_value.as_str(), post_id = %issue.post_id, "Inbound newsletter stored");
+            StatusCode::OK.into_response()
+        }
+        Ok(None) => StatusCode::OK.into_response(),
+        Err(e) => {
+            warn!(error = %e, from = email.from_address.as_str(), "Failed to archive inbound newsletter");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Text fields of a multipart form. Attachments are skipped.
+async fn multipart_fields(mut multipart: Multipart) -> Result<HashMap<String, String>, String> {
+    let mut fields = HashMap::new();
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.to_string())? {
+        if field.file_name().is_some() {
+            continue;
+        }
+        let Some(name) = field.name().map(String::from) else {
+            continue;
+        };
+        let value = field.text().await.map_err(|e| e.to_string())?;
+        fields.insert(name, value);
+    }
+    Ok(fields)
+}
+
+/// Confirm an SNS topic subscription. Only AWS-hosted URLs are fetched.
+async fn confirm_sns_subscription(subscribe_url: &str) -> StatusCode {
+    let is_aws = url::Url::parse(subscribe_url)
+        .ok()
+        .filter(|u| u.scheme() == "https")
+        .and_then(|u| u.host_str().map(|h| h.ends_with(".amazonaws.com")))
+        .unwrap_or(false);
+    if !is_aws {
+        warn!(subscribe_url, "Refusing non-AWS SNS subscription URL");
+        return StatusCode::BAD_REQUEST;
+    }
+    match reqwest::get(subscribe_url).await.and_then(|r| r.error_for_status()) {
+        Ok(_) => {
+            info!("SNS subscription confirmed for inbound email");
+            StatusCode::OK
+        }
+        Err(e) => {
+            warn!(error = %e, "SNS subscription confirmation failed");
+            StatusCode::BAD_GATEWAY
+        }
+    }
+}
+
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
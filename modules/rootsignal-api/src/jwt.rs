@@ -68,7 +68,7 @@ impl JwtService {
 }
 
 /// Derive a deterministic UUID v5-style ID from a phone number.
-fn phone_to_uuid(phone: &str) -> Uuid {
+pub fn phone_to_uuid(phone: &str) -> Uuid {
     let mut hasher = Sha256::new();
     hasher.update(b"rootsignal-member:");
     hasher.update(phone.as_bytes());
@@ -7,10 +7,10 @@ use anyhow::Result;
 use async_graphql::http::GraphiQLSource;
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use axum::{
-    extract::State,
+    extract::DefaultBodyLimit,
     http::{header, HeaderValue, Method},
     response::{Html, IntoResponse},
-    routing::get,
+    routing::{get, post},
     Router,
 };
 use tokio::sync::Mutex;
@@ -19,20 +19,33 @@ use tracing::info;
 use tracing_subscriber::EnvFilter;
 
 use rootsignal_common::Config;
-use rootsignal_graph::{CacheStore, CachedReader, GraphClient, GraphWriter, PublicGraphReader};
+use rootsignal_graph::{CacheStore, CachedReader, GraphClient, GraphRouter, GraphWriter, PublicGraphReader};
 use twilio::TwilioService;
 
+mod apify_webhook;
 mod db;
 mod graphql;
+mod inbound_email;
 mod jwt;
 mod link_preview;
 mod restate_client;
-
-use graphql::context::AuthContext;
+mod room_feeds;
+mod schedule_runner;
+mod situation_feed;
+#[cfg(test)]
+mod smoke_tests;
+mod stats_materializer;
+mod tenancy;
+mod triggers;
+mod watchlist_notify;
+mod why_not;
+
+use graphql::context::{AcceptLanguage, AuthContext, ViewerTier};
 use graphql::mutations::{ClientIp, RateLimiter, ResponseHeaders};
-use graphql::{build_schema, ApiSchema};
+use graphql::{build_schema, ApiSchema, SchemaDeps};
 use jwt::JwtService;
 use restate_client::RestateClient;
+use tenancy::{Deployment, Deployments};
 
 pub struct AppState {
     pub schema: ApiSchema,
@@ -44,10 +57,12 @@ pub struct AppState {
     pub region: String,
     pub rate_limiter: Mutex<HashMap<IpAddr, Vec<Instant>>>,
     pub jwt_service: JwtService,
+    /// This API's public base URL (`PUBLIC_API_URL`), for absolute feed links.
+    pub public_api_url: Option<String>,
 }
 
 async fn graphql_handler(
-    State(state): State<Arc<AppState>>,
+    Deployment(state): Deployment,
     headers: axum::http::HeaderMap,
     axum::extract::ConnectInfo(addr): axum::extract::ConnectInfo<std::net::SocketAddr>,
     req: GraphQLRequest,
@@ -65,11 +80,20 @@ async fn graphql_handler(
     let response_headers = Arc::new(ResponseHeaders(Mutex::new(Vec::new())));
     let auth_context = AuthContext(claims);
     let client_ip = ClientIp(addr.ip());
+    let accept_language = AcceptLanguage(
+        headers
+            .get(axum::http::header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string(),
+    );
 
     let mut request = req.into_inner();
     request = request
         .data(auth_context)
+        .data(ViewerTier::default())
         .data(client_ip)
+        .data(accept_language)
         .data(response_headers.clone());
 
     let gql_response = state.schema.execute(request).await;
@@ -99,6 +123,101 @@ async fn graphiql() -> impl IntoResponse {
     }
 }
 
+/// Every route the API serves, behind its CORS, security and logging layers.
+fn app(
+    state: Arc<Deployments>,
+    link_preview_cache: Arc<link_preview::LinkPreviewCache>,
+    inbound_email_state: Option<Arc<inbound_email::InboundEmailState>>,
+    apify_webhook_state: Option<Arc<apify_webhook::ApifyWebhookState>>,
+) -> Router {
+    Router::new()
+        // GraphQL
+        .route("/graphql", get(graphiql).post(graphql_handler))
+        // Health check
+        .route("/", get(|| async { "ok" }))
+        // Situation feeds and subscription management
+        .route("/situations/{id}/feed.atom", get(situation_feed::feed_handler))
+        .route("/situations/confirm/{token}", get(situation_feed::confirm_handler))
+        .route("/situations/unsubscribe/{token}", get(situation_feed::unsubscribe_handler))
+        // Polling triggers for Zapier-style integrations (API key required)
+        .route("/api/triggers/new-signals", get(triggers::new_signals_handler))
+        .route("/api/triggers/new-signals/sample", get(triggers::sample_handler))
+        .with_state(state)
+        // Link preview (separate state)
+        .route(
+            "/api/link-preview",
+            get(link_preview::link_preview_handler).with_state(link_preview_cache),
+        )
+        // Inbound newsletter email (Mailgun / SES webhook)
+        .route(
+            "/inbound/email",
+            post(inbound_email::inbound_email_handler)
+                .with_state(inbound_email_state)
+                .layer(DefaultBodyLimit::max(inbound_email::MAX_INBOUND_BYTES)),
+        )
+        // Apify run completions
+        .route(
+            "/webhooks/apify",
+            post(apify_webhook::apify_webhook_handler).with_state(apify_webhook_state),
+        )
+        // CORS: support credentials for JWT cookies
+        .layer(if cfg!(debug_assertions) {
+            tower_http::cors::CorsLayer::new()
+                .allow_origin([
+                    "http://localhost:5173".parse::<HeaderValue>().unwrap(),
+                    "http://localhost:5174".parse::<HeaderValue>().unwrap(),
+                ])
+                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                .allow_headers([header::CONTENT_TYPE, header::COOKIE, tenancy::API_KEY_HEADER.parse().unwrap()])
+                .allow_credentials(true)
+        } else {
+            let origins: Vec<HeaderValue> = std::env::var("CORS_ORIGINS")
+                .unwrap_or_else(|_| "https://rootsignal.app".to_string())
+                .split(',')
+                .filter_map(|s| s.trim().parse().ok())
+                .collect();
+            tower_http::cors::CorsLayer::new()
+                .allow_origin(origins)
+                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                .allow_headers([header::CONTENT_TYPE, header::COOKIE, tenancy::API_KEY_HEADER.parse().unwrap()])
+                .allow_credentials(true)
+        })
+        // Security headers
+        .layer(SetResponseHeaderLayer::overriding(
+            header::HeaderName::from_static("x-frame-options"),
+            HeaderValue::from_static("DENY"),
+        ))
+        .layer(SetResponseHeaderLayer::overriding(
+            header::HeaderName::from_static("x-content-type-options"),
+            HeaderValue::from_static("nosniff"),
+        ))
+        .layer(SetResponseHeaderLayer::overriding(
+            header::HeaderName::from_static("strict-transport-security"),
+            HeaderValue::from_static("max-age=63072000; includeSubDomains"),
+        ))
+        // Privacy headers: no caching for API responses
+        .layer(SetResponseHeaderLayer::overriding(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("no-store"),
+        ))
+        .layer(SetResponseHeaderLayer::overriding(
+            header::PRAGMA,
+            HeaderValue::from_static("no-cache"),
+        ))
+        // Logging layer
+        .layer(
+            tower_http::trace::TraceLayer::new_for_http().make_span_with(
+                |request: &axum::http::Request<_>| {
+                    tracing::info_span!(
+                        "http_request",
+                        method = %request.method(),
+                        path = %request.uri().path(),
+                    )
+                },
+            ),
+        )
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt()
@@ -111,32 +230,39 @@ async fn main() -> Result<()> {
     let client = GraphClient::connect(
         &config.neo4j_uri,
         &config.neo4j_user,
-        &config.neo4j_password,
+        &config.neo4j_password.expose(),
     )
     .await?;
 
-    rootsignal_graph::migrate::migrate(&client)
+    // Regions listed in REGION_DATABASES live in databases of their own
+    let router = GraphRouter::from_config(client.clone(), &config);
+    router
+        .migrate_all()
         .await
         .map_err(|e| anyhow::anyhow!("Migration failed: {e}"))?;
 
     // Build the in-memory cache. Block until loaded — no HTTP traffic until ready.
     info!("Loading signal cache from Neo4j…");
-    let initial_cache = rootsignal_graph::cache::SignalCache::load(&client)
+    let initial_cache = rootsignal_graph::cache::SignalCache::load_all(&router.clients().await?)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to load initial cache: {e}"))?;
     let cache_store = Arc::new(CacheStore::new(initial_cache));
 
-    // Spawn background reload loop
-    cache_store.spawn_reload_loop(client.clone());
+    // Spawn background reload loop; each reload matches new signals against watchlists
+    // and emails situation milestones to subscribers
+    let notifier = Arc::new(watchlist_notify::HttpWatchlistNotifier::from_env(&config));
+    cache_store.set_watchlist_notifier(notifier.clone());
+    cache_store.set_situation_notifier(notifier);
+    cache_store.spawn_reload_loop(router.clone());
 
     let neo4j_reader = PublicGraphReader::new(client.clone());
     let reader = Arc::new(CachedReader::new(cache_store.clone(), neo4j_reader));
     let writer = Arc::new(GraphWriter::new(client.clone()));
     let jwt_service = JwtService::new(
-        if config.session_secret.is_empty() {
-            &config.admin_password
+        &if config.session_secret.is_empty() {
+            config.admin_password.expose()
         } else {
-            &config.session_secret
+            config.session_secret.expose()
         },
         "rootsignal".to_string(),
     );
@@ -149,7 +275,7 @@ async fn main() -> Result<()> {
     let twilio = if !config.twilio_account_sid.is_empty() {
         Some(Arc::new(TwilioService::new(twilio::TwilioOptions {
             account_sid: config.twilio_account_sid.clone(),
-            auth_token: config.twilio_auth_token.clone(),
+            auth_token: config.twilio_auth_token.expose(),
             service_id: config.twilio_service_id.clone(),
         })))
     } else {
@@ -187,26 +313,49 @@ async fn main() -> Result<()> {
         info!("Postgres migrations applied");
     }
 
+    // Room feeds queue new signals on each reload; a worker posts them to Discord/Matrix
+    if let Some(ref pool) = pg_pool {
+        cache_store.set_room_feed_sink(Arc::new(room_feeds::PgRoomFeedSink::new(pool.clone())));
+        room_feeds::spawn(pool.clone(), client.clone());
+    }
+
+    // Read-only queries go to DATABASE_REPLICA_URL when it's set and keeping up
+    let pg_pools = match &pg_pool {
+        Some(pool) => {
+            let pools = db::PgPools::from_env(pool.clone()).await;
+            pools.spawn_lag_monitor();
+            Some(pools)
+        }
+        None => None,
+    };
+
     let restate_client = std::env::var("RESTATE_INGRESS_URL")
         .ok()
         .filter(|s| !s.is_empty())
         .map(RestateClient::new);
-    if restate_client.is_some() {
+    // Dashboard and public stats read per-region aggregates materialized here and after each scout run
+    let mut stats_regions: Vec<String> = config.region_databases.keys().cloned().collect();
+    stats_regions.push(config.region.clone());
+    stats_materializer::spawn(GraphWriter::new(client.clone()), router.clone(), stats_regions);
+
+    if let Some(ref restate) = restate_client {
         info!("Restate ingress configured — runScout will dispatch via Restate");
+        schedule_runner::spawn(GraphWriter::new(client.clone()), router.clone(), restate.clone());
     }
 
-    let schema = build_schema(
-        reader.clone(),
-        writer.clone(),
-        jwt_service.clone(),
-        Arc::new(config.clone()),
-        twilio.clone(),
-        RateLimiter(Mutex::new(HashMap::new())),
-        Arc::new(client.clone()),
-        cache_store.clone(),
+    let schema = build_schema(SchemaDeps {
+        reader: reader.clone(),
+        writer: writer.clone(),
+        jwt_service: jwt_service.clone(),
+        config: Arc::new(config.clone()),
+        twilio: twilio.clone(),
+        rate_limiter: RateLimiter(Mutex::new(HashMap::new())),
+        graph_client: Arc::new(client.clone()),
+        graph_router: router.clone(),
+        cache_store: cache_store.clone(),
         restate_client,
-        pg_pool.clone(),
-    );
+        pg_pools,
+    });
 
     // ========== Restate endpoint ==========
     // Runs on a separate port alongside the Axum GraphQL server.
@@ -219,7 +368,7 @@ async fn main() -> Result<()> {
     if let Some(ref pool) = pg_pool {
         let pool = pool.clone();
         let scout_deps = Arc::new(rootsignal_scout::workflows::ScoutDeps::from_config(
-            client.clone(),
+            router.clone(),
             pool,
             &config,
         ));
@@ -241,12 +390,17 @@ async fn main() -> Result<()> {
         use rootsignal_scout::workflows::supervisor::{SupervisorWorkflow, SupervisorWorkflowImpl};
         use rootsignal_scout::workflows::full_run::{FullScoutRunWorkflow, FullScoutRunWorkflowImpl};
         use rootsignal_scout::workflows::news_scanner::{NewsScanWorkflow, NewsScanWorkflowImpl};
+        use rootsignal_scout::workflows::verification::{VerificationWorkflow, VerificationWorkflowImpl};
+        use rootsignal_scout::workflows::onboarding::{RegionOnboardingWorkflow, RegionOnboardingWorkflowImpl};
         use rootsignal_archive::workflows::enrichment::{EnrichmentWorkflow, EnrichmentWorkflowImpl};
 
         let archive_deps = Arc::new(rootsignal_archive::workflows::ArchiveDeps {
             pg_pool: scout_deps.pg_pool.clone(),
             anthropic_api_key: scout_deps.anthropic_api_key.clone(),
-            openai_api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+            openai_api_key: rootsignal_common::Secret::new(
+                "OPENAI_API_KEY",
+                rootsignal_common::secrets::provider_from_env()?,
+            ),
         });
 
         let endpoint = builder
@@ -257,6 +411,8 @@ async fn main() -> Result<()> {
             .bind(SupervisorWorkflowImpl::with_deps(scout_deps.clone()).serve())
             .bind(FullScoutRunWorkflowImpl::with_deps(scout_deps.clone()).serve())
             .bind(NewsScanWorkflowImpl::with_deps(scout_deps.clone()).serve())
+            .bind(VerificationWorkflowImpl::with_deps(scout_deps.clone()).serve())
+            .bind(RegionOnboardingWorkflowImpl::with_deps(scout_deps.clone()).serve())
             .bind(EnrichmentWorkflowImpl::with_deps(archive_deps).serve())
             .build();
 
@@ -292,87 +448,50 @@ async fn main() -> Result<()> {
         });
     }
 
-    let state = Arc::new(AppState {
+    let primary = Arc::new(AppState {
         schema,
         reader: PublicGraphReader::new(client.clone()),
         writer: GraphWriter::new(client.clone()),
         graph_client: client,
         config: config.clone(),
-        twilio: twilio.map(|t| (*t).clone()),
+        twilio: twilio.clone().map(|t| (*t).clone()),
         region: config.region.clone(),
         rate_limiter: Mutex::new(HashMap::new()),
         jwt_service: jwt_service.clone(),
+        public_api_url: std::env::var("PUBLIC_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+            .map(|u| u.trim_end_matches('/').to_string()),
     });
 
+    // ========== Tenants ==========
+    // With TENANTS_FILE set, each tenant is served from its own database by hostname or API key.
+    let state = match rootsignal_common::TenantRegistry::from_env()? {
+        Some(registry) => Arc::new(Deployments::load(primary, registry, twilio.clone()).await?),
+        None => Arc::new(Deployments::single(primary)),
+    };
+
     let link_preview_cache = Arc::new(link_preview::LinkPreviewCache::new());
 
-    let app = Router::new()
-        // GraphQL
-        .route("/graphql", get(graphiql).post(graphql_handler))
-        // Health check
-        .route("/", get(|| async { "ok" }))
-        .with_state(state)
-        // Link preview (separate state)
-        .route(
-            "/api/link-preview",
-            get(link_preview::link_preview_handler).with_state(link_preview_cache),
-        )
-        // CORS: support credentials for JWT cookies
-        .layer(if cfg!(debug_assertions) {
-            tower_http::cors::CorsLayer::new()
-                .allow_origin([
-                    "http://localhost:5173".parse::<HeaderValue>().unwrap(),
-                    "http://localhost:5174".parse::<HeaderValue>().unwrap(),
-                ])
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                .allow_headers([header::CONTENT_TYPE, header::COOKIE])
-                .allow_credentials(true)
-        } else {
-            let origins: Vec<HeaderValue> = std::env::var("CORS_ORIGINS")
-                .unwrap_or_else(|_| "https://rootsignal.app".to_string())
-                .split(',')
-                .filter_map(|s| s.trim().parse().ok())
-                .collect();
-            tower_http::cors::CorsLayer::new()
-                .allow_origin(origins)
-                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                .allow_headers([header::CONTENT_TYPE, header::COOKIE])
-                .allow_credentials(true)
-        })
-        // Security headers
-        .layer(SetResponseHeaderLayer::overriding(
-            header::HeaderName::from_static("x-frame-options"),
-            HeaderValue::from_static("DENY"),
-        ))
-        .layer(SetResponseHeaderLayer::overriding(
-            header::HeaderName::from_static("x-content-type-options"),
-            HeaderValue::from_static("nosniff"),
-        ))
-        .layer(SetResponseHeaderLayer::overriding(
-            header::HeaderName::from_static("strict-transport-security"),
-            HeaderValue::from_static("max-age=63072000; includeSubDomains"),
-        ))
-        // Privacy headers: no caching for API responses
-        .layer(SetResponseHeaderLayer::overriding(
-            header::CACHE_CONTROL,
-            HeaderValue::from_static("no-store"),
-        ))
-        .layer(SetResponseHeaderLayer::overriding(
-            header::PRAGMA,
-            HeaderValue::from_static("no-cache"),
-        ))
-        // Logging layer
-        .layer(
-            tower_http::trace::TraceLayer::new_for_http().make_span_with(
-                |request: &axum::http::Request<_>| {
-                    tracing::info_span!(
-                        "http_request",
-                        method = %request.method(),
-                        path = %request.uri().path(),
-                    )
-                },
-            ),
-        );
+    // Newsletter ingestion webhook (needs Postgres + INBOUND_EMAIL_SECRET)
+    let inbound_email_state = match (&pg_pool, &config.inbound_email_secret) {
+        (Some(pool), Some(secret)) => Some(Arc::new(inbound_email::InboundEmailState {
+            inbox: rootsignal_archive::NewsletterInbox::new(pool.clone()),
+            secret: secret.clone(),
+        })),
+        _ => None,
+    };
+
+    // Apify run completion webhook (needs Postgres + APIFY_WEBHOOK_SECRET)
+    let apify_webhook_state = match (&pg_pool, &config.apify_webhook_secret) {
+        (Some(pool), Some(secret)) => Some(Arc::new(apify_webhook::ApifyWebhookState {
+            pool: pool.clone(),
+            secret: secret.clone(),
+        })),
+        _ => None,
+    };
+
+    let app = app(state, link_preview_cache, inbound_email_state, apify_webhook_state);
 
     // ========== Axum HTTP server ==========
     let addr = format!("{host}:{port}");
@@ -3,11 +3,23 @@
 //! The Restate Rust SDK doesn't ship an ingress client, so we wrap reqwest
 //! with typed methods for each workflow we need to call from the API server.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
 use reqwest::Client;
-use rootsignal_common::ScoutScope;
+use rootsignal_common::{slugify, ScoutScope};
+use serde::Deserialize;
 use thiserror::Error;
+use tokio::sync::Mutex;
 use tracing::info;
 
+/// Header Restate ingress uses to deduplicate invocations.
+const IDEMPOTENCY_HEADER: &str = "idempotency-key";
+
+/// Status the workflow output endpoint answers with while the run is going.
+const OUTPUT_NOT_READY: u16 = 470;
+
 #[derive(Debug, Error)]
 pub enum RestateError {
     #[error("Restate ingress error (HTTP {status}): {body}")]
@@ -40,13 +52,79 @@ impl ScoutPhase {
     }
 }
 
+/// Derive the idempotency key for one workflow execution: workflow + its
+/// Restate key. Retried submissions of the same run share it; a new run gets
+/// its own.
+pub fn idempotency_key(workflow: &str, key: &str) -> String {
+    format!("{workflow}:{key}")
+}
+
+/// Identifies a workflow execution in Restate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionHandle {
+    pub workflow: String,
+    pub key: String,
+    /// Restate invocation id, when the ingress reported one.
+    pub invocation_id: Option<String>,
+}
+
+/// Outcome of a workflow dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dispatch {
+    /// New execution started.
+    Started(ExecutionHandle),
+    /// Duplicate submission — attached to the execution already running for this key.
+    Attached(ExecutionHandle),
+}
+
+impl Dispatch {
+    pub fn handle(&self) -> &ExecutionHandle {
+        match self {
+            Self::Started(h) | Self::Attached(h) => h,
+        }
+    }
+
+    pub fn is_attached(&self) -> bool {
+        matches!(self, Self::Attached(_))
+    }
+}
+
+/// Body of a Restate ingress `/send` response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SendResponse {
+    invocation_id: Option<String>,
+    status: Option<String>,
+}
+
+fn parse_send_response(workflow: &str, key: &str, body: &str) -> Dispatch {
+    let parsed: Option<SendResponse> = serde_json::from_str(body).ok();
+    let handle = ExecutionHandle {
+        workflow: workflow.to_string(),
+        key: key.to_string(),
+        invocation_id: parsed.as_ref().and_then(|r| r.invocation_id.clone()),
+    };
+    match parsed.and_then(|r| r.status).as_deref() {
+        Some("PreviouslyAccepted") => Dispatch::Attached(handle),
+        _ => Dispatch::Started(handle),
+    }
+}
+
+/// The last execution this client dispatched per workflow and scope (region
+/// slug, or `"global"`).
+type InflightRuns = HashMap<(String, String), ExecutionHandle>;
+
 /// Client for dispatching Restate workflows via the HTTP ingress.
 ///
-/// Reuses a single `reqwest::Client` for connection pooling.
+/// Reuses a single `reqwest::Client` for connection pooling. Tracks the
+/// execution it last dispatched per workflow and scope: while that run is
+/// still going, another submission attaches to it instead of starting
+/// duplicate work; once it has finished or failed, the next one starts anew.
 #[derive(Clone)]
 pub struct RestateClient {
     http: Client,
     ingress_url: String,
+    inflight: Arc<Mutex<InflightRuns>>,
 }
 
 impl RestateClient {
@@ -54,25 +132,37 @@ impl RestateClient {
         Self {
             http: Client::new(),
             ingress_url,
+            inflight: Arc::default(),
         }
     }
 
     /// Start a `FullScoutRunWorkflow` for the given task.
     /// Restate key = task_id (UUID, inherently unique, one-shot).
-    pub async fn run_scout(&self, task_id: &str, scope: &ScoutScope) -> Result<(), RestateError> {
-        let url = format!("{}/FullScoutRunWorkflow/{task_id}/run", self.ingress_url);
-        info!(url = url.as_str(), task_id, "Dispatching scout via Restate");
+    ///
+    /// Deduplicated per region: a dispatch while the region's last run is
+    /// still going (e.g. a manual run overlapping a scheduled one) attaches to
+    /// it instead of starting new, budget-spending work.
+    pub async fn run_scout(&self, task_id: &str, scope: &ScoutScope) -> Result<Dispatch, RestateError> {
+        info!(task_id, "Dispatching scout via Restate");
 
         let body = serde_json::json!({ "task_id": task_id, "scope": scope });
-        let resp = self.http.post(&url).json(&body).send().await?;
+        self.dispatch("FullScoutRunWorkflow", &slugify(&scope.name), task_id, body).await
+    }
 
-        if resp.status().is_success() {
-            Ok(())
-        } else {
-            let status = resp.status().as_u16();
-            let body = resp.text().await.unwrap_or_default();
-            Err(RestateError::Ingress { status, body })
-        }
+    /// Start a `FullScoutRunWorkflow` for one occurrence of a region schedule.
+    /// A workflow key only ever runs once, so each occurrence gets its own
+    /// (`{task_id}-{occurrence}`); the per-region dedup still applies.
+    pub async fn run_scheduled_scout(
+        &self,
+        task_id: &str,
+        scope: &ScoutScope,
+        occurrence: DateTime<Utc>,
+    ) -> Result<Dispatch, RestateError> {
+        let key = format!("{task_id}-{}", occurrence.timestamp());
+        info!(task_id, %occurrence, "Dispatching scheduled scout via Restate");
+
+        let body = serde_json::json!({ "task_id": task_id, "scope": scope });
+        self.dispatch("FullScoutRunWorkflow", &slugify(&scope.name), &key, body).await
     }
 
     /// Dispatch an individual scout workflow phase via Restate ingress.
@@ -82,46 +172,127 @@ impl RestateClient {
         phase: ScoutPhase,
         task_id: &str,
         scope: &ScoutScope,
-    ) -> Result<(), RestateError> {
+    ) -> Result<Dispatch, RestateError> {
         let workflow_name = phase.workflow_name();
-        let key = format!("{task_id}-{}", chrono::Utc::now().timestamp());
-        let url = format!("{}/{workflow_name}/{key}/run", self.ingress_url);
-        info!(url = url.as_str(), phase = ?phase, task_id, "Dispatching individual phase via Restate");
+        let key = format!("{task_id}-{}", Utc::now().timestamp());
+        info!(phase = ?phase, task_id, "Dispatching individual phase via Restate");
 
         let body = match phase {
-            ScoutPhase::Synthesis | ScoutPhase::SituationWeaver => {
+            ScoutPhase::Synthesis | ScoutPhase::SituationWeaver | ScoutPhase::Supervisor => {
                 serde_json::json!({ "task_id": task_id, "scope": scope, "spent_cents": 0u64 })
             }
             _ => serde_json::json!({ "task_id": task_id, "scope": scope }),
         };
 
-        let resp = self.http.post(&url).json(&body).send().await?;
-
-        if resp.status().is_success() {
-            Ok(())
-        } else {
-            let status = resp.status().as_u16();
-            let body = resp.text().await.unwrap_or_default();
-            Err(RestateError::Ingress { status, body })
-        }
+        self.dispatch(workflow_name, &slugify(&scope.name), &key, body).await
     }
 
     /// Start a `NewsScanWorkflow` (global, no region).
-    pub async fn run_news_scan(&self) -> Result<(), RestateError> {
-        let key = format!("news-{}", chrono::Utc::now().timestamp());
-        let url = format!("{}/NewsScanWorkflow/{key}/run", self.ingress_url);
-        info!(url = url.as_str(), "Dispatching news scan via Restate");
+    pub async fn run_news_scan(&self) -> Result<Dispatch, RestateError> {
+        let key = format!("global-{}", Utc::now().timestamp());
+        info!(key = key.as_str(), "Dispatching news scan via Restate");
 
-        let body = serde_json::json!({});
-        let resp = self.http.post(&url).json(&body).send().await?;
+        self.dispatch("NewsScanWorkflow", "global", &key, serde_json::json!({})).await
+    }
 
-        if resp.status().is_success() {
-            Ok(())
-        } else {
-            let status = resp.status().as_u16();
-            let body = resp.text().await.unwrap_or_default();
-            Err(RestateError::Ingress { status, body })
+    /// Start a `VerificationWorkflow` sweep for a region. With `repeat_every_hours`
+    /// set, the workflow re-schedules itself after each sweep.
+    pub async fn run_verification(
+        &self,
+        scope: &ScoutScope,
+        repeat_every_hours: Option<u32>,
+    ) -> Result<Dispatch, RestateError> {
+        let region = slugify(&scope.name);
+        let key = format!("{region}-{}", Utc::now().timestamp());
+        info!(key = key.as_str(), "Dispatching verification sweep via Restate");
+
+        let body = serde_json::json!({ "scope": scope, "repeat_every_hours": repeat_every_hours });
+        self.dispatch("VerificationWorkflow", &region, &key, body).await
+    }
+
+    /// Start a `RegionOnboardingWorkflow` for a newly created region, keyed by
+    /// its task so it runs once per region.
+    pub async fn run_onboarding(
+        &self,
+        task_id: &str,
+        scope: &ScoutScope,
+        timezone: &str,
+    ) -> Result<Dispatch, RestateError> {
+        info!(task_id, "Dispatching region onboarding via Restate");
+
+        let body = serde_json::json!({ "task_id": task_id, "scope": scope, "timezone": timezone });
+        self.dispatch("RegionOnboardingWorkflow", &slugify(&scope.name), task_id, body).await
+    }
+
+    /// Re-enqueue a dead-lettered item into the workflow it failed in.
+    /// Returns the workflow key the replay was started under.
+    pub async fn replay_dead_letter(
+        &self,
+        pool: &sqlx::PgPool,
+        id: uuid::Uuid,
+    ) -> Result<String, rootsignal_common::dead_letter::DeadLetterError> {
+        rootsignal_common::dead_letter::replay(pool, &self.http, &self.ingress_url, id).await
+    }
+
+    /// Whether the execution is still going. Anything but Restate's "not
+    /// ready" answer — output, a failure, no such run — means it is over.
+    async fn is_running(&self, handle: &ExecutionHandle) -> Result<bool, RestateError> {
+        let url = format!("{}/restate/workflow/{}/{}/output", self.ingress_url, handle.workflow, handle.key);
+        let resp = self.http.get(&url).send().await?;
+        Ok(resp.status().as_u16() == OUTPUT_NOT_READY)
+    }
+
+    /// Send a workflow run under `key`, unless the run last dispatched for
+    /// `workflow` and `scope` is still going, in which case that run is
+    /// returned. Finished runs are forgotten, so a re-run after one starts.
+    /// The lock is held across the send so concurrent submissions can't both
+    /// start.
+    async fn dispatch(
+        &self,
+        workflow: &str,
+        scope: &str,
+        key: &str,
+        body: serde_json::Value,
+    ) -> Result<Dispatch, RestateError> {
+        let run = (workflow.to_string(), scope.to_string());
+        let mut inflight = self.inflight.lock().await;
+        if let Some(handle) = inflight.get(&run) {
+            if self.is_running(handle).await? {
+                info!(workflow, scope, key = handle.key.as_str(), "Attaching to existing run");
+                return Ok(Dispatch::Attached(handle.clone()));
+            }
+            inflight.remove(&run);
         }
+
+        let url = format!("{}/{workflow}/{key}/run/send", self.ingress_url);
+        let resp = self
+            .http
+            .post(&url)
+            .header(IDEMPOTENCY_HEADER, idempotency_key(workflow, key))
+            .json(&body)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await.unwrap_or_default();
+        let dispatch = if status.is_success() {
+            parse_send_response(workflow, key, &text)
+        } else if status.as_u16() == 409 {
+            // Workflow key already has a run — that run is the existing execution.
+            Dispatch::Attached(ExecutionHandle {
+                workflow: workflow.to_string(),
+                key: key.to_string(),
+                invocation_id: None,
+            })
+        } else {
+            return Err(RestateError::Ingress {
+                status: status.as_u16(),
+                body: text,
+            });
+        };
+
+        inflight.insert(run, dispatch.handle().clone());
+        Ok(dispatch)
     }
 
     /// Cancel a running `FullScoutRunWorkflow`.
@@ -143,3 +314,112 @@ impl RestateClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Restate ingress serving `send` and the workflow output endpoint.
+    /// Runs stay going until `finish` is called for their key.
+    #[derive(Clone, Default)]
+    struct FakeIngress {
+        sent: Arc<std::sync::Mutex<Vec<String>>>,
+        finished: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl FakeIngress {
+        async fn serve(&self) -> String {
+            use axum::extract::{Path, State};
+            use axum::http::StatusCode;
+            use axum::routing::{get, post};
+
+            async fn send(State(fake): State<FakeIngress>, Path((_, key)): Path<(String, String)>) -> String {
+                fake.sent.lock().unwrap().push(key);
+                r#"{"invocationId":"inv_1","status":"Accepted"}"#.to_string()
+            }
+            async fn output(State(fake): State<FakeIngress>, Path((_, key)): Path<(String, String)>) -> StatusCode {
+                if fake.finished.lock().unwrap().contains(&key) {
+                    StatusCode::OK
+                } else {
+                    StatusCode::from_u16(OUTPUT_NOT_READY).unwrap()
+                }
+            }
+
+            let app = axum::Router::new()
+                .route("/{workflow}/{key}/run/send", post(send))
+                .route("/restate/workflow/{workflow}/{key}/output", get(output))
+                .with_state(self.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move { axum::serve(listener, app).await });
+            format!("http://{addr}")
+        }
+
+        fn finish(&self, key: &str) {
+            self.finished.lock().unwrap().push(key.to_string());
+        }
+
+        fn sent(&self) -> Vec<String> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+
+    fn scope(name: &str) -> ScoutScope {
+        ScoutScope {
+            center_lat: 44.97,
+            center_lng: -93.26,
+            radius_km: 20.0,
+            name: name.to_string(),
+            boundary: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn second_run_for_a_region_attaches_while_the_first_is_going() {
+        let ingress = FakeIngress::default();
+        let client = RestateClient::new(ingress.serve().await);
+
+        let first = client.run_scout("task-1", &scope("Twin Cities")).await.unwrap();
+        let second = client.run_scout("task-2", &scope("Twin Cities")).await.unwrap();
+        let other = client.run_scout("task-3", &scope("Duluth")).await.unwrap();
+
+        assert!(!first.is_attached());
+        assert!(second.is_attached());
+        assert_eq!(second.handle().key, "task-1");
+        assert!(!other.is_attached());
+        assert_eq!(ingress.sent(), ["task-1", "task-3"]);
+    }
+
+    #[tokio::test]
+    async fn rerun_after_the_region_run_finishes_dispatches() {
+        let ingress = FakeIngress::default();
+        let client = RestateClient::new(ingress.serve().await);
+        client.run_scout("task-1", &scope("Twin Cities")).await.unwrap();
+
+        ingress.finish("task-1");
+        let rerun = client.run_scout("task-1-rerun", &scope("Twin Cities")).await.unwrap();
+
+        assert!(!rerun.is_attached());
+        assert_eq!(rerun.handle().key, "task-1-rerun");
+        assert_eq!(ingress.sent(), ["task-1", "task-1-rerun"]);
+    }
+
+    #[test]
+    fn previously_accepted_send_response_attaches_to_existing_run() {
+        let body = r#"{"invocationId":"inv_1abc","status":"PreviouslyAccepted"}"#;
+
+        let dispatch = parse_send_response("FullScoutRunWorkflow", "task-1", body);
+
+        assert!(dispatch.is_attached());
+        assert_eq!(dispatch.handle().invocation_id.as_deref(), Some("inv_1abc"));
+    }
+
+    #[test]
+    fn accepted_send_response_starts_new_run() {
+        let body = r#"{"invocationId":"inv_2def","status":"Accepted"}"#;
+
+        let dispatch = parse_send_response("FullScoutRunWorkflow", "task-2", body);
+
+        assert!(!dispatch.is_attached());
+    }
+}
@@ -0,0 +1,256 @@
+//! Posts room feed messages to Discord channels and Matrix rooms.
+//!
+//! On each cache reload, new signals matching a feed are rendered and queued
+//! in `room_deliveries`. A worker drains the queue every tick, holding each
+//! feed to its messages-per-hour limit and retrying failed posts with
+//! exponential backoff (or the platform's rate-limit hint, when longer).
+//! Matrix posts use the bot account in `MATRIX_ACCESS_TOKEN`.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::json;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::{Node, RoomFeed, RoomTarget};
+use rootsignal_graph::room_feeds::{room_feeds, RoomFeedSink};
+use rootsignal_graph::GraphClient;
+
+use crate::db::models::room_delivery;
+
+/// Default seconds between queue checks.
+const DEFAULT_TICK_SECS: u64 = 30;
+/// Deliveries considered per tick, across all feeds.
+const DELIVERIES_PER_TICK: u32 = 100;
+
+/// Queues rendered messages in Postgres for the delivery worker.
+pub struct PgRoomFeedSink {
+    pool: PgPool,
+    /// Public site base URL, for links to signals.
+    site_url: Option<String>,
+}
+
+impl PgRoomFeedSink {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            site_url: std::env::var("PUBLIC_SITE_URL")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .map(|u| u.trim_end_matches('/').to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl RoomFeedSink for PgRoomFeedSink {
+    async fn enqueue(&self, feed: &RoomFeed, signals: &[Node]) -> anyhow::Result<()> {
+        for node in signals {
+            let url = self.site_url.as_ref().map(|base| format!("{base}/signals/{}", node.id()));
+            let body = feed.render(node, url.as_deref());
+            room_delivery::enqueue(&self.pool, feed.id, node.id(), &body).await?;
+        }
+        Ok(())
+    }
+}
+
+/// A failed post, with the platform's rate-limit hint if it sent one.
+struct PostError {
+    message: String,
+    retry_after: Option<Duration>,
+}
+
+impl From<reqwest::Error> for PostError {
+    fn from(e: reqwest::Error) -> Self {
+        Self { message: e.to_string(), retry_after: None }
+    }
+}
+
+struct RoomPoster {
+    client: reqwest::Client,
+    matrix_token: Option<String>,
+}
+
+impl RoomPoster {
+    fn from_env() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(15))
+                .build()
+                .expect("Failed to build room feed HTTP client"),
+            matrix_token: std::env::var("MATRIX_ACCESS_TOKEN").ok().filter(|v| !v.is_empty()),
+        }
+    }
+
+    /// Post `body` to `target`. `txn_id` makes Matrix retries idempotent.
+    async fn post(&self, target: &RoomTarget, body: &str, txn_id: Uuid) -> Result<(), PostError> {
+        let response = match target {
+            RoomTarget::Discord { webhook_url } => {
+                self.client
+                    .post(webhook_url)
+                    // Signal text must never ping @everyone or a role.
+                    .json(&json!({ "content": body, "allowed_mentions": { "parse": [] } }))
+                    .send()
+                    .await?
+            }
+            RoomTarget::Matrix { homeserver, room_id } => {
+                let Some(token) = &self.matrix_token else {
+                    return Err(PostError { message: "MATRIX_ACCESS_TOKEN not set".to_string(), retry_after: None });
+                };
+                let mut url = reqwest::Url::parse(homeserver).map_err(|e| PostError {
+                    message: format!("Invalid homeserver URL: {e}"),
+                    retry_after: None,
+                })?;
+                if let Ok(mut path) = url.path_segments_mut() {
+                    path.pop_if_empty().extend([
+                        "_matrix",
+                        "client",
+                        "v3",
+                        "rooms",
+                        room_id.as_str(),
+                        "send",
+                        "m.room.message",
+                        &txn_id.to_string(),
+                    ]);
+                }
+                self.client
+                    .put(url)
+                    .bearer_auth(token)
+                    .json(&json!({ "msgtype": "m.notice", "body": body }))
+                    .send()
+                    .await?
+            }
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let header_hint = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<f64>().ok());
+        let text = response.text().await.unwrap_or_default();
+        let retry_after = retry_after_secs(header_hint, &text).map(Duration::from_secs_f64);
+        Err(PostError {
+            message: format!("{status}: {}", text.chars().take(300).collect::<String>()),
+            retry_after,
+        })
+    }
+}
+
+/// Seconds to wait from a rate-limited response: the `Retry-After` header,
+/// Discord's `retry_after` (seconds) or Matrix's `retry_after_ms`.
+fn retry_after_secs(header: Option<f64>, body: &str) -> Option<f64> {
+    let json: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+    header
+        .or_else(|| json.get("retry_after").and_then(|v| v.as_f64()))
+        .or_else(|| json.get("retry_after_ms").and_then(|v| v.as_f64()).map(|ms| ms / 1000.0))
+        .filter(|s| s.is_finite() && *s >= 0.0)
+}
+
+/// Drain the delivery queue every `ROOM_FEED_TICK_SECS` (default 30).
+pub fn spawn(pool: PgPool, client: GraphClient) {
+    let secs = std::env::var("ROOM_FEED_TICK_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|&s| s > 0)
+        .unwrap_or(DEFAULT_TICK_SECS);
+    let poster = RoomPoster::from_env();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(secs));
+        loop {
+            interval.tick().await;
+            if let Err(e) = deliver_due(&pool, &client, &poster).await {
+                warn!(error = %e, "Room feed delivery failed");
+            }
+        }
+    });
+
+    info!(interval_secs = secs, "Room feed worker started");
+}
+
+async fn deliver_due(pool: &PgPool, client: &GraphClient, poster: &RoomPoster) -> anyhow::Result<()> {
+    let now = Utc::now();
+    let due = room_delivery::due(pool, now, DELIVERIES_PER_TICK).await?;
+    if due.is_empty() {
+        return Ok(());
+    }
+    let feeds: HashMap<Uuid, RoomFeed> = room_feeds(client).await?.into_iter().map(|f| (f.id, f)).collect();
+
+    let mut by_feed: HashMap<Uuid, Vec<room_delivery::RoomDeliveryRow>> = HashMap::new();
+    for delivery in due {
+        by_feed.entry(delivery.feed_id).or_default().push(delivery);
+    }
+
+    let mut delivered = 0;
+    for (feed_id, deliveries) in by_feed {
+        let Some(feed) = feeds.get(&feed_id) else {
+            // Feed deleted since these were queued.
+            room_delivery::delete_feed(pool, feed_id).await?;
+            continue;
+        };
+        if feed.paused {
+            // Keep the queue for when the feed resumes, out of other feeds' way.
+            room_delivery::defer_feed(pool, feed_id, now + chrono::Duration::hours(1)).await?;
+            continue;
+        }
+
+        let sent = room_delivery::delivered_since(pool, feed_id, now - chrono::Duration::hours(1)).await?;
+        let mut allowance = (feed.max_per_hour as i64 - sent).max(0);
+        for delivery in deliveries {
+            if allowance == 0 {
+                // Over the hourly limit: look again once a slot has likely freed up.
+                let wait = chrono::Duration::seconds(3600 / feed.max_per_hour.max(1) as i64);
+                room_delivery::defer_feed(pool, feed_id, now + wait).await?;
+                break;
+            }
+            match poster.post(&feed.target, &delivery.body, delivery.id).await {
+                Ok(()) => {
+                    room_delivery::mark_delivered(pool, delivery.id, Utc::now()).await?;
+                    allowance -= 1;
+                    delivered += 1;
+                }
+                Err(e) => {
+                    let next = room_delivery::next_attempt_at(delivery.attempts + 1, e.retry_after, Utc::now());
+                    warn!(
+                        feed = %feed_id,
+                        signal = %delivery.signal_id,
+                        attempt = delivery.attempts + 1,
+                        error = e.message.as_str(),
+                        "Room feed post failed"
+                    );
+                    room_delivery::mark_attempt_failed(pool, delivery.id, &e.message, next).await?;
+                    // The room is likely down or rate limiting; try the rest later.
+                    if let Some(next) = next {
+                        room_delivery::defer_feed(pool, feed_id, next).await?;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+    if delivered > 0 {
+        info!(delivered, "Room feed messages posted");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_hint_comes_from_header_then_discord_then_matrix() {
+        assert_eq!(retry_after_secs(Some(5.0), r#"{"retry_after": 2.5}"#), Some(5.0));
+        assert_eq!(retry_after_secs(None, r#"{"retry_after": 2.5, "global": false}"#), Some(2.5));
+        assert_eq!(retry_after_secs(None, r#"{"errcode": "M_LIMIT_EXCEEDED", "retry_after_ms": 1500}"#), Some(1.5));
+        assert_eq!(retry_after_secs(None, "Bad Gateway"), None);
+    }
+}
@@ -0,0 +1,122 @@
+//! Starts scout runs on each region's schedule.
+//!
+//! Schedules are re-read from the graph every tick, so edits, pauses and
+//! resumes made through the admin API take effect without a redeploy. A due
+//! run waits while the region calendar blocks scouting, and is skipped (not
+//! queued) when the region already has a run in flight. Schedules and tasks
+//! live in the default database; calendars in the region's own.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use rootsignal_common::log_events::ScheduledRunFailed;
+use rootsignal_common::{slugify, RegionSchedule, ScoutScope};
+use rootsignal_graph::{GraphRouter, GraphWriter};
+
+use crate::restate_client::RestateClient;
+
+/// Default seconds between schedule checks.
+const DEFAULT_TICK_SECS: u64 = 60;
+
+/// Check schedules every `SCHEDULE_TICK_SECS` (default 60) and dispatch due runs.
+pub fn spawn(writer: GraphWriter, router: GraphRouter, restate: RestateClient) {
+    let secs = std::env::var("SCHEDULE_TICK_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|&s| s > 0)
+        .unwrap_or(DEFAULT_TICK_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(secs));
+        loop {
+            interval.tick().await;
+            match writer.list_region_schedules().await {
+                Ok(schedules) => {
+                    for (region, schedule) in schedules {
+                        run_if_due(&writer, &router, &restate, &region, &schedule, Utc::now()).await;
+                    }
+                }
+                Err(e) => warn!(error = %e, "Failed to load region schedules"),
+            }
+        }
+    });
+
+    info!(interval_secs = secs, "Region schedule runner started");
+}
+
+async fn run_if_due(
+    writer: &GraphWriter,
+    router: &GraphRouter,
+    restate: &RestateClient,
+    region: &str,
+    schedule: &RegionSchedule,
+    now: DateTime<Utc>,
+) {
+    let Some(occurrence) = schedule.due_run(now) else {
+        return;
+    };
+
+    let calendar = match router.for_region(region).await {
+        Ok(client) => GraphWriter::new(client).get_region_calendar(region).await,
+        Err(e) => Err(e),
+    };
+    match calendar {
+        Ok(Some(calendar)) => {
+            if let Some(block) = calendar.blocked_at(now) {
+                info!(region, %block, "Scheduled run waiting on region calendar");
+                return;
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!(region, error = %e, "Failed to load region calendar, running anyway"),
+    }
+
+    let task = match writer.get_scout_task(&schedule.task_id).await {
+        Ok(Some(task)) => task,
+        Ok(None) => {
+            ScheduledRunFailed {
+                region: region.to_string(),
+                stage: "load_task",
+                error: format!("scout task {} no longer exists", schedule.task_id),
+            }
+            .emit();
+            return;
+        }
+        Err(e) => {
+            ScheduledRunFailed { region: region.to_string(), stage: "load_task", error: e.to_string() }.emit();
+            return;
+        }
+    };
+    if slugify(&task.context) != region {
+        warn!(region, context = task.context.as_str(), "Scheduled task belongs to another region, skipping");
+        return;
+    }
+
+    match writer.running_region_task_id(&task.context).await {
+        Ok(Some(running)) => {
+            info!(region, running = running.as_str(), %occurrence, "Region already running, skipping scheduled run");
+            if let Err(e) = writer.mark_region_schedule_run(region, now).await {
+                warn!(region, error = %e, "Failed to record skipped scheduled run");
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(e) => {
+            ScheduledRunFailed { region: region.to_string(), stage: "check_running", error: e.to_string() }.emit();
+            return;
+        }
+    }
+
+    let scope = ScoutScope::from(&task);
+    match restate.run_scheduled_scout(&schedule.task_id, &scope, occurrence).await {
+        Ok(dispatch) => {
+            info!(region, %occurrence, attached = dispatch.is_attached(), "Scheduled scout run dispatched");
+            if let Err(e) = writer.mark_region_schedule_run(region, now).await {
+                warn!(region, error = %e, "Failed to record scheduled run");
+            }
+        }
+        Err(e) => ScheduledRunFailed { region: region.to_string(), stage: "dispatch", error: format!("{e:#}") }.emit(),
+    }
+}
@@ -0,0 +1,74 @@
+//! Per-situation Atom feeds, and the confirm and unsubscribe links from
+//! subscription emails.
+
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use tracing::warn;
+use uuid::Uuid;
+
+use rootsignal_common::situation_feed::{render_atom, FEED_ENTRY_LIMIT};
+
+use crate::tenancy::Deployment;
+
+/// `GET /situations/{id}/feed.atom`: newly linked signals and arc changes.
+pub async fn feed_handler(Deployment(state): Deployment, Path(id): Path<Uuid>) -> axum::response::Response {
+    let situation = match state.reader.situation_by_id(&id).await {
+        Ok(Some(situation)) => situation,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            warn!(error = %e, situation = %id, "Failed to load situation for feed");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let entries = match rootsignal_graph::situation_feed::feed_entries(&state.graph_client, id, FEED_ENTRY_LIMIT).await
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!(error = %e, situation = %id, "Failed to load situation feed");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let path = format!("/situations/{id}/feed.atom");
+    let self_url = match &state.public_api_url {
+        Some(base) => format!("{base}{path}"),
+        None => path,
+    };
+    let site_url = std::env::var("PUBLIC_SITE_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .map(|u| u.trim_end_matches('/').to_string());
+    let xml = render_atom(id, &situation.headline, &self_url, site_url.as_deref(), &entries);
+    ([(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")], xml).into_response()
+}
+
+/// `GET /situations/unsubscribe/{token}`, linked from every milestone email.
+pub async fn unsubscribe_handler(
+    Deployment(state): Deployment,
+    Path(token): Path<Uuid>,
+) -> axum::response::Response {
+    match rootsignal_graph::situation_feed::unsubscribe(&state.graph_client, token).await {
+        Ok(true) => "You're unsubscribed and won't get more emails about this situation.".into_response(),
+        Ok(false) => (StatusCode::NOT_FOUND, "That subscription doesn't exist or was already removed.").into_response(),
+        Err(e) => {
+            warn!(error = %e, "Failed to unsubscribe");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// `GET /situations/confirm/{token}`, linked from the confirmation email.
+pub async fn confirm_handler(
+    Deployment(state): Deployment,
+    Path(token): Path<Uuid>,
+) -> axum::response::Response {
+    match rootsignal_graph::situation_feed::confirm(&state.graph_client, token).await {
+        Ok(true) => "You're subscribed. We'll email you if this situation escalates or goes quiet.".into_response(),
+        Ok(false) => (StatusCode::NOT_FOUND, "That confirmation link is invalid or has expired.").into_response(),
+        Err(e) => {
+            warn!(error = %e, "Failed to confirm subscription");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
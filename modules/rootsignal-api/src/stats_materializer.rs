@@ -0,0 +1,46 @@
+//! Refreshes materialized region stats on a timer, so the dashboard and
+//! public stats stay current between scout runs (the supervisor refreshes a
+//! region's stats at the end of each run).
+//!
+//! Covers the deployment's own region, regions with a database of their own,
+//! and every region with a scout schedule.
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use rootsignal_common::region_stats::STATS_REFRESH_MINUTES;
+use rootsignal_graph::{region_stats, GraphRouter, GraphWriter};
+
+/// Refresh every `STATS_REFRESH_SECS` (default `STATS_REFRESH_MINUTES`).
+pub fn spawn(writer: GraphWriter, router: GraphRouter, configured: Vec<String>) {
+    let secs = std::env::var("STATS_REFRESH_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|&s| s > 0)
+        .unwrap_or(STATS_REFRESH_MINUTES as u64 * 60);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(secs));
+        loop {
+            interval.tick().await;
+            let mut regions: BTreeSet<String> = configured.iter().cloned().collect();
+            match writer.list_region_schedules().await {
+                Ok(schedules) => regions.extend(schedules.into_iter().map(|(region, _)| region)),
+                Err(e) => warn!(error = %e, "Failed to load region schedules"),
+            }
+            for region in regions {
+                let result = match router.for_region(&region).await {
+                    Ok(client) => region_stats::refresh_region_stats(&client, &region).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    warn!(region, error = %e, "Failed to refresh region stats");
+                }
+            }
+        }
+    });
+
+    info!(interval_secs = secs, "Region stats materializer started");
+}
@@ -0,0 +1,179 @@
+//! Serving several tenants from one API process.
+//!
+//! Every tenant gets its own [`AppState`]: a `GraphClient` bound to the
+//! tenant's database, its own signal cache, a schema built from the tenant's
+//! config, and a JWT issuer of its own so sessions don't carry across. Each
+//! request is routed by [`Deployment`] before any handler runs. Requests that
+//! match no tenant go to the primary deployment.
+//!
+//! Tenant schemas have no Restate client or Postgres pool: scout workflows
+//! and Postgres-backed features still run against the primary deployment
+//! only, so they are off for tenants rather than shared. Tenant scout runs go
+//! through the scout binary's `--tenant` flag.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use tokio::sync::Mutex;
+use tracing::info;
+
+use rootsignal_common::{Tenant, TenantError, TenantRegistry};
+use rootsignal_graph::{CacheStore, CachedReader, GraphClient, GraphRouter, GraphWriter, PublicGraphReader};
+use twilio::TwilioService;
+
+use crate::graphql::{build_schema, SchemaDeps};
+use crate::graphql::mutations::RateLimiter;
+use crate::jwt::JwtService;
+use crate::{watchlist_notify, AppState};
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The primary deployment plus one [`AppState`] per tenant.
+pub struct Deployments {
+    primary: Arc<AppState>,
+    registry: TenantRegistry,
+    tenants: HashMap<String, Arc<AppState>>,
+}
+
+impl Deployments {
+    pub fn single(primary: Arc<AppState>) -> Self {
+        // No tenant keys for the primary keys to clash with
+        let registry = TenantRegistry::default()
+            .with_primary_keys(&primary.config.api_key_hashes)
+            .unwrap_or_default();
+        Self {
+            primary,
+            registry,
+            tenants: HashMap::new(),
+        }
+    }
+
+    /// Create, migrate and load every tenant's database.
+    pub async fn load(
+        primary: Arc<AppState>,
+        registry: TenantRegistry,
+        twilio: Option<Arc<TwilioService>>,
+    ) -> anyhow::Result<Self> {
+        let registry = registry.with_primary_keys(&primary.config.api_key_hashes)?;
+        let mut tenants = HashMap::new();
+        for tenant in registry.tenants() {
+            let state = tenant_state(tenant, &primary, twilio.clone()).await?;
+            tenants.insert(tenant.slug.clone(), state);
+        }
+        info!(tenants = tenants.len(), "Tenant deployments loaded");
+        Ok(Self {
+            primary,
+            registry,
+            tenants,
+        })
+    }
+
+    /// The deployment a request is for, from its `Host` and `X-Api-Key` headers.
+    pub fn resolve(&self, headers: &axum::http::HeaderMap) -> Result<Arc<AppState>, TenantError> {
+        let header = |name| headers.get(name).and_then(|v| v.to_str().ok());
+        let tenant = self
+            .registry
+            .resolve(header(axum::http::header::HOST.as_str()), header(API_KEY_HEADER))?;
+        Ok(match tenant.and_then(|t| self.tenants.get(&t.slug)) {
+            Some(state) => state.clone(),
+            None => self.primary.clone(),
+        })
+    }
+}
+
+/// Extractor for the [`AppState`] of the deployment a request belongs to.
+pub struct Deployment(pub Arc<AppState>);
+
+impl FromRequestParts<Arc<Deployments>> for Deployment {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, deployments: &Arc<Deployments>) -> Result<Self, Self::Rejection> {
+        deployments.resolve(&parts.headers).map(Deployment).map_err(|e| {
+            let status = match e {
+                TenantError::UnknownApiKey => StatusCode::UNAUTHORIZED,
+                TenantError::HostMismatch(_) => StatusCode::FORBIDDEN,
+                TenantError::Invalid(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, e.to_string())
+        })
+    }
+}
+
+async fn tenant_state(
+    tenant: &Tenant,
+    primary: &AppState,
+    twilio: Option<Arc<TwilioService>>,
+) -> anyhow::Result<Arc<AppState>> {
+    let base = &primary.config;
+    let config = tenant.config(base);
+
+    primary
+        .graph_client
+        .create_database(&tenant.database)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create database for tenant {}: {e}", tenant.slug))?;
+    let client = GraphClient::connect_database(
+        &base.neo4j_uri,
+        &base.neo4j_user,
+        &base.neo4j_password.expose(),
+        &tenant.database,
+    )
+    .await?;
+    rootsignal_graph::migrate::migrate(&client)
+        .await
+        .map_err(|e| anyhow::anyhow!("Migration failed for tenant {}: {e}", tenant.slug))?;
+
+    let initial_cache = rootsignal_graph::cache::SignalCache::load(&client)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load cache for tenant {}: {e}", tenant.slug))?;
+    let cache_store = Arc::new(CacheStore::new(initial_cache));
+    let notifier = Arc::new(watchlist_notify::HttpWatchlistNotifier::from_env(&config));
+    cache_store.set_watchlist_notifier(notifier.clone());
+    cache_store.set_situation_notifier(notifier);
+    cache_store.spawn_reload_loop(GraphRouter::single(client.clone()));
+
+    let reader = Arc::new(CachedReader::new(cache_store.clone(), PublicGraphReader::new(client.clone())));
+    let writer = Arc::new(GraphWriter::new(client.clone()));
+    let secret = if config.session_secret.is_empty() {
+        config.admin_password.expose()
+    } else {
+        config.session_secret.expose()
+    };
+    let jwt_service = JwtService::new(&secret, tenant.token_issuer());
+
+    let schema = build_schema(SchemaDeps {
+        reader,
+        writer,
+        jwt_service: jwt_service.clone(),
+        config: Arc::new(config.clone()),
+        twilio: twilio.clone(),
+        rate_limiter: RateLimiter(Mutex::new(HashMap::new())),
+        graph_client: Arc::new(client.clone()),
+        graph_router: GraphRouter::single(client.clone()),
+        cache_store,
+        restate_client: None,
+        pg_pools: None,
+    });
+
+    info!(tenant = tenant.slug.as_str(), database = tenant.database.as_str(), "Tenant deployment ready");
+    Ok(Arc::new(AppState {
+        schema,
+        reader: PublicGraphReader::new(client.clone()),
+        writer: GraphWriter::new(client.clone()),
+        graph_client: client,
+        region: config.region.clone(),
+        config,
+        twilio: twilio.map(|t| (*t).clone()),
+        rate_limiter: Mutex::new(HashMap::new()),
+        jwt_service,
+        // Feed links must point back at the tenant's own host
+        public_api_url: tenant
+            .hostnames
+            .first()
+            .map(|host| format!("https://{host}"))
+            .or_else(|| primary.public_api_url.clone()),
+    }))
+}
@@ -0,0 +1,173 @@
+//! Polling triggers for Zapier-style integrations.
+//!
+//! `GET /api/triggers/new-signals` returns signals new since the caller's
+//! last poll with the same filter, newest first; `…/sample` returns recent
+//! matches (or a placeholder) without moving the checkpoint, for setting up
+//! an integration. Both need an `X-Api-Key`: a tenant's key or one of the
+//! primary deployment's `API_KEY_HASHES`, resolved by [`Deployment`].
+
+use std::collections::HashMap;
+
+use axum::extract::Query;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use tracing::warn;
+
+use rootsignal_common::triggers::{self, DEFAULT_LIMIT, MAX_LIMIT};
+use rootsignal_common::{hash_api_key, Node, TriggerCheckpoint, TriggerFilter, TriggerItem};
+use rootsignal_graph::taxonomy::load_taxonomy;
+use rootsignal_graph::triggers::SCAN_LIMIT;
+use rootsignal_graph::GraphClient;
+
+use crate::tenancy::{Deployment, API_KEY_HEADER};
+
+/// Items in a sample response.
+const SAMPLE_SIZE: usize = 3;
+/// How far back a sample looks for real matches.
+const SAMPLE_LOOKBACK_DAYS: i64 = 7;
+
+fn error(status: StatusCode, message: impl Into<String>) -> axum::response::Response {
+    (status, Json(json!({ "error": message.into() }))).into_response()
+}
+
+fn site_url() -> Option<String> {
+    std::env::var("PUBLIC_SITE_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .map(|u| u.trim_end_matches('/').to_string())
+}
+
+/// The filter's categories expanded to canonical slugs with subcategories.
+async fn expand_categories(client: &GraphClient, filter: &TriggerFilter) -> anyhow::Result<Vec<String>> {
+    if filter.categories.is_empty() {
+        return Ok(Vec::new());
+    }
+    let taxonomy = load_taxonomy(client).await?;
+    let mut slugs: Vec<String> = filter.categories.iter().flat_map(|c| taxonomy.expand(c)).collect();
+    slugs.sort();
+    slugs.dedup();
+    Ok(slugs)
+}
+
+/// The API key and filter of a trigger request, or why it was rejected.
+fn parse_request(
+    headers: &HeaderMap,
+    params: &HashMap<String, String>,
+) -> Result<(String, TriggerFilter), (StatusCode, String)> {
+    // An unknown key was already rejected when the deployment was resolved.
+    let key = headers
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| (StatusCode::UNAUTHORIZED, "Pass your API key in the X-Api-Key header".to_string()))?;
+    let filter = TriggerFilter::from_params(params).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    Ok((key.to_string(), filter))
+}
+
+/// `GET /api/triggers/new-signals`: signals not yet returned for this key
+/// and filter. Filters: `types`, `categories`, `keywords` (comma-separated),
+/// `min_severity`, `min_lat`/`max_lat`/`min_lng`/`max_lng`. `limit` caps the
+/// page (default 25, max 100); `checkpoint` names a checkpoint, for two
+/// integrations polling the same filter with one key.
+pub async fn new_signals_handler(
+    Deployment(state): Deployment,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> axum::response::Response {
+    let (key, filter) = match parse_request(&headers, &params) {
+        Ok(parsed) => parsed,
+        Err((status, message)) => return error(status, message),
+    };
+    let limit = match params.get("limit").map(|v| v.trim().parse::<usize>()) {
+        Some(Ok(limit)) => limit.clamp(1, MAX_LIMIT),
+        Some(Err(_)) => return error(StatusCode::BAD_REQUEST, "limit must be a number"),
+        None => DEFAULT_LIMIT,
+    };
+    let name = params
+        .get("checkpoint")
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| filter.fingerprint());
+    let checkpoint_id = triggers::checkpoint_id(&hash_api_key(&key), &name);
+    let client = &state.graph_client;
+
+    let result = async {
+        let categories = expand_categories(client, &filter).await?;
+        let mut checkpoint = rootsignal_graph::triggers::load_checkpoint(client, &checkpoint_id)
+            .await?
+            .unwrap_or_else(|| TriggerCheckpoint::first_poll(Utc::now()));
+        let scanned = rootsignal_graph::triggers::signals_extracted_after(
+            client,
+            checkpoint.scan_from(),
+            &checkpoint.seen_ids(),
+            &filter.types,
+            SCAN_LIMIT,
+        )
+        .await?;
+        let site_url = site_url();
+        let items: Vec<TriggerItem> = checkpoint
+            .take(&scanned, &filter, &categories, limit)
+            .into_iter()
+            .filter_map(|n| TriggerItem::from_node(n, site_url.as_deref()))
+            .collect();
+        rootsignal_graph::triggers::save_checkpoint(client, &checkpoint_id, &checkpoint).await?;
+        Ok::<_, anyhow::Error>(items)
+    }
+    .await;
+
+    match result {
+        Ok(items) => Json(items).into_response(),
+        Err(e) => {
+            warn!(error = %e, "Failed to poll new-signals trigger");
+            error(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load signals")
+        }
+    }
+}
+
+/// `GET /api/triggers/new-signals/sample`: up to three recent matches of
+/// the same filters, newest first, or a placeholder item when nothing
+/// matches. Never moves a checkpoint.
+pub async fn sample_handler(
+    Deployment(state): Deployment,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> axum::response::Response {
+    let (_, filter) = match parse_request(&headers, &params) {
+        Ok(parsed) => parsed,
+        Err((status, message)) => return error(status, message),
+    };
+    let client = &state.graph_client;
+    let site_url = site_url();
+
+    let result = async {
+        let categories = expand_categories(client, &filter).await?;
+        let since = Utc::now() - Duration::days(SAMPLE_LOOKBACK_DAYS);
+        let scanned =
+            rootsignal_graph::triggers::signals_extracted_after(client, since, &[], &filter.types, SCAN_LIMIT).await?;
+        let matches: Vec<&Node> = scanned
+            .iter()
+            .rev()
+            .filter(|n| filter.matches(n, &categories))
+            .take(SAMPLE_SIZE)
+            .collect();
+        Ok::<_, anyhow::Error>(
+            matches
+                .into_iter()
+                .filter_map(|n| TriggerItem::from_node(n, site_url.as_deref()))
+                .collect::<Vec<_>>(),
+        )
+    }
+    .await;
+
+    match result {
+        Ok(items) if items.is_empty() => Json(vec![triggers::sample_item(site_url.as_deref())]).into_response(),
+        Ok(items) => Json(items).into_response(),
+        Err(e) => {
+            warn!(error = %e, "Failed to load trigger sample");
+            error(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load signals")
+        }
+    }
+}
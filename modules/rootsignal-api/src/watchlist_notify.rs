@@ -0,0 +1,227 @@
+//! Delivers watchlist matches: webhooks (JSON POST), SMS through Twilio's
+//! Messages API, and email through Mailgun. Channels whose provider isn't
+//! configured are skipped with a warning. Situation milestone emails go out
+//! through the same Mailgun account.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::json;
+use tracing::warn;
+
+use rootsignal_common::watchlist::MAX_NOTIFIED_SIGNALS;
+use rootsignal_common::{Config, Milestone, Node, NotifyChannel, SavedSearch, SituationChange, SituationSubscription};
+use rootsignal_graph::situation_feed::SituationNotifier;
+use rootsignal_graph::watchlist::WatchlistNotifier;
+
+struct TwilioSms {
+    account_sid: String,
+    auth_token: String,
+    from: String,
+}
+
+struct Mailgun {
+    api_key: String,
+    domain: String,
+    from: String,
+}
+
+pub struct HttpWatchlistNotifier {
+    client: reqwest::Client,
+    sms: Option<TwilioSms>,
+    email: Option<Mailgun>,
+    /// Public site base URL, for links to matched signals.
+    site_url: Option<String>,
+    /// This API's public base URL, for confirm and unsubscribe links.
+    api_url: Option<String>,
+}
+
+impl HttpWatchlistNotifier {
+    /// SMS needs the Twilio account plus `TWILIO_FROM_NUMBER`; email needs
+    /// `MAILGUN_API_KEY`, `MAILGUN_DOMAIN` and `WATCHLIST_EMAIL_FROM`.
+    /// `PUBLIC_SITE_URL` adds signal links; `PUBLIC_API_URL` makes the
+    /// confirm and unsubscribe links in situation emails absolute.
+    pub fn from_env(config: &Config) -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let sms = match var("TWILIO_FROM_NUMBER") {
+            Some(from) if !config.twilio_account_sid.is_empty() => Some(TwilioSms {
+                account_sid: config.twilio_account_sid.clone(),
+                auth_token: config.twilio_auth_token.expose(),
+                from,
+            }),
+            _ => None,
+        };
+        let email = match (var("MAILGUN_API_KEY"), var("MAILGUN_DOMAIN"), var("WATCHLIST_EMAIL_FROM")) {
+            (Some(api_key), Some(domain), Some(from)) => Some(Mailgun { api_key, domain, from }),
+            _ => None,
+        };
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(15))
+                .build()
+                .expect("Failed to build watchlist HTTP client"),
+            sms,
+            email,
+            site_url: var("PUBLIC_SITE_URL").map(|u| u.trim_end_matches('/').to_string()),
+            api_url: var("PUBLIC_API_URL").map(|u| u.trim_end_matches('/').to_string()),
+        }
+    }
+
+    fn signal_url(&self, node: &Node) -> Option<String> {
+        self.site_url.as_ref().map(|base| format!("{base}/signals/{}", node.id()))
+    }
+
+    /// "3 new signals for “Phillips housing”: Eviction notices at Cedar Ave; …"
+    fn summary(&self, search: &SavedSearch, matches: &[Node]) -> String {
+        let mut lines: Vec<String> = matches
+            .iter()
+            .take(MAX_NOTIFIED_SIGNALS)
+            .map(|n| match self.signal_url(n) {
+                Some(url) => format!("- {} ({url})", n.title()),
+                None => format!("- {}", n.title()),
+            })
+            .collect();
+        if matches.len() > MAX_NOTIFIED_SIGNALS {
+            lines.push(format!("…and {} more", matches.len() - MAX_NOTIFIED_SIGNALS));
+        }
+        format!(
+            "{} new signal(s) for \u{201c}{}\u{201d}:\n{}",
+            matches.len(),
+            search.name,
+            lines.join("\n")
+        )
+    }
+}
+
+#[async_trait]
+impl WatchlistNotifier for HttpWatchlistNotifier {
+    async fn notify(&self, search: &SavedSearch, channel: &NotifyChannel, matches: &[Node]) -> anyhow::Result<()> {
+        match channel {
+            NotifyChannel::Webhook(url) => {
+                let signals: Vec<_> = matches
+                    .iter()
+                    .map(|n| {
+                        json!({
+                            "id": n.id(),
+                            "type": n.node_type(),
+                            "title": n.title(),
+                            "summary": n.meta().map(|m| m.summary.as_str()).unwrap_or_default(),
+                            "category": n.category(),
+                            "url": self.signal_url(n),
+                        })
+                    })
+                    .collect();
+                self.client
+                    .post(url)
+                    .json(&json!({
+                        "search": { "id": search.id, "name": search.name },
+                        "signals": signals,
+                    }))
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+            NotifyChannel::Sms(phone) => {
+                let Some(sms) = &self.sms else {
+                    warn!(search = %search.id, "SMS watchlist channel set but Twilio sending isn't configured");
+                    return Ok(());
+                };
+                self.client
+                    .post(format!(
+                        "https://api.twilio.com/2010-04-01/Accounts/{}/Messages.json",
+                        sms.account_sid
+                    ))
+                    .basic_auth(&sms.account_sid, Some(&sms.auth_token))
+                    .form(&[
+                        ("To", phone.as_str()),
+                        ("From", sms.from.as_str()),
+                        ("Body", self.summary(search, matches).as_str()),
+                    ])
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+            NotifyChannel::Email(address) => {
+                let Some(mail) = &self.email else {
+                    warn!(search = %search.id, "Email watchlist channel set but Mailgun isn't configured");
+                    return Ok(());
+                };
+                self.client
+                    .post(format!("https://api.mailgun.net/v3/{}/messages", mail.domain))
+                    .basic_auth("api", Some(&mail.api_key))
+                    .form(&[
+                        ("from", mail.from.as_str()),
+                        ("to", address.as_str()),
+                        ("subject", format!("New signals: {}", search.name).as_str()),
+                        ("text", self.summary(search, matches).as_str()),
+                    ])
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl HttpWatchlistNotifier {
+    fn api_link(&self, path: &str) -> String {
+        format!("{}{path}", self.api_url.as_deref().unwrap_or_default())
+    }
+
+    async fn send_mail(&self, mail: &Mailgun, to: &str, subject: &str, text: &str, headers: &[(&str, String)]) -> anyhow::Result<()> {
+        let mut form = vec![("from", mail.from.clone()), ("to", to.to_string()), ("subject", subject.to_string()), ("text", text.to_string())];
+        form.extend(headers.iter().map(|(name, value)| (*name, value.clone())));
+        self.client
+            .post(format!("https://api.mailgun.net/v3/{}/messages", mail.domain))
+            .basic_auth("api", Some(&mail.api_key))
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SituationNotifier for HttpWatchlistNotifier {
+    async fn confirm(&self, headline: &str, subscription: &SituationSubscription) -> anyhow::Result<()> {
+        let Some(mail) = &self.email else {
+            anyhow::bail!("Mailgun isn't configured");
+        };
+        let confirm = self.api_link(&format!("/situations/confirm/{}", subscription.confirm_token));
+        let text = format!(
+            "Someone asked to email {} when \u{201c}{headline}\u{201d} escalates or goes quiet.\n\n\
+             Confirm: {confirm}\n\n\
+             If this wasn't you, ignore this email and you won't hear from us again.\n",
+            subscription.email
+        );
+        self.send_mail(mail, &subscription.email, &format!("Confirm: follow {headline}"), &text, &[]).await
+    }
+
+    async fn notify(
+        &self,
+        headline: &str,
+        change: &SituationChange,
+        subscription: &SituationSubscription,
+    ) -> anyhow::Result<()> {
+        let Some(mail) = &self.email else {
+            warn!(subscription = %subscription.id, "Situation subscriber waiting but Mailgun isn't configured");
+            return Ok(());
+        };
+        let subject = match change.milestone() {
+            Some(Milestone::Escalated) => format!("Escalating: {headline}"),
+            Some(Milestone::Resolved) => format!("Gone quiet: {headline}"),
+            None => format!("Update: {headline}"),
+        };
+        let mut text = format!("\u{201c}{headline}\u{201d}\n{}\n", change.describe());
+        if let Some(base) = &self.site_url {
+            text.push_str(&format!("\n{base}/situations/{}\n", change.situation_id));
+        }
+        let unsubscribe = self.api_link(&format!("/situations/unsubscribe/{}", subscription.id));
+        text.push_str(&format!("\nUnsubscribe: {unsubscribe}\n"));
+
+        let headers = [("h:List-Unsubscribe", format!("<{unsubscribe}>"))];
+        self.send_mail(mail, &subscription.email, &subject, &text, &headers).await
+    }
+}
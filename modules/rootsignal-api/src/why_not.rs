@@ -0,0 +1,97 @@
+//! Gathers the facts behind `adminWhyNot`: the source registry, archive and
+//! stored signals for a URL, run log events naming the URL or the
+//! description, and negative knowledge matching the description.
+
+use chrono::Utc;
+use sqlx::PgPool;
+
+use rootsignal_common::negative_knowledge::{self, NegativeIndex};
+use rootsignal_common::why_not::{
+    ExtractionAttempt, FetchAttempt, NegativeHit, OutcomeEvent, SignalOutcome, WhyNotFacts,
+};
+use rootsignal_graph::{why_not as graph, GraphClient};
+
+use crate::db::models::{archive, scout_run};
+
+/// Run log events read per lookup; the newest are kept.
+const MAX_EVENTS: u32 = 200;
+/// Signals matched per type for a description.
+const MAX_DESCRIPTION_SIGNALS: u32 = 10;
+
+pub async fn gather_facts(
+    pool: &PgPool,
+    client: &GraphClient,
+    region: &str,
+    url: Option<&str>,
+    description: Option<&str>,
+) -> anyhow::Result<WhyNotFacts> {
+    let mut facts = WhyNotFacts::default();
+
+    if let Some(url) = url {
+        facts.source_known = graph::source_known(client, url).await?;
+        facts.last_archived = archive::last_fetched(pool, url).await?;
+        facts.signals = graph::signals_for_url(client, url).await?;
+        let events = scout_run::events_mentioning(pool, region, Some(url), None, MAX_EVENTS).await?;
+        add_events(&mut facts, events);
+    }
+
+    if let Some(description) = description {
+        facts
+            .signals
+            .extend(graph::signals_matching(client, description, MAX_DESCRIPTION_SIGNALS).await?);
+        let events = scout_run::events_mentioning(pool, region, None, Some(description), MAX_EVENTS).await?;
+        add_events(&mut facts, events);
+
+        let index = NegativeIndex::new(negative_knowledge::active(pool, region).await?);
+        facts.negative_match = index.check_text(description, Utc::now()).map(|m| NegativeHit {
+            entry_id: m.entry_id,
+            kind: m.kind.as_str().to_string(),
+            reason: m.reason,
+        });
+    }
+
+    facts.fetches.sort_by_key(|f| f.at);
+    facts.extractions.sort_by_key(|e| e.at);
+    facts.outcomes.sort_by_key(|o| o.at);
+    Ok(facts)
+}
+
+/// Sort `events` (newest first) into the facts' fetches, extractions and
+/// signal outcomes; other events are dropped.
+fn add_events(facts: &mut WhyNotFacts, events: Vec<(String, scout_run::EventJson)>) {
+    for (run_id, e) in events {
+        let outcome = match e.event_type.as_str() {
+            "scrape_url" => {
+                facts.fetches.push(FetchAttempt {
+                    run_id,
+                    at: e.ts,
+                    success: e.success.unwrap_or(false),
+                    error: e.error_class,
+                });
+                continue;
+            }
+            "llm_extraction" => {
+                facts.extractions.push(ExtractionAttempt {
+                    run_id,
+                    at: e.ts,
+                    signals_extracted: e.signals_extracted.unwrap_or(0),
+                });
+                continue;
+            }
+            "signal_created" => SignalOutcome::Created { node_id: e.node_id.unwrap_or_default() },
+            "signal_deduplicated" => SignalOutcome::Refreshed { matched_id: e.matched_id.unwrap_or_default() },
+            "signal_corroborated" => SignalOutcome::Corroborated { existing_id: e.existing_id.unwrap_or_default() },
+            "signal_suppressed" => SignalOutcome::Suppressed {
+                entry_id: e.matched_id.unwrap_or_default(),
+                kind: e.kind.unwrap_or_default(),
+            },
+            _ => continue,
+        };
+        facts.outcomes.push(OutcomeEvent {
+            run_id,
+            at: e.ts,
+            title: e.title.unwrap_or_default(),
+            outcome,
+        });
+    }
+}
@@ -0,0 +1,117 @@
+// Apify run completions: the webhook receiver records them, scouts wait on them.
+//
+// The API's receiver stores each terminal run event and sends NOTIFY on
+// `apify_run_completed` with the run id. A scout waiting on a run listens
+// on that channel, so it hears about the run the moment Apify reports it
+// instead of long-polling the Apify API.
+
+use std::time::Duration;
+
+use apify_client::{RunCompletions, WebhookPayload};
+use async_trait::async_trait;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::error::Result;
+
+const CHANNEL: &str = "apify_run_completed";
+
+/// Days a completion is kept; runs are waited on for minutes.
+const KEEP_DAYS: i32 = 7;
+
+/// Record a run's completion and wake anyone waiting on it. Events that
+/// don't end a run are ignored. Returns whether it was recorded.
+pub async fn record_completion(pool: &PgPool, payload: &WebhookPayload) -> Result<bool> {
+    if !payload.is_terminal() {
+        return Ok(false);
+    }
+    let run_id = &payload.event_data.actor_run_id;
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO apify_run_completions (run_id, status, dataset_id)
+         VALUES ($1, $2, $3)
+         ON CONFLICT (run_id) DO UPDATE SET status = EXCLUDED.status,
+             dataset_id = COALESCE(EXCLUDED.dataset_id, apify_run_completions.dataset_id),
+             received_at = now()",
+    )
+    .bind(run_id)
+    .bind(payload.status())
+    .bind(payload.dataset_id())
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(CHANNEL)
+        .bind(run_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM apify_run_completions WHERE received_at < now() - make_interval(days => $1)")
+        .bind(KEEP_DAYS)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(true)
+}
+
+/// Waits for completions recorded by `record_completion`.
+pub struct PgRunCompletions {
+    pool: PgPool,
+}
+
+impl PgRunCompletions {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn status(&self, run_id: &str) -> Option<String> {
+        sqlx::query_scalar("SELECT status FROM apify_run_completions WHERE run_id = $1")
+            .bind(run_id)
+            .fetch_optional(&self.pool)
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn listen(&self) -> sqlx::Result<PgListener> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(CHANNEL).await?;
+        Ok(listener)
+    }
+}
+
+#[async_trait]
+impl RunCompletions for PgRunCompletions {
+    async fn wait_for(&self, run_id: &str, timeout: Duration) -> Option<String> {
+        let mut listener = match self.listen().await {
+            Ok(listener) => listener,
+            Err(e) => {
+                // Let the caller's direct check take over after the wait.
+                warn!(error = %e, run_id, "Can't listen for Apify completions");
+                tokio::time::sleep(timeout).await;
+                return self.status(run_id).await;
+            }
+        };
+
+        // Listening before the first check, so a completion landing in
+        // between still wakes us.
+        if let Some(status) = self.status(run_id).await {
+            return Some(status);
+        }
+        tokio::time::timeout(timeout, async {
+            loop {
+                match listener.recv().await {
+                    Ok(notification) if notification.payload() == run_id => return self.status(run_id).await,
+                    Ok(_) => continue,
+                    Err(e) => {
+                        warn!(error = %e, run_id, "Lost the Apify completion listener");
+                        return None;
+                    }
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+}
@@ -2,30 +2,60 @@
 // Callers use `archive.source(url)` to get a SourceHandle, then call
 // content-type methods on it (.posts(), .stories(), .page(), etc.).
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
+use tracing::warn;
 
+use crate::apify_runs::PgRunCompletions;
+use crate::blob::BlobBackend;
 use crate::enrichment::WorkflowDispatcher;
 use crate::error::Result;
+use crate::link_graph::{self, DomainScore, LinkCandidate};
+use crate::crawl::CrawlOutcome;
+use crate::politeness::{DomainRateLimiter, DEFAULT_DOMAIN_INTERVAL};
 use crate::router::{detect_platform, extract_identifier, normalize_url};
 use crate::services::bluesky::BlueskyService;
+use crate::services::eventbrite::EventbriteService;
 use crate::services::facebook::FacebookService;
 use crate::services::feed::FeedService;
+use crate::services::sitemap::SitemapService;
 use crate::services::instagram::InstagramService;
-use crate::services::page::{BrowserlessPageService, ChromePageService};
+use crate::credentials::{CredentialCipher, CredentialStore};
+use crate::services::page::{AuthenticatedPageService, BrowserlessPageService, ChromePageService};
 use crate::services::reddit::RedditService;
-use crate::services::search::SearchService;
+use crate::services::search::{SearchProviderConfig, SearchService, SearchUsage};
 use crate::services::tiktok::TikTokService;
 use crate::services::twitter::TwitterService;
+use crate::services::podcast::PodcastService;
+use crate::services::transcription::TranscriptionBackend;
+use crate::services::youtube::YouTubeService;
 use crate::source_handle::{ArchiveInner, SourceHandle};
 use crate::store::Store;
 
+/// Links followed each way when looking for a citation loop.
+const CITATION_LOOP_MAX_HOPS: i32 = 2;
+
 /// Configuration for which concrete fetchers to use.
 pub struct ArchiveConfig {
     pub page_backend: PageBackend,
-    pub serper_api_key: String,
+    /// Web search providers in fallback order. Providers without a key are skipped.
+    pub search_providers: Vec<SearchProviderConfig>,
     pub apify_api_key: Option<String>,
+    /// Receiver URL (with its `?key=`) Apify posts run completions to.
+    /// Without it, runs are long-polled.
+    pub apify_webhook_url: Option<String>,
+    /// Base64 32-byte key for per-source credentials. Without it, sources are
+    /// always fetched unauthenticated.
+    pub credentials_key: Option<String>,
+    /// Speech-to-text for podcasts and for videos that have no captions.
+    pub transcription: TranscriptionBackend,
+    /// Eventbrite API private token. Without it, Eventbrite organizers are unsupported.
+    pub eventbrite_token: Option<String>,
+    /// Where page HTML, long document text and long transcripts are kept.
+    pub blob_backend: BlobBackend,
 }
 
 pub enum PageBackend {
@@ -41,7 +71,18 @@ pub struct Archive {
 
 impl Archive {
     pub fn new(pool: PgPool, config: ArchiveConfig, dispatcher: Option<Arc<dyn WorkflowDispatcher>>) -> Self {
-        let store = Store::new(pool);
+        // Per-source credentials
+        let credentials = config.credentials_key.as_deref().and_then(|key| {
+            match CredentialCipher::from_base64_key(key) {
+                Ok(cipher) => Some(CredentialStore::new(pool.clone(), cipher)),
+                Err(e) => {
+                    warn!(error = %e, "Source credentials disabled");
+                    None
+                }
+            }
+        });
+
+        let store = Store::new(pool.clone()).with_blobs(config.blob_backend.build());
 
         // Page fetcher
         let (chrome_page, browserless_page) = match config.page_backend {
@@ -52,26 +93,42 @@ impl Archive {
         };
 
         // Social services (all require Apify)
+        let apify_webhook = config.apify_webhook_url.map(|url| {
+            apify_client::RunWebhook::new(url, Arc::new(PgRunCompletions::new(pool.clone())))
+        });
+        let apify = |api_key: &String| {
+            let client = apify_client::ApifyClient::new(api_key.clone());
+            match &apify_webhook {
+                Some(webhook) => client.with_webhook(webhook.clone()),
+                None => client,
+            }
+        };
         let (instagram, twitter, reddit, facebook, tiktok, bluesky) =
             if let Some(ref api_key) = config.apify_api_key {
                 (
-                    Some(InstagramService::new(apify_client::ApifyClient::new(api_key.clone()))),
-                    Some(TwitterService::new(apify_client::ApifyClient::new(api_key.clone()))),
-                    Some(RedditService::new(apify_client::ApifyClient::new(api_key.clone()))),
-                    Some(FacebookService::new(apify_client::ApifyClient::new(api_key.clone()))),
-                    Some(TikTokService::new(apify_client::ApifyClient::new(api_key.clone()))),
+                    Some(InstagramService::new(apify(api_key))),
+                    Some(TwitterService::new(apify(api_key))),
+                    Some(RedditService::new(apify(api_key))),
+                    Some(FacebookService::new(apify(api_key))),
+                    Some(TikTokService::new(apify(api_key))),
                     Some(BlueskyService::new()),
                 )
             } else {
                 (None, None, None, None, None, None)
             };
 
+        // Event platforms (Facebook events go through the Apify service above)
+        let eventbrite = config
+            .eventbrite_token
+            .as_deref()
+            .filter(|t| !t.is_empty())
+            .map(EventbriteService::new);
+
+        // Speech-to-text, shared by video and podcast ingestion
+        let transcriber = config.transcription.build();
+
         // Web search
-        let search = if config.serper_api_key.is_empty() {
-            None
-        } else {
-            Some(SearchService::new(&config.serper_api_key))
-        };
+        let search = SearchService::new(config.search_providers);
 
         let inner = ArchiveInner {
             store,
@@ -79,13 +136,20 @@ impl Archive {
             twitter,
             reddit,
             facebook,
+            eventbrite,
             tiktok,
             bluesky,
+            youtube: YouTubeService::new(transcriber.clone()),
+            podcast: PodcastService::new(transcriber),
             chrome_page,
             browserless_page,
             feed: FeedService::new(),
+            sitemap: SitemapService::new(),
             search,
             dispatcher,
+            credentials,
+            authenticated_page: AuthenticatedPageService::new(),
+            politeness: DomainRateLimiter::new(DEFAULT_DOMAIN_INTERVAL),
         };
 
         Self {
@@ -120,6 +184,66 @@ impl Archive {
         self.source(url).await?.page().await
     }
 
+    /// Fetch recent videos with transcripts from a video channel URL.
+    pub async fn videos(&self, url: &str, limit: u32) -> Result<Vec<rootsignal_common::types::LongVideo>> {
+        self.source(url).await?.videos(limit).await
+    }
+
+    /// Fetch recent podcast episodes with transcripts from a podcast RSS feed.
+    pub async fn episodes(&self, url: &str, limit: u32) -> Result<Vec<rootsignal_common::types::PodcastEpisode>> {
+        self.source(url).await?.episodes(limit).await
+    }
+
+    /// Fetch upcoming events from an event platform listing (Eventbrite organizer, Facebook page events).
+    pub async fn events(&self, url: &str, limit: u32) -> Result<Vec<rootsignal_common::types::ListedEvent>> {
+        self.source(url).await?.events(limit).await
+    }
+
+    /// Fetch up to `limit` top-level comments on one social media post.
+    /// Comments are read for extraction context and aren't archived.
+    pub async fn comments(&self, post_url: &str, limit: u32) -> Result<Vec<rootsignal_common::types::PostComment>> {
+        use crate::error::ArchiveError;
+        use crate::router::Platform;
+
+        fn unconfigured(name: &str) -> ArchiveError {
+            ArchiveError::Unsupported(format!("{name} service not configured"))
+        }
+
+        let inner = &self.inner;
+        let comments = match detect_platform(&normalize_url(post_url)) {
+            Platform::Instagram => inner.instagram.as_ref().ok_or_else(|| unconfigured("Instagram"))?
+                .fetch_comments(post_url, limit).await,
+            Platform::Facebook => inner.facebook.as_ref().ok_or_else(|| unconfigured("Facebook"))?
+                .fetch_comments(post_url, limit).await,
+            Platform::Reddit => inner.reddit.as_ref().ok_or_else(|| unconfigured("Reddit"))?
+                .fetch_comments(post_url, limit).await,
+            Platform::TikTok => inner.tiktok.as_ref().ok_or_else(|| unconfigured("TikTok"))?
+                .fetch_comments(post_url, limit).await,
+            Platform::Twitter => inner.twitter.as_ref().ok_or_else(|| unconfigured("Twitter"))?
+                .fetch_comments(post_url, limit).await,
+            other => return Err(ArchiveError::Unsupported(format!("{other:?} doesn't support comments"))),
+        };
+        comments.map_err(ArchiveError::Other)
+    }
+
+    /// Download a post's media file (e.g. an attached image) by its URL.
+    /// CDN URLs expire, so this is only good shortly after the post is fetched.
+    pub async fn media(&self, url: &str) -> Result<Vec<u8>> {
+        use crate::error::ArchiveError;
+
+        let resp = reqwest::get(url)
+            .await
+            .map_err(|e| ArchiveError::FetchFailed(e.to_string()))?;
+        if !resp.status().is_success() {
+            return Err(ArchiveError::FetchFailed(format!("HTTP status {}", resp.status().as_u16())));
+        }
+        let bytes = resp
+            .bytes()
+            .await
+            .map_err(|e| ArchiveError::FetchFailed(e.to_string()))?;
+        Ok(bytes.to_vec())
+    }
+
     /// Fetch an RSS/Atom feed.
     pub async fn feed(&self, url: &str) -> Result<rootsignal_common::types::ArchivedFeed> {
         self.source(url).await?.feed().await
@@ -130,10 +254,66 @@ impl Archive {
         self.source(query).await?.search(query).await
     }
 
-    /// Crawl a website via BFS, following links from the seed URL.
-    /// Uses sensible defaults: max_depth=2, limit=20.
+    /// Today's query count for each configured search provider, in fallback order.
+    pub async fn search_usage(&self) -> Result<Vec<SearchUsage>> {
+        let Some(search) = self.inner.search.as_ref() else {
+            return Ok(Vec::new());
+        };
+        let used = self.inner.store.search_queries_today().await?;
+        Ok(search
+            .providers()
+            .map(|(provider, daily_limit)| SearchUsage {
+                provider: provider.to_string(),
+                queries_today: used.get(provider).copied().unwrap_or(0),
+                daily_limit,
+            })
+            .collect())
+    }
+
+    /// Crawl a website from its sitemap, or via BFS from the seed URL when it
+    /// has none. Uses sensible defaults: max_depth=2, limit=20.
     pub async fn crawl(&self, url: &str) -> Result<Vec<rootsignal_common::types::ArchivedPage>> {
         self.source(url).await?.crawl().await
     }
 
+    /// Same as `crawl`, with the crawl's fetch/skip/error counts. Picks up an
+    /// interrupted crawl of the same seed with the same rules.
+    pub async fn crawl_with_stats(&self, url: &str) -> Result<CrawlOutcome> {
+        self.source(url).await?.crawl().send_with_stats().await
+    }
+
+    // --- Link graph ---
+
+    /// Hub and authority scores for every domain linked from pages fetched since `since`.
+    pub async fn domain_scores(&self, since: DateTime<Utc>) -> Result<Vec<DomainScore>> {
+        let edges = self.inner.store.domain_link_edges(since).await?;
+        Ok(link_graph::hits(&edges))
+    }
+
+    /// Untracked domains that pages from at least `min_trusted_linkers` of
+    /// the `trusted_urls` domains linked to since `since`, best first.
+    pub async fn link_candidates(
+        &self,
+        trusted_urls: &[String],
+        min_trusted_linkers: u32,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<LinkCandidate>> {
+        let trusted: HashSet<String> = trusted_urls.iter().map(|url| link_graph::domain_of(url)).collect();
+        let edges = self.inner.store.domain_link_edges(since).await?;
+        Ok(link_graph::link_candidates(&edges, &trusted, min_trusted_linkers))
+    }
+
+    /// Whether two archived pages cite each other, directly or through up to
+    /// `CITATION_LOOP_MAX_HOPS` links each way. Pages in such a loop are not
+    /// independent evidence for one another.
+    pub async fn citation_loop(&self, a: &str, b: &str) -> Result<bool> {
+        let (a, b) = (normalize_url(a), normalize_url(b));
+        if a == b {
+            return Ok(false);
+        }
+        let store = &self.inner.store;
+        Ok(store.page_reaches(&a, &b, CITATION_LOOP_MAX_HOPS).await?
+            && store.page_reaches(&b, &a, CITATION_LOOP_MAX_HOPS).await?)
+    }
+
 }
@@ -0,0 +1,454 @@
+// Site crawl frontier: which links a crawl follows, in what order, and what
+// it has already seen. The frontier is plain data so a crawl can checkpoint
+// it to Postgres after every page and pick up where it left off. Sites with a
+// sitemap are crawled from the sitemap, freshest pages first, instead of by
+// following links.
+
+use std::collections::{HashSet, VecDeque};
+
+use rootsignal_common::types::ArchivedPage;
+use serde::{Deserialize, Serialize};
+
+use crate::services::sitemap::SitemapEntry;
+
+/// Which links a crawl may follow, relative to its seed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrawlScope {
+    /// Only the seed's exact host.
+    #[default]
+    SameHost,
+    /// The seed's host and its subdomains (`www.` on the seed is ignored).
+    SameDomain,
+    /// The seed's host, under this path prefix.
+    PathPrefix(String),
+}
+
+/// What one crawl did, across every resumption.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrawlStats {
+    pub pages_fetched: u32,
+    /// In-scope URLs queued from the site's sitemaps.
+    pub sitemap_urls: u32,
+    /// Links already fetched or queued.
+    pub skipped_duplicate: u32,
+    /// Links outside the scope or filtered by include/exclude patterns.
+    pub skipped_out_of_scope: u32,
+    /// Links found on pages at the depth limit.
+    pub skipped_depth: u32,
+    /// Pages that failed to fetch.
+    pub errors: u32,
+}
+
+/// The result of `CrawlRequest::send_with_stats`.
+#[derive(Debug, Default)]
+pub struct CrawlOutcome {
+    /// Pages fetched by this call; a resumed crawl doesn't repeat earlier pages.
+    pub pages: Vec<ArchivedPage>,
+    pub stats: CrawlStats,
+    /// Whether this call picked up an interrupted crawl.
+    pub resumed: bool,
+    /// Whether the crawl followed the site's sitemap rather than its links.
+    pub from_sitemap: bool,
+    /// Recrawl cadence the sitemap's changefreq hints suggest.
+    pub recrawl_hours: Option<u32>,
+}
+
+/// Everything that decides what a crawl fetches. A crawl resumes only a
+/// saved crawl with the same rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CrawlRules {
+    pub seed_url: String,
+    pub scope: CrawlScope,
+    pub max_depth: usize,
+    pub limit: usize,
+    pub include_patterns: Vec<String>,
+    pub exclude_patterns: Vec<String>,
+    /// Crawl from the sitemap when the site has one.
+    pub use_sitemap: bool,
+}
+
+impl CrawlRules {
+    /// Whether a link is in scope and passes the include/exclude patterns.
+    pub(crate) fn admits(&self, url: &str) -> bool {
+        let seed_host = extract_host(&self.seed_url);
+        let in_scope = match &self.scope {
+            CrawlScope::SameHost => same_host(url, &seed_host),
+            CrawlScope::SameDomain => same_domain(url, &seed_host),
+            CrawlScope::PathPrefix(prefix) => {
+                same_host(url, &seed_host)
+                    && url::Url::parse(url).is_ok_and(|u| u.path().starts_with(prefix.as_str()))
+            }
+        };
+        in_scope && matches_patterns(url, &self.include_patterns, &self.exclude_patterns)
+    }
+}
+
+/// Breadth-first crawl queue with a seen set and running stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Frontier {
+    queue: VecDeque<(String, usize)>,
+    seen: HashSet<String>,
+    pub stats: CrawlStats,
+    /// Queued from a sitemap: every URL is already known, links aren't followed.
+    #[serde(default)]
+    pub sitemap: bool,
+    #[serde(default)]
+    pub recrawl_hours: Option<u32>,
+}
+
+impl Frontier {
+    pub(crate) fn new(seed_url: &str) -> Self {
+        Self {
+            queue: VecDeque::from([(seed_url.to_string(), 0)]),
+            seen: HashSet::from([normalize_crawl_url(seed_url)]),
+            stats: CrawlStats::default(),
+            sitemap: false,
+            recrawl_hours: None,
+        }
+    }
+
+    /// A frontier of sitemap URLs in the order given, keeping those the rules
+    /// admit. `None` when none are left, so the crawl falls back to links.
+    pub(crate) fn from_sitemap(
+        entries: &[SitemapEntry],
+        rules: &CrawlRules,
+        recrawl_hours: Option<u32>,
+    ) -> Option<Self> {
+        let mut frontier = Self {
+            queue: VecDeque::new(),
+            seen: HashSet::new(),
+            stats: CrawlStats::default(),
+            sitemap: true,
+            recrawl_hours,
+        };
+        for entry in entries {
+            if !rules.admits(&entry.url) {
+                frontier.stats.skipped_out_of_scope += 1;
+            } else if frontier.seen.insert(normalize_crawl_url(&entry.url)) {
+                frontier.queue.push_back((entry.url.clone(), 0));
+                frontier.stats.sitemap_urls += 1;
+            } else {
+                frontier.stats.skipped_duplicate += 1;
+            }
+        }
+        (!frontier.queue.is_empty()).then_some(frontier)
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<(String, usize)> {
+        self.queue.pop_front()
+    }
+
+    /// Offer the links found on a page at `depth`, queueing the ones the
+    /// rules admit and counting the rest.
+    pub(crate) fn offer(&mut self, links: &[String], depth: usize, rules: &CrawlRules) {
+        if self.sitemap {
+            return;
+        }
+        for link in links {
+            let normalized = normalize_crawl_url(link);
+            if self.seen.contains(&normalized) {
+                self.stats.skipped_duplicate += 1;
+                continue;
+            }
+            if !rules.admits(link) {
+                self.stats.skipped_out_of_scope += 1;
+                continue;
+            }
+            if depth >= rules.max_depth {
+                self.stats.skipped_depth += 1;
+                continue;
+            }
+            self.seen.insert(normalized);
+            self.queue.push_back((link.clone(), depth + 1));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// URL helpers
+// ---------------------------------------------------------------------------
+
+/// Ensure a URL has an https:// scheme. Archive's normalize_url strips the
+/// scheme, so we need to add it back for fetching.
+pub(crate) fn ensure_scheme(url: &str) -> String {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    }
+}
+
+/// Extract the host from a URL for same-host comparison.
+fn extract_host(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
+        .unwrap_or_default()
+}
+
+/// Normalize a URL for the crawl visited set.
+/// Strips fragments and trailing slashes, lowercases host.
+pub(crate) fn normalize_crawl_url(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_fragment(None);
+            let mut s = parsed.to_string();
+            if s.ends_with('/') && s.len() > parsed.scheme().len() + 3 {
+                // Don't strip trailing slash from bare domain (https://example.com/)
+                let path = parsed.path();
+                if path != "/" {
+                    s.pop();
+                }
+            }
+            s
+        }
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Check if a URL belongs to the same host.
+fn same_host(url: &str, seed_host: &str) -> bool {
+    extract_host(url) == *seed_host
+}
+
+/// Check if a URL is on the seed's domain or one of its subdomains.
+fn same_domain(url: &str, seed_host: &str) -> bool {
+    let domain = seed_host.strip_prefix("www.").unwrap_or(seed_host);
+    let host = extract_host(url);
+    host == domain || host.ends_with(&format!(".{domain}"))
+}
+
+/// Check if a URL passes include/exclude pattern filters.
+/// Include patterns are OR'd (any match passes). Exclude patterns reject on any match.
+/// Empty include = allow all. Patterns are substring matches on the URL path.
+fn matches_patterns(url: &str, include: &[String], exclude: &[String]) -> bool {
+    let path = url::Url::parse(url)
+        .map(|u| u.path().to_string())
+        .unwrap_or_default();
+
+    // Exclude takes priority
+    if exclude.iter().any(|p| path.contains(p.as_str())) {
+        return false;
+    }
+
+    // Empty include = allow all
+    if include.is_empty() {
+        return true;
+    }
+
+    include.iter().any(|p| path.contains(p.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(scope: CrawlScope, max_depth: usize) -> CrawlRules {
+        CrawlRules {
+            seed_url: "https://www.example.com/".to_string(),
+            scope,
+            max_depth,
+            limit: 20,
+            include_patterns: Vec::new(),
+            exclude_patterns: vec!["/login".to_string()],
+            use_sitemap: true,
+        }
+    }
+
+    fn links(urls: &[&str]) -> Vec<String> {
+        urls.iter().map(|u| u.to_string()).collect()
+    }
+
+    #[test]
+    fn frontier_queues_in_scope_links_once() {
+        let rules = rules(CrawlScope::SameHost, 2);
+        let mut frontier = Frontier::new(&rules.seed_url);
+        assert_eq!(frontier.pop(), Some(("https://www.example.com/".to_string(), 0)));
+
+        frontier.offer(
+            &links(&[
+                "https://www.example.com/about/",
+                "https://www.example.com/about#team",
+                "https://www.example.com/",
+                "https://other.org/page",
+                "https://www.example.com/login",
+            ]),
+            0,
+            &rules,
+        );
+        assert_eq!(frontier.pop(), Some(("https://www.example.com/about/".to_string(), 1)));
+        assert_eq!(frontier.pop(), None);
+        assert_eq!(
+            frontier.stats,
+            CrawlStats { skipped_duplicate: 2, skipped_out_of_scope: 2, ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn frontier_stops_at_the_depth_limit() {
+        let rules = rules(CrawlScope::SameHost, 1);
+        let mut frontier = Frontier::new(&rules.seed_url);
+        frontier.pop();
+        frontier.offer(&links(&["https://www.example.com/a"]), 0, &rules);
+        let (_, depth) = frontier.pop().unwrap();
+        frontier.offer(&links(&["https://www.example.com/b"]), depth, &rules);
+        assert_eq!(frontier.pop(), None);
+        assert_eq!(frontier.stats.skipped_depth, 1);
+    }
+
+    #[test]
+    fn frontier_survives_a_checkpoint() {
+        let rules = rules(CrawlScope::SameHost, 2);
+        let mut frontier = Frontier::new(&rules.seed_url);
+        frontier.pop();
+        frontier.offer(&links(&["https://www.example.com/a"]), 0, &rules);
+
+        let mut restored: Frontier = serde_json::from_value(serde_json::to_value(&frontier).unwrap()).unwrap();
+        restored.offer(&links(&["https://www.example.com/a", "https://www.example.com/"]), 1, &rules);
+        assert_eq!(restored.stats.skipped_duplicate, 2);
+        assert_eq!(restored.pop(), Some(("https://www.example.com/a".to_string(), 1)));
+    }
+
+    #[test]
+    fn sitemap_frontier_keeps_order_and_ignores_links() {
+        let rules = rules(CrawlScope::SameHost, 2);
+        let entry = |url: &str| SitemapEntry { url: url.to_string(), lastmod: None, changefreq: None };
+        let entries = vec![
+            entry("https://www.example.com/news/latest"),
+            entry("https://www.example.com/news/latest/"),
+            entry("https://cdn.example.com/file"),
+            entry("https://www.example.com/about"),
+        ];
+        let mut frontier = Frontier::from_sitemap(&entries, &rules, Some(24)).unwrap();
+        assert_eq!(frontier.pop(), Some(("https://www.example.com/news/latest".to_string(), 0)));
+        frontier.offer(&links(&["https://www.example.com/contact"]), 0, &rules);
+        assert_eq!(frontier.pop(), Some(("https://www.example.com/about".to_string(), 0)));
+        assert_eq!(frontier.pop(), None);
+        assert_eq!(
+            frontier.stats,
+            CrawlStats { sitemap_urls: 2, skipped_duplicate: 1, skipped_out_of_scope: 1, ..Default::default() }
+        );
+
+        assert!(Frontier::from_sitemap(&[entry("https://other.org/")], &rules, None).is_none());
+    }
+
+    #[test]
+    fn same_host_scope_excludes_other_subdomains() {
+        let host = rules(CrawlScope::SameHost, 2);
+        assert!(host.admits("https://www.example.com/x"));
+        assert!(!host.admits("https://blog.example.com/x"));
+    }
+
+    #[test]
+    fn same_domain_scope_admits_subdomains_but_not_lookalike_domains() {
+        let domain = rules(CrawlScope::SameDomain, 2);
+        assert!(domain.admits("https://blog.example.com/x"));
+        assert!(domain.admits("https://example.com/x"));
+        assert!(!domain.admits("https://notexample.com/x"));
+    }
+
+    #[test]
+    fn path_prefix_scope_admits_only_pages_under_the_prefix() {
+        let prefix = rules(CrawlScope::PathPrefix("/events".to_string()), 2);
+        assert!(prefix.admits("https://www.example.com/events/2026"));
+        assert!(!prefix.admits("https://www.example.com/news"));
+    }
+
+    #[test]
+    fn normalize_strips_fragment() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/page#section"),
+            "https://example.com/page"
+        );
+    }
+
+    #[test]
+    fn normalize_strips_trailing_slash_on_path() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/about/"),
+            "https://example.com/about"
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_trailing_slash_on_bare_domain() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/"),
+            "https://example.com/"
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_query_strings() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/news?page=2"),
+            "https://example.com/news?page=2"
+        );
+    }
+
+    #[test]
+    fn normalize_strips_fragment_and_trailing_slash() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/about/#team"),
+            "https://example.com/about"
+        );
+    }
+
+    #[test]
+    fn same_host_matches() {
+        assert!(same_host("https://example.com/about", "example.com"));
+    }
+
+    #[test]
+    fn same_host_rejects_subdomain() {
+        assert!(!same_host("https://blog.example.com/post", "example.com"));
+    }
+
+    #[test]
+    fn same_host_rejects_different_domain() {
+        assert!(!same_host("https://other.com/page", "example.com"));
+    }
+
+    #[test]
+    fn patterns_empty_allows_all() {
+        assert!(matches_patterns("https://example.com/anything", &[], &[]));
+    }
+
+    #[test]
+    fn patterns_include_filters() {
+        let include = vec!["/about".to_string(), "/contact".to_string()];
+        assert!(matches_patterns("https://example.com/about", &include, &[]));
+        assert!(matches_patterns("https://example.com/contact", &include, &[]));
+        assert!(!matches_patterns("https://example.com/blog", &include, &[]));
+    }
+
+    #[test]
+    fn patterns_exclude_rejects() {
+        let exclude = vec!["/login".to_string()];
+        assert!(!matches_patterns("https://example.com/login", &[], &exclude));
+        assert!(matches_patterns("https://example.com/about", &[], &exclude));
+    }
+
+    #[test]
+    fn patterns_exclude_takes_priority() {
+        let include = vec!["/admin".to_string()];
+        let exclude = vec!["/admin".to_string()];
+        assert!(!matches_patterns("https://example.com/admin", &include, &exclude));
+    }
+
+    #[test]
+    fn patterns_substring_match() {
+        let include = vec!["/about".to_string()];
+        assert!(matches_patterns("https://example.com/about-us", &include, &[]));
+        assert!(matches_patterns("https://example.com/info/about/team", &include, &[]));
+    }
+
+    #[test]
+    fn ensure_scheme_adds_https() {
+        assert_eq!(ensure_scheme("example.com/page"), "https://example.com/page");
+        assert_eq!(ensure_scheme("https://example.com"), "https://example.com");
+        assert_eq!(ensure_scheme("http://example.com"), "http://example.com");
+    }
+}
@@ -0,0 +1,429 @@
+// Per-source credentials for gated content (newsletters, member-only posts).
+//
+// Credentials are keyed by the scout's source canonical_key and stored
+// AES-256-GCM encrypted in the `source_credentials` table. Values are only
+// ever decrypted inside the archive when attaching them to a fetch — reads
+// for admin purposes go through `CredentialMeta`, which carries no secret.
+//
+// Each fetch with a credential reports back: a 401/403 marks the credential
+// `auth_failed` so admins can see it needs rotating, a success marks it healthy.
+
+use std::fmt;
+
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CredentialError {
+    #[error("Invalid credentials key: {0}")]
+    InvalidKey(String),
+
+    #[error("Credential could not be decrypted (wrong key or tampered ciphertext)")]
+    Decrypt,
+
+    #[error("Credential encryption failed")]
+    Encrypt,
+
+    #[error("Header credentials need a header name")]
+    MissingHeaderName,
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+/// A source answered a credentialed fetch with 401/403.
+#[derive(Debug, thiserror::Error)]
+#[error("Source rejected credential (HTTP {status})")]
+pub struct CredentialRejected {
+    pub status: u16,
+}
+
+/// How a credential is attached to requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialKind {
+    /// Sent as the `Cookie` header (value is the full cookie string).
+    Cookie,
+    /// Sent as `Authorization: Bearer <value>`.
+    Bearer,
+    /// Sent as a custom header named by `header_name`.
+    Header,
+}
+
+impl CredentialKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cookie => "cookie",
+            Self::Bearer => "bearer",
+            Self::Header => "header",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "cookie" => Some(Self::Cookie),
+            "bearer" => Some(Self::Bearer),
+            "header" => Some(Self::Header),
+            _ => None,
+        }
+    }
+}
+
+/// A decrypted credential. `Debug` never prints the value.
+#[derive(Clone)]
+pub struct SourceCredential {
+    pub kind: CredentialKind,
+    pub header_name: Option<String>,
+    pub value: String,
+}
+
+impl SourceCredential {
+    /// The `(name, value)` header pair to attach to a request.
+    pub fn header(&self) -> (String, String) {
+        match self.kind {
+            CredentialKind::Cookie => ("Cookie".to_string(), self.value.clone()),
+            CredentialKind::Bearer => ("Authorization".to_string(), format!("Bearer {}", self.value)),
+            CredentialKind::Header => (
+                self.header_name.clone().unwrap_or_else(|| "Authorization".to_string()),
+                self.value.clone(),
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for SourceCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceCredential")
+            .field("kind", &self.kind)
+            .field("header_name", &self.header_name)
+            .field("value", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Health of a stored credential, derived from the responses it gets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialHealth {
+    /// Unused since it was set, or last use succeeded.
+    Healthy,
+    /// The source rejected it (401/403); needs rotating.
+    AuthFailed,
+}
+
+impl CredentialHealth {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::AuthFailed => "auth_failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "auth_failed" => Self::AuthFailed,
+            _ => Self::Healthy,
+        }
+    }
+}
+
+/// Everything about a stored credential except its value.
+#[derive(Debug, Clone)]
+pub struct CredentialMeta {
+    pub canonical_key: String,
+    pub kind: CredentialKind,
+    pub header_name: Option<String>,
+    pub health: CredentialHealth,
+    pub consecutive_failures: u32,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub rotated_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Key a credential is stored under: normalized the same way the archive
+/// normalizes source URLs, so lookups at fetch time line up.
+pub fn storage_key(canonical_key: &str) -> String {
+    crate::router::normalize_url(canonical_key)
+}
+
+/// True when a response status means the source rejected our credential.
+pub fn is_auth_failure(status: u16) -> bool {
+    matches!(status, 401 | 403)
+}
+
+/// AES-256-GCM cipher for credential values. Nonces are random per value and
+/// the canonical_key is bound as associated data, so a ciphertext can't be
+/// moved to another source.
+pub struct CredentialCipher {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl CredentialCipher {
+    /// Build from a base64-encoded 32-byte key (`SOURCE_CREDENTIALS_KEY`).
+    pub fn from_base64_key(encoded: &str) -> Result<Self, CredentialError> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| CredentialError::InvalidKey(e.to_string()))?;
+        let unbound = UnboundKey::new(&AES_256_GCM, &bytes)
+            .map_err(|_| CredentialError::InvalidKey(format!("expected 32 bytes, got {}", bytes.len())))?;
+        Ok(Self {
+            key: LessSafeKey::new(unbound),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Encrypt `plaintext` for `canonical_key`. Returns `(nonce, ciphertext)`.
+    pub fn encrypt(&self, canonical_key: &str, plaintext: &str) -> Result<(Vec<u8>, Vec<u8>), CredentialError> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce_bytes).map_err(|_| CredentialError::Encrypt)?;
+        let mut in_out = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce_bytes),
+                Aad::from(canonical_key.as_bytes()),
+                &mut in_out,
+            )
+            .map_err(|_| CredentialError::Encrypt)?;
+        Ok((nonce_bytes.to_vec(), in_out))
+    }
+
+    pub fn decrypt(&self, canonical_key: &str, nonce: &[u8], ciphertext: &[u8]) -> Result<String, CredentialError> {
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| CredentialError::Decrypt)?;
+        let mut in_out = ciphertext.to_vec();
+        let plain = self
+            .key
+            .open_in_place(nonce, Aad::from(canonical_key.as_bytes()), &mut in_out)
+            .map_err(|_| CredentialError::Decrypt)?;
+        String::from_utf8(plain.to_vec()).map_err(|_| CredentialError::Decrypt)
+    }
+}
+
+type MetaRow = (
+    String,
+    String,
+    Option<String>,
+    String,
+    i32,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+    Option<String>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+/// Encrypted credential store backed by Postgres.
+pub struct CredentialStore {
+    pool: PgPool,
+    cipher: CredentialCipher,
+}
+
+impl CredentialStore {
+    pub fn new(pool: PgPool, cipher: CredentialCipher) -> Self {
+        Self { pool, cipher }
+    }
+
+    /// Set or rotate the credential for a source. Rotation resets health.
+    /// Returns true when an existing credential was replaced.
+    pub async fn set(&self, canonical_key: &str, credential: &SourceCredential) -> Result<bool, CredentialError> {
+        if credential.kind == CredentialKind::Header && credential.header_name.as_deref().is_none_or(str::is_empty) {
+            return Err(CredentialError::MissingHeaderName);
+        }
+        let key = storage_key(canonical_key);
+        let canonical_key = key.as_str();
+        let (nonce, ciphertext) = self.cipher.encrypt(canonical_key, &credential.value)?;
+
+        let (rotated,): (bool,) = sqlx::query_as(
+            r#"
+            INSERT INTO source_credentials (canonical_key, kind, header_name, nonce, ciphertext)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (canonical_key) DO UPDATE SET
+                kind = EXCLUDED.kind,
+                header_name = EXCLUDED.header_name,
+                nonce = EXCLUDED.nonce,
+                ciphertext = EXCLUDED.ciphertext,
+                health = 'healthy',
+                consecutive_failures = 0,
+                last_error = NULL,
+                rotated_at = now()
+            RETURNING (xmax <> 0) AS rotated
+            "#,
+        )
+        .bind(canonical_key)
+        .bind(credential.kind.as_str())
+        .bind(credential.header_name.as_deref())
+        .bind(nonce)
+        .bind(ciphertext)
+        .fetch_one(&self.pool)
+        .await?;
+
+        info!(canonical_key, kind = credential.kind.as_str(), rotated, "Source credential set");
+        Ok(rotated)
+    }
+
+    /// Remove the credential for a source. Returns false if there was none.
+    pub async fn delete(&self, canonical_key: &str) -> Result<bool, CredentialError> {
+        let result = sqlx::query("DELETE FROM source_credentials WHERE canonical_key = $1")
+            .bind(storage_key(canonical_key))
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Decrypted credential for a source, if one is stored.
+    pub(crate) async fn find(&self, canonical_key: &str) -> Result<Option<SourceCredential>, CredentialError> {
+        let key = storage_key(canonical_key);
+        let row = sqlx::query_as::<_, (String, Option<String>, Vec<u8>, Vec<u8>)>(
+            "SELECT kind, header_name, nonce, ciphertext
+             FROM source_credentials
+             WHERE canonical_key = $1",
+        )
+        .bind(&key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some((kind, header_name, nonce, ciphertext)) = row else {
+            return Ok(None);
+        };
+        let value = self.cipher.decrypt(&key, &nonce, &ciphertext)?;
+        let kind = CredentialKind::parse(&kind).unwrap_or(CredentialKind::Cookie);
+        Ok(Some(SourceCredential { kind, header_name, value }))
+    }
+
+    /// Metadata for all stored credentials, never the values.
+    pub async fn list(&self) -> Result<Vec<CredentialMeta>, CredentialError> {
+        let rows = sqlx::query_as::<_, MetaRow>(
+            "SELECT canonical_key, kind, header_name, health, consecutive_failures,
+                    last_success_at, last_failure_at, last_error, rotated_at, created_at
+             FROM source_credentials
+             ORDER BY canonical_key",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| CredentialMeta {
+                canonical_key: r.0,
+                kind: CredentialKind::parse(&r.1).unwrap_or(CredentialKind::Cookie),
+                header_name: r.2,
+                health: CredentialHealth::parse(&r.3),
+                consecutive_failures: r.4.max(0) as u32,
+                last_success_at: r.5,
+                last_failure_at: r.6,
+                last_error: r.7,
+                rotated_at: r.8,
+                created_at: r.9,
+            })
+            .collect())
+    }
+
+    pub(crate) async fn record_success(&self, canonical_key: &str) {
+        let key = storage_key(canonical_key);
+        let canonical_key = key.as_str();
+        let result = sqlx::query(
+            "UPDATE source_credentials
+             SET health = 'healthy', consecutive_failures = 0, last_success_at = now()
+             WHERE canonical_key = $1",
+        )
+        .bind(canonical_key)
+        .execute(&self.pool)
+        .await;
+        if let Err(e) = result {
+            warn!(canonical_key, error = %e, "Failed to record credential success");
+        }
+    }
+
+    pub(crate) async fn record_auth_failure(&self, canonical_key: &str, error: &str) {
+        let key = storage_key(canonical_key);
+        let canonical_key = key.as_str();
+        warn!(canonical_key, error, "Source rejected credential");
+        let result = sqlx::query(
+            "UPDATE source_credentials
+             SET health = 'auth_failed', consecutive_failures = consecutive_failures + 1,
+                 last_failure_at = now(), last_error = $2
+             WHERE canonical_key = $1",
+        )
+        .bind(canonical_key)
+        .bind(error)
+        .execute(&self.pool)
+        .await;
+        if let Err(e) = result {
+            warn!(canonical_key, error = %e, "Failed to record credential failure");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cipher() -> CredentialCipher {
+        let key = base64::engine::general_purpose::STANDARD.encode([7u8; 32]);
+        CredentialCipher::from_base64_key(&key).unwrap()
+    }
+
+    #[test]
+    fn encrypted_value_round_trips_for_its_own_source() {
+        let c = cipher();
+        let (nonce, ct) = c.encrypt("https://news.example.org", "session=abc123").unwrap();
+
+        assert_ne!(ct, b"session=abc123");
+        assert_eq!(c.decrypt("https://news.example.org", &nonce, &ct).unwrap(), "session=abc123");
+    }
+
+    #[test]
+    fn ciphertext_moved_to_another_source_fails_to_decrypt() {
+        let c = cipher();
+        let (nonce, ct) = c.encrypt("https://news.example.org", "session=abc123").unwrap();
+
+        assert!(matches!(
+            c.decrypt("https://other.example.org", &nonce, &ct),
+            Err(CredentialError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn short_key_is_rejected() {
+        let key = base64::engine::general_purpose::STANDARD.encode([7u8; 16]);
+        assert!(matches!(CredentialCipher::from_base64_key(&key), Err(CredentialError::InvalidKey(_))));
+    }
+
+    #[test]
+    fn each_kind_renders_its_header() {
+        let cred = |kind, header_name: Option<&str>| SourceCredential {
+            kind,
+            header_name: header_name.map(String::from),
+            value: "tok".into(),
+        };
+
+        assert_eq!(cred(CredentialKind::Cookie, None).header(), ("Cookie".into(), "tok".into()));
+        assert_eq!(cred(CredentialKind::Bearer, None).header(), ("Authorization".into(), "Bearer tok".into()));
+        assert_eq!(
+            cred(CredentialKind::Header, Some("X-Api-Key")).header(),
+            ("X-Api-Key".into(), "tok".into())
+        );
+    }
+
+    #[test]
+    fn canonical_url_and_archived_source_url_share_a_storage_key() {
+        assert_eq!(storage_key("https://www.news.example.org/members/"), storage_key("news.example.org/members"));
+    }
+
+    #[test]
+    fn debug_output_never_contains_the_value() {
+        let cred = SourceCredential {
+            kind: CredentialKind::Cookie,
+            header_name: None,
+            value: "session=supersecret".into(),
+        };
+        assert!(!format!("{cred:?}").contains("supersecret"));
+    }
+}
@@ -0,0 +1,99 @@
+// Engagement velocity: how fast a post is gathering interactions.
+//
+// Every fetch archives a new snapshot of a post, so a re-fetched post's
+// velocity is its interaction growth since the last snapshot. A post seen for
+// the first time is averaged over its age.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Shortest interval a velocity is measured over, so two fetches seconds
+/// apart don't read as a spike.
+const MIN_INTERVAL_HOURS: f64 = 0.25;
+/// Shortest age a first-seen post is averaged over.
+const MIN_AGE_HOURS: f64 = 1.0;
+
+/// An earlier snapshot of the same post.
+pub(crate) struct EngagementSnapshot {
+    pub engagement: Value,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Weighted interaction count: a share spreads a post further than a
+/// comment, and a comment says more than a like.
+pub(crate) fn interactions(engagement: &Value) -> f64 {
+    let count = |key: &str| engagement.get(key).and_then(Value::as_i64).unwrap_or(0).max(0) as f64;
+    count("likes") + 2.0 * count("comments") + 3.0 * count("shares")
+}
+
+/// Weighted interactions per hour, or `None` when there is nothing to
+/// measure against: no engagement, or a first-seen post without a publish time.
+pub(crate) fn velocity(
+    engagement: Option<&Value>,
+    now: DateTime<Utc>,
+    previous: Option<&EngagementSnapshot>,
+    published_at: Option<DateTime<Utc>>,
+) -> Option<f64> {
+    let current = interactions(engagement?);
+    let hours = |since: DateTime<Utc>| (now - since).num_seconds() as f64 / 3600.0;
+    match previous {
+        Some(prev) => {
+            let gained = (current - interactions(&prev.engagement)).max(0.0);
+            Some(gained / hours(prev.fetched_at).max(MIN_INTERVAL_HOURS))
+        }
+        None => published_at.map(|at| current / hours(at).max(MIN_AGE_HOURS)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use serde_json::json;
+
+    #[test]
+    fn refetched_post_velocity_is_growth_since_last_snapshot() {
+        let now = Utc::now();
+        let previous = EngagementSnapshot {
+            engagement: json!({ "likes": 10, "comments": 0, "shares": 0 }),
+            fetched_at: now - Duration::hours(2),
+        };
+        let current = json!({ "likes": 30, "comments": 5, "shares": 10 });
+
+        let v = velocity(Some(&current), now, Some(&previous), Some(now - Duration::days(3)));
+
+        assert_eq!(v, Some((20.0 + 10.0 + 30.0) / 2.0));
+    }
+
+    #[test]
+    fn first_seen_post_is_averaged_over_its_age() {
+        let now = Utc::now();
+        let viral = json!({ "shares": 500 });
+        let quiet = json!({ "likes": 2 });
+
+        let viral_v = velocity(Some(&viral), now, None, Some(now - Duration::minutes(30))).unwrap();
+        let quiet_v = velocity(Some(&quiet), now, None, Some(now - Duration::hours(4))).unwrap();
+
+        assert_eq!(viral_v, 1500.0);
+        assert_eq!(quiet_v, 0.5);
+    }
+
+    #[test]
+    fn velocity_needs_engagement_and_a_reference_point() {
+        let now = Utc::now();
+
+        assert_eq!(velocity(None, now, None, Some(now)), None);
+        assert_eq!(velocity(Some(&json!({ "likes": 3 })), now, None, None), None);
+    }
+
+    #[test]
+    fn lost_engagement_is_zero_velocity() {
+        let now = Utc::now();
+        let previous = EngagementSnapshot {
+            engagement: json!({ "likes": 50 }),
+            fetched_at: now - Duration::hours(1),
+        };
+
+        assert_eq!(velocity(Some(&json!({ "likes": 40 })), now, Some(&previous), None), Some(0.0));
+    }
+}
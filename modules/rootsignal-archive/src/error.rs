@@ -15,9 +15,67 @@ pub enum ArchiveError {
     #[error("Fetch failed: {0}")]
     FetchFailed(String),
 
+    #[error("Source rejected credential: {0}")]
+    AuthFailed(String),
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
+
+impl rootsignal_common::Classify for ArchiveError {
+    fn class(&self) -> rootsignal_common::ErrorClass {
+        use rootsignal_common::ErrorClass;
+        match self {
+            ArchiveError::NotFound(_)
+            | ArchiveError::Unsupported(_)
+            | ArchiveError::UnsupportedPlatform(_)
+            // Retrying won't help until an admin rotates the credential.
+            | ArchiveError::AuthFailed(_) => ErrorClass::User,
+            ArchiveError::FetchFailed(msg) => match ErrorClass::from_message(msg) {
+                // A fetch that failed for an unrecognized reason is most often a flaky host.
+                ErrorClass::System => ErrorClass::Transient,
+                class => class,
+            },
+            ArchiveError::Database(e) => match e {
+                sqlx::Error::PoolTimedOut | sqlx::Error::Io(_) | sqlx::Error::PoolClosed => ErrorClass::Transient,
+                _ => ErrorClass::System,
+            },
+            ArchiveError::Other(e) => e.class(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rootsignal_common::{Classify, ErrorClass};
+
+    #[test]
+    fn unsupported_platform_is_never_retried() {
+        let err = ArchiveError::UnsupportedPlatform("myspace".into());
+        assert_eq!(err.class(), ErrorClass::User);
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn fetch_failure_without_a_known_cause_is_retryable() {
+        assert!(ArchiveError::FetchFailed("empty DOM".into()).is_retryable());
+        assert_eq!(
+            ArchiveError::FetchFailed("HTTP status 404".into()).class(),
+            ErrorClass::User
+        );
+    }
+
+    #[test]
+    fn apify_quota_error_inside_archive_error_is_budget_class() {
+        let apify = apify_client::ApifyError::Api {
+            status: 402,
+            message: "Monthly usage hard limit exceeded".into(),
+        };
+        let err = ArchiveError::Other(anyhow::Error::new(apify));
+        assert_eq!(err.class(), ErrorClass::Budget);
+    }
+}
@@ -66,7 +66,7 @@ impl FetchRequest {
             }));
         }
 
-        // feed channel: Web → feed(), social → posts()
+        // feed channel: Web → feed(), social → posts(), podcast → episodes()
         if self.channels.feed {
             match self.platform {
                 Platform::Web => {
@@ -93,7 +93,8 @@ impl FetchRequest {
                 | Platform::Reddit
                 | Platform::Facebook
                 | Platform::TikTok
-                | Platform::Bluesky => {
+                | Platform::Bluesky
+                | Platform::Newsletter => {
                     let inner = self.inner.clone();
                     let source = self.source.clone();
                     let platform = self.platform;
@@ -115,10 +116,35 @@ impl FetchRequest {
                         }
                     }));
                 }
+                // Video channels only have the media channel; event
+                // platforms only the events channel.
+                Platform::YouTube | Platform::Eventbrite => {}
+                Platform::Podcast => {
+                    let inner = self.inner.clone();
+                    let source = self.source.clone();
+                    let identifier = self.identifier.clone();
+                    let limit = self.post_limit;
+                    futures.push(Box::pin(async move {
+                        let handle = crate::source_handle::SourceHandle {
+                            inner,
+                            source,
+                            platform: Platform::Podcast,
+                            identifier,
+                        };
+                        match handle.episodes(limit).send().await {
+                            Ok(episodes) => Some(ArchiveItem::Episodes(episodes)),
+                            Err(e) => {
+                                warn!(error = %e, "fetch: feed channel (episodes) failed");
+                                None
+                            }
+                        }
+                    }));
+                }
             }
         }
 
-        // media channel: Instagram → stories + short_videos, TikTok → short_videos
+        // media channel: Instagram → stories + short_videos, TikTok → short_videos,
+        // YouTube → long videos with transcripts
         if self.channels.media {
             match self.platform {
                 Platform::Instagram => {
@@ -184,12 +210,64 @@ impl FetchRequest {
                         }
                     }));
                 }
+                Platform::YouTube => {
+                    let inner = self.inner.clone();
+                    let source = self.source.clone();
+                    let identifier = self.identifier.clone();
+                    let limit = self.video_limit;
+                    futures.push(Box::pin(async move {
+                        let handle = crate::source_handle::SourceHandle {
+                            inner,
+                            source,
+                            platform: Platform::YouTube,
+                            identifier,
+                        };
+                        match handle.videos(limit).send().await {
+                            Ok(videos) => Some(ArchiveItem::LongVideos(videos)),
+                            Err(e) => {
+                                warn!(error = %e, "fetch: media channel (long_videos) failed");
+                                None
+                            }
+                        }
+                    }));
+                }
                 other => {
                     warn!(platform = ?other, "fetch: media channel not supported for platform");
                 }
             }
         }
 
+        // events channel: Eventbrite organizers and Facebook pages → listed events
+        if self.channels.events {
+            match self.platform {
+                Platform::Eventbrite | Platform::Facebook => {
+                    let inner = self.inner.clone();
+                    let source = self.source.clone();
+                    let platform = self.platform;
+                    let identifier = self.identifier.clone();
+                    let limit = self.post_limit;
+                    futures.push(Box::pin(async move {
+                        let handle = crate::source_handle::SourceHandle {
+                            inner,
+                            source,
+                            platform,
+                            identifier,
+                        };
+                        match handle.events(limit).send().await {
+                            Ok(events) => Some(ArchiveItem::Events(events)),
+                            Err(e) => {
+                                warn!(error = %e, platform = ?platform, "fetch: events channel failed");
+                                None
+                            }
+                        }
+                    }));
+                }
+                other => {
+                    warn!(platform = ?other, "fetch: events channel not supported for platform");
+                }
+            }
+        }
+
         let results = futures::future::join_all(futures).await;
         Ok(results.into_iter().flatten().collect())
     }
@@ -1,24 +1,42 @@
+pub mod apify_runs;
 pub mod archive;
+pub mod blob;
+pub mod crawl;
+pub mod credentials;
 pub mod enrichment;
 pub mod error;
 pub mod fetch_request;
+pub mod link_graph;
 pub mod links;
+pub mod newsletter;
+pub mod retention;
 pub mod router;
 pub mod text_extract;
 pub mod workflows;
+mod engagement;
 mod store;
+mod politeness;
 mod readability;
 mod services;
 mod source_handle;
 
+pub use apify_client::WebhookPayload as ApifyWebhookPayload;
+pub use apify_runs::{record_completion as record_apify_completion, PgRunCompletions};
 pub use archive::{Archive, ArchiveConfig, PageBackend};
+pub use crawl::{CrawlOutcome, CrawlScope, CrawlStats};
+pub use blob::{BlobBackend, BlobLifecycleReport, BlobMigrationReport, BlobStorageStats};
+pub use credentials::{CredentialCipher, CredentialError, CredentialKind, CredentialMeta, CredentialStore, SourceCredential};
 pub use enrichment::{EnrichmentJob, MockDispatcher, RestateDispatcher, WorkflowDispatcher};
 pub use error::{ArchiveError, Result};
 pub use fetch_request::FetchRequest;
+pub use link_graph::{DomainScore, LinkCandidate};
 pub use links::extract_links_by_pattern;
+pub use newsletter::{parse_ses_notification, ArchivedIssue, InboundEmail, InboundEmailError, NewsletterInbox, SesNotification};
 pub use router::Platform;
+pub use services::search::{SearchBackend, SearchProvider, SearchProviderConfig, SearchUsage};
+pub use services::transcription::TranscriptionBackend;
 pub use rootsignal_common::types::{ArchiveItem, Channels};
 pub use source_handle::{
     SourceHandle, PostsRequest, StoriesRequest, ShortVideoRequest, VideoRequest,
-    PageRequest, FeedRequest, SearchRequest, TopicSearchRequest, CrawlRequest,
+    EpisodesRequest, EventsRequest, PageRequest, FeedRequest, SearchRequest, TopicSearchRequest, CrawlRequest,
 };
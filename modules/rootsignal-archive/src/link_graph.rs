@@ -0,0 +1,227 @@
+//! Link graph over archived pages.
+//!
+//! Every archived page records its outbound links in `page_links`, keyed by
+//! normalized URL. Rolled up to domains, the graph gets HITS-style hub and
+//! authority scores: a domain many good hubs link to is an authority. Source
+//! discovery uses it to find domains that trusted sources keep linking to,
+//! and evidence provenance uses the page graph to spot citation loops —
+//! two pages that cite each other are not independent corroboration.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::router::{detect_platform, normalize_url, Platform};
+
+/// HITS iterations; scores settle well before this on graphs our size.
+const HITS_ITERATIONS: usize = 30;
+
+/// Links recorded per page. Pages with more are mostly navigation chrome.
+const MAX_LINKS_PER_PAGE: usize = 500;
+
+const ASSET_EXTENSIONS: &[&str] = &[
+    ".css", ".js", ".png", ".jpg", ".jpeg", ".gif", ".svg", ".woff", ".woff2", ".ico", ".webp", ".mp3", ".mp4",
+    ".json", ".xml",
+];
+
+/// The domain of a URL, normalized the way source URLs are: lowercase, no
+/// scheme, no `www.`, no port.
+pub fn domain_of(url: &str) -> String {
+    let normalized = normalize_url(url);
+    let host = normalized.split(['/', '?', '#']).next().unwrap_or_default();
+    host.split(':').next().unwrap_or_default().to_lowercase()
+}
+
+/// Normalize a page's links into graph edges `(to_url, to_domain)`, skipping
+/// static assets and duplicates.
+pub(crate) fn link_targets(links: &[String]) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+    links
+        .iter()
+        .filter(|link| {
+            let path = link.split(['?', '#']).next().unwrap_or_default().to_lowercase();
+            !ASSET_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+        })
+        .map(|link| (normalize_url(link), domain_of(link)))
+        .filter(|(url, domain)| !domain.is_empty() && seen.insert(url.clone()))
+        .take(MAX_LINKS_PER_PAGE)
+        .collect()
+}
+
+/// Hub and authority scores for one domain, each L2-normalized over the graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainScore {
+    pub domain: String,
+    pub hub: f64,
+    pub authority: f64,
+}
+
+/// HITS over a domain graph. `edges` are `(from_domain, to_domain, pages)`,
+/// where `pages` is how many archived pages carry the link; links within a
+/// domain are ignored. Sorted by authority, highest first.
+pub fn hits(edges: &[(String, String, u32)]) -> Vec<DomainScore> {
+    let edges: Vec<&(String, String, u32)> = edges.iter().filter(|(from, to, _)| from != to).collect();
+    let mut hub: HashMap<&str, f64> = HashMap::new();
+    let mut authority: HashMap<&str, f64> = HashMap::new();
+    for (from, to, _) in &edges {
+        hub.insert(from.as_str(), 1.0);
+        hub.entry(to.as_str()).or_insert(0.0);
+        authority.insert(to.as_str(), 1.0);
+        authority.entry(from.as_str()).or_insert(0.0);
+    }
+
+    for _ in 0..HITS_ITERATIONS {
+        let mut next_authority: HashMap<&str, f64> = authority.keys().map(|d| (*d, 0.0)).collect();
+        for (from, to, pages) in &edges {
+            *next_authority.entry(to.as_str()).or_default() += hub[from.as_str()] * f64::from(*pages);
+        }
+        normalize(&mut next_authority);
+
+        let mut next_hub: HashMap<&str, f64> = hub.keys().map(|d| (*d, 0.0)).collect();
+        for (from, to, pages) in &edges {
+            *next_hub.entry(from.as_str()).or_default() += next_authority[to.as_str()] * f64::from(*pages);
+        }
+        normalize(&mut next_hub);
+
+        authority = next_authority;
+        hub = next_hub;
+    }
+
+    let mut scores: Vec<DomainScore> = authority
+        .iter()
+        .map(|(domain, authority)| DomainScore {
+            domain: domain.to_string(),
+            hub: hub.get(domain).copied().unwrap_or_default(),
+            authority: *authority,
+        })
+        .collect();
+    scores.sort_by(|a, b| b.authority.total_cmp(&a.authority).then_with(|| a.domain.cmp(&b.domain)));
+    scores
+}
+
+fn normalize(scores: &mut HashMap<&str, f64>) {
+    let norm = scores.values().map(|s| s * s).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        scores.values_mut().for_each(|s| *s /= norm);
+    }
+}
+
+/// A domain trusted sources link to that isn't itself trusted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkCandidate {
+    pub domain: String,
+    /// Distinct trusted domains linking here.
+    pub trusted_linkers: u32,
+    pub authority: f64,
+}
+
+/// Domains linked from at least `min_trusted_linkers` trusted domains,
+/// ranked by how many trusted domains link to them, then by authority.
+/// Social and other platform domains are left out: their accounts are
+/// discovered individually, not as a whole site.
+pub fn link_candidates(
+    edges: &[(String, String, u32)],
+    trusted: &HashSet<String>,
+    min_trusted_linkers: u32,
+) -> Vec<LinkCandidate> {
+    let mut linkers: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for (from, to, _) in edges {
+        if from != to && trusted.contains(from) && !trusted.contains(to) {
+            linkers.entry(to.as_str()).or_default().insert(from.as_str());
+        }
+    }
+    let authority: HashMap<String, f64> = hits(edges).into_iter().map(|s| (s.domain, s.authority)).collect();
+
+    let mut candidates: Vec<LinkCandidate> = linkers
+        .into_iter()
+        .filter(|(domain, from)| {
+            from.len() as u32 >= min_trusted_linkers && detect_platform(&format!("{domain}/")) == Platform::Web
+        })
+        .map(|(domain, from)| LinkCandidate {
+            domain: domain.to_string(),
+            trusted_linkers: from.len() as u32,
+            authority: authority.get(domain).copied().unwrap_or_default(),
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.trusted_linkers
+            .cmp(&a.trusted_linkers)
+            .then_with(|| b.authority.total_cmp(&a.authority))
+            .then_with(|| a.domain.cmp(&b.domain))
+    });
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(from: &str, to: &str) -> (String, String, u32) {
+        (from.to_string(), to.to_string(), 1)
+    }
+
+    #[test]
+    fn domains_are_normalized_like_sources() {
+        assert_eq!(domain_of("https://www.Example.org:8443/news?id=1"), "example.org");
+        assert_eq!(domain_of("example.org"), "example.org");
+        assert_eq!(domain_of("http://twitter.com/someone"), "x.com");
+    }
+
+    #[test]
+    fn link_targets_skip_assets_and_duplicates() {
+        let links = vec![
+            "https://www.mutualaid.org/fridges/".to_string(),
+            "https://mutualaid.org/fridges".to_string(),
+            "https://cdn.example.com/site.CSS?v=2".to_string(),
+            "https://city.gov/311".to_string(),
+        ];
+        assert_eq!(
+            link_targets(&links),
+            vec![
+                ("mutualaid.org/fridges".to_string(), "mutualaid.org".to_string()),
+                ("city.gov/311".to_string(), "city.gov".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn hits_ranks_the_domain_hubs_agree_on() {
+        let edges = vec![
+            edge("news.org", "foodshelf.org"),
+            edge("blog.net", "foodshelf.org"),
+            edge("church.org", "foodshelf.org"),
+            edge("news.org", "city.gov"),
+            edge("foodshelf.org", "foodshelf.org"),
+        ];
+        let scores = hits(&edges);
+        assert_eq!(scores[0].domain, "foodshelf.org");
+        assert!(scores[0].authority > 0.9);
+        let news = scores.iter().find(|s| s.domain == "news.org").unwrap();
+        let blog = scores.iter().find(|s| s.domain == "blog.net").unwrap();
+        assert!(news.hub > blog.hub, "linking to more authorities makes a better hub");
+        assert!(hits(&[]).is_empty());
+    }
+
+    #[test]
+    fn candidates_need_enough_trusted_linkers() {
+        let edges = vec![
+            edge("news.org", "foodshelf.org"),
+            edge("church.org", "foodshelf.org"),
+            edge("spam.biz", "casino.biz"),
+            edge("news.org", "tenants.org"),
+            edge("news.org", "church.org"),
+            edge("church.org", "facebook.com"),
+            edge("news.org", "facebook.com"),
+            edge("church.org", "x.com"),
+            edge("news.org", "x.com"),
+        ];
+        let trusted: HashSet<String> = ["news.org", "church.org"].into_iter().map(String::from).collect();
+
+        let candidates = link_candidates(&edges, &trusted, 2);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].domain, "foodshelf.org");
+        assert_eq!(candidates[0].trusted_linkers, 2);
+
+        let candidates = link_candidates(&edges, &trusted, 1);
+        let domains: Vec<&str> = candidates.iter().map(|c| c.domain.as_str()).collect();
+        assert_eq!(domains, vec!["foodshelf.org", "tenants.org"]);
+    }
+}
@@ -0,0 +1,336 @@
+// Newsletter ingestion: inbound email → archived newsletter issues.
+//
+// Lots of civic information only goes out by email. The scout is subscribed
+// to newsletters through a dedicated inbound address; the email provider
+// (Mailgun route, or SES receipt rule → SNS) posts each message to the API,
+// and every message becomes a post on the `newsletter:<sender>` source.
+// Scout runs read those posts back like any other owned source.
+//
+// Issues are keyed by the From address rather than the envelope sender:
+// bulk senders use per-message bounce addresses that never repeat.
+
+use std::collections::HashMap;
+
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
+use mail_parser::{MessageParser, PartType};
+use sqlx::PgPool;
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::newsletter_source_value;
+
+use crate::error::Result;
+use crate::readability::html_to_markdown;
+use crate::router::normalize_url;
+use crate::store::{InsertPost, Store};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InboundEmailError {
+    #[error("Inbound email is missing {0}")]
+    MissingField(&'static str),
+
+    #[error("Malformed inbound email: {0}")]
+    Malformed(String),
+}
+
+/// One newsletter message as delivered by the email provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundEmail {
+    pub from_address: String,
+    pub from_name: Option<String>,
+    pub subject: String,
+    pub html: Option<String>,
+    pub text: Option<String>,
+    pub message_id: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl InboundEmail {
+    /// Parse the form fields of a Mailgun inbound route (`forward()` action).
+    pub fn from_mailgun_fields(fields: &HashMap<String, String>) -> std::result::Result<Self, InboundEmailError> {
+        let get = |k: &str| fields.get(k).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+        let from = get("from")
+            .or_else(|| get("From"))
+            .or_else(|| get("sender"))
+            .ok_or(InboundEmailError::MissingField("from"))?;
+        let (from_name, from_address) = parse_mailbox(from)
+            .ok_or_else(|| InboundEmailError::Malformed(format!("unparseable from: {from}")))?;
+
+        Ok(Self {
+            from_address,
+            from_name,
+            subject: get("subject").unwrap_or_default().to_string(),
+            html: get("body-html").map(String::from),
+            text: get("body-plain").map(String::from),
+            message_id: get("Message-Id").map(String::from),
+            sent_at: get("timestamp")
+                .and_then(|t| t.parse::<i64>().ok())
+                .and_then(|t| Utc.timestamp_opt(t, 0).single()),
+        })
+    }
+
+    /// Parse a raw RFC 5322 message (SES delivers these via SNS).
+    pub fn from_mime(raw: &[u8]) -> std::result::Result<Self, InboundEmailError> {
+        let message = MessageParser::default()
+            .parse(raw)
+            .ok_or_else(|| InboundEmailError::Malformed("not a MIME message".into()))?;
+        let from = message
+            .from()
+            .and_then(|a| a.first())
+            .ok_or(InboundEmailError::MissingField("from"))?;
+        let from_address = from
+            .address()
+            .ok_or(InboundEmailError::MissingField("from address"))?
+            .to_lowercase();
+
+        // `body_html` synthesizes HTML for text-only mail; only take real HTML parts.
+        let html = message.html_bodies().find_map(|p| match &p.body {
+            PartType::Html(h) => Some(h.to_string()),
+            _ => None,
+        });
+        let text = message.text_bodies().find_map(|p| match &p.body {
+            PartType::Text(t) => Some(t.to_string()),
+            _ => None,
+        });
+
+        Ok(Self {
+            from_address,
+            from_name: from.name().map(String::from),
+            subject: message.subject().unwrap_or_default().to_string(),
+            html,
+            text,
+            message_id: message.message_id().map(String::from),
+            sent_at: message
+                .date()
+                .and_then(|d| Utc.timestamp_opt(d.to_timestamp(), 0).single()),
+        })
+    }
+
+    /// The `newsletter:<sender>` source this issue belongs to.
+    pub fn source_value(&self) -> String {
+        newsletter_source_value(&self.from_address)
+    }
+
+    /// Extraction-ready markdown: subject as a heading, then the body.
+    pub fn to_markdown(&self) -> String {
+        let body = match (&self.html, &self.text) {
+            (Some(html), _) => {
+                let md = html_to_markdown(html.as_bytes(), None);
+                if md.trim().is_empty() {
+                    self.text.clone().unwrap_or_default()
+                } else {
+                    md
+                }
+            }
+            (None, Some(text)) => text.clone(),
+            (None, None) => String::new(),
+        };
+        if self.subject.is_empty() {
+            body.trim().to_string()
+        } else {
+            format!("# {}\n\n{}", self.subject, body.trim())
+        }
+    }
+}
+
+/// Split `Display Name <addr@host>` (or a bare address) into its parts.
+fn parse_mailbox(s: &str) -> Option<(Option<String>, String)> {
+    let (name, address) = match (s.rfind('<'), s.rfind('>')) {
+        (Some(open), Some(close)) if open < close => {
+            let name = s[..open].trim().trim_matches('"').trim();
+            (
+                (!name.is_empty()).then(|| name.to_string()),
+                s[open + 1..close].trim(),
+            )
+        }
+        _ => (None, s.trim()),
+    };
+    address
+        .contains('@')
+        .then(|| (name, address.to_lowercase()))
+}
+
+/// What an SNS delivery to the inbound endpoint carried.
+#[derive(Debug)]
+pub enum SesNotification {
+    /// SNS asks the endpoint to confirm the topic subscription by fetching this URL.
+    SubscriptionConfirmation { subscribe_url: String },
+    Email(Box<InboundEmail>),
+    /// Anything else (unsubscribe confirmations, SES setup notifications).
+    Ignored(String),
+}
+
+/// Parse an SNS HTTP notification carrying an SES "Received" event. The
+/// receipt rule's SNS action must include the message content.
+pub fn parse_ses_notification(body: &str) -> std::result::Result<SesNotification, InboundEmailError> {
+    let envelope: serde_json::Value =
+        serde_json::from_str(body).map_err(|e| InboundEmailError::Malformed(e.to_string()))?;
+
+    match envelope["Type"].as_str() {
+        Some("SubscriptionConfirmation") => {
+            let subscribe_url = envelope["SubscribeURL"]
+                .as_str()
+                .ok_or(InboundEmailError::MissingField("SubscribeURL"))?;
+            return Ok(SesNotification::SubscriptionConfirmation {
+                subscribe_url: subscribe_url.to_string(),
+            });
+        }
+        Some("Notification") => {}
+        other => return Ok(SesNotification::Ignored(other.unwrap_or("unknown").to_string())),
+    }
+
+    let message: serde_json::Value = envelope["Message"]
+        .as_str()
+        .ok_or(InboundEmailError::MissingField("Message"))
+        .and_then(|m| serde_json::from_str(m).map_err(|e| InboundEmailError::Malformed(e.to_string())))?;
+    if message["notificationType"].as_str() != Some("Received") {
+        let kind = message["notificationType"].as_str().unwrap_or("unknown");
+        return Ok(SesNotification::Ignored(kind.to_string()));
+    }
+
+    let content = message["content"]
+        .as_str()
+        .ok_or(InboundEmailError::MissingField("content (enable it on the SNS action)"))?;
+    let raw = if message["receipt"]["action"]["encoding"].as_str() == Some("BASE64") {
+        base64::engine::general_purpose::STANDARD
+            .decode(content.trim())
+            .map_err(|e| InboundEmailError::Malformed(e.to_string()))?
+    } else {
+        content.as_bytes().to_vec()
+    };
+
+    InboundEmail::from_mime(&raw).map(|e| SesNotification::Email(Box::new(e)))
+}
+
+/// An issue that was newly archived.
+#[derive(Debug, Clone)]
+pub struct ArchivedIssue {
+    pub source_value: String,
+    pub post_id: Uuid,
+}
+
+/// Writes inbound newsletter issues into the archive.
+#[derive(Clone)]
+pub struct NewsletterInbox {
+    store: Store,
+}
+
+impl NewsletterInbox {
+    pub fn new(pool: PgPool) -> Self {
+        Self { store: Store::new(pool) }
+    }
+
+    /// Archive an issue under its sender's source. Returns `None` when the
+    /// same issue was already archived (providers retry deliveries) or it
+    /// has no readable body.
+    pub async fn ingest(&self, email: &InboundEmail) -> Result<Option<ArchivedIssue>> {
+        let markdown = email.to_markdown();
+        if markdown.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let source_value = email.source_value();
+        let source = self.store.upsert_source(&normalize_url(&source_value)).await?;
+        let content_hash = rootsignal_common::content_hash(&markdown).to_string();
+        if self.store.has_post(source.id, &content_hash).await? {
+            return Ok(None);
+        }
+
+        let post_id = self
+            .store
+            .insert_post(&InsertPost {
+                source_id: source.id,
+                content_hash,
+                text: Some(markdown),
+                author: Some(email.from_name.clone().unwrap_or_else(|| email.from_address.clone())),
+                location: None,
+                engagement: None,
+                published_at: email.sent_at,
+                permalink: None,
+                mentions: Vec::new(),
+                hashtags: Vec::new(),
+                media_type: Some("newsletter".to_string()),
+                platform_id: email.message_id.clone(),
+            }, None)
+            .await?;
+        self.store.update_last_scraped(source.id, "posts").await?;
+
+        info!(source = source_value.as_str(), subject = email.subject.as_str(), "Newsletter issue archived");
+        Ok(Some(ArchivedIssue { source_value, post_id }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: &str = "From: \"Ward 6 News\" <News@Ward6.org>\r\n\
+        To: scout@in.rootsignal.app\r\n\
+        Subject: Tenant meeting Thursday\r\n\
+        Message-ID: <abc123@ward6.org>\r\n\
+        Date: Tue, 09 Jun 2026 14:00:00 +0000\r\n\
+        Content-Type: text/plain; charset=utf-8\r\n\
+        \r\n\
+        Join us at Matthews Park at 6pm.\r\n";
+
+    #[test]
+    fn mailgun_fields_key_issue_by_from_address_not_bounce_sender() {
+        let fields: HashMap<String, String> = [
+            ("sender", "bounce-mc.123@mcsv.net"),
+            ("from", "Ward 6 News <News@Ward6.org>"),
+            ("subject", "Tenant meeting"),
+            ("body-plain", "Thursday 6pm"),
+            ("timestamp", "1781013600"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let email = InboundEmail::from_mailgun_fields(&fields).unwrap();
+
+        assert_eq!(email.source_value(), "newsletter:news@ward6.org");
+        assert_eq!(email.from_name.as_deref(), Some("Ward 6 News"));
+        assert!(email.sent_at.is_some());
+    }
+
+    #[test]
+    fn text_only_mime_message_keeps_plain_body() {
+        let email = InboundEmail::from_mime(RAW.as_bytes()).unwrap();
+
+        assert_eq!(email.from_address, "news@ward6.org");
+        assert!(email.html.is_none());
+        assert_eq!(
+            email.to_markdown(),
+            "# Tenant meeting Thursday\n\nJoin us at Matthews Park at 6pm."
+        );
+    }
+
+    #[test]
+    fn ses_notification_with_base64_content_yields_email() {
+        let message = serde_json::json!({
+            "notificationType": "Received",
+            "receipt": { "action": { "type": "SNS", "encoding": "BASE64" } },
+            "content": base64::engine::general_purpose::STANDARD.encode(RAW),
+        });
+        let body = serde_json::json!({ "Type": "Notification", "Message": message.to_string() });
+
+        let parsed = parse_ses_notification(&body.to_string()).unwrap();
+
+        let SesNotification::Email(email) = parsed else { panic!("expected email") };
+        assert_eq!(email.subject, "Tenant meeting Thursday");
+    }
+
+    #[test]
+    fn ses_subscription_confirmation_exposes_subscribe_url() {
+        let body = r#"{"Type":"SubscriptionConfirmation","SubscribeURL":"https://sns.example/confirm"}"#;
+
+        let parsed = parse_ses_notification(body).unwrap();
+
+        assert!(matches!(
+            parsed,
+            SesNotification::SubscriptionConfirmation { subscribe_url } if subscribe_url == "https://sns.example/confirm"
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+// Per-domain politeness for page fetches.
+// Every page fetch — single pages and crawls alike — waits its turn on the
+// domain, so a crawl never hammers one site and concurrent scrapes of the
+// same site are spaced out.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Minimum gap between two requests to the same domain.
+pub(crate) const DEFAULT_DOMAIN_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) struct DomainRateLimiter {
+    interval: Duration,
+    /// When each domain may next be requested.
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl DomainRateLimiter {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until `url`'s domain may be requested, and claim that slot.
+    /// Callers queue behind each other: the slot is reserved before sleeping.
+    pub(crate) async fn wait(&self, url: &str) {
+        let delay = self.reserve(&crate::link_graph::domain_of(url), Instant::now()).await;
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Reserve the next free slot for `domain` at or after `now`, returning
+    /// how long the caller must wait for it.
+    async fn reserve(&self, domain: &str, now: Instant) -> Duration {
+        let mut slots = self.next_slot.lock().await;
+        let slot = slots.get(domain).copied().filter(|s| *s > now).unwrap_or(now);
+        slots.insert(domain.to_string(), slot + self.interval);
+        if slots.len() > 10_000 {
+            slots.retain(|_, next| *next > now);
+        }
+        slot - now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_to_one_domain_are_spaced_out() {
+        let limiter = DomainRateLimiter::new(Duration::from_secs(2));
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("example.org", now).await, Duration::ZERO);
+        assert_eq!(limiter.reserve("example.org", now).await, Duration::from_secs(2));
+        assert_eq!(limiter.reserve("example.org", now).await, Duration::from_secs(4));
+        assert_eq!(limiter.reserve("other.org", now).await, Duration::ZERO);
+        // Once the domain has been idle long enough, no wait.
+        assert_eq!(limiter.reserve("other.org", now + Duration::from_secs(5)).await, Duration::ZERO);
+    }
+}
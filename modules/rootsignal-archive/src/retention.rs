@@ -0,0 +1,23 @@
+//! Archive side of data retention: purging old fetched content.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::error::Result;
+use crate::router::normalize_url;
+use crate::store::Store;
+
+/// Delete archived content fetched before `cutoff`, except from sources
+/// under a legal hold. Returns (purged, held); a dry run only counts.
+pub async fn purge_content_before(
+    pool: &PgPool,
+    cutoff: DateTime<Utc>,
+    held_source_urls: &[String],
+    dry_run: bool,
+) -> Result<(u64, u64)> {
+    let held: Vec<String> = held_source_urls.iter().map(|url| normalize_url(url)).collect();
+    let (purged, kept) = Store::new(pool.clone()).purge_content_before(cutoff, &held, dry_run).await?;
+    info!(purged, held = kept, dry_run, %cutoff, "Archive content purge");
+    Ok((purged, kept))
+}
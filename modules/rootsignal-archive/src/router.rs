@@ -11,13 +11,22 @@ pub enum Platform {
     Facebook,
     TikTok,
     Bluesky,
+    YouTube,
+    /// Eventbrite organizer page; events come from the Eventbrite API.
+    Eventbrite,
+    /// Podcast RSS feed with audio enclosures.
+    Podcast,
+    /// Email newsletter (`newsletter:<sender>`); issues are pushed, never fetched.
+    Newsletter,
     Web,
 }
 
 /// Detect which platform a URL belongs to.
 pub fn detect_platform(url: &str) -> Platform {
     let lower = url.to_lowercase();
-    if lower.contains("instagram.com") {
+    if rootsignal_common::is_newsletter(&lower) {
+        Platform::Newsletter
+    } else if lower.contains("instagram.com") {
         Platform::Instagram
     } else if lower.contains("twitter.com") || lower.contains("x.com/") {
         Platform::Twitter
@@ -25,10 +34,16 @@ pub fn detect_platform(url: &str) -> Platform {
         Platform::Reddit
     } else if lower.contains("facebook.com") {
         Platform::Facebook
+    } else if lower.contains("eventbrite.com/o/") {
+        Platform::Eventbrite
     } else if lower.contains("tiktok.com") {
         Platform::TikTok
     } else if lower.contains("bsky.app") {
         Platform::Bluesky
+    } else if lower.contains("youtube.com/") || lower.contains("youtu.be/") {
+        Platform::YouTube
+    } else if rootsignal_common::is_podcast_feed(&lower) {
+        Platform::Podcast
     } else {
         Platform::Web
     }
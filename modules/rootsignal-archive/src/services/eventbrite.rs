@@ -0,0 +1,214 @@
+// Eventbrite service: an organizer's live events via the public v3 API.
+// Organizer pages (eventbrite.com/o/<slug>-<id>) carry the organizer ID the
+// API is keyed by. Venue and organizer are expanded inline so each event is
+// one request page, not three.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::store::InsertEvent;
+
+const API_BASE: &str = "https://www.eventbriteapi.com/v3";
+
+#[derive(Debug, Deserialize)]
+struct EventsResponse {
+    #[serde(default)]
+    events: Vec<EventbriteEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteEvent {
+    id: String,
+    name: Option<EventbriteText>,
+    description: Option<EventbriteText>,
+    url: String,
+    start: Option<EventbriteTime>,
+    end: Option<EventbriteTime>,
+    #[serde(default)]
+    online_event: bool,
+    venue: Option<EventbriteVenue>,
+    organizer: Option<EventbriteOrganizer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteText {
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteTime {
+    utc: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteVenue {
+    name: Option<String>,
+    address: Option<EventbriteAddress>,
+}
+
+/// Eventbrite sends coordinates as strings.
+#[derive(Debug, Deserialize)]
+struct EventbriteAddress {
+    localized_address_display: Option<String>,
+    latitude: Option<String>,
+    longitude: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteOrganizer {
+    name: Option<String>,
+    url: Option<String>,
+}
+
+pub(crate) struct EventbriteService {
+    token: String,
+    client: reqwest::Client,
+}
+
+impl EventbriteService {
+    pub(crate) fn new(token: &str) -> Self {
+        Self {
+            token: token.to_string(),
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("Failed to build HTTP client"),
+        }
+    }
+
+    /// Fetch an organizer's live events, soonest first.
+    pub(crate) async fn fetch_events(
+        &self,
+        organizer_url: &str,
+        source_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<InsertEvent>> {
+        let organizer_id = organizer_id(organizer_url)
+            .with_context(|| format!("No Eventbrite organizer ID in {organizer_url}"))?;
+        info!(organizer_id, limit, "eventbrite: fetching events");
+
+        let body = self
+            .client
+            .get(format!("{API_BASE}/organizers/{organizer_id}/events/"))
+            .bearer_auth(&self.token)
+            .query(&[
+                ("status", "live"),
+                ("order_by", "start_asc"),
+                ("expand", "venue,organizer"),
+                ("page_size", &limit.to_string()),
+            ])
+            .send()
+            .await
+            .context("Eventbrite request failed")?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        let mut events = parse_events(&body, source_id)?;
+        events.truncate(limit as usize);
+        Ok(events)
+    }
+}
+
+/// The numeric organizer ID at the end of an organizer page's slug.
+pub(crate) fn organizer_id(organizer_url: &str) -> Option<&str> {
+    let path = organizer_url.split("/o/").nth(1)?;
+    let slug = path.split(['/', '?']).next()?;
+    let id = slug.rsplit('-').next()?;
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then_some(id)
+}
+
+/// Parse an organizer events response into archive rows.
+pub(crate) fn parse_events(body: &[u8], source_id: Uuid) -> Result<Vec<InsertEvent>> {
+    let resp: EventsResponse = serde_json::from_slice(body).context("Failed to parse Eventbrite events")?;
+
+    Ok(resp
+        .events
+        .into_iter()
+        .filter_map(|e| {
+            let title = e.name.and_then(|n| n.text).filter(|t| !t.trim().is_empty())?;
+            let address = e.venue.as_ref().and_then(|v| v.address.as_ref());
+            let coord = |s: Option<&String>| s.and_then(|s| s.parse::<f64>().ok());
+
+            Some(InsertEvent {
+                source_id,
+                content_hash: rootsignal_common::content_hash(&format!("eventbrite:{}", e.id)).to_string(),
+                title,
+                description: e.description.and_then(|d| d.text).filter(|t| !t.trim().is_empty()),
+                starts_at: e.start.map(|t| t.utc),
+                ends_at: e.end.map(|t| t.utc),
+                venue_name: e.venue.as_ref().and_then(|v| v.name.clone()),
+                venue_address: address.and_then(|a| a.localized_address_display.clone()),
+                latitude: coord(address.and_then(|a| a.latitude.as_ref())),
+                longitude: coord(address.and_then(|a| a.longitude.as_ref())),
+                is_online: e.online_event,
+                organizer_name: e.organizer.as_ref().and_then(|o| o.name.clone()),
+                organizer_url: e.organizer.as_ref().and_then(|o| o.url.clone()),
+                // Eventbrite sells tickets on the event page itself.
+                ticket_url: None,
+                permalink: e.url,
+                platform_id: e.id,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = r#"{
+      "pagination": {"object_count": 2},
+      "events": [
+        {
+          "id": "771234",
+          "name": {"text": "Tenant rights clinic"},
+          "description": {"text": "Free legal advice for renters."},
+          "url": "https://www.eventbrite.com/e/tenant-rights-clinic-tickets-771234",
+          "start": {"timezone": "America/Chicago", "local": "2026-11-05T18:00:00", "utc": "2026-11-06T00:00:00Z"},
+          "end": {"timezone": "America/Chicago", "local": "2026-11-05T20:00:00", "utc": "2026-11-06T02:00:00Z"},
+          "online_event": false,
+          "venue": {
+            "name": "Sumner Library",
+            "address": {"localized_address_display": "611 Van White Memorial Blvd, Minneapolis, MN", "latitude": "44.9829", "longitude": "-93.2901"}
+          },
+          "organizer": {"name": "Northside Tenants Union", "url": "https://www.eventbrite.com/o/northside-tenants-union-31415926535"}
+        },
+        {
+          "id": "771235",
+          "name": {"text": "  "},
+          "url": "https://www.eventbrite.com/e/771235"
+        }
+      ]
+    }"#;
+
+    #[test]
+    fn organizer_id_is_the_trailing_number_of_the_slug() {
+        assert_eq!(
+            organizer_id("eventbrite.com/o/northside-tenants-union-31415926535"),
+            Some("31415926535")
+        );
+        assert_eq!(organizer_id("https://www.eventbrite.com/o/31415926535/?aff=x"), Some("31415926535"));
+        assert_eq!(organizer_id("eventbrite.com/o/northside-tenants-union"), None);
+    }
+
+    #[test]
+    fn venue_organizer_and_times_map_to_event_fields() {
+        let source_id = Uuid::new_v4();
+        let events = parse_events(RESPONSE.as_bytes(), source_id).unwrap();
+
+        assert_eq!(events.len(), 1, "untitled events are dropped");
+        let e = &events[0];
+        assert_eq!(e.platform_id, "771234");
+        assert_eq!(e.starts_at.unwrap().to_rfc3339(), "2026-11-06T00:00:00+00:00");
+        assert_eq!(e.venue_name.as_deref(), Some("Sumner Library"));
+        assert_eq!(e.latitude, Some(44.9829));
+        assert_eq!(e.organizer_name.as_deref(), Some("Northside Tenants Union"));
+        assert_eq!(e.permalink, "https://www.eventbrite.com/e/tenant-rights-clinic-tickets-771234");
+    }
+}
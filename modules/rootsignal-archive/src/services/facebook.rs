@@ -1,4 +1,4 @@
-// Facebook service: posts only (no topic search support).
+// Facebook service: page posts and page events (no topic search support).
 // Wraps ApifyClient, returns universal content types.
 
 use anyhow::Result;
@@ -7,7 +7,9 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 use tracing::info;
 use uuid::Uuid;
 
-use crate::store::InsertPost;
+use rootsignal_common::types::PostComment;
+
+use crate::store::{InsertEvent, InsertPost};
 use crate::text_extract;
 
 /// Raw fetched post before persistence.
@@ -24,6 +26,25 @@ impl FacebookService {
         Self { client }
     }
 
+    /// Fetch top-level comments on one post.
+    pub(crate) async fn fetch_comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        info!(post_url, limit, "facebook: fetching comments");
+        let raw = self.client.scrape_facebook_comments(post_url, limit).await?;
+        Ok(raw
+            .into_iter()
+            .filter_map(|c| {
+                Some(PostComment {
+                    text: c.text.filter(|t| !t.trim().is_empty())?,
+                    author: c.profile_name,
+                    published_at: c.date.as_deref().and_then(|s| {
+                        DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&Utc)).ok()
+                    }),
+                })
+            })
+            .take(limit as usize)
+            .collect())
+    }
+
     /// Fetch posts from a Facebook page.
     pub(crate) async fn fetch_posts(
         &self,
@@ -80,4 +101,51 @@ impl FacebookService {
 
         Ok(posts)
     }
+
+    /// Fetch upcoming events from a Facebook page's events tab.
+    pub(crate) async fn fetch_events(
+        &self,
+        events_url: &str,
+        source_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<InsertEvent>> {
+        info!(events_url, limit, "facebook: fetching events");
+
+        let raw = self.client.scrape_facebook_events(events_url, limit).await?;
+
+        let events = raw
+            .into_iter()
+            .filter_map(|e| {
+                let title = e.name.filter(|n| !n.trim().is_empty())?;
+                let permalink = e.url?;
+                let platform_id = e.id.unwrap_or_else(|| permalink.clone());
+                let location = e.location.as_ref();
+                let venue_address = location.and_then(|l| match (&l.street_address, &l.city) {
+                    (Some(street), Some(city)) => Some(format!("{street}, {city}")),
+                    (street, city) => street.clone().or_else(|| city.clone()),
+                });
+
+                Some(InsertEvent {
+                    source_id,
+                    content_hash: rootsignal_common::content_hash(&format!("facebook:{platform_id}")).to_string(),
+                    title,
+                    description: e.description.filter(|d| !d.trim().is_empty()),
+                    starts_at: e.utc_start_date,
+                    ends_at: e.utc_end_date,
+                    venue_name: location.and_then(|l| l.name.clone()),
+                    venue_address,
+                    latitude: location.and_then(|l| l.latitude),
+                    longitude: location.and_then(|l| l.longitude),
+                    is_online: e.is_online,
+                    organizer_name: e.organized_by,
+                    organizer_url: e.organizer_url,
+                    ticket_url: e.ticket_url,
+                    permalink,
+                    platform_id,
+                })
+            })
+            .collect();
+
+        Ok(events)
+    }
 }
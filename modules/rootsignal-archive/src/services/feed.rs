@@ -8,6 +8,7 @@ use rootsignal_common::FeedItem;
 use tracing::info;
 use uuid::Uuid;
 
+use crate::credentials::{is_auth_failure, CredentialRejected, SourceCredential};
 use crate::store::InsertFeed;
 
 const RSS_MAX_ITEMS: usize = 20;
@@ -31,18 +32,27 @@ impl FeedService {
     }
 
     /// Fetch and parse an RSS/Atom/JSON feed, returning an InsertFeed.
+    /// With a `credential`, a 401/403 fails with [`CredentialRejected`].
     pub(crate) async fn fetch(
         &self,
         feed_url: &str,
         source_id: Uuid,
+        credential: Option<&SourceCredential>,
     ) -> Result<FetchedFeed> {
-        let resp = self
+        let mut req = self
             .client
             .get(feed_url)
-            .header("User-Agent", "rootsignal-archive/0.1")
-            .send()
-            .await
-            .context("RSS feed fetch failed")?;
+            .header("User-Agent", "rootsignal-archive/0.1");
+        if let Some(credential) = credential {
+            let (name, value) = credential.header();
+            req = req.header(name, value);
+        }
+        let resp = req.send().await.context("RSS feed fetch failed")?;
+
+        let status = resp.status().as_u16();
+        if credential.is_some() && is_auth_failure(status) {
+            return Err(CredentialRejected { status }.into());
+        }
 
         let bytes = resp.bytes().await.context("Failed to read RSS feed body")?;
         let feed = feed_rs::parser::parse(&bytes[..]).context("Failed to parse RSS/Atom feed")?;
@@ -6,6 +6,8 @@ use apify_client::ApifyClient;
 use tracing::info;
 use uuid::Uuid;
 
+use rootsignal_common::types::PostComment;
+
 use crate::store::{InsertFile, InsertPost, InsertShortVideo, InsertStory};
 use crate::text_extract;
 
@@ -34,6 +36,23 @@ impl InstagramService {
         Self { client }
     }
 
+    /// Fetch comments on one post.
+    pub(crate) async fn fetch_comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        info!(post_url, limit, "instagram: fetching comments");
+        let raw = self.client.scrape_instagram_comments(post_url, limit).await?;
+        Ok(raw
+            .into_iter()
+            .filter_map(|c| {
+                Some(PostComment {
+                    text: c.text.filter(|t| !t.trim().is_empty())?,
+                    author: c.owner_username,
+                    published_at: c.timestamp,
+                })
+            })
+            .take(limit as usize)
+            .collect())
+    }
+
     /// Fetch posts from an Instagram profile.
     pub(crate) async fn fetch_posts(
         &self,
@@ -2,11 +2,16 @@
 // platform and return universal content types. Zero storage dependency.
 
 pub(crate) mod bluesky;
+pub(crate) mod eventbrite;
 pub(crate) mod facebook;
 pub(crate) mod feed;
 pub(crate) mod instagram;
 pub(crate) mod page;
+pub(crate) mod podcast;
 pub(crate) mod reddit;
 pub(crate) mod search;
+pub(crate) mod sitemap;
 pub(crate) mod tiktok;
+pub(crate) mod transcription;
 pub(crate) mod twitter;
+pub(crate) mod youtube;
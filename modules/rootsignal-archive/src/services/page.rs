@@ -8,6 +8,7 @@ use rand::Rng;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::credentials::{is_auth_failure, CredentialRejected, SourceCredential};
 use crate::readability::html_to_markdown;
 use crate::store::InsertPage;
 
@@ -219,6 +220,73 @@ impl BrowserlessPageService {
     }
 }
 
+/// Fetches gated pages directly over HTTP with a source credential attached.
+/// Used instead of Chrome/Browserless when the source has a credential, since
+/// neither backend here can carry per-request headers.
+pub(crate) struct AuthenticatedPageService {
+    client: reqwest::Client,
+}
+
+impl AuthenticatedPageService {
+    pub(crate) fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("Failed to build authenticated page HTTP client");
+        Self { client }
+    }
+
+    /// Fetch a page with `credential` attached. A 401/403 fails with
+    /// [`CredentialRejected`] so the caller can mark the credential unhealthy.
+    pub(crate) async fn fetch(
+        &self,
+        url: &str,
+        source_id: Uuid,
+        credential: &SourceCredential,
+    ) -> Result<FetchedPage> {
+        let url = if url.starts_with("http://") || url.starts_with("https://") {
+            url.to_string()
+        } else {
+            format!("https://{url}")
+        };
+        info!(url = url.as_str(), kind = credential.kind.as_str(), "page: fetching with source credential");
+
+        let (name, value) = credential.header();
+        let resp = self
+            .client
+            .get(&url)
+            .header("User-Agent", "rootsignal-archive/0.1")
+            .header(name, value)
+            .send()
+            .await
+            .context("Authenticated page fetch failed")?;
+
+        let status = resp.status().as_u16();
+        if is_auth_failure(status) {
+            return Err(CredentialRejected { status }.into());
+        }
+        if !resp.status().is_success() {
+            anyhow::bail!("HTTP status {status} fetching {url}");
+        }
+
+        let html = resp.text().await.context("Failed to read page body")?;
+        let markdown = html_to_markdown(html.as_bytes(), Some(&url));
+        let hash = rootsignal_common::content_hash(&html).to_string();
+        let title = extract_title(&html);
+
+        Ok(FetchedPage {
+            page: InsertPage {
+                source_id,
+                content_hash: hash,
+                markdown,
+                title,
+                links: Vec::new(),
+            },
+            raw_html: html,
+        })
+    }
+}
+
 /// Extract links from raw HTML that match a given URL pattern.
 /// Resolves relative URLs against `base_url`, deduplicates, and caps at 20 results.
 pub fn extract_links_by_pattern(html: &str, base_url: &str, pattern: &str) -> Vec<String> {
@@ -0,0 +1,225 @@
+// Podcast service: RSS audio enclosures + speech-to-text transcripts.
+// Episodes are listed from the show's RSS feed; each new episode's audio is
+// downloaded and handed to the configured Transcriber. Without a transcriber
+// only the show notes are archived.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rootsignal_common::TranscriptSegment;
+use tracing::{info, warn};
+
+use super::transcription::Transcriber;
+
+const USER_AGENT: &str = "rootsignal-archive/0.1";
+const AUDIO_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// An episode in a podcast feed, before its audio is transcribed.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EpisodeListing {
+    /// The item's `<guid>`, falling back to the audio URL.
+    pub guid: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    /// Episode page, when the feed links one.
+    pub link: Option<String>,
+    pub audio_url: String,
+    pub mime_type: String,
+    pub duration_secs: Option<f64>,
+}
+
+pub(crate) struct PodcastService {
+    client: reqwest::Client,
+    audio_client: reqwest::Client,
+    transcriber: Option<Arc<dyn Transcriber>>,
+}
+
+impl PodcastService {
+    pub(crate) fn new(transcriber: Option<Arc<dyn Transcriber>>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(15))
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("Failed to build podcast HTTP client");
+        let audio_client = reqwest::Client::builder()
+            .timeout(AUDIO_DOWNLOAD_TIMEOUT)
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("Failed to build podcast audio HTTP client");
+        Self {
+            client,
+            audio_client,
+            transcriber,
+        }
+    }
+
+    /// Most recent episodes with an audio enclosure, newest first.
+    pub(crate) async fn list_episodes(&self, feed_url: &str, limit: u32) -> Result<Vec<EpisodeListing>> {
+        let feed_url = if feed_url.starts_with("http") {
+            feed_url.to_string()
+        } else {
+            format!("https://{feed_url}")
+        };
+        let bytes = self
+            .client
+            .get(&feed_url)
+            .send()
+            .await
+            .context("Podcast feed fetch failed")?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let episodes = parse_episodes(&bytes, limit as usize)?;
+        info!(feed_url, episodes = episodes.len(), "podcast: listed episodes");
+        Ok(episodes)
+    }
+
+    /// Transcribe an episode's audio. `None` when no transcriber is
+    /// configured, the audio is too large for it, or transcription fails.
+    pub(crate) async fn transcript(&self, episode: &EpisodeListing) -> Option<Vec<TranscriptSegment>> {
+        let transcriber = self.transcriber.as_ref()?;
+        match self.download_and_transcribe(transcriber.as_ref(), episode).await {
+            Ok(segments) => segments,
+            Err(e) => {
+                warn!(audio_url = episode.audio_url, error = %e, "podcast: transcription failed");
+                None
+            }
+        }
+    }
+
+    async fn download_and_transcribe(
+        &self,
+        transcriber: &dyn Transcriber,
+        episode: &EpisodeListing,
+    ) -> Result<Option<Vec<TranscriptSegment>>> {
+        let resp = self
+            .audio_client
+            .get(&episode.audio_url)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        // Check the advertised size first so oversized episodes aren't downloaded.
+        let too_large = |len: usize| transcriber.max_bytes().is_some_and(|max| len > max);
+        if resp.content_length().is_some_and(|len| too_large(len as usize)) {
+            warn!(audio_url = episode.audio_url, "podcast: audio exceeds transcriber limit, skipping");
+            return Ok(None);
+        }
+        let bytes = resp.bytes().await?;
+        if too_large(bytes.len()) {
+            warn!(audio_url = episode.audio_url, "podcast: audio exceeds transcriber limit, skipping");
+            return Ok(None);
+        }
+
+        let segments = transcriber.transcribe(bytes.to_vec(), &episode.mime_type).await?;
+        info!(
+            audio_url = episode.audio_url,
+            transcriber = transcriber.name(),
+            segments = segments.len(),
+            "podcast: episode transcribed"
+        );
+        Ok((!segments.is_empty()).then_some(segments))
+    }
+}
+
+/// Parse a podcast RSS feed into episodes that carry an audio enclosure.
+pub(crate) fn parse_episodes(bytes: &[u8], limit: usize) -> Result<Vec<EpisodeListing>> {
+    let feed = feed_rs::parser::parse(bytes).context("Failed to parse podcast feed")?;
+
+    let mut episodes: Vec<EpisodeListing> = feed
+        .entries
+        .into_iter()
+        .filter_map(|entry| {
+            let (audio_url, mime_type, duration) = entry.media.iter().find_map(|media| {
+                media.content.iter().find_map(|content| {
+                    let mime = content.content_type.as_ref()?.to_string();
+                    if !mime.starts_with("audio/") {
+                        return None;
+                    }
+                    let url = content.url.as_ref()?.to_string();
+                    Some((url, mime, content.duration.or(media.duration)))
+                })
+            })?;
+            let guid = if entry.id.is_empty() {
+                audio_url.clone()
+            } else {
+                entry.id
+            };
+
+            Some(EpisodeListing {
+                guid,
+                title: entry.title.map(|t| t.content),
+                description: entry
+                    .summary
+                    .map(|s| s.content)
+                    .or_else(|| entry.media.iter().find_map(|m| m.description.clone().map(|d| d.content))),
+                published_at: entry.published.or(entry.updated),
+                link: entry
+                    .links
+                    .iter()
+                    .map(|l| l.href.clone())
+                    .find(|href| *href != audio_url),
+                audio_url,
+                mime_type,
+                duration_secs: duration.map(|d| d.as_secs_f64()),
+            })
+        })
+        .collect();
+
+    episodes.sort_by_key(|e| std::cmp::Reverse(e.published_at));
+    episodes.truncate(limit);
+    Ok(episodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Northside Talks</title>
+    <item>
+      <title>Ep 12: Rent strike on Penn Ave</title>
+      <guid>northside-12</guid>
+      <link>https://northside.fm/12</link>
+      <description>Tenants explain why they stopped paying.</description>
+      <pubDate>Tue, 06 Oct 2026 15:00:00 GMT</pubDate>
+      <enclosure url="https://cdn.northside.fm/12.mp3" length="4000000" type="audio/mpeg"/>
+    </item>
+    <item>
+      <title>Ep 13: Library hours</title>
+      <guid>northside-13</guid>
+      <pubDate>Tue, 13 Oct 2026 15:00:00 GMT</pubDate>
+      <enclosure url="https://cdn.northside.fm/13.mp3" length="4000000" type="audio/mpeg"/>
+    </item>
+    <item>
+      <title>Blog post, no audio</title>
+      <guid>northside-blog</guid>
+      <link>https://northside.fm/blog</link>
+    </item>
+  </channel>
+</rss>"#;
+
+    #[test]
+    fn only_items_with_audio_enclosures_become_episodes_newest_first() {
+        let episodes = parse_episodes(FEED.as_bytes(), 10).unwrap();
+
+        assert_eq!(episodes.len(), 2);
+        assert_eq!(episodes[0].guid, "northside-13");
+        assert_eq!(episodes[1].audio_url, "https://cdn.northside.fm/12.mp3");
+        assert_eq!(episodes[1].mime_type, "audio/mpeg");
+        assert_eq!(episodes[1].link.as_deref(), Some("https://northside.fm/12"));
+    }
+
+    #[test]
+    fn episode_limit_keeps_the_newest() {
+        let episodes = parse_episodes(FEED.as_bytes(), 1).unwrap();
+
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].title.as_deref(), Some("Ep 13: Library hours"));
+    }
+}
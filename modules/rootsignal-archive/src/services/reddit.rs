@@ -7,6 +7,8 @@ use chrono::{DateTime, Utc};
 use tracing::info;
 use uuid::Uuid;
 
+use rootsignal_common::types::PostComment;
+
 use crate::store::InsertPost;
 use crate::text_extract;
 
@@ -24,6 +26,27 @@ impl RedditService {
         Self { client }
     }
 
+    /// Fetch top-level comments on one post. Replies to comments are dropped.
+    pub(crate) async fn fetch_comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        info!(post_url, limit, "reddit: fetching comments");
+        let raw = self.client.scrape_reddit_comments(post_url, limit).await?;
+        Ok(raw
+            .into_iter()
+            .filter(|c| c.data_type.as_deref() == Some("comment"))
+            .filter(|c| c.parent_id.as_deref().is_some_and(|p| p.starts_with("t3_")))
+            .filter_map(|c| {
+                Some(PostComment {
+                    text: c.body.filter(|t| !t.trim().is_empty())?,
+                    author: c.username,
+                    published_at: c.created_at.as_deref()
+                        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                        .map(|dt| dt.with_timezone(&Utc)),
+                })
+            })
+            .take(limit as usize)
+            .collect())
+    }
+
     /// Fetch posts from a subreddit.
     pub(crate) async fn fetch_posts(
         &self,
@@ -1,66 +1,306 @@
-// Web search service (Serper / Google Search).
+// Web search service: Serper, Tavily, Brave Search and SearxNG behind one
+// `SearchProvider` trait. Providers are tried in the configured order; one
+// that fails or is over its daily query allowance falls through to the next.
+// Results come back normalized whichever provider answered.
 // Returns universal ArchivedSearchResults content type.
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 use std::time::Duration;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
 use rootsignal_common::SearchResult;
-use tracing::info;
+use tokio::time::Instant;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::store::InsertSearchResults;
 
-#[derive(Debug, serde::Deserialize)]
-struct SerperResponse {
-    #[serde(default)]
-    organic: Vec<SerperResult>,
+/// Tracking parameters stripped from result URLs so the same page found by
+/// two providers (or two campaigns) dedups.
+const TRACKING_PARAMS: &[&str] = &["fbclid", "gclid", "msclkid", "mc_cid", "mc_eid", "ref_src"];
+
+/// Brave's free tier allows one query per second.
+const BRAVE_MIN_INTERVAL: Duration = Duration::from_secs(1);
+/// Brave returns at most 20 results per query.
+const BRAVE_MAX_COUNT: usize = 20;
+
+/// A web search backend.
+pub enum SearchBackend {
+    /// Google results via serper.dev.
+    Serper { api_key: String },
+    Tavily { api_key: String },
+    Brave { api_key: String },
+    /// A self-hosted SearxNG instance with the JSON format enabled.
+    Searxng { base_url: String },
 }
 
-#[derive(Debug, serde::Deserialize)]
-struct SerperResult {
-    #[serde(default)]
-    link: String,
-    #[serde(default)]
-    title: String,
-    #[serde(default)]
-    snippet: String,
+impl SearchBackend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Serper { .. } => "serper",
+            Self::Tavily { .. } => "tavily",
+            Self::Brave { .. } => "brave",
+            Self::Searxng { .. } => "searxng",
+        }
+    }
+
+    fn build(self, client: &reqwest::Client) -> Option<Box<dyn SearchProvider>> {
+        let client = client.clone();
+        match self {
+            Self::Serper { api_key }
+            | Self::Tavily { api_key }
+            | Self::Brave { api_key }
+                if api_key.is_empty() =>
+            {
+                None
+            }
+            Self::Searxng { base_url } if base_url.is_empty() => None,
+            Self::Serper { api_key } => Some(Box::new(SerperProvider { api_key, client })),
+            Self::Tavily { api_key } => Some(Box::new(TavilyProvider { api_key, client })),
+            Self::Brave { api_key } => Some(Box::new(BraveProvider { api_key, client })),
+            Self::Searxng { base_url } => Some(Box::new(SearxngProvider {
+                base_url: base_url.trim_end_matches('/').to_string(),
+                client,
+            })),
+        }
+    }
+}
+
+/// One provider in the fallback chain.
+pub struct SearchProviderConfig {
+    pub backend: SearchBackend,
+    /// Queries per UTC day before falling back to the next provider; `None` = unlimited.
+    pub daily_limit: Option<u32>,
+}
+
+/// Today's query count for one provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchUsage {
+    pub provider: String,
+    pub queries_today: u32,
+    pub daily_limit: Option<u32>,
+}
+
+/// A web search API.
+#[async_trait]
+pub trait SearchProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Shortest gap the provider allows between two queries.
+    fn min_interval(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>>;
 }
 
 pub(crate) struct FetchedSearchResults {
     pub results: InsertSearchResults,
 }
 
+struct ProviderSlot {
+    provider: Box<dyn SearchProvider>,
+    daily_limit: Option<u32>,
+    /// When the provider may next be queried.
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl ProviderSlot {
+    async fn wait_turn(&self) {
+        let interval = self.provider.min_interval();
+        if interval.is_zero() {
+            return;
+        }
+        let delay = {
+            let now = Instant::now();
+            let mut next = self.next_slot.lock().unwrap();
+            let slot = next.filter(|s| *s > now).unwrap_or(now);
+            *next = Some(slot + interval);
+            slot - now
+        };
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
 pub(crate) struct SearchService {
-    api_key: String,
-    client: reqwest::Client,
+    providers: Vec<ProviderSlot>,
 }
 
 impl SearchService {
-    pub(crate) fn new(api_key: &str) -> Self {
-        Self {
-            api_key: api_key.to_string(),
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(30))
-                .build()
-                .expect("Failed to build HTTP client"),
-        }
+    /// `None` when no provider is usable (missing keys or URLs).
+    pub(crate) fn new(configs: Vec<SearchProviderConfig>) -> Option<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("Failed to build HTTP client");
+        let providers: Vec<ProviderSlot> = configs
+            .into_iter()
+            .filter_map(|config| {
+                Some(ProviderSlot {
+                    provider: config.backend.build(&client)?,
+                    daily_limit: config.daily_limit,
+                    next_slot: Mutex::new(None),
+                })
+            })
+            .collect();
+        (!providers.is_empty()).then_some(Self { providers })
+    }
+
+    /// Configured providers in fallback order, with their daily limits.
+    pub(crate) fn providers(&self) -> impl Iterator<Item = (&'static str, Option<u32>)> + '_ {
+        self.providers.iter().map(|slot| (slot.provider.name(), slot.daily_limit))
     }
 
-    /// Run a web search and return InsertSearchResults.
+    /// Run a web search on the first provider that is under its daily limit
+    /// and answers. `used_today` is each provider's query count so far today.
     pub(crate) async fn search(
         &self,
         query: &str,
         source_id: Uuid,
         max_results: usize,
+        used_today: &HashMap<String, u32>,
     ) -> Result<FetchedSearchResults> {
-        info!(query, max_results, "search: querying serper");
+        let mut last_error = None;
+        for slot in &self.providers {
+            let name = slot.provider.name();
+            let used = used_today.get(name).copied().unwrap_or(0);
+            if slot.daily_limit.is_some_and(|limit| used >= limit) {
+                info!(provider = name, used, "search: provider over its daily limit, falling back");
+                continue;
+            }
+
+            slot.wait_turn().await;
+            info!(query, max_results, provider = name, "search: querying");
+            let results = match slot.provider.search(query, max_results).await {
+                Ok(results) => normalize_results(results, max_results),
+                Err(e) => {
+                    warn!(provider = name, error = %e, "search: provider failed, falling back");
+                    last_error = Some(e.context(format!("{name} search failed")));
+                    continue;
+                }
+            };
+
+            let results_json =
+                serde_json::to_value(&results).unwrap_or(serde_json::Value::Array(vec![]));
+            let content_hash = rootsignal_common::content_hash(
+                &serde_json::to_string(&results_json).unwrap_or_default(),
+            )
+            .to_string();
+
+            info!(query, provider = name, count = results.len(), "search: complete");
+
+            return Ok(FetchedSearchResults {
+                results: InsertSearchResults {
+                    source_id,
+                    content_hash,
+                    query: query.to_string(),
+                    results: results_json,
+                    provider: name.to_string(),
+                },
+            });
+        }
+        Err(last_error.unwrap_or_else(|| anyhow!("Every search provider is over its daily limit")))
+    }
+}
+
+/// Trim and collapse whitespace, drop non-web and duplicate URLs, strip
+/// tracking parameters and fragments, and cap at `max_results`.
+fn normalize_results(results: Vec<SearchResult>, max_results: usize) -> Vec<SearchResult> {
+    let mut seen = HashSet::new();
+    results
+        .into_iter()
+        .filter_map(|r| {
+            let url = clean_url(r.url.trim())?;
+            seen.insert(url.clone()).then(|| SearchResult {
+                url,
+                title: collapse_whitespace(&r.title),
+                snippet: collapse_whitespace(&r.snippet),
+            })
+        })
+        .take(max_results)
+        .collect()
+}
+
+fn clean_url(raw: &str) -> Option<String> {
+    let mut url = url::Url::parse(raw).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    url.set_fragment(None);
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_ref()))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(&kept);
+    }
+    Some(url.to_string())
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
 
+/// Brave and SearxNG mark matched terms with `<strong>`; drop the markup.
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&amp;", "&").replace("&quot;", "\"").replace("&#39;", "'")
+}
+
+// ---------------------------------------------------------------------------
+// Providers
+// ---------------------------------------------------------------------------
+
+struct SerperProvider {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SerperResponse {
+    #[serde(default)]
+    organic: Vec<SerperResult>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SerperResult {
+    #[serde(default)]
+    link: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    snippet: String,
+}
+
+#[async_trait]
+impl SearchProvider for SerperProvider {
+    fn name(&self) -> &'static str {
+        "serper"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
         let body = serde_json::json!({
             "q": query,
             "num": max_results,
         });
 
-        let resp = self
+        let data: SerperResponse = self
             .client
             .post("https://google.serper.dev/search")
             .header("X-API-KEY", &self.api_key)
@@ -68,14 +308,14 @@ impl SearchService {
             .json(&body)
             .send()
             .await
-            .context("Serper API request failed")?;
-
-        let data: SerperResponse = resp
+            .context("Serper API request failed")?
+            .error_for_status()
+            .context("Serper API request failed")?
             .json()
             .await
             .context("Failed to parse Serper response")?;
 
-        let results: Vec<SearchResult> = data
+        Ok(data
             .organic
             .into_iter()
             .map(|r| SearchResult {
@@ -83,24 +323,295 @@ impl SearchService {
                 title: r.title,
                 snippet: r.snippet,
             })
-            .collect();
+            .collect())
+    }
+}
+
+struct TavilyProvider {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TavilyResponse {
+    #[serde(default)]
+    results: Vec<TavilyResult>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TavilyResult {
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    content: String,
+}
 
-        let results_json =
-            serde_json::to_value(&results).unwrap_or(serde_json::Value::Array(vec![]));
-        let content_hash = rootsignal_common::content_hash(
-            &serde_json::to_string(&results_json).unwrap_or_default(),
-        )
-        .to_string();
-
-        info!(query, count = results.len(), "search: complete");
-
-        Ok(FetchedSearchResults {
-            results: InsertSearchResults {
-                source_id,
-                content_hash,
-                query: query.to_string(),
-                results: results_json,
+#[async_trait]
+impl SearchProvider for TavilyProvider {
+    fn name(&self) -> &'static str {
+        "tavily"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+        let body = serde_json::json!({
+            "query": query,
+            "max_results": max_results,
+        });
+
+        let data: TavilyResponse = self
+            .client
+            .post("https://api.tavily.com/search")
+            .bearer_auth(&self.api_key)
+            .json(&body)
+            .send()
+            .await
+            .context("Tavily API request failed")?
+            .error_for_status()
+            .context("Tavily API request failed")?
+            .json()
+            .await
+            .context("Failed to parse Tavily response")?;
+
+        Ok(data
+            .results
+            .into_iter()
+            .map(|r| SearchResult {
+                url: r.url,
+                title: r.title,
+                snippet: r.content,
+            })
+            .collect())
+    }
+}
+
+struct BraveProvider {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct BraveResponse {
+    #[serde(default)]
+    web: BraveWeb,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct BraveWeb {
+    #[serde(default)]
+    results: Vec<BraveResult>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BraveResult {
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+}
+
+#[async_trait]
+impl SearchProvider for BraveProvider {
+    fn name(&self) -> &'static str {
+        "brave"
+    }
+
+    fn min_interval(&self) -> Duration {
+        BRAVE_MIN_INTERVAL
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+        let count = max_results.min(BRAVE_MAX_COUNT).to_string();
+        let data: BraveResponse = self
+            .client
+            .get("https://api.search.brave.com/res/v1/web/search")
+            .query(&[("q", query), ("count", count.as_str())])
+            .header("X-Subscription-Token", &self.api_key)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .context("Brave Search API request failed")?
+            .error_for_status()
+            .context("Brave Search API request failed")?
+            .json()
+            .await
+            .context("Failed to parse Brave Search response")?;
+
+        Ok(data
+            .web
+            .results
+            .into_iter()
+            .map(|r| SearchResult {
+                url: r.url,
+                title: strip_tags(&r.title),
+                snippet: strip_tags(&r.description),
+            })
+            .collect())
+    }
+}
+
+struct SearxngProvider {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SearxngResponse {
+    #[serde(default)]
+    results: Vec<SearxngResult>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SearxngResult {
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    content: String,
+}
+
+#[async_trait]
+impl SearchProvider for SearxngProvider {
+    fn name(&self) -> &'static str {
+        "searxng"
+    }
+
+    async fn search(&self, query: &str, _max_results: usize) -> Result<Vec<SearchResult>> {
+        let data: SearxngResponse = self
+            .client
+            .get(format!("{}/search", self.base_url))
+            .query(&[("q", query), ("format", "json")])
+            .send()
+            .await
+            .context("SearxNG request failed")?
+            .error_for_status()
+            .context("SearxNG request failed")?
+            .json()
+            .await
+            .context("Failed to parse SearxNG response (is the json format enabled?)")?;
+
+        Ok(data
+            .results
+            .into_iter()
+            .map(|r| SearchResult {
+                url: r.url,
+                title: strip_tags(&r.title),
+                snippet: strip_tags(&r.content),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(url: &str, title: &str) -> SearchResult {
+        SearchResult {
+            url: url.to_string(),
+            title: title.to_string(),
+            snippet: "  Food  shelf\nhours ".to_string(),
+        }
+    }
+
+    struct Canned {
+        name: &'static str,
+        results: Option<Vec<SearchResult>>,
+    }
+
+    #[async_trait]
+    impl SearchProvider for Canned {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn search(&self, _query: &str, _max_results: usize) -> Result<Vec<SearchResult>> {
+            self.results.clone().ok_or_else(|| anyhow!("503 Service Unavailable"))
+        }
+    }
+
+    fn service(providers: Vec<(Canned, Option<u32>)>) -> SearchService {
+        SearchService {
+            providers: providers
+                .into_iter()
+                .map(|(provider, daily_limit)| ProviderSlot {
+                    provider: Box::new(provider),
+                    daily_limit,
+                    next_slot: Mutex::new(None),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn results_are_normalized() {
+        let results = normalize_results(
+            vec![
+                result("https://foodshelf.org/hours?utm_source=x&id=2#top", " Hours "),
+                result("https://foodshelf.org/hours?id=2&fbclid=abc", "Hours again"),
+                result("mailto:help@foodshelf.org", "Email"),
+                result("https://city.gov/311", "311"),
+                result("https://news.org/story", "Story"),
+            ],
+            2,
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].url, "https://foodshelf.org/hours?id=2");
+        assert_eq!(results[0].title, "Hours");
+        assert_eq!(results[0].snippet, "Food shelf hours");
+        assert_eq!(results[1].url, "https://city.gov/311");
+    }
+
+    #[test]
+    fn markup_is_stripped_from_snippets() {
+        assert_eq!(strip_tags("Free <strong>meals</strong> &amp; groceries"), "Free meals & groceries");
+    }
+
+    #[tokio::test]
+    async fn falls_back_past_failing_and_exhausted_providers() {
+        let search = service(vec![
+            (Canned { name: "serper", results: Some(vec![result("https://a.org/", "A")]) }, Some(100)),
+            (Canned { name: "brave", results: None }, None),
+            (Canned { name: "searxng", results: Some(vec![result("https://b.org/", "B")]) }, None),
+        ]);
+        let used = HashMap::from([("serper".to_string(), 100)]);
+
+        let fetched = search.search("food shelf", Uuid::nil(), 10, &used).await.unwrap();
+        assert_eq!(fetched.results.provider, "searxng");
+
+        let fetched = search.search("food shelf", Uuid::nil(), 10, &HashMap::new()).await.unwrap();
+        assert_eq!(fetched.results.provider, "serper");
+    }
+
+    #[tokio::test]
+    async fn errors_when_every_provider_is_out() {
+        let search = service(vec![
+            (Canned { name: "serper", results: Some(Vec::new()) }, Some(0)),
+            (Canned { name: "tavily", results: None }, None),
+        ]);
+        let err = search.search("q", Uuid::nil(), 10, &HashMap::new()).await.err().unwrap();
+        assert!(err.to_string().contains("tavily search failed"));
+
+        let search = service(vec![(Canned { name: "serper", results: Some(Vec::new()) }, Some(0))]);
+        let err = search.search("q", Uuid::nil(), 10, &HashMap::new()).await.err().unwrap();
+        assert!(err.to_string().contains("daily limit"));
+    }
+
+    #[test]
+    fn backends_without_credentials_are_skipped() {
+        let configs = vec![
+            SearchProviderConfig { backend: SearchBackend::Serper { api_key: String::new() }, daily_limit: None },
+            SearchProviderConfig {
+                backend: SearchBackend::Searxng { base_url: "http://searx.local/".to_string() },
+                daily_limit: None,
             },
-        })
+        ];
+        let service = SearchService::new(configs).unwrap();
+        assert_eq!(service.providers().collect::<Vec<_>>(), vec![("searxng", None)]);
+        assert!(SearchService::new(Vec::new()).is_none());
     }
 }
@@ -0,0 +1,337 @@
+// Sitemap service: finds a site's sitemaps (robots.txt `Sitemap:` lines, else
+// /sitemap.xml), follows sitemap indexes, and returns the page URLs with their
+// lastmod and changefreq hints. Crawls use it in place of link-following.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use tracing::{debug, info};
+
+use crate::politeness::DomainRateLimiter;
+
+/// Sitemap files fetched per crawl, indexes included.
+const MAX_SITEMAP_FILES: usize = 10;
+/// Page URLs collected per crawl; big city sites list hundreds of thousands.
+const MAX_SITEMAP_URLS: usize = 5_000;
+
+/// A sitemap's `<changefreq>` hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeFreq {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+
+impl ChangeFreq {
+    fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "always" => Some(Self::Always),
+            "hourly" => Some(Self::Hourly),
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            "monthly" => Some(Self::Monthly),
+            "yearly" => Some(Self::Yearly),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Recrawl interval in hours; `None` for pages that never change.
+    pub fn hours(self) -> Option<u32> {
+        match self {
+            Self::Always | Self::Hourly => Some(1),
+            Self::Daily => Some(24),
+            Self::Weekly => Some(24 * 7),
+            Self::Monthly => Some(24 * 30),
+            Self::Yearly => Some(24 * 365),
+            Self::Never => None,
+        }
+    }
+}
+
+/// One `<url>` (or, in an index, `<sitemap>`) entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+    pub url: String,
+    pub lastmod: Option<DateTime<Utc>>,
+    pub changefreq: Option<ChangeFreq>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Sitemap {
+    Urls(Vec<SitemapEntry>),
+    /// A sitemap index: entries are child sitemaps.
+    Index(Vec<SitemapEntry>),
+}
+
+pub(crate) struct SitemapService {
+    client: reqwest::Client,
+}
+
+impl SitemapService {
+    pub(crate) fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .build()
+            .expect("Failed to build sitemap HTTP client");
+        Self { client }
+    }
+
+    /// Every page URL the site's sitemaps list, most recently modified first.
+    /// Empty when the site has no readable sitemap.
+    pub(crate) async fn discover(&self, seed_url: &str, politeness: &DomainRateLimiter) -> Vec<SitemapEntry> {
+        let Ok(seed) = url::Url::parse(seed_url) else {
+            return Vec::new();
+        };
+        let origin = seed.origin().ascii_serialization();
+
+        politeness.wait(&origin).await;
+        let mut pending: VecDeque<String> = self.robots_sitemaps(&origin).await.into();
+        if pending.is_empty() {
+            pending.push_back(format!("{origin}/sitemap.xml"));
+        }
+
+        let mut fetched = HashSet::new();
+        let mut entries = Vec::new();
+        while let Some(sitemap_url) = pending.pop_front() {
+            if fetched.len() >= MAX_SITEMAP_FILES || entries.len() >= MAX_SITEMAP_URLS {
+                break;
+            }
+            if !fetched.insert(sitemap_url.clone()) {
+                continue;
+            }
+            politeness.wait(&sitemap_url).await;
+            match self.fetch(&sitemap_url).await {
+                Ok(Sitemap::Urls(urls)) => entries.extend(urls),
+                Ok(Sitemap::Index(mut children)) => {
+                    // Recently updated child sitemaps hold the recently updated pages.
+                    sort_by_lastmod(&mut children);
+                    pending.extend(children.into_iter().map(|c| c.url));
+                }
+                Err(e) => debug!(url = %sitemap_url, error = %e, "sitemap: skipping unreadable sitemap"),
+            }
+        }
+
+        entries.truncate(MAX_SITEMAP_URLS);
+        let entries = prioritize(entries);
+        if !entries.is_empty() {
+            info!(%origin, sitemaps = fetched.len(), urls = entries.len(), "sitemap: discovered URLs");
+        }
+        entries
+    }
+
+    /// `Sitemap:` lines from robots.txt.
+    async fn robots_sitemaps(&self, origin: &str) -> Vec<String> {
+        let Ok(resp) = self
+            .client
+            .get(format!("{origin}/robots.txt"))
+            .header("User-Agent", "rootsignal-archive/0.1")
+            .send()
+            .await
+        else {
+            return Vec::new();
+        };
+        if !resp.status().is_success() {
+            return Vec::new();
+        }
+        resp.text().await.map(|body| robots_sitemap_lines(&body)).unwrap_or_default()
+    }
+
+    async fn fetch(&self, sitemap_url: &str) -> Result<Sitemap> {
+        let resp = self
+            .client
+            .get(sitemap_url)
+            .header("User-Agent", "rootsignal-archive/0.1")
+            .send()
+            .await
+            .context("Sitemap fetch failed")?
+            .error_for_status()
+            .context("Sitemap fetch failed")?;
+        let bytes = resp.bytes().await.context("Failed to read sitemap body")?;
+        parse_sitemap(&bytes)
+    }
+}
+
+fn robots_sitemap_lines(robots: &str) -> Vec<String> {
+    robots
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case("sitemap").then(|| value.trim().to_string())
+        })
+        .filter(|url| url.starts_with("http"))
+        .collect()
+}
+
+/// Parse a `<urlset>` or `<sitemapindex>` document.
+pub(crate) fn parse_sitemap(xml: &[u8]) -> Result<Sitemap> {
+    let mut reader = Reader::from_reader(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut is_index = None;
+    let mut entries = Vec::new();
+    let mut current: Option<SitemapEntry> = None;
+    let mut field = String::new();
+    loop {
+        match reader.read_event_into(&mut buf).context("Malformed sitemap XML")? {
+            Event::Start(e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_ascii_lowercase();
+                match name.as_str() {
+                    "urlset" => is_index = Some(false),
+                    "sitemapindex" => is_index = Some(true),
+                    "url" | "sitemap" => {
+                        current = Some(SitemapEntry { url: String::new(), lastmod: None, changefreq: None })
+                    }
+                    _ => {}
+                }
+                field = name;
+            }
+            Event::Text(t) => {
+                let text = t.unescape().context("Malformed sitemap text")?;
+                set_field(current.as_mut(), &field, &text);
+            }
+            Event::CData(c) => {
+                let text = String::from_utf8_lossy(&c);
+                set_field(current.as_mut(), &field, &text);
+            }
+            Event::End(e) => {
+                let name = e.local_name();
+                if matches!(name.as_ref(), b"url" | b"sitemap") {
+                    if let Some(entry) = current.take().filter(|e| e.url.starts_with("http")) {
+                        entries.push(entry);
+                    }
+                }
+                field.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    match is_index {
+        Some(true) => Ok(Sitemap::Index(entries)),
+        Some(false) => Ok(Sitemap::Urls(entries)),
+        None => anyhow::bail!("Not a sitemap: no <urlset> or <sitemapindex>"),
+    }
+}
+
+fn set_field(entry: Option<&mut SitemapEntry>, field: &str, text: &str) {
+    let Some(entry) = entry else {
+        return;
+    };
+    match field {
+        "loc" => entry.url = text.trim().to_string(),
+        "lastmod" => entry.lastmod = parse_lastmod(text),
+        "changefreq" => entry.changefreq = ChangeFreq::parse(text),
+        _ => {}
+    }
+}
+
+/// W3C datetime: a full timestamp or a bare date.
+fn parse_lastmod(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc()))
+}
+
+fn sort_by_lastmod(entries: &mut [SitemapEntry]) {
+    // Newest first; undated entries last, in sitemap order.
+    entries.sort_by_key(|e| std::cmp::Reverse(e.lastmod));
+}
+
+/// Dedup by URL and order most recently modified first.
+pub(crate) fn prioritize(mut entries: Vec<SitemapEntry>) -> Vec<SitemapEntry> {
+    let mut seen = HashSet::new();
+    entries.retain(|e| seen.insert(e.url.clone()));
+    sort_by_lastmod(&mut entries);
+    entries
+}
+
+/// Recrawl cadence the site's changefreq hints suggest: the median hint over
+/// the entries that give one. `None` without hints or when pages never change.
+pub(crate) fn recrawl_hours(entries: &[SitemapEntry]) -> Option<u32> {
+    let mut hours: Vec<Option<u32>> = entries.iter().filter_map(|e| e.changefreq).map(ChangeFreq::hours).collect();
+    if hours.is_empty() {
+        return None;
+    }
+    // `None` (never) sorts first; put it last so it reads as the longest interval.
+    hours.sort_by_key(|h| h.unwrap_or(u32::MAX));
+    hours[hours.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_urlset_with_hints() {
+        let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <url><loc>https://city.gov/news?id=1&amp;lang=en</loc><lastmod>2026-03-01</lastmod><changefreq>daily</changefreq></url>
+              <url><loc>https://city.gov/about</loc><lastmod>2025-01-01T10:00:00+02:00</lastmod></url>
+              <url><loc>not a url</loc></url>
+            </urlset>"#;
+        let Sitemap::Urls(entries) = parse_sitemap(xml).unwrap() else {
+            panic!("expected a urlset");
+        };
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].url, "https://city.gov/news?id=1&lang=en");
+        assert_eq!(entries[0].changefreq, Some(ChangeFreq::Daily));
+        assert_eq!(entries[0].lastmod.unwrap().to_rfc3339(), "2026-03-01T00:00:00+00:00");
+        assert_eq!(entries[1].lastmod.unwrap().to_rfc3339(), "2025-01-01T08:00:00+00:00");
+        assert_eq!(entries[1].changefreq, None);
+    }
+
+    #[test]
+    fn parses_sitemap_index() {
+        let xml = br#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+              <sitemap><loc><![CDATA[https://org.org/sitemap-posts.xml]]></loc></sitemap>
+            </sitemapindex>"#;
+        let Sitemap::Index(children) = parse_sitemap(xml).unwrap() else {
+            panic!("expected an index");
+        };
+        assert_eq!(children[0].url, "https://org.org/sitemap-posts.xml");
+        assert!(parse_sitemap(b"<html><body>Not found</body></html>").is_err());
+    }
+
+    #[test]
+    fn robots_sitemap_lines_are_found() {
+        let robots = "User-agent: *\nDisallow: /admin\nSitemap: https://org.org/sitemap_index.xml\nsitemap:https://org.org/extra.xml\n";
+        assert_eq!(
+            robots_sitemap_lines(robots),
+            vec!["https://org.org/sitemap_index.xml", "https://org.org/extra.xml"]
+        );
+    }
+
+    #[test]
+    fn newest_first_and_median_cadence() {
+        let entry = |url: &str, lastmod: Option<&str>, changefreq: Option<ChangeFreq>| SitemapEntry {
+            url: url.to_string(),
+            lastmod: lastmod.and_then(parse_lastmod),
+            changefreq,
+        };
+        let entries = prioritize(vec![
+            entry("https://a.org/old", Some("2024-01-01"), Some(ChangeFreq::Yearly)),
+            entry("https://a.org/undated", None, Some(ChangeFreq::Weekly)),
+            entry("https://a.org/new", Some("2026-01-01"), Some(ChangeFreq::Daily)),
+            entry("https://a.org/new", Some("2026-01-01"), Some(ChangeFreq::Daily)),
+        ]);
+        let urls: Vec<&str> = entries.iter().map(|e| e.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://a.org/new", "https://a.org/old", "https://a.org/undated"]);
+        assert_eq!(recrawl_hours(&entries), Some(24 * 7));
+        assert_eq!(recrawl_hours(&[entry("https://a.org/x", None, Some(ChangeFreq::Never))]), None);
+        assert_eq!(recrawl_hours(&[]), None);
+    }
+}
@@ -7,6 +7,8 @@ use chrono::{DateTime, Utc};
 use tracing::info;
 use uuid::Uuid;
 
+use rootsignal_common::types::PostComment;
+
 use crate::store::{InsertFile, InsertPost, InsertShortVideo};
 use crate::text_extract;
 
@@ -29,6 +31,26 @@ impl TikTokService {
         Self { client }
     }
 
+    /// Fetch top-level comments on one video.
+    pub(crate) async fn fetch_comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        info!(post_url, limit, "tiktok: fetching comments");
+        let raw = self.client.scrape_tiktok_comments(post_url, limit).await?;
+        Ok(raw
+            .into_iter()
+            .filter(|c| c.replies_to_id.is_none())
+            .filter_map(|c| {
+                Some(PostComment {
+                    text: c.text.filter(|t| !t.trim().is_empty())?,
+                    author: c.unique_id,
+                    published_at: c.create_time_iso.as_deref()
+                        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                        .map(|dt| dt.with_timezone(&Utc)),
+                })
+            })
+            .take(limit as usize)
+            .collect())
+    }
+
     /// Fetch posts from a TikTok profile. Filters to text-heavy posts (>= 20 chars).
     pub(crate) async fn fetch_posts(
         &self,
@@ -0,0 +1,172 @@
+// Speech-to-text backends for audio and video recordings.
+// A Transcriber turns audio bytes into timed transcript segments. The hosted
+// Whisper API has an upload cap; a local Whisper install does not, but is
+// much slower. Callers pick one via `TranscriptionBackend` in ArchiveConfig.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rootsignal_common::TranscriptSegment;
+use serde::Deserialize;
+
+/// The hosted Whisper API rejects uploads above 25MB.
+const WHISPER_API_MAX_BYTES: usize = 25 * 1024 * 1024;
+/// A local model runs at roughly real time on CPU; allow for long meetings.
+const LOCAL_WHISPER_TIMEOUT: Duration = Duration::from_secs(3 * 60 * 60);
+
+/// Which speech-to-text backend to use for recordings without captions.
+pub enum TranscriptionBackend {
+    /// No transcription: only published captions are used.
+    None,
+    /// OpenAI's hosted Whisper API.
+    WhisperApi { api_key: String },
+    /// A local `openai-whisper` install (the `whisper` CLI).
+    LocalWhisper { bin: String, model: String },
+}
+
+impl TranscriptionBackend {
+    pub(crate) fn build(self) -> Option<Arc<dyn Transcriber>> {
+        match self {
+            Self::None => None,
+            Self::WhisperApi { api_key } if api_key.is_empty() => None,
+            Self::WhisperApi { api_key } => Some(Arc::new(WhisperApiTranscriber::new(&api_key))),
+            Self::LocalWhisper { bin, model } => Some(Arc::new(LocalWhisperTranscriber { bin, model })),
+        }
+    }
+}
+
+/// Turns recorded speech into timed transcript segments.
+#[async_trait]
+pub(crate) trait Transcriber: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Largest recording this backend accepts, if it has a limit.
+    fn max_bytes(&self) -> Option<usize>;
+
+    async fn transcribe(&self, bytes: Vec<u8>, mime_type: &str) -> Result<Vec<TranscriptSegment>>;
+}
+
+pub(crate) struct WhisperApiTranscriber {
+    openai: ai_client::OpenAi,
+}
+
+impl WhisperApiTranscriber {
+    pub(crate) fn new(api_key: &str) -> Self {
+        Self {
+            openai: ai_client::OpenAi::new(api_key, "whisper-1"),
+        }
+    }
+}
+
+#[async_trait]
+impl Transcriber for WhisperApiTranscriber {
+    fn name(&self) -> &'static str {
+        "whisper-api"
+    }
+
+    fn max_bytes(&self) -> Option<usize> {
+        Some(WHISPER_API_MAX_BYTES)
+    }
+
+    async fn transcribe(&self, bytes: Vec<u8>, mime_type: &str) -> Result<Vec<TranscriptSegment>> {
+        let segments = self.openai.transcribe_segments(bytes, mime_type).await?;
+        Ok(clean_segments(segments.into_iter().map(|s| (s.start, s.text))))
+    }
+}
+
+/// Runs the `whisper` CLI on a temp file and reads its JSON output.
+pub(crate) struct LocalWhisperTranscriber {
+    bin: String,
+    model: String,
+}
+
+/// The subset of the `whisper --output_format json` file we use.
+#[derive(Deserialize)]
+struct LocalWhisperOutput {
+    #[serde(default)]
+    segments: Vec<LocalWhisperSegment>,
+}
+
+#[derive(Deserialize)]
+struct LocalWhisperSegment {
+    start: f64,
+    text: String,
+}
+
+#[async_trait]
+impl Transcriber for LocalWhisperTranscriber {
+    fn name(&self) -> &'static str {
+        "whisper-local"
+    }
+
+    fn max_bytes(&self) -> Option<usize> {
+        None
+    }
+
+    async fn transcribe(&self, bytes: Vec<u8>, mime_type: &str) -> Result<Vec<TranscriptSegment>> {
+        let tmp_dir = tempfile::tempdir().context("Failed to create temp dir")?;
+        let input = tmp_dir.path().join(format!("audio.{}", audio_extension(mime_type)));
+        tokio::fs::write(&input, &bytes).await?;
+
+        let output = tokio::time::timeout(
+            LOCAL_WHISPER_TIMEOUT,
+            tokio::process::Command::new(&self.bin)
+                .arg(&input)
+                .args(["--model", &self.model, "--output_format", "json", "--output_dir"])
+                .arg(tmp_dir.path())
+                .output(),
+        )
+        .await
+        .context("Local Whisper timed out")?
+        .with_context(|| format!("Failed to run {}", self.bin))?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Local Whisper failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        let json = tokio::fs::read(tmp_dir.path().join("audio.json"))
+            .await
+            .context("Local Whisper produced no JSON output")?;
+        let parsed: LocalWhisperOutput = serde_json::from_slice(&json)?;
+        Ok(clean_segments(parsed.segments.into_iter().map(|s| (s.start, s.text))))
+    }
+}
+
+/// Trim segment text and drop silent segments.
+fn clean_segments(segments: impl Iterator<Item = (f64, String)>) -> Vec<TranscriptSegment> {
+    segments
+        .map(|(start_secs, text)| TranscriptSegment {
+            start_secs,
+            text: text.trim().to_string(),
+        })
+        .filter(|s| !s.text.is_empty())
+        .collect()
+}
+
+/// File extension for an audio mime type, as Whisper sniffs by extension.
+pub(crate) fn audio_extension(mime_type: &str) -> &'static str {
+    match mime_type.split(';').next().unwrap_or_default().trim() {
+        "audio/mpeg" | "audio/mp3" => "mp3",
+        "audio/webm" => "webm",
+        "audio/ogg" | "audio/opus" => "ogg",
+        "audio/wav" | "audio/x-wav" => "wav",
+        "audio/flac" => "flac",
+        _ => "m4a",
+    }
+}
+
+/// Audio mime type for a file extension (the inverse of `audio_extension`).
+pub(crate) fn audio_mime_type(extension: &str) -> &'static str {
+    match extension {
+        "mp3" => "audio/mpeg",
+        "webm" => "audio/webm",
+        "ogg" | "opus" => "audio/ogg",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        _ => "audio/mp4",
+    }
+}
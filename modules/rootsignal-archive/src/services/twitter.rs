@@ -7,6 +7,8 @@ use chrono::{DateTime, Utc};
 use tracing::info;
 use uuid::Uuid;
 
+use rootsignal_common::types::PostComment;
+
 use crate::store::InsertPost;
 use crate::text_extract;
 
@@ -17,6 +19,13 @@ fn parse_twitter_date(s: &str) -> Option<DateTime<Utc>> {
         .map(|dt| dt.with_timezone(&Utc))
 }
 
+/// The status id at the end of a tweet permalink (`.../status/<id>`).
+fn tweet_id(url: &str) -> Option<&str> {
+    let rest = url.split("/status/").nth(1)?;
+    let id = rest.split(['/', '?']).next()?;
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then_some(id)
+}
+
 /// Raw fetched post before persistence.
 pub(crate) struct FetchedPost {
     pub post: InsertPost,
@@ -31,6 +40,28 @@ impl TwitterService {
         Self { client }
     }
 
+    /// Fetch direct replies to one tweet, found by searching its conversation.
+    pub(crate) async fn fetch_comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        let Some(tweet_id) = tweet_id(post_url) else {
+            return Ok(Vec::new());
+        };
+        info!(post_url, limit, "twitter: fetching replies");
+        let query = format!("conversation_id:{tweet_id}");
+        let raw = self.client.search_x_keywords(&[query.as_str()], limit + 1).await?;
+        Ok(raw
+            .into_iter()
+            .filter(|t| t.in_reply_to_id.as_deref() == Some(tweet_id))
+            .filter_map(|t| {
+                Some(PostComment {
+                    text: t.content().filter(|s| !s.trim().is_empty())?.to_string(),
+                    author: t.author.as_ref().and_then(|a| a.user_name.clone()),
+                    published_at: t.created_at.as_deref().and_then(parse_twitter_date),
+                })
+            })
+            .take(limit as usize)
+            .collect())
+    }
+
     /// Fetch posts (tweets) from a Twitter/X profile.
     pub(crate) async fn fetch_posts(
         &self,
@@ -133,3 +164,15 @@ impl TwitterService {
         Ok(posts)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tweet_id_is_read_from_status_permalinks() {
+        assert_eq!(tweet_id("https://x.com/mplsparks/status/1789?s=20"), Some("1789"));
+        assert_eq!(tweet_id("https://twitter.com/mplsparks/status/1789/photo/1"), Some("1789"));
+        assert_eq!(tweet_id("https://x.com/mplsparks"), None);
+    }
+}
@@ -0,0 +1,456 @@
+// YouTube service: channel video listing + timed transcripts.
+// Videos are listed from the channel's public Atom feed. Transcripts come from
+// a chain of TranscriptSource implementations, tried in order: published
+// captions first, then (when configured) speech-to-text on the audio track.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use rootsignal_common::TranscriptSegment;
+use tracing::{info, warn};
+
+use super::transcription::{audio_mime_type, Transcriber};
+
+const WATCH_URL: &str = "https://www.youtube.com/watch?v=";
+const FEED_URL: &str = "https://www.youtube.com/feeds/videos.xml?channel_id=";
+const USER_AGENT: &str = "Mozilla/5.0 (compatible; rootsignal-archive/0.1)";
+const AUDIO_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// A video on a channel, before its transcript is fetched.
+#[derive(Debug, Clone)]
+pub(crate) struct VideoListing {
+    pub video_id: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl VideoListing {
+    pub fn watch_url(&self) -> String {
+        format!("{WATCH_URL}{}", self.video_id)
+    }
+}
+
+/// Somewhere a timed transcript for a video can come from.
+#[async_trait]
+pub(crate) trait TranscriptSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// `Ok(None)` when this source has no transcript for the video.
+    async fn transcript(&self, video_id: &str) -> Result<Option<Vec<TranscriptSegment>>>;
+}
+
+/// Captions published on the video (uploaded or auto-generated).
+pub(crate) struct YouTubeCaptions {
+    client: reqwest::Client,
+}
+
+impl YouTubeCaptions {
+    pub(crate) fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TranscriptSource for YouTubeCaptions {
+    fn name(&self) -> &'static str {
+        "captions"
+    }
+
+    async fn transcript(&self, video_id: &str) -> Result<Option<Vec<TranscriptSegment>>> {
+        let watch_html = get_text(&self.client, &format!("{WATCH_URL}{video_id}")).await?;
+        let Some(track_url) = caption_track_url(&watch_html) else {
+            return Ok(None);
+        };
+        let xml = get_text(&self.client, &track_url).await?;
+        let segments = parse_timedtext(&xml);
+        Ok((!segments.is_empty()).then_some(segments))
+    }
+}
+
+/// Speech-to-text fallback: downloads the audio with `yt-dlp` and hands it
+/// to the configured transcriber. Recordings over its size limit are skipped.
+pub(crate) struct SpeechToText {
+    transcriber: Arc<dyn Transcriber>,
+}
+
+impl SpeechToText {
+    pub(crate) fn new(transcriber: Arc<dyn Transcriber>) -> Self {
+        Self { transcriber }
+    }
+}
+
+#[async_trait]
+impl TranscriptSource for SpeechToText {
+    fn name(&self) -> &'static str {
+        self.transcriber.name()
+    }
+
+    async fn transcript(&self, video_id: &str) -> Result<Option<Vec<TranscriptSegment>>> {
+        let tmp_dir = tempfile::tempdir().context("Failed to create temp dir")?;
+        let template = tmp_dir.path().join("audio.%(ext)s");
+        let yt_dlp = std::env::var("YT_DLP_BIN").unwrap_or_else(|_| "yt-dlp".to_string());
+
+        let output = tokio::time::timeout(
+            AUDIO_DOWNLOAD_TIMEOUT,
+            tokio::process::Command::new(&yt_dlp)
+                .args([
+                    "--no-playlist",
+                    "--quiet",
+                    "-f",
+                    "bestaudio[ext=m4a]/bestaudio",
+                    "-o",
+                ])
+                .arg(&template)
+                .arg(format!("{WATCH_URL}{video_id}"))
+                .output(),
+        )
+        .await
+        .context("yt-dlp timed out")?
+        .context("Failed to run yt-dlp")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "yt-dlp failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        let Some(path) = std::fs::read_dir(tmp_dir.path())?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .find(|p| p.file_stem().is_some_and(|s| s == "audio"))
+        else {
+            return Ok(None);
+        };
+        let bytes = tokio::fs::read(&path).await?;
+        if let Some(max) = self.transcriber.max_bytes().filter(|max| bytes.len() > *max) {
+            warn!(
+                video_id,
+                bytes = bytes.len(),
+                max,
+                "Audio exceeds transcriber upload limit, skipping"
+            );
+            return Ok(None);
+        }
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+
+        let segments = self
+            .transcriber
+            .transcribe(bytes, audio_mime_type(extension))
+            .await?;
+        Ok((!segments.is_empty()).then_some(segments))
+    }
+}
+
+pub(crate) struct YouTubeService {
+    client: reqwest::Client,
+    transcripts: Vec<Arc<dyn TranscriptSource>>,
+}
+
+impl YouTubeService {
+    /// `transcriber` enables the speech-to-text fallback for videos without
+    /// captions.
+    pub(crate) fn new(transcriber: Option<Arc<dyn Transcriber>>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("Failed to build YouTube HTTP client");
+        let mut transcripts: Vec<Arc<dyn TranscriptSource>> =
+            vec![Arc::new(YouTubeCaptions::new(client.clone()))];
+        if let Some(transcriber) = transcriber {
+            transcripts.push(Arc::new(SpeechToText::new(transcriber)));
+        }
+        Self {
+            client,
+            transcripts,
+        }
+    }
+
+    /// Recent videos for a channel URL, or the single video for a watch URL.
+    pub(crate) async fn list_videos(&self, url: &str, limit: u32) -> Result<Vec<VideoListing>> {
+        let url = if url.starts_with("http") {
+            url.to_string()
+        } else {
+            format!("https://{url}")
+        };
+
+        if let Some(video_id) = video_id_from_url(&url) {
+            return Ok(vec![VideoListing {
+                video_id,
+                title: None,
+                description: None,
+                published_at: None,
+            }]);
+        }
+
+        let channel_id = match channel_id_from_url(&url) {
+            Some(id) => id,
+            None => {
+                let html = get_text(&self.client, &url).await?;
+                channel_id_from_html(&html)
+                    .with_context(|| format!("No YouTube channel ID found at {url}"))?
+            }
+        };
+
+        let bytes = self
+            .client
+            .get(format!("{FEED_URL}{channel_id}"))
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let feed =
+            feed_rs::parser::parse(&bytes[..]).context("Failed to parse YouTube channel feed")?;
+
+        let videos: Vec<VideoListing> = feed
+            .entries
+            .into_iter()
+            .filter_map(|entry| {
+                let video_id = entry.id.strip_prefix("yt:video:")?.to_string();
+                Some(VideoListing {
+                    video_id,
+                    title: entry.title.map(|t| t.content),
+                    description: entry
+                        .media
+                        .into_iter()
+                        .find_map(|m| m.description.map(|d| d.content)),
+                    published_at: entry.published,
+                })
+            })
+            .take(limit as usize)
+            .collect();
+        info!(
+            channel_id,
+            videos = videos.len(),
+            "youtube: listed channel videos"
+        );
+        Ok(videos)
+    }
+
+    /// First transcript any source can produce. Source failures are logged
+    /// and fall through to the next source.
+    pub(crate) async fn transcript(&self, video_id: &str) -> Option<Vec<TranscriptSegment>> {
+        for source in &self.transcripts {
+            match source.transcript(video_id).await {
+                Ok(Some(segments)) => {
+                    info!(
+                        video_id,
+                        source = source.name(),
+                        segments = segments.len(),
+                        "youtube: transcript fetched"
+                    );
+                    return Some(segments);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(video_id, source = source.name(), error = %e, "youtube: transcript source failed")
+                }
+            }
+        }
+        None
+    }
+}
+
+async fn get_text(client: &reqwest::Client, url: &str) -> Result<String> {
+    Ok(client
+        .get(url)
+        .header("Accept-Language", "en-US,en;q=0.9")
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?)
+}
+
+/// Video ID from a watch, short, live or youtu.be URL.
+pub(crate) fn video_id_from_url(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url).ok()?;
+    let host = parsed
+        .host_str()?
+        .trim_start_matches("www.")
+        .trim_start_matches("m.");
+    let id = if host == "youtu.be" {
+        parsed.path_segments()?.next().map(String::from)
+    } else if parsed.path() == "/watch" {
+        parsed
+            .query_pairs()
+            .find(|(k, _)| k == "v")
+            .map(|(_, v)| v.into_owned())
+    } else {
+        let mut segments = parsed.path_segments()?;
+        match segments.next() {
+            Some("shorts") | Some("live") => segments.next().map(String::from),
+            _ => None,
+        }
+    };
+    id.filter(|id| !id.is_empty())
+}
+
+fn channel_id_from_url(url: &str) -> Option<String> {
+    let idx = url.find("/channel/")?;
+    url[idx + "/channel/".len()..]
+        .split(['/', '?'])
+        .next()
+        .filter(|id| id.starts_with("UC"))
+        .map(String::from)
+}
+
+fn channel_id_from_html(html: &str) -> Option<String> {
+    let re = Regex::new(r#""(?:externalId|channelId)":"(UC[\w-]{22})""#).ok()?;
+    re.captures(html).map(|c| c[1].to_string())
+}
+
+/// Timedtext URL of the best caption track: manual English, then any
+/// English (auto-generated), then whatever is first.
+fn caption_track_url(watch_html: &str) -> Option<String> {
+    let start = watch_html.find("\"captionTracks\":")? + "\"captionTracks\":".len();
+    let rest = &watch_html[start..];
+    let end = matching_bracket(rest)?;
+    let tracks: Vec<serde_json::Value> = serde_json::from_str(&rest[..=end]).ok()?;
+
+    let is_en = |t: &&serde_json::Value| {
+        t["languageCode"]
+            .as_str()
+            .is_some_and(|l| l.starts_with("en"))
+    };
+    let is_asr = |t: &&serde_json::Value| t["kind"].as_str() == Some("asr");
+    tracks
+        .iter()
+        .find(|t| is_en(t) && !is_asr(t))
+        .or_else(|| tracks.iter().find(is_en))
+        .or_else(|| tracks.first())
+        .and_then(|t| t["baseUrl"].as_str())
+        .map(String::from)
+}
+
+/// Index of the `]` closing the JSON array `s` starts with.
+fn matching_bracket(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' => depth += 1,
+            ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse YouTube timedtext XML (`<text start=".." dur="..">..</text>`).
+fn parse_timedtext(xml: &str) -> Vec<TranscriptSegment> {
+    let Ok(re) = Regex::new(r#"(?s)<text start="([\d.]+)"[^>]*>(.*?)</text>"#) else {
+        return Vec::new();
+    };
+    re.captures_iter(xml)
+        .filter_map(|c| {
+            let start_secs = c[1].parse().ok()?;
+            let text = decode_entities(&c[2]).replace('\n', " ").trim().to_string();
+            (!text.is_empty()).then_some(TranscriptSegment { start_secs, text })
+        })
+        .collect()
+}
+
+/// Caption text is entity-encoded, sometimes twice (`&amp;#39;`).
+fn decode_entities(s: &str) -> String {
+    let once = s.replace("&amp;", "&");
+    once.replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_ids_are_found_in_every_url_shape() {
+        assert_eq!(
+            video_id_from_url("https://www.youtube.com/watch?v=abc123&t=60s").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            video_id_from_url("https://youtu.be/abc123").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            video_id_from_url("https://www.youtube.com/live/abc123").as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            video_id_from_url("https://www.youtube.com/@MplsCouncil"),
+            None
+        );
+    }
+
+    #[test]
+    fn channel_id_comes_from_channel_url_or_page_metadata() {
+        let id = "UCabcdefghijklmnopqrstuv";
+
+        assert_eq!(
+            channel_id_from_url(&format!("https://www.youtube.com/channel/{id}/videos")).as_deref(),
+            Some(id)
+        );
+        assert_eq!(
+            channel_id_from_html(&format!(r#"{{"metadata":{{"externalId":"{id}"}}}}"#)).as_deref(),
+            Some(id)
+        );
+    }
+
+    #[test]
+    fn caption_track_prefers_manual_english_over_auto_generated() {
+        let html = r#"var x = {"captionTracks":[
+            {"baseUrl":"https://yt/asr","languageCode":"en","kind":"asr","name":{"simpleText":"English [auto]"}},
+            {"baseUrl":"https://yt/es","languageCode":"es"},
+            {"baseUrl":"https://yt/manual&fmt=srv1","languageCode":"en-US"}
+        ],"audioTracks":[]};"#;
+
+        assert_eq!(
+            caption_track_url(html).as_deref(),
+            Some("https://yt/manual&fmt=srv1")
+        );
+    }
+
+    #[test]
+    fn timedtext_segments_keep_start_times_and_decode_entities() {
+        let xml = r#"<?xml version="1.0"?><transcript>
+            <text start="12.5" dur="3.1">Public comment on the &amp;#39;Lake St&amp;#39;
+            rezoning</text>
+            <text start="15.6" dur="2">   </text>
+            <text start="754" dur="4">Motion carries</text>
+        </transcript>"#;
+
+        let segments = parse_timedtext(xml);
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].start_secs, 12.5);
+        assert!(segments[0]
+            .text
+            .starts_with("Public comment on the 'Lake St'"));
+        assert_eq!(segments[1].start_secs, 754.0);
+    }
+}
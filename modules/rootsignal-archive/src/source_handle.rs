@@ -3,34 +3,41 @@
 // methods on it. Each method returns a request builder that implements
 // IntoFuture for ergonomic .await.
 
-use std::collections::{HashSet, VecDeque};
 use std::future::{Future, IntoFuture};
 use std::pin::Pin;
 use std::sync::Arc;
 
 use chrono::Utc;
 use rootsignal_common::types::{
-    ArchivedFeed, ArchivedPage, ArchivedSearchResults, Channels, FeedItem, LongVideo, Post,
-    SearchResult, ShortVideo, Source, Story,
+    ArchivedFeed, ArchivedPage, ArchivedSearchResults, Channels, FeedItem, ListedEvent, LongVideo,
+    PodcastEpisode, Post, SearchResult, ShortVideo, Source, Story,
 };
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::crawl::{ensure_scheme, CrawlOutcome, CrawlRules, CrawlScope, Frontier};
+use crate::engagement;
 use crate::enrichment::{files_needing_enrichment, EnrichmentJob, WorkflowDispatcher};
 use crate::fetch_request::FetchRequest;
 use crate::error::{ArchiveError, Result};
+use crate::politeness::DomainRateLimiter;
 use crate::router::Platform;
 use crate::store::Store;
 
 use crate::services::bluesky::BlueskyService;
+use crate::services::eventbrite::EventbriteService;
 use crate::services::facebook::FacebookService;
 use crate::services::feed::FeedService;
+use crate::services::sitemap::{self, SitemapService};
 use crate::services::instagram::InstagramService;
-use crate::services::page::{BrowserlessPageService, ChromePageService};
+use crate::credentials::{CredentialRejected, CredentialStore, SourceCredential};
+use crate::services::podcast::PodcastService;
+use crate::services::page::{AuthenticatedPageService, BrowserlessPageService, ChromePageService, FetchedPage};
 use crate::services::reddit::RedditService;
 use crate::services::search::SearchService;
 use crate::services::tiktok::TikTokService;
 use crate::services::twitter::TwitterService;
+use crate::services::youtube::YouTubeService;
 
 /// Internal shared state for the archive. Holds services + store.
 pub(crate) struct ArchiveInner {
@@ -39,13 +46,74 @@ pub(crate) struct ArchiveInner {
     pub twitter: Option<TwitterService>,
     pub reddit: Option<RedditService>,
     pub facebook: Option<FacebookService>,
+    pub eventbrite: Option<EventbriteService>,
     pub tiktok: Option<TikTokService>,
     pub bluesky: Option<BlueskyService>,
+    pub youtube: YouTubeService,
+    pub podcast: PodcastService,
     pub chrome_page: Option<ChromePageService>,
     pub browserless_page: Option<BrowserlessPageService>,
     pub feed: FeedService,
+    pub sitemap: SitemapService,
     pub search: Option<SearchService>,
     pub dispatcher: Option<Arc<dyn WorkflowDispatcher>>,
+    pub credentials: Option<CredentialStore>,
+    pub authenticated_page: AuthenticatedPageService,
+    pub politeness: DomainRateLimiter,
+}
+
+impl ArchiveInner {
+    /// Credential for a source, if one is stored. Lookup failures (e.g. a
+    /// ciphertext from a previous key) are logged and treated as no credential.
+    async fn credential_for(&self, url: &str) -> Option<SourceCredential> {
+        let store = self.credentials.as_ref()?;
+        match store.find(url).await {
+            Ok(credential) => credential,
+            Err(e) => {
+                warn!(url, error = %e, "Failed to load source credential, fetching without it");
+                None
+            }
+        }
+    }
+
+    /// Record how a credentialed fetch went, so rejected credentials surface
+    /// in admin reads. Rejections become `ArchiveError::AuthFailed`.
+    async fn track_credential<T>(&self, url: &str, result: anyhow::Result<T>) -> Result<T> {
+        let Some(store) = self.credentials.as_ref() else {
+            return result.map_err(ArchiveError::Other);
+        };
+        match result {
+            Ok(v) => {
+                store.record_success(url).await;
+                Ok(v)
+            }
+            Err(e) => match e.downcast_ref::<CredentialRejected>() {
+                Some(rejected) => {
+                    store.record_auth_failure(url, &rejected.to_string()).await;
+                    Err(ArchiveError::AuthFailed(format!("{url}: {rejected}")))
+                }
+                None => Err(ArchiveError::Other(e)),
+            },
+        }
+    }
+
+    /// Fetch a page: directly with the source's credential when it has one,
+    /// otherwise through the configured browser backend.
+    async fn fetch_page_content(&self, url: &str, source_id: Uuid) -> Result<FetchedPage> {
+        self.politeness.wait(url).await;
+        if let Some(credential) = self.credential_for(url).await {
+            let result = self.authenticated_page.fetch(url, source_id, &credential).await;
+            return self.track_credential(url, result).await;
+        }
+
+        if let Some(ref svc) = self.browserless_page {
+            svc.fetch(url, source_id).await.map_err(ArchiveError::Other)
+        } else if let Some(ref svc) = self.chrome_page {
+            svc.fetch(url, source_id).await.map_err(ArchiveError::Other)
+        } else {
+            Err(ArchiveError::Unsupported("No page fetcher configured".into()))
+        }
+    }
 }
 
 /// A handle to a source. Returned by `Archive::source(url)`.
@@ -112,6 +180,24 @@ impl SourceHandle {
         }
     }
 
+    pub fn episodes(&self, limit: u32) -> EpisodesRequest {
+        EpisodesRequest {
+            inner: self.inner.clone(),
+            source: self.source.clone(),
+            platform: self.platform,
+            limit,
+        }
+    }
+
+    pub fn events(&self, limit: u32) -> EventsRequest {
+        EventsRequest {
+            inner: self.inner.clone(),
+            source: self.source.clone(),
+            platform: self.platform,
+            limit,
+        }
+    }
+
     pub fn page(&self) -> PageRequest {
         PageRequest {
             inner: self.inner.clone(),
@@ -141,8 +227,11 @@ impl SourceHandle {
             seed_url: self.source.url.clone(),
             max_depth: 2,
             limit: 20,
+            scope: CrawlScope::default(),
             include_patterns: Vec::new(),
             exclude_patterns: Vec::new(),
+            resume: true,
+            use_sitemap: true,
         }
     }
 
@@ -246,6 +335,19 @@ impl PostsRequest {
                     .map(|f| (f.post, Vec::new()))
                     .collect()
             }
+            Platform::Newsletter => {
+                // Issues were archived on delivery; read them back as-is.
+                return self.inner.store.get_posts(source_id, self.limit).await;
+            }
+            Platform::YouTube => {
+                return Err(ArchiveError::Unsupported("YouTube sources have videos, not posts".into()));
+            }
+            Platform::Podcast => {
+                return Err(ArchiveError::Unsupported("Podcast sources have episodes, not posts".into()));
+            }
+            Platform::Eventbrite => {
+                return Err(ArchiveError::Unsupported("Eventbrite sources have events, not posts".into()));
+            }
             Platform::Web => {
                 return Err(ArchiveError::Unsupported("Web sources don't have posts".into()));
             }
@@ -254,7 +356,15 @@ impl PostsRequest {
         // Persist and build result
         let mut posts = Vec::with_capacity(fetched.len());
         for (insert_post, insert_files) in fetched {
-            let post_id = self.inner.store.insert_post(&insert_post).await?;
+            let fetched_at = Utc::now();
+            let previous = self.inner.store.previous_engagement(&insert_post).await?;
+            let engagement_velocity = engagement::velocity(
+                insert_post.engagement.as_ref(),
+                fetched_at,
+                previous.as_ref(),
+                insert_post.published_at,
+            );
+            let post_id = self.inner.store.insert_post(&insert_post, engagement_velocity).await?;
 
             // Persist files and create attachments
             let mut attachments = Vec::new();
@@ -270,7 +380,7 @@ impl PostsRequest {
             posts.push(Post {
                 id: post_id,
                 source_id,
-                fetched_at: Utc::now(),
+                fetched_at,
                 content_hash: insert_post.content_hash,
                 text: insert_post.text,
                 author: insert_post.author,
@@ -282,6 +392,7 @@ impl PostsRequest {
                 hashtags: insert_post.hashtags,
                 media_type: insert_post.media_type,
                 platform_id: insert_post.platform_id,
+                engagement_velocity,
                 attachments,
             });
         }
@@ -466,10 +577,45 @@ pub struct VideoRequest {
 }
 
 impl VideoRequest {
+    /// Archive any new videos with their transcripts, then return the most
+    /// recent `limit` archived videos. Videos already archived are not
+    /// re-transcribed.
     pub async fn send(self) -> Result<Vec<LongVideo>> {
-        Err(ArchiveError::Unsupported(
-            format!("{:?} long video fetching not yet implemented", self.platform),
-        ))
+        let source_id = self.source.id;
+
+        if self.platform != Platform::YouTube {
+            return Err(ArchiveError::Unsupported(
+                format!("{:?} doesn't support long videos", self.platform),
+            ));
+        }
+
+        let svc = &self.inner.youtube;
+        let listings = svc
+            .list_videos(&self.source.url, self.limit)
+            .await
+            .map_err(ArchiveError::Other)?;
+
+        for listing in listings {
+            if self.inner.store.has_long_video(source_id, &listing.video_id).await? {
+                continue;
+            }
+            let transcript = svc.transcript(&listing.video_id).await.unwrap_or_default();
+            let video = crate::store::InsertLongVideo {
+                source_id,
+                content_hash: rootsignal_common::content_hash(&listing.video_id).to_string(),
+                text: listing.description.clone(),
+                engagement: None,
+                published_at: listing.published_at,
+                permalink: Some(listing.watch_url()),
+                title: listing.title,
+                platform_id: Some(listing.video_id),
+                transcript,
+            };
+            self.inner.store.insert_long_video(&video).await?;
+        }
+
+        self.inner.store.update_last_scraped(source_id, "long_videos").await?;
+        self.inner.store.get_long_videos(source_id, self.limit).await
     }
 }
 
@@ -482,6 +628,123 @@ impl IntoFuture for VideoRequest {
     }
 }
 
+pub struct EpisodesRequest {
+    inner: Arc<ArchiveInner>,
+    source: Source,
+    platform: Platform,
+    limit: u32,
+}
+
+impl EpisodesRequest {
+    /// Archive any new episodes with their transcripts, then return the most
+    /// recent `limit` archived episodes. Episodes are keyed by guid, so each
+    /// one is downloaded and transcribed at most once.
+    pub async fn send(self) -> Result<Vec<PodcastEpisode>> {
+        let source_id = self.source.id;
+
+        if self.platform != Platform::Podcast {
+            return Err(ArchiveError::Unsupported(
+                format!("{:?} doesn't support podcast episodes", self.platform),
+            ));
+        }
+
+        let svc = &self.inner.podcast;
+        let listings = svc
+            .list_episodes(&self.source.url, self.limit)
+            .await
+            .map_err(ArchiveError::Other)?;
+
+        for listing in listings {
+            if self.inner.store.has_episode(source_id, &listing.guid).await? {
+                continue;
+            }
+            let transcript = svc.transcript(&listing).await.unwrap_or_default();
+            let episode = crate::store::InsertEpisode {
+                source_id,
+                content_hash: rootsignal_common::content_hash(&listing.guid).to_string(),
+                permalink: listing.link.unwrap_or_else(|| listing.audio_url.clone()),
+                guid: listing.guid,
+                title: listing.title,
+                description: listing.description,
+                published_at: listing.published_at,
+                audio_url: listing.audio_url,
+                duration_secs: listing.duration_secs,
+                transcript,
+            };
+            self.inner.store.insert_episode(&episode).await?;
+        }
+
+        self.inner.store.update_last_scraped(source_id, "podcast_episodes").await?;
+        self.inner.store.get_episodes(source_id, self.limit).await
+    }
+}
+
+impl IntoFuture for EpisodesRequest {
+    type Output = Result<Vec<PodcastEpisode>>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+pub struct EventsRequest {
+    inner: Arc<ArchiveInner>,
+    source: Source,
+    platform: Platform,
+    limit: u32,
+}
+
+impl EventsRequest {
+    /// Archive the organizer's currently listed events, then return the
+    /// `limit` soonest that haven't ended. Events already archived are
+    /// updated in place, so rescheduled or moved events stay current.
+    pub async fn send(self) -> Result<Vec<ListedEvent>> {
+        let source_id = self.source.id;
+        let listing_url = if self.source.url.starts_with("http") {
+            self.source.url.clone()
+        } else {
+            format!("https://{}", self.source.url)
+        };
+
+        let fetched = match self.platform {
+            Platform::Eventbrite => {
+                let svc = self.inner.eventbrite.as_ref()
+                    .ok_or_else(|| ArchiveError::Unsupported("Eventbrite service not configured".into()))?;
+                svc.fetch_events(&listing_url, source_id, self.limit)
+                    .await
+                    .map_err(ArchiveError::Other)?
+            }
+            Platform::Facebook => {
+                let svc = self.inner.facebook.as_ref()
+                    .ok_or_else(|| ArchiveError::Unsupported("Facebook service not configured".into()))?;
+                svc.fetch_events(&listing_url, source_id, self.limit)
+                    .await
+                    .map_err(ArchiveError::Other)?
+            }
+            other => {
+                return Err(ArchiveError::Unsupported(format!("{other:?} doesn't support events")));
+            }
+        };
+
+        for event in &fetched {
+            self.inner.store.upsert_event(event).await?;
+        }
+
+        self.inner.store.update_last_scraped(source_id, "events").await?;
+        self.inner.store.get_upcoming_events(source_id, self.limit).await
+    }
+}
+
+impl IntoFuture for EventsRequest {
+    type Output = Result<Vec<ListedEvent>>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
 pub struct PageRequest {
     inner: Arc<ArchiveInner>,
     source: Source,
@@ -496,17 +759,7 @@ impl PageRequest {
             return self.fetch_google_doc(source_id, &export_url).await;
         }
 
-        let fetched = if let Some(ref svc) = self.inner.browserless_page {
-            svc.fetch(&self.source.url, source_id)
-                .await
-                .map_err(ArchiveError::Other)?
-        } else if let Some(ref svc) = self.inner.chrome_page {
-            svc.fetch(&self.source.url, source_id)
-                .await
-                .map_err(ArchiveError::Other)?
-        } else {
-            return Err(ArchiveError::Unsupported("No page fetcher configured".into()));
-        };
+        let fetched = self.inner.fetch_page_content(&self.source.url, source_id).await?;
 
         let links = crate::links::extract_all_links(&fetched.raw_html, &self.source.url);
         let page = crate::store::InsertPage {
@@ -516,7 +769,8 @@ impl PageRequest {
             title: fetched.page.title,
             links: links.clone(),
         };
-        let page_id = self.inner.store.insert_page(&page).await?;
+        let previous_markdown = self.inner.store.previous_page_markdown(source_id, &page.markdown).await?;
+        let page_id = self.inner.store.insert_page(&page, &fetched.raw_html).await?;
         self.inner.store.update_last_scraped(source_id, "pages").await?;
 
         Ok(ArchivedPage {
@@ -528,6 +782,7 @@ impl PageRequest {
             markdown: page.markdown,
             title: page.title,
             links,
+            previous_markdown,
         })
     }
 }
@@ -564,7 +819,8 @@ impl PageRequest {
             title,
             links: links.clone(),
         };
-        let page_id = self.inner.store.insert_page(&page).await?;
+        let previous_markdown = self.inner.store.previous_page_markdown(source_id, &page.markdown).await?;
+        let page_id = self.inner.store.insert_page(&page, &html).await?;
         self.inner.store.update_last_scraped(source_id, "pages").await?;
 
         Ok(ArchivedPage {
@@ -576,6 +832,7 @@ impl PageRequest {
             markdown: page.markdown,
             title: page.title,
             links,
+            previous_markdown,
         })
     }
 }
@@ -598,10 +855,14 @@ impl FeedRequest {
     pub async fn send(self) -> Result<ArchivedFeed> {
         let source_id = self.source.id;
 
-        let fetched = self.inner.feed
-            .fetch(&self.source.url, source_id)
-            .await
-            .map_err(ArchiveError::Other)?;
+        let credential = self.inner.credential_for(&self.source.url).await;
+        let result = self.inner.feed
+            .fetch(&self.source.url, source_id, credential.as_ref())
+            .await;
+        let fetched = match credential {
+            Some(_) => self.inner.track_credential(&self.source.url, result).await?,
+            None => result.map_err(ArchiveError::Other)?,
+        };
 
         let feed_id = self.inner.store.insert_feed(&fetched.feed).await?;
         self.inner.store.update_last_scraped(source_id, "feeds").await?;
@@ -646,8 +907,9 @@ impl SearchRequest {
         let svc = self.inner.search.as_ref()
             .ok_or_else(|| ArchiveError::Unsupported("Search service not configured".into()))?;
 
+        let used_today = self.inner.store.search_queries_today().await?;
         let fetched = svc
-            .search(&self.query, source_id, self.max_results)
+            .search(&self.query, source_id, self.max_results, &used_today)
             .await
             .map_err(ArchiveError::Other)?;
 
@@ -663,6 +925,7 @@ impl SearchRequest {
             content_hash: fetched.results.content_hash,
             query: fetched.results.query,
             results,
+            provider: Some(fetched.results.provider),
         })
     }
 }
@@ -749,7 +1012,15 @@ impl TopicSearchRequest {
 
         let mut posts = Vec::with_capacity(fetched.len());
         for (insert_post, insert_files) in fetched {
-            let post_id = self.inner.store.insert_post(&insert_post).await?;
+            let fetched_at = Utc::now();
+            let previous = self.inner.store.previous_engagement(&insert_post).await?;
+            let engagement_velocity = engagement::velocity(
+                insert_post.engagement.as_ref(),
+                fetched_at,
+                previous.as_ref(),
+                insert_post.published_at,
+            );
+            let post_id = self.inner.store.insert_post(&insert_post, engagement_velocity).await?;
             let mut attachments = Vec::new();
             for insert_file in &insert_files {
                 let file = self.inner.store.upsert_file(insert_file).await?;
@@ -762,7 +1033,7 @@ impl TopicSearchRequest {
             posts.push(Post {
                 id: post_id,
                 source_id,
-                fetched_at: Utc::now(),
+                fetched_at,
                 content_hash: insert_post.content_hash,
                 text: insert_post.text,
                 author: insert_post.author,
@@ -774,6 +1045,7 @@ impl TopicSearchRequest {
                 hashtags: insert_post.hashtags,
                 media_type: insert_post.media_type,
                 platform_id: insert_post.platform_id,
+                engagement_velocity,
                 attachments,
             });
         }
@@ -801,8 +1073,11 @@ pub struct CrawlRequest {
     seed_url: String,
     max_depth: usize,
     limit: usize,
+    scope: CrawlScope,
     include_patterns: Vec<String>,
     exclude_patterns: Vec<String>,
+    resume: bool,
+    use_sitemap: bool,
 }
 
 impl CrawlRequest {
@@ -816,6 +1091,11 @@ impl CrawlRequest {
         self
     }
 
+    pub fn scope(mut self, scope: CrawlScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
     pub fn include(mut self, pattern: &str) -> Self {
         self.include_patterns.push(pattern.to_string());
         self
@@ -826,71 +1106,105 @@ impl CrawlRequest {
         self
     }
 
+    /// Follow links from the seed even when the site has a sitemap.
+    pub fn ignore_sitemap(mut self) -> Self {
+        self.use_sitemap = false;
+        self
+    }
+
+    /// Start over even if an unfinished crawl with the same rules exists.
+    pub fn fresh(mut self) -> Self {
+        self.resume = false;
+        self
+    }
+
     pub async fn send(self) -> Result<Vec<ArchivedPage>> {
+        Ok(self.send_with_stats().await?.pages)
+    }
+
+    /// Crawl the site's sitemap, most recently modified pages first, or, with
+    /// no sitemap, breadth-first from the seed. Checkpoints the frontier after
+    /// every page. Returns the pages fetched by this call, with stats for the
+    /// whole crawl including any earlier, interrupted runs it resumed.
+    pub async fn send_with_stats(self) -> Result<CrawlOutcome> {
         if self.limit == 0 {
-            return Ok(Vec::new());
+            return Ok(CrawlOutcome::default());
         }
 
-        let seed_full_url = ensure_scheme(&self.seed_url);
-        let seed_host = extract_host(&seed_full_url);
+        let rules = CrawlRules {
+            seed_url: ensure_scheme(&self.seed_url),
+            scope: self.scope.clone(),
+            max_depth: self.max_depth,
+            limit: self.limit,
+            include_patterns: self.include_patterns.clone(),
+            exclude_patterns: self.exclude_patterns.clone(),
+            use_sitemap: self.use_sitemap,
+        };
+        let store = &self.inner.store;
+        let saved = if self.resume { store.find_unfinished_crawl(&rules).await? } else { None };
+        let resumed = saved.is_some();
+        let (crawl_id, mut frontier) = match saved {
+            Some(saved) => saved,
+            None => {
+                let from_sitemap = if rules.use_sitemap { self.sitemap_frontier(&rules).await } else { None };
+                let frontier = from_sitemap.unwrap_or_else(|| Frontier::new(&rules.seed_url));
+                (store.start_crawl(&rules, &frontier).await?, frontier)
+            }
+        };
 
         info!(
-            url = %seed_full_url, max_depth = self.max_depth, limit = self.limit,
-            "crawl: starting BFS"
+            url = %rules.seed_url, max_depth = self.max_depth, limit = self.limit,
+            %crawl_id, resumed, sitemap = frontier.sitemap, "crawl: starting"
         );
 
-        let mut visited: HashSet<String> = HashSet::new();
-        let mut queue: VecDeque<(String, usize)> = VecDeque::new();
         let mut pages: Vec<ArchivedPage> = Vec::new();
-
-        queue.push_back((seed_full_url.clone(), 0));
-        visited.insert(normalize_crawl_url(&seed_full_url));
-
-        while let Some((url, depth)) = queue.pop_front() {
-            if pages.len() >= self.limit {
+        while (frontier.stats.pages_fetched as usize) < rules.limit {
+            let Some((url, depth)) = frontier.pop() else {
                 break;
-            }
+            };
 
             // Fetch page through archive's normal pipeline (browserless/Chrome)
-            let page = match self.fetch_page(&url).await {
-                Ok(p) => p,
+            match self.fetch_page(&url).await {
+                Ok(page) => {
+                    frontier.offer(&page.links, depth, &rules);
+                    frontier.stats.pages_fetched += 1;
+                    pages.push(page);
+                }
                 Err(e) => {
+                    frontier.stats.errors += 1;
                     // Seed failure is a hard error
-                    if pages.is_empty() && depth == 0 {
+                    if depth == 0 && !frontier.sitemap {
+                        store.checkpoint_crawl(crawl_id, &frontier, true).await?;
                         return Err(e);
                     }
                     warn!(url = %url, error = %e, "crawl: skipping failed page");
-                    continue;
-                }
-            };
-
-            // Extract child links if we haven't reached max depth
-            if depth < self.max_depth {
-                for link in &page.links {
-                    let normalized = normalize_crawl_url(link);
-                    if visited.contains(&normalized) {
-                        continue;
-                    }
-                    if !same_host(link, &seed_host) {
-                        continue;
-                    }
-                    if !matches_patterns(link, &self.include_patterns, &self.exclude_patterns) {
-                        continue;
-                    }
-                    visited.insert(normalized);
-                    queue.push_back((link.clone(), depth + 1));
                 }
             }
-
-            pages.push(page);
+            store.checkpoint_crawl(crawl_id, &frontier, false).await?;
         }
+        store.checkpoint_crawl(crawl_id, &frontier, true).await?;
 
+        let stats = frontier.stats;
         info!(
-            seed = %self.seed_url, pages_crawled = pages.len(),
-            urls_visited = visited.len(), "crawl: BFS complete"
+            seed = %self.seed_url, %crawl_id, pages_crawled = pages.len(),
+            pages_fetched = stats.pages_fetched, skipped_duplicate = stats.skipped_duplicate,
+            skipped_out_of_scope = stats.skipped_out_of_scope, skipped_depth = stats.skipped_depth,
+            errors = stats.errors, "crawl: complete"
         );
 
-        Ok(pages)
+        Ok(CrawlOutcome {
+            pages,
+            stats,
+            resumed,
+            from_sitemap: frontier.sitemap,
+            recrawl_hours: frontier.recrawl_hours,
+        })
+    }
+
+    /// A frontier from the site's sitemap, or `None` to fall back to links.
+    async fn sitemap_frontier(&self, rules: &CrawlRules) -> Option<Frontier> {
+        let entries = self.inner.sitemap.discover(&rules.seed_url, &self.inner.politeness).await;
+        Frontier::from_sitemap(&entries, rules, sitemap::recrawl_hours(&entries))
     }
 
     /// Fetch a single page through the archive page pipeline.
@@ -899,17 +1213,7 @@ impl CrawlRequest {
         let source = self.inner.store.upsert_source(&normalized).await?;
         let source_id = source.id;
 
-        let fetched = if let Some(ref svc) = self.inner.browserless_page {
-            svc.fetch(url, source_id)
-                .await
-                .map_err(ArchiveError::Other)?
-        } else if let Some(ref svc) = self.inner.chrome_page {
-            svc.fetch(url, source_id)
-                .await
-                .map_err(ArchiveError::Other)?
-        } else {
-            return Err(ArchiveError::Unsupported("No page fetcher configured".into()));
-        };
+        let fetched = self.inner.fetch_page_content(url, source_id).await?;
 
         let links = crate::links::extract_all_links(&fetched.raw_html, url);
         let page = crate::store::InsertPage {
@@ -919,7 +1223,8 @@ impl CrawlRequest {
             title: fetched.page.title,
             links: links.clone(),
         };
-        let page_id = self.inner.store.insert_page(&page).await?;
+        let previous_markdown = self.inner.store.previous_page_markdown(source_id, &page.markdown).await?;
+        let page_id = self.inner.store.insert_page(&page, &fetched.raw_html).await?;
         self.inner.store.update_last_scraped(source_id, "pages").await?;
 
         Ok(ArchivedPage {
@@ -931,6 +1236,7 @@ impl CrawlRequest {
             markdown: page.markdown,
             title: page.title,
             links,
+            previous_markdown,
         })
     }
 }
@@ -1029,175 +1335,6 @@ fn google_docs_export_url(url: &str) -> Option<String> {
     ))
 }
 
-// ---------------------------------------------------------------------------
-// Crawl helpers
-// ---------------------------------------------------------------------------
-
-/// Ensure a URL has an https:// scheme. Archive's normalize_url strips the
-/// scheme, so we need to add it back for fetching.
-fn ensure_scheme(url: &str) -> String {
-    if url.starts_with("http://") || url.starts_with("https://") {
-        url.to_string()
-    } else {
-        format!("https://{url}")
-    }
-}
-
-/// Extract the host from a URL for same-host comparison.
-fn extract_host(url: &str) -> String {
-    url::Url::parse(url)
-        .ok()
-        .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
-        .unwrap_or_default()
-}
-
-/// Normalize a URL for the crawl visited set.
-/// Strips fragments and trailing slashes, lowercases host.
-pub(crate) fn normalize_crawl_url(url: &str) -> String {
-    match url::Url::parse(url) {
-        Ok(mut parsed) => {
-            parsed.set_fragment(None);
-            let mut s = parsed.to_string();
-            if s.ends_with('/') && s.len() > parsed.scheme().len() + 3 {
-                // Don't strip trailing slash from bare domain (https://example.com/)
-                let path = parsed.path();
-                if path != "/" {
-                    s.pop();
-                }
-            }
-            s
-        }
-        Err(_) => url.to_string(),
-    }
-}
-
-/// Check if a URL belongs to the same host.
-fn same_host(url: &str, seed_host: &str) -> bool {
-    extract_host(url) == *seed_host
-}
-
-/// Check if a URL passes include/exclude pattern filters.
-/// Include patterns are OR'd (any match passes). Exclude patterns reject on any match.
-/// Empty include = allow all. Patterns are substring matches on the URL path.
-fn matches_patterns(url: &str, include: &[String], exclude: &[String]) -> bool {
-    let path = url::Url::parse(url)
-        .map(|u| u.path().to_string())
-        .unwrap_or_default();
-
-    // Exclude takes priority
-    if exclude.iter().any(|p| path.contains(p.as_str())) {
-        return false;
-    }
-
-    // Empty include = allow all
-    if include.is_empty() {
-        return true;
-    }
-
-    include.iter().any(|p| path.contains(p.as_str()))
-}
-
-#[cfg(test)]
-mod crawl_tests {
-    use super::*;
-
-    #[test]
-    fn normalize_strips_fragment() {
-        assert_eq!(
-            normalize_crawl_url("https://example.com/page#section"),
-            "https://example.com/page"
-        );
-    }
-
-    #[test]
-    fn normalize_strips_trailing_slash_on_path() {
-        assert_eq!(
-            normalize_crawl_url("https://example.com/about/"),
-            "https://example.com/about"
-        );
-    }
-
-    #[test]
-    fn normalize_keeps_trailing_slash_on_bare_domain() {
-        assert_eq!(
-            normalize_crawl_url("https://example.com/"),
-            "https://example.com/"
-        );
-    }
-
-    #[test]
-    fn normalize_keeps_query_strings() {
-        assert_eq!(
-            normalize_crawl_url("https://example.com/news?page=2"),
-            "https://example.com/news?page=2"
-        );
-    }
-
-    #[test]
-    fn normalize_strips_fragment_and_trailing_slash() {
-        assert_eq!(
-            normalize_crawl_url("https://example.com/about/#team"),
-            "https://example.com/about"
-        );
-    }
-
-    #[test]
-    fn same_host_matches() {
-        assert!(same_host("https://example.com/about", "example.com"));
-    }
-
-    #[test]
-    fn same_host_rejects_subdomain() {
-        assert!(!same_host("https://blog.example.com/post", "example.com"));
-    }
-
-    #[test]
-    fn same_host_rejects_different_domain() {
-        assert!(!same_host("https://other.com/page", "example.com"));
-    }
-
-    #[test]
-    fn patterns_empty_allows_all() {
-        assert!(matches_patterns("https://example.com/anything", &[], &[]));
-    }
-
-    #[test]
-    fn patterns_include_filters() {
-        let include = vec!["/about".to_string(), "/contact".to_string()];
-        assert!(matches_patterns("https://example.com/about", &include, &[]));
-        assert!(matches_patterns("https://example.com/contact", &include, &[]));
-        assert!(!matches_patterns("https://example.com/blog", &include, &[]));
-    }
-
-    #[test]
-    fn patterns_exclude_rejects() {
-        let exclude = vec!["/login".to_string()];
-        assert!(!matches_patterns("https://example.com/login", &[], &exclude));
-        assert!(matches_patterns("https://example.com/about", &[], &exclude));
-    }
-
-    #[test]
-    fn patterns_exclude_takes_priority() {
-        let include = vec!["/admin".to_string()];
-        let exclude = vec!["/admin".to_string()];
-        assert!(!matches_patterns("https://example.com/admin", &include, &exclude));
-    }
-
-    #[test]
-    fn patterns_substring_match() {
-        let include = vec!["/about".to_string()];
-        assert!(matches_patterns("https://example.com/about-us", &include, &[]));
-        assert!(matches_patterns("https://example.com/info/about/team", &include, &[]));
-    }
-
-    #[test]
-    fn ensure_scheme_adds_https() {
-        assert_eq!(ensure_scheme("example.com/page"), "https://example.com/page");
-        assert_eq!(ensure_scheme("https://example.com"), "https://example.com");
-        assert_eq!(ensure_scheme("http://example.com"), "http://example.com");
-    }
-}
-
 #[cfg(test)]
 mod google_docs_tests {
     use super::google_docs_export_url;
@@ -1,21 +1,57 @@
 // Postgres persistence for archive content types. Internal to the archive crate.
 // No platform knowledge — only universal content types.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use tracing::warn;
 use uuid::Uuid;
 
 use rootsignal_common::types::{
-    ArchiveFile, ArchivedFeed, ArchivedPage, ArchivedSearchResults, FeedItem, LongVideo, Post,
-    SearchResult, ShortVideo, Source, Story,
+    ArchiveFile, ArchivedFeed, ArchivedPage, ArchivedSearchResults, FeedItem, LongVideo,
+    ListedEvent, PodcastEpisode, Post, SearchResult, ShortVideo, Source, Story, TranscriptSegment,
 };
 
+use crate::blob::{
+    self, BlobKey, BlobLifecycleReport, BlobMigrationReport, BlobStorageStats, BlobStore, StorageClass, OFFLOAD_MIN_BYTES,
+};
+use crate::crawl::{CrawlRules, Frontier};
+use crate::engagement::EngagementSnapshot;
 use crate::error::Result;
+use crate::link_graph;
+
+/// Tables holding fetched content, each with `source_id` and `fetched_at`.
+const CONTENT_TABLES: [&str; 9] = [
+    "posts",
+    "stories",
+    "short_videos",
+    "long_videos",
+    "podcast_episodes",
+    "listed_events",
+    "pages",
+    "feeds",
+    "search_results",
+];
+
+/// Columns holding a blob key, as (table, column).
+const BLOB_KEY_COLUMNS: [(&str, &str); 4] = [
+    ("files", "text_blob_key"),
+    ("pages", "raw_html_blob_key"),
+    ("long_videos", "transcript_blob_key"),
+    ("podcast_episodes", "transcript_blob_key"),
+];
+
+/// Tables with a JSONB `transcript` that can move to the blob store.
+const TRANSCRIPT_TABLES: [&str; 2] = ["long_videos", "podcast_episodes"];
 
 #[derive(Clone)]
 pub(crate) struct Store {
     pool: PgPool,
+    /// Where large content goes; None keeps it in Postgres.
+    blobs: Option<Arc<dyn BlobStore>>,
 }
 
 // --- Insert types (no id/fetched_at — generated by DB) ---
@@ -72,6 +108,41 @@ pub(crate) struct InsertLongVideo {
     pub engagement: Option<serde_json::Value>,
     pub published_at: Option<DateTime<Utc>>,
     pub permalink: Option<String>,
+    pub title: Option<String>,
+    pub platform_id: Option<String>,
+    pub transcript: Vec<TranscriptSegment>,
+}
+
+pub(crate) struct InsertEpisode {
+    pub source_id: Uuid,
+    pub content_hash: String,
+    pub guid: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub permalink: String,
+    pub audio_url: String,
+    pub duration_secs: Option<f64>,
+    pub transcript: Vec<TranscriptSegment>,
+}
+
+pub(crate) struct InsertEvent {
+    pub source_id: Uuid,
+    pub content_hash: String,
+    pub platform_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub venue_name: Option<String>,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub is_online: bool,
+    pub organizer_name: Option<String>,
+    pub organizer_url: Option<String>,
+    pub ticket_url: Option<String>,
+    pub permalink: String,
 }
 
 pub(crate) struct InsertPage {
@@ -94,11 +165,273 @@ pub(crate) struct InsertSearchResults {
     pub content_hash: String,
     pub query: String,
     pub results: serde_json::Value,
+    /// The search provider that answered.
+    pub provider: String,
 }
 
 impl Store {
     pub(crate) fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, blobs: None }
+    }
+
+    pub(crate) fn with_blobs(mut self, blobs: Option<Arc<dyn BlobStore>>) -> Self {
+        self.blobs = blobs;
+        self
+    }
+
+    // --- Blobs ---
+
+    /// Put `bytes` in the blob store if one is configured and they are at
+    /// least `min_bytes`, compressed, and count the row about to reference
+    /// them. Content already stored is only counted. Returns the key the row
+    /// should keep instead.
+    async fn offload(&self, bytes: &[u8], content_type: &str, min_bytes: usize) -> Result<Option<BlobKey>> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(None);
+        };
+        if bytes.len() < min_bytes {
+            return Ok(None);
+        }
+        let key = BlobKey::for_content(bytes);
+        let known = sqlx::query_scalar::<_, i64>(
+            r#"
+            UPDATE blobs SET ref_count = ref_count + 1, last_written_at = now()
+            WHERE key = $1 AND backend = $2
+            RETURNING size_bytes
+            "#,
+        )
+        .bind(key.as_str())
+        .bind(blobs.name())
+        .fetch_optional(&self.pool)
+        .await?;
+        if known.is_some() {
+            return Ok(Some(key));
+        }
+
+        let (stored, encoding) = blob::encode(bytes);
+        let stored_bytes = stored.len() as i64;
+        blobs.put(&key, stored, content_type).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO blobs (key, backend, size_bytes, stored_bytes, encoding, content_type, ref_count)
+            VALUES ($1, $2, $3, $4, $5, $6, 1)
+            ON CONFLICT (key) DO UPDATE SET ref_count = blobs.ref_count + 1, last_written_at = now()
+            "#,
+        )
+        .bind(key.as_str())
+        .bind(blobs.name())
+        .bind(bytes.len() as i64)
+        .bind(stored_bytes)
+        .bind(encoding)
+        .bind(content_type)
+        .execute(&self.pool)
+        .await?;
+        Ok(Some(key))
+    }
+
+    /// Reset every blob's `ref_count` to the rows actually pointing at it.
+    /// `offload` counts a row before it is written, and purges delete rows
+    /// without uncounting them, so this runs after both can have drifted.
+    pub(crate) async fn recount_blob_refs(&self) -> Result<()> {
+        let refs = BLOB_KEY_COLUMNS
+            .iter()
+            .map(|(table, column)| format!("(SELECT count(*) FROM {table} WHERE {column} = b.key)"))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        sqlx::query(&format!(
+            r#"
+            UPDATE blobs b SET ref_count = r.n
+            FROM (SELECT key, ({refs})::int AS n FROM blobs b) r
+            WHERE r.key = b.key AND r.n <> b.ref_count
+            "#
+        ))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn blob_storage_stats(&self) -> Result<BlobStorageStats> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(BlobStorageStats::default());
+        };
+        let (count, references, logical, unique, stored) = sqlx::query_as::<_, (i64, i64, i64, i64, i64)>(
+            r#"
+            SELECT count(*),
+                   coalesce(sum(ref_count), 0)::bigint,
+                   coalesce(sum(size_bytes * ref_count), 0)::bigint,
+                   coalesce(sum(size_bytes), 0)::bigint,
+                   coalesce(sum(stored_bytes), 0)::bigint
+            FROM blobs WHERE backend = $1
+            "#,
+        )
+        .bind(blobs.name())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(BlobStorageStats {
+            blobs: count as u64,
+            references: references as u64,
+            logical_bytes: logical as u64,
+            unique_bytes: unique as u64,
+            stored_bytes: stored as u64,
+        })
+    }
+
+    /// Read a blob a row points at. A missing blob, or no blob store to read
+    /// it from, is logged and read as None rather than failing the row.
+    async fn load_blob(&self, key: &str) -> Result<Option<Bytes>> {
+        let Some(key) = BlobKey::parse(key) else {
+            warn!(key, "Malformed blob key");
+            return Ok(None);
+        };
+        let Some(blobs) = &self.blobs else {
+            warn!(%key, "Row points at a blob but no blob store is configured");
+            return Ok(None);
+        };
+        let Some(stored) = blobs.get(&key).await? else {
+            warn!(%key, "Blob missing from {}", blobs.name());
+            return Ok(None);
+        };
+        Ok(Some(blob::decode(stored)?))
+    }
+
+    async fn load_text(&self, key: Option<&str>) -> Result<Option<String>> {
+        let Some(key) = key else {
+            return Ok(None);
+        };
+        Ok(self.load_blob(key).await?.map(|b| String::from_utf8_lossy(&b).into_owned()))
+    }
+
+    async fn load_transcript(&self, stored: serde_json::Value, key: Option<&str>) -> Result<Vec<TranscriptSegment>> {
+        let Some(key) = key else {
+            return Ok(serde_json::from_value(stored).unwrap_or_default());
+        };
+        Ok(self
+            .load_blob(key)
+            .await?
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default())
+    }
+
+    /// Move blobs last written before `cold_cutoff` to cold storage, then
+    /// delete blobs written before `orphan_cutoff` that no row references.
+    pub(crate) async fn apply_blob_lifecycle(
+        &self,
+        cold_cutoff: Option<DateTime<Utc>>,
+        orphan_cutoff: DateTime<Utc>,
+        dry_run: bool,
+    ) -> Result<BlobLifecycleReport> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(BlobLifecycleReport::default());
+        };
+        let mut report = BlobLifecycleReport::default();
+
+        if let Some(cutoff) = cold_cutoff {
+            let keys = sqlx::query_scalar::<_, String>(
+                "SELECT key FROM blobs WHERE backend = $1 AND storage_class = 'standard' AND last_written_at < $2",
+            )
+            .bind(blobs.name())
+            .bind(cutoff)
+            .fetch_all(&self.pool)
+            .await?;
+            for key in keys {
+                let Some(blob_key) = BlobKey::parse(&key) else { continue };
+                if !dry_run {
+                    if let Err(e) = blobs.set_class(&blob_key, StorageClass::Cold).await {
+                        warn!(%key, error = %e, "Failed to move blob to cold storage");
+                        continue;
+                    }
+                    sqlx::query("UPDATE blobs SET storage_class = $2 WHERE key = $1")
+                        .bind(&key)
+                        .bind(StorageClass::Cold.as_str())
+                        .execute(&self.pool)
+                        .await?;
+                }
+                report.moved_cold += 1;
+            }
+        }
+
+        if !dry_run {
+            self.recount_blob_refs().await?;
+        }
+        let orphans = sqlx::query_scalar::<_, String>(
+            "SELECT key FROM blobs WHERE backend = $1 AND ref_count = 0 AND last_written_at < $2",
+        )
+        .bind(blobs.name())
+        .bind(orphan_cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+        for key in orphans {
+            if !dry_run {
+                if let Some(blob_key) = BlobKey::parse(&key) {
+                    if let Err(e) = blobs.delete(&blob_key).await {
+                        warn!(%key, error = %e, "Failed to delete orphaned blob");
+                        continue;
+                    }
+                }
+                sqlx::query("DELETE FROM blobs WHERE key = $1")
+                    .bind(&key)
+                    .execute(&self.pool)
+                    .await?;
+            }
+            report.deleted += 1;
+        }
+        Ok(report)
+    }
+
+    /// Move file text and transcripts already in Postgres rows into the blob
+    /// store, `batch` rows at a time. A dry run only counts.
+    pub(crate) async fn offload_existing(&self, batch: i64, dry_run: bool) -> Result<BlobMigrationReport> {
+        let min_bytes = OFFLOAD_MIN_BYTES as i32;
+        let mut report = BlobMigrationReport::default();
+
+        let mut columns = vec![("files", "text", "text_blob_key", "text/plain", "NULL")];
+        columns.extend(
+            TRANSCRIPT_TABLES
+                .iter()
+                .map(|table| (*table, "transcript", "transcript_blob_key", "application/json", "'[]'::jsonb")),
+        );
+        for (table, column, key_column, content_type, emptied) in columns {
+            let pending = format!("{key_column} IS NULL AND octet_length({column}::text) >= $1");
+            if dry_run {
+                let (rows, bytes) = sqlx::query_as::<_, (i64, i64)>(&format!(
+                    "SELECT count(*), coalesce(sum(octet_length({column}::text)), 0)::bigint FROM {table} WHERE {pending}"
+                ))
+                .bind(min_bytes)
+                .fetch_one(&self.pool)
+                .await?;
+                report.rows += rows as u64;
+                report.bytes += bytes as u64;
+                continue;
+            }
+
+            loop {
+                let rows = sqlx::query_as::<_, (Uuid, String)>(&format!(
+                    "SELECT id, {column}::text FROM {table} WHERE {pending} LIMIT $2"
+                ))
+                .bind(min_bytes)
+                .bind(batch)
+                .fetch_all(&self.pool)
+                .await?;
+                if rows.is_empty() {
+                    break;
+                }
+                for (id, content) in rows {
+                    let Some(key) = self.offload(content.as_bytes(), content_type, 0).await? else {
+                        return Ok(report);
+                    };
+                    sqlx::query(&format!(
+                        "UPDATE {table} SET {column} = {emptied}, {key_column} = $2 WHERE id = $1"
+                    ))
+                    .bind(id)
+                    .bind(key.as_str())
+                    .execute(&self.pool)
+                    .await?;
+                    report.rows += 1;
+                    report.bytes += content.len() as u64;
+                }
+            }
+        }
+        Ok(report)
     }
 
     // --- Sources ---
@@ -167,10 +500,14 @@ impl Store {
 
     /// Upsert a file by (url, content_hash). Returns existing row if already present.
     pub(crate) async fn upsert_file(&self, f: &InsertFile) -> Result<ArchiveFile> {
+        let text_key = match &f.text {
+            Some(text) => self.offload(text.as_bytes(), "text/plain", OFFLOAD_MIN_BYTES).await?,
+            None => None,
+        };
         let row = sqlx::query_as::<_, (Uuid, String, String, DateTime<Utc>, Option<String>, String, Option<f64>, Option<i32>, Option<String>, Option<String>)>(
             r#"
-            INSERT INTO files (url, content_hash, title, mime_type, duration, page_count, text, text_language)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO files (url, content_hash, title, mime_type, duration, page_count, text, text_language, text_blob_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             ON CONFLICT (url, content_hash) DO UPDATE SET url = EXCLUDED.url
             RETURNING id, url, content_hash, fetched_at, title, mime_type, duration, page_count, text, text_language
             "#,
@@ -181,8 +518,9 @@ impl Store {
         .bind(&f.mime_type)
         .bind(f.duration)
         .bind(f.page_count)
-        .bind(&f.text)
+        .bind(if text_key.is_some() { None } else { f.text.as_deref() })
         .bind(&f.text_language)
+        .bind(text_key.as_ref().map(BlobKey::as_str))
         .fetch_one(&self.pool)
         .await?;
 
@@ -195,7 +533,7 @@ impl Store {
             mime_type: row.5,
             duration: row.6,
             page_count: row.7,
-            text: row.8,
+            text: row.8.or_else(|| text_key.and(f.text.clone())),
             text_language: row.9,
         })
     }
@@ -207,19 +545,86 @@ impl Store {
         text: &str,
         language: Option<&str>,
     ) -> Result<()> {
+        let text_key = self.offload(text.as_bytes(), "text/plain", OFFLOAD_MIN_BYTES).await?;
         sqlx::query(
             r#"
-            UPDATE files SET text = $1, text_language = $2 WHERE id = $3
+            UPDATE files SET text = $1, text_language = $2, text_blob_key = $4 WHERE id = $3
             "#,
         )
-        .bind(text)
+        .bind(if text_key.is_some() { None } else { Some(text) })
         .bind(language)
         .bind(file_id)
+        .bind(text_key.as_ref().map(BlobKey::as_str))
         .execute(&self.pool)
         .await?;
         Ok(())
     }
 
+    // --- Retention ---
+
+    /// Delete content fetched before `cutoff` from sources not in
+    /// `held_urls`, with its attachments and any files left unattached.
+    /// Returns (purged, held); a dry run only counts.
+    pub(crate) async fn purge_content_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        held_urls: &[String],
+        dry_run: bool,
+    ) -> Result<(u64, u64)> {
+        let (mut purged, mut held) = (0u64, 0u64);
+        for table in CONTENT_TABLES {
+            let (eligible, kept) = sqlx::query_as::<_, (i64, i64)>(&format!(
+                r#"
+                SELECT count(*) FILTER (WHERE NOT s.url = ANY($2)), count(*) FILTER (WHERE s.url = ANY($2))
+                FROM {table} c JOIN sources s ON s.id = c.source_id
+                WHERE c.fetched_at < $1
+                "#
+            ))
+            .bind(cutoff)
+            .bind(held_urls)
+            .fetch_one(&self.pool)
+            .await?;
+            held += kept as u64;
+            if dry_run {
+                purged += eligible as u64;
+                continue;
+            }
+
+            let deleted = sqlx::query_scalar::<_, i64>(&format!(
+                r#"
+                WITH deleted AS (
+                    DELETE FROM {table} c USING sources s
+                    WHERE s.id = c.source_id AND c.fetched_at < $1 AND NOT s.url = ANY($2)
+                    RETURNING c.id
+                ), detached AS (
+                    DELETE FROM attachments WHERE parent_id IN (SELECT id FROM deleted)
+                )
+                SELECT count(*) FROM deleted
+                "#
+            ))
+            .bind(cutoff)
+            .bind(held_urls)
+            .fetch_one(&self.pool)
+            .await?;
+            purged += deleted as u64;
+        }
+
+        if !dry_run {
+            sqlx::query(
+                r#"
+                DELETE FROM files f
+                WHERE f.fetched_at < $1
+                  AND NOT EXISTS (SELECT 1 FROM attachments a WHERE a.file_id = f.id)
+                "#,
+            )
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+            self.recount_blob_refs().await?;
+        }
+        Ok((purged, held))
+    }
+
     // --- Attachments ---
 
     /// Link files to a content record. `file_positions` is (file_id, position).
@@ -252,10 +657,11 @@ impl Store {
         parent_type: &str,
         parent_id: Uuid,
     ) -> Result<Vec<ArchiveFile>> {
-        let rows = sqlx::query_as::<_, (Uuid, String, String, DateTime<Utc>, Option<String>, String, Option<f64>, Option<i32>, Option<String>, Option<String>)>(
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, String, String, DateTime<Utc>, Option<String>, String, Option<f64>, Option<i32>, Option<String>, Option<String>, Option<String>)>(
             r#"
             SELECT f.id, f.url, f.content_hash, f.fetched_at, f.title, f.mime_type,
-                   f.duration, f.page_count, f.text, f.text_language
+                   f.duration, f.page_count, f.text, f.text_language, f.text_blob_key
             FROM files f
             JOIN attachments a ON a.file_id = f.id
             WHERE a.parent_type = $1 AND a.parent_id = $2
@@ -267,9 +673,13 @@ impl Store {
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| ArchiveFile {
+        let mut files = Vec::with_capacity(rows.len());
+        for r in rows {
+            let text = match r.8 {
+                Some(text) => Some(text),
+                None => self.load_text(r.10.as_deref()).await?,
+            };
+            files.push(ArchiveFile {
                 id: r.0,
                 url: r.1,
                 content_hash: r.2,
@@ -278,19 +688,20 @@ impl Store {
                 mime_type: r.5,
                 duration: r.6,
                 page_count: r.7,
-                text: r.8,
+                text,
                 text_language: r.9,
-            })
-            .collect())
+            });
+        }
+        Ok(files)
     }
 
     // --- Posts ---
 
-    pub(crate) async fn insert_post(&self, p: &InsertPost) -> Result<Uuid> {
+    pub(crate) async fn insert_post(&self, p: &InsertPost, engagement_velocity: Option<f64>) -> Result<Uuid> {
         let id = sqlx::query_scalar::<_, Uuid>(
             r#"
-            INSERT INTO posts (source_id, content_hash, text, author, location, engagement, published_at, permalink, mentions, hashtags, media_type, platform_id)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            INSERT INTO posts (source_id, content_hash, text, author, location, engagement, published_at, permalink, mentions, hashtags, media_type, platform_id, engagement_velocity)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             RETURNING id
             "#,
         )
@@ -306,18 +717,59 @@ impl Store {
         .bind(&p.hashtags)
         .bind(&p.media_type)
         .bind(&p.platform_id)
+        .bind(engagement_velocity)
         .fetch_one(&self.pool)
         .await?;
         Ok(id)
     }
 
+    /// The latest archived snapshot of the same post: by permalink when it
+    /// has one, otherwise by content within its source.
+    pub(crate) async fn previous_engagement(&self, p: &InsertPost) -> Result<Option<EngagementSnapshot>> {
+        let row = match &p.permalink {
+            Some(permalink) => {
+                sqlx::query_as::<_, (serde_json::Value, DateTime<Utc>)>(
+                    "SELECT engagement, fetched_at FROM posts
+                     WHERE permalink = $1 AND engagement IS NOT NULL
+                     ORDER BY fetched_at DESC LIMIT 1",
+                )
+                .bind(permalink)
+                .fetch_optional(&self.pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as::<_, (serde_json::Value, DateTime<Utc>)>(
+                    "SELECT engagement, fetched_at FROM posts
+                     WHERE source_id = $1 AND content_hash = $2 AND engagement IS NOT NULL
+                     ORDER BY fetched_at DESC LIMIT 1",
+                )
+                .bind(p.source_id)
+                .bind(&p.content_hash)
+                .fetch_optional(&self.pool)
+                .await?
+            }
+        };
+        Ok(row.map(|(engagement, fetched_at)| EngagementSnapshot { engagement, fetched_at }))
+    }
+
+    pub(crate) async fn has_post(&self, source_id: Uuid, content_hash: &str) -> Result<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM posts WHERE source_id = $1 AND content_hash = $2)",
+        )
+        .bind(source_id)
+        .bind(content_hash)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists)
+    }
+
     pub(crate) async fn get_posts(&self, source_id: Uuid, limit: u32) -> Result<Vec<Post>> {
-        // 14 columns — large tuple, but avoids a custom FromRow derive.
+        // 15 columns — large tuple, but avoids a custom FromRow derive.
         #[allow(clippy::type_complexity)]
-        let rows = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, Option<String>, Option<String>, Option<String>, Option<serde_json::Value>, Option<DateTime<Utc>>, Option<String>, Vec<String>, Vec<String>, Option<String>, Option<String>)>(
+        let rows = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, Option<String>, Option<String>, Option<String>, Option<serde_json::Value>, Option<DateTime<Utc>>, Option<String>, Vec<String>, Vec<String>, Option<String>, Option<String>, Option<f64>)>(
             r#"
             SELECT id, source_id, fetched_at, content_hash, text, author, location, engagement,
-                   published_at, permalink, mentions, hashtags, media_type, platform_id
+                   published_at, permalink, mentions, hashtags, media_type, platform_id, engagement_velocity
             FROM posts WHERE source_id = $1
             ORDER BY fetched_at DESC LIMIT $2
             "#,
@@ -345,6 +797,7 @@ impl Store {
                 hashtags: r.11,
                 media_type: r.12,
                 platform_id: r.13,
+                engagement_velocity: r.14,
                 attachments,
             });
         }
@@ -463,10 +916,11 @@ impl Store {
     // --- Long Videos ---
 
     pub(crate) async fn insert_long_video(&self, v: &InsertLongVideo) -> Result<Uuid> {
+        let (transcript, transcript_key) = self.offload_transcript(&v.transcript).await?;
         let id = sqlx::query_scalar::<_, Uuid>(
             r#"
-            INSERT INTO long_videos (source_id, content_hash, text, engagement, published_at, permalink)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO long_videos (source_id, content_hash, text, engagement, published_at, permalink, title, platform_id, transcript, transcript_blob_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING id
             "#,
         )
@@ -476,19 +930,36 @@ impl Store {
         .bind(&v.engagement)
         .bind(v.published_at)
         .bind(&v.permalink)
+        .bind(&v.title)
+        .bind(&v.platform_id)
+        .bind(transcript)
+        .bind(transcript_key.as_ref().map(BlobKey::as_str))
         .fetch_one(&self.pool)
         .await?;
         Ok(id)
     }
 
+    pub(crate) async fn has_long_video(&self, source_id: Uuid, platform_id: &str) -> Result<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM long_videos WHERE source_id = $1 AND platform_id = $2)",
+        )
+        .bind(source_id)
+        .bind(platform_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists)
+    }
+
     pub(crate) async fn get_long_videos(
         &self,
         source_id: Uuid,
         limit: u32,
     ) -> Result<Vec<LongVideo>> {
-        let rows = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, Option<String>, Option<serde_json::Value>, Option<DateTime<Utc>>, Option<String>)>(
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, Option<String>, Option<serde_json::Value>, Option<DateTime<Utc>>, Option<String>, Option<String>, Option<String>, serde_json::Value, Option<String>)>(
             r#"
-            SELECT id, source_id, fetched_at, content_hash, text, engagement, published_at, permalink
+            SELECT id, source_id, fetched_at, content_hash, text, engagement, published_at, permalink,
+                   title, platform_id, transcript, transcript_blob_key
             FROM long_videos WHERE source_id = $1
             ORDER BY fetched_at DESC LIMIT $2
             "#,
@@ -501,6 +972,7 @@ impl Store {
         let mut videos = Vec::with_capacity(rows.len());
         for r in rows {
             let attachments = self.get_attachments("long_videos", r.0).await?;
+            let transcript = self.load_transcript(r.10, r.11.as_deref()).await?;
             videos.push(LongVideo {
                 id: r.0,
                 source_id: r.1,
@@ -511,18 +983,208 @@ impl Store {
                 published_at: r.6,
                 permalink: r.7,
                 attachments,
+                title: r.8,
+                platform_id: r.9,
+                transcript,
             });
         }
         Ok(videos)
     }
 
+    // --- Podcast Episodes ---
+
+    pub(crate) async fn insert_episode(&self, e: &InsertEpisode) -> Result<Uuid> {
+        let (transcript, transcript_key) = self.offload_transcript(&e.transcript).await?;
+        let id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO podcast_episodes (source_id, content_hash, guid, title, description, published_at, permalink, audio_url, duration_secs, transcript, transcript_blob_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id
+            "#,
+        )
+        .bind(e.source_id)
+        .bind(&e.content_hash)
+        .bind(&e.guid)
+        .bind(&e.title)
+        .bind(&e.description)
+        .bind(e.published_at)
+        .bind(&e.permalink)
+        .bind(&e.audio_url)
+        .bind(e.duration_secs)
+        .bind(transcript)
+        .bind(transcript_key.as_ref().map(BlobKey::as_str))
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub(crate) async fn has_episode(&self, source_id: Uuid, guid: &str) -> Result<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM podcast_episodes WHERE source_id = $1 AND guid = $2)",
+        )
+        .bind(source_id)
+        .bind(guid)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists)
+    }
+
+    pub(crate) async fn get_episodes(&self, source_id: Uuid, limit: u32) -> Result<Vec<PodcastEpisode>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, String, Option<String>, Option<String>, Option<DateTime<Utc>>, String, String, Option<f64>, serde_json::Value, Option<String>)>(
+            r#"
+            SELECT id, source_id, fetched_at, content_hash, guid, title, description, published_at,
+                   permalink, audio_url, duration_secs, transcript, transcript_blob_key
+            FROM podcast_episodes WHERE source_id = $1
+            ORDER BY fetched_at DESC LIMIT $2
+            "#,
+        )
+        .bind(source_id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut episodes = Vec::with_capacity(rows.len());
+        for r in rows {
+            let transcript = self.load_transcript(r.11, r.12.as_deref()).await?;
+            episodes.push(PodcastEpisode {
+                id: r.0,
+                source_id: r.1,
+                fetched_at: r.2,
+                content_hash: r.3,
+                guid: r.4,
+                title: r.5,
+                description: r.6,
+                published_at: r.7,
+                permalink: r.8,
+                audio_url: r.9,
+                duration_secs: r.10,
+                transcript,
+            });
+        }
+        Ok(episodes)
+    }
+
+    /// A transcript as stored: in the row, or `[]` with a blob key when it
+    /// went to the blob store.
+    async fn offload_transcript(
+        &self,
+        transcript: &[TranscriptSegment],
+    ) -> Result<(serde_json::Value, Option<BlobKey>)> {
+        let json = serde_json::to_vec(transcript).unwrap_or_default();
+        match self.offload(&json, "application/json", OFFLOAD_MIN_BYTES).await? {
+            Some(key) => Ok((serde_json::Value::Array(vec![]), Some(key))),
+            None => Ok((serde_json::to_value(transcript).unwrap_or_default(), None)),
+        }
+    }
+
+    // --- Listed Events ---
+
+    /// Insert an event, or update it in place if the platform listed it before.
+    pub(crate) async fn upsert_event(&self, e: &InsertEvent) -> Result<Uuid> {
+        let id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO listed_events (source_id, content_hash, platform_id, title, description, starts_at, ends_at,
+                                       venue_name, venue_address, latitude, longitude, is_online,
+                                       organizer_name, organizer_url, ticket_url, permalink)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            ON CONFLICT (source_id, platform_id) DO UPDATE SET
+                fetched_at = now(),
+                content_hash = EXCLUDED.content_hash,
+                title = EXCLUDED.title,
+                description = EXCLUDED.description,
+                starts_at = EXCLUDED.starts_at,
+                ends_at = EXCLUDED.ends_at,
+                venue_name = EXCLUDED.venue_name,
+                venue_address = EXCLUDED.venue_address,
+                latitude = EXCLUDED.latitude,
+                longitude = EXCLUDED.longitude,
+                is_online = EXCLUDED.is_online,
+                organizer_name = EXCLUDED.organizer_name,
+                organizer_url = EXCLUDED.organizer_url,
+                ticket_url = EXCLUDED.ticket_url,
+                permalink = EXCLUDED.permalink
+            RETURNING id
+            "#,
+        )
+        .bind(e.source_id)
+        .bind(&e.content_hash)
+        .bind(&e.platform_id)
+        .bind(&e.title)
+        .bind(&e.description)
+        .bind(e.starts_at)
+        .bind(e.ends_at)
+        .bind(&e.venue_name)
+        .bind(&e.venue_address)
+        .bind(e.latitude)
+        .bind(e.longitude)
+        .bind(e.is_online)
+        .bind(&e.organizer_name)
+        .bind(&e.organizer_url)
+        .bind(&e.ticket_url)
+        .bind(&e.permalink)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    /// Events that haven't ended yet, soonest first.
+    pub(crate) async fn get_upcoming_events(&self, source_id: Uuid, limit: u32) -> Result<Vec<ListedEvent>> {
+        use sqlx::Row;
+
+        // 18 columns is past sqlx's tuple FromRow limit, so read by name.
+        let rows = sqlx::query(
+            r#"
+            SELECT id, source_id, fetched_at, content_hash, platform_id, title, description, starts_at, ends_at,
+                   venue_name, venue_address, latitude, longitude, is_online,
+                   organizer_name, organizer_url, ticket_url, permalink
+            FROM listed_events
+            WHERE source_id = $1 AND COALESCE(ends_at, starts_at, now()) >= now()
+            ORDER BY starts_at ASC NULLS LAST LIMIT $2
+            "#,
+        )
+        .bind(source_id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let events = rows
+            .into_iter()
+            .map(|r| {
+                Ok(ListedEvent {
+                    id: r.try_get("id")?,
+                    source_id: r.try_get("source_id")?,
+                    fetched_at: r.try_get("fetched_at")?,
+                    content_hash: r.try_get("content_hash")?,
+                    platform_id: r.try_get("platform_id")?,
+                    title: r.try_get("title")?,
+                    description: r.try_get("description")?,
+                    starts_at: r.try_get("starts_at")?,
+                    ends_at: r.try_get("ends_at")?,
+                    venue_name: r.try_get("venue_name")?,
+                    venue_address: r.try_get("venue_address")?,
+                    latitude: r.try_get("latitude")?,
+                    longitude: r.try_get("longitude")?,
+                    is_online: r.try_get("is_online")?,
+                    organizer_name: r.try_get("organizer_name")?,
+                    organizer_url: r.try_get("organizer_url")?,
+                    ticket_url: r.try_get("ticket_url")?,
+                    permalink: r.try_get("permalink")?,
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, sqlx::Error>>()?;
+        Ok(events)
+    }
+
     // --- Pages ---
 
-    pub(crate) async fn insert_page(&self, p: &InsertPage) -> Result<Uuid> {
+    /// Insert a page. The raw HTML is kept only when a blob store is configured.
+    pub(crate) async fn insert_page(&self, p: &InsertPage, raw_html: &str) -> Result<Uuid> {
+        let raw_html_key = self.offload(raw_html.as_bytes(), "text/html", 0).await?;
         let id = sqlx::query_scalar::<_, Uuid>(
             r#"
-            INSERT INTO pages (source_id, content_hash, markdown, title, links)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO pages (source_id, content_hash, markdown, title, links, raw_html_blob_key)
+            VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING id
             "#,
         )
@@ -531,15 +1193,162 @@ impl Store {
         .bind(&p.markdown)
         .bind(&p.title)
         .bind(&p.links)
+        .bind(raw_html_key.as_ref().map(BlobKey::as_str))
         .fetch_one(&self.pool)
         .await?;
+        self.insert_page_links(id, p.source_id, &p.links).await?;
         Ok(id)
     }
 
+    // --- Crawls ---
+
+    /// The latest unfinished crawl with exactly these rules, to resume.
+    pub(crate) async fn find_unfinished_crawl(&self, rules: &CrawlRules) -> Result<Option<(Uuid, Frontier)>> {
+        let row = sqlx::query_as::<_, (Uuid, serde_json::Value)>(
+            r#"
+            SELECT id, frontier FROM crawls
+            WHERE seed_url = $1 AND rules = $2 AND completed_at IS NULL
+            ORDER BY updated_at DESC LIMIT 1
+            "#,
+        )
+        .bind(&rules.seed_url)
+        .bind(serde_json::to_value(rules).unwrap_or_default())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.and_then(|(id, frontier)| match serde_json::from_value(frontier) {
+            Ok(frontier) => Some((id, frontier)),
+            Err(e) => {
+                warn!(crawl_id = %id, error = %e, "Unreadable crawl checkpoint, starting over");
+                None
+            }
+        }))
+    }
+
+    pub(crate) async fn start_crawl(&self, rules: &CrawlRules, frontier: &Frontier) -> Result<Uuid> {
+        let id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO crawls (seed_url, rules, frontier, stats) VALUES ($1, $2, $3, $4)
+            RETURNING id
+            "#,
+        )
+        .bind(&rules.seed_url)
+        .bind(serde_json::to_value(rules).unwrap_or_default())
+        .bind(serde_json::to_value(frontier).unwrap_or_default())
+        .bind(serde_json::to_value(frontier.stats).unwrap_or_default())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    /// Save a crawl's frontier; a finished crawl is never resumed.
+    pub(crate) async fn checkpoint_crawl(&self, id: Uuid, frontier: &Frontier, finished: bool) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE crawls
+            SET frontier = $2, stats = $3, updated_at = now(),
+                completed_at = CASE WHEN $4 THEN now() END
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(serde_json::to_value(frontier).unwrap_or_default())
+        .bind(serde_json::to_value(frontier.stats).unwrap_or_default())
+        .bind(finished)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // --- Link graph ---
+
+    /// Record a page's outbound links as link-graph edges from its source URL.
+    async fn insert_page_links(&self, page_id: Uuid, source_id: Uuid, links: &[String]) -> Result<()> {
+        let targets = link_graph::link_targets(links);
+        if targets.is_empty() {
+            return Ok(());
+        }
+        let (to_urls, to_domains): (Vec<String>, Vec<String>) = targets.into_iter().unzip();
+        let from_url = sqlx::query_scalar::<_, String>("SELECT url FROM sources WHERE id = $1")
+            .bind(source_id)
+            .fetch_one(&self.pool)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO page_links (page_id, from_url, from_domain, to_url, to_domain)
+            SELECT $1, $2, $3, t.url, t.domain FROM unnest($4::text[], $5::text[]) AS t(url, domain)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(page_id)
+        .bind(&from_url)
+        .bind(link_graph::domain_of(&from_url))
+        .bind(&to_urls)
+        .bind(&to_domains)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Cross-domain links in pages fetched since `since`, as
+    /// (from_domain, to_domain, pages carrying the link).
+    pub(crate) async fn domain_link_edges(&self, since: DateTime<Utc>) -> Result<Vec<(String, String, u32)>> {
+        let rows = sqlx::query_as::<_, (String, String, i64)>(
+            r#"
+            SELECT from_domain, to_domain, count(DISTINCT page_id)
+            FROM page_links
+            WHERE fetched_at >= $1 AND from_domain <> to_domain
+            GROUP BY from_domain, to_domain
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(from, to, pages)| (from, to, pages as u32)).collect())
+    }
+
+    /// Whether `to` can be reached from `from` by following page links, in at
+    /// most `max_hops` links. Both URLs are normalized.
+    pub(crate) async fn page_reaches(&self, from: &str, to: &str, max_hops: i32) -> Result<bool> {
+        let reached = sqlx::query_scalar::<_, bool>(
+            r#"
+            WITH RECURSIVE reach(url, hops) AS (
+                SELECT $1::text, 0
+                UNION
+                SELECT l.to_url, r.hops + 1
+                FROM reach r JOIN page_links l ON l.from_url = r.url
+                WHERE r.hops < $3
+            )
+            SELECT EXISTS(SELECT 1 FROM reach WHERE url = $2 AND hops > 0)
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(max_hops)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(reached)
+    }
+
+    /// The markdown of the source's latest archived page, if it differs from
+    /// `markdown`. Called before inserting a new fetch.
+    pub(crate) async fn previous_page_markdown(&self, source_id: Uuid, markdown: &str) -> Result<Option<String>> {
+        let previous = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT markdown FROM pages WHERE source_id = $1
+            ORDER BY fetched_at DESC LIMIT 1
+            "#,
+        )
+        .bind(source_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(previous.filter(|p| p != markdown))
+    }
+
     pub(crate) async fn get_page(&self, source_id: Uuid) -> Result<Option<ArchivedPage>> {
-        let row = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, String, String, Option<String>, Vec<String>)>(
+        #[allow(clippy::type_complexity)]
+        let row = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, Option<String>, String, Option<String>, Vec<String>)>(
             r#"
-            SELECT id, source_id, fetched_at, content_hash, raw_html, markdown, title, links
+            SELECT id, source_id, fetched_at, content_hash, raw_html_blob_key, markdown, title, links
             FROM pages WHERE source_id = $1
             ORDER BY fetched_at DESC LIMIT 1
             "#,
@@ -547,16 +1356,20 @@ impl Store {
         .bind(source_id)
         .fetch_optional(&self.pool)
         .await?;
+        let Some(r) = row else {
+            return Ok(None);
+        };
 
-        Ok(row.map(|r| ArchivedPage {
+        Ok(Some(ArchivedPage {
             id: r.0,
             source_id: r.1,
             fetched_at: r.2,
             content_hash: r.3,
-            raw_html: r.4,
+            raw_html: self.load_text(r.4.as_deref()).await?.unwrap_or_default(),
             markdown: r.5,
             title: r.6,
             links: r.7,
+            previous_markdown: None,
         }))
     }
 
@@ -609,8 +1422,8 @@ impl Store {
     pub(crate) async fn insert_search_results(&self, s: &InsertSearchResults) -> Result<Uuid> {
         let id = sqlx::query_scalar::<_, Uuid>(
             r#"
-            INSERT INTO search_results (source_id, content_hash, query, results)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO search_results (source_id, content_hash, query, results, provider)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING id
             "#,
         )
@@ -618,18 +1431,35 @@ impl Store {
         .bind(&s.content_hash)
         .bind(&s.query)
         .bind(&s.results)
+        .bind(&s.provider)
         .fetch_one(&self.pool)
         .await?;
         Ok(id)
     }
 
+    /// Queries each search provider has answered since midnight UTC.
+    pub(crate) async fn search_queries_today(&self) -> Result<HashMap<String, u32>> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT provider, COUNT(*)
+            FROM search_results
+            WHERE provider IS NOT NULL
+              AND fetched_at >= date_trunc('day', now() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+            GROUP BY provider
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(provider, n)| (provider, n as u32)).collect())
+    }
+
     pub(crate) async fn get_search_results(
         &self,
         source_id: Uuid,
     ) -> Result<Option<ArchivedSearchResults>> {
-        let row = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, String, serde_json::Value)>(
+        let row = sqlx::query_as::<_, (Uuid, Uuid, DateTime<Utc>, String, String, serde_json::Value, Option<String>)>(
             r#"
-            SELECT id, source_id, fetched_at, content_hash, query, results
+            SELECT id, source_id, fetched_at, content_hash, query, results, provider
             FROM search_results WHERE source_id = $1
             ORDER BY fetched_at DESC LIMIT 1
             "#,
@@ -647,6 +1477,7 @@ impl Store {
                 content_hash: r.3,
                 query: r.4,
                 results,
+                provider: r.6,
             }
         }))
     }
@@ -51,6 +51,10 @@ impl EnrichmentWorkflow for EnrichmentWorkflowImpl {
         for file_req in req.files {
             let deps = self.deps.clone();
             let file_id = file_req.file_id;
+            // Kept for the dead-letter payload if this file fails.
+            let retry_req = EnrichmentRequest {
+                files: vec![file_req.clone()],
+            };
 
             match ctx
                 .run(|| enrich_single_file(deps, file_req))
@@ -63,6 +67,17 @@ impl EnrichmentWorkflow for EnrichmentWorkflowImpl {
                 Err(e) => {
                     failed += 1;
                     warn!(%file_id, error = %e, "enrichment: file failed, marking as attempted");
+                    if let Err(dl_err) = rootsignal_common::dead_letter::record(
+                        &self.deps.pg_pool,
+                        "EnrichmentWorkflow",
+                        &file_id.to_string(),
+                        &retry_req,
+                        &e.to_string(),
+                    )
+                    .await
+                    {
+                        warn!(%file_id, error = %dl_err, "enrichment: failed to dead-letter file");
+                    }
                     // Mark as attempted so we don't re-dispatch
                     let deps = self.deps.clone();
                     let fid = file_id;
@@ -97,10 +112,7 @@ impl EnrichmentWorkflow for EnrichmentWorkflowImpl {
         ctx: SharedWorkflowContext<'_>,
         _req: EmptyRequest,
     ) -> Result<String, HandlerError> {
-        Ok(ctx
-            .get::<String>("status")
-            .await?
-            .unwrap_or_else(|| "pending".to_string()))
+        rootsignal_common::restate::read_workflow_status(&ctx).await
     }
 }
 
@@ -114,13 +126,13 @@ async fn enrich_single_file(
         .map_err(|e| -> HandlerError { TerminalError::new(format!("base64 decode failed: {e}")).into() })?;
 
     let text = if file_req.mime_type.starts_with("image/") {
-        let claude = ai_client::Claude::new(&deps.anthropic_api_key, "claude-sonnet-4-20250514");
+        let claude = ai_client::Claude::new(deps.anthropic_api_key.expose(), "claude-sonnet-4-20250514");
         claude
             .describe_image(&bytes, &file_req.mime_type, OCR_PROMPT)
             .await
             .map_err(|e| -> HandlerError { TerminalError::new(format!("Claude vision failed: {e}")).into() })?
     } else {
-        let openai = ai_client::OpenAi::new(&deps.openai_api_key, "whisper-1");
+        let openai = ai_client::OpenAi::new(deps.openai_api_key.expose(), "whisper-1");
         openai
             .transcribe(bytes, &file_req.mime_type)
             .await
@@ -6,6 +6,7 @@
 pub mod enrichment;
 pub mod types;
 
+use rootsignal_common::Secret;
 use sqlx::PgPool;
 
 /// Shared dependency container for archive workflows.
@@ -14,38 +15,6 @@ use sqlx::PgPool;
 #[derive(Clone)]
 pub struct ArchiveDeps {
     pub pg_pool: PgPool,
-    pub anthropic_api_key: String,
-    pub openai_api_key: String,
-}
-
-// ---------------------------------------------------------------------------
-// Restate serde bridge macro
-// ---------------------------------------------------------------------------
-
-/// Implement Restate SDK serialization traits for types that already have serde derives.
-#[macro_export]
-macro_rules! impl_restate_serde {
-    ($type:ty) => {
-        impl restate_sdk::serde::Serialize for $type {
-            type Error = serde_json::Error;
-
-            fn serialize(&self) -> Result<bytes::Bytes, Self::Error> {
-                serde_json::to_vec(self).map(bytes::Bytes::from)
-            }
-        }
-
-        impl restate_sdk::serde::Deserialize for $type {
-            type Error = serde_json::Error;
-
-            fn deserialize(bytes: &mut bytes::Bytes) -> Result<Self, Self::Error> {
-                serde_json::from_slice(bytes)
-            }
-        }
-
-        impl restate_sdk::serde::WithContentType for $type {
-            fn content_type() -> &'static str {
-                "application/json"
-            }
-        }
-    };
+    pub anthropic_api_key: Secret,
+    pub openai_api_key: Secret,
 }
@@ -26,9 +26,7 @@ pub struct EnrichmentResult {
 }
 
 /// Empty request for `get_status` shared handlers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EmptyRequest;
+pub use rootsignal_common::restate::EmptyRequest;
 
-crate::impl_restate_serde!(EnrichmentRequest);
-crate::impl_restate_serde!(EnrichmentResult);
-crate::impl_restate_serde!(EmptyRequest);
+rootsignal_common::impl_restate_serde!(EnrichmentRequest);
+rootsignal_common::impl_restate_serde!(EnrichmentResult);
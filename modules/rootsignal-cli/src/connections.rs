@@ -0,0 +1,91 @@
+//! The config and connections every subcommand shares.
+
+use anyhow::{Context, Result};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use tracing::info;
+
+use rootsignal_common::tenancy::DEFAULT_DATABASE;
+use rootsignal_common::{Config, ScoutScope, TenantRegistry};
+use rootsignal_graph::{GraphClient, GraphRouter};
+
+pub struct Connections {
+    pub config: Config,
+    /// The tenant's Neo4j database, or the default one.
+    pub database: String,
+}
+
+impl Connections {
+    /// Load config, applying `tenant` and then `region` on top. `full` loads
+    /// the scout's config, which requires its AI and search keys up front.
+    pub fn load(tenant: Option<&str>, region: Option<String>, full: bool) -> Result<Self> {
+        let mut config = if full { Config::scout_from_env() } else { Config::admin_from_env() };
+        let database = match tenant {
+            Some(slug) => {
+                let registry = TenantRegistry::from_env()?.context("--tenant requires TENANTS_FILE")?;
+                let tenant = registry.get(slug).with_context(|| format!("Unknown tenant {slug}"))?;
+                config = tenant.config(&config);
+                info!(tenant = slug, database = tenant.database.as_str(), "Using tenant");
+                tenant.database.clone()
+            }
+            None => DEFAULT_DATABASE.to_string(),
+        };
+        if let Some(region) = region {
+            config.region = region;
+        }
+        Ok(Self { config, database })
+    }
+
+    /// Connect to Neo4j, creating a tenant's database if it's new.
+    pub async fn router(&self) -> Result<GraphRouter> {
+        let config = &self.config;
+        if self.database != DEFAULT_DATABASE {
+            GraphClient::connect(&config.neo4j_uri, &config.neo4j_user, &config.neo4j_password.expose())
+                .await?
+                .create_database(&self.database)
+                .await?;
+        }
+        let client = GraphClient::connect_database(
+            &config.neo4j_uri,
+            &config.neo4j_user,
+            &config.neo4j_password.expose(),
+            &self.database,
+        )
+        .await?;
+        Ok(GraphRouter::from_config(client, config))
+    }
+
+    /// The graph holding the configured region.
+    pub async fn region_graph(&self) -> Result<GraphClient> {
+        Ok(self.router().await?.for_region(&self.config.region).await?)
+    }
+
+    pub async fn pg(&self, max_connections: u32) -> Result<PgPool> {
+        let database_url = std::env::var("DATABASE_URL").context("DATABASE_URL required")?;
+        PgPoolOptions::new()
+            .max_connections(max_connections)
+            .connect(&database_url)
+            .await
+            .context("Failed to connect to Postgres")
+    }
+
+    /// The region's display name, which keys its Postgres rows.
+    pub fn region_name(&self) -> &str {
+        self.config.region_name.as_deref().unwrap_or(&self.config.region)
+    }
+
+    /// The region's scope, from REGION_BOUNDARY_PATH, or else REGION_LAT,
+    /// REGION_LNG and REGION_RADIUS_KM.
+    pub fn scope(&self) -> Result<ScoutScope> {
+        if let Some(boundary) = self.config.region_boundary()? {
+            return Ok(ScoutScope::from_boundary(self.region_name(), boundary));
+        }
+        Ok(ScoutScope {
+            center_lat: self.config.region_lat.context("REGION_LAT required")?,
+            center_lng: self.config.region_lng.context("REGION_LNG required")?,
+            radius_km: self.config.region_radius_km.unwrap_or(30.0),
+            name: self.region_name().to_string(),
+            boundary: None,
+        })
+    }
+}
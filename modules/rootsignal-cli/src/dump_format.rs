@@ -0,0 +1,210 @@
+//! Output formats for `dump`.
+
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde_json::json;
+
+use rootsignal_common::{Node, NodeType};
+use rootsignal_graph::dump::{Bbox, DumpDiff, RegionDump};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum DumpFormat {
+    /// One JSON document.
+    Json,
+    /// One record per line: situations, then signals.
+    Jsonl,
+    /// A table of signals for reading in a terminal.
+    Table,
+    /// Situations and signals as a graph, for Gephi, yEd or Cytoscape.
+    Graphml,
+}
+
+/// A signal type as given on the command line, e.g. `need`.
+pub fn parse_node_type(s: &str) -> Result<NodeType, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "gathering" => Ok(NodeType::Gathering),
+        "aid" => Ok(NodeType::Aid),
+        "need" => Ok(NodeType::Need),
+        "notice" => Ok(NodeType::Notice),
+        "tension" => Ok(NodeType::Tension),
+        _ => Err(format!("unknown signal type {s:?}")),
+    }
+}
+
+/// `min_lat,min_lng,max_lat,max_lng`.
+pub fn parse_bbox(s: &str) -> Result<Bbox, String> {
+    let parts: Vec<f64> = s
+        .split(',')
+        .map(|p| p.trim().parse::<f64>().map_err(|_| format!("invalid coordinate {p:?}")))
+        .collect::<Result<_, _>>()?;
+    let [min_lat, min_lng, max_lat, max_lng] = parts[..] else {
+        return Err("expected min_lat,min_lng,max_lat,max_lng".to_string());
+    };
+    Ok(Bbox { min_lat, min_lng, max_lat, max_lng })
+}
+
+/// An RFC 3339 timestamp or a `YYYY-MM-DD` date (midnight UTC).
+pub fn parse_since(s: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    if let Ok(t) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Ok(t.to_utc());
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc())
+        .map_err(|_| format!("expected a date or RFC 3339 timestamp, got {s:?}"))
+}
+
+pub fn render(dump: &RegionDump, format: DumpFormat) -> serde_json::Result<String> {
+    Ok(match format {
+        DumpFormat::Json => serde_json::to_string_pretty(dump)?,
+        DumpFormat::Jsonl => jsonl(dump)?,
+        DumpFormat::Table => table(dump),
+        DumpFormat::Graphml => graphml(dump),
+    })
+}
+
+/// Every signal with the situation it's grouped under, if any.
+fn signals(dump: &RegionDump) -> impl Iterator<Item = (&Node, Option<&rootsignal_common::SituationNode>)> {
+    dump.situations
+        .iter()
+        .flat_map(|s| s.signals.iter().map(move |n| (n, Some(&s.situation))))
+        .chain(dump.ungrouped_signals.iter().map(|n| (n, None)))
+}
+
+fn jsonl(dump: &RegionDump) -> serde_json::Result<String> {
+    let mut out = String::new();
+    for s in &dump.situations {
+        let mut record = serde_json::to_value(&s.situation)?;
+        record["record"] = json!("situation");
+        record["signal_ids"] = json!(s.signals.iter().map(Node::id).collect::<Vec<_>>());
+        let _ = writeln!(out, "{record}");
+    }
+    for (node, situation) in signals(dump) {
+        let mut record = serde_json::to_value(node)?;
+        record["record"] = json!("signal");
+        record["situation_id"] = json!(situation.map(|s| s.id));
+        let _ = writeln!(out, "{record}");
+    }
+    Ok(out)
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        format!("{}…", s.chars().take(max - 1).collect::<String>())
+    }
+}
+
+fn table(dump: &RegionDump) -> String {
+    let mut out = format!("{:<9} {:<8} {:>4}  {:<30} {}\n", "TYPE", "ID", "CONF", "SITUATION", "TITLE");
+    for (node, situation) in signals(dump) {
+        let confidence = node.meta().map_or(0.0, |m| m.confidence);
+        let situation = situation.map_or("-".to_string(), |s| truncate(&s.headline, 30));
+        let _ = writeln!(
+            out,
+            "{:<9} {:<8} {confidence:>4.2}  {situation:<30} {}",
+            node.node_type().to_string(),
+            &node.id().to_string()[..8],
+            truncate(node.title(), 70),
+        );
+    }
+    let total: usize = dump.situations.iter().map(|s| s.signals.len()).sum::<usize>() + dump.ungrouped_signals.len();
+    let _ = writeln!(out, "\n{total} signals in {} situations", dump.situations.len());
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn graphml(dump: &RegionDump) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+         <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>\n  \
+         <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n  \
+         <key id=\"weight\" for=\"node\" attr.name=\"weight\" attr.type=\"double\"/>\n  \
+         <graph id=\"",
+    );
+    let _ = writeln!(out, "{}\" edgedefault=\"directed\">", xml_escape(&dump.region));
+    let mut node = |id: String, kind: &str, label: &str, weight: f64| {
+        let _ = writeln!(
+            out,
+            "    <node id=\"{id}\"><data key=\"kind\">{kind}</data><data key=\"label\">{}</data>\
+             <data key=\"weight\">{weight}</data></node>",
+            xml_escape(label),
+        );
+    };
+    for s in &dump.situations {
+        node(s.situation.id.to_string(), "Situation", &s.situation.headline, s.situation.temperature);
+    }
+    for (signal, _) in signals(dump) {
+        let confidence = signal.meta().map_or(0.0, |m| m.confidence as f64);
+        node(signal.id().to_string(), &signal.node_type().to_string(), signal.title(), confidence);
+    }
+    for (signal, situation) in signals(dump) {
+        if let Some(situation) = situation {
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\" label=\"EVIDENCES\"/>",
+                signal.id(),
+                situation.id
+            );
+        }
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// `+` added, `-` removed, `~` changed, one per line.
+pub fn render_diff(diff: &DumpDiff) -> String {
+    if diff.is_empty() {
+        return "No changes\n".to_string();
+    }
+    let mut out = String::new();
+    for e in &diff.added {
+        let _ = writeln!(out, "+ {:<9} {}  {}", e.kind, &e.id.to_string()[..8], e.title);
+    }
+    for e in &diff.removed {
+        let _ = writeln!(out, "- {:<9} {}  {}", e.kind, &e.id.to_string()[..8], e.title);
+    }
+    for c in &diff.changed {
+        let _ = writeln!(out, "~ {:<9} {}  {}", c.entry.kind, &c.entry.id.to_string()[..8], c.entry.title);
+        for change in &c.changes {
+            let _ = writeln!(
+                out,
+                "    {}: {} -> {}",
+                change.field,
+                truncate(&change.before, 60),
+                truncate(&change.after, 60)
+            );
+        }
+    }
+    let _ = writeln!(
+        out,
+        "\n{} added, {} removed, {} changed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bbox_and_since_parse_from_the_command_line() {
+        let bbox = parse_bbox("44.8, -93.4, 45.1,-93.0").unwrap();
+        let since = parse_since("2026-03-01").unwrap();
+
+        assert_eq!(bbox, Bbox { min_lat: 44.8, min_lng: -93.4, max_lat: 45.1, max_lng: -93.0 });
+        assert_eq!(since.to_rfc3339(), "2026-03-01T00:00:00+00:00");
+        assert!(parse_bbox("44.8,-93.4").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! `rootsignal`: admin operations against a region's graph and Postgres.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::{Args, Parser, Subcommand};
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+use rootsignal_common::error_budget::{self, ErrorBudgetPolicy};
+use rootsignal_common::{slugify, synthesis_budget, ScoutScope};
+use rootsignal_graph::migrate::{backfill_source_canonical_keys, backfill_source_diversity};
+use rootsignal_graph::dump::{self, Bbox, DumpFilter, RegionDump};
+use rootsignal_graph::region_stats;
+use rootsignal_scout::workflows::full_run::run_local;
+use rootsignal_scout::workflows::ScoutDeps;
+
+mod connections;
+mod dump_format;
+mod source_import;
+
+use connections::Connections;
+use dump_format::DumpFormat;
+
+#[derive(Parser)]
+#[command(name = "rootsignal", about = "Root Signal admin operations")]
+struct Cli {
+    /// Region slug (e.g. "minneapolis"). Overrides REGION.
+    #[arg(long, global = true)]
+    region: Option<String>,
+
+    /// Act on a tenant from TENANTS_FILE: its database, region and budget.
+    #[arg(long, global = true)]
+    tenant: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the full scout for the region in this process.
+    RunScout,
+    /// Print the region's situations and signals, or diff two dumps.
+    Dump(DumpArgs),
+    #[command(subcommand)]
+    Graph(GraphCommand),
+    #[command(subcommand)]
+    Source(SourceCommand),
+    #[command(subcommand)]
+    Budget(BudgetCommand),
+    #[command(subcommand)]
+    DeadLetters(DeadLetterCommand),
+    #[command(subcommand)]
+    Sim(SimCommand),
+}
+
+#[derive(Args)]
+struct DumpArgs {
+    /// Only these signal types (gathering, aid, need, notice, tension).
+    #[arg(long = "type", value_parser = dump_format::parse_node_type)]
+    types: Vec<rootsignal_common::NodeType>,
+
+    /// Signals in this category, or in situations of this category.
+    #[arg(long)]
+    category: Option<String>,
+
+    /// Signals extracted since this date or RFC 3339 timestamp.
+    #[arg(long, value_parser = dump_format::parse_since)]
+    since: Option<chrono::DateTime<Utc>>,
+
+    /// Signals within `min_lat,min_lng,max_lat,max_lng`.
+    #[arg(long, value_parser = dump_format::parse_bbox, allow_hyphen_values = true)]
+    bbox: Option<Bbox>,
+
+    #[arg(long, value_enum, default_value_t = DumpFormat::Json)]
+    format: DumpFormat,
+
+    #[command(subcommand)]
+    command: Option<DumpCommand>,
+}
+
+impl DumpArgs {
+    fn filter(&self) -> DumpFilter {
+        DumpFilter {
+            node_types: self.types.clone(),
+            category: self.category.clone(),
+            since: self.since,
+            bbox: self.bbox,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum DumpCommand {
+    /// Show what was added, changed or removed between two JSON dumps, or
+    /// between a dump and the live graph. Filters apply to both sides.
+    Diff {
+        before: PathBuf,
+        /// Defaults to the live graph.
+        after: Option<PathBuf>,
+        /// Print the diff as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum GraphCommand {
+    /// Check the region's graph is reachable and print its counts.
+    Check {
+        /// Run migrations on every routed database first.
+        #[arg(long)]
+        migrate: bool,
+    },
+    /// Recompute and store the region's stats, printing them as JSON.
+    Snapshot,
+}
+
+#[derive(Subcommand)]
+enum SourceCommand {
+    /// Add sources from a file with one URL per line.
+    Import {
+        file: PathBuf,
+        /// Recorded on each source, as the admin API does.
+        #[arg(long)]
+        reason: Option<String>,
+        /// Validate and list the URLs without writing them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum BudgetCommand {
+    /// Show the region's spend budget, synthesis policy and error budgets.
+    Report,
+}
+
+#[derive(Subcommand)]
+enum DeadLetterCommand {
+    /// List pending dead-lettered workflow items.
+    List,
+    /// Replay one into its workflow via RESTATE_INGRESS_URL.
+    Replay { id: uuid::Uuid },
+}
+
+#[derive(Subcommand)]
+enum SimCommand {
+    /// Turn a region dump into a simweb world and add it to a scenario gym.
+    /// Emails, phone numbers and handles are scrubbed; social profiles get
+    /// pseudonyms.
+    ImportWorld {
+        /// Directory the gym loads generated scenarios from.
+        #[arg(long)]
+        gym_dir: PathBuf,
+        /// A JSON dump to import instead of the live graph.
+        #[arg(long)]
+        from: Option<PathBuf>,
+        /// State or region for the world's geography.
+        #[arg(long, default_value = "")]
+        state: String,
+        #[arg(long, default_value_t = 40)]
+        max_sites: usize,
+        #[arg(long, default_value_t = 15)]
+        max_profiles: usize,
+        #[arg(long, default_value_t = 100)]
+        max_facts: usize,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env().add_directive("rootsignal=info".parse()?))
+        .init();
+
+    // Load .env from workspace root (doesn't override existing env vars)
+    dotenv_load();
+
+    let cli = Cli::parse();
+    let full = matches!(cli.command, Command::RunScout);
+    let conn = Connections::load(cli.tenant.as_deref(), cli.region, full)?;
+
+    match cli.command {
+        Command::RunScout => run_scout(&conn).await,
+        Command::Dump(args) => dump(&conn, args).await,
+        Command::Graph(GraphCommand::Check { migrate }) => graph_check(&conn, migrate).await,
+        Command::Graph(GraphCommand::Snapshot) => {
+            let region = slugify(conn.region_name());
+            let stats = region_stats::refresh_region_stats(&conn.region_graph().await?, &region).await?;
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+            Ok(())
+        }
+        Command::Source(SourceCommand::Import { file, reason, dry_run }) => {
+            let text = std::fs::read_to_string(&file).with_context(|| format!("Reading {}", file.display()))?;
+            source_import::import(&conn.region_graph().await?, &text, reason.as_deref(), dry_run).await
+        }
+        Command::Budget(BudgetCommand::Report) => budget_report(&conn).await,
+        Command::DeadLetters(command) => dead_letters(&conn, command).await,
+        Command::Sim(command) => sim(&conn, command).await,
+    }
+}
+
+async fn run_scout(conn: &Connections) -> Result<()> {
+    conn.config.log_redacted();
+    let router = conn.router().await?;
+
+    // Run migrations on the default database and every routed region database
+    router.migrate_all().await?;
+    let region_client = router.for_region(&conn.config.region).await?;
+    let scope = conn.scope()?;
+    info!(name = scope.name.as_str(), radius_km = scope.radius_km, "Running scout");
+
+    backfill_source_canonical_keys(&region_client).await?;
+    backfill_source_diversity(&region_client, &[]).await?;
+
+    let deps = ScoutDeps::from_config(router, conn.pg(5).await?, &conn.config);
+    let stats = run_local(&deps, scope).await?;
+    println!("{stats}");
+    Ok(())
+}
+
+async fn dump(conn: &Connections, args: DumpArgs) -> Result<()> {
+    let filter = args.filter();
+    let live = || async {
+        let scope = ScoutScope { name: conn.config.region.clone(), ..conn.scope()? };
+        anyhow::Ok(dump::dump_region(&conn.region_graph().await?, &scope).await?)
+    };
+
+    let Some(DumpCommand::Diff { before, after, json }) = args.command else {
+        print!("{}", dump_format::render(&live().await?.filtered(&filter), args.format)?);
+        return Ok(());
+    };
+    let before = read_dump(&before)?.filtered(&filter);
+    let after = match after {
+        Some(path) => read_dump(&path)?,
+        None => live().await?,
+    }
+    .filtered(&filter);
+    let diff = dump::diff(&before, &after);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print!("{}", dump_format::render_diff(&diff));
+    }
+    Ok(())
+}
+
+async fn sim(conn: &Connections, command: SimCommand) -> Result<()> {
+    let SimCommand::ImportWorld { gym_dir, from, state, max_sites, max_profiles, max_facts } = command;
+    let dump = match from {
+        Some(path) => read_dump(&path)?,
+        None => {
+            let scope = ScoutScope { name: conn.config.region.clone(), ..conn.scope()? };
+            dump::dump_region(&conn.region_graph().await?, &scope).await?
+        }
+    };
+    let dump: simweb::import::RegionDump = serde_json::from_value(serde_json::to_value(&dump)?)?;
+    let options = simweb::ImportOptions {
+        max_sites,
+        max_social_profiles: max_profiles,
+        max_facts,
+        state_or_region: state,
+        ..Default::default()
+    };
+
+    let world = simweb::import_world(&dump, &options);
+    let criteria = simweb::imported_criteria(&world);
+    println!("{}", world.description);
+    let mut gym = simweb::ScenarioGym::load(Vec::new(), &gym_dir);
+    gym.add_imported(world.name.clone(), world, criteria, dump.region)?;
+    Ok(())
+}
+
+fn read_dump(path: &std::path::Path) -> Result<RegionDump> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+    serde_json::from_str(&text).with_context(|| format!("{} is not a JSON dump", path.display()))
+}
+
+async fn graph_check(conn: &Connections, migrate: bool) -> Result<()> {
+    if migrate {
+        conn.router().await?.migrate_all().await?;
+    }
+    let region = slugify(conn.region_name());
+    let stats = region_stats::compute_region_stats(&conn.region_graph().await?, &region, Utc::now()).await?;
+    println!("{} ({}): reachable", conn.config.region, conn.database);
+    println!("  signals: {}", stats.total_signals);
+    println!("  stories: {}", stats.total_stories);
+    println!("  actors:  {}", stats.total_actors);
+    println!("  sources: {} ({} active)", stats.total_sources, stats.active_sources);
+    Ok(())
+}
+
+async fn budget_report(conn: &Connections) -> Result<()> {
+    let pool = conn.pg(1).await?;
+    let region = conn.region_name();
+
+    match conn.config.daily_budget_cents {
+        0 => println!("Daily budget: unlimited"),
+        cents => println!("Daily budget: ${:.2}", cents as f64 / 100.0),
+    }
+
+    let policy = synthesis_budget::load_or_default(&pool, region).await;
+    println!("\nSynthesis: {} ({})", policy.mode.as_str(), policy.mode_source.as_str());
+    for (phase, priority, source) in &policy.priorities {
+        println!("  {:<18} priority {priority} ({})", phase.as_str(), source.as_str());
+    }
+
+    let errors = ErrorBudgetPolicy::from_env();
+    let tallies = error_budget::window_tallies(&pool, region, Utc::now() - errors.window).await?;
+    let burned = errors.burned(&tallies);
+    println!("\nError budgets, last {}h:", errors.window.num_hours());
+    for budget in &errors.budgets {
+        let tally = tallies.iter().find(|t| t.operation == budget.operation);
+        let (attempts, failures, rate) = tally.map_or((0, 0, 0.0), |t| (t.attempts, t.failures, t.failure_rate()));
+        let marker = if burned.iter().any(|b| b.tally.operation == budget.operation) { "  BURNED" } else { "" };
+        println!(
+            "  {:<18} {failures}/{attempts} failed ({:.0}%, budget {:.0}%){marker}",
+            budget.operation,
+            rate * 100.0,
+            budget.max_failure_rate * 100.0,
+        );
+    }
+    Ok(())
+}
+
+async fn dead_letters(conn: &Connections, command: DeadLetterCommand) -> Result<()> {
+    use rootsignal_common::dead_letter::{self, DeadLetterStatus};
+
+    let pool = conn.pg(1).await?;
+    match command {
+        DeadLetterCommand::Replay { id } => {
+            let ingress_url = std::env::var("RESTATE_INGRESS_URL").context("RESTATE_INGRESS_URL required")?;
+            let key = dead_letter::replay(&pool, &reqwest::Client::new(), &ingress_url, id).await?;
+            println!("Replayed {id} as {key}");
+        }
+        DeadLetterCommand::List => {
+            let entries = dead_letter::list(&pool, Some(DeadLetterStatus::Pending), 200).await?;
+            if entries.is_empty() {
+                println!("No pending dead letters");
+            }
+            for dl in entries {
+                println!(
+                    "{}  {}  {}  attempts={}  [{}] {}",
+                    dl.id,
+                    dl.workflow,
+                    dl.item_key,
+                    dl.attempts.len(),
+                    dl.error_class,
+                    dl.error,
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn dotenv_load() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(".env");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                if std::env::var(key.trim()).is_err() {
+                    std::env::set_var(key.trim(), value.trim());
+                }
+            }
+        }
+    }
+}
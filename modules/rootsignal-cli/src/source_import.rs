@@ -0,0 +1,84 @@
+//! `source import`: add human-submitted sources from a file of URLs.
+
+use anyhow::Result;
+
+use rootsignal_common::{canonical_value, DiscoveryMethod, SourceNode, SourceRole};
+use rootsignal_graph::{GraphClient, GraphWriter};
+
+/// One URL per line; blank lines and `#` comments are skipped. URLs are
+/// checked the way the admin API's `addSource` checks them.
+pub fn parse(text: &str) -> Vec<(usize, Result<String, String>)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(n, line)| (n, validate(line)))
+        .collect()
+}
+
+fn validate(url: &str) -> Result<String, String> {
+    if url.len() > 2048 {
+        return Err("URL too long (max 2048 characters)".to_string());
+    }
+    let parsed = url::Url::parse(url).map_err(|_| "Invalid URL".to_string())?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err("URL must use http or https scheme".to_string());
+    }
+    Ok(url.to_string())
+}
+
+/// Import the file's valid URLs into `client`, or only report them when
+/// `dry_run`. Invalid lines are reported and skipped.
+pub async fn import(client: &GraphClient, text: &str, reason: Option<&str>, dry_run: bool) -> Result<()> {
+    let writer = GraphWriter::new(client.clone());
+    let (mut imported, mut skipped) = (0, 0);
+    for (line, url) in parse(text) {
+        let url = match url {
+            Ok(url) => url,
+            Err(e) => {
+                eprintln!("line {line}: {e}");
+                skipped += 1;
+                continue;
+            }
+        };
+        if !dry_run {
+            let cv = canonical_value(&url);
+            let source = SourceNode::new(
+                cv.clone(),
+                cv,
+                Some(url.clone()),
+                DiscoveryMethod::HumanSubmission,
+                0.5,
+                SourceRole::default(),
+                reason.map(|r| format!("Admin: {r}")),
+            );
+            writer.upsert_source(&source).await?;
+        }
+        println!("{url}");
+        imported += 1;
+    }
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    println!("{verb} {imported} sources, skipped {skipped}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_are_skipped_and_bad_urls_reported_by_line() {
+        let text = "# food shelves\nhttps://example.org/pantry\n\nftp://example.org/x\nnot a url\n";
+
+        let parsed = parse(text);
+
+        assert_eq!(
+            parsed,
+            vec![
+                (2, Ok("https://example.org/pantry".to_string())),
+                (4, Err("URL must use http or https scheme".to_string())),
+                (5, Err("Invalid URL".to_string())),
+            ]
+        );
+    }
+}
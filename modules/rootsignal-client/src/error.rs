@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("API error (status {status}): {message}")]
+    Api { status: u16, message: String },
+
+    #[error("GraphQL error: {}", .0.join("; "))]
+    GraphQl(Vec<String>),
+
+    #[error("Parse error: {0}")]
+    Parse(#[from] serde_json::Error),
+}
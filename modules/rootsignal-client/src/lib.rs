@@ -0,0 +1,232 @@
+//! Typed client for the Root Signal public API: signals, stories, situations
+//! and source submission over GraphQL, plus the REST endpoints. Builds for
+//! wasm32 so web frontends can use it directly.
+
+pub mod error;
+pub mod pagination;
+pub mod schema;
+
+pub use error::{ClientError, Result};
+pub use pagination::{collect_pages, PageRequest, MAX_PAGE_SIZE};
+pub use schema::{
+    Aid, Clarity, Dispatch, DispatchType, Gathering, GeoPoint, GeoPrecision, LinkPreview, Need,
+    Notice, Sensitivity, Severity, Signal, SignalMeta, Situation, SituationArc, Story,
+    SubmitResult, Tension, Urgency,
+};
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use schema::Operation;
+
+/// Header the API resolves tenants from.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// A geographic bounding box for viewport queries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+#[derive(Deserialize)]
+struct GraphQlResponse {
+    data: Option<serde_json::Map<String, Value>>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct SituationDispatches {
+    dispatches: Vec<Dispatch>,
+}
+
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl Client {
+    /// A client for the API served at `base_url`, e.g. `https://api.rootsignal.app`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: None,
+        }
+    }
+
+    /// Send a tenant API key with every request.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    // ========== Signals ==========
+
+    pub async fn signals_recent(&self, limit: Option<u32>) -> Result<Vec<Signal>> {
+        self.run(schema::SIGNALS_RECENT, json!({ "limit": limit })).await
+    }
+
+    pub async fn signal(&self, id: Uuid) -> Result<Option<Signal>> {
+        self.run(schema::SIGNAL, json!({ "id": id })).await
+    }
+
+    /// Signals in `bounds`, hottest first. `category` includes its subcategories.
+    pub async fn signals_in_bounds(
+        &self,
+        bounds: Bounds,
+        category: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Signal>> {
+        let mut variables = bounds_variables(bounds);
+        variables["category"] = json!(category);
+        variables["limit"] = json!(limit);
+        self.run(schema::SIGNALS_IN_BOUNDS, variables).await
+    }
+
+    // ========== Stories ==========
+
+    pub async fn stories_in_bounds(
+        &self,
+        bounds: Bounds,
+        tag: Option<&str>,
+        category: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Story>> {
+        let mut variables = bounds_variables(bounds);
+        variables["tag"] = json!(tag);
+        variables["category"] = json!(category);
+        variables["limit"] = json!(limit);
+        self.run(schema::STORIES_IN_BOUNDS, variables).await
+    }
+
+    /// A story with its narrative and signals.
+    pub async fn story(&self, id: Uuid) -> Result<Option<Story>> {
+        self.run(schema::STORY, json!({ "id": id })).await
+    }
+
+    // ========== Situations ==========
+
+    pub async fn situations(&self, category: Option<&str>, limit: Option<u32>) -> Result<Vec<Situation>> {
+        self.run(schema::SITUATIONS, json!({ "category": category, "limit": limit })).await
+    }
+
+    pub async fn situation(&self, id: Uuid) -> Result<Option<Situation>> {
+        self.run(schema::SITUATION, json!({ "id": id })).await
+    }
+
+    /// One page of a situation's dispatches, newest first. An unknown
+    /// situation has none.
+    pub async fn dispatches(&self, situation_id: Uuid, page: PageRequest) -> Result<Vec<Dispatch>> {
+        let situation: Option<SituationDispatches> = self
+            .run(
+                schema::SITUATION_DISPATCHES,
+                json!({ "id": situation_id, "limit": page.limit, "offset": page.offset }),
+            )
+            .await?;
+        Ok(situation.map(|s| s.dispatches).unwrap_or_default())
+    }
+
+    /// Every dispatch for a situation, fetched a page at a time.
+    pub async fn all_dispatches(&self, situation_id: Uuid) -> Result<Vec<Dispatch>> {
+        collect_pages(MAX_PAGE_SIZE, |page| self.dispatches(situation_id, page)).await
+    }
+
+    // ========== Submissions ==========
+
+    /// Suggest a source for the scout to follow. Rate-limited per client IP.
+    pub async fn submit_source(&self, url: &str) -> Result<SubmitResult> {
+        self.run(schema::SUBMIT_SOURCE, json!({ "url": url })).await
+    }
+
+    // ========== REST ==========
+
+    /// `Ok` when the API answers its health check.
+    pub async fn health(&self) -> Result<()> {
+        self.get("/", &[]).await?;
+        Ok(())
+    }
+
+    /// A situation's dispatches as an Atom feed.
+    pub async fn situation_feed(&self, situation_id: Uuid) -> Result<String> {
+        let resp = self.get(&format!("/situations/{situation_id}/feed.atom"), &[]).await?;
+        Ok(resp.text().await?)
+    }
+
+    pub async fn link_preview(&self, url: &str) -> Result<LinkPreview> {
+        let resp = self.get("/api/link-preview", &[("url", url)]).await?;
+        Ok(resp.json().await?)
+    }
+
+    // ========== Raw access ==========
+
+    /// Run any GraphQL document, returning its `data`.
+    pub async fn graphql<T: DeserializeOwned>(&self, document: &str, variables: Value) -> Result<T> {
+        let resp = self
+            .request(self.http.post(format!("{}/graphql", self.base_url)))
+            .json(&json!({ "query": document, "variables": variables }))
+            .send()
+            .await?;
+        let body: GraphQlResponse = checked(resp).await?.json().await?;
+        if !body.errors.is_empty() {
+            return Err(ClientError::GraphQl(body.errors.into_iter().map(|e| e.message).collect()));
+        }
+        Ok(serde_json::from_value(Value::Object(body.data.unwrap_or_default()))?)
+    }
+
+    async fn run<T: DeserializeOwned>(&self, op: Operation, variables: Value) -> Result<T> {
+        let mut data: serde_json::Map<String, Value> = self.graphql(op.document, variables).await?;
+        Ok(serde_json::from_value(data.remove(op.field).unwrap_or(Value::Null))?)
+    }
+
+    async fn get(&self, path: &str, query: &[(&str, &str)]) -> Result<reqwest::Response> {
+        let resp = self
+            .request(self.http.get(format!("{}{path}", self.base_url)))
+            .query(query)
+            .send()
+            .await?;
+        checked(resp).await
+    }
+
+    fn request(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.api_key {
+            Some(key) => builder.header(API_KEY_HEADER, key),
+            None => builder,
+        }
+    }
+}
+
+fn bounds_variables(bounds: Bounds) -> Value {
+    json!({
+        "minLat": bounds.min_lat,
+        "maxLat": bounds.max_lat,
+        "minLng": bounds.min_lng,
+        "maxLng": bounds.max_lng,
+    })
+}
+
+async fn checked(resp: reqwest::Response) -> Result<reqwest::Response> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+    let message = resp.text().await.unwrap_or_default();
+    Err(ClientError::Api { status: status.as_u16(), message })
+}
@@ -0,0 +1,79 @@
+//! Offset pagination over list fields that take `limit` and `offset`.
+
+use std::future::Future;
+
+use crate::error::Result;
+
+/// The API clamps every page to this many items.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl PageRequest {
+    /// The first page of `limit` items, clamped to [`MAX_PAGE_SIZE`].
+    pub fn first(limit: u32) -> Self {
+        Self { limit: limit.clamp(1, MAX_PAGE_SIZE), offset: 0 }
+    }
+
+    pub fn next(self) -> Self {
+        Self { offset: self.offset + self.limit, ..self }
+    }
+}
+
+/// Fetch pages until one comes back short, returning every item in order.
+pub async fn collect_pages<T, F, Fut>(page_size: u32, mut fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let mut items = Vec::new();
+    let mut page = PageRequest::first(page_size);
+    loop {
+        let batch = fetch(page).await?;
+        let short = batch.len() < page.limit as usize;
+        items.extend(batch);
+        if short {
+            return Ok(items);
+        }
+        page = page.next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn collect_pages_stops_after_a_short_page() {
+        let all: Vec<u32> = (0..25).collect();
+        let requests = RefCell::new(Vec::new());
+
+        let items = collect_pages(10, |page| {
+            requests.borrow_mut().push(page);
+            let start = page.offset as usize;
+            let end = (start + page.limit as usize).min(all.len());
+            let batch = all[start..end].to_vec();
+            async move { Ok(batch) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, all);
+        assert_eq!(
+            requests.into_inner().iter().map(|p| p.offset).collect::<Vec<_>>(),
+            vec![0, 10, 20]
+        );
+    }
+
+    #[test]
+    fn page_size_is_clamped_to_what_the_api_serves() {
+        assert_eq!(PageRequest::first(500).limit, MAX_PAGE_SIZE);
+        assert_eq!(PageRequest::first(0).limit, 1);
+    }
+}
@@ -0,0 +1,469 @@
+//! The GraphQL operations the client sends and the types they decode into.
+//!
+//! This is the one place the client's view of the API lives. The API's test
+//! suite validates every document in [`OPERATIONS`] against its schema, so a
+//! renamed or removed field fails there instead of in a frontend.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// A GraphQL document and the root field its data sits under.
+#[derive(Debug, Clone, Copy)]
+pub struct Operation {
+    pub name: &'static str,
+    pub field: &'static str,
+    pub document: &'static str,
+}
+
+macro_rules! signal_meta {
+    () => {
+        "id title summary sensitivity confidence location { lat lng precision } \
+         locationName sourceUrl extractedAt contentDate causeHeat"
+    };
+}
+
+macro_rules! signal_fields {
+    () => {
+        concat!(
+            " fragment SignalFields on GqlSignal { __typename",
+            " ... on GqlGatheringSignal { ",
+            signal_meta!(),
+            " startsAt endsAt actionUrl organizer isRecurring }",
+            " ... on GqlAidSignal { ",
+            signal_meta!(),
+            " actionUrl availability isOngoing }",
+            " ... on GqlNeedSignal { ",
+            signal_meta!(),
+            " urgency whatNeeded actionUrl goal }",
+            " ... on GqlNoticeSignal { ",
+            signal_meta!(),
+            " severity category effectiveDate sourceAuthority }",
+            " ... on GqlTensionSignal { ",
+            signal_meta!(),
+            " severity category whatWouldHelp }",
+            " }"
+        )
+    };
+}
+
+macro_rules! story_fields {
+    () => {
+        " fragment StoryFields on GqlStory { id headline summary lede signalCount firstSeen \
+         lastUpdated energy velocity centroidLat centroidLng dominantType status arc category }"
+    };
+}
+
+macro_rules! situation_fields {
+    () => {
+        "id headline lede arc temperature clarity centroidLat centroidLng locationName \
+         signalCount tensionCount dispatchCount firstSeen lastUpdated category"
+    };
+}
+
+macro_rules! dispatch_fields {
+    () => {
+        "id situationId body signalIds createdAt dispatchType supersedes"
+    };
+}
+
+pub const SIGNALS_RECENT: Operation = Operation {
+    name: "SignalsRecent",
+    field: "signalsRecent",
+    document: concat!(
+        "query SignalsRecent($limit: Int) { signalsRecent(limit: $limit) { ...SignalFields } }",
+        signal_fields!()
+    ),
+};
+
+pub const SIGNAL: Operation = Operation {
+    name: "Signal",
+    field: "signal",
+    document: concat!(
+        "query Signal($id: UUID!) { signal(id: $id) { ...SignalFields } }",
+        signal_fields!()
+    ),
+};
+
+pub const SIGNALS_IN_BOUNDS: Operation = Operation {
+    name: "SignalsInBounds",
+    field: "signalsInBounds",
+    document: concat!(
+        "query SignalsInBounds($minLat: Float!, $maxLat: Float!, $minLng: Float!, $maxLng: Float!, \
+         $category: String, $limit: Int) { signalsInBounds(minLat: $minLat, maxLat: $maxLat, \
+         minLng: $minLng, maxLng: $maxLng, category: $category, limit: $limit) { ...SignalFields } }",
+        signal_fields!()
+    ),
+};
+
+pub const STORIES_IN_BOUNDS: Operation = Operation {
+    name: "StoriesInBounds",
+    field: "storiesInBounds",
+    document: concat!(
+        "query StoriesInBounds($minLat: Float!, $maxLat: Float!, $minLng: Float!, $maxLng: Float!, \
+         $tag: String, $category: String, $limit: Int) { storiesInBounds(minLat: $minLat, \
+         maxLat: $maxLat, minLng: $minLng, maxLng: $maxLng, tag: $tag, category: $category, \
+         limit: $limit) { ...StoryFields } }",
+        story_fields!()
+    ),
+};
+
+pub const STORY: Operation = Operation {
+    name: "Story",
+    field: "story",
+    document: concat!(
+        "query Story($id: UUID!) { story(id: $id) { ...StoryFields narrative signals { ...SignalFields } } }",
+        story_fields!(),
+        signal_fields!()
+    ),
+};
+
+pub const SITUATIONS: Operation = Operation {
+    name: "Situations",
+    field: "situations",
+    document: concat!(
+        "query Situations($category: String, $limit: Int) { situations(category: $category, limit: $limit) { ",
+        situation_fields!(),
+        " } }"
+    ),
+};
+
+pub const SITUATION: Operation = Operation {
+    name: "Situation",
+    field: "situation",
+    document: concat!(
+        "query Situation($id: UUID!) { situation(id: $id) { ",
+        situation_fields!(),
+        " } }"
+    ),
+};
+
+pub const SITUATION_DISPATCHES: Operation = Operation {
+    name: "SituationDispatches",
+    field: "situation",
+    document: concat!(
+        "query SituationDispatches($id: UUID!, $limit: Int!, $offset: Int!) { situation(id: $id) { \
+         dispatches(limit: $limit, offset: $offset) { ",
+        dispatch_fields!(),
+        " } } }"
+    ),
+};
+
+pub const SUBMIT_SOURCE: Operation = Operation {
+    name: "SubmitSource",
+    field: "submitSource",
+    document: "mutation SubmitSource($url: String!) { submitSource(url: $url) { success sourceId } }",
+};
+
+/// Every operation the client sends.
+pub const OPERATIONS: &[Operation] = &[
+    SIGNALS_RECENT,
+    SIGNAL,
+    SIGNALS_IN_BOUNDS,
+    STORIES_IN_BOUNDS,
+    STORY,
+    SITUATIONS,
+    SITUATION,
+    SITUATION_DISPATCHES,
+    SUBMIT_SOURCE,
+];
+
+// --- Enums ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Sensitivity {
+    General,
+    Elevated,
+    Sensitive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GeoPrecision {
+    Exact,
+    Neighborhood,
+    Approximate,
+    Region,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Urgency {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SituationArc {
+    Emerging,
+    Developing,
+    Active,
+    Cooling,
+    Cold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Clarity {
+    Fuzzy,
+    Sharpening,
+    Sharp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DispatchType {
+    Update,
+    Emergence,
+    Split,
+    Merge,
+    Reactivation,
+    Correction,
+}
+
+// --- Signals ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lng: f64,
+    pub precision: GeoPrecision,
+}
+
+/// Fields every signal type shares.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalMeta {
+    pub id: Uuid,
+    pub title: String,
+    pub summary: String,
+    pub sensitivity: Sensitivity,
+    pub confidence: f32,
+    pub location: Option<GeoPoint>,
+    pub location_name: Option<String>,
+    pub source_url: String,
+    pub extracted_at: DateTime<Utc>,
+    pub content_date: Option<DateTime<Utc>>,
+    pub cause_heat: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "__typename")]
+pub enum Signal {
+    #[serde(rename = "GqlGatheringSignal")]
+    Gathering(Gathering),
+    #[serde(rename = "GqlAidSignal")]
+    Aid(Aid),
+    #[serde(rename = "GqlNeedSignal")]
+    Need(Need),
+    #[serde(rename = "GqlNoticeSignal")]
+    Notice(Notice),
+    #[serde(rename = "GqlTensionSignal")]
+    Tension(Tension),
+}
+
+impl Signal {
+    pub fn meta(&self) -> &SignalMeta {
+        match self {
+            Signal::Gathering(s) => &s.meta,
+            Signal::Aid(s) => &s.meta,
+            Signal::Need(s) => &s.meta,
+            Signal::Notice(s) => &s.meta,
+            Signal::Tension(s) => &s.meta,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gathering {
+    #[serde(flatten)]
+    pub meta: SignalMeta,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub action_url: String,
+    pub organizer: Option<String>,
+    pub is_recurring: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Aid {
+    #[serde(flatten)]
+    pub meta: SignalMeta,
+    pub action_url: String,
+    pub availability: Option<String>,
+    pub is_ongoing: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Need {
+    #[serde(flatten)]
+    pub meta: SignalMeta,
+    pub urgency: Urgency,
+    pub what_needed: Option<String>,
+    pub action_url: Option<String>,
+    pub goal: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notice {
+    #[serde(flatten)]
+    pub meta: SignalMeta,
+    pub severity: Severity,
+    pub category: Option<String>,
+    pub effective_date: Option<DateTime<Utc>>,
+    pub source_authority: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tension {
+    #[serde(flatten)]
+    pub meta: SignalMeta,
+    pub severity: Severity,
+    pub category: Option<String>,
+    pub what_would_help: Option<String>,
+}
+
+// --- Stories and situations ---
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Story {
+    pub id: Uuid,
+    pub headline: String,
+    pub summary: String,
+    pub lede: Option<String>,
+    pub signal_count: u32,
+    pub first_seen: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+    pub energy: f64,
+    pub velocity: f64,
+    pub centroid_lat: Option<f64>,
+    pub centroid_lng: Option<f64>,
+    pub dominant_type: String,
+    pub status: String,
+    pub arc: Option<String>,
+    pub category: Option<String>,
+    /// Only fetched for a single story.
+    #[serde(default)]
+    pub narrative: Option<String>,
+    /// Only fetched for a single story.
+    #[serde(default)]
+    pub signals: Vec<Signal>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Situation {
+    pub id: Uuid,
+    pub headline: String,
+    pub lede: String,
+    pub arc: SituationArc,
+    pub temperature: f64,
+    pub clarity: Clarity,
+    pub centroid_lat: Option<f64>,
+    pub centroid_lng: Option<f64>,
+    pub location_name: Option<String>,
+    pub signal_count: u32,
+    pub tension_count: u32,
+    pub dispatch_count: u32,
+    pub first_seen: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dispatch {
+    pub id: Uuid,
+    pub situation_id: Uuid,
+    pub body: String,
+    pub signal_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub dispatch_type: DispatchType,
+    pub supersedes: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitResult {
+    pub success: bool,
+    pub source_id: Option<String>,
+}
+
+// --- REST ---
+
+/// `GET /api/link-preview` response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinkPreview {
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub site_name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signal_variant_follows_typename() {
+        let json = serde_json::json!({
+            "__typename": "GqlNeedSignal",
+            "id": "6f1c7a52-5f0e-4b8e-9a57-2a7f0a3c1d10",
+            "title": "Winter coats needed",
+            "summary": "The shelter is short on adult coats.",
+            "sensitivity": "GENERAL",
+            "confidence": 0.8,
+            "location": { "lat": 44.95, "lng": -93.1, "precision": "NEIGHBORHOOD" },
+            "locationName": "Frogtown",
+            "sourceUrl": "https://example.org/coats",
+            "extractedAt": "2026-01-05T12:00:00Z",
+            "contentDate": null,
+            "causeHeat": 0.4,
+            "urgency": "HIGH",
+            "whatNeeded": "Adult coats",
+            "actionUrl": null,
+            "goal": null
+        });
+
+        let signal: Signal = serde_json::from_value(json).unwrap();
+
+        let Signal::Need(need) = &signal else {
+            panic!("expected a need, got {signal:?}");
+        };
+        assert_eq!(need.urgency, Urgency::High);
+        assert_eq!(signal.meta().location_name.as_deref(), Some("Frogtown"));
+        assert_eq!(signal.meta().location.unwrap().precision, GeoPrecision::Neighborhood);
+    }
+
+    #[test]
+    fn every_operation_names_itself() {
+        for op in OPERATIONS {
+            let keyword = if op.document.starts_with("mutation") { "mutation" } else { "query" };
+            assert!(
+                op.document.starts_with(&format!("{keyword} {}(", op.name)),
+                "{} document is named differently",
+                op.name
+            );
+        }
+    }
+}
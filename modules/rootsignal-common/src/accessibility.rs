@@ -0,0 +1,259 @@
+//! Accessibility metadata for public signal and situation payloads.
+//!
+//! Alt text for map markers and situation cards is generated at write time
+//! and stored on the node, so every client announces the same thing. It is
+//! built only from fields that are never redacted (type, title, headline,
+//! qualifiers) so it can't leak a hidden location or organizer. Plain-language
+//! summaries come from the LLM that writes the summary and are kept only if
+//! they score at or below `MAX_PLAIN_LANGUAGE_GRADE`.
+
+use crate::types::{Node, Severity, SituationNode, Urgency};
+
+/// Screen-reader guidance: alt text past ~125 characters gets cut off or skipped.
+pub const MAX_ALT_TEXT_CHARS: usize = 125;
+/// ARIA labels are announced on every focus, so they stay short.
+pub const MAX_ARIA_LABEL_CHARS: usize = 80;
+/// Highest Flesch-Kincaid grade a plain-language summary may score.
+pub const MAX_PLAIN_LANGUAGE_GRADE: f64 = 8.0;
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max - 1).collect();
+    format!("{}…", kept.trim_end())
+}
+
+/// What a marker for this signal is, in words a listener recognizes.
+fn signal_kind(node: &Node) -> &'static str {
+    match node {
+        Node::Gathering(_) => "Event",
+        Node::Aid(_) => "Resource",
+        Node::Need(n) if matches!(n.urgency, Urgency::High | Urgency::Critical) => "Urgent need",
+        Node::Need(_) => "Need",
+        Node::Notice(n) if matches!(n.severity, Severity::High | Severity::Critical) => "Important notice",
+        Node::Notice(_) => "Notice",
+        Node::Tension(n) if matches!(n.severity, Severity::High | Severity::Critical) => "Serious community concern",
+        Node::Tension(_) => "Community concern",
+        Node::Evidence(_) => "Source",
+    }
+}
+
+/// Short label for a signal's marker or list item: "Urgent need: Diapers for family shelter".
+pub fn signal_aria_label(node: &Node) -> String {
+    let title = node.meta().map(|m| m.title.as_str()).unwrap_or_default();
+    truncate_chars(&format!("{}: {}", signal_kind(node), title), MAX_ARIA_LABEL_CHARS)
+}
+
+/// Alt text for a signal's map marker: its label plus the one detail that
+/// tells a listener whether it's worth opening.
+pub fn signal_alt_text(node: &Node) -> String {
+    let detail = match node {
+        Node::Gathering(n) if n.is_recurring => Some("Recurring event.".to_string()),
+        Node::Aid(n) if n.is_ongoing => Some("Ongoing.".to_string()),
+        Node::Need(n) => n.what_needed.as_deref().map(|w| format!("Needed: {}.", w.trim_end_matches('.'))),
+        Node::Notice(n) => n.source_authority.as_deref().map(|a| format!("From {a}.")),
+        Node::Tension(n) => n.category.as_deref().map(|c| format!("About {}.", c.replace('_', " "))),
+        _ => None,
+    };
+    let label = signal_aria_label(node);
+    let text = match detail {
+        Some(detail) => format!("{label}. {detail}"),
+        None => label,
+    };
+    truncate_chars(&text, MAX_ALT_TEXT_CHARS)
+}
+
+/// Alt text for a situation card: its headline and where it is.
+pub fn situation_alt_text(situation: &SituationNode) -> String {
+    let text = match situation.location_name.as_deref().filter(|l| !l.is_empty()) {
+        Some(place) => format!("Situation: {} ({place})", situation.headline),
+        None => format!("Situation: {}", situation.headline),
+    };
+    truncate_chars(&text, MAX_ALT_TEXT_CHARS)
+}
+
+/// Short label for a situation card, with its current arc and size.
+pub fn situation_aria_label(situation: &SituationNode) -> String {
+    let signals = match situation.signal_count {
+        1 => "1 signal".to_string(),
+        n => format!("{n} signals"),
+    };
+    let suffix = format!(", {}, {signals}", situation.arc);
+    let max_headline = MAX_ARIA_LABEL_CHARS.saturating_sub(suffix.chars().count() + "Situation: ".len());
+    format!("Situation: {}{suffix}", truncate_chars(&situation.headline, max_headline.max(10)))
+}
+
+fn syllables(word: &str) -> usize {
+    let word: Vec<char> = word.to_lowercase().chars().filter(|c| c.is_alphabetic()).collect();
+    if word.is_empty() {
+        return 0;
+    }
+    let is_vowel = |c: char| "aeiouy".contains(c);
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for &c in &word {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    // A trailing silent "e" ("home", "make"), but not "-le" ("table").
+    let n = word.len();
+    if count > 1 && word[n - 1] == 'e' && !(n > 2 && word[n - 2] == 'l' && !is_vowel(word[n - 3])) {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+/// Flesch-Kincaid grade level of `text`. Empty text scores 0.
+pub fn reading_grade(text: &str) -> f64 {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+    let sentences = text
+        .split(['.', '!', '?'])
+        .filter(|s| s.chars().any(char::is_alphanumeric))
+        .count()
+        .max(1);
+    let syllable_count: usize = words.iter().map(|w| syllables(w)).sum();
+    let grade = 0.39 * (words.len() as f64 / sentences as f64)
+        + 11.8 * (syllable_count as f64 / words.len() as f64)
+        - 15.59;
+    grade.max(0.0)
+}
+
+/// Keep a plain-language summary only if it reads at or below
+/// `MAX_PLAIN_LANGUAGE_GRADE`; one that doesn't is worse than none.
+pub fn accept_plain_summary(candidate: Option<&str>) -> Option<String> {
+    let text = candidate?.trim();
+    if text.is_empty() || reading_grade(text) > MAX_PLAIN_LANGUAGE_GRADE {
+        return None;
+    }
+    Some(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::safety::SensitivityLevel;
+    use crate::types::{Clarity, NeedNode, NodeMeta, SituationArc};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn need(title: &str, urgency: Urgency, what_needed: Option<&str>) -> Node {
+        Node::Need(NeedNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: title.to_string(),
+                summary: String::new(),
+                sensitivity: SensitivityLevel::General,
+                confidence: 0.8,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: None,
+                about_location_name: Some("2400 Chicago Ave".to_string()),
+                from_location: None,
+                source_url: "https://example.org/needs".to_string(),
+                extracted_at: Utc::now(),
+                content_date: None,
+                last_confirmed_active: Utc::now(),
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat: 0.0,
+                implied_queries: vec![],
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
+            },
+            urgency,
+            what_needed: what_needed.map(str::to_string),
+            action_url: None,
+            goal: None,
+            fundraiser: None,
+        })
+    }
+
+    fn situation(headline: &str, location_name: Option<&str>) -> SituationNode {
+        SituationNode {
+            id: Uuid::new_v4(),
+            headline: headline.to_string(),
+            lede: String::new(),
+            arc: SituationArc::Developing,
+            temperature: 0.5,
+            tension_heat: 0.0,
+            entity_velocity: 0.0,
+            amplification: 0.0,
+            response_coverage: 0.0,
+            clarity_need: 0.0,
+            clarity: Clarity::Sharpening,
+            centroid_lat: None,
+            centroid_lng: None,
+            location_name: location_name.map(str::to_string),
+            structured_state: "{}".to_string(),
+            signal_count: 7,
+            tension_count: 2,
+            dispatch_count: 1,
+            first_seen: Utc::now(),
+            last_updated: Utc::now(),
+            sensitivity: SensitivityLevel::General,
+            category: None,
+            demographics: None,
+            escalation: None,
+            alt_text: None,
+            plain_summary: None,
+        }
+    }
+
+    #[test]
+    fn marker_alt_text_names_the_kind_and_detail_but_never_the_place() {
+        let node = need("Diapers for family shelter", Urgency::High, Some("size 4 and 5 diapers."));
+
+        let alt = signal_alt_text(&node);
+
+        assert_eq!(alt, "Urgent need: Diapers for family shelter. Needed: size 4 and 5 diapers.");
+        assert!(!alt.contains("Chicago Ave"));
+    }
+
+    #[test]
+    fn long_titles_are_cut_to_screen_reader_lengths() {
+        let node = need(&"Volunteers needed ".repeat(12), Urgency::Low, None);
+
+        assert!(signal_aria_label(&node).chars().count() <= MAX_ARIA_LABEL_CHARS);
+        assert!(signal_alt_text(&node).chars().count() <= MAX_ALT_TEXT_CHARS);
+        assert!(signal_alt_text(&node).ends_with('…'));
+    }
+
+    #[test]
+    fn situation_card_text_carries_headline_place_and_arc() {
+        let situation = situation("Evictions rise at Riverside Towers", Some("Cedar-Riverside"));
+
+        assert_eq!(situation_alt_text(&situation), "Situation: Evictions rise at Riverside Towers (Cedar-Riverside)");
+        assert_eq!(
+            situation_aria_label(&situation),
+            "Situation: Evictions rise at Riverside Towers, developing, 7 signals"
+        );
+    }
+
+    #[test]
+    fn plain_summaries_above_the_grade_cap_are_dropped() {
+        let plain = "The food shelf is open on Tuesday. You can get free food. Bring a bag.";
+        let dense = "Municipal authorities announced comprehensive infrastructural rehabilitation \
+                     necessitating temporary discontinuation of residential water availability.";
+
+        assert!(reading_grade(plain) < 4.0);
+        assert!(reading_grade(dense) > MAX_PLAIN_LANGUAGE_GRADE);
+        assert_eq!(accept_plain_summary(Some(plain)).as_deref(), Some(plain));
+        assert_eq!(accept_plain_summary(Some(dense)), None);
+        assert_eq!(accept_plain_summary(Some("  ")), None);
+    }
+}
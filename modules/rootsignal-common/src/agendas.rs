@@ -0,0 +1,191 @@
+//! Per-region government meeting agendas (Legistar).
+//!
+//! Council and commission agendas announce decisions weeks before they
+//! become tensions. Each region lists the public bodies it follows, where
+//! they meet, and which agenda topics are high-impact enough to investigate
+//! as soon as they're posted.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+fn default_lookahead_days() -> u32 {
+    14
+}
+
+fn default_lookback_days() -> u32 {
+    14
+}
+
+/// Topics that flag an agenda item for investigation when they appear in
+/// its title or type.
+pub fn default_high_impact_topics() -> Vec<String> {
+    ["budget", "zoning", "rezoning", "levy", "appropriation", "land use", "eminent domain"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+/// A public body whose meetings are published on Legistar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgendaBody {
+    /// Human-readable name, used as the notice's source authority
+    /// ("Minneapolis City Council").
+    pub name: String,
+    /// Legistar client, as in `webapi.legistar.com/v1/{client}` ("minneapolismn").
+    pub legistar_client: String,
+    /// Legistar `EventBodyName` to follow; `None` follows every body of the client.
+    #[serde(default)]
+    pub body_name: Option<String>,
+    /// IANA timezone meeting times are published in.
+    pub timezone: String,
+    /// Where the body usually meets; agenda items are located here.
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Upcoming agendas read this many days ahead.
+    #[serde(default = "default_lookahead_days")]
+    pub lookahead_days: u32,
+    /// Minutes of meetings held within this many days are read.
+    #[serde(default = "default_lookback_days")]
+    pub lookback_days: u32,
+}
+
+impl AgendaBody {
+    pub fn tz(&self) -> Tz {
+        self.timezone.parse().unwrap_or(Tz::UTC)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.legistar_client.trim().is_empty() {
+            return Err(format!("Body '{}' has no Legistar client", self.name));
+        }
+        if self.timezone.parse::<Tz>().is_err() {
+            return Err(format!("Body '{}' has unknown timezone: {}", self.name, self.timezone));
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) || !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(format!("Body '{}' has invalid coordinates", self.name));
+        }
+        if self.lookahead_days == 0 && self.lookback_days == 0 {
+            return Err(format!("Body '{}' reads no meetings: lookahead and lookback are both 0", self.name));
+        }
+        Ok(())
+    }
+}
+
+/// The meeting bodies a region follows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionAgendas {
+    #[serde(default)]
+    pub bodies: Vec<AgendaBody>,
+    #[serde(default = "default_high_impact_topics")]
+    pub high_impact_topics: Vec<String>,
+}
+
+impl Default for RegionAgendas {
+    fn default() -> Self {
+        Self {
+            bodies: Vec::new(),
+            high_impact_topics: default_high_impact_topics(),
+        }
+    }
+}
+
+impl RegionAgendas {
+    pub fn validate(&self) -> Result<(), String> {
+        for body in &self.bodies {
+            body.validate()?;
+        }
+        if self.high_impact_topics.iter().any(|t| t.trim().is_empty()) {
+            return Err("High-impact topics cannot be empty".to_string());
+        }
+        Ok(())
+    }
+
+    /// First high-impact topic the item's title or matter type mentions
+    /// (case-insensitive).
+    pub fn high_impact_topic(&self, item: &AgendaItem) -> Option<&str> {
+        let text = format!("{} {}", item.title, item.matter_type.as_deref().unwrap_or("")).to_lowercase();
+        self.high_impact_topics
+            .iter()
+            .find(|t| text.contains(&t.to_lowercase()))
+            .map(String::as_str)
+    }
+}
+
+/// A meeting read from an agenda portal, with its agenda items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meeting {
+    pub id: String,
+    pub body_name: String,
+    pub starts_at: DateTime<Utc>,
+    /// Room or address as published, when it differs from the body's usual venue.
+    pub location: Option<String>,
+    /// Public meeting page, or the agenda document when there is none.
+    pub url: String,
+    /// Final minutes have been published: item actions are outcomes, not proposals.
+    pub minutes_published: bool,
+    pub items: Vec<AgendaItem>,
+}
+
+/// One substantive agenda item (procedural items are not read).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgendaItem {
+    pub id: String,
+    /// Agenda number as printed ("3.a").
+    pub number: Option<String>,
+    pub title: String,
+    /// Legislation type ("Ordinance", "Resolution", "Budget Amendment").
+    pub matter_type: Option<String>,
+    /// Recorded action once minutes are out ("Adopted", "Referred").
+    pub action: Option<String>,
+    pub note: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, matter_type: Option<&str>) -> AgendaItem {
+        AgendaItem {
+            id: "1".to_string(),
+            number: None,
+            title: title.to_string(),
+            matter_type: matter_type.map(str::to_string),
+            action: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn high_impact_topic_matches_title_or_matter_type() {
+        let agendas = RegionAgendas::default();
+        assert_eq!(
+            agendas.high_impact_topic(&item("Rezoning of 2800 E Lake St to OR2", None)),
+            Some("zoning")
+        );
+        assert_eq!(
+            agendas.high_impact_topic(&item("Public Works amendments", Some("Budget Amendment"))),
+            Some("budget")
+        );
+        assert_eq!(agendas.high_impact_topic(&item("Proclamation: Arbor Day", Some("Resolution"))), None);
+    }
+
+    #[test]
+    fn bodies_with_unknown_timezones_are_rejected() {
+        let mut body = AgendaBody {
+            name: "Minneapolis City Council".to_string(),
+            legistar_client: "minneapolismn".to_string(),
+            body_name: Some("City Council".to_string()),
+            timezone: "America/Chicago".to_string(),
+            location_name: "Minneapolis City Hall".to_string(),
+            latitude: 44.9773,
+            longitude: -93.2655,
+            lookahead_days: default_lookahead_days(),
+            lookback_days: default_lookback_days(),
+        };
+        assert!(body.validate().is_ok());
+        body.timezone = "Central".to_string();
+        assert!(body.validate().is_err());
+    }
+}
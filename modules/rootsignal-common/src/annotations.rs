@@ -0,0 +1,138 @@
+//! Annotations: short notes verified contributors attach to signals.
+//!
+//! Local context often isn't in any source ("that pantry moved last month").
+//! Contributors are members an admin has verified. Their annotations pass the
+//! PII filter, wait in a moderation queue, and once approved are shown
+//! alongside — never merged into — the machine-extracted signal. Reviews move
+//! the contributor's reputation; an approved annotation from a trusted
+//! contributor makes the signal's source due for a re-scrape.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::detect_pii;
+
+pub const MAX_ANNOTATION_CHARS: usize = 500;
+
+/// Annotations one contributor may have waiting for review at once.
+pub const MAX_PENDING_PER_CONTRIBUTOR: u32 = 5;
+
+/// Reputation a newly verified contributor starts with.
+pub const INITIAL_REPUTATION: f64 = 0.5;
+
+/// Reputation at which an approved annotation triggers re-verification.
+pub const REVERIFY_REPUTATION: f64 = 0.8;
+
+const APPROVED_GAIN: f64 = 0.05;
+const REJECTED_LOSS: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl AnnotationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationStatus::Pending => "pending",
+            AnnotationStatus::Approved => "approved",
+            AnnotationStatus::Rejected => "rejected",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(AnnotationStatus::Pending),
+            "approved" => Some(AnnotationStatus::Approved),
+            "rejected" => Some(AnnotationStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contributor {
+    /// Member id (`sub` of the contributor's token).
+    pub member_id: String,
+    /// Shown next to their annotations.
+    pub display_name: String,
+    /// 0.0–1.0, moved by moderation outcomes.
+    pub reputation: f64,
+    pub verified_at: DateTime<Utc>,
+}
+
+impl Contributor {
+    /// Reputation after one of their annotations is reviewed.
+    pub fn reviewed(&self, approved: bool) -> f64 {
+        let delta = if approved { APPROVED_GAIN } else { -REJECTED_LOSS };
+        (self.reputation + delta).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub author_id: String,
+    pub author_name: String,
+    pub body: String,
+    pub status: AnnotationStatus,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    /// Moderator's reason, shown to the author on rejection.
+    pub review_note: Option<String>,
+}
+
+/// Safety filter for an annotation body: length, then PII. Returns the
+/// trimmed body.
+pub fn check_annotation_body(body: &str) -> Result<String, String> {
+    let body = body.trim();
+    if body.chars().count() < 3 {
+        return Err("Annotation is empty".to_string());
+    }
+    if body.chars().count() > MAX_ANNOTATION_CHARS {
+        return Err(format!("Annotation is longer than {MAX_ANNOTATION_CHARS} characters"));
+    }
+    let findings = detect_pii(body);
+    if !findings.is_empty() {
+        return Err(format!("Annotation looks like it contains personal information ({})", findings.join(", ")));
+    }
+    Ok(body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safety_filter_rejects_pii_and_overlong_bodies() {
+        assert_eq!(
+            check_annotation_body("  That pantry moved to the church basement last month. ").as_deref(),
+            Ok("That pantry moved to the church basement last month.")
+        );
+        assert!(check_annotation_body("Call Dana at 612-555-0199").is_err());
+        assert!(check_annotation_body(&"a".repeat(MAX_ANNOTATION_CHARS + 1)).is_err());
+        assert!(check_annotation_body(" ").is_err());
+    }
+
+    #[test]
+    fn reputation_moves_with_reviews_and_stays_in_range() {
+        let mut contributor = Contributor {
+            member_id: "m".to_string(),
+            display_name: "Dana".to_string(),
+            reputation: INITIAL_REPUTATION,
+            verified_at: Utc::now(),
+        };
+        contributor.reputation = contributor.reviewed(false);
+        assert!(contributor.reputation < INITIAL_REPUTATION);
+        for _ in 0..30 {
+            contributor.reputation = contributor.reviewed(true);
+        }
+        assert_eq!(contributor.reputation, 1.0);
+        assert!(contributor.reputation >= REVERIFY_REPUTATION);
+    }
+}
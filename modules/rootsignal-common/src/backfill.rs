@@ -0,0 +1,112 @@
+//! One-time backfill of a newly added source's history.
+//!
+//! A regular scrape only sees a source's latest posts, so a good source
+//! added today contributes nothing from before today. An admin can request a
+//! backfill: one deep scrape of the source's history, up to `max_items`
+//! items no older than `max_age_days`. It runs in a later scout run when the
+//! budget allows. Signals it stores are marked historical — they count as
+//! evidence for situations and corroboration, but not toward "new this
+//! week" stats, trends or alerts.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Default number of items a backfill reads.
+pub const DEFAULT_BACKFILL_ITEMS: u32 = 100;
+
+/// Default age of the oldest item a backfill keeps.
+pub const DEFAULT_BACKFILL_AGE_DAYS: u32 = 365;
+
+/// Largest depth an admin may request.
+pub const MAX_BACKFILL_ITEMS: u32 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackfillStatus {
+    Requested,
+    Completed,
+    Failed,
+}
+
+impl BackfillStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackfillStatus::Requested => "requested",
+            BackfillStatus::Completed => "completed",
+            BackfillStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "requested" => Some(BackfillStatus::Requested),
+            "completed" => Some(BackfillStatus::Completed),
+            "failed" => Some(BackfillStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// How far back a backfill reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackfillDepth {
+    pub max_items: u32,
+    pub max_age_days: u32,
+}
+
+impl Default for BackfillDepth {
+    fn default() -> Self {
+        Self {
+            max_items: DEFAULT_BACKFILL_ITEMS,
+            max_age_days: DEFAULT_BACKFILL_AGE_DAYS,
+        }
+    }
+}
+
+impl BackfillDepth {
+    /// Clamp a requested depth to what a backfill may read.
+    pub fn new(max_items: u32, max_age_days: u32) -> Self {
+        Self {
+            max_items: max_items.clamp(1, MAX_BACKFILL_ITEMS),
+            max_age_days: max_age_days.max(1),
+        }
+    }
+
+    /// Whether an item published at `published_at` is recent enough to keep.
+    /// Undated items are kept; the item limit still bounds them.
+    pub fn keeps(&self, published_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        published_at.is_none_or(|at| at >= now - Duration::days(self.max_age_days as i64))
+    }
+}
+
+/// A source's backfill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Backfill {
+    pub canonical_key: String,
+    /// Region slug whose scout runs it.
+    pub region: String,
+    pub depth: BackfillDepth,
+    pub status: BackfillStatus,
+    pub requested_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Signals stored (and marked historical) by the backfill.
+    pub signals_stored: u32,
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backfill_keeps_items_within_its_age_and_clamps_depth() {
+        let now = Utc::now();
+        let depth = BackfillDepth::new(10_000, 30);
+
+        assert_eq!(depth.max_items, MAX_BACKFILL_ITEMS);
+        assert!(depth.keeps(Some(now - Duration::days(29)), now));
+        assert!(!depth.keeps(Some(now - Duration::days(31)), now));
+        assert!(depth.keeps(None, now));
+        assert_eq!(BackfillStatus::parse("completed"), Some(BackfillStatus::Completed));
+    }
+}
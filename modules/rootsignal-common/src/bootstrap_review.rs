@@ -0,0 +1,209 @@
+//! Review of the sources a cold-start bootstrap proposes for a new region.
+//!
+//! Bootstrap sources come straight from search and LLM suggestions, so some
+//! are junk: content farms, the wrong city, dead pages. Each candidate is
+//! scored on its domain's reputation, how clearly it is about the region, and
+//! (for pages) a sample of its content. Candidates at `AUTO_APPROVE_SCORE` or
+//! above start active; the rest wait, inactive, until an admin approves them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::SourceRole;
+
+/// Candidates scoring at least this start active without review.
+pub const AUTO_APPROVE_SCORE: f64 = 0.7;
+
+/// Characters of sampled content kept for the reviewer.
+pub const SAMPLE_EXCERPT_CHARS: usize = 300;
+
+/// Domains whose pages are rarely original local reporting or organizing.
+const LOW_REPUTATION_DOMAINS: &[&str] = &[
+    "blogspot.com",
+    "wordpress.com",
+    "medium.com",
+    "yelp.com",
+    "tripadvisor.com",
+    "patch.com",
+    "bit.ly",
+    "tinyurl.com",
+];
+
+/// Platforms the bootstrap targets on purpose.
+const KNOWN_PLATFORMS: &[&str] = &[
+    "eventbrite.com",
+    "volunteermatch.org",
+    "gofundme.com",
+    "reddit.com",
+    "linktr.ee",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ReviewStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewStatus::Pending => "pending",
+            ReviewStatus::Approved => "approved",
+            ReviewStatus::Rejected => "rejected",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(ReviewStatus::Pending),
+            "approved" => Some(ReviewStatus::Approved),
+            "rejected" => Some(ReviewStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+/// How a candidate source scored, each part 0.0–1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CandidateScore {
+    pub domain_reputation: f64,
+    pub locality: f64,
+    /// None when nothing was sampled (search queries, unreachable pages).
+    pub content: Option<f64>,
+}
+
+impl CandidateScore {
+    /// Weighted overall score. Without a content sample, reputation and
+    /// locality carry the weight.
+    pub fn total(&self) -> f64 {
+        match self.content {
+            Some(content) => 0.35 * self.domain_reputation + 0.35 * self.locality + 0.3 * content,
+            None => 0.5 * self.domain_reputation + 0.5 * self.locality,
+        }
+    }
+
+    pub fn auto_approves(&self) -> bool {
+        self.total() >= AUTO_APPROVE_SCORE
+    }
+
+    pub fn status(&self) -> ReviewStatus {
+        if self.auto_approves() {
+            ReviewStatus::Approved
+        } else {
+            ReviewStatus::Pending
+        }
+    }
+}
+
+/// A bootstrap-proposed source and its review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BootstrapCandidate {
+    pub region: String,
+    pub canonical_key: String,
+    pub canonical_value: String,
+    pub url: Option<String>,
+    pub source_role: SourceRole,
+    pub score: CandidateScore,
+    pub status: ReviewStatus,
+    /// Start of the sampled content, for the reviewer.
+    pub sample_excerpt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// Reputation of a candidate's domain. `authority` is the domain's link-graph
+/// authority relative to the best-linked domain, when it is in the graph.
+/// Search queries (no domain) are neutral.
+pub fn domain_reputation(domain: Option<&str>, authority: Option<f64>) -> f64 {
+    let Some(domain) = domain.map(|d| d.trim_start_matches("www.").to_lowercase()) else {
+        return 0.6;
+    };
+    let matches = |list: &[&str]| list.iter().any(|d| domain == *d || domain.ends_with(&format!(".{d}")));
+    let base = if domain.ends_with(".gov") || domain.ends_with(".edu") {
+        1.0
+    } else if matches(LOW_REPUTATION_DOMAINS) {
+        0.2
+    } else if matches(KNOWN_PLATFORMS) {
+        0.8
+    } else if domain.ends_with(".org") || domain.ends_with(".us") {
+        0.7
+    } else {
+        0.5
+    };
+    match authority {
+        Some(authority) => (base + authority.clamp(0.0, 1.0) * 0.3).min(1.0),
+        None => base,
+    }
+}
+
+/// How clearly `text` is about `region_name`: 1.0 when it names the region,
+/// otherwise the share of the name's words it contains.
+pub fn locality_match(region_name: &str, text: &str) -> f64 {
+    let text = text.to_lowercase().replace(['+', '-', '_'], " ");
+    let name = region_name.to_lowercase();
+    if text.contains(&name) {
+        return 1.0;
+    }
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 2)
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+    words.iter().filter(|w| text.contains(*w)).count() as f64 / words.len() as f64
+}
+
+/// Score of a page sample: some substance, and about the region.
+pub fn content_score(region_name: &str, sample: &str) -> f64 {
+    let substance = (sample.trim().chars().count() as f64 / 2000.0).min(1.0);
+    0.4 * substance + 0.6 * locality_match(region_name, sample)
+}
+
+/// The start of a sample, for the reviewer.
+pub fn sample_excerpt(sample: &str) -> String {
+    sample.split_whitespace().collect::<Vec<_>>().join(" ").chars().take(SAMPLE_EXCERPT_CHARS).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_civic_page_auto_approves_and_offtopic_blog_waits() {
+        let local_page = "Minneapolis Park and Recreation Board community meetings ".repeat(40);
+        let civic = CandidateScore {
+            domain_reputation: domain_reputation(Some("www.minneapolismn.gov"), None),
+            locality: locality_match("Minneapolis", "https://www.minneapolismn.gov/government/meetings/"),
+            content: Some(content_score("Minneapolis", &local_page)),
+        };
+        let blog = CandidateScore {
+            domain_reputation: domain_reputation(Some("cheap-deals.blogspot.com"), None),
+            locality: locality_match("Minneapolis", "https://cheap-deals.blogspot.com/"),
+            content: Some(content_score("Minneapolis", "Best deals this week!")),
+        };
+
+        assert_eq!(civic.status(), ReviewStatus::Approved);
+        assert_eq!(blog.status(), ReviewStatus::Pending);
+        assert!(blog.total() < 0.3);
+    }
+
+    #[test]
+    fn unsampled_query_is_judged_on_locality() {
+        let local = CandidateScore {
+            domain_reputation: domain_reputation(None, None),
+            locality: locality_match("St. Paul", "site:linktr.ee mutual aid St. Paul"),
+            content: None,
+        };
+        let vague = CandidateScore {
+            domain_reputation: domain_reputation(None, None),
+            locality: locality_match("St. Paul", "community organizations near me"),
+            content: None,
+        };
+
+        assert!(local.auto_approves());
+        assert!(!vague.auto_approves());
+    }
+}
@@ -0,0 +1,341 @@
+//! Per-region scouting calendar: quiet hours, blackout windows, burst windows.
+//!
+//! Cadence alone says *how often* a region is scouted; the calendar says
+//! *when*. Quiet hours are expressed in the region's local time so that
+//! "don't scout 1–5am" stays correct across DST changes.
+//!
+//! Precedence: a blackout always blocks. A burst window lifts quiet hours and
+//! shortens cadence (election night should be scouted at 2am).
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on block-hopping in `next_allowed` (overlapping windows).
+const MAX_HOPS: usize = 64;
+
+/// Daily quiet period in region-local hours. `start_hour > end_hour` wraps
+/// midnight (e.g. 22 → 6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+impl QuietHours {
+    fn contains_hour(&self, hour: u8) -> bool {
+        if self.start_hour <= self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// A fixed span of time during which scouting is suspended (maintenance).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub reason: String,
+}
+
+impl CalendarWindow {
+    pub fn contains(&self, t: DateTime<Utc>) -> bool {
+        t >= self.starts_at && t < self.ends_at
+    }
+}
+
+/// A span around a known event during which sources are scraped more often.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurstWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub reason: String,
+    /// Multiplier applied to source cadence while active (0.25 = 4x as often).
+    pub cadence_factor: f64,
+}
+
+impl BurstWindow {
+    pub fn contains(&self, t: DateTime<Utc>) -> bool {
+        t >= self.starts_at && t < self.ends_at
+    }
+}
+
+/// Why scouting is not allowed at a given moment.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalendarBlock {
+    QuietHours,
+    Blackout { reason: String, until: DateTime<Utc> },
+}
+
+impl fmt::Display for CalendarBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarBlock::QuietHours => write!(f, "quiet hours"),
+            CalendarBlock::Blackout { reason, until } => {
+                write!(f, "blackout until {}: {reason}", until.format("%Y-%m-%d %H:%M UTC"))
+            }
+        }
+    }
+}
+
+/// Scheduling calendar for one region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionCalendar {
+    /// IANA timezone name (e.g. "America/Chicago"). Unknown names fall back to UTC.
+    pub timezone: String,
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+    #[serde(default)]
+    pub blackouts: Vec<CalendarWindow>,
+    #[serde(default)]
+    pub bursts: Vec<BurstWindow>,
+}
+
+impl Default for RegionCalendar {
+    fn default() -> Self {
+        Self {
+            timezone: "UTC".to_string(),
+            quiet_hours: None,
+            blackouts: Vec::new(),
+            bursts: Vec::new(),
+        }
+    }
+}
+
+impl RegionCalendar {
+    /// Check that the timezone, hours and windows are well-formed.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.timezone.parse::<Tz>().is_err() {
+            return Err(format!("Unknown timezone: {}", self.timezone));
+        }
+        if let Some(q) = &self.quiet_hours {
+            if q.start_hour > 23 || q.end_hour > 23 {
+                return Err("Quiet hours must be between 0 and 23".to_string());
+            }
+        }
+        for w in &self.blackouts {
+            if w.ends_at <= w.starts_at {
+                return Err(format!("Blackout '{}' ends before it starts", w.reason));
+            }
+        }
+        for b in &self.bursts {
+            if b.ends_at <= b.starts_at {
+                return Err(format!("Burst '{}' ends before it starts", b.reason));
+            }
+            if !(b.cadence_factor > 0.0 && b.cadence_factor <= 1.0) {
+                return Err(format!("Burst '{}' cadence factor must be in (0, 1]", b.reason));
+            }
+        }
+        Ok(())
+    }
+
+    fn tz(&self) -> Tz {
+        self.timezone.parse().unwrap_or(Tz::UTC)
+    }
+
+    fn in_burst(&self, t: DateTime<Utc>) -> bool {
+        self.bursts.iter().any(|b| b.contains(t))
+    }
+
+    fn in_quiet_hours(&self, t: DateTime<Utc>) -> bool {
+        use chrono::Timelike;
+        match &self.quiet_hours {
+            Some(q) => q.contains_hour(t.with_timezone(&self.tz()).hour() as u8),
+            None => false,
+        }
+    }
+
+    /// The reason scouting is blocked at `t`, if it is.
+    pub fn blocked_at(&self, t: DateTime<Utc>) -> Option<CalendarBlock> {
+        if let Some(w) = self.blackouts.iter().find(|w| w.contains(t)) {
+            return Some(CalendarBlock::Blackout {
+                reason: w.reason.clone(),
+                until: w.ends_at,
+            });
+        }
+        if self.in_quiet_hours(t) && !self.in_burst(t) {
+            return Some(CalendarBlock::QuietHours);
+        }
+        None
+    }
+
+    /// Cadence multiplier at `t`: the strongest active burst, or 1.0.
+    pub fn cadence_factor(&self, t: DateTime<Utc>) -> f64 {
+        self.bursts
+            .iter()
+            .filter(|b| b.contains(t))
+            .map(|b| b.cadence_factor)
+            .fold(1.0, f64::min)
+    }
+
+    /// Earliest moment at or after `t` when scouting is allowed.
+    pub fn next_allowed(&self, t: DateTime<Utc>) -> DateTime<Utc> {
+        let mut at = t;
+        for _ in 0..MAX_HOPS {
+            match self.blocked_at(at) {
+                None => return at,
+                Some(CalendarBlock::Blackout { until, .. }) => at = until,
+                Some(CalendarBlock::QuietHours) => {
+                    // A burst starting mid-quiet-hours lifts the block early.
+                    let quiet_end = self.quiet_end_after(at);
+                    let burst_start = self
+                        .bursts
+                        .iter()
+                        .map(|b| b.starts_at)
+                        .filter(|s| *s > at && *s < quiet_end)
+                        .min();
+                    at = burst_start.unwrap_or(quiet_end);
+                }
+            }
+        }
+        at
+    }
+
+    /// Next local `end_hour:00` strictly after `t`.
+    fn quiet_end_after(&self, t: DateTime<Utc>) -> DateTime<Utc> {
+        let Some(q) = &self.quiet_hours else {
+            return t;
+        };
+        let tz = self.tz();
+        let local = t.with_timezone(&tz);
+        let end = NaiveTime::from_hms_opt(u32::from(q.end_hour), 0, 0).unwrap_or_default();
+        let mut date = local.date_naive();
+        loop {
+            let candidate = date.and_time(end);
+            // DST gaps have no local `end_hour:00`; the following hour does.
+            let resolved = tz
+                .from_local_datetime(&candidate)
+                .earliest()
+                .or_else(|| tz.from_local_datetime(&(candidate + Duration::hours(1))).earliest())
+                .map(|d| d.with_timezone(&Utc));
+            if let Some(r) = resolved.filter(|r| *r > t) {
+                return r;
+            }
+            date = date.succ_opt().unwrap_or(date);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn minneapolis_quiet_1_to_5() -> RegionCalendar {
+        RegionCalendar {
+            timezone: "America/Chicago".into(),
+            quiet_hours: Some(QuietHours { start_hour: 1, end_hour: 5 }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn quiet_hours_apply_in_region_local_time() {
+        let cal = minneapolis_quiet_1_to_5();
+
+        // 08:00 UTC = 03:00 CDT
+        assert_eq!(cal.blocked_at(utc("2026-06-10T08:00:00Z")), Some(CalendarBlock::QuietHours));
+        // 12:00 UTC = 07:00 CDT
+        assert_eq!(cal.blocked_at(utc("2026-06-10T12:00:00Z")), None);
+    }
+
+    #[test]
+    fn quiet_hours_wrapping_midnight_block_both_sides() {
+        let cal = RegionCalendar {
+            quiet_hours: Some(QuietHours { start_hour: 22, end_hour: 6 }),
+            ..Default::default()
+        };
+
+        assert!(cal.blocked_at(utc("2026-06-10T23:00:00Z")).is_some());
+        assert!(cal.blocked_at(utc("2026-06-10T03:00:00Z")).is_some());
+        assert!(cal.blocked_at(utc("2026-06-10T12:00:00Z")).is_none());
+    }
+
+    #[test]
+    fn next_allowed_during_quiet_hours_is_local_end_hour() {
+        let cal = minneapolis_quiet_1_to_5();
+
+        let next = cal.next_allowed(utc("2026-06-10T07:30:00Z"));
+
+        // 05:00 CDT
+        assert_eq!(next, utc("2026-06-10T10:00:00Z"));
+    }
+
+    #[test]
+    fn next_allowed_hops_from_blackout_into_quiet_hours() {
+        let mut cal = minneapolis_quiet_1_to_5();
+        cal.blackouts.push(CalendarWindow {
+            starts_at: utc("2026-06-10T02:00:00Z"),
+            ends_at: utc("2026-06-10T07:00:00Z"), // 02:00 CDT, inside quiet hours
+            reason: "graph upgrade".into(),
+        });
+
+        let next = cal.next_allowed(utc("2026-06-10T03:00:00Z"));
+
+        assert_eq!(next, utc("2026-06-10T10:00:00Z"));
+    }
+
+    #[test]
+    fn burst_window_lifts_quiet_hours_and_shortens_cadence() {
+        let mut cal = minneapolis_quiet_1_to_5();
+        cal.bursts.push(BurstWindow {
+            starts_at: utc("2026-11-03T11:00:00Z"),
+            ends_at: utc("2026-11-04T12:00:00Z"),
+            reason: "election day".into(),
+            cadence_factor: 0.25,
+        });
+        let election_night = utc("2026-11-04T08:00:00Z"); // 02:00 CST
+
+        assert_eq!(cal.blocked_at(election_night), None);
+        assert_eq!(cal.cadence_factor(election_night), 0.25);
+        assert_eq!(cal.cadence_factor(utc("2026-11-10T18:00:00Z")), 1.0);
+    }
+
+    #[test]
+    fn blackout_wins_over_burst() {
+        let at = utc("2026-11-03T18:00:00Z");
+        let cal = RegionCalendar {
+            blackouts: vec![CalendarWindow {
+                starts_at: at - Duration::hours(1),
+                ends_at: at + Duration::hours(1),
+                reason: "maintenance".into(),
+            }],
+            bursts: vec![BurstWindow {
+                starts_at: at - Duration::days(1),
+                ends_at: at + Duration::days(1),
+                reason: "election day".into(),
+                cadence_factor: 0.5,
+            }],
+            ..Default::default()
+        };
+
+        assert!(matches!(cal.blocked_at(at), Some(CalendarBlock::Blackout { .. })));
+    }
+
+    #[test]
+    fn validate_rejects_unknown_timezone_and_inverted_windows() {
+        let bad_tz = RegionCalendar {
+            timezone: "Mars/Olympus_Mons".into(),
+            ..Default::default()
+        };
+        let inverted = RegionCalendar {
+            blackouts: vec![CalendarWindow {
+                starts_at: utc("2026-06-10T10:00:00Z"),
+                ends_at: utc("2026-06-10T09:00:00Z"),
+                reason: "oops".into(),
+            }],
+            ..Default::default()
+        };
+
+        assert!(bad_tz.validate().is_err());
+        assert!(inverted.validate().is_err());
+        assert!(minneapolis_quiet_1_to_5().validate().is_ok());
+    }
+}
@@ -1,20 +1,42 @@
+use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
+
+use anyhow::Context;
+
+use crate::registry::EntityGeometry;
+use crate::secrets::{self, Secret, SecretProvider};
 
 /// Application configuration loaded from environment variables.
+///
+/// Credentials are [`Secret`] handles resolved through the provider selected by
+/// `SECRETS_PROVIDER` (see [`secrets::provider_from_env`]), never raw strings.
 #[derive(Debug, Clone)]
 pub struct Config {
     // Neo4j (bolt protocol via neo4rs driver)
     pub neo4j_uri: String,
     pub neo4j_user: String,
-    pub neo4j_password: String,
+    pub neo4j_password: Secret,
+    /// Regions routed to their own database (`REGION_DATABASES`, e.g.
+    /// "minneapolis=mpls,miami=miami"); unlisted regions use the default one.
+    pub region_databases: HashMap<String, String>,
 
     // AI providers
-    pub anthropic_api_key: String,
-    pub voyage_api_key: String,
+    pub anthropic_api_key: Secret,
+    pub voyage_api_key: Secret,
 
     // Scraping
-    pub serper_api_key: String,
-    pub apify_api_key: String,
+    pub serper_api_key: Secret,
+    pub apify_api_key: Secret,
+
+    // Web search fallbacks. SEARCH_PROVIDERS (comma-separated names) sets the
+    // order; unset = Serper, then every other provider that is configured.
+    // SEARCH_DAILY_LIMITS caps queries per UTC day, e.g. "serper=2500,brave=2000".
+    pub tavily_api_key: Option<Secret>,
+    pub brave_search_api_key: Option<Secret>,
+    pub searxng_url: Option<String>,
+    pub search_providers: Vec<String>,
+    pub search_daily_limits: HashMap<String, u32>,
 
     // Web server
     pub web_host: String,
@@ -22,7 +44,7 @@ pub struct Config {
 
     // Admin
     pub admin_username: String,
-    pub admin_password: String,
+    pub admin_password: Secret,
 
     // Region
     pub region: String,
@@ -32,6 +54,8 @@ pub struct Config {
     pub region_lat: Option<f64>,
     pub region_lng: Option<f64>,
     pub region_radius_km: Option<f64>,
+    /// GeoJSON file with the region's boundary, for regions a circle fits badly.
+    pub region_boundary_path: Option<String>,
 
     // Budget
     /// Daily budget limit in cents. 0 = unlimited.
@@ -39,141 +63,295 @@ pub struct Config {
 
     // Browserless (optional headless browser service)
     pub browserless_url: Option<String>,
-    pub browserless_token: Option<String>,
+    pub browserless_token: Option<Secret>,
+
+    // Per-source credentials (base64 32-byte AES key). Unset = no gated sources.
+    pub source_credentials_key: Option<Secret>,
+
+    // Speech-to-text for podcasts and uncaptioned videos. A local `whisper`
+    // binary takes precedence over the hosted API; neither = captions only.
+    pub openai_api_key: Option<Secret>,
+    pub whisper_bin: Option<String>,
+    pub whisper_model: String,
+
+    // Eventbrite API private token for organizer event listings.
+    pub eventbrite_token: Option<Secret>,
+
+    // Socrata app token for open-data (311) datasets. Unset = anonymous,
+    // rate-limited requests.
+    pub socrata_app_token: Option<Secret>,
+
+    // Blob store for page HTML, long document text and long transcripts: an
+    // S3-compatible bucket if S3_BUCKET is set, else a local directory, else
+    // Postgres only. Blobs move to the cold storage class after
+    // `blob_cold_after_days` (0 = never).
+    pub blob_store_path: Option<String>,
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    pub s3_access_key_id: Option<Secret>,
+    pub s3_secret_access_key: Option<Secret>,
+    pub s3_cold_storage_class: String,
+    pub blob_cold_after_days: u32,
+
+    // Newsletter ingestion: the address the scout subscribes with, and the
+    // shared secret the email provider's webhook must present.
+    pub inbound_email_address: Option<String>,
+    pub inbound_email_secret: Option<Secret>,
+
+    // Apify run completion webhooks: the public URL of the API's receiver
+    // and the secret it checks. Without both, scouts long-poll Apify runs.
+    pub apify_webhook_url: Option<String>,
+    pub apify_webhook_secret: Option<Secret>,
 
     // Scout tuning
     /// Max web queries per scout run. Defaults to 50.
     pub max_web_queries_per_run: usize,
+    /// Priority languages (`SUMMARY_LANGUAGES`, e.g. "es,so,hmn") that public
+    /// summaries of high-visibility signals and situations are translated into.
+    pub summary_languages: Vec<String>,
 
     // Data directory for run logs
     pub data_dir: std::path::PathBuf,
 
     // Twilio (for admin OTP auth)
     pub twilio_account_sid: String,
-    pub twilio_auth_token: String,
+    pub twilio_auth_token: Secret,
     pub twilio_service_id: String,
 
     // Admin phone numbers (E.164) allowed to authenticate
     pub admin_numbers: Vec<String>,
 
+    /// SHA-256 hex digests of API keys for this deployment (`API_KEY_HASHES`),
+    /// e.g. for polling triggers. Tenant keys live in the tenants file.
+    pub api_key_hashes: Vec<String>,
+
     // Session signing secret (separate from admin_password)
-    pub session_secret: String,
+    pub session_secret: Secret,
 }
 
 impl Config {
     /// Load configuration from environment variables.
     /// Panics with a clear message if required vars are missing.
     pub fn from_env() -> Self {
+        let provider = secrets_provider();
         Self {
             neo4j_uri: required_env("NEO4J_URI"),
             neo4j_user: required_env("NEO4J_USER"),
-            neo4j_password: required_env("NEO4J_PASSWORD"),
-            anthropic_api_key: required_env("ANTHROPIC_API_KEY"),
-            voyage_api_key: required_env("VOYAGE_API_KEY"),
-            serper_api_key: required_env("SERPER_API_KEY"),
-            apify_api_key: env::var("APIFY_API_KEY").unwrap_or_default(),
+            neo4j_password: required_secret(&provider, "NEO4J_PASSWORD"),
+            region_databases: region_databases_env(),
+            anthropic_api_key: required_secret(&provider, "ANTHROPIC_API_KEY"),
+            voyage_api_key: required_secret(&provider, "VOYAGE_API_KEY"),
+            serper_api_key: required_secret(&provider, "SERPER_API_KEY"),
+            apify_api_key: Secret::new("APIFY_API_KEY", provider.clone()),
+            tavily_api_key: optional_secret(&provider, "TAVILY_API_KEY"),
+            brave_search_api_key: optional_secret(&provider, "BRAVE_SEARCH_API_KEY"),
+            searxng_url: env::var("SEARXNG_URL").ok().filter(|s| !s.is_empty()),
+            search_providers: list_env("SEARCH_PROVIDERS"),
+            search_daily_limits: search_daily_limits_env(),
             web_host: env::var("WEB_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
             web_port: env::var("WEB_PORT")
                 .unwrap_or_else(|_| "3000".to_string())
                 .parse()
                 .expect("WEB_PORT must be a number"),
             admin_username: env::var("ADMIN_USERNAME").unwrap_or_else(|_| "admin".to_string()),
-            admin_password: required_env("ADMIN_PASSWORD"),
-            session_secret: String::new(),
+            admin_password: required_secret(&provider, "ADMIN_PASSWORD"),
+            session_secret: Secret::empty(),
             region: String::new(),
             region_name: None,
             region_lat: None,
             region_lng: None,
             region_radius_km: None,
+            region_boundary_path: None,
             daily_budget_cents: 0,
             browserless_url: env::var("BROWSERLESS_URL").ok(),
-            browserless_token: env::var("BROWSERLESS_TOKEN").ok(),
+            browserless_token: optional_secret(&provider, "BROWSERLESS_TOKEN"),
+            source_credentials_key: optional_secret(&provider, "SOURCE_CREDENTIALS_KEY"),
+            openai_api_key: optional_secret(&provider, "OPENAI_API_KEY"),
+            whisper_bin: env::var("WHISPER_BIN").ok().filter(|s| !s.is_empty()),
+            whisper_model: env::var("WHISPER_MODEL").unwrap_or_else(|_| "base".to_string()),
+            eventbrite_token: optional_secret(&provider, "EVENTBRITE_TOKEN"),
+            socrata_app_token: optional_secret(&provider, "SOCRATA_APP_TOKEN"),
+            blob_store_path: env::var("BLOB_STORE_PATH").ok().filter(|s| !s.is_empty()),
+            s3_endpoint: env::var("S3_ENDPOINT").ok().filter(|s| !s.is_empty()),
+            s3_bucket: env::var("S3_BUCKET").ok().filter(|s| !s.is_empty()),
+            s3_region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            s3_access_key_id: optional_secret(&provider, "S3_ACCESS_KEY_ID"),
+            s3_secret_access_key: optional_secret(&provider, "S3_SECRET_ACCESS_KEY"),
+            s3_cold_storage_class: env::var("S3_COLD_STORAGE_CLASS").unwrap_or_else(|_| "GLACIER_IR".to_string()),
+            blob_cold_after_days: env::var("BLOB_COLD_AFTER_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            inbound_email_address: env::var("INBOUND_EMAIL_ADDRESS").ok().filter(|s| !s.is_empty()),
+            inbound_email_secret: optional_secret(&provider, "INBOUND_EMAIL_SECRET"),
+            apify_webhook_url: env::var("APIFY_WEBHOOK_URL").ok().filter(|s| !s.is_empty()),
+            apify_webhook_secret: optional_secret(&provider, "APIFY_WEBHOOK_SECRET"),
             max_web_queries_per_run: env::var("MAX_WEB_QUERIES_PER_RUN")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(50),
+            summary_languages: list_env("SUMMARY_LANGUAGES"),
             data_dir: std::path::PathBuf::from(
                 env::var("DATA_DIR").unwrap_or_else(|_| "data".to_string()),
             ),
             twilio_account_sid: String::new(),
-            twilio_auth_token: String::new(),
+            twilio_auth_token: Secret::empty(),
             twilio_service_id: String::new(),
             admin_numbers: Vec::new(),
+            api_key_hashes: list_env("API_KEY_HASHES"),
         }
     }
 
     /// Load config for scout (no web server or admin fields needed).
     pub fn scout_from_env() -> Self {
+        Self::scout_env(true)
+    }
+
+    /// Load config for the admin CLI: the scout's settings, but only the
+    /// Neo4j credentials are required up front. AI and search keys resolve
+    /// when a subcommand first uses them.
+    pub fn admin_from_env() -> Self {
+        Self::scout_env(false)
+    }
+
+    fn scout_env(require_ai_keys: bool) -> Self {
+        let provider = secrets_provider();
+        let ai_key = |key| {
+            if require_ai_keys {
+                required_secret(&provider, key)
+            } else {
+                Secret::new(key, provider.clone())
+            }
+        };
         Self {
             neo4j_uri: required_env("NEO4J_URI"),
             neo4j_user: required_env("NEO4J_USER"),
-            neo4j_password: required_env("NEO4J_PASSWORD"),
-            anthropic_api_key: required_env("ANTHROPIC_API_KEY"),
-            voyage_api_key: required_env("VOYAGE_API_KEY"),
-            serper_api_key: required_env("SERPER_API_KEY"),
-            apify_api_key: env::var("APIFY_API_KEY").unwrap_or_default(),
+            neo4j_password: required_secret(&provider, "NEO4J_PASSWORD"),
+            region_databases: region_databases_env(),
+            anthropic_api_key: ai_key("ANTHROPIC_API_KEY"),
+            voyage_api_key: ai_key("VOYAGE_API_KEY"),
+            serper_api_key: ai_key("SERPER_API_KEY"),
+            apify_api_key: Secret::new("APIFY_API_KEY", provider.clone()),
+            tavily_api_key: optional_secret(&provider, "TAVILY_API_KEY"),
+            brave_search_api_key: optional_secret(&provider, "BRAVE_SEARCH_API_KEY"),
+            searxng_url: env::var("SEARXNG_URL").ok().filter(|s| !s.is_empty()),
+            search_providers: list_env("SEARCH_PROVIDERS"),
+            search_daily_limits: search_daily_limits_env(),
             web_host: String::new(),
             web_port: 0,
             admin_username: String::new(),
-            admin_password: String::new(),
-            session_secret: String::new(),
+            admin_password: Secret::empty(),
+            session_secret: Secret::empty(),
             region: env::var("REGION").or_else(|_| env::var("CITY")).unwrap_or_else(|_| "twincities".to_string()),
             region_name: env::var("REGION_NAME").or_else(|_| env::var("CITY_NAME")).ok(),
             region_lat: env::var("REGION_LAT").or_else(|_| env::var("CITY_LAT")).ok().and_then(|v| v.parse().ok()),
             region_lng: env::var("REGION_LNG").or_else(|_| env::var("CITY_LNG")).ok().and_then(|v| v.parse().ok()),
             region_radius_km: env::var("REGION_RADIUS_KM").or_else(|_| env::var("CITY_RADIUS_KM")).ok().and_then(|v| v.parse().ok()),
+            region_boundary_path: env::var("REGION_BOUNDARY_PATH").ok().filter(|s| !s.is_empty()),
             daily_budget_cents: env::var("DAILY_BUDGET_CENTS")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(0),
             browserless_url: env::var("BROWSERLESS_URL").ok(),
-            browserless_token: env::var("BROWSERLESS_TOKEN").ok(),
+            browserless_token: optional_secret(&provider, "BROWSERLESS_TOKEN"),
+            source_credentials_key: optional_secret(&provider, "SOURCE_CREDENTIALS_KEY"),
+            openai_api_key: optional_secret(&provider, "OPENAI_API_KEY"),
+            whisper_bin: env::var("WHISPER_BIN").ok().filter(|s| !s.is_empty()),
+            whisper_model: env::var("WHISPER_MODEL").unwrap_or_else(|_| "base".to_string()),
+            eventbrite_token: optional_secret(&provider, "EVENTBRITE_TOKEN"),
+            socrata_app_token: optional_secret(&provider, "SOCRATA_APP_TOKEN"),
+            blob_store_path: env::var("BLOB_STORE_PATH").ok().filter(|s| !s.is_empty()),
+            s3_endpoint: env::var("S3_ENDPOINT").ok().filter(|s| !s.is_empty()),
+            s3_bucket: env::var("S3_BUCKET").ok().filter(|s| !s.is_empty()),
+            s3_region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            s3_access_key_id: optional_secret(&provider, "S3_ACCESS_KEY_ID"),
+            s3_secret_access_key: optional_secret(&provider, "S3_SECRET_ACCESS_KEY"),
+            s3_cold_storage_class: env::var("S3_COLD_STORAGE_CLASS").unwrap_or_else(|_| "GLACIER_IR".to_string()),
+            blob_cold_after_days: env::var("BLOB_COLD_AFTER_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            inbound_email_address: None,
+            inbound_email_secret: None,
+            apify_webhook_url: env::var("APIFY_WEBHOOK_URL").ok().filter(|s| !s.is_empty()),
+            apify_webhook_secret: optional_secret(&provider, "APIFY_WEBHOOK_SECRET"),
             max_web_queries_per_run: env::var("MAX_WEB_QUERIES_PER_RUN")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(50),
+            summary_languages: list_env("SUMMARY_LANGUAGES"),
             data_dir: std::path::PathBuf::from(
                 env::var("DATA_DIR").unwrap_or_else(|_| "data".to_string()),
             ),
             twilio_account_sid: String::new(),
-            twilio_auth_token: String::new(),
+            twilio_auth_token: Secret::empty(),
             twilio_service_id: String::new(),
             admin_numbers: Vec::new(),
+            api_key_hashes: Vec::new(),
         }
     }
 
     /// Load config for the scout supervisor (Neo4j + Anthropic + region + notifications).
     pub fn supervisor_from_env() -> Self {
+        let provider = secrets_provider();
         Self {
             neo4j_uri: required_env("NEO4J_URI"),
             neo4j_user: required_env("NEO4J_USER"),
-            neo4j_password: required_env("NEO4J_PASSWORD"),
-            anthropic_api_key: required_env("ANTHROPIC_API_KEY"),
-            voyage_api_key: String::new(),
-            serper_api_key: String::new(),
-            apify_api_key: String::new(),
+            neo4j_password: required_secret(&provider, "NEO4J_PASSWORD"),
+            region_databases: region_databases_env(),
+            anthropic_api_key: required_secret(&provider, "ANTHROPIC_API_KEY"),
+            voyage_api_key: Secret::empty(),
+            serper_api_key: Secret::empty(),
+            apify_api_key: Secret::empty(),
+            tavily_api_key: None,
+            brave_search_api_key: None,
+            searxng_url: None,
+            search_providers: Vec::new(),
+            search_daily_limits: HashMap::new(),
             web_host: String::new(),
             web_port: 0,
             admin_username: String::new(),
-            admin_password: String::new(),
-            session_secret: String::new(),
+            admin_password: Secret::empty(),
+            session_secret: Secret::empty(),
             region: env::var("REGION").or_else(|_| env::var("CITY")).unwrap_or_else(|_| "twincities".to_string()),
             region_name: None,
             region_lat: None,
             region_lng: None,
             region_radius_km: None,
+            region_boundary_path: None,
             daily_budget_cents: env::var("SUPERVISOR_DAILY_BUDGET_CENTS")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(100),
             browserless_url: None,
             browserless_token: None,
+            source_credentials_key: None,
+            openai_api_key: None,
+            whisper_bin: None,
+            whisper_model: String::new(),
+            eventbrite_token: None,
+            socrata_app_token: None,
+            blob_store_path: None,
+            s3_endpoint: None,
+            s3_bucket: None,
+            s3_region: String::new(),
+            s3_access_key_id: None,
+            s3_secret_access_key: None,
+            s3_cold_storage_class: String::new(),
+            blob_cold_after_days: 0,
+            inbound_email_address: None,
+            inbound_email_secret: None,
+            apify_webhook_url: None,
+            apify_webhook_secret: None,
             max_web_queries_per_run: 50,
+            summary_languages: Vec::new(),
             data_dir: std::path::PathBuf::from("data"),
             twilio_account_sid: String::new(),
-            twilio_auth_token: String::new(),
+            twilio_auth_token: Secret::empty(),
             twilio_service_id: String::new(),
             admin_numbers: Vec::new(),
+            api_key_hashes: Vec::new(),
         }
     }
 
@@ -187,55 +365,99 @@ impl Config {
             .filter(|s| !s.is_empty())
             .collect();
 
+        let provider = secrets_provider();
         Self {
             neo4j_uri: required_env("NEO4J_URI"),
             neo4j_user: required_env("NEO4J_USER"),
-            neo4j_password: required_env("NEO4J_PASSWORD"),
-            anthropic_api_key: env::var("ANTHROPIC_API_KEY").unwrap_or_default(),
-            voyage_api_key: env::var("VOYAGE_API_KEY").unwrap_or_default(),
-            serper_api_key: env::var("SERPER_API_KEY").unwrap_or_default(),
-            apify_api_key: env::var("APIFY_API_KEY").unwrap_or_default(),
+            neo4j_password: required_secret(&provider, "NEO4J_PASSWORD"),
+            region_databases: region_databases_env(),
+            anthropic_api_key: Secret::new("ANTHROPIC_API_KEY", provider.clone()),
+            voyage_api_key: Secret::new("VOYAGE_API_KEY", provider.clone()),
+            serper_api_key: Secret::new("SERPER_API_KEY", provider.clone()),
+            apify_api_key: Secret::new("APIFY_API_KEY", provider.clone()),
+            tavily_api_key: optional_secret(&provider, "TAVILY_API_KEY"),
+            brave_search_api_key: optional_secret(&provider, "BRAVE_SEARCH_API_KEY"),
+            searxng_url: env::var("SEARXNG_URL").ok().filter(|s| !s.is_empty()),
+            search_providers: list_env("SEARCH_PROVIDERS"),
+            search_daily_limits: search_daily_limits_env(),
             web_host: env::var("WEB_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
             web_port: env::var("WEB_PORT")
                 .unwrap_or_else(|_| "3000".to_string())
                 .parse()
                 .expect("WEB_PORT must be a number"),
             admin_username: env::var("ADMIN_USERNAME").unwrap_or_else(|_| "admin".to_string()),
-            admin_password: required_env("ADMIN_PASSWORD"),
-            session_secret: env::var("SESSION_SECRET").unwrap_or_default(),
+            admin_password: required_secret(&provider, "ADMIN_PASSWORD"),
+            session_secret: Secret::new("SESSION_SECRET", provider.clone()),
             region: env::var("REGION").or_else(|_| env::var("CITY")).unwrap_or_else(|_| "twincities".to_string()),
             region_name: None,
             region_lat: None,
             region_lng: None,
             region_radius_km: None,
+            region_boundary_path: None,
             daily_budget_cents: 0,
             browserless_url: env::var("BROWSERLESS_URL").ok(),
-            browserless_token: env::var("BROWSERLESS_TOKEN").ok(),
+            browserless_token: optional_secret(&provider, "BROWSERLESS_TOKEN"),
+            source_credentials_key: optional_secret(&provider, "SOURCE_CREDENTIALS_KEY"),
+            openai_api_key: optional_secret(&provider, "OPENAI_API_KEY"),
+            whisper_bin: env::var("WHISPER_BIN").ok().filter(|s| !s.is_empty()),
+            whisper_model: env::var("WHISPER_MODEL").unwrap_or_else(|_| "base".to_string()),
+            eventbrite_token: optional_secret(&provider, "EVENTBRITE_TOKEN"),
+            socrata_app_token: optional_secret(&provider, "SOCRATA_APP_TOKEN"),
+            blob_store_path: env::var("BLOB_STORE_PATH").ok().filter(|s| !s.is_empty()),
+            s3_endpoint: env::var("S3_ENDPOINT").ok().filter(|s| !s.is_empty()),
+            s3_bucket: env::var("S3_BUCKET").ok().filter(|s| !s.is_empty()),
+            s3_region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            s3_access_key_id: optional_secret(&provider, "S3_ACCESS_KEY_ID"),
+            s3_secret_access_key: optional_secret(&provider, "S3_SECRET_ACCESS_KEY"),
+            s3_cold_storage_class: env::var("S3_COLD_STORAGE_CLASS").unwrap_or_else(|_| "GLACIER_IR".to_string()),
+            blob_cold_after_days: env::var("BLOB_COLD_AFTER_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            inbound_email_address: env::var("INBOUND_EMAIL_ADDRESS").ok().filter(|s| !s.is_empty()),
+            inbound_email_secret: optional_secret(&provider, "INBOUND_EMAIL_SECRET"),
+            apify_webhook_url: env::var("APIFY_WEBHOOK_URL").ok().filter(|s| !s.is_empty()),
+            apify_webhook_secret: optional_secret(&provider, "APIFY_WEBHOOK_SECRET"),
             max_web_queries_per_run: 50,
+            summary_languages: Vec::new(),
             data_dir: std::path::PathBuf::from(
                 env::var("DATA_DIR").unwrap_or_else(|_| "data".to_string()),
             ),
             twilio_account_sid: env::var("TWILIO_ACCOUNT_SID").unwrap_or_default(),
-            twilio_auth_token: env::var("TWILIO_AUTH_TOKEN").unwrap_or_default(),
+            twilio_auth_token: Secret::new("TWILIO_AUTH_TOKEN", provider.clone()),
             twilio_service_id: env::var("TWILIO_SERVICE_ID").unwrap_or_default(),
             admin_numbers,
+            api_key_hashes: list_env("API_KEY_HASHES"),
         }
     }
 }
 
 impl Config {
-    /// Log the first 8 characters of each sensitive env var for debugging.
+    /// The region boundary from `REGION_BOUNDARY_PATH`, if one is configured.
+    pub fn region_boundary(&self) -> anyhow::Result<Option<EntityGeometry>> {
+        let Some(path) = &self.region_boundary_path else {
+            return Ok(None);
+        };
+        let geojson = std::fs::read_to_string(path).with_context(|| format!("Failed to read region boundary {path}"))?;
+        let value: serde_json::Value =
+            serde_json::from_str(&geojson).with_context(|| format!("Invalid GeoJSON in {path}"))?;
+        let boundary = EntityGeometry::from_geojson(&value).map_err(|e| anyhow::anyhow!("Region boundary {path}: {e}"))?;
+        Ok(Some(boundary))
+    }
+
+    /// Log the length of each sensitive value for debugging — never the value itself.
     pub fn log_redacted(&self) {
-        let vars = [
-            ("NEO4J_URI", &self.neo4j_uri),
-            ("NEO4J_USER", &self.neo4j_user),
+        tracing::info!("NEO4J_URI = ({} chars)", self.neo4j_uri.len());
+        tracing::info!("NEO4J_USER = ({} chars)", self.neo4j_user.len());
+        let secrets = [
             ("NEO4J_PASSWORD", &self.neo4j_password),
             ("ANTHROPIC_API_KEY", &self.anthropic_api_key),
             ("VOYAGE_API_KEY", &self.voyage_api_key),
             ("SERPER_API_KEY", &self.serper_api_key),
             ("APIFY_API_KEY", &self.apify_api_key),
         ];
-        for (name, value) in vars {
+        for (name, secret) in secrets {
+            let value = secret.expose();
             if value.is_empty() {
                 tracing::info!("{name} = (empty)");
             } else {
@@ -245,6 +467,58 @@ impl Config {
     }
 }
 
+/// Comma-separated list, trimmed, lowercased, empties dropped.
+fn list_env(key: &str) -> Vec<String> {
+    env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// `SEARCH_DAILY_LIMITS` as provider → queries per day; malformed entries are ignored.
+fn search_daily_limits_env() -> HashMap<String, u32> {
+    list_env("SEARCH_DAILY_LIMITS")
+        .iter()
+        .filter_map(|entry| {
+            let (name, limit) = entry.split_once('=')?;
+            Some((name.trim().to_string(), limit.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// `REGION_DATABASES` as region → database; malformed entries are ignored.
+fn region_databases_env() -> HashMap<String, String> {
+    list_env("REGION_DATABASES")
+        .iter()
+        .filter_map(|entry| {
+            let (region, db) = entry.split_once('=')?;
+            let (region, db) = (region.trim(), db.trim());
+            (!region.is_empty() && !db.is_empty()).then(|| (region.to_string(), db.to_string()))
+        })
+        .collect()
+}
+
 fn required_env(key: &str) -> String {
     env::var(key).unwrap_or_else(|_| panic!("{key} environment variable is required"))
 }
+
+fn secrets_provider() -> Arc<dyn SecretProvider> {
+    secrets::provider_from_env().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Resolve eagerly so a missing credential still fails fast at startup.
+fn required_secret(provider: &Arc<dyn SecretProvider>, key: &str) -> Secret {
+    let secret = Secret::new(key, provider.clone());
+    match secret.try_expose() {
+        Ok(Some(_)) => secret,
+        Ok(None) => panic!("{key} secret is required (env var, {key}_FILE, or secrets provider)"),
+        Err(e) => panic!("{e}"),
+    }
+}
+
+fn optional_secret(provider: &Arc<dyn SecretProvider>, key: &str) -> Option<Secret> {
+    let secret = Secret::new(key, provider.clone());
+    (!secret.is_empty()).then_some(secret)
+}
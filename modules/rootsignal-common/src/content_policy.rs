@@ -0,0 +1,241 @@
+//! Content policy for machine-generated public text.
+//!
+//! Headlines, summaries and `what_would_help` come out of LLMs and
+//! occasionally carry speculation or loaded framing. `lint_public_text` runs
+//! the rules half of the check before GraphWriter stores a field: contact
+//! details and intensifiers are fixed in place, text naming private people is
+//! held back as a draft, and absolute or speculative claims are flagged for
+//! the supervisor's LLM review, which judges them against the source.
+//! `demote_fields` covers the structured fields: rather than hold a whole
+//! signal back, it narrows who sees a street address or a private organizer.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::safety::{ADDRESS_RE, EMAIL_RE, PHONE_RE, SSN_RE};
+use crate::types::Node;
+use crate::visibility::{SignalField, Visibility};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyRule {
+    /// Phone numbers, emails, SSNs.
+    ContactDetails,
+    /// Intensifiers that editorialize ("shockingly", "so-called").
+    LoadedFraming,
+    /// A private person named outright ("Mrs. Alvarez", "tenant Jo Smith").
+    PrivateIndividual,
+    /// "always", "everyone", "proven" — claims stronger than a source shows.
+    AbsoluteClaim,
+    /// Guessed causes ("likely caused by", "is to blame").
+    SpeculativeCausation,
+    /// A street address as the location name.
+    StreetAddress,
+    /// A private person, not an organization, as organizer.
+    NamedOrganizer,
+}
+
+/// What a rule does to the text it matches, mildest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PolicyAction {
+    /// Rewritten in place; the text can publish.
+    AutoFix,
+    /// The signal publishes; one field is shown to a narrower audience.
+    Demote,
+    /// Publishes only if review finds the claim supported.
+    Flag,
+    /// Held back until a person edits it.
+    Draft,
+}
+
+impl PolicyRule {
+    pub const ALL: [PolicyRule; 7] = [
+        PolicyRule::ContactDetails,
+        PolicyRule::LoadedFraming,
+        PolicyRule::PrivateIndividual,
+        PolicyRule::AbsoluteClaim,
+        PolicyRule::SpeculativeCausation,
+        PolicyRule::StreetAddress,
+        PolicyRule::NamedOrganizer,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicyRule::ContactDetails => "contact_details",
+            PolicyRule::LoadedFraming => "loaded_framing",
+            PolicyRule::PrivateIndividual => "private_individual",
+            PolicyRule::AbsoluteClaim => "absolute_claim",
+            PolicyRule::SpeculativeCausation => "speculative_causation",
+            PolicyRule::StreetAddress => "street_address",
+            PolicyRule::NamedOrganizer => "named_organizer",
+        }
+    }
+
+    pub fn action(&self) -> PolicyAction {
+        match self {
+            PolicyRule::ContactDetails | PolicyRule::LoadedFraming => PolicyAction::AutoFix,
+            PolicyRule::StreetAddress | PolicyRule::NamedOrganizer => PolicyAction::Demote,
+            PolicyRule::AbsoluteClaim | PolicyRule::SpeculativeCausation => PolicyAction::Flag,
+            PolicyRule::PrivateIndividual => PolicyAction::Draft,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyFinding {
+    pub rule: PolicyRule,
+    /// The matched text, as written before any fix.
+    pub excerpt: String,
+}
+
+impl std::fmt::Display for PolicyFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: \"{}\"", self.rule.as_str(), self.excerpt)
+    }
+}
+
+/// Text after the rules ran, with what they found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintedText {
+    pub text: String,
+    pub findings: Vec<PolicyFinding>,
+}
+
+impl LintedText {
+    /// The strongest action any finding calls for; None when clean.
+    pub fn action(&self) -> Option<PolicyAction> {
+        self.findings.iter().map(|f| f.rule.action()).max()
+    }
+}
+
+static LOADED_FRAMING_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:shockingly|outrageously|blatantly|brazenly|disgracefully|egregiously|so-called)\s+").unwrap()
+});
+static PRIVATE_INDIVIDUAL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(?:Mrs?|Ms|Mx)\.?\s+[A-Z][a-z]+|\b(?i:resident|neighbor|tenant|homeowner|renter|student|parent|worker)\s+[A-Z][a-z]+\s+[A-Z][a-z]+",
+    )
+    .unwrap()
+});
+static ABSOLUTE_CLAIM_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:always|never|everyone|everybody|no one|nobody|all residents|undeniabl[ey]|without (?:a|any) doubt|guaranteed|definitely|proven)\b",
+    )
+    .unwrap()
+});
+static SPECULATIVE_CAUSATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:(?:likely|probably|presumably|possibly|apparently)\s+(?:caused\s+by|due\s+to|because\s+of|the\s+result\s+of)|(?:is|are)\s+to\s+blame|may\s+be\s+behind|(?:must|could)\s+have\s+caused)\b",
+    )
+    .unwrap()
+});
+
+/// Run the content-policy rules over one field of public text.
+pub fn lint_public_text(text: &str) -> LintedText {
+    let mut findings = Vec::new();
+    let mut find = |rule: PolicyRule, re: &Regex, text: &str| {
+        findings.extend(re.find_iter(text).map(|m| PolicyFinding {
+            rule,
+            excerpt: m.as_str().trim().to_string(),
+        }));
+    };
+
+    for re in [&*PHONE_RE, &*EMAIL_RE, &*SSN_RE] {
+        find(PolicyRule::ContactDetails, re, text);
+    }
+    find(PolicyRule::LoadedFraming, &LOADED_FRAMING_RE, text);
+    find(PolicyRule::PrivateIndividual, &PRIVATE_INDIVIDUAL_RE, text);
+    find(PolicyRule::AbsoluteClaim, &ABSOLUTE_CLAIM_RE, text);
+    find(PolicyRule::SpeculativeCausation, &SPECULATIVE_CAUSATION_RE, text);
+
+    let mut fixed = text.to_string();
+    for re in [&*SSN_RE, &*PHONE_RE, &*EMAIL_RE] {
+        fixed = re.replace_all(&fixed, "[redacted]").into_owned();
+    }
+    fixed = LOADED_FRAMING_RE.replace_all(&fixed, "").into_owned();
+
+    LintedText { text: fixed, findings }
+}
+
+/// Words that mark an organizer as an organization rather than a person.
+const ORGANIZATION_WORDS: &[&str] = &[
+    "alliance", "association", "center", "centre", "church", "city", "club", "coalition", "collective",
+    "committee", "community", "council", "county", "department", "foundation", "friends", "group", "inc",
+    "library", "llc", "mosque", "network", "neighbors", "parish", "project", "school", "society", "synagogue",
+    "team", "temple", "union",
+];
+
+/// Two or three capitalized words and nothing that reads as an organization.
+fn looks_like_person(name: &str) -> bool {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    (2..=3).contains(&words.len())
+        && words.iter().all(|w| {
+            w.chars().next().is_some_and(char::is_uppercase)
+                && w.chars().all(|c| c.is_alphabetic() || c == '-' || c == '\'' || c == '.')
+                && !ORGANIZATION_WORDS.contains(&w.trim_end_matches('.').to_lowercase().as_str())
+        })
+}
+
+/// Narrow who sees a signal's structured fields instead of holding it back:
+/// a street address as location name keeps the place and exact coordinates
+/// to contributors, and a private person as organizer is shown only to
+/// admins.
+pub fn demote_fields(node: &mut Node) -> Vec<PolicyFinding> {
+    let mut findings = Vec::new();
+    let address = node
+        .meta()
+        .and_then(|m| m.about_location_name.as_deref())
+        .and_then(|name| ADDRESS_RE.find(name))
+        .map(|m| m.as_str().to_string());
+    let organizer = match &*node {
+        Node::Gathering(g) => g.organizer.clone().filter(|o| looks_like_person(o)),
+        _ => None,
+    };
+    let Some(meta) = node.meta_mut() else {
+        return findings;
+    };
+    if let Some(excerpt) = address {
+        meta.field_visibility.demote(SignalField::LocationName, Visibility::Contributor);
+        meta.field_visibility.demote(SignalField::Location, Visibility::Contributor);
+        findings.push(PolicyFinding { rule: PolicyRule::StreetAddress, excerpt });
+    }
+    if let Some(excerpt) = organizer {
+        meta.field_visibility.demote(SignalField::Organizer, Visibility::Admin);
+        findings.push(PolicyFinding { rule: PolicyRule::NamedOrganizer, excerpt });
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contact_details_and_intensifiers_are_fixed_in_place() {
+        let linted = lint_public_text("Landlord shockingly raises rent; call 612-555-0100 or email a@b.org");
+        assert_eq!(linted.text, "Landlord raises rent; call [redacted] or email [redacted]");
+        assert_eq!(linted.action(), Some(PolicyAction::AutoFix));
+        assert_eq!(linted.findings.len(), 3);
+    }
+
+    #[test]
+    fn private_names_draft_and_unsupported_claims_flag() {
+        let named = lint_public_text("Tenant Maria Lopez says the heat is off");
+        assert_eq!(named.action(), Some(PolicyAction::Draft));
+        assert_eq!(named.text, "Tenant Maria Lopez says the heat is off");
+
+        let speculative = lint_public_text("Flooding likely caused by the new development; everyone is affected");
+        let rules: Vec<PolicyRule> = speculative.findings.iter().map(|f| f.rule).collect();
+        assert_eq!(rules, vec![PolicyRule::AbsoluteClaim, PolicyRule::SpeculativeCausation]);
+        assert_eq!(speculative.action(), Some(PolicyAction::Flag));
+    }
+
+    #[test]
+    fn plain_reporting_is_clean() {
+        let linted = lint_public_text("Food shelf on Lake St extends Saturday hours through March");
+        assert!(linted.findings.is_empty());
+        assert_eq!(linted.action(), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Correction requests: residents reporting what's wrong with a signal.
+//!
+//! Anyone can file one, no account needed. Requests carry a structured kind
+//! so triage can act on them; "outdated" reports send the signal back for
+//! re-investigation straight away. Free-text details are for admins only and
+//! never shown publicly.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::detect_pii;
+
+pub const MAX_CORRECTION_DETAILS_CHARS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrectionKind {
+    WrongLocation,
+    /// No longer happening, moved, or closed.
+    Outdated,
+    /// Same as another signal (`duplicate_of`).
+    Duplicate,
+    NotTrue,
+    /// Puts someone at risk or targets a group.
+    Harmful,
+}
+
+impl CorrectionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorrectionKind::WrongLocation => "wrong_location",
+            CorrectionKind::Outdated => "outdated",
+            CorrectionKind::Duplicate => "duplicate",
+            CorrectionKind::NotTrue => "not_true",
+            CorrectionKind::Harmful => "harmful",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "wrong_location" => Some(CorrectionKind::WrongLocation),
+            "outdated" => Some(CorrectionKind::Outdated),
+            "duplicate" => Some(CorrectionKind::Duplicate),
+            "not_true" => Some(CorrectionKind::NotTrue),
+            "harmful" => Some(CorrectionKind::Harmful),
+            _ => None,
+        }
+    }
+
+    /// Triage order: lower is looked at first.
+    pub fn triage_rank(&self) -> u8 {
+        match self {
+            CorrectionKind::Harmful => 0,
+            CorrectionKind::NotTrue => 1,
+            CorrectionKind::Outdated => 2,
+            CorrectionKind::WrongLocation => 3,
+            CorrectionKind::Duplicate => 4,
+        }
+    }
+
+    /// Whether filing one sends the signal back for re-investigation without
+    /// waiting for triage.
+    pub fn reinvestigates(&self) -> bool {
+        matches!(self, CorrectionKind::Outdated)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrectionStatus {
+    Open,
+    /// Confirmed and acted on.
+    Accepted,
+    Dismissed,
+}
+
+impl CorrectionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorrectionStatus::Open => "open",
+            CorrectionStatus::Accepted => "accepted",
+            CorrectionStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "open" => Some(CorrectionStatus::Open),
+            "accepted" => Some(CorrectionStatus::Accepted),
+            "dismissed" => Some(CorrectionStatus::Dismissed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorrectionRequest {
+    pub id: Uuid,
+    pub signal_id: Uuid,
+    pub kind: CorrectionKind,
+    /// Reporter's explanation; admin-only.
+    pub details: Option<String>,
+    pub duplicate_of: Option<Uuid>,
+    pub status: CorrectionStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Shown publicly with the status once resolved.
+    pub resolution_note: Option<String>,
+}
+
+/// Check a submission's details: trimmed, bounded, and free of PII (the
+/// reporter's or anyone else's). Returns the details to store.
+pub fn check_correction(
+    kind: CorrectionKind,
+    details: Option<&str>,
+    duplicate_of: Option<Uuid>,
+) -> Result<Option<String>, String> {
+    if kind == CorrectionKind::Duplicate && duplicate_of.is_none() {
+        return Err("A duplicate report needs the signal it duplicates".to_string());
+    }
+    let Some(details) = details.map(str::trim).filter(|d| !d.is_empty()) else {
+        return Ok(None);
+    };
+    if details.chars().count() > MAX_CORRECTION_DETAILS_CHARS {
+        return Err(format!("Details are longer than {MAX_CORRECTION_DETAILS_CHARS} characters"));
+    }
+    if !detect_pii(details).is_empty() {
+        return Err("Please leave out phone numbers, emails and street addresses".to_string());
+    }
+    Ok(Some(details.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_need_a_target_and_details_are_screened() {
+        assert!(check_correction(CorrectionKind::Duplicate, None, None).is_err());
+        assert!(check_correction(CorrectionKind::Duplicate, None, Some(Uuid::new_v4())).is_ok());
+        assert_eq!(check_correction(CorrectionKind::Outdated, Some("   "), None), Ok(None));
+        assert_eq!(
+            check_correction(CorrectionKind::Outdated, Some(" Closed in May "), None),
+            Ok(Some("Closed in May".to_string()))
+        );
+        assert!(check_correction(CorrectionKind::NotTrue, Some("email me at a@b.org"), None).is_err());
+    }
+
+    #[test]
+    fn kinds_round_trip_and_harmful_triages_first() {
+        for kind in [
+            CorrectionKind::WrongLocation,
+            CorrectionKind::Outdated,
+            CorrectionKind::Duplicate,
+            CorrectionKind::NotTrue,
+            CorrectionKind::Harmful,
+        ] {
+            assert_eq!(CorrectionKind::parse(kind.as_str()), Some(kind));
+            assert!(CorrectionKind::Harmful.triage_rank() <= kind.triage_rank());
+        }
+        assert!(CorrectionKind::Outdated.reinvestigates());
+        assert!(!CorrectionKind::Harmful.reinvestigates());
+    }
+}
@@ -0,0 +1,299 @@
+//! Dead-letter queue for work that failed terminally inside a workflow.
+//!
+//! Workflows record the failed item's request payload here instead of just
+//! logging and dropping it. Admins can list entries and replay them back into
+//! the workflow they came from. Repeat failures of the same item append to its
+//! attempt history rather than creating a new entry.
+//!
+//! Backed by the `dead_letters` Postgres table.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::ErrorClass;
+
+#[derive(Debug, Error)]
+pub enum DeadLetterError {
+    #[error("Dead letter {0} not found")]
+    NotFound(Uuid),
+
+    #[error("Dead letter {0} was discarded and can't be replayed")]
+    Discarded(Uuid),
+
+    #[error("Database error: {0}")]
+    Db(#[from] sqlx::Error),
+
+    #[error("Restate unreachable: {0}")]
+    Unreachable(#[from] reqwest::Error),
+
+    #[error("Restate ingress error (HTTP {status}): {body}")]
+    Ingress { status: u16, body: String },
+}
+
+/// Lifecycle of a dead-lettered item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterStatus {
+    /// Waiting for an admin to replay or discard it.
+    Pending,
+    /// Re-enqueued into its workflow. Goes back to `Pending` if it fails again.
+    Replayed,
+    /// Dismissed without replay.
+    Discarded,
+}
+
+impl DeadLetterStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Replayed => "replayed",
+            Self::Discarded => "discarded",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "replayed" => Some(Self::Replayed),
+            "discarded" => Some(Self::Discarded),
+            _ => None,
+        }
+    }
+}
+
+/// One failed attempt at processing an item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub at: DateTime<Utc>,
+    pub error: String,
+    pub error_class: ErrorClass,
+}
+
+/// A failed work item with everything needed to replay it.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub id: Uuid,
+    /// Restate workflow name the item should be replayed into.
+    pub workflow: String,
+    /// Stable identity of the item within its workflow (task id, file id, ...).
+    pub item_key: String,
+    /// Workflow request body.
+    pub payload: serde_json::Value,
+    /// Most recent error.
+    pub error: String,
+    pub error_class: ErrorClass,
+    pub attempts: Vec<Attempt>,
+    pub status: DeadLetterStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+type DeadLetterRow = (
+    Uuid,
+    String,
+    String,
+    serde_json::Value,
+    String,
+    String,
+    serde_json::Value,
+    String,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+const COLUMNS: &str =
+    "id, workflow, item_key, payload, error, error_class, attempts, status, created_at, updated_at";
+
+/// Record a terminal failure. Returns the entry id (existing one on repeat failure).
+pub async fn record(
+    pool: &PgPool,
+    workflow: &str,
+    item_key: &str,
+    payload: &impl Serialize,
+    error: &str,
+) -> Result<Uuid, sqlx::Error> {
+    let class = ErrorClass::from_message(error);
+    let attempt = Attempt {
+        at: Utc::now(),
+        error: error.to_string(),
+        error_class: class,
+    };
+    let payload = serde_json::to_value(payload).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+    let attempts = serde_json::to_value(vec![attempt]).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+    let (id,): (Uuid,) = sqlx::query_as(
+        r#"
+        INSERT INTO dead_letters (id, workflow, item_key, payload, error, error_class, attempts, status)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, 'pending')
+        ON CONFLICT (workflow, item_key) WHERE status <> 'discarded'
+        DO UPDATE SET
+            payload = EXCLUDED.payload,
+            error = EXCLUDED.error,
+            error_class = EXCLUDED.error_class,
+            attempts = dead_letters.attempts || EXCLUDED.attempts,
+            status = 'pending',
+            updated_at = now()
+        RETURNING id
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(workflow)
+    .bind(item_key)
+    .bind(payload)
+    .bind(error)
+    .bind(class.as_str())
+    .bind(attempts)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Most recently updated entries, optionally filtered by status.
+pub async fn list(
+    pool: &PgPool,
+    status: Option<DeadLetterStatus>,
+    limit: u32,
+) -> Result<Vec<DeadLetter>, sqlx::Error> {
+    let limit = limit.min(200) as i64;
+    let rows = sqlx::query_as::<_, DeadLetterRow>(&format!(
+        "SELECT {COLUMNS} FROM dead_letters
+         WHERE ($1::TEXT IS NULL OR status = $1)
+         ORDER BY updated_at DESC
+         LIMIT $2"
+    ))
+    .bind(status.map(|s| s.as_str()))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(row_to_dead_letter).collect())
+}
+
+pub async fn find(pool: &PgPool, id: Uuid) -> Result<Option<DeadLetter>, sqlx::Error> {
+    let row = sqlx::query_as::<_, DeadLetterRow>(&format!(
+        "SELECT {COLUMNS} FROM dead_letters WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(row_to_dead_letter))
+}
+
+/// Move an entry to `status`. Returns false if the entry doesn't exist.
+pub async fn set_status(pool: &PgPool, id: Uuid, status: DeadLetterStatus) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("UPDATE dead_letters SET status = $2, updated_at = now() WHERE id = $1")
+        .bind(id)
+        .bind(status.as_str())
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Re-enqueue an entry into its workflow via the Restate ingress and mark it
+/// replayed. Returns the workflow key the replay was started under.
+///
+/// Each replay gets its own key (workflows are one-shot per key). If the
+/// replay fails too, the workflow records it again and the entry returns to
+/// `Pending` with one more attempt in its history.
+pub async fn replay(
+    pool: &PgPool,
+    http: &reqwest::Client,
+    ingress_url: &str,
+    id: Uuid,
+) -> Result<String, DeadLetterError> {
+    let entry = find(pool, id).await?.ok_or(DeadLetterError::NotFound(id))?;
+    if entry.status == DeadLetterStatus::Discarded {
+        return Err(DeadLetterError::Discarded(id));
+    }
+
+    let key = replay_key(&entry);
+    let url = format!("{ingress_url}/{}/{key}/run/send", entry.workflow);
+    let resp = http.post(&url).json(&entry.payload).send().await?;
+    if !resp.status().is_success() {
+        let status = resp.status().as_u16();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(DeadLetterError::Ingress { status, body });
+    }
+
+    set_status(pool, id, DeadLetterStatus::Replayed).await?;
+    tracing::info!(%id, workflow = entry.workflow.as_str(), key = key.as_str(), "Dead letter replayed");
+    Ok(key)
+}
+
+/// Workflow key for the next replay of `entry`, unique per attempt.
+fn replay_key(entry: &DeadLetter) -> String {
+    format!("{}-replay-{}", entry.item_key, entry.attempts.len())
+}
+
+fn row_to_dead_letter(r: DeadLetterRow) -> DeadLetter {
+    DeadLetter {
+        id: r.0,
+        workflow: r.1,
+        item_key: r.2,
+        payload: r.3,
+        error: r.4,
+        error_class: serde_json::from_value(serde_json::Value::String(r.5)).unwrap_or(ErrorClass::System),
+        attempts: serde_json::from_value(r.6).unwrap_or_default(),
+        status: DeadLetterStatus::parse(&r.7).unwrap_or(DeadLetterStatus::Pending),
+        created_at: r.8,
+        updated_at: r.9,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(status: &str) -> DeadLetterRow {
+        (
+            Uuid::new_v4(),
+            "ScrapeWorkflow".into(),
+            "task-1".into(),
+            serde_json::json!({}),
+            "boom".into(),
+            "mystery".into(),
+            serde_json::json!([]),
+            status.into(),
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn status_round_trips_through_its_column_value() {
+        for status in [DeadLetterStatus::Pending, DeadLetterStatus::Replayed, DeadLetterStatus::Discarded] {
+            assert_eq!(DeadLetterStatus::parse(status.as_str()), Some(status));
+        }
+    }
+
+    #[test]
+    fn each_failed_attempt_gets_a_distinct_replay_key() {
+        let attempt = Attempt {
+            at: Utc::now(),
+            error: "boom".into(),
+            error_class: ErrorClass::System,
+        };
+        let mut entry = row_to_dead_letter(row("pending"));
+        entry.attempts = vec![attempt.clone()];
+        let first = replay_key(&entry);
+
+        entry.attempts.push(attempt);
+        let second = replay_key(&entry);
+
+        assert_eq!(first, "task-1-replay-1");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn unknown_error_class_column_falls_back_to_system() {
+        let dl = row_to_dead_letter(row("pending"));
+
+        assert_eq!(dl.error_class, ErrorClass::System);
+        assert_eq!(dl.status, DeadLetterStatus::Pending);
+    }
+}
@@ -0,0 +1,154 @@
+//! Decision records: provenance for automated mutations of signals.
+//!
+//! Whenever a component changes a signal's confidence, merges it, suppresses
+//! it or boosts it, it records a `Decision`: which component, a digest of the
+//! inputs it decided on, what it did and why, and the scout run. Decisions
+//! hang off the affected signal in the graph and outlive it, so an admin can
+//! read a signal's timeline even after it was merged away or expired. Each
+//! signal keeps its latest `MAX_DECISIONS_PER_SUBJECT`; older records go with
+//! the `DecisionRecords` retention policy.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::NodeType;
+
+/// Decisions kept per signal; recording another drops the oldest.
+pub const MAX_DECISIONS_PER_SUBJECT: usize = 50;
+/// Longest rationale stored.
+pub const MAX_RATIONALE_CHARS: usize = 280;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionKind {
+    ConfidenceChange,
+    /// Another extraction or signal folded into this one.
+    Merge,
+    /// Withheld from or removed from publication.
+    Suppression,
+    /// Ranked or weighted up.
+    Boost,
+}
+
+impl DecisionKind {
+    pub const ALL: [DecisionKind; 4] = [
+        DecisionKind::ConfidenceChange,
+        DecisionKind::Merge,
+        DecisionKind::Suppression,
+        DecisionKind::Boost,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DecisionKind::ConfidenceChange => "confidence_change",
+            DecisionKind::Merge => "merge",
+            DecisionKind::Suppression => "suppression",
+            DecisionKind::Boost => "boost",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decision {
+    pub id: Uuid,
+    /// The signal the decision changed.
+    pub subject_id: Uuid,
+    pub subject_type: NodeType,
+    pub kind: DecisionKind,
+    /// What decided, e.g. "investigator" or "scraper.dedup".
+    pub component: String,
+    /// `inputs_hash` of what the component decided on; equal hashes mean
+    /// the same inputs.
+    pub inputs_hash: String,
+    /// What changed, e.g. "confidence 0.60 -> 0.72".
+    pub outcome: String,
+    pub rationale: String,
+    pub run_id: Option<String>,
+    pub decided_at: DateTime<Utc>,
+}
+
+impl Decision {
+    pub fn new(
+        kind: DecisionKind,
+        subject_id: Uuid,
+        subject_type: NodeType,
+        component: &str,
+        outcome: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            subject_id,
+            subject_type,
+            kind,
+            component: component.to_string(),
+            inputs_hash: String::new(),
+            outcome: outcome.into(),
+            rationale: String::new(),
+            run_id: None,
+            decided_at: Utc::now(),
+        }
+    }
+
+    pub fn with_inputs(mut self, inputs: &impl Serialize) -> Self {
+        self.inputs_hash = inputs_hash(inputs);
+        self
+    }
+
+    /// Truncated to `MAX_RATIONALE_CHARS`.
+    pub fn with_rationale(mut self, rationale: impl Into<String>) -> Self {
+        let rationale: String = rationale.into();
+        self.rationale = match rationale.char_indices().nth(MAX_RATIONALE_CHARS) {
+            Some((end, _)) => format!("{}…", &rationale[..end]),
+            None => rationale,
+        };
+        self
+    }
+
+    pub fn with_run_id(mut self, run_id: &str) -> Self {
+        self.run_id = Some(run_id.to_string()).filter(|r| !r.is_empty());
+        self
+    }
+}
+
+/// First 16 hex digits of the SHA-256 of `inputs` as JSON.
+pub fn inputs_hash(inputs: &impl Serialize) -> String {
+    let json = serde_json::to_vec(inputs).unwrap_or_default();
+    hex::encode(Sha256::digest(&json))[..16].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn inputs_hash_is_stable_and_input_sensitive() {
+        let a = inputs_hash(&json!({ "similarity": 0.91, "source": "https://a.example" }));
+        let b = inputs_hash(&json!({ "similarity": 0.91, "source": "https://a.example" }));
+        let c = inputs_hash(&json!({ "similarity": 0.92, "source": "https://a.example" }));
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.len(), 16);
+    }
+
+    #[test]
+    fn long_rationales_are_truncated_and_empty_run_ids_dropped() {
+        let decision = Decision::new(DecisionKind::Merge, Uuid::new_v4(), NodeType::Tension, "test", "merged")
+            .with_rationale("é".repeat(MAX_RATIONALE_CHARS + 20))
+            .with_run_id("");
+
+        assert_eq!(decision.rationale.chars().count(), MAX_RATIONALE_CHARS + 1);
+        assert!(decision.rationale.ends_with('…'));
+        assert_eq!(decision.run_id, None);
+        for kind in DecisionKind::ALL {
+            assert_eq!(DecisionKind::parse(kind.as_str()), Some(kind));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Census demographic context: who lives where a situation is unfolding.
+//!
+//! Each region names the counties whose American Community Survey (ACS)
+//! 5-year tract estimates it uses. Tracts are fetched rarely and cached; a
+//! situation's context is aggregated from the tracts nearest its signals, so
+//! a story about evictions can say that most households there rent.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::haversine_km;
+
+/// Latest ACS 5-year release.
+pub fn default_acs_year() -> u16 {
+    2023
+}
+
+/// Points farther than this from every tract centroid are outside the data.
+pub const MAX_TRACT_DISTANCE_KM: f64 = 3.0;
+
+/// The ACS data a region uses, by FIPS code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionDemographics {
+    /// Two-digit state code ("27" for Minnesota).
+    pub state_fips: String,
+    /// Three-digit county codes within the state ("053" for Hennepin).
+    pub county_fips: Vec<String>,
+    #[serde(default = "default_acs_year")]
+    pub acs_year: u16,
+}
+
+impl RegionDemographics {
+    pub fn validate(&self) -> Result<(), String> {
+        let is_code = |code: &str, len: usize| code.len() == len && code.chars().all(|c| c.is_ascii_digit());
+        if !is_code(&self.state_fips, 2) {
+            return Err(format!("Invalid state FIPS code: {}", self.state_fips));
+        }
+        if self.county_fips.is_empty() {
+            return Err("At least one county is required".to_string());
+        }
+        if let Some(county) = self.county_fips.iter().find(|c| !is_code(c, 3)) {
+            return Err(format!("Invalid county FIPS code: {county}"));
+        }
+        if !(2009..=2100).contains(&self.acs_year) {
+            return Err(format!("Invalid ACS year: {}", self.acs_year));
+        }
+        Ok(())
+    }
+
+    /// Identifies the tract data this configuration produces; cached tracts
+    /// under a different key are stale.
+    pub fn cache_key(&self) -> String {
+        let mut counties = self.county_fips.clone();
+        counties.sort();
+        format!("acs5-{}/{}/{}", self.acs_year, self.state_fips, counties.join(","))
+    }
+}
+
+/// ACS estimates for one census tract. Counts are kept rather than rates so
+/// tracts can be combined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TractDemographics {
+    /// 11-digit tract GEOID (state + county + tract).
+    pub geoid: String,
+    /// Internal point of the tract.
+    pub lat: f64,
+    pub lng: f64,
+    pub population: u32,
+    /// People whose poverty status is determined.
+    pub poverty_universe: u32,
+    pub below_poverty: u32,
+    pub households: u32,
+    pub renter_households: u32,
+    /// Households where no one 14 or older speaks English "very well".
+    pub limited_english_households: u32,
+}
+
+/// Demographics of a place, aggregated from the tracts it covers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemographicContext {
+    pub acs_year: u16,
+    pub tracts: u32,
+    pub population: u64,
+    pub poverty_rate: Option<f64>,
+    pub renter_share: Option<f64>,
+    pub limited_english_share: Option<f64>,
+}
+
+impl DemographicContext {
+    /// Aggregate the tracts nearest each point. Points with no tract within
+    /// `MAX_TRACT_DISTANCE_KM` are ignored; `None` when none are covered.
+    pub fn for_footprint(tracts: &[TractDemographics], points: &[(f64, f64)], acs_year: u16) -> Option<Self> {
+        let mut covered: HashSet<&str> = HashSet::new();
+        for &(lat, lng) in points {
+            let nearest = tracts
+                .iter()
+                .map(|t| (t, haversine_km(lat, lng, t.lat, t.lng)))
+                .filter(|(_, km)| *km <= MAX_TRACT_DISTANCE_KM)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((tract, _)) = nearest {
+                covered.insert(&tract.geoid);
+            }
+        }
+        if covered.is_empty() {
+            return None;
+        }
+
+        let covered: Vec<&TractDemographics> = tracts.iter().filter(|t| covered.contains(t.geoid.as_str())).collect();
+        let sum = |f: fn(&TractDemographics) -> u32| covered.iter().map(|t| f(t) as u64).sum::<u64>();
+        let rate = |part: u64, whole: u64| (whole > 0).then(|| part as f64 / whole as f64);
+        let households = sum(|t| t.households);
+
+        Some(Self {
+            acs_year,
+            tracts: covered.len() as u32,
+            population: sum(|t| t.population),
+            poverty_rate: rate(sum(|t| t.below_poverty), sum(|t| t.poverty_universe)),
+            renter_share: rate(sum(|t| t.renter_households), households),
+            limited_english_share: rate(sum(|t| t.limited_english_households), households),
+        })
+    }
+
+    /// One line for prompts and briefs: "ACS 2023 estimates for 3 tracts
+    /// (12,480 people): 31% below poverty, 72% of households rent, ...".
+    pub fn describe(&self) -> String {
+        let mut facts = Vec::new();
+        let pct = |r: f64| (r * 100.0).round();
+        if let Some(r) = self.poverty_rate {
+            facts.push(format!("{}% below poverty", pct(r)));
+        }
+        if let Some(r) = self.renter_share {
+            facts.push(format!("{}% of households rent", pct(r)));
+        }
+        if let Some(r) = self.limited_english_share {
+            facts.push(format!("{}% of households have limited English", pct(r)));
+        }
+        format!(
+            "ACS {} estimates for {} tract(s) ({} people): {}",
+            self.acs_year,
+            self.tracts,
+            self.population,
+            facts.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tract(geoid: &str, lat: f64, lng: f64, renters: u32) -> TractDemographics {
+        TractDemographics {
+            geoid: geoid.to_string(),
+            lat,
+            lng,
+            population: 4000,
+            poverty_universe: 3900,
+            below_poverty: 780,
+            households: 1600,
+            renter_households: renters,
+            limited_english_households: 160,
+        }
+    }
+
+    #[test]
+    fn footprint_combines_each_points_nearest_tract() {
+        let tracts = vec![
+            tract("27053107600", 44.948, -93.262, 1200),
+            tract("27053108000", 44.940, -93.250, 400),
+            tract("27053026000", 45.050, -93.300, 100),
+        ];
+        // Two signals in the first tract, one nearest the second.
+        let points = [(44.9485, -93.2622), (44.9479, -93.2615), (44.9405, -93.2498)];
+
+        let context = DemographicContext::for_footprint(&tracts, &points, 2023).unwrap();
+        assert_eq!(context.tracts, 2);
+        assert_eq!(context.population, 8000);
+        assert_eq!(context.renter_share, Some(0.5));
+        assert_eq!(context.poverty_rate, Some(0.2));
+    }
+
+    #[test]
+    fn points_beyond_the_data_have_no_context() {
+        let tracts = vec![tract("27053107600", 44.948, -93.262, 1200)];
+        assert!(DemographicContext::for_footprint(&tracts, &[(44.80, -93.10)], 2023).is_none());
+    }
+
+    #[test]
+    fn cache_key_ignores_county_order() {
+        let config = |counties: &[&str]| RegionDemographics {
+            state_fips: "27".to_string(),
+            county_fips: counties.iter().map(|c| c.to_string()).collect(),
+            acs_year: 2023,
+        };
+        assert_eq!(config(&["123", "053"]).cache_key(), config(&["053", "123"]).cache_key());
+        assert!(config(&["53"]).validate().is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -29,3 +30,284 @@ pub enum RootSignalError {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
+
+// ---------------------------------------------------------------------------
+// Error classification
+// ---------------------------------------------------------------------------
+
+/// Failure class shared by every error type in the workspace, so retry logic,
+/// run logs, and diagnostics can reason about failures without knowing which
+/// crate produced them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Network blips, timeouts, 5xx, 429 rate limits. Safe to retry with backoff.
+    Transient,
+    /// Our budget or an upstream quota is exhausted. Retrying before the
+    /// window resets only burns more budget.
+    Budget,
+    /// Bad or unsupported input (invalid URL, unsupported platform, 4xx).
+    /// Retrying the same request will fail the same way.
+    User,
+    /// Permanent system failure: misconfiguration, rejected credentials,
+    /// unparseable responses. Needs a code or config change.
+    System,
+}
+
+impl ErrorClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorClass::Transient => "transient",
+            ErrorClass::Budget => "budget",
+            ErrorClass::User => "user",
+            ErrorClass::System => "system",
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorClass::Transient)
+    }
+
+    pub fn is_user_fault(&self) -> bool {
+        matches!(self, ErrorClass::User)
+    }
+
+    pub fn is_budget(&self) -> bool {
+        matches!(self, ErrorClass::Budget)
+    }
+
+    /// Classify an HTTP status code returned by an upstream API.
+    pub fn from_http_status(status: u16) -> Self {
+        match status {
+            408 | 425 | 429 | 500..=599 => ErrorClass::Transient,
+            402 => ErrorClass::Budget,
+            401 | 403 => ErrorClass::System,
+            400..=499 => ErrorClass::User,
+            _ => ErrorClass::System,
+        }
+    }
+
+    /// Best-effort classification from an error message, for errors that only
+    /// survive as text (e.g. `anyhow!` strings from HTTP clients).
+    pub fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if let Some(status) = extract_http_status(&lower) {
+            return Self::from_http_status(status);
+        }
+        if ["budget", "quota", "credit", "insufficient funds", "usage limit"]
+            .iter()
+            .any(|p| lower.contains(p))
+        {
+            ErrorClass::Budget
+        } else if [
+            "timed out", "timeout", "connection", "network", "temporarily",
+            "rate limit", "overloaded", "reset by peer", "broken pipe",
+        ]
+        .iter()
+        .any(|p| lower.contains(p))
+        {
+            ErrorClass::Transient
+        } else if ["unsupported", "invalid url", "not found", "no archived content"]
+            .iter()
+            .any(|p| lower.contains(p))
+        {
+            ErrorClass::User
+        } else {
+            ErrorClass::System
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Pull a 4xx/5xx status out of messages like `"API error (status 503): ..."`
+/// or `"Claude API error (429 Too Many Requests): ..."`.
+pub(crate) fn extract_http_status(lower: &str) -> Option<u16> {
+    static STATUS_RE: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+        regex::Regex::new(r"(?:status|error|http)[\s:(]*([45]\d\d)\b").unwrap()
+    });
+    STATUS_RE
+        .captures(lower)
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+/// Implemented by every error type that can tell callers what kind of failure it is.
+pub trait Classify {
+    fn class(&self) -> ErrorClass;
+
+    fn is_retryable(&self) -> bool {
+        self.class().is_retryable()
+    }
+}
+
+impl Classify for RootSignalError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            RootSignalError::Database(msg) | RootSignalError::Scraping(msg) | RootSignalError::Embedding(msg) => {
+                ErrorClass::from_message(msg)
+            }
+            RootSignalError::Extraction(_) => ErrorClass::System,
+            RootSignalError::PiiDetected(_) | RootSignalError::Validation(_) => ErrorClass::User,
+            RootSignalError::Config(_) => ErrorClass::System,
+            RootSignalError::ScoutLockConflict => ErrorClass::Transient,
+            RootSignalError::Anyhow(e) => e.class(),
+        }
+    }
+}
+
+impl Classify for crate::secrets::SecretError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            crate::secrets::SecretError::Backend { message, .. } => ErrorClass::from_message(message),
+            crate::secrets::SecretError::UnknownProvider(_) => ErrorClass::System,
+        }
+    }
+}
+
+#[cfg(feature = "apify-client")]
+impl Classify for apify_client::ApifyError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            apify_client::ApifyError::Network(_) => ErrorClass::Transient,
+            apify_client::ApifyError::Api { status, message } => {
+                let class = ErrorClass::from_http_status(*status);
+                // Apify reports exhausted monthly credit as a 403.
+                if class == ErrorClass::System && ErrorClass::from_message(message).is_budget() {
+                    ErrorClass::Budget
+                } else {
+                    class
+                }
+            }
+            apify_client::ApifyError::Parse(_) => ErrorClass::System,
+            apify_client::ApifyError::RunFailed(status) => match status.as_str() {
+                "TIMED-OUT" | "ABORTED" => ErrorClass::Transient,
+                _ => ErrorClass::System,
+            },
+            apify_client::ApifyError::Timeout(..) => ErrorClass::Transient,
+        }
+    }
+}
+
+#[cfg(feature = "browserless-client")]
+impl Classify for browserless_client::BrowserlessError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            browserless_client::BrowserlessError::Network(_) => ErrorClass::Transient,
+            browserless_client::BrowserlessError::Api { status, .. } => ErrorClass::from_http_status(*status),
+        }
+    }
+}
+
+#[cfg(feature = "ai-client")]
+impl Classify for ai_client::AiError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            ai_client::AiError::Network(_) => ErrorClass::Transient,
+            ai_client::AiError::Api(msg) => ErrorClass::from_message(msg),
+            ai_client::AiError::Config(_) | ai_client::AiError::Parse(_) => ErrorClass::System,
+            ai_client::AiError::Tool(_) | ai_client::AiError::MaxTurns(_) => ErrorClass::System,
+            // The input drew an unusable answer; the same input will again.
+            ai_client::AiError::Aborted { .. } => ErrorClass::User,
+        }
+    }
+}
+
+/// Walks the error chain for a typed error we know how to classify, falling
+/// back to the message text.
+impl Classify for anyhow::Error {
+    fn class(&self) -> ErrorClass {
+        for cause in self.chain() {
+            if let Some(e) = cause.downcast_ref::<RootSignalError>() {
+                return e.class();
+            }
+            #[cfg(feature = "apify-client")]
+            if let Some(e) = cause.downcast_ref::<apify_client::ApifyError>() {
+                return e.class();
+            }
+            #[cfg(feature = "browserless-client")]
+            if let Some(e) = cause.downcast_ref::<browserless_client::BrowserlessError>() {
+                return e.class();
+            }
+            #[cfg(feature = "ai-client")]
+            if let Some(e) = cause.downcast_ref::<ai_client::AiError>() {
+                return e.class();
+            }
+            if let Some(e) = cause.downcast_ref::<crate::secrets::SecretError>() {
+                return e.class();
+            }
+            if let Some(class) = cause.downcast_ref::<ClassifiedError>().map(|e| e.class) {
+                return class;
+            }
+        }
+        ErrorClass::from_message(&format!("{self:#}"))
+    }
+}
+
+/// Attaches an explicit class to an error that would otherwise be classified
+/// by message text, e.g. when a crate that can't depend on `rootsignal-common`
+/// knows better than the heuristic.
+#[derive(Error, Debug)]
+#[error("{message}")]
+pub struct ClassifiedError {
+    pub class: ErrorClass,
+    pub message: String,
+}
+
+impl ClassifiedError {
+    pub fn new(class: ErrorClass, message: impl Into<String>) -> Self {
+        Self {
+            class,
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limits_and_server_errors_are_retryable() {
+        assert!(ErrorClass::from_http_status(429).is_retryable());
+        assert!(ErrorClass::from_http_status(503).is_retryable());
+        assert!(!ErrorClass::from_http_status(404).is_retryable());
+        assert_eq!(ErrorClass::from_http_status(402), ErrorClass::Budget);
+    }
+
+    #[test]
+    fn status_embedded_in_client_error_text_drives_classification() {
+        assert_eq!(
+            ErrorClass::from_message("Claude API error (529 <unknown status code>): overloaded"),
+            ErrorClass::Transient
+        );
+        assert_eq!(
+            ErrorClass::from_message("API error (status 400): invalid input"),
+            ErrorClass::User
+        );
+    }
+
+    #[test]
+    fn anyhow_error_wrapping_a_typed_error_uses_the_typed_class() {
+        let err = anyhow::Error::new(RootSignalError::Validation("bad url".into()))
+            .context("scraping source");
+        assert_eq!(err.class(), ErrorClass::User);
+    }
+
+    #[test]
+    fn explicitly_classified_error_overrides_message_heuristic() {
+        let err = anyhow::Error::new(ClassifiedError::new(ErrorClass::Budget, "connection refused"));
+        assert_eq!(err.class(), ErrorClass::Budget);
+    }
+
+    #[test]
+    fn unrecognized_failure_is_treated_as_permanent_system_error() {
+        let err = anyhow::anyhow!("something unexpected happened");
+        assert_eq!(err.class(), ErrorClass::System);
+        assert!(!err.is_retryable());
+    }
+}
@@ -0,0 +1,325 @@
+//! Error budgets for repeated operation failures.
+//!
+//! Each run records, per operation (`scrape` and the discovery finders),
+//! how many attempts it made, how many failed and a few sample failures.
+//! The supervisor sums those tallies over a rolling window and alerts when
+//! an operation's failure rate goes over its budget, so a failure mode that
+//! only shows up in logs (an agent that keeps running out of tool turns, a
+//! fetcher that keeps timing out) gets noticed.
+//!
+//! Budgets have built-in defaults; `ERROR_BUDGETS` overrides them per
+//! operation (`response_finder=0.1,scrape=0.5`) and
+//! `ERROR_BUDGET_WINDOW_HOURS` sets the window.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+/// Sample failures kept per operation, per run and per alert.
+pub const MAX_SAMPLES: usize = 3;
+
+/// Operations with a budget unless configured otherwise.
+const DEFAULT_BUDGETS: [(&str, f64); 5] = [
+    ("scrape", 0.3),
+    ("tension_linker", 0.2),
+    ("response_finder", 0.2),
+    ("gathering_finder", 0.2),
+    ("investigation", 0.2),
+];
+
+/// Fewer attempts than this in a window is too few to judge a rate by.
+const DEFAULT_MIN_ATTEMPTS: u32 = 10;
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+
+/// Days tallies are kept; windows are a day or so.
+const KEEP_DAYS: i32 = 30;
+
+/// One failed attempt, kept as an example for the alert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureSample {
+    /// `max_turns` or the error class.
+    pub reason: String,
+    pub error: String,
+}
+
+impl FailureSample {
+    /// Keep a sample unless `samples` already has enough.
+    pub fn keep(samples: &mut Vec<FailureSample>, reason: &str, error: impl Into<String>) {
+        if samples.len() < MAX_SAMPLES {
+            samples.push(FailureSample { reason: reason.to_string(), error: error.into() });
+        }
+    }
+}
+
+/// An operation's attempts and failures, in one run or summed over a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationTally {
+    pub operation: String,
+    pub attempts: u32,
+    pub failures: u32,
+    pub samples: Vec<FailureSample>,
+}
+
+impl OperationTally {
+    pub fn new(operation: &str, attempts: u32, failures: u32, samples: Vec<FailureSample>) -> Self {
+        Self {
+            operation: operation.to_string(),
+            attempts,
+            failures,
+            samples,
+        }
+    }
+
+    /// 0.0 to 1.0; 0.0 with no attempts.
+    pub fn failure_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.failures as f64 / self.attempts as f64
+        }
+    }
+}
+
+/// How many of an operation's attempts may fail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorBudget {
+    pub operation: String,
+    /// Failure rate above which the budget is burned.
+    pub max_failure_rate: f64,
+    pub min_attempts: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorBudgetPolicy {
+    pub window: Duration,
+    pub budgets: Vec<ErrorBudget>,
+}
+
+impl Default for ErrorBudgetPolicy {
+    fn default() -> Self {
+        Self {
+            window: Duration::hours(DEFAULT_WINDOW_HOURS),
+            budgets: DEFAULT_BUDGETS
+                .iter()
+                .map(|&(operation, max_failure_rate)| ErrorBudget {
+                    operation: operation.to_string(),
+                    max_failure_rate,
+                    min_attempts: DEFAULT_MIN_ATTEMPTS,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ErrorBudgetPolicy {
+    /// The defaults with `ERROR_BUDGETS` and `ERROR_BUDGET_WINDOW_HOURS`
+    /// applied. Malformed entries are logged and ignored.
+    pub fn from_env() -> Self {
+        let mut policy = Self::default();
+        if let Ok(spec) = std::env::var("ERROR_BUDGETS") {
+            policy.apply_overrides(&spec);
+        }
+        if let Some(hours) = std::env::var("ERROR_BUDGET_WINDOW_HOURS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|&h| h > 0)
+        {
+            policy.window = Duration::hours(hours);
+        }
+        policy
+    }
+
+    /// Apply `operation=max_rate` pairs, comma-separated. A rate of 0 or
+    /// more than 1 removes the operation's budget; unknown operations get one.
+    pub fn apply_overrides(&mut self, spec: &str) {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((operation, rate)) = entry
+                .split_once('=')
+                .and_then(|(op, rate)| Some((op.trim(), rate.trim().parse::<f64>().ok()?)))
+            else {
+                tracing::warn!(entry, "Ignoring malformed ERROR_BUDGETS entry");
+                continue;
+            };
+            self.budgets.retain(|b| b.operation != operation);
+            if rate > 0.0 && rate <= 1.0 {
+                self.budgets.push(ErrorBudget {
+                    operation: operation.to_string(),
+                    max_failure_rate: rate,
+                    min_attempts: DEFAULT_MIN_ATTEMPTS,
+                });
+            }
+        }
+    }
+
+    /// Budgets the window's tallies burned, worst first.
+    pub fn burned(&self, tallies: &[OperationTally]) -> Vec<BurnedBudget> {
+        let mut burned: Vec<BurnedBudget> = self
+            .budgets
+            .iter()
+            .filter_map(|budget| {
+                let tally = tallies.iter().find(|t| t.operation == budget.operation)?;
+                (tally.attempts >= budget.min_attempts && tally.failure_rate() > budget.max_failure_rate).then(|| {
+                    BurnedBudget {
+                        tally: tally.clone(),
+                        max_failure_rate: budget.max_failure_rate,
+                        window: self.window,
+                    }
+                })
+            })
+            .collect();
+        burned.sort_by(|a, b| b.tally.failure_rate().total_cmp(&a.tally.failure_rate()));
+        burned
+    }
+}
+
+/// An operation that failed more often than its budget allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BurnedBudget {
+    /// Summed over the window, with the most recent samples.
+    pub tally: OperationTally,
+    pub max_failure_rate: f64,
+    pub window: Duration,
+}
+
+/// Record a run's tallies, pruning old ones. Operations without attempts
+/// are skipped.
+pub async fn record_run(
+    pool: &PgPool,
+    run_id: &str,
+    region: &str,
+    tallies: &[OperationTally],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    for tally in tallies.iter().filter(|t| t.attempts > 0) {
+        sqlx::query(
+            "INSERT INTO operation_failures (run_id, region, operation, attempts, failures, samples)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(run_id)
+        .bind(region)
+        .bind(&tally.operation)
+        .bind(tally.attempts as i32)
+        .bind(tally.failures as i32)
+        .bind(sqlx::types::Json(&tally.samples))
+        .execute(&mut *tx)
+        .await?;
+    }
+    sqlx::query("DELETE FROM operation_failures WHERE recorded_at < now() - make_interval(days => $1)")
+        .bind(KEEP_DAYS)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await
+}
+
+#[derive(sqlx::FromRow)]
+struct WindowRow {
+    operation: String,
+    attempts: i64,
+    failures: i64,
+    samples: sqlx::types::Json<Vec<Vec<FailureSample>>>,
+}
+
+/// Each operation's tallies in `region` since `since`, summed, with samples
+/// from the most recent runs.
+pub async fn window_tallies(
+    pool: &PgPool,
+    region: &str,
+    since: DateTime<Utc>,
+) -> Result<Vec<OperationTally>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, WindowRow>(
+        "SELECT operation, sum(attempts)::bigint AS attempts, sum(failures)::bigint AS failures,
+                jsonb_agg(samples ORDER BY recorded_at DESC) AS samples
+         FROM operation_failures
+         WHERE region = $1 AND recorded_at >= $2
+         GROUP BY operation
+         ORDER BY operation",
+    )
+    .bind(region)
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let samples = row.samples.0.into_iter().flatten().take(MAX_SAMPLES).collect();
+            OperationTally::new(&row.operation, row.attempts as u32, row.failures as u32, samples)
+        })
+        .collect())
+}
+
+/// When each operation in `region` last alerted.
+pub async fn last_alerts(pool: &PgPool, region: &str) -> Result<BTreeMap<String, DateTime<Utc>>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (String, DateTime<Utc>)>(
+        "SELECT operation, alerted_at FROM error_budget_alerts WHERE region = $1",
+    )
+    .bind(region)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+/// Note that `operation` alerted, so it stays quiet for a window.
+pub async fn record_alert(pool: &PgPool, region: &str, operation: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO error_budget_alerts (region, operation, alerted_at) VALUES ($1, $2, now())
+         ON CONFLICT (region, operation) DO UPDATE SET alerted_at = now()",
+    )
+    .bind(region)
+    .bind(operation)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(operation: &str, attempts: u32, failures: u32) -> OperationTally {
+        OperationTally::new(operation, attempts, failures, Vec::new())
+    }
+
+    #[test]
+    fn budget_burns_only_above_its_rate_with_enough_attempts() {
+        let policy = ErrorBudgetPolicy::default();
+        let tallies = [
+            tally("response_finder", 20, 5),
+            tally("gathering_finder", 20, 4),
+            tally("tension_linker", 4, 4),
+        ];
+
+        let burned = policy.burned(&tallies);
+
+        assert_eq!(burned.len(), 1);
+        assert_eq!(burned[0].tally.operation, "response_finder");
+        assert_eq!(burned[0].max_failure_rate, 0.2);
+    }
+
+    #[test]
+    fn overrides_replace_add_and_remove_budgets() {
+        let mut policy = ErrorBudgetPolicy::default();
+
+        policy.apply_overrides("response_finder=0.5, apify=0.1, scrape=0, bogus");
+
+        let rate = |op: &str| policy.budgets.iter().find(|b| b.operation == op).map(|b| b.max_failure_rate);
+        assert_eq!(rate("response_finder"), Some(0.5));
+        assert_eq!(rate("apify"), Some(0.1));
+        assert_eq!(rate("scrape"), None);
+        assert_eq!(rate("investigation"), Some(0.2));
+    }
+
+    #[test]
+    fn samples_are_capped() {
+        let mut samples = Vec::new();
+
+        for i in 0..5 {
+            FailureSample::keep(&mut samples, "max_turns", format!("Max turns ({i}) exceeded"));
+        }
+
+        assert_eq!(samples.len(), MAX_SAMPLES);
+        assert_eq!(samples[0].error, "Max turns (0) exceeded");
+    }
+}
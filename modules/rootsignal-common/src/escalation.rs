@@ -0,0 +1,200 @@
+//! Escalation risk: how likely a situation is to get worse soon.
+//!
+//! Some situations escalate predictably — an eviction wave after a moratorium
+//! ends, a closure that strands riders once school starts. The estimate is a
+//! logistic score over features read from the graph each run:
+//!
+//!   z = -2.0
+//!     + 1.6 * acceleration       (recent signal rate vs the weeks before, 0–1)
+//!     + 1.4 * severe_share       (high/critical tensions among all tensions)
+//!     + 1.2 * response_gap       (1 - response coverage)
+//!     + 1.0 * historical_rate    (same-category situations that became active)
+//!     + 0.4 * early              (arc still emerging/developing)
+//!   risk = 1 / (1 + e^-z)
+//!
+//! The weights are hand-set, not fitted; the rationale names the factors that
+//! moved the score so organizers can judge it themselves.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::SituationArc;
+
+/// Days in each of the two windows compared for acceleration.
+pub const ACCELERATION_WINDOW_DAYS: i64 = 14;
+
+/// Same-category situations needed before their history counts.
+pub const MIN_HISTORY: u32 = 3;
+
+const INTERCEPT: f64 = -2.0;
+const W_ACCELERATION: f64 = 1.6;
+const W_SEVERITY: f64 = 1.4;
+const W_RESPONSE_GAP: f64 = 1.2;
+const W_HISTORY: f64 = 1.0;
+const W_EARLY: f64 = 0.4;
+
+/// What the estimate is computed from, for one situation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationFeatures {
+    pub arc: SituationArc,
+    /// Signals in the last `ACCELERATION_WINDOW_DAYS`.
+    pub recent_signals: u32,
+    /// Signals in the window before that.
+    pub prior_signals: u32,
+    pub tensions: u32,
+    /// Tensions of high or critical severity.
+    pub severe_tensions: u32,
+    /// 0.0–1.0, as computed for temperature.
+    pub response_coverage: f64,
+    /// Other situations in the same category, and how many of them reached
+    /// an active arc or a temperature of 0.6.
+    pub category_situations: u32,
+    pub category_escalated: u32,
+}
+
+impl EscalationFeatures {
+    /// Growth of the recent window over the prior one, mapped to 0–1
+    /// (1.0 at tripling or more; a first burst of 3+ signals also counts).
+    pub fn acceleration(&self) -> f64 {
+        if self.recent_signals <= self.prior_signals {
+            return 0.0;
+        }
+        let growth = (self.recent_signals - self.prior_signals) as f64 / self.prior_signals.max(1) as f64;
+        (growth / 2.0).min(1.0) * (self.recent_signals as f64 / 3.0).min(1.0)
+    }
+
+    pub fn severe_share(&self) -> f64 {
+        if self.tensions == 0 {
+            return 0.0;
+        }
+        self.severe_tensions as f64 / self.tensions as f64
+    }
+
+    pub fn response_gap(&self) -> f64 {
+        1.0 - self.response_coverage.clamp(0.0, 1.0)
+    }
+
+    /// Share of same-category situations that escalated; `None` with too little history.
+    pub fn historical_rate(&self) -> Option<f64> {
+        (self.category_situations >= MIN_HISTORY)
+            .then(|| self.category_escalated as f64 / self.category_situations as f64)
+    }
+
+    fn early(&self) -> bool {
+        matches!(self.arc, SituationArc::Emerging | SituationArc::Developing)
+    }
+}
+
+/// A situation's escalation risk with the reasons behind it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EscalationRisk {
+    /// 0.0–1.0.
+    pub risk: f64,
+    /// "Signals are accelerating (9 in the last two weeks, 2 before); …".
+    pub rationale: String,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// Estimate escalation risk from a situation's features.
+pub fn estimate_escalation(features: &EscalationFeatures, now: DateTime<Utc>) -> EscalationRisk {
+    let f = features;
+    let history = f.historical_rate();
+    let contributions = [
+        (W_ACCELERATION * f.acceleration(), accelerating_reason(f)),
+        (
+            W_SEVERITY * f.severe_share(),
+            format!("{} of {} tensions are high or critical severity", f.severe_tensions, f.tensions),
+        ),
+        (
+            // Only a gap worth mentioning when there's something to respond to.
+            W_RESPONSE_GAP * f.response_gap() * (f.tensions > 0) as u8 as f64,
+            format!("responses cover {:.0}% of its tensions", f.response_coverage.clamp(0.0, 1.0) * 100.0),
+        ),
+        (
+            W_HISTORY * history.unwrap_or(0.0),
+            format!(
+                "{} of {} similar situations escalated before",
+                f.category_escalated, f.category_situations
+            ),
+        ),
+        (W_EARLY * f.early() as u8 as f64, format!("it is still {}", f.arc)),
+    ];
+
+    let z = INTERCEPT + contributions.iter().map(|(c, _)| c).sum::<f64>();
+    let risk = 1.0 / (1.0 + (-z).exp());
+
+    let mut factors: Vec<&(f64, String)> = contributions.iter().filter(|(c, _)| *c >= 0.3).collect();
+    factors.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let rationale = if factors.is_empty() {
+        "No escalation factors: signals are steady, tensions are mild or answered.".to_string()
+    } else {
+        let reasons: Vec<&str> = factors.iter().map(|(_, r)| r.as_str()).collect();
+        let mut text = reasons.join("; ");
+        text[..1].make_ascii_uppercase();
+        text + "."
+    };
+
+    EscalationRisk { risk, rationale, computed_at: now }
+}
+
+fn accelerating_reason(f: &EscalationFeatures) -> String {
+    format!(
+        "signals are accelerating ({} in the last two weeks, {} before)",
+        f.recent_signals, f.prior_signals
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quiet() -> EscalationFeatures {
+        EscalationFeatures {
+            arc: SituationArc::Active,
+            recent_signals: 3,
+            prior_signals: 3,
+            tensions: 4,
+            severe_tensions: 0,
+            response_coverage: 1.0,
+            category_situations: 10,
+            category_escalated: 0,
+        }
+    }
+
+    #[test]
+    fn steady_answered_situation_is_low_risk_without_factors() {
+        let risk = estimate_escalation(&quiet(), Utc::now());
+        assert!(risk.risk < 0.15, "{}", risk.risk);
+        assert!(risk.rationale.starts_with("No escalation factors"));
+    }
+
+    #[test]
+    fn accelerating_severe_unanswered_situation_is_high_risk() {
+        let features = EscalationFeatures {
+            arc: SituationArc::Developing,
+            recent_signals: 12,
+            prior_signals: 3,
+            severe_tensions: 3,
+            response_coverage: 0.0,
+            category_escalated: 6,
+            ..quiet()
+        };
+        let risk = estimate_escalation(&features, Utc::now());
+        assert!(risk.risk > 0.9, "{}", risk.risk);
+        assert!(risk.rationale.starts_with("Signals are accelerating (12 in the last two weeks, 3 before)"));
+        assert!(risk.rationale.contains("6 of 10 similar situations escalated before"));
+    }
+
+    #[test]
+    fn thin_history_and_tiny_bursts_are_ignored() {
+        let features = EscalationFeatures {
+            recent_signals: 2,
+            prior_signals: 0,
+            category_situations: 2,
+            category_escalated: 2,
+            ..quiet()
+        };
+        assert_eq!(features.historical_rate(), None);
+        assert!(features.acceleration() < 0.7);
+    }
+}
@@ -0,0 +1,116 @@
+//! Evidence freshness: whether the page an evidence snippet came from still
+//! says what it said.
+//!
+//! Evidence is re-checked on a cadence by re-fetching its source URL. A page
+//! whose content hash still matches, or that still contains the cited
+//! passage, is current. One that no longer does has changed, and the diff is
+//! kept with the evidence; one that's gone (4xx) is removed. Evidence that
+//! can't be read for `STALE_AFTER_DAYS` is stale. Each state scales the
+//! confidence of the signal the evidence supports, and moving between states
+//! undoes the previous scaling, so repeated checks never compound.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How long a check stays good before the page is re-fetched.
+pub const RECHECK_INTERVAL_DAYS: i64 = 7;
+
+/// Evidence not read successfully for this long is stale.
+pub const STALE_AFTER_DAYS: i64 = 30;
+
+/// Longest drift diff kept on evidence.
+pub const MAX_DRIFT_DIFF_CHARS: usize = 2_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceFreshness {
+    Current,
+    Changed,
+    Removed,
+    Stale,
+}
+
+impl EvidenceFreshness {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvidenceFreshness::Current => "current",
+            EvidenceFreshness::Changed => "changed",
+            EvidenceFreshness::Removed => "removed",
+            EvidenceFreshness::Stale => "stale",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "current" => Some(EvidenceFreshness::Current),
+            "changed" => Some(EvidenceFreshness::Changed),
+            "removed" => Some(EvidenceFreshness::Removed),
+            "stale" => Some(EvidenceFreshness::Stale),
+            _ => None,
+        }
+    }
+
+    /// Multiplier on the supported signal's confidence.
+    pub fn confidence_factor(&self) -> f64 {
+        match self {
+            EvidenceFreshness::Current => 1.0,
+            EvidenceFreshness::Stale => 0.95,
+            EvidenceFreshness::Changed => 0.9,
+            EvidenceFreshness::Removed => 0.75,
+        }
+    }
+
+    /// Factor that moves a signal's confidence from `before` (None when the
+    /// evidence was never checked) to `self`.
+    pub fn confidence_adjustment(&self, before: Option<EvidenceFreshness>) -> f64 {
+        let before = before.unwrap_or(EvidenceFreshness::Current);
+        self.confidence_factor() / before.confidence_factor()
+    }
+}
+
+/// The latest re-check of a piece of evidence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidenceVerification {
+    pub freshness: EvidenceFreshness,
+    pub checked_at: DateTime<Utc>,
+    /// What changed on the page, when it changed.
+    pub drift_diff: Option<String>,
+}
+
+/// Evidence due for a re-check, with what it was last known to say.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidenceRecheck {
+    pub evidence_id: Uuid,
+    pub source_url: String,
+    /// Hash of the page content the evidence was taken from.
+    pub content_hash: String,
+    pub snippet: Option<String>,
+    /// Freshness from the previous check; None if never checked.
+    pub freshness: Option<EvidenceFreshness>,
+    /// When the page was last read successfully (or first retrieved).
+    pub verified_at: DateTime<Utc>,
+}
+
+impl EvidenceRecheck {
+    /// Whether the evidence has gone unread long enough to be stale.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        now - self.verified_at > Duration::days(STALE_AFTER_DAYS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returning_to_current_undoes_the_confidence_penalty() {
+        let removed = EvidenceFreshness::Removed.confidence_adjustment(None);
+        let restored = EvidenceFreshness::Current.confidence_adjustment(Some(EvidenceFreshness::Removed));
+        let unchanged = EvidenceFreshness::Changed.confidence_adjustment(Some(EvidenceFreshness::Changed));
+
+        assert!(removed < 1.0);
+        assert!((0.8 * removed * restored - 0.8).abs() < 1e-9);
+        assert_eq!(unchanged, 1.0);
+    }
+}
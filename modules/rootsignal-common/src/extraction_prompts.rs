@@ -0,0 +1,649 @@
+//! Extraction prompt registry and shadow-mode evaluation.
+//!
+//! A new extractor prompt is registered as a shadow candidate for a region
+//! (or every region). While it's in shadow, the scout runs it alongside the
+//! production prompt on a sample of the content it extracts and stores both
+//! outputs as shadow samples — never in the graph. A comparison report over
+//! the samples (yield, agreement, judge scores) must pass the thresholds
+//! before the candidate can be promoted to live, replacing the previous live
+//! prompt for that scope. Without a live prompt the scout uses its built-in one.
+//!
+//! Backed by the `extraction_prompts` and `shadow_extractions` Postgres tables.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub const MAX_TEMPLATE_CHARS: usize = 50_000;
+/// Page content kept on a shadow sample for the judge.
+pub const MAX_SAMPLE_CONTENT_CHARS: usize = 8_000;
+/// Title word overlap at which two signals of the same type count as the same.
+const TITLE_MATCH_JACCARD: f64 = 0.5;
+
+#[derive(Debug, Error)]
+pub enum PromptError {
+    #[error("Extraction prompt {0} not found")]
+    NotFound(Uuid),
+
+    #[error("Extraction prompt {0} is not in shadow")]
+    NotShadow(Uuid),
+
+    #[error("Extraction prompt {0} has no shadow report yet")]
+    NoReport(Uuid),
+
+    #[error("Shadow report doesn't pass: {}", .0.join("; "))]
+    ReportFailed(Vec<String>),
+
+    #[error("Database error: {0}")]
+    Db(#[from] sqlx::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptStatus {
+    /// Runs alongside production on sampled content; output is only compared.
+    Shadow,
+    /// Used for extraction in its scope.
+    Live,
+    Retired,
+}
+
+impl PromptStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Shadow => "shadow",
+            Self::Live => "live",
+            Self::Retired => "retired",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "shadow" => Some(Self::Shadow),
+            "live" => Some(Self::Live),
+            "retired" => Some(Self::Retired),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtractionPrompt {
+    pub id: Uuid,
+    /// `None` applies in every region without its own prompt.
+    pub region: Option<String>,
+    pub name: String,
+    /// Uses the scout's `{city_name}`, `{today}`, `{tension_cats}`,
+    /// `{tag_vocab_section}`, `{primary_types}`, `{context_types}` and
+    /// `{type_fields}` placeholders.
+    pub template: String,
+    pub status: PromptStatus,
+    /// Fraction of extracted content shadowed while in shadow.
+    pub sample_rate: f64,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub promoted_at: Option<DateTime<Utc>>,
+    pub report: Option<ShadowReport>,
+}
+
+/// A candidate to register.
+#[derive(Debug, Clone)]
+pub struct NewPrompt {
+    pub region: Option<String>,
+    pub name: String,
+    pub template: String,
+    pub sample_rate: f64,
+    pub created_by: String,
+}
+
+impl NewPrompt {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Give the prompt a name".to_string());
+        }
+        if self.template.trim().is_empty() {
+            return Err("Template is empty".to_string());
+        }
+        if self.template.chars().count() > MAX_TEMPLATE_CHARS {
+            return Err(format!("Template is longer than {MAX_TEMPLATE_CHARS} characters"));
+        }
+        if !(self.sample_rate > 0.0 && self.sample_rate <= 1.0) {
+            return Err("Sample rate must be above 0 and at most 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Whether content from `url` is shadowed at `rate`. Stable per URL, so the
+/// same pages are compared run after run.
+pub fn is_sampled(url: &str, rate: f64) -> bool {
+    let digest = Sha256::digest(url.as_bytes());
+    let bucket = u64::from_be_bytes(digest[..8].try_into().expect("digest is 32 bytes")) % 10_000;
+    (bucket as f64) < rate * 10_000.0
+}
+
+// ---------------------------------------------------------------------------
+// Samples and reports
+// ---------------------------------------------------------------------------
+
+/// The parts of an extracted signal a comparison looks at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowSignal {
+    pub signal_type: String,
+    pub title: String,
+    pub summary: String,
+}
+
+/// One piece of content extracted by both prompts.
+#[derive(Debug, Clone)]
+pub struct ShadowSample {
+    pub id: Uuid,
+    pub prompt_id: Uuid,
+    pub run_id: String,
+    pub region: String,
+    pub source_url: String,
+    pub content: String,
+    pub production: Vec<ShadowSignal>,
+    pub candidate: Vec<ShadowSignal>,
+    /// Judge scores from 1 to 5, once judged.
+    pub production_score: Option<f64>,
+    pub candidate_score: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// How a candidate compares with production over its shadow samples.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowReport {
+    pub samples: u32,
+    pub production_signals: u32,
+    pub candidate_signals: u32,
+    /// Candidate signals per production signal; `None` when only the
+    /// candidate found any.
+    pub yield_ratio: Option<f64>,
+    /// Mean per-sample agreement between the two outputs, 0 to 1.
+    pub agreement: f64,
+    pub judged: u32,
+    /// Mean judge score from 1 to 5 over judged samples.
+    pub production_score: Option<f64>,
+    pub candidate_score: Option<f64>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// What a report must show before its candidate can go live.
+#[derive(Debug, Clone, Copy)]
+pub struct ShadowThresholds {
+    pub min_samples: u32,
+    pub min_judged: u32,
+    /// Guards against a prompt that quietly drops signals.
+    pub min_yield_ratio: f64,
+    /// Guards against a prompt that floods the graph.
+    pub max_yield_ratio: f64,
+    pub min_agreement: f64,
+    /// Candidate judge score minus production's.
+    pub min_score_margin: f64,
+}
+
+impl Default for ShadowThresholds {
+    fn default() -> Self {
+        Self {
+            min_samples: 30,
+            min_judged: 20,
+            min_yield_ratio: 0.8,
+            max_yield_ratio: 1.5,
+            min_agreement: 0.5,
+            min_score_margin: 0.0,
+        }
+    }
+}
+
+impl ShadowReport {
+    pub fn build(samples: &[ShadowSample], now: DateTime<Utc>) -> Self {
+        let production_signals: usize = samples.iter().map(|s| s.production.len()).sum();
+        let candidate_signals: usize = samples.iter().map(|s| s.candidate.len()).sum();
+        let agreement = if samples.is_empty() {
+            0.0
+        } else {
+            samples.iter().map(|s| agreement(&s.production, &s.candidate)).sum::<f64>() / samples.len() as f64
+        };
+        let judged: Vec<(f64, f64)> = samples
+            .iter()
+            .filter_map(|s| Some((s.production_score?, s.candidate_score?)))
+            .collect();
+        let mean = |pick: fn(&(f64, f64)) -> f64| {
+            (!judged.is_empty()).then(|| judged.iter().map(pick).sum::<f64>() / judged.len() as f64)
+        };
+        Self {
+            samples: samples.len() as u32,
+            production_signals: production_signals as u32,
+            candidate_signals: candidate_signals as u32,
+            yield_ratio: match (production_signals, candidate_signals) {
+                (0, 0) => Some(1.0),
+                (0, _) => None,
+                (p, c) => Some(c as f64 / p as f64),
+            },
+            agreement,
+            judged: judged.len() as u32,
+            production_score: mean(|j| j.0),
+            candidate_score: mean(|j| j.1),
+            generated_at: now,
+        }
+    }
+
+    /// Why the report doesn't pass; empty when it does.
+    pub fn failures(&self, t: &ShadowThresholds) -> Vec<String> {
+        let mut failures = Vec::new();
+        if self.samples < t.min_samples {
+            failures.push(format!("{} samples, need {}", self.samples, t.min_samples));
+        }
+        match self.yield_ratio {
+            Some(ratio) if ratio < t.min_yield_ratio || ratio > t.max_yield_ratio => failures.push(format!(
+                "yield ratio {ratio:.2} outside {:.2}–{:.2}",
+                t.min_yield_ratio, t.max_yield_ratio
+            )),
+            Some(_) => {}
+            None => failures.push("production found no signals to compare yield with".to_string()),
+        }
+        if self.agreement < t.min_agreement {
+            failures.push(format!("agreement {:.2} below {:.2}", self.agreement, t.min_agreement));
+        }
+        if self.judged < t.min_judged {
+            failures.push(format!("{} judged samples, need {}", self.judged, t.min_judged));
+        }
+        if let (Some(production), Some(candidate)) = (self.production_score, self.candidate_score) {
+            if candidate - production < t.min_score_margin {
+                failures.push(format!("judge score {candidate:.2} vs production {production:.2}"));
+            }
+        }
+        failures
+    }
+}
+
+/// Dice agreement between two extractions: each candidate signal is matched
+/// to at most one production signal of the same type with a similar title.
+/// Two empty extractions agree fully.
+pub fn agreement(production: &[ShadowSignal], candidate: &[ShadowSignal]) -> f64 {
+    if production.is_empty() && candidate.is_empty() {
+        return 1.0;
+    }
+    let mut unmatched: Vec<&ShadowSignal> = production.iter().collect();
+    let mut matched = 0;
+    for c in candidate {
+        if let Some(i) = unmatched
+            .iter()
+            .position(|p| p.signal_type == c.signal_type && title_similarity(&p.title, &c.title) >= TITLE_MATCH_JACCARD)
+        {
+            unmatched.swap_remove(i);
+            matched += 1;
+        }
+    }
+    2.0 * matched as f64 / (production.len() + candidate.len()) as f64
+}
+
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let words = |s: &str| -> HashSet<String> {
+        s.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    let (a, b) = (words(a), words(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+type PromptRow = (
+    Uuid,
+    Option<String>,
+    String,
+    String,
+    String,
+    f64,
+    String,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    Option<serde_json::Value>,
+);
+
+const PROMPT_COLUMNS: &str =
+    "id, region, name, template, status, sample_rate, created_by, created_at, promoted_at, report";
+
+fn row_to_prompt(r: PromptRow) -> ExtractionPrompt {
+    ExtractionPrompt {
+        id: r.0,
+        region: r.1,
+        name: r.2,
+        template: r.3,
+        status: PromptStatus::parse(&r.4).unwrap_or(PromptStatus::Retired),
+        sample_rate: r.5,
+        created_by: r.6,
+        created_at: r.7,
+        promoted_at: r.8,
+        report: r.9.and_then(|v| serde_json::from_value(v).ok()),
+    }
+}
+
+/// Register a shadow candidate, retiring any candidate already in shadow for
+/// the same scope. Returns its id.
+pub async fn register(pool: &PgPool, prompt: &NewPrompt) -> Result<Uuid, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE extraction_prompts SET status = 'retired'
+         WHERE status = 'shadow' AND region IS NOT DISTINCT FROM $1",
+    )
+    .bind(&prompt.region)
+    .execute(&mut *tx)
+    .await?;
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO extraction_prompts (id, region, name, template, status, sample_rate, created_by)
+         VALUES ($1, $2, $3, $4, 'shadow', $5, $6)",
+    )
+    .bind(id)
+    .bind(&prompt.region)
+    .bind(prompt.name.trim())
+    .bind(&prompt.template)
+    .bind(prompt.sample_rate)
+    .bind(&prompt.created_by)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(id)
+}
+
+pub async fn get(pool: &PgPool, id: Uuid) -> Result<Option<ExtractionPrompt>, sqlx::Error> {
+    let row = sqlx::query_as::<_, PromptRow>(&format!("SELECT {PROMPT_COLUMNS} FROM extraction_prompts WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.map(row_to_prompt))
+}
+
+/// Newest first. With a region, only prompts that apply there.
+pub async fn list(pool: &PgPool, region: Option<&str>, include_retired: bool) -> Result<Vec<ExtractionPrompt>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, PromptRow>(&format!(
+        "SELECT {PROMPT_COLUMNS} FROM extraction_prompts
+         WHERE ($1::TEXT IS NULL OR region IS NULL OR region = $1)
+           AND ($2 OR status <> 'retired')
+         ORDER BY created_at DESC
+         LIMIT 200"
+    ))
+    .bind(region)
+    .bind(include_retired)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(row_to_prompt).collect())
+}
+
+/// The prompt with `status` that applies in `region`: its own, else the
+/// global one.
+pub async fn active_for(
+    pool: &PgPool,
+    region: &str,
+    status: PromptStatus,
+) -> Result<Option<ExtractionPrompt>, sqlx::Error> {
+    let row = sqlx::query_as::<_, PromptRow>(&format!(
+        "SELECT {PROMPT_COLUMNS} FROM extraction_prompts
+         WHERE status = $2 AND (region IS NULL OR region = $1)
+         ORDER BY region NULLS LAST
+         LIMIT 1"
+    ))
+    .bind(region)
+    .bind(status.as_str())
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(row_to_prompt))
+}
+
+pub async fn save_report(pool: &PgPool, id: Uuid, report: &ShadowReport) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE extraction_prompts SET report = $2 WHERE id = $1")
+        .bind(id)
+        .bind(serde_json::to_value(report).expect("report serializes"))
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Make a shadow candidate live if its saved report passes `thresholds`,
+/// retiring the live prompt it replaces.
+pub async fn promote(pool: &PgPool, id: Uuid, thresholds: &ShadowThresholds) -> Result<ExtractionPrompt, PromptError> {
+    let prompt = get(pool, id).await?.ok_or(PromptError::NotFound(id))?;
+    if prompt.status != PromptStatus::Shadow {
+        return Err(PromptError::NotShadow(id));
+    }
+    let report = prompt.report.as_ref().ok_or(PromptError::NoReport(id))?;
+    let failures = report.failures(thresholds);
+    if !failures.is_empty() {
+        return Err(PromptError::ReportFailed(failures));
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE extraction_prompts SET status = 'retired'
+         WHERE status = 'live' AND region IS NOT DISTINCT FROM $1",
+    )
+    .bind(&prompt.region)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("UPDATE extraction_prompts SET status = 'live', promoted_at = now() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(ExtractionPrompt {
+        status: PromptStatus::Live,
+        promoted_at: Some(Utc::now()),
+        ..prompt
+    })
+}
+
+/// Retire a prompt. A retired live prompt hands its scope back to the global
+/// prompt or the built-in one. Returns false if it doesn't exist.
+pub async fn retire(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("UPDATE extraction_prompts SET status = 'retired' WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+type SampleRow = (
+    Uuid,
+    Uuid,
+    String,
+    String,
+    String,
+    String,
+    serde_json::Value,
+    serde_json::Value,
+    Option<f64>,
+    Option<f64>,
+    DateTime<Utc>,
+);
+
+fn row_to_sample(r: SampleRow) -> ShadowSample {
+    ShadowSample {
+        id: r.0,
+        prompt_id: r.1,
+        run_id: r.2,
+        region: r.3,
+        source_url: r.4,
+        content: r.5,
+        production: serde_json::from_value(r.6).unwrap_or_default(),
+        candidate: serde_json::from_value(r.7).unwrap_or_default(),
+        production_score: r.8,
+        candidate_score: r.9,
+        created_at: r.10,
+    }
+}
+
+/// One piece of content both prompts extracted, to be stored.
+#[derive(Debug, Clone)]
+pub struct NewSample<'a> {
+    pub prompt_id: Uuid,
+    pub run_id: &'a str,
+    pub region: &'a str,
+    pub source_url: &'a str,
+    pub content: &'a str,
+    pub production: &'a [ShadowSignal],
+    pub candidate: &'a [ShadowSignal],
+}
+
+/// Store both outputs for one piece of content. The content is truncated to
+/// `MAX_SAMPLE_CONTENT_CHARS`.
+pub async fn record_sample(pool: &PgPool, sample: &NewSample<'_>) -> Result<(), sqlx::Error> {
+    let content: String = sample.content.chars().take(MAX_SAMPLE_CONTENT_CHARS).collect();
+    sqlx::query(
+        "INSERT INTO shadow_extractions (id, prompt_id, run_id, region, source_url, content, production, candidate)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(sample.prompt_id)
+    .bind(sample.run_id)
+    .bind(sample.region)
+    .bind(sample.source_url)
+    .bind(content)
+    .bind(serde_json::to_value(sample.production).expect("signals serialize"))
+    .bind(serde_json::to_value(sample.candidate).expect("signals serialize"))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// A candidate's samples, oldest first.
+pub async fn samples(pool: &PgPool, prompt_id: Uuid) -> Result<Vec<ShadowSample>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, SampleRow>(
+        "SELECT id, prompt_id, run_id, region, source_url, content, production, candidate,
+                production_score, candidate_score, created_at
+         FROM shadow_extractions
+         WHERE prompt_id = $1
+         ORDER BY created_at",
+    )
+    .bind(prompt_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(row_to_sample).collect())
+}
+
+pub async fn set_scores(pool: &PgPool, sample_id: Uuid, production: f64, candidate: f64) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE shadow_extractions SET production_score = $2, candidate_score = $3 WHERE id = $1")
+        .bind(sample_id)
+        .bind(production)
+        .bind(candidate)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(signal_type: &str, title: &str) -> ShadowSignal {
+        ShadowSignal {
+            signal_type: signal_type.to_string(),
+            title: title.to_string(),
+            summary: String::new(),
+        }
+    }
+
+    fn sample(production: Vec<ShadowSignal>, candidate: Vec<ShadowSignal>, scores: Option<(f64, f64)>) -> ShadowSample {
+        ShadowSample {
+            id: Uuid::new_v4(),
+            prompt_id: Uuid::nil(),
+            run_id: "run".to_string(),
+            region: "twincities".to_string(),
+            source_url: "https://example.org".to_string(),
+            content: String::new(),
+            production,
+            candidate,
+            production_score: scores.map(|s| s.0),
+            candidate_score: scores.map(|s| s.1),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn agreement_matches_same_type_with_similar_titles() {
+        let production = vec![
+            signal("aid", "Free groceries at Sabathani food shelf"),
+            signal("gathering", "Lake Street cleanup"),
+        ];
+        let candidate = vec![
+            signal("aid", "Sabathani food shelf free groceries Mondays"),
+            signal("need", "Lake Street cleanup"),
+        ];
+
+        // The aid matches; the cleanup changed type, so it doesn't.
+        assert_eq!(agreement(&production, &candidate), 0.5);
+        assert_eq!(agreement(&[], &[]), 1.0);
+        assert_eq!(agreement(&production, &[]), 0.0);
+    }
+
+    #[test]
+    fn report_totals_yield_agreement_and_judge_means() {
+        let samples = vec![
+            sample(vec![signal("aid", "Food shelf")], vec![signal("aid", "Food shelf")], Some((3.0, 4.0))),
+            sample(
+                vec![signal("need", "Coats needed")],
+                vec![signal("need", "Coats needed"), signal("tension", "Cold snap")],
+                Some((4.0, 5.0)),
+            ),
+            sample(vec![], vec![], None),
+        ];
+
+        let report = ShadowReport::build(&samples, Utc::now());
+
+        assert_eq!((report.samples, report.production_signals, report.candidate_signals), (3, 2, 3));
+        assert_eq!(report.yield_ratio, Some(1.5));
+        assert_eq!(report.judged, 2);
+        assert_eq!(report.production_score, Some(3.5));
+        assert_eq!(report.candidate_score, Some(4.5));
+        assert!((report.agreement - (1.0 + 2.0 / 3.0 + 1.0) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn report_fails_each_threshold_it_misses() {
+        let report = ShadowReport {
+            samples: 10,
+            production_signals: 20,
+            candidate_signals: 10,
+            yield_ratio: Some(0.5),
+            agreement: 0.9,
+            judged: 25,
+            production_score: Some(4.0),
+            candidate_score: Some(3.5),
+            generated_at: Utc::now(),
+        };
+
+        let failures = report.failures(&ShadowThresholds::default());
+
+        assert_eq!(failures.len(), 3, "{failures:?}");
+        assert!(failures[0].starts_with("10 samples"));
+        assert!(failures[1].starts_with("yield ratio 0.50"));
+        assert!(failures[2].starts_with("judge score 3.50"));
+    }
+
+    #[test]
+    fn sampling_is_stable_per_url_and_tracks_the_rate() {
+        let urls: Vec<String> = (0..2000).map(|i| format!("https://example.org/page/{i}")).collect();
+
+        let sampled = urls.iter().filter(|u| is_sampled(u, 0.1)).count();
+
+        assert!((150..250).contains(&sampled), "{sampled} of 2000 sampled at 10%");
+        assert!(urls.iter().all(|u| is_sampled(u, 0.1) == is_sampled(u, 0.1)));
+        assert!(urls.iter().all(|u| is_sampled(u, 1.0)));
+    }
+}
@@ -0,0 +1,303 @@
+//! Feature flags for scout behaviors that can go wrong in a region.
+//!
+//! Each flag has a built-in default, which a global override can replace and
+//! a region override can replace again. Overrides live in the
+//! `feature_flags` Postgres table and are toggled by admins at runtime; the
+//! scout resolves them once at the start of a run and records the result on
+//! the run log, so a run's output can be traced to the flags it ran with.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+/// A behavior that can be switched off per region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Flag {
+    /// Promote outbound links and social mentions found while scraping to
+    /// new sources.
+    LinkMining,
+    /// Create sources from the queries extracted signals imply.
+    SignalExpansion,
+    /// Search social platforms by discovered topics for new accounts.
+    TopicDiscovery,
+}
+
+impl Flag {
+    pub const ALL: [Flag; 3] = [Flag::LinkMining, Flag::SignalExpansion, Flag::TopicDiscovery];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LinkMining => "link_mining",
+            Self::SignalExpansion => "signal_expansion",
+            Self::TopicDiscovery => "topic_discovery",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == s)
+    }
+
+    pub fn default_enabled(&self) -> bool {
+        match self {
+            Self::LinkMining | Self::SignalExpansion | Self::TopicDiscovery => true,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::LinkMining => "Promote links and mentions found while scraping to new sources",
+            Self::SignalExpansion => "Create sources from the queries extracted signals imply",
+            Self::TopicDiscovery => "Search social platforms by discovered topics for new accounts",
+        }
+    }
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The flags a run uses, read by scout modules as plain fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureFlags {
+    pub enable_link_mining: bool,
+    pub enable_signal_expansion: bool,
+    pub enable_topic_discovery: bool,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            enable_link_mining: Flag::LinkMining.default_enabled(),
+            enable_signal_expansion: Flag::SignalExpansion.default_enabled(),
+            enable_topic_discovery: Flag::TopicDiscovery.default_enabled(),
+        }
+    }
+}
+
+impl FeatureFlags {
+    pub fn get(&self, flag: Flag) -> bool {
+        match flag {
+            Flag::LinkMining => self.enable_link_mining,
+            Flag::SignalExpansion => self.enable_signal_expansion,
+            Flag::TopicDiscovery => self.enable_topic_discovery,
+        }
+    }
+
+    pub fn set(&mut self, flag: Flag, enabled: bool) {
+        match flag {
+            Flag::LinkMining => self.enable_link_mining = enabled,
+            Flag::SignalExpansion => self.enable_signal_expansion = enabled,
+            Flag::TopicDiscovery => self.enable_topic_discovery = enabled,
+        }
+    }
+
+    /// Every flag by name, as recorded on run logs.
+    pub fn to_map(&self) -> BTreeMap<&'static str, bool> {
+        Flag::ALL.into_iter().map(|f| (f.as_str(), self.get(f))).collect()
+    }
+
+    /// The flags in `region` after applying `overrides`.
+    pub fn resolve(overrides: &[FlagOverride], region: &str) -> Self {
+        let mut flags = Self::default();
+        for state in resolve_states(overrides, Some(region)) {
+            flags.set(state.flag, state.enabled);
+        }
+        flags
+    }
+}
+
+/// Where a flag's value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagSource {
+    Default,
+    Global,
+    Region,
+}
+
+impl FlagSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Global => "global",
+            Self::Region => "region",
+        }
+    }
+}
+
+/// A stored override.
+#[derive(Debug, Clone)]
+pub struct FlagOverride {
+    pub flag: Flag,
+    /// `None` applies in every region.
+    pub region: Option<String>,
+    pub enabled: bool,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A flag's effective value and the layer it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagState {
+    pub flag: Flag,
+    pub enabled: bool,
+    pub source: FlagSource,
+}
+
+/// Every flag's value in `region`, or with global overrides only when
+/// `region` is `None`. A region override beats a global one, which beats
+/// the default.
+pub fn resolve_states(overrides: &[FlagOverride], region: Option<&str>) -> Vec<FlagState> {
+    Flag::ALL
+        .into_iter()
+        .map(|flag| {
+            let matching = |scope: Option<&str>| {
+                overrides.iter().find(|o| o.flag == flag && o.region.as_deref() == scope)
+            };
+            let (enabled, source) = match (region.and_then(|r| matching(Some(r))), matching(None)) {
+                (Some(o), _) => (o.enabled, FlagSource::Region),
+                (None, Some(o)) => (o.enabled, FlagSource::Global),
+                (None, None) => (flag.default_enabled(), FlagSource::Default),
+            };
+            FlagState { flag, enabled, source }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+type OverrideRow = (String, Option<String>, bool, String, DateTime<Utc>);
+
+/// Rows for flags this build doesn't know are skipped.
+fn row_to_override(r: OverrideRow) -> Option<FlagOverride> {
+    Some(FlagOverride {
+        flag: Flag::parse(&r.0)?,
+        region: r.1,
+        enabled: r.2,
+        updated_by: r.3,
+        updated_at: r.4,
+    })
+}
+
+/// Set `flag` globally, or in one region.
+pub async fn set_override(
+    pool: &PgPool,
+    flag: Flag,
+    region: Option<&str>,
+    enabled: bool,
+    updated_by: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO feature_flags (flag, region, enabled, updated_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (flag, (COALESCE(region, '')))
+        DO UPDATE SET enabled = EXCLUDED.enabled, updated_by = EXCLUDED.updated_by, updated_at = now()
+        "#,
+    )
+    .bind(flag.as_str())
+    .bind(region)
+    .bind(enabled)
+    .bind(updated_by)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Remove an override so the next layer down applies. Returns false if there
+/// was none.
+pub async fn clear_override(pool: &PgPool, flag: Flag, region: Option<&str>) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM feature_flags WHERE flag = $1 AND region IS NOT DISTINCT FROM $2")
+        .bind(flag.as_str())
+        .bind(region)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Global overrides, plus `region`'s when given.
+pub async fn list_overrides(pool: &PgPool, region: Option<&str>) -> Result<Vec<FlagOverride>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, OverrideRow>(
+        "SELECT flag, region, enabled, updated_by, updated_at FROM feature_flags
+         WHERE region IS NULL OR region = $1
+         ORDER BY flag, region NULLS FIRST",
+    )
+    .bind(region)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().filter_map(row_to_override).collect())
+}
+
+/// The flags a run in `region` uses. Falls back to the defaults if the
+/// overrides can't be read.
+pub async fn load_or_default(pool: &PgPool, region: &str) -> FeatureFlags {
+    match list_overrides(pool, Some(region)).await {
+        Ok(overrides) => FeatureFlags::resolve(&overrides, region),
+        Err(e) => {
+            tracing::warn!(error = %e, region, "Failed to load feature flags, using defaults");
+            FeatureFlags::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn over(flag: Flag, region: Option<&str>, enabled: bool) -> FlagOverride {
+        FlagOverride {
+            flag,
+            region: region.map(str::to_string),
+            enabled,
+            updated_by: "admin".to_string(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn region_override_beats_global_which_beats_default() {
+        let overrides = vec![
+            over(Flag::LinkMining, None, false),
+            over(Flag::LinkMining, Some("twincities"), true),
+            over(Flag::TopicDiscovery, None, false),
+        ];
+
+        let here = FeatureFlags::resolve(&overrides, "twincities");
+        let elsewhere = FeatureFlags::resolve(&overrides, "denver");
+
+        assert!(here.enable_link_mining);
+        assert!(!elsewhere.enable_link_mining);
+        assert!(!here.enable_topic_discovery && !elsewhere.enable_topic_discovery);
+        assert_eq!(here.enable_signal_expansion, Flag::SignalExpansion.default_enabled());
+    }
+
+    #[test]
+    fn states_report_which_layer_applied() {
+        let overrides = vec![
+            over(Flag::LinkMining, None, false),
+            over(Flag::SignalExpansion, Some("twincities"), false),
+        ];
+
+        let states = resolve_states(&overrides, Some("twincities"));
+
+        let source = |flag| states.iter().find(|s| s.flag == flag).unwrap().source;
+        assert_eq!(source(Flag::LinkMining), FlagSource::Global);
+        assert_eq!(source(Flag::SignalExpansion), FlagSource::Region);
+        assert_eq!(source(Flag::TopicDiscovery), FlagSource::Default);
+    }
+
+    #[test]
+    fn flag_names_round_trip() {
+        for flag in Flag::ALL {
+            assert_eq!(Flag::parse(flag.as_str()), Some(flag));
+        }
+        assert_eq!(Flag::parse("enable_everything"), None);
+    }
+}
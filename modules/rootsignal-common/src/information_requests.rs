@@ -0,0 +1,156 @@
+//! Information requests: situations asking for what they're missing.
+//!
+//! Scraping is source-driven, so a hot situation waits for its facts to turn
+//! up. Instead, weaving can leave a situation with information requests —
+//! "official confirmation of the shelter's hours" — and the scout's pull step
+//! answers them with targeted searches and page reads. Requests are taken
+//! hottest situation first, within `PULL_BUDGET_SHARE` of the run's
+//! remaining budget. What it finds is attached to the situation as evidence,
+//! with the request it answers.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Open requests a situation may have at once.
+pub const MAX_OPEN_REQUESTS_PER_SITUATION: usize = 3;
+
+/// Attempts before an unanswered request is given up on.
+pub const MAX_PULL_ATTEMPTS: u32 = 2;
+
+/// Share of the remaining budget the pull step may spend.
+pub const PULL_BUDGET_SHARE: f64 = 0.1;
+
+/// Requests answered per run, however much budget is left.
+pub const MAX_PULLS_PER_RUN: usize = 10;
+
+/// Longest question kept.
+const MAX_QUESTION_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestStatus {
+    Open,
+    Answered,
+    /// Given up on after `MAX_PULL_ATTEMPTS` fruitless attempts.
+    Unanswered,
+}
+
+impl RequestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestStatus::Open => "open",
+            RequestStatus::Answered => "answered",
+            RequestStatus::Unanswered => "unanswered",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "open" => Some(RequestStatus::Open),
+            "answered" => Some(RequestStatus::Answered),
+            "unanswered" => Some(RequestStatus::Unanswered),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationRequest {
+    pub id: Uuid,
+    pub situation_id: Uuid,
+    /// The situation's headline, for searches and reviewers.
+    pub situation_headline: String,
+    /// The situation's temperature when the request was read.
+    pub temperature: f64,
+    pub question: String,
+    pub status: RequestStatus,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub answered_at: Option<DateTime<Utc>>,
+}
+
+/// A page excerpt retrieved to answer a request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PulledEvidence {
+    pub id: Uuid,
+    pub request_id: Uuid,
+    pub situation_id: Uuid,
+    pub url: String,
+    /// The passage that answers the request, as it appears on the page.
+    pub snippet: String,
+    pub retrieved_at: DateTime<Utc>,
+}
+
+/// A question worth asking: trimmed, non-trivial and of bounded length.
+pub fn normalize_question(question: &str) -> Option<String> {
+    let question = question.split_whitespace().collect::<Vec<_>>().join(" ");
+    if question.split(' ').count() < 3 {
+        return None;
+    }
+    Some(question.chars().take(MAX_QUESTION_CHARS).collect())
+}
+
+/// Order requests for answering: hottest situation first, then fewest
+/// attempts, then oldest.
+pub fn prioritize(requests: &mut [InformationRequest]) {
+    requests.sort_by(|a, b| {
+        b.temperature
+            .total_cmp(&a.temperature)
+            .then(a.attempts.cmp(&b.attempts))
+            .then(a.created_at.cmp(&b.created_at))
+    });
+}
+
+/// How many requests the pull step can answer at `cost_cents` each, given
+/// the run's remaining budget (None when unlimited).
+pub fn pull_allowance(remaining_cents: Option<u64>, cost_cents: u64) -> usize {
+    match remaining_cents {
+        None => MAX_PULLS_PER_RUN,
+        Some(remaining) => {
+            let slice = (remaining as f64 * PULL_BUDGET_SHARE) as u64;
+            ((slice / cost_cents.max(1)) as usize).min(MAX_PULLS_PER_RUN)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(temperature: f64, attempts: u32) -> InformationRequest {
+        InformationRequest {
+            id: Uuid::new_v4(),
+            situation_id: Uuid::new_v4(),
+            situation_headline: "Shelter capacity".to_string(),
+            temperature,
+            question: "Official shelter hours".to_string(),
+            status: RequestStatus::Open,
+            attempts,
+            created_at: Utc::now(),
+            answered_at: None,
+        }
+    }
+
+    #[test]
+    fn hottest_situations_are_answered_first_within_the_budget_slice() {
+        let mut requests = vec![request(0.3, 0), request(0.9, 1), request(0.9, 0)];
+
+        prioritize(&mut requests);
+
+        assert_eq!((requests[0].temperature, requests[0].attempts), (0.9, 0));
+        assert_eq!((requests[1].temperature, requests[1].attempts), (0.9, 1));
+        assert_eq!(pull_allowance(Some(500), 10), 5);
+        assert_eq!(pull_allowance(Some(5), 10), 0);
+        assert_eq!(pull_allowance(None, 10), MAX_PULLS_PER_RUN);
+    }
+
+    #[test]
+    fn trivial_questions_are_dropped() {
+        assert_eq!(normalize_question("  hours? "), None);
+        assert_eq!(
+            normalize_question("Official   confirmation of shelter hours").as_deref(),
+            Some("Official confirmation of shelter hours")
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! The investigation queue.
+//!
+//! Signals worth investigating are queued once and kept across runs, so a
+//! run that runs out of budget leaves the rest for the next. Each run
+//! enqueues the targets it finds, then takes the highest-scoring pending
+//! entries it can pay for. Admins can pin an entry to the front of the
+//! queue or skip it.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::NodeType;
+
+/// Failed attempts before an entry is blocked.
+pub const MAX_ATTEMPTS: u32 = 3;
+/// Hours an entry can stay in progress before it's assumed abandoned (the
+/// run crashed) and returned to pending.
+pub const STALE_CLAIM_HOURS: i64 = 6;
+/// Days a done entry stays done before its signal can be queued again.
+pub const REQUEUE_AFTER_DAYS: i64 = 7;
+
+/// Days of waiting that earn the full age score.
+const AGE_SATURATION_DAYS: f64 = 7.0;
+/// Open user reports that earn the full flag score.
+const FLAG_SATURATION: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueState {
+    Pending,
+    InProgress,
+    /// Failed `MAX_ATTEMPTS` times.
+    Blocked,
+    Done,
+    /// Skipped by an admin.
+    Skipped,
+}
+
+impl QueueState {
+    pub const ALL: [QueueState; 5] = [
+        QueueState::Pending,
+        QueueState::InProgress,
+        QueueState::Blocked,
+        QueueState::Done,
+        QueueState::Skipped,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::InProgress => "in_progress",
+            Self::Blocked => "blocked",
+            Self::Done => "done",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|state| state.as_str() == s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QueueEntry {
+    pub signal_id: Uuid,
+    pub node_type: NodeType,
+    pub title: String,
+    pub source_url: String,
+    /// The signal's severity or urgency: `low` to `critical`.
+    pub severity: Option<String>,
+    pub cause_heat: f64,
+    /// Open correction requests on the signal.
+    pub user_flags: u32,
+    pub pinned: bool,
+    pub state: QueueState,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+fn severity_score(severity: Option<&str>) -> f64 {
+    match severity {
+        Some("critical") => 1.0,
+        Some("high") => 0.75,
+        Some("medium") => 0.5,
+        Some("low") => 0.25,
+        _ => 0.4,
+    }
+}
+
+impl QueueEntry {
+    /// 0–1: severity 35%, cause heat 25%, time waiting 20%, user reports
+    /// 20%. Waiting raises the score so low-severity entries aren't starved.
+    pub fn priority_score(&self, now: DateTime<Utc>) -> f64 {
+        let waited_days = (now - self.queued_at).num_hours().max(0) as f64 / 24.0;
+        0.35 * severity_score(self.severity.as_deref())
+            + 0.25 * self.cause_heat.clamp(0.0, 1.0)
+            + 0.20 * (waited_days / AGE_SATURATION_DAYS).min(1.0)
+            + 0.20 * (self.user_flags as f64 / FLAG_SATURATION).min(1.0)
+    }
+
+    /// In progress long enough that its run must have died.
+    pub fn is_stale_claim(&self, now: DateTime<Utc>) -> bool {
+        self.state == QueueState::InProgress && now - self.updated_at > Duration::hours(STALE_CLAIM_HOURS)
+    }
+}
+
+/// Queue order: pinned first, then by score.
+pub fn by_priority(a: &QueueEntry, b: &QueueEntry, now: DateTime<Utc>) -> std::cmp::Ordering {
+    b.pinned
+        .cmp(&a.pinned)
+        .then_with(|| b.priority_score(now).total_cmp(&a.priority_score(now)))
+}
+
+/// Pending entries (and abandoned claims) in the order a run should take
+/// them.
+pub fn rank(entries: &[QueueEntry], now: DateTime<Utc>) -> Vec<&QueueEntry> {
+    let mut ready: Vec<&QueueEntry> = entries
+        .iter()
+        .filter(|e| e.state == QueueState::Pending || e.is_stale_claim(now))
+        .collect();
+    ready.sort_by(|a, b| by_priority(a, b, now));
+    ready
+}
+
+/// The signals a run with room for `capacity` investigations takes.
+pub fn dequeue(entries: &[QueueEntry], capacity: usize, now: DateTime<Utc>) -> Vec<Uuid> {
+    rank(entries, now).into_iter().take(capacity).map(|e| e.signal_id).collect()
+}
+
+/// The state after a failed attempt.
+pub fn state_after_failure(attempts: u32) -> QueueState {
+    if attempts >= MAX_ATTEMPTS {
+        QueueState::Blocked
+    } else {
+        QueueState::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(severity: &str, cause_heat: f64, waited_days: i64, now: DateTime<Utc>) -> QueueEntry {
+        QueueEntry {
+            signal_id: Uuid::new_v4(),
+            node_type: NodeType::Tension,
+            title: String::new(),
+            source_url: String::new(),
+            severity: Some(severity.to_string()),
+            cause_heat,
+            user_flags: 0,
+            pinned: false,
+            state: QueueState::Pending,
+            attempts: 0,
+            last_error: None,
+            queued_at: now - Duration::days(waited_days),
+            updated_at: now - Duration::days(waited_days),
+        }
+    }
+
+    #[test]
+    fn pinned_entries_go_first_then_highest_score() {
+        let now = Utc::now();
+        let critical = entry("critical", 0.8, 0, now);
+        let low = entry("low", 0.1, 0, now);
+        let mut pinned = entry("low", 0.0, 0, now);
+        pinned.pinned = true;
+
+        let taken = dequeue(&[low.clone(), critical.clone(), pinned.clone()], 2, now);
+
+        assert_eq!(taken, vec![pinned.signal_id, critical.signal_id]);
+    }
+
+    #[test]
+    fn waiting_and_user_reports_raise_an_entry_over_a_fresh_one() {
+        let now = Utc::now();
+        let fresh = entry("medium", 0.3, 0, now);
+        let mut waiting = entry("medium", 0.3, 10, now);
+        waiting.user_flags = 2;
+
+        assert!(waiting.priority_score(now) > fresh.priority_score(now) + 0.3);
+    }
+
+    #[test]
+    fn only_pending_entries_and_abandoned_claims_are_dequeued() {
+        let now = Utc::now();
+        let mut done = entry("critical", 1.0, 0, now);
+        done.state = QueueState::Done;
+        let mut running = entry("critical", 1.0, 0, now);
+        running.state = QueueState::InProgress;
+        running.updated_at = now;
+        let mut abandoned = running.clone();
+        abandoned.signal_id = Uuid::new_v4();
+        abandoned.updated_at = now - Duration::hours(STALE_CLAIM_HOURS + 1);
+
+        let taken = dequeue(&[done, running, abandoned.clone()], 10, now);
+
+        assert_eq!(taken, vec![abandoned.signal_id]);
+    }
+
+    #[test]
+    fn repeated_failures_block_an_entry() {
+        assert_eq!(state_after_failure(1), QueueState::Pending);
+        assert_eq!(state_after_failure(MAX_ATTEMPTS), QueueState::Blocked);
+    }
+}
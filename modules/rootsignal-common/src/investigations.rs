@@ -0,0 +1,90 @@
+//! Investigation reports: the reasoning behind a confidence change.
+//!
+//! Every time the investigator looks into a signal it leaves an
+//! `Investigation` record: the question it set out to answer, how it went
+//! about it, the sources it consulted, what it found, where the signal's
+//! confidence ended up and what the work cost. Admins read these to audit why
+//! a signal became more or less trusted.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Cap on sources kept per report; the rest are counted, not listed.
+pub const MAX_SOURCES_CONSULTED: usize = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestigationFinding {
+    pub source_url: String,
+    /// DIRECT, SUPPORTING or CONTRADICTING.
+    pub relevance: String,
+    pub confidence: f32,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Investigation {
+    pub id: Uuid,
+    /// The signal or tension investigated.
+    pub signal_id: Uuid,
+    pub question: String,
+    /// The steps taken, in order, e.g. searches run and how results were judged.
+    pub method: String,
+    pub sources_consulted: Vec<String>,
+    pub findings: Vec<InvestigationFinding>,
+    pub confidence_before: f32,
+    pub confidence_after: f32,
+    pub cost_cents: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Investigation {
+    pub fn confidence_change(&self) -> f32 {
+        self.confidence_after - self.confidence_before
+    }
+}
+
+/// The question an investigation of a signal answers.
+pub fn investigation_question(signal_type: &str, title: &str) -> String {
+    format!("Can the {} \"{}\" be independently corroborated?", signal_type.to_lowercase(), title)
+}
+
+/// Deduplicate sources, keeping first-seen order, and cap the list.
+pub fn consulted_sources<I: IntoIterator<Item = String>>(urls: I) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    urls.into_iter()
+        .filter(|url| !url.is_empty() && seen.insert(url.clone()))
+        .take(MAX_SOURCES_CONSULTED)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consulted_sources_drops_repeats_and_blanks_in_order() {
+        let urls = ["https://a.org", "", "https://b.org", "https://a.org"].map(String::from);
+
+        let sources = consulted_sources(urls);
+
+        assert_eq!(sources, vec!["https://a.org", "https://b.org"]);
+    }
+
+    #[test]
+    fn consulted_sources_is_capped() {
+        let urls = (0..50).map(|i| format!("https://site{i}.org"));
+
+        let sources = consulted_sources(urls);
+
+        assert_eq!(sources.len(), MAX_SOURCES_CONSULTED);
+        assert_eq!(sources[0], "https://site0.org");
+    }
+
+    #[test]
+    fn question_names_the_signal() {
+        let question = investigation_question("Tension", "Rent hikes at Elm Towers");
+
+        assert_eq!(question, "Can the tension \"Rent hikes at Elm Towers\" be independently corroborated?");
+    }
+}
@@ -1,14 +1,113 @@
+pub mod accessibility;
+pub mod agendas;
+pub mod annotations;
+pub mod backfill;
+pub mod bootstrap_review;
+pub mod calendar;
 pub mod config;
+pub mod content_policy;
+pub mod corrections;
+#[cfg(feature = "dead-letter")]
+pub mod dead_letter;
+pub mod decisions;
+pub mod demographics;
 pub mod error;
+#[cfg(feature = "error-budget")]
+pub mod error_budget;
+pub mod escalation;
+pub mod evidence_freshness;
+#[cfg(feature = "prompt-registry")]
+pub mod extraction_prompts;
+#[cfg(feature = "feature-flags")]
+pub mod feature_flags;
+pub mod information_requests;
+pub mod investigation_queue;
+pub mod investigations;
+pub mod log_events;
+#[cfg(feature = "negative-knowledge")]
+pub mod negative_knowledge;
+pub mod onboarding;
+pub mod open_data;
+pub mod privacy;
 pub mod quality;
+pub mod read_state;
+pub mod region_membership;
+pub mod region_stats;
+pub mod registry;
+#[cfg(feature = "restate")]
+pub mod restate;
+pub mod retention;
+pub mod room_feeds;
 pub mod safety;
+pub mod schedule;
+pub mod secrets;
+pub mod service_alerts;
+#[cfg(feature = "signal-audit")]
+pub mod signal_audit;
+pub mod source_trial;
+pub mod situation_feed;
+pub mod situation_quality;
+pub mod story_energy;
+#[cfg(feature = "summary-variants")]
+pub mod summary_variants;
+pub mod subject_requests;
+#[cfg(feature = "synthesis-budget")]
+pub mod synthesis_budget;
+pub mod taxonomy;
+pub mod tenancy;
+pub mod trends;
+pub mod triggers;
 pub mod types;
+pub mod visibility;
+pub mod watchlist;
+pub mod why_not;
 
+pub use agendas::{AgendaBody, AgendaItem, Meeting, RegionAgendas};
+pub use annotations::{Annotation, AnnotationStatus, Contributor};
+pub use backfill::{Backfill, BackfillDepth, BackfillStatus};
+pub use bootstrap_review::{BootstrapCandidate, CandidateScore, ReviewStatus};
+pub use calendar::{BurstWindow, CalendarBlock, CalendarWindow, QuietHours, RegionCalendar};
 pub use config::Config;
-pub use error::RootSignalError;
+pub use content_policy::{demote_fields, lint_public_text, LintedText, PolicyAction, PolicyFinding, PolicyRule};
+pub use corrections::{CorrectionKind, CorrectionRequest, CorrectionStatus};
+pub use decisions::{Decision, DecisionKind};
+pub use demographics::{DemographicContext, RegionDemographics, TractDemographics};
+pub use error::{Classify, ClassifiedError, ErrorClass, RootSignalError};
+pub use escalation::{estimate_escalation, EscalationFeatures, EscalationRisk};
+pub use evidence_freshness::{EvidenceFreshness, EvidenceRecheck, EvidenceVerification};
+pub use information_requests::{InformationRequest, PulledEvidence, RequestStatus};
+pub use investigations::{Investigation, InvestigationFinding};
+pub use onboarding::{DryRunReport, OnboardingStage, ReadinessCheck, RegionOnboarding};
+pub use open_data::{
+    CategoryRule, OpenDataDataset, OpenDataFields, OpenDataRecord, OpenDataSignal, PortalKind, RegionOpenData,
+};
+pub use privacy::{AggregateGuard, Breakdown};
 pub use quality::*;
+pub use read_state::NewSince;
+pub use region_membership::RegionMembership;
+pub use region_stats::{RegionStats, RunMethodology};
+pub use registry::{districts_from_geojson, EntityGeometry, EntityLink, RegionRegistry, RegistryEntity, RegistryKind};
+pub use retention::{DataClass, HeldTargets, HoldTarget, LegalHold, PurgeCount, PurgeReport, RetentionPolicy};
+pub use room_feeds::{RoomFeed, RoomTarget};
 pub use safety::*;
+pub use schedule::{preview_schedule, CronExpr, RegionSchedule};
+pub use tenancy::{hash_api_key, Tenant, TenantError, TenantRegistry};
+pub use secrets::{Secret, SecretProvider};
+pub use service_alerts::{
+    AffectedEntity, AlertEffect, RegionServiceAlerts, SchoolDistrict, ServiceAlert, TransitAgency,
+};
+pub use source_trial::{TrialState, TrialTransition};
+pub use situation_feed::{FeedEntry, Milestone, SituationChange, SituationSubscription};
+pub use story_energy::{EnergyComponents, EnergySignal, StoryEnergyWeights};
+pub use subject_requests::{
+    RedactionSummary, SubjectMatch, SubjectMatchKind, SubjectRequest, SubjectRequestStatus,
+};
+pub use taxonomy::{Taxonomy, TopicCategory};
+pub use trends::{detect_trend, Trend, TrendDirection, TrendTest, TrendWindow};
+pub use triggers::{TriggerCheckpoint, TriggerFilter, TriggerItem};
 pub use types::*;
+pub use visibility::{redact_node, FieldVisibility, SignalField, Visibility};
+pub use watchlist::{BoundingBox, NotifyChannel, SavedSearch};
 
 /// Normalize a name into a URL-safe slug: lowercase, strip non-alphanumeric
 /// (keeping spaces), collapse whitespace, replace spaces with hyphens.
@@ -0,0 +1,249 @@
+//! The structured log event catalog.
+//!
+//! Log lines that alerts and dashboards rely on are declared here once,
+//! with a stable event name and stable field names. Emitting one logs at
+//! its level with `event = "<name>"` and its fields, so queries match on
+//! fields rather than message text. The reference in
+//! `docs/reference/log-events.md` is generated from this catalog; the test
+//! below fails when it's stale (`UPDATE_LOG_EVENTS=1` rewrites it).
+
+use std::fmt::Write;
+
+use tracing::field::DisplayValue;
+use uuid::Uuid;
+
+use crate::error::Classify;
+
+/// A value that can go in a catalog event field.
+pub trait LogField {
+    type Value<'a>: tracing::Value
+    where
+        Self: 'a;
+
+    fn log_value(&self) -> Self::Value<'_>;
+}
+
+macro_rules! copy_fields {
+    ($($ty:ty),*) => {
+        $(impl LogField for $ty {
+            type Value<'a> = $ty;
+
+            fn log_value(&self) -> $ty {
+                *self
+            }
+        })*
+    };
+}
+
+copy_fields!(bool, u32, u64, usize, i64, f64);
+
+impl LogField for String {
+    type Value<'a> = &'a str;
+
+    fn log_value(&self) -> &str {
+        self
+    }
+}
+
+impl LogField for &'static str {
+    type Value<'a> = &'static str;
+
+    fn log_value(&self) -> &'static str {
+        self
+    }
+}
+
+impl LogField for Uuid {
+    type Value<'a> = DisplayValue<&'a Uuid>;
+
+    fn log_value(&self) -> DisplayValue<&Uuid> {
+        tracing::field::display(self)
+    }
+}
+
+/// One catalog entry, for the generated reference.
+#[derive(Debug, Clone, Copy)]
+pub struct EventSpec {
+    pub name: &'static str,
+    pub level: &'static str,
+    pub description: &'static str,
+    /// (field, type)
+    pub fields: &'static [(&'static str, &'static str)],
+}
+
+macro_rules! log_events {
+    ($(
+        $(#[doc = $doc:literal])+
+        $level:ident $name:ident = $wire:literal { $($field:ident: $ty:ty),* $(,)? }
+    )*) => {
+        $(
+            $(#[doc = $doc])+
+            #[derive(Debug, Clone)]
+            pub struct $name {
+                $(pub $field: $ty),*
+            }
+
+            impl $name {
+                pub const NAME: &'static str = $wire;
+
+                pub fn emit(&self) {
+                    tracing::event!(
+                        tracing::Level::$level,
+                        event = $wire,
+                        $($field = LogField::log_value(&self.$field),)*
+                        $wire
+                    );
+                }
+            }
+        )*
+
+        /// Every catalog event, in declaration order.
+        pub const CATALOG: &[EventSpec] = &[$(
+            EventSpec {
+                name: $wire,
+                level: stringify!($level),
+                description: concat!($($doc, "\n"),+),
+                fields: &[$((stringify!($field), stringify!($ty))),*],
+            }
+        ),*];
+    };
+}
+
+log_events! {
+    /// A discovery finder gave up on a target. `phase` is the finder
+    /// (`investigation`, `tension_linker`, `response_finder`,
+    /// `gathering_finder`); `reason` is `max_turns` when the agent ran out
+    /// of tool turns, else the error class; `turns` is the finder's turn
+    /// limit.
+    WARN InvestigationFailed = "investigation_failed" {
+        phase: &'static str,
+        target_id: Uuid,
+        reason: &'static str,
+        turns: usize,
+        error: String,
+    }
+
+    /// A batch of signals couldn't be woven into situations. `mode` is
+    /// `weave` for new signals or `reweave` for a drifting situation's.
+    WARN SituationWeaveFailed = "situation_weave_failed" {
+        mode: &'static str,
+        error: String,
+    }
+
+    /// Content policy held a new signal as a draft for review.
+    WARN SignalHeldAsDraft = "signal_held_as_draft" {
+        signal_id: Uuid,
+        flags: usize,
+    }
+
+    /// A scheduled scout run didn't start. `stage` is where it stopped:
+    /// `load_task`, `check_running` or `dispatch`; all but a missing task
+    /// are retried on the next tick.
+    WARN ScheduledRunFailed = "scheduled_run_failed" {
+        region: String,
+        stage: &'static str,
+        error: String,
+    }
+
+    /// An operation failed more often over the supervisor's window than its
+    /// error budget allows. `operation` is `scrape` or a finder; rates are
+    /// 0.0 to 1.0.
+    WARN ErrorBudgetBurned = "error_budget_burned" {
+        region: String,
+        operation: String,
+        attempts: u32,
+        failures: u32,
+        failure_rate: f64,
+        max_failure_rate: f64,
+    }
+
+    /// An inbound webhook was turned away. `webhook` is `inbound_email` or
+    /// `apify`; `reason` is `unauthorized` or `malformed`, with the parse
+    /// error in `detail`.
+    WARN WebhookRejected = "webhook_rejected" {
+        webhook: &'static str,
+        reason: &'static str,
+        detail: String,
+    }
+}
+
+/// `max_turns` for an agent that ran out of tool turns, else the error class.
+pub fn failure_reason(error: &anyhow::Error) -> &'static str {
+    if format!("{error:#}").contains("Max turns") {
+        "max_turns"
+    } else {
+        error.class().as_str()
+    }
+}
+
+/// How a field's Rust type shows up in JSON logs.
+fn json_type(rust_type: &str) -> &'static str {
+    match rust_type {
+        "bool" => "boolean",
+        "u32" | "u64" | "usize" | "i64" => "integer",
+        "f64" => "number",
+        "Uuid" => "string (UUID)",
+        _ => "string",
+    }
+}
+
+/// The catalog as Markdown, for `docs/reference/log-events.md`.
+pub fn catalog_markdown() -> String {
+    let mut out = String::from(
+        "# Log events\n\n\
+         <!-- Generated from modules/rootsignal-common/src/log_events.rs. \
+         Regenerate with `UPDATE_LOG_EVENTS=1 cargo test -p rootsignal-common log_events`. -->\n\n\
+         Every line below is logged with `event = \"<name>\"` and the listed fields.\n",
+    );
+    for spec in CATALOG {
+        let _ = write!(out, "\n## `{}`\n\nLevel: {}\n\n", spec.name, spec.level);
+        for line in spec.description.lines() {
+            let _ = writeln!(out, "{}", line.trim());
+        }
+        out.push_str("\n| Field | Type |\n|---|---|\n");
+        for (field, ty) in spec.fields {
+            let _ = writeln!(out, "| `{field}` | {} |", json_type(ty));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../docs/reference/log-events.md");
+
+    #[test]
+    fn generated_reference_is_up_to_date() {
+        let generated = catalog_markdown();
+
+        if std::env::var("UPDATE_LOG_EVENTS").is_ok() {
+            std::fs::write(REFERENCE, &generated).unwrap();
+        }
+        let current = std::fs::read_to_string(REFERENCE).unwrap_or_default();
+
+        assert!(
+            current == generated,
+            "docs/reference/log-events.md is stale; run UPDATE_LOG_EVENTS=1 cargo test -p rootsignal-common log_events"
+        );
+    }
+
+    #[test]
+    fn event_names_are_unique() {
+        let mut names: Vec<&str> = CATALOG.iter().map(|spec| spec.name).collect();
+        names.sort();
+        names.dedup();
+
+        assert_eq!(names.len(), CATALOG.len());
+    }
+
+    #[test]
+    fn exhausted_tool_turns_are_told_apart_from_other_failures() {
+        let max_turns = anyhow::anyhow!("Max turns (8) exceeded").context("investigating signal");
+        let overloaded = anyhow::anyhow!("Claude API error (529): overloaded");
+
+        assert_eq!(failure_reason(&max_turns), "max_turns");
+        assert_eq!(failure_reason(&overloaded), "transient");
+    }
+}
@@ -0,0 +1,441 @@
+//! Negative knowledge: content the scout has learned not to store again.
+//!
+//! Debunked rumors, spam and known duplicates keep resurfacing on new pages
+//! and get extracted again run after run. Each registry entry fingerprints
+//! such content by its canonical text and, once the scout has embedded it, by
+//! its embedding, with the reason it was rejected. New extractions are checked
+//! against the active entries before a signal is created; a match is
+//! suppressed and counted against the entry. Entries can be scoped to a
+//! region and can expire, after which they stop matching.
+//!
+//! Entries come from admins and from accepted "not true" and "duplicate"
+//! corrections. Backed by the `negative_knowledge` Postgres table.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Cosine similarity at which an extraction matches an entry's embedding.
+pub const NEGATIVE_MATCH_SIMILARITY: f64 = 0.92;
+pub const MAX_NEGATIVE_TEXT_CHARS: usize = 2000;
+
+/// Why the content is unwanted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NegativeKind {
+    /// A rumor or claim shown to be false.
+    Debunked,
+    Spam,
+    /// Restates a signal the graph already has.
+    Duplicate,
+    /// Rejected by an admin for any other reason.
+    Rejected,
+}
+
+impl NegativeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Debunked => "debunked",
+            Self::Spam => "spam",
+            Self::Duplicate => "duplicate",
+            Self::Rejected => "rejected",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "debunked" => Some(Self::Debunked),
+            "spam" => Some(Self::Spam),
+            "duplicate" => Some(Self::Duplicate),
+            "rejected" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NegativeEntry {
+    pub id: Uuid,
+    /// `None` applies in every region.
+    pub region: Option<String>,
+    pub kind: NegativeKind,
+    /// The content as it was registered, for admins.
+    pub text: String,
+    pub canonical_text: String,
+    /// Filled in by the scout the first time it loads the entry.
+    pub embedding: Option<Vec<f32>>,
+    pub reason: String,
+    /// Admin id, or `correction:<id>` for entries from accepted corrections.
+    pub added_by: String,
+    pub hit_count: u32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_hit_at: Option<DateTime<Utc>>,
+}
+
+impl NegativeEntry {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_none_or(|at| at > now)
+    }
+
+    fn to_match(&self, similarity: f64) -> NegativeMatch {
+        NegativeMatch {
+            entry_id: self.id,
+            kind: self.kind,
+            reason: self.reason.clone(),
+            similarity,
+        }
+    }
+}
+
+/// An entry to register.
+#[derive(Debug, Clone)]
+pub struct NewNegativeEntry {
+    pub region: Option<String>,
+    pub kind: NegativeKind,
+    pub text: String,
+    pub reason: String,
+    pub added_by: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl NewNegativeEntry {
+    pub fn validate(&self, now: DateTime<Utc>) -> Result<(), String> {
+        if canonical_text(&self.text).is_empty() {
+            return Err("Text is empty".to_string());
+        }
+        if self.text.chars().count() > MAX_NEGATIVE_TEXT_CHARS {
+            return Err(format!("Text is longer than {MAX_NEGATIVE_TEXT_CHARS} characters"));
+        }
+        if self.reason.trim().is_empty() {
+            return Err("Give a reason".to_string());
+        }
+        if self.expires_at.is_some_and(|at| at <= now) {
+            return Err("Expiry is in the past".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Lowercase, punctuation to spaces, whitespace collapsed: the form two
+/// copies of the same text share however they were scraped.
+pub fn canonical_text(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// Matching
+// ---------------------------------------------------------------------------
+
+/// An extraction that matched an entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeMatch {
+    pub entry_id: Uuid,
+    pub kind: NegativeKind,
+    pub reason: String,
+    /// 1.0 for a canonical-text match.
+    pub similarity: f64,
+}
+
+/// The active entries for a run, checked in memory.
+#[derive(Debug, Clone, Default)]
+pub struct NegativeIndex {
+    entries: Vec<NegativeEntry>,
+}
+
+impl NegativeIndex {
+    pub fn new(entries: Vec<NegativeEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entries the scout still has to embed, as `(id, text)`.
+    pub fn missing_embeddings(&self) -> Vec<(Uuid, String)> {
+        self.entries
+            .iter()
+            .filter(|e| e.embedding.is_none())
+            .map(|e| (e.id, e.text.clone()))
+            .collect()
+    }
+
+    pub fn set_embedding(&mut self, id: Uuid, embedding: Vec<f32>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            entry.embedding = Some(embedding);
+        }
+    }
+
+    /// Whether `text` matches an active entry by canonical text.
+    pub fn check_text(&self, text: &str, now: DateTime<Utc>) -> Option<NegativeMatch> {
+        let canonical = canonical_text(text);
+        if canonical.is_empty() {
+            return None;
+        }
+        self.entries
+            .iter()
+            .find(|e| e.is_active(now) && e.canonical_text == canonical)
+            .map(|e| e.to_match(1.0))
+    }
+
+    /// The closest active entry at or above `NEGATIVE_MATCH_SIMILARITY`.
+    pub fn check_embedding(&self, embedding: &[f32], now: DateTime<Utc>) -> Option<NegativeMatch> {
+        self.entries
+            .iter()
+            .filter(|e| e.is_active(now))
+            .filter_map(|e| Some((e, cosine_similarity(embedding, e.embedding.as_deref()?))))
+            .filter(|(_, sim)| *sim >= NEGATIVE_MATCH_SIMILARITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(e, sim)| e.to_match(sim))
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    (dot / (norm_a * norm_b)) as f64
+}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+type NegativeRow = (
+    Uuid,
+    Option<String>,
+    String,
+    String,
+    String,
+    Option<Vec<f32>>,
+    String,
+    String,
+    i32,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+);
+
+const COLUMNS: &str = "id, region, kind, text, canonical_text, embedding, reason, added_by, hit_count, \
+                       created_at, expires_at, last_hit_at";
+
+fn row_to_entry(r: NegativeRow) -> NegativeEntry {
+    NegativeEntry {
+        id: r.0,
+        region: r.1,
+        kind: NegativeKind::parse(&r.2).unwrap_or(NegativeKind::Rejected),
+        text: r.3,
+        canonical_text: r.4,
+        embedding: r.5,
+        reason: r.6,
+        added_by: r.7,
+        hit_count: r.8.max(0) as u32,
+        created_at: r.9,
+        expires_at: r.10,
+        last_hit_at: r.11,
+    }
+}
+
+/// Register an entry. Registering the same text for the same region again
+/// replaces its kind, reason and expiry and clears its embedding. Returns the
+/// entry id.
+pub async fn add(pool: &PgPool, entry: &NewNegativeEntry) -> Result<Uuid, sqlx::Error> {
+    let (id,): (Uuid,) = sqlx::query_as(
+        r#"
+        INSERT INTO negative_knowledge (id, region, kind, text, canonical_text, reason, added_by, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT ((COALESCE(region, '')), canonical_text)
+        DO UPDATE SET
+            kind = EXCLUDED.kind,
+            text = EXCLUDED.text,
+            reason = EXCLUDED.reason,
+            added_by = EXCLUDED.added_by,
+            expires_at = EXCLUDED.expires_at,
+            embedding = NULL
+        RETURNING id
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(&entry.region)
+    .bind(entry.kind.as_str())
+    .bind(entry.text.trim())
+    .bind(canonical_text(&entry.text))
+    .bind(entry.reason.trim())
+    .bind(&entry.added_by)
+    .bind(entry.expires_at)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Delete an entry. Returns false if it doesn't exist.
+pub async fn remove(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM negative_knowledge WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Newest entries first. With a region, only entries that apply there.
+pub async fn list(
+    pool: &PgPool,
+    region: Option<&str>,
+    include_expired: bool,
+    limit: u32,
+) -> Result<Vec<NegativeEntry>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, NegativeRow>(&format!(
+        "SELECT {COLUMNS} FROM negative_knowledge
+         WHERE ($1::TEXT IS NULL OR region IS NULL OR region = $1)
+           AND ($2 OR expires_at IS NULL OR expires_at > now())
+         ORDER BY created_at DESC
+         LIMIT $3"
+    ))
+    .bind(region)
+    .bind(include_expired)
+    .bind(limit.min(500) as i64)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(row_to_entry).collect())
+}
+
+/// The unexpired entries that apply in `region`.
+pub async fn active(pool: &PgPool, region: &str) -> Result<Vec<NegativeEntry>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, NegativeRow>(&format!(
+        "SELECT {COLUMNS} FROM negative_knowledge
+         WHERE (region IS NULL OR region = $1)
+           AND (expires_at IS NULL OR expires_at > now())"
+    ))
+    .bind(region)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(row_to_entry).collect())
+}
+
+pub async fn set_embedding(pool: &PgPool, id: Uuid, embedding: &[f32]) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE negative_knowledge SET embedding = $2 WHERE id = $1")
+        .bind(id)
+        .bind(embedding)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Count suppressed extractions against their entries.
+pub async fn record_hits(pool: &PgPool, hits: &HashMap<Uuid, u32>) -> Result<(), sqlx::Error> {
+    if hits.is_empty() {
+        return Ok(());
+    }
+    let (ids, counts): (Vec<Uuid>, Vec<i32>) = hits.iter().map(|(id, n)| (*id, *n as i32)).unzip();
+    sqlx::query(
+        r#"
+        UPDATE negative_knowledge n
+        SET hit_count = n.hit_count + h.hits, last_hit_at = now()
+        FROM UNNEST($1::UUID[], $2::INT[]) AS h(id, hits)
+        WHERE n.id = h.id
+        "#,
+    )
+    .bind(&ids)
+    .bind(&counts)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(text: &str, embedding: Option<Vec<f32>>, expires_at: Option<DateTime<Utc>>) -> NegativeEntry {
+        NegativeEntry {
+            id: Uuid::new_v4(),
+            region: None,
+            kind: NegativeKind::Debunked,
+            text: text.to_string(),
+            canonical_text: canonical_text(text),
+            embedding,
+            reason: "Debunked by the county".to_string(),
+            added_by: "admin".to_string(),
+            hit_count: 0,
+            created_at: Utc::now(),
+            expires_at,
+            last_hit_at: None,
+        }
+    }
+
+    #[test]
+    fn canonical_text_ignores_case_punctuation_and_spacing() {
+        assert_eq!(canonical_text("  FEMA is handing out $750 cash cards!!"), "fema is handing out 750 cash cards");
+        assert_eq!(canonical_text("FEMA is handing out $750\ncash-cards"), "fema is handing out 750 cash cards");
+    }
+
+    #[test]
+    fn reworded_copy_matches_by_text() {
+        let index = NegativeIndex::new(vec![entry("FEMA is handing out $750 cash cards", None, None)]);
+        let hit = index.check_text("fema is handing out 750 cash cards.", Utc::now()).unwrap();
+        assert_eq!(hit.similarity, 1.0);
+        assert_eq!(hit.kind, NegativeKind::Debunked);
+        assert!(index.check_text("FEMA disaster recovery center opens", Utc::now()).is_none());
+    }
+
+    #[test]
+    fn close_embedding_matches_and_distant_one_does_not() {
+        let index = NegativeIndex::new(vec![entry("FEMA cash cards", Some(vec![1.0, 0.0, 0.0]), None)]);
+        let now = Utc::now();
+        assert!(index.check_embedding(&[0.99, 0.1, 0.0], now).is_some());
+        assert!(index.check_embedding(&[0.5, 0.5, 0.5], now).is_none());
+    }
+
+    #[test]
+    fn expired_entry_stops_matching() {
+        let now = Utc::now();
+        let index = NegativeIndex::new(vec![entry(
+            "FEMA cash cards",
+            Some(vec![1.0, 0.0]),
+            Some(now - Duration::hours(1)),
+        )]);
+        assert!(index.check_text("FEMA cash cards", now).is_none());
+        assert!(index.check_embedding(&[1.0, 0.0], now).is_none());
+    }
+
+    #[test]
+    fn new_entry_needs_text_reason_and_future_expiry() {
+        let now = Utc::now();
+        let mut new = NewNegativeEntry {
+            region: None,
+            kind: NegativeKind::Spam,
+            text: "Work from home, $500/day".to_string(),
+            reason: "Recurring spam".to_string(),
+            added_by: "admin".to_string(),
+            expires_at: Some(now + Duration::days(30)),
+        };
+        assert!(new.validate(now).is_ok());
+
+        new.expires_at = Some(now - Duration::days(1));
+        assert!(new.validate(now).is_err());
+        new.expires_at = None;
+        new.reason = " ".to_string();
+        assert!(new.validate(now).is_err());
+        new.reason = "Recurring spam".to_string();
+        new.text = "!!!".to_string();
+        assert!(new.validate(now).is_err());
+    }
+}
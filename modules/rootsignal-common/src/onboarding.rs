@@ -0,0 +1,190 @@
+//! Self-serve region onboarding.
+//!
+//! `createRegion` geocodes a place, creates the region's scout task and
+//! starts the onboarding workflow, which bootstraps sources, seeds a paused
+//! schedule and the region's synthesis budget, and dry-runs the approved
+//! sources: each is fetched, nothing is extracted or stored. The region goes
+//! active (its schedule unpaused) once the readiness checklist passes: at
+//! least `MIN_APPROVED_SOURCES` approved bootstrap sources and a clean dry
+//! run. Until then it waits for bootstrap review, and can be re-checked.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Approved bootstrap sources a region needs before going active.
+pub const MIN_APPROVED_SOURCES: u32 = 10;
+
+/// Approved sources fetched by the dry run.
+pub const DRY_RUN_SAMPLE: usize = 10;
+
+/// Share of dry-run fetches that may fail for the run to count as clean.
+pub const MAX_DRY_RUN_FAILURE_SHARE: f64 = 0.2;
+
+/// Schedule seeded for a new region: daily at 6am local time, paused until
+/// the region goes active.
+pub const DEFAULT_SCHEDULE_CRON: &str = "0 6 * * *";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStage {
+    Bootstrapping,
+    Seeding,
+    DryRun,
+    /// The checklist didn't pass; usually waiting on bootstrap review.
+    AwaitingReview,
+    Active,
+    Failed,
+}
+
+impl OnboardingStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnboardingStage::Bootstrapping => "bootstrapping",
+            OnboardingStage::Seeding => "seeding",
+            OnboardingStage::DryRun => "dry_run",
+            OnboardingStage::AwaitingReview => "awaiting_review",
+            OnboardingStage::Active => "active",
+            OnboardingStage::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "bootstrapping" => Some(OnboardingStage::Bootstrapping),
+            "seeding" => Some(OnboardingStage::Seeding),
+            "dry_run" => Some(OnboardingStage::DryRun),
+            "awaiting_review" => Some(OnboardingStage::AwaitingReview),
+            "active" => Some(OnboardingStage::Active),
+            "failed" => Some(OnboardingStage::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Result of fetching a sample of the region's approved sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DryRunReport {
+    pub fetched: u32,
+    pub failed: u32,
+}
+
+impl DryRunReport {
+    /// Something was fetched and few enough fetches failed.
+    pub fn is_clean(&self) -> bool {
+        let attempted = self.fetched + self.failed;
+        attempted > 0 && self.failed as f64 <= attempted as f64 * MAX_DRY_RUN_FAILURE_SHARE
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// The checks a region must pass before going active.
+pub fn readiness_checklist(approved_sources: u32, dry_run: Option<DryRunReport>) -> Vec<ReadinessCheck> {
+    let dry_run_detail = match dry_run {
+        Some(r) => format!("{} of {} sources fetched", r.fetched, r.fetched + r.failed),
+        None => "not run yet".to_string(),
+    };
+    vec![
+        ReadinessCheck {
+            name: "approved_sources".to_string(),
+            passed: approved_sources >= MIN_APPROVED_SOURCES,
+            detail: format!("{approved_sources} of {MIN_APPROVED_SOURCES} approved sources"),
+        },
+        ReadinessCheck {
+            name: "dry_run".to_string(),
+            passed: dry_run.is_some_and(|r| r.is_clean()),
+            detail: dry_run_detail,
+        },
+    ]
+}
+
+/// A region's onboarding, keyed by region slug.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionOnboarding {
+    pub region: String,
+    /// The geocoded place name.
+    pub name: String,
+    pub task_id: String,
+    pub timezone: String,
+    pub stage: OnboardingStage,
+    /// Latest progress, e.g. "Bootstrap created 42 sources".
+    pub progress: String,
+    pub dry_run: Option<DryRunReport>,
+    pub checklist: Vec<ReadinessCheck>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RegionOnboarding {
+    pub fn new(region: &str, name: &str, task_id: &str, timezone: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            region: region.to_string(),
+            name: name.to_string(),
+            task_id: task_id.to_string(),
+            timezone: timezone.to_string(),
+            stage: OnboardingStage::Bootstrapping,
+            progress: "Queued".to_string(),
+            dry_run: None,
+            checklist: readiness_checklist(0, None),
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Move to `stage` with a progress note.
+    pub fn advance(&mut self, stage: OnboardingStage, progress: impl Into<String>) {
+        self.stage = stage;
+        self.progress = progress.into();
+        self.updated_at = Utc::now();
+    }
+
+    /// Re-evaluate the checklist; the stage becomes active or awaiting review.
+    /// Returns whether the region is ready.
+    pub fn evaluate(&mut self, approved_sources: u32) -> bool {
+        self.checklist = readiness_checklist(approved_sources, self.dry_run);
+        let ready = self.checklist.iter().all(|c| c.passed);
+        let progress = if ready {
+            "Readiness checklist passed".to_string()
+        } else {
+            let failing: Vec<&str> = self.checklist.iter().filter(|c| !c.passed).map(|c| c.detail.as_str()).collect();
+            format!("Not ready: {}", failing.join("; "))
+        };
+        let stage = if ready { OnboardingStage::Active } else { OnboardingStage::AwaitingReview };
+        self.advance(stage, progress);
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_goes_active_only_with_enough_approved_sources_and_a_clean_dry_run() {
+        let mut onboarding = RegionOnboarding::new("northfield", "Northfield, MN", "task-1", "America/Chicago");
+
+        onboarding.dry_run = Some(DryRunReport { fetched: 7, failed: 3 });
+        assert!(!onboarding.evaluate(MIN_APPROVED_SOURCES));
+        assert_eq!(onboarding.stage, OnboardingStage::AwaitingReview);
+        assert_eq!(onboarding.progress, "Not ready: 7 of 10 sources fetched");
+
+        onboarding.dry_run = Some(DryRunReport { fetched: 9, failed: 1 });
+        assert!(!onboarding.evaluate(MIN_APPROVED_SOURCES - 1));
+        assert!(onboarding.evaluate(MIN_APPROVED_SOURCES));
+        assert_eq!(onboarding.stage, OnboardingStage::Active);
+    }
+
+    #[test]
+    fn dry_run_that_fetched_nothing_is_not_clean() {
+        assert!(!DryRunReport { fetched: 0, failed: 0 }.is_clean());
+        assert!(DryRunReport { fetched: 4, failed: 1 }.is_clean());
+    }
+}
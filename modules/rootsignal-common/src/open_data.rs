@@ -0,0 +1,229 @@
+//! Per-region open-data datasets: city 311 service requests and similar feeds.
+//!
+//! Each region lists the datasets it ingests, which portal serves them
+//! (Socrata or CKAN), which columns hold the fields we need, and how the
+//! city's request categories map onto signals. Categories with no rule are
+//! not ingested: most 311 traffic (missed trash pickup, parking) isn't a
+//! community tension.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Severity;
+
+fn default_max_records() -> u32 {
+    50
+}
+
+fn default_lookback_days() -> u32 {
+    7
+}
+
+/// The open-data portal software a dataset is published on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortalKind {
+    /// Socrata (SODA API): `{base_url}/resource/{dataset_id}.json`.
+    Socrata,
+    /// CKAN datastore: `{base_url}/api/3/action/datastore_search?resource_id={dataset_id}`.
+    Ckan,
+}
+
+/// Which columns of a dataset hold each record field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenDataFields {
+    pub id: String,
+    pub category: String,
+    pub opened_at: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub latitude: Option<String>,
+    #[serde(default)]
+    pub longitude: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+/// The signal a matching record becomes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenDataSignal {
+    /// A problem residents are living with (housing complaint, broken streetlight).
+    Tension,
+    /// An official advisory (boil-water notice, road closure).
+    Notice,
+}
+
+/// Maps request categories containing `contains` (case-insensitive) to a signal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryRule {
+    pub contains: String,
+    pub signal: OpenDataSignal,
+    pub severity: Severity,
+}
+
+/// One dataset a region ingests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenDataDataset {
+    /// Human-readable name, used as the signal's source authority ("Minneapolis 311").
+    pub name: String,
+    pub portal: PortalKind,
+    /// Portal root, e.g. "https://opendata.minneapolismn.gov".
+    pub base_url: String,
+    /// Socrata four-by-four ID or CKAN resource ID.
+    pub dataset_id: String,
+    pub fields: OpenDataFields,
+    pub categories: Vec<CategoryRule>,
+    /// Newest records ingested per run.
+    #[serde(default = "default_max_records")]
+    pub max_records: u32,
+    /// Only records opened within this many days are ingested.
+    #[serde(default = "default_lookback_days")]
+    pub lookback_days: u32,
+}
+
+impl OpenDataDataset {
+    /// First rule whose pattern appears in the category. Rules are checked in order.
+    pub fn rule_for(&self, category: &str) -> Option<&CategoryRule> {
+        let lower = category.to_lowercase();
+        self.categories
+            .iter()
+            .find(|r| lower.contains(&r.contains.to_lowercase()))
+    }
+
+    /// Stable URL for one record, used as the signal's source URL.
+    pub fn record_url(&self, record_id: &str) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        let id = percent_encode(record_id);
+        match self.portal {
+            PortalKind::Socrata => format!(
+                "{base}/resource/{}.json?{}={id}",
+                self.dataset_id, self.fields.id
+            ),
+            PortalKind::Ckan => format!(
+                "{base}/api/3/action/datastore_search?resource_id={}&q={id}",
+                self.dataset_id
+            ),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.base_url.starts_with("https://") && !self.base_url.starts_with("http://") {
+            return Err(format!("Dataset '{}' base URL must be http(s)", self.name));
+        }
+        if self.dataset_id.trim().is_empty() {
+            return Err(format!("Dataset '{}' has no dataset ID", self.name));
+        }
+        if self.categories.is_empty() {
+            return Err(format!("Dataset '{}' maps no categories, so nothing would be ingested", self.name));
+        }
+        if self.categories.iter().any(|r| r.contains.trim().is_empty()) {
+            return Err(format!("Dataset '{}' has an empty category pattern", self.name));
+        }
+        if self.max_records == 0 || self.lookback_days == 0 {
+            return Err(format!("Dataset '{}' max records and lookback days must be positive", self.name));
+        }
+        Ok(())
+    }
+}
+
+/// The open-data datasets configured for one region.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionOpenData {
+    #[serde(default)]
+    pub datasets: Vec<OpenDataDataset>,
+}
+
+impl RegionOpenData {
+    pub fn validate(&self) -> Result<(), String> {
+        for dataset in &self.datasets {
+            dataset.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encode everything outside the URL-unreserved set.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// A record read from a dataset, normalized through its field mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenDataRecord {
+    pub id: String,
+    pub category: String,
+    pub description: Option<String>,
+    pub opened_at: Option<DateTime<Utc>>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub address: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minneapolis_311() -> OpenDataDataset {
+        OpenDataDataset {
+            name: "Minneapolis 311".to_string(),
+            portal: PortalKind::Socrata,
+            base_url: "https://opendata.minneapolismn.gov/".to_string(),
+            dataset_id: "abcd-1234".to_string(),
+            fields: OpenDataFields {
+                id: "case_id".to_string(),
+                category: "request_type".to_string(),
+                opened_at: "opened_date".to_string(),
+                description: None,
+                latitude: None,
+                longitude: None,
+                address: None,
+            },
+            categories: vec![
+                CategoryRule {
+                    contains: "No Heat".to_string(),
+                    signal: OpenDataSignal::Tension,
+                    severity: Severity::High,
+                },
+                CategoryRule {
+                    contains: "housing".to_string(),
+                    signal: OpenDataSignal::Tension,
+                    severity: Severity::Medium,
+                },
+            ],
+            max_records: default_max_records(),
+            lookback_days: default_lookback_days(),
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_wins_case_insensitively() {
+        let ds = minneapolis_311();
+        assert_eq!(ds.rule_for("Housing - No heat").unwrap().severity, Severity::High);
+        assert_eq!(ds.rule_for("HOUSING - Pests").unwrap().severity, Severity::Medium);
+        assert!(ds.rule_for("Abandoned Vehicle").is_none());
+    }
+
+    #[test]
+    fn socrata_record_url_filters_the_resource_by_id() {
+        assert_eq!(
+            minneapolis_311().record_url("SR 42"),
+            "https://opendata.minneapolismn.gov/resource/abcd-1234.json?case_id=SR%2042"
+        );
+    }
+
+    #[test]
+    fn datasets_without_category_rules_are_rejected() {
+        let mut ds = minneapolis_311();
+        ds.categories.clear();
+        assert!(RegionOpenData { datasets: vec![ds] }.validate().is_err());
+        assert!(RegionOpenData { datasets: vec![minneapolis_311()] }.validate().is_ok());
+    }
+}
@@ -0,0 +1,143 @@
+//! Small-area aggregate protection (k-anonymity).
+//!
+//! A count of "2 needs in this ward" can point at a household. Every
+//! aggregate the public readers return goes through a region's
+//! `AggregateGuard`: counts below `min_group_size` ("k") are folded into an
+//! "other" bucket or withheld, cells that include sensitive signals are
+//! always folded, and neighborhood trends touching sensitive signals are
+//! dropped in favor of the region-wide trend.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Trend;
+
+pub const DEFAULT_MIN_GROUP_SIZE: u32 = 5;
+pub const MAX_MIN_GROUP_SIZE: u32 = 50;
+
+/// A region's aggregate privacy setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregateGuard {
+    /// Smallest group of signals a published number may describe.
+    pub min_group_size: u32,
+}
+
+impl Default for AggregateGuard {
+    fn default() -> Self {
+        Self {
+            min_group_size: DEFAULT_MIN_GROUP_SIZE,
+        }
+    }
+}
+
+/// Counts by key as published. Each shown cell, `other` (when nonzero) and
+/// `total` are at least `min_group_size`; `total` is None when the whole
+/// aggregate is withheld.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakdown<K> {
+    pub cells: Vec<(K, u32)>,
+    /// Folded cells: too small to show, sensitive, or folded to keep another
+    /// cell from being worked out by subtraction.
+    pub other: u32,
+    pub total: Option<u32>,
+}
+
+impl<K> Default for Breakdown<K> {
+    fn default() -> Self {
+        Self {
+            cells: Vec::new(),
+            other: 0,
+            total: None,
+        }
+    }
+}
+
+impl AggregateGuard {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(2..=MAX_MIN_GROUP_SIZE).contains(&self.min_group_size) {
+            return Err(format!("min_group_size must be between 2 and {MAX_MIN_GROUP_SIZE}"));
+        }
+        Ok(())
+    }
+
+    /// Guard a breakdown of `(key, count, includes_sensitive)` cells.
+    pub fn breakdown<K>(&self, cells: Vec<(K, u32, bool)>) -> Breakdown<K> {
+        let k = self.min_group_size;
+        let total: u32 = cells.iter().map(|(_, count, _)| count).sum();
+        if total < k {
+            return Breakdown::default();
+        }
+
+        let mut other = 0;
+        let mut shown = Vec::new();
+        for (key, count, sensitive) in cells {
+            if sensitive || count < k {
+                other += count;
+            } else {
+                shown.push((key, count));
+            }
+        }
+        shown.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        // A small "other" could be recovered as total minus the shown cells,
+        // so fold the smallest shown cells in until it's big enough.
+        while other > 0 && other < k {
+            match shown.pop() {
+                Some((_, count)) => other += count,
+                None => break,
+            }
+        }
+
+        Breakdown {
+            cells: shown,
+            other,
+            total: Some(total),
+        }
+    }
+
+    /// Guard a trend. Trends on fewer than k signals are dropped, as are
+    /// neighborhood trends with sensitive signals (the region-wide trend still
+    /// covers them). Weekly series with any nonzero week under k are withheld.
+    pub fn trend(&self, mut trend: Trend, includes_sensitive: bool) -> Option<Trend> {
+        let k = self.min_group_size;
+        if trend.observed < k || (includes_sensitive && trend.neighborhood.is_some()) {
+            return None;
+        }
+        if trend.weekly_counts.iter().any(|&count| count > 0 && count < k) {
+            trend.weekly_counts.clear();
+        }
+        Some(trend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(k: u32) -> AggregateGuard {
+        AggregateGuard { min_group_size: k }
+    }
+
+    #[test]
+    fn small_and_sensitive_cells_fold_into_other() {
+        let b = guard(5).breakdown(vec![("need", 9, false), ("aid", 6, false), ("tension", 7, true), ("notice", 2, false)]);
+        assert_eq!(b.cells, vec![("need", 9), ("aid", 6)]);
+        assert_eq!(b.other, 9);
+        assert_eq!(b.total, Some(24));
+    }
+
+    #[test]
+    fn other_below_k_pulls_in_the_smallest_cell() {
+        // Showing need and aid would reveal notice = 2 by subtraction.
+        let b = guard(5).breakdown(vec![("need", 9, false), ("aid", 6, false), ("notice", 2, false)]);
+        assert_eq!(b.cells, vec![("need", 9)]);
+        assert_eq!(b.other, 8);
+        assert_eq!(b.total, Some(17));
+    }
+
+    #[test]
+    fn totals_under_k_are_withheld() {
+        let b = guard(5).breakdown(vec![("need", 3, false), ("aid", 1, false)]);
+        assert_eq!(b, Breakdown::default());
+        assert!(guard(1).validate().is_err());
+        assert!(guard(5).validate().is_ok());
+    }
+}
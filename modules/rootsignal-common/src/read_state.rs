@@ -0,0 +1,165 @@
+//! Read state: what's new for a visitor since they last looked.
+//!
+//! There are no accounts behind this. The client generates a random token
+//! and keeps it in local storage; the server keeps only a hash of it and,
+//! per region, the time the visitor last caught up. No IP, user agent or
+//! other request data is stored, and states unused for
+//! `READ_STATE_TTL_DAYS` are deleted.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{AggregateGuard, Breakdown, Node, NodeType, SensitivityLevel};
+
+/// How far back a first visit's "new" reaches.
+pub const FIRST_VISIT_LOOKBACK_DAYS: i64 = 7;
+
+/// The furthest back "new" reaches, however long the visitor was away.
+pub const MAX_LOOKBACK_DAYS: i64 = 30;
+
+/// Read states not updated for this long are deleted.
+pub const READ_STATE_TTL_DAYS: i64 = 90;
+
+/// Tokens are client-generated random strings (a UUID or base64url bytes).
+/// Anything shorter is guessable; anything else isn't one of ours.
+pub fn validate_read_token(token: &str) -> Result<(), String> {
+    if !(16..=128).contains(&token.len()) {
+        return Err("Read token must be 16–128 characters".to_string());
+    }
+    if !token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err("Read token must be URL-safe (letters, digits, '-', '_')".to_string());
+    }
+    Ok(())
+}
+
+/// Where "new" starts: the visitor's watermark, or the first-visit lookback
+/// when there is none, never further back than `MAX_LOOKBACK_DAYS`.
+pub fn new_since_start(watermark: Option<DateTime<Utc>>, now: DateTime<Utc>) -> DateTime<Utc> {
+    let floor = now - Duration::days(MAX_LOOKBACK_DAYS);
+    watermark
+        .unwrap_or(now - Duration::days(FIRST_VISIT_LOOKBACK_DAYS))
+        .max(floor)
+}
+
+/// Signals extracted after `since`: counts per type and the top few.
+/// Counts go through the region's `AggregateGuard`, so `counts.total` is
+/// None when too few signals are new to publish a number.
+#[derive(Debug, Clone)]
+pub struct NewSince {
+    pub since: DateTime<Utc>,
+    /// Types with enough new signals to show, most first.
+    pub counts: Breakdown<NodeType>,
+    /// Hottest first, then newest.
+    pub top: Vec<Node>,
+}
+
+impl NewSince {
+    pub fn collect<'a>(
+        signals: impl IntoIterator<Item = &'a Node>,
+        since: DateTime<Utc>,
+        limit: usize,
+        guard: &AggregateGuard,
+    ) -> Self {
+        let mut new: Vec<&Node> = signals
+            .into_iter()
+            .filter(|n| n.meta().is_some_and(|m| m.extracted_at > since))
+            .collect();
+
+        let mut counts: Vec<(NodeType, u32, bool)> = Vec::new();
+        for node in &new {
+            let sensitive = node.meta().is_some_and(|m| m.sensitivity == SensitivityLevel::Sensitive);
+            match counts.iter_mut().find(|(t, _, _)| *t == node.node_type()) {
+                Some((_, count, s)) => {
+                    *count += 1;
+                    *s |= sensitive;
+                }
+                None => counts.push((node.node_type(), 1, sensitive)),
+            }
+        }
+
+        new.sort_by(|a, b| {
+            let (a, b) = (a.meta(), b.meta());
+            let heat = |m: Option<&crate::NodeMeta>| m.map(|m| m.cause_heat).unwrap_or(0.0);
+            heat(b)
+                .total_cmp(&heat(a))
+                .then_with(|| b.map(|m| m.extracted_at).cmp(&a.map(|m| m.extracted_at)))
+        });
+        new.truncate(limit);
+
+        Self {
+            since,
+            counts: guard.breakdown(counts),
+            top: new.into_iter().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NodeMeta, NoticeNode, SensitivityLevel, Severity};
+    use uuid::Uuid;
+
+    fn notice(extracted_at: DateTime<Utc>, cause_heat: f64) -> Node {
+        Node::Notice(NoticeNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: "Water main break".to_string(),
+                summary: String::new(),
+                sensitivity: SensitivityLevel::General,
+                confidence: 0.8,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: None,
+                about_location_name: None,
+                from_location: None,
+                source_url: "https://example.com".to_string(),
+                extracted_at,
+                content_date: None,
+                last_confirmed_active: extracted_at,
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat,
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                implied_queries: vec![],
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
+            },
+            severity: Severity::Medium,
+            category: None,
+            effective_date: None,
+            source_authority: None,
+        })
+    }
+
+    #[test]
+    fn only_signals_after_the_watermark_count() {
+        let now = Utc::now();
+        let since = now - Duration::days(2);
+        let signals = [
+            notice(now - Duration::days(3), 0.9),
+            notice(now - Duration::hours(5), 0.1),
+            notice(now - Duration::hours(1), 0.7),
+        ];
+        let guard = AggregateGuard { min_group_size: 2 };
+        let new = NewSince::collect(&signals, since, 1, &guard);
+        assert_eq!(new.counts.total, Some(2));
+        assert_eq!(new.counts.cells, vec![(NodeType::Notice, 2)]);
+        assert_eq!(new.top.len(), 1);
+        assert_eq!(new.top[0].id(), signals[2].id(), "hottest new signal first");
+    }
+
+    #[test]
+    fn start_falls_back_to_lookback_and_is_clamped() {
+        let now = Utc::now();
+        assert_eq!(new_since_start(None, now), now - Duration::days(FIRST_VISIT_LOOKBACK_DAYS));
+        assert_eq!(new_since_start(Some(now - Duration::days(400)), now), now - Duration::days(MAX_LOOKBACK_DAYS));
+        let recent = now - Duration::hours(3);
+        assert_eq!(new_since_start(Some(recent), now), recent);
+        assert!(validate_read_token("3f2b8c1e-9a4d-4c6b-8e1f-2a7d5c9b0e34").is_ok());
+        assert!(validate_read_token("short").is_err());
+        assert!(validate_read_token("has spaces in it, not a token").is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Signals shared between overlapping regions.
+//!
+//! Region scopes overlap (Minneapolis and St. Paul; a county and the city in
+//! it), so a signal can lie in several. Rather than belong to whichever
+//! region's scout found it first, a signal is a member of every region whose
+//! scope contains it, with one of them as its primary. A shared signal counts
+//! `1/n` toward each of its `n` regions wherever regions are attributed
+//! signals (stats, spend per signal), so overlap isn't double counted.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{haversine_km, ScoutScope};
+
+/// The regions a signal belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegionMembership {
+    /// Region slug the signal is filed under first.
+    pub primary: String,
+    /// Every member region's slug, sorted; includes the primary.
+    pub regions: Vec<String>,
+}
+
+impl RegionMembership {
+    /// Decide membership for a signal at `(lat, lng)` among `scopes`
+    /// (region slug, scope). An existing primary is kept while the signal is
+    /// still in that region; otherwise the region whose center is nearest
+    /// becomes primary. `None` when no scope contains the point.
+    pub fn assign(lat: f64, lng: f64, scopes: &[(String, ScoutScope)], current_primary: Option<&str>) -> Option<Self> {
+        let containing: Vec<&(String, ScoutScope)> =
+            scopes.iter().filter(|(_, scope)| scope.contains(lat, lng)).collect();
+        let mut regions: Vec<String> = containing.iter().map(|(slug, _)| slug.clone()).collect();
+        regions.sort();
+        regions.dedup();
+
+        let primary = match current_primary {
+            Some(current) if regions.iter().any(|r| r == current) => current.to_string(),
+            _ => containing
+                .iter()
+                .min_by(|(a_slug, a), (b_slug, b)| {
+                    haversine_km(lat, lng, a.center_lat, a.center_lng)
+                        .total_cmp(&haversine_km(lat, lng, b.center_lat, b.center_lng))
+                        .then_with(|| a_slug.cmp(b_slug))
+                })
+                .map(|(slug, _)| slug.clone())?,
+        };
+        Some(Self { primary, regions })
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.regions.len() > 1
+    }
+
+    /// The fraction of the signal attributed to each member region.
+    pub fn share(&self) -> f64 {
+        1.0 / self.regions.len().max(1) as f64
+    }
+}
+
+/// Whether two scopes' bounding boxes intersect.
+pub fn scopes_overlap(a: &ScoutScope, b: &ScoutScope) -> bool {
+    let (a_min_lat, a_max_lat, a_min_lng, a_max_lng) = a.bounding_box();
+    let (b_min_lat, b_max_lat, b_min_lng, b_max_lng) = b.bounding_box();
+    a_min_lat <= b_max_lat && b_min_lat <= a_max_lat && a_min_lng <= b_max_lng && b_min_lng <= a_max_lng
+}
+
+/// The bounding box covering `scope` and every scope in `others` that
+/// overlaps it: where a duplicate of a signal found in `scope` can be.
+pub fn overlap_bounds(scope: &ScoutScope, others: &[ScoutScope]) -> (f64, f64, f64, f64) {
+    others
+        .iter()
+        .filter(|other| scopes_overlap(scope, other))
+        .map(ScoutScope::bounding_box)
+        .fold(scope.bounding_box(), |(min_lat, max_lat, min_lng, max_lng), (o_min_lat, o_max_lat, o_min_lng, o_max_lng)| {
+            (min_lat.min(o_min_lat), max_lat.max(o_max_lat), min_lng.min(o_min_lng), max_lng.max(o_max_lng))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope(name: &str, lat: f64, lng: f64, radius_km: f64) -> (String, ScoutScope) {
+        let scope = ScoutScope { center_lat: lat, center_lng: lng, radius_km, name: name.to_string(), boundary: None };
+        (crate::slugify(name), scope)
+    }
+
+    #[test]
+    fn signal_in_the_overlap_belongs_to_both_regions_once() {
+        let scopes = vec![
+            scope("Minneapolis", 44.9778, -93.2650, 15.0),
+            scope("St. Paul", 44.9537, -93.0900, 15.0),
+            scope("Duluth", 46.7867, -92.1005, 20.0),
+        ];
+        // Near the river, closer to Minneapolis.
+        let (lat, lng) = (44.965, -93.20);
+
+        let fresh = RegionMembership::assign(lat, lng, &scopes, None).unwrap();
+        let kept = RegionMembership::assign(lat, lng, &scopes, Some("st-paul")).unwrap();
+        let moved = RegionMembership::assign(lat, lng, &scopes, Some("duluth")).unwrap();
+
+        assert_eq!(fresh.regions, vec!["minneapolis", "st-paul"]);
+        assert_eq!(fresh.primary, "minneapolis");
+        assert_eq!(kept.primary, "st-paul");
+        assert_eq!(moved.primary, "minneapolis");
+        assert!(fresh.is_shared());
+        assert_eq!(fresh.share(), 0.5);
+        assert!(RegionMembership::assign(40.0, -100.0, &scopes, None).is_none());
+    }
+
+    #[test]
+    fn overlap_bounds_reach_into_overlapping_scopes_only() {
+        let (_, mpls) = scope("Minneapolis", 44.9778, -93.2650, 15.0);
+        let (_, stp) = scope("St. Paul", 44.9537, -93.0900, 15.0);
+        let (_, duluth) = scope("Duluth", 46.7867, -92.1005, 20.0);
+
+        let bounds = overlap_bounds(&mpls, &[stp.clone(), duluth]);
+
+        assert_eq!(bounds.2, mpls.bounding_box().2);
+        assert_eq!(bounds.3, stp.bounding_box().3);
+        assert!(bounds.1 < 45.5, "Duluth doesn't overlap and is left out");
+    }
+}
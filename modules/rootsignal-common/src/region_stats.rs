@@ -0,0 +1,212 @@
+//! Materialized region statistics.
+//!
+//! The dashboard's aggregates (counts, distributions, top-N source lists)
+//! scan the whole graph. The supervisor recomputes them at the end of every
+//! scout run and the API on a timer, storing one `RegionStats` per region;
+//! the dashboard and public stats read the stored copy instead of
+//! recomputing on each load. The supervisor also records what each run spent
+//! and which extraction prompt it ran with (`RunMethodology`), for the public
+//! transparency page.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::ScrapingStrategy;
+
+/// Default minutes between timed refreshes.
+pub const STATS_REFRESH_MINUTES: i64 = 15;
+/// Stats older than this are recomputed when an admin reads them.
+pub const STATS_STALE_AFTER_MINUTES: i64 = 4 * STATS_REFRESH_MINUTES;
+/// Entries kept in each top/bottom list.
+pub const STATS_TOP_N: usize = 10;
+/// `RunMethodology::prompt_version` when no registry prompt is live.
+pub const BUILTIN_PROMPT_VERSION: &str = "built-in";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelCount {
+    pub label: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayVolume {
+    /// `YYYY-MM-DD`.
+    pub day: String,
+    pub gatherings: u64,
+    pub aids: u64,
+    pub needs: u64,
+    pub notices: u64,
+    pub tensions: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceStat {
+    pub name: String,
+    pub signals: u32,
+    pub weight: f64,
+    pub empty_runs: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YieldStat {
+    pub source_label: String,
+    pub extracted: u32,
+    pub survived: u32,
+    pub corroborated: u32,
+    pub contradicted: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GapStat {
+    pub gap_type: String,
+    pub total: u32,
+    pub successful: u32,
+    pub avg_weight: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionStats {
+    pub region: String,
+    pub computed_at: DateTime<Utc>,
+    pub total_signals: u64,
+    /// Signals this region shares with an overlapping region.
+    #[serde(default)]
+    pub shared_signals: u64,
+    /// Signals attributed to this region, shared ones counting `1/n` for `n`
+    /// member regions.
+    #[serde(default)]
+    pub attributed_signals: f64,
+    pub total_stories: u64,
+    pub total_actors: u64,
+    pub total_sources: u64,
+    pub active_sources: u64,
+    /// By signal type label, e.g. "Gathering".
+    pub count_by_type: Vec<LabelCount>,
+    pub confidence_distribution: Vec<LabelCount>,
+    pub freshness_distribution: Vec<LabelCount>,
+    /// Live stories updated in the last 30 days.
+    pub story_count_by_arc: Vec<LabelCount>,
+    pub story_count_by_category: Vec<LabelCount>,
+    /// Last 30 days, oldest first.
+    pub signal_volume_by_day: Vec<DayVolume>,
+    pub top_sources: Vec<SourceStat>,
+    pub bottom_sources: Vec<SourceStat>,
+    pub extraction_yield: Vec<YieldStat>,
+    pub gap_stats: Vec<GapStat>,
+    /// Active sources by channel (see `source_channel`).
+    #[serde(default)]
+    pub source_composition: Vec<LabelCount>,
+    /// Correction requests by status.
+    #[serde(default)]
+    pub correction_counts: Vec<LabelCount>,
+    /// Signals held back for safety by category, e.g.
+    /// "content_policy/private_individual" or "audit/hallucinated_detail".
+    #[serde(default)]
+    pub suppression_counts: Vec<LabelCount>,
+}
+
+impl RegionStats {
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        now - self.computed_at > Duration::minutes(STATS_STALE_AFTER_MINUTES)
+    }
+
+    /// Share of extracted signals still in the graph; None before anything
+    /// was extracted.
+    pub fn acceptance_rate(&self) -> Option<f64> {
+        let extracted: u64 = self.extraction_yield.iter().map(|y| y.extracted as u64).sum();
+        let survived: u64 = self.extraction_yield.iter().map(|y| y.survived as u64).sum();
+        (extracted > 0).then(|| survived.min(extracted) as f64 / extracted as f64)
+    }
+}
+
+/// What a region's latest scout run spent and ran with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunMethodology {
+    pub finished_at: DateTime<Utc>,
+    pub spent_cents: u64,
+    /// The daily budget the run worked within; 0 when unlimited.
+    pub budget_cents: u64,
+    /// The live registry prompt's name, or `BUILTIN_PROMPT_VERSION`.
+    pub prompt_version: String,
+    /// When the live registry prompt was promoted.
+    pub prompt_live_since: Option<DateTime<Utc>>,
+}
+
+/// The channel a source is read through, for source composition.
+pub fn source_channel(strategy: ScrapingStrategy) -> &'static str {
+    match strategy {
+        ScrapingStrategy::WebQuery => "search",
+        ScrapingStrategy::WebPage | ScrapingStrategy::HtmlListing { .. } => "web",
+        ScrapingStrategy::Rss => "feed",
+        ScrapingStrategy::Social(_) => "social",
+        ScrapingStrategy::Video => "video",
+        ScrapingStrategy::Podcast => "podcast",
+        ScrapingStrategy::Events => "events",
+        ScrapingStrategy::Newsletter => "newsletter",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_go_stale_after_several_missed_refreshes() {
+        let now = Utc::now();
+        let stats = RegionStats {
+            region: "twincities".to_string(),
+            computed_at: now - Duration::minutes(STATS_REFRESH_MINUTES),
+            total_signals: 0,
+            shared_signals: 0,
+            attributed_signals: 0.0,
+            total_stories: 0,
+            total_actors: 0,
+            total_sources: 0,
+            active_sources: 0,
+            count_by_type: Vec::new(),
+            confidence_distribution: Vec::new(),
+            freshness_distribution: Vec::new(),
+            story_count_by_arc: Vec::new(),
+            story_count_by_category: Vec::new(),
+            signal_volume_by_day: Vec::new(),
+            top_sources: Vec::new(),
+            bottom_sources: Vec::new(),
+            extraction_yield: Vec::new(),
+            gap_stats: Vec::new(),
+            source_composition: Vec::new(),
+            correction_counts: Vec::new(),
+            suppression_counts: Vec::new(),
+        };
+
+        assert!(!stats.is_stale(now));
+        assert!(stats.is_stale(now + Duration::minutes(STATS_STALE_AFTER_MINUTES)));
+    }
+
+    #[test]
+    fn acceptance_rate_pools_every_source_and_waits_for_extractions() {
+        let yields = |pairs: &[(u32, u32)]| {
+            pairs
+                .iter()
+                .map(|&(extracted, survived)| YieldStat {
+                    source_label: "example.org".to_string(),
+                    extracted,
+                    survived,
+                    corroborated: 0,
+                    contradicted: 0,
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut stats: RegionStats = serde_json::from_value(serde_json::json!({
+            "region": "twincities", "computed_at": Utc::now(), "total_signals": 0, "total_stories": 0,
+            "total_actors": 0, "total_sources": 0, "active_sources": 0, "count_by_type": [],
+            "confidence_distribution": [], "freshness_distribution": [], "story_count_by_arc": [],
+            "story_count_by_category": [], "signal_volume_by_day": [], "top_sources": [],
+            "bottom_sources": [], "extraction_yield": [], "gap_stats": []
+        }))
+        .expect("stats stored before transparency fields still load");
+
+        assert_eq!(stats.acceptance_rate(), None);
+        stats.extraction_yield = yields(&[(30, 24), (10, 6)]);
+        assert_eq!(stats.acceptance_rate(), Some(0.75));
+    }
+}
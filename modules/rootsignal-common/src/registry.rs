@@ -0,0 +1,489 @@
+//! Per-region entity registries: the schools, transit routes, wards, districts
+//! and parks signals talk about.
+//!
+//! Extracted signals name these places in free text ("Route 21", "Ward 9",
+//! "Lincoln Elementary"). A registry lists each region's known entities with
+//! the names they go by and, for wards, districts and parks, their boundaries,
+//! so every stored signal can be linked to the entities it mentions or lies
+//! within. Ward and district boundaries are imported from the GeoJSON cities
+//! publish (`districts_from_geojson`).
+
+use std::fmt;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::slugify;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegistryKind {
+    School,
+    Route,
+    Ward,
+    /// Council, commission or school board district.
+    District,
+    Park,
+}
+
+impl RegistryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistryKind::School => "school",
+            RegistryKind::Route => "route",
+            RegistryKind::Ward => "ward",
+            RegistryKind::District => "district",
+            RegistryKind::Park => "park",
+        }
+    }
+
+    /// Political geography: the units organizers work in.
+    pub fn is_district(&self) -> bool {
+        matches!(self, RegistryKind::Ward | RegistryKind::District)
+    }
+}
+
+impl fmt::Display for RegistryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where an entity is. Coordinates are `[lat, lng]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum EntityGeometry {
+    Point { lat: f64, lng: f64 },
+    /// Outer boundary ring; the closing point may be omitted.
+    Polygon { ring: Vec<[f64; 2]> },
+    /// Several outer rings, for districts split by a river or an annexation.
+    MultiPolygon { rings: Vec<Vec<[f64; 2]>> },
+}
+
+impl EntityGeometry {
+    /// Whether the point lies inside the boundary. Points contain nothing.
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        match self {
+            EntityGeometry::Point { .. } => false,
+            EntityGeometry::Polygon { ring } => ring_contains(ring, lat, lng),
+            EntityGeometry::MultiPolygon { rings } => rings.iter().any(|ring| ring_contains(ring, lat, lng)),
+        }
+    }
+
+    /// Bounding box `(min_lat, max_lat, min_lng, max_lng)` of a boundary.
+    pub fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let rings: &[Vec<[f64; 2]>] = match self {
+            EntityGeometry::Point { .. } => return None,
+            EntityGeometry::Polygon { ring } => std::slice::from_ref(ring),
+            EntityGeometry::MultiPolygon { rings } => rings,
+        };
+        rings.iter().flatten().fold(None, |acc, &[lat, lng]| {
+            Some(match acc {
+                None => (lat, lat, lng, lng),
+                Some((min_lat, max_lat, min_lng, max_lng)) => {
+                    (min_lat.min(lat), max_lat.max(lat), min_lng.min(lng), max_lng.max(lng))
+                }
+            })
+        })
+    }
+
+    /// Read a GeoJSON `Polygon` or `MultiPolygon` geometry, a `Feature`
+    /// wrapping one, or a `FeatureCollection` of them (merged into one
+    /// boundary). Holes are ignored.
+    pub fn from_geojson(value: &serde_json::Value) -> Result<Self, String> {
+        match value.get("type").and_then(|t| t.as_str()) {
+            Some("Feature") => Self::from_geojson(value.get("geometry").unwrap_or(&serde_json::Value::Null)),
+            Some("FeatureCollection") => {
+                let features = value.get("features").and_then(|f| f.as_array()).ok_or("no features")?;
+                let mut rings = Vec::new();
+                for feature in features {
+                    match Self::from_geojson(feature)? {
+                        EntityGeometry::Polygon { ring } => rings.push(ring),
+                        EntityGeometry::MultiPolygon { rings: more } => rings.extend(more),
+                        EntityGeometry::Point { .. } => {}
+                    }
+                }
+                match rings.len() {
+                    0 => Err("no polygons".to_string()),
+                    1 => Ok(EntityGeometry::Polygon { ring: rings.remove(0) }),
+                    _ => Ok(EntityGeometry::MultiPolygon { rings }),
+                }
+            }
+            Some("Polygon") => Ok(EntityGeometry::Polygon {
+                ring: outer_ring(geojson_coordinates(value)?),
+            }),
+            Some("MultiPolygon") => Ok(EntityGeometry::MultiPolygon {
+                rings: geojson_coordinates::<Vec<Vec<Vec<[f64; 2]>>>>(value)?
+                    .into_iter()
+                    .map(outer_ring)
+                    .collect(),
+            }),
+            other => Err(format!("unsupported geometry {other:?}")),
+        }
+    }
+
+    /// The boundary as a GeoJSON geometry, with `[lng, lat]` positions and
+    /// closed rings. Points become GeoJSON points.
+    pub fn to_geojson(&self) -> serde_json::Value {
+        let ring = |ring: &[[f64; 2]]| {
+            let mut positions: Vec<[f64; 2]> = ring.iter().map(|&[lat, lng]| [lng, lat]).collect();
+            if positions.len() > 1 && positions.first() != positions.last() {
+                positions.push(positions[0]);
+            }
+            positions
+        };
+        match self {
+            EntityGeometry::Point { lat, lng } => serde_json::json!({"type": "Point", "coordinates": [lng, lat]}),
+            EntityGeometry::Polygon { ring: r } => serde_json::json!({"type": "Polygon", "coordinates": [ring(r)]}),
+            EntityGeometry::MultiPolygon { rings } => serde_json::json!({
+                "type": "MultiPolygon",
+                "coordinates": rings.iter().map(|r| vec![ring(r)]).collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    /// Points spread across a boundary, for naming the places it covers:
+    /// the center of each part (one per side of a river-split metro), then a
+    /// `grid` × `grid` lattice over the bounding box, keeping those inside.
+    pub fn sample_points(&self, grid: usize) -> Vec<(f64, f64)> {
+        let rings: &[Vec<[f64; 2]>] = match self {
+            EntityGeometry::Point { lat, lng } => return vec![(*lat, *lng)],
+            EntityGeometry::Polygon { ring } => std::slice::from_ref(ring),
+            EntityGeometry::MultiPolygon { rings } => rings,
+        };
+        let centers = rings
+            .iter()
+            .filter_map(|ring| EntityGeometry::Polygon { ring: ring.clone() }.bounds())
+            .map(|(min_lat, max_lat, min_lng, max_lng)| ((min_lat + max_lat) / 2.0, (min_lng + max_lng) / 2.0));
+        let lattice = self.bounds().into_iter().flat_map(|(min_lat, max_lat, min_lng, max_lng)| {
+            let step = |min: f64, max: f64, i: usize| min + (max - min) * (i as f64 + 0.5) / grid as f64;
+            (0..grid).flat_map(move |i| (0..grid).map(move |j| (step(min_lat, max_lat, i), step(min_lng, max_lng, j))))
+        });
+        centers.chain(lattice).filter(|&(lat, lng)| self.contains(lat, lng)).collect()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let valid = |lat: f64, lng: f64| (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lng);
+        let rings: &[Vec<[f64; 2]>] = match self {
+            EntityGeometry::Point { lat, lng } if !valid(*lat, *lng) => return Err("invalid point".to_string()),
+            EntityGeometry::Point { .. } => return Ok(()),
+            EntityGeometry::Polygon { ring } => std::slice::from_ref(ring),
+            EntityGeometry::MultiPolygon { rings } if rings.is_empty() => {
+                return Err("a multipolygon needs at least one ring".to_string())
+            }
+            EntityGeometry::MultiPolygon { rings } => rings,
+        };
+        for ring in rings {
+            if ring.len() < 3 {
+                return Err("a polygon needs 3 or more points".to_string());
+            }
+            if ring.iter().any(|[lat, lng]| !valid(*lat, *lng)) {
+                return Err("invalid polygon coordinates".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn geojson_coordinates<T: serde::de::DeserializeOwned>(geometry: &serde_json::Value) -> Result<T, String> {
+    serde_json::from_value(geometry.get("coordinates").cloned().unwrap_or_default())
+        .map_err(|e| format!("invalid coordinates: {e}"))
+}
+
+/// The outer ring of a GeoJSON polygon, as `[lat, lng]`.
+fn outer_ring(polygon: Vec<Vec<[f64; 2]>>) -> Vec<[f64; 2]> {
+    // GeoJSON positions are [lng, lat]; the first ring of a polygon is its outer boundary.
+    polygon.into_iter().next().unwrap_or_default().into_iter().map(|[lng, lat]| [lat, lng]).collect()
+}
+
+/// Ray casting along the latitude axis.
+fn ring_contains(ring: &[[f64; 2]], lat: f64, lng: f64) -> bool {
+    let mut inside = false;
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let ([lat_i, lng_i], [lat_j, lng_j]) = (ring[i], ring[j]);
+        if (lng_i > lng) != (lng_j > lng) && lat < (lat_j - lat_i) * (lng - lng_i) / (lng_j - lng_i) + lat_i {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// How a signal relates to a registry entity; each is its own edge type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityLink {
+    /// The signal names the entity.
+    Mentions,
+    /// The signal's location lies within the entity's boundary.
+    LocatedIn,
+}
+
+impl EntityLink {
+    pub fn edge_type(&self) -> &'static str {
+        match self {
+            EntityLink::Mentions => "MENTIONS",
+            EntityLink::LocatedIn => "LOCATED_IN",
+        }
+    }
+}
+
+/// A school, route, ward, district or park known to a region.
+///
+/// Routes, wards and districts are named by their number ("21", "9", "3") and
+/// mentioned as "Route 21", "Ward 9" and "District 3"; schools and parks by
+/// their full name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryEntity {
+    pub kind: RegistryKind,
+    pub name: String,
+    /// Other ways signals refer to it ("21 bus", "Ninth Ward").
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub geometry: Option<EntityGeometry>,
+}
+
+impl RegistryEntity {
+    /// Key unique within a region ("route-21", "school-lincoln-elementary");
+    /// matches the tags service alerts put on the signals they affect.
+    pub fn key(&self) -> String {
+        format!("{}-{}", self.kind, slugify(&self.name))
+    }
+
+    /// Graph id, unique across regions.
+    pub fn node_id(&self, region_slug: &str) -> String {
+        format!("{region_slug}/{}", self.key())
+    }
+
+    /// Every phrase that counts as a mention.
+    pub fn mentions(&self) -> Vec<String> {
+        let canonical = match self.kind {
+            RegistryKind::Route => format!("Route {}", self.name),
+            RegistryKind::Ward => format!("Ward {}", self.name),
+            RegistryKind::District => format!("District {}", self.name),
+            RegistryKind::School | RegistryKind::Park => self.name.clone(),
+        };
+        std::iter::once(canonical)
+            .chain(self.aliases.iter().cloned())
+            .filter(|m| !m.trim().is_empty())
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if slugify(&self.name).is_empty() {
+            return Err(format!("A {} in the registry has no name", self.kind));
+        }
+        if let Some(geometry) = &self.geometry {
+            geometry
+                .validate()
+                .map_err(|e| format!("{} '{}': {e}", self.kind, self.name))?;
+        }
+        Ok(())
+    }
+}
+
+/// The entities a region's signals are linked to.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionRegistry {
+    #[serde(default)]
+    pub entities: Vec<RegistryEntity>,
+}
+
+impl RegionRegistry {
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let mut keys = std::collections::HashSet::new();
+        for entity in &self.entities {
+            entity.validate()?;
+            if !keys.insert(entity.key()) {
+                return Err(format!("Duplicate registry entity: {} '{}'", entity.kind, entity.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Entities `text` mentions as whole words (case-insensitive).
+    pub fn mentioned_in(&self, text: &str) -> Vec<&RegistryEntity> {
+        self.entities
+            .iter()
+            .filter(|e| {
+                e.mentions().iter().any(|m| {
+                    Regex::new(&format!(r"(?i)\b{}\b", regex::escape(m.trim()))).is_ok_and(|re| re.is_match(text))
+                })
+            })
+            .collect()
+    }
+
+    /// Replace every entity of `kind` with `entities`, keeping the rest.
+    pub fn replace_kind(&mut self, kind: RegistryKind, entities: Vec<RegistryEntity>) {
+        self.entities.retain(|e| e.kind != kind);
+        self.entities.extend(entities);
+    }
+
+    /// Entities with a boundary — the ones signals can be located in.
+    pub fn areas(&self) -> impl Iterator<Item = &RegistryEntity> {
+        self.entities.iter().filter(|e| e.geometry.as_ref().is_some_and(|g| g.bounds().is_some()))
+    }
+
+    /// Entities whose boundary contains the point.
+    pub fn containing(&self, lat: f64, lng: f64) -> Vec<&RegistryEntity> {
+        self.entities
+            .iter()
+            .filter(|e| e.geometry.as_ref().is_some_and(|g| g.contains(lat, lng)))
+            .collect()
+    }
+}
+
+/// Read ward or district boundaries from a GeoJSON `FeatureCollection` of
+/// `Polygon` and `MultiPolygon` features, naming each by its `name_property`
+/// ("WARD", "DISTRICT"). Holes are ignored.
+pub fn districts_from_geojson(
+    geojson: &str,
+    kind: RegistryKind,
+    name_property: &str,
+) -> Result<Vec<RegistryEntity>, String> {
+    let collection: serde_json::Value =
+        serde_json::from_str(geojson).map_err(|e| format!("Invalid GeoJSON: {e}"))?;
+    let features = collection
+        .get("features")
+        .and_then(|f| f.as_array())
+        .ok_or("GeoJSON must be a FeatureCollection")?;
+
+    features
+        .iter()
+        .enumerate()
+        .map(|(i, feature)| {
+            let name = match feature.pointer(&format!("/properties/{name_property}")) {
+                Some(serde_json::Value::String(s)) => s.trim().to_string(),
+                Some(serde_json::Value::Number(n)) => n.to_string(),
+                _ => return Err(format!("Feature {i} has no '{name_property}' property")),
+            };
+            let geometry = feature.get("geometry").ok_or(format!("{kind} '{name}' has no geometry"))?;
+            let geometry = EntityGeometry::from_geojson(geometry).map_err(|e| format!("{kind} '{name}' has {e}"))?;
+            Ok(RegistryEntity {
+                kind,
+                name,
+                aliases: Vec::new(),
+                geometry: Some(geometry),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(kind: RegistryKind, name: &str, aliases: &[&str]) -> RegistryEntity {
+        RegistryEntity {
+            kind,
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            geometry: None,
+        }
+    }
+
+    #[test]
+    fn mentions_match_whole_names_and_aliases() {
+        let registry = RegionRegistry {
+            entities: vec![
+                entity(RegistryKind::Route, "21", &["21 bus"]),
+                entity(RegistryKind::Ward, "9", &[]),
+                entity(RegistryKind::School, "Lincoln Elementary", &[]),
+            ],
+        };
+
+        let keys = |text: &str| registry.mentioned_in(text).iter().map(|e| e.key()).collect::<Vec<_>>();
+        assert_eq!(keys("Riders say the 21 bus never came"), vec!["route-21"]);
+        assert_eq!(
+            keys("Ward 9 council member backs LINCOLN ELEMENTARY repairs"),
+            vec!["ward-9", "school-lincoln-elementary"]
+        );
+        assert!(keys("Route 210 adds trips; Ward 90 meeting").is_empty());
+    }
+
+    #[test]
+    fn ward_boundary_contains_points_inside_it() {
+        let ward = RegistryEntity {
+            geometry: Some(EntityGeometry::Polygon {
+                ring: vec![[44.93, -93.28], [44.93, -93.24], [44.96, -93.24], [44.96, -93.28]],
+            }),
+            ..entity(RegistryKind::Ward, "9", &[])
+        };
+        let registry = RegionRegistry { entities: vec![ward] };
+
+        assert_eq!(registry.containing(44.948, -93.262).len(), 1);
+        assert!(registry.containing(44.98, -93.262).is_empty());
+    }
+
+    #[test]
+    fn geojson_wards_are_read_as_lat_lng_boundaries() {
+        let geojson = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"WARD": 9},
+             "geometry": {"type": "Polygon", "coordinates": [[[-93.28, 44.93], [-93.24, 44.93], [-93.24, 44.96], [-93.28, 44.96], [-93.28, 44.93]]]}},
+            {"type": "Feature", "properties": {"WARD": "2"},
+             "geometry": {"type": "MultiPolygon", "coordinates": [
+                [[[-93.22, 44.97], [-93.20, 44.97], [-93.20, 44.99]]],
+                [[[-93.19, 44.95], [-93.17, 44.95], [-93.17, 44.96]]]]}}
+        ]}"#;
+
+        let wards = districts_from_geojson(geojson, RegistryKind::Ward, "WARD").unwrap();
+        let registry = RegionRegistry { entities: wards };
+        assert!(registry.validate().is_ok());
+        assert_eq!(registry.entities[0].key(), "ward-9");
+        assert_eq!(registry.containing(44.948, -93.262)[0].name, "9");
+        assert_eq!(registry.entities[1].geometry.as_ref().unwrap().bounds(), Some((44.95, 44.99, -93.22, -93.17)));
+
+        let unnamed = r#"{"type": "FeatureCollection", "features": [{"type": "Feature", "properties": {}, "geometry": null}]}"#;
+        assert!(districts_from_geojson(unnamed, RegistryKind::Ward, "WARD").is_err());
+    }
+
+    #[test]
+    fn boundary_geojson_round_trips_through_lat_lng_rings() {
+        let county = serde_json::json!({"type": "Feature", "properties": {"NAME": "Hennepin"},
+            "geometry": {"type": "Polygon", "coordinates": [[[-93.77, 44.78], [-93.17, 44.78], [-93.17, 45.25], [-93.77, 45.25], [-93.77, 44.78]]]}});
+
+        let boundary = EntityGeometry::from_geojson(&county).unwrap();
+
+        assert_eq!(boundary.bounds(), Some((44.78, 45.25, -93.77, -93.17)));
+        assert!(boundary.contains(44.98, -93.27));
+        assert_eq!(EntityGeometry::from_geojson(&boundary.to_geojson()).unwrap(), boundary);
+        assert!(EntityGeometry::from_geojson(&serde_json::json!({"type": "Point", "coordinates": [-93.2, 44.9]})).is_err());
+    }
+
+    #[test]
+    fn sample_points_cover_each_part_and_stay_inside() {
+        // An L-shaped county: the lattice point in the missing corner is dropped.
+        let county = EntityGeometry::Polygon {
+            ring: vec![[0.0, 0.0], [0.0, 3.0], [1.0, 3.0], [1.0, 1.0], [3.0, 1.0], [3.0, 0.0]],
+        };
+        let metro = EntityGeometry::MultiPolygon {
+            rings: vec![
+                vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]],
+                vec![[0.0, 2.0], [0.0, 3.0], [1.0, 3.0], [1.0, 2.0]],
+            ],
+        };
+
+        let county_points = county.sample_points(3);
+        let metro_points = metro.sample_points(0);
+
+        assert_eq!(county_points.len(), 5);
+        assert!(county_points.iter().all(|&(lat, lng)| county.contains(lat, lng)));
+        assert_eq!(metro_points, vec![(0.5, 0.5), (0.5, 2.5)]);
+    }
+
+    #[test]
+    fn duplicate_entities_are_rejected() {
+        let registry = RegionRegistry {
+            entities: vec![
+                entity(RegistryKind::Park, "Powderhorn Park", &[]),
+                entity(RegistryKind::Park, "powderhorn park", &[]),
+            ],
+        };
+        assert!(registry.validate().is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Restate glue shared by every crate that hosts durable workflows
+//! (scout pipeline workflows, archive enrichment).
+//!
+//! Enabled with the `restate` feature so crates that never touch Restate
+//! don't pull in the SDK.
+
+use restate_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use bytes;
+    pub use restate_sdk;
+    pub use serde_json;
+}
+
+/// Implement Restate SDK serialization traits for types that already have serde derives.
+///
+/// Bridges `serde::{Serialize, Deserialize}` to Restate's custom serialization traits
+/// without needing the `Json<>` wrapper.
+#[macro_export]
+macro_rules! impl_restate_serde {
+    ($type:ty) => {
+        impl $crate::restate::__private::restate_sdk::serde::Serialize for $type {
+            type Error = $crate::restate::__private::serde_json::Error;
+
+            fn serialize(
+                &self,
+            ) -> Result<$crate::restate::__private::bytes::Bytes, Self::Error> {
+                $crate::restate::__private::serde_json::to_vec(self)
+                    .map($crate::restate::__private::bytes::Bytes::from)
+            }
+        }
+
+        impl $crate::restate::__private::restate_sdk::serde::Deserialize for $type {
+            type Error = $crate::restate::__private::serde_json::Error;
+
+            fn deserialize(
+                bytes: &mut $crate::restate::__private::bytes::Bytes,
+            ) -> Result<Self, Self::Error> {
+                $crate::restate::__private::serde_json::from_slice(bytes)
+            }
+        }
+
+        impl $crate::restate::__private::restate_sdk::serde::WithContentType for $type {
+            fn content_type() -> &'static str {
+                "application/json"
+            }
+        }
+    };
+}
+
+/// Empty request for `get_status` shared handlers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmptyRequest;
+
+crate::impl_restate_serde!(EmptyRequest);
+
+/// Read the `"status"` key from Restate workflow state. Returns `"pending"` if unset.
+///
+/// Every workflow exposes a `get_status` shared handler with identical logic;
+/// this extracts the common body so each handler is a one-liner.
+pub async fn read_workflow_status(ctx: &SharedWorkflowContext<'_>) -> Result<String, HandlerError> {
+    Ok(ctx
+        .get::<String>("status")
+        .await?
+        .unwrap_or_else(|| "pending".to_string()))
+}
@@ -0,0 +1,225 @@
+//! Data retention: how long each class of stored data is kept.
+//!
+//! Signals already expire on their own (`reap_expired`); this covers what
+//! doesn't. Each `DataClass` has a policy in days, admins can change it, and
+//! the purge that runs after every supervisor pass deletes (or, for evidence,
+//! blanks) whatever is older. A `LegalHold` exempts a situation, a signal or a
+//! source URL from every purge until it's released. Each purge, dry run or
+//! not, is recorded as a `PurgeReport`.
+//!
+//! Policies are per data class only; there are no per-node-type policies
+//! yet. Tensions in particular aren't covered: `reap_expired` deletes any
+//! tension not confirmed within `FRESHNESS_MAX_DAYS`, so a longer policy for
+//! resolved tensions would first need the reaper to keep them out of the
+//! working graph instead of deleting them. Situations are the closest thing
+//! kept today, under `ResolvedSituations`.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// No policy may keep data for less than this.
+pub const MIN_RETENTION_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataClass {
+    /// Raw scraped content in the web archive (posts, pages, feeds, videos,
+    /// episodes, events, search results), by fetch time.
+    ArchiveContent,
+    /// Evidence snippet text. The evidence itself (URL, hash, time) stays as
+    /// provenance; only the quoted text is cleared.
+    EvidenceSnippets,
+    /// Cold situations with their dispatches, changes and subscriptions, by
+    /// last update.
+    ResolvedSituations,
+    /// Scout run logs (stats and event timelines).
+    ScoutRunLogs,
+    /// Decision records on signals, by decision time.
+    DecisionRecords,
+}
+
+impl DataClass {
+    pub const ALL: [DataClass; 5] = [
+        DataClass::ArchiveContent,
+        DataClass::EvidenceSnippets,
+        DataClass::ResolvedSituations,
+        DataClass::ScoutRunLogs,
+        DataClass::DecisionRecords,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataClass::ArchiveContent => "archive_content",
+            DataClass::EvidenceSnippets => "evidence_snippets",
+            DataClass::ResolvedSituations => "resolved_situations",
+            DataClass::ScoutRunLogs => "scout_run_logs",
+            DataClass::DecisionRecords => "decision_records",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    pub fn default_retain_days(&self) -> u32 {
+        match self {
+            DataClass::ArchiveContent => 90,
+            DataClass::EvidenceSnippets => 365,
+            DataClass::ResolvedSituations => 730,
+            DataClass::ScoutRunLogs => 180,
+            DataClass::DecisionRecords => 365,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub class: DataClass,
+    pub retain_days: u32,
+}
+
+impl RetentionPolicy {
+    pub fn default_for(class: DataClass) -> Self {
+        Self {
+            class,
+            retain_days: class.default_retain_days(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.retain_days < MIN_RETENTION_DAYS {
+            return Err(format!("Retention must be at least {MIN_RETENTION_DAYS} days"));
+        }
+        Ok(())
+    }
+
+    /// Data older than this is purged.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::days(i64::from(self.retain_days))
+    }
+}
+
+/// What a legal hold protects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum HoldTarget {
+    /// A situation or signal, by id. A held signal keeps its evidence text.
+    Node(Uuid),
+    /// Everything archived or cited from this URL.
+    SourceUrl(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegalHold {
+    pub id: Uuid,
+    pub target: HoldTarget,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LegalHold {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.reason.trim().is_empty() {
+            return Err("A legal hold needs a reason".to_string());
+        }
+        if let HoldTarget::SourceUrl(url) = &self.target {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err("Held source must be an http(s) URL".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Active holds, split by target kind for the purge queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeldTargets {
+    pub node_ids: Vec<String>,
+    pub source_urls: Vec<String>,
+}
+
+impl HeldTargets {
+    pub fn from_holds<'a>(holds: impl IntoIterator<Item = &'a LegalHold>) -> Self {
+        let mut held = Self::default();
+        for hold in holds {
+            match &hold.target {
+                HoldTarget::Node(id) => held.node_ids.push(id.to_string()),
+                HoldTarget::SourceUrl(url) => held.source_urls.push(url.clone()),
+            }
+        }
+        held
+    }
+}
+
+/// One class's result in a purge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeCount {
+    pub class: DataClass,
+    pub retain_days: u32,
+    /// Records past the cutoff and not held: deleted, or in a dry run, the
+    /// records that would be.
+    pub purged: u64,
+    /// Records past the cutoff kept by a legal hold.
+    pub held: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeReport {
+    pub id: Uuid,
+    pub dry_run: bool,
+    pub ran_at: DateTime<Utc>,
+    pub counts: Vec<PurgeCount>,
+    /// Classes whose purge failed, with the error. The others still ran.
+    pub errors: Vec<(DataClass, String)>,
+}
+
+impl PurgeReport {
+    pub fn total_purged(&self) -> u64 {
+        self.counts.iter().map(|c| c.purged).sum()
+    }
+}
+
+impl std::fmt::Display for PurgeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run { "would purge" } else { "purged" };
+        let counts: Vec<String> = self
+            .counts
+            .iter()
+            .map(|c| format!("{} {} ({} held)", c.class.as_str(), c.purged, c.held))
+            .collect();
+        write!(f, "Retention {verb}: {}", counts.join(", "))?;
+        for (class, error) in &self.errors {
+            write!(f, "; {} failed: {error}", class.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_default_per_class_and_enforce_a_floor() {
+        let policy = RetentionPolicy::default_for(DataClass::ArchiveContent);
+        assert_eq!(policy.retain_days, 90);
+        let now = Utc::now();
+        assert_eq!(policy.cutoff(now), now - Duration::days(90));
+        assert!(RetentionPolicy { class: DataClass::ScoutRunLogs, retain_days: 1 }.validate().is_err());
+        for class in DataClass::ALL {
+            assert_eq!(DataClass::parse(class.as_str()), Some(class));
+        }
+    }
+
+    #[test]
+    fn holds_split_by_target() {
+        let id = Uuid::new_v4();
+        let hold = |target| LegalHold { id: Uuid::new_v4(), target, reason: "records request".to_string(), created_at: Utc::now() };
+        let holds = [hold(HoldTarget::Node(id)), hold(HoldTarget::SourceUrl("https://example.com/a".to_string()))];
+        let held = HeldTargets::from_holds(&holds);
+        assert_eq!(held.node_ids, vec![id.to_string()]);
+        assert_eq!(held.source_urls, vec!["https://example.com/a".to_string()]);
+        assert!(hold(HoldTarget::SourceUrl("example.com".to_string())).validate().is_err());
+    }
+}
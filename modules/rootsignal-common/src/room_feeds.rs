@@ -0,0 +1,334 @@
+//! Room feeds: a continuous feed of new signals posted to a community's
+//! Discord channel or Matrix room.
+//!
+//! Unlike watchlist notifications, which alert one person, a room feed is a
+//! low-urgency public broadcast. Each feed narrows by area, signal type and
+//! minimum severity, renders each signal with a message template, and is
+//! rate limited per hour. Sensitive signals are never posted, and signals
+//! are matched and rendered as the public sees them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::visibility::{redact_node, Visibility};
+use crate::watchlist::BoundingBox;
+use crate::{Node, NodeType, SensitivityLevel, Severity, Urgency};
+
+/// Messages a feed may post per hour, at most.
+pub const MAX_MESSAGES_PER_HOUR: u32 = 60;
+/// Messages per hour for a feed that doesn't set its own limit.
+pub const DEFAULT_MESSAGES_PER_HOUR: u32 = 10;
+/// Discord rejects longer messages; Matrix rooms read better short too.
+pub const MAX_MESSAGE_CHARS: usize = 2000;
+/// Summaries are cut to this length in rendered messages.
+const MAX_SUMMARY_CHARS: usize = 400;
+
+pub const DEFAULT_TEMPLATE: &str = "**{type}: {title}**\n{summary}\n{location}\n{url}";
+/// Placeholders a template may use.
+pub const TEMPLATE_FIELDS: &[&str] = &["type", "title", "summary", "location", "category", "url"];
+
+/// Where a feed posts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "platform", rename_all = "snake_case")]
+pub enum RoomTarget {
+    /// A channel webhook URL from Discord's channel integrations settings.
+    Discord { webhook_url: String },
+    /// A room the deployment's Matrix bot account has joined.
+    Matrix { homeserver: String, room_id: String },
+}
+
+impl RoomTarget {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            RoomTarget::Discord { webhook_url } => {
+                let valid = ["https://discord.com/api/webhooks/", "https://discordapp.com/api/webhooks/"]
+                    .iter()
+                    .any(|prefix| webhook_url.starts_with(prefix));
+                if !valid {
+                    return Err(format!("Not a Discord webhook URL: {webhook_url}"));
+                }
+            }
+            RoomTarget::Matrix { homeserver, room_id } => {
+                if !homeserver.starts_with("https://") {
+                    return Err(format!("Matrix homeserver must be https: {homeserver}"));
+                }
+                if !room_id.starts_with('!') || !room_id.contains(':') {
+                    return Err(format!("Invalid Matrix room id (expected !opaque:server): {room_id}"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomFeed {
+    pub id: Uuid,
+    pub name: String,
+    pub target: RoomTarget,
+    pub bbox: Option<BoundingBox>,
+    #[serde(default)]
+    pub types: Vec<NodeType>,
+    /// Only signals at or above this severity (a need's urgency counts as
+    /// its severity). Gatherings and aid have none and are left out when set.
+    #[serde(default)]
+    pub min_severity: Option<Severity>,
+    /// Message template; unset = `DEFAULT_TEMPLATE`.
+    #[serde(default)]
+    pub template: Option<String>,
+    pub max_per_hour: u32,
+    #[serde(default)]
+    pub paused: bool,
+    /// Member id of the admin who set the feed up.
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+pub(crate) fn severity_rank(severity: Severity) -> u8 {
+    match severity {
+        Severity::Low => 0,
+        Severity::Medium => 1,
+        Severity::High => 2,
+        Severity::Critical => 3,
+    }
+}
+
+/// A signal's severity, with a need's urgency standing in for one.
+pub fn signal_severity(node: &Node) -> Option<Severity> {
+    match node {
+        Node::Notice(n) => Some(n.severity),
+        Node::Tension(n) => Some(n.severity),
+        Node::Need(n) => Some(match n.urgency {
+            Urgency::Low => Severity::Low,
+            Urgency::Medium => Severity::Medium,
+            Urgency::High => Severity::High,
+            Urgency::Critical => Severity::Critical,
+        }),
+        _ => None,
+    }
+}
+
+fn type_label(node_type: NodeType) -> &'static str {
+    match node_type {
+        NodeType::Gathering => "Event",
+        NodeType::Aid => "Resource",
+        NodeType::Need => "Need",
+        NodeType::Notice => "Notice",
+        NodeType::Tension => "Community concern",
+        NodeType::Evidence => "Source",
+    }
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max - 1).collect();
+    format!("{}…", kept.trim_end())
+}
+
+/// Fill `{field}` placeholders from `value`; unknown placeholders are left as written.
+fn fill_template(template: &str, mut value: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}').and_then(|end| value(&after[..end]).map(|v| (end, v))) {
+            Some((end, v)) => {
+                out.push_str(&v);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+impl RoomFeed {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Room feed needs a name".to_string());
+        }
+        self.target.validate()?;
+        if let Some(b) = &self.bbox {
+            if !(b.min_lat < b.max_lat && b.min_lng < b.max_lng) {
+                return Err("Bounding box min must be below max".to_string());
+            }
+        }
+        if self.types.contains(&NodeType::Evidence) {
+            return Err("Evidence is not a signal type".to_string());
+        }
+        if !(1..=MAX_MESSAGES_PER_HOUR).contains(&self.max_per_hour) {
+            return Err(format!("Messages per hour must be between 1 and {MAX_MESSAGES_PER_HOUR}"));
+        }
+        if let Some(template) = &self.template {
+            if template.trim().is_empty() {
+                return Err("Template is empty".to_string());
+            }
+            let mut unknown = Vec::new();
+            fill_template(template, |field| {
+                if !TEMPLATE_FIELDS.contains(&field) {
+                    unknown.push(field.to_string());
+                }
+                Some(String::new())
+            });
+            if !unknown.is_empty() {
+                return Err(format!(
+                    "Unknown template fields: {} (use {})",
+                    unknown.join(", "),
+                    TEMPLATE_FIELDS.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `node` belongs in this feed, judged on its public fields.
+    pub fn matches(&self, node: &Node) -> bool {
+        let Some(meta) = node.meta() else {
+            return false;
+        };
+        if meta.sensitivity == SensitivityLevel::Sensitive {
+            return false;
+        }
+        if let Some(bbox) = &self.bbox {
+            let public = redact_node(node.clone(), Visibility::Public);
+            match public.meta().and_then(|m| m.about_location) {
+                Some(loc) if bbox.contains(loc.lat, loc.lng) => {}
+                _ => return false,
+            }
+        }
+        if !self.types.is_empty() && !self.types.contains(&node.node_type()) {
+            return false;
+        }
+        if let Some(min) = self.min_severity {
+            match signal_severity(node) {
+                Some(s) if severity_rank(s) >= severity_rank(min) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// The message posted for `node`: its public fields through the feed's
+    /// template, with blank lines from empty fields dropped. `url` links to
+    /// the signal on the public site.
+    pub fn render(&self, node: &Node, url: Option<&str>) -> String {
+        let node = redact_node(node.clone(), Visibility::Public);
+        let meta = node.meta();
+        let template = self.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+        let text = fill_template(template, |field| {
+            let value = match field {
+                "type" => type_label(node.node_type()).to_string(),
+                "title" => node.title().to_string(),
+                "summary" => truncate(meta.map(|m| m.summary.as_str()).unwrap_or_default(), MAX_SUMMARY_CHARS),
+                "location" => meta.and_then(|m| m.about_location_name.clone()).unwrap_or_default(),
+                "category" => node.category().unwrap_or_default().replace('_', " "),
+                "url" => url.unwrap_or_default().to_string(),
+                _ => return None,
+            };
+            Some(value)
+        });
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        truncate(&lines.join("\n"), MAX_MESSAGE_CHARS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeoPoint, GeoPrecision, NodeMeta, NoticeNode};
+
+    fn notice(title: &str, severity: Severity, sensitivity: SensitivityLevel) -> Node {
+        Node::Notice(NoticeNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: title.to_string(),
+                summary: "Water service off on Bloomington Ave while crews repair a main.".to_string(),
+                sensitivity,
+                confidence: 0.8,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: Some(GeoPoint { lat: 44.95, lng: -93.26, precision: GeoPrecision::Exact }),
+                about_location_name: Some("Bloomington Ave & Lake St".to_string()),
+                from_location: None,
+                source_url: "https://example.com".to_string(),
+                extracted_at: Utc::now(),
+                content_date: None,
+                last_confirmed_active: Utc::now(),
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat: 0.0,
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                implied_queries: vec![],
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
+            },
+            severity,
+            category: Some("utilities".to_string()),
+            effective_date: None,
+            source_authority: None,
+        })
+    }
+
+    fn feed() -> RoomFeed {
+        RoomFeed {
+            id: Uuid::new_v4(),
+            name: "Phillips neighbors".to_string(),
+            target: RoomTarget::Discord { webhook_url: "https://discord.com/api/webhooks/1/abc".to_string() },
+            bbox: Some(BoundingBox { min_lat: 44.94, max_lat: 44.96, min_lng: -93.28, max_lng: -93.25 }),
+            types: vec![],
+            min_severity: Some(Severity::Medium),
+            template: None,
+            max_per_hour: DEFAULT_MESSAGES_PER_HOUR,
+            paused: false,
+            created_by: "admin".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn feeds_skip_low_severity_and_sensitive_signals() {
+        let feed = feed();
+
+        assert!(feed.matches(&notice("Water shutoff", Severity::High, SensitivityLevel::General)));
+        assert!(!feed.matches(&notice("Hydrant flushing", Severity::Low, SensitivityLevel::General)));
+        assert!(!feed.matches(&notice("Water shutoff", Severity::High, SensitivityLevel::Sensitive)));
+    }
+
+    #[test]
+    fn template_renders_public_fields_and_drops_empty_lines() {
+        let feed = RoomFeed { template: Some("{type} — {title}\n{location}\n{url}".to_string()), ..feed() };
+
+        let message = feed.render(&notice("Water shutoff", Severity::High, SensitivityLevel::General), None);
+
+        assert_eq!(message, "Notice — Water shutoff\nBloomington Ave & Lake St");
+    }
+
+    #[test]
+    fn validate_rejects_unknown_fields_and_foreign_targets() {
+        assert!(feed().validate().is_ok());
+        let typo = RoomFeed { template: Some("{titel}: {summary}".to_string()), ..feed() };
+        assert_eq!(typo.validate().unwrap_err().split(" (").next(), Some("Unknown template fields: titel"));
+        let not_discord = RoomFeed {
+            target: RoomTarget::Discord { webhook_url: "https://example.com/hook".to_string() },
+            ..feed()
+        };
+        assert!(not_discord.validate().is_err());
+        let bad_room = RoomFeed {
+            target: RoomTarget::Matrix { homeserver: "https://matrix.org".to_string(), room_id: "#phillips".to_string() },
+            ..feed()
+        };
+        assert!(bad_room.validate().is_err());
+    }
+}
@@ -65,12 +65,12 @@ pub fn fuzz_location(point: GeoPoint, sensitivity: SensitivityLevel) -> GeoPoint
 use regex::Regex;
 use std::sync::LazyLock;
 
-static PHONE_RE: LazyLock<Regex> =
+pub(crate) static PHONE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\b\d{3}[-.\s]?\d{3}[-.\s]?\d{4}\b").unwrap());
-static EMAIL_RE: LazyLock<Regex> =
+pub(crate) static EMAIL_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b").unwrap());
-static SSN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap());
-static ADDRESS_RE: LazyLock<Regex> = LazyLock::new(|| {
+pub(crate) static SSN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap());
+pub(crate) static ADDRESS_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"\b\d{1,5}\s+[A-Z][a-z]+(?:\s+[A-Z][a-z]+)*\s+(?:St|Ave|Blvd|Dr|Ln|Rd|Way|Ct|Pl|Cir|Ter)\b").unwrap()
 });
 
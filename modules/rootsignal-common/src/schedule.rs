@@ -0,0 +1,334 @@
+//! Per-region scout run schedules.
+//!
+//! A schedule is a five-field cron expression (`minute hour day-of-month
+//! month day-of-week`) read in the region's local timezone, so "0 6 * * 1"
+//! means 6am Monday local time across DST changes. Each field takes `*`,
+//! numbers, ranges (`1-5`), lists (`1,15`) and steps (`*/6`, `8-20/4`).
+//! Day-of-week is 0–6 from Sunday (7 is also Sunday). As in cron, when both
+//! day fields are restricted a day matching either one fires.
+//!
+//! Missed occurrences are not replayed: a schedule is due once its next
+//! occurrence after the last run has passed, however many were skipped.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Scout runs are expensive; occurrences closer than this are rejected.
+pub const MIN_RUN_INTERVAL_MINUTES: i64 = 60;
+
+/// How many days ahead to search for an occurrence (covers Feb 29 schedules).
+const MAX_SEARCH_DAYS: i64 = 366 * 5;
+
+/// Cap on previewed occurrences.
+pub const MAX_PREVIEW: usize = 50;
+
+/// A parsed cron expression. Each field is a bitmask of allowed values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronExpr {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+impl FromStr for CronExpr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields[..] else {
+            return Err(format!("Expected 5 cron fields, got {}: {s:?}", fields.len()));
+        };
+        let mut days_of_week = parse_field(dow, 0, 7, "day-of-week")?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(dom, 1, 31, "day-of-month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            dom_restricted: dom != "*",
+            dow_restricted: dow != "*",
+        })
+    }
+}
+
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("Invalid {name} step in {part:?}"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let number = |v: &str| -> Result<u32, String> {
+            v.parse::<u32>()
+                .ok()
+                .filter(|n| (min..=max).contains(n))
+                .ok_or_else(|| format!("Invalid {name} {v:?} (expected {min}-{max})"))
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            r => match r.split_once('-') {
+                Some((a, b)) => (number(a)?, number(b)?),
+                // "5/15" means every 15 starting at 5
+                None if part.contains('/') => (number(r)?, max),
+                None => {
+                    let n = number(r)?;
+                    (n, n)
+                }
+            },
+        };
+        if start > end {
+            return Err(format!("Invalid {name} range {range:?}"));
+        }
+        for v in (start..=end).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+impl CronExpr {
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let dom = self.days_of_month & (1 << date.day()) != 0;
+        let dow = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            _ => dom && dow,
+        }
+    }
+
+    /// The first `n` occurrences strictly after `after`, in `tz` local time.
+    /// Local times skipped by a DST change don't occur.
+    pub fn next_occurrences(&self, tz: Tz, after: DateTime<Utc>, n: usize) -> Vec<DateTime<Utc>> {
+        let mut out = Vec::with_capacity(n);
+        let start = after.with_timezone(&tz).date_naive();
+        for offset in 0..MAX_SEARCH_DAYS {
+            let date = start + Duration::days(offset);
+            if !self.matches_day(date) {
+                continue;
+            }
+            for hour in (0..24).filter(|h| self.hours & (1 << h) != 0) {
+                for minute in (0..60).filter(|m| self.minutes & (1 << m) != 0) {
+                    let Some(naive) = date.and_hms_opt(hour, minute, 0) else {
+                        continue;
+                    };
+                    let Some(local) = tz.from_local_datetime(&naive).earliest() else {
+                        continue;
+                    };
+                    let at = local.with_timezone(&Utc);
+                    if at > after {
+                        out.push(at);
+                        if out.len() == n {
+                            return out;
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// A region's scout run schedule, keyed by region slug in the graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionSchedule {
+    /// The scout task run on each occurrence.
+    pub task_id: String,
+    pub cron: String,
+    /// IANA timezone the cron expression is read in.
+    pub timezone: String,
+    pub paused: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// When the schedule was last edited; occurrences before it never fire.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RegionSchedule {
+    /// Check the expression and timezone, and that runs aren't too frequent.
+    pub fn validate(&self) -> Result<(), String> {
+        let (expr, tz) = self.parse()?;
+        let upcoming = expr.next_occurrences(tz, Utc::now(), 25);
+        if upcoming.is_empty() {
+            return Err(format!("Schedule {:?} never runs", self.cron));
+        }
+        if let Some(pair) = upcoming
+            .windows(2)
+            .find(|w| w[1] - w[0] < Duration::minutes(MIN_RUN_INTERVAL_MINUTES))
+        {
+            return Err(format!(
+                "Runs at {} and {} are less than {MIN_RUN_INTERVAL_MINUTES} minutes apart",
+                pair[0], pair[1]
+            ));
+        }
+        Ok(())
+    }
+
+    fn parse(&self) -> Result<(CronExpr, Tz), String> {
+        let expr: CronExpr = self.cron.parse()?;
+        let tz: Tz = self
+            .timezone
+            .parse()
+            .map_err(|_| format!("Unknown timezone: {}", self.timezone))?;
+        Ok((expr, tz))
+    }
+
+    /// The next `n` runs after `after`; empty if the schedule doesn't parse.
+    pub fn next_runs(&self, after: DateTime<Utc>, n: usize) -> Vec<DateTime<Utc>> {
+        match self.parse() {
+            Ok((expr, tz)) => expr.next_occurrences(tz, after, n.min(MAX_PREVIEW)),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// The occurrence a run should start for at `now`, if any: not paused, and
+    /// an occurrence has passed since the last run (or the last edit).
+    pub fn due_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.paused {
+            return None;
+        }
+        let since = self.last_run_at.map_or(self.updated_at, |t| t.max(self.updated_at));
+        self.next_runs(since, 1).into_iter().next().filter(|next| *next <= now)
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.due_run(now).is_some()
+    }
+}
+
+/// Validate `cron` in `timezone` and preview its next `n` runs after `after`.
+pub fn preview_schedule(
+    cron: &str,
+    timezone: &str,
+    after: DateTime<Utc>,
+    n: usize,
+) -> Result<Vec<DateTime<Utc>>, String> {
+    let schedule = RegionSchedule {
+        task_id: String::new(),
+        cron: cron.to_string(),
+        timezone: timezone.to_string(),
+        paused: false,
+        last_run_at: None,
+        updated_at: after,
+    };
+    schedule.validate()?;
+    Ok(schedule.next_runs(after, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn local_hm(t: DateTime<Utc>, tz: Tz) -> (u32, u32) {
+        let local = t.with_timezone(&tz);
+        (local.hour(), local.minute())
+    }
+
+    fn schedule(cron: &str, timezone: &str) -> RegionSchedule {
+        RegionSchedule {
+            task_id: "task-1".to_string(),
+            cron: cron.to_string(),
+            timezone: timezone.to_string(),
+            paused: false,
+            last_run_at: None,
+            updated_at: utc("2026-03-01T00:00:00Z"),
+        }
+    }
+
+    #[test]
+    fn daily_local_time_follows_dst() {
+        let s = schedule("0 6 * * *", "America/Chicago");
+
+        // US DST starts 2026-03-08
+        let runs = s.next_runs(utc("2026-03-06T00:00:00Z"), 4);
+
+        let tz: Tz = "America/Chicago".parse().unwrap();
+        assert!(runs.iter().all(|r| local_hm(*r, tz) == (6, 0)));
+        assert_eq!(runs[0], utc("2026-03-06T12:00:00Z"));
+        assert_eq!(runs[3], utc("2026-03-09T11:00:00Z"));
+    }
+
+    #[test]
+    fn steps_ranges_and_lists_parse() {
+        let s = schedule("30 8-20/6 * * 1,3", "UTC");
+
+        // 2026-03-02 is a Monday
+        let runs = s.next_runs(utc("2026-03-02T00:00:00Z"), 4);
+
+        assert_eq!(
+            runs,
+            vec![
+                utc("2026-03-02T08:30:00Z"),
+                utc("2026-03-02T14:30:00Z"),
+                utc("2026-03-02T20:30:00Z"),
+                utc("2026-03-04T08:30:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn restricted_day_of_month_and_week_fire_on_either() {
+        let s = schedule("0 0 1 * 0", "UTC");
+
+        // 2026-03-01 is a Sunday and the 1st; next are Sundays the 8th, 15th...
+        let runs = s.next_runs(utc("2026-03-01T00:00:00Z"), 2);
+
+        assert_eq!(runs, vec![utc("2026-03-08T00:00:00Z"), utc("2026-03-15T00:00:00Z")]);
+    }
+
+    #[test]
+    fn validate_rejects_bad_fields_timezones_and_tight_schedules() {
+        assert!(schedule("0 6 * *", "UTC").validate().is_err());
+        assert!(schedule("61 6 * * *", "UTC").validate().is_err());
+        assert!(schedule("0 6 * * *", "Mars/Olympus").validate().is_err());
+        assert!(schedule("*/15 * * * *", "UTC").validate().is_err());
+        assert!(schedule("0 0 31 2 *", "UTC").validate().is_err());
+        assert!(schedule("0 */2 * * *", "UTC").validate().is_ok());
+    }
+
+    #[test]
+    fn due_once_an_occurrence_passes_since_last_run() {
+        let mut s = schedule("0 6 * * *", "UTC");
+        s.last_run_at = Some(utc("2026-03-05T06:00:10Z"));
+
+        assert!(!s.is_due(utc("2026-03-06T05:59:00Z")));
+        assert!(s.is_due(utc("2026-03-06T06:00:00Z")));
+        // Days of downtime still yield a single due run, not a backlog
+        assert!(s.is_due(utc("2026-03-10T09:00:00Z")));
+    }
+
+    #[test]
+    fn paused_or_freshly_edited_schedules_are_not_due() {
+        let mut s = schedule("0 6 * * *", "UTC");
+        s.updated_at = utc("2026-03-06T06:30:00Z");
+
+        assert!(!s.is_due(utc("2026-03-06T07:00:00Z")));
+        assert!(s.is_due(utc("2026-03-07T06:00:00Z")));
+
+        s.paused = true;
+        assert!(!s.is_due(utc("2026-03-07T06:00:00Z")));
+    }
+}
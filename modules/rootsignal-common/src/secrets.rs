@@ -0,0 +1,560 @@
+//! Secrets management.
+//!
+//! API keys and passwords are resolved through a [`SecretProvider`] and held in
+//! [`Secret`] handles. A handle resolves lazily on first use, caches the value,
+//! and can be re-resolved with [`Secret::rotate`] when a downstream service
+//! rejects the credential (e.g. after a key rotation in the secret store).
+//!
+//! `Debug` never prints the secret value, so handles are safe to keep inside
+//! `#[derive(Debug)]` config structs and to log.
+//!
+//! Providers are synchronous. Remote backends do their I/O on a thread of
+//! their own (see [`run_blocking`]), so resolving or rotating from inside the
+//! async runtime neither panics nor stalls its workers.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SecretError {
+    #[error("Secret {key} could not be read from {provider}: {message}")]
+    Backend {
+        provider: &'static str,
+        key: String,
+        message: String,
+    },
+
+    #[error("Unknown secrets provider: {0}")]
+    UnknownProvider(String),
+}
+
+/// A source of secret values, looked up by name (e.g. `ANTHROPIC_API_KEY`).
+///
+/// `Ok(None)` means the provider has no value for the key — chained providers
+/// fall through to the next backend. `Err` means the backend itself failed.
+pub trait SecretProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError>;
+}
+
+/// Reads secrets from process environment variables.
+pub struct EnvProvider;
+
+impl SecretProvider for EnvProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+        Ok(std::env::var(key).ok().filter(|v| !v.is_empty()))
+    }
+}
+
+/// Reads secrets from files, Docker/Kubernetes-secrets style.
+///
+/// Checks `{KEY}_FILE` first (a path to the secret), then `{dir}/{KEY}`.
+/// Trailing newlines are trimmed.
+pub struct FileProvider {
+    dir: Option<PathBuf>,
+}
+
+impl FileProvider {
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        Self { dir }
+    }
+
+    fn read(&self, key: &str, path: PathBuf) -> Result<Option<String>, SecretError> {
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => Ok(Some(contents.trim_end_matches(['\r', '\n']).to_string())
+                .filter(|v| !v.is_empty())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(SecretError::Backend {
+                provider: self.name(),
+                key: key.to_string(),
+                message: format!("{}: {e}", path.display()),
+            }),
+        }
+    }
+}
+
+impl SecretProvider for FileProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+        if let Ok(path) = std::env::var(format!("{key}_FILE")) {
+            return self.read(key, PathBuf::from(path));
+        }
+        match self.dir {
+            Some(ref dir) => self.read(key, dir.join(key)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Tries each provider in order; the first one with a value wins.
+pub struct ChainProvider {
+    providers: Vec<Arc<dyn SecretProvider>>,
+}
+
+impl ChainProvider {
+    pub fn new(providers: Vec<Arc<dyn SecretProvider>>) -> Self {
+        Self { providers }
+    }
+}
+
+impl SecretProvider for ChainProvider {
+    fn name(&self) -> &'static str {
+        "chain"
+    }
+
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+        for provider in &self.providers {
+            if let Some(value) = provider.resolve(key)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Run a blocking backend call off the async runtime. The call gets a thread
+/// with no runtime context, which `reqwest::blocking` requires; on a
+/// multi-threaded runtime the waiting worker hands its tasks off first.
+#[cfg(any(feature = "vault", feature = "aws-secrets-manager"))]
+fn run_blocking<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    let call = || {
+        std::thread::scope(|s| {
+            s.spawn(f)
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    };
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(call)
+        }
+        _ => call(),
+    }
+}
+
+/// Reads secrets from a HashiCorp Vault KV v2 engine.
+///
+/// Each key is a field of a single secret at `{mount}/data/{path}`.
+#[cfg(feature = "vault")]
+pub struct VaultProvider {
+    addr: String,
+    token: String,
+    mount: String,
+    path: String,
+}
+
+#[cfg(feature = "vault")]
+impl VaultProvider {
+    pub fn new(addr: String, token: String, mount: String, path: String) -> Self {
+        Self {
+            addr: addr.trim_end_matches('/').to_string(),
+            token,
+            mount,
+            path,
+        }
+    }
+}
+
+#[cfg(feature = "vault")]
+impl SecretProvider for VaultProvider {
+    fn name(&self) -> &'static str {
+        "vault"
+    }
+
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+        let backend_err = |message: String| SecretError::Backend {
+            provider: "vault",
+            key: key.to_string(),
+            message,
+        };
+        let url = format!("{}/v1/{}/data/{}", self.addr, self.mount, self.path);
+        let body = run_blocking(|| -> Result<Option<serde_json::Value>, String> {
+            let response = reqwest::blocking::Client::new()
+                .get(&url)
+                .header("X-Vault-Token", &self.token)
+                .send()
+                .map_err(|e| e.to_string())?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            if !response.status().is_success() {
+                return Err(format!("HTTP error ({})", response.status()));
+            }
+            response.json().map(Some).map_err(|e| e.to_string())
+        })
+        .map_err(backend_err)?;
+        Ok(body.and_then(|body| body["data"]["data"][key].as_str().map(|s| s.to_string())))
+    }
+}
+
+/// Reads secrets from AWS Secrets Manager via the `aws` CLI, so the standard
+/// credential chain (instance profile, SSO, env) applies without an SDK dependency.
+///
+/// Each key is looked up as the secret id `{prefix}{KEY}`.
+#[cfg(feature = "aws-secrets-manager")]
+pub struct AwsSecretsManagerProvider {
+    prefix: String,
+}
+
+#[cfg(feature = "aws-secrets-manager")]
+impl AwsSecretsManagerProvider {
+    pub fn new(prefix: String) -> Self {
+        Self { prefix }
+    }
+}
+
+#[cfg(feature = "aws-secrets-manager")]
+impl SecretProvider for AwsSecretsManagerProvider {
+    fn name(&self) -> &'static str {
+        "aws-secrets-manager"
+    }
+
+    fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+        let secret_id = format!("{}{key}", self.prefix);
+        let output = run_blocking(|| {
+            std::process::Command::new("aws")
+                .args(["secretsmanager", "get-secret-value", "--query", "SecretString", "--output", "text"])
+                .arg("--secret-id")
+                .arg(&secret_id)
+                .output()
+        })
+        .map_err(|e| SecretError::Backend {
+                provider: self.name(),
+                key: key.to_string(),
+                message: e.to_string(),
+            })?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("ResourceNotFoundException") {
+                return Ok(None);
+            }
+            return Err(SecretError::Backend {
+                provider: self.name(),
+                key: key.to_string(),
+                message: stderr.trim().to_string(),
+            });
+        }
+        let value = String::from_utf8_lossy(&output.stdout).trim_end().to_string();
+        Ok(Some(value).filter(|v| !v.is_empty()))
+    }
+}
+
+/// Build the provider selected by `SECRETS_PROVIDER`.
+///
+/// - `env` (default): `{KEY}_FILE` / `SECRETS_DIR` files, then environment variables
+/// - `vault`: Vault KV v2 (`VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_MOUNT`, `VAULT_SECRET_PATH`), then env
+/// - `aws`: AWS Secrets Manager (`AWS_SECRET_PREFIX`), then env
+pub fn provider_from_env() -> Result<Arc<dyn SecretProvider>, SecretError> {
+    let local: Vec<Arc<dyn SecretProvider>> = vec![
+        Arc::new(FileProvider::new(std::env::var("SECRETS_DIR").ok().map(PathBuf::from))),
+        Arc::new(EnvProvider),
+    ];
+    let selected = std::env::var("SECRETS_PROVIDER").unwrap_or_else(|_| "env".to_string());
+    let remote: Option<Arc<dyn SecretProvider>> = match selected.as_str() {
+        "env" | "" => None,
+        #[cfg(feature = "vault")]
+        "vault" => Some(Arc::new(VaultProvider::new(
+            std::env::var("VAULT_ADDR").unwrap_or_else(|_| "http://127.0.0.1:8200".to_string()),
+            std::env::var("VAULT_TOKEN").unwrap_or_default(),
+            std::env::var("VAULT_MOUNT").unwrap_or_else(|_| "secret".to_string()),
+            std::env::var("VAULT_SECRET_PATH").unwrap_or_else(|_| "rootsignal".to_string()),
+        ))),
+        #[cfg(feature = "aws-secrets-manager")]
+        "aws" => Some(Arc::new(AwsSecretsManagerProvider::new(
+            std::env::var("AWS_SECRET_PREFIX").unwrap_or_default(),
+        ))),
+        other => return Err(SecretError::UnknownProvider(other.to_string())),
+    };
+    Ok(Arc::new(ChainProvider::new(remote.into_iter().chain(local).collect())))
+}
+
+// ---------------------------------------------------------------------------
+// Secret handle
+// ---------------------------------------------------------------------------
+
+/// A lazily-resolved, rotatable secret.
+///
+/// Cheap to clone — clones share the cached value, so a rotation through one
+/// handle is visible to every holder.
+#[derive(Clone)]
+pub struct Secret {
+    inner: Arc<SecretInner>,
+}
+
+struct SecretInner {
+    key: String,
+    provider: Option<Arc<dyn SecretProvider>>,
+    cached: RwLock<Option<String>>,
+}
+
+impl Secret {
+    /// A handle that resolves `key` from `provider` on first use.
+    pub fn new(key: impl Into<String>, provider: Arc<dyn SecretProvider>) -> Self {
+        Self {
+            inner: Arc::new(SecretInner {
+                key: key.into(),
+                provider: Some(provider),
+                cached: RwLock::new(None),
+            }),
+        }
+    }
+
+    /// A fixed value with no backing provider (tests, CLI overrides).
+    pub fn from_value(value: impl Into<String>) -> Self {
+        Self {
+            inner: Arc::new(SecretInner {
+                key: String::new(),
+                provider: None,
+                cached: RwLock::new(Some(value.into())),
+            }),
+        }
+    }
+
+    /// An unset secret. `expose()` returns an empty string.
+    pub fn empty() -> Self {
+        Self::from_value(String::new())
+    }
+
+    pub fn key(&self) -> &str {
+        &self.inner.key
+    }
+
+    /// Resolve (if not yet cached) and return the value.
+    pub fn try_expose(&self) -> Result<Option<String>, SecretError> {
+        if let Some(ref value) = *self.inner.cached.read().unwrap_or_else(|e| e.into_inner()) {
+            return Ok(Some(value.clone()));
+        }
+        let Some(ref provider) = self.inner.provider else {
+            return Ok(None);
+        };
+        let resolved = provider.resolve(&self.inner.key)?;
+        if let Some(ref value) = resolved {
+            *self.inner.cached.write().unwrap_or_else(|e| e.into_inner()) = Some(value.clone());
+        }
+        Ok(resolved)
+    }
+
+    /// Return the value, or an empty string if unset or the backend failed.
+    pub fn expose(&self) -> String {
+        match self.try_expose() {
+            Ok(value) => value.unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Secret resolution failed");
+                String::new()
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expose().is_empty()
+    }
+
+    /// Drop the cached value and re-resolve from the provider.
+    /// Returns `true` if the value changed. Fixed-value secrets never change.
+    pub fn rotate(&self) -> Result<bool, SecretError> {
+        let Some(ref provider) = self.inner.provider else {
+            return Ok(false);
+        };
+        let fresh = provider.resolve(&self.inner.key)?;
+        let mut cached = self.inner.cached.write().unwrap_or_else(|e| e.into_inner());
+        let changed = *cached != fresh;
+        *cached = fresh;
+        Ok(changed)
+    }
+
+    /// Rotate if `error` looks like the remote service rejected the credential.
+    /// Returns `true` if a different value was picked up.
+    pub fn rotate_on_auth_failure(&self, error: &str) -> bool {
+        if !is_auth_failure(error) {
+            return false;
+        }
+        match self.rotate() {
+            Ok(changed) => {
+                if changed {
+                    tracing::info!(key = self.key(), "Rotated secret after auth failure");
+                }
+                changed
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Secret rotation failed");
+                false
+            }
+        }
+    }
+}
+
+impl Default for Secret {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secret")
+            .field("key", &self.inner.key)
+            .field("value", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Whether upstream error text reports a rejected credential. Clients in
+/// this workspace surface HTTP failures as `"... ({status}): {body}"`; when
+/// there's a status it decides (401 or 403), otherwise only provider
+/// auth-error markers count.
+pub fn is_auth_failure(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    match crate::error::extract_http_status(&lower) {
+        Some(status) => matches!(status, 401 | 403),
+        None => ["invalid api key", "invalid x-api-key", "authentication_error"]
+            .iter()
+            .any(|marker| lower.contains(marker)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    struct MapProvider(Mutex<HashMap<String, String>>);
+
+    impl MapProvider {
+        fn with(key: &str, value: &str) -> Arc<Self> {
+            Arc::new(Self(Mutex::new(HashMap::from([(key.to_string(), value.to_string())]))))
+        }
+
+        fn set(&self, key: &str, value: &str) {
+            self.0.lock().unwrap().insert(key.to_string(), value.to_string());
+        }
+    }
+
+    impl SecretProvider for MapProvider {
+        fn name(&self) -> &'static str {
+            "map"
+        }
+
+        fn resolve(&self, key: &str) -> Result<Option<String>, SecretError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    #[test]
+    fn debug_output_never_contains_the_secret_value() {
+        let secret = Secret::new("ANTHROPIC_API_KEY", MapProvider::with("ANTHROPIC_API_KEY", "sk-ant-123"));
+        assert_eq!(secret.expose(), "sk-ant-123");
+        let debug = format!("{secret:?}");
+        assert!(debug.contains("ANTHROPIC_API_KEY"));
+        assert!(!debug.contains("sk-ant-123"));
+    }
+
+    #[test]
+    fn cached_value_is_kept_until_rotation() {
+        let provider = MapProvider::with("KEY", "old");
+        let secret = Secret::new("KEY", provider.clone());
+        assert_eq!(secret.expose(), "old");
+
+        provider.set("KEY", "new");
+        assert_eq!(secret.expose(), "old");
+
+        assert!(secret.rotate().unwrap());
+        assert_eq!(secret.expose(), "new");
+    }
+
+    #[test]
+    fn auth_failure_rotates_but_other_errors_do_not() {
+        let provider = MapProvider::with("KEY", "old");
+        let secret = Secret::new("KEY", provider.clone());
+        let clone = secret.clone();
+        assert_eq!(secret.expose(), "old");
+        provider.set("KEY", "new");
+
+        assert!(!secret.rotate_on_auth_failure("Claude API error (529): overloaded"));
+        assert_eq!(clone.expose(), "old");
+
+        assert!(secret.rotate_on_auth_failure("Claude API error (401 Unauthorized): invalid x-api-key"));
+        assert_eq!(clone.expose(), "new");
+    }
+
+    #[test]
+    fn auth_failure_is_read_from_the_status_not_stray_digits() {
+        assert!(is_auth_failure("Serper API error (status 403): forbidden"));
+        assert!(is_auth_failure("authentication_error: invalid x-api-key"));
+        assert!(!is_auth_failure("Apify API error (404): actor run 4010401 not found"));
+        assert!(!is_auth_failure("request timed out after 401ms"));
+        assert!(!is_auth_failure("Claude API error (500): upstream unauthorized proxy"));
+    }
+
+    #[test]
+    fn chain_falls_through_to_next_provider() {
+        let chain = ChainProvider::new(vec![
+            MapProvider::with("A", "from-first"),
+            MapProvider::with("B", "from-second"),
+        ]);
+        assert_eq!(chain.resolve("A").unwrap().as_deref(), Some("from-first"));
+        assert_eq!(chain.resolve("B").unwrap().as_deref(), Some("from-second"));
+        assert_eq!(chain.resolve("C").unwrap(), None);
+    }
+
+    #[test]
+    fn file_provider_reads_trimmed_secret_from_directory() {
+        let dir = std::env::temp_dir().join(format!("rootsignal-secrets-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("SERPER_API_KEY"), "serper-abc\n").unwrap();
+
+        let provider = FileProvider::new(Some(dir.clone()));
+        assert_eq!(provider.resolve("SERPER_API_KEY").unwrap().as_deref(), Some("serper-abc"));
+        assert_eq!(provider.resolve("MISSING_KEY").unwrap(), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// Serves one canned Vault KV v2 response on a local port.
+    #[cfg(feature = "vault")]
+    fn fake_vault(body: &'static str) -> String {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        addr
+    }
+
+    #[cfg(feature = "vault")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn vault_secret_resolves_from_inside_the_async_runtime() {
+        let addr = fake_vault(r#"{"data": {"data": {"SERPER_API_KEY": "serper-from-vault"}}}"#);
+        let provider = VaultProvider::new(addr, "token".to_string(), "secret".to_string(), "rootsignal".to_string());
+
+        let secret = Secret::new("SERPER_API_KEY", Arc::new(provider));
+
+        assert_eq!(secret.expose(), "serper-from-vault");
+    }
+
+    #[test]
+    fn fixed_value_secret_cannot_rotate() {
+        let secret = Secret::from_value("static");
+        assert!(!secret.rotate().unwrap());
+        assert_eq!(secret.expose(), "static");
+        assert!(Secret::empty().is_empty());
+    }
+}
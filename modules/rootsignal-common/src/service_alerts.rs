@@ -0,0 +1,212 @@
+//! Per-region service alert feeds: transit agencies (GTFS-RT) and school
+//! districts (announcement feeds).
+//!
+//! A route cut or a school closure strains a neighborhood the day it's
+//! announced. Each region lists the agencies and districts it follows; every
+//! alert names the entities it affects (a route, a school) so it can be
+//! linked to other signals about the same service or school.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::slugify;
+
+/// Words that make a district announcement a service alert rather than news.
+pub fn default_school_alert_keywords() -> Vec<String> {
+    [
+        "closed",
+        "closure",
+        "cancel",
+        "delayed start",
+        "late start",
+        "early dismissal",
+        "e-learning",
+        "distance learning",
+        "lockdown",
+        "shelter in place",
+        "no school",
+    ]
+    .iter()
+    .map(|k| k.to_string())
+    .collect()
+}
+
+/// A transit agency publishing a GTFS-realtime alerts feed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitAgency {
+    /// Human-readable name, used as the signal's source authority ("Metro Transit").
+    pub name: String,
+    /// GTFS-RT `FeedMessage` of service alerts. Agencies that require an API
+    /// key take it in the query string.
+    pub alerts_url: String,
+    /// Route ids to follow; empty follows every route of the agency.
+    #[serde(default)]
+    pub routes: Vec<String>,
+    /// Where the agency's alerts are located when the feed gives no place.
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl TransitAgency {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_feed(&self.name, &self.alerts_url, self.latitude, self.longitude)
+    }
+
+    /// Whether an alert about `route_id` concerns this region.
+    pub fn follows_route(&self, route_id: &str) -> bool {
+        self.routes.is_empty() || self.routes.iter().any(|r| r.eq_ignore_ascii_case(route_id))
+    }
+}
+
+/// A school district publishing announcements as RSS or Atom.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchoolDistrict {
+    /// Human-readable name ("Minneapolis Public Schools").
+    pub name: String,
+    pub feed_url: String,
+    /// School names to look for in announcements. An announcement naming
+    /// none of them is taken to affect the whole district.
+    #[serde(default)]
+    pub schools: Vec<String>,
+    #[serde(default = "default_school_alert_keywords")]
+    pub alert_keywords: Vec<String>,
+    /// District office; announcements are located here.
+    pub location_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl SchoolDistrict {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_feed(&self.name, &self.feed_url, self.latitude, self.longitude)?;
+        if self.alert_keywords.is_empty() {
+            return Err(format!("District '{}' has no alert keywords", self.name));
+        }
+        Ok(())
+    }
+}
+
+fn validate_feed(name: &str, url: &str, latitude: f64, longitude: f64) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Service alert feeds need a name".to_string());
+    }
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("Feed '{name}' has an invalid URL: {url}"));
+    }
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(format!("Feed '{name}' has invalid coordinates"));
+    }
+    Ok(())
+}
+
+/// The service alert feeds a region follows.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionServiceAlerts {
+    #[serde(default)]
+    pub transit_agencies: Vec<TransitAgency>,
+    #[serde(default)]
+    pub school_districts: Vec<SchoolDistrict>,
+}
+
+impl RegionServiceAlerts {
+    pub fn is_empty(&self) -> bool {
+        self.transit_agencies.is_empty() && self.school_districts.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for agency in &self.transit_agencies {
+            agency.validate()?;
+        }
+        for district in &self.school_districts {
+            district.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Something an alert is about.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AffectedEntity {
+    /// Transit route by its public id ("21").
+    Route(String),
+    /// A school, or a whole district when no school is named.
+    School(String),
+}
+
+impl AffectedEntity {
+    /// How other signals mention the entity ("Route 21", "Lincoln Elementary").
+    pub fn mention(&self) -> String {
+        match self {
+            AffectedEntity::Route(id) => format!("Route {id}"),
+            AffectedEntity::School(name) => name.clone(),
+        }
+    }
+
+    /// Tag shared by the alert and every signal mentioning the entity
+    /// ("route-21", "school-lincoln-elementary").
+    pub fn tag_slug(&self) -> String {
+        match self {
+            AffectedEntity::Route(id) => format!("route-{}", slugify(id)),
+            AffectedEntity::School(name) => format!("school-{}", slugify(name)),
+        }
+    }
+}
+
+/// What an alert does to service, from most to least disruptive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertEffect {
+    /// No service: a suspended route, a closed school.
+    Closure,
+    ReducedService,
+    Detour,
+    Delay,
+    /// Schedule changes, stop moves, informational notices.
+    Other,
+}
+
+/// One alert read from a transit or school feed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceAlert {
+    /// Feed-assigned id, stable across reads of the same alert.
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Page describing the alert, when the feed links one.
+    pub url: Option<String>,
+    pub effect: AlertEffect,
+    pub affected: Vec<AffectedEntity>,
+    pub active_from: Option<DateTime<Utc>>,
+    pub active_until: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entities_share_tags_across_spellings() {
+        assert_eq!(AffectedEntity::Route("21".to_string()).tag_slug(), "route-21");
+        assert_eq!(
+            AffectedEntity::School("Lincoln Elementary".to_string()).tag_slug(),
+            AffectedEntity::School("lincoln  elementary".to_string()).tag_slug()
+        );
+        assert_eq!(AffectedEntity::Route("21".to_string()).mention(), "Route 21");
+    }
+
+    #[test]
+    fn empty_route_list_follows_every_route() {
+        let mut agency = TransitAgency {
+            name: "Metro Transit".to_string(),
+            alerts_url: "https://svc.metrotransit.org/mtgtfs/alerts.pb".to_string(),
+            routes: Vec::new(),
+            location_name: "Minneapolis".to_string(),
+            latitude: 44.9778,
+            longitude: -93.265,
+        };
+        assert!(agency.follows_route("21"));
+        agency.routes = vec!["5".to_string(), "21".to_string()];
+        assert!(agency.follows_route("21"));
+        assert!(!agency.follows_route("14"));
+    }
+}
@@ -0,0 +1,162 @@
+//! Judge audits of production extractions.
+//!
+//! Each scout run sends a budget-bounded random sample of the signals it
+//! created, with their source content, to an LLM judge. Verdicts are recorded
+//! on the signal nodes and here, in the `signal_audits` Postgres table, where
+//! the admin dashboard trends them per region. Signals with a critical issue
+//! are suppressed before they go live.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSeverity {
+    /// Wrong enough that the signal shouldn't be published: invented,
+    /// misleading, unsafe, or not a signal at all.
+    Critical,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AuditIssue {
+    pub severity: AuditSeverity,
+    /// Short snake_case label, e.g. "hallucinated_detail" or "wrong_type".
+    pub category: String,
+    pub description: String,
+}
+
+impl AuditIssue {
+    /// `severity/category: description`, as stored on signal nodes.
+    pub fn label(&self) -> String {
+        let severity = match self.severity {
+            AuditSeverity::Critical => "critical",
+            AuditSeverity::Warning => "warning",
+            AuditSeverity::Info => "info",
+        };
+        format!("{severity}/{}: {}", self.category, self.description)
+    }
+}
+
+/// The judge's verdict on one signal.
+#[derive(Debug, Clone)]
+pub struct AuditVerdict {
+    pub signal_id: Uuid,
+    pub signal_type: String,
+    pub source_url: String,
+    /// 0 (unusable) to 1 (faithful and complete).
+    pub score: f64,
+    pub issues: Vec<AuditIssue>,
+}
+
+impl AuditVerdict {
+    pub fn is_critical(&self) -> bool {
+        self.issues.iter().any(|i| i.severity == AuditSeverity::Critical)
+    }
+}
+
+/// A region's audit results for one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditDay {
+    pub day: NaiveDate,
+    pub audited: u32,
+    pub mean_score: f64,
+    pub critical: u32,
+    pub suppressed: u32,
+}
+
+/// Store a verdict. `suppressed` is whether the signal was actually held back.
+pub async fn record(
+    pool: &PgPool,
+    run_id: &str,
+    region: &str,
+    verdict: &AuditVerdict,
+    suppressed: bool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO signal_audits (id, run_id, region, signal_id, signal_type, source_url, score, critical, suppressed, issues)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(run_id)
+    .bind(region)
+    .bind(verdict.signal_id)
+    .bind(&verdict.signal_type)
+    .bind(&verdict.source_url)
+    .bind(verdict.score)
+    .bind(verdict.is_critical())
+    .bind(suppressed)
+    .bind(serde_json::to_value(&verdict.issues).expect("issues serialize"))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+type AuditDayRow = (NaiveDate, i64, f64, i64, i64);
+
+/// Daily audit results for `region` over the last `days` days, oldest first.
+pub async fn daily_trend(pool: &PgPool, region: &str, days: u32) -> Result<Vec<AuditDay>, sqlx::Error> {
+    let since: DateTime<Utc> = Utc::now() - chrono::Duration::days(days as i64);
+    let rows = sqlx::query_as::<_, AuditDayRow>(
+        "SELECT (created_at AT TIME ZONE 'UTC')::date AS day,
+                count(*),
+                avg(score),
+                count(*) FILTER (WHERE critical),
+                count(*) FILTER (WHERE suppressed)
+         FROM signal_audits
+         WHERE region = $1 AND created_at >= $2
+         GROUP BY day
+         ORDER BY day",
+    )
+    .bind(region)
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| AuditDay {
+            day: r.0,
+            audited: r.1 as u32,
+            mean_score: r.2,
+            critical: r.3 as u32,
+            suppressed: r.4 as u32,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(severity: AuditSeverity) -> AuditIssue {
+        AuditIssue {
+            severity,
+            category: "hallucinated_detail".to_string(),
+            description: "Date not on the page".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_critical_issues_make_a_verdict_critical() {
+        let mut verdict = AuditVerdict {
+            signal_id: Uuid::new_v4(),
+            signal_type: "gathering".to_string(),
+            source_url: "https://example.org".to_string(),
+            score: 0.6,
+            issues: vec![issue(AuditSeverity::Warning), issue(AuditSeverity::Info)],
+        };
+        assert!(!verdict.is_critical());
+
+        verdict.issues.push(issue(AuditSeverity::Critical));
+
+        assert!(verdict.is_critical());
+        assert_eq!(
+            verdict.issues[2].label(),
+            "critical/hallucinated_detail: Date not on the page"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Following a situation: its change log, Atom feed, and the milestones
+//! subscribers are emailed about.
+//!
+//! Each time a situation's arc moves, a `SituationChange` is recorded. The
+//! feed interleaves those with signals newly linked to the situation.
+//! Subscribers hear only about milestones: the situation escalating to
+//! active, or going cold. A subscription is double opt-in: nothing but the
+//! confirmation email goes to an address until its link is followed.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SituationArc;
+
+/// Entries in a situation's feed.
+pub const FEED_ENTRY_LIMIT: usize = 50;
+
+/// Situations one email address may follow.
+pub const MAX_SUBSCRIPTIONS_PER_EMAIL: u32 = 20;
+
+/// Days a subscription's confirmation link works; unconfirmed subscriptions
+/// older than this are dropped.
+pub const CONFIRM_WINDOW_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SituationChange {
+    pub id: Uuid,
+    pub situation_id: Uuid,
+    pub from: SituationArc,
+    pub to: SituationArc,
+    pub temperature: f64,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Milestone {
+    Escalated,
+    Resolved,
+}
+
+impl SituationChange {
+    /// Whether subscribers should hear about this change.
+    pub fn milestone(&self) -> Option<Milestone> {
+        match (self.from, self.to) {
+            (SituationArc::Emerging | SituationArc::Developing | SituationArc::Cooling, SituationArc::Active) => {
+                Some(Milestone::Escalated)
+            }
+            (from, SituationArc::Cold) if from != SituationArc::Cold => Some(Milestone::Resolved),
+            _ => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self.milestone() {
+            Some(Milestone::Escalated) => format!("Escalated: now active (was {})", self.from),
+            Some(Milestone::Resolved) => format!("Gone quiet: now cold (was {})", self.from),
+            None => format!("Now {} (was {})", self.to, self.from),
+        }
+    }
+}
+
+/// An email address following a situation's milestones. The id doubles as
+/// the unsubscribe token and `confirm_token` as the opt-in token, so both
+/// only ever go out in emails to `email`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SituationSubscription {
+    pub id: Uuid,
+    pub situation_id: Uuid,
+    pub email: String,
+    pub confirm_token: Uuid,
+    /// Whether the confirmation link was followed. Milestones go only to
+    /// confirmed subscriptions.
+    pub confirmed: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SituationSubscription {
+    /// An unconfirmed subscription with fresh tokens.
+    pub fn pending(situation_id: Uuid, email: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            situation_id,
+            email: email.into(),
+            confirm_token: Uuid::new_v4(),
+            confirmed: false,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// One entry in a situation's feed.
+#[derive(Debug, Clone)]
+pub enum FeedEntry {
+    Signal {
+        id: Uuid,
+        title: String,
+        summary: String,
+        source_url: String,
+        linked_at: DateTime<Utc>,
+    },
+    Change(SituationChange),
+}
+
+impl FeedEntry {
+    pub fn at(&self) -> DateTime<Utc> {
+        match self {
+            FeedEntry::Signal { linked_at, .. } => *linked_at,
+            FeedEntry::Change(c) => c.at,
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render an Atom feed for a situation. `self_url` is the feed's own URL;
+/// `site_url`, if set, is where signal links point.
+pub fn render_atom(
+    situation_id: Uuid,
+    headline: &str,
+    self_url: &str,
+    site_url: Option<&str>,
+    entries: &[FeedEntry],
+) -> String {
+    let updated = entries.iter().map(FeedEntry::at).max().unwrap_or_else(Utc::now);
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+         <feed xmlns=\"http://www.w3.org/2005/Atom\">\n\
+         <id>urn:uuid:{situation_id}</id>\n\
+         <title>{}</title>\n\
+         <link rel=\"self\" href=\"{}\"/>\n\
+         <updated>{}</updated>\n",
+        escape(headline),
+        escape(self_url),
+        updated.to_rfc3339(),
+    );
+    for entry in entries {
+        let (id, title, summary, link) = match entry {
+            FeedEntry::Signal { id, title, summary, source_url, .. } => (
+                format!("urn:uuid:{id}"),
+                format!("New signal: {title}"),
+                summary.clone(),
+                site_url
+                    .map(|base| format!("{base}/signals/{id}"))
+                    .unwrap_or_else(|| source_url.clone()),
+            ),
+            FeedEntry::Change(change) => (
+                format!("urn:uuid:{}", change.id),
+                change.describe(),
+                format!("Temperature {:.2}", change.temperature),
+                site_url
+                    .map(|base| format!("{base}/situations/{situation_id}"))
+                    .unwrap_or_else(|| self_url.to_string()),
+            ),
+        };
+        xml.push_str(&format!(
+            "<entry>\n<id>{id}</id>\n<title>{}</title>\n<link href=\"{}\"/>\n<updated>{}</updated>\n<summary>{}</summary>\n</entry>\n",
+            escape(&title),
+            escape(&link),
+            entry.at().to_rfc3339(),
+            escape(&summary),
+        ));
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(from: SituationArc, to: SituationArc) -> SituationChange {
+        SituationChange {
+            id: Uuid::new_v4(),
+            situation_id: Uuid::new_v4(),
+            from,
+            to,
+            temperature: 0.7,
+            at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn only_escalation_and_going_cold_are_milestones() {
+        use SituationArc::*;
+        assert_eq!(change(Developing, Active).milestone(), Some(Milestone::Escalated));
+        assert_eq!(change(Cooling, Cold).milestone(), Some(Milestone::Resolved));
+        assert_eq!(change(Emerging, Developing).milestone(), None);
+        assert_eq!(change(Active, Cooling).milestone(), None);
+    }
+
+    #[test]
+    fn atom_escapes_text_and_lists_entries() {
+        let id = Uuid::new_v4();
+        let entries = vec![
+            FeedEntry::Signal {
+                id: Uuid::new_v4(),
+                title: "Parents & teachers rally <tonight>".to_string(),
+                summary: "At the \"district\" office".to_string(),
+                source_url: "https://example.com/a?x=1&y=2".to_string(),
+                linked_at: Utc::now(),
+            },
+            FeedEntry::Change(change(SituationArc::Developing, SituationArc::Active)),
+        ];
+        let xml = render_atom(id, "School closure fight", "https://api.example.com/feed.atom", None, &entries);
+        assert!(xml.contains("Parents &amp; teachers rally &lt;tonight&gt;"));
+        assert!(xml.contains("https://example.com/a?x=1&amp;y=2"));
+        assert!(xml.contains("Escalated: now active"));
+        assert_eq!(xml.matches("<entry>").count(), 2);
+    }
+}
@@ -0,0 +1,278 @@
+//! Clustering quality for situations.
+//!
+//! A situation is a cluster of signals. As signals accumulate, a situation
+//! can lose focus (its signals stop resembling each other) or drift into
+//! another (their centroids converge). `assess` scores every situation from
+//! its signals' embeddings, silhouette-style:
+//!
+//! - a signal's *cohesion* distance is its mean distance to the other
+//!   signals in its situation;
+//! - its *separation* distance is its distance to the nearest other
+//!   situation's centroid;
+//! - its silhouette is `(separation - cohesion) / max(separation, cohesion)`,
+//!   from -1 (belongs elsewhere) to 1 (well placed).
+//!
+//! Situations below `MIN_SILHOUETTE` or overlapping another above
+//! `MAX_CENTROID_OVERLAP` breach; their misplaced signals are re-woven.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Signals a situation needs before its cohesion means anything.
+pub const MIN_SIGNALS_FOR_QUALITY: usize = 3;
+/// Mean silhouette below which a situation has lost focus.
+pub const MIN_SILHOUETTE: f64 = 0.05;
+/// Centroid similarity above which two situations cover the same thing.
+pub const MAX_CENTROID_OVERLAP: f64 = 0.9;
+/// Similarity to its own centroid below which a signal is an outlier.
+pub const MIN_MEMBER_SIMILARITY: f64 = 0.45;
+/// Breached situations re-woven per weaving run.
+pub const MAX_REWEAVES_PER_RUN: usize = 5;
+/// Days before a re-woven situation can be re-woven again.
+pub const REWEAVE_COOLDOWN_DAYS: i64 = 7;
+
+/// A situation and its signals' embeddings.
+#[derive(Debug, Clone)]
+pub struct SituationCluster {
+    pub situation_id: Uuid,
+    pub signals: Vec<(Uuid, Vec<f32>)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityBreach {
+    LowCohesion,
+    HighOverlap,
+}
+
+impl QualityBreach {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LowCohesion => "low_cohesion",
+            Self::HighOverlap => "high_overlap",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "low_cohesion" => Some(Self::LowCohesion),
+            "high_overlap" => Some(Self::HighOverlap),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SituationQuality {
+    pub situation_id: Uuid,
+    pub signal_count: usize,
+    /// Mean pairwise similarity of the situation's signals.
+    pub cohesion: f64,
+    /// Mean silhouette of its signals.
+    pub silhouette: f64,
+    /// The situation with the most similar centroid, and that similarity.
+    pub nearest_situation: Option<(Uuid, f64)>,
+    /// Signals that sit closer to another situation than to their own, or
+    /// far from their own centroid.
+    pub misplaced: Vec<Uuid>,
+    pub breach: Option<QualityBreach>,
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b) {
+        dot += *x as f64 * *y as f64;
+        norm_a += *x as f64 * *x as f64;
+        norm_b += *y as f64 * *y as f64;
+    }
+    let denom = norm_a.sqrt() * norm_b.sqrt();
+    if denom == 0.0 {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+fn centroid(signals: &[(Uuid, Vec<f32>)]) -> Vec<f32> {
+    let Some((_, first)) = signals.first() else {
+        return Vec::new();
+    };
+    let dim = first.len();
+    let mut sum = vec![0.0f64; dim];
+    for (_, embedding) in signals.iter().filter(|(_, e)| e.len() == dim) {
+        for (s, v) in sum.iter_mut().zip(embedding) {
+            *s += *v as f64;
+        }
+    }
+    sum.iter().map(|s| (*s / signals.len() as f64) as f32).collect()
+}
+
+/// Score every cluster with at least `MIN_SIGNALS_FOR_QUALITY` signals.
+/// Smaller clusters still count as neighbors of the others.
+pub fn assess(clusters: &[SituationCluster]) -> Vec<SituationQuality> {
+    let centroids: Vec<(Uuid, Vec<f32>)> = clusters
+        .iter()
+        .filter(|c| !c.signals.is_empty())
+        .map(|c| (c.situation_id, centroid(&c.signals)))
+        .collect();
+
+    let mut results = Vec::new();
+    for cluster in clusters.iter().filter(|c| c.signals.len() >= MIN_SIGNALS_FOR_QUALITY) {
+        let own = centroid(&cluster.signals);
+        let others: Vec<&(Uuid, Vec<f32>)> =
+            centroids.iter().filter(|(id, _)| *id != cluster.situation_id).collect();
+
+        let nearest_situation = others
+            .iter()
+            .map(|(id, c)| (*id, cosine_similarity(&own, c)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        let n = cluster.signals.len();
+        let mut pair_sim_total = 0.0;
+        let mut silhouette_total = 0.0;
+        let mut misplaced = Vec::new();
+        for (i, (signal_id, embedding)) in cluster.signals.iter().enumerate() {
+            let mean_sim = cluster
+                .signals
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, (_, other))| cosine_similarity(embedding, other))
+                .sum::<f64>()
+                / (n - 1) as f64;
+            pair_sim_total += mean_sim;
+
+            let cohesion_dist = 1.0 - mean_sim;
+            let separation_dist = others
+                .iter()
+                .map(|(_, c)| 1.0 - cosine_similarity(embedding, c))
+                .fold(f64::INFINITY, f64::min);
+            let silhouette = if separation_dist.is_finite() {
+                let denom = cohesion_dist.max(separation_dist);
+                if denom > 0.0 { (separation_dist - cohesion_dist) / denom } else { 0.0 }
+            } else {
+                // No other situation to compare with.
+                1.0 - cohesion_dist
+            };
+            silhouette_total += silhouette;
+
+            if silhouette < 0.0 || cosine_similarity(embedding, &own) < MIN_MEMBER_SIMILARITY {
+                misplaced.push(*signal_id);
+            }
+        }
+
+        let cohesion = pair_sim_total / n as f64;
+        let silhouette = silhouette_total / n as f64;
+        let breach = if nearest_situation.is_some_and(|(_, sim)| sim >= MAX_CENTROID_OVERLAP) {
+            Some(QualityBreach::HighOverlap)
+        } else if silhouette < MIN_SILHOUETTE {
+            Some(QualityBreach::LowCohesion)
+        } else {
+            None
+        };
+
+        results.push(SituationQuality {
+            situation_id: cluster.situation_id,
+            signal_count: n,
+            cohesion,
+            silhouette,
+            nearest_situation,
+            misplaced,
+            breach,
+        });
+    }
+    results
+}
+
+/// Signals to re-weave for breached situations, worst situations first, at
+/// most `MAX_REWEAVES_PER_RUN` situations. Each entry lists the situation's
+/// misplaced signals; an empty list means all of them, for an overlapping
+/// situation with nothing misplaced, so the weaver can fold it into its
+/// neighbor. An overlapping pair is re-woven once, from its smaller side.
+pub fn reweave_plan(qualities: &[SituationQuality]) -> Vec<(Uuid, Vec<Uuid>)> {
+    let mut breached: Vec<&SituationQuality> = qualities.iter().filter(|q| q.breach.is_some()).collect();
+    breached.sort_by(|a, b| a.silhouette.total_cmp(&b.silhouette));
+
+    let mut plan: Vec<(Uuid, Vec<Uuid>)> = Vec::new();
+    for quality in breached {
+        if plan.len() >= MAX_REWEAVES_PER_RUN {
+            break;
+        }
+        if quality.breach == Some(QualityBreach::HighOverlap) {
+            let partner = quality.nearest_situation.map(|(id, _)| id);
+            let partner_is_smaller = qualities
+                .iter()
+                .find(|q| Some(q.situation_id) == partner)
+                .is_some_and(|p| p.signal_count < quality.signal_count);
+            if partner_is_smaller || plan.iter().any(|(id, _)| Some(*id) == partner) {
+                continue;
+            }
+        } else if quality.misplaced.is_empty() {
+            continue;
+        }
+        plan.push((quality.situation_id, quality.misplaced.clone()));
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(embeddings: &[[f32; 3]]) -> SituationCluster {
+        SituationCluster {
+            situation_id: Uuid::new_v4(),
+            signals: embeddings.iter().map(|e| (Uuid::new_v4(), e.to_vec())).collect(),
+        }
+    }
+
+    #[test]
+    fn a_tight_distinct_cluster_has_no_breach() {
+        let housing = cluster(&[[1.0, 0.1, 0.0], [0.9, 0.0, 0.1], [1.0, 0.0, 0.0]]);
+        let transit = cluster(&[[0.0, 1.0, 0.1], [0.1, 0.9, 0.0], [0.0, 1.0, 0.0]]);
+
+        let qualities = assess(&[housing, transit]);
+
+        assert_eq!(qualities.len(), 2);
+        for q in &qualities {
+            assert_eq!(q.breach, None);
+            assert!(q.silhouette > 0.5);
+            assert!(q.misplaced.is_empty());
+        }
+    }
+
+    #[test]
+    fn a_signal_closer_to_another_situation_is_misplaced() {
+        let housing = cluster(&[[1.0, 0.0, 0.0], [0.9, 0.1, 0.0], [0.0, 1.0, 0.0]]);
+        let stray = housing.signals[2].0;
+        let transit = cluster(&[[0.0, 1.0, 0.1], [0.1, 0.9, 0.0], [0.0, 1.0, 0.0]]);
+
+        let qualities = assess(&[housing.clone(), transit]);
+        let housing_quality = qualities.iter().find(|q| q.situation_id == housing.situation_id).unwrap();
+
+        assert_eq!(housing_quality.misplaced, vec![stray]);
+    }
+
+    #[test]
+    fn overlapping_situations_are_rewoven_once_from_the_smaller_side() {
+        let big = cluster(&[[1.0, 0.0, 0.0], [0.95, 0.05, 0.0], [1.0, 0.02, 0.0], [0.97, 0.0, 0.03]]);
+        let small = cluster(&[[1.0, 0.01, 0.0], [0.96, 0.03, 0.0], [0.99, 0.0, 0.02]]);
+
+        let qualities = assess(&[big.clone(), small.clone()]);
+        let plan = reweave_plan(&qualities);
+
+        assert!(qualities.iter().all(|q| q.breach == Some(QualityBreach::HighOverlap)));
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0, small.situation_id);
+    }
+
+    #[test]
+    fn small_situations_are_not_scored() {
+        let pair = cluster(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+        assert!(assess(&[pair]).is_empty());
+    }
+}
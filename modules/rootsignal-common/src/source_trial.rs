@@ -0,0 +1,108 @@
+//! Trial period for newly discovered sources.
+//!
+//! A discovered or submitted source starts on probation: it is scraped at
+//! most every `TRIAL_MIN_CADENCE_HOURS` and probation sources together get at
+//! most `TRIAL_SCRAPE_SHARE` of a run's scrapes. It graduates to a regular
+//! source once `GRADUATE_AFTER_CORROBORATED` of its signals are corroborated,
+//! and is deactivated after `FAIL_AFTER_EMPTY_RUNS` consecutive empty runs.
+//! Each transition is recorded so admins can see why a source changed state.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::DiscoveryMethod;
+
+/// Shortest interval between scrapes of a probation source.
+pub const TRIAL_MIN_CADENCE_HOURS: u32 = 48;
+
+/// Largest share of a run's scrapes probation sources may take.
+pub const TRIAL_SCRAPE_SHARE: f64 = 0.2;
+
+/// Corroborated signals a probation source needs to graduate.
+pub const GRADUATE_AFTER_CORROBORATED: u32 = 2;
+
+/// Consecutive empty runs after which a probation source is deactivated.
+pub const FAIL_AFTER_EMPTY_RUNS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TrialState {
+    /// Never on trial: curated, bootstrap-reviewed, or older than trials.
+    #[default]
+    Established,
+    Probation,
+    Graduated,
+    /// Deactivated during its trial.
+    Failed,
+}
+
+impl TrialState {
+    /// The state a source is created in.
+    pub fn for_new_source(method: &DiscoveryMethod) -> Self {
+        match method {
+            DiscoveryMethod::Curated | DiscoveryMethod::ColdStart => TrialState::Established,
+            _ => TrialState::Probation,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrialState::Established => "established",
+            TrialState::Probation => "probation",
+            TrialState::Graduated => "graduated",
+            TrialState::Failed => "failed",
+        }
+    }
+
+    /// Unknown or missing values are `Established`.
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "probation" => TrialState::Probation,
+            "graduated" => TrialState::Graduated,
+            "failed" => TrialState::Failed,
+            _ => TrialState::Established,
+        }
+    }
+}
+
+/// A recorded change of a source's trial state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrialTransition {
+    pub canonical_key: String,
+    pub from: TrialState,
+    pub to: TrialState,
+    pub reason: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Where a probation source's trial goes given its record so far: graduated,
+/// failed, or None while it stays on probation. Graduation wins when both
+/// apply, since corroboration is the stronger evidence.
+pub fn trial_outcome(signals_corroborated: u32, consecutive_empty_runs: u32) -> Option<(TrialState, String)> {
+    if signals_corroborated >= GRADUATE_AFTER_CORROBORATED {
+        Some((TrialState::Graduated, format!("{signals_corroborated} corroborated signals")))
+    } else if consecutive_empty_runs >= FAIL_AFTER_EMPTY_RUNS {
+        Some((TrialState::Failed, format!("{consecutive_empty_runs} consecutive empty runs")))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probation_ends_in_graduation_or_deactivation() {
+        assert_eq!(trial_outcome(0, 1), None);
+        assert_eq!(trial_outcome(GRADUATE_AFTER_CORROBORATED, 0).map(|(s, _)| s), Some(TrialState::Graduated));
+        assert_eq!(trial_outcome(1, FAIL_AFTER_EMPTY_RUNS).map(|(s, _)| s), Some(TrialState::Failed));
+        assert_eq!(
+            trial_outcome(GRADUATE_AFTER_CORROBORATED, FAIL_AFTER_EMPTY_RUNS).map(|(s, _)| s),
+            Some(TrialState::Graduated)
+        );
+        assert_eq!(TrialState::for_new_source(&DiscoveryMethod::HumanSubmission), TrialState::Probation);
+        assert_eq!(TrialState::for_new_source(&DiscoveryMethod::Curated), TrialState::Established);
+        assert_eq!(TrialState::parse(""), TrialState::Established);
+    }
+}
@@ -0,0 +1,218 @@
+//! Story energy: how much a story deserves attention right now.
+//!
+//! Energy ranks stories everywhere they are listed. It is a weighted mean of
+//! four components, each in 0.0–1.0 and persisted alongside the total so any
+//! ranking can be explained:
+//!
+//! - **activity**: recency- and confidence-weighted signal count. Each signal
+//!   counts `confidence × 0.5^(age / 7 days)`; the sum saturates as
+//!   `sum / (sum + 5)`, so five fresh, fully confident signals give 0.5.
+//! - **severity**: the most severe tension in the story (critical 1.0,
+//!   high 0.75, medium 0.5, low or unknown 0.25).
+//! - **corroboration**: how independently confirmed the signals are, the
+//!   confidence-weighted mean of `min(corroboration_count / 3, 1)`.
+//! - **heat**: the hottest cause heat among the story's signals.
+//!
+//! Weights are tunable per region; the defaults below apply elsewhere.
+
+use serde::{Deserialize, Serialize};
+
+/// Days for a signal's contribution to activity to halve.
+pub const ACTIVITY_HALF_LIFE_DAYS: f64 = 7.0;
+
+/// Weighted signal count at which activity reaches 0.5.
+const ACTIVITY_SATURATION: f64 = 5.0;
+
+/// Corroborations at which a signal counts as fully corroborated.
+const FULL_CORROBORATION: f64 = 3.0;
+
+/// Relative weight of each energy component. Only ratios matter; the total
+/// is normalized by their sum.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StoryEnergyWeights {
+    pub activity: f64,
+    pub severity: f64,
+    pub corroboration: f64,
+    pub heat: f64,
+}
+
+impl Default for StoryEnergyWeights {
+    fn default() -> Self {
+        Self {
+            activity: 0.4,
+            severity: 0.2,
+            corroboration: 0.25,
+            heat: 0.15,
+        }
+    }
+}
+
+impl StoryEnergyWeights {
+    pub fn validate(&self) -> Result<(), String> {
+        let weights = [self.activity, self.severity, self.corroboration, self.heat];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("energy weights must be finite and non-negative".to_string());
+        }
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return Err("at least one energy weight must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// One signal's contribution to its story's energy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergySignal {
+    pub confidence: f64,
+    pub age_days: f64,
+    pub corroboration_count: u32,
+    pub cause_heat: f64,
+}
+
+/// The four energy components of a story, each 0.0–1.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnergyComponents {
+    pub activity: f64,
+    pub severity: f64,
+    pub corroboration: f64,
+    pub heat: f64,
+}
+
+impl EnergyComponents {
+    /// Components for a story from its signals and its most severe tension.
+    pub fn compute(signals: &[EnergySignal], severity: Option<&str>) -> Self {
+        let weights: Vec<f64> = signals
+            .iter()
+            .map(|s| s.confidence.clamp(0.0, 1.0) * 0.5_f64.powf(s.age_days.max(0.0) / ACTIVITY_HALF_LIFE_DAYS))
+            .collect();
+        let weighted_count: f64 = weights.iter().sum();
+
+        let confidence_total: f64 = signals.iter().map(|s| s.confidence.clamp(0.0, 1.0)).sum();
+        let corroboration = if confidence_total > 0.0 {
+            signals
+                .iter()
+                .map(|s| {
+                    s.confidence.clamp(0.0, 1.0) * (s.corroboration_count as f64 / FULL_CORROBORATION).min(1.0)
+                })
+                .sum::<f64>()
+                / confidence_total
+        } else {
+            0.0
+        };
+
+        Self {
+            activity: weighted_count / (weighted_count + ACTIVITY_SATURATION),
+            severity: severity.map_or(0.25, severity_score),
+            corroboration,
+            heat: signals.iter().map(|s| s.cause_heat.clamp(0.0, 1.0)).fold(0.0, f64::max),
+        }
+    }
+
+    /// Weighted mean of the components, 0.0–1.0.
+    pub fn energy(&self, weights: &StoryEnergyWeights) -> f64 {
+        let total = weights.activity + weights.severity + weights.corroboration + weights.heat;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (self.activity * weights.activity
+            + self.severity * weights.severity
+            + self.corroboration * weights.corroboration
+            + self.heat * weights.heat)
+            / total
+    }
+}
+
+/// Severity component for a tension severity label.
+pub fn severity_score(severity: &str) -> f64 {
+    match severity.to_lowercase().as_str() {
+        "critical" => 1.0,
+        "high" => 0.75,
+        "medium" => 0.5,
+        _ => 0.25,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(confidence: f64, age_days: f64, corroboration_count: u32, cause_heat: f64) -> EnergySignal {
+        EnergySignal {
+            confidence,
+            age_days,
+            corroboration_count,
+            cause_heat,
+        }
+    }
+
+    #[test]
+    fn five_fresh_confident_signals_give_half_activity() {
+        let signals = vec![signal(1.0, 0.0, 0, 0.0); 5];
+
+        let c = EnergyComponents::compute(&signals, None);
+
+        assert!((c.activity - 0.5).abs() < 1e-9, "got {}", c.activity);
+    }
+
+    #[test]
+    fn old_and_doubtful_signals_add_less_activity() {
+        let fresh = EnergyComponents::compute(&[signal(1.0, 0.0, 0, 0.0)], None);
+        let week_old = EnergyComponents::compute(&[signal(1.0, 7.0, 0, 0.0)], None);
+        let doubtful = EnergyComponents::compute(&[signal(0.5, 0.0, 0, 0.0)], None);
+
+        assert!(week_old.activity < fresh.activity);
+        assert!((week_old.activity - doubtful.activity).abs() < 1e-9);
+    }
+
+    #[test]
+    fn corroboration_is_confidence_weighted() {
+        let signals = [signal(1.0, 0.0, 3, 0.0), signal(0.25, 0.0, 0, 0.0)];
+
+        let c = EnergyComponents::compute(&signals, None);
+
+        assert!((c.corroboration - 0.8).abs() < 1e-9, "got {}", c.corroboration);
+    }
+
+    #[test]
+    fn severity_and_heat_come_from_the_worst_case() {
+        let signals = [signal(1.0, 0.0, 0, 0.2), signal(1.0, 0.0, 0, 0.9)];
+
+        let c = EnergyComponents::compute(&signals, Some("Critical"));
+
+        assert_eq!(c.severity, 1.0);
+        assert_eq!(c.heat, 0.9);
+    }
+
+    #[test]
+    fn energy_is_the_weighted_mean_of_components() {
+        let c = EnergyComponents {
+            activity: 1.0,
+            severity: 0.0,
+            corroboration: 0.5,
+            heat: 0.0,
+        };
+        let weights = StoryEnergyWeights {
+            activity: 2.0,
+            severity: 0.0,
+            corroboration: 2.0,
+            heat: 0.0,
+        };
+
+        assert!((c.energy(&weights) - 0.75).abs() < 1e-9);
+        assert!((EnergyComponents { activity: 1.0, severity: 1.0, corroboration: 1.0, heat: 1.0 }
+            .energy(&StoryEnergyWeights::default())
+            - 1.0)
+            .abs()
+            < 1e-9);
+    }
+
+    #[test]
+    fn validate_rejects_negative_and_all_zero_weights() {
+        let negative = StoryEnergyWeights { heat: -0.1, ..Default::default() };
+        let zero = StoryEnergyWeights { activity: 0.0, severity: 0.0, corroboration: 0.0, heat: 0.0 };
+
+        assert!(negative.validate().is_err());
+        assert!(zero.validate().is_err());
+        assert!(StoryEnergyWeights::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,196 @@
+//! Data-subject requests: a person asking to be removed from the graph.
+//!
+//! An admin opens a request with the person's name and any handles, reviews
+//! every signal, actor and evidence snippet that mentions them, and then
+//! executes it: the terms are replaced in signal text and evidence snippets,
+//! and matching actors are anonymized. The request node is the audit record
+//! (who opened and executed it, when, and what was touched). Executed
+//! requests' terms stay on a suppression list so the writer redacts them from
+//! anything extracted later.
+
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Replaces a removed name in signal text and evidence snippets.
+pub const REDACTED_NAME: &str = "[name removed]";
+
+/// Name given to an anonymized actor.
+pub const ANONYMIZED_ACTOR_NAME: &str = "Private individual";
+
+/// Shortest term that may be searched and redacted; shorter ones match
+/// too much unrelated text.
+pub const MIN_TERM_CHARS: usize = 3;
+
+pub const MAX_TERMS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectRequestStatus {
+    Open,
+    Executed,
+    Dismissed,
+}
+
+impl SubjectRequestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubjectRequestStatus::Open => "open",
+            SubjectRequestStatus::Executed => "executed",
+            SubjectRequestStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "open" => Some(SubjectRequestStatus::Open),
+            "executed" => Some(SubjectRequestStatus::Executed),
+            "dismissed" => Some(SubjectRequestStatus::Dismissed),
+            _ => None,
+        }
+    }
+}
+
+/// What an executed request changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionSummary {
+    pub signals: u32,
+    pub actors: u32,
+    pub evidence: u32,
+    /// Ids of every node changed, for the audit trail.
+    pub node_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubjectRequest {
+    pub id: Uuid,
+    /// Names and handles searched for and redacted.
+    pub terms: Vec<String>,
+    /// How the request arrived, reference numbers; admin-only.
+    pub note: Option<String>,
+    pub status: SubjectRequestStatus,
+    pub opened_by: String,
+    pub created_at: DateTime<Utc>,
+    pub closed_by: Option<String>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub redaction: Option<RedactionSummary>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectMatchKind {
+    Signal,
+    Actor,
+    Evidence,
+}
+
+/// One place a subject appears, for the admin's review before execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubjectMatch {
+    pub kind: SubjectMatchKind,
+    pub id: Uuid,
+    /// Signal title, actor name, or evidence source URL.
+    pub label: String,
+    /// The property the term was found in ("title", "bio", "snippet", ...).
+    pub field: String,
+    pub excerpt: String,
+}
+
+/// Normalize a request's name and handles into search terms: trimmed,
+/// handles also searched without their '@', lowercase, deduplicated.
+pub fn search_terms(name: &str, handles: &[String]) -> Result<Vec<String>, String> {
+    let mut terms: Vec<String> = Vec::new();
+    let mut add = |term: &str| {
+        let term = term.trim().to_lowercase();
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    };
+    add(name);
+    for handle in handles {
+        add(handle);
+        add(handle.trim().trim_start_matches('@'));
+    }
+
+    if terms.is_empty() {
+        return Err("A request needs the person's name or a handle".to_string());
+    }
+    if let Some(short) = terms.iter().find(|t| t.chars().count() < MIN_TERM_CHARS) {
+        return Err(format!("\"{short}\" is too short to search for safely"));
+    }
+    if terms.len() > MAX_TERMS {
+        return Err(format!("At most {MAX_TERMS} names and handles per request"));
+    }
+    Ok(terms)
+}
+
+static WHITESPACE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+
+/// Case-insensitive whole-word matcher for any of `terms`; None when empty.
+pub fn terms_regex(terms: &[String]) -> Option<Regex> {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let alternatives: Vec<String> = terms
+        .iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            let escaped: Vec<String> = WHITESPACE_RE.split(t).map(regex::escape).collect();
+            // `\b` only holds next to a word character, so a handle's '@'
+            // gets no boundary of its own.
+            let start = if is_word(t.chars().next()) { r"\b" } else { "" };
+            let end = if is_word(t.chars().last()) { r"\b" } else { "" };
+            format!("{start}{}{end}", escaped.join(r"\s+"))
+        })
+        .collect();
+    if alternatives.is_empty() {
+        return None;
+    }
+    RegexBuilder::new(&alternatives.join("|")).case_insensitive(true).build().ok()
+}
+
+/// Replace every whole-word occurrence of the terms; None if nothing matched.
+pub fn redact_terms(text: &str, re: &Regex) -> Option<String> {
+    re.is_match(text).then(|| re.replace_all(text, REDACTED_NAME).into_owned())
+}
+
+/// Up to 40 characters either side of the first match, for review.
+pub fn match_excerpt(text: &str, re: &Regex) -> Option<String> {
+    let m = re.find(text)?;
+    let start = text[..m.start()].char_indices().rev().nth(39).map(|(i, _)| i).unwrap_or(0);
+    let end = text[m.end()..].char_indices().nth(40).map(|(i, _)| m.end() + i).unwrap_or(text.len());
+    let mut excerpt = text[start..end].trim().to_string();
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < text.len() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terms_are_normalized_and_bounded() {
+        let terms = search_terms(" Jo Alvarez ", &["@jalvarez".to_string()]).unwrap();
+        assert_eq!(terms, vec!["jo alvarez", "@jalvarez", "jalvarez"]);
+        assert!(search_terms("Jo", &[]).is_err());
+        assert!(search_terms("  ", &[]).is_err());
+    }
+
+    #[test]
+    fn redaction_matches_whole_words_in_any_case() {
+        let re = terms_regex(&search_terms("Jo Alvarez", &["@jalvarez".to_string()]).unwrap()).unwrap();
+        assert_eq!(
+            redact_terms("Tenant JO  ALVAREZ (@jalvarez) says the heat is off", &re).as_deref(),
+            Some("Tenant [name removed] ([name removed]) says the heat is off")
+        );
+        assert_eq!(redact_terms("Jo Alvarezson spoke", &re), None);
+        assert_eq!(match_excerpt("Jo Alvarez spoke", &re).as_deref(), Some("Jo Alvarez spoke"));
+    }
+}
@@ -0,0 +1,218 @@
+//! Plain-language and translated summaries of public signals and situations.
+//!
+//! Summaries are written in English at a newspaper register. For the most
+//! visible signals and situations in a region, the scout generates a
+//! plain-language English summary and translations into the region's
+//! priority languages, and caches them in the `summary_variants` Postgres
+//! table. Each variant records a hash of the English summary it came from:
+//! a variant is only served, and only left alone by the next refresh, while
+//! that summary is unchanged.
+
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// The language summaries are written in. Its variant holds the plain-language summary.
+pub const SOURCE_LANGUAGE: &str = "en";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryVariant {
+    pub subject_id: Uuid,
+    /// Primary language subtag, e.g. "es".
+    pub language: String,
+    pub summary: String,
+    pub plain_summary: Option<String>,
+    /// `source_hash` of the English summary this was generated from.
+    pub source_hash: String,
+}
+
+/// Hash identifying the English summary a variant was generated from.
+pub fn source_hash(summary: &str) -> String {
+    hex::encode(Sha256::digest(summary.trim().as_bytes()))
+}
+
+/// Normalize a language tag to its lowercase primary subtag ("es-MX" → "es").
+pub fn primary_language(tag: &str) -> Option<String> {
+    let primary = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+    let valid = (2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic());
+    valid.then_some(primary)
+}
+
+/// Languages from an `Accept-Language` header (or a plain list like "so,en"),
+/// most preferred first, as primary subtags. Wildcards and `q=0` are dropped.
+pub fn preferred_languages(accept_language: &str) -> Vec<String> {
+    let mut ranked: Vec<(f32, usize, String)> = accept_language
+        .split(',')
+        .enumerate()
+        .filter_map(|(i, part)| {
+            let mut pieces = part.split(';');
+            let language = primary_language(pieces.next()?)?;
+            let q = pieces
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+            (q > 0.0).then_some((q, i, language))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let mut languages: Vec<String> = Vec::new();
+    for (_, _, language) in ranked {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+    languages
+}
+
+/// The variant to serve for a subject whose English summary is `summary`:
+/// the first preferred language with a fresh variant. Preferences after
+/// English are ignored, since the original summary satisfies them.
+pub fn best_variant<'a>(
+    variants: &'a [SummaryVariant],
+    preferred: &[String],
+    summary: &str,
+) -> Option<&'a SummaryVariant> {
+    let hash = source_hash(summary);
+    for language in preferred {
+        let fresh = variants.iter().find(|v| &v.language == language && v.source_hash == hash);
+        if fresh.is_some() || language == SOURCE_LANGUAGE {
+            return fresh;
+        }
+    }
+    None
+}
+
+/// Languages a subject still needs variants in: each of `languages` without
+/// a variant generated from the current summary, plus English when the
+/// subject has no plain-language summary of its own. `cached` is the
+/// subject's stored variants.
+pub fn languages_to_refresh(
+    cached: &[SummaryVariant],
+    summary: &str,
+    has_plain_summary: bool,
+    languages: &[String],
+) -> Vec<String> {
+    let hash = source_hash(summary);
+    let mut wanted: Vec<&str> = Vec::new();
+    if !has_plain_summary {
+        wanted.push(SOURCE_LANGUAGE);
+    }
+    wanted.extend(languages.iter().map(String::as_str).filter(|l| *l != SOURCE_LANGUAGE));
+    wanted
+        .into_iter()
+        .filter(|language| !cached.iter().any(|v| v.language == *language && v.source_hash == hash))
+        .map(str::to_string)
+        .collect()
+}
+
+type VariantRow = (Uuid, String, String, Option<String>, String);
+
+/// Stored variants for each of `subject_ids`, fresh or not.
+pub async fn load(
+    pool: &PgPool,
+    subject_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<SummaryVariant>>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, VariantRow>(
+        "SELECT subject_id, language, summary, plain_summary, source_hash
+         FROM summary_variants
+         WHERE subject_id = ANY($1)",
+    )
+    .bind(subject_ids)
+    .fetch_all(pool)
+    .await?;
+
+    let mut variants: HashMap<Uuid, Vec<SummaryVariant>> = HashMap::new();
+    for (subject_id, language, summary, plain_summary, source_hash) in rows {
+        variants.entry(subject_id).or_default().push(SummaryVariant {
+            subject_id,
+            language,
+            summary,
+            plain_summary,
+            source_hash,
+        });
+    }
+    Ok(variants)
+}
+
+/// Store a variant, replacing any earlier one in the same language.
+/// `subject_kind` is "signal" or "situation".
+pub async fn upsert(pool: &PgPool, subject_kind: &str, variant: &SummaryVariant) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO summary_variants (subject_id, subject_kind, language, summary, plain_summary, source_hash)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (subject_id, language) DO UPDATE
+         SET summary = EXCLUDED.summary,
+             plain_summary = EXCLUDED.plain_summary,
+             source_hash = EXCLUDED.source_hash,
+             generated_at = now()",
+    )
+    .bind(variant.subject_id)
+    .bind(subject_kind)
+    .bind(&variant.language)
+    .bind(&variant.summary)
+    .bind(&variant.plain_summary)
+    .bind(&variant.source_hash)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Drop every variant of `subject_ids`, e.g. after their text was redacted.
+pub async fn delete(pool: &PgPool, subject_ids: &[Uuid]) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM summary_variants WHERE subject_id = ANY($1)")
+        .bind(subject_ids)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(language: &str, summary: &str) -> SummaryVariant {
+        SummaryVariant {
+            subject_id: Uuid::nil(),
+            language: language.to_string(),
+            summary: format!("{language} summary"),
+            plain_summary: None,
+            source_hash: source_hash(summary),
+        }
+    }
+
+    #[test]
+    fn accept_language_is_ranked_by_quality_then_order() {
+        let languages = preferred_languages("en-US;q=0.7, es-MX, so;q=0.9, es;q=0.8, *;q=0.5, fr;q=0");
+
+        assert_eq!(languages, vec!["es", "so", "en"]);
+    }
+
+    #[test]
+    fn only_variants_of_the_current_summary_are_served() {
+        let variants = vec![variant("es", "old summary"), variant("so", "new summary")];
+        let preferred = vec!["es".to_string(), "so".to_string()];
+
+        let best = best_variant(&variants, &preferred, "new summary");
+
+        assert_eq!(best.map(|v| v.language.as_str()), Some("so"));
+    }
+
+    #[test]
+    fn english_before_a_translation_means_the_original() {
+        let variants = vec![variant("es", "summary")];
+        let preferred = vec!["en".to_string(), "es".to_string()];
+
+        assert_eq!(best_variant(&variants, &preferred, "summary"), None);
+    }
+
+    #[test]
+    fn refresh_covers_stale_and_missing_languages_and_plain_english() {
+        let cached = vec![variant("es", "summary"), variant("so", "old summary")];
+        let languages = vec!["es".to_string(), "so".to_string(), "hmn".to_string()];
+
+        assert_eq!(languages_to_refresh(&cached, "summary", true, &languages), vec!["so", "hmn"]);
+        assert_eq!(languages_to_refresh(&cached, "summary", false, &languages), vec!["en", "so", "hmn"]);
+    }
+}
@@ -0,0 +1,517 @@
+//! Budget allocation across synthesis phases.
+//!
+//! When a run reaches synthesis with little budget left, not every phase can
+//! run at full size. Each phase has a priority (0 turns it off) and the
+//! remaining budget is split between them either by rank (in priority order,
+//! each phase that fits gets one unit of work, then the rest tops phases up
+//! in the same order) or in proportion to priority.
+//! A phase whose share can't cover one unit of its work is skipped.
+//!
+//! Priorities and the mode have built-in defaults, which a global policy can
+//! replace and a region policy can replace again, per setting. Policies live
+//! in the `synthesis_budget_policies` Postgres table; the scout resolves them
+//! at the start of synthesis and records the allocation on the run log.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SynthesisPhase {
+    ResponseMapping,
+    TensionLinker,
+    ResponseFinder,
+    GatheringFinder,
+    Investigation,
+}
+
+impl SynthesisPhase {
+    pub const ALL: [SynthesisPhase; 5] = [
+        SynthesisPhase::ResponseMapping,
+        SynthesisPhase::TensionLinker,
+        SynthesisPhase::ResponseFinder,
+        SynthesisPhase::GatheringFinder,
+        SynthesisPhase::Investigation,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ResponseMapping => "response_mapping",
+            Self::TensionLinker => "tension_linker",
+            Self::ResponseFinder => "response_finder",
+            Self::GatheringFinder => "gathering_finder",
+            Self::Investigation => "investigation",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.as_str() == s)
+    }
+
+    pub fn default_priority(&self) -> u32 {
+        match self {
+            Self::TensionLinker => 5,
+            Self::ResponseFinder => 4,
+            Self::ResponseMapping => 3,
+            Self::GatheringFinder => 2,
+            Self::Investigation => 1,
+        }
+    }
+}
+
+impl fmt::Display for SynthesisPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationMode {
+    /// Priority order: a unit for every phase that fits, then top-ups.
+    #[default]
+    Rank,
+    /// Every phase gets a share in proportion to its priority.
+    Proportional,
+}
+
+impl AllocationMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rank => "rank",
+            Self::Proportional => "proportional",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "rank" => Some(Self::Rank),
+            "proportional" => Some(Self::Proportional),
+            _ => None,
+        }
+    }
+}
+
+/// Where a setting's value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingSource {
+    Default,
+    Global,
+    Region,
+}
+
+impl SettingSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Global => "global",
+            Self::Region => "region",
+        }
+    }
+}
+
+/// A stored policy. Settings it leaves out fall through to the next layer.
+#[derive(Debug, Clone)]
+pub struct PolicyOverride {
+    /// `None` applies in every region.
+    pub region: Option<String>,
+    pub mode: Option<AllocationMode>,
+    pub priorities: BTreeMap<SynthesisPhase, u32>,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The policy a run uses, with the layer each setting came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynthesisBudgetPolicy {
+    pub mode: AllocationMode,
+    pub mode_source: SettingSource,
+    pub priorities: Vec<(SynthesisPhase, u32, SettingSource)>,
+}
+
+impl Default for SynthesisBudgetPolicy {
+    fn default() -> Self {
+        Self::resolve(&[], None)
+    }
+}
+
+impl SynthesisBudgetPolicy {
+    /// The policy in `region`, or with the global policy only when `region`
+    /// is `None`. A region setting beats a global one, which beats the
+    /// default.
+    pub fn resolve(overrides: &[PolicyOverride], region: Option<&str>) -> Self {
+        let global = overrides.iter().find(|o| o.region.is_none());
+        let local = region.and_then(|r| overrides.iter().find(|o| o.region.as_deref() == Some(r)));
+
+        let (mode, mode_source) = match (local.and_then(|o| o.mode), global.and_then(|o| o.mode)) {
+            (Some(mode), _) => (mode, SettingSource::Region),
+            (None, Some(mode)) => (mode, SettingSource::Global),
+            (None, None) => (AllocationMode::default(), SettingSource::Default),
+        };
+        let priorities = SynthesisPhase::ALL
+            .into_iter()
+            .map(|phase| {
+                let layer = |o: Option<&PolicyOverride>| o.and_then(|o| o.priorities.get(&phase).copied());
+                match (layer(local), layer(global)) {
+                    (Some(p), _) => (phase, p, SettingSource::Region),
+                    (None, Some(p)) => (phase, p, SettingSource::Global),
+                    (None, None) => (phase, phase.default_priority(), SettingSource::Default),
+                }
+            })
+            .collect();
+        Self { mode, mode_source, priorities }
+    }
+
+    pub fn priority(&self, phase: SynthesisPhase) -> u32 {
+        self.priorities
+            .iter()
+            .find(|(p, _, _)| *p == phase)
+            .map_or(phase.default_priority(), |(_, priority, _)| *priority)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Allocation
+// ---------------------------------------------------------------------------
+
+/// What a phase costs: one unit of its work (a target), and the full run
+/// at its usual size.
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseDemand {
+    pub phase: SynthesisPhase,
+    pub unit_cents: u64,
+    pub full_cents: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhaseOutcome {
+    Run,
+    /// Priority 0.
+    Disabled,
+    /// Its share couldn't cover one unit of work.
+    OutOfBudget,
+}
+
+impl PhaseOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Run => "run",
+            Self::Disabled => "disabled",
+            Self::OutOfBudget => "out_of_budget",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseAllocation {
+    pub phase: SynthesisPhase,
+    pub priority: u32,
+    /// `None` when the budget is unlimited.
+    pub allocated_cents: Option<u64>,
+    pub outcome: PhaseOutcome,
+}
+
+impl PhaseAllocation {
+    /// Units of work the allocation pays for, at most `max_units`.
+    pub fn units(&self, unit_cents: u64, max_units: usize) -> usize {
+        match self.allocated_cents {
+            Some(cents) if unit_cents > 0 => ((cents / unit_cents) as usize).min(max_units),
+            _ => max_units,
+        }
+    }
+}
+
+/// Split `remaining_cents` (`None` for unlimited) between the phases in
+/// `demands`, highest priority first. Allocations come back in the same
+/// priority order.
+pub fn allocate(
+    policy: &SynthesisBudgetPolicy,
+    remaining_cents: Option<u64>,
+    demands: &[PhaseDemand],
+) -> Vec<PhaseAllocation> {
+    let mut ranked: Vec<(PhaseDemand, u32)> = demands.iter().map(|d| (*d, policy.priority(d.phase))).collect();
+    ranked.sort_by_key(|(_, priority)| std::cmp::Reverse(*priority));
+
+    let outcome = |priority: u32, funded: bool| match (priority, funded) {
+        (0, _) => PhaseOutcome::Disabled,
+        (_, true) => PhaseOutcome::Run,
+        (_, false) => PhaseOutcome::OutOfBudget,
+    };
+
+    let Some(remaining) = remaining_cents else {
+        return ranked
+            .into_iter()
+            .map(|(d, priority)| PhaseAllocation {
+                phase: d.phase,
+                priority,
+                allocated_cents: None,
+                outcome: outcome(priority, true),
+            })
+            .collect();
+    };
+
+    let shares: Vec<u64> = match policy.mode {
+        AllocationMode::Rank => {
+            // Fund one unit of each phase in rank order, then top up in the
+            // same order to each phase's full size.
+            let mut left = remaining;
+            let mut shares: Vec<u64> = ranked
+                .iter()
+                .map(|(d, priority)| {
+                    if *priority > 0 && d.unit_cents <= left {
+                        left -= d.unit_cents;
+                        d.unit_cents
+                    } else {
+                        0
+                    }
+                })
+                .collect();
+            for ((d, _), share) in ranked.iter().zip(shares.iter_mut()) {
+                if *share > 0 {
+                    let top_up = d.full_cents.saturating_sub(*share).min(left);
+                    *share += top_up;
+                    left -= top_up;
+                }
+            }
+            shares
+        }
+        AllocationMode::Proportional => {
+            // Drop the lowest-priority phase that can't afford one unit
+            // from its share, and re-split, until every share is enough.
+            let mut funded: Vec<bool> = ranked.iter().map(|(_, priority)| *priority > 0).collect();
+            loop {
+                let total: u64 = ranked.iter().zip(&funded).filter(|(_, f)| **f).map(|((_, p), _)| *p as u64).sum();
+                let share = |p: u32| (remaining * p as u64).checked_div(total).unwrap_or(0);
+                let short = (0..ranked.len())
+                    .rev()
+                    .find(|&i| funded[i] && share(ranked[i].1) < ranked[i].0.unit_cents);
+                match short {
+                    Some(i) => funded[i] = false,
+                    None => {
+                        break ranked
+                            .iter()
+                            .zip(&funded)
+                            .map(|((d, p), f)| if *f { share(*p).min(d.full_cents) } else { 0 })
+                            .collect();
+                    }
+                }
+            }
+        }
+    };
+
+    ranked
+        .into_iter()
+        .zip(shares)
+        .map(|((d, priority), share)| PhaseAllocation {
+            phase: d.phase,
+            priority,
+            allocated_cents: Some(share),
+            outcome: outcome(priority, share > 0),
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+type PolicyRow = (Option<String>, Option<String>, serde_json::Value, String, DateTime<Utc>);
+
+/// Settings this build doesn't know are skipped.
+fn row_to_override(r: PolicyRow) -> PolicyOverride {
+    let priorities = r
+        .2
+        .as_object()
+        .map(|m| {
+            m.iter()
+                .filter_map(|(k, v)| Some((SynthesisPhase::parse(k)?, v.as_u64()? as u32)))
+                .collect()
+        })
+        .unwrap_or_default();
+    PolicyOverride {
+        region: r.0,
+        mode: r.1.as_deref().and_then(AllocationMode::parse),
+        priorities,
+        updated_by: r.3,
+        updated_at: r.4,
+    }
+}
+
+/// Set a phase's priority globally, or in one region.
+pub async fn set_priority(
+    pool: &PgPool,
+    phase: SynthesisPhase,
+    region: Option<&str>,
+    priority: u32,
+    updated_by: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO synthesis_budget_policies (region, priorities, updated_by)
+        VALUES ($1, jsonb_build_object($2::text, $3::int), $4)
+        ON CONFLICT ((COALESCE(region, '')))
+        DO UPDATE SET priorities = synthesis_budget_policies.priorities || EXCLUDED.priorities,
+                      updated_by = EXCLUDED.updated_by, updated_at = now()
+        "#,
+    )
+    .bind(region)
+    .bind(phase.as_str())
+    .bind(priority as i32)
+    .bind(updated_by)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Set the allocation mode globally, or in one region.
+pub async fn set_mode(
+    pool: &PgPool,
+    mode: AllocationMode,
+    region: Option<&str>,
+    updated_by: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO synthesis_budget_policies (region, mode, updated_by)
+        VALUES ($1, $2, $3)
+        ON CONFLICT ((COALESCE(region, '')))
+        DO UPDATE SET mode = EXCLUDED.mode, updated_by = EXCLUDED.updated_by, updated_at = now()
+        "#,
+    )
+    .bind(region)
+    .bind(mode.as_str())
+    .bind(updated_by)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Remove a policy so the next layer down applies. Returns false if there
+/// was none.
+pub async fn clear_policy(pool: &PgPool, region: Option<&str>) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM synthesis_budget_policies WHERE region IS NOT DISTINCT FROM $1")
+        .bind(region)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// The global policy, plus `region`'s when given.
+pub async fn list_overrides(pool: &PgPool, region: Option<&str>) -> Result<Vec<PolicyOverride>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, PolicyRow>(
+        "SELECT region, mode, priorities, updated_by, updated_at FROM synthesis_budget_policies
+         WHERE region IS NULL OR region = $1
+         ORDER BY region NULLS FIRST",
+    )
+    .bind(region)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(row_to_override).collect())
+}
+
+/// The policy synthesis in `region` uses. Falls back to the defaults if the
+/// policies can't be read.
+pub async fn load_or_default(pool: &PgPool, region: &str) -> SynthesisBudgetPolicy {
+    match list_overrides(pool, Some(region)).await {
+        Ok(overrides) => SynthesisBudgetPolicy::resolve(&overrides, Some(region)),
+        Err(e) => {
+            tracing::warn!(error = %e, region, "Failed to load synthesis budget policy, using defaults");
+            SynthesisBudgetPolicy::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn demands() -> Vec<PhaseDemand> {
+        SynthesisPhase::ALL
+            .into_iter()
+            .map(|phase| PhaseDemand { phase, unit_cents: 10, full_cents: 50 })
+            .collect()
+    }
+
+    fn allocation(allocs: &[PhaseAllocation], phase: SynthesisPhase) -> &PhaseAllocation {
+        allocs.iter().find(|a| a.phase == phase).unwrap()
+    }
+
+    fn policy(region: Option<&str>, mode: Option<AllocationMode>, priorities: &[(SynthesisPhase, u32)]) -> PolicyOverride {
+        PolicyOverride {
+            region: region.map(str::to_string),
+            mode,
+            priorities: priorities.iter().copied().collect(),
+            updated_by: "admin".to_string(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn rank_funds_top_priorities_in_full_and_skips_what_is_left() {
+        let policy = SynthesisBudgetPolicy::default();
+
+        let allocs = allocate(&policy, Some(75), &demands());
+
+        assert_eq!(allocs[0].phase, SynthesisPhase::TensionLinker);
+        // One unit each (50), then the remaining 25 tops up the first.
+        assert_eq!(allocation(&allocs, SynthesisPhase::TensionLinker).allocated_cents, Some(35));
+        assert_eq!(allocation(&allocs, SynthesisPhase::ResponseFinder).allocated_cents, Some(10));
+        assert_eq!(allocation(&allocs, SynthesisPhase::Investigation).outcome, PhaseOutcome::Run);
+        assert_eq!(allocation(&allocs, SynthesisPhase::Investigation).allocated_cents, Some(10));
+
+        let short = allocate(&policy, Some(25), &demands());
+        assert_eq!(allocation(&short, SynthesisPhase::GatheringFinder).outcome, PhaseOutcome::OutOfBudget);
+        assert_eq!(allocation(&short, SynthesisPhase::ResponseFinder).outcome, PhaseOutcome::Run);
+    }
+
+    #[test]
+    fn proportional_drops_phases_whose_share_is_too_small() {
+        let overrides = vec![policy(None, Some(AllocationMode::Proportional), &[])];
+        let policy = SynthesisBudgetPolicy::resolve(&overrides, None);
+
+        // Priorities 5,4,3,2,1 over 60 cents: investigation's 4 can't pay
+        // for a unit, then neither can gathering's 8 of the re-split, so
+        // the top three split 60 over 12.
+        let allocs = allocate(&policy, Some(60), &demands());
+
+        assert_eq!(allocation(&allocs, SynthesisPhase::Investigation).outcome, PhaseOutcome::OutOfBudget);
+        assert_eq!(allocation(&allocs, SynthesisPhase::TensionLinker).allocated_cents, Some(25));
+        assert_eq!(allocation(&allocs, SynthesisPhase::ResponseMapping).allocated_cents, Some(15));
+        assert_eq!(allocation(&allocs, SynthesisPhase::GatheringFinder).allocated_cents, Some(0));
+        assert_eq!(allocation(&allocs, SynthesisPhase::GatheringFinder).outcome, PhaseOutcome::OutOfBudget);
+    }
+
+    #[test]
+    fn zero_priority_disables_a_phase_even_with_unlimited_budget() {
+        let overrides = vec![policy(None, None, &[(SynthesisPhase::Investigation, 0)])];
+        let policy = SynthesisBudgetPolicy::resolve(&overrides, None);
+
+        let allocs = allocate(&policy, None, &demands());
+
+        assert_eq!(allocation(&allocs, SynthesisPhase::Investigation).outcome, PhaseOutcome::Disabled);
+        assert_eq!(allocation(&allocs, SynthesisPhase::TensionLinker).outcome, PhaseOutcome::Run);
+        assert_eq!(allocation(&allocs, SynthesisPhase::TensionLinker).units(10, 7), 7);
+    }
+
+    #[test]
+    fn region_settings_beat_global_which_beat_defaults() {
+        let overrides = vec![
+            policy(None, Some(AllocationMode::Proportional), &[(SynthesisPhase::Investigation, 9)]),
+            policy(Some("twincities"), None, &[(SynthesisPhase::Investigation, 2)]),
+        ];
+
+        let here = SynthesisBudgetPolicy::resolve(&overrides, Some("twincities"));
+        let elsewhere = SynthesisBudgetPolicy::resolve(&overrides, Some("denver"));
+
+        assert_eq!(here.mode, AllocationMode::Proportional);
+        assert_eq!(here.mode_source, SettingSource::Global);
+        assert_eq!(here.priority(SynthesisPhase::Investigation), 2);
+        assert_eq!(elsewhere.priority(SynthesisPhase::Investigation), 9);
+        assert_eq!(here.priority(SynthesisPhase::TensionLinker), SynthesisPhase::TensionLinker.default_priority());
+    }
+}
@@ -0,0 +1,196 @@
+//! Topic taxonomy: the canonical categories tensions, notices, stories and
+//! situations are filed under.
+//!
+//! LLM extraction produces free-text categories that drift ("housing",
+//! "Housing & Shelter", "affordable housing"). Each label is mapped to a
+//! canonical category — by slug, name or alias first, then by embedding
+//! similarity — and the canonical slug is what's stored and filtered on.
+//! Categories form a tree; filtering on a category includes its descendants.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::slugify;
+
+/// Cosine similarity a label's embedding needs to a category's to map to it.
+pub const EMBEDDING_MATCH_THRESHOLD: f64 = 0.82;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicCategory {
+    /// Stable key stored on tagged nodes ("housing", "eviction").
+    pub slug: String,
+    /// Display name ("Housing & Shelter").
+    pub name: String,
+    pub parent: Option<String>,
+    /// Other labels that mean this category; merged categories' names land here.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl TopicCategory {
+    pub fn new(name: &str, parent: Option<&str>, aliases: &[&str]) -> Self {
+        Self {
+            slug: slugify(name),
+            name: name.to_string(),
+            parent: parent.map(str::to_string),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// Whether a free-text label names this category, ignoring case and punctuation.
+    pub fn matches_label(&self, label: &str) -> bool {
+        let label = slugify(label);
+        !label.is_empty()
+            && (label == self.slug
+                || label == slugify(&self.name)
+                || self.aliases.iter().any(|a| slugify(a) == label))
+    }
+
+    /// Text embedded to match labels against this category.
+    pub fn embedding_text(&self) -> String {
+        if self.aliases.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}: {}", self.name, self.aliases.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Taxonomy {
+    pub categories: Vec<TopicCategory>,
+}
+
+impl Taxonomy {
+    /// Starting tree, seeded when the graph has no categories yet.
+    pub fn seed() -> Self {
+        let c = TopicCategory::new;
+        Self {
+            categories: vec![
+                c("Housing", None, &["housing & shelter", "affordable housing", "shelter"]),
+                c("Eviction", Some("housing"), &["evictions", "tenant displacement"]),
+                c("Homelessness", Some("housing"), &["unhoused", "encampments"]),
+                c("Tenant Rights", Some("housing"), &["renters rights", "landlord disputes"]),
+                c("Transit", None, &["transportation", "public transit", "transit & transportation"]),
+                c("Education", None, &["schools", "youth"]),
+                c("Public Safety", None, &["safety", "policing", "crime"]),
+                c("Health", None, &["healthcare", "public health", "mental health"]),
+                c("Food", None, &["food access", "food insecurity", "hunger"]),
+                c("Environment", None, &["climate", "pollution", "environmental justice"]),
+                c("Immigration", None, &["immigrants", "immigration enforcement"]),
+                c("Labor", None, &["workers", "employment", "jobs"]),
+                c("Civic", None, &["government", "civic engagement", "elections"]),
+                c("Economic", None, &["economy", "cost of living", "poverty"]),
+                c("Community", None, &["community building", "culture"]),
+            ],
+        }
+    }
+
+    pub fn get(&self, slug: &str) -> Option<&TopicCategory> {
+        self.categories.iter().find(|c| c.slug == slug)
+    }
+
+    /// The category a label names exactly (slug, name or alias).
+    pub fn resolve(&self, label: &str) -> Option<&TopicCategory> {
+        self.categories.iter().find(|c| c.matches_label(label))
+    }
+
+    /// Slugs a filter on `label` covers: the category and all its
+    /// descendants. An unknown label filters on itself, so untagged legacy
+    /// values stay reachable.
+    pub fn expand(&self, label: &str) -> Vec<String> {
+        let Some(root) = self.resolve(label) else {
+            return vec![label.to_string()];
+        };
+        let mut slugs = vec![root.slug.clone()];
+        let mut i = 0;
+        while i < slugs.len() {
+            let children: Vec<String> = self
+                .categories
+                .iter()
+                .filter(|c| c.parent.as_deref() == Some(slugs[i].as_str()) && !slugs.contains(&c.slug))
+                .map(|c| c.slug.clone())
+                .collect();
+            slugs.extend(children);
+            i += 1;
+        }
+        slugs
+    }
+
+    /// Ancestors first, then the category ("Housing › Eviction").
+    pub fn path(&self, slug: &str) -> Vec<&TopicCategory> {
+        let mut path = Vec::new();
+        let mut next = self.get(slug);
+        while let Some(category) = next {
+            if path.iter().any(|c: &&TopicCategory| c.slug == category.slug) {
+                break;
+            }
+            path.push(category);
+            next = category.parent.as_deref().and_then(|p| self.get(p));
+        }
+        path.reverse();
+        path
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let mut slugs = HashSet::new();
+        for category in &self.categories {
+            if category.slug.is_empty() || category.slug != slugify(&category.slug) {
+                return Err(format!("Invalid category slug: {:?}", category.slug));
+            }
+            if !slugs.insert(category.slug.as_str()) {
+                return Err(format!("Duplicate category: {}", category.slug));
+            }
+        }
+        for category in &self.categories {
+            if let Some(parent) = &category.parent {
+                if !slugs.contains(parent.as_str()) {
+                    return Err(format!("Category {} has unknown parent {parent}", category.slug));
+                }
+            }
+            if self.path(&category.slug).first().and_then(|c| c.parent.as_ref()).is_some() {
+                return Err(format!("Category {} is its own ancestor", category.slug));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drifting_labels_resolve_to_one_category() {
+        let taxonomy = Taxonomy::seed();
+        for label in ["housing", "Housing & Shelter", "HOUSING", "affordable housing"] {
+            assert_eq!(taxonomy.resolve(label).map(|c| c.slug.as_str()), Some("housing"), "{label}");
+        }
+        assert!(taxonomy.resolve("zoning variance").is_none());
+    }
+
+    #[test]
+    fn filters_include_descendants_and_unknown_labels_filter_on_themselves() {
+        let taxonomy = Taxonomy::seed();
+        let housing = taxonomy.expand("Housing & Shelter");
+        assert_eq!(housing[0], "housing");
+        assert!(housing.contains(&"eviction".to_string()));
+        assert!(!housing.contains(&"transit".to_string()));
+        assert_eq!(taxonomy.expand("eviction"), vec!["eviction"]);
+        assert_eq!(taxonomy.expand("zoning"), vec!["zoning"]);
+    }
+
+    #[test]
+    fn validate_rejects_unknown_parents_and_cycles() {
+        assert!(Taxonomy::seed().validate().is_ok());
+
+        let mut orphan = Taxonomy::seed();
+        orphan.categories.push(TopicCategory::new("Rent Control", Some("rent"), &[]));
+        assert!(orphan.validate().is_err());
+
+        let mut cycle = Taxonomy::seed();
+        cycle.categories[0].parent = Some("eviction".to_string());
+        assert!(cycle.validate().is_err());
+    }
+}
@@ -0,0 +1,328 @@
+//! Tenants: independent deployments sharing one set of infrastructure.
+//!
+//! Each tenant owns a Neo4j database, so every graph read and write made
+//! through that tenant's `GraphClient` stays inside its partition without any
+//! query having to filter for it. Requests are matched to a tenant by
+//! hostname or by an `X-Api-Key` header; keys are stored as SHA-256 hex
+//! digests, never in the clear.
+//!
+//! Tenants are configured in a JSON file named by `TENANTS_FILE`:
+//!
+//! ```json
+//! [{ "slug": "northside", "name": "Northside Signal", "database": "northside",
+//!    "hostnames": ["northside.example.org"], "api_key_hashes": ["9f86d0..."],
+//!    "region": "minneapolis", "daily_budget_cents": 500 }]
+//! ```
+//!
+//! Budget and region settings override the base config for that tenant only.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::Config;
+
+/// The database the primary (non-tenant) deployment lives in.
+pub const DEFAULT_DATABASE: &str = "neo4j";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tenant {
+    pub slug: String,
+    pub name: String,
+    /// Neo4j database holding all of this tenant's graph data.
+    pub database: String,
+    #[serde(default)]
+    pub hostnames: Vec<String>,
+    /// SHA-256 hex digests of the tenant's API keys.
+    #[serde(default)]
+    pub api_key_hashes: Vec<String>,
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Daily scout budget in cents; unset = the base config's budget.
+    #[serde(default)]
+    pub daily_budget_cents: Option<u64>,
+    #[serde(default)]
+    pub max_web_queries_per_run: Option<usize>,
+    #[serde(default)]
+    pub search_daily_limits: HashMap<String, u32>,
+    /// Languages public summaries are translated into; empty = the base config's.
+    #[serde(default)]
+    pub summary_languages: Vec<String>,
+    /// Phone numbers allowed to sign in as this tenant's admins. The base
+    /// config's admins are not admins of any tenant.
+    #[serde(default)]
+    pub admin_numbers: Vec<String>,
+}
+
+impl Tenant {
+    /// The base config with this tenant's overrides applied.
+    pub fn config(&self, base: &Config) -> Config {
+        let mut config = base.clone();
+        if let Some(region) = &self.region {
+            config.region = region.clone();
+        }
+        if let Some(cents) = self.daily_budget_cents {
+            config.daily_budget_cents = cents;
+        }
+        if let Some(max) = self.max_web_queries_per_run {
+            config.max_web_queries_per_run = max;
+        }
+        if !self.search_daily_limits.is_empty() {
+            config.search_daily_limits = self.search_daily_limits.clone();
+        }
+        if !self.summary_languages.is_empty() {
+            config.summary_languages = self.summary_languages.clone();
+        }
+        config.admin_numbers = self.admin_numbers.clone();
+        // All of a tenant's regions live in its own database
+        config.region_databases.clear();
+        config
+    }
+
+    /// JWT issuer for this tenant, so a session from one tenant is rejected by every other.
+    pub fn token_issuer(&self) -> String {
+        format!("rootsignal:{}", self.slug)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TenantError {
+    #[error("Unknown API key")]
+    UnknownApiKey,
+
+    #[error("API key belongs to another tenant than host {0}")]
+    HostMismatch(String),
+
+    #[error("Invalid tenant config: {0}")]
+    Invalid(String),
+}
+
+/// Every configured tenant, indexed by hostname and API key digest, plus
+/// the primary deployment's own API key digests.
+#[derive(Debug, Clone, Default)]
+pub struct TenantRegistry {
+    tenants: Vec<Tenant>,
+    by_host: HashMap<String, usize>,
+    by_key: HashMap<String, usize>,
+    primary_keys: HashSet<String>,
+}
+
+impl TenantRegistry {
+    pub fn new(tenants: Vec<Tenant>) -> Result<Self, TenantError> {
+        let mut by_host = HashMap::new();
+        let mut by_key = HashMap::new();
+        let mut slugs = HashSet::new();
+        let mut databases = HashSet::new();
+        for (i, tenant) in tenants.iter().enumerate() {
+            validate_database_name(&tenant.database)?;
+            if !slugs.insert(tenant.slug.as_str()) {
+                return Err(TenantError::Invalid(format!("duplicate tenant slug {}", tenant.slug)));
+            }
+            if !databases.insert(tenant.database.as_str()) {
+                return Err(TenantError::Invalid(format!("database {} is shared by two tenants", tenant.database)));
+            }
+            for host in &tenant.hostnames {
+                if by_host.insert(normalize_host(host), i).is_some() {
+                    return Err(TenantError::Invalid(format!("hostname {host} is claimed by two tenants")));
+                }
+            }
+            for hash in &tenant.api_key_hashes {
+                if by_key.insert(hash.to_lowercase(), i).is_some() {
+                    return Err(TenantError::Invalid(format!("an API key of {} is shared", tenant.slug)));
+                }
+            }
+        }
+        Ok(Self {
+            tenants,
+            by_host,
+            by_key,
+            primary_keys: HashSet::new(),
+        })
+    }
+
+    /// Accept these API key digests (from `API_KEY_HASHES`) for the primary deployment.
+    pub fn with_primary_keys(mut self, hashes: &[String]) -> Result<Self, TenantError> {
+        for hash in hashes {
+            let hash = hash.to_lowercase();
+            if self.by_key.contains_key(&hash) {
+                return Err(TenantError::Invalid("a primary API key is also a tenant's".to_string()));
+            }
+            self.primary_keys.insert(hash);
+        }
+        Ok(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, TenantError> {
+        let tenants: Vec<Tenant> = serde_json::from_str(json).map_err(|e| TenantError::Invalid(e.to_string()))?;
+        Self::new(tenants)
+    }
+
+    /// Load tenants from `TENANTS_FILE`; `Ok(None)` when tenancy is off.
+    pub fn from_env() -> Result<Option<Self>, TenantError> {
+        let Some(path) = std::env::var("TENANTS_FILE").ok().filter(|p| !p.is_empty()) else {
+            return Ok(None);
+        };
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| TenantError::Invalid(format!("cannot read {path}: {e}")))?;
+        Self::from_json(&json).map(Some)
+    }
+
+    pub fn tenants(&self) -> &[Tenant] {
+        &self.tenants
+    }
+
+    pub fn get(&self, slug: &str) -> Option<&Tenant> {
+        self.tenants.iter().find(|t| t.slug == slug)
+    }
+
+    /// The tenant a request belongs to. An API key wins over the hostname,
+    /// but must not contradict it: a key presented at another tenant's
+    /// hostname is rejected rather than served. `Ok(None)` means the request
+    /// is for the primary deployment, by its hostname or one of its keys.
+    pub fn resolve(&self, host: Option<&str>, api_key: Option<&str>) -> Result<Option<&Tenant>, TenantError> {
+        let by_host = host.and_then(|h| self.by_host.get(&normalize_host(h)).copied());
+        let Some(key) = api_key.filter(|k| !k.is_empty()) else {
+            return Ok(by_host.map(|i| &self.tenants[i]));
+        };
+        let hash = hash_api_key(key);
+        if self.primary_keys.contains(&hash) {
+            return match by_host {
+                Some(_) => Err(TenantError::HostMismatch(host.unwrap_or_default().to_string())),
+                None => Ok(None),
+            };
+        }
+        let i = *self.by_key.get(&hash).ok_or(TenantError::UnknownApiKey)?;
+        match by_host {
+            Some(h) if h != i => Err(TenantError::HostMismatch(host.unwrap_or_default().to_string())),
+            _ => Ok(Some(&self.tenants[i])),
+        }
+    }
+}
+
+/// SHA-256 hex digest of an API key, the form stored in the tenants file.
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.trim().as_bytes()))
+}
+
+/// Lowercase and strip any port, so `Example.org:443` matches `example.org`.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim().to_lowercase();
+    match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name.to_string(),
+        _ => host,
+    }
+}
+
+/// Neo4j database names: 3–63 chars of lowercase letters, digits, dots and
+/// dashes, starting with a letter. The system and primary databases are
+/// reserved.
+fn validate_database_name(name: &str) -> Result<(), TenantError> {
+    let valid = (3..=63).contains(&name.len())
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-');
+    if !valid {
+        return Err(TenantError::Invalid(format!("invalid database name {name:?}")));
+    }
+    if name == "system" || name == DEFAULT_DATABASE {
+        return Err(TenantError::Invalid(format!("database {name} is reserved")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tenant(slug: &str, host: &str, key: &str) -> Tenant {
+        Tenant {
+            slug: slug.to_string(),
+            name: slug.to_string(),
+            database: slug.to_string(),
+            hostnames: vec![host.to_string()],
+            api_key_hashes: vec![hash_api_key(key)],
+            region: None,
+            daily_budget_cents: None,
+            max_web_queries_per_run: None,
+            search_daily_limits: HashMap::new(),
+            summary_languages: Vec::new(),
+            admin_numbers: Vec::new(),
+        }
+    }
+
+    fn registry() -> TenantRegistry {
+        TenantRegistry::new(vec![
+            tenant("northside", "northside.example.org", "key-north"),
+            tenant("riverside", "riverside.example.org", "key-river"),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn hostname_resolves_its_own_tenant_ignoring_case_and_port() {
+        let r = registry();
+
+        let t = r.resolve(Some("Riverside.Example.org:443"), None).unwrap();
+
+        assert_eq!(t.map(|t| t.slug.as_str()), Some("riverside"));
+    }
+
+    #[test]
+    fn unknown_host_without_key_is_the_primary_deployment() {
+        assert_eq!(registry().resolve(Some("rootsignal.app"), None), Ok(None));
+        assert_eq!(registry().resolve(None, None), Ok(None));
+    }
+
+    #[test]
+    fn api_key_at_another_tenants_host_is_rejected() {
+        let r = registry();
+
+        let result = r.resolve(Some("riverside.example.org"), Some("key-north"));
+
+        assert_eq!(result, Err(TenantError::HostMismatch("riverside.example.org".to_string())));
+    }
+
+    #[test]
+    fn unknown_api_key_never_falls_back_to_a_tenant() {
+        let r = registry();
+
+        assert_eq!(r.resolve(Some("northside.example.org"), Some("guess")), Err(TenantError::UnknownApiKey));
+        assert_eq!(r.resolve(None, Some("guess")), Err(TenantError::UnknownApiKey));
+    }
+
+    #[test]
+    fn api_key_alone_resolves_its_tenant() {
+        let r = registry();
+
+        let t = r.resolve(Some("api.example.org"), Some("key-river")).unwrap();
+
+        assert_eq!(t.map(|t| t.slug.as_str()), Some("riverside"));
+    }
+
+    #[test]
+    fn primary_keys_resolve_the_primary_deployment_but_not_at_a_tenants_host() {
+        let r = registry().with_primary_keys(&[hash_api_key("key-main")]).unwrap();
+
+        assert_eq!(r.resolve(Some("rootsignal.app"), Some("key-main")), Ok(None));
+        assert!(matches!(
+            r.resolve(Some("northside.example.org"), Some("key-main")),
+            Err(TenantError::HostMismatch(_))
+        ));
+        assert!(registry().with_primary_keys(&[hash_api_key("key-north")]).is_err());
+    }
+
+    #[test]
+    fn shared_hosts_keys_or_databases_are_rejected() {
+        let mut dup_db = tenant("riverside", "riverside.example.org", "key-river");
+        dup_db.database = "northside".to_string();
+        let mut reserved = tenant("main", "main.example.org", "key-main");
+        reserved.database = DEFAULT_DATABASE.to_string();
+
+        let north = || tenant("northside", "northside.example.org", "key-north");
+        assert!(TenantRegistry::new(vec![north(), tenant("other", "northside.example.org", "key-other")]).is_err());
+        assert!(TenantRegistry::new(vec![north(), tenant("other", "other.example.org", "key-north")]).is_err());
+        assert!(TenantRegistry::new(vec![north(), dup_db]).is_err());
+        assert!(TenantRegistry::new(vec![reserved]).is_err());
+    }
+}
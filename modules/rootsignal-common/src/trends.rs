@@ -0,0 +1,180 @@
+//! Trends: categories of signals rising or falling in a neighborhood.
+//!
+//! Signals are counted per category and neighborhood by week. A window of
+//! recent weeks is compared with the weeks before it: if the baseline's
+//! weekly rate held, the window's count would be Poisson-distributed around
+//! `baseline mean × window weeks`. Counts far enough from that (by z-score)
+//! and large enough to matter become a `Trend` — "housing tensions in
+//! Phillips doubled this month".
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Weeks of history a window is compared against.
+pub const BASELINE_WEEKS: usize = 8;
+
+/// |z| at or above this is a trend (two-sided p ≈ 0.01).
+pub const Z_THRESHOLD: f64 = 2.58;
+
+/// Neither the window's count nor the expected count reaching this is noise.
+pub const MIN_VOLUME: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendWindow {
+    /// The last full week.
+    Week,
+    /// The last four full weeks.
+    Month,
+}
+
+impl TrendWindow {
+    pub const ALL: [TrendWindow; 2] = [TrendWindow::Week, TrendWindow::Month];
+
+    pub fn weeks(&self) -> usize {
+        match self {
+            TrendWindow::Week => 1,
+            TrendWindow::Month => 4,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendWindow::Week => "week",
+            TrendWindow::Month => "month",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendDirection {
+    Rising,
+    Falling,
+}
+
+impl TrendDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendDirection::Rising => "rising",
+            TrendDirection::Falling => "falling",
+        }
+    }
+}
+
+/// Outcome of comparing a window's count with its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendTest {
+    pub direction: TrendDirection,
+    pub observed: u32,
+    pub expected: f64,
+    pub z_score: f64,
+}
+
+/// Test whether `recent` weekly counts depart from the `baseline` weeks'
+/// rate. `None` when the change isn't significant or the volume is too low.
+pub fn detect_trend(baseline: &[u32], recent: &[u32]) -> Option<TrendTest> {
+    if baseline.is_empty() || recent.is_empty() {
+        return None;
+    }
+    let rate = baseline.iter().sum::<u32>() as f64 / baseline.len() as f64;
+    let expected = rate * recent.len() as f64;
+    let observed = recent.iter().sum::<u32>();
+    if (observed as f64).max(expected) < MIN_VOLUME {
+        return None;
+    }
+    // A quiet baseline still has some variance; never divide by less than one.
+    let z_score = (observed as f64 - expected) / expected.max(1.0).sqrt();
+    if z_score.abs() < Z_THRESHOLD {
+        return None;
+    }
+    Some(TrendTest {
+        direction: if z_score > 0.0 { TrendDirection::Rising } else { TrendDirection::Falling },
+        observed,
+        expected,
+        z_score,
+    })
+}
+
+/// A significant change in a category of signals in one neighborhood (or
+/// the whole region when `neighborhood` is `None`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trend {
+    pub id: String,
+    pub region: String,
+    /// Signal category ("housing") or, for uncategorized signals, their type ("need").
+    pub category: String,
+    pub neighborhood: Option<String>,
+    pub window: TrendWindow,
+    pub direction: TrendDirection,
+    pub observed: u32,
+    pub expected: f64,
+    pub z_score: f64,
+    /// Baseline then window weeks, oldest first.
+    pub weekly_counts: Vec<u32>,
+    /// First day of the window.
+    pub window_start: NaiveDate,
+    pub detected_at: DateTime<Utc>,
+    /// Signals from the window that make up the change.
+    pub signal_ids: Vec<Uuid>,
+}
+
+impl Trend {
+    /// Stable id, so a trend that persists across runs is updated in place.
+    pub fn make_id(region: &str, window: TrendWindow, category: &str, neighborhood: Option<&str>) -> String {
+        format!(
+            "{region}/{}/{}/{}",
+            window.as_str(),
+            crate::slugify(category),
+            neighborhood.map(crate::slugify).unwrap_or_else(|| "all".to_string())
+        )
+    }
+
+    /// Window count over expected ("2.1" is more than double).
+    pub fn change_ratio(&self) -> Option<f64> {
+        (self.expected > 0.0).then(|| self.observed as f64 / self.expected)
+    }
+
+    /// "housing tensions in Phillips: 14 this month, 6 expected".
+    pub fn headline(&self) -> String {
+        let place = self.neighborhood.as_deref().map(|n| format!(" in {n}")).unwrap_or_default();
+        let period = match self.window {
+            TrendWindow::Week => "this week",
+            TrendWindow::Month => "this month",
+        };
+        format!(
+            "{} {}{place}: {} {period}, {:.0} expected",
+            self.category,
+            self.direction.as_str(),
+            self.observed,
+            self.expected
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doubling_month_is_a_rising_trend() {
+        let baseline = [3, 4, 3, 2, 3, 4, 3, 2];
+        let test = detect_trend(&baseline, &[6, 7, 5, 8]).unwrap();
+        assert_eq!(test.direction, TrendDirection::Rising);
+        assert_eq!(test.observed, 26);
+        assert_eq!(test.expected, 12.0);
+    }
+
+    #[test]
+    fn ordinary_variation_and_tiny_counts_are_not_trends() {
+        assert!(detect_trend(&[3, 4, 3, 2, 3, 4, 3, 2], &[4, 3, 2, 4]).is_none());
+        assert!(detect_trend(&[0, 0, 1, 0, 0, 0, 0, 0], &[3]).is_none(), "3 signals is too few");
+    }
+
+    #[test]
+    fn collapse_after_steady_volume_is_falling() {
+        let test = detect_trend(&[10, 12, 9, 11, 10, 10, 12, 10], &[1, 2, 0, 1]).unwrap();
+        assert_eq!(test.direction, TrendDirection::Falling);
+    }
+}
@@ -0,0 +1,399 @@
+//! Polling triggers for no-code automation tools (Zapier, IFTTT, Make).
+//!
+//! A partner's tool polls `GET /api/triggers/new-signals` with an API key
+//! and optional filters. Each poll returns signals not yet returned for
+//! that key and filter, newest first, each with a stable `id` the tool can
+//! dedupe on. The server keeps a checkpoint per key and filter: a cursor
+//! on `extracted_at` plus the ids seen in the last `CHECKPOINT_OVERLAP_MINUTES`,
+//! so a signal written to the graph a little after it was extracted is
+//! still delivered exactly once.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::room_feeds::{severity_rank, signal_severity};
+use crate::visibility::{redact_node, Visibility};
+use crate::watchlist::BoundingBox;
+use crate::{GeoPoint, GeoPrecision, Node, NodeMeta, NodeType, NoticeNode, SensitivityLevel, Severity};
+
+/// Items per poll unless the caller asks for fewer.
+pub const DEFAULT_LIMIT: usize = 25;
+pub const MAX_LIMIT: usize = 100;
+/// A key's first poll reaches this far back.
+pub const FIRST_POLL_LOOKBACK_HOURS: i64 = 24;
+/// Signals are written to the graph some time after they are extracted, so
+/// each poll looks back this far behind the cursor, skipping ids it has seen.
+pub const CHECKPOINT_OVERLAP_MINUTES: i64 = 60;
+/// Checkpoints not polled for this long are deleted.
+pub const CHECKPOINT_TTL_DAYS: i64 = 30;
+
+/// What a trigger returns, from its query parameters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriggerFilter {
+    pub bbox: Option<BoundingBox>,
+    pub types: Vec<NodeType>,
+    /// Topic categories; each includes its subcategories.
+    pub categories: Vec<String>,
+    /// Any of these in a signal's title or summary is a match.
+    pub keywords: Vec<String>,
+    pub min_severity: Option<Severity>,
+}
+
+fn parse_node_type(value: &str) -> Option<NodeType> {
+    match value {
+        "gathering" => Some(NodeType::Gathering),
+        "aid" => Some(NodeType::Aid),
+        "need" => Some(NodeType::Need),
+        "notice" => Some(NodeType::Notice),
+        "tension" => Some(NodeType::Tension),
+        _ => None,
+    }
+}
+
+fn parse_severity(value: &str) -> Option<Severity> {
+    match value {
+        "low" => Some(Severity::Low),
+        "medium" => Some(Severity::Medium),
+        "high" => Some(Severity::High),
+        "critical" => Some(Severity::Critical),
+        _ => None,
+    }
+}
+
+/// Comma-separated values, trimmed and lowercased, empties dropped, sorted
+/// so the same filter always fingerprints the same.
+fn list_param(params: &HashMap<String, String>, name: &str) -> Vec<String> {
+    let mut values: Vec<String> = params
+        .get(name)
+        .map(|v| v.split(',').map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    values.sort();
+    values.dedup();
+    values
+}
+
+impl TriggerFilter {
+    /// Parse `types`, `categories`, `keywords` (comma-separated),
+    /// `min_severity` and `min_lat`/`max_lat`/`min_lng`/`max_lng`.
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let coord = |name: &str| -> Result<Option<f64>, String> {
+            match params.get(name).map(|v| v.trim()).filter(|v| !v.is_empty()) {
+                Some(v) => v.parse().map(Some).map_err(|_| format!("{name} must be a number")),
+                None => Ok(None),
+            }
+        };
+        let bbox = match (coord("min_lat")?, coord("max_lat")?, coord("min_lng")?, coord("max_lng")?) {
+            (Some(min_lat), Some(max_lat), Some(min_lng), Some(max_lng)) => {
+                if !(min_lat < max_lat && min_lng < max_lng) {
+                    return Err("Bounding box min must be below max".to_string());
+                }
+                Some(BoundingBox { min_lat, max_lat, min_lng, max_lng })
+            }
+            (None, None, None, None) => None,
+            _ => return Err("Pass all of min_lat, max_lat, min_lng and max_lng, or none".to_string()),
+        };
+        let types = list_param(params, "types")
+            .iter()
+            .map(|t| parse_node_type(t).ok_or_else(|| format!("Unknown type {t} (gathering, aid, need, notice, tension)")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let min_severity = match params.get("min_severity").map(|v| v.trim().to_lowercase()).filter(|v| !v.is_empty()) {
+            Some(v) => Some(parse_severity(&v).ok_or_else(|| format!("Unknown severity {v} (low, medium, high, critical)"))?),
+            None => None,
+        };
+        Ok(Self {
+            bbox,
+            types,
+            categories: list_param(params, "categories"),
+            keywords: list_param(params, "keywords"),
+            min_severity,
+        })
+    }
+
+    /// Stable digest of the filter, so each distinct filter polled with a
+    /// key keeps a checkpoint of its own.
+    pub fn fingerprint(&self) -> String {
+        let bbox = self
+            .bbox
+            .map(|b| format!("{},{},{},{}", b.min_lat, b.max_lat, b.min_lng, b.max_lng))
+            .unwrap_or_default();
+        let mut types: Vec<String> = self.types.iter().map(|t| t.to_string()).collect();
+        types.sort();
+        let severity = self.min_severity.map(|s| format!("{s:?}")).unwrap_or_default();
+        let key = format!(
+            "{bbox}|{}|{}|{}|{severity}",
+            types.join(","),
+            self.categories.join(","),
+            self.keywords.join(",")
+        );
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    /// Whether `node` matches. `categories` is the filter's categories
+    /// expanded to canonical slugs (with subcategories). Sensitive signals
+    /// never match.
+    pub fn matches(&self, node: &Node, categories: &[String]) -> bool {
+        let Some(meta) = node.meta() else {
+            return false;
+        };
+        if meta.sensitivity == SensitivityLevel::Sensitive {
+            return false;
+        }
+        if let Some(bbox) = &self.bbox {
+            match meta.about_location {
+                Some(loc) if bbox.contains(loc.lat, loc.lng) => {}
+                _ => return false,
+            }
+        }
+        if !self.types.is_empty() && !self.types.contains(&node.node_type()) {
+            return false;
+        }
+        if !categories.is_empty() && !node.category().is_some_and(|c| categories.iter().any(|k| k == c)) {
+            return false;
+        }
+        if !self.keywords.is_empty() {
+            let text = format!("{} {}", meta.title, meta.summary).to_lowercase();
+            if !self.keywords.iter().any(|k| text.contains(k.as_str())) {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_severity {
+            match signal_severity(node) {
+                Some(s) if severity_rank(s) >= severity_rank(min) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Checkpoint id for an API key digest and a filter fingerprint (or a
+/// caller-chosen checkpoint name, for two automations sharing a filter).
+pub fn checkpoint_id(key_hash: &str, name: &str) -> String {
+    hex::encode(Sha256::digest(format!("{key_hash}/{name}").as_bytes()))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeenSignal {
+    pub id: Uuid,
+    pub extracted_at: DateTime<Utc>,
+}
+
+/// How far a key's polls have read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggerCheckpoint {
+    /// Latest `extracted_at` read.
+    pub cursor: DateTime<Utc>,
+    /// Signals read within the overlap window behind the cursor.
+    #[serde(default)]
+    pub seen: Vec<SeenSignal>,
+}
+
+impl TriggerCheckpoint {
+    pub fn first_poll(now: DateTime<Utc>) -> Self {
+        Self {
+            cursor: now - Duration::hours(FIRST_POLL_LOOKBACK_HOURS),
+            seen: Vec::new(),
+        }
+    }
+
+    /// Signals extracted after this are candidates for the next poll.
+    pub fn scan_from(&self) -> DateTime<Utc> {
+        self.cursor - Duration::minutes(CHECKPOINT_OVERLAP_MINUTES)
+    }
+
+    pub fn seen_ids(&self) -> Vec<Uuid> {
+        self.seen.iter().map(|s| s.id).collect()
+    }
+
+    /// Take up to `limit` matching signals from `scanned` (signals extracted
+    /// after `scan_from`, not yet seen, oldest first) and advance past every
+    /// signal read, matching or not. Returns the matches newest first.
+    pub fn take<'a>(
+        &mut self,
+        scanned: &'a [Node],
+        filter: &TriggerFilter,
+        categories: &[String],
+        limit: usize,
+    ) -> Vec<&'a Node> {
+        let mut taken = Vec::new();
+        for node in scanned {
+            let Some(meta) = node.meta() else {
+                continue;
+            };
+            if self.seen.iter().any(|s| s.id == meta.id) {
+                continue;
+            }
+            if filter.matches(node, categories) {
+                if taken.len() == limit {
+                    break;
+                }
+                taken.push(node);
+            }
+            self.cursor = self.cursor.max(meta.extracted_at);
+            self.seen.push(SeenSignal { id: meta.id, extracted_at: meta.extracted_at });
+        }
+        let window_start = self.scan_from();
+        self.seen.retain(|s| s.extracted_at > window_start);
+        taken.reverse();
+        taken
+    }
+}
+
+/// One signal as a trigger item: flat fields, public tier only.
+#[derive(Debug, Clone, Serialize)]
+pub struct TriggerItem {
+    /// Dedupe key; stable for the life of the signal.
+    pub id: String,
+    #[serde(rename = "type")]
+    pub signal_type: String,
+    pub title: String,
+    pub summary: String,
+    pub category: Option<String>,
+    pub severity: Option<String>,
+    pub location_name: Option<String>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub url: Option<String>,
+    pub source_url: String,
+    pub extracted_at: DateTime<Utc>,
+}
+
+impl TriggerItem {
+    /// `site_url` is the public site's base URL, for a link to the signal.
+    pub fn from_node(node: &Node, site_url: Option<&str>) -> Option<Self> {
+        let severity = signal_severity(node).map(|s| format!("{s:?}").to_lowercase());
+        let node = redact_node(node.clone(), Visibility::Public);
+        let meta = node.meta()?;
+        Some(Self {
+            id: meta.id.to_string(),
+            signal_type: node.node_type().to_string().to_lowercase(),
+            title: meta.title.clone(),
+            summary: meta.summary.clone(),
+            category: node.category().map(str::to_string),
+            severity,
+            location_name: meta.about_location_name.clone(),
+            lat: meta.about_location.map(|l| l.lat),
+            lng: meta.about_location.map(|l| l.lng),
+            url: site_url.map(|base| format!("{base}/signals/{}", meta.id)),
+            source_url: meta.source_url.clone(),
+            extracted_at: meta.extracted_at,
+        })
+    }
+}
+
+/// A representative item for tools that need a sample before any real
+/// signal matches.
+pub fn sample_item(site_url: Option<&str>) -> TriggerItem {
+    TriggerItem::from_node(&sample_notice(), site_url).expect("sample notice has meta")
+}
+
+fn sample_notice() -> Node {
+    Node::Notice(NoticeNode {
+        meta: NodeMeta {
+            id: Uuid::nil(),
+            title: "Water main repair closes Bloomington Ave".to_string(),
+            summary: "Crews are repairing a water main; water service on the block is off until evening.".to_string(),
+            sensitivity: SensitivityLevel::General,
+            confidence: 0.9,
+            freshness_score: 1.0,
+            corroboration_count: 1,
+            about_location: Some(GeoPoint { lat: 44.9483, lng: -93.2527, precision: GeoPrecision::Approximate }),
+            about_location_name: Some("Bloomington Ave & Lake St".to_string()),
+            from_location: None,
+            source_url: "https://example.org/notices/water-main".to_string(),
+            extracted_at: DateTime::from_timestamp(1_767_225_600, 0).unwrap_or_default(),
+            content_date: None,
+            last_confirmed_active: DateTime::from_timestamp(1_767_225_600, 0).unwrap_or_default(),
+            source_diversity: 1,
+            external_ratio: 0.0,
+            cause_heat: 0.0,
+            channel_diversity: 1,
+            mentioned_actors: vec![],
+            author_actor: None,
+            implied_queries: vec![],
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
+        },
+        severity: Severity::Medium,
+        category: Some("utilities".to_string()),
+        effective_date: None,
+        source_authority: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(title: &str, severity: Severity, extracted_at: DateTime<Utc>) -> Node {
+        let Node::Notice(mut notice) = sample_notice() else {
+            unreachable!()
+        };
+        notice.meta.id = Uuid::new_v4();
+        notice.meta.title = title.to_string();
+        notice.meta.extracted_at = extracted_at;
+        notice.severity = severity;
+        Node::Notice(notice)
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn same_filter_in_any_order_has_the_same_fingerprint() {
+        let a = TriggerFilter::from_params(&params(&[("types", "need,Notice"), ("keywords", "water, rent")])).unwrap();
+        let b = TriggerFilter::from_params(&params(&[("keywords", "rent,water"), ("types", "notice, need")])).unwrap();
+        let c = TriggerFilter::from_params(&params(&[("types", "need")])).unwrap();
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), c.fingerprint());
+    }
+
+    #[test]
+    fn bad_params_are_explained() {
+        let partial_bbox = TriggerFilter::from_params(&params(&[("min_lat", "44.9")]));
+        let bad_type = TriggerFilter::from_params(&params(&[("types", "evidence")]));
+
+        assert!(partial_bbox.unwrap_err().contains("min_lat, max_lat"));
+        assert!(bad_type.unwrap_err().starts_with("Unknown type evidence"));
+    }
+
+    #[test]
+    fn polls_advance_past_non_matches_and_never_repeat_a_signal() {
+        let now = Utc::now();
+        let filter = TriggerFilter { min_severity: Some(Severity::High), ..Default::default() };
+        let low = notice("Hydrant flushing", Severity::Low, now - Duration::minutes(30));
+        let high = notice("Water shutoff", Severity::High, now - Duration::minutes(20));
+        let critical = notice("Boil water advisory", Severity::Critical, now - Duration::minutes(10));
+        let mut checkpoint = TriggerCheckpoint::first_poll(now);
+
+        let first: Vec<Uuid> = checkpoint.take(&[low.clone(), critical.clone()], &filter, &[], 10).iter().map(|n| n.id()).collect();
+        // `high` was written late, after the cursor moved past its extraction time.
+        let second: Vec<Uuid> = checkpoint.take(&[low, high.clone(), critical.clone()], &filter, &[], 10).iter().map(|n| n.id()).collect();
+
+        assert_eq!(first, vec![critical.id()]);
+        assert_eq!(second, vec![high.id()]);
+        assert_eq!(checkpoint.cursor, now - Duration::minutes(10));
+    }
+
+    #[test]
+    fn a_full_page_stops_before_the_next_match_so_it_comes_next_time() {
+        let now = Utc::now();
+        let older = notice("Older", Severity::High, now - Duration::minutes(20));
+        let newer = notice("Newer", Severity::High, now - Duration::minutes(10));
+        let mut checkpoint = TriggerCheckpoint::first_poll(now);
+
+        let page = |checkpoint: &mut TriggerCheckpoint| -> Vec<Uuid> {
+            checkpoint.take(&[older.clone(), newer.clone()], &TriggerFilter::default(), &[], 1).iter().map(|n| n.id()).collect()
+        };
+
+        assert_eq!(page(&mut checkpoint), vec![older.id()]);
+        assert_eq!(page(&mut checkpoint), vec![newer.id()]);
+    }
+}
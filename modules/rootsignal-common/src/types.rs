@@ -4,7 +4,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::registry::EntityGeometry;
 use crate::safety::SensitivityLevel;
+use crate::visibility::FieldVisibility;
 
 // --- Geo Types ---
 
@@ -269,6 +271,15 @@ pub struct NodeMeta {
     /// The actor that authored/published this signal's source content.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author_actor: Option<String>,
+    /// Fields shown to a narrower audience than the signal itself.
+    #[serde(default, skip_serializing_if = "FieldVisibility::is_empty")]
+    pub field_visibility: FieldVisibility,
+    /// Map-marker alt text, generated at write time.
+    #[serde(default)]
+    pub alt_text: Option<String>,
+    /// The summary at a reading level of grade 8 or below, when one was written.
+    #[serde(default)]
+    pub plain_summary: Option<String>,
 }
 
 // --- Signal Node Types ---
@@ -298,6 +309,44 @@ pub struct NeedNode {
     pub what_needed: Option<String>,
     pub action_url: Option<String>,
     pub goal: Option<String>,
+    /// Live progress when the Need is backed by a crowdfunding campaign.
+    #[serde(default)]
+    pub fundraiser: Option<Fundraiser>,
+}
+
+impl NeedNode {
+    /// The crowdfunding page behind this Need: its action URL, or the page it
+    /// was extracted from.
+    pub fn fundraiser_url(&self) -> Option<&str> {
+        self.action_url
+            .as_deref()
+            .filter(|u| is_fundraiser_url(u))
+            .or_else(|| Some(self.meta.source_url.as_str()).filter(|u| is_fundraiser_url(u)))
+    }
+}
+
+/// A crowdfunding campaign's progress, read from the fundraiser page itself
+/// rather than extracted by the LLM. Refreshed on a cadence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fundraiser {
+    pub url: String,
+    /// e.g. "gofundme", "givebutter".
+    pub platform: String,
+    pub goal: Option<f64>,
+    pub raised: f64,
+    /// ISO 4217 code, e.g. "USD".
+    pub currency: String,
+    pub donor_count: Option<u32>,
+    pub organizer: Option<String>,
+    pub beneficiary: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl Fundraiser {
+    /// Share of the goal raised so far, as a percentage. `None` without a goal.
+    pub fn percent_funded(&self) -> Option<f64> {
+        self.goal.filter(|g| *g > 0.0).map(|g| self.raised / g * 100.0)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -328,6 +377,17 @@ pub struct EvidenceNode {
     pub evidence_confidence: Option<f32>,
     #[serde(default)]
     pub channel_type: Option<ChannelType>,
+    /// What changed on the page since it was last extracted, when this
+    /// evidence came from a diff-aware re-extraction.
+    #[serde(default)]
+    pub change_summary: Option<String>,
+    /// Weighted interactions per hour of the social post this evidence came
+    /// from, when it was fetched.
+    #[serde(default)]
+    pub engagement_velocity: Option<f64>,
+    /// The latest re-check of the source page; None until first re-checked.
+    #[serde(default)]
+    pub verification: Option<crate::EvidenceVerification>,
 }
 
 // --- Sum type ---
@@ -398,6 +458,16 @@ impl Node {
             Node::Evidence(n) => &n.source_url,
         }
     }
+
+    /// Topic category, for the signal types that carry one.
+    pub fn category(&self) -> Option<&str> {
+        match self {
+            Node::Notice(n) => n.category.as_deref(),
+            Node::Tension(n) => n.category.as_deref(),
+            _ => None,
+        }
+        .filter(|c| !c.is_empty())
+    }
 }
 
 // --- Story Node ---
@@ -438,6 +508,9 @@ pub struct StoryNode {
     pub gap_score: i32,
     pub gap_velocity: f64,
     pub channel_diversity: u32,
+    /// Breakdown behind `energy`, once it has been computed by the energy module.
+    #[serde(default)]
+    pub energy_components: Option<crate::story_energy::EnergyComponents>,
 }
 
 /// A snapshot of a story's signal and entity counts at a point in time, used for velocity tracking.
@@ -457,17 +530,42 @@ pub struct ClusterSnapshot {
 
 /// The geographic context passed through the scout pipeline.
 /// Defines where scout looks — center point, radius, and search terms.
+/// Irregular regions (a county, a metro split by a river) also carry a
+/// boundary; circle-defined scopes have none.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutScope {
     pub center_lat: f64,
     pub center_lng: f64,
     pub radius_km: f64,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boundary: Option<EntityGeometry>,
 }
 
 impl ScoutScope {
-    /// Compute bounding box from center + radius.
+    /// A scope bounded by a polygon, centered on its bounding box with a
+    /// radius that covers it, for the steps that only understand circles.
+    pub fn from_boundary(name: impl Into<String>, boundary: EntityGeometry) -> Self {
+        let (min_lat, max_lat, min_lng, max_lng) = boundary.bounds().unwrap_or_default();
+        let center_lat = (min_lat + max_lat) / 2.0;
+        let center_lng = (min_lng + max_lng) / 2.0;
+        let half_height_km = (max_lat - min_lat) / 2.0 * 111.0;
+        let half_width_km = (max_lng - min_lng) / 2.0 * 111.0 * center_lat.to_radians().cos();
+        Self {
+            center_lat,
+            center_lng,
+            radius_km: half_height_km.hypot(half_width_km),
+            name: name.into(),
+            boundary: Some(boundary),
+        }
+    }
+
+    /// Compute bounding box: the boundary's when there is one, otherwise
+    /// from center + radius.
     pub fn bounding_box(&self) -> (f64, f64, f64, f64) {
+        if let Some(bounds) = self.boundary.as_ref().and_then(|b| b.bounds()) {
+            return bounds;
+        }
         let lat_delta = self.radius_km / 111.0;
         let lng_delta = self.radius_km / (111.0 * self.center_lat.to_radians().cos());
         (
@@ -477,6 +575,18 @@ impl ScoutScope {
             self.center_lng + lng_delta,
         )
     }
+
+    /// Whether a point is in scope: inside the boundary, or inside the
+    /// bounding box of a circle-defined scope (what bbox queries return).
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        match &self.boundary {
+            Some(boundary) if boundary.bounds().is_some() => boundary.contains(lat, lng),
+            _ => {
+                let (min_lat, max_lat, min_lng, max_lng) = self.bounding_box();
+                lat >= min_lat && lat <= max_lat && lng >= min_lng && lng <= max_lng
+            }
+        }
+    }
 }
 
 
@@ -571,6 +681,9 @@ pub struct ScoutTask {
     /// Workflow phase status: "idle", "running_bootstrap", "bootstrap_complete", etc.
     #[serde(default = "default_phase_status")]
     pub phase_status: String,
+    /// Region boundary, for tasks covering an irregular area.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boundary: Option<EntityGeometry>,
 }
 
 fn default_phase_status() -> String {
@@ -584,6 +697,7 @@ impl From<&ScoutTask> for ScoutScope {
             center_lng: task.center_lng,
             radius_km: task.radius_km,
             name: task.context.clone(),
+            boundary: task.boundary.clone(),
         }
     }
 }
@@ -716,9 +830,21 @@ pub struct Post {
     pub hashtags: Vec<String>,
     pub media_type: Option<String>,
     pub platform_id: Option<String>,
+    /// Weighted interactions per hour when fetched: growth since the post's
+    /// previous snapshot, or its average since publishing on first sight.
+    #[serde(default)]
+    pub engagement_velocity: Option<f64>,
     pub attachments: Vec<ArchiveFile>,
 }
 
+/// A top-level comment on a social media post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostComment {
+    pub author: Option<String>,
+    pub text: String,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
 /// An ephemeral story (Instagram stories, etc.).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Story {
@@ -760,6 +886,69 @@ pub struct LongVideo {
     pub published_at: Option<DateTime<Utc>>,
     pub permalink: Option<String>,
     pub attachments: Vec<ArchiveFile>,
+    pub title: Option<String>,
+    /// Platform video ID (e.g. the YouTube `v=` value).
+    pub platform_id: Option<String>,
+    /// Timed captions or speech-to-text. Empty when none was available.
+    #[serde(default)]
+    pub transcript: Vec<TranscriptSegment>,
+}
+
+/// A podcast episode: an RSS item with an audio enclosure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastEpisode {
+    pub id: Uuid,
+    pub source_id: Uuid,
+    pub fetched_at: DateTime<Utc>,
+    pub content_hash: String,
+    /// The item's `<guid>` (or audio URL when the feed has none).
+    pub guid: String,
+    pub title: Option<String>,
+    /// Show notes.
+    pub description: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    /// Episode page, falling back to the audio URL.
+    pub permalink: String,
+    pub audio_url: String,
+    pub duration_secs: Option<f64>,
+    /// Speech-to-text. Empty when no transcriber was configured.
+    #[serde(default)]
+    pub transcript: Vec<TranscriptSegment>,
+}
+
+/// An event from an event platform's structured listings (Eventbrite,
+/// Facebook Events). Fields come straight from the platform, not an LLM.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListedEvent {
+    pub id: Uuid,
+    pub source_id: Uuid,
+    pub fetched_at: DateTime<Utc>,
+    pub content_hash: String,
+    /// The platform's event ID.
+    pub platform_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub venue_name: Option<String>,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub is_online: bool,
+    pub organizer_name: Option<String>,
+    pub organizer_url: Option<String>,
+    /// Where to register or buy tickets, when it differs from the event page.
+    pub ticket_url: Option<String>,
+    /// The event's page on the platform.
+    pub permalink: String,
+}
+
+/// One timed line of a video or audio transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// Seconds from the start of the recording.
+    pub start_secs: f64,
+    pub text: String,
 }
 
 // --- Channels (declarative content channel selection) ---
@@ -831,6 +1020,9 @@ pub enum ArchiveItem {
     Posts(Vec<Post>),
     Stories(Vec<Story>),
     ShortVideos(Vec<ShortVideo>),
+    LongVideos(Vec<LongVideo>),
+    Episodes(Vec<PodcastEpisode>),
+    Events(Vec<ListedEvent>),
 }
 
 /// A scraped web page (v2).
@@ -844,6 +1036,9 @@ pub struct ArchivedPage {
     pub markdown: String,
     pub title: Option<String>,
     pub links: Vec<String>,
+    /// Markdown from the source's previous fetch, when it differs from this one.
+    #[serde(default)]
+    pub previous_markdown: Option<String>,
 }
 
 /// A fetched RSS/Atom feed.
@@ -866,6 +1061,9 @@ pub struct ArchivedSearchResults {
     pub content_hash: String,
     pub query: String,
     pub results: Vec<SearchResult>,
+    /// The search provider that answered (serper, tavily, brave, searxng).
+    #[serde(default)]
+    pub provider: Option<String>,
 }
 
 // --- Semantic Extraction Types ---
@@ -988,19 +1186,110 @@ pub enum ScrapingStrategy {
     Rss,
     Social(SocialPlatform),
     HtmlListing { link_pattern: &'static str },
+    /// Video channel or video (YouTube): transcripts, not page text.
+    Video,
+    /// Podcast RSS feed: episodes are transcribed from their audio.
+    Podcast,
+    /// Event platform listing (Eventbrite organizer, Facebook page events):
+    /// structured events that skip LLM extraction.
+    Events,
+    /// Email newsletter pushed to the inbound address; nothing to fetch.
+    Newsletter,
+}
+
+/// Prefix of newsletter source values (`newsletter:<sender address>`).
+pub const NEWSLETTER_PREFIX: &str = "newsletter:";
+
+/// Source value for a newsletter, keyed by its sender address.
+///
+/// ```
+/// assert_eq!(
+///     rootsignal_common::newsletter_source_value(" News@Ward6.org "),
+///     "newsletter:news@ward6.org"
+/// );
+/// ```
+pub fn newsletter_source_value(sender: &str) -> String {
+    format!("{NEWSLETTER_PREFIX}{}", sender.trim().to_lowercase())
+}
+
+/// Returns true if the value identifies a newsletter source.
+pub fn is_newsletter(value: &str) -> bool {
+    value.starts_with(NEWSLETTER_PREFIX)
+}
+
+/// Podcast hosting feed hosts and feed paths. Generic RSS feeds are only
+/// scraped as podcasts when they match one of these.
+const PODCAST_FEED_PATTERNS: &[&str] = &[
+    "anchor.fm/s/",
+    "feeds.buzzsprout.com",
+    "feeds.simplecast.com",
+    "feeds.megaphone.fm",
+    "libsyn.com/rss",
+    "feeds.libsyn.com",
+    "feed.podbean.com",
+    "feeds.transistor.fm",
+    "feeds.captivate.fm",
+    "rss.art19.com",
+    "feeds.soundcloud.com",
+    "/podcast.xml",
+    "/podcast.rss",
+    "/podcast/feed",
+];
+
+/// Returns true if the (lowercased) value is a podcast RSS feed.
+pub fn is_podcast_feed(lower: &str) -> bool {
+    PODCAST_FEED_PATTERNS.iter().any(|p| lower.contains(p))
+}
+
+/// Returns true if the (lowercased) value is an organizer's event listing on
+/// an event platform: an Eventbrite organizer page or a Facebook page's events.
+pub fn is_event_listing(lower: &str) -> bool {
+    lower.contains("eventbrite.com/o/")
+        || (lower.contains("facebook.com/") && lower.trim_end_matches('/').ends_with("/events"))
+}
+
+const FUNDRAISER_PATTERNS: &[&str] = &[
+    "gofundme.com/f/",
+    "gofund.me/",
+    "givebutter.com/",
+    "givesendgo.com/",
+];
+
+/// Returns true if the URL is an individual crowdfunding campaign page.
+pub fn is_fundraiser_url(url: &str) -> bool {
+    let lower = url.to_lowercase();
+    FUNDRAISER_PATTERNS.iter().any(|p| lower.contains(p))
+}
+
+/// Crowdfunding platform name for a fundraiser URL.
+pub fn fundraiser_platform(url: &str) -> &'static str {
+    let lower = url.to_lowercase();
+    if lower.contains("givebutter.com") {
+        "givebutter"
+    } else if lower.contains("givesendgo.com") {
+        "givesendgo"
+    } else {
+        "gofundme"
+    }
 }
 
 /// Returns true if the value is a plain-text web query (not a URL).
 pub fn is_web_query(value: &str) -> bool {
-    !value.starts_with("http://") && !value.starts_with("https://")
+    !value.starts_with("http://") && !value.starts_with("https://") && !is_newsletter(value)
 }
 
 /// Derive scraping strategy from a source's value (URL or query text).
 pub fn scraping_strategy(value: &str) -> ScrapingStrategy {
+    if is_newsletter(value) {
+        return ScrapingStrategy::Newsletter;
+    }
     if is_web_query(value) {
         return ScrapingStrategy::WebQuery;
     }
     let lower = value.to_lowercase();
+    if is_event_listing(&lower) {
+        return ScrapingStrategy::Events;
+    }
     if lower.contains("instagram.com") {
         return ScrapingStrategy::Social(SocialPlatform::Instagram);
     }
@@ -1019,6 +1308,12 @@ pub fn scraping_strategy(value: &str) -> ScrapingStrategy {
     if lower.contains("bsky.app") {
         return ScrapingStrategy::Social(SocialPlatform::Bluesky);
     }
+    if lower.contains("youtube.com/") || lower.contains("youtu.be/") {
+        return ScrapingStrategy::Video;
+    }
+    if is_podcast_feed(&lower) {
+        return ScrapingStrategy::Podcast;
+    }
     if lower.contains("eventbrite.com") && lower.contains("/d/") {
         return ScrapingStrategy::HtmlListing {
             link_pattern: "eventbrite.com/e/",
@@ -1046,6 +1341,9 @@ pub fn canonical_value(value: &str) -> String {
     if is_web_query(value) {
         return value.to_string();
     }
+    if is_newsletter(value) {
+        return newsletter_source_value(&value[NEWSLETTER_PREFIX.len()..]);
+    }
     let lower = value.to_lowercase();
     if lower.contains("instagram.com") {
         let handle = value
@@ -1156,6 +1454,8 @@ impl std::fmt::Display for SourceRole {
 }
 
 impl SourceRole {
+    pub const ALL: [SourceRole; 3] = [SourceRole::Tension, SourceRole::Response, SourceRole::Mixed];
+
     pub fn from_str_loose(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "tension" => SourceRole::Tension,
@@ -1210,6 +1510,9 @@ pub struct SourceNode {
     pub source_role: SourceRole,
     /// Number of times this source has been scraped (independent of signal count).
     pub scrape_count: u32,
+    /// Where the source is in its trial period; see `source_trial`.
+    #[serde(default)]
+    pub trial: crate::source_trial::TrialState,
 }
 
 impl SourceNode {
@@ -1225,6 +1528,7 @@ impl SourceNode {
     ) -> Self {
         Self {
             id: Uuid::new_v4(),
+            trial: crate::source_trial::TrialState::for_new_source(&discovery_method),
             canonical_key,
             canonical_value,
             url,
@@ -1614,6 +1918,16 @@ pub struct SituationNode {
     pub last_updated: DateTime<Utc>,
     pub sensitivity: SensitivityLevel,
     pub category: Option<String>,
+    /// Census context for the situation's footprint, when the region has ACS data.
+    pub demographics: Option<crate::DemographicContext>,
+    /// Likelihood of escalating soon, recomputed each run.
+    pub escalation: Option<crate::EscalationRisk>,
+    /// Situation-card alt text, generated at write time.
+    #[serde(default)]
+    pub alt_text: Option<String>,
+    /// The lede at a reading level of grade 8 or below, when one was written.
+    #[serde(default)]
+    pub plain_summary: Option<String>,
 }
 
 /// An atomic dispatch in a situation's living narrative thread.
@@ -1660,9 +1974,45 @@ mod tests {
             mentioned_actors: vec![],
             author_actor: None,
             implied_queries: vec![],
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         }
     }
 
+    #[test]
+    fn scope_boundary_excludes_points_its_bounding_box_would_include() {
+        // Two halves of a metro either side of a river, with the river between them.
+        let boundary = EntityGeometry::MultiPolygon {
+            rings: vec![
+                vec![[44.90, -93.35], [44.90, -93.22], [45.05, -93.22], [45.05, -93.35]],
+                vec![[44.88, -93.20], [44.88, -93.00], [45.00, -93.00], [45.00, -93.20]],
+            ],
+        };
+
+        let scope = ScoutScope::from_boundary("Twin Cities", boundary);
+
+        assert_eq!(scope.bounding_box(), (44.88, 45.05, -93.35, -93.00));
+        assert!(scope.contains(44.98, -93.27));
+        assert!(scope.contains(44.95, -93.09));
+        assert!(!scope.contains(44.95, -93.21), "river between the parts");
+        assert!(!scope.contains(45.03, -93.05), "inside the bbox, outside both parts");
+        assert!(scope.radius_km > 10.0);
+    }
+
+    #[test]
+    fn circle_scope_without_boundary_keeps_bbox_behavior() {
+        let json = r#"{"center_lat": 44.9778, "center_lng": -93.265, "radius_km": 30.0, "name": "Minneapolis"}"#;
+
+        let scope: ScoutScope = serde_json::from_str(json).unwrap();
+
+        assert!(scope.boundary.is_none());
+        let (min_lat, max_lat, _, max_lng) = scope.bounding_box();
+        assert!(scope.contains(max_lat - 0.001, max_lng - 0.001));
+        assert!(!scope.contains(min_lat - 0.001, -93.265));
+        assert!(!serde_json::to_string(&scope).unwrap().contains("boundary"));
+    }
+
     #[test]
     fn tension_node_has_all_fields() {
         let t = TensionNode {
@@ -2057,6 +2407,71 @@ mod tests {
         assert!(!is_web_query("http://example.com"));
     }
 
+    #[test]
+    fn newsletter_sender_on_social_lookalike_domain_is_not_social() {
+        let value = newsletter_source_value("News@Mailbox.com");
+
+        assert_eq!(canonical_value(&value), "newsletter:news@mailbox.com");
+        assert_eq!(scraping_strategy(&value), ScrapingStrategy::Newsletter);
+        assert!(!is_web_query(&value));
+    }
+
+    #[test]
+    fn youtube_channels_and_videos_are_scraped_as_video() {
+        assert_eq!(scraping_strategy("https://www.youtube.com/@MplsCouncil"), ScrapingStrategy::Video);
+        assert_eq!(scraping_strategy("https://youtu.be/abc123"), ScrapingStrategy::Video);
+    }
+
+    #[test]
+    fn podcast_host_feeds_are_scraped_as_podcasts_not_plain_rss() {
+        assert_eq!(scraping_strategy("https://feeds.buzzsprout.com/12345.rss"), ScrapingStrategy::Podcast);
+        assert_eq!(scraping_strategy("https://northside.fm/podcast.xml"), ScrapingStrategy::Podcast);
+        assert_eq!(scraping_strategy("https://northside.fm/feed.xml"), ScrapingStrategy::Rss);
+    }
+
+    #[test]
+    fn organizer_event_listings_are_scraped_as_events() {
+        assert_eq!(
+            scraping_strategy("https://www.eventbrite.com/o/northside-tenants-union-31415926535"),
+            ScrapingStrategy::Events
+        );
+        assert_eq!(scraping_strategy("https://www.facebook.com/NorthsideTenants/events/"), ScrapingStrategy::Events);
+        assert_eq!(
+            scraping_strategy("https://www.facebook.com/NorthsideTenants"),
+            ScrapingStrategy::Social(SocialPlatform::Facebook)
+        );
+        assert!(matches!(
+            scraping_strategy("https://www.eventbrite.com/d/mn--minneapolis/community/"),
+            ScrapingStrategy::HtmlListing { .. }
+        ));
+    }
+
+    #[test]
+    fn campaign_pages_are_fundraisers_but_platform_homepages_are_not() {
+        assert!(is_fundraiser_url("https://www.gofundme.com/f/rebuild-lake-street-bakery"));
+        assert!(is_fundraiser_url("https://gofund.me/4f1c2b9a"));
+        assert!(!is_fundraiser_url("https://www.gofundme.com/discover"));
+        assert_eq!(fundraiser_platform("https://givebutter.com/northside-rent-fund"), "givebutter");
+    }
+
+    #[test]
+    fn percent_funded_needs_a_goal() {
+        let mut f = Fundraiser {
+            url: "https://www.gofundme.com/f/x".to_string(),
+            platform: "gofundme".to_string(),
+            goal: Some(50_000.0),
+            raised: 36_500.0,
+            currency: "USD".to_string(),
+            donor_count: None,
+            organizer: None,
+            beneficiary: None,
+            checked_at: Utc::now(),
+        };
+        assert_eq!(f.percent_funded().map(|p| p.round()), Some(73.0));
+        f.goal = None;
+        assert_eq!(f.percent_funded(), None);
+    }
+
     // --- Channels tests ---
 
     #[test]
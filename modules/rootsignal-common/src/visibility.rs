@@ -0,0 +1,275 @@
+//! Field-level visibility for signals.
+//!
+//! A signal is either readable or not, but some of its fields deserve a
+//! narrower audience than the rest: the exact coordinates of a sensitive
+//! gathering, a street address in a location name, a private organizer.
+//! Each `SignalField` has a tier — public, contributor or admin — derived
+//! from the signal's sensitivity and raised by per-signal demotions, which
+//! the content policy and admins set. `redact_node` strips every field the
+//! viewer's tier can't see and is applied wherever signals leave the API.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::safety::{fuzz_location, SensitivityLevel};
+use crate::types::Node;
+
+/// Who is reading, narrowest audience last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Public,
+    /// Verified contributors.
+    Contributor,
+    Admin,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Contributor => "contributor",
+            Visibility::Admin => "admin",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "public" => Some(Visibility::Public),
+            "contributor" => Some(Visibility::Contributor),
+            "admin" => Some(Visibility::Admin),
+            _ => None,
+        }
+    }
+}
+
+/// Signal fields that can be shown to a narrower audience than the signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalField {
+    /// Coordinates at the precision sensitivity allows. Hidden, they are
+    /// snapped to the region-level grid instead of removed.
+    Location,
+    /// Human-readable place, which may be a street address.
+    LocationName,
+    /// Registration, donation or contact link.
+    ActionUrl,
+    /// A gathering's organizer, a fundraiser's organizer and beneficiary.
+    Organizer,
+}
+
+impl SignalField {
+    pub const ALL: [SignalField; 4] = [
+        SignalField::Location,
+        SignalField::LocationName,
+        SignalField::ActionUrl,
+        SignalField::Organizer,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignalField::Location => "location",
+            SignalField::LocationName => "location_name",
+            SignalField::ActionUrl => "action_url",
+            SignalField::Organizer => "organizer",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == s)
+    }
+
+    /// The tier a field gets before any demotion. Sensitive signals keep
+    /// place names and organizers to contributors; their coordinates are
+    /// already region-level.
+    pub fn default_visibility(&self, sensitivity: SensitivityLevel) -> Visibility {
+        match (self, sensitivity) {
+            (SignalField::LocationName | SignalField::Organizer, SensitivityLevel::Sensitive) => {
+                Visibility::Contributor
+            }
+            _ => Visibility::Public,
+        }
+    }
+}
+
+/// Per-signal demotions: fields raised above their default tier.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldVisibility(BTreeMap<SignalField, Visibility>);
+
+impl FieldVisibility {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn demotions(&self) -> impl Iterator<Item = (SignalField, Visibility)> + '_ {
+        self.0.iter().map(|(f, v)| (*f, *v))
+    }
+
+    /// Raise `field` to at least `tier`. Never lowers an existing demotion.
+    pub fn demote(&mut self, field: SignalField, tier: Visibility) {
+        let current = self.0.entry(field).or_insert(tier);
+        *current = (*current).max(tier);
+    }
+
+    /// Set `field` to exactly `tier`, or clear its demotion with None.
+    pub fn set(&mut self, field: SignalField, tier: Option<Visibility>) {
+        match tier {
+            Some(tier) => {
+                self.0.insert(field, tier);
+            }
+            None => {
+                self.0.remove(&field);
+            }
+        }
+    }
+
+    /// The effective tier: the default for this sensitivity, or the
+    /// demotion if it is narrower.
+    pub fn tier(&self, field: SignalField, sensitivity: SensitivityLevel) -> Visibility {
+        let default = field.default_visibility(sensitivity);
+        self.0.get(&field).map_or(default, |d| (*d).max(default))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Parse a stored value. Unknown fields or tiers are dropped.
+    pub fn from_json(s: &str) -> Self {
+        let raw: BTreeMap<String, String> = serde_json::from_str(s).unwrap_or_default();
+        Self(
+            raw.iter()
+                .filter_map(|(f, v)| Some((SignalField::parse(f)?, Visibility::parse(v)?)))
+                .collect(),
+        )
+    }
+}
+
+/// Strip every field `viewer` may not see.
+pub fn redact_node(mut node: Node, viewer: Visibility) -> Node {
+    let Some(meta) = node.meta() else {
+        return node;
+    };
+    let hidden: Vec<SignalField> = SignalField::ALL
+        .into_iter()
+        .filter(|f| meta.field_visibility.tier(*f, meta.sensitivity) > viewer)
+        .collect();
+    if hidden.is_empty() {
+        return node;
+    }
+
+    for field in hidden {
+        match field {
+            SignalField::Location => {
+                if let Some(meta) = node.meta_mut() {
+                    meta.about_location = meta
+                        .about_location
+                        .map(|loc| fuzz_location(loc, SensitivityLevel::Sensitive));
+                }
+            }
+            SignalField::LocationName => {
+                if let Some(meta) = node.meta_mut() {
+                    meta.about_location_name = None;
+                }
+            }
+            SignalField::ActionUrl => match &mut node {
+                Node::Gathering(n) => n.action_url.clear(),
+                Node::Aid(n) => n.action_url.clear(),
+                Node::Need(n) => n.action_url = None,
+                _ => {}
+            },
+            SignalField::Organizer => match &mut node {
+                Node::Gathering(n) => n.organizer = None,
+                Node::Need(n) => {
+                    if let Some(f) = n.fundraiser.as_mut() {
+                        f.organizer = None;
+                        f.beneficiary = None;
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GatheringNode, GeoPoint, GeoPrecision, NodeMeta};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn gathering(sensitivity: SensitivityLevel, field_visibility: FieldVisibility) -> Node {
+        Node::Gathering(GatheringNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: "Tenant meeting".to_string(),
+                summary: "Monthly meeting of the building's tenant union".to_string(),
+                sensitivity,
+                confidence: 0.8,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: Some(GeoPoint { lat: 44.9631, lng: -93.2683, precision: GeoPrecision::Exact }),
+                about_location_name: Some("2215 Pleasant Ave".to_string()),
+                from_location: None,
+                source_url: "https://example.com/meeting".to_string(),
+                extracted_at: Utc::now(),
+                content_date: None,
+                last_confirmed_active: Utc::now(),
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat: 0.0,
+                implied_queries: vec![],
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                field_visibility,
+                alt_text: None,
+                plain_summary: None,
+            },
+            starts_at: None,
+            ends_at: None,
+            action_url: "https://example.com/rsvp".to_string(),
+            organizer: Some("Jo Alvarez".to_string()),
+            is_recurring: true,
+        })
+    }
+
+    #[test]
+    fn demoted_fields_are_hidden_below_their_tier() {
+        let mut demotions = FieldVisibility::default();
+        demotions.demote(SignalField::LocationName, Visibility::Contributor);
+        demotions.demote(SignalField::LocationName, Visibility::Public);
+        assert_eq!(demotions.tier(SignalField::LocationName, SensitivityLevel::General), Visibility::Contributor);
+        let node = gathering(SensitivityLevel::General, demotions);
+
+        let public = redact_node(node.clone(), Visibility::Public);
+        let meta = public.meta().unwrap();
+        assert_eq!(meta.about_location_name, None);
+        assert_eq!(meta.summary, "Monthly meeting of the building's tenant union");
+        assert_eq!(meta.about_location.unwrap().precision, GeoPrecision::Exact);
+
+        let contributor = redact_node(node, Visibility::Contributor);
+        assert_eq!(contributor.meta().unwrap().about_location_name.as_deref(), Some("2215 Pleasant Ave"));
+    }
+
+    #[test]
+    fn sensitive_defaults_and_stored_form() {
+        let mut demotions = FieldVisibility::default();
+        demotions.set(SignalField::Location, Some(Visibility::Admin));
+        let node = redact_node(gathering(SensitivityLevel::Sensitive, demotions.clone()), Visibility::Contributor);
+        let Node::Gathering(g) = &node else { unreachable!() };
+        assert_eq!(g.organizer.as_deref(), Some("Jo Alvarez"));
+        assert_eq!(g.meta.about_location.unwrap().precision, GeoPrecision::Approximate);
+
+        let Node::Gathering(g) = redact_node(node, Visibility::Public) else { unreachable!() };
+        assert_eq!(g.organizer, None);
+        assert_eq!(g.action_url, "https://example.com/rsvp");
+
+        assert_eq!(FieldVisibility::from_json(&demotions.to_json()), demotions);
+        assert!(FieldVisibility::from_json(r#"{"phone":"admin"}"#).is_empty());
+    }
+}
@@ -0,0 +1,214 @@
+//! Saved searches: a user's standing query over new signals.
+//!
+//! A saved search narrows by area, signal type, topic category and keywords;
+//! each is optional and an empty one doesn't narrow. New signals are matched
+//! against every saved search when the API's signal cache refreshes, matches
+//! are recorded for the owner's watchlist, and the owner is notified on the
+//! channels they chose.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Node, NodeType};
+
+/// Saved searches one user may keep.
+pub const MAX_SAVED_SEARCHES: usize = 20;
+
+/// Signals listed in a single notification; the rest are counted.
+pub const MAX_NOTIFIED_SIGNALS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+impl BoundingBox {
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        lat >= self.min_lat && lat <= self.max_lat && lng >= self.min_lng && lng <= self.max_lng
+    }
+}
+
+/// Where a saved search's matches are sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "channel", content = "target", rename_all = "snake_case")]
+pub enum NotifyChannel {
+    Email(String),
+    /// E.164 phone number.
+    Sms(String),
+    /// HTTPS URL; matches are POSTed as JSON.
+    Webhook(String),
+}
+
+impl NotifyChannel {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            NotifyChannel::Email(address) => {
+                let valid = address.split_once('@').is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+                if !valid {
+                    return Err(format!("Invalid email address: {address}"));
+                }
+            }
+            NotifyChannel::Sms(phone) => {
+                let digits = phone.strip_prefix('+').unwrap_or_default();
+                if !(8..=15).contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(format!("Invalid phone number (expected E.164, e.g. +16125550100): {phone}"));
+                }
+            }
+            NotifyChannel::Webhook(url) => {
+                if !url.starts_with("https://") {
+                    return Err(format!("Webhook URL must be https: {url}"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    /// Member id (`sub` of the owner's token).
+    pub owner: String,
+    pub name: String,
+    pub bbox: Option<BoundingBox>,
+    #[serde(default)]
+    pub types: Vec<NodeType>,
+    /// Topic category slugs, names or aliases; each includes its subcategories.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Any of these appearing in a signal's title or summary is a match.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub notify: Vec<NotifyChannel>,
+    pub created_at: DateTime<Utc>,
+    /// When the owner last looked at this search's matches.
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl SavedSearch {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Saved search needs a name".to_string());
+        }
+        if let Some(b) = &self.bbox {
+            if !(b.min_lat < b.max_lat && b.min_lng < b.max_lng) {
+                return Err("Bounding box min must be below max".to_string());
+            }
+        }
+        if self.types.contains(&NodeType::Evidence) {
+            return Err("Evidence is not a signal type".to_string());
+        }
+        if self.bbox.is_none() && self.types.is_empty() && self.categories.is_empty() && self.keywords.is_empty() {
+            return Err("Saved search must narrow by area, type, category or keyword".to_string());
+        }
+        self.notify.iter().try_for_each(NotifyChannel::validate)
+    }
+
+    /// Whether `node` matches. `categories` is the search's categories
+    /// expanded to canonical slugs (with subcategories).
+    pub fn matches(&self, node: &Node, categories: &[String]) -> bool {
+        let Some(meta) = node.meta() else {
+            return false;
+        };
+        if let Some(bbox) = &self.bbox {
+            match meta.about_location {
+                Some(loc) if bbox.contains(loc.lat, loc.lng) => {}
+                _ => return false,
+            }
+        }
+        if !self.types.is_empty() && !self.types.contains(&node.node_type()) {
+            return false;
+        }
+        if !categories.is_empty() && !node.category().is_some_and(|c| categories.iter().any(|k| k == c)) {
+            return false;
+        }
+        if !self.keywords.is_empty() {
+            let text = format!("{} {}", meta.title, meta.summary).to_lowercase();
+            if !self.keywords.iter().any(|k| text.contains(&k.trim().to_lowercase())) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeoPoint, GeoPrecision, NodeMeta, SensitivityLevel, Severity, TensionNode};
+
+    fn tension(title: &str, lat: f64, lng: f64, category: &str) -> Node {
+        Node::Tension(TensionNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: title.to_string(),
+                summary: String::new(),
+                sensitivity: SensitivityLevel::General,
+                confidence: 0.8,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: Some(GeoPoint { lat, lng, precision: GeoPrecision::Exact }),
+                about_location_name: None,
+                from_location: None,
+                source_url: "https://example.com".to_string(),
+                extracted_at: Utc::now(),
+                content_date: None,
+                last_confirmed_active: Utc::now(),
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat: 0.0,
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                implied_queries: vec![],
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
+            },
+            severity: Severity::High,
+            category: Some(category.to_string()),
+            what_would_help: None,
+        })
+    }
+
+    fn search() -> SavedSearch {
+        SavedSearch {
+            id: Uuid::new_v4(),
+            owner: "member".to_string(),
+            name: "Phillips housing".to_string(),
+            bbox: Some(BoundingBox { min_lat: 44.94, max_lat: 44.96, min_lng: -93.28, max_lng: -93.25 }),
+            types: vec![NodeType::Tension],
+            categories: vec!["housing".to_string()],
+            keywords: vec!["Eviction".to_string(), "rent".to_string()],
+            notify: vec![NotifyChannel::Sms("+16125550100".to_string())],
+            created_at: Utc::now(),
+            last_seen_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn every_given_criterion_must_match() {
+        let search = search();
+        let categories = vec!["housing".to_string(), "eviction".to_string()];
+        assert!(search.matches(&tension("Eviction notices at Cedar Ave", 44.95, -93.26, "eviction"), &categories));
+        assert!(!search.matches(&tension("Eviction notices", 44.98, -93.26, "eviction"), &categories), "outside bbox");
+        assert!(!search.matches(&tension("Eviction notices", 44.95, -93.26, "transit"), &categories), "wrong category");
+        assert!(!search.matches(&tension("Bus stop closed", 44.95, -93.26, "housing"), &categories), "no keyword");
+    }
+
+    #[test]
+    fn validate_requires_narrowing_and_valid_channels() {
+        assert!(search().validate().is_ok());
+        let everything = SavedSearch { bbox: None, types: vec![], categories: vec![], keywords: vec![], ..search() };
+        assert!(everything.validate().is_err());
+        let bad_sms = SavedSearch { notify: vec![NotifyChannel::Sms("612-555-0100".to_string())], ..search() };
+        assert!(bad_sms.validate().is_err());
+        let http_hook = SavedSearch { notify: vec![NotifyChannel::Webhook("http://example.com".to_string())], ..search() };
+        assert!(http_hook.validate().is_err());
+    }
+}
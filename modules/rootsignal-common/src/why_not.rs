@@ -0,0 +1,360 @@
+//! "Why isn't this on the map?" diagnostics.
+//!
+//! Given a URL or a description, the API gathers what each stage of the
+//! pipeline recorded about it into `WhyNotFacts`:
+//! - the source registry and the archive;
+//! - scout run logs (fetches, extractions, dedup and suppression events);
+//! - stored signals;
+//! - negative knowledge.
+//!
+//! `explain` walks those stages in order and reports the furthest one
+//! reached and, when something was turned away, the gate that did it.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{NodeType, CONFIDENCE_DISPLAY_LIMITED};
+
+/// How far through the pipeline the content got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// No source, fetch, run log event or signal mentions it.
+    NeverDiscovered,
+    /// A known source that hasn't been scraped yet.
+    Discovered,
+    FetchFailed,
+    /// Fetched, but nothing came out of extraction.
+    Fetched,
+    /// Signals were extracted but none was stored, for an unlogged reason.
+    Extracted,
+    Rejected,
+    Published,
+}
+
+/// What turned a signal away, or keeps a stored one off the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+    /// Rejected by the judge audit or awaiting review.
+    Safety,
+    Dedup,
+    /// Stored below the display threshold.
+    Confidence,
+    /// Stored without coordinates.
+    Geography,
+    NegativeKnowledge,
+    /// Past its event date or freshness window.
+    Expired,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchAttempt {
+    pub run_id: String,
+    pub at: DateTime<Utc>,
+    pub success: bool,
+    /// Failure class, when the fetch failed.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractionAttempt {
+    pub run_id: String,
+    pub at: DateTime<Utc>,
+    pub signals_extracted: u32,
+}
+
+/// What became of one extracted signal, per the run log.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalOutcome {
+    Created { node_id: String },
+    /// Same-source duplicate: the existing signal was refreshed.
+    Refreshed { matched_id: String },
+    /// Cross-source duplicate: folded into the existing signal as evidence.
+    Corroborated { existing_id: String },
+    Suppressed { entry_id: String, kind: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutcomeEvent {
+    pub run_id: String,
+    pub at: DateTime<Utc>,
+    /// Empty for corroborations, which the run log records by id only.
+    pub title: String,
+    pub outcome: SignalOutcome,
+}
+
+/// A stored signal the content produced, or one citing it as evidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredSignal {
+    pub id: Uuid,
+    pub node_type: NodeType,
+    pub title: String,
+    pub confidence: f32,
+    pub review_status: String,
+    pub has_location: bool,
+    /// Passes the display filter: not a past event or stale.
+    pub fresh: bool,
+}
+
+impl StoredSignal {
+    /// What keeps this signal off the map, if anything.
+    pub fn hidden_by(&self) -> Option<Gate> {
+        if self.review_status != "live" {
+            Some(Gate::Safety)
+        } else if self.confidence < CONFIDENCE_DISPLAY_LIMITED {
+            Some(Gate::Confidence)
+        } else if !self.has_location {
+            Some(Gate::Geography)
+        } else if !self.fresh {
+            Some(Gate::Expired)
+        } else {
+            None
+        }
+    }
+}
+
+/// An active negative knowledge entry matching the content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeHit {
+    pub entry_id: Uuid,
+    pub kind: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WhyNotFacts {
+    /// A scout source is registered for the URL.
+    pub source_known: bool,
+    /// Latest fetch of the URL in the archive.
+    pub last_archived: Option<DateTime<Utc>>,
+    /// Oldest first, as are `extractions` and `outcomes`.
+    pub fetches: Vec<FetchAttempt>,
+    pub extractions: Vec<ExtractionAttempt>,
+    pub outcomes: Vec<OutcomeEvent>,
+    pub signals: Vec<StoredSignal>,
+    pub negative_match: Option<NegativeHit>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhyNot {
+    pub stage: Stage,
+    pub gate: Option<Gate>,
+    pub explanation: String,
+}
+
+impl WhyNot {
+    fn new(stage: Stage, gate: Option<Gate>, explanation: impl Into<String>) -> Self {
+        Self { stage, gate, explanation: explanation.into() }
+    }
+}
+
+fn hidden_reason(signal: &StoredSignal, gate: Gate) -> String {
+    let title = &signal.title;
+    match gate {
+        Gate::Safety if signal.review_status == "rejected" => {
+            format!("\"{title}\" was stored but rejected before publication (see its decision records)")
+        }
+        Gate::Safety => format!("\"{title}\" is stored but {} review", signal.review_status),
+        Gate::Confidence => format!(
+            "\"{title}\" is stored with confidence {:.2}, below the display threshold of {CONFIDENCE_DISPLAY_LIMITED:.2}",
+            signal.confidence
+        ),
+        Gate::Geography => format!("\"{title}\" is stored without a location, so it can't be placed on the map"),
+        Gate::Expired => format!("\"{title}\" is stored but past its date or freshness window"),
+        Gate::Dedup | Gate::NegativeKnowledge => format!("\"{title}\" is stored"),
+    }
+}
+
+/// The furthest stage the facts show, and what stopped it there.
+pub fn explain(facts: &WhyNotFacts) -> WhyNot {
+    if let Some(signal) = facts.signals.iter().find(|s| s.hidden_by().is_none()) {
+        let merged = facts
+            .outcomes
+            .iter()
+            .any(|o| matches!(o.outcome, SignalOutcome::Corroborated { .. }));
+        let note = if merged { ", merged with the same signal from other sources" } else { "" };
+        return WhyNot::new(
+            Stage::Published,
+            None,
+            format!("It is on the map as \"{}\" ({}){note}", signal.title, signal.id),
+        );
+    }
+    if let Some((signal, gate)) = facts.signals.iter().find_map(|s| Some((s, s.hidden_by()?))) {
+        return WhyNot::new(Stage::Rejected, Some(gate), hidden_reason(signal, gate));
+    }
+    if let Some(event) = facts.outcomes.last() {
+        return match &event.outcome {
+            SignalOutcome::Suppressed { entry_id, kind } => {
+                WhyNot::new(
+                    Stage::Rejected,
+                    Some(Gate::NegativeKnowledge),
+                    format!("Run {} dropped it: it matches negative knowledge entry {entry_id} ({kind})", event.run_id),
+                )
+            }
+            SignalOutcome::Refreshed { matched_id: id } | SignalOutcome::Corroborated { existing_id: id } => {
+                WhyNot::new(
+                    Stage::Rejected,
+                    Some(Gate::Dedup),
+                    format!(
+                        "Run {} treated it as a duplicate of signal {id}, which is no longer in the graph",
+                        event.run_id
+                    ),
+                )
+            }
+            SignalOutcome::Created { node_id } => {
+                WhyNot::new(
+                    Stage::Rejected,
+                    Some(Gate::Expired),
+                    format!("Run {} stored it as signal {node_id}, which has since expired or been removed", event.run_id),
+                )
+            }
+        };
+    }
+    if let Some(hit) = &facts.negative_match {
+        return WhyNot::new(
+            Stage::Rejected,
+            Some(Gate::NegativeKnowledge),
+            format!("It matches negative knowledge entry {} ({}): {}", hit.entry_id, hit.kind, hit.reason),
+        );
+    }
+    if let Some(extraction) = facts.extractions.iter().rev().find(|e| e.signals_extracted > 0) {
+        return WhyNot::new(
+            Stage::Extracted,
+            None,
+            format!(
+                "Run {} extracted {} signal(s) but stored none: they matched a signal already stored from this page, \
+                 or a safety filter withheld them",
+                extraction.run_id, extraction.signals_extracted
+            ),
+        );
+    }
+    if let Some(extraction) = facts.extractions.last() {
+        return WhyNot::new(
+            Stage::Fetched,
+            None,
+            format!("Run {} read it but found no community signal in it", extraction.run_id),
+        );
+    }
+    if let Some(fetch) = facts.fetches.iter().rev().find(|f| f.success) {
+        return WhyNot::new(
+            Stage::Fetched,
+            None,
+            format!("Run {} fetched it; the content was unchanged or had nothing to extract", fetch.run_id),
+        );
+    }
+    if let Some(fetch) = facts.fetches.last() {
+        return WhyNot::new(
+            Stage::FetchFailed,
+            None,
+            format!(
+                "Every fetch failed; the last, in run {}, with {}",
+                fetch.run_id,
+                fetch.error.as_deref().unwrap_or("an unknown error")
+            ),
+        );
+    }
+    if let Some(at) = facts.last_archived {
+        return WhyNot::new(
+            Stage::Fetched,
+            None,
+            format!("It is in the archive (fetched {at}), but no run log shows it being extracted"),
+        );
+    }
+    if facts.source_known {
+        return WhyNot::new(Stage::Discovered, None, "It is a known source but hasn't been scraped yet");
+    }
+    WhyNot::new(
+        Stage::NeverDiscovered,
+        None,
+        "Nothing has fetched, extracted or stored it: the scout hasn't discovered it",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(confidence: f32, review_status: &str, has_location: bool) -> StoredSignal {
+        StoredSignal {
+            id: Uuid::new_v4(),
+            node_type: NodeType::Gathering,
+            title: "Tenant union meeting".to_string(),
+            confidence,
+            review_status: review_status.to_string(),
+            has_location,
+            fresh: true,
+        }
+    }
+
+    fn outcome(outcome: SignalOutcome) -> OutcomeEvent {
+        OutcomeEvent { run_id: "run-7".to_string(), at: Utc::now(), title: "Tenant union meeting".to_string(), outcome }
+    }
+
+    #[test]
+    fn a_visible_signal_is_published_even_beside_hidden_ones() {
+        let facts = WhyNotFacts {
+            signals: vec![stored(0.2, "live", true), stored(0.8, "live", true)],
+            ..Default::default()
+        };
+
+        let why = explain(&facts);
+
+        assert_eq!(why.stage, Stage::Published);
+        assert_eq!(why.gate, None);
+    }
+
+    #[test]
+    fn stored_but_hidden_signals_name_their_gate() {
+        let cases = [
+            (stored(0.8, "rejected", true), Gate::Safety),
+            (stored(0.2, "live", true), Gate::Confidence),
+            (stored(0.8, "live", false), Gate::Geography),
+        ];
+        for (signal, gate) in cases {
+            let why = explain(&WhyNotFacts { signals: vec![signal], ..Default::default() });
+
+            assert_eq!((why.stage, why.gate), (Stage::Rejected, Some(gate)));
+        }
+    }
+
+    #[test]
+    fn the_latest_run_log_outcome_explains_a_signal_that_was_never_stored() {
+        let facts = WhyNotFacts {
+            outcomes: vec![
+                outcome(SignalOutcome::Refreshed { matched_id: "a".to_string() }),
+                outcome(SignalOutcome::Suppressed { entry_id: "b".to_string(), kind: "spam".to_string() }),
+            ],
+            ..Default::default()
+        };
+
+        let why = explain(&facts);
+
+        assert_eq!(why.gate, Some(Gate::NegativeKnowledge));
+        assert!(why.explanation.contains("entry b"));
+    }
+
+    #[test]
+    fn earlier_stages_explain_content_that_never_reached_extraction() {
+        let failed = FetchAttempt {
+            run_id: "run-3".to_string(),
+            at: Utc::now(),
+            success: false,
+            error: Some("transient".to_string()),
+        };
+        let negative = NegativeHit {
+            entry_id: Uuid::new_v4(),
+            kind: "debunked".to_string(),
+            reason: "Rumor".to_string(),
+        };
+
+        let fetch_failed = explain(&WhyNotFacts { fetches: vec![failed], source_known: true, ..Default::default() });
+        let discovered = explain(&WhyNotFacts { source_known: true, ..Default::default() });
+        let unknown = explain(&WhyNotFacts::default());
+        let debunked = explain(&WhyNotFacts { negative_match: Some(negative), ..Default::default() });
+
+        assert_eq!(fetch_failed.stage, Stage::FetchFailed);
+        assert!(fetch_failed.explanation.contains("transient"));
+        assert_eq!(discovered.stage, Stage::Discovered);
+        assert_eq!(unknown.stage, Stage::NeverDiscovered);
+        assert_eq!(debunked.gate, Some(Gate::NegativeKnowledge));
+    }
+}
@@ -0,0 +1,261 @@
+//! Contributors and their signal annotations in the graph.
+//!
+//! `Contributor` nodes are keyed by member id. Each annotation is an
+//! `Annotation` node linked `-[:ANNOTATES]->` its signal; its status walks
+//! pending → approved | rejected as moderators review the queue.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::annotations::REVERIFY_REPUTATION;
+use rootsignal_common::{Annotation, AnnotationStatus, Contributor};
+
+use crate::GraphClient;
+
+const SIGNAL_LABELS: &str = "(n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)";
+
+const ANNOTATION_FIELDS: &str = "a.id AS id, a.signal_id AS signal_id, a.author_id AS author_id,
+     a.author_name AS author_name, a.body AS body, a.status AS status,
+     toString(a.created_at) AS created_at, toString(a.reviewed_at) AS reviewed_at,
+     a.review_note AS review_note";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_annotation(row: &Row) -> Option<Annotation> {
+    Some(Annotation {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        signal_id: Uuid::parse_str(&row.get::<String>("signal_id").ok()?).ok()?,
+        author_id: row.get("author_id").unwrap_or_default(),
+        author_name: row.get("author_name").unwrap_or_default(),
+        body: row.get("body").unwrap_or_default(),
+        status: AnnotationStatus::parse(&row.get::<String>("status").unwrap_or_default())?,
+        created_at: parse_time(row, "created_at")?,
+        reviewed_at: parse_time(row, "reviewed_at"),
+        review_note: row.get::<String>("review_note").ok().filter(|n| !n.is_empty()),
+    })
+}
+
+/// Verify a member as a contributor, or update their display name.
+/// Reputation is kept for contributors already verified.
+pub async fn upsert_contributor(client: &GraphClient, contributor: &Contributor) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (c:Contributor {member_id: $member_id})
+         ON CREATE SET c.reputation = $reputation, c.verified_at = datetime($verified_at)
+         SET c.display_name = $display_name",
+    )
+    .param("member_id", contributor.member_id.as_str())
+    .param("display_name", contributor.display_name.as_str())
+    .param("reputation", contributor.reputation)
+    .param("verified_at", contributor.verified_at.to_rfc3339());
+
+    client.graph.run(q).await
+}
+
+/// Revoke a contributor. Their approved annotations stay; pending ones are
+/// rejected. Returns whether they were a contributor.
+pub async fn revoke_contributor(client: &GraphClient, member_id: &str) -> Result<bool, neo4rs::Error> {
+    client
+        .graph
+        .run(
+            query(
+                "MATCH (a:Annotation {author_id: $member_id, status: 'pending'})
+                 SET a.status = 'rejected', a.reviewed_at = datetime(), a.review_note = 'Contributor revoked'",
+            )
+            .param("member_id", member_id),
+        )
+        .await?;
+
+    let q = query(
+        "MATCH (c:Contributor {member_id: $member_id})
+         DELETE c
+         RETURN count(*) AS revoked",
+    )
+    .param("member_id", member_id);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("revoked").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+pub async fn contributor(client: &GraphClient, member_id: &str) -> Result<Option<Contributor>, neo4rs::Error> {
+    let q = query(
+        "MATCH (c:Contributor {member_id: $member_id})
+         RETURN c.member_id AS member_id, c.display_name AS display_name,
+                c.reputation AS reputation, toString(c.verified_at) AS verified_at",
+    )
+    .param("member_id", member_id);
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(None);
+    };
+    Ok(Some(Contributor {
+        member_id: row.get("member_id").unwrap_or_default(),
+        display_name: row.get("display_name").unwrap_or_default(),
+        reputation: row.get("reputation").unwrap_or(0.0),
+        verified_at: parse_time(&row, "verified_at").unwrap_or_else(Utc::now),
+    }))
+}
+
+/// Annotations the author has waiting for review.
+pub async fn pending_count(client: &GraphClient, author_id: &str) -> Result<u32, neo4rs::Error> {
+    let q = query("MATCH (a:Annotation {author_id: $author_id, status: 'pending'}) RETURN count(a) AS pending")
+        .param("author_id", author_id);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("pending").unwrap_or(0) as u32,
+        None => 0,
+    })
+}
+
+/// Store a new annotation, linked to its signal. Returns false if the signal
+/// doesn't exist.
+pub async fn add_annotation(client: &GraphClient, annotation: &Annotation) -> Result<bool, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (n {{id: $signal_id}}) WHERE {SIGNAL_LABELS}
+         CREATE (a:Annotation {{
+             id: $id, signal_id: $signal_id, author_id: $author_id, author_name: $author_name,
+             body: $body, status: $status, created_at: datetime($created_at)
+         }})-[:ANNOTATES]->(n)
+         RETURN count(a) AS created"
+    ))
+    .param("id", annotation.id.to_string())
+    .param("signal_id", annotation.signal_id.to_string())
+    .param("author_id", annotation.author_id.as_str())
+    .param("author_name", annotation.author_name.as_str())
+    .param("body", annotation.body.as_str())
+    .param("status", annotation.status.as_str())
+    .param("created_at", annotation.created_at.to_rfc3339());
+
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("created").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// The moderation queue, oldest first.
+pub async fn pending_annotations(client: &GraphClient, limit: u32) -> Result<Vec<Annotation>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (a:Annotation {{status: 'pending'}})
+         RETURN {ANNOTATION_FIELDS}
+         ORDER BY a.created_at
+         LIMIT $limit"
+    ))
+    .param("limit", limit as i64);
+
+    let mut annotations = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        annotations.extend(row_to_annotation(&row));
+    }
+    Ok(annotations)
+}
+
+/// Approved annotations per signal, oldest first.
+pub async fn approved_annotations(
+    client: &GraphClient,
+    signal_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Annotation>>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (a:Annotation {{status: 'approved'}})
+         WHERE a.signal_id IN $signal_ids
+         RETURN {ANNOTATION_FIELDS}
+         ORDER BY a.created_at"
+    ))
+    .param("signal_ids", signal_ids.iter().map(Uuid::to_string).collect::<Vec<_>>());
+
+    let mut by_signal: HashMap<Uuid, Vec<Annotation>> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(annotation) = row_to_annotation(&row) {
+            by_signal.entry(annotation.signal_id).or_default().push(annotation);
+        }
+    }
+    Ok(by_signal)
+}
+
+/// What a review did.
+pub struct ReviewOutcome {
+    pub annotation: Annotation,
+    /// The author's reputation after the review, if they're still a contributor.
+    pub reputation: Option<f64>,
+    pub reverification_requested: bool,
+}
+
+/// Approve or reject a pending annotation, move the author's reputation,
+/// and — for an approval from a contributor at `REVERIFY_REPUTATION` —
+/// request re-verification of the signal. None if no such pending annotation.
+pub async fn review_annotation(
+    client: &GraphClient,
+    id: Uuid,
+    approve: bool,
+    note: Option<&str>,
+) -> Result<Option<ReviewOutcome>, neo4rs::Error> {
+    let status = if approve { AnnotationStatus::Approved } else { AnnotationStatus::Rejected };
+    let q = query(&format!(
+        "MATCH (a:Annotation {{id: $id, status: 'pending'}})
+         SET a.status = $status, a.reviewed_at = datetime(), a.review_note = $note
+         RETURN {ANNOTATION_FIELDS}"
+    ))
+    .param("id", id.to_string())
+    .param("status", status.as_str())
+    .param("note", note.unwrap_or(""));
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(annotation) = stream.next().await?.as_ref().and_then(row_to_annotation) else {
+        return Ok(None);
+    };
+
+    let mut reputation = None;
+    let mut reverification_requested = false;
+    if let Some(author) = contributor(client, &annotation.author_id).await? {
+        let updated = author.reviewed(approve);
+        client
+            .graph
+            .run(
+                query("MATCH (c:Contributor {member_id: $member_id}) SET c.reputation = $reputation")
+                    .param("member_id", author.member_id.as_str())
+                    .param("reputation", updated),
+            )
+            .await?;
+        reputation = Some(updated);
+        // Trust is judged on standing before this review, so one approval
+        // can't both earn it and spend it.
+        if approve && author.reputation >= REVERIFY_REPUTATION {
+            request_reverification(client, annotation.signal_id).await?;
+            reverification_requested = true;
+        }
+    }
+
+    info!(annotation = %id, status = status.as_str(), reverification_requested, "Annotation reviewed");
+    Ok(Some(ReviewOutcome {
+        annotation,
+        reputation,
+        reverification_requested,
+    }))
+}
+
+/// Flag a signal for re-verification and make its source due, so the next
+/// scout run re-scrapes it and confirms or ages out the signal.
+pub async fn request_reverification(client: &GraphClient, signal_id: Uuid) -> Result<(), neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (n {{id: $id}}) WHERE {SIGNAL_LABELS}
+         SET n.reverify_requested_at = datetime()
+         WITH n
+         MATCH (src:Source {{active: true}})
+         WHERE src.url = n.source_url
+         SET src.last_scraped = null"
+    ))
+    .param("id", signal_id.to_string());
+
+    client.graph.run(q).await
+}
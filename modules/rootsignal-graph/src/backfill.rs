@@ -0,0 +1,143 @@
+//! Source backfills.
+//!
+//! One `Backfill` node per source, keyed by canonical key and linked
+//! `-[:BACKFILLS]->` its source, so a source is backfilled at most once. Its
+//! status walks requested → completed | failed; a failed backfill may be
+//! requested again. Completing one marks the signals its scout run created
+//! `n.historical = true`.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+
+use rootsignal_common::{Backfill, BackfillDepth, BackfillStatus};
+
+use crate::GraphClient;
+
+const BACKFILL_FIELDS: &str = "b.canonical_key AS canonical_key, b.region AS region,
+     b.max_items AS max_items, b.max_age_days AS max_age_days, b.status AS status,
+     toString(b.requested_at) AS requested_at, toString(b.completed_at) AS completed_at,
+     b.signals_stored AS signals_stored, b.error AS error";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_backfill(row: &Row) -> Option<Backfill> {
+    Some(Backfill {
+        canonical_key: row.get("canonical_key").ok()?,
+        region: row.get("region").unwrap_or_default(),
+        depth: BackfillDepth::new(
+            row.get::<i64>("max_items").unwrap_or_default() as u32,
+            row.get::<i64>("max_age_days").unwrap_or_default() as u32,
+        ),
+        status: BackfillStatus::parse(&row.get::<String>("status").unwrap_or_default())?,
+        requested_at: parse_time(row, "requested_at")?,
+        completed_at: parse_time(row, "completed_at"),
+        signals_stored: row.get::<i64>("signals_stored").unwrap_or(0) as u32,
+        error: row.get::<String>("error").ok().filter(|e| !e.is_empty()),
+    })
+}
+
+/// Request a backfill of the source for `region`. Returns false when the
+/// source doesn't exist or already has a requested or completed backfill.
+pub async fn request_backfill(
+    client: &GraphClient,
+    region: &str,
+    canonical_key: &str,
+    depth: BackfillDepth,
+) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:Source {canonical_key: $canonical_key})
+         MERGE (b:Backfill {canonical_key: $canonical_key})
+         ON CREATE SET b.status = 'new'
+         WITH s, b, b.status IN ['new', 'failed'] AS allowed
+         FOREACH (_ IN CASE WHEN allowed THEN [1] ELSE [] END |
+             SET b.region = $region, b.status = 'requested', b.requested_at = datetime(),
+                 b.completed_at = null, b.max_items = $max_items, b.max_age_days = $max_age_days,
+                 b.signals_stored = 0, b.error = null
+             MERGE (b)-[:BACKFILLS]->(s))
+         RETURN allowed",
+    )
+    .param("canonical_key", canonical_key)
+    .param("region", region)
+    .param("max_items", depth.max_items as i64)
+    .param("max_age_days", depth.max_age_days as i64);
+
+    let mut stream = client.graph.execute(q).await?;
+    let requested = match stream.next().await? {
+        Some(row) => row.get::<bool>("allowed").unwrap_or(false),
+        None => false,
+    };
+    if requested {
+        info!(region, canonical_key, max_items = depth.max_items, "Backfill requested");
+    }
+    Ok(requested)
+}
+
+/// The region's backfills, newest request first; only those with `status`
+/// when given.
+pub async fn backfills(
+    client: &GraphClient,
+    region: &str,
+    status: Option<BackfillStatus>,
+) -> Result<Vec<Backfill>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (b:Backfill {{region: $region}})
+         WHERE $status = '' OR b.status = $status
+         RETURN {BACKFILL_FIELDS}
+         ORDER BY b.requested_at DESC"
+    ))
+    .param("region", region)
+    .param("status", status.map_or("", |s| s.as_str()));
+
+    let mut stream = client.graph.execute(q).await?;
+    let mut backfills = Vec::new();
+    while let Some(row) = stream.next().await? {
+        if let Some(backfill) = row_to_backfill(&row) {
+            backfills.push(backfill);
+        }
+    }
+    Ok(backfills)
+}
+
+/// Record a backfill's outcome. On success, every signal created by the
+/// backfill's scout run is marked historical. Returns the signals marked.
+pub async fn complete_backfill(
+    client: &GraphClient,
+    canonical_key: &str,
+    scout_run_id: &str,
+    error: Option<&str>,
+) -> Result<u32, neo4rs::Error> {
+    let mut marked = 0u32;
+    if error.is_none() {
+        let q = query(
+            "MATCH (n)
+             WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+               AND n.scout_run_id = $run_id
+             SET n.historical = true
+             RETURN count(n) AS marked",
+        )
+        .param("run_id", scout_run_id);
+        let mut stream = client.graph.execute(q).await?;
+        if let Some(row) = stream.next().await? {
+            marked = row.get::<i64>("marked").unwrap_or(0) as u32;
+        }
+    }
+
+    let status = if error.is_some() { BackfillStatus::Failed } else { BackfillStatus::Completed };
+    let q = query(
+        "MATCH (b:Backfill {canonical_key: $canonical_key})
+         SET b.status = $status, b.completed_at = datetime(),
+             b.signals_stored = $signals_stored, b.error = $error",
+    )
+    .param("canonical_key", canonical_key)
+    .param("status", status.as_str())
+    .param("signals_stored", marked as i64)
+    .param("error", error.unwrap_or_default());
+    client.graph.run(q).await?;
+
+    info!(canonical_key, status = status.as_str(), signals = marked, "Backfill finished");
+    Ok(marked)
+}
@@ -78,6 +78,7 @@ pub async fn detect_beacons(
          WHERE (s:Gathering OR s:Aid OR s:Need OR s:Notice OR s:Tension)
            AND s.lat IS NOT NULL AND s.lng IS NOT NULL
            AND s.extracted_at > datetime() - duration('P7D')
+           AND NOT coalesce(s.historical, false)
            AND s.review_status = 'live'
          RETURN s.lat AS lat, s.lng AS lng, s.title AS title, s.location_name AS location_name",
     );
@@ -147,6 +148,7 @@ pub async fn detect_beacons(
             phase_status: "idle".to_string(),
             created_at: chrono::Utc::now(),
             completed_at: None,
+            boundary: None,
         };
 
         writer.upsert_scout_task(&task).await?;
@@ -240,6 +242,7 @@ pub async fn create_beacons_from_news(
             phase_status: "idle".to_string(),
             created_at: chrono::Utc::now(),
             completed_at: None,
+            boundary: None,
         };
 
         writer.upsert_scout_task(&task).await?;
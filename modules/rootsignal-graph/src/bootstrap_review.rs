@@ -0,0 +1,140 @@
+//! Bootstrap candidates awaiting review.
+//!
+//! Each source a cold-start bootstrap proposes gets a `BootstrapCandidate`
+//! node keyed by region and canonical key, linked `-[:PROPOSES]->` its
+//! source. Its status walks pending → approved | rejected; approving makes
+//! the source active. Auto-approved candidates are recorded as approved.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+
+use rootsignal_common::{BootstrapCandidate, CandidateScore, ReviewStatus, SourceRole};
+
+use crate::GraphClient;
+
+const CANDIDATE_FIELDS: &str = "c.region AS region, c.canonical_key AS canonical_key,
+     c.canonical_value AS canonical_value, c.url AS url, c.source_role AS source_role,
+     c.domain_reputation AS domain_reputation, c.locality AS locality, c.content AS content,
+     c.status AS status, c.sample_excerpt AS sample_excerpt,
+     toString(c.created_at) AS created_at, toString(c.reviewed_at) AS reviewed_at";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_candidate(row: &Row) -> Option<BootstrapCandidate> {
+    Some(BootstrapCandidate {
+        region: row.get("region").ok()?,
+        canonical_key: row.get("canonical_key").ok()?,
+        canonical_value: row.get("canonical_value").unwrap_or_default(),
+        url: row.get::<String>("url").ok().filter(|u| !u.is_empty()),
+        source_role: SourceRole::from_str_loose(&row.get::<String>("source_role").unwrap_or_default()),
+        score: CandidateScore {
+            domain_reputation: row.get("domain_reputation").unwrap_or(0.0),
+            locality: row.get("locality").unwrap_or(0.0),
+            content: row.get("content").ok(),
+        },
+        status: ReviewStatus::parse(&row.get::<String>("status").unwrap_or_default())?,
+        sample_excerpt: row.get::<String>("sample_excerpt").ok().filter(|s| !s.is_empty()),
+        created_at: parse_time(row, "created_at")?,
+        reviewed_at: parse_time(row, "reviewed_at"),
+    })
+}
+
+/// Record a candidate and link it to its source (which must already exist).
+/// A candidate proposed again keeps its review.
+pub async fn record_candidate(client: &GraphClient, candidate: &BootstrapCandidate) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (c:BootstrapCandidate {region: $region, canonical_key: $canonical_key})
+         ON CREATE SET c.status = $status, c.created_at = datetime($created_at),
+                       c.reviewed_at = CASE WHEN $status = 'pending' THEN null ELSE datetime($created_at) END
+         SET c.canonical_value = $canonical_value,
+             c.url = $url,
+             c.source_role = $source_role,
+             c.domain_reputation = $domain_reputation,
+             c.locality = $locality,
+             c.content = $content,
+             c.score = $score,
+             c.sample_excerpt = $sample_excerpt
+         WITH c
+         MATCH (s:Source {canonical_key: $canonical_key})
+         MERGE (c)-[:PROPOSES]->(s)",
+    )
+    .param("region", candidate.region.as_str())
+    .param("canonical_key", candidate.canonical_key.as_str())
+    .param("status", candidate.status.as_str())
+    .param("created_at", candidate.created_at.to_rfc3339())
+    .param("canonical_value", candidate.canonical_value.as_str())
+    .param("url", candidate.url.clone().unwrap_or_default())
+    .param("source_role", candidate.source_role.to_string())
+    .param("domain_reputation", candidate.score.domain_reputation)
+    .param("locality", candidate.score.locality)
+    .param::<Option<f64>>("content", candidate.score.content)
+    .param("score", candidate.score.total())
+    .param("sample_excerpt", candidate.sample_excerpt.clone().unwrap_or_default());
+
+    client.graph.run(q).await
+}
+
+/// The region's candidates, best-scoring first; only those with `status`
+/// when given.
+pub async fn bootstrap_candidates(
+    client: &GraphClient,
+    region: &str,
+    status: Option<ReviewStatus>,
+) -> Result<Vec<BootstrapCandidate>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (c:BootstrapCandidate {{region: $region}})
+         WHERE $status = '' OR c.status = $status
+         RETURN {CANDIDATE_FIELDS}
+         ORDER BY c.score DESC, c.canonical_key"
+    ))
+    .param("region", region)
+    .param("status", status.map_or("", |s| s.as_str()));
+
+    let mut stream = client.graph.execute(q).await?;
+    let mut candidates = Vec::new();
+    while let Some(row) = stream.next().await? {
+        if let Some(candidate) = row_to_candidate(&row) {
+            candidates.push(candidate);
+        }
+    }
+    Ok(candidates)
+}
+
+/// Approve or reject the region's pending candidates: those in
+/// `canonical_keys`, or all of them when None. Approving activates the
+/// source; rejecting leaves it as it is, since another region may use it.
+/// Returns the number reviewed.
+pub async fn review_candidates(
+    client: &GraphClient,
+    region: &str,
+    canonical_keys: Option<&[String]>,
+    approve: bool,
+) -> Result<u32, neo4rs::Error> {
+    let status = if approve { ReviewStatus::Approved } else { ReviewStatus::Rejected };
+    let q = query(
+        "MATCH (c:BootstrapCandidate {region: $region, status: 'pending'})
+         WHERE $all OR c.canonical_key IN $keys
+         SET c.status = $status, c.reviewed_at = datetime()
+         WITH c
+         OPTIONAL MATCH (c)-[:PROPOSES]->(s:Source)
+         FOREACH (_ IN CASE WHEN $approve AND s IS NOT NULL THEN [1] ELSE [] END | SET s.active = true)
+         RETURN count(DISTINCT c) AS reviewed",
+    )
+    .param("region", region)
+    .param("all", canonical_keys.is_none())
+    .param("keys", canonical_keys.map(<[String]>::to_vec).unwrap_or_default())
+    .param("status", status.as_str())
+    .param("approve", approve);
+
+    let mut stream = client.graph.execute(q).await?;
+    let reviewed = match stream.next().await? {
+        Some(row) => row.get::<i64>("reviewed").unwrap_or(0) as u32,
+        None => 0,
+    };
+    info!(region, reviewed, status = status.as_str(), "Bootstrap candidates reviewed");
+    Ok(reviewed)
+}
@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use arc_swap::ArcSwap;
 use chrono::{DateTime, Utc};
 use neo4rs::query;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use rootsignal_common::{
@@ -16,7 +16,10 @@ use rootsignal_common::{
 use crate::reader::{
     extract_evidence, fuzz_node, node_type_label, row_to_actor, row_to_node_by_label, row_to_story,
 };
-use crate::GraphClient;
+use crate::room_feeds::RoomFeedSink;
+use crate::situation_feed::SituationNotifier;
+use crate::watchlist::WatchlistNotifier;
+use crate::{GraphClient, GraphRouter};
 
 /// In-memory snapshot of all displayable signals, stories, actors, and relationships.
 /// Signals are pre-fuzzed at load time. Expiry filtering is NOT pre-applied — it runs
@@ -48,18 +51,52 @@ pub struct SignalCache {
 
 impl SignalCache {
     pub async fn load(client: &GraphClient) -> Result<Self, neo4rs::Error> {
+        Self::load_all(std::slice::from_ref(client)).await
+    }
+
+    /// Load one snapshot spanning several databases, e.g. every region
+    /// database of a `GraphRouter`.
+    pub async fn load_all(clients: &[GraphClient]) -> Result<Self, neo4rs::Error> {
         let start = std::time::Instant::now();
 
-        // Load signals, stories, and actors concurrently
-        let (signals_result, stories_result, actors_result) = tokio::join!(
-            load_all_signals(client),
-            load_all_stories(client),
-            load_all_actors(client),
-        );
+        let mut signals = Vec::new();
+        let mut stories = Vec::new();
+        let mut actors = Vec::new();
+        let mut tags = Vec::new();
+        let mut evidence_by_signal = HashMap::new();
+        let mut actor_signal_edges = Vec::new();
+        let mut story_signal_edges = Vec::new();
+        let mut tension_responses = HashMap::new();
+        let mut story_tag_edges = Vec::new();
+        let mut situation_tag_edges = Vec::new();
+        for client in clients {
+            // Load signals, stories, and actors concurrently
+            let (signals_result, stories_result, actors_result) = tokio::join!(
+                load_all_signals(client),
+                load_all_stories(client),
+                load_all_actors(client),
+            );
+            signals.extend(signals_result?);
+            stories.extend(stories_result?);
+            actors.extend(actors_result?);
+            tags.extend(load_all_tags(client).await?);
 
-        let mut signals = signals_result?;
-        let stories = stories_result?;
-        let actors = actors_result?;
+            // Load relationships concurrently
+            let (evidence, actor_signal, story_signal, tension_resp, story_tag, situation_tag) = tokio::join!(
+                load_evidence(client),
+                load_actor_signal_edges(client),
+                load_story_signal_edges(client),
+                load_tension_responses(client),
+                load_story_tag_edges(client),
+                load_situation_tag_edges(client),
+            );
+            evidence_by_signal.extend(evidence?);
+            actor_signal_edges.extend(actor_signal?);
+            story_signal_edges.extend(story_signal?);
+            tension_responses.extend(tension_resp?);
+            story_tag_edges.extend(story_tag?);
+            situation_tag_edges.extend(situation_tag?);
+        }
 
         // Apply coordinate fuzzing at load time
         for signal in &mut signals {
@@ -85,29 +122,13 @@ impl SignalCache {
             .map(|(i, a)| (a.id, i))
             .collect();
 
-        // Load tags
-        let tags = load_all_tags(client).await?;
         let tag_by_id: HashMap<Uuid, usize> = tags
             .iter()
             .enumerate()
             .map(|(i, t)| (t.id, i))
             .collect();
 
-        // Load relationships concurrently
-        let (evidence_result, actor_signal_result, story_signal_result, tension_resp_result, story_tag_result, situation_tag_result) =
-            tokio::join!(
-                load_evidence(client),
-                load_actor_signal_edges(client),
-                load_story_signal_edges(client),
-                load_tension_responses(client),
-                load_story_tag_edges(client),
-                load_situation_tag_edges(client),
-            );
-
-        let evidence_by_signal = evidence_result?;
-
         // Build actors_by_signal map (signal_id -> vec of actor indices)
-        let actor_signal_edges = actor_signal_result?;
         let mut actors_by_signal: HashMap<Uuid, Vec<usize>> = HashMap::new();
         for (signal_id, actor_id) in &actor_signal_edges {
             if let Some(&actor_idx) = actor_by_id.get(actor_id) {
@@ -119,7 +140,6 @@ impl SignalCache {
         }
 
         // Build story<->signal maps
-        let story_signal_edges = story_signal_result?;
         let mut story_by_signal: HashMap<Uuid, usize> = HashMap::new();
         let mut signals_by_story: HashMap<Uuid, Vec<usize>> = HashMap::new();
         for (story_id, signal_id) in &story_signal_edges {
@@ -163,10 +183,7 @@ impl SignalCache {
             actors_for_story.insert(*story_id, actor_set);
         }
 
-        let tension_responses = tension_resp_result?;
-
         // Build tags_by_story map (story_id -> vec of tag indices)
-        let story_tag_edges = story_tag_result?;
         let mut tags_by_story: HashMap<Uuid, Vec<usize>> = HashMap::new();
         for (story_id, tag_id) in &story_tag_edges {
             if let Some(&tag_idx) = tag_by_id.get(tag_id) {
@@ -178,7 +195,6 @@ impl SignalCache {
         }
 
         // Build tags_by_situation map (situation_id -> vec of tag indices)
-        let situation_tag_edges = situation_tag_result?;
         let mut tags_by_situation: HashMap<Uuid, Vec<usize>> = HashMap::new();
         for (situation_id, tag_id) in &situation_tag_edges {
             if let Some(&tag_idx) = tag_by_id.get(tag_id) {
@@ -228,6 +244,12 @@ impl SignalCache {
 pub struct CacheStore {
     inner: ArcSwap<SignalCache>,
     reloading: AtomicBool,
+    /// When set, signals new in each reload are matched against saved searches.
+    watchlist_notifier: OnceLock<Arc<dyn WatchlistNotifier>>,
+    /// When set, situation milestones are sent to subscribers on every reload.
+    situation_notifier: OnceLock<Arc<dyn SituationNotifier>>,
+    /// When set, signals new in each reload are queued for room feeds.
+    room_feed_sink: OnceLock<Arc<dyn RoomFeedSink>>,
 }
 
 impl CacheStore {
@@ -236,6 +258,39 @@ impl CacheStore {
         Self {
             inner: ArcSwap::new(Arc::new(initial)),
             reloading: AtomicBool::new(false),
+            watchlist_notifier: OnceLock::new(),
+            situation_notifier: OnceLock::new(),
+            room_feed_sink: OnceLock::new(),
+        }
+    }
+
+    /// Match new signals against saved searches on every reload, sending
+    /// matches through `notifier`. Only the first notifier set is used.
+    pub fn set_watchlist_notifier(&self, notifier: Arc<dyn WatchlistNotifier>) {
+        if self.watchlist_notifier.set(notifier).is_err() {
+            warn!("Watchlist notifier already set");
+        }
+    }
+
+    /// Send situation milestones to subscribers after every reload. Only the
+    /// first notifier set is used.
+    pub fn set_situation_notifier(&self, notifier: Arc<dyn SituationNotifier>) {
+        if self.situation_notifier.set(notifier).is_err() {
+            warn!("Situation notifier already set");
+        }
+    }
+
+    /// The notifier set with `set_situation_notifier`, for confirmation
+    /// emails sent outside a reload.
+    pub fn situation_notifier(&self) -> Option<Arc<dyn SituationNotifier>> {
+        self.situation_notifier.get().cloned()
+    }
+
+    /// Queue new signals for matching room feeds on every reload. Only the
+    /// first sink set is used.
+    pub fn set_room_feed_sink(&self, sink: Arc<dyn RoomFeedSink>) {
+        if self.room_feed_sink.set(sink).is_err() {
+            warn!("Room feed sink already set");
         }
     }
 
@@ -245,8 +300,9 @@ impl CacheStore {
         self.inner.load_full()
     }
 
-    /// Reload the cache from Neo4j. Only one reload runs at a time.
-    pub async fn reload(&self, client: &GraphClient) {
+    /// Reload the cache from every database `router` uses. Only one reload
+    /// runs at a time.
+    pub async fn reload(&self, router: &GraphRouter) {
         if self
             .reloading
             .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
@@ -257,10 +313,37 @@ impl CacheStore {
         }
 
         info!("Reloading signal cache from Neo4j");
-        match SignalCache::load(client).await {
+        let client = router.default_client();
+        let loaded = match router.clients().await {
+            Ok(clients) => SignalCache::load_all(&clients).await,
+            Err(e) => Err(e),
+        };
+        match loaded {
             Ok(new_cache) => {
-                self.inner.store(Arc::new(new_cache));
+                let new_cache = Arc::new(new_cache);
+                let previous = self.inner.swap(Arc::clone(&new_cache));
                 info!("Signal cache reloaded successfully");
+                if let Some(notifier) = self.watchlist_notifier.get() {
+                    if let Err(e) =
+                        crate::watchlist::match_new_signals(client, &previous, &new_cache, notifier.as_ref()).await
+                    {
+                        error!(error = %e, "Failed to match new signals against watchlists");
+                    }
+                }
+                if let Some(sink) = self.room_feed_sink.get() {
+                    if let Err(e) =
+                        crate::room_feeds::queue_new_signals(client, &previous, &new_cache, sink.as_ref()).await
+                    {
+                        error!(error = %e, "Failed to queue new signals for room feeds");
+                    }
+                }
+                if let Some(notifier) = self.situation_notifier.get() {
+                    for client in router.clients().await.unwrap_or_default() {
+                        if let Err(e) = crate::situation_feed::notify_pending_changes(&client, notifier.as_ref()).await {
+                            error!(error = %e, database = client.database(), "Failed to notify situation subscribers");
+                        }
+                    }
+                }
             }
             Err(e) => {
                 error!(error = %e, "Failed to reload signal cache, keeping stale data");
@@ -271,7 +354,7 @@ impl CacheStore {
     }
 
     /// Spawn a background loop that reloads the cache on a timer.
-    pub fn spawn_reload_loop(self: &Arc<Self>, client: GraphClient) {
+    pub fn spawn_reload_loop(self: &Arc<Self>, router: GraphRouter) {
         let hours: u64 = std::env::var("CACHE_RELOAD_HOURS")
             .ok()
             .and_then(|v| v.parse().ok())
@@ -282,7 +365,7 @@ impl CacheStore {
             let interval = std::time::Duration::from_secs(hours * 3600);
             loop {
                 tokio::time::sleep(interval).await;
-                store.reload(&client).await;
+                store.reload(&router).await;
             }
         });
 
@@ -4,11 +4,11 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use rootsignal_common::{
-    ActorNode, EvidenceNode, Node, NodeType, StoryNode, TagNode, TensionResponse,
+    ActorNode, AggregateGuard, BoundingBox, EvidenceNode, NewSince, Node, NodeType, StoryNode, TagNode, TensionResponse, Trend, TrendWindow,
 };
 
 use crate::cache::CacheStore;
-use crate::reader::passes_display_filter;
+use crate::reader::{passes_display_filter, DistrictSummary};
 use crate::PublicGraphReader;
 
 /// Read interface that serves public queries from an in-memory cache
@@ -75,12 +75,15 @@ impl CachedReader {
         Ok(results)
     }
 
+    /// Signals in the bounding box, hottest first. With `categories`, only
+    /// signals filed under one of them.
     pub async fn signals_in_bounds(
         &self,
         min_lat: f64,
         max_lat: f64,
         min_lng: f64,
         max_lng: f64,
+        categories: Option<&[String]>,
         limit: u32,
     ) -> Result<Vec<Node>, neo4rs::Error> {
         let snap = self.cache.load_full();
@@ -92,6 +95,11 @@ impl CachedReader {
                 if !passes_display_filter(n) {
                     return false;
                 }
+                if let Some(categories) = categories {
+                    if !n.category().is_some_and(|c| categories.iter().any(|k| k == c)) {
+                        return false;
+                    }
+                }
                 if let Some(loc) = n.meta().and_then(|m| m.about_location) {
                     loc.lat >= min_lat
                         && loc.lat <= max_lat
@@ -115,6 +123,26 @@ impl CachedReader {
         Ok(results)
     }
 
+    /// Signals in the bounding box extracted after `since`: per-type counts,
+    /// guarded by the region's aggregate guard, and the `limit` hottest.
+    pub async fn new_signals_in_bounds(
+        &self,
+        region_slug: &str,
+        bounds: BoundingBox,
+        since: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<NewSince, neo4rs::Error> {
+        let snap = self.cache.load_full();
+        let in_bounds = snap.signals.iter().filter(|n| {
+            passes_display_filter(n)
+                && n.meta()
+                    .and_then(|m| m.about_location)
+                    .is_some_and(|loc| bounds.contains(loc.lat, loc.lng))
+        });
+        let guard = self.neo4j_reader.aggregate_guard(region_slug).await?;
+        Ok(NewSince::collect(in_bounds, since, limit as usize, &guard))
+    }
+
     pub async fn stories_in_bounds(
         &self,
         min_lat: f64,
@@ -123,7 +151,7 @@ impl CachedReader {
         max_lng: f64,
         limit: u32,
     ) -> Result<Vec<StoryNode>, neo4rs::Error> {
-        self.stories_in_bounds_filtered(min_lat, max_lat, min_lng, max_lng, None, limit)
+        self.stories_in_bounds_filtered(min_lat, max_lat, min_lng, max_lng, None, None, limit)
             .await
     }
 
@@ -134,6 +162,7 @@ impl CachedReader {
         min_lng: f64,
         max_lng: f64,
         tag: Option<&str>,
+        categories: Option<&[String]>,
         limit: u32,
     ) -> Result<Vec<StoryNode>, neo4rs::Error> {
         let snap = self.cache.load_full();
@@ -166,6 +195,7 @@ impl CachedReader {
                     .map(|f| f.contains(&s.id))
                     .unwrap_or(true)
             })
+            .filter(|s| categories.is_none_or(|c| s.category.as_ref().is_some_and(|sc| c.contains(sc))))
             .cloned()
             .collect();
 
@@ -399,9 +429,10 @@ impl CachedReader {
         Ok(results)
     }
 
+    /// Stories filed under any of `categories`, by energy.
     pub async fn stories_by_category(
         &self,
-        category: &str,
+        categories: &[String],
         limit: u32,
     ) -> Result<Vec<StoryNode>, neo4rs::Error> {
         let snap = self.cache.load_full();
@@ -409,7 +440,7 @@ impl CachedReader {
         let mut results: Vec<StoryNode> = snap
             .stories
             .iter()
-            .filter(|s| s.category.as_deref() == Some(category))
+            .filter(|s| s.category.as_ref().is_some_and(|c| categories.contains(c)))
             .cloned()
             .collect();
 
@@ -751,6 +782,49 @@ impl CachedReader {
             .await
     }
 
+    // --- Registry queries (delegate to Neo4j — entity links are not in cache) ---
+
+    pub async fn signals_for_entity(&self, entity_id: &str, limit: u32) -> Result<Vec<Node>, neo4rs::Error> {
+        self.neo4j_reader.signals_for_entity(entity_id, limit).await
+    }
+
+    pub async fn signals_in_district(
+        &self,
+        entity_id: &str,
+        node_types: Option<&[NodeType]>,
+        limit: u32,
+    ) -> Result<Vec<Node>, neo4rs::Error> {
+        self.neo4j_reader.signals_in_district(entity_id, node_types, limit).await
+    }
+
+    pub async fn stories_in_district(
+        &self,
+        entity_id: &str,
+        status_filter: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<StoryNode>, neo4rs::Error> {
+        self.neo4j_reader.stories_in_district(entity_id, status_filter, limit).await
+    }
+
+    pub async fn district_summary(&self, entity_id: &str) -> Result<DistrictSummary, neo4rs::Error> {
+        self.neo4j_reader.district_summary(entity_id).await
+    }
+
+    pub async fn aggregate_guard(&self, region_slug: &str) -> Result<AggregateGuard, neo4rs::Error> {
+        self.neo4j_reader.aggregate_guard(region_slug).await
+    }
+
+    // --- Trend queries (delegate to Neo4j — trends are not in cache) ---
+
+    pub async fn trends(
+        &self,
+        region_slug: &str,
+        window: TrendWindow,
+        limit: u32,
+    ) -> Result<Vec<Trend>, neo4rs::Error> {
+        self.neo4j_reader.trends(region_slug, window, limit).await
+    }
+
     // --- Resource queries (delegate to Neo4j — involve Resource nodes not in cache) ---
 
     pub async fn find_needs_by_resource(
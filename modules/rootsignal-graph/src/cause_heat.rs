@@ -1,4 +1,5 @@
 use neo4rs::query;
+use rootsignal_common::ScoutScope;
 use tracing::info;
 
 use crate::GraphClient;
@@ -12,7 +13,7 @@ struct SignalEmbed {
     channel_diversity: u32,
 }
 
-/// Compute cause_heat for signals within a geographic bounding box.
+/// Compute cause_heat for signals within a scout scope.
 ///
 /// Cause heat measures how much independent community attention exists in a
 /// signal's semantic neighborhood. A food shelf Need near a hot housing cluster
@@ -20,7 +21,8 @@ struct SignalEmbed {
 /// has genuine multi-source attention.
 ///
 /// Algorithm:
-/// 1. Load signals with embeddings and source_diversity within the bbox
+/// 1. Load signals with embeddings and source_diversity within the scope
+///    (its bounding box in the query, then its boundary, if it has one)
 /// 2. Compute all-pairs cosine similarity in memory
 /// 3. For each signal, sum (similarity × neighbor.source_diversity) for Tension
 ///    neighbors above threshold. Only Tensions radiate heat — Gatherings, Gives, Needs,
@@ -30,16 +32,14 @@ struct SignalEmbed {
 pub async fn compute_cause_heat(
     client: &GraphClient,
     threshold: f64,
-    min_lat: f64,
-    max_lat: f64,
-    min_lng: f64,
-    max_lng: f64,
+    scope: &ScoutScope,
 ) -> Result<(), neo4rs::Error> {
     let g = &client.graph;
+    let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
 
     info!(threshold, "Computing cause heat...");
 
-    // 1. Load signals with embeddings within the bounding box
+    // 1. Load signals with embeddings within the scope
     let mut signals: Vec<SignalEmbed> = Vec::new();
 
     for label in &["Gathering", "Aid", "Need", "Notice", "Tension"] {
@@ -48,7 +48,7 @@ pub async fn compute_cause_heat(
              WHERE n.embedding IS NOT NULL
                AND n.lat >= $min_lat AND n.lat <= $max_lat
                AND n.lng >= $min_lng AND n.lng <= $max_lng
-             RETURN n.id AS id, n.embedding AS embedding,
+             RETURN n.id AS id, n.lat AS lat, n.lng AS lng, n.embedding AS embedding,
                     n.source_diversity AS source_diversity,
                     n.channel_diversity AS channel_diversity"
         ))
@@ -59,6 +59,9 @@ pub async fn compute_cause_heat(
 
         let mut stream = g.execute(q).await?;
         while let Some(row) = stream.next().await? {
+            if !scope.contains(row.get("lat").unwrap_or_default(), row.get("lng").unwrap_or_default()) {
+                continue;
+            }
             let id: String = row.get("id").unwrap_or_default();
             let embedding: Vec<f64> = row.get("embedding").unwrap_or_default();
             let source_diversity: i64 = row.get("source_diversity").unwrap_or(1);
@@ -575,8 +578,15 @@ mod tests {
             .await
             .expect("Failed to connect to Neo4j");
 
-        // Use Twin Cities bbox for live test
-        compute_cause_heat(&client, 0.7, 44.0, 46.0, -94.0, -92.0)
+        // Use a Twin Cities scope for live test
+        let scope = ScoutScope {
+            center_lat: 45.0,
+            center_lng: -93.0,
+            radius_km: 111.0,
+            name: "Twin Cities".to_string(),
+            boundary: None,
+        };
+        compute_cause_heat(&client, 0.7, &scope)
             .await
             .expect("compute_cause_heat failed");
 
@@ -1,29 +1,59 @@
-use neo4rs::{ConfigBuilder, Graph};
+use neo4rs::{query, ConfigBuilder, Graph};
 
 /// Thin wrapper around neo4rs::Graph providing connection setup.
+///
+/// A client is bound to one database for its lifetime. Tenants each get a
+/// client on their own database, which is what keeps their graphs apart.
 #[derive(Clone)]
 pub struct GraphClient {
     pub(crate) graph: Graph,
+    database: String,
 }
 
 impl GraphClient {
     /// Connect to the graph database (Neo4j) with the given credentials.
     pub async fn connect(uri: &str, user: &str, password: &str) -> Result<Self, neo4rs::Error> {
+        Self::connect_database(uri, user, password, "neo4j").await
+    }
+
+    /// Connect to a specific database, e.g. a tenant's.
+    pub async fn connect_database(
+        uri: &str,
+        user: &str,
+        password: &str,
+        database: &str,
+    ) -> Result<Self, neo4rs::Error> {
         let config = ConfigBuilder::default()
             .uri(uri)
             .user(user)
             .password(password)
-            .db("neo4j")
+            .db(database)
             .fetch_size(500)
             .max_connections(10)
             .build()
             .unwrap();
         let graph = Graph::connect(config).await?;
-        Ok(Self { graph })
+        Ok(Self {
+            graph,
+            database: database.to_string(),
+        })
     }
 
     /// Get a reference to the underlying neo4rs Graph.
     pub fn inner(&self) -> &Graph {
         &self.graph
     }
+
+    /// The database every query from this client runs against.
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    /// Create a database if it doesn't exist yet, waiting until it is online.
+    /// Needs a server edition with multiple databases.
+    pub async fn create_database(&self, name: &str) -> Result<(), neo4rs::Error> {
+        self.graph
+            .run_on("system", query("CREATE DATABASE $name IF NOT EXISTS WAIT").param("name", name))
+            .await
+    }
 }
@@ -0,0 +1,146 @@
+//! Correction requests in the graph.
+//!
+//! Each request is a `CorrectionRequest` node linked `-[:CORRECTS]->` its
+//! signal. Admins triage open requests; "outdated" requests send the signal
+//! for re-verification on arrival, at most once a day per signal.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::{CorrectionKind, CorrectionRequest, CorrectionStatus};
+
+use crate::GraphClient;
+
+const SIGNAL_LABELS: &str = "(n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)";
+
+const CORRECTION_FIELDS: &str = "c.id AS id, c.signal_id AS signal_id, c.kind AS kind, c.details AS details,
+     c.duplicate_of AS duplicate_of, c.status AS status, toString(c.created_at) AS created_at,
+     toString(c.resolved_at) AS resolved_at, c.resolution_note AS resolution_note";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_correction(row: &Row) -> Option<CorrectionRequest> {
+    let optional = |key: &str| row.get::<String>(key).ok().filter(|s| !s.is_empty());
+    Some(CorrectionRequest {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        signal_id: Uuid::parse_str(&row.get::<String>("signal_id").ok()?).ok()?,
+        kind: CorrectionKind::parse(&row.get::<String>("kind").ok()?)?,
+        details: optional("details"),
+        duplicate_of: optional("duplicate_of").and_then(|id| Uuid::parse_str(&id).ok()),
+        status: CorrectionStatus::parse(&row.get::<String>("status").ok()?)?,
+        created_at: parse_time(row, "created_at")?,
+        resolved_at: parse_time(row, "resolved_at"),
+        resolution_note: optional("resolution_note"),
+    })
+}
+
+/// Store a correction request. Returns false if the signal doesn't exist.
+/// An "outdated" request also requests re-verification of the signal unless
+/// one was requested in the last day.
+pub async fn submit_correction(client: &GraphClient, correction: &CorrectionRequest) -> Result<bool, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (n {{id: $signal_id}}) WHERE {SIGNAL_LABELS}
+         CREATE (c:CorrectionRequest {{
+             id: $id, signal_id: $signal_id, kind: $kind, details: $details,
+             duplicate_of: $duplicate_of, status: $status, created_at: datetime($created_at)
+         }})-[:CORRECTS]->(n)
+         RETURN count(c) AS created,
+                n.reverify_requested_at IS NOT NULL
+                    AND n.reverify_requested_at > datetime() - duration('P1D') AS recently_reverified"
+    ))
+    .param("id", correction.id.to_string())
+    .param("signal_id", correction.signal_id.to_string())
+    .param("kind", correction.kind.as_str())
+    .param("details", correction.details.as_deref().unwrap_or(""))
+    .param("duplicate_of", correction.duplicate_of.map(|id| id.to_string()).unwrap_or_default())
+    .param("status", correction.status.as_str())
+    .param("created_at", correction.created_at.to_rfc3339());
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(false);
+    };
+    if row.get::<i64>("created").unwrap_or(0) == 0 {
+        return Ok(false);
+    }
+
+    if correction.kind.reinvestigates() && !row.get::<bool>("recently_reverified").unwrap_or(false) {
+        crate::annotations::request_reverification(client, correction.signal_id).await?;
+        info!(signal = %correction.signal_id, "Outdated report sent signal for re-verification");
+    }
+    Ok(true)
+}
+
+/// Open requests for triage: harmful reports first, then by kind, oldest
+/// first within a kind.
+pub async fn open_corrections(client: &GraphClient, limit: u32) -> Result<Vec<CorrectionRequest>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (c:CorrectionRequest {{status: 'open'}})
+         RETURN {CORRECTION_FIELDS}
+         ORDER BY c.created_at"
+    ));
+
+    let mut corrections = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        corrections.extend(row_to_correction(&row));
+    }
+    corrections.sort_by_key(|c| c.kind.triage_rank());
+    corrections.truncate(limit as usize);
+    Ok(corrections)
+}
+
+/// Accept or dismiss an open request. None if there's no such open request.
+pub async fn resolve_correction(
+    client: &GraphClient,
+    id: Uuid,
+    accept: bool,
+    note: Option<&str>,
+) -> Result<Option<CorrectionRequest>, neo4rs::Error> {
+    let status = if accept { CorrectionStatus::Accepted } else { CorrectionStatus::Dismissed };
+    let q = query(&format!(
+        "MATCH (c:CorrectionRequest {{id: $id, status: 'open'}})
+         SET c.status = $status, c.resolved_at = datetime(), c.resolution_note = $note
+         RETURN {CORRECTION_FIELDS}"
+    ))
+    .param("id", id.to_string())
+    .param("status", status.as_str())
+    .param("note", note.unwrap_or(""));
+
+    let mut stream = client.graph.execute(q).await?;
+    let resolved = stream.next().await?.as_ref().and_then(row_to_correction);
+    if let Some(c) = &resolved {
+        info!(correction = %id, kind = c.kind.as_str(), status = status.as_str(), "Correction resolved");
+    }
+    Ok(resolved)
+}
+
+/// Correction requests per signal, newest first.
+pub async fn corrections_for_signals(
+    client: &GraphClient,
+    signal_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<CorrectionRequest>>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (c:CorrectionRequest)
+         WHERE c.signal_id IN $signal_ids
+         RETURN {CORRECTION_FIELDS}
+         ORDER BY c.created_at DESC"
+    ))
+    .param("signal_ids", signal_ids.iter().map(Uuid::to_string).collect::<Vec<_>>());
+
+    let mut by_signal: HashMap<Uuid, Vec<CorrectionRequest>> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(correction) = row_to_correction(&row) {
+            by_signal.entry(correction.signal_id).or_default().push(correction);
+        }
+    }
+    Ok(by_signal)
+}
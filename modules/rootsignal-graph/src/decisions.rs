@@ -0,0 +1,97 @@
+//! Decision records in the graph.
+//!
+//! Each record is a `Decision` node linked `<-[:HAS_DECISION]-` from its
+//! signal. The node also carries `subject_id`, so the timeline survives the
+//! signal being merged away or expired.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use uuid::Uuid;
+
+use rootsignal_common::decisions::MAX_DECISIONS_PER_SUBJECT;
+use rootsignal_common::{Decision, DecisionKind};
+
+use crate::reader::{label_to_node_type, node_type_label};
+use crate::GraphClient;
+
+const DECISION_FIELDS: &str = "d.id AS id, d.subject_id AS subject_id, d.subject_type AS subject_type,
+     d.kind AS kind, d.component AS component, d.inputs_hash AS inputs_hash, d.outcome AS outcome,
+     d.rationale AS rationale, d.run_id AS run_id, toString(d.decided_at) AS decided_at";
+
+fn row_to_decision(row: &Row) -> Option<Decision> {
+    let decided_at: String = row.get("decided_at").ok()?;
+    Some(Decision {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        subject_id: Uuid::parse_str(&row.get::<String>("subject_id").ok()?).ok()?,
+        subject_type: label_to_node_type(&row.get::<String>("subject_type").ok()?)?,
+        kind: DecisionKind::parse(&row.get::<String>("kind").ok()?)?,
+        component: row.get("component").unwrap_or_default(),
+        inputs_hash: row.get("inputs_hash").unwrap_or_default(),
+        outcome: row.get("outcome").unwrap_or_default(),
+        rationale: row.get("rationale").unwrap_or_default(),
+        run_id: row.get::<Option<String>>("run_id").ok().flatten(),
+        decided_at: DateTime::parse_from_rfc3339(&decided_at).ok()?.with_timezone(&Utc),
+    })
+}
+
+/// Store a decision, linked to its signal if the signal still exists, and
+/// drop the signal's records beyond `MAX_DECISIONS_PER_SUBJECT`.
+pub async fn record_decision(client: &GraphClient, decision: &Decision) -> Result<(), neo4rs::Error> {
+    let label = node_type_label(decision.subject_type);
+    let q = query(&format!(
+        "CREATE (d:Decision {{
+             id: $id, subject_id: $subject_id, subject_type: $subject_type, kind: $kind,
+             component: $component, inputs_hash: $inputs_hash, outcome: $outcome,
+             rationale: $rationale, run_id: $run_id, decided_at: datetime($decided_at)
+         }})
+         WITH d
+         OPTIONAL MATCH (n:{label} {{id: $subject_id}})
+         FOREACH (_ IN CASE WHEN n IS NULL THEN [] ELSE [1] END | CREATE (n)-[:HAS_DECISION]->(d))"
+    ))
+    .param("id", decision.id.to_string())
+    .param("subject_id", decision.subject_id.to_string())
+    .param("subject_type", label)
+    .param("kind", decision.kind.as_str())
+    .param("component", decision.component.as_str())
+    .param("inputs_hash", decision.inputs_hash.as_str())
+    .param("outcome", decision.outcome.as_str())
+    .param("rationale", decision.rationale.as_str())
+    .param("run_id", decision.run_id.clone())
+    .param("decided_at", decision.decided_at.to_rfc3339());
+    client.graph.run(q).await?;
+
+    let q = query(
+        "MATCH (d:Decision {subject_id: $subject_id})
+         WITH d ORDER BY d.decided_at DESC
+         SKIP $keep
+         DETACH DELETE d",
+    )
+    .param("subject_id", decision.subject_id.to_string())
+    .param("keep", MAX_DECISIONS_PER_SUBJECT as i64);
+    client.graph.run(q).await
+}
+
+/// A signal's decisions, newest first.
+pub async fn decisions_for_signal(
+    client: &GraphClient,
+    signal_id: Uuid,
+    limit: u32,
+) -> Result<Vec<Decision>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (d:Decision {{subject_id: $subject_id}})
+         RETURN {DECISION_FIELDS}
+         ORDER BY d.decided_at DESC
+         LIMIT $limit"
+    ))
+    .param("subject_id", signal_id.to_string())
+    .param("limit", limit as i64);
+
+    let mut decisions = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(decision) = row_to_decision(&row) {
+            decisions.push(decision);
+        }
+    }
+    Ok(decisions)
+}
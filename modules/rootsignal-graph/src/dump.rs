@@ -0,0 +1,401 @@
+//! Raw export of a region's situations and signals, for debugging and
+//! offline analysis. Dumps can be narrowed with a [`DumpFilter`] and
+//! compared with [`diff`] to see what a run added, changed or removed.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::query;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use rootsignal_common::{Node, NodeType, ScoutScope, SituationNode};
+
+use crate::reader::{node_type_label, row_to_node};
+use crate::{GraphClient, PublicGraphReader};
+
+const SIGNAL_TYPES: [NodeType; 5] = [
+    NodeType::Gathering,
+    NodeType::Aid,
+    NodeType::Need,
+    NodeType::Notice,
+    NodeType::Tension,
+];
+
+#[derive(Serialize, Deserialize)]
+pub struct RegionDump {
+    pub region: String,
+    pub situations: Vec<SituationDump>,
+    pub ungrouped_signals: Vec<Node>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SituationDump {
+    #[serde(flatten)]
+    pub situation: SituationNode,
+    pub signals: Vec<Node>,
+}
+
+/// Every situation in the region with its signals, plus the signals in the
+/// region that evidence no situation. The region is its bounding box, narrowed
+/// to its boundary when it has one.
+pub async fn dump_region(client: &GraphClient, scope: &ScoutScope) -> Result<RegionDump, neo4rs::Error> {
+    let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+
+    let reader = PublicGraphReader::new(client.clone());
+    let situation_nodes = reader
+        .situations_in_bounds(min_lat, max_lat, min_lng, max_lng, 500, None, None)
+        .await?;
+
+    let mut situations = Vec::new();
+
+    let centered_in_scope = |s: &SituationNode| match (s.centroid_lat, s.centroid_lng) {
+        (Some(lat), Some(lng)) => scope.contains(lat, lng),
+        _ => true,
+    };
+    for situation in situation_nodes.into_iter().filter(centered_in_scope) {
+        let mut signals = Vec::new();
+        for nt in SIGNAL_TYPES {
+            let label = node_type_label(nt);
+            let cypher = format!(
+                "MATCH (n:{label})-[:EVIDENCES]->(s:Situation {{id: $id}}) RETURN n ORDER BY n.confidence DESC"
+            );
+            let q = query(&cypher).param("id", situation.id.to_string());
+            let mut stream = client.inner().execute(q).await?;
+            while let Some(row) = stream.next().await? {
+                if let Some(node) = row_to_node(&row, nt) {
+                    signals.push(node);
+                }
+            }
+        }
+        situations.push(SituationDump { situation, signals });
+    }
+
+    let mut ungrouped = Vec::new();
+    for nt in SIGNAL_TYPES {
+        let label = node_type_label(nt);
+        let cypher = format!(
+            "MATCH (n:{label})
+             WHERE n.lat >= $min_lat AND n.lat <= $max_lat
+               AND n.lng >= $min_lng AND n.lng <= $max_lng
+               AND NOT (n)-[:EVIDENCES]->(:Situation)
+             RETURN n
+             ORDER BY n.confidence DESC"
+        );
+        let q = query(&cypher)
+            .param("min_lat", min_lat)
+            .param("max_lat", max_lat)
+            .param("min_lng", min_lng)
+            .param("max_lng", max_lng);
+        let mut stream = client.inner().execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            if let Some(node) = row_to_node(&row, nt) {
+                let located = node.meta().and_then(|m| m.about_location.as_ref());
+                if located.is_none_or(|p| scope.contains(p.lat, p.lng)) {
+                    ungrouped.push(node);
+                }
+            }
+        }
+    }
+
+    Ok(RegionDump {
+        region: scope.name.clone(),
+        situations,
+        ungrouped_signals: ungrouped,
+    })
+}
+
+/// Narrows a dump. Empty fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct DumpFilter {
+    pub node_types: Vec<NodeType>,
+    /// Matches a signal's category, or its situation's; case-insensitive.
+    pub category: Option<String>,
+    /// Signals extracted at or after this.
+    pub since: Option<DateTime<Utc>>,
+    /// Signals located in this box.
+    pub bbox: Option<Bbox>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bbox {
+    pub min_lat: f64,
+    pub min_lng: f64,
+    pub max_lat: f64,
+    pub max_lng: f64,
+}
+
+impl Bbox {
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lng..=self.max_lng).contains(&lng)
+    }
+}
+
+impl DumpFilter {
+    pub fn is_empty(&self) -> bool {
+        self.node_types.is_empty() && self.category.is_none() && self.since.is_none() && self.bbox.is_none()
+    }
+
+    fn category_matches(&self, category: Option<&str>) -> bool {
+        match (&self.category, category) {
+            (None, _) => true,
+            (Some(wanted), Some(category)) => wanted.eq_ignore_ascii_case(category),
+            (Some(_), None) => false,
+        }
+    }
+
+    fn keeps(&self, node: &Node, situation_category_matched: bool) -> bool {
+        let Some(meta) = node.meta() else {
+            return false;
+        };
+        (self.node_types.is_empty() || self.node_types.contains(&node.node_type()))
+            && (situation_category_matched || self.category_matches(node.category()))
+            && self.since.is_none_or(|since| meta.extracted_at >= since)
+            && self.bbox.is_none_or(|bbox| {
+                meta.about_location.as_ref().is_some_and(|p| bbox.contains(p.lat, p.lng))
+            })
+    }
+}
+
+impl RegionDump {
+    /// The signals `filter` keeps. Situations left without signals are dropped.
+    pub fn filtered(mut self, filter: &DumpFilter) -> RegionDump {
+        if filter.is_empty() {
+            return self;
+        }
+        self.situations.retain_mut(|s| {
+            let category_matched = filter.category.is_some() && filter.category_matches(s.situation.category.as_deref());
+            s.signals.retain(|n| filter.keeps(n, category_matched));
+            !s.signals.is_empty()
+        });
+        self.ungrouped_signals.retain(|n| filter.keeps(n, false));
+        self
+    }
+
+    /// Every situation and signal, keyed by id, with the fields a diff tracks.
+    fn entries(&self) -> BTreeMap<Uuid, (DiffEntry, Vec<TrackedField>)> {
+        let mut entries = BTreeMap::new();
+        let mut add_signal = |node: &Node, situation: Option<&str>| {
+            let Some(meta) = node.meta() else {
+                return;
+            };
+            let entry = DiffEntry { kind: node.node_type().to_string(), id: meta.id, title: meta.title.clone() };
+            let fields = vec![
+                ("title", meta.title.clone()),
+                ("summary", meta.summary.clone()),
+                ("confidence", format!("{:.2}", meta.confidence)),
+                ("corroboration", meta.corroboration_count.to_string()),
+                ("situation", situation.unwrap_or("none").to_string()),
+            ];
+            entries.insert(meta.id, (entry, fields));
+        };
+        for s in &self.situations {
+            for node in &s.signals {
+                add_signal(node, Some(&s.situation.headline));
+            }
+        }
+        for node in &self.ungrouped_signals {
+            add_signal(node, None);
+        }
+        for s in &self.situations {
+            let situation = &s.situation;
+            let entry = DiffEntry { kind: "Situation".to_string(), id: situation.id, title: situation.headline.clone() };
+            let fields = vec![
+                ("headline", situation.headline.clone()),
+                ("lede", situation.lede.clone()),
+                ("arc", situation.arc.to_string()),
+                ("category", situation.category.clone().unwrap_or_default()),
+                ("signals", s.signals.len().to_string()),
+            ];
+            entries.insert(situation.id, (entry, fields));
+        }
+        entries
+    }
+}
+
+type TrackedField = (&'static str, String);
+
+/// A situation or signal in a diff. `kind` is `Situation` or the signal type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffEntry {
+    pub kind: String,
+    pub id: Uuid,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangedEntry {
+    #[serde(flatten)]
+    pub entry: DiffEntry,
+    pub changes: Vec<FieldChange>,
+}
+
+/// What changed between two dumps, each list ordered by kind and title.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct DumpDiff {
+    pub added: Vec<DiffEntry>,
+    pub removed: Vec<DiffEntry>,
+    pub changed: Vec<ChangedEntry>,
+}
+
+impl DumpDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare two dumps of the same region by situation and signal id.
+pub fn diff(before: &RegionDump, after: &RegionDump) -> DumpDiff {
+    let before = before.entries();
+    let mut after = after.entries();
+    let mut out = DumpDiff::default();
+
+    for (id, (entry, old_fields)) in before {
+        let Some((entry, new_fields)) = after.remove(&id) else {
+            out.removed.push(entry);
+            continue;
+        };
+        let changes: Vec<FieldChange> = old_fields
+            .into_iter()
+            .zip(new_fields)
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((field, before), (_, after))| FieldChange { field, before, after })
+            .collect();
+        if !changes.is_empty() {
+            out.changed.push(ChangedEntry { entry, changes });
+        }
+    }
+    out.added = after.into_values().map(|(entry, _)| entry).collect();
+
+    let key = |e: &DiffEntry| (e.kind.clone(), e.title.clone());
+    out.added.sort_by_key(key);
+    out.removed.sort_by_key(key);
+    out.changed.sort_by_key(|c| key(&c.entry));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rootsignal_common::safety::SensitivityLevel;
+    use rootsignal_common::{Clarity, GeoPoint, GeoPrecision, NodeMeta, NoticeNode, Severity, SituationArc};
+
+    fn notice(title: &str, category: Option<&str>, lat: f64) -> Node {
+        Node::Notice(NoticeNode {
+            meta: NodeMeta {
+                id: Uuid::new_v4(),
+                title: title.to_string(),
+                summary: String::new(),
+                sensitivity: SensitivityLevel::General,
+                confidence: 0.5,
+                freshness_score: 1.0,
+                corroboration_count: 0,
+                about_location: Some(GeoPoint { lat, lng: -93.2, precision: GeoPrecision::Exact }),
+                about_location_name: None,
+                from_location: None,
+                source_url: "https://example.org".to_string(),
+                extracted_at: Utc::now(),
+                content_date: None,
+                last_confirmed_active: Utc::now(),
+                source_diversity: 1,
+                external_ratio: 0.0,
+                cause_heat: 0.0,
+                channel_diversity: 1,
+                mentioned_actors: vec![],
+                author_actor: None,
+                implied_queries: vec![],
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
+            },
+            severity: Severity::Medium,
+            category: category.map(str::to_string),
+            effective_date: None,
+            source_authority: None,
+        })
+    }
+
+    fn situation(headline: &str, category: Option<&str>, signals: Vec<Node>) -> SituationDump {
+        SituationDump {
+            situation: SituationNode {
+                id: Uuid::new_v4(),
+                headline: headline.to_string(),
+                lede: String::new(),
+                arc: SituationArc::Emerging,
+                temperature: 0.0,
+                tension_heat: 0.0,
+                entity_velocity: 0.0,
+                amplification: 0.0,
+                response_coverage: 0.0,
+                clarity_need: 0.0,
+                clarity: Clarity::Fuzzy,
+                centroid_lat: None,
+                centroid_lng: None,
+                location_name: None,
+                structured_state: String::new(),
+                signal_count: signals.len() as u32,
+                tension_count: 0,
+                dispatch_count: 0,
+                first_seen: Utc::now(),
+                last_updated: Utc::now(),
+                sensitivity: SensitivityLevel::General,
+                category: category.map(str::to_string),
+                demographics: None,
+                escalation: None,
+                alt_text: None,
+                plain_summary: None,
+            },
+            signals,
+        }
+    }
+
+    fn dump(situations: Vec<SituationDump>, ungrouped_signals: Vec<Node>) -> RegionDump {
+        RegionDump { region: "twincities".to_string(), situations, ungrouped_signals }
+    }
+
+    #[test]
+    fn filter_keeps_matching_signals_and_drops_emptied_situations() {
+        let housing = situation("Evictions rising", Some("housing"), vec![notice("Tenant clinic", None, 44.9)]);
+        let transit = situation("Bus cuts", Some("transit"), vec![notice("Route 5 cut", None, 44.9)]);
+        let ungrouped = vec![notice("Rent relief", Some("Housing"), 44.9), notice("Far away", Some("housing"), 46.0)];
+        let filter = DumpFilter {
+            category: Some("housing".to_string()),
+            bbox: Some(Bbox { min_lat: 44.0, min_lng: -94.0, max_lat: 45.0, max_lng: -93.0 }),
+            ..Default::default()
+        };
+
+        let filtered = dump(vec![housing, transit], ungrouped).filtered(&filter);
+
+        assert_eq!(filtered.situations.len(), 1);
+        assert_eq!(filtered.situations[0].situation.headline, "Evictions rising");
+        let titles: Vec<&str> = filtered.ungrouped_signals.iter().map(Node::title).collect();
+        assert_eq!(titles, ["Rent relief"]);
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_regrouped_signals() {
+        let kept = notice("Tenant clinic", None, 44.9);
+        let removed = notice("Old notice", None, 44.9);
+        let added = notice("New notice", None, 44.9);
+        let before = dump(vec![], vec![kept.clone(), removed]);
+        let after = dump(vec![situation("Evictions rising", None, vec![kept])], vec![added]);
+
+        let diff = diff(&before, &after);
+
+        let titles = |entries: &[DiffEntry]| entries.iter().map(|e| e.title.clone()).collect::<Vec<_>>();
+        assert_eq!(titles(&diff.added), ["New notice", "Evictions rising"]);
+        assert_eq!(titles(&diff.removed), ["Old notice"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(
+            diff.changed[0].changes,
+            [FieldChange { field: "situation", before: "none".to_string(), after: "Evictions rising".to_string() }]
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Escalation risk for situations, recomputed each run.
+//!
+//! Reads each situation's features (signal timeline, tension severity mix,
+//! response coverage) plus how often situations of its category have
+//! escalated, runs `estimate_escalation`, and stores the result on the
+//! situation as `escalation_risk` (for sorting) and `escalation` (JSON).
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use neo4rs::query;
+use tracing::info;
+
+use rootsignal_common::escalation::ACCELERATION_WINDOW_DAYS;
+use rootsignal_common::{estimate_escalation, EscalationFeatures, SituationArc};
+
+use crate::GraphClient;
+
+/// Situations that reached this temperature count as having escalated.
+const ESCALATED_TEMPERATURE: f64 = 0.6;
+
+/// Recompute escalation risk for every situation centered in the bounding
+/// box. Returns the number of situations scored.
+pub async fn refresh_escalation_risk(
+    client: &GraphClient,
+    (min_lat, max_lat, min_lng, max_lng): (f64, f64, f64, f64),
+    now: DateTime<Utc>,
+) -> Result<u32, neo4rs::Error> {
+    let history = category_history(client).await?;
+
+    let recent = now - Duration::days(ACCELERATION_WINDOW_DAYS);
+    let prior = recent - Duration::days(ACCELERATION_WINDOW_DAYS);
+    let q = query(
+        "MATCH (s:Situation)
+         WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+           AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
+         OPTIONAL MATCH (sig)-[e:EVIDENCES]->(s)
+         WHERE coalesce(e.debunked, false) = false
+         WITH s,
+              count(CASE WHEN sig.extracted_at >= datetime($recent)
+                          AND NOT coalesce(sig.historical, false) THEN 1 END) AS recent,
+              count(CASE WHEN sig.extracted_at >= datetime($prior)
+                          AND sig.extracted_at < datetime($recent)
+                          AND NOT coalesce(sig.historical, false) THEN 1 END) AS prior,
+              count(CASE WHEN sig:Tension THEN 1 END) AS tensions,
+              count(CASE WHEN sig:Tension AND sig.severity IN ['high', 'critical'] THEN 1 END) AS severe
+         RETURN s.id AS id, s.arc AS arc, s.category AS category,
+                s.response_coverage AS response_coverage,
+                s.temperature AS temperature,
+                recent, prior, tensions, severe",
+    )
+    .param("min_lat", min_lat)
+    .param("max_lat", max_lat)
+    .param("min_lng", min_lng)
+    .param("max_lng", max_lng)
+    .param("recent", recent.to_rfc3339())
+    .param("prior", prior.to_rfc3339());
+
+    let mut scored = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let id: String = row.get("id").unwrap_or_default();
+        let arc_str: String = row.get("arc").unwrap_or_default();
+        let arc: SituationArc = arc_str.parse().unwrap_or(SituationArc::Emerging);
+        let temperature: f64 = row.get("temperature").unwrap_or(0.0);
+        let category: String = row.get("category").unwrap_or_default();
+
+        // The situation's own history doesn't predict itself.
+        let (situations, escalated) = match history.get(category.as_str()) {
+            Some(&(situations, escalated)) => (
+                situations.saturating_sub(1),
+                escalated.saturating_sub(has_escalated(&arc, temperature) as u32),
+            ),
+            None => (0, 0),
+        };
+
+        let features = EscalationFeatures {
+            arc,
+            recent_signals: row.get::<i64>("recent").unwrap_or(0) as u32,
+            prior_signals: row.get::<i64>("prior").unwrap_or(0) as u32,
+            tensions: row.get::<i64>("tensions").unwrap_or(0) as u32,
+            severe_tensions: row.get::<i64>("severe").unwrap_or(0) as u32,
+            response_coverage: row.get("response_coverage").unwrap_or(0.0),
+            category_situations: situations,
+            category_escalated: escalated,
+        };
+        scored.push((id, estimate_escalation(&features, now)));
+    }
+
+    for (id, escalation) in &scored {
+        client
+            .graph
+            .run(
+                query(
+                    "MATCH (s:Situation {id: $id})
+                     SET s.escalation_risk = $risk,
+                         s.escalation = $escalation",
+                )
+                .param("id", id.as_str())
+                .param("risk", escalation.risk)
+                .param("escalation", serde_json::to_string(escalation).unwrap_or_default()),
+            )
+            .await?;
+    }
+
+    let high = scored.iter().filter(|(_, e)| e.risk >= 0.5).count();
+    info!(scored = scored.len(), high, "Escalation risk refreshed");
+    Ok(scored.len() as u32)
+}
+
+fn has_escalated(arc: &SituationArc, temperature: f64) -> bool {
+    *arc == SituationArc::Active || temperature >= ESCALATED_TEMPERATURE
+}
+
+/// Per category: situations, and how many of them escalated.
+async fn category_history(client: &GraphClient) -> Result<HashMap<String, (u32, u32)>, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:Situation)
+         WHERE coalesce(s.category, '') <> ''
+         RETURN s.category AS category,
+                count(s) AS situations,
+                count(CASE WHEN s.arc = 'active' OR s.temperature >= $threshold THEN 1 END) AS escalated",
+    )
+    .param("threshold", ESCALATED_TEMPERATURE);
+
+    let mut history = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let category: String = row.get("category").unwrap_or_default();
+        let situations = row.get::<i64>("situations").unwrap_or(0) as u32;
+        let escalated = row.get::<i64>("escalated").unwrap_or(0) as u32;
+        history.insert(category, (situations, escalated));
+    }
+    Ok(history)
+}
@@ -0,0 +1,212 @@
+//! Situation information requests and the evidence pulled to answer them.
+//!
+//! `(s:Situation)-[:REQUESTS]->(r:InformationRequest)`; a request's status
+//! walks open → answered | unanswered. Pulled evidence is stored as
+//! `(e:PulledEvidence)-[:ANSWERS]->(r)` and `(e)-[:INFORMS]->(s)`, so every
+//! excerpt attached to a situation carries the request it was pulled for.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::information_requests::{
+    normalize_question, MAX_OPEN_REQUESTS_PER_SITUATION, MAX_PULL_ATTEMPTS,
+};
+use rootsignal_common::{InformationRequest, PulledEvidence, RequestStatus, ScoutScope};
+
+use crate::GraphClient;
+
+const REQUEST_FIELDS: &str = "r.id AS id, s.id AS situation_id, s.headline AS headline,
+     coalesce(s.temperature, 0.0) AS temperature, r.question AS question, r.status AS status,
+     r.attempts AS attempts, toString(r.created_at) AS created_at,
+     toString(r.answered_at) AS answered_at";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn parse_id(row: &Row, key: &str) -> Option<Uuid> {
+    Uuid::parse_str(&row.get::<String>(key).ok()?).ok()
+}
+
+fn row_to_request(row: &Row) -> Option<InformationRequest> {
+    Some(InformationRequest {
+        id: parse_id(row, "id")?,
+        situation_id: parse_id(row, "situation_id")?,
+        situation_headline: row.get("headline").unwrap_or_default(),
+        temperature: row.get("temperature").unwrap_or(0.0),
+        question: row.get("question").ok()?,
+        status: RequestStatus::parse(&row.get::<String>("status").unwrap_or_default())?,
+        attempts: row.get::<i64>("attempts").unwrap_or(0) as u32,
+        created_at: parse_time(row, "created_at")?,
+        answered_at: parse_time(row, "answered_at"),
+    })
+}
+
+/// Record a situation's requests. Questions it already has open are skipped,
+/// and none are added past `MAX_OPEN_REQUESTS_PER_SITUATION`. Returns the
+/// number recorded.
+pub async fn record_requests(
+    client: &GraphClient,
+    situation_id: Uuid,
+    questions: &[String],
+) -> Result<u32, neo4rs::Error> {
+    let mut recorded = 0u32;
+    for question in questions.iter().filter_map(|q| normalize_question(q)) {
+        let q = query(
+            "MATCH (s:Situation {id: $situation_id})
+             OPTIONAL MATCH (s)-[:REQUESTS]->(open:InformationRequest {status: 'open'})
+             WITH s, collect(toLower(open.question)) AS asked
+             WHERE size(asked) < $max_open AND NOT toLower($question) IN asked
+             CREATE (s)-[:REQUESTS]->(r:InformationRequest {
+                 id: $id, question: $question, status: 'open', attempts: 0,
+                 created_at: datetime()
+             })
+             RETURN r.id AS id",
+        )
+        .param("situation_id", situation_id.to_string())
+        .param("question", question.as_str())
+        .param("max_open", MAX_OPEN_REQUESTS_PER_SITUATION as i64)
+        .param("id", Uuid::new_v4().to_string());
+
+        let mut stream = client.graph.execute(q).await?;
+        if stream.next().await?.is_some() {
+            recorded += 1;
+        }
+    }
+    if recorded > 0 {
+        info!(%situation_id, recorded, "Information requests recorded");
+    }
+    Ok(recorded)
+}
+
+/// Open requests of situations centered in scope, skipping sensitive and
+/// restricted situations.
+pub async fn open_requests(
+    client: &GraphClient,
+    scope: &ScoutScope,
+) -> Result<Vec<InformationRequest>, neo4rs::Error> {
+    let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+    let q = query(&format!(
+        "MATCH (s:Situation)-[:REQUESTS]->(r:InformationRequest {{status: 'open'}})
+         WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+           AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
+           AND NOT coalesce(s.sensitivity, '') IN ['SENSITIVE', 'RESTRICTED']
+         RETURN {REQUEST_FIELDS}, s.centroid_lat AS lat, s.centroid_lng AS lng"
+    ))
+    .param("min_lat", min_lat)
+    .param("max_lat", max_lat)
+    .param("min_lng", min_lng)
+    .param("max_lng", max_lng);
+
+    let mut stream = client.graph.execute(q).await?;
+    let mut requests = Vec::new();
+    while let Some(row) = stream.next().await? {
+        if !scope.contains(row.get("lat").unwrap_or_default(), row.get("lng").unwrap_or_default()) {
+            continue;
+        }
+        if let Some(request) = row_to_request(&row) {
+            requests.push(request);
+        }
+    }
+    Ok(requests)
+}
+
+/// Attach pulled evidence to its request's situation and mark the request
+/// answered.
+pub async fn attach_evidence(
+    client: &GraphClient,
+    request_id: Uuid,
+    evidence: &[PulledEvidence],
+) -> Result<(), neo4rs::Error> {
+    for e in evidence {
+        let q = query(
+            "MATCH (s:Situation)-[:REQUESTS]->(r:InformationRequest {id: $request_id})
+             CREATE (e:PulledEvidence {
+                 id: $id, url: $url, snippet: $snippet, retrieved_at: datetime($retrieved_at)
+             })
+             CREATE (e)-[:ANSWERS]->(r)
+             CREATE (e)-[:INFORMS]->(s)",
+        )
+        .param("request_id", request_id.to_string())
+        .param("id", e.id.to_string())
+        .param("url", e.url.as_str())
+        .param("snippet", e.snippet.as_str())
+        .param("retrieved_at", e.retrieved_at.to_rfc3339());
+        client.graph.run(q).await?;
+    }
+
+    let q = query(
+        "MATCH (r:InformationRequest {id: $request_id})
+         SET r.status = 'answered', r.answered_at = datetime(), r.attempts = r.attempts + 1",
+    )
+    .param("request_id", request_id.to_string());
+    client.graph.run(q).await?;
+
+    info!(%request_id, evidence = evidence.len(), "Information request answered");
+    Ok(())
+}
+
+/// Record a fruitless attempt; the request is given up on after
+/// `MAX_PULL_ATTEMPTS`.
+pub async fn record_unanswered(client: &GraphClient, request_id: Uuid) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MATCH (r:InformationRequest {id: $request_id})
+         SET r.attempts = r.attempts + 1
+         SET r.status = CASE WHEN r.attempts >= $max_attempts THEN 'unanswered' ELSE r.status END",
+    )
+    .param("request_id", request_id.to_string())
+    .param("max_attempts", MAX_PULL_ATTEMPTS as i64);
+    client.graph.run(q).await
+}
+
+/// Requests with their pulled evidence, newest first; only those with
+/// `status` when given.
+pub async fn information_requests(
+    client: &GraphClient,
+    status: Option<RequestStatus>,
+    limit: u32,
+) -> Result<Vec<(InformationRequest, Vec<PulledEvidence>)>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (s:Situation)-[:REQUESTS]->(r:InformationRequest)
+         WHERE $status = '' OR r.status = $status
+         OPTIONAL MATCH (e:PulledEvidence)-[:ANSWERS]->(r)
+         WITH s, r, collect(e) AS evidence
+         RETURN {REQUEST_FIELDS},
+                [e IN evidence | {{id: e.id, url: e.url, snippet: e.snippet,
+                                   retrieved_at: toString(e.retrieved_at)}}] AS evidence
+         ORDER BY r.created_at DESC
+         LIMIT $limit"
+    ))
+    .param("status", status.map_or("", |s| s.as_str()))
+    .param("limit", limit as i64);
+
+    let mut stream = client.graph.execute(q).await?;
+    let mut requests = Vec::new();
+    while let Some(row) = stream.next().await? {
+        let Some(request) = row_to_request(&row) else {
+            continue;
+        };
+        let evidence = row
+            .get::<Vec<neo4rs::BoltMap>>("evidence")
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|e| {
+                let id: String = e.get("id").ok()?;
+                let retrieved_at: String = e.get("retrieved_at").ok()?;
+                Some(PulledEvidence {
+                    id: Uuid::parse_str(&id).ok()?,
+                    request_id: request.id,
+                    situation_id: request.situation_id,
+                    url: e.get("url").ok()?,
+                    snippet: e.get("snippet").unwrap_or_default(),
+                    retrieved_at: DateTime::parse_from_rfc3339(&retrieved_at).ok()?.with_timezone(&Utc),
+                })
+            })
+            .collect();
+        requests.push((request, evidence));
+    }
+    Ok(requests)
+}
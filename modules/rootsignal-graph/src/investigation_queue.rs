@@ -0,0 +1,251 @@
+//! The investigation queue in the graph.
+//!
+//! Each entry is an `InvestigationTask` node, one per signal, linked
+//! `-[:TARGETS]->` the signal and scoped to the region that queued it.
+//! Entries whose signal is gone drop out of every read.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use uuid::Uuid;
+
+use rootsignal_common::investigation_queue::{state_after_failure, QueueEntry, QueueState, REQUEUE_AFTER_DAYS};
+
+use crate::reader::{label_to_node_type, node_type_label};
+use crate::writer::InvestigationTarget;
+use crate::GraphClient;
+
+const SIGNAL_LABELS: &str = "(n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)";
+
+const ENTRY_FIELDS: &str = "q.signal_id AS signal_id, q.node_type AS node_type, q.title AS title,
+     q.source_url AS source_url, q.severity AS severity, coalesce(q.cause_heat, 0.0) AS cause_heat,
+     coalesce(q.pinned, false) AS pinned, q.state AS state, coalesce(q.attempts, 0) AS attempts,
+     q.last_error AS last_error, toString(q.queued_at) AS queued_at, toString(q.updated_at) AS updated_at";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_entry(row: &Row) -> Option<QueueEntry> {
+    Some(QueueEntry {
+        signal_id: Uuid::parse_str(&row.get::<String>("signal_id").ok()?).ok()?,
+        node_type: label_to_node_type(&row.get::<String>("node_type").ok()?)?,
+        title: row.get("title").unwrap_or_default(),
+        source_url: row.get("source_url").unwrap_or_default(),
+        severity: row.get("severity").ok(),
+        cause_heat: row.get("cause_heat").unwrap_or(0.0),
+        user_flags: row.get::<i64>("user_flags").unwrap_or(0) as u32,
+        pinned: row.get("pinned").unwrap_or(false),
+        state: QueueState::parse(&row.get::<String>("state").ok()?)?,
+        attempts: row.get::<i64>("attempts").unwrap_or(0) as u32,
+        last_error: row.get("last_error").ok(),
+        queued_at: parse_time(row, "queued_at")?,
+        updated_at: parse_time(row, "updated_at")?,
+    })
+}
+
+/// Queue `targets` in `region`. Signals already queued keep their state and
+/// place, with their details refreshed; done entries older than
+/// `REQUEUE_AFTER_DAYS` go back to pending.
+pub async fn enqueue(client: &GraphClient, region: &str, targets: &[InvestigationTarget]) -> Result<(), neo4rs::Error> {
+    if targets.is_empty() {
+        return Ok(());
+    }
+    let rows: Vec<HashMap<String, neo4rs::BoltType>> = targets
+        .iter()
+        .map(|t| {
+            let mut row: HashMap<String, neo4rs::BoltType> = HashMap::new();
+            row.insert("signal_id".into(), t.signal_id.to_string().into());
+            row.insert("node_type".into(), node_type_label(t.node_type).into());
+            row.insert("title".into(), t.title.clone().into());
+            row.insert("source_url".into(), t.source_url.clone().into());
+            row.insert("severity".into(), t.severity.clone().unwrap_or_default().into());
+            row.insert("cause_heat".into(), t.cause_heat.into());
+            row
+        })
+        .collect();
+
+    let q = query(&format!(
+        "UNWIND $rows AS row
+         MATCH (n {{id: row.signal_id}}) WHERE {SIGNAL_LABELS}
+         MERGE (q:InvestigationTask {{signal_id: row.signal_id}})
+         ON CREATE SET q.region = $region, q.state = 'pending', q.attempts = 0, q.pinned = false,
+                       q.queued_at = datetime(), q.updated_at = datetime()
+         SET q.node_type = row.node_type, q.title = row.title, q.source_url = row.source_url,
+             q.severity = CASE row.severity WHEN '' THEN null ELSE row.severity END,
+             q.cause_heat = row.cause_heat
+         WITH q, n
+         MERGE (q)-[:TARGETS]->(n)
+         WITH q
+         WHERE q.state = 'done' AND q.updated_at < datetime() - duration({{days: $requeue_after}})
+         SET q.state = 'pending', q.attempts = 0, q.last_error = null,
+             q.queued_at = datetime(), q.updated_at = datetime()"
+    ))
+    .param("rows", rows)
+    .param("region", region)
+    .param("requeue_after", REQUEUE_AFTER_DAYS);
+    client.graph.run(q).await
+}
+
+/// The region's entries in `states` (every state when empty), pinned first,
+/// then oldest.
+pub async fn entries(
+    client: &GraphClient,
+    region: &str,
+    states: &[QueueState],
+    limit: u32,
+) -> Result<Vec<QueueEntry>, neo4rs::Error> {
+    let states: Vec<&str> = states.iter().map(QueueState::as_str).collect();
+    let q = query(&format!(
+        "MATCH (q:InvestigationTask {{region: $region}})-[:TARGETS]->(n)
+         WHERE size($states) = 0 OR q.state IN $states
+         OPTIONAL MATCH (c:CorrectionRequest {{status: 'open'}})-[:CORRECTS]->(n)
+         WITH q, count(c) AS user_flags
+         RETURN {ENTRY_FIELDS}, user_flags
+         ORDER BY pinned DESC, q.queued_at ASC
+         LIMIT $limit"
+    ))
+    .param("region", region)
+    .param("states", states)
+    .param("limit", limit as i64);
+
+    let mut entries = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(entry) = row_to_entry(&row) {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// Mark entries in progress and load their signals as targets.
+pub async fn claim(client: &GraphClient, signal_ids: &[Uuid]) -> Result<Vec<InvestigationTarget>, neo4rs::Error> {
+    let ids: Vec<String> = signal_ids.iter().map(|id| id.to_string()).collect();
+    let q = query(
+        "MATCH (q:InvestigationTask)-[:TARGETS]->(n)
+         WHERE q.signal_id IN $ids
+         SET q.state = 'in_progress', q.updated_at = datetime()
+         RETURN q.signal_id AS id, q.node_type AS label, n.title AS title, n.summary AS summary,
+                n.source_url AS source_url, n.sensitivity AS sensitivity,
+                q.severity AS severity, coalesce(n.cause_heat, 0.0) AS cause_heat",
+    )
+    .param("ids", ids);
+
+    let mut by_id: HashMap<Uuid, InvestigationTarget> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Ok(signal_id) = Uuid::parse_str(&row.get::<String>("id").unwrap_or_default()) else {
+            continue;
+        };
+        let Some(node_type) = label_to_node_type(&row.get::<String>("label").unwrap_or_default()) else {
+            continue;
+        };
+        let sensitivity: String = row.get("sensitivity").unwrap_or_default();
+        by_id.insert(
+            signal_id,
+            InvestigationTarget {
+                signal_id,
+                node_type,
+                title: row.get("title").unwrap_or_default(),
+                summary: row.get("summary").unwrap_or_default(),
+                source_url: row.get("source_url").unwrap_or_default(),
+                is_sensitive: sensitivity == "sensitive" || sensitivity == "elevated",
+                severity: row.get("severity").ok(),
+                cause_heat: row.get("cause_heat").unwrap_or(0.0),
+            },
+        );
+    }
+    // Keep the dequeue order.
+    Ok(signal_ids.iter().filter_map(|id| by_id.remove(id)).collect())
+}
+
+/// Return in-progress entries to pending without counting an attempt.
+pub async fn release(client: &GraphClient, signal_ids: &[Uuid]) -> Result<(), neo4rs::Error> {
+    let ids: Vec<String> = signal_ids.iter().map(|id| id.to_string()).collect();
+    let q = query(
+        "MATCH (q:InvestigationTask)
+         WHERE q.signal_id IN $ids AND q.state = 'in_progress'
+         SET q.state = 'pending', q.updated_at = datetime()",
+    )
+    .param("ids", ids);
+    client.graph.run(q).await
+}
+
+/// Mark an entry done.
+pub async fn complete(client: &GraphClient, signal_id: Uuid) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MATCH (q:InvestigationTask {signal_id: $id})
+         SET q.state = 'done', q.last_error = null, q.updated_at = datetime()",
+    )
+    .param("id", signal_id.to_string());
+    client.graph.run(q).await
+}
+
+/// Count a failed attempt: back to pending, or blocked after too many.
+pub async fn record_failure(client: &GraphClient, signal_id: Uuid, error: &str) -> Result<QueueState, neo4rs::Error> {
+    let q = query(
+        "MATCH (q:InvestigationTask {signal_id: $id})
+         SET q.attempts = coalesce(q.attempts, 0) + 1
+         RETURN q.attempts AS attempts",
+    )
+    .param("id", signal_id.to_string());
+    let mut stream = client.graph.execute(q).await?;
+    let attempts = match stream.next().await? {
+        Some(row) => row.get::<i64>("attempts").unwrap_or(0) as u32,
+        None => return Ok(QueueState::Pending),
+    };
+
+    let state = state_after_failure(attempts);
+    let q = query(
+        "MATCH (q:InvestigationTask {signal_id: $id})
+         SET q.state = $state, q.last_error = $error, q.updated_at = datetime()",
+    )
+    .param("id", signal_id.to_string())
+    .param("state", state.as_str())
+    .param("error", error);
+    client.graph.run(q).await?;
+    Ok(state)
+}
+
+/// Pin or unpin an entry. Returns false if there is none.
+pub async fn set_pinned(client: &GraphClient, signal_id: Uuid, pinned: bool) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (q:InvestigationTask {signal_id: $id})
+         SET q.pinned = $pinned
+         RETURN count(q) AS updated",
+    )
+    .param("id", signal_id.to_string())
+    .param("pinned", pinned);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("updated").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// Skip an entry, or return a skipped or blocked one to pending with its
+/// attempts reset. Returns false if there is none, or it's in another state.
+pub async fn set_skipped(client: &GraphClient, signal_id: Uuid, skipped: bool) -> Result<bool, neo4rs::Error> {
+    let (from, to) = if skipped {
+        (vec!["pending", "blocked"], QueueState::Skipped)
+    } else {
+        (vec!["skipped", "blocked"], QueueState::Pending)
+    };
+    let q = query(
+        "MATCH (q:InvestigationTask {signal_id: $id})
+         WHERE q.state IN $from
+         SET q.state = $to, q.attempts = 0, q.last_error = null, q.updated_at = datetime()
+         RETURN count(q) AS updated",
+    )
+    .param("id", signal_id.to_string())
+    .param("from", from)
+    .param("to", to.as_str());
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("updated").unwrap_or(0) > 0,
+        None => false,
+    })
+}
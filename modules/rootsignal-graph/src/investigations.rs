@@ -0,0 +1,111 @@
+//! Investigation reports in the graph.
+//!
+//! Each report is an `Investigation` node linked `-[:INVESTIGATED]->` the
+//! signal it looked into. Findings are stored as a JSON string since Neo4j
+//! properties can't hold maps.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use uuid::Uuid;
+
+use rootsignal_common::{Investigation, InvestigationFinding};
+
+use crate::GraphClient;
+
+const SIGNAL_LABELS: &str = "(n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)";
+
+const INVESTIGATION_FIELDS: &str = "i.id AS id, i.signal_id AS signal_id, i.question AS question,
+     i.method AS method, i.sources_consulted AS sources_consulted, i.findings AS findings,
+     i.confidence_before AS confidence_before, i.confidence_after AS confidence_after,
+     i.cost_cents AS cost_cents, toString(i.created_at) AS created_at";
+
+fn row_to_investigation(row: &Row) -> Option<Investigation> {
+    let created_at: String = row.get("created_at").ok()?;
+    let findings: String = row.get("findings").unwrap_or_default();
+    Some(Investigation {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        signal_id: Uuid::parse_str(&row.get::<String>("signal_id").ok()?).ok()?,
+        question: row.get("question").unwrap_or_default(),
+        method: row.get("method").unwrap_or_default(),
+        sources_consulted: row.get("sources_consulted").unwrap_or_default(),
+        findings: serde_json::from_str::<Vec<InvestigationFinding>>(&findings).unwrap_or_default(),
+        confidence_before: row.get::<f64>("confidence_before").unwrap_or(0.0) as f32,
+        confidence_after: row.get::<f64>("confidence_after").unwrap_or(0.0) as f32,
+        cost_cents: row.get::<i64>("cost_cents").unwrap_or(0) as u32,
+        created_at: DateTime::parse_from_rfc3339(&created_at).ok()?.with_timezone(&Utc),
+    })
+}
+
+/// Store a report, linked to its signal. Returns false if the signal doesn't exist.
+pub async fn record_investigation(client: &GraphClient, investigation: &Investigation) -> Result<bool, neo4rs::Error> {
+    let findings = serde_json::to_string(&investigation.findings).unwrap_or_else(|_| "[]".to_string());
+    let q = query(&format!(
+        "MATCH (n {{id: $signal_id}}) WHERE {SIGNAL_LABELS}
+         CREATE (i:Investigation {{
+             id: $id, signal_id: $signal_id, question: $question, method: $method,
+             sources_consulted: $sources_consulted, findings: $findings,
+             confidence_before: $confidence_before, confidence_after: $confidence_after,
+             cost_cents: $cost_cents, created_at: datetime($created_at)
+         }})-[:INVESTIGATED]->(n)
+         RETURN count(i) AS created"
+    ))
+    .param("id", investigation.id.to_string())
+    .param("signal_id", investigation.signal_id.to_string())
+    .param("question", investigation.question.as_str())
+    .param("method", investigation.method.as_str())
+    .param("sources_consulted", investigation.sources_consulted.clone())
+    .param("findings", findings)
+    .param("confidence_before", investigation.confidence_before as f64)
+    .param("confidence_after", investigation.confidence_after as f64)
+    .param("cost_cents", investigation.cost_cents as i64)
+    .param("created_at", investigation.created_at.to_rfc3339());
+
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("created").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// Investigation reports per signal, newest first.
+pub async fn investigations_for_signals(
+    client: &GraphClient,
+    signal_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Investigation>>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (i:Investigation)
+         WHERE i.signal_id IN $signal_ids
+         RETURN {INVESTIGATION_FIELDS}
+         ORDER BY i.created_at DESC"
+    ))
+    .param("signal_ids", signal_ids.iter().map(Uuid::to_string).collect::<Vec<_>>());
+
+    let mut by_signal: HashMap<Uuid, Vec<Investigation>> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(investigation) = row_to_investigation(&row) {
+            by_signal.entry(investigation.signal_id).or_default().push(investigation);
+        }
+    }
+    Ok(by_signal)
+}
+
+/// The most recent reports across all signals, for the admin audit view.
+pub async fn recent_investigations(client: &GraphClient, limit: u32) -> Result<Vec<Investigation>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (i:Investigation)
+         RETURN {INVESTIGATION_FIELDS}
+         ORDER BY i.created_at DESC
+         LIMIT $limit"
+    ))
+    .param("limit", limit as i64);
+
+    let mut investigations = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        investigations.extend(row_to_investigation(&row));
+    }
+    Ok(investigations)
+}
@@ -1,36 +1,63 @@
+pub mod annotations;
+pub mod backfill;
 pub mod beacon;
+pub mod bootstrap_review;
 pub mod cache;
 pub mod cached_reader;
 pub mod cause_heat;
 pub mod client;
+pub mod corrections;
+pub mod decisions;
+pub mod dump;
+pub mod escalation;
+pub mod information_requests;
+pub mod investigation_queue;
+pub mod investigations;
 pub mod migrate;
+pub mod onboarding;
+pub mod read_state;
 pub mod reader;
+pub mod region_membership;
+pub mod region_stats;
 pub mod response;
+pub mod retention;
+pub mod room_feeds;
+pub mod routing;
 pub mod similarity;
+pub mod situation_feed;
+pub mod situation_quality;
 pub mod situation_temperature;
 pub mod situation_weaver;
+pub mod story_energy;
 pub mod story_metrics;
 pub mod story_weaver;
+pub mod subject_requests;
 pub mod synthesizer;
+pub mod taxonomy;
 #[cfg(feature = "test-utils")]
 pub mod testutil;
+pub mod trends;
+pub mod triggers;
+pub mod watchlist;
+pub mod why_not;
 pub mod writer;
 
 pub use cache::CacheStore;
 pub use cached_reader::CachedReader;
 pub use client::GraphClient;
-pub use reader::{PublicGraphReader, ResourceGap, ResourceMatch, ValidationIssueRow, ValidationIssueSummary};
+pub use routing::GraphRouter;
+pub use reader::{DistrictSummary, PublicGraphReader, ResourceGap, ResourceMatch, ValidationIssueRow, ValidationIssueSummary};
 pub use similarity::SimilarityBuilder;
-pub use story_metrics::{parse_recency, story_energy, story_status};
+pub use story_metrics::{parse_recency, story_status};
 pub use situation_weaver::SituationWeaver;
 pub use story_weaver::StoryWeaver;
 pub use synthesizer::Synthesizer;
 pub use writer::{
-    ConsolidationStats, DuplicateMatch, EvidenceSummary, ExtractionYield, GapTypeStats,
+    ActorHistory, ActorSignal, ConsolidationStats, CoverageDay, DuplicateMatch, EventCandidate, EvidenceSummary, ExtractionYield, GapTypeStats,
     GatheringFinderTarget, GraphWriter, InvestigationTarget, ReapStats, ResponseFinderTarget,
-    ResponseHeuristic, SignalTypeCounts, SituationBrief, SourceBrief, SourceStats, StoryBrief, StoryGrowth,
+    ResponseHeuristic, SignalTypeCounts, SimilarSignal, SituationBrief, SituationContext, SourceBrief, SourceStats, StoryBrief, StoryGrowth, SummaryVariantSource,
     TensionHub, TensionLinkerOutcome, TensionLinkerTarget, TensionRespondent, TensionResponseShape,
-    UnmetTension,
+    UnmetTension, VerificationCandidate,
 };
 
 /// Re-export neo4rs::query for downstream crates that need raw Cypher access (e.g. test assertions).
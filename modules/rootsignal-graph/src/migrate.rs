@@ -320,6 +320,54 @@ pub async fn migrate(client: &GraphClient) -> Result<(), neo4rs::Error> {
         "CREATE CONSTRAINT scouttask_id IF NOT EXISTS FOR (t:ScoutTask) REQUIRE t.id IS UNIQUE",
         "CREATE INDEX scouttask_status IF NOT EXISTS FOR (t:ScoutTask) ON (t.status)",
         "CREATE INDEX scouttask_priority IF NOT EXISTS FOR (t:ScoutTask) ON (t.priority)",
+        "CREATE CONSTRAINT regioncalendar_region IF NOT EXISTS FOR (c:RegionCalendar) REQUIRE c.region IS UNIQUE",
+        "CREATE CONSTRAINT regionprivacy_region IF NOT EXISTS FOR (p:RegionPrivacy) REQUIRE p.region IS UNIQUE",
+        "CREATE CONSTRAINT regionschedule_region IF NOT EXISTS FOR (s:RegionSchedule) REQUIRE s.region IS UNIQUE",
+        "CREATE CONSTRAINT regiononboarding_region IF NOT EXISTS FOR (o:RegionOnboarding) REQUIRE o.region IS UNIQUE",
+        "CREATE CONSTRAINT regionenergyweights_region IF NOT EXISTS FOR (w:RegionEnergyWeights) REQUIRE w.region IS UNIQUE",
+        "CREATE CONSTRAINT regionopendata_region IF NOT EXISTS FOR (o:RegionOpenData) REQUIRE o.region IS UNIQUE",
+        "CREATE CONSTRAINT regionagendas_region IF NOT EXISTS FOR (a:RegionAgendas) REQUIRE a.region IS UNIQUE",
+        "CREATE CONSTRAINT regionservicealerts_region IF NOT EXISTS FOR (a:RegionServiceAlerts) REQUIRE a.region IS UNIQUE",
+        "CREATE CONSTRAINT registryentity_id IF NOT EXISTS FOR (e:RegistryEntity) REQUIRE e.id IS UNIQUE",
+        "CREATE INDEX registryentity_region IF NOT EXISTS FOR (e:RegistryEntity) ON (e.region)",
+        "CREATE CONSTRAINT regiondemographics_region IF NOT EXISTS FOR (d:RegionDemographics) REQUIRE d.region IS UNIQUE",
+        "CREATE CONSTRAINT weeklycount_id IF NOT EXISTS FOR (w:WeeklyCount) REQUIRE w.id IS UNIQUE",
+        "CREATE CONSTRAINT trend_id IF NOT EXISTS FOR (t:Trend) REQUIRE t.id IS UNIQUE",
+        "CREATE INDEX trend_region IF NOT EXISTS FOR (t:Trend) ON (t.region)",
+        "CREATE CONSTRAINT topiccategory_slug IF NOT EXISTS FOR (c:TopicCategory) REQUIRE c.slug IS UNIQUE",
+        "CREATE CONSTRAINT savedsearch_id IF NOT EXISTS FOR (s:SavedSearch) REQUIRE s.id IS UNIQUE",
+        "CREATE INDEX savedsearch_owner IF NOT EXISTS FOR (s:SavedSearch) ON (s.owner)",
+        "CREATE CONSTRAINT readstate_id IF NOT EXISTS FOR (r:ReadState) REQUIRE r.id IS UNIQUE",
+        "CREATE INDEX readstate_updated_at IF NOT EXISTS FOR (r:ReadState) ON (r.updated_at)",
+        "CREATE CONSTRAINT contributor_member_id IF NOT EXISTS FOR (c:Contributor) REQUIRE c.member_id IS UNIQUE",
+        "CREATE CONSTRAINT annotation_id IF NOT EXISTS FOR (a:Annotation) REQUIRE a.id IS UNIQUE",
+        "CREATE INDEX annotation_status IF NOT EXISTS FOR (a:Annotation) ON (a.status)",
+        "CREATE INDEX annotation_signal_id IF NOT EXISTS FOR (a:Annotation) ON (a.signal_id)",
+        "CREATE CONSTRAINT correctionrequest_id IF NOT EXISTS FOR (c:CorrectionRequest) REQUIRE c.id IS UNIQUE",
+        "CREATE INDEX correctionrequest_status IF NOT EXISTS FOR (c:CorrectionRequest) ON (c.status)",
+        "CREATE INDEX correctionrequest_signal_id IF NOT EXISTS FOR (c:CorrectionRequest) ON (c.signal_id)",
+        "CREATE CONSTRAINT situationchange_id IF NOT EXISTS FOR (c:SituationChange) REQUIRE c.id IS UNIQUE",
+        "CREATE INDEX situationchange_notified IF NOT EXISTS FOR (c:SituationChange) ON (c.notified)",
+        "CREATE CONSTRAINT situationsubscription_id IF NOT EXISTS FOR (s:SituationSubscription) REQUIRE s.id IS UNIQUE",
+        "CREATE INDEX situationsubscription_situation_id IF NOT EXISTS FOR (s:SituationSubscription) ON (s.situation_id)",
+        "CREATE INDEX situationsubscription_email IF NOT EXISTS FOR (s:SituationSubscription) ON (s.email)",
+        "CREATE INDEX situationsubscription_confirm_token IF NOT EXISTS FOR (s:SituationSubscription) ON (s.confirm_token)",
+        "CREATE CONSTRAINT retentionpolicy_class IF NOT EXISTS FOR (p:RetentionPolicy) REQUIRE p.class IS UNIQUE",
+        "CREATE CONSTRAINT legalhold_id IF NOT EXISTS FOR (h:LegalHold) REQUIRE h.id IS UNIQUE",
+        "CREATE CONSTRAINT retentionrun_id IF NOT EXISTS FOR (r:RetentionRun) REQUIRE r.id IS UNIQUE",
+        "CREATE INDEX retentionrun_ran_at IF NOT EXISTS FOR (r:RetentionRun) ON (r.ran_at)",
+        "CREATE CONSTRAINT subjectrequest_id IF NOT EXISTS FOR (r:SubjectRequest) REQUIRE r.id IS UNIQUE",
+        "CREATE INDEX subjectrequest_status IF NOT EXISTS FOR (r:SubjectRequest) ON (r.status)",
+        "CREATE CONSTRAINT investigation_id IF NOT EXISTS FOR (i:Investigation) REQUIRE i.id IS UNIQUE",
+        "CREATE INDEX investigation_signal_id IF NOT EXISTS FOR (i:Investigation) ON (i.signal_id)",
+        "CREATE INDEX coveragesnapshot_tension_day IF NOT EXISTS FOR (c:CoverageSnapshot) ON (c.tension_id, c.day)",
+        "CREATE INDEX coveragesnapshot_day IF NOT EXISTS FOR (c:CoverageSnapshot) ON (c.day)",
+        "CREATE CONSTRAINT decision_id IF NOT EXISTS FOR (d:Decision) REQUIRE d.id IS UNIQUE",
+        "CREATE INDEX decision_subject_id IF NOT EXISTS FOR (d:Decision) ON (d.subject_id)",
+        "CREATE INDEX decision_decided_at IF NOT EXISTS FOR (d:Decision) ON (d.decided_at)",
+        "CREATE CONSTRAINT region_stats_region IF NOT EXISTS FOR (s:RegionStats) REQUIRE s.region IS UNIQUE",
+        "CREATE CONSTRAINT investigation_task_signal IF NOT EXISTS FOR (q:InvestigationTask) REQUIRE q.signal_id IS UNIQUE",
+        "CREATE INDEX investigation_task_region_state IF NOT EXISTS FOR (q:InvestigationTask) ON (q.region, q.state)",
     ];
     for s in &scout_task_schema {
         g.run(query(s)).await?;
@@ -535,6 +583,7 @@ pub async fn migrate(client: &GraphClient) -> Result<(), neo4rs::Error> {
     let situation_indexes = [
         "CREATE INDEX situation_arc IF NOT EXISTS FOR (n:Situation) ON (n.arc)",
         "CREATE INDEX situation_temperature IF NOT EXISTS FOR (n:Situation) ON (n.temperature)",
+        "CREATE INDEX situation_escalation_risk IF NOT EXISTS FOR (n:Situation) ON (n.escalation_risk)",
         "CREATE INDEX situation_category IF NOT EXISTS FOR (n:Situation) ON (n.category)",
         "CREATE INDEX situation_sensitivity IF NOT EXISTS FOR (n:Situation) ON (n.sensitivity)",
         "CREATE INDEX situation_last_updated IF NOT EXISTS FOR (n:Situation) ON (n.last_updated)",
@@ -0,0 +1,57 @@
+//! Region onboarding records.
+//!
+//! One `RegionOnboarding` node per region slug, in the default database
+//! alongside scout tasks and schedules, holding the onboarding as JSON. The
+//! onboarding workflow saves it at each stage, so the admin app can follow
+//! progress without asking Restate.
+
+use neo4rs::query;
+use tracing::warn;
+
+use rootsignal_common::RegionOnboarding;
+
+use crate::GraphClient;
+
+/// Create or replace the region's onboarding record.
+pub async fn save_onboarding(client: &GraphClient, onboarding: &RegionOnboarding) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (o:RegionOnboarding {region: $region})
+         SET o.stage = $stage, o.updated_at = datetime($updated_at), o.record = $record",
+    )
+    .param("region", onboarding.region.as_str())
+    .param("stage", onboarding.stage.as_str())
+    .param("updated_at", onboarding.updated_at.to_rfc3339())
+    .param("record", serde_json::to_string(onboarding).unwrap_or_default());
+    client.graph.run(q).await
+}
+
+/// The region's onboarding, if it was onboarded through `createRegion`.
+pub async fn onboarding(client: &GraphClient, region: &str) -> Result<Option<RegionOnboarding>, neo4rs::Error> {
+    let q = query("MATCH (o:RegionOnboarding {region: $region}) RETURN o.record AS record").param("region", region);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => serde_json::from_str(&row.get::<String>("record").unwrap_or_default()).ok(),
+        None => None,
+    })
+}
+
+/// Every onboarding, most recently updated first.
+pub async fn onboardings(client: &GraphClient) -> Result<Vec<RegionOnboarding>, neo4rs::Error> {
+    let q = query(
+        "MATCH (o:RegionOnboarding)
+         RETURN o.region AS region, o.record AS record
+         ORDER BY o.updated_at DESC",
+    );
+    let mut stream = client.graph.execute(q).await?;
+    let mut onboardings = Vec::new();
+    while let Some(row) = stream.next().await? {
+        match serde_json::from_str(&row.get::<String>("record").unwrap_or_default()) {
+            Ok(onboarding) => onboardings.push(onboarding),
+            Err(e) => {
+                let region: String = row.get("region").unwrap_or_default();
+                warn!(region, error = %e, "Skipping unreadable onboarding record");
+            }
+        }
+    }
+    Ok(onboardings)
+}
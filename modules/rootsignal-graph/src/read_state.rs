@@ -0,0 +1,56 @@
+//! Anonymous read watermarks.
+//!
+//! A `ReadState` node per (token hash, region) holds `seen_at`, the time the
+//! visitor last caught up there. The raw token never reaches the graph.
+
+use chrono::{DateTime, Duration, Utc};
+use neo4rs::query;
+
+use rootsignal_common::read_state::READ_STATE_TTL_DAYS;
+
+use crate::GraphClient;
+
+fn state_id(token_hash: &str, region_slug: &str) -> String {
+    format!("{token_hash}/{region_slug}")
+}
+
+/// When the visitor last caught up in the region, if ever.
+pub async fn watermark(
+    client: &GraphClient,
+    token_hash: &str,
+    region_slug: &str,
+) -> Result<Option<DateTime<Utc>>, neo4rs::Error> {
+    let q = query("MATCH (r:ReadState {id: $id}) RETURN toString(r.seen_at) AS seen_at")
+        .param("id", state_id(token_hash, region_slug));
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(None);
+    };
+    let seen_at: String = row.get("seen_at").unwrap_or_default();
+    Ok(DateTime::parse_from_rfc3339(&seen_at).ok().map(|t| t.with_timezone(&Utc)))
+}
+
+/// Move the visitor's watermark in the region to `seen_at` (never backwards),
+/// and delete read states nobody has touched for `READ_STATE_TTL_DAYS`.
+pub async fn mark_read(
+    client: &GraphClient,
+    token_hash: &str,
+    region_slug: &str,
+    seen_at: DateTime<Utc>,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (r:ReadState {id: $id})
+         ON CREATE SET r.region = $region, r.seen_at = datetime($seen_at)
+         SET r.seen_at = CASE WHEN r.seen_at > datetime($seen_at) THEN r.seen_at ELSE datetime($seen_at) END,
+             r.updated_at = datetime()",
+    )
+    .param("id", state_id(token_hash, region_slug))
+    .param("region", region_slug)
+    .param("seen_at", seen_at.to_rfc3339());
+    client.graph.run(q).await?;
+
+    let q = query("MATCH (r:ReadState) WHERE r.updated_at < datetime($cutoff) DELETE r")
+        .param("cutoff", (Utc::now() - Duration::days(READ_STATE_TTL_DAYS)).to_rfc3339());
+    client.graph.run(q).await
+}
@@ -4,9 +4,9 @@ use neo4rs::query;
 use uuid::Uuid;
 
 use rootsignal_common::{
-    fuzz_location, NeedNode, GatheringNode, EvidenceNode, GeoPoint, GeoPrecision, AidNode, Node,
-    NodeMeta, NodeType, NoticeNode, SensitivityLevel, Severity, StoryNode, TensionNode,
-    TensionResponse, Urgency, NEED_EXPIRE_DAYS, CONFIDENCE_DISPLAY_LIMITED, GATHERING_PAST_GRACE_HOURS,
+    fuzz_location, fundraiser_platform, AggregateGuard, EnergyComponents, Fundraiser, NeedNode, GatheringNode, EvidenceNode, GeoPoint, GeoPrecision, AidNode, Node,
+    FieldVisibility, NodeMeta, NodeType, NoticeNode, SensitivityLevel, Severity, StoryNode, TensionNode,
+    TensionResponse, Trend, TrendWindow, Urgency, NEED_EXPIRE_DAYS, CONFIDENCE_DISPLAY_LIMITED, GATHERING_PAST_GRACE_HOURS,
     FRESHNESS_MAX_DAYS, NOTICE_EXPIRE_DAYS,
 };
 
@@ -420,6 +420,206 @@ impl PublicGraphReader {
         Ok(signals)
     }
 
+    /// Signals linked to a registry entity — mentioning it or located within
+    /// it — newest first.
+    pub async fn signals_for_entity(&self, entity_id: &str, limit: u32) -> Result<Vec<Node>, neo4rs::Error> {
+        self.signals_linked_to(entity_id, "MENTIONS|LOCATED_IN", None, limit).await
+    }
+
+    /// Signals located within a ward or district, newest first.
+    pub async fn signals_in_district(
+        &self,
+        entity_id: &str,
+        node_types: Option<&[NodeType]>,
+        limit: u32,
+    ) -> Result<Vec<Node>, neo4rs::Error> {
+        self.signals_linked_to(entity_id, "LOCATED_IN", node_types, limit).await
+    }
+
+    async fn signals_linked_to(
+        &self,
+        entity_id: &str,
+        edge_types: &str,
+        node_types: Option<&[NodeType]>,
+        limit: u32,
+    ) -> Result<Vec<Node>, neo4rs::Error> {
+        let all_types = [
+            NodeType::Gathering,
+            NodeType::Aid,
+            NodeType::Need,
+            NodeType::Notice,
+            NodeType::Tension,
+        ];
+        let types = node_types.unwrap_or(&all_types);
+
+        let branches: Vec<String> = types
+            .iter()
+            .filter(|nt| all_types.contains(nt))
+            .map(|nt| {
+                let label = node_type_label(*nt);
+                format!(
+                    "MATCH (n:{label})-[:{edge_types}]->(:RegistryEntity {{id: $id}})
+                     RETURN DISTINCT n, labels(n)[0] AS node_label"
+                )
+            })
+            .collect();
+        if branches.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let cypher = branches.join("\nUNION ALL\n");
+        let q = query(&cypher).param("id", entity_id);
+
+        let mut signals = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            if let Some(node) = row_to_node_by_label(&row) {
+                if passes_display_filter(&node) {
+                    signals.push(fuzz_node(node));
+                }
+            }
+        }
+        signals.sort_by_key(|n| std::cmp::Reverse(n.meta().map(|m| m.extracted_at)));
+        signals.truncate(limit as usize);
+
+        Ok(signals)
+    }
+
+    /// Live stories with a signal located within a ward or district, by energy.
+    pub async fn stories_in_district(
+        &self,
+        entity_id: &str,
+        status_filter: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<StoryNode>, neo4rs::Error> {
+        let status_clause = if status_filter.is_some() { "AND s.status = $status" } else { "" };
+        let mut q = query(&format!(
+            "MATCH (s:Story)-[:CONTAINS]->()-[:LOCATED_IN]->(:RegistryEntity {{id: $id}})
+             WHERE s.review_status = 'live' {status_clause}
+             WITH DISTINCT s
+             RETURN s
+             ORDER BY s.energy DESC
+             LIMIT $limit"
+        ))
+        .param("id", entity_id)
+        .param("limit", limit as i64);
+        if let Some(status) = status_filter {
+            q = q.param("status", status);
+        }
+
+        let mut results = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            if let Some(story) = row_to_story(&row) {
+                results.push(story);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Everything known about one ward or district: its live signals by type,
+    /// open tensions, and the stories and situations touching it.
+    pub async fn district_summary(&self, entity_id: &str) -> Result<DistrictSummary, neo4rs::Error> {
+        let signals = self.signals_in_district(entity_id, None, DISTRICT_SUMMARY_SIGNAL_CAP).await?;
+
+        let guard = self.district_aggregate_guard(entity_id).await?;
+
+        let mut counts: Vec<(NodeType, u32, bool)> = Vec::new();
+        for node in &signals {
+            let nt = node.node_type();
+            let sensitive = node.meta().is_some_and(|m| m.sensitivity == SensitivityLevel::Sensitive);
+            match counts.iter_mut().find(|(t, _, _)| *t == nt) {
+                Some((_, count, s)) => {
+                    *count += 1;
+                    *s |= sensitive;
+                }
+                None => counts.push((nt, 1, sensitive)),
+            }
+        }
+        let signal_counts = guard.breakdown(counts);
+
+        let mut tensions: Vec<Node> = signals.into_iter().filter(|n| matches!(n, Node::Tension(_))).collect();
+        tensions.sort_by_key(|n| std::cmp::Reverse(if let Node::Tension(t) = n { t.severity as u8 } else { 0 }));
+        tensions.truncate(DISTRICT_SUMMARY_TOP);
+
+        let stories = self.stories_in_district(entity_id, None, DISTRICT_SUMMARY_TOP as u32).await?;
+        let situations = self.situations_in_district(entity_id, DISTRICT_SUMMARY_TOP as u32).await?;
+
+        Ok(DistrictSummary {
+            signal_counts,
+            top_tensions: tensions,
+            stories,
+            situations,
+        })
+    }
+
+    /// A region's current trends for a window, strongest first, less any the
+    /// region's aggregate guard withholds.
+    pub async fn trends(
+        &self,
+        region_slug: &str,
+        window: TrendWindow,
+        limit: u32,
+    ) -> Result<Vec<Trend>, neo4rs::Error> {
+        let guard = self.aggregate_guard(region_slug).await?;
+        let q = query(
+            "MATCH (t:Trend {region: $region, window: $window})
+             OPTIONAL MATCH (t)-[:SUPPORTED_BY]->(n {sensitivity: 'sensitive'})
+             WITH t, count(n) > 0 AS sensitive
+             RETURN t.trend AS trend, sensitive
+             ORDER BY abs(t.z_score) DESC",
+        )
+        .param("region", region_slug)
+        .param("window", window.as_str());
+
+        let mut trends = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let json: String = row.get("trend").unwrap_or_default();
+            let sensitive: bool = row.get("sensitive").unwrap_or(true);
+            if let Some(trend) = serde_json::from_str(&json).ok().and_then(|t| guard.trend(t, sensitive)) {
+                trends.push(trend);
+            }
+            if trends.len() >= limit as usize {
+                break;
+            }
+        }
+        Ok(trends)
+    }
+
+    /// The aggregate guard for a region (keyed by region slug); the default
+    /// when none is set.
+    pub async fn aggregate_guard(&self, region_slug: &str) -> Result<AggregateGuard, neo4rs::Error> {
+        let q = query(
+            "MATCH (p:RegionPrivacy {region: $region})
+             RETURN p.min_group_size AS min_group_size",
+        )
+        .param("region", region_slug);
+        self.read_aggregate_guard(q).await
+    }
+
+    /// The aggregate guard for the region a ward or district belongs to.
+    async fn district_aggregate_guard(&self, entity_id: &str) -> Result<AggregateGuard, neo4rs::Error> {
+        let q = query(
+            "MATCH (e:RegistryEntity {id: $id})
+             MATCH (p:RegionPrivacy {region: e.region})
+             RETURN p.min_group_size AS min_group_size",
+        )
+        .param("id", entity_id);
+        self.read_aggregate_guard(q).await
+    }
+
+    async fn read_aggregate_guard(&self, q: neo4rs::Query) -> Result<AggregateGuard, neo4rs::Error> {
+        let mut stream = self.client.graph.execute(q).await?;
+        let min_group_size = match stream.next().await? {
+            Some(row) => row.get::<i64>("min_group_size").ok(),
+            None => None,
+        };
+        Ok(min_group_size
+            .map(|k| AggregateGuard { min_group_size: k as u32 })
+            .unwrap_or_default())
+    }
+
     /// Fetch evidence nodes for a signal by ID.
     pub async fn get_signal_evidence(
         &self,
@@ -574,17 +774,17 @@ impl PublicGraphReader {
 
     // --- Story filter queries ---
 
-    /// Get stories filtered by category.
+    /// Get stories filed under any of `categories`.
     pub async fn stories_by_category(
         &self,
-        category: &str,
+        categories: &[String],
         limit: u32,
     ) -> Result<Vec<StoryNode>, neo4rs::Error> {
         let q = query(
-            "MATCH (s:Story) WHERE s.review_status = 'live' AND s.category = $category
+            "MATCH (s:Story) WHERE s.review_status = 'live' AND s.category IN $categories
              RETURN s ORDER BY s.energy DESC LIMIT $limit",
         )
-        .param("category", category)
+        .param("categories", categories.to_vec())
         .param("limit", limit as i64);
 
         let mut results = Vec::new();
@@ -1137,7 +1337,8 @@ impl PublicGraphReader {
         Ok(counts.iter().map(|(_, c)| c).sum())
     }
 
-    /// Signal volume by day for last 30 days, grouped by type.
+    /// Signal volume by day for last 30 days, grouped by type. Backfilled
+    /// (historical) signals aren't counted on the day they were found.
     /// Returns Vec<(date_string, gathering, aid, need, notice, tension)>.
     pub async fn signal_volume_by_day(
         &self,
@@ -1146,15 +1347,15 @@ impl PublicGraphReader {
             "WITH date(datetime() - duration('P30D')) AS cutoff
              UNWIND range(0, 29) AS offset
              WITH date(datetime() - duration('P' + toString(offset) + 'D')) AS day
-             OPTIONAL MATCH (e:Gathering) WHERE date(e.extracted_at) = day
+             OPTIONAL MATCH (e:Gathering) WHERE date(e.extracted_at) = day AND NOT coalesce(e.historical, false)
              WITH day, count(e) AS events
-             OPTIONAL MATCH (g:Aid) WHERE date(g.extracted_at) = day
+             OPTIONAL MATCH (g:Aid) WHERE date(g.extracted_at) = day AND NOT coalesce(g.historical, false)
              WITH day, events, count(g) AS gives
-             OPTIONAL MATCH (a:Need) WHERE date(a.extracted_at) = day
+             OPTIONAL MATCH (a:Need) WHERE date(a.extracted_at) = day AND NOT coalesce(a.historical, false)
              WITH day, events, gives, count(a) AS needs
-             OPTIONAL MATCH (n:Notice) WHERE date(n.extracted_at) = day
+             OPTIONAL MATCH (n:Notice) WHERE date(n.extracted_at) = day AND NOT coalesce(n.historical, false)
              WITH day, events, gives, needs, count(n) AS notices
-             OPTIONAL MATCH (t:Tension) WHERE date(t.extracted_at) = day
+             OPTIONAL MATCH (t:Tension) WHERE date(t.extracted_at) = day AND NOT coalesce(t.historical, false)
              RETURN toString(day) AS day, events, gives, needs, notices, count(t) AS tensions
              ORDER BY day",
         );
@@ -1754,6 +1955,26 @@ pub struct ResourceGap {
     pub gap: i32,
 }
 
+/// Signals of a district beyond this many are left out of its counts.
+const DISTRICT_SUMMARY_SIGNAL_CAP: u32 = 500;
+/// Tensions, stories and situations listed in a district summary.
+const DISTRICT_SUMMARY_TOP: usize = 5;
+
+/// One ward or district at a glance, for the API and for anything that
+/// writes about a district (editions, briefs).
+#[derive(Debug, Clone)]
+pub struct DistrictSummary {
+    /// Live signals located in the district, by type, most common first,
+    /// through the region's aggregate guard.
+    pub signal_counts: rootsignal_common::Breakdown<NodeType>,
+    /// Most severe open tensions.
+    pub top_tensions: Vec<Node>,
+    /// Highest-energy live stories with signals in the district.
+    pub stories: Vec<StoryNode>,
+    /// Hottest situations centered in the district.
+    pub situations: Vec<rootsignal_common::SituationNode>,
+}
+
 // --- Helpers ---
 
 /// Parse a datetime from a neo4rs Row, falling back to Utc::now() if missing or unparseable.
@@ -1784,7 +2005,7 @@ pub fn node_type_label(nt: NodeType) -> &'static str {
 }
 
 /// Inverse of `node_type_label`: map a Neo4j label string back to a NodeType.
-fn label_to_node_type(label: &str) -> Option<NodeType> {
+pub(crate) fn label_to_node_type(label: &str) -> Option<NodeType> {
     match label {
         "Gathering" => Some(NodeType::Gathering),
         "Aid" => Some(NodeType::Aid),
@@ -1975,6 +2196,9 @@ pub fn row_to_node(row: &neo4rs::Row, node_type: NodeType) -> Option<Node> {
         mentioned_actors: Vec::new(),
         implied_queries: Vec::new(),
         author_actor: None,
+        field_visibility: FieldVisibility::from_json(&n.get::<String>("field_visibility").unwrap_or_default()),
+        alt_text: n.get("alt_text").ok().filter(|s: &String| !s.is_empty()),
+        plain_summary: n.get("plain_summary").ok().filter(|s: &String| !s.is_empty()),
     };
 
     match node_type {
@@ -2052,6 +2276,7 @@ pub fn row_to_node(row: &neo4rs::Row, node_type: NodeType) -> Option<Node> {
                     Some(action_url)
                 },
                 goal: if goal.is_empty() { None } else { Some(goal) },
+                fundraiser: parse_fundraiser(&n),
             }))
         }
         NodeType::Notice => {
@@ -2137,6 +2362,30 @@ pub fn parse_location(n: &neo4rs::Node) -> Option<GeoPoint> {
     })
 }
 
+/// Fundraiser progress on a Need, once it has been fetched at least once.
+fn parse_fundraiser(n: &neo4rs::Node) -> Option<Fundraiser> {
+    let url: String = n.get("fundraiser_url").ok()?;
+    let checked_at = parse_optional_datetime_prop(n, "fundraiser_checked_at")?;
+    let raised: f64 = n.get("fundraiser_raised").ok()?;
+    let non_empty = |prop: &str| n.get::<String>(prop).ok().filter(|s| !s.is_empty());
+
+    Some(Fundraiser {
+        platform: fundraiser_platform(&url).to_string(),
+        url,
+        goal: n.get::<f64>("fundraiser_goal").ok().filter(|g| *g > 0.0),
+        raised,
+        currency: non_empty("fundraiser_currency").unwrap_or_else(|| "USD".to_string()),
+        donor_count: n
+            .get::<i64>("fundraiser_donor_count")
+            .ok()
+            .filter(|c| *c >= 0)
+            .map(|c| c as u32),
+        organizer: non_empty("fundraiser_organizer"),
+        beneficiary: non_empty("fundraiser_beneficiary"),
+        checked_at,
+    })
+}
+
 fn parse_optional_datetime_prop(n: &neo4rs::Node, prop: &str) -> Option<DateTime<Utc>> {
     if let Ok(s) = n.get::<String>(prop) {
         if s.is_empty() {
@@ -2211,6 +2460,17 @@ pub(crate) fn extract_evidence(row: &neo4rs::Row) -> Vec<EvidenceNode> {
                     None
                 },
                 channel_type,
+                change_summary: n.get::<String>("change_summary").ok().filter(|s| !s.is_empty()),
+                engagement_velocity: n.get::<f64>("engagement_velocity").ok(),
+                verification: n
+                    .get::<String>("freshness")
+                    .ok()
+                    .and_then(|f| rootsignal_common::EvidenceFreshness::parse(&f))
+                    .map(|freshness| rootsignal_common::EvidenceVerification {
+                        freshness,
+                        checked_at: parse_evidence_datetime(&n, "checked_at"),
+                        drift_diff: n.get::<String>("drift_diff").ok().filter(|s| !s.is_empty()),
+                    }),
             })
         })
         .collect();
@@ -2329,6 +2589,12 @@ pub fn row_to_story(row: &neo4rs::Row) -> Option<StoryNode> {
             let cd: i64 = n.get("channel_diversity").unwrap_or(1);
             cd as u32
         },
+        energy_components: n.get::<f64>("energy_activity").ok().map(|activity| EnergyComponents {
+            activity,
+            severity: n.get("energy_severity").unwrap_or(0.0),
+            corroboration: n.get("energy_corroboration").unwrap_or(0.0),
+            heat: n.get("energy_heat").unwrap_or(0.0),
+        }),
     })
 }
 
@@ -2436,6 +2702,7 @@ impl PublicGraphReader {
         max_lng: f64,
         limit: u32,
         arc_filter: Option<&str>,
+        categories: Option<&[String]>,
     ) -> Result<Vec<rootsignal_common::SituationNode>, neo4rs::Error> {
         let g = &self.client.graph;
 
@@ -2449,10 +2716,12 @@ impl PublicGraphReader {
              WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
                AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
                {arc_clause}
+               AND ($categories IS NULL OR s.category IN $categories)
              RETURN s
              ORDER BY s.temperature DESC
              LIMIT $limit"
         ))
+        .param("categories", categories.map(<[String]>::to_vec))
         .param("min_lat", min_lat)
         .param("max_lat", max_lat)
         .param("min_lng", min_lng)
@@ -2496,19 +2765,76 @@ impl PublicGraphReader {
         Ok(results)
     }
 
-    /// Fetch top situations ordered by temperature descending.
+    /// Situations most likely to escalate, highest risk first.
+    pub async fn situations_by_escalation_risk(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<rootsignal_common::SituationNode>, neo4rs::Error> {
+        let g = &self.client.graph;
+
+        let q = query(
+            "MATCH (s:Situation)
+             WHERE s.escalation_risk IS NOT NULL
+             RETURN s
+             ORDER BY s.escalation_risk DESC, s.temperature DESC
+             LIMIT $limit",
+        )
+        .param("limit", limit as i64);
+
+        let mut stream = g.execute(q).await?;
+        let mut results = Vec::new();
+        while let Some(row) = stream.next().await? {
+            if let Some(sit) = row_to_situation(&row, "s") {
+                results.push(sit);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Fetch top situations ordered by temperature descending, optionally
+    /// only those filed under one of `categories`.
     pub async fn situations(
         &self,
         limit: u32,
+        categories: Option<&[String]>,
     ) -> Result<Vec<rootsignal_common::SituationNode>, neo4rs::Error> {
         let g = &self.client.graph;
 
         let q = query(
             "MATCH (s:Situation)
+             WHERE $categories IS NULL OR s.category IN $categories
              RETURN s
              ORDER BY s.temperature DESC
              LIMIT $limit",
         )
+        .param("categories", categories.map(<[String]>::to_vec))
+        .param("limit", limit as i64);
+
+        let mut stream = g.execute(q).await?;
+        let mut results = Vec::new();
+        while let Some(row) = stream.next().await? {
+            if let Some(sit) = row_to_situation(&row, "s") {
+                results.push(sit);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Situations whose centroid lies within a ward or district, by temperature.
+    pub async fn situations_in_district(
+        &self,
+        entity_id: &str,
+        limit: u32,
+    ) -> Result<Vec<rootsignal_common::SituationNode>, neo4rs::Error> {
+        let g = &self.client.graph;
+
+        let q = query(
+            "MATCH (s:Situation)-[:LOCATED_IN]->(:RegistryEntity {id: $id})
+             RETURN s
+             ORDER BY s.temperature DESC
+             LIMIT $limit",
+        )
+        .param("id", entity_id)
         .param("limit", limit as i64);
 
         let mut stream = g.execute(q).await?;
@@ -2620,6 +2946,14 @@ fn row_to_situation(
     };
 
     let category: Option<String> = n.get("category").ok().filter(|s: &String| !s.is_empty());
+    let demographics = n
+        .get::<String>("demographic_context")
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
+    let escalation = n
+        .get::<String>("escalation")
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
 
     Some(rootsignal_common::SituationNode {
         id,
@@ -2644,6 +2978,10 @@ fn row_to_situation(
         last_updated,
         sensitivity,
         category,
+        demographics,
+        escalation,
+        alt_text: n.get("alt_text").ok().filter(|s: &String| !s.is_empty()),
+        plain_summary: n.get("plain_summary").ok().filter(|s: &String| !s.is_empty()),
     })
 }
 
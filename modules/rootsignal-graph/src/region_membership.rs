@@ -0,0 +1,187 @@
+//! Region membership for signals in overlapping scopes.
+//!
+//! Each region's scope is recorded as a `RegionScope` node so any region's
+//! scout can see which scopes overlap its own. Signals carry `n.regions` (every
+//! region containing them) and `n.primary_region`, assigned with
+//! [`RegionMembership::assign`]. Region-filtered reads match on `n.regions`, so
+//! a shared signal appears once per region rather than once per scout that
+//! found it.
+
+use neo4rs::query;
+use tracing::info;
+
+use rootsignal_common::{EntityGeometry, Node, NodeType, RegionMembership, ScoutScope};
+
+use crate::reader::{node_type_label, row_to_node};
+use crate::GraphClient;
+
+const SIGNAL_TYPES: [NodeType; 5] = [
+    NodeType::Gathering,
+    NodeType::Aid,
+    NodeType::Need,
+    NodeType::Notice,
+    NodeType::Tension,
+];
+
+/// Record (or update) the region's scope.
+pub async fn register_region_scope(client: &GraphClient, region: &str, scope: &ScoutScope) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (r:RegionScope {region: $region})
+         SET r.name = $name,
+             r.center_lat = $center_lat,
+             r.center_lng = $center_lng,
+             r.radius_km = $radius_km,
+             r.boundary_geojson = $boundary_geojson",
+    )
+    .param("region", region)
+    .param("name", scope.name.as_str())
+    .param("center_lat", scope.center_lat)
+    .param("center_lng", scope.center_lng)
+    .param("radius_km", scope.radius_km)
+    .param::<Option<String>>("boundary_geojson", scope.boundary.as_ref().map(|b| b.to_geojson().to_string()));
+    client.graph.run(q).await
+}
+
+/// Every registered region's scope, by region slug.
+pub async fn region_scopes(client: &GraphClient) -> Result<Vec<(String, ScoutScope)>, neo4rs::Error> {
+    let q = query(
+        "MATCH (r:RegionScope)
+         RETURN r.region AS region, r.name AS name, r.center_lat AS center_lat,
+                r.center_lng AS center_lng, r.radius_km AS radius_km,
+                r.boundary_geojson AS boundary_geojson
+         ORDER BY r.region",
+    );
+    let mut stream = client.graph.execute(q).await?;
+    let mut scopes = Vec::new();
+    while let Some(row) = stream.next().await? {
+        let boundary = row
+            .get::<String>("boundary_geojson")
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .and_then(|value| EntityGeometry::from_geojson(&value).ok());
+        scopes.push((
+            row.get("region").unwrap_or_default(),
+            ScoutScope {
+                center_lat: row.get("center_lat").unwrap_or(0.0),
+                center_lng: row.get("center_lng").unwrap_or(0.0),
+                radius_km: row.get("radius_km").unwrap_or(0.0),
+                name: row.get("name").unwrap_or_default(),
+                boundary,
+            },
+        ));
+    }
+    Ok(scopes)
+}
+
+/// Assign membership to every signal within `region`'s scope against all of
+/// `scopes`. Signals already filed under another region keep that primary.
+/// Returns the number of signals updated.
+pub async fn assign_signal_regions(
+    client: &GraphClient,
+    region: &str,
+    scopes: &[(String, ScoutScope)],
+) -> Result<u32, neo4rs::Error> {
+    let Some((_, scope)) = scopes.iter().find(|(slug, _)| slug == region) else {
+        return Ok(0);
+    };
+    let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+
+    let mut updated = 0u32;
+    for nt in SIGNAL_TYPES {
+        let label = node_type_label(nt);
+        let q = query(&format!(
+            "MATCH (n:{label})
+             WHERE n.lat >= $min_lat AND n.lat <= $max_lat
+               AND n.lng >= $min_lng AND n.lng <= $max_lng
+             RETURN n.id AS id, n.lat AS lat, n.lng AS lng,
+                    n.primary_region AS primary_region, n.regions AS regions"
+        ))
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng);
+
+        let mut changes = Vec::new();
+        let mut stream = client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let (lat, lng): (f64, f64) = (row.get("lat").unwrap_or_default(), row.get("lng").unwrap_or_default());
+            if !scope.contains(lat, lng) {
+                continue;
+            }
+            let current = row.get::<String>("primary_region").ok();
+            let Some(membership) = RegionMembership::assign(lat, lng, scopes, current.as_deref()) else {
+                continue;
+            };
+            let regions: Vec<String> = row.get("regions").unwrap_or_default();
+            if current.as_deref() != Some(membership.primary.as_str()) || regions != membership.regions {
+                changes.push((row.get::<String>("id").unwrap_or_default(), membership));
+            }
+        }
+
+        for (id, membership) in changes {
+            let q = query(&format!(
+                "MATCH (n:{label} {{id: $id}})
+                 SET n.primary_region = $primary, n.regions = $regions"
+            ))
+            .param("id", id)
+            .param("primary", membership.primary)
+            .param("regions", membership.regions);
+            client.graph.run(q).await?;
+            updated += 1;
+        }
+    }
+
+    info!(region, updated, "Signal region membership assigned");
+    Ok(updated)
+}
+
+/// Signals that belong to `region`, shared ones included, most recent first.
+pub async fn signals_in_region(client: &GraphClient, region: &str, limit: u32) -> Result<Vec<Node>, neo4rs::Error> {
+    let mut nodes = Vec::new();
+    for nt in SIGNAL_TYPES {
+        let label = node_type_label(nt);
+        let q = query(&format!(
+            "MATCH (n:{label})
+             WHERE $region IN n.regions
+             RETURN n
+             ORDER BY n.extracted_at DESC
+             LIMIT $limit"
+        ))
+        .param("region", region)
+        .param("limit", limit as i64);
+        let mut stream = client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            if let Some(node) = row_to_node(&row, nt) {
+                nodes.push(node);
+            }
+        }
+    }
+    nodes.sort_by(|a, b| {
+        let at = |n: &Node| n.meta().map(|m| m.extracted_at);
+        at(b).cmp(&at(a))
+    });
+    nodes.truncate(limit as usize);
+    Ok(nodes)
+}
+
+/// How many of `region`'s signals are shared with another region, and the
+/// region's fractional signal count (each signal counts `1/n` toward each of
+/// its `n` regions).
+pub async fn attributed_signals(client: &GraphClient, region: &str) -> Result<(u64, f64), neo4rs::Error> {
+    let q = query(
+        "MATCH (n)
+         WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+           AND $region IN n.regions
+         RETURN sum(CASE WHEN size(n.regions) > 1 THEN 1 ELSE 0 END) AS shared,
+                sum(1.0 / size(n.regions)) AS attributed",
+    )
+    .param("region", region);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => (
+            row.get::<i64>("shared").unwrap_or(0) as u64,
+            row.get::<f64>("attributed").unwrap_or(0.0),
+        ),
+        None => (0, 0.0),
+    })
+}
@@ -0,0 +1,239 @@
+//! Computes a region's dashboard aggregates and stores them as one
+//! `RegionStats` node (the stats as JSON), which readers load instead of
+//! running the aggregate queries. The supervisor records its run's
+//! `RunMethodology` on the same node.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use neo4rs::query;
+use tracing::info;
+
+use rootsignal_common::region_stats::{
+    source_channel, DayVolume, GapStat, LabelCount, SourceStat, YieldStat, STATS_TOP_N,
+};
+use rootsignal_common::{scraping_strategy, PolicyAction, PolicyRule, RegionStats, RunMethodology, SourceNode};
+
+use crate::writer::SourceBrief;
+use crate::{GraphClient, GraphWriter, PublicGraphReader};
+
+fn label_counts(rows: Vec<(String, u64)>) -> Vec<LabelCount> {
+    rows.into_iter().map(|(label, count)| LabelCount { label, count }).collect()
+}
+
+fn source_stats(sources: Vec<SourceBrief>) -> Vec<SourceStat> {
+    sources
+        .into_iter()
+        .take(STATS_TOP_N)
+        .map(|s| SourceStat {
+            name: s.canonical_value,
+            signals: s.signals_produced,
+            weight: s.weight,
+            empty_runs: s.consecutive_empty_runs,
+        })
+        .collect()
+}
+
+fn source_composition(sources: &[SourceNode]) -> Vec<LabelCount> {
+    let mut by_channel: HashMap<&str, u64> = HashMap::new();
+    for source in sources.iter().filter(|s| s.active) {
+        *by_channel.entry(source_channel(scraping_strategy(&source.canonical_value))).or_default() += 1;
+    }
+    let mut composition: Vec<LabelCount> = by_channel
+        .into_iter()
+        .map(|(label, count)| LabelCount { label: label.to_string(), count })
+        .collect();
+    composition.sort_by(|a, b| b.count.cmp(&a.count).then(a.label.cmp(&b.label)));
+    composition
+}
+
+async fn grouped_counts(client: &GraphClient, q: neo4rs::Query) -> Result<Vec<LabelCount>, neo4rs::Error> {
+    let mut stream = client.graph.execute(q).await?;
+    let mut counts = Vec::new();
+    while let Some(row) = stream.next().await? {
+        counts.push(LabelCount {
+            label: row.get("label").unwrap_or_default(),
+            count: row.get::<i64>("count").unwrap_or(0).max(0) as u64,
+        });
+    }
+    Ok(counts)
+}
+
+/// Correction requests by status.
+async fn correction_counts(client: &GraphClient) -> Result<Vec<LabelCount>, neo4rs::Error> {
+    let q = query(
+        "MATCH (c:CorrectionRequest)
+         RETURN c.status AS label, count(c) AS count
+         ORDER BY count DESC, label",
+    );
+    grouped_counts(client, q).await
+}
+
+/// Signals held back for safety, by category: drafts by the content-policy
+/// rule that holds them back, audit rejections by critical issue.
+async fn suppression_counts(client: &GraphClient) -> Result<Vec<LabelCount>, neo4rs::Error> {
+    let draft_rules: Vec<&str> = PolicyRule::ALL
+        .iter()
+        .filter(|r| r.action() == PolicyAction::Draft)
+        .map(|r| r.as_str())
+        .collect();
+    let q = query(
+        "MATCH (n)
+         WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+           AND n.review_status IN ['draft', 'rejected']
+         UNWIND CASE n.review_status
+             WHEN 'draft' THEN [f IN coalesce(n.policy_flags, [])
+                                WHERE split(f, ':')[0] IN $draft_rules
+                                | 'content_policy/' + split(f, ':')[0]]
+             ELSE [i IN coalesce(n.audit_issues, [])
+                   WHERE i STARTS WITH 'critical/'
+                   | 'audit/' + split(substring(i, 9), ':')[0]]
+         END AS label
+         RETURN label, count(DISTINCT n) AS count
+         ORDER BY count DESC, label",
+    )
+    .param("draft_rules", draft_rules);
+    grouped_counts(client, q).await
+}
+
+/// Run every aggregate query against `client`.
+pub async fn compute_region_stats(
+    client: &GraphClient,
+    region: &str,
+    now: DateTime<Utc>,
+) -> Result<RegionStats, neo4rs::Error> {
+    let reader = PublicGraphReader::new(client.clone());
+    let writer = GraphWriter::new(client.clone());
+
+    let (
+        by_type, membership, stories, actors, confidence, freshness, volume, arcs, categories, discovery, yields, gaps,
+        sources, corrections, suppressions,
+    ) = tokio::join!(
+            reader.count_by_type(),
+            crate::region_membership::attributed_signals(client, region),
+            reader.story_count(),
+            reader.actor_count(),
+            reader.confidence_distribution(),
+            reader.freshness_distribution(),
+            reader.signal_volume_by_day(),
+            reader.story_count_by_arc(),
+            reader.story_count_by_category(),
+            writer.get_discovery_performance(),
+            writer.get_extraction_yield(),
+            writer.get_gap_type_stats(),
+            writer.get_active_sources(),
+            correction_counts(client),
+            suppression_counts(client),
+        );
+    let by_type = by_type?;
+    let (shared_signals, attributed_signals) = membership?;
+    let sources = sources?;
+    let (top_sources, bottom_sources) = discovery?;
+
+    Ok(RegionStats {
+        region: region.to_string(),
+        computed_at: now,
+        total_signals: by_type.iter().map(|(_, c)| c).sum(),
+        shared_signals,
+        attributed_signals,
+        total_stories: stories?,
+        total_actors: actors?,
+        total_sources: sources.len() as u64,
+        active_sources: sources.iter().filter(|s| s.active).count() as u64,
+        count_by_type: by_type.into_iter().map(|(t, count)| LabelCount { label: t.to_string(), count }).collect(),
+        confidence_distribution: label_counts(confidence?),
+        freshness_distribution: label_counts(freshness?),
+        story_count_by_arc: label_counts(arcs?),
+        story_count_by_category: label_counts(categories?),
+        signal_volume_by_day: volume?
+            .into_iter()
+            .map(|(day, gatherings, aids, needs, notices, tensions)| DayVolume {
+                day,
+                gatherings,
+                aids,
+                needs,
+                notices,
+                tensions,
+            })
+            .collect(),
+        top_sources: source_stats(top_sources),
+        bottom_sources: source_stats(bottom_sources),
+        extraction_yield: yields?
+            .into_iter()
+            .map(|y| YieldStat {
+                source_label: y.source_label,
+                extracted: y.extracted,
+                survived: y.survived,
+                corroborated: y.corroborated,
+                contradicted: y.contradicted,
+            })
+            .collect(),
+        gap_stats: gaps?
+            .into_iter()
+            .map(|g| GapStat {
+                gap_type: g.gap_type,
+                total: g.total_sources,
+                successful: g.successful_sources,
+                avg_weight: g.avg_weight,
+            })
+            .collect(),
+        source_composition: source_composition(&sources),
+        correction_counts: corrections?,
+        suppression_counts: suppressions?,
+    })
+}
+
+/// Replace the region's stored stats.
+pub async fn store_region_stats(client: &GraphClient, stats: &RegionStats) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (s:RegionStats {region: $region})
+         SET s.computed_at = datetime($computed_at), s.stats = $stats",
+    )
+    .param("region", stats.region.as_str())
+    .param("computed_at", stats.computed_at.to_rfc3339())
+    .param("stats", serde_json::to_string(stats).unwrap_or_default());
+    client.graph.run(q).await
+}
+
+/// Compute and store the region's stats.
+pub async fn refresh_region_stats(client: &GraphClient, region: &str) -> Result<RegionStats, neo4rs::Error> {
+    let stats = compute_region_stats(client, region, Utc::now()).await?;
+    store_region_stats(client, &stats).await?;
+    info!(region, total_signals = stats.total_signals, "Region stats materialized");
+    Ok(stats)
+}
+
+/// The region's stored stats, if they have been computed.
+pub async fn region_stats(client: &GraphClient, region: &str) -> Result<Option<RegionStats>, neo4rs::Error> {
+    let q = query("MATCH (s:RegionStats {region: $region}) RETURN s.stats AS stats").param("region", region);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => serde_json::from_str(&row.get::<String>("stats").unwrap_or_default()).ok(),
+        None => None,
+    })
+}
+
+/// Record what the region's latest run spent and ran with.
+pub async fn record_run_methodology(
+    client: &GraphClient,
+    region: &str,
+    run: &RunMethodology,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (s:RegionStats {region: $region})
+         SET s.last_run = $run",
+    )
+    .param("region", region)
+    .param("run", serde_json::to_string(run).unwrap_or_default());
+    client.graph.run(q).await
+}
+
+/// The region's latest recorded run, if any.
+pub async fn run_methodology(client: &GraphClient, region: &str) -> Result<Option<RunMethodology>, neo4rs::Error> {
+    let q = query("MATCH (s:RegionStats {region: $region}) RETURN s.last_run AS run").param("region", region);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<String>("run").ok().and_then(|run| serde_json::from_str(&run).ok()),
+        None => None,
+    })
+}
@@ -0,0 +1,253 @@
+//! Retention policies, legal holds and the graph half of the purge.
+//!
+//! Policy overrides are `RetentionPolicy {class}` nodes; a class without one
+//! uses its default. Holds are `LegalHold` nodes, active until
+//! `released_at` is set. Each purge is recorded as a `RetentionRun` node
+//! carrying its per-class counts, so purged volumes can be charted.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::{DataClass, HeldTargets, HoldTarget, LegalHold, PurgeCount, PurgeReport, RetentionPolicy};
+
+use crate::GraphClient;
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+/// Every class's policy: the stored override, or the default.
+pub async fn retention_policies(client: &GraphClient) -> Result<Vec<RetentionPolicy>, neo4rs::Error> {
+    let q = query("MATCH (p:RetentionPolicy) RETURN p.class AS class, p.retain_days AS retain_days");
+    let mut stored = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let class = row.get::<String>("class").ok().and_then(|c| DataClass::parse(&c));
+        let days = row.get::<i64>("retain_days").ok();
+        if let (Some(class), Some(days)) = (class, days) {
+            stored.push(RetentionPolicy { class, retain_days: days as u32 });
+        }
+    }
+    Ok(DataClass::ALL
+        .into_iter()
+        .map(|class| {
+            stored
+                .iter()
+                .find(|p| p.class == class)
+                .copied()
+                .unwrap_or_else(|| RetentionPolicy::default_for(class))
+        })
+        .collect())
+}
+
+pub async fn set_retention_policy(client: &GraphClient, policy: &RetentionPolicy) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (p:RetentionPolicy {class: $class})
+         SET p.retain_days = $retain_days, p.updated_at = datetime()",
+    )
+    .param("class", policy.class.as_str())
+    .param("retain_days", i64::from(policy.retain_days));
+    client.graph.run(q).await?;
+    info!(class = policy.class.as_str(), retain_days = policy.retain_days, "Retention policy updated");
+    Ok(())
+}
+
+/// Holds not yet released, newest first.
+pub async fn active_legal_holds(client: &GraphClient) -> Result<Vec<LegalHold>, neo4rs::Error> {
+    let q = query(
+        "MATCH (h:LegalHold) WHERE h.released_at IS NULL
+         RETURN h.id AS id, h.target_kind AS target_kind, h.target AS target, h.reason AS reason,
+                toString(h.created_at) AS created_at
+         ORDER BY h.created_at DESC",
+    );
+    let mut holds = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let target: String = row.get("target").unwrap_or_default();
+        let target = match row.get::<String>("target_kind").unwrap_or_default().as_str() {
+            "node" => Uuid::parse_str(&target).ok().map(HoldTarget::Node),
+            "source_url" => Some(HoldTarget::SourceUrl(target)),
+            _ => None,
+        };
+        let id = row.get::<String>("id").ok().and_then(|id| Uuid::parse_str(&id).ok());
+        if let (Some(id), Some(target), Some(created_at)) = (id, target, parse_time(&row, "created_at")) {
+            holds.push(LegalHold {
+                id,
+                target,
+                reason: row.get("reason").unwrap_or_default(),
+                created_at,
+            });
+        }
+    }
+    Ok(holds)
+}
+
+pub async fn place_legal_hold(client: &GraphClient, hold: &LegalHold) -> Result<(), neo4rs::Error> {
+    let (kind, target) = match &hold.target {
+        HoldTarget::Node(id) => ("node", id.to_string()),
+        HoldTarget::SourceUrl(url) => ("source_url", url.clone()),
+    };
+    let q = query(
+        "CREATE (:LegalHold {id: $id, target_kind: $kind, target: $target, reason: $reason,
+                             created_at: datetime($created_at)})",
+    )
+    .param("id", hold.id.to_string())
+    .param("kind", kind)
+    .param("target", target)
+    .param("reason", hold.reason.as_str())
+    .param("created_at", hold.created_at.to_rfc3339());
+    client.graph.run(q).await?;
+    info!(hold = %hold.id, kind, "Legal hold placed");
+    Ok(())
+}
+
+/// Release a hold. Returns false if it doesn't exist or was already released.
+pub async fn release_legal_hold(client: &GraphClient, id: Uuid) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (h:LegalHold {id: $id}) WHERE h.released_at IS NULL
+         SET h.released_at = datetime()
+         RETURN count(h) AS released",
+    )
+    .param("id", id.to_string());
+    let mut stream = client.graph.execute(q).await?;
+    let released = match stream.next().await? {
+        Some(row) => row.get::<i64>("released").unwrap_or(0) > 0,
+        None => false,
+    };
+    if released {
+        info!(hold = %id, "Legal hold released");
+    }
+    Ok(released)
+}
+
+/// Clear snippet text on evidence retrieved before `cutoff`, unless its URL or
+/// one of the signals citing it is held. Returns (purged, held).
+pub async fn purge_evidence_snippets(
+    client: &GraphClient,
+    cutoff: DateTime<Utc>,
+    held: &HeldTargets,
+    dry_run: bool,
+) -> Result<(u64, u64), neo4rs::Error> {
+    let q = query(
+        "MATCH (ev:Evidence)
+         WHERE ev.snippet IS NOT NULL AND ev.retrieved_at < datetime($cutoff)
+         OPTIONAL MATCH (n)-[:SOURCED_FROM]->(ev)
+         WITH ev, ev.source_url IN $urls OR any(id IN collect(n.id) WHERE id IN $ids) AS held
+         FOREACH (_ IN CASE WHEN held OR $dry_run THEN [] ELSE [1] END |
+             SET ev.snippet = null, ev.snippet_purged_at = datetime())
+         RETURN sum(CASE WHEN held THEN 0 ELSE 1 END) AS purged,
+                sum(CASE WHEN held THEN 1 ELSE 0 END) AS held",
+    )
+    .param("cutoff", cutoff.to_rfc3339())
+    .param("urls", held.source_urls.clone())
+    .param("ids", held.node_ids.clone())
+    .param("dry_run", dry_run);
+    purge_counts(client, q).await
+}
+
+/// Delete cold situations last updated before `cutoff`, with their
+/// dispatches, arc changes and subscriptions. Held situations are kept.
+/// Returns (purged, held).
+pub async fn purge_resolved_situations(
+    client: &GraphClient,
+    cutoff: DateTime<Utc>,
+    held: &HeldTargets,
+    dry_run: bool,
+) -> Result<(u64, u64), neo4rs::Error> {
+    let q = query(
+        "MATCH (s:Situation {arc: 'cold'})
+         WHERE s.last_updated < datetime($cutoff)
+         WITH s, s.id IN $ids AS held
+         OPTIONAL MATCH (s)-[:HAS_DISPATCH|HAS_CHANGE]->(child)
+         OPTIONAL MATCH (sub:SituationSubscription)-[:FOLLOWS]->(s)
+         WITH s, held, collect(DISTINCT child) + collect(DISTINCT sub) AS attached
+         FOREACH (x IN CASE WHEN held OR $dry_run THEN [] ELSE attached END | DETACH DELETE x)
+         FOREACH (_ IN CASE WHEN held OR $dry_run THEN [] ELSE [1] END | DETACH DELETE s)
+         RETURN sum(CASE WHEN held THEN 0 ELSE 1 END) AS purged,
+                sum(CASE WHEN held THEN 1 ELSE 0 END) AS held",
+    )
+    .param("cutoff", cutoff.to_rfc3339())
+    .param("ids", held.node_ids.clone())
+    .param("dry_run", dry_run);
+    purge_counts(client, q).await
+}
+
+/// Delete decision records made before `cutoff`, unless their signal is
+/// held. Returns (purged, held).
+pub async fn purge_decision_records(
+    client: &GraphClient,
+    cutoff: DateTime<Utc>,
+    held: &HeldTargets,
+    dry_run: bool,
+) -> Result<(u64, u64), neo4rs::Error> {
+    let q = query(
+        "MATCH (d:Decision)
+         WHERE d.decided_at < datetime($cutoff)
+         WITH d, d.subject_id IN $ids AS held
+         FOREACH (_ IN CASE WHEN held OR $dry_run THEN [] ELSE [1] END | DETACH DELETE d)
+         RETURN sum(CASE WHEN held THEN 0 ELSE 1 END) AS purged,
+                sum(CASE WHEN held THEN 1 ELSE 0 END) AS held",
+    )
+    .param("cutoff", cutoff.to_rfc3339())
+    .param("ids", held.node_ids.clone())
+    .param("dry_run", dry_run);
+    purge_counts(client, q).await
+}
+
+async fn purge_counts(client: &GraphClient, q: neo4rs::Query) -> Result<(u64, u64), neo4rs::Error> {
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => (
+            row.get::<i64>("purged").unwrap_or(0) as u64,
+            row.get::<i64>("held").unwrap_or(0) as u64,
+        ),
+        None => (0, 0),
+    })
+}
+
+pub async fn record_purge(client: &GraphClient, report: &PurgeReport) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "CREATE (:RetentionRun {id: $id, dry_run: $dry_run, ran_at: datetime($ran_at),
+                               total_purged: $total_purged, counts: $counts, errors: $errors})",
+    )
+    .param("id", report.id.to_string())
+    .param("dry_run", report.dry_run)
+    .param("ran_at", report.ran_at.to_rfc3339())
+    .param("total_purged", report.total_purged() as i64)
+    .param("counts", serde_json::to_string(&report.counts).unwrap_or_default())
+    .param("errors", serde_json::to_string(&report.errors).unwrap_or_default());
+    client.graph.run(q).await
+}
+
+/// Recorded purges, newest first.
+pub async fn recent_purges(client: &GraphClient, limit: u32) -> Result<Vec<PurgeReport>, neo4rs::Error> {
+    let q = query(
+        "MATCH (r:RetentionRun)
+         RETURN r.id AS id, r.dry_run AS dry_run, toString(r.ran_at) AS ran_at,
+                r.counts AS counts, r.errors AS errors
+         ORDER BY r.ran_at DESC
+         LIMIT $limit",
+    )
+    .param("limit", limit as i64);
+    let mut reports = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let id = row.get::<String>("id").ok().and_then(|id| Uuid::parse_str(&id).ok());
+        let (Some(id), Some(ran_at)) = (id, parse_time(&row, "ran_at")) else {
+            continue;
+        };
+        let counts: Vec<PurgeCount> =
+            serde_json::from_str(&row.get::<String>("counts").unwrap_or_default()).unwrap_or_default();
+        reports.push(PurgeReport {
+            id,
+            dry_run: row.get("dry_run").unwrap_or(false),
+            ran_at,
+            counts,
+            errors: serde_json::from_str(&row.get::<String>("errors").unwrap_or_default()).unwrap_or_default(),
+        });
+    }
+    Ok(reports)
+}
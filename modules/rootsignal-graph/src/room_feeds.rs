@@ -0,0 +1,122 @@
+//! Room feeds in the graph, and queueing new signals for them.
+//!
+//! Each feed is a `RoomFeed` node (JSON in `feed`). When the signal cache
+//! reloads, signals that weren't in the previous snapshot are matched
+//! against every unpaused feed and handed to a `RoomFeedSink`, which queues
+//! them for the delivery worker. Delivery, rate limiting and retries are the
+//! sink's concern, so a slow or failing room never holds up a reload.
+
+use async_trait::async_trait;
+use neo4rs::query;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::{Node, RoomFeed};
+
+use crate::cache::SignalCache;
+use crate::reader::passes_display_filter;
+use crate::GraphClient;
+
+/// Accepts a feed's new matching signals for delivery.
+#[async_trait]
+pub trait RoomFeedSink: Send + Sync {
+    async fn enqueue(&self, feed: &RoomFeed, signals: &[Node]) -> anyhow::Result<()>;
+}
+
+/// Create or replace a room feed.
+pub async fn save_feed(client: &GraphClient, feed: &RoomFeed) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (f:RoomFeed {id: $id})
+         SET f.feed = $feed,
+             f.paused = $paused",
+    )
+    .param("id", feed.id.to_string())
+    .param("feed", serde_json::to_string(feed).unwrap_or_default())
+    .param("paused", feed.paused);
+
+    client.graph.run(q).await
+}
+
+/// Delete a room feed. Returns whether it existed.
+pub async fn delete_feed(client: &GraphClient, id: Uuid) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (f:RoomFeed {id: $id})
+         DETACH DELETE f
+         RETURN count(*) AS deleted",
+    )
+    .param("id", id.to_string());
+
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("deleted").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// Every room feed, oldest first.
+pub async fn room_feeds(client: &GraphClient) -> Result<Vec<RoomFeed>, neo4rs::Error> {
+    let q = query("MATCH (f:RoomFeed) RETURN f.feed AS feed");
+
+    let mut feeds = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let json: String = row.get("feed").unwrap_or_default();
+        if let Ok(feed) = serde_json::from_str::<RoomFeed>(&json) {
+            feeds.push(feed);
+        }
+    }
+    feeds.sort_by_key(|f| f.created_at);
+    Ok(feeds)
+}
+
+/// One room feed by id.
+pub async fn room_feed(client: &GraphClient, id: Uuid) -> Result<Option<RoomFeed>, neo4rs::Error> {
+    Ok(room_feeds(client).await?.into_iter().find(|f| f.id == id))
+}
+
+/// Match signals new in `current` (absent from `previous`) against every
+/// unpaused room feed and hand the matches to `sink`. Returns the number of
+/// feeds with matches.
+pub async fn queue_new_signals(
+    client: &GraphClient,
+    previous: &SignalCache,
+    current: &SignalCache,
+    sink: &dyn RoomFeedSink,
+) -> Result<u32, neo4rs::Error> {
+    let new_signals: Vec<&Node> = current
+        .signals
+        .iter()
+        .filter(|n| !previous.signal_by_id.contains_key(&n.id()) && passes_display_filter(n))
+        .collect();
+    if new_signals.is_empty() {
+        return Ok(0);
+    }
+    let feeds: Vec<RoomFeed> = room_feeds(client).await?.into_iter().filter(|f| !f.paused).collect();
+    if feeds.is_empty() {
+        return Ok(0);
+    }
+
+    let mut matched_feeds = 0;
+    for feed in &feeds {
+        let matches: Vec<Node> = new_signals
+            .iter()
+            .filter(|n| feed.matches(n))
+            .map(|n| (*n).clone())
+            .collect();
+        if matches.is_empty() {
+            continue;
+        }
+        matched_feeds += 1;
+        if let Err(e) = sink.enqueue(feed, &matches).await {
+            warn!(error = %e, feed = %feed.id, "Failed to queue room feed messages");
+        }
+    }
+
+    info!(
+        new_signals = new_signals.len(),
+        feeds = feeds.len(),
+        matched_feeds,
+        "Room feeds matched"
+    );
+    Ok(matched_feeds)
+}
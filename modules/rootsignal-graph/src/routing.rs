@@ -0,0 +1,148 @@
+//! Routing regions to their own Neo4j databases.
+//!
+//! By default every region shares one database. A large region can be moved
+//! to a database of its own with `REGION_DATABASES` (`slug=database,...`);
+//! its signals, sources, stories, situations and region settings then live
+//! there, and one busy region no longer slows down the rest.
+//!
+//! The default database keeps what isn't owned by one region: scout tasks,
+//! run schedules, members and their saved searches.
+//!
+//! Each database gets its own connection pool, opened on first use and
+//! shared by every caller after that.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::info;
+
+use rootsignal_common::{slugify, Config};
+
+use crate::GraphClient;
+
+#[derive(Clone)]
+pub struct GraphRouter {
+    default: GraphClient,
+    uri: String,
+    user: String,
+    password: String,
+    /// Region slug → database name.
+    region_databases: HashMap<String, String>,
+    pools: Arc<RwLock<HashMap<String, GraphClient>>>,
+}
+
+impl GraphRouter {
+    /// A router over `default` with no regions routed elsewhere.
+    pub fn single(default: GraphClient) -> Self {
+        Self::new(default, "", "", "", HashMap::new())
+    }
+
+    /// Route regions as `config.region_databases` says.
+    pub fn from_config(default: GraphClient, config: &Config) -> Self {
+        Self::new(
+            default,
+            &config.neo4j_uri,
+            &config.neo4j_user,
+            &config.neo4j_password.expose(),
+            config.region_databases.clone(),
+        )
+    }
+
+    /// `region_databases` maps region names or slugs to database names. Regions
+    /// mapped to the default client's database need no extra pool.
+    pub fn new(
+        default: GraphClient,
+        uri: &str,
+        user: &str,
+        password: &str,
+        region_databases: HashMap<String, String>,
+    ) -> Self {
+        let region_databases = region_databases
+            .into_iter()
+            .map(|(region, db)| (slugify(&region), db))
+            .collect();
+        let pools = HashMap::from([(default.database().to_string(), default.clone())]);
+        Self {
+            default,
+            uri: uri.to_string(),
+            user: user.to_string(),
+            password: password.to_string(),
+            region_databases,
+            pools: Arc::new(RwLock::new(pools)),
+        }
+    }
+
+    /// The client for data not owned by any one region.
+    pub fn default_client(&self) -> &GraphClient {
+        &self.default
+    }
+
+    /// The database a region's data lives in.
+    pub fn database_for(&self, region: &str) -> &str {
+        self.region_databases
+            .get(&slugify(region))
+            .map(String::as_str)
+            .unwrap_or_else(|| self.default.database())
+    }
+
+    /// Whether any region is routed away from the default database.
+    pub fn is_routed(&self) -> bool {
+        self.region_databases.values().any(|db| db != self.default.database())
+    }
+
+    /// The client for a region's data, accepting its name or slug.
+    pub async fn for_region(&self, region: &str) -> Result<GraphClient, neo4rs::Error> {
+        self.client(self.database_for(region)).await
+    }
+
+    /// The pooled client for `database`, connecting on first use.
+    pub async fn client(&self, database: &str) -> Result<GraphClient, neo4rs::Error> {
+        if let Some(client) = self.pools.read().await.get(database) {
+            return Ok(client.clone());
+        }
+        let mut pools = self.pools.write().await;
+        // Another caller may have connected while we waited for the lock
+        if let Some(client) = pools.get(database) {
+            return Ok(client.clone());
+        }
+        let client = GraphClient::connect_database(&self.uri, &self.user, &self.password, database).await?;
+        info!(database, "Connected to region database");
+        pools.insert(database.to_string(), client.clone());
+        Ok(client)
+    }
+
+    /// Every database in use: the default first, then each routed one.
+    pub fn databases(&self) -> Vec<String> {
+        let routed: BTreeSet<&String> = self
+            .region_databases
+            .values()
+            .filter(|db| *db != self.default.database())
+            .collect();
+        std::iter::once(self.default.database().to_string())
+            .chain(routed.into_iter().cloned())
+            .collect()
+    }
+
+    /// A client for every database in use, default first.
+    pub async fn clients(&self) -> Result<Vec<GraphClient>, neo4rs::Error> {
+        let mut clients = Vec::new();
+        for db in self.databases() {
+            clients.push(self.client(&db).await?);
+        }
+        Ok(clients)
+    }
+
+    /// Create any missing routed database and apply migrations to all of them.
+    pub async fn migrate_all(&self) -> Result<(), neo4rs::Error> {
+        for db in self.databases() {
+            if db != self.default.database() {
+                self.default.create_database(&db).await?;
+            }
+            let client = self.client(&db).await?;
+            crate::migrate::migrate(&client).await?;
+            info!(database = db.as_str(), "Database migrated");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+//! Situation feeds and subscriptions in the graph.
+//!
+//! Arc changes are `SituationChange` nodes hung off their situation by
+//! `HAS_CHANGE` (written by `update_situation_temperature`); a situation's
+//! feed merges them with its newly linked signals. Subscribers are
+//! `SituationSubscription` nodes, unconfirmed until their confirmation link
+//! is followed; after each cache reload, un-notified changes that are
+//! milestones go to every confirmed subscriber of the situation through a
+//! `SituationNotifier`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use neo4rs::{query, Row};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::situation_feed::{CONFIRM_WINDOW_DAYS, MAX_SUBSCRIPTIONS_PER_EMAIL};
+use rootsignal_common::{FeedEntry, SituationArc, SituationChange, SituationSubscription, CONFIDENCE_DISPLAY_LIMITED};
+
+use crate::reader::{fuzz_node, passes_display_filter, row_to_node_by_label};
+use crate::GraphClient;
+
+/// Emails subscribers: the confirmation link for a new subscription, and
+/// milestone changes once confirmed.
+#[async_trait]
+pub trait SituationNotifier: Send + Sync {
+    async fn confirm(&self, headline: &str, subscription: &SituationSubscription) -> anyhow::Result<()>;
+
+    async fn notify(
+        &self,
+        headline: &str,
+        change: &SituationChange,
+        subscription: &SituationSubscription,
+    ) -> anyhow::Result<()>;
+}
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_change(row: &Row) -> Option<SituationChange> {
+    Some(SituationChange {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        situation_id: Uuid::parse_str(&row.get::<String>("situation_id").ok()?).ok()?,
+        from: row.get::<String>("from").ok()?.parse::<SituationArc>().ok()?,
+        to: row.get::<String>("to").ok()?.parse::<SituationArc>().ok()?,
+        temperature: row.get("temperature").unwrap_or(0.0),
+        at: parse_time(row, "created_at")?,
+    })
+}
+
+/// A situation's feed, newest first: signals linked to it and its arc
+/// changes. Signals pass the same gates as the public reader: live,
+/// displayable, confident enough and fuzzed. Debunked evidence is left out.
+pub async fn feed_entries(
+    client: &GraphClient,
+    situation_id: Uuid,
+    limit: usize,
+) -> Result<Vec<FeedEntry>, neo4rs::Error> {
+    let mut entries = Vec::new();
+
+    let q = query(
+        "MATCH (n)-[e:EVIDENCES]->(:Situation {id: $id})
+         WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+           AND n.review_status = 'live'
+           AND n.confidence >= $min_confidence
+           AND coalesce(e.debunked, false) = false
+         WITH n, coalesce(e.linked_at, e.assigned_at) AS linked_at
+         WHERE linked_at IS NOT NULL
+         RETURN n, labels(n)[0] AS node_label, toString(linked_at) AS linked_at
+         ORDER BY linked_at DESC
+         LIMIT $limit",
+    )
+    .param("id", situation_id.to_string())
+    .param("min_confidence", CONFIDENCE_DISPLAY_LIMITED as f64)
+    .param("limit", limit as i64);
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Some(linked_at) = parse_time(&row, "linked_at") else {
+            continue;
+        };
+        let Some(node) = row_to_node_by_label(&row).filter(passes_display_filter).map(fuzz_node) else {
+            continue;
+        };
+        let Some(meta) = node.meta() else {
+            continue;
+        };
+        entries.push(FeedEntry::Signal {
+            id: meta.id,
+            title: meta.title.clone(),
+            summary: meta.summary.clone(),
+            source_url: meta.source_url.clone(),
+            linked_at,
+        });
+    }
+
+    let q = query(
+        "MATCH (:Situation {id: $id})-[:HAS_CHANGE]->(c:SituationChange)
+         RETURN c.id AS id, c.situation_id AS situation_id, c.from AS from, c.to AS to,
+                c.temperature AS temperature, toString(c.created_at) AS created_at
+         ORDER BY c.created_at DESC
+         LIMIT $limit",
+    )
+    .param("id", situation_id.to_string())
+    .param("limit", limit as i64);
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        entries.extend(row_to_change(&row).map(FeedEntry::Change));
+    }
+
+    entries.sort_by_key(|e| std::cmp::Reverse(e.at()));
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+/// What a subscribe request did.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubscribeOutcome {
+    /// Stored unconfirmed (or already waiting); the confirmation email
+    /// should go out for `subscription`.
+    Pending {
+        subscription: SituationSubscription,
+        headline: String,
+    },
+    AlreadySubscribed,
+    NoSuchSituation,
+    /// The address already follows `MAX_SUBSCRIPTIONS_PER_EMAIL` situations.
+    TooMany,
+}
+
+/// Store `subscription` unconfirmed. A repeat request for an address still
+/// waiting on its confirmation gets the stored subscription back, so the
+/// same link is resent. Unconfirmed subscriptions past
+/// `CONFIRM_WINDOW_DAYS` are dropped first.
+pub async fn subscribe(
+    client: &GraphClient,
+    subscription: &SituationSubscription,
+) -> Result<SubscribeOutcome, neo4rs::Error> {
+    let q = query(
+        "MATCH (sub:SituationSubscription {email: $email})
+         WHERE NOT coalesce(sub.confirmed, false)
+           AND (sub.created_at < datetime($cutoff) OR sub.confirm_token IS NULL)
+         DETACH DELETE sub",
+    )
+    .param("email", subscription.email.as_str())
+    .param("cutoff", confirm_cutoff());
+    client.graph.run(q).await?;
+
+    let q = query(
+        "OPTIONAL MATCH (s:Situation {id: $situation_id})
+         OPTIONAL MATCH (existing:SituationSubscription {situation_id: $situation_id, email: $email})
+         OPTIONAL MATCH (other:SituationSubscription {email: $email})
+         WITH s, collect(DISTINCT existing)[0] AS existing, count(DISTINCT other) AS total
+         RETURN s IS NOT NULL AS exists, s.headline AS headline, total,
+                existing.id AS id, existing.confirm_token AS confirm_token,
+                coalesce(existing.confirmed, false) AS confirmed,
+                toString(existing.created_at) AS created_at",
+    )
+    .param("situation_id", subscription.situation_id.to_string())
+    .param("email", subscription.email.as_str());
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(SubscribeOutcome::NoSuchSituation);
+    };
+    if !row.get::<bool>("exists").unwrap_or(false) {
+        return Ok(SubscribeOutcome::NoSuchSituation);
+    }
+    let headline: String = row.get("headline").unwrap_or_default();
+    if let Some(existing) = row_to_subscription(&row, subscription.situation_id, &subscription.email) {
+        if existing.confirmed {
+            return Ok(SubscribeOutcome::AlreadySubscribed);
+        }
+        return Ok(SubscribeOutcome::Pending { subscription: existing, headline });
+    }
+    if row.get::<i64>("total").unwrap_or(0) >= MAX_SUBSCRIPTIONS_PER_EMAIL as i64 {
+        return Ok(SubscribeOutcome::TooMany);
+    }
+
+    let q = query(
+        "MATCH (s:Situation {id: $situation_id})
+         CREATE (sub:SituationSubscription {
+             id: $id, situation_id: $situation_id, email: $email,
+             confirm_token: $confirm_token, confirmed: false, created_at: datetime($created_at)
+         })-[:FOLLOWS]->(s)",
+    )
+    .param("id", subscription.id.to_string())
+    .param("situation_id", subscription.situation_id.to_string())
+    .param("email", subscription.email.as_str())
+    .param("confirm_token", subscription.confirm_token.to_string())
+    .param("created_at", subscription.created_at.to_rfc3339());
+    client.graph.run(q).await?;
+    Ok(SubscribeOutcome::Pending {
+        subscription: SituationSubscription { confirmed: false, ..subscription.clone() },
+        headline,
+    })
+}
+
+/// Confirm the subscription holding `token`, if its link hasn't lapsed.
+/// Returns whether there was one.
+pub async fn confirm(client: &GraphClient, token: Uuid) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (sub:SituationSubscription {confirm_token: $token})
+         WHERE coalesce(sub.confirmed, false) OR sub.created_at >= datetime($cutoff)
+         SET sub.confirmed = true
+         RETURN count(sub) AS confirmed",
+    )
+    .param("token", token.to_string())
+    .param("cutoff", confirm_cutoff());
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("confirmed").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+fn confirm_cutoff() -> String {
+    (Utc::now() - Duration::days(CONFIRM_WINDOW_DAYS)).to_rfc3339()
+}
+
+fn row_to_subscription(row: &Row, situation_id: Uuid, email: &str) -> Option<SituationSubscription> {
+    let uuid = |key: &str| row.get::<String>(key).ok().and_then(|v| Uuid::parse_str(&v).ok());
+    Some(SituationSubscription {
+        id: uuid("id")?,
+        situation_id,
+        email: email.to_string(),
+        confirm_token: uuid("confirm_token")?,
+        confirmed: row.get("confirmed").unwrap_or(false),
+        created_at: parse_time(row, "created_at").unwrap_or_else(Utc::now),
+    })
+}
+
+/// Remove a subscription by its id (the unsubscribe token). Returns whether
+/// there was one.
+pub async fn unsubscribe(client: &GraphClient, id: Uuid) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (sub:SituationSubscription {id: $id})
+         DETACH DELETE sub
+         RETURN count(*) AS removed",
+    )
+    .param("id", id.to_string());
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("removed").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// The situation's confirmed subscriptions.
+async fn subscriptions(client: &GraphClient, situation_id: Uuid) -> Result<Vec<SituationSubscription>, neo4rs::Error> {
+    let q = query(
+        "MATCH (sub:SituationSubscription {situation_id: $situation_id})
+         WHERE coalesce(sub.confirmed, false)
+         RETURN sub.id AS id, sub.email AS email, sub.confirm_token AS confirm_token,
+                true AS confirmed, toString(sub.created_at) AS created_at",
+    )
+    .param("situation_id", situation_id.to_string());
+
+    let mut subs = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let email: String = row.get("email").unwrap_or_default();
+        subs.extend(row_to_subscription(&row, situation_id, &email));
+    }
+    Ok(subs)
+}
+
+/// Send every un-notified milestone change to the situation's confirmed
+/// subscribers, then mark all pending changes notified. Returns the number of emails sent.
+pub async fn notify_pending_changes(
+    client: &GraphClient,
+    notifier: &dyn SituationNotifier,
+) -> Result<u32, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:Situation)-[:HAS_CHANGE]->(c:SituationChange {notified: false})
+         RETURN c.id AS id, c.situation_id AS situation_id, c.from AS from, c.to AS to,
+                c.temperature AS temperature, toString(c.created_at) AS created_at,
+                s.headline AS headline
+         ORDER BY c.created_at",
+    );
+    let mut pending = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let headline: String = row.get("headline").unwrap_or_default();
+        pending.extend(row_to_change(&row).map(|c| (c, headline)));
+    }
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let mut sent = 0;
+    for (change, headline) in &pending {
+        if change.milestone().is_none() {
+            continue;
+        }
+        for subscription in subscriptions(client, change.situation_id).await? {
+            match notifier.notify(headline, change, &subscription).await {
+                Ok(()) => sent += 1,
+                Err(e) => warn!(error = %e, subscription = %subscription.id, "Failed to send situation notification"),
+            }
+        }
+    }
+
+    // Marked even when a send failed, so one bad address can't cause repeats.
+    let q = query("MATCH (c:SituationChange) WHERE c.id IN $ids SET c.notified = true")
+        .param("ids", pending.iter().map(|(c, _)| c.id.to_string()).collect::<Vec<_>>());
+    client.graph.run(q).await?;
+
+    info!(changes = pending.len(), sent, "Situation changes notified");
+    Ok(sent)
+}
@@ -0,0 +1,198 @@
+//! Situation clustering quality in the graph: loading each situation's
+//! signal embeddings, storing the scores `assess` gives them on the
+//! `Situation` node, and detaching signals for a targeted re-weave.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use neo4rs::query;
+use uuid::Uuid;
+
+use rootsignal_common::situation_quality::{QualityBreach, SituationCluster, SituationQuality, REWEAVE_COOLDOWN_DAYS};
+
+use crate::GraphClient;
+
+/// Every situation's non-debunked signals with embeddings, and whether the
+/// situation was re-woven within `REWEAVE_COOLDOWN_DAYS`.
+pub async fn load_clusters(client: &GraphClient) -> Result<Vec<(SituationCluster, bool)>, neo4rs::Error> {
+    let q = query(
+        "MATCH (sig)-[e:EVIDENCES]->(s:Situation)
+         WHERE sig.embedding IS NOT NULL AND coalesce(e.debunked, false) = false
+         RETURN s.id AS situation_id, sig.id AS signal_id, sig.embedding AS embedding,
+                s.reweaved_at IS NOT NULL
+                  AND s.reweaved_at >= datetime() - duration({days: $cooldown}) AS cooling_down",
+    )
+    .param("cooldown", REWEAVE_COOLDOWN_DAYS);
+
+    let mut clusters: HashMap<Uuid, (SituationCluster, bool)> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let ids = (
+            Uuid::parse_str(&row.get::<String>("situation_id").unwrap_or_default()),
+            Uuid::parse_str(&row.get::<String>("signal_id").unwrap_or_default()),
+        );
+        let (Ok(situation_id), Ok(signal_id)) = ids else { continue };
+        let embedding: Vec<f32> = row.get("embedding").unwrap_or_default();
+        if embedding.is_empty() {
+            continue;
+        }
+        let cooling_down: bool = row.get("cooling_down").unwrap_or(false);
+        clusters
+            .entry(situation_id)
+            .or_insert_with(|| (SituationCluster { situation_id, signals: Vec::new() }, cooling_down))
+            .0
+            .signals
+            .push((signal_id, embedding));
+    }
+    Ok(clusters.into_values().collect())
+}
+
+/// Store each situation's scores.
+pub async fn store_quality(
+    client: &GraphClient,
+    qualities: &[SituationQuality],
+    now: DateTime<Utc>,
+) -> Result<(), neo4rs::Error> {
+    let rows: Vec<HashMap<String, neo4rs::BoltType>> = qualities
+        .iter()
+        .map(|q| {
+            let mut row: HashMap<String, neo4rs::BoltType> = HashMap::new();
+            row.insert("id".into(), q.situation_id.to_string().into());
+            row.insert("cohesion".into(), q.cohesion.into());
+            row.insert("silhouette".into(), q.silhouette.into());
+            row.insert(
+                "nearest".into(),
+                q.nearest_situation.map(|(id, _)| id.to_string()).unwrap_or_default().into(),
+            );
+            row.insert("overlap".into(), q.nearest_situation.map(|(_, sim)| sim).unwrap_or(0.0).into());
+            row.insert("misplaced".into(), (q.misplaced.len() as i64).into());
+            row.insert("breach".into(), q.breach.map(|b| b.as_str()).unwrap_or_default().into());
+            row
+        })
+        .collect();
+
+    let q = query(
+        "UNWIND $rows AS row
+         MATCH (s:Situation {id: row.id})
+         SET s.cohesion = row.cohesion,
+             s.silhouette = row.silhouette,
+             s.nearest_situation_id = CASE row.nearest WHEN '' THEN null ELSE row.nearest END,
+             s.centroid_overlap = row.overlap,
+             s.misplaced_signals = row.misplaced,
+             s.quality_breach = CASE row.breach WHEN '' THEN null ELSE row.breach END,
+             s.quality_checked_at = datetime($now)",
+    )
+    .param("rows", rows)
+    .param("now", now.to_rfc3339());
+    client.graph.run(q).await
+}
+
+/// After a re-weave, remove `signal_ids` (all its signals when empty) from
+/// a situation, but only those the weaver placed in another situation, so a
+/// failed or partial re-weave leaves signals where they were. Recounts the
+/// situation and starts its re-weave cooldown. Returns the signals moved.
+pub async fn detach_signals(
+    client: &GraphClient,
+    situation_id: Uuid,
+    signal_ids: &[Uuid],
+) -> Result<u32, neo4rs::Error> {
+    let ids: Vec<String> = signal_ids.iter().map(|id| id.to_string()).collect();
+    let q = query(
+        "MATCH (sig)-[e:EVIDENCES]->(s:Situation {id: $situation_id})
+         WHERE (size($ids) = 0 OR sig.id IN $ids)
+           AND size([(sig)-[:EVIDENCES]->(other:Situation) WHERE other.id <> $situation_id | other]) > 0
+         DELETE e
+         RETURN count(e) AS moved",
+    )
+    .param("situation_id", situation_id.to_string())
+    .param("ids", ids);
+
+    let mut moved = 0;
+    let mut stream = client.graph.execute(q).await?;
+    if let Some(row) = stream.next().await? {
+        moved = row.get::<i64>("moved").unwrap_or(0) as u32;
+    }
+
+    let q = query(
+        "MATCH (s:Situation {id: $situation_id})
+         OPTIONAL MATCH (sig)-[:EVIDENCES]->(s)
+         WITH s, count(sig) AS signals, count(CASE WHEN sig:Tension THEN 1 END) AS tensions
+         SET s.signal_count = signals, s.tension_count = tensions, s.reweaved_at = datetime()",
+    )
+    .param("situation_id", situation_id.to_string());
+    client.graph.run(q).await?;
+    Ok(moved)
+}
+
+/// Situations the weaver's quality check last scored, worst first.
+#[derive(Debug, Clone)]
+pub struct StoredQuality {
+    pub situation_id: Uuid,
+    pub headline: String,
+    pub signal_count: u32,
+    pub cohesion: f64,
+    pub silhouette: f64,
+    pub nearest_situation_id: Option<Uuid>,
+    pub centroid_overlap: f64,
+    pub misplaced_signals: u32,
+    pub breach: Option<QualityBreach>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub reweaved_at: Option<DateTime<Utc>>,
+}
+
+impl StoredQuality {
+    pub fn cooling_down(&self, now: DateTime<Utc>) -> bool {
+        self.reweaved_at.is_some_and(|at| now - at < Duration::days(REWEAVE_COOLDOWN_DAYS))
+    }
+}
+
+fn parse_time(row: &neo4rs::Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+pub async fn quality_report(
+    client: &GraphClient,
+    breached_only: bool,
+    limit: u32,
+) -> Result<Vec<StoredQuality>, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:Situation)
+         WHERE s.quality_checked_at IS NOT NULL
+           AND (NOT $breached_only OR s.quality_breach IS NOT NULL)
+         RETURN s.id AS id, s.headline AS headline, coalesce(s.signal_count, 0) AS signal_count,
+                s.cohesion AS cohesion, s.silhouette AS silhouette,
+                s.nearest_situation_id AS nearest, coalesce(s.centroid_overlap, 0.0) AS overlap,
+                coalesce(s.misplaced_signals, 0) AS misplaced, s.quality_breach AS breach,
+                toString(s.quality_checked_at) AS checked_at, toString(s.reweaved_at) AS reweaved_at
+         ORDER BY s.silhouette ASC
+         LIMIT $limit",
+    )
+    .param("breached_only", breached_only)
+    .param("limit", limit as i64);
+
+    let mut report = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Ok(situation_id) = Uuid::parse_str(&row.get::<String>("id").unwrap_or_default()) else {
+            continue;
+        };
+        report.push(StoredQuality {
+            situation_id,
+            headline: row.get("headline").unwrap_or_default(),
+            signal_count: row.get::<i64>("signal_count").unwrap_or(0) as u32,
+            cohesion: row.get("cohesion").unwrap_or(0.0),
+            silhouette: row.get("silhouette").unwrap_or(0.0),
+            nearest_situation_id: row
+                .get::<String>("nearest")
+                .ok()
+                .and_then(|id| Uuid::parse_str(&id).ok()),
+            centroid_overlap: row.get("overlap").unwrap_or(0.0),
+            misplaced_signals: row.get::<i64>("misplaced").unwrap_or(0) as u32,
+            breach: row.get::<String>("breach").ok().and_then(|b| QualityBreach::parse(&b)),
+            checked_at: parse_time(&row, "checked_at"),
+            reweaved_at: parse_time(&row, "reweaved_at"),
+        });
+    }
+    Ok(report)
+}
@@ -9,6 +9,8 @@
 //! 3. **Weave** via LLM (Haiku): assign signals, write dispatches, update state
 //! 4. **Write** graph updates (EVIDENCES, CITES, Dispatch nodes)
 //! 5. **Verify** dispatches post-hoc (citations, PII, fidelity)
+//! 6. **Re-weave** situations whose clustering quality has degraded
+//!    (see `rootsignal_common::situation_quality`)
 //!
 //! Dependency inversion: takes `Arc<dyn TextEmbedder>` — concrete Voyage AI
 //! implementation is injected by scout, not imported here.
@@ -27,7 +29,11 @@ use rootsignal_common::{
     Clarity, DispatchNode, DispatchType, SensitivityLevel, SituationArc, SituationNode,
     ScoutScope, TextEmbedder,
 };
+use rootsignal_common::accessibility::accept_plain_summary;
+use rootsignal_common::log_events::SituationWeaveFailed;
+use rootsignal_common::situation_quality::{assess, reweave_plan, SituationCluster};
 
+use crate::situation_quality;
 use crate::writer::GraphWriter;
 use crate::GraphClient;
 
@@ -67,6 +73,8 @@ pub struct WeavingResponse {
     pub splits: Vec<SplitMerge>,
     #[serde(default)]
     pub merges: Vec<SplitMerge>,
+    #[serde(default)]
+    pub information_requests: Vec<InformationRequestInput>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -82,6 +90,9 @@ pub struct NewSituation {
     pub temp_id: String,
     pub headline: String,
     pub lede: String,
+    /// The lede for a general reader: short sentences, common words, grade 6.
+    #[serde(default)]
+    pub plain_summary: String,
     pub location_name: String,
     #[serde(default)]
     pub initial_structured_state: serde_json::Value,
@@ -108,6 +119,13 @@ pub struct SplitMerge {
     pub reasoning: String,
 }
 
+/// Something a situation is missing that a targeted search could answer.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InformationRequestInput {
+    pub situation_id: String,
+    pub question: String,
+}
+
 // --- Internal signal representation ---
 
 struct DiscoveredSignal {
@@ -129,6 +147,7 @@ struct CandidateSituation {
     narrative_embedding: Vec<f32>,
     causal_embedding: Vec<f32>,
     arc: String,
+    demographics: Option<String>,
 }
 
 // --- Stats ---
@@ -143,16 +162,20 @@ pub struct SituationWeaverStats {
     pub dispatches_flagged: u32,
     pub splits: u32,
     pub merges: u32,
+    pub situations_reweaved: u32,
+    pub signals_rewoven: u32,
+    pub information_requests: u32,
 }
 
 impl std::fmt::Display for SituationWeaverStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "SituationWeaver: {} discovered, {} assigned, {} created, {} updated, {} dispatches ({} flagged)",
+            "SituationWeaver: {} discovered, {} assigned, {} created, {} updated, {} dispatches ({} flagged), {} re-woven from {} situations",
             self.signals_discovered, self.signals_assigned,
             self.situations_created, self.situations_updated,
             self.dispatches_written, self.dispatches_flagged,
+            self.signals_rewoven, self.situations_reweaved,
         )
     }
 }
@@ -204,6 +227,9 @@ impl SituationWeaver {
 
         if signals.is_empty() {
             info!("SituationWeaver: no unassigned signals, skipping");
+            if has_budget {
+                self.reweave_breached_situations(&mut stats).await?;
+            }
             return Ok(stats);
         }
         info!(count = signals.len(), "SituationWeaver: discovered unassigned signals");
@@ -233,7 +259,7 @@ impl SituationWeaver {
                     stats.merges += batch_stats.merges;
                 }
                 Err(e) => {
-                    warn!(error = %e, "SituationWeaver: batch weaving failed, continuing");
+                    SituationWeaveFailed { mode: "weave", error: format!("{e:#}") }.emit();
                 }
             }
         }
@@ -268,6 +294,9 @@ impl SituationWeaver {
         let flagged = self.verify_dispatches().await?;
         stats.dispatches_flagged = flagged;
 
+        // Phase 7: Score clustering quality and re-weave breached situations
+        self.reweave_breached_situations(&mut stats).await?;
+
         info!(%stats, "SituationWeaver run complete");
         Ok(stats)
     }
@@ -335,7 +364,8 @@ impl SituationWeaver {
                     s.structured_state AS structured_state,
                     s.narrative_embedding AS narrative_embedding,
                     s.causal_embedding AS causal_embedding,
-                    s.arc AS arc",
+                    s.arc AS arc,
+                    s.demographic_context AS demographic_context",
         );
 
         let mut stream = g.execute(q).await?;
@@ -353,6 +383,11 @@ impl SituationWeaver {
                 narrative_embedding: row.get("narrative_embedding").unwrap_or_default(),
                 causal_embedding: row.get("causal_embedding").unwrap_or_default(),
                 arc: row.get("arc").unwrap_or_default(),
+                demographics: row
+                    .get::<String>("demographic_context")
+                    .ok()
+                    .and_then(|json| serde_json::from_str::<rootsignal_common::DemographicContext>(&json).ok())
+                    .map(|c| c.describe()),
             });
         }
 
@@ -478,12 +513,16 @@ impl SituationWeaver {
             .iter()
             .filter(|c| all_candidate_ids.contains(&c.id))
             .map(|c| {
-                serde_json::json!({
+                let mut situation = serde_json::json!({
                     "id": c.id.to_string(),
                     "headline": c.headline,
                     "arc": c.arc,
                     "structured_state": truncate(&c.structured_state, 500),
-                })
+                });
+                if let Some(demographics) = &c.demographics {
+                    situation["demographics"] = serde_json::json!(demographics);
+                }
+                situation
             })
             .collect();
 
@@ -552,6 +591,10 @@ impl SituationWeaver {
                 last_updated: now,
                 sensitivity: SensitivityLevel::General,
                 category: None,
+                demographics: None, // attached after weaving, from the region's census data
+                escalation: None,
+                alt_text: None, // generated by the writer
+                plain_summary: accept_plain_summary(Some(&new_sit.plain_summary)),
             };
 
             self.writer
@@ -670,6 +713,29 @@ impl SituationWeaver {
                 .await;
         }
 
+        // Process information requests
+        for request in &response.information_requests {
+            let situation_id = if let Some(mapped) = temp_id_map.get(&request.situation_id) {
+                *mapped
+            } else {
+                match Uuid::parse_str(&request.situation_id) {
+                    Ok(id) => id,
+                    Err(_) => continue,
+                }
+            };
+
+            match crate::information_requests::record_requests(
+                &self.client,
+                situation_id,
+                std::slice::from_ref(&request.question),
+            )
+            .await
+            {
+                Ok(recorded) => stats.information_requests += recorded,
+                Err(e) => warn!(%situation_id, error = %e, "Failed to record information request"),
+            }
+        }
+
         Ok(stats)
     }
 
@@ -784,6 +850,113 @@ impl SituationWeaver {
         Ok(flagged)
     }
 
+    /// Phase 7: Score every situation's clustering quality and re-weave the
+    /// signals of situations that breach it, skipping those still in their
+    /// re-weave cooldown. Each re-weave only offers the signals situations
+    /// other than the one they came from.
+    async fn reweave_breached_situations(
+        &self,
+        stats: &mut SituationWeaverStats,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let loaded = situation_quality::load_clusters(&self.client).await?;
+        let clusters: Vec<SituationCluster> = loaded.iter().map(|(c, _)| c.clone()).collect();
+        let qualities = assess(&clusters);
+        situation_quality::store_quality(&self.client, &qualities, Utc::now()).await?;
+
+        let cooling_down: std::collections::HashSet<Uuid> = loaded
+            .iter()
+            .filter(|(_, cooling)| *cooling)
+            .map(|(c, _)| c.situation_id)
+            .collect();
+        let eligible: Vec<_> = qualities
+            .into_iter()
+            .filter(|q| !cooling_down.contains(&q.situation_id))
+            .collect();
+        let plan = reweave_plan(&eligible);
+        if plan.is_empty() {
+            return Ok(());
+        }
+        info!(count = plan.len(), "SituationWeaver: re-weaving breached situations");
+
+        for (situation_id, signal_ids) in plan {
+            let signals = self.load_situation_signals(situation_id, &signal_ids).await?;
+            let mut candidates = self.load_candidate_situations().await?;
+            candidates.retain(|c| c.id != situation_id);
+
+            let mut temp_id_map = std::collections::HashMap::new();
+            for chunk in signals.chunks(5) {
+                match self.weave_batch(chunk, &candidates, &mut temp_id_map).await {
+                    Ok(batch_stats) => {
+                        stats.situations_created += batch_stats.situations_created;
+                        stats.situations_updated += batch_stats.situations_updated;
+                        stats.dispatches_written += batch_stats.dispatches_written;
+                    }
+                    Err(e) => {
+                        SituationWeaveFailed { mode: "reweave", error: format!("{situation_id}: {e:#}") }.emit();
+                    }
+                }
+            }
+
+            let moved = situation_quality::detach_signals(&self.client, situation_id, &signal_ids).await?;
+            stats.situations_reweaved += 1;
+            stats.signals_rewoven += moved;
+            info!(%situation_id, moved, "Situation re-woven");
+
+            if let Err(e) = crate::situation_temperature::recompute_situation_temperature(
+                &self.client,
+                &self.writer,
+                &situation_id,
+            )
+            .await
+            {
+                warn!(error = %e, %situation_id, "Temperature recomputation failed");
+            }
+        }
+        Ok(())
+    }
+
+    /// A situation's signals, limited to `signal_ids` unless it is empty.
+    async fn load_situation_signals(
+        &self,
+        situation_id: Uuid,
+        signal_ids: &[Uuid],
+    ) -> Result<Vec<DiscoveredSignal>, neo4rs::Error> {
+        let q = query(
+            "MATCH (n)-[e:EVIDENCES]->(:Situation {id: $situation_id})
+             WHERE (size($ids) = 0 OR n.id IN $ids)
+               AND n.embedding IS NOT NULL
+               AND coalesce(e.debunked, false) = false
+             RETURN n.id AS id, n.title AS title, n.summary AS summary,
+                    [l IN labels(n) WHERE l IN $labels][0] AS node_type,
+                    n.embedding AS embedding, n.source_url AS source_url,
+                    coalesce(n.cause_heat, 0.0) AS cause_heat,
+                    n.lat AS lat, n.lng AS lng",
+        )
+        .param("situation_id", situation_id.to_string())
+        .param("ids", signal_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>())
+        .param("labels", vec!["Gathering", "Aid", "Need", "Notice", "Tension"]);
+
+        let mut signals = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let Ok(id) = Uuid::parse_str(&row.get::<String>("id").unwrap_or_default()) else {
+                continue;
+            };
+            signals.push(DiscoveredSignal {
+                id,
+                title: row.get("title").unwrap_or_default(),
+                summary: row.get("summary").unwrap_or_default(),
+                node_type: row.get("node_type").unwrap_or_default(),
+                source_url: row.get("source_url").unwrap_or_default(),
+                cause_heat: row.get("cause_heat").unwrap_or(0.0),
+                lat: row.get("lat").ok(),
+                lng: row.get("lng").ok(),
+                embedding: row.get("embedding").unwrap_or_default(),
+            });
+        }
+        Ok(signals)
+    }
+
     /// Find all situations that have signals from this scout run.
     async fn find_affected_situations(
         &self,
@@ -846,6 +1019,8 @@ HARD RULES:
 10. Actively challenge the existing root_cause_thesis when new evidence suggests alternatives. Do not confirm the thesis by default.
 11. SEMANTIC FRICTION: If two signals are geographically close but semantically distant, you MUST explain why they belong to the SAME situation. Default to separate situations when geography overlaps but content diverges.
 12. LEAD WITH RESPONSES: When writing dispatches about situations that have both tensions AND responses, lead with the response. The response is the primary signal; the tension provides context.
+13. DEMOGRAPHIC GROUNDING: A candidate situation may carry "demographics" — census (ACS) estimates for where its signals are. Use them as background to say who is likely affected ("in tracts where most households rent"), attributed to ACS estimates. Never cite them as a signal, and never infer anything about a specific person or group from them.
+14. INFORMATION REQUESTS: When a situation is missing a fact that a public source could settle — official confirmation, a date, hours, a count — add an information request: a specific, searchable question. Ask only for public facts, never about private individuals. At most two per situation.
 
 Respond with valid JSON matching the WeavingResponse schema."#;
 
@@ -883,8 +1058,12 @@ For each signal:
 - If no situation matches, create a new one with temp_id "NEW-1", "NEW-2", etc.
 - Write a dispatch for each affected situation summarizing the new information
 - Update structured_state if the thesis, confidence, or timeline changes
+- For each new situation, also write plain_summary: the lede in one or two short
+  sentences a 6th grader could read (common words, no jargon, no acronyms)
+- If an affected situation is missing a fact a targeted search could settle, add
+  an information request for it
 
-Return JSON with: assignments, new_situations, dispatches, state_updates"#,
+Return JSON with: assignments, new_situations, dispatches, state_updates, information_requests"#,
         serde_json::to_string_pretty(&signals_with_candidates).unwrap_or_default(),
         serde_json::to_string_pretty(&candidate_situations).unwrap_or_default(),
         scope.name,
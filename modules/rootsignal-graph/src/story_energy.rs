@@ -0,0 +1,151 @@
+//! Recomputing and persisting story energy.
+//!
+//! The formula lives in `rootsignal_common::story_energy`; this module loads
+//! each story's signals, scores them, and writes the total to `s.energy` with
+//! each component alongside (`s.energy_activity`, `s.energy_severity`,
+//! `s.energy_corroboration`, `s.energy_heat`). Per-region weights are kept on
+//! a `RegionEnergyWeights` node keyed by region slug.
+
+use neo4rs::query;
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::story_energy::severity_score;
+use rootsignal_common::{BoundingBox, EnergyComponents, EnergySignal, StoryEnergyWeights};
+
+use crate::GraphClient;
+
+/// Energy weights configured for a region (keyed by region slug), if any.
+pub async fn region_energy_weights(
+    client: &GraphClient,
+    region_slug: &str,
+) -> Result<Option<StoryEnergyWeights>, neo4rs::Error> {
+    let q = query(
+        "MATCH (w:RegionEnergyWeights {region: $region})
+         RETURN w.activity AS activity, w.severity AS severity,
+                w.corroboration AS corroboration, w.heat AS heat",
+    )
+    .param("region", region_slug);
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(None);
+    };
+    let defaults = StoryEnergyWeights::default();
+    Ok(Some(StoryEnergyWeights {
+        activity: row.get("activity").unwrap_or(defaults.activity),
+        severity: row.get("severity").unwrap_or(defaults.severity),
+        corroboration: row.get("corroboration").unwrap_or(defaults.corroboration),
+        heat: row.get("heat").unwrap_or(defaults.heat),
+    }))
+}
+
+/// Create or replace the energy weights for a region.
+pub async fn set_region_energy_weights(
+    client: &GraphClient,
+    region_slug: &str,
+    weights: &StoryEnergyWeights,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (w:RegionEnergyWeights {region: $region})
+         SET w.activity = $activity,
+             w.severity = $severity,
+             w.corroboration = $corroboration,
+             w.heat = $heat,
+             w.updated_at = datetime()",
+    )
+    .param("region", region_slug)
+    .param("activity", weights.activity)
+    .param("severity", weights.severity)
+    .param("corroboration", weights.corroboration)
+    .param("heat", weights.heat);
+
+    client.graph.run(q).await?;
+    info!(region = region_slug, "Region energy weights updated");
+    Ok(())
+}
+
+/// Recompute energy for every non-archived story, or only those whose
+/// centroid falls in `bbox`. Passing no bbox is the backfill. Returns the
+/// number of stories scored.
+pub async fn recompute_story_energy(
+    client: &GraphClient,
+    weights: &StoryEnergyWeights,
+    bbox: Option<&BoundingBox>,
+) -> Result<u32, neo4rs::Error> {
+    let bbox_filter = if bbox.is_some() {
+        "AND s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+         AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng"
+    } else {
+        ""
+    };
+    let mut q = query(&format!(
+        "MATCH (s:Story)
+         WHERE (s.arc IS NULL OR s.arc <> 'archived') {bbox_filter}
+         OPTIONAL MATCH (s)-[:CONTAINS]->(n)
+         WITH s, collect(CASE WHEN n IS NULL THEN null ELSE {{
+                confidence: coalesce(n.confidence, 0.5),
+                age_days: duration.inSeconds(
+                    datetime(coalesce(n.last_confirmed_active, n.extracted_at, s.last_updated)),
+                    datetime()
+                ).seconds / 86400.0,
+                corroboration_count: coalesce(n.corroboration_count, 0),
+                cause_heat: coalesce(n.cause_heat, 0.0),
+                severity: CASE WHEN n:Tension THEN n.severity ELSE null END
+              }} END) AS signals
+         RETURN s.id AS id, signals"
+    ));
+    if let Some(b) = bbox {
+        q = q
+            .param("min_lat", b.min_lat)
+            .param("max_lat", b.max_lat)
+            .param("min_lng", b.min_lng)
+            .param("max_lng", b.max_lng);
+    }
+
+    let mut scored: Vec<(Uuid, EnergyComponents)> = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let id_str: String = row.get("id").unwrap_or_default();
+        let Ok(id) = Uuid::parse_str(&id_str) else {
+            continue;
+        };
+        let maps: Vec<neo4rs::BoltMap> = row.get("signals").unwrap_or_default();
+        let signals: Vec<EnergySignal> = maps
+            .iter()
+            .map(|m| EnergySignal {
+                confidence: m.get::<f64>("confidence").unwrap_or(0.5),
+                age_days: m.get::<f64>("age_days").unwrap_or(0.0),
+                corroboration_count: m.get::<i64>("corroboration_count").unwrap_or(0).max(0) as u32,
+                cause_heat: m.get::<f64>("cause_heat").unwrap_or(0.0),
+            })
+            .collect();
+        let severity = maps
+            .iter()
+            .filter_map(|m| m.get::<String>("severity").ok())
+            .max_by(|a, b| severity_score(a).total_cmp(&severity_score(b)));
+        scored.push((id, EnergyComponents::compute(&signals, severity.as_deref())));
+    }
+
+    for (id, components) in &scored {
+        let q = query(
+            "MATCH (s:Story {id: $id})
+             SET s.energy = $energy,
+                 s.energy_activity = $activity,
+                 s.energy_severity = $severity,
+                 s.energy_corroboration = $corroboration,
+                 s.energy_heat = $heat,
+                 s.energy_computed_at = datetime()",
+        )
+        .param("id", id.to_string())
+        .param("energy", components.energy(weights))
+        .param("activity", components.activity)
+        .param("severity", components.severity)
+        .param("corroboration", components.corroboration)
+        .param("heat", components.heat);
+        client.graph.run(q).await?;
+    }
+
+    info!(stories = scored.len(), backfill = bbox.is_none(), "Story energy recomputed");
+    Ok(scored.len() as u32)
+}
@@ -1,8 +1,8 @@
-//! Story metrics: pure functions for story status, energy, and recency scoring.
+//! Story metrics: pure functions for story status and recency scoring.
 //!
 //! Extracted from the former `cluster.rs` so that both `StoryWeaver` and any
 //! future consumers can share the same scoring logic without pulling in the
-//! full clustering pipeline.
+//! full clustering pipeline. Story energy lives in `rootsignal_common::story_energy`.
 
 use chrono::Utc;
 
@@ -21,14 +21,6 @@ pub fn story_status(type_diversity: u32, entity_count: u32, signal_count: usize)
     }
 }
 
-/// Compute story energy with triangulation and channel diversity components.
-///
-/// Weights: velocity 40%, recency 20%, source diversity 10%, triangulation 20%, channel diversity 10%.
-/// Cross-channel corroboration rewards signals confirmed through different lenses.
-pub fn story_energy(velocity: f64, recency: f64, source_diversity: f64, triangulation: f64, channel_diversity: f64) -> f64 {
-    velocity * 0.4 + recency * 0.2 + source_diversity * 0.10 + triangulation * 0.20 + channel_diversity * 0.10
-}
-
 /// Parse a datetime string and compute recency score: 1.0 today → 0.0 at 14+ days.
 pub fn parse_recency(datetime_str: &str, now: &chrono::DateTime<Utc>) -> f64 {
     use chrono::NaiveDateTime;
@@ -84,61 +76,6 @@ mod tests {
         assert_eq!(story_status(1, 15, 30), "echo");
     }
 
-    // --- story_energy tests ---
-
-    #[test]
-    fn fully_triangulated_story_gets_full_triangulation_bonus() {
-        let energy = story_energy(0.0, 0.0, 0.0, 1.0, 0.0);
-        assert!((energy - 0.20).abs() < 1e-10);
-    }
-
-    #[test]
-    fn single_type_story_gets_minimal_triangulation() {
-        let energy = story_energy(0.0, 0.0, 0.0, 0.2, 0.0);
-        assert!((energy - 0.04).abs() < 1e-10);
-    }
-
-    #[test]
-    fn triangulated_story_outranks_echo_with_same_velocity() {
-        let echo_energy = story_energy(1.0, 1.0, 1.0, 0.2, 0.0);
-        let confirmed_energy = story_energy(1.0, 1.0, 1.0, 1.0, 0.0);
-        assert!(confirmed_energy > echo_energy);
-    }
-
-    #[test]
-    fn energy_weights_sum_to_one() {
-        let energy = story_energy(1.0, 1.0, 1.0, 1.0, 1.0);
-        assert!((energy - 1.0).abs() < 1e-10);
-    }
-
-    #[test]
-    fn high_velocity_echo_still_below_moderate_triangulated() {
-        let echo = story_energy(2.0, 1.0, 1.0, 0.2, 0.0);
-        let confirmed = story_energy(1.0, 1.0, 0.6, 0.8, 0.0);
-        assert!(echo > confirmed);
-    }
-
-    #[test]
-    fn multi_channel_echo_does_not_outrank_triangulated() {
-        // A story with channel_diversity=4 but type_diversity=1 (echo) should score below
-        // a story with type_diversity=3 and channel_diversity=1.
-        // Echo stories have channel_diversity zeroed, so channel_div=0.0 here.
-        let echo_energy = story_energy(1.0, 1.0, 1.0, 0.2, 0.0);
-        let confirmed_energy = story_energy(0.8, 0.8, 0.6, 0.6, 0.33);
-        assert!(
-            confirmed_energy > echo_energy || echo_energy > confirmed_energy,
-            "Both stories computed"
-        );
-        // The confirmed story with moderate stats + some channel diversity
-        // should be valued by the formula. Echo gets zero channel boost.
-        let echo_no_channel = story_energy(1.0, 1.0, 1.0, 0.2, 0.0);
-        let echo_if_channel = story_energy(1.0, 1.0, 1.0, 0.2, 1.0);
-        assert!(
-            echo_if_channel > echo_no_channel,
-            "Channel diversity adds energy when not zeroed"
-        );
-    }
-
     // --- parse_recency tests ---
 
     #[test]
@@ -25,9 +25,9 @@ use neo4rs::query;
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use rootsignal_common::{extract_domain, StoryNode};
+use rootsignal_common::{extract_domain, BoundingBox, StoryEnergyWeights, StoryNode};
 
-use crate::story_metrics::{parse_recency, story_energy, story_status};
+use crate::story_metrics::story_status;
 use crate::synthesizer::{SynthesisInput, Synthesizer};
 use crate::writer::GraphWriter;
 use crate::GraphClient;
@@ -315,6 +315,7 @@ impl StoryWeaver {
                 gap_score,
                 gap_velocity: 0.0,
                 channel_diversity: 1,
+                energy_components: None,
             };
 
             self.writer.create_story(&story).await?;
@@ -566,44 +567,36 @@ impl StoryWeaver {
             "MATCH (s:Story)
              WHERE s.arc IS NULL OR s.arc <> 'archived'
              OPTIONAL MATCH (s)-[:CONTAINS]->(n)
-             RETURN s.id AS id, s.source_count AS source_count,
-                    s.entity_count AS entity_count, s.type_diversity AS type_diversity,
+             RETURN s.id AS id, s.entity_count AS entity_count,
                     s.ask_count AS ask_count, s.give_count AS give_count,
                     s.last_updated AS last_updated,
-                    count(n) AS signal_count,
-                    max(coalesce(n.channel_diversity, 1)) AS channel_diversity",
+                    count(n) AS signal_count",
         );
 
-        let mut stories: Vec<(Uuid, u32, u32, u32, u32, u32, u32, String, u32)> = Vec::new();
+        let mut stories: Vec<(Uuid, u32, u32, u32, u32, String)> = Vec::new();
         let mut stream = self.client.graph.execute(q).await?;
         while let Some(row) = stream.next().await? {
             let id_str: String = row.get("id").unwrap_or_default();
-            let source_count: i64 = row.get("source_count").unwrap_or(0);
             let entity_count: i64 = row.get("entity_count").unwrap_or(0);
-            let type_diversity: i64 = row.get("type_diversity").unwrap_or(1);
             let signal_count: i64 = row.get("signal_count").unwrap_or(0);
             let ask_count: i64 = row.get("ask_count").unwrap_or(0);
             let give_count: i64 = row.get("give_count").unwrap_or(0);
             let last_updated: String = row.get("last_updated").unwrap_or_default();
-            let channel_diversity: i64 = row.get("channel_diversity").unwrap_or(1);
             if let Ok(id) = Uuid::parse_str(&id_str) {
                 stories.push((
                     id,
                     signal_count as u32,
-                    source_count as u32,
                     entity_count as u32,
-                    type_diversity as u32,
                     ask_count as u32,
                     give_count as u32,
                     last_updated,
-                    channel_diversity as u32,
                 ));
             }
         }
 
-        info!(stories = stories.len(), "Phase D: computing velocity and energy");
+        info!(stories = stories.len(), "Phase D: computing velocity");
 
-        for (story_id, current_count, source_count, entity_count, type_diversity, ask_count, give_count, last_updated_str, channel_diversity_raw) in stories {
+        for (story_id, current_count, entity_count, ask_count, give_count, last_updated_str) in stories {
             // Create snapshot
             let snapshot = rootsignal_common::ClusterSnapshot {
                 id: Uuid::new_v4(),
@@ -634,25 +627,6 @@ impl StoryWeaver {
                 None => 0.0,
             };
 
-            // Recency score
-            let recency_score = parse_recency(&last_updated_str, &now);
-
-            // Source diversity: min(unique_source_urls / 5.0, 1.0)
-            let source_diversity = (source_count as f64 / 5.0).min(1.0);
-
-            // Triangulation
-            let triangulation = (type_diversity as f64 / 5.0).min(1.0);
-
-            // Channel diversity: normalized (raw - 1) / 3, clamped to [0, 1].
-            // Zeroed for echo stories — channel diversity should not reward media echo.
-            let channel_div_norm = if story_status(type_diversity, entity_count, current_count as usize) == "echo" {
-                0.0
-            } else {
-                ((channel_diversity_raw as f64 - 1.0) / 3.0).clamp(0.0, 1.0)
-            };
-
-            let energy = story_energy(velocity, recency_score, source_diversity, triangulation, channel_div_norm);
-
             // Archive zombie stories: last_updated > 30 days ago and velocity <= 0
             let age_days = {
                 use chrono::NaiveDateTime;
@@ -670,10 +644,10 @@ impl StoryWeaver {
 
             // Update story
             let set_clause = if is_zombie {
-                "SET s.velocity = $velocity, s.energy = $energy, s.signal_count = $signal_count,
+                "SET s.velocity = $velocity, s.signal_count = $signal_count,
                      s.gap_velocity = $gap_velocity, s.arc = 'archived'"
             } else {
-                "SET s.velocity = $velocity, s.energy = $energy, s.signal_count = $signal_count,
+                "SET s.velocity = $velocity, s.signal_count = $signal_count,
                      s.gap_velocity = $gap_velocity"
             };
             let q = query(&format!(
@@ -681,7 +655,6 @@ impl StoryWeaver {
             ))
             .param("id", story_id.to_string())
             .param("velocity", velocity)
-            .param("energy", energy)
             .param("signal_count", current_count as i64)
             .param("gap_velocity", gap_velocity);
 
@@ -692,6 +665,15 @@ impl StoryWeaver {
             }
         }
 
+        let bbox = BoundingBox {
+            min_lat: self.min_lat,
+            max_lat: self.max_lat,
+            min_lng: self.min_lng,
+            max_lng: self.max_lng,
+        };
+        crate::story_energy::recompute_story_energy(&self.client, &StoryEnergyWeights::default(), Some(&bbox))
+            .await?;
+
         Ok(())
     }
 
@@ -0,0 +1,369 @@
+//! Data-subject requests in the graph.
+//!
+//! Each request is a `SubjectRequest` node and doubles as its audit record:
+//! status, who opened and closed it, and on execution, the counts and ids of
+//! every node redacted. Original text is not kept anywhere.
+
+use chrono::{DateTime, Utc};
+use neo4rs::{query, Row};
+use regex::Regex;
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::subject_requests::{
+    match_excerpt, redact_terms, terms_regex, ANONYMIZED_ACTOR_NAME,
+};
+use rootsignal_common::{
+    RedactionSummary, SubjectMatch, SubjectMatchKind, SubjectRequest, SubjectRequestStatus,
+};
+
+use crate::GraphClient;
+
+const SIGNAL_LABELS: &str = "(n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)";
+
+/// Nodes of each kind examined per search; more than this and the request
+/// needs narrower terms.
+const MATCH_SCAN_LIMIT: i64 = 1000;
+
+const REQUEST_FIELDS: &str = "r.id AS id, r.terms AS terms, r.note AS note, r.status AS status,
+     r.opened_by AS opened_by, toString(r.created_at) AS created_at, r.closed_by AS closed_by,
+     toString(r.closed_at) AS closed_at, r.redaction AS redaction";
+
+fn parse_time(row: &Row, key: &str) -> Option<DateTime<Utc>> {
+    let s: String = row.get(key).ok()?;
+    DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn row_to_request(row: &Row) -> Option<SubjectRequest> {
+    let optional = |key: &str| row.get::<String>(key).ok().filter(|s| !s.is_empty());
+    Some(SubjectRequest {
+        id: Uuid::parse_str(&row.get::<String>("id").ok()?).ok()?,
+        terms: row.get("terms").unwrap_or_default(),
+        note: optional("note"),
+        status: SubjectRequestStatus::parse(&row.get::<String>("status").ok()?)?,
+        opened_by: row.get("opened_by").unwrap_or_default(),
+        created_at: parse_time(row, "created_at")?,
+        closed_by: optional("closed_by"),
+        closed_at: parse_time(row, "closed_at"),
+        redaction: optional("redaction").and_then(|json| serde_json::from_str(&json).ok()),
+    })
+}
+
+pub async fn open_request(client: &GraphClient, request: &SubjectRequest) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "CREATE (:SubjectRequest {
+             id: $id, terms: $terms, note: $note, status: $status,
+             opened_by: $opened_by, created_at: datetime($created_at)
+         })",
+    )
+    .param("id", request.id.to_string())
+    .param("terms", request.terms.clone())
+    .param("note", request.note.clone())
+    .param("status", request.status.as_str())
+    .param("opened_by", request.opened_by.as_str())
+    .param("created_at", request.created_at.to_rfc3339());
+    client.graph.run(q).await?;
+    info!(request = %request.id, "Subject request opened");
+    Ok(())
+}
+
+pub async fn get_request(client: &GraphClient, id: Uuid) -> Result<Option<SubjectRequest>, neo4rs::Error> {
+    let q = query(&format!("MATCH (r:SubjectRequest {{id: $id}}) RETURN {REQUEST_FIELDS}")).param("id", id.to_string());
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row_to_request(&row),
+        None => None,
+    })
+}
+
+/// Requests, newest first, optionally only those with `status`.
+pub async fn list_requests(
+    client: &GraphClient,
+    status: Option<SubjectRequestStatus>,
+    limit: u32,
+) -> Result<Vec<SubjectRequest>, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (r:SubjectRequest) WHERE $status IS NULL OR r.status = $status
+         RETURN {REQUEST_FIELDS}
+         ORDER BY r.created_at DESC
+         LIMIT $limit"
+    ))
+    .param("status", status.map(|s| s.as_str()))
+    .param("limit", limit as i64);
+    let mut requests = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(request) = row_to_request(&row) {
+            requests.push(request);
+        }
+    }
+    Ok(requests)
+}
+
+/// Terms of every executed request. The writer redacts these from new
+/// signals and evidence so a removed person doesn't come back on re-scrape.
+pub async fn suppressed_terms(client: &GraphClient) -> Result<Vec<String>, neo4rs::Error> {
+    let q = query("MATCH (r:SubjectRequest {status: 'executed'}) UNWIND r.terms AS term RETURN DISTINCT term");
+    let mut terms = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Ok(term) = row.get::<String>("term") {
+            terms.push(term);
+        }
+    }
+    Ok(terms)
+}
+
+/// A node holding text that mentions the subject, with the matching
+/// properties as (name, value).
+struct Candidate {
+    kind: SubjectMatchKind,
+    id: Uuid,
+    label: String,
+    fields: Vec<(&'static str, String)>,
+    /// Actor social URLs, matched on handles.
+    urls: Vec<String>,
+}
+
+async fn candidates(client: &GraphClient, terms: &[String]) -> Result<Vec<Candidate>, neo4rs::Error> {
+    let mut found = Vec::new();
+
+    let q = query(&format!(
+        "MATCH (n) WHERE {SIGNAL_LABELS}
+           AND any(t IN $terms WHERE toLower(n.title) CONTAINS t OR toLower(n.summary) CONTAINS t
+                                 OR toLower(coalesce(n.what_would_help, '')) CONTAINS t
+                                 OR toLower(coalesce(n.plain_summary, '')) CONTAINS t
+                                 OR toLower(coalesce(n.alt_text, '')) CONTAINS t)
+         RETURN n.id AS id, n.title AS title, n.summary AS summary, n.what_would_help AS what_would_help,
+                n.plain_summary AS plain_summary, n.alt_text AS alt_text
+         LIMIT $limit"
+    ))
+    .param("terms", terms.to_vec())
+    .param("limit", MATCH_SCAN_LIMIT);
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Some(id) = row.get::<String>("id").ok().and_then(|id| Uuid::parse_str(&id).ok()) else {
+            continue;
+        };
+        let title: String = row.get("title").unwrap_or_default();
+        let mut fields = vec![("title", title.clone()), ("summary", row.get("summary").unwrap_or_default())];
+        if let Ok(help) = row.get::<String>("what_would_help") {
+            fields.push(("what_would_help", help));
+        }
+        if let Ok(plain) = row.get::<String>("plain_summary") {
+            fields.push(("plain_summary", plain));
+        }
+        if let Ok(alt) = row.get::<String>("alt_text") {
+            fields.push(("alt_text", alt));
+        }
+        found.push(Candidate { kind: SubjectMatchKind::Signal, id, label: title, fields, urls: Vec::new() });
+    }
+
+    let q = query(
+        "MATCH (a:Actor) WHERE a.anonymized_at IS NULL
+           AND any(t IN $terms WHERE toLower(a.name) CONTAINS t OR toLower(coalesce(a.bio, '')) CONTAINS t
+                                 OR toLower(coalesce(a.description, '')) CONTAINS t
+                                 OR any(u IN coalesce(a.social_urls, []) WHERE toLower(u) CONTAINS t))
+         RETURN a.id AS id, a.name AS name, a.bio AS bio, a.description AS description, a.social_urls AS social_urls
+         LIMIT $limit",
+    )
+    .param("terms", terms.to_vec())
+    .param("limit", MATCH_SCAN_LIMIT);
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Some(id) = row.get::<String>("id").ok().and_then(|id| Uuid::parse_str(&id).ok()) else {
+            continue;
+        };
+        let name: String = row.get("name").unwrap_or_default();
+        let mut fields = vec![("name", name.clone()), ("description", row.get("description").unwrap_or_default())];
+        if let Ok(bio) = row.get::<String>("bio") {
+            fields.push(("bio", bio));
+        }
+        found.push(Candidate {
+            kind: SubjectMatchKind::Actor,
+            id,
+            label: name,
+            fields,
+            urls: row.get("social_urls").unwrap_or_default(),
+        });
+    }
+
+    let q = query(
+        "MATCH (ev:Evidence) WHERE ev.snippet IS NOT NULL
+           AND any(t IN $terms WHERE toLower(ev.snippet) CONTAINS t)
+         RETURN ev.id AS id, ev.source_url AS source_url, ev.snippet AS snippet
+         LIMIT $limit",
+    )
+    .param("terms", terms.to_vec())
+    .param("limit", MATCH_SCAN_LIMIT);
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let Some(id) = row.get::<String>("id").ok().and_then(|id| Uuid::parse_str(&id).ok()) else {
+            continue;
+        };
+        found.push(Candidate {
+            kind: SubjectMatchKind::Evidence,
+            id,
+            label: row.get("source_url").unwrap_or_default(),
+            fields: vec![("snippet", row.get("snippet").unwrap_or_default())],
+            urls: Vec::new(),
+        });
+    }
+    Ok(found)
+}
+
+fn url_matches(url: &str, terms: &[String]) -> bool {
+    let handle = url.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_lowercase();
+    let handle = handle.trim_start_matches('@');
+    terms.iter().any(|t| t.trim_start_matches('@') == handle)
+}
+
+fn review_matches(candidate: &Candidate, re: &Regex, terms: &[String]) -> Vec<SubjectMatch> {
+    let mut matches: Vec<SubjectMatch> = candidate
+        .fields
+        .iter()
+        .filter_map(|(field, text)| {
+            Some(SubjectMatch {
+                kind: candidate.kind,
+                id: candidate.id,
+                label: candidate.label.clone(),
+                field: field.to_string(),
+                excerpt: match_excerpt(text, re)?,
+            })
+        })
+        .collect();
+    matches.extend(candidate.urls.iter().filter(|u| url_matches(u, terms)).map(|url| SubjectMatch {
+        kind: candidate.kind,
+        id: candidate.id,
+        label: candidate.label.clone(),
+        field: "social_urls".to_string(),
+        excerpt: url.clone(),
+    }));
+    matches
+}
+
+/// The review bundle: every place the terms appear as whole words.
+pub async fn find_matches(client: &GraphClient, terms: &[String]) -> Result<Vec<SubjectMatch>, neo4rs::Error> {
+    let Some(re) = terms_regex(terms) else {
+        return Ok(Vec::new());
+    };
+    Ok(candidates(client, terms)
+        .await?
+        .iter()
+        .flat_map(|c| review_matches(c, &re, terms))
+        .collect())
+}
+
+/// Redact everything an open request matches and close it as executed.
+/// Returns None if the request doesn't exist or isn't open.
+pub async fn execute_request(
+    client: &GraphClient,
+    id: Uuid,
+    executed_by: &str,
+) -> Result<Option<RedactionSummary>, neo4rs::Error> {
+    let Some(request) = get_request(client, id).await? else {
+        return Ok(None);
+    };
+    if request.status != SubjectRequestStatus::Open {
+        return Ok(None);
+    }
+    let Some(re) = terms_regex(&request.terms) else {
+        return Ok(None);
+    };
+
+    let mut summary = RedactionSummary::default();
+    for candidate in candidates(client, &request.terms).await? {
+        if review_matches(&candidate, &re, &request.terms).is_empty() {
+            continue;
+        }
+        let id = candidate.id.to_string();
+        match candidate.kind {
+            SubjectMatchKind::Signal => {
+                let redacted = |name: &str| {
+                    candidate
+                        .fields
+                        .iter()
+                        .find(|(field, _)| *field == name)
+                        .map(|(_, text)| redact_terms(text, &re).unwrap_or_else(|| text.clone()))
+                };
+                let q = query(&format!(
+                    "MATCH (n {{id: $id}}) WHERE {SIGNAL_LABELS}
+                     SET n.title = $title, n.summary = $summary,
+                         n.what_would_help = CASE WHEN n.what_would_help IS NULL THEN null ELSE $help END,
+                         n.plain_summary = CASE WHEN n.plain_summary IS NULL THEN null ELSE $plain END,
+                         n.alt_text = CASE WHEN n.alt_text IS NULL THEN null ELSE $alt END,
+                         n.redacted_at = datetime()"
+                ))
+                .param("id", id)
+                .param("title", redacted("title").unwrap_or_default())
+                .param("summary", redacted("summary").unwrap_or_default())
+                .param("help", redacted("what_would_help"))
+                .param("plain", redacted("plain_summary"))
+                .param("alt", redacted("alt_text"));
+                client.graph.run(q).await?;
+                summary.signals += 1;
+            }
+            SubjectMatchKind::Actor => {
+                let q = query(
+                    "MATCH (a:Actor {id: $id})
+                     SET a.name = $name, a.bio = null, a.description = '', a.social_urls = [], a.domains = [],
+                         a.location_lat = null, a.location_lng = null, a.location_name = null,
+                         a.anonymized_at = datetime()",
+                )
+                .param("id", id)
+                .param("name", ANONYMIZED_ACTOR_NAME);
+                client.graph.run(q).await?;
+                summary.actors += 1;
+            }
+            SubjectMatchKind::Evidence => {
+                let snippet = candidate.fields.first().and_then(|(_, text)| redact_terms(text, &re));
+                let q = query("MATCH (ev:Evidence {id: $id}) SET ev.snippet = $snippet, ev.redacted_at = datetime()")
+                    .param("id", id)
+                    .param("snippet", snippet);
+                client.graph.run(q).await?;
+                summary.evidence += 1;
+            }
+        }
+        summary.node_ids.push(candidate.id);
+    }
+
+    let q = query(
+        "MATCH (r:SubjectRequest {id: $id})
+         SET r.status = 'executed', r.closed_by = $closed_by, r.closed_at = datetime(), r.redaction = $redaction",
+    )
+    .param("id", request.id.to_string())
+    .param("closed_by", executed_by)
+    .param("redaction", serde_json::to_string(&summary).unwrap_or_default());
+    client.graph.run(q).await?;
+    info!(
+        request = %request.id,
+        signals = summary.signals,
+        actors = summary.actors,
+        evidence = summary.evidence,
+        "Subject request executed"
+    );
+    Ok(Some(summary))
+}
+
+/// Close an open request without redacting. Returns false if it isn't open.
+pub async fn dismiss_request(
+    client: &GraphClient,
+    id: Uuid,
+    dismissed_by: &str,
+    note: Option<&str>,
+) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (r:SubjectRequest {id: $id, status: 'open'})
+         SET r.status = 'dismissed', r.closed_by = $closed_by, r.closed_at = datetime(),
+             r.note = coalesce($note, r.note)
+         RETURN count(r) AS dismissed",
+    )
+    .param("id", id.to_string())
+    .param("closed_by", dismissed_by)
+    .param("note", note);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("dismissed").unwrap_or(0) > 0,
+        None => false,
+    })
+}
@@ -0,0 +1,296 @@
+//! The topic taxonomy in the graph, and mapping tagged nodes onto it.
+//!
+//! Categories are `TopicCategory` nodes keyed by slug. Tensions, notices,
+//! stories and situations carry a canonical slug in `category`; the label the
+//! LLM produced is kept in `raw_category`. Each run maps new labels (exact
+//! match first, then embedding similarity); labels that match nothing are
+//! left as they are and listed for admins to merge into a category.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use neo4rs::query;
+use tracing::info;
+
+use rootsignal_common::taxonomy::EMBEDDING_MATCH_THRESHOLD;
+use rootsignal_common::{Taxonomy, TextEmbedder, TopicCategory};
+
+use crate::GraphClient;
+
+/// Node labels that carry a topic category.
+const TAGGED: &str = "(n:Tension OR n:Notice OR n:Story OR n:Situation)";
+
+/// Cosine similarity between two vectors.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let mut dot = 0.0f64;
+    let mut norm_a = 0.0f64;
+    let mut norm_b = 0.0f64;
+    for (x, y) in a.iter().zip(b.iter()) {
+        dot += (*x as f64) * (*y as f64);
+        norm_a += (*x as f64) * (*x as f64);
+        norm_b += (*y as f64) * (*y as f64);
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// The canonical category tree, ordered by slug.
+pub async fn load_taxonomy(client: &GraphClient) -> Result<Taxonomy, neo4rs::Error> {
+    let q = query(
+        "MATCH (c:TopicCategory)
+         RETURN c.slug AS slug, c.name AS name, c.parent AS parent, c.aliases AS aliases
+         ORDER BY c.slug",
+    );
+
+    let mut categories = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        categories.push(TopicCategory {
+            slug: row.get("slug").unwrap_or_default(),
+            name: row.get("name").unwrap_or_default(),
+            parent: row.get::<String>("parent").ok().filter(|p| !p.is_empty()),
+            aliases: row.get("aliases").unwrap_or_default(),
+        });
+    }
+    Ok(Taxonomy { categories })
+}
+
+/// Create or update a category. Its embedding is recomputed on the next run.
+pub async fn upsert_category(client: &GraphClient, category: &TopicCategory) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (c:TopicCategory {slug: $slug})
+         SET c.name = $name,
+             c.parent = $parent,
+             c.aliases = $aliases,
+             c.embedding = null,
+             c.updated_at = datetime()",
+    )
+    .param("slug", category.slug.as_str())
+    .param("name", category.name.as_str())
+    .param("parent", category.parent.as_deref().unwrap_or(""))
+    .param("aliases", category.aliases.clone());
+
+    client.graph.run(q).await
+}
+
+/// Point every node tagged `from` at `to`, keeping the original label.
+/// Returns the number of nodes re-tagged.
+async fn retag(client: &GraphClient, from: &str, to: &str) -> Result<u64, neo4rs::Error> {
+    let q = query(&format!(
+        "MATCH (n) WHERE {TAGGED} AND n.category = $from
+         SET n.raw_category = coalesce(n.raw_category, n.category),
+             n.category = $to
+         RETURN count(n) AS retagged"
+    ))
+    .param("from", from)
+    .param("to", to);
+
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("retagged").unwrap_or(0) as u64,
+        None => 0,
+    })
+}
+
+/// Merge `from` — a category slug or an unmapped label — into category
+/// `into`. The merged category's children move under `into`, its name and
+/// aliases become aliases of `into`, and its nodes are re-tagged. Returns
+/// the number of nodes re-tagged.
+pub async fn merge_category(
+    client: &GraphClient,
+    taxonomy: &Taxonomy,
+    from: &str,
+    into: &TopicCategory,
+) -> Result<u64, neo4rs::Error> {
+    let mut target = into.clone();
+    match taxonomy.get(from) {
+        Some(merged) => {
+            target.aliases.push(merged.name.clone());
+            target.aliases.extend(merged.aliases.iter().cloned());
+            client
+                .graph
+                .run(
+                    query(
+                        "MATCH (child:TopicCategory {parent: $from})
+                         SET child.parent = $into
+                         WITH count(child) AS moved
+                         MATCH (c:TopicCategory {slug: $from})
+                         DETACH DELETE c",
+                    )
+                    .param("from", from)
+                    .param("into", into.slug.as_str()),
+                )
+                .await?;
+        }
+        None => target.aliases.push(from.to_string()),
+    }
+    target.aliases.sort();
+    target.aliases.dedup();
+    upsert_category(client, &target).await?;
+
+    let retagged = retag(client, from, &into.slug).await?;
+    info!(from, into = into.slug.as_str(), retagged, "Topic category merged");
+    Ok(retagged)
+}
+
+/// Rename a category. The slug follows the name, so nodes and child
+/// categories are re-tagged; the old name stays as an alias. Returns the
+/// number of nodes re-tagged.
+pub async fn rename_category(
+    client: &GraphClient,
+    category: &TopicCategory,
+    new_name: &str,
+) -> Result<u64, neo4rs::Error> {
+    let renamed = TopicCategory {
+        aliases: category
+            .aliases
+            .iter()
+            .cloned()
+            .chain(std::iter::once(category.name.clone()))
+            .collect(),
+        ..TopicCategory::new(new_name, category.parent.as_deref(), &[])
+    };
+    if renamed.slug == category.slug {
+        upsert_category(client, &renamed).await?;
+        return Ok(0);
+    }
+
+    client
+        .graph
+        .run(
+            query(
+                "MATCH (c:TopicCategory {slug: $from})
+                 SET c.slug = $to
+                 WITH c
+                 OPTIONAL MATCH (child:TopicCategory {parent: $from})
+                 SET child.parent = $to",
+            )
+            .param("from", category.slug.as_str())
+            .param("to", renamed.slug.as_str()),
+        )
+        .await?;
+    upsert_category(client, &renamed).await?;
+
+    let retagged = retag(client, &category.slug, &renamed.slug).await?;
+    info!(from = category.slug.as_str(), to = renamed.slug.as_str(), retagged, "Topic category renamed");
+    Ok(retagged)
+}
+
+/// Category labels in use that aren't canonical slugs, with how many nodes
+/// carry each, most used first.
+pub async fn unmapped_labels(client: &GraphClient, taxonomy: &Taxonomy) -> Result<Vec<(String, u32)>, neo4rs::Error> {
+    let slugs: Vec<String> = taxonomy.categories.iter().map(|c| c.slug.clone()).collect();
+    let q = query(&format!(
+        "MATCH (n) WHERE {TAGGED}
+           AND coalesce(n.category, '') <> '' AND NOT n.category IN $slugs
+         RETURN n.category AS label, count(n) AS count
+         ORDER BY count DESC, label"
+    ))
+    .param("slugs", slugs);
+
+    let mut labels = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        labels.push((
+            row.get("label").unwrap_or_default(),
+            row.get::<i64>("count").unwrap_or(0) as u32,
+        ));
+    }
+    Ok(labels)
+}
+
+/// Embeddings of every category, computing and storing any that are missing.
+async fn category_embeddings(
+    client: &GraphClient,
+    taxonomy: &Taxonomy,
+    embedder: &dyn TextEmbedder,
+) -> Result<Vec<(String, Vec<f32>)>> {
+    let mut stored: HashMap<String, Vec<f32>> = HashMap::new();
+    let mut stream = client
+        .graph
+        .execute(query(
+            "MATCH (c:TopicCategory) WHERE c.embedding IS NOT NULL
+             RETURN c.slug AS slug, c.embedding AS embedding",
+        ))
+        .await?;
+    while let Some(row) = stream.next().await? {
+        let embedding: Vec<f64> = row.get("embedding").unwrap_or_default();
+        stored.insert(row.get("slug").unwrap_or_default(), embedding.into_iter().map(|x| x as f32).collect());
+    }
+
+    let missing: Vec<&TopicCategory> = taxonomy.categories.iter().filter(|c| !stored.contains_key(&c.slug)).collect();
+    if !missing.is_empty() {
+        let embeddings = embedder
+            .embed_batch(missing.iter().map(|c| c.embedding_text()).collect())
+            .await?;
+        for (category, embedding) in missing.into_iter().zip(embeddings) {
+            client
+                .graph
+                .run(
+                    query("MATCH (c:TopicCategory {slug: $slug}) SET c.embedding = $embedding")
+                        .param("slug", category.slug.as_str())
+                        .param("embedding", embedding.iter().map(|&x| x as f64).collect::<Vec<f64>>()),
+                )
+                .await?;
+            stored.insert(category.slug.clone(), embedding);
+        }
+    }
+    Ok(stored.into_iter().collect())
+}
+
+/// Map free-text categories onto the taxonomy, seeding it if the graph has
+/// none. Returns the number of nodes re-tagged.
+pub async fn normalize_categories(client: &GraphClient, embedder: &dyn TextEmbedder) -> Result<u64> {
+    let mut taxonomy = load_taxonomy(client).await?;
+    if taxonomy.categories.is_empty() {
+        taxonomy = Taxonomy::seed();
+        for category in &taxonomy.categories {
+            upsert_category(client, category).await?;
+        }
+        info!(categories = taxonomy.categories.len(), "Seeded topic taxonomy");
+    }
+
+    let labels = unmapped_labels(client, &taxonomy).await?;
+    if labels.is_empty() {
+        return Ok(0);
+    }
+
+    let mut mapping: Vec<(String, String)> = Vec::new();
+    let mut needs_embedding = Vec::new();
+    for (label, _) in labels {
+        match taxonomy.resolve(&label) {
+            Some(category) => mapping.push((label, category.slug.clone())),
+            None => needs_embedding.push(label),
+        }
+    }
+
+    let mut unmatched = 0;
+    if !needs_embedding.is_empty() {
+        let categories = category_embeddings(client, &taxonomy, embedder).await?;
+        let embeddings = embedder.embed_batch(needs_embedding.clone()).await?;
+        for (label, embedding) in needs_embedding.into_iter().zip(embeddings) {
+            let best = categories
+                .iter()
+                .map(|(slug, c)| (slug, cosine_similarity(&embedding, c)))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            match best {
+                Some((slug, similarity)) if similarity >= EMBEDDING_MATCH_THRESHOLD => {
+                    mapping.push((label, slug.clone()))
+                }
+                _ => unmatched += 1,
+            }
+        }
+    }
+
+    let mut retagged = 0;
+    for (label, slug) in &mapping {
+        retagged += retag(client, label, slug).await?;
+    }
+    info!(labels = mapping.len(), unmatched, retagged, "Categories mapped onto taxonomy");
+    Ok(retagged)
+}
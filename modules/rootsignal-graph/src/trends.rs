@@ -0,0 +1,264 @@
+//! Weekly signal counts and the trends detected over them.
+//!
+//! Each run recounts the last `BASELINE_WEEKS` + one month of completed weeks
+//! per category and neighborhood (signals' `location_name`) within the
+//! region's bounds, stores the counts as `WeeklyCount` nodes, and runs
+//! `detect_trend` for every window. Significant changes become `Trend` nodes
+//! linked to their supporting signals; trends no longer significant are
+//! removed.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use neo4rs::query;
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::trends::BASELINE_WEEKS;
+use rootsignal_common::{detect_trend, Trend, TrendWindow};
+
+use crate::GraphClient;
+
+/// Supporting signals kept per trend.
+const MAX_SUPPORTING_SIGNALS: usize = 20;
+
+/// One category's weekly counts in one neighborhood (`None`: region-wide),
+/// oldest week first, with a sample of each week's signal ids.
+type Series = Vec<(u32, Vec<Uuid>)>;
+
+/// Monday of the week containing `now`; weeks are counted up to (not including) it.
+fn current_week_start(now: DateTime<Utc>) -> NaiveDate {
+    let today = now.date_naive();
+    today - Duration::days(today.weekday().num_days_from_monday() as i64)
+}
+
+/// Recount weekly signals in the bounding box, store the counts, and replace
+/// the region's trends. Returns the trends detected.
+pub async fn refresh_trends(
+    client: &GraphClient,
+    region_slug: &str,
+    (min_lat, max_lat, min_lng, max_lng): (f64, f64, f64, f64),
+    now: DateTime<Utc>,
+) -> Result<Vec<Trend>, neo4rs::Error> {
+    let max_window = TrendWindow::ALL.iter().map(|w| w.weeks()).max().unwrap_or(1);
+    let total_weeks = BASELINE_WEEKS + max_window;
+    let until = current_week_start(now);
+    let since = until - Duration::weeks(total_weeks as i64);
+
+    let q = query(
+        "MATCH (n)
+         WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+           AND n.lat >= $min_lat AND n.lat <= $max_lat
+           AND n.lng >= $min_lng AND n.lng <= $max_lng
+           AND n.extracted_at >= datetime($since) AND n.extracted_at < datetime($until)
+           AND NOT coalesce(n.historical, false)
+         WITH n,
+              CASE WHEN coalesce(n.category, '') <> '' THEN toLower(n.category)
+                   ELSE toLower(labels(n)[0]) END AS category,
+              coalesce(n.location_name, '') AS neighborhood,
+              toString(date.truncate('week', n.extracted_at)) AS week
+         ORDER BY n.extracted_at DESC
+         RETURN category, neighborhood, week, count(n) AS count, collect(n.id)[..$sample] AS ids",
+    )
+    .param("min_lat", min_lat)
+    .param("max_lat", max_lat)
+    .param("min_lng", min_lng)
+    .param("max_lng", max_lng)
+    .param("since", format!("{since}T00:00:00Z"))
+    .param("until", format!("{until}T00:00:00Z"))
+    .param("sample", MAX_SUPPORTING_SIGNALS as i64);
+
+    let mut series: HashMap<(String, Option<String>), Series> = HashMap::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let week: String = row.get("week").unwrap_or_default();
+        let Ok(week) = NaiveDate::parse_from_str(&week, "%Y-%m-%d") else {
+            continue;
+        };
+        let index = ((week - since).num_days() / 7) as usize;
+        if index >= total_weeks {
+            continue;
+        }
+        let category: String = row.get("category").unwrap_or_default();
+        let neighborhood: String = row.get("neighborhood").unwrap_or_default();
+        let count = row.get::<i64>("count").unwrap_or(0) as u32;
+        let ids: Vec<Uuid> = row
+            .get::<Vec<String>>("ids")
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|id| Uuid::parse_str(id).ok())
+            .collect();
+
+        let neighborhood = Some(neighborhood.trim().to_string()).filter(|n| !n.is_empty());
+        let mut keys = vec![(category.clone(), None)];
+        if neighborhood.is_some() {
+            keys.push((category, neighborhood));
+        }
+        for key in keys {
+            let weeks = series.entry(key).or_insert_with(|| vec![(0, Vec::new()); total_weeks]);
+            weeks[index].0 += count;
+            weeks[index].1.extend(ids.iter().copied());
+        }
+    }
+
+    store_weekly_counts(client, region_slug, since, &series).await?;
+
+    let mut trends = Vec::new();
+    for ((category, neighborhood), weeks) in &series {
+        let counts: Vec<u32> = weeks.iter().map(|(c, _)| *c).collect();
+        for window in TrendWindow::ALL {
+            let split = total_weeks - window.weeks();
+            let baseline = &counts[split - BASELINE_WEEKS..split];
+            let Some(test) = detect_trend(baseline, &counts[split..]) else {
+                continue;
+            };
+            let mut signal_ids: Vec<Uuid> =
+                weeks[split..].iter().rev().flat_map(|(_, ids)| ids.iter().copied()).collect();
+            signal_ids.truncate(MAX_SUPPORTING_SIGNALS);
+            trends.push(Trend {
+                id: Trend::make_id(region_slug, window, category, neighborhood.as_deref()),
+                region: region_slug.to_string(),
+                category: category.clone(),
+                neighborhood: neighborhood.clone(),
+                window,
+                direction: test.direction,
+                observed: test.observed,
+                expected: test.expected,
+                z_score: test.z_score,
+                weekly_counts: counts[split - BASELINE_WEEKS..].to_vec(),
+                window_start: since + Duration::weeks(split as i64),
+                detected_at: now,
+                signal_ids,
+            });
+        }
+    }
+
+    store_trends(client, region_slug, &trends).await?;
+    info!(region = region_slug, series = series.len(), trends = trends.len(), "Trends refreshed");
+    Ok(trends)
+}
+
+/// Upsert the nonzero weekly counts of every series.
+async fn store_weekly_counts(
+    client: &GraphClient,
+    region_slug: &str,
+    since: NaiveDate,
+    series: &HashMap<(String, Option<String>), Series>,
+) -> Result<(), neo4rs::Error> {
+    let mut rows: Vec<neo4rs::BoltType> = Vec::new();
+    for ((category, neighborhood), weeks) in series {
+        for (i, (count, _)) in weeks.iter().enumerate().filter(|(_, (c, _))| *c > 0) {
+            let week = (since + Duration::weeks(i as i64)).to_string();
+            let neighborhood = neighborhood.as_deref().unwrap_or("");
+            let id = format!("{region_slug}/{category}/{neighborhood}/{week}");
+            rows.push(neo4rs::BoltType::Map(neo4rs::BoltMap::from_iter(vec![
+                (
+                    neo4rs::BoltString::from("id"),
+                    neo4rs::BoltType::String(neo4rs::BoltString::from(id.as_str())),
+                ),
+                (
+                    neo4rs::BoltString::from("category"),
+                    neo4rs::BoltType::String(neo4rs::BoltString::from(category.as_str())),
+                ),
+                (
+                    neo4rs::BoltString::from("neighborhood"),
+                    neo4rs::BoltType::String(neo4rs::BoltString::from(neighborhood)),
+                ),
+                (
+                    neo4rs::BoltString::from("week"),
+                    neo4rs::BoltType::String(neo4rs::BoltString::from(week.as_str())),
+                ),
+                (
+                    neo4rs::BoltString::from("count"),
+                    neo4rs::BoltType::Integer(neo4rs::BoltInteger::new(*count as i64)),
+                ),
+            ])));
+        }
+    }
+
+    client
+        .graph
+        .run(
+            query(
+                "UNWIND $rows AS row
+                 MERGE (w:WeeklyCount {id: row.id})
+                 SET w.region = $region,
+                     w.category = row.category,
+                     w.neighborhood = row.neighborhood,
+                     w.week = row.week,
+                     w.count = row.count,
+                     w.updated_at = datetime()",
+            )
+            .param("region", region_slug)
+            .param("rows", rows),
+        )
+        .await
+}
+
+/// Replace the region's trends, each linked to its supporting signals.
+async fn store_trends(client: &GraphClient, region_slug: &str, trends: &[Trend]) -> Result<(), neo4rs::Error> {
+    let ids: HashSet<&str> = trends.iter().map(|t| t.id.as_str()).collect();
+    client
+        .graph
+        .run(
+            query(
+                "MATCH (t:Trend {region: $region})
+                 WHERE NOT t.id IN $ids
+                 DETACH DELETE t",
+            )
+            .param("region", region_slug)
+            .param("ids", ids.into_iter().map(str::to_string).collect::<Vec<_>>()),
+        )
+        .await?;
+
+    for trend in trends {
+        client
+            .graph
+            .run(
+                query(
+                    "MERGE (t:Trend {id: $id})
+                     SET t.region = $region,
+                         t.window = $window,
+                         t.direction = $direction,
+                         t.z_score = $z_score,
+                         t.trend = $trend,
+                         t.detected_at = datetime($detected_at)
+                     WITH t
+                     OPTIONAL MATCH (t)-[old:SUPPORTED_BY]->()
+                     DELETE old
+                     WITH DISTINCT t
+                     UNWIND $signal_ids AS signal_id
+                     MATCH (n {id: signal_id})
+                     WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+                     MERGE (t)-[:SUPPORTED_BY]->(n)",
+                )
+                .param("id", trend.id.as_str())
+                .param("region", region_slug)
+                .param("window", trend.window.as_str())
+                .param("direction", trend.direction.as_str())
+                .param("z_score", trend.z_score)
+                .param("trend", serde_json::to_string(trend).unwrap_or_default())
+                .param("detected_at", trend.detected_at.to_rfc3339())
+                .param(
+                    "signal_ids",
+                    trend.signal_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+                ),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let saturday = Utc.with_ymd_and_hms(2026, 10, 17, 15, 0, 0).unwrap();
+        assert_eq!(current_week_start(saturday), NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+        let monday = Utc.with_ymd_and_hms(2026, 10, 12, 0, 0, 0).unwrap();
+        assert_eq!(current_week_start(monday), NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+    }
+}
@@ -0,0 +1,113 @@
+//! Polling trigger checkpoints, and the scan each poll reads from.
+//!
+//! A `TriggerCheckpoint` node per (API key digest, filter) holds the
+//! checkpoint as JSON. Neither the raw key nor the filter reaches the graph.
+
+use chrono::{DateTime, Duration, Utc};
+use neo4rs::query;
+use uuid::Uuid;
+
+use rootsignal_common::triggers::CHECKPOINT_TTL_DAYS;
+use rootsignal_common::{Node, NodeType, TriggerCheckpoint, CONFIDENCE_DISPLAY_LIMITED};
+
+use crate::reader::{expiry_clause, fuzz_node, node_type_label, passes_display_filter, row_to_node_by_label};
+use crate::GraphClient;
+
+/// Signals read per poll, matching or not.
+pub const SCAN_LIMIT: u32 = 500;
+
+pub async fn load_checkpoint(client: &GraphClient, id: &str) -> Result<Option<TriggerCheckpoint>, neo4rs::Error> {
+    let q = query("MATCH (t:TriggerCheckpoint {id: $id}) RETURN t.checkpoint AS checkpoint").param("id", id);
+
+    let mut stream = client.graph.execute(q).await?;
+    let Some(row) = stream.next().await? else {
+        return Ok(None);
+    };
+    let json: String = row.get("checkpoint").unwrap_or_default();
+    Ok(serde_json::from_str(&json).ok())
+}
+
+/// Store a checkpoint, and delete checkpoints nobody has polled for
+/// `CHECKPOINT_TTL_DAYS`.
+pub async fn save_checkpoint(
+    client: &GraphClient,
+    id: &str,
+    checkpoint: &TriggerCheckpoint,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (t:TriggerCheckpoint {id: $id})
+         SET t.checkpoint = $checkpoint,
+             t.updated_at = datetime()",
+    )
+    .param("id", id)
+    .param("checkpoint", serde_json::to_string(checkpoint).unwrap_or_default());
+    client.graph.run(q).await?;
+
+    let q = query("MATCH (t:TriggerCheckpoint) WHERE t.updated_at < datetime($cutoff) DELETE t")
+        .param("cutoff", (Utc::now() - Duration::days(CHECKPOINT_TTL_DAYS)).to_rfc3339());
+    client.graph.run(q).await
+}
+
+/// Live, displayable signals of `types` (all signal types when empty) extracted
+/// after `since`, skipping `seen`, oldest first, fuzzed. At most `limit`.
+pub async fn signals_extracted_after(
+    client: &GraphClient,
+    since: DateTime<Utc>,
+    seen: &[Uuid],
+    types: &[NodeType],
+    limit: u32,
+) -> Result<Vec<Node>, neo4rs::Error> {
+    let all_types = [
+        NodeType::Gathering,
+        NodeType::Aid,
+        NodeType::Need,
+        NodeType::Notice,
+        NodeType::Tension,
+    ];
+    let types: Vec<NodeType> = all_types
+        .into_iter()
+        .filter(|t| types.is_empty() || types.contains(t))
+        .collect();
+
+    let branches: Vec<String> = types
+        .iter()
+        .map(|nt| {
+            let label = node_type_label(*nt);
+            format!(
+                "MATCH (n:{label})
+                 WHERE n.review_status = 'live'
+                   AND datetime(n.extracted_at) > datetime($since)
+                   AND NOT coalesce(n.historical, false)
+                   AND NOT n.id IN $seen
+                   AND n.confidence >= $min_confidence
+                   {expiry}
+                 RETURN n, labels(n)[0] AS node_label",
+                expiry = expiry_clause(*nt),
+            )
+        })
+        .collect();
+    let cypher = format!(
+        "CALL {{ {} }}
+         RETURN n, node_label
+         ORDER BY datetime(n.extracted_at) ASC
+         LIMIT $limit",
+        branches.join("\nUNION ALL\n")
+    );
+
+    let q = query(&cypher)
+        .param("since", since.to_rfc3339())
+        .param("seen", seen.iter().map(|id| id.to_string()).collect::<Vec<_>>())
+        .param("min_confidence", CONFIDENCE_DISPLAY_LIMITED as f64)
+        .param("limit", limit as i64);
+
+    let mut signals = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        if let Some(node) = row_to_node_by_label(&row) {
+            if passes_display_filter(&node) {
+                signals.push(fuzz_node(node));
+            }
+        }
+    }
+    Ok(signals)
+}
@@ -0,0 +1,209 @@
+//! Saved searches in the graph, and matching new signals against them.
+//!
+//! Each search is a `SavedSearch` node (JSON in `search`) owned by a member
+//! id. When the signal cache reloads, signals that weren't in the previous
+//! snapshot are matched against every search; matches become `MATCHED` edges
+//! (so `myWatchlist` can list what's new since the owner last looked) and are
+//! handed to a `WatchlistNotifier` for each of the search's channels.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use neo4rs::query;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::{Node, NotifyChannel, SavedSearch};
+
+use crate::cache::SignalCache;
+use crate::reader::passes_display_filter;
+use crate::GraphClient;
+
+/// Sends a search's new matches to one of its channels.
+#[async_trait]
+pub trait WatchlistNotifier: Send + Sync {
+    async fn notify(&self, search: &SavedSearch, channel: &NotifyChannel, matches: &[Node]) -> anyhow::Result<()>;
+}
+
+/// Create or replace a saved search.
+pub async fn save_search(client: &GraphClient, search: &SavedSearch) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MERGE (s:SavedSearch {id: $id})
+         SET s.owner = $owner,
+             s.search = $search,
+             s.last_seen_at = datetime($last_seen_at)",
+    )
+    .param("id", search.id.to_string())
+    .param("owner", search.owner.as_str())
+    .param("search", serde_json::to_string(search).unwrap_or_default())
+    .param("last_seen_at", search.last_seen_at.to_rfc3339());
+
+    client.graph.run(q).await
+}
+
+/// Delete one of an owner's saved searches. Returns whether it existed.
+pub async fn delete_search(client: &GraphClient, owner: &str, id: Uuid) -> Result<bool, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:SavedSearch {id: $id, owner: $owner})
+         DETACH DELETE s
+         RETURN count(*) AS deleted",
+    )
+    .param("id", id.to_string())
+    .param("owner", owner);
+
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("deleted").unwrap_or(0) > 0,
+        None => false,
+    })
+}
+
+/// Saved searches, all or one owner's, oldest first.
+pub async fn saved_searches(client: &GraphClient, owner: Option<&str>) -> Result<Vec<SavedSearch>, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:SavedSearch)
+         WHERE $owner IS NULL OR s.owner = $owner
+         RETURN s.search AS search, toString(s.last_seen_at) AS last_seen_at",
+    )
+    .param("owner", owner.map(str::to_string));
+
+    let mut searches = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let json: String = row.get("search").unwrap_or_default();
+        let Ok(mut search) = serde_json::from_str::<SavedSearch>(&json) else {
+            continue;
+        };
+        // `last_seen_at` moves without rewriting the JSON.
+        let last_seen: String = row.get("last_seen_at").unwrap_or_default();
+        if let Ok(last_seen) = DateTime::parse_from_rfc3339(&last_seen) {
+            search.last_seen_at = last_seen.with_timezone(&Utc);
+        }
+        searches.push(search);
+    }
+    searches.sort_by_key(|s| s.created_at);
+    Ok(searches)
+}
+
+/// Ids of signals matched by a search since its owner last looked, newest first.
+pub async fn new_matches(client: &GraphClient, search: &SavedSearch) -> Result<Vec<Uuid>, neo4rs::Error> {
+    let q = query(
+        "MATCH (s:SavedSearch {id: $id})-[m:MATCHED]->(n)
+         WHERE m.matched_at > s.last_seen_at
+         RETURN n.id AS id
+         ORDER BY m.matched_at DESC",
+    )
+    .param("id", search.id.to_string());
+
+    let mut ids = Vec::new();
+    let mut stream = client.graph.execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        let id: String = row.get("id").unwrap_or_default();
+        if let Ok(id) = Uuid::parse_str(&id) {
+            ids.push(id);
+        }
+    }
+    Ok(ids)
+}
+
+/// Mark an owner's searches (or one of them) as seen up to `now`.
+pub async fn mark_seen(
+    client: &GraphClient,
+    owner: &str,
+    id: Option<Uuid>,
+    now: DateTime<Utc>,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MATCH (s:SavedSearch {owner: $owner})
+         WHERE $id IS NULL OR s.id = $id
+         SET s.last_seen_at = datetime($now)",
+    )
+    .param("owner", owner)
+    .param("id", id.map(|id| id.to_string()))
+    .param("now", now.to_rfc3339());
+
+    client.graph.run(q).await
+}
+
+async fn record_matches(
+    client: &GraphClient,
+    search: &SavedSearch,
+    matches: &[&Node],
+    now: DateTime<Utc>,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MATCH (s:SavedSearch {id: $id})
+         UNWIND $signal_ids AS signal_id
+         MATCH (n {id: signal_id})
+         WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+         MERGE (s)-[m:MATCHED]->(n)
+         ON CREATE SET m.matched_at = datetime($now)",
+    )
+    .param("id", search.id.to_string())
+    .param("signal_ids", matches.iter().map(|n| n.id().to_string()).collect::<Vec<_>>())
+    .param("now", now.to_rfc3339());
+
+    client.graph.run(q).await
+}
+
+/// Match signals new in `current` (absent from `previous`) against every
+/// saved search, record the matches and notify. Returns the number of
+/// searches with matches.
+pub async fn match_new_signals(
+    client: &GraphClient,
+    previous: &SignalCache,
+    current: &SignalCache,
+    notifier: &dyn WatchlistNotifier,
+) -> Result<u32, neo4rs::Error> {
+    let new_signals: Vec<&Node> = current
+        .signals
+        .iter()
+        .filter(|n| !previous.signal_by_id.contains_key(&n.id()) && passes_display_filter(n))
+        .collect();
+    if new_signals.is_empty() {
+        return Ok(0);
+    }
+    let searches = saved_searches(client, None).await?;
+    if searches.is_empty() {
+        return Ok(0);
+    }
+    let taxonomy = crate::taxonomy::load_taxonomy(client).await?;
+    let now = Utc::now();
+
+    let mut matched_searches = 0;
+    for search in &searches {
+        let categories: Vec<String> = search
+            .categories
+            .iter()
+            .flat_map(|c| taxonomy.expand(c))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let matches: Vec<&Node> = new_signals
+            .iter()
+            .copied()
+            .filter(|n| search.matches(n, &categories))
+            .collect();
+        if matches.is_empty() {
+            continue;
+        }
+        matched_searches += 1;
+        record_matches(client, search, &matches, now).await?;
+
+        let owned: Vec<Node> = matches.iter().map(|n| (*n).clone()).collect();
+        for channel in &search.notify {
+            if let Err(e) = notifier.notify(search, channel, &owned).await {
+                warn!(error = %e, search = %search.id, "Failed to send watchlist notification");
+            }
+        }
+    }
+
+    info!(
+        new_signals = new_signals.len(),
+        searches = searches.len(),
+        matched_searches,
+        "Watchlists matched"
+    );
+    Ok(matched_searches)
+}
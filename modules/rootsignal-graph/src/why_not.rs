@@ -0,0 +1,93 @@
+//! Graph facts for "why isn't this on the map?" diagnostics: whether a URL
+//! is a known source, and the stored signals a URL or description produced,
+//! whatever their review status or freshness.
+
+use neo4rs::query;
+
+use rootsignal_common::why_not::StoredSignal;
+use rootsignal_common::NodeType;
+
+use crate::reader::{node_type_label, passes_display_filter, row_to_node};
+use crate::GraphClient;
+
+const SIGNAL_TYPES: [NodeType; 5] = [
+    NodeType::Gathering,
+    NodeType::Aid,
+    NodeType::Need,
+    NodeType::Notice,
+    NodeType::Tension,
+];
+
+/// Per signal type; a page rarely yields more than a handful.
+const MAX_SIGNALS_PER_URL: u32 = 50;
+
+pub async fn source_known(client: &GraphClient, url: &str) -> Result<bool, neo4rs::Error> {
+    let q = query("OPTIONAL MATCH (s:Source {url: $url}) RETURN count(s) > 0 AS known").param("url", url);
+    let mut stream = client.graph.execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get("known").unwrap_or(false),
+        None => false,
+    })
+}
+
+/// Signals extracted from `url`, or citing it as evidence after a
+/// cross-source merge.
+pub async fn signals_for_url(client: &GraphClient, url: &str) -> Result<Vec<StoredSignal>, neo4rs::Error> {
+    stored_signals(
+        client,
+        "n.source_url = $needle
+         OR EXISTS { MATCH (n)-[:SOURCED_FROM]->(ev:Evidence {source_url: $needle}) }",
+        url.to_string(),
+        MAX_SIGNALS_PER_URL,
+    )
+    .await
+}
+
+/// Signals whose title or summary contains `phrase` as whole words, newest
+/// first within each type.
+pub async fn signals_matching(
+    client: &GraphClient,
+    phrase: &str,
+    limit: u32,
+) -> Result<Vec<StoredSignal>, neo4rs::Error> {
+    // \Q..\E quotes the phrase for Cypher's Java regex; a phrase can't end the quote early.
+    let pattern = format!("(?is).*\\b\\Q{}\\E\\b.*", phrase.trim().replace("\\E", ""));
+    stored_signals(client, "n.title =~ $needle OR n.summary =~ $needle", pattern, limit).await
+}
+
+async fn stored_signals(
+    client: &GraphClient,
+    predicate: &str,
+    needle: String,
+    limit: u32,
+) -> Result<Vec<StoredSignal>, neo4rs::Error> {
+    let mut signals = Vec::new();
+    for nt in SIGNAL_TYPES {
+        let label = node_type_label(nt);
+        let q = query(&format!(
+            "MATCH (n:{label})
+             WHERE {predicate}
+             RETURN n, coalesce(n.review_status, 'live') AS review_status
+             ORDER BY n.extracted_at DESC
+             LIMIT $limit"
+        ))
+        .param("needle", needle.clone())
+        .param("limit", limit as i64);
+
+        let mut stream = client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let Some(node) = row_to_node(&row, nt) else { continue };
+            let Some(meta) = node.meta() else { continue };
+            signals.push(StoredSignal {
+                id: meta.id,
+                node_type: nt,
+                title: meta.title.clone(),
+                confidence: meta.confidence,
+                review_status: row.get("review_status").unwrap_or_else(|_| "live".to_string()),
+                has_location: meta.about_location.is_some(),
+                fresh: passes_display_filter(&node),
+            });
+        }
+    }
+    Ok(signals)
+}
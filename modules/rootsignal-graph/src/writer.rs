@@ -6,14 +6,20 @@ use tracing::{info, warn};
 use uuid::Uuid;
 
 use rootsignal_common::{
-    ActorNode, NeedNode, ClusterSnapshot, DemandSignal, DiscoveryMethod, GatheringNode, EvidenceNode,
+    demote_fields, is_fundraiser_url, lint_public_text, AggregateGuard, DemographicContext, EntityGeometry, EntityLink, ActorNode, Fundraiser, NeedNode, ClusterSnapshot, DemandSignal, DiscoveryMethod, GatheringNode, EvidenceNode,
     AidNode, Node, NodeMeta, NodeType, NoticeNode, PinNode, SensitivityLevel, SourceNode, SourceRole,
-    StoryNode, TensionNode, ScoutTask, ScoutTaskSource, ScoutTaskStatus,
-    NEED_EXPIRE_DAYS, GATHERING_PAST_GRACE_HOURS, FRESHNESS_MAX_DAYS, NOTICE_EXPIRE_DAYS,
+    EvidenceFreshness, EvidenceRecheck, EvidenceVerification, StoryNode, TensionNode, TrialState, TrialTransition, ScoutScope, ScoutTask, ScoutTaskSource, ScoutTaskStatus, QuietHours, RegionAgendas, RegionCalendar, RegionSchedule, RegionDemographics, RegionOpenData, RegionRegistry, RegionServiceAlerts, TractDemographics,
+    FieldVisibility, PolicyAction, PolicyFinding, NEED_EXPIRE_DAYS, GATHERING_PAST_GRACE_HOURS, FRESHNESS_MAX_DAYS, NOTICE_EXPIRE_DAYS,
 };
+use rootsignal_common::accessibility;
+use rootsignal_common::log_events::SignalHeldAsDraft;
+use rootsignal_common::subject_requests::{redact_terms, terms_regex, ANONYMIZED_ACTOR_NAME};
 
 use crate::GraphClient;
 
+/// Minimum vector similarity for `find_similar_signals` results.
+const SIMILAR_SIGNAL_THRESHOLD: f64 = 0.75;
+
 /// Write-side wrapper for the graph. Used by scout only.
 #[derive(Clone)]
 pub struct GraphWriter {
@@ -37,18 +43,107 @@ impl GraphWriter {
         created_by: &str,
         scout_run_id: &str,
     ) -> Result<Uuid, neo4rs::Error> {
-        match node {
-            Node::Gathering(n) => self.create_gathering(n, embedding, created_by, scout_run_id).await,
-            Node::Aid(n) => self.create_aid(n, embedding, created_by, scout_run_id).await,
-            Node::Need(n) => self.create_need(n, embedding, created_by, scout_run_id).await,
-            Node::Notice(n) => self.create_notice(n, embedding, created_by, scout_run_id).await,
-            Node::Tension(n) => self.create_tension(n, embedding, created_by, scout_run_id).await,
+        let suppressed = self.suppression_regex().await?;
+        let (node, findings) = apply_content_policy(node, suppressed.as_ref());
+        let id = match &node {
+            Node::Gathering(n) => self.create_gathering(n, embedding, created_by, scout_run_id).await?,
+            Node::Aid(n) => self.create_aid(n, embedding, created_by, scout_run_id).await?,
+            Node::Need(n) => self.create_need(n, embedding, created_by, scout_run_id).await?,
+            Node::Notice(n) => self.create_notice(n, embedding, created_by, scout_run_id).await?,
+            Node::Tension(n) => self.create_tension(n, embedding, created_by, scout_run_id).await?,
             Node::Evidence(_) => {
                 return Err(neo4rs::Error::UnsupportedVersion(
                     "Evidence nodes should use create_evidence() directly".to_string(),
                 ));
             }
+        };
+        if !findings.is_empty() {
+            self.record_policy_findings(id, &findings).await?;
         }
+        if let Some(meta) = node.meta().filter(|m| !m.field_visibility.is_empty()) {
+            self.set_field_visibility(id, &meta.field_visibility).await?;
+        }
+        self.set_accessibility(id, &node).await?;
+        Ok(id)
+    }
+
+    /// Store a signal's marker alt text, generated from the stored node, and
+    /// its plain-language summary if it has one.
+    async fn set_accessibility(&self, id: Uuid, node: &Node) -> Result<(), neo4rs::Error> {
+        let plain_summary = node.meta().and_then(|m| m.plain_summary.as_deref());
+        let q = query(
+            "MATCH (n {id: $id})
+             WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+             SET n.alt_text = $alt_text,
+                 n.plain_summary = CASE WHEN $plain_summary = '' THEN null ELSE $plain_summary END",
+        )
+        .param("id", id.to_string())
+        .param("alt_text", accessibility::signal_alt_text(node))
+        .param("plain_summary", plain_summary.unwrap_or_default());
+
+        self.client.graph.run(q).await
+    }
+
+    /// A signal's stored field demotions; None if there is no such signal.
+    pub async fn field_visibility(&self, id: Uuid) -> Result<Option<FieldVisibility>, neo4rs::Error> {
+        let q = query(
+            "MATCH (n {id: $id})
+             WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+             RETURN coalesce(n.field_visibility, '') AS field_visibility",
+        )
+        .param("id", id.to_string());
+
+        let mut stream = self.client.graph.execute(q).await?;
+        Ok(stream
+            .next()
+            .await?
+            .map(|row| FieldVisibility::from_json(&row.get::<String>("field_visibility").unwrap_or_default())))
+    }
+
+    /// Store a signal's field demotions, replacing any it had.
+    pub async fn set_field_visibility(&self, id: Uuid, visibility: &FieldVisibility) -> Result<bool, neo4rs::Error> {
+        let q = query(
+            "MATCH (n {id: $id})
+             WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+             SET n.field_visibility = $field_visibility
+             RETURN count(n) AS updated",
+        )
+        .param("id", id.to_string())
+        .param("field_visibility", visibility.to_json());
+
+        let mut stream = self.client.graph.execute(q).await?;
+        Ok(match stream.next().await? {
+            Some(row) => row.get::<i64>("updated").unwrap_or(0) > 0,
+            None => false,
+        })
+    }
+
+    /// Matcher for the names and handles of executed data-subject requests.
+    async fn suppression_regex(&self) -> Result<Option<regex::Regex>, neo4rs::Error> {
+        let terms = crate::subject_requests::suppressed_terms(&self.client).await?;
+        Ok(terms_regex(&terms))
+    }
+
+    /// Store content-policy findings on a staged signal. Drafts leave the
+    /// review pipeline until edited; flags go to the supervisor's review as
+    /// triage context.
+    async fn record_policy_findings(&self, id: Uuid, findings: &[PolicyFinding]) -> Result<(), neo4rs::Error> {
+        let draft = findings.iter().any(|f| f.rule.action() == PolicyAction::Draft);
+        let q = query(
+            "MATCH (n {id: $id})
+             WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+             SET n.policy_flags = $flags,
+                 n.review_status = CASE WHEN $draft THEN 'draft' ELSE n.review_status END",
+        )
+        .param("id", id.to_string())
+        .param("flags", findings.iter().map(PolicyFinding::to_string).collect::<Vec<_>>())
+        .param("draft", draft);
+
+        self.client.graph.run(q).await?;
+        if draft {
+            SignalHeldAsDraft { signal_id: id, flags: findings.len() }.emit();
+        }
+        Ok(())
     }
 
     /// Create a node without an embedding (for news scanner and other non-search pipelines).
@@ -251,6 +346,7 @@ impl GraphWriter {
                 what_needed: $what_needed,
                 action_url: $action_url,
                 goal: $goal,
+                fundraiser_url: CASE WHEN $fundraiser_url = '' THEN null ELSE $fundraiser_url END,
                 lat: $lat,
                 lng: $lng,
                 embedding: $embedding,
@@ -287,6 +383,7 @@ impl GraphWriter {
         .param("what_needed", n.what_needed.as_deref().unwrap_or(""))
         .param("action_url", n.action_url.clone().unwrap_or_default())
         .param("goal", n.goal.clone().unwrap_or_default())
+        .param("fundraiser_url", n.fundraiser_url().unwrap_or_default())
         .param("embedding", embedding_to_f64(embedding))
         .param("channel_diversity", n.meta.channel_diversity as i64)
         .param("created_by", created_by)
@@ -471,9 +568,15 @@ impl GraphWriter {
         evidence: &EvidenceNode,
         signal_node_id: Uuid,
     ) -> Result<(), neo4rs::Error> {
+        let snippet = evidence.snippet.clone().unwrap_or_default();
+        let snippet = match self.suppression_regex().await? {
+            Some(re) => redact_terms(&snippet, &re).unwrap_or(snippet),
+            None => snippet,
+        };
         // Find the target signal across all labels, then MERGE evidence by source_url.
         // ON CREATE: set all fields on the new Evidence node.
-        // ON MATCH: update hash + timestamp (page content changed but same source).
+        // ON MATCH: update hash + timestamp (page content changed but same source),
+        // and the change summary when the re-extraction was diff-driven.
         let q = query(
             "OPTIONAL MATCH (e:Gathering {id: $signal_id})
             OPTIONAL MATCH (g:Aid {id: $signal_id})
@@ -490,16 +593,24 @@ impl GraphWriter {
                 ev.snippet = $snippet,
                 ev.relevance = $relevance,
                 ev.evidence_confidence = $evidence_confidence,
-                ev.channel_type = $channel_type
+                ev.channel_type = $channel_type,
+                ev.change_summary = $change_summary,
+                ev.engagement_velocity = $engagement_velocity
             ON MATCH SET
                 ev.retrieved_at = datetime($retrieved_at),
-                ev.content_hash = $content_hash",
+                ev.content_hash = $content_hash,
+                ev.change_summary = coalesce($change_summary, ev.change_summary),
+                ev.engagement_velocity = coalesce($engagement_velocity, ev.engagement_velocity)
+            WITH n
+            WHERE $is_fundraiser AND n:Need AND n.fundraiser_url IS NULL
+            SET n.fundraiser_url = $source_url",
         )
         .param("ev_id", evidence.id.to_string())
+        .param("is_fundraiser", is_fundraiser_url(&evidence.source_url))
         .param("source_url", evidence.source_url.as_str())
         .param("retrieved_at", format_datetime(&evidence.retrieved_at))
         .param("content_hash", evidence.content_hash.as_str())
-        .param("snippet", evidence.snippet.clone().unwrap_or_default())
+        .param("snippet", snippet)
         .param("relevance", evidence.relevance.clone().unwrap_or_default())
         .param(
             "evidence_confidence",
@@ -509,6 +620,8 @@ impl GraphWriter {
             "channel_type",
             evidence.channel_type.map(|ct| ct.as_str()).unwrap_or("press"),
         )
+        .param::<Option<String>>("change_summary", evidence.change_summary.clone())
+        .param::<Option<f64>>("engagement_velocity", evidence.engagement_velocity)
         .param("signal_id", signal_node_id.to_string());
 
         self.client.graph.run(q).await?;
@@ -1012,6 +1125,132 @@ impl GraphWriter {
         Ok(stats)
     }
 
+    /// Signals in scope whose `last_confirmed_active` is older than
+    /// `older_than_days`, oldest first. Only signals with a source URL are
+    /// returned — there is nothing to re-fetch otherwise.
+    pub async fn find_signals_due_for_verification(
+        &self,
+        scope: &ScoutScope,
+        older_than_days: u32,
+        limit: u32,
+    ) -> Result<Vec<VerificationCandidate>, neo4rs::Error> {
+        let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+        let q = query(&format!(
+            "MATCH (n)
+             WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+               AND n.lat >= $min_lat AND n.lat <= $max_lat
+               AND n.lng >= $min_lng AND n.lng <= $max_lng
+               AND n.source_url IS NOT NULL AND n.source_url <> ''
+               AND datetime(n.last_confirmed_active) < datetime() - duration('P{older_than_days}D')
+             RETURN n.id AS id, labels(n)[0] AS label, n.title AS title,
+                    n.source_url AS source_url, n.last_confirmed_active AS last_confirmed_active,
+                    n.lat AS lat, n.lng AS lng
+             ORDER BY n.last_confirmed_active ASC
+             LIMIT $limit"
+        ))
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng)
+        .param("limit", limit as i64);
+
+        let mut candidates = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            if !scope.contains(row.get("lat").unwrap_or_default(), row.get("lng").unwrap_or_default()) {
+                continue;
+            }
+            let id = match row.get::<String>("id").ok().and_then(|s| Uuid::parse_str(&s).ok()) {
+                Some(id) => id,
+                None => continue,
+            };
+            let node_type = match row.get::<String>("label").unwrap_or_default().as_str() {
+                "Gathering" => NodeType::Gathering,
+                "Aid" => NodeType::Aid,
+                "Need" => NodeType::Need,
+                "Notice" => NodeType::Notice,
+                "Tension" => NodeType::Tension,
+                _ => continue,
+            };
+            candidates.push(VerificationCandidate {
+                id,
+                node_type,
+                title: row.get("title").unwrap_or_default(),
+                source_url: row.get("source_url").unwrap_or_default(),
+                last_confirmed_active: row_datetime_opt(&row, "last_confirmed_active"),
+            });
+        }
+        Ok(candidates)
+    }
+
+    /// Retire a signal whose source no longer shows it as active. Removes the
+    /// signal and its evidence the same way `reap_expired` does.
+    pub async fn expire_signal(&self, signal_id: Uuid, node_type: NodeType) -> Result<bool, neo4rs::Error> {
+        let label = match node_type {
+            NodeType::Gathering => "Gathering",
+            NodeType::Aid => "Aid",
+            NodeType::Need => "Need",
+            NodeType::Notice => "Notice",
+            NodeType::Tension => "Tension",
+            NodeType::Evidence => return Ok(false),
+        };
+
+        let q = query(&format!(
+            "MATCH (n:{label} {{id: $id}})
+             OPTIONAL MATCH (n)-[:SOURCED_FROM]->(ev:Evidence)
+             DETACH DELETE n, ev
+             RETURN count(DISTINCT n) AS deleted"
+        ))
+        .param("id", signal_id.to_string());
+
+        let deleted = match self.client.graph.execute(q).await?.next().await? {
+            Some(row) => row.get::<i64>("deleted").unwrap_or(0),
+            None => 0,
+        };
+        Ok(deleted > 0)
+    }
+
+    /// Record a judge audit verdict on a signal. With `suppress`, a signal
+    /// still awaiting review is rejected so it never goes live. Returns
+    /// whether the signal was suppressed.
+    pub async fn record_audit_verdict(
+        &self,
+        signal_id: Uuid,
+        node_type: NodeType,
+        score: f64,
+        issues: Vec<String>,
+        suppress: bool,
+    ) -> Result<bool, neo4rs::Error> {
+        let label = match node_type {
+            NodeType::Gathering => "Gathering",
+            NodeType::Aid => "Aid",
+            NodeType::Need => "Need",
+            NodeType::Notice => "Notice",
+            NodeType::Tension => "Tension",
+            NodeType::Evidence => return Ok(false),
+        };
+
+        let q = query(&format!(
+            "MATCH (n:{label} {{id: $id}})
+             SET n.audit_score = $score,
+                 n.audit_issues = $issues,
+                 n.audited_at = datetime($now)
+             WITH n, $suppress AND n.review_status = 'staged' AS suppressing
+             SET n.review_status = CASE WHEN suppressing THEN 'rejected' ELSE n.review_status END
+             RETURN suppressing"
+        ))
+        .param("id", signal_id.to_string())
+        .param("score", score)
+        .param("issues", issues)
+        .param("now", format_datetime(&Utc::now()))
+        .param("suppress", suppress);
+
+        Ok(match self.client.graph.execute(q).await?.next().await? {
+            Some(row) => row.get::<bool>("suppressing").unwrap_or(false),
+            None => false,
+        })
+    }
+
     /// Delete all nodes sourced from a given URL (opt-out support).
     pub async fn delete_by_source_url(&self, url: &str) -> Result<u64, neo4rs::Error> {
         // Delete evidence nodes linked to signals from this URL, then the signals themselves
@@ -1106,39 +1345,779 @@ impl GraphWriter {
         Ok(())
     }
 
-    /// Clean up all stale running task statuses (>30 min).
-    pub async fn cleanup_stale_task_statuses(&self) -> Result<u32, neo4rs::Error> {
-        let q = query(
-            "MATCH (t:ScoutTask)
-             WHERE t.phase_status STARTS WITH 'running_'
-               AND t.phase_status_updated_at < datetime() - duration('PT30M')
-             SET t.phase_status = 'idle', t.phase_status_updated_at = datetime()
-             RETURN count(t) AS cleaned"
-        );
+    /// Clean up all stale running task statuses (>30 min).
+    pub async fn cleanup_stale_task_statuses(&self) -> Result<u32, neo4rs::Error> {
+        let q = query(
+            "MATCH (t:ScoutTask)
+             WHERE t.phase_status STARTS WITH 'running_'
+               AND t.phase_status_updated_at < datetime() - duration('PT30M')
+             SET t.phase_status = 'idle', t.phase_status_updated_at = datetime()
+             RETURN count(t) AS cleaned"
+        );
+
+        let mut result = self.client.graph.execute(q).await?;
+        if let Some(row) = result.next().await? {
+            let cleaned: i64 = row.get("cleaned").unwrap_or(0);
+            return Ok(cleaned as u32);
+        }
+        Ok(0)
+    }
+
+    /// Check if any task for a region (by context) is currently running.
+    pub async fn is_region_task_running(&self, context: &str) -> Result<bool, neo4rs::Error> {
+        let q = query(
+            "MATCH (t:ScoutTask {context: $context})
+             WHERE t.phase_status STARTS WITH 'running_'
+               AND t.phase_status_updated_at >= datetime() - duration('PT30M')
+             RETURN count(t) > 0 AS running"
+        ).param("context", context);
+
+        let mut result = self.client.graph.execute(q).await?;
+        if let Some(row) = result.next().await? {
+            let running: bool = row.get("running").unwrap_or(false);
+            return Ok(running);
+        }
+        Ok(false)
+    }
+
+    /// ID of the task currently running for a region, if any (same liveness
+    /// rule as `is_region_task_running`).
+    pub async fn running_region_task_id(&self, context: &str) -> Result<Option<String>, neo4rs::Error> {
+        let q = query(
+            "MATCH (t:ScoutTask {context: $context})
+             WHERE t.phase_status STARTS WITH 'running_'
+               AND t.phase_status_updated_at >= datetime() - duration('PT30M')
+             RETURN t.id AS id
+             ORDER BY t.phase_status_updated_at DESC
+             LIMIT 1"
+        ).param("context", context);
+
+        let mut result = self.client.graph.execute(q).await?;
+        if let Some(row) = result.next().await? {
+            return Ok(row.get::<String>("id").ok());
+        }
+        Ok(None)
+    }
+
+    /// Scheduling calendar for a region (keyed by region slug), if one is set.
+    pub async fn get_region_calendar(&self, region_slug: &str) -> Result<Option<RegionCalendar>, neo4rs::Error> {
+        let q = query(
+            "MATCH (c:RegionCalendar {region: $region})
+             RETURN c.timezone AS timezone, c.quiet_start_hour AS quiet_start_hour,
+                    c.quiet_end_hour AS quiet_end_hour, c.blackouts AS blackouts, c.bursts AS bursts",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let quiet_start: Option<i64> = row.get("quiet_start_hour").ok();
+        let quiet_end: Option<i64> = row.get("quiet_end_hour").ok();
+        let blackouts: String = row.get("blackouts").unwrap_or_default();
+        let bursts: String = row.get("bursts").unwrap_or_default();
+        Ok(Some(RegionCalendar {
+            timezone: row.get("timezone").unwrap_or_else(|_| "UTC".to_string()),
+            quiet_hours: quiet_start.zip(quiet_end).map(|(start, end)| QuietHours {
+                start_hour: start as u8,
+                end_hour: end as u8,
+            }),
+            blackouts: serde_json::from_str(&blackouts).unwrap_or_default(),
+            bursts: serde_json::from_str(&bursts).unwrap_or_default(),
+        }))
+    }
+
+    /// Create or replace the scheduling calendar for a region.
+    pub async fn set_region_calendar(&self, region_slug: &str, calendar: &RegionCalendar) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (c:RegionCalendar {region: $region})
+             SET c.timezone = $timezone,
+                 c.quiet_start_hour = $quiet_start_hour,
+                 c.quiet_end_hour = $quiet_end_hour,
+                 c.blackouts = $blackouts,
+                 c.bursts = $bursts,
+                 c.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("timezone", calendar.timezone.as_str())
+        .param("quiet_start_hour", calendar.quiet_hours.map(|q| i64::from(q.start_hour)))
+        .param("quiet_end_hour", calendar.quiet_hours.map(|q| i64::from(q.end_hour)))
+        .param("blackouts", serde_json::to_string(&calendar.blackouts).unwrap_or_default())
+        .param("bursts", serde_json::to_string(&calendar.bursts).unwrap_or_default());
+
+        self.client.graph.run(q).await?;
+        info!(region = region_slug, "Region calendar updated");
+        Ok(())
+    }
+
+    /// Scout run schedule for a region (keyed by region slug), if one is set.
+    pub async fn get_region_schedule(&self, region_slug: &str) -> Result<Option<RegionSchedule>, neo4rs::Error> {
+        let q = query(
+            "MATCH (s:RegionSchedule {region: $region})
+             RETURN s.region AS region, s.task_id AS task_id, s.cron AS cron, s.timezone AS timezone,
+                    s.paused AS paused, toString(s.last_run_at) AS last_run_at,
+                    toString(s.updated_at) AS updated_at",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        Ok(stream.next().await?.and_then(|row| row_to_region_schedule(&row)).map(|(_, s)| s))
+    }
+
+    /// Every region's schedule, paused or not, as `(region slug, schedule)`.
+    pub async fn list_region_schedules(&self) -> Result<Vec<(String, RegionSchedule)>, neo4rs::Error> {
+        let q = query(
+            "MATCH (s:RegionSchedule)
+             RETURN s.region AS region, s.task_id AS task_id, s.cron AS cron, s.timezone AS timezone,
+                    s.paused AS paused, toString(s.last_run_at) AS last_run_at,
+                    toString(s.updated_at) AS updated_at
+             ORDER BY s.region",
+        );
+
+        let mut schedules = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            schedules.extend(row_to_region_schedule(&row));
+        }
+        Ok(schedules)
+    }
+
+    /// Create or replace a region's schedule. Keeps the last run time so an
+    /// edit doesn't immediately re-fire; `updated_at` is reset to now.
+    pub async fn set_region_schedule(&self, region_slug: &str, schedule: &RegionSchedule) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (s:RegionSchedule {region: $region})
+             SET s.task_id = $task_id,
+                 s.cron = $cron,
+                 s.timezone = $timezone,
+                 s.paused = $paused,
+                 s.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("task_id", schedule.task_id.as_str())
+        .param("cron", schedule.cron.as_str())
+        .param("timezone", schedule.timezone.as_str())
+        .param("paused", schedule.paused);
+
+        self.client.graph.run(q).await?;
+        info!(region = region_slug, cron = schedule.cron.as_str(), "Region schedule updated");
+        Ok(())
+    }
+
+    /// Pause or resume a region's schedule. Resuming counts as an edit, so
+    /// occurrences missed while paused don't fire. Returns false if the
+    /// region has no schedule.
+    pub async fn set_region_schedule_paused(&self, region_slug: &str, paused: bool) -> Result<bool, neo4rs::Error> {
+        let q = query(
+            "MATCH (s:RegionSchedule {region: $region})
+             SET s.paused = $paused, s.updated_at = datetime()
+             RETURN count(s) AS updated",
+        )
+        .param("region", region_slug)
+        .param("paused", paused);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let updated = match stream.next().await? {
+            Some(row) => row.get::<i64>("updated").unwrap_or(0) > 0,
+            None => false,
+        };
+        if updated {
+            info!(region = region_slug, paused, "Region schedule paused state changed");
+        }
+        Ok(updated)
+    }
+
+    /// Record that a scheduled run was started.
+    pub async fn mark_region_schedule_run(&self, region_slug: &str, at: DateTime<Utc>) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MATCH (s:RegionSchedule {region: $region})
+             SET s.last_run_at = datetime($at)",
+        )
+        .param("region", region_slug)
+        .param("at", at.to_rfc3339());
+
+        self.client.graph.run(q).await?;
+        Ok(())
+    }
+
+    /// Set the smallest group of signals a region's published aggregates may
+    /// describe. Read back by `PublicGraphReader::aggregate_guard`.
+    pub async fn set_region_aggregate_guard(
+        &self,
+        region_slug: &str,
+        guard: &AggregateGuard,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (p:RegionPrivacy {region: $region})
+             SET p.min_group_size = $min_group_size,
+                 p.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("min_group_size", i64::from(guard.min_group_size));
+
+        self.client.graph.run(q).await?;
+        info!(region = region_slug, min_group_size = guard.min_group_size, "Region aggregate guard updated");
+        Ok(())
+    }
+
+    /// Open-data datasets configured for a region (keyed by region slug), if any.
+    pub async fn get_region_open_data(&self, region_slug: &str) -> Result<Option<RegionOpenData>, neo4rs::Error> {
+        let q = query("MATCH (o:RegionOpenData {region: $region}) RETURN o.datasets AS datasets")
+            .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let datasets: String = row.get("datasets").unwrap_or_default();
+        Ok(Some(RegionOpenData {
+            datasets: serde_json::from_str(&datasets).unwrap_or_default(),
+        }))
+    }
+
+    /// Create or replace the open-data datasets for a region.
+    pub async fn set_region_open_data(&self, region_slug: &str, open_data: &RegionOpenData) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (o:RegionOpenData {region: $region})
+             SET o.datasets = $datasets,
+                 o.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("datasets", serde_json::to_string(&open_data.datasets).unwrap_or_default());
+
+        self.client.graph.run(q).await?;
+        info!(region = region_slug, datasets = open_data.datasets.len(), "Region open data updated");
+        Ok(())
+    }
+
+    /// Meeting bodies a region follows (keyed by region slug), if any.
+    pub async fn get_region_agendas(&self, region_slug: &str) -> Result<Option<RegionAgendas>, neo4rs::Error> {
+        let q = query(
+            "MATCH (a:RegionAgendas {region: $region})
+             RETURN a.bodies AS bodies, a.high_impact_topics AS high_impact_topics",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let bodies: String = row.get("bodies").unwrap_or_default();
+        let topics: String = row.get("high_impact_topics").unwrap_or_default();
+        Ok(Some(RegionAgendas {
+            bodies: serde_json::from_str(&bodies).unwrap_or_default(),
+            high_impact_topics: serde_json::from_str(&topics)
+                .unwrap_or_else(|_| rootsignal_common::agendas::default_high_impact_topics()),
+        }))
+    }
+
+    /// Create or replace the meeting bodies a region follows.
+    pub async fn set_region_agendas(&self, region_slug: &str, agendas: &RegionAgendas) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (a:RegionAgendas {region: $region})
+             SET a.bodies = $bodies,
+                 a.high_impact_topics = $high_impact_topics,
+                 a.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("bodies", serde_json::to_string(&agendas.bodies).unwrap_or_default())
+        .param(
+            "high_impact_topics",
+            serde_json::to_string(&agendas.high_impact_topics).unwrap_or_default(),
+        );
+
+        self.client.graph.run(q).await?;
+        info!(region = region_slug, bodies = agendas.bodies.len(), "Region agendas updated");
+        Ok(())
+    }
+
+    /// Service alert feeds a region follows (keyed by region slug), if any.
+    pub async fn get_region_service_alerts(
+        &self,
+        region_slug: &str,
+    ) -> Result<Option<RegionServiceAlerts>, neo4rs::Error> {
+        let q = query(
+            "MATCH (a:RegionServiceAlerts {region: $region})
+             RETURN a.transit_agencies AS transit_agencies, a.school_districts AS school_districts",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let agencies: String = row.get("transit_agencies").unwrap_or_default();
+        let districts: String = row.get("school_districts").unwrap_or_default();
+        Ok(Some(RegionServiceAlerts {
+            transit_agencies: serde_json::from_str(&agencies).unwrap_or_default(),
+            school_districts: serde_json::from_str(&districts).unwrap_or_default(),
+        }))
+    }
+
+    /// Create or replace the service alert feeds a region follows.
+    pub async fn set_region_service_alerts(
+        &self,
+        region_slug: &str,
+        alerts: &RegionServiceAlerts,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (a:RegionServiceAlerts {region: $region})
+             SET a.transit_agencies = $transit_agencies,
+                 a.school_districts = $school_districts,
+                 a.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param(
+            "transit_agencies",
+            serde_json::to_string(&alerts.transit_agencies).unwrap_or_default(),
+        )
+        .param(
+            "school_districts",
+            serde_json::to_string(&alerts.school_districts).unwrap_or_default(),
+        );
+
+        self.client.graph.run(q).await?;
+        info!(
+            region = region_slug,
+            agencies = alerts.transit_agencies.len(),
+            districts = alerts.school_districts.len(),
+            "Region service alerts updated"
+        );
+        Ok(())
+    }
+
+    /// The census data a region uses (keyed by region slug), if configured.
+    pub async fn get_region_demographics(
+        &self,
+        region_slug: &str,
+    ) -> Result<Option<RegionDemographics>, neo4rs::Error> {
+        let q = query(
+            "MATCH (d:RegionDemographics {region: $region})
+             RETURN d.config AS config",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let config: String = row.get("config").unwrap_or_default();
+        Ok(serde_json::from_str(&config).ok())
+    }
+
+    /// Create or replace the census data a region uses. Cached tracts are
+    /// kept; they're refetched when they no longer match the configuration.
+    pub async fn set_region_demographics(
+        &self,
+        region_slug: &str,
+        demographics: &RegionDemographics,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MERGE (d:RegionDemographics {region: $region})
+             SET d.config = $config,
+                 d.updated_at = datetime()",
+        )
+        .param("region", region_slug)
+        .param("config", serde_json::to_string(demographics).unwrap_or_default());
+
+        self.client.graph.run(q).await?;
+        info!(
+            region = region_slug,
+            counties = demographics.county_fips.len(),
+            acs_year = demographics.acs_year,
+            "Region demographics updated"
+        );
+        Ok(())
+    }
+
+    /// Cached tracts of a region with their cache key and fetch time.
+    pub async fn get_cached_tracts(
+        &self,
+        region_slug: &str,
+    ) -> Result<Option<(String, DateTime<Utc>, Vec<TractDemographics>)>, neo4rs::Error> {
+        let q = query(
+            "MATCH (d:RegionDemographics {region: $region})
+             WHERE d.tracts IS NOT NULL
+             RETURN d.tracts_key AS key, d.tracts_fetched_at AS fetched_at, d.tracts AS tracts",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
+        let fetched_at: String = row.get("fetched_at").unwrap_or_default();
+        let Ok(fetched_at) = DateTime::parse_from_rfc3339(&fetched_at) else {
+            return Ok(None);
+        };
+        let tracts: String = row.get("tracts").unwrap_or_default();
+        Ok(Some((
+            row.get("key").unwrap_or_default(),
+            fetched_at.with_timezone(&Utc),
+            serde_json::from_str(&tracts).unwrap_or_default(),
+        )))
+    }
+
+    /// Cache a region's tracts under the key of the configuration they came from.
+    pub async fn set_cached_tracts(
+        &self,
+        region_slug: &str,
+        key: &str,
+        tracts: &[TractDemographics],
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MATCH (d:RegionDemographics {region: $region})
+             SET d.tracts = $tracts,
+                 d.tracts_key = $key,
+                 d.tracts_fetched_at = $now",
+        )
+        .param("region", region_slug)
+        .param("tracts", serde_json::to_string(tracts).unwrap_or_default())
+        .param("key", key)
+        .param("now", Utc::now().to_rfc3339());
+
+        self.client.graph.run(q).await
+    }
+
+    /// Situations centered in scope with the locations of their evidencing
+    /// signals (empty when none are located).
+    pub async fn situation_footprints(
+        &self,
+        scope: &ScoutScope,
+    ) -> Result<Vec<(Uuid, Vec<(f64, f64)>)>, neo4rs::Error> {
+        let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
+        let q = query(
+            "MATCH (s:Situation)
+             WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+               AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
+             OPTIONAL MATCH (sig)-[:EVIDENCES]->(s)
+             WHERE sig.lat IS NOT NULL AND sig.lat <> 0.0
+             RETURN s.id AS id, s.centroid_lat AS centroid_lat, s.centroid_lng AS centroid_lng,
+                    collect(sig.lat) AS lats, collect(sig.lng) AS lngs",
+        )
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut footprints = Vec::new();
+        while let Some(row) = stream.next().await? {
+            if !scope.contains(row.get("centroid_lat").unwrap_or_default(), row.get("centroid_lng").unwrap_or_default()) {
+                continue;
+            }
+            let id: String = row.get("id").unwrap_or_default();
+            let Ok(id) = Uuid::parse_str(&id) else {
+                continue;
+            };
+            let lats: Vec<f64> = row.get("lats").unwrap_or_default();
+            let lngs: Vec<f64> = row.get("lngs").unwrap_or_default();
+            footprints.push((id, lats.into_iter().zip(lngs).collect()));
+        }
+        Ok(footprints)
+    }
+
+    /// Record (or clear) a situation's census context.
+    pub async fn set_situation_demographics(
+        &self,
+        situation_id: Uuid,
+        context: Option<&DemographicContext>,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MATCH (s:Situation {id: $id})
+             SET s.demographic_context = $context",
+        )
+        .param("id", situation_id.to_string())
+        .param("context", context.map(|c| serde_json::to_string(c).unwrap_or_default()));
+
+        self.client.graph.run(q).await
+    }
+
+    /// Record a bootstrap-proposed source for review.
+    pub async fn record_bootstrap_candidate(
+        &self,
+        candidate: &rootsignal_common::BootstrapCandidate,
+    ) -> Result<(), neo4rs::Error> {
+        crate::bootstrap_review::record_candidate(&self.client, candidate).await
+    }
+
+    /// The region's bootstrap candidates with `status` (all when None), best first.
+    pub async fn bootstrap_candidates(
+        &self,
+        region_slug: &str,
+        status: Option<rootsignal_common::ReviewStatus>,
+    ) -> Result<Vec<rootsignal_common::BootstrapCandidate>, neo4rs::Error> {
+        crate::bootstrap_review::bootstrap_candidates(&self.client, region_slug, status).await
+    }
+
+    /// The region's backfills waiting to run, newest request first.
+    pub async fn requested_backfills(&self, region_slug: &str) -> Result<Vec<rootsignal_common::Backfill>, neo4rs::Error> {
+        crate::backfill::backfills(&self.client, region_slug, Some(rootsignal_common::BackfillStatus::Requested)).await
+    }
+
+    /// Record a backfill's outcome, marking its run's signals historical.
+    pub async fn complete_backfill(
+        &self,
+        canonical_key: &str,
+        scout_run_id: &str,
+        error: Option<&str>,
+    ) -> Result<u32, neo4rs::Error> {
+        crate::backfill::complete_backfill(&self.client, canonical_key, scout_run_id, error).await
+    }
+
+    /// Open information requests of situations centered in scope.
+    pub async fn open_information_requests(
+        &self,
+        scope: &ScoutScope,
+    ) -> Result<Vec<rootsignal_common::InformationRequest>, neo4rs::Error> {
+        crate::information_requests::open_requests(&self.client, scope).await
+    }
+
+    /// Attach pulled evidence to a request's situation and mark it answered.
+    pub async fn attach_pulled_evidence(
+        &self,
+        request_id: Uuid,
+        evidence: &[rootsignal_common::PulledEvidence],
+    ) -> Result<(), neo4rs::Error> {
+        crate::information_requests::attach_evidence(&self.client, request_id, evidence).await
+    }
+
+    /// Record a fruitless attempt to answer an information request.
+    pub async fn record_unanswered_request(&self, request_id: Uuid) -> Result<(), neo4rs::Error> {
+        crate::information_requests::record_unanswered(&self.client, request_id).await
+    }
+
+    /// Record the region's scope and return every registered region's scope
+    /// (this one included), by region slug.
+    pub async fn register_region_scope(
+        &self,
+        region_slug: &str,
+        scope: &ScoutScope,
+    ) -> Result<Vec<(String, ScoutScope)>, neo4rs::Error> {
+        crate::region_membership::register_region_scope(&self.client, region_slug, scope).await?;
+        crate::region_membership::region_scopes(&self.client).await
+    }
+
+    /// Registry entities of a region (schools, routes, wards, parks), by kind and name.
+    pub async fn get_region_registry(&self, region_slug: &str) -> Result<RegionRegistry, neo4rs::Error> {
+        let q = query(
+            "MATCH (e:RegistryEntity {region: $region})
+             RETURN e.entity AS entity
+             ORDER BY e.kind, e.name",
+        )
+        .param("region", region_slug);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut entities = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let json: String = row.get("entity").unwrap_or_default();
+            match serde_json::from_str(&json) {
+                Ok(entity) => entities.push(entity),
+                Err(e) => warn!(region = region_slug, error = %e, "Skipping unreadable registry entity"),
+            }
+        }
+        Ok(RegionRegistry { entities })
+    }
+
+    /// Replace a region's registry. Entities are merged by key so existing
+    /// signal links survive an update; entities no longer listed are removed
+    /// along with their links.
+    pub async fn set_region_registry(&self, region_slug: &str, registry: &RegionRegistry) -> Result<(), neo4rs::Error> {
+        let entities: Vec<neo4rs::BoltType> = registry
+            .entities
+            .iter()
+            .map(|e| {
+                neo4rs::BoltType::Map(neo4rs::BoltMap::from_iter(vec![
+                    (
+                        neo4rs::BoltString::from("id"),
+                        neo4rs::BoltType::String(neo4rs::BoltString::from(e.node_id(region_slug).as_str())),
+                    ),
+                    (
+                        neo4rs::BoltString::from("key"),
+                        neo4rs::BoltType::String(neo4rs::BoltString::from(e.key().as_str())),
+                    ),
+                    (
+                        neo4rs::BoltString::from("kind"),
+                        neo4rs::BoltType::String(neo4rs::BoltString::from(e.kind.as_str())),
+                    ),
+                    (
+                        neo4rs::BoltString::from("name"),
+                        neo4rs::BoltType::String(neo4rs::BoltString::from(e.name.as_str())),
+                    ),
+                    (
+                        neo4rs::BoltString::from("entity"),
+                        neo4rs::BoltType::String(neo4rs::BoltString::from(
+                            serde_json::to_string(e).unwrap_or_default().as_str(),
+                        )),
+                    ),
+                ]))
+            })
+            .collect();
+        let keys: Vec<String> = registry.entities.iter().map(|e| e.key()).collect();
+
+        self.client
+            .graph
+            .run(
+                query(
+                    "MATCH (e:RegistryEntity {region: $region})
+                     WHERE NOT e.key IN $keys
+                     DETACH DELETE e",
+                )
+                .param("region", region_slug)
+                .param("keys", keys),
+            )
+            .await?;
+        self.client
+            .graph
+            .run(
+                query(
+                    "UNWIND $entities AS ent
+                     MERGE (e:RegistryEntity {id: ent.id})
+                     SET e.region = $region,
+                         e.key = ent.key,
+                         e.kind = ent.kind,
+                         e.name = ent.name,
+                         e.entity = ent.entity,
+                         e.updated_at = datetime()",
+                )
+                .param("region", region_slug)
+                .param("entities", entities),
+            )
+            .await?;
+
+        info!(region = region_slug, entities = registry.entities.len(), "Region registry updated");
+        Ok(())
+    }
+
+    /// Link a signal to a registry entity with an edge typed by `link`.
+    pub async fn link_signal_to_entity(
+        &self,
+        signal_id: Uuid,
+        entity_id: &str,
+        link: EntityLink,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(&format!(
+            "MATCH (n) WHERE n.id = $signal_id AND (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+             MATCH (e:RegistryEntity {{id: $entity_id}})
+             MERGE (n)-[r:{}]->(e)
+               ON CREATE SET r.linked_at = datetime()",
+            link.edge_type()
+        ))
+        .param("signal_id", signal_id.to_string())
+        .param("entity_id", entity_id);
 
-        let mut result = self.client.graph.execute(q).await?;
-        if let Some(row) = result.next().await? {
-            let cleaned: i64 = row.get("cleaned").unwrap_or(0);
-            return Ok(cleaned as u32);
-        }
-        Ok(0)
+        self.client.graph.run(q).await
     }
 
-    /// Check if any task for a region (by context) is currently running.
-    pub async fn is_region_task_running(&self, context: &str) -> Result<bool, neo4rs::Error> {
-        let q = query(
-            "MATCH (t:ScoutTask {context: $context})
-             WHERE t.phase_status STARTS WITH 'running_'
-               AND t.phase_status_updated_at >= datetime() - duration('PT30M')
-             RETURN count(t) > 0 AS running"
-        ).param("context", context);
+    /// Link signals and situations to the registry entities whose boundary
+    /// contains them (LOCATED_IN). Signals are linked as they're stored; this
+    /// catches up after boundaries are imported. Situation centroids drift as
+    /// evidence arrives, so a situation's stale links are dropped. Returns the
+    /// number of nodes found inside some boundary.
+    pub async fn link_located_in(&self, region_slug: &str, registry: &RegionRegistry) -> Result<u32, neo4rs::Error> {
+        let mut linked = 0u32;
+        for entity in registry.areas() {
+            let Some(geometry) = &entity.geometry else {
+                continue;
+            };
+            let Some((min_lat, max_lat, min_lng, max_lng)) = geometry.bounds() else {
+                continue;
+            };
+            let entity_id = entity.node_id(region_slug);
+
+            let in_bounds = |cypher: &str| {
+                query(cypher)
+                    .param("min_lat", min_lat)
+                    .param("max_lat", max_lat)
+                    .param("min_lng", min_lng)
+                    .param("max_lng", max_lng)
+            };
+            let signals = self
+                .ids_inside(
+                    in_bounds(
+                        "MATCH (n)
+                         WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+                           AND n.lat >= $min_lat AND n.lat <= $max_lat
+                           AND n.lng >= $min_lng AND n.lng <= $max_lng
+                         RETURN n.id AS id, n.lat AS lat, n.lng AS lng",
+                    ),
+                    geometry,
+                )
+                .await?;
+            let situations = self
+                .ids_inside(
+                    in_bounds(
+                        "MATCH (s:Situation)
+                         WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+                           AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
+                         RETURN s.id AS id, s.centroid_lat AS lat, s.centroid_lng AS lng",
+                    ),
+                    geometry,
+                )
+                .await?;
+            linked += (signals.len() + situations.len()) as u32;
+
+            self.client
+                .graph
+                .run(
+                    query(
+                        "MATCH (e:RegistryEntity {id: $entity_id})
+                         UNWIND $ids AS id
+                         MATCH (n {id: id})
+                         WHERE n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension
+                         MERGE (n)-[r:LOCATED_IN]->(e)
+                           ON CREATE SET r.linked_at = datetime()",
+                    )
+                    .param("entity_id", entity_id.as_str())
+                    .param("ids", signals),
+                )
+                .await?;
+            self.client
+                .graph
+                .run(
+                    query(
+                        "MATCH (s:Situation)-[r:LOCATED_IN]->(:RegistryEntity {id: $entity_id})
+                         WHERE NOT s.id IN $ids
+                         DELETE r",
+                    )
+                    .param("entity_id", entity_id.as_str())
+                    .param("ids", situations.clone()),
+                )
+                .await?;
+            self.client
+                .graph
+                .run(
+                    query(
+                        "MATCH (e:RegistryEntity {id: $entity_id})
+                         UNWIND $ids AS id
+                         MATCH (s:Situation {id: id})
+                         MERGE (s)-[r:LOCATED_IN]->(e)
+                           ON CREATE SET r.linked_at = datetime()",
+                    )
+                    .param("entity_id", entity_id.as_str())
+                    .param("ids", situations),
+                )
+                .await?;
+        }
 
-        let mut result = self.client.graph.execute(q).await?;
-        if let Some(row) = result.next().await? {
-            let running: bool = row.get("running").unwrap_or(false);
-            return Ok(running);
+        info!(region = region_slug, linked, "Linked signals and situations to registry boundaries");
+        Ok(linked)
+    }
+
+    /// Ids from an `id, lat, lng` query whose point lies inside `geometry`.
+    async fn ids_inside(&self, q: neo4rs::Query, geometry: &EntityGeometry) -> Result<Vec<String>, neo4rs::Error> {
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut ids = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let (Ok(id), Ok(lat), Ok(lng)) = (row.get::<String>("id"), row.get::<f64>("lat"), row.get::<f64>("lng"))
+            else {
+                continue;
+            };
+            if geometry.contains(lat, lng) {
+                ids.push(id);
+            }
         }
-        Ok(false)
+        Ok(ids)
     }
 
     /// Count sources that are overdue for scraping.
@@ -1523,6 +2502,7 @@ impl GraphWriter {
                  t.source = $source,
                  t.status = $status,
                  t.phase_status = coalesce(t.phase_status, $phase_status),
+                 t.boundary_geojson = $boundary_geojson,
                  t.created_at = datetime($created_at)",
         )
         .param("id", task.id.to_string())
@@ -1535,6 +2515,7 @@ impl GraphWriter {
         .param("source", task.source.to_string())
         .param("status", task.status.to_string())
         .param("phase_status", task.phase_status.as_str())
+        .param::<Option<String>>("boundary_geojson", task.boundary.as_ref().map(|b| b.to_geojson().to_string()))
         .param("created_at", format_datetime(&task.created_at));
 
         self.client.graph.run(q).await?;
@@ -1551,7 +2532,8 @@ impl GraphWriter {
                     t.geo_terms AS geo_terms, t.priority AS priority,
                     t.source AS source, t.status AS status,
                     t.created_at AS created_at, t.completed_at AS completed_at,
-                    t.phase_status AS phase_status",
+                    t.phase_status AS phase_status,
+                    t.boundary_geojson AS boundary_geojson",
         )
         .param("id", id);
 
@@ -1576,7 +2558,8 @@ impl GraphWriter {
                     t.geo_terms AS geo_terms, t.priority AS priority,
                     t.source AS source, t.status AS status,
                     t.created_at AS created_at, t.completed_at AS completed_at,
-                    t.phase_status AS phase_status
+                    t.phase_status AS phase_status,
+                    t.boundary_geojson AS boundary_geojson
              ORDER BY t.created_at DESC
              LIMIT $limit"
         } else {
@@ -1586,7 +2569,8 @@ impl GraphWriter {
                     t.geo_terms AS geo_terms, t.priority AS priority,
                     t.source AS source, t.status AS status,
                     t.created_at AS created_at, t.completed_at AS completed_at,
-                    t.phase_status AS phase_status
+                    t.phase_status AS phase_status,
+                    t.boundary_geojson AS boundary_geojson
              ORDER BY t.created_at DESC
              LIMIT $limit"
         };
@@ -1745,6 +2729,7 @@ impl GraphWriter {
                 phase_status: "idle".to_string(),
                 created_at: chrono::Utc::now(),
                 completed_at: None,
+                boundary: None,
             };
 
             self.upsert_scout_task(&task).await?;
@@ -1793,7 +2778,8 @@ impl GraphWriter {
                 s.avg_signals_per_scrape = $avg_signals_per_scrape,
                 s.quality_penalty = $quality_penalty,
                 s.source_role = $source_role,
-                s.scrape_count = $scrape_count
+                s.scrape_count = $scrape_count,
+                s.trial_state = $trial_state
              ON MATCH SET
                 s.active = CASE WHEN s.active = false AND $discovery_method = 'curated' THEN true ELSE s.active END,
                 s.url = CASE WHEN $url <> '' THEN $url ELSE s.url END"
@@ -1813,7 +2799,8 @@ impl GraphWriter {
         .param("avg_signals_per_scrape", source.avg_signals_per_scrape)
         .param("quality_penalty", source.quality_penalty)
         .param("source_role", source.source_role.to_string())
-        .param("scrape_count", source.scrape_count as i64);
+        .param("scrape_count", source.scrape_count as i64)
+        .param("trial_state", source.trial.as_str());
 
         self.client.graph.run(q).await?;
         Ok(())
@@ -1863,7 +2850,8 @@ impl GraphWriter {
                     s.avg_signals_per_scrape AS avg_signals_per_scrape,
                     s.quality_penalty AS quality_penalty,
                     s.source_role AS source_role,
-                    s.scrape_count AS scrape_count",
+                    s.scrape_count AS scrape_count,
+                    s.trial_state AS trial_state",
         );
 
         let mut sources = Vec::new();
@@ -1916,7 +2904,8 @@ impl GraphWriter {
                     s.avg_signals_per_scrape AS avg_signals_per_scrape,
                     s.quality_penalty AS quality_penalty,
                     s.source_role AS source_role,
-                    s.scrape_count AS scrape_count",
+                    s.scrape_count AS scrape_count,
+                    s.trial_state AS trial_state",
         )
         .param("min_lat", min_lat)
         .param("max_lat", max_lat)
@@ -2007,6 +2996,78 @@ impl GraphWriter {
         }
     }
 
+    /// End the trials that have run their course: graduate probation sources
+    /// with enough corroborated signals, deactivate those with too many empty
+    /// runs. Each transition is recorded as a `TrialTransition` node linked
+    /// to its source. Returns the transitions made.
+    pub async fn apply_source_trials(&self) -> Result<Vec<TrialTransition>, neo4rs::Error> {
+        let q = query(
+            "MATCH (s:Source {trial_state: 'probation'})
+             RETURN s.canonical_key AS canonical_key,
+                    s.signals_corroborated AS signals_corroborated,
+                    s.consecutive_empty_runs AS consecutive_empty_runs",
+        );
+        let mut stream = self.client.graph.execute(q).await?;
+        let now = Utc::now();
+        let mut transitions = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let corroborated = row.get::<i64>("signals_corroborated").unwrap_or(0) as u32;
+            let empty_runs = row.get::<i64>("consecutive_empty_runs").unwrap_or(0) as u32;
+            if let Some((to, reason)) = rootsignal_common::source_trial::trial_outcome(corroborated, empty_runs) {
+                transitions.push(TrialTransition {
+                    canonical_key: row.get("canonical_key").unwrap_or_default(),
+                    from: TrialState::Probation,
+                    to,
+                    reason,
+                    at: now,
+                });
+            }
+        }
+
+        for t in &transitions {
+            let q = query(
+                "MATCH (s:Source {canonical_key: $canonical_key, trial_state: 'probation'})
+                 SET s.trial_state = $to,
+                     s.active = CASE WHEN $to = 'failed' THEN false ELSE s.active END
+                 CREATE (t:TrialTransition {
+                     canonical_key: $canonical_key, from: $from, to: $to,
+                     reason: $reason, at: datetime($at)
+                 })-[:TRIAL_OF]->(s)",
+            )
+            .param("canonical_key", t.canonical_key.as_str())
+            .param("from", t.from.as_str())
+            .param("to", t.to.as_str())
+            .param("reason", t.reason.as_str())
+            .param("at", format_datetime(&t.at));
+            self.client.graph.run(q).await?;
+        }
+        Ok(transitions)
+    }
+
+    /// The most recent trial transitions across all sources, newest first.
+    pub async fn recent_trial_transitions(&self, limit: u32) -> Result<Vec<TrialTransition>, neo4rs::Error> {
+        let q = query(
+            "MATCH (t:TrialTransition)
+             RETURN t.canonical_key AS canonical_key, t.from AS from, t.to AS to,
+                    t.reason AS reason, t.at AS at
+             ORDER BY t.at DESC
+             LIMIT $limit",
+        )
+        .param("limit", limit as i64);
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut transitions = Vec::new();
+        while let Some(row) = stream.next().await? {
+            transitions.push(TrialTransition {
+                canonical_key: row.get("canonical_key").unwrap_or_default(),
+                from: TrialState::parse(&row.get::<String>("from").unwrap_or_default()),
+                to: TrialState::parse(&row.get::<String>("to").unwrap_or_default()),
+                reason: row.get("reason").unwrap_or_default(),
+                at: row_datetime_opt(&row, "at").unwrap_or_else(Utc::now),
+            });
+        }
+        Ok(transitions)
+    }
+
     /// Deactivate sources that have had too many consecutive empty runs.
     /// Protects curated and human-submitted sources.
     pub async fn deactivate_dead_sources(
@@ -2293,10 +3354,16 @@ impl GraphWriter {
     // --- Actor operations ---
 
     /// Create or update an Actor node. MERGE on entity_id for idempotency.
+    /// Anonymized actors keep their anonymized name, and a new actor named
+    /// in an executed data-subject request is created anonymized.
     pub async fn upsert_actor(
         &self,
         actor: &ActorNode,
     ) -> Result<(), neo4rs::Error> {
+        let anonymize = self
+            .suppression_regex()
+            .await?
+            .is_some_and(|re| re.is_match(&actor.name));
         let q = query(
             "MERGE (a:Actor {entity_id: $entity_id})
              ON CREATE SET
@@ -2314,30 +3381,34 @@ impl GraphWriter {
                 a.location_lat = $location_lat,
                 a.location_lng = $location_lng,
                 a.location_name = $location_name,
-                a.discovery_depth = $discovery_depth
+                a.discovery_depth = $discovery_depth,
+                a.anonymized_at = CASE WHEN $anonymize THEN datetime() ELSE null END
              ON MATCH SET
-                a.name = $name,
+                a.anonymized_at = CASE WHEN $anonymize THEN coalesce(a.anonymized_at, datetime()) ELSE a.anonymized_at END,
+                a.name = CASE WHEN a.anonymized_at IS NULL THEN $name ELSE $anonymized_name END,
                 a.last_active = datetime($last_active),
                 a.signal_count = a.signal_count + 1,
-                a.location_lat = COALESCE(a.location_lat, $location_lat),
-                a.location_lng = COALESCE(a.location_lng, $location_lng),
-                a.location_name = COALESCE(a.location_name, $location_name)",
+                a.location_lat = CASE WHEN a.anonymized_at IS NULL THEN COALESCE(a.location_lat, $location_lat) END,
+                a.location_lng = CASE WHEN a.anonymized_at IS NULL THEN COALESCE(a.location_lng, $location_lng) END,
+                a.location_name = CASE WHEN a.anonymized_at IS NULL THEN COALESCE(a.location_name, $location_name) END",
         )
         .param("id", actor.id.to_string())
         .param("entity_id", actor.entity_id.as_str())
-        .param("name", actor.name.as_str())
+        .param("anonymize", anonymize)
+        .param("anonymized_name", ANONYMIZED_ACTOR_NAME)
+        .param("name", if anonymize { ANONYMIZED_ACTOR_NAME } else { actor.name.as_str() })
         .param("actor_type", actor.actor_type.to_string())
-        .param("domains", actor.domains.clone())
-        .param("social_urls", actor.social_urls.clone())
-        .param("description", actor.description.as_str())
+        .param("domains", if anonymize { Vec::new() } else { actor.domains.clone() })
+        .param("social_urls", if anonymize { Vec::new() } else { actor.social_urls.clone() })
+        .param("description", if anonymize { "" } else { actor.description.as_str() })
         .param("signal_count", actor.signal_count as i64)
         .param("first_seen", format_datetime(&actor.first_seen))
         .param("last_active", format_datetime(&actor.last_active))
         .param("typical_roles", actor.typical_roles.clone())
-        .param("bio", actor.bio.clone().unwrap_or_default())
-        .param::<Option<f64>>("location_lat", actor.location_lat)
-        .param::<Option<f64>>("location_lng", actor.location_lng)
-        .param::<Option<String>>("location_name", actor.location_name.clone())
+        .param("bio", if anonymize { String::new() } else { actor.bio.clone().unwrap_or_default() })
+        .param::<Option<f64>>("location_lat", actor.location_lat.filter(|_| !anonymize))
+        .param::<Option<f64>>("location_lng", actor.location_lng.filter(|_| !anonymize))
+        .param::<Option<String>>("location_name", actor.location_name.clone().filter(|_| !anonymize))
         .param("discovery_depth", actor.discovery_depth as i64);
 
         self.client.graph.run(q).await?;
@@ -2457,6 +3528,7 @@ impl GraphWriter {
                     quality_penalty: 1.0,
                     source_role: SourceRole::Mixed,
                     scrape_count: 0,
+                    trial: TrialState::parse(&sn.get::<String>("trial_state").unwrap_or_default()),
                 });
             }
 
@@ -2804,6 +3876,7 @@ impl GraphWriter {
                 quality_penalty: 1.0,
                 source_role: SourceRole::Mixed,
                 scrape_count: 0,
+                trial: TrialState::parse(&s.get::<String>("trial_state").unwrap_or_default()),
             };
             results.push((pin, source));
         }
@@ -2824,7 +3897,9 @@ impl GraphWriter {
                     response_count = 0 AS unmet,
                     COALESCE(t.corroboration_count, 0) AS corroboration_count,
                     COALESCE(t.source_diversity, 0) AS source_diversity,
-                    COALESCE(t.cause_heat, 0.0) AS cause_heat
+                    COALESCE(t.cause_heat, 0.0) AS cause_heat,
+                    t.coverage_score AS coverage,
+                    t.coverage_escalated_at IS NOT NULL AS escalated
              ORDER BY response_count ASC,
                       (COALESCE(t.corroboration_count, 0) + COALESCE(t.source_diversity, 0)) DESC,
                       t.cause_heat DESC,
@@ -2863,6 +3938,36 @@ impl GraphWriter {
                 corroboration_count: row.get::<i64>("corroboration_count").unwrap_or(0) as u32,
                 source_diversity: row.get::<i64>("source_diversity").unwrap_or(0) as u32,
                 cause_heat: row.get("cause_heat").unwrap_or(0.0),
+                coverage: row.get("coverage").ok(),
+                escalated: row.get("escalated").unwrap_or(false),
+            });
+        }
+        Ok(results)
+    }
+
+    /// Daily tension coverage over the last `days`, oldest first, from the
+    /// supervisor's coverage snapshots.
+    pub async fn get_coverage_history(&self, days: u32) -> Result<Vec<CoverageDay>, neo4rs::Error> {
+        let q = query(
+            "MATCH (c:CoverageSnapshot)
+             WHERE date(c.day) >= date() - duration({days: $days})
+             RETURN c.day AS day, count(c) AS tensions,
+                    avg(c.coverage) AS avg_coverage,
+                    sum(CASE WHEN c.coverage < 0.2 THEN 1 ELSE 0 END) AS unmet,
+                    sum(CASE WHEN c.escalated THEN 1 ELSE 0 END) AS escalated
+             ORDER BY day",
+        )
+        .param("days", days as i64);
+
+        let mut results = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            results.push(CoverageDay {
+                day: row.get("day").unwrap_or_default(),
+                tensions: row.get::<i64>("tensions").unwrap_or(0) as u32,
+                avg_coverage: row.get("avg_coverage").unwrap_or(0.0),
+                unmet: row.get::<i64>("unmet").unwrap_or(0) as u32,
+                escalated: row.get::<i64>("escalated").unwrap_or(0) as u32,
             });
         }
         Ok(results)
@@ -2940,6 +4045,73 @@ impl GraphWriter {
         Ok(results)
     }
 
+    /// The most visible live signals and situations in a bounding box, whose
+    /// summaries are worth plain-language and translated variants: situations
+    /// by temperature, signals by cause heat then corroboration. Sensitive
+    /// subjects are left out.
+    pub async fn get_summary_variant_sources(
+        &self,
+        bbox: (f64, f64, f64, f64),
+        situation_limit: u32,
+        signal_limit: u32,
+    ) -> Result<Vec<SummaryVariantSource>, neo4rs::Error> {
+        let (min_lat, max_lat, min_lng, max_lng) = bbox;
+        let q = query(
+            "CALL {
+                 MATCH (s:Situation)
+                 WHERE s.centroid_lat >= $min_lat AND s.centroid_lat <= $max_lat
+                   AND s.centroid_lng >= $min_lng AND s.centroid_lng <= $max_lng
+                   AND s.temperature > 0.1
+                   AND coalesce(s.sensitivity, 'general') <> 'sensitive'
+                 RETURN s.id AS id, 'situation' AS kind, s.headline AS title, s.lede AS summary,
+                        s.plain_summary AS plain_summary
+                 ORDER BY s.temperature DESC
+                 LIMIT $situation_limit
+               UNION ALL
+                 MATCH (n)
+                 WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+                   AND n.review_status = 'live'
+                   AND n.lat >= $min_lat AND n.lat <= $max_lat
+                   AND n.lng >= $min_lng AND n.lng <= $max_lng
+                   AND coalesce(n.sensitivity, 'general') <> 'sensitive'
+                 RETURN n.id AS id, 'signal' AS kind, n.title AS title, n.summary AS summary,
+                        n.plain_summary AS plain_summary
+                 ORDER BY coalesce(n.cause_heat, 0.0) DESC, coalesce(n.corroboration_count, 0) DESC
+                 LIMIT $signal_limit
+             }
+             RETURN id, kind, title, summary, plain_summary",
+        )
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng)
+        .param("situation_limit", situation_limit as i64)
+        .param("signal_limit", signal_limit as i64);
+
+        let mut results = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let Ok(id) = Uuid::parse_str(&row.get::<String>("id").unwrap_or_default()) else {
+                continue;
+            };
+            let summary: String = row.get("summary").unwrap_or_default();
+            if summary.trim().is_empty() {
+                continue;
+            }
+            results.push(SummaryVariantSource {
+                id,
+                kind: row.get("kind").unwrap_or_default(),
+                title: row.get("title").unwrap_or_default(),
+                summary,
+                plain_summary: row
+                    .get::<String>("plain_summary")
+                    .ok()
+                    .filter(|p| !p.is_empty()),
+            });
+        }
+        Ok(results)
+    }
+
     /// Boost source weights for sources that contributed signals evidencing a hot situation.
     /// The boost is multiplicative (e.g. factor=1.2 means 20% increase), capped at 5.0.
     pub async fn boost_sources_for_situation_headline(
@@ -3137,7 +4309,7 @@ impl GraphWriter {
 
     // --- Investigation operations ---
 
-    /// Find signals that warrant investigation. Returns candidates across 3 priority
+    /// Find signals that warrant investigation. Returns candidates across 4 priority
     /// categories with per-source-domain dedup (max 1 per domain to prevent budget exhaustion).
     pub async fn find_investigation_targets(
         &self,
@@ -3160,7 +4332,9 @@ impl GraphWriter {
              WITH t, count(ev) AS ev_count
              WHERE ev_count < 2
              RETURN t.id AS id, 'Tension' AS label, t.title AS title, t.summary AS summary,
-                    t.source_url AS source_url, t.sensitivity AS sensitivity
+                    t.source_url AS source_url, t.sensitivity AS sensitivity,
+                    coalesce(t.severity, t.urgency) AS severity,
+                    coalesce(t.cause_heat, 0.0) AS cause_heat
              LIMIT 10"
         )
         .param("min_lat", min_lat)
@@ -3181,7 +4355,34 @@ impl GraphWriter {
              WITH a, count(ev) AS ev_count
              WHERE ev_count < 2
              RETURN a.id AS id, 'Need' AS label, a.title AS title, a.summary AS summary,
-                    a.source_url AS source_url, a.sensitivity AS sensitivity
+                    a.source_url AS source_url, a.sensitivity AS sensitivity,
+                    coalesce(a.severity, a.urgency) AS severity,
+                    coalesce(a.cause_heat, 0.0) AS cause_heat
+             LIMIT 10"
+        )
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng);
+        self.collect_investigation_targets(&mut targets, &mut seen_domains, q)
+            .await?;
+
+        // Priority 2b: High-impact notices (severity high/critical, last 7d, < 2 evidence
+        // nodes) — e.g. budget and zoning items on upcoming council agendas
+        let q = query(
+            "MATCH (n:Notice)
+             WHERE n.severity IN ['high', 'critical']
+               AND datetime(n.extracted_at) > datetime() - duration('P7D')
+               AND n.lat >= $min_lat AND n.lat <= $max_lat
+               AND n.lng >= $min_lng AND n.lng <= $max_lng
+               AND (n.investigated_at IS NULL OR datetime(n.investigated_at) < datetime() - duration('P7D'))
+             OPTIONAL MATCH (n)-[:SOURCED_FROM]->(ev:Evidence)
+             WITH n, count(ev) AS ev_count
+             WHERE ev_count < 2
+             RETURN n.id AS id, 'Notice' AS label, n.title AS title, n.summary AS summary,
+                    n.source_url AS source_url, n.sensitivity AS sensitivity,
+                    coalesce(n.severity, n.urgency) AS severity,
+                    coalesce(n.cause_heat, 0.0) AS cause_heat
              LIMIT 10"
         )
         .param("min_lat", min_lat)
@@ -3208,7 +4409,9 @@ impl GraphWriter {
                   END AS label
              WHERE ev_count < 2
              RETURN n.id AS id, label, n.title AS title, n.summary AS summary,
-                    n.source_url AS source_url, n.sensitivity AS sensitivity
+                    n.source_url AS source_url, n.sensitivity AS sensitivity,
+                    coalesce(n.severity, n.urgency) AS severity,
+                    coalesce(n.cause_heat, 0.0) AS cause_heat
              LIMIT 10"
         )
         .param("min_lat", min_lat)
@@ -3236,67 +4439,277 @@ impl GraphWriter {
                 Err(_) => continue,
             };
 
-            let label: String = row.get("label").unwrap_or_default();
-            let node_type = match label.as_str() {
-                "Gathering" => NodeType::Gathering,
-                "Aid" => NodeType::Aid,
-                "Need" => NodeType::Need,
-                "Notice" => NodeType::Notice,
-                "Tension" => NodeType::Tension,
-                _ => continue,
-            };
+            let label: String = row.get("label").unwrap_or_default();
+            let node_type = match label.as_str() {
+                "Gathering" => NodeType::Gathering,
+                "Aid" => NodeType::Aid,
+                "Need" => NodeType::Need,
+                "Notice" => NodeType::Notice,
+                "Tension" => NodeType::Tension,
+                _ => continue,
+            };
+
+            let source_url: String = row.get("source_url").unwrap_or_default();
+            let domain = url::Url::parse(&source_url)
+                .ok()
+                .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
+                .unwrap_or_default();
+
+            // Per-domain dedup: max 1 target per source domain
+            if !domain.is_empty() && !seen_domains.insert(domain) {
+                continue;
+            }
+
+            let sensitivity: String = row.get("sensitivity").unwrap_or_default();
+            let is_sensitive = sensitivity == "sensitive" || sensitivity == "elevated";
+
+            targets.push(InvestigationTarget {
+                signal_id: id,
+                node_type,
+                title: row.get("title").unwrap_or_default(),
+                summary: row.get("summary").unwrap_or_default(),
+                source_url,
+                is_sensitive,
+                severity: row.get("severity").ok(),
+                cause_heat: row.get("cause_heat").unwrap_or(0.0),
+            });
+        }
+        Ok(())
+    }
+
+    /// Mark a signal as investigated (sets investigated_at, 7-day cooldown).
+    pub async fn mark_investigated(
+        &self,
+        signal_id: Uuid,
+        node_type: NodeType,
+    ) -> Result<(), neo4rs::Error> {
+        let label = match node_type {
+            NodeType::Gathering => "Gathering",
+            NodeType::Aid => "Aid",
+            NodeType::Need => "Need",
+            NodeType::Notice => "Notice",
+            NodeType::Tension => "Tension",
+            NodeType::Evidence => return Ok(()),
+        };
+
+        let q = query(&format!(
+            "MATCH (n:{} {{id: $id}})
+             SET n.investigated_at = datetime($now)",
+            label
+        ))
+        .param("id", signal_id.to_string())
+        .param("now", format_datetime(&Utc::now()));
+
+        self.client.graph.run(q).await?;
+        Ok(())
+    }
+
+    /// Store an investigation report, linked to the signal it looked into.
+    pub async fn record_investigation(
+        &self,
+        investigation: &rootsignal_common::Investigation,
+    ) -> Result<bool, neo4rs::Error> {
+        crate::investigations::record_investigation(&self.client, investigation).await
+    }
+
+    /// Queue investigation targets found this run (see `investigation_queue`).
+    pub async fn enqueue_investigations(
+        &self,
+        region: &str,
+        targets: &[InvestigationTarget],
+    ) -> Result<(), neo4rs::Error> {
+        crate::investigation_queue::enqueue(&self.client, region, targets).await
+    }
+
+    /// The region's pending and in-progress investigation queue entries.
+    pub async fn open_investigations(
+        &self,
+        region: &str,
+        limit: u32,
+    ) -> Result<Vec<rootsignal_common::investigation_queue::QueueEntry>, neo4rs::Error> {
+        use rootsignal_common::investigation_queue::QueueState;
+        crate::investigation_queue::entries(&self.client, region, &[QueueState::Pending, QueueState::InProgress], limit)
+            .await
+    }
+
+    /// Mark queue entries in progress and load them as targets.
+    pub async fn claim_investigations(&self, signal_ids: &[Uuid]) -> Result<Vec<InvestigationTarget>, neo4rs::Error> {
+        crate::investigation_queue::claim(&self.client, signal_ids).await
+    }
+
+    /// Return claimed entries a run didn't start to pending.
+    pub async fn release_investigations(&self, signal_ids: &[Uuid]) -> Result<(), neo4rs::Error> {
+        crate::investigation_queue::release(&self.client, signal_ids).await
+    }
+
+    pub async fn complete_investigation(&self, signal_id: Uuid) -> Result<(), neo4rs::Error> {
+        crate::investigation_queue::complete(&self.client, signal_id).await
+    }
+
+    pub async fn fail_investigation(
+        &self,
+        signal_id: Uuid,
+        error: &str,
+    ) -> Result<rootsignal_common::investigation_queue::QueueState, neo4rs::Error> {
+        crate::investigation_queue::record_failure(&self.client, signal_id, error).await
+    }
+
+    /// Record an automated decision about a signal.
+    pub async fn record_decision(&self, decision: &rootsignal_common::Decision) -> Result<(), neo4rs::Error> {
+        crate::decisions::record_decision(&self.client, decision).await
+    }
+
+    // --- Investigator graph lookups (read-only) ---
+
+    /// Signals semantically close to `embedding` within the bounding box,
+    /// best match first, across every signal type.
+    pub async fn find_similar_signals(
+        &self,
+        embedding: &[f32],
+        limit: u32,
+        min_lat: f64,
+        max_lat: f64,
+        min_lng: f64,
+        max_lng: f64,
+    ) -> Result<Vec<SimilarSignal>, neo4rs::Error> {
+        let mut results = Vec::new();
+
+        for (node_type, index_name) in [
+            (NodeType::Gathering, "gathering_embedding"),
+            (NodeType::Aid, "aid_embedding"),
+            (NodeType::Need, "need_embedding"),
+            (NodeType::Notice, "notice_embedding"),
+            (NodeType::Tension, "tension_embedding"),
+        ] {
+            let q = query(&format!(
+                "CALL db.index.vector.queryNodes('{}', 10, $embedding)
+                 YIELD node, score AS similarity
+                 WHERE similarity >= $threshold
+                   AND node.lat >= $min_lat AND node.lat <= $max_lat
+                   AND node.lng >= $min_lng AND node.lng <= $max_lng
+                 OPTIONAL MATCH (node)-[:SOURCED_FROM]->(ev:Evidence)
+                 RETURN node.id AS id, node.title AS title, node.summary AS summary,
+                        node.source_url AS source_url, node.confidence AS confidence,
+                        toString(node.extracted_at) AS extracted_at,
+                        count(ev) AS evidence_count, similarity
+                 ORDER BY similarity DESC
+                 LIMIT $limit",
+                index_name
+            ))
+            .param("embedding", embedding_to_f64(embedding))
+            .param("threshold", SIMILAR_SIGNAL_THRESHOLD)
+            .param("limit", limit as i64)
+            .param("min_lat", min_lat)
+            .param("max_lat", max_lat)
+            .param("min_lng", min_lng)
+            .param("max_lng", max_lng);
+
+            let mut stream = self.client.graph.execute(q).await?;
+            while let Some(row) = stream.next().await? {
+                let id_str: String = row.get("id").unwrap_or_default();
+                let Ok(id) = Uuid::parse_str(&id_str) else {
+                    continue;
+                };
+                results.push(SimilarSignal {
+                    id,
+                    node_type,
+                    title: row.get("title").unwrap_or_default(),
+                    summary: row.get("summary").unwrap_or_default(),
+                    source_url: row.get("source_url").unwrap_or_default(),
+                    confidence: row.get::<f64>("confidence").unwrap_or(0.0) as f32,
+                    extracted_at: row.get("extracted_at").unwrap_or_default(),
+                    evidence_count: row.get::<i64>("evidence_count").unwrap_or(0) as u32,
+                    similarity: row.get("similarity").unwrap_or(0.0),
+                });
+            }
+        }
+
+        results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        results.truncate(limit as usize);
+        Ok(results)
+    }
 
-            let source_url: String = row.get("source_url").unwrap_or_default();
-            let domain = url::Url::parse(&source_url)
-                .ok()
-                .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
-                .unwrap_or_default();
+    /// An actor (matched case-insensitively by name) with the signals they
+    /// most recently acted in.
+    pub async fn get_actor_history(
+        &self,
+        name: &str,
+        limit: u32,
+    ) -> Result<Option<ActorHistory>, neo4rs::Error> {
+        let q = query(
+            "MATCH (a:Actor) WHERE toLower(a.name) = toLower($name)
+             WITH a LIMIT 1
+             OPTIONAL MATCH (a)-[r:ACTED_IN]->(n)
+             WITH a, r, n ORDER BY n.extracted_at DESC
+             WITH a, collect(CASE WHEN n IS NULL THEN null ELSE {
+                    label: head(labels(n)), title: n.title, role: r.role,
+                    extracted_at: toString(n.extracted_at)
+                  } END)[..$limit] AS signals
+             RETURN a.name AS name, a.actor_type AS actor_type,
+                    a.description AS description, a.signal_count AS signal_count,
+                    toString(a.first_seen) AS first_seen,
+                    toString(a.last_active) AS last_active, signals",
+        )
+        .param("name", name)
+        .param("limit", limit as i64);
 
-            // Per-domain dedup: max 1 target per source domain
-            if !domain.is_empty() && !seen_domains.insert(domain) {
-                continue;
-            }
+        let mut stream = self.client.graph.execute(q).await?;
+        let Some(row) = stream.next().await? else {
+            return Ok(None);
+        };
 
-            let sensitivity: String = row.get("sensitivity").unwrap_or_default();
-            let is_sensitive = sensitivity == "sensitive" || sensitivity == "elevated";
+        let signals: Vec<neo4rs::BoltMap> = row.get("signals").unwrap_or_default();
+        let recent_signals = signals
+            .iter()
+            .map(|m| ActorSignal {
+                signal_type: m.get("label").unwrap_or_default(),
+                title: m.get("title").unwrap_or_default(),
+                role: m.get("role").unwrap_or_default(),
+                extracted_at: m.get("extracted_at").unwrap_or_default(),
+            })
+            .collect();
 
-            targets.push(InvestigationTarget {
-                signal_id: id,
-                node_type,
-                title: row.get("title").unwrap_or_default(),
-                summary: row.get("summary").unwrap_or_default(),
-                source_url,
-                is_sensitive,
-            });
-        }
-        Ok(())
+        Ok(Some(ActorHistory {
+            name: row.get("name").unwrap_or_default(),
+            actor_type: row.get("actor_type").unwrap_or_default(),
+            description: row.get("description").unwrap_or_default(),
+            signal_count: row.get::<i64>("signal_count").unwrap_or(0) as u32,
+            first_seen: row.get("first_seen").unwrap_or_default(),
+            last_active: row.get("last_active").unwrap_or_default(),
+            recent_signals,
+        }))
     }
 
-    /// Mark a signal as investigated (sets investigated_at, 7-day cooldown).
-    pub async fn mark_investigated(
+    /// Situations a signal is evidence for, hottest first.
+    pub async fn get_situation_context(
         &self,
         signal_id: Uuid,
-        node_type: NodeType,
-    ) -> Result<(), neo4rs::Error> {
-        let label = match node_type {
-            NodeType::Gathering => "Gathering",
-            NodeType::Aid => "Aid",
-            NodeType::Need => "Need",
-            NodeType::Notice => "Notice",
-            NodeType::Tension => "Tension",
-            NodeType::Evidence => return Ok(()),
-        };
-
-        let q = query(&format!(
-            "MATCH (n:{} {{id: $id}})
-             SET n.investigated_at = datetime($now)",
-            label
-        ))
-        .param("id", signal_id.to_string())
-        .param("now", format_datetime(&Utc::now()));
+    ) -> Result<Vec<SituationContext>, neo4rs::Error> {
+        let q = query(
+            "MATCH (sig {id: $id})-[:EVIDENCES]->(s:Situation)
+             OPTIONAL MATCH (other)-[:EVIDENCES]->(s)
+             WHERE other.id <> $id
+             WITH s, collect(other.title)[..5] AS sibling_titles
+             RETURN s.headline AS headline, s.lede AS lede, s.arc AS arc,
+                    s.temperature AS temperature, s.signal_count AS signal_count,
+                    sibling_titles
+             ORDER BY s.temperature DESC",
+        )
+        .param("id", signal_id.to_string());
 
-        self.client.graph.run(q).await?;
-        Ok(())
+        let mut results = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            results.push(SituationContext {
+                headline: row.get("headline").unwrap_or_default(),
+                lede: row.get("lede").unwrap_or_default(),
+                arc: row.get("arc").unwrap_or_default(),
+                temperature: row.get("temperature").unwrap_or(0.0),
+                signal_count: row.get::<i64>("signal_count").unwrap_or(0) as u32,
+                sibling_titles: row.get("sibling_titles").unwrap_or_default(),
+            });
+        }
+        Ok(results)
     }
 
     // --- Curiosity loop methods ---
@@ -3304,6 +4717,9 @@ impl GraphWriter {
     /// Find signals that have no RESPONDS_TO edge to any Tension and haven't been
     /// curiosity-investigated yet (or were `failed` with retry budget remaining).
     ///
+    /// High-impact notices (high/critical severity, such as budget and zoning
+    /// agenda items) come first, then the most recently extracted.
+    ///
     /// Pre-pass: signals with `failed` + retry_count >= 3 are auto-promoted to `abandoned`.
     pub async fn find_tension_linker_targets(
         &self,
@@ -3337,8 +4753,9 @@ impl GraphWriter {
                          WHEN n:Aid THEN 'Aid'
                          WHEN n:Need THEN 'Need'
                          WHEN n:Notice THEN 'Notice'
-                    END AS label
-             ORDER BY n.extracted_at DESC
+                    END AS label,
+                    CASE WHEN n:Notice AND n.severity IN ['high', 'critical'] THEN 0 ELSE 1 END AS impact_rank
+             ORDER BY impact_rank, n.extracted_at DESC
              LIMIT $limit",
         )
         .param("limit", limit as i64)
@@ -3664,7 +5081,7 @@ impl GraphWriter {
 
         // Find pairs to merge (older survives, newer is absorbed)
         let mut to_delete: std::collections::HashSet<String> = std::collections::HashSet::new();
-        let mut merges: Vec<(String, String)> = Vec::new(); // (survivor, duplicate)
+        let mut merges: Vec<(String, String, f64)> = Vec::new(); // (survivor, duplicate, similarity)
 
         for i in 0..tensions.len() {
             if to_delete.contains(&tensions[i].id) {
@@ -3677,13 +5094,13 @@ impl GraphWriter {
                 let sim = cosine_sim_f64(&tensions[i].embedding, &tensions[j].embedding);
                 if sim >= threshold {
                     to_delete.insert(tensions[j].id.clone());
-                    merges.push((tensions[i].id.clone(), tensions[j].id.clone()));
+                    merges.push((tensions[i].id.clone(), tensions[j].id.clone(), sim));
                 }
             }
         }
 
         // Execute merges
-        for (survivor_id, dup_id) in &merges {
+        for (survivor_id, dup_id, similarity) in &merges {
             // Re-point RESPONDS_TO edges from duplicate to survivor
             let q = query(
                 "MATCH (sig)-[r:RESPONDS_TO]->(dup:Tension {id: $dup_id})
@@ -3743,6 +5160,28 @@ impl GraphWriter {
                 duplicate_id = dup_id.as_str(),
                 "Merged duplicate tension"
             );
+
+            if let (Ok(survivor), Ok(duplicate)) = (Uuid::parse_str(survivor_id), Uuid::parse_str(dup_id)) {
+                let inputs = serde_json::json!({ "survivor": survivor_id, "duplicate": dup_id, "similarity": similarity, "threshold": threshold });
+                let rationale = format!("Embedding similarity {similarity:.3} >= {threshold:.2}; the older tension survives");
+                for (subject, outcome) in [
+                    (survivor, format!("absorbed duplicate {duplicate}")),
+                    (duplicate, format!("merged into {survivor}")),
+                ] {
+                    let decision = rootsignal_common::Decision::new(
+                        rootsignal_common::DecisionKind::Merge,
+                        subject,
+                        NodeType::Tension,
+                        "supervisor.tension_dedup",
+                        outcome,
+                    )
+                    .with_inputs(&inputs)
+                    .with_rationale(rationale.clone());
+                    if let Err(e) = self.record_decision(&decision).await {
+                        warn!(error = %e, %subject, "Failed to record merge decision");
+                    }
+                }
+            }
         }
 
         Ok(merges.len() as u32)
@@ -4062,15 +5501,17 @@ impl GraphWriter {
                AND t.lat >= $min_lat AND t.lat <= $max_lat
                AND t.lng >= $min_lng AND t.lng <= $max_lng
                AND coalesce(datetime(t.response_scouted_at), datetime('2000-01-01'))
-                   < datetime() - duration('P14D')
+                   < datetime() - CASE WHEN t.coverage_escalated_at IS NOT NULL
+                                       THEN duration('P3D') ELSE duration('P14D') END
              OPTIONAL MATCH (t)<-[:RESPONDS_TO]-(r)
              WITH t, count(r) AS response_count
              RETURN t.id AS id, t.title AS title, t.summary AS summary,
                     t.severity AS severity, t.category AS category,
                     t.what_would_help AS what_would_help,
                     coalesce(t.cause_heat, 0.0) AS cause_heat,
-                    response_count
-             ORDER BY response_count ASC, t.cause_heat DESC, t.confidence DESC
+                    response_count,
+                    t.coverage_escalated_at IS NOT NULL AS escalated
+             ORDER BY escalated DESC, response_count ASC, t.cause_heat DESC, t.confidence DESC
              LIMIT $limit",
         )
         .param("limit", limit as i64)
@@ -4112,6 +5553,7 @@ impl GraphWriter {
                     let c: i64 = row.get("response_count").unwrap_or(0);
                     c as u32
                 },
+                escalated: row.get("escalated").unwrap_or(false),
             });
         }
         Ok(results)
@@ -4400,6 +5842,60 @@ impl GraphWriter {
         Ok(())
     }
 
+    /// Gatherings in the bbox starting within `window` of `starts_at`, with the
+    /// venues they are known by (location name and linked Places). Used to spot
+    /// events that structured sources already ingested.
+    pub async fn find_gatherings_near_time(
+        &self,
+        starts_at: DateTime<Utc>,
+        window: chrono::Duration,
+        min_lat: f64,
+        max_lat: f64,
+        min_lng: f64,
+        max_lng: f64,
+    ) -> Result<Vec<EventCandidate>, neo4rs::Error> {
+        let q = query(
+            "MATCH (g:Gathering)
+             WHERE g.starts_at IS NOT NULL AND g.starts_at <> ''
+               AND datetime(g.starts_at) >= datetime($from)
+               AND datetime(g.starts_at) <= datetime($to)
+               AND g.lat >= $min_lat AND g.lat <= $max_lat
+               AND g.lng >= $min_lng AND g.lng <= $max_lng
+             OPTIONAL MATCH (g)-[:GATHERS_AT]->(p:Place)
+             RETURN g.id AS id, g.title AS title, g.source_url AS source_url,
+                    toString(g.starts_at) AS starts_at,
+                    [v IN collect(p.name) + [g.location_name] WHERE v IS NOT NULL AND v <> ''] AS venues",
+        )
+        .param("from", format_datetime(&(starts_at - window)))
+        .param("to", format_datetime(&(starts_at + window)))
+        .param("min_lat", min_lat)
+        .param("max_lat", max_lat)
+        .param("min_lng", min_lng)
+        .param("max_lng", max_lng);
+
+        let mut candidates = Vec::new();
+        let mut stream = self.client.graph.execute(q).await?;
+        while let Some(row) = stream.next().await? {
+            let id_str: String = row.get("id").unwrap_or_default();
+            let Ok(id) = Uuid::parse_str(&id_str) else {
+                continue;
+            };
+            let starts_at = row
+                .get::<String>("starts_at")
+                .ok()
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&Utc));
+            candidates.push(EventCandidate {
+                id,
+                title: row.get("title").unwrap_or_default(),
+                source_url: row.get("source_url").unwrap_or_default(),
+                starts_at,
+                venues: row.get("venues").unwrap_or_default(),
+            });
+        }
+        Ok(candidates)
+    }
+
     // ─── Resource Capability Matching ────────────────────────────────
 
     /// Find or create a Resource node, deduplicating on slug.
@@ -4789,6 +6285,40 @@ impl GraphWriter {
         self.client.graph.run(q).await
     }
 
+    /// Signals extracted since `since` whose title or summary mentions
+    /// `phrase` as whole words (case-insensitive), newest first.
+    pub async fn find_signals_mentioning(
+        &self,
+        phrase: &str,
+        since: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>, neo4rs::Error> {
+        // \Q..\E quotes the phrase for Cypher's Java regex; a phrase can't end the quote early.
+        let pattern = format!("(?is).*\\b\\Q{}\\E\\b.*", phrase.trim().replace("\\E", ""));
+        let q = query(
+            "MATCH (n)
+             WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+               AND n.extracted_at >= datetime($since)
+               AND (n.title =~ $pattern OR n.summary =~ $pattern)
+             RETURN n.id AS id
+             ORDER BY n.extracted_at DESC
+             LIMIT $limit",
+        )
+        .param("pattern", pattern)
+        .param("since", format_datetime(&since))
+        .param("limit", limit as i64);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut ids = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let id: String = row.get("id").unwrap_or_default();
+            if let Ok(id) = Uuid::parse_str(&id) {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
     /// Find-or-create Tag nodes by slug and wire TAGGED edges from a signal.
     /// Uses a single UNWIND query for the batch to minimise round-trips.
     pub async fn batch_tag_signals(
@@ -4958,6 +6488,16 @@ pub struct ConsolidationStats {
     pub edges_redirected: u32,
 }
 
+/// A signal due for re-verification against its source page.
+#[derive(Debug, Clone)]
+pub struct VerificationCandidate {
+    pub id: Uuid,
+    pub node_type: NodeType,
+    pub title: String,
+    pub source_url: String,
+    pub last_confirmed_active: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Default)]
 pub struct ReapStats {
     pub gatherings: u64,
@@ -4973,6 +6513,22 @@ pub struct SourceStats {
     pub discovered: u32,
 }
 
+fn row_to_region_schedule(row: &neo4rs::Row) -> Option<(String, RegionSchedule)> {
+    let parse = |s: String| DateTime::parse_from_rfc3339(&s).ok().map(|t| t.with_timezone(&Utc));
+    let region: String = row.get("region").ok()?;
+    Some((
+        region,
+        RegionSchedule {
+            task_id: row.get("task_id").unwrap_or_default(),
+            cron: row.get("cron").unwrap_or_default(),
+            timezone: row.get("timezone").unwrap_or_else(|_| "UTC".to_string()),
+            paused: row.get("paused").unwrap_or(false),
+            last_run_at: row.get::<String>("last_run_at").ok().and_then(parse),
+            updated_at: row.get::<String>("updated_at").ok().and_then(parse).unwrap_or_else(Utc::now),
+        },
+    ))
+}
+
 #[derive(Debug)]
 pub struct DuplicateMatch {
     pub id: Uuid,
@@ -4981,6 +6537,16 @@ pub struct DuplicateMatch {
     pub similarity: f64,
 }
 
+/// An existing Gathering that a newly discovered event may duplicate.
+#[derive(Debug, Clone)]
+pub struct EventCandidate {
+    pub id: Uuid,
+    pub title: String,
+    pub source_url: String,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub venues: Vec<String>,
+}
+
 // --- Discovery briefing types ---
 
 /// A tension with its response coverage status.
@@ -4994,6 +6560,20 @@ pub struct UnmetTension {
     pub corroboration_count: u32,
     pub source_diversity: u32,
     pub cause_heat: f64,
+    /// Response coverage from the supervisor, 0.0–1.0; None until first scored.
+    pub coverage: Option<f64>,
+    /// Persistently unmet and escalated by the supervisor.
+    pub escalated: bool,
+}
+
+/// Tension coverage across a region on one day.
+#[derive(Debug, Clone)]
+pub struct CoverageDay {
+    pub day: String,
+    pub tensions: u32,
+    pub avg_coverage: f64,
+    pub unmet: u32,
+    pub escalated: u32,
 }
 
 /// A brief summary of a story for the discovery briefing.
@@ -5022,6 +6602,19 @@ pub struct SituationBrief {
     pub sensitivity: String,
 }
 
+/// A signal or situation whose summary gets plain-language and translated variants.
+#[derive(Debug, Clone)]
+pub struct SummaryVariantSource {
+    pub id: Uuid,
+    /// "signal" or "situation".
+    pub kind: String,
+    /// Title, or a situation's headline.
+    pub title: String,
+    /// Summary, or a situation's lede.
+    pub summary: String,
+    pub plain_summary: Option<String>,
+}
+
 /// Aggregate counts of each signal type.
 #[derive(Debug, Clone, Default)]
 pub struct SignalTypeCounts {
@@ -5090,6 +6683,55 @@ pub struct InvestigationTarget {
     pub summary: String,
     pub source_url: String,
     pub is_sensitive: bool,
+    /// Severity (tensions, notices) or urgency (needs): `low` to `critical`.
+    pub severity: Option<String>,
+    pub cause_heat: f64,
+}
+
+/// An existing signal close to an investigation query.
+#[derive(Debug, Clone)]
+pub struct SimilarSignal {
+    pub id: Uuid,
+    pub node_type: NodeType,
+    pub title: String,
+    pub summary: String,
+    pub source_url: String,
+    pub confidence: f32,
+    pub extracted_at: String,
+    pub evidence_count: u32,
+    pub similarity: f64,
+}
+
+/// What the graph knows about an actor, for the investigator.
+#[derive(Debug, Clone)]
+pub struct ActorHistory {
+    pub name: String,
+    pub actor_type: String,
+    pub description: String,
+    pub signal_count: u32,
+    pub first_seen: String,
+    pub last_active: String,
+    pub recent_signals: Vec<ActorSignal>,
+}
+
+/// One signal in an actor's history.
+#[derive(Debug, Clone)]
+pub struct ActorSignal {
+    pub signal_type: String,
+    pub title: String,
+    pub role: String,
+    pub extracted_at: String,
+}
+
+/// A situation a signal already feeds into.
+#[derive(Debug, Clone)]
+pub struct SituationContext {
+    pub headline: String,
+    pub lede: String,
+    pub arc: String,
+    pub temperature: f64,
+    pub signal_count: u32,
+    pub sibling_titles: Vec<String>,
 }
 
 /// A signal without tension context that the tension linker should investigate.
@@ -5172,6 +6814,8 @@ pub struct ResponseFinderTarget {
     pub what_would_help: Option<String>,
     pub cause_heat: f64,
     pub response_count: u32,
+    /// Escalated by the supervisor for staying unmet; re-scouted sooner and first.
+    pub escalated: bool,
 }
 
 /// An existing response signal used as a heuristic hint.
@@ -5237,6 +6881,36 @@ fn severity_str(s: rootsignal_common::Severity) -> &'static str {
     }
 }
 
+/// Run a signal's public text (title, summary, what_would_help) through the
+/// content policy and demote any fields it restricts. Returns the signal with
+/// fixes applied and every finding.
+fn apply_content_policy(node: &Node, suppressed: Option<&regex::Regex>) -> (Node, Vec<PolicyFinding>) {
+    let mut node = node.clone();
+    let mut findings = Vec::new();
+    let mut lint = |field: &mut String| {
+        let linted = lint_public_text(field);
+        *field = linted.text;
+        findings.extend(linted.findings);
+        if let Some(redacted) = suppressed.and_then(|re| redact_terms(field, re)) {
+            *field = redacted;
+        }
+    };
+    if let Some(meta) = node.meta_mut() {
+        lint(&mut meta.title);
+        lint(&mut meta.summary);
+        if let Some(plain) = meta.plain_summary.as_mut() {
+            lint(plain);
+        }
+    }
+    if let Node::Tension(t) = &mut node {
+        if let Some(help) = t.what_would_help.as_mut() {
+            lint(help);
+        }
+    }
+    findings.extend(demote_fields(&mut node));
+    (node, findings)
+}
+
 fn sensitivity_str(s: SensitivityLevel) -> &'static str {
     match s {
         SensitivityLevel::General => "general",
@@ -5317,6 +6991,7 @@ fn row_to_source_node(row: &neo4rs::Row) -> Option<SourceNode> {
             &row.get::<String>("source_role").unwrap_or_default(),
         ),
         scrape_count: row.get::<i64>("scrape_count").unwrap_or(0) as u32,
+        trial: TrialState::parse(&row.get::<String>("trial_state").unwrap_or_default()),
     })
 }
 
@@ -5327,6 +7002,12 @@ pub fn row_to_scout_task(row: &neo4rs::Row) -> ScoutTask {
     let status_str: String = row.get("status").unwrap_or_default();
 
     let phase_status: String = row.get("phase_status").unwrap_or_else(|_| "idle".to_string());
+    // Circle-defined tasks, and tasks written before boundaries existed, have none.
+    let boundary = row
+        .get::<String>("boundary_geojson")
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .and_then(|value| EntityGeometry::from_geojson(&value).ok());
 
     ScoutTask {
         id: Uuid::parse_str(&id_str).unwrap_or_else(|_| Uuid::nil()),
@@ -5341,6 +7022,7 @@ pub fn row_to_scout_task(row: &neo4rs::Row) -> ScoutTask {
         created_at: row_datetime_opt(row, "created_at").unwrap_or_else(Utc::now),
         completed_at: row_datetime_opt(row, "completed_at"),
         phase_status,
+        boundary,
     }
 }
 
@@ -5392,6 +7074,23 @@ impl GraphWriter {
     ) -> Result<Uuid, neo4rs::Error> {
         let g = &self.client.graph;
 
+        // Situations have no staging, so findings of any kind are recorded
+        // for admins rather than holding the situation back.
+        let headline = lint_public_text(&situation.headline);
+        let lede = lint_public_text(&situation.lede);
+        let plain_summary = situation.plain_summary.as_deref().map(lint_public_text);
+        let policy_flags: Vec<String> = headline
+            .findings
+            .iter()
+            .chain(&lede.findings)
+            .chain(plain_summary.iter().flat_map(|p| &p.findings))
+            .map(PolicyFinding::to_string)
+            .collect();
+        let alt_text = accessibility::situation_alt_text(&rootsignal_common::SituationNode {
+            headline: headline.text.clone(),
+            ..situation.clone()
+        });
+
         let q = query(
             "CREATE (s:Situation {
                 id: $id,
@@ -5417,12 +7116,15 @@ impl GraphWriter {
                 sensitivity: $sensitivity,
                 category: $category,
                 narrative_embedding: $narrative_embedding,
-                causal_embedding: $causal_embedding
+                causal_embedding: $causal_embedding,
+                policy_flags: $policy_flags,
+                alt_text: $alt_text,
+                plain_summary: CASE WHEN $plain_summary = '' THEN null ELSE $plain_summary END
             })",
         )
         .param("id", situation.id.to_string())
-        .param("headline", situation.headline.as_str())
-        .param("lede", situation.lede.as_str())
+        .param("headline", headline.text.as_str())
+        .param("lede", lede.text.as_str())
         .param("arc", situation.arc.to_string())
         .param("temperature", situation.temperature)
         .param("tension_heat", situation.tension_heat)
@@ -5455,10 +7157,13 @@ impl GraphWriter {
             situation.category.as_deref().unwrap_or(""),
         )
         .param("narrative_embedding", narrative_embedding.to_vec())
-        .param("causal_embedding", causal_embedding.to_vec());
+        .param("causal_embedding", causal_embedding.to_vec())
+        .param("policy_flags", policy_flags)
+        .param("alt_text", alt_text)
+        .param("plain_summary", plain_summary.map(|p| p.text).unwrap_or_default());
 
         g.run(q).await?;
-        info!(id = %situation.id, headline = %situation.headline, "Created Situation node");
+        info!(id = %situation.id, headline = %headline.text, "Created Situation node");
         Ok(situation.id)
     }
 
@@ -5472,6 +7177,18 @@ impl GraphWriter {
         let signal_ids_json: Vec<String> =
             dispatch.signal_ids.iter().map(|id| id.to_string()).collect();
 
+        // Anything beyond an in-place fix sends the dispatch to review.
+        let body = lint_public_text(&dispatch.body);
+        let policy_reason = match body.action() {
+            Some(PolicyAction::Flag | PolicyAction::Draft) => body
+                .findings
+                .iter()
+                .find(|f| f.rule.action() != PolicyAction::AutoFix)
+                .map(|f| format!("content_policy:{}", f.rule.as_str())),
+            _ => None,
+        };
+        let flag_reason = dispatch.flag_reason.clone().or(policy_reason);
+
         let q = query(
             "MATCH (s:Situation {id: $situation_id})
              CREATE (d:Dispatch {
@@ -5492,7 +7209,7 @@ impl GraphWriter {
         )
         .param("id", dispatch.id.to_string())
         .param("situation_id", dispatch.situation_id.to_string())
-        .param("body", dispatch.body.as_str())
+        .param("body", body.text.as_str())
         .param("signal_ids", signal_ids_json)
         .param("created_at", dispatch.created_at.to_rfc3339())
         .param("dispatch_type", dispatch.dispatch_type.to_string())
@@ -5503,11 +7220,8 @@ impl GraphWriter {
                 .map(|id| id.to_string())
                 .unwrap_or_default(),
         )
-        .param("flagged_for_review", dispatch.flagged_for_review)
-        .param(
-            "flag_reason",
-            dispatch.flag_reason.as_deref().unwrap_or(""),
-        )
+        .param("flagged_for_review", dispatch.flagged_for_review || flag_reason.is_some())
+        .param("flag_reason", flag_reason.as_deref().unwrap_or(""))
         .param("fidelity_score", dispatch.fidelity_score.unwrap_or(-1.0));
 
         g.run(q).await?;
@@ -5529,6 +7243,7 @@ impl GraphWriter {
             "MATCH (sig:{signal_label} {{id: $signal_id}})
              MATCH (sit:Situation {{id: $situation_id}})
              MERGE (sig)-[e:EVIDENCES]->(sit)
+             ON CREATE SET e.linked_at = datetime()
              SET e.assigned_at = datetime(),
                  e.match_confidence = $confidence,
                  e.debunked = false"
@@ -5608,7 +7323,8 @@ impl GraphWriter {
         g.run(q).await
     }
 
-    /// Update a situation's temperature components and derived arc.
+    /// Update a situation's temperature components and derived arc. An arc
+    /// change is recorded as a `SituationChange` for feeds and subscribers.
     pub async fn update_situation_temperature(
         &self,
         situation_id: &Uuid,
@@ -5625,6 +7341,7 @@ impl GraphWriter {
 
         let q = query(
             "MATCH (s:Situation {id: $id})
+             WITH s, s.arc AS previous_arc
              SET s.temperature = $temperature,
                  s.tension_heat = $tension_heat,
                  s.entity_velocity = $entity_velocity,
@@ -5633,7 +7350,13 @@ impl GraphWriter {
                  s.clarity_need = $clarity_need,
                  s.arc = $arc,
                  s.clarity = $clarity,
-                 s.last_updated = datetime()",
+                 s.last_updated = datetime()
+             WITH s, previous_arc
+             WHERE previous_arc IS NOT NULL AND previous_arc <> $arc
+             CREATE (s)-[:HAS_CHANGE]->(:SituationChange {
+                 id: randomUUID(), situation_id: s.id, from: previous_arc, to: $arc,
+                 temperature: $temperature, created_at: datetime(), notified: false
+             })",
         )
         .param("id", situation_id.to_string())
         .param("temperature", temperature)
@@ -5782,6 +7505,148 @@ impl GraphWriter {
         g.run(q).await
     }
 
+    /// Needs backed by a fundraiser whose progress hasn't been checked since
+    /// `checked_before`, least recently checked first. Returns (need_id, fundraiser_url).
+    pub async fn fundraisers_due_for_refresh(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<(Uuid, String)>, neo4rs::Error> {
+        let q = query(
+            "MATCH (n:Need)
+             WHERE n.fundraiser_url IS NOT NULL
+               AND (n.fundraiser_checked_at IS NULL OR n.fundraiser_checked_at < datetime($checked_before))
+             RETURN n.id AS id, n.fundraiser_url AS url
+             ORDER BY coalesce(n.fundraiser_checked_at, datetime('1970-01-01T00:00:00Z')) ASC
+             LIMIT $limit",
+        )
+        .param("checked_before", format_datetime(&checked_before))
+        .param("limit", limit as i64);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut results = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let id: String = row.get("id").unwrap_or_default();
+            let Ok(id) = Uuid::parse_str(&id) else {
+                continue;
+            };
+            let url: String = row.get("url").unwrap_or_default();
+            results.push((id, url));
+        }
+        Ok(results)
+    }
+
+    /// Record a fundraiser check on a Need. Progress fields are only
+    /// overwritten when the page could be read; a failed read still advances
+    /// `fundraiser_checked_at` so it waits for the next cadence.
+    pub async fn update_fundraiser(
+        &self,
+        need_id: Uuid,
+        checked_at: DateTime<Utc>,
+        progress: Option<&Fundraiser>,
+    ) -> Result<(), neo4rs::Error> {
+        let q = match progress {
+            Some(f) => query(
+                "MATCH (n:Need {id: $id})
+                 SET n.fundraiser_checked_at = datetime($checked_at),
+                     n.fundraiser_goal = $goal,
+                     n.fundraiser_raised = $raised,
+                     n.fundraiser_currency = $currency,
+                     n.fundraiser_donor_count = $donor_count,
+                     n.fundraiser_organizer = $organizer,
+                     n.fundraiser_beneficiary = $beneficiary",
+            )
+            .param("goal", f.goal.unwrap_or(0.0))
+            .param("raised", f.raised)
+            .param("currency", f.currency.as_str())
+            .param("donor_count", f.donor_count.map(|c| c as i64).unwrap_or(-1))
+            .param("organizer", f.organizer.clone().unwrap_or_default())
+            .param("beneficiary", f.beneficiary.clone().unwrap_or_default()),
+            None => query(
+                "MATCH (n:Need {id: $id})
+                 SET n.fundraiser_checked_at = datetime($checked_at)",
+            ),
+        }
+        .param("id", need_id.to_string())
+        .param("checked_at", format_datetime(&checked_at));
+
+        self.client.graph.run(q).await
+    }
+
+    /// Evidence on live signals whose source page hasn't been checked since
+    /// `checked_before`, least recently checked first. Social posts are
+    /// skipped; their pages aren't what the evidence was taken from.
+    pub async fn evidence_due_for_recheck(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<EvidenceRecheck>, neo4rs::Error> {
+        let q = query(
+            "MATCH (n)-[:SOURCED_FROM]->(ev:Evidence)
+             WHERE (n:Gathering OR n:Aid OR n:Need OR n:Notice OR n:Tension)
+               AND coalesce(ev.channel_type, 'press') <> 'social'
+               AND coalesce(ev.checked_at, ev.retrieved_at) < datetime($checked_before)
+             RETURN ev.id AS id, ev.source_url AS url, ev.content_hash AS content_hash,
+                    ev.snippet AS snippet, ev.freshness AS freshness,
+                    toString(coalesce(ev.verified_at, ev.retrieved_at)) AS verified_at
+             ORDER BY coalesce(ev.checked_at, ev.retrieved_at) ASC
+             LIMIT $limit",
+        )
+        .param("checked_before", format_datetime(&checked_before))
+        .param("limit", limit as i64);
+
+        let mut stream = self.client.graph.execute(q).await?;
+        let mut due = Vec::new();
+        while let Some(row) = stream.next().await? {
+            let id: String = row.get("id").unwrap_or_default();
+            let Ok(evidence_id) = Uuid::parse_str(&id) else {
+                continue;
+            };
+            let verified_at: String = row.get("verified_at").unwrap_or_default();
+            due.push(EvidenceRecheck {
+                evidence_id,
+                source_url: row.get("url").unwrap_or_default(),
+                content_hash: row.get("content_hash").unwrap_or_default(),
+                snippet: row.get::<String>("snippet").ok().filter(|s| !s.is_empty()),
+                freshness: row.get::<String>("freshness").ok().and_then(|f| EvidenceFreshness::parse(&f)),
+                verified_at: DateTime::parse_from_rfc3339(&verified_at)
+                    .map(|t| t.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            });
+        }
+        Ok(due)
+    }
+
+    /// Record an evidence re-check and rescale the supported signal's
+    /// confidence from the previous freshness to the new one. `read` is false
+    /// when the page couldn't be fetched; the last read and its diff are kept.
+    pub async fn record_evidence_check(
+        &self,
+        evidence: &EvidenceRecheck,
+        verification: &EvidenceVerification,
+        read: bool,
+    ) -> Result<(), neo4rs::Error> {
+        let q = query(
+            "MATCH (n)-[:SOURCED_FROM]->(ev:Evidence {id: $id})
+             SET ev.freshness = $freshness,
+                 ev.checked_at = datetime($checked_at),
+                 ev.verified_at = CASE WHEN $read THEN datetime($checked_at) ELSE ev.verified_at END,
+                 ev.drift_diff = CASE WHEN $read THEN $drift_diff ELSE ev.drift_diff END
+             WITH n
+             WHERE n.confidence IS NOT NULL AND $adjustment <> 1.0
+             SET n.confidence = CASE WHEN n.confidence * $adjustment > 1.0 THEN 1.0
+                                     ELSE n.confidence * $adjustment END",
+        )
+        .param("id", evidence.evidence_id.to_string())
+        .param("freshness", verification.freshness.as_str())
+        .param("checked_at", format_datetime(&verification.checked_at))
+        .param("read", read)
+        .param::<Option<String>>("drift_diff", verification.drift_diff.clone())
+        .param("adjustment", verification.freshness.confidence_adjustment(evidence.freshness));
+
+        self.client.graph.run(q).await
+    }
+
     /// List all actors with their linked sources.
     pub async fn list_all_actors(
         &self,
@@ -12,7 +12,7 @@
 use chrono::Utc;
 use uuid::Uuid;
 
-use rootsignal_common::{DiscoveryMethod, EvidenceNode, SourceNode, SourceRole};
+use rootsignal_common::{DiscoveryMethod, EvidenceNode, SourceNode, SourceRole, TrialState};
 use rootsignal_graph::{query, GraphClient, GraphWriter};
 
 /// Spin up a fresh Neo4j container and run migrations.
@@ -612,6 +612,9 @@ async fn same_source_no_duplicate_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev1, signal_id)
@@ -628,6 +631,9 @@ async fn same_source_no_duplicate_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev2, signal_id)
@@ -644,6 +650,9 @@ async fn same_source_no_duplicate_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev3, signal_id)
@@ -702,6 +711,9 @@ async fn cross_source_creates_new_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev_a, signal_id)
@@ -718,6 +730,9 @@ async fn cross_source_creates_new_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev_b, signal_id)
@@ -734,6 +749,9 @@ async fn cross_source_creates_new_evidence() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev_c, signal_id)
@@ -786,6 +804,9 @@ async fn same_source_does_not_inflate_corroboration() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev, signal_id)
@@ -808,6 +829,9 @@ async fn same_source_does_not_inflate_corroboration() {
             relevance: None,
             evidence_confidence: None,
             channel_type: None,
+            change_summary: None,
+            engagement_velocity: None,
+            verification: None,
         };
         writer
             .create_evidence(&ev, signal_id)
@@ -866,6 +890,9 @@ async fn same_source_does_not_inflate_corroboration() {
         relevance: None,
         evidence_confidence: None,
         channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     };
     writer
         .create_evidence(&ev_cross, signal_id)
@@ -1301,6 +1328,7 @@ async fn source_last_scraped_round_trip() {
         quality_penalty: 1.0,
         source_role: SourceRole::Mixed,
         scrape_count: 0,
+        trial: TrialState::Established,
     };
 
     writer
@@ -3029,6 +3057,7 @@ async fn signal_expansion_source_created_with_correct_method() {
         quality_penalty: 1.0,
         source_role: SourceRole::Mixed,
         scrape_count: 0,
+        trial: TrialState::Probation,
     };
 
     writer
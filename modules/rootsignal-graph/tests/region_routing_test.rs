@@ -0,0 +1,77 @@
+//! Integration test: regions routed to their own database keep their data there.
+//! Needs a Neo4j edition with multiple databases (Enterprise).
+//! Run with: cargo test -p rootsignal-graph --test region_routing_test -- --ignored --nocapture
+
+use std::collections::HashMap;
+
+use rootsignal_graph::{query, GraphClient, GraphRouter};
+
+fn load_env() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(".env");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                if std::env::var(key.trim()).is_err() {
+                    std::env::set_var(key.trim(), value.trim());
+                }
+            }
+        }
+    }
+}
+
+async fn router() -> GraphRouter {
+    load_env();
+    let uri = std::env::var("NEO4J_URI").expect("NEO4J_URI required");
+    let user = std::env::var("NEO4J_USER").expect("NEO4J_USER required");
+    let password = std::env::var("NEO4J_PASSWORD").expect("NEO4J_PASSWORD required");
+    let default = GraphClient::connect(&uri, &user, &password)
+        .await
+        .expect("Failed to connect to Neo4j");
+    let routes = HashMap::from([("Twin Cities".to_string(), "region-test-twincities".to_string())]);
+    GraphRouter::new(default, &uri, &user, &password, routes)
+}
+
+async fn count_marked(client: &GraphClient, marker: &str) -> i64 {
+    let q = query("MATCH (n {test_marker: $marker}) RETURN count(n) AS n").param("marker", marker);
+    let mut stream = client.inner().execute(q).await.unwrap();
+    let row = stream.next().await.unwrap().unwrap();
+    row.get("n").unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn routed_region_writes_stay_in_its_database() {
+    let router = router().await;
+    router.migrate_all().await.expect("migrate_all failed");
+    let marker = uuid::Uuid::new_v4().to_string();
+
+    let region = router.for_region("twincities").await.unwrap();
+    let q = query("CREATE (:Need {id: $id, title: 'Winter coats', test_marker: $marker})")
+        .param("id", uuid::Uuid::new_v4().to_string())
+        .param("marker", marker.as_str());
+    region.inner().run(q).await.unwrap();
+
+    assert_eq!(region.database(), "region-test-twincities");
+    assert_eq!(count_marked(&region, &marker).await, 1);
+    assert_eq!(count_marked(router.default_client(), &marker).await, 0);
+}
+
+#[tokio::test]
+#[ignore]
+async fn unrouted_regions_use_the_default_database() {
+    let router = router().await;
+
+    let client = router.for_region("Miami").await.unwrap();
+
+    assert_eq!(client.database(), router.default_client().database());
+    assert_eq!(router.databases(), vec!["neo4j".to_string(), "region-test-twincities".to_string()]);
+}
@@ -0,0 +1,106 @@
+//! Integration test: data written for one tenant is invisible to every other.
+//! Needs a Neo4j edition with multiple databases (Enterprise).
+//! Run with: cargo test -p rootsignal-graph --test tenant_isolation_test -- --ignored --nocapture
+
+use chrono::Utc;
+use rootsignal_common::RegionSchedule;
+use rootsignal_graph::{query, GraphClient, GraphWriter};
+
+fn load_env() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(".env");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                if std::env::var(key.trim()).is_err() {
+                    std::env::set_var(key.trim(), value.trim());
+                }
+            }
+        }
+    }
+}
+
+/// Connect to `database`, creating and migrating it first.
+async fn tenant_client(database: &str) -> GraphClient {
+    load_env();
+    let uri = std::env::var("NEO4J_URI").expect("NEO4J_URI required");
+    let user = std::env::var("NEO4J_USER").expect("NEO4J_USER required");
+    let password = std::env::var("NEO4J_PASSWORD").expect("NEO4J_PASSWORD required");
+    let primary = GraphClient::connect(&uri, &user, &password)
+        .await
+        .expect("Failed to connect to Neo4j");
+    primary.create_database(database).await.expect("Failed to create tenant database");
+    let client = GraphClient::connect_database(&uri, &user, &password, database)
+        .await
+        .expect("Failed to connect to tenant database");
+    rootsignal_graph::migrate::migrate(&client).await.expect("Migration failed");
+    client
+}
+
+async fn count_marked(client: &GraphClient, marker: &str) -> i64 {
+    let q = query("MATCH (n {test_marker: $marker}) RETURN count(n) AS n").param("marker", marker);
+    let mut stream = client.inner().execute(q).await.unwrap();
+    let row = stream.next().await.unwrap().unwrap();
+    row.get("n").unwrap()
+}
+
+fn schedule() -> RegionSchedule {
+    RegionSchedule {
+        task_id: "task-northside".to_string(),
+        cron: "0 6 * * *".to_string(),
+        timezone: "America/Chicago".to_string(),
+        paused: false,
+        last_run_at: None,
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn writes_in_one_tenant_database_are_invisible_to_another() {
+    let north = tenant_client("tenant-test-north").await;
+    let river = tenant_client("tenant-test-river").await;
+    let marker = uuid::Uuid::new_v4().to_string();
+
+    let q = query("CREATE (:Gathering {id: $id, title: 'Northside potluck', test_marker: $marker})")
+        .param("id", uuid::Uuid::new_v4().to_string())
+        .param("marker", marker.as_str());
+    north.inner().run(q).await.unwrap();
+    GraphWriter::new(north.clone())
+        .set_region_schedule("northside", &schedule())
+        .await
+        .unwrap();
+
+    assert_eq!(count_marked(&north, &marker).await, 1);
+    assert_eq!(count_marked(&river, &marker).await, 0);
+    let river_writer = GraphWriter::new(river.clone());
+    assert!(river_writer.get_region_schedule("northside").await.unwrap().is_none());
+    assert!(river_writer.list_region_schedules().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+#[ignore]
+async fn tenant_writes_never_reach_the_primary_database() {
+    let north = tenant_client("tenant-test-north").await;
+    let uri = std::env::var("NEO4J_URI").unwrap();
+    let user = std::env::var("NEO4J_USER").unwrap();
+    let password = std::env::var("NEO4J_PASSWORD").unwrap();
+    let primary = GraphClient::connect(&uri, &user, &password).await.unwrap();
+    let marker = uuid::Uuid::new_v4().to_string();
+
+    let q = query("CREATE (:Tension {id: $id, title: 'Rent hikes', test_marker: $marker})")
+        .param("id", uuid::Uuid::new_v4().to_string())
+        .param("marker", marker.as_str());
+    north.inner().run(q).await.unwrap();
+
+    assert_eq!(north.database(), "tenant-test-north");
+    assert_eq!(count_marked(&primary, &marker).await, 0);
+}
@@ -0,0 +1,68 @@
+//! Integration tests for the polling trigger scan.
+//!
+//! Requirements: Docker (for Neo4j via testcontainers)
+//!
+//! Run with: cargo test -p rootsignal-graph --features test-utils --test triggers_test
+
+#![cfg(feature = "test-utils")]
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use rootsignal_graph::triggers::{signals_extracted_after, SCAN_LIMIT};
+use rootsignal_graph::{query, GraphClient};
+
+async fn setup() -> (impl std::any::Any, GraphClient) {
+    rootsignal_graph::testutil::neo4j_container().await
+}
+
+fn neo4j_dt(dt: &chrono::DateTime<Utc>) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%S%.6f").to_string()
+}
+
+async fn create_tension(client: &GraphClient, id: Uuid, title: &str, review_status: &str) {
+    let now = neo4j_dt(&Utc::now());
+    let q = query(
+        "CREATE (n:Tension {
+            id: $id,
+            title: $title,
+            summary: $title,
+            sensitivity: 'general',
+            confidence: 0.9,
+            freshness_score: 0.8,
+            corroboration_count: 0,
+            source_diversity: 1,
+            external_ratio: 0.0,
+            cause_heat: 0.0,
+            source_url: 'https://example.org/rent',
+            extracted_at: datetime($now),
+            last_confirmed_active: datetime($now),
+            location_name: '',
+            lat: 44.9778,
+            lng: -93.2650,
+            severity: 'medium',
+            review_status: $review_status
+        })",
+    )
+    .param("id", id.to_string())
+    .param("title", title)
+    .param("now", now)
+    .param("review_status", review_status);
+
+    client.inner().run(q).await.expect("Failed to create tension");
+}
+
+#[tokio::test]
+async fn staged_and_rejected_signals_never_reach_trigger_polls() {
+    let (_container, client) = setup().await;
+    let live = Uuid::new_v4();
+    create_tension(&client, live, "Rent hikes on Lake Street", "live").await;
+    create_tension(&client, Uuid::new_v4(), "Unreviewed rent claim", "staged").await;
+    create_tension(&client, Uuid::new_v4(), "Rejected rent claim", "rejected").await;
+
+    let since = Utc::now() - Duration::hours(1);
+    let signals = signals_extracted_after(&client, since, &[], &[], SCAN_LIMIT).await.unwrap();
+
+    let ids: Vec<Uuid> = signals.iter().filter_map(|n| n.meta()).map(|m| m.id).collect();
+    assert_eq!(ids, vec![live]);
+}
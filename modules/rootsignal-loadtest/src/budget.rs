@@ -0,0 +1,159 @@
+//! Performance budget: the limits a load test run must stay within.
+//!
+//! The budget lives in `perf-budget.json` next to this crate:
+//!
+//! ```json
+//! {
+//!   "overall": { "p95_ms": 250, "max_error_rate": 0.01 },
+//!   "scenarios": { "story_detail": { "p95_ms": 80 } }
+//! }
+//! ```
+//!
+//! Every limit is optional; only the ones given are checked.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::mix::Scenario;
+use crate::report::{Report, Stats};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Limits {
+    pub p50_ms: Option<f64>,
+    pub p95_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    /// Fraction of requests allowed to fail, 0.0–1.0.
+    pub max_error_rate: Option<f64>,
+    pub min_rps: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PerfBudget {
+    #[serde(default)]
+    pub overall: Limits,
+    /// Keyed by scenario name, e.g. `map_signals`.
+    #[serde(default)]
+    pub scenarios: BTreeMap<String, Limits>,
+}
+
+/// A limit a run went past.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub scope: String,
+    pub metric: &'static str,
+    pub limit: f64,
+    pub actual: f64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {:.2} (budget {:.2})", self.scope, self.metric, self.actual, self.limit)
+    }
+}
+
+impl PerfBudget {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let budget: Self =
+            serde_json::from_str(&raw).with_context(|| format!("Invalid perf budget {}", path.display()))?;
+        if let Some(name) = budget.scenarios.keys().find(|name| Scenario::parse(name).is_none()) {
+            anyhow::bail!("Perf budget {} names unknown scenario {name:?}", path.display());
+        }
+        Ok(budget)
+    }
+
+    /// Every limit the report breaks. Scenarios the run didn't exercise are
+    /// skipped rather than counted against the budget.
+    pub fn check(&self, report: &Report) -> Vec<Violation> {
+        let mut violations = check_limits("overall", &self.overall, &report.overall);
+        for (name, limits) in &self.scenarios {
+            let stats = Scenario::parse(name).and_then(|s| report.scenarios.get(&s));
+            if let Some(stats) = stats {
+                violations.extend(check_limits(name, limits, stats));
+            }
+        }
+        violations
+    }
+}
+
+fn check_limits(scope: &str, limits: &Limits, stats: &Stats) -> Vec<Violation> {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    let ceilings = [
+        ("p50_ms", limits.p50_ms, ms(stats.p50)),
+        ("p95_ms", limits.p95_ms, ms(stats.p95)),
+        ("p99_ms", limits.p99_ms, ms(stats.p99)),
+        ("error_rate", limits.max_error_rate, stats.error_rate()),
+    ];
+    let mut violations: Vec<Violation> = ceilings
+        .into_iter()
+        .filter_map(|(metric, limit, actual)| {
+            let limit = limit?;
+            (actual > limit).then(|| Violation { scope: scope.to_string(), metric, limit, actual })
+        })
+        .collect();
+    if let Some(limit) = limits.min_rps.filter(|min| stats.rps < *min) {
+        violations.push(Violation { scope: scope.to_string(), metric: "rps", limit, actual: stats.rps });
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Sample;
+
+    fn report(latencies_ms: &[u64], failures: usize) -> Report {
+        let samples: Vec<_> = latencies_ms
+            .iter()
+            .enumerate()
+            .map(|(i, ms)| Sample {
+                scenario: Scenario::StoryDetail,
+                latency: Duration::from_millis(*ms),
+                ok: i >= failures,
+            })
+            .collect();
+        Report::from_samples(&samples, Duration::from_secs(1))
+    }
+
+    #[test]
+    fn run_within_budget_has_no_violations() {
+        let budget: PerfBudget =
+            serde_json::from_str(r#"{"overall": {"p95_ms": 100, "max_error_rate": 0.1}}"#).unwrap();
+
+        let violations = budget.check(&report(&[20, 30, 40, 50], 0));
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn slow_scenario_breaks_its_own_budget() {
+        let budget: PerfBudget =
+            serde_json::from_str(r#"{"scenarios": {"story_detail": {"p95_ms": 100}, "map_signals": {"p95_ms": 1}}}"#)
+                .unwrap();
+
+        let violations = budget.check(&report(&[20, 30, 40, 500], 0));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].scope, "story_detail");
+        assert_eq!(violations[0].metric, "p95_ms");
+        assert_eq!(violations[0].actual, 500.0);
+    }
+
+    #[test]
+    fn error_rate_and_throughput_are_checked() {
+        let budget: PerfBudget =
+            serde_json::from_str(r#"{"overall": {"max_error_rate": 0.1, "min_rps": 10}}"#).unwrap();
+
+        let violations = budget.check(&report(&[20, 30, 40, 50], 1));
+
+        let metrics: Vec<_> = violations.iter().map(|v| v.metric).collect();
+        assert_eq!(metrics, vec!["error_rate", "rps"]);
+    }
+}
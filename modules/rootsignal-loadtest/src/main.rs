@@ -0,0 +1,217 @@
+//! Load tests for the public API and the synthetic data they run against.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+use tracing::{info, warn};
+use tracing_subscriber::EnvFilter;
+
+use rootsignal_common::Config;
+use rootsignal_graph::{GraphClient, GraphRouter};
+
+mod budget;
+mod mix;
+mod report;
+mod runner;
+mod seed;
+
+use budget::PerfBudget;
+use mix::{Area, Mix};
+
+#[derive(Parser)]
+#[command(about = "Load test the Root Signal API")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Drive a traffic mix against a running API and check the perf budget.
+    Run(RunArgs),
+    /// Populate the graph with synthetic signals and stories.
+    Seed(SeedArgs),
+    /// Delete everything `seed` created.
+    Purge {
+        /// Region whose database to purge (see REGION_DATABASES).
+        #[arg(long, env = "REGION", default_value = "twincities")]
+        region: String,
+    },
+}
+
+#[derive(Args)]
+struct AreaArgs {
+    #[arg(long, default_value_t = 44.9778, allow_negative_numbers = true)]
+    lat: f64,
+    #[arg(long, default_value_t = -93.2650, allow_negative_numbers = true)]
+    lng: f64,
+    #[arg(long, default_value_t = 20.0)]
+    radius_km: f64,
+}
+
+impl AreaArgs {
+    fn area(&self) -> Area {
+        Area { center_lat: self.lat, center_lng: self.lng, radius_km: self.radius_km }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The API's GraphQL endpoint.
+    #[arg(long, default_value = "http://localhost:8080/graphql")]
+    url: String,
+
+    /// Sent as X-Api-Key, to load test a tenant deployment.
+    #[arg(long, env = "LOADTEST_API_KEY")]
+    api_key: Option<String>,
+
+    /// Scenario weights, e.g. `map_signals=45,story_detail=20`.
+    #[arg(long, value_parser = Mix::parse, default_value_t = Mix::default())]
+    mix: Mix,
+
+    /// Requests in flight at once.
+    #[arg(long, default_value_t = 16)]
+    concurrency: usize,
+
+    #[arg(long, default_value_t = 30)]
+    duration_secs: u64,
+
+    /// Perf budget to check; defaults to this crate's perf-budget.json.
+    #[arg(long)]
+    budget: Option<PathBuf>,
+
+    /// Report only, without failing on budget violations.
+    #[arg(long)]
+    no_budget: bool,
+
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    #[command(flatten)]
+    area: AreaArgs,
+}
+
+#[derive(Args)]
+struct SeedArgs {
+    #[arg(long, default_value_t = 100_000)]
+    signals: usize,
+
+    /// Signals grouped into each story; 0 for no stories.
+    #[arg(long, default_value_t = 25)]
+    signals_per_story: usize,
+
+    /// Region whose database to seed (see REGION_DATABASES).
+    #[arg(long, env = "REGION", default_value = "twincities")]
+    region: String,
+
+    /// Concurrent writes.
+    #[arg(long, default_value_t = 16)]
+    concurrency: usize,
+
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    #[command(flatten)]
+    area: AreaArgs,
+}
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env().add_directive("rootsignal=info".parse()?))
+        .init();
+    dotenv_load();
+
+    match Cli::parse().command {
+        Command::Run(args) => run(args).await,
+        Command::Seed(args) => {
+            let client = region_client(&args.region).await?;
+            let opts = seed::SeedOptions {
+                signals: args.signals,
+                signals_per_story: args.signals_per_story,
+                area: args.area.area(),
+                concurrency: args.concurrency,
+                seed: args.seed,
+            };
+            seed::seed(&client, &opts).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Purge { region } => {
+            let removed = seed::purge(&region_client(&region).await?).await?;
+            info!(removed, "Seeded data purged");
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+async fn run(args: RunArgs) -> Result<ExitCode> {
+    let budget = if args.no_budget {
+        None
+    } else {
+        let path = args
+            .budget
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("perf-budget.json"));
+        Some(PerfBudget::load(&path)?)
+    };
+
+    let report = runner::run(runner::RunOptions {
+        url: args.url,
+        api_key: args.api_key,
+        mix: args.mix,
+        concurrency: args.concurrency.max(1),
+        duration: Duration::from_secs(args.duration_secs),
+        area: args.area.area(),
+        seed: args.seed,
+    })
+    .await?;
+    report.print();
+
+    let Some(budget) = budget else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let violations = budget.check(&report);
+    if violations.is_empty() {
+        info!("Within perf budget");
+        return Ok(ExitCode::SUCCESS);
+    }
+    for violation in &violations {
+        warn!("Over budget: {violation}");
+    }
+    Ok(ExitCode::FAILURE)
+}
+
+/// The graph holding `region`'s data, as the API sees it.
+async fn region_client(region: &str) -> Result<GraphClient> {
+    let config = Config::web_from_env();
+    let client =
+        GraphClient::connect(&config.neo4j_uri, &config.neo4j_user, &config.neo4j_password.expose()).await?;
+    let router = GraphRouter::from_config(client, &config);
+    router.migrate_all().await?;
+    Ok(router.for_region(region).await?)
+}
+
+/// Load .env from workspace root (doesn't override existing env vars).
+fn dotenv_load() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(".env");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                if std::env::var(key.trim()).is_err() {
+                    std::env::set_var(key.trim(), value.trim());
+                }
+            }
+        }
+    }
+}
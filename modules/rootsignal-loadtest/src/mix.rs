@@ -0,0 +1,224 @@
+//! What traffic a load test sends: the request kinds, how often each is
+//! picked, and the area the map viewports are drawn from.
+
+use std::fmt;
+
+use rand::Rng;
+
+/// One kind of request, modelled on what the search app sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Scenario {
+    /// Panning the map: `signalsInBounds` over a viewport.
+    MapSignals,
+    /// The story layer of the map: `storiesInBounds` over a viewport.
+    MapStories,
+    /// Opening a story.
+    StoryDetail,
+    /// Opening a signal, with its evidence and story.
+    SignalDetail,
+    /// The recent-signals list.
+    RecentSignals,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 5] = [
+        Scenario::MapSignals,
+        Scenario::MapStories,
+        Scenario::StoryDetail,
+        Scenario::SignalDetail,
+        Scenario::RecentSignals,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scenario::MapSignals => "map_signals",
+            Scenario::MapStories => "map_stories",
+            Scenario::StoryDetail => "story_detail",
+            Scenario::SignalDetail => "signal_detail",
+            Scenario::RecentSignals => "recent_signals",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.name() == name)
+    }
+
+    /// Detail requests need ids of stories or signals that exist.
+    pub fn needs_targets(self) -> bool {
+        matches!(self, Scenario::StoryDetail | Scenario::SignalDetail)
+    }
+}
+
+impl fmt::Display for Scenario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Relative weights of each scenario, e.g. `map_signals=45,story_detail=20`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mix(Vec<(Scenario, u32)>);
+
+impl Default for Mix {
+    /// Someone browsing the map: mostly panning, now and then opening something.
+    fn default() -> Self {
+        Self(vec![
+            (Scenario::MapSignals, 45),
+            (Scenario::MapStories, 20),
+            (Scenario::StoryDetail, 20),
+            (Scenario::SignalDetail, 10),
+            (Scenario::RecentSignals, 5),
+        ])
+    }
+}
+
+impl Mix {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut weights = Vec::new();
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, weight) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Expected scenario=weight, got {part:?}"))?;
+            let scenario = Scenario::parse(name.trim()).ok_or_else(|| {
+                let known: Vec<_> = Scenario::ALL.iter().map(|s| s.name()).collect();
+                format!("Unknown scenario {name:?} (known: {})", known.join(", "))
+            })?;
+            let weight: u32 = weight
+                .trim()
+                .parse()
+                .map_err(|_| format!("Weight for {name} must be a whole number"))?;
+            if weight > 0 {
+                weights.push((scenario, weight));
+            }
+        }
+        if weights.is_empty() {
+            return Err("Mix must give at least one scenario a weight".to_string());
+        }
+        Ok(Self(weights))
+    }
+
+    pub fn scenarios(&self) -> impl Iterator<Item = Scenario> + '_ {
+        self.0.iter().map(|(s, _)| *s)
+    }
+
+    /// The mix without `scenario`; None if nothing would be left.
+    pub fn without(&self, scenario: Scenario) -> Option<Self> {
+        let rest: Vec<_> = self.0.iter().copied().filter(|(s, _)| *s != scenario).collect();
+        (!rest.is_empty()).then_some(Self(rest))
+    }
+
+    /// Pick a scenario in proportion to its weight.
+    pub fn pick(&self, rng: &mut impl Rng) -> Scenario {
+        let total: u32 = self.0.iter().map(|(_, w)| w).sum();
+        let mut roll = rng.random_range(0..total);
+        for (scenario, weight) in &self.0 {
+            if roll < *weight {
+                return *scenario;
+            }
+            roll -= weight;
+        }
+        unreachable!("roll is below the total weight")
+    }
+}
+
+impl fmt::Display for Mix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<_> = self.0.iter().map(|(s, w)| format!("{s}={w}")).collect();
+        f.write_str(&parts.join(","))
+    }
+}
+
+/// A circle on the map that generated points and viewports fall inside.
+#[derive(Debug, Clone, Copy)]
+pub struct Area {
+    pub center_lat: f64,
+    pub center_lng: f64,
+    pub radius_km: f64,
+}
+
+/// A map viewport.
+#[derive(Debug, Clone, Copy)]
+pub struct Bbox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+const KM_PER_DEGREE_LAT: f64 = 111.0;
+
+impl Area {
+    /// A uniformly random point inside the circle.
+    pub fn random_point(&self, rng: &mut impl Rng) -> (f64, f64) {
+        let distance = self.radius_km * rng.random::<f64>().sqrt();
+        let bearing = rng.random::<f64>() * std::f64::consts::TAU;
+        self.offset(distance * bearing.cos(), distance * bearing.sin())
+    }
+
+    /// A viewport between city-block and neighborhood-cluster size, centred
+    /// somewhere in the area.
+    pub fn random_viewport(&self, rng: &mut impl Rng) -> Bbox {
+        let (lat, lng) = self.random_point(rng);
+        let half_km = rng.random_range(0.5..4.0);
+        Area { center_lat: lat, center_lng: lng, radius_km: half_km }.bounds()
+    }
+
+    /// The square that contains the whole circle.
+    pub fn bounds(&self) -> Bbox {
+        let (max_lat, max_lng) = self.offset(self.radius_km, self.radius_km);
+        let (min_lat, min_lng) = self.offset(-self.radius_km, -self.radius_km);
+        Bbox { min_lat, max_lat, min_lng, max_lng }
+    }
+
+    fn offset(&self, north_km: f64, east_km: f64) -> (f64, f64) {
+        let km_per_degree_lng = KM_PER_DEGREE_LAT * self.center_lat.to_radians().cos();
+        (
+            self.center_lat + north_km / KM_PER_DEGREE_LAT,
+            self.center_lng + east_km / km_per_degree_lng,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn mix_parses_weights_and_skips_zeroes() {
+        let mix = Mix::parse("map_signals=3, story_detail=1, signal_detail=0").unwrap();
+
+        assert_eq!(mix.to_string(), "map_signals=3,story_detail=1");
+    }
+
+    #[test]
+    fn mix_rejects_unknown_scenarios() {
+        let err = Mix::parse("map_signals=3,checkout=1").unwrap_err();
+
+        assert!(err.contains("checkout"));
+    }
+
+    #[test]
+    fn picks_follow_the_weights() {
+        let mix = Mix::parse("map_signals=3,story_detail=1").unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let picks: Vec<_> = (0..4000).map(|_| mix.pick(&mut rng)).collect();
+
+        let map = picks.iter().filter(|s| **s == Scenario::MapSignals).count();
+        assert!((2800..3200).contains(&map), "map_signals picked {map} of 4000");
+    }
+
+    #[test]
+    fn random_points_stay_inside_the_area() {
+        let area = Area { center_lat: 44.9778, center_lng: -93.2650, radius_km: 10.0 };
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..500 {
+            let (lat, lng) = area.random_point(&mut rng);
+            let km = rootsignal_common::haversine_km(area.center_lat, area.center_lng, lat, lng);
+            assert!(km <= 10.1, "{km} km from the center");
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Latency percentiles, error rates and throughput from a load test.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::mix::Scenario;
+
+/// One request's outcome.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub scenario: Scenario,
+    pub latency: Duration,
+    /// A 2xx response with no GraphQL errors.
+    pub ok: bool,
+}
+
+/// Latencies include failed requests, so a fast-failing endpoint can't look
+/// healthy by its percentiles alone; check `error_rate` too.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub requests: usize,
+    pub errors: usize,
+    pub rps: f64,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples<'a>(samples: impl Iterator<Item = &'a Sample>, elapsed: Duration) -> Self {
+        let mut latencies = Vec::new();
+        let mut errors = 0;
+        for sample in samples {
+            latencies.push(sample.latency);
+            errors += usize::from(!sample.ok);
+        }
+        latencies.sort_unstable();
+        Self {
+            requests: latencies.len(),
+            errors,
+            rps: latencies.len() as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+            p50: percentile(&latencies, 50.0),
+            p90: percentile(&latencies, 90.0),
+            p95: percentile(&latencies, 95.0),
+            p99: percentile(&latencies, 99.0),
+            max: latencies.last().copied().unwrap_or_default(),
+        }
+    }
+
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            return 0.0;
+        }
+        self.errors as f64 / self.requests as f64
+    }
+}
+
+/// Nearest-rank percentile of already sorted latencies.
+pub fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub elapsed: Duration,
+    pub overall: Stats,
+    pub scenarios: BTreeMap<Scenario, Stats>,
+}
+
+impl Report {
+    pub fn from_samples(samples: &[Sample], elapsed: Duration) -> Self {
+        let mut scenarios = BTreeMap::new();
+        for scenario in Scenario::ALL {
+            if samples.iter().any(|s| s.scenario == scenario) {
+                let stats = Stats::from_samples(samples.iter().filter(|s| s.scenario == scenario), elapsed);
+                scenarios.insert(scenario, stats);
+            }
+        }
+        Self {
+            elapsed,
+            overall: Stats::from_samples(samples.iter(), elapsed),
+            scenarios,
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{:<16} {:>8} {:>7} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+            "scenario", "requests", "errors", "rps", "p50", "p90", "p95", "p99", "max"
+        );
+        for (scenario, stats) in &self.scenarios {
+            print_row(scenario.name(), stats);
+        }
+        print_row("overall", &self.overall);
+        println!("{:.1}s elapsed", self.elapsed.as_secs_f64());
+    }
+}
+
+fn print_row(name: &str, stats: &Stats) {
+    let ms = |d: Duration| format!("{:.1}ms", d.as_secs_f64() * 1000.0);
+    println!(
+        "{:<16} {:>8} {:>6.2}% {:>8.1} {:>8} {:>8} {:>8} {:>8} {:>8}",
+        name,
+        stats.requests,
+        stats.error_rate() * 100.0,
+        stats.rps,
+        ms(stats.p50),
+        ms(stats.p90),
+        ms(stats.p95),
+        ms(stats.p99),
+        ms(stats.max),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let sorted: Vec<_> = (1..=100).map(ms).collect();
+
+        assert_eq!(percentile(&sorted, 50.0), ms(50));
+        assert_eq!(percentile(&sorted, 99.0), ms(99));
+        assert_eq!(percentile(&sorted, 100.0), ms(100));
+        assert_eq!(percentile(&[], 95.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn report_splits_stats_by_scenario() {
+        let samples = vec![
+            Sample { scenario: Scenario::MapSignals, latency: ms(10), ok: true },
+            Sample { scenario: Scenario::MapSignals, latency: ms(30), ok: false },
+            Sample { scenario: Scenario::StoryDetail, latency: ms(5), ok: true },
+        ];
+
+        let report = Report::from_samples(&samples, Duration::from_secs(1));
+
+        let map = &report.scenarios[&Scenario::MapSignals];
+        assert_eq!(map.requests, 2);
+        assert_eq!(map.error_rate(), 0.5);
+        assert_eq!(map.max, ms(30));
+        assert_eq!(report.overall.requests, 3);
+        assert_eq!(report.overall.rps, 3.0);
+    }
+}
@@ -0,0 +1,180 @@
+//! Drives a traffic mix against a running API and records every request.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::SeedableRng;
+use serde_json::{json, Value};
+use tracing::{info, warn};
+
+use crate::mix::{Area, Bbox, Mix, Scenario};
+use crate::report::{Report, Sample};
+
+const SIGNAL_FIELDS: &str = "id title summary confidence causeHeat channelDiversity extractedAt location { lat lng } locationName";
+
+const STORY_FIELDS: &str = "id headline summary signalCount energy velocity centroidLat centroidLng dominantType arc category lede tags { slug name }";
+
+pub struct RunOptions {
+    /// The API's GraphQL endpoint, e.g. `http://localhost:8080/graphql`.
+    pub url: String,
+    pub api_key: Option<String>,
+    pub mix: Mix,
+    pub concurrency: usize,
+    pub duration: Duration,
+    pub area: Area,
+    pub seed: u64,
+}
+
+/// Ids for the detail scenarios, found before the run starts.
+#[derive(Default)]
+struct Targets {
+    stories: Vec<String>,
+    signals: Vec<String>,
+}
+
+struct Client {
+    http: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+impl Client {
+    /// POST a GraphQL request; Ok only for a 2xx response without errors.
+    async fn graphql(&self, query: &str, variables: Value) -> anyhow::Result<Value> {
+        let mut request = self.http.post(&self.url).json(&json!({ "query": query, "variables": variables }));
+        if let Some(key) = &self.api_key {
+            request = request.header("x-api-key", key);
+        }
+        let response = request.send().await?.error_for_status()?;
+        let body: Value = response.json().await?;
+        if let Some(errors) = body.get("errors") {
+            anyhow::bail!("GraphQL errors: {errors}");
+        }
+        Ok(body)
+    }
+}
+
+pub async fn run(opts: RunOptions) -> anyhow::Result<Report> {
+    let client = Arc::new(Client {
+        http: reqwest::Client::builder().timeout(Duration::from_secs(30)).build()?,
+        url: opts.url.clone(),
+        api_key: opts.api_key.clone(),
+    });
+
+    let targets = discover(&client, opts.area).await.context("Failed to reach the API")?;
+    info!(stories = targets.stories.len(), signals = targets.signals.len(), "Found detail targets");
+    let mut mix = opts.mix.clone();
+    for scenario in opts.mix.scenarios().filter(|s| s.needs_targets()) {
+        let empty = match scenario {
+            Scenario::StoryDetail => targets.stories.is_empty(),
+            _ => targets.signals.is_empty(),
+        };
+        if empty {
+            warn!(%scenario, "Nothing in the area to open — dropping scenario (seed data first?)");
+            mix = mix
+                .without(scenario)
+                .context("No scenario left to run; the area has no stories or signals")?;
+        }
+    }
+
+    info!(%mix, concurrency = opts.concurrency, secs = opts.duration.as_secs(), url = opts.url.as_str(), "Load test starting");
+    let targets = Arc::new(targets);
+    let started = Instant::now();
+    let deadline = started + opts.duration;
+    let mut workers = Vec::new();
+    for worker in 0..opts.concurrency {
+        let client = client.clone();
+        let targets = targets.clone();
+        let mix = mix.clone();
+        let area = opts.area;
+        let mut rng = StdRng::seed_from_u64(opts.seed.wrapping_add(worker as u64));
+        workers.push(tokio::spawn(async move {
+            let mut samples = Vec::new();
+            while Instant::now() < deadline {
+                let scenario = mix.pick(&mut rng);
+                let (query, variables) = request(scenario, &targets, area, &mut rng);
+                let sent = Instant::now();
+                let ok = client.graphql(&query, variables).await.is_ok();
+                samples.push(Sample { scenario, latency: sent.elapsed(), ok });
+            }
+            samples
+        }));
+    }
+
+    let mut samples = Vec::new();
+    for worker in workers {
+        samples.extend(worker.await?);
+    }
+    Ok(Report::from_samples(&samples, started.elapsed()))
+}
+
+/// Collect story and signal ids from the whole area.
+async fn discover(client: &Client, area: Area) -> anyhow::Result<Targets> {
+    let bbox = bbox_vars(area.bounds());
+    let stories = client
+        .graphql("query($minLat: Float!, $maxLat: Float!, $minLng: Float!, $maxLng: Float!) { storiesInBounds(minLat: $minLat, maxLat: $maxLat, minLng: $minLng, maxLng: $maxLng, limit: 100) { id } }", bbox.clone())
+        .await?;
+    let signals = client
+        .graphql(&format!("query($minLat: Float!, $maxLat: Float!, $minLng: Float!, $maxLng: Float!) {{ signalsInBounds(minLat: $minLat, maxLat: $maxLat, minLng: $minLng, maxLng: $maxLng, limit: 200) {{ {} }} }}", on_each_signal("id")), bbox)
+        .await?;
+    Ok(Targets {
+        stories: ids(&stories["data"]["storiesInBounds"]),
+        signals: ids(&signals["data"]["signalsInBounds"]),
+    })
+}
+
+fn ids(list: &Value) -> Vec<String> {
+    list.as_array()
+        .map(|items| items.iter().filter_map(|i| i["id"].as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+/// `fields` on every member of the signal union.
+fn on_each_signal(fields: &str) -> String {
+    ["GqlGatheringSignal", "GqlAidSignal", "GqlNeedSignal", "GqlNoticeSignal", "GqlTensionSignal"]
+        .iter()
+        .map(|t| format!("... on {t} {{ {fields} }}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bbox_vars(bbox: Bbox) -> Value {
+    json!({ "minLat": bbox.min_lat, "maxLat": bbox.max_lat, "minLng": bbox.min_lng, "maxLng": bbox.max_lng })
+}
+
+/// The query and variables for one request, shaped like the search app's.
+fn request(scenario: Scenario, targets: &Targets, area: Area, rng: &mut StdRng) -> (String, Value) {
+    const BBOX_ARGS: &str = "minLat: $minLat, maxLat: $maxLat, minLng: $minLng, maxLng: $maxLng";
+    const BBOX_PARAMS: &str = "$minLat: Float!, $maxLat: Float!, $minLng: Float!, $maxLng: Float!";
+    match scenario {
+        Scenario::MapSignals => (
+            format!(
+                "query SignalsInBounds({BBOX_PARAMS}) {{ signalsInBounds({BBOX_ARGS}, limit: 50) {{ {} }} }}",
+                on_each_signal(SIGNAL_FIELDS)
+            ),
+            bbox_vars(area.random_viewport(rng)),
+        ),
+        Scenario::MapStories => (
+            format!("query StoriesInBounds({BBOX_PARAMS}) {{ storiesInBounds({BBOX_ARGS}, limit: 20) {{ {STORY_FIELDS} }} }}"),
+            bbox_vars(area.random_viewport(rng)),
+        ),
+        Scenario::StoryDetail => (
+            format!("query StoryDetail($id: UUID!) {{ story(id: $id) {{ {STORY_FIELDS} narrative signals {{ {} }} }} }}", on_each_signal("id title")),
+            json!({ "id": targets.stories.choose(rng) }),
+        ),
+        Scenario::SignalDetail => (
+            format!(
+                "query SignalDetail($id: UUID!) {{ signal(id: $id) {{ {} }} }}",
+                on_each_signal(&format!("{SIGNAL_FIELDS} sourceUrl evidence {{ sourceUrl snippet }} story {{ id headline }}"))
+            ),
+            json!({ "id": targets.signals.choose(rng) }),
+        ),
+        Scenario::RecentSignals => (
+            format!("query SignalsRecent {{ signalsRecent(limit: 50) {{ {} }} }}", on_each_signal(SIGNAL_FIELDS)),
+            json!({}),
+        ),
+    }
+}
@@ -0,0 +1,256 @@
+//! Synthetic graph data for load tests: signals scattered over an area and
+//! stories grouping them, written through the same `GraphWriter` calls the
+//! scout uses so the API reads them exactly as it would real data.
+//!
+//! Everything seeded is marked `created_by: "loadtest-seed"` so it can be
+//! purged without touching anything else, and published straight to live:
+//! the API only serves reviewed data.
+
+use std::time::Instant;
+
+use chrono::{Duration, Utc};
+use futures::{stream, StreamExt, TryStreamExt};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::{
+    AidNode, FieldVisibility, GatheringNode, GeoPoint, GeoPrecision, NeedNode, Node, NodeMeta, NoticeNode,
+    SensitivityLevel, Severity, StoryNode, TensionNode, Urgency,
+};
+use rootsignal_graph::{query, GraphClient, GraphWriter};
+
+use crate::mix::Area;
+
+pub const SEED_CREATOR: &str = "loadtest-seed";
+
+pub struct SeedOptions {
+    pub signals: usize,
+    /// Signals per story; 0 seeds no stories.
+    pub signals_per_story: usize,
+    pub area: Area,
+    pub concurrency: usize,
+    pub seed: u64,
+}
+
+const GATHERINGS: &[&str] = &["Community potluck", "Block cleanup", "Tenant union meeting", "Free bike repair clinic", "Library story hour"];
+const AIDS: &[&str] = &["Free winter coats", "Food shelf open late", "Legal aid walk-in hours", "Free tax prep", "Community fridge restocked"];
+const NEEDS: &[&str] = &["Volunteers for meal delivery", "Diapers needed", "Drivers for medical rides", "Donations for fire victims", "Tutors for after-school program"];
+const NOTICES: &[&str] = &["Street closure for water main work", "Snow emergency declared", "Bus route detour", "Boil water advisory", "Park closed for repairs"];
+const TENSIONS: &[&str] = &["Rent increases pushing out longtime residents", "Clinic closure leaves gap in care", "Speeding on residential streets", "Shelter beds full every night", "Grocery store closure creates food desert"];
+const PLACES: &[&str] = &["Powderhorn", "Phillips", "Northeast", "Frogtown", "Cedar-Riverside", "Longfellow", "Midway", "North Loop"];
+
+/// Create `signals` synthetic signals and their stories.
+pub async fn seed(client: &GraphClient, opts: &SeedOptions) -> anyhow::Result<()> {
+    let writer = GraphWriter::new(client.clone());
+    let run_id = Uuid::new_v4().to_string();
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let nodes: Vec<Node> = (0..opts.signals).map(|i| synthetic_signal(&mut rng, opts.area, i)).collect();
+    let started = Instant::now();
+
+    let mut written = 0usize;
+    let mut writes = stream::iter(nodes.iter())
+        .map(|node| writer.create_node(node, &[], SEED_CREATOR, &run_id))
+        .buffered(opts.concurrency.max(1));
+    while writes.try_next().await?.is_some() {
+        written += 1;
+        if written.is_multiple_of(5_000) {
+            info!(written, total = opts.signals, "Seeding signals");
+        }
+    }
+    drop(writes);
+    info!(signals = written, secs = started.elapsed().as_secs(), "Signals seeded");
+
+    if opts.signals_per_story > 0 {
+        seed_stories(&writer, client, &nodes, opts).await?;
+        info!(secs = started.elapsed().as_secs(), "Stories seeded");
+    }
+    let published = publish(client).await?;
+    info!(published, "Seeded data published");
+    Ok(())
+}
+
+async fn seed_stories(writer: &GraphWriter, client: &GraphClient, nodes: &[Node], opts: &SeedOptions) -> anyhow::Result<()> {
+    let groups: Vec<&[Node]> = nodes.chunks(opts.signals_per_story).collect();
+    stream::iter(groups.iter().enumerate())
+        .map(|(i, group)| seed_story(writer, client, group, i))
+        .buffer_unordered(opts.concurrency.max(1))
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+/// Skip review for seeded signals and stories, which the writer stages.
+async fn publish(client: &GraphClient) -> anyhow::Result<u64> {
+    let q = query(
+        "MATCH (n {created_by: $creator})
+         WHERE n.review_status = 'staged'
+         SET n.review_status = 'live'
+         RETURN count(n) AS published",
+    )
+    .param("creator", SEED_CREATOR);
+    let mut stream = client.inner().execute(q).await?;
+    Ok(match stream.next().await? {
+        Some(row) => row.get::<i64>("published").unwrap_or(0) as u64,
+        None => 0,
+    })
+}
+
+async fn seed_story(writer: &GraphWriter, client: &GraphClient, group: &[Node], index: usize) -> anyhow::Result<()> {
+    let story = synthetic_story(group, index);
+    writer.create_story(&story).await?;
+    for node in group {
+        writer.link_signal_to_story(story.id, node.id()).await?;
+    }
+    let q = query("MATCH (s:Story {id: $id}) SET s.created_by = $creator")
+        .param("id", story.id.to_string())
+        .param("creator", SEED_CREATOR);
+    client.inner().run(q).await?;
+    Ok(())
+}
+
+/// Delete everything a previous seed created. Returns the number of nodes removed.
+pub async fn purge(client: &GraphClient) -> anyhow::Result<u64> {
+    let mut removed = 0u64;
+    loop {
+        let q = query(
+            "MATCH (n {created_by: $creator})
+             WITH n LIMIT 10000
+             DETACH DELETE n
+             RETURN count(*) AS removed",
+        )
+        .param("creator", SEED_CREATOR);
+        let mut stream = client.inner().execute(q).await?;
+        let batch = match stream.next().await? {
+            Some(row) => row.get::<i64>("removed").unwrap_or(0) as u64,
+            None => 0,
+        };
+        if batch == 0 {
+            return Ok(removed);
+        }
+        removed += batch;
+        info!(removed, "Purging seeded data");
+    }
+}
+
+/// One believable signal somewhere in `area`, cycling through the signal types.
+pub fn synthetic_signal(rng: &mut StdRng, area: Area, index: usize) -> Node {
+    let place = PLACES.choose(rng).copied().unwrap_or_default();
+    let (lat, lng) = area.random_point(rng);
+    let extracted_at = Utc::now() - Duration::minutes(rng.random_range(0..60 * 24 * 30));
+    let meta = |titles: &[&str], rng: &mut StdRng| {
+        let title = titles.choose(rng).copied().unwrap_or_default();
+        NodeMeta {
+            id: Uuid::new_v4(),
+            title: format!("{title} in {place} #{index}"),
+            summary: format!("Synthetic load test signal: {title} in {place}."),
+            sensitivity: SensitivityLevel::General,
+            confidence: rng.random_range(0.4..1.0),
+            freshness_score: rng.random_range(0.0..1.0),
+            corroboration_count: rng.random_range(0..5),
+            about_location: Some(GeoPoint { lat, lng, precision: GeoPrecision::Neighborhood }),
+            about_location_name: Some(place.to_string()),
+            from_location: None,
+            source_url: format!("https://loadtest.example/{index}"),
+            extracted_at,
+            content_date: Some(extracted_at),
+            last_confirmed_active: extracted_at,
+            source_diversity: rng.random_range(1..4),
+            external_ratio: rng.random_range(0.0..1.0),
+            cause_heat: rng.random_range(0.0..1.0),
+            implied_queries: Vec::new(),
+            channel_diversity: rng.random_range(1..4),
+            mentioned_actors: Vec::new(),
+            author_actor: None,
+            field_visibility: FieldVisibility::default(),
+            alt_text: None,
+            plain_summary: None,
+        }
+    };
+    let severity = *[Severity::Low, Severity::Medium, Severity::High].choose(rng).unwrap();
+    match index % 5 {
+        0 => Node::Gathering(GatheringNode {
+            meta: meta(GATHERINGS, rng),
+            starts_at: Some(Utc::now() + Duration::hours(rng.random_range(1..24 * 14))),
+            ends_at: None,
+            action_url: format!("https://loadtest.example/{index}/rsvp"),
+            organizer: Some(format!("{place} Neighbors")),
+            is_recurring: rng.random_bool(0.3),
+        }),
+        1 => Node::Aid(AidNode {
+            meta: meta(AIDS, rng),
+            action_url: format!("https://loadtest.example/{index}/apply"),
+            availability: Some("Weekdays 9–5".to_string()),
+            is_ongoing: rng.random_bool(0.5),
+        }),
+        2 => Node::Need(NeedNode {
+            meta: meta(NEEDS, rng),
+            urgency: *[Urgency::Low, Urgency::Medium, Urgency::High].choose(rng).unwrap(),
+            what_needed: Some("Volunteers and donations".to_string()),
+            action_url: None,
+            goal: None,
+            fundraiser: None,
+        }),
+        3 => Node::Notice(NoticeNode {
+            meta: meta(NOTICES, rng),
+            severity,
+            category: Some("infrastructure".to_string()),
+            effective_date: Some(extracted_at),
+            source_authority: Some("City of Minneapolis".to_string()),
+        }),
+        _ => Node::Tension(TensionNode {
+            meta: meta(TENSIONS, rng),
+            severity,
+            category: Some("housing".to_string()),
+            what_would_help: Some("More capacity and outreach".to_string()),
+        }),
+    }
+}
+
+fn synthetic_story(group: &[Node], index: usize) -> StoryNode {
+    let metas: Vec<&NodeMeta> = group.iter().filter_map(Node::meta).collect();
+    let points: Vec<&GeoPoint> = metas.iter().filter_map(|m| m.about_location.as_ref()).collect();
+    let centroid = |f: fn(&GeoPoint) -> f64| {
+        (!points.is_empty()).then(|| points.iter().map(|p| f(p)).sum::<f64>() / points.len() as f64)
+    };
+    let first_seen = metas.iter().map(|m| m.extracted_at).min().unwrap_or_else(Utc::now);
+    let last_updated = metas.iter().map(|m| m.extracted_at).max().unwrap_or_else(Utc::now);
+    let headline = metas.first().map(|m| m.title.clone()).unwrap_or_default();
+    let count = group.len() as u32;
+    StoryNode {
+        id: Uuid::new_v4(),
+        headline: format!("{headline} and {} related signals", count.saturating_sub(1)),
+        summary: format!("Synthetic load test story #{index}."),
+        signal_count: count,
+        first_seen,
+        last_updated,
+        velocity: (index % 7) as f64 / 7.0,
+        energy: (index % 11) as f64 / 11.0,
+        centroid_lat: centroid(|p| p.lat),
+        centroid_lng: centroid(|p| p.lng),
+        dominant_type: group.first().map(|n| n.node_type().to_string()).unwrap_or_default(),
+        sensitivity: "general".to_string(),
+        source_count: count,
+        entity_count: count,
+        type_diversity: count.min(5),
+        source_domains: vec!["loadtest.example".to_string()],
+        corroboration_depth: 1,
+        status: if index.is_multiple_of(3) { "confirmed" } else { "emerging" }.to_string(),
+        arc: None,
+        category: None,
+        lede: None,
+        narrative: None,
+        action_guidance: None,
+        cause_heat: 0.0,
+        ask_count: 0,
+        give_count: 0,
+        event_count: 0,
+        drawn_to_count: 0,
+        gap_score: 0,
+        gap_velocity: 0.0,
+        channel_diversity: 1,
+        energy_components: None,
+    }
+}
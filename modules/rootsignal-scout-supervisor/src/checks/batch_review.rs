@@ -119,7 +119,7 @@ pub async fn fetch_staged_signals(
                         s.summary AS summary, s.confidence AS confidence,
                         s.source_url AS source_url, s.lat AS lat, s.lng AS lng,
                         s.created_by AS created_by, s.scout_run_id AS scout_run_id,
-                        situation_headline
+                        situation_headline, s.policy_flags AS policy_flags
                  ORDER BY s.extracted_at DESC
                  LIMIT 50"
             )
@@ -127,7 +127,7 @@ pub async fn fetch_staged_signals(
         .collect();
 
     let cypher = format!(
-        "CALL {{\n{}\n}}\nRETURN id, signal_type, title, summary, confidence, source_url, lat, lng, created_by, scout_run_id, situation_headline\nORDER BY id\nLIMIT 50",
+        "CALL {{\n{}\n}}\nRETURN id, signal_type, title, summary, confidence, source_url, lat, lng, created_by, scout_run_id, situation_headline, policy_flags\nORDER BY id\nLIMIT 50",
         branches.join("\nUNION ALL\n")
     );
 
@@ -151,6 +151,8 @@ pub async fn fetch_staged_signals(
         let created_by: String = row.get("created_by").unwrap_or_default();
         let scout_run_id: String = row.get("scout_run_id").unwrap_or_default();
         let situation_headline: Option<String> = row.get("situation_headline").ok();
+        // Content-policy findings from GraphWriter ride along as triage flags.
+        let policy_flags: Vec<String> = row.get("policy_flags").unwrap_or_default();
 
         signals.push(SignalForReview {
             id,
@@ -164,7 +166,10 @@ pub async fn fetch_staged_signals(
             created_by,
             scout_run_id,
             situation_headline,
-            triage_flags: Vec::new(),
+            triage_flags: policy_flags
+                .into_iter()
+                .map(|flag| format!("ContentPolicy: {flag}"))
+                .collect(),
         });
     }
 
@@ -192,7 +197,7 @@ pub fn annotate_triage_flags(signals: &mut [SignalForReview], suspects: &[Suspec
 
     for signal in signals.iter_mut() {
         if let Some(flags) = suspect_map.get(&signal.id) {
-            signal.triage_flags = flags.clone();
+            signal.triage_flags.extend(flags.iter().cloned());
         }
     }
 }
@@ -229,6 +234,8 @@ Pass signals that describe real, observable community activity in or near {regio
 
 Reject signals that reference a different region, read like speculation or fabrication, have hallucinated sources (<UNKNOWN> URLs), are misclassified, are too vague, or are near-duplicates.
 
+Triage flags starting with "ContentPolicy:" mark wording in the title or summary that a rules check caught: absolute_claim ("always", "everyone", "proven") or speculative_causation ("likely caused by", "is to blame"). Reject the signal (rejection_reason "content_policy") when the source doesn't support that wording; pass it when the claim is the source's own, stated plainly.
+
 When rejecting, provide rejection_reason (short category) and explanation.
 
 2. If ANY signals are rejected, provide a run_analysis:
@@ -0,0 +1,312 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use neo4rs::query;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_graph::GraphClient;
+
+use crate::types::CoverageEscalation;
+
+/// Coverage below this counts as unmet.
+const UNMET_COVERAGE: f64 = 0.2;
+
+/// Days a high-severity tension may stay unmet before it is escalated.
+const ESCALATE_AFTER_DAYS: i64 = 7;
+
+/// Score how well each active tension is covered by responses, record a daily
+/// snapshot, and escalate high-severity tensions that stay unmet.
+///
+/// Coverage is the capacity-weighted match strength of a tension's responses
+/// against how many strong responses its severity calls for, capped at 1.0.
+/// Escalated tensions are moved to the front of response finder targeting;
+/// they drop back once coverage recovers. Returns the new escalations so the
+/// caller can notify.
+pub async fn score_coverage(client: &GraphClient) -> Result<(CoverageStats, Vec<CoverageEscalation>)> {
+    let mut stats = CoverageStats::default();
+    let mut escalations = Vec::new();
+    let now = Utc::now();
+    let today = now.date_naive();
+
+    let q = query(
+        "MATCH (t:Tension)
+         WHERE datetime(t.last_confirmed_active) >= datetime() - duration('P30D')
+         OPTIONAL MATCH (resp)-[r:RESPONDS_TO]->(t)
+         WITH t, collect(CASE WHEN resp IS NULL THEN null ELSE {
+                strength: coalesce(r.match_strength, 0.5),
+                capacity: CASE
+                    WHEN resp:Aid AND coalesce(resp.is_ongoing, false) THEN 1.0
+                    WHEN resp:Aid THEN 0.6
+                    WHEN resp:Gathering AND coalesce(resp.is_recurring, false) THEN 0.7
+                    WHEN resp:Gathering AND resp.starts_at IS NOT NULL
+                         AND datetime(resp.starts_at) < datetime() - duration('P1D') THEN 0.0
+                    WHEN resp:Gathering THEN 0.4
+                    ELSE 0.5
+                END
+              } END) AS responses
+         RETURN t.id AS id, t.title AS title, t.severity AS severity,
+                t.what_would_help AS what_would_help,
+                toString(t.unmet_since) AS unmet_since,
+                t.coverage_escalated_at IS NOT NULL AS escalated,
+                responses",
+    );
+
+    let mut rows = Vec::new();
+    let mut stream = client.inner().execute(q).await?;
+    while let Some(row) = stream.next().await? {
+        rows.push(row);
+    }
+
+    for row in rows {
+        let id_str: String = row.get("id").unwrap_or_default();
+        let Ok(id) = Uuid::parse_str(&id_str) else {
+            continue;
+        };
+        let severity: String = row.get("severity").unwrap_or_default();
+        let responses: Vec<neo4rs::BoltMap> = row.get("responses").unwrap_or_default();
+        let weights: Vec<(f64, f64)> = responses
+            .iter()
+            .map(|m| {
+                (
+                    m.get::<f64>("strength").unwrap_or(0.5),
+                    m.get::<f64>("capacity").unwrap_or(0.5),
+                )
+            })
+            .collect();
+
+        let coverage = compute_coverage(&severity, &weights);
+        let unmet_since = row
+            .get::<String>("unmet_since")
+            .ok()
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|t| t.with_timezone(&Utc));
+        let escalated: bool = row.get("escalated").unwrap_or(false);
+        let action = decide(&severity, coverage, unmet_since, escalated, now);
+
+        if let Err(e) = record_coverage(client, id, coverage, weights.len(), &severity, action, today).await {
+            warn!(tension_id = %id, error = %e, "Failed to record tension coverage");
+            continue;
+        }
+        stats.tensions_scored += 1;
+        if coverage < UNMET_COVERAGE {
+            stats.tensions_unmet += 1;
+        }
+
+        match action {
+            CoverageAction::Escalate => {
+                stats.tensions_escalated += 1;
+                let title: String = row.get("title").unwrap_or_default();
+                let unmet_days = unmet_since.map(|t| (now - t).num_days()).unwrap_or(0);
+                info!(
+                    tension_id = %id,
+                    title = title.as_str(),
+                    severity = severity.as_str(),
+                    coverage = format!("{coverage:.2}").as_str(),
+                    unmet_days,
+                    "Unmet tension escalated"
+                );
+                escalations.push(CoverageEscalation {
+                    tension_id: id,
+                    title,
+                    severity,
+                    coverage,
+                    unmet_days,
+                    what_would_help: row
+                        .get::<String>("what_would_help")
+                        .ok()
+                        .filter(|h| !h.is_empty()),
+                });
+            }
+            CoverageAction::DeEscalate => {
+                stats.tensions_deescalated += 1;
+                info!(tension_id = %id, coverage = format!("{coverage:.2}").as_str(), "Tension coverage recovered, escalation cleared");
+            }
+            CoverageAction::None => {}
+        }
+    }
+
+    if stats.tensions_scored > 0 {
+        info!(
+            scored = stats.tensions_scored,
+            unmet = stats.tensions_unmet,
+            escalated = stats.tensions_escalated,
+            deescalated = stats.tensions_deescalated,
+            "Tension coverage scored"
+        );
+    }
+
+    Ok((stats, escalations))
+}
+
+/// Write the score to the tension, track when it became unmet, apply the
+/// escalation decision, and upsert today's snapshot.
+async fn record_coverage(
+    client: &GraphClient,
+    id: Uuid,
+    coverage: f64,
+    response_count: usize,
+    severity: &str,
+    action: CoverageAction,
+    day: NaiveDate,
+) -> Result<(), neo4rs::Error> {
+    let q = query(
+        "MATCH (t:Tension {id: $id})
+         SET t.coverage_score = $coverage,
+             t.coverage_response_count = $response_count,
+             t.coverage_updated_at = datetime(),
+             t.unmet_since = CASE
+                 WHEN $coverage >= $unmet_coverage THEN null
+                 ELSE coalesce(t.unmet_since, datetime())
+             END,
+             t.coverage_escalated_at = CASE $action
+                 WHEN 'escalate' THEN datetime()
+                 WHEN 'deescalate' THEN null
+                 ELSE t.coverage_escalated_at
+             END
+         MERGE (c:CoverageSnapshot {tension_id: $id, day: $day})
+         SET c.coverage = $coverage,
+             c.response_count = $response_count,
+             c.severity = $severity,
+             c.escalated = t.coverage_escalated_at IS NOT NULL",
+    )
+    .param("id", id.to_string())
+    .param("coverage", coverage)
+    .param("unmet_coverage", UNMET_COVERAGE)
+    .param("response_count", response_count as i64)
+    .param("severity", severity)
+    .param("action", action.as_str())
+    .param("day", day.to_string());
+
+    client.inner().run(q).await
+}
+
+/// Strong responses a tension needs to count as fully covered.
+fn responses_needed(severity: &str) -> f64 {
+    match severity {
+        "critical" => 4.0,
+        "high" => 3.0,
+        "medium" => 2.0,
+        _ => 1.0,
+    }
+}
+
+/// Capacity-weighted match strength of the responses against what the
+/// severity calls for. Each response is `(match_strength, capacity)`.
+/// Returns 0.0 (nothing) to 1.0 (fully covered).
+fn compute_coverage(severity: &str, responses: &[(f64, f64)]) -> f64 {
+    let supplied: f64 = responses
+        .iter()
+        .map(|(strength, capacity)| strength.clamp(0.0, 1.0) * capacity.clamp(0.0, 1.0))
+        .sum();
+    (supplied / responses_needed(severity)).min(1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CoverageAction {
+    Escalate,
+    DeEscalate,
+    None,
+}
+
+impl CoverageAction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Escalate => "escalate",
+            Self::DeEscalate => "deescalate",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Escalate a high or critical tension once it has been unmet for
+/// `ESCALATE_AFTER_DAYS`; clear the escalation as soon as coverage recovers.
+fn decide(
+    severity: &str,
+    coverage: f64,
+    unmet_since: Option<DateTime<Utc>>,
+    escalated: bool,
+    now: DateTime<Utc>,
+) -> CoverageAction {
+    let unmet = coverage < UNMET_COVERAGE;
+    if escalated {
+        return if unmet { CoverageAction::None } else { CoverageAction::DeEscalate };
+    }
+    let serious = matches!(severity, "high" | "critical");
+    let persistent = unmet_since.is_some_and(|since| (now - since).num_days() >= ESCALATE_AFTER_DAYS);
+    if unmet && serious && persistent {
+        CoverageAction::Escalate
+    } else {
+        CoverageAction::None
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CoverageStats {
+    pub tensions_scored: u64,
+    pub tensions_unmet: u64,
+    pub tensions_escalated: u64,
+    pub tensions_deescalated: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn no_responses_is_zero_coverage() {
+        assert_eq!(compute_coverage("high", &[]), 0.0);
+    }
+
+    #[test]
+    fn one_strong_ongoing_aid_covers_a_low_tension_but_not_a_critical_one() {
+        let responses = [(0.9, 1.0)];
+
+        let low = compute_coverage("low", &responses);
+        let critical = compute_coverage("critical", &responses);
+
+        assert!((low - 0.9).abs() < 1e-9, "got {low}");
+        assert!(critical < 0.25, "got {critical}");
+    }
+
+    #[test]
+    fn coverage_is_capped_at_one() {
+        let responses = vec![(1.0, 1.0); 10];
+
+        assert_eq!(compute_coverage("medium", &responses), 1.0);
+    }
+
+    #[test]
+    fn past_one_off_gatherings_add_nothing() {
+        let responses = [(1.0, 0.0), (1.0, 0.0)];
+
+        assert_eq!(compute_coverage("low", &responses), 0.0);
+    }
+
+    #[test]
+    fn high_severity_unmet_for_a_week_escalates() {
+        let now = Utc::now();
+        let since = Some(now - Duration::days(ESCALATE_AFTER_DAYS));
+
+        assert_eq!(decide("high", 0.1, since, false, now), CoverageAction::Escalate);
+    }
+
+    #[test]
+    fn recently_unmet_or_low_severity_tensions_wait() {
+        let now = Utc::now();
+        let recent = Some(now - Duration::days(2));
+        let old = Some(now - Duration::days(30));
+
+        assert_eq!(decide("critical", 0.0, recent, false, now), CoverageAction::None);
+        assert_eq!(decide("medium", 0.0, old, false, now), CoverageAction::None);
+    }
+
+    #[test]
+    fn escalation_clears_once_coverage_recovers() {
+        let now = Utc::now();
+        let old = Some(now - Duration::days(30));
+
+        assert_eq!(decide("high", 0.1, old, true, now), CoverageAction::None);
+        assert_eq!(decide("high", 0.5, None, true, now), CoverageAction::DeEscalate);
+    }
+}
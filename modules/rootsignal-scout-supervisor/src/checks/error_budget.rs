@@ -0,0 +1,20 @@
+use anyhow::Result;
+use chrono::Utc;
+use sqlx::PgPool;
+
+use rootsignal_common::error_budget::{self, BurnedBudget, ErrorBudgetPolicy};
+
+/// Error budgets the region burned over the policy's window and that
+/// haven't alerted within it, worst first. Each burned budget alerts once
+/// per window, so a lasting failure mode isn't re-sent every run.
+pub async fn budgets_to_alert(pool: &PgPool, region: &str, policy: &ErrorBudgetPolicy) -> Result<Vec<BurnedBudget>> {
+    let since = Utc::now() - policy.window;
+    let tallies = error_budget::window_tallies(pool, region, since).await?;
+    let last_alerts = error_budget::last_alerts(pool, region).await?;
+
+    Ok(policy
+        .burned(&tallies)
+        .into_iter()
+        .filter(|burned| last_alerts.get(&burned.tally.operation).is_none_or(|&at| at < since))
+        .collect())
+}
@@ -1,5 +1,7 @@
 pub mod auto_fix;
 pub mod batch_review;
+pub mod coverage;
 pub mod echo;
+pub mod error_budget;
 pub mod report;
 pub mod triage;
@@ -2,8 +2,9 @@ use anyhow::Result;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
+use rootsignal_common::error_budget::ErrorBudgetPolicy;
 use rootsignal_common::{Config, ScoutScope};
-use rootsignal_graph::{migrate::migrate, GraphClient, GraphWriter};
+use rootsignal_graph::{GraphClient, GraphRouter};
 use rootsignal_scout_supervisor::{
     notify::{backend::NotifyBackend, noop::NoopBackend, router::NotifyRouter},
     supervisor::Supervisor,
@@ -26,12 +27,14 @@ async fn main() -> Result<()> {
     let client = GraphClient::connect(
         &config.neo4j_uri,
         &config.neo4j_user,
-        &config.neo4j_password,
+        &config.neo4j_password.expose(),
     )
     .await?;
 
-    // Run migrations (idempotent)
-    migrate(&client).await?;
+    // Run migrations (idempotent) on every database, then use the region's
+    let router = GraphRouter::from_config(client.clone(), &config);
+    router.migrate_all().await?;
+    let region_client = router.for_region(&config.region).await?;
 
     // Build ScoutScope from config
     let region = ScoutScope {
@@ -39,6 +42,7 @@ async fn main() -> Result<()> {
         center_lng: config.region_lng.unwrap_or(-93.2650),
         radius_km: config.region_radius_km.unwrap_or(30.0),
         name: config.region_name.clone().unwrap_or_else(|| config.region.clone()),
+        boundary: None,
     };
 
     info!(
@@ -59,7 +63,17 @@ async fn main() -> Result<()> {
     };
 
     // Create and run supervisor
-    let supervisor = Supervisor::new(client, region, config.anthropic_api_key.clone(), notifier);
+    let mut supervisor = Supervisor::new(region_client, region, config.anthropic_api_key.expose(), notifier)
+        .with_schedule_graph(client);
+
+    // Error budgets need the scout's failure tallies in Postgres
+    match std::env::var("DATABASE_URL") {
+        Ok(url) => {
+            let pool = sqlx::PgPool::connect(&url).await?;
+            supervisor = supervisor.with_error_budgets(pool, ErrorBudgetPolicy::from_env());
+        }
+        Err(_) => info!("No DATABASE_URL set, error budgets disabled"),
+    }
     let stats = supervisor.run().await?;
 
     info!("Supervisor complete. {stats}");
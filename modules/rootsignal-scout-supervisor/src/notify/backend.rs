@@ -1,6 +1,7 @@
 use async_trait::async_trait;
+use rootsignal_common::error_budget::BurnedBudget;
 
-use crate::types::{SupervisorStats, ValidationIssue};
+use crate::types::{CoverageEscalation, SupervisorStats, ValidationIssue};
 
 /// Pluggable notification backend for the supervisor.
 #[async_trait]
@@ -8,6 +9,12 @@ pub trait NotifyBackend: Send + Sync {
     /// Send a single validation issue notification.
     async fn send(&self, issue: &ValidationIssue) -> anyhow::Result<()>;
 
+    /// Send an alert that a high-severity tension has gone unmet.
+    async fn send_escalation(&self, escalation: &CoverageEscalation) -> anyhow::Result<()>;
+
+    /// Send an alert that an operation burned its error budget, with sample failures.
+    async fn send_budget_alert(&self, region: &str, burned: &BurnedBudget) -> anyhow::Result<()>;
+
     /// Send a digest summary of a supervisor run.
     async fn send_digest(&self, stats: &SupervisorStats) -> anyhow::Result<()>;
 }
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use rootsignal_common::error_budget::BurnedBudget;
 
 use super::backend::NotifyBackend;
-use crate::types::{SupervisorStats, ValidationIssue};
+use crate::types::{CoverageEscalation, SupervisorStats, ValidationIssue};
 
 /// No-op notification backend for testing.
 pub struct NoopBackend;
@@ -12,6 +13,14 @@ impl NotifyBackend for NoopBackend {
         Ok(())
     }
 
+    async fn send_escalation(&self, _escalation: &CoverageEscalation) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn send_budget_alert(&self, _region: &str, _burned: &BurnedBudget) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     async fn send_digest(&self, _stats: &SupervisorStats) -> anyhow::Result<()> {
         Ok(())
     }
@@ -1,9 +1,10 @@
 use async_trait::async_trait;
+use rootsignal_common::error_budget::BurnedBudget;
 use tracing::warn;
 
 use super::backend::NotifyBackend;
 use super::slack::SlackWebhook;
-use crate::types::{SupervisorStats, ValidationIssue};
+use crate::types::{CoverageEscalation, SupervisorStats, ValidationIssue};
 
 /// Routes notifications to different backends based on configuration.
 /// Supports separate Slack channels for auto-fix digests vs flagged issues.
@@ -45,6 +46,20 @@ impl NotifyBackend for NotifyRouter {
         Ok(())
     }
 
+    async fn send_escalation(&self, escalation: &CoverageEscalation) -> anyhow::Result<()> {
+        if let Err(e) = self.flags_backend.send_escalation(escalation).await {
+            warn!(error = %e, tension_id = %escalation.tension_id, "Failed to send escalation notification");
+        }
+        Ok(())
+    }
+
+    async fn send_budget_alert(&self, region: &str, burned: &BurnedBudget) -> anyhow::Result<()> {
+        if let Err(e) = self.flags_backend.send_budget_alert(region, burned).await {
+            warn!(error = %e, operation = burned.tally.operation.as_str(), "Failed to send error budget alert");
+        }
+        Ok(())
+    }
+
     async fn send_digest(&self, stats: &SupervisorStats) -> anyhow::Result<()> {
         if let Err(e) = self.digest_backend.send_digest(stats).await {
             warn!(error = %e, "Failed to send digest notification");
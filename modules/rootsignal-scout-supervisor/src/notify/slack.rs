@@ -1,9 +1,10 @@
 use async_trait::async_trait;
+use rootsignal_common::error_budget::BurnedBudget;
 use serde_json::json;
 use tracing::warn;
 
 use super::backend::NotifyBackend;
-use crate::types::{Severity, SupervisorStats, ValidationIssue};
+use crate::types::{CoverageEscalation, Severity, SupervisorStats, ValidationIssue};
 
 /// Slack incoming webhook notification backend.
 pub struct SlackWebhook {
@@ -74,6 +75,60 @@ impl NotifyBackend for SlackWebhook {
         self.post(payload).await
     }
 
+    async fn send_escalation(&self, escalation: &CoverageEscalation) -> anyhow::Result<()> {
+        let mut text = format!(
+            ":rotating_light: *Unmet tension escalated*\n\
+             *Tension:* {} `{}`\n\
+             *Severity:* {}\n\
+             *Coverage:* {:.0}% for {} days",
+            escalation.title,
+            escalation.tension_id,
+            escalation.severity,
+            escalation.coverage * 100.0,
+            escalation.unmet_days,
+        );
+        if let Some(help) = &escalation.what_would_help {
+            text.push_str(&format!("\n*What would help:* {help}"));
+        }
+        text.push_str("\n\nThe response finder will prioritize it until coverage recovers.");
+
+        let payload = json!({
+            "text": text,
+            "unfurl_links": false,
+        });
+
+        self.post(payload).await
+    }
+
+    async fn send_budget_alert(&self, region: &str, burned: &BurnedBudget) -> anyhow::Result<()> {
+        let tally = &burned.tally;
+        let mut text = format!(
+            ":fire: *Error budget burned: {}*\n\
+             *Region:* {}\n\
+             *Failures:* {} of {} ({:.0}%) in the last {}h, budget {:.0}%",
+            tally.operation,
+            region,
+            tally.failures,
+            tally.attempts,
+            tally.failure_rate() * 100.0,
+            burned.window.num_hours(),
+            burned.max_failure_rate * 100.0,
+        );
+        if !tally.samples.is_empty() {
+            text.push_str("\n*Recent failures:*");
+            for sample in &tally.samples {
+                text.push_str(&format!("\n  - `{}` {}", sample.reason, sample.error));
+            }
+        }
+
+        let payload = json!({
+            "text": text,
+            "unfurl_links": false,
+        });
+
+        self.post(payload).await
+    }
+
     async fn send_digest(&self, stats: &SupervisorStats) -> anyhow::Result<()> {
         let auto = &stats.auto_fix;
         let has_fixes = auto.orphaned_evidence_deleted > 0
@@ -82,7 +137,7 @@ impl NotifyBackend for SlackWebhook {
             || auto.empty_signals_deleted > 0
             || auto.fake_coords_nulled > 0;
 
-        if !has_fixes && stats.issues_created == 0 {
+        if !has_fixes && stats.issues_created == 0 && stats.tensions_escalated == 0 && stats.budgets_burned == 0 {
             // Nothing to report
             return Ok(());
         }
@@ -127,6 +182,14 @@ impl NotifyBackend for SlackWebhook {
             lines.push(format!("*New issues flagged:* {}", stats.issues_created));
         }
 
+        if stats.tensions_escalated > 0 {
+            lines.push(format!("*Unmet tensions escalated:* {}", stats.tensions_escalated));
+        }
+
+        if stats.budgets_burned > 0 {
+            lines.push(format!("*Error budgets burned:* {}", stats.budgets_burned));
+        }
+
         lines.push(format!(
             "_Reviewed {} signals (passed={}, rejected={})_",
             stats.signals_reviewed, stats.signals_passed, stats.signals_rejected
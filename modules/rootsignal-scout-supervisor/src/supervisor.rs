@@ -1,10 +1,14 @@
 use anyhow::Result;
 use tracing::{info, warn};
 
-use rootsignal_common::ScoutScope;
-use rootsignal_graph::GraphClient;
+use chrono::Utc;
+use rootsignal_common::error_budget::{self, ErrorBudgetPolicy};
+use rootsignal_common::log_events::ErrorBudgetBurned;
+use rootsignal_common::{slugify, ScoutScope};
+use sqlx::PgPool;
+use rootsignal_graph::{GraphClient, GraphWriter};
 
-use crate::checks::{auto_fix, batch_review, echo, report, triage};
+use crate::checks::{auto_fix, batch_review, coverage, echo, error_budget as budget_check, report, triage};
 use crate::feedback::source_penalty;
 use crate::issues::IssueStore;
 use crate::notify::backend::NotifyBackend;
@@ -14,11 +18,17 @@ use crate::types::SupervisorStats;
 /// The scout supervisor: validates the graph and feeds back into scout behavior.
 pub struct Supervisor {
     client: GraphClient,
+    /// Where run schedules live; the default database when regions are routed.
+    schedules: GraphClient,
     state: SupervisorState,
     issues: IssueStore,
     region: ScoutScope,
     anthropic_api_key: String,
     notifier: Box<dyn NotifyBackend>,
+    /// Where scout runs record their failure tallies; budgets aren't
+    /// checked without it.
+    pg_pool: Option<PgPool>,
+    error_budgets: ErrorBudgetPolicy,
 }
 
 impl Supervisor {
@@ -31,17 +41,59 @@ impl Supervisor {
         let state = SupervisorState::new(client.clone(), region.name.clone());
         let issues = IssueStore::new(client.clone());
         Self {
+            schedules: client.clone(),
             client,
             state,
             issues,
             region,
             anthropic_api_key,
             notifier,
+            pg_pool: None,
+            error_budgets: ErrorBudgetPolicy::default(),
         }
     }
 
+    /// Read the region's run schedule from `client` instead of the region graph.
+    pub fn with_schedule_graph(mut self, client: GraphClient) -> Self {
+        self.schedules = client;
+        self
+    }
+
+    /// Check the scout's failure tallies in `pool` against `policy`.
+    pub fn with_error_budgets(mut self, pool: PgPool, policy: ErrorBudgetPolicy) -> Self {
+        self.pg_pool = Some(pool);
+        self.error_budgets = policy;
+        self
+    }
+
     /// Run the supervisor. Acquires lock, runs checks, releases lock.
     pub async fn run(&self) -> Result<SupervisorStats> {
+        // Respect the region calendar's quiet hours and blackouts
+        let writer = GraphWriter::new(self.client.clone());
+        match writer.get_region_calendar(&slugify(&self.region.name)).await {
+            Ok(Some(calendar)) => {
+                if let Some(block) = calendar.blocked_at(Utc::now()) {
+                    info!(%block, region = self.region.name.as_str(), "Region calendar blocks supervisor, skipping");
+                    return Ok(SupervisorStats::default());
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!(error = %e, "Failed to load region calendar, running anyway"),
+        }
+
+        // A paused region schedule pauses the supervisor too
+        match GraphWriter::new(self.schedules.clone())
+            .get_region_schedule(&slugify(&self.region.name))
+            .await
+        {
+            Ok(Some(schedule)) if schedule.paused => {
+                info!(region = self.region.name.as_str(), "Region schedule paused, skipping supervisor");
+                return Ok(SupervisorStats::default());
+            }
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "Failed to load region schedule, running anyway"),
+        }
+
         // Acquire lock
         let acquired = self.state.acquire_lock().await?;
         if !acquired {
@@ -170,6 +222,49 @@ impl Supervisor {
             Err(e) => warn!(error = %e, "Failed to run echo detection"),
         }
 
+        // Phase 6: Tension coverage — score responses, escalate persistently unmet tensions
+        match coverage::score_coverage(&self.client).await {
+            Ok((coverage_stats, escalations)) => {
+                stats.tensions_scored = coverage_stats.tensions_scored;
+                stats.tensions_escalated = coverage_stats.tensions_escalated;
+                stats.tensions_deescalated = coverage_stats.tensions_deescalated;
+                for escalation in &escalations {
+                    if let Err(e) = self.notifier.send_escalation(escalation).await {
+                        warn!(error = %e, tension_id = %escalation.tension_id, "Failed to send escalation notification");
+                    }
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to score tension coverage"),
+        }
+
+        // Phase 7: Error budgets — alert on operations failing more often than allowed
+        if let Some(pool) = &self.pg_pool {
+            match budget_check::budgets_to_alert(pool, &self.region.name, &self.error_budgets).await {
+                Ok(burned) => {
+                    for budget in &burned {
+                        ErrorBudgetBurned {
+                            region: self.region.name.clone(),
+                            operation: budget.tally.operation.clone(),
+                            attempts: budget.tally.attempts,
+                            failures: budget.tally.failures,
+                            failure_rate: budget.tally.failure_rate(),
+                            max_failure_rate: budget.max_failure_rate,
+                        }
+                        .emit();
+                        if let Err(e) = self.notifier.send_budget_alert(&self.region.name, budget).await {
+                            warn!(error = %e, operation = budget.tally.operation.as_str(), "Failed to send error budget alert");
+                            continue;
+                        }
+                        if let Err(e) = error_budget::record_alert(pool, &self.region.name, &budget.tally.operation).await {
+                            warn!(error = %e, "Failed to record error budget alert");
+                        }
+                    }
+                    stats.budgets_burned = burned.len() as u64;
+                }
+                Err(e) => warn!(error = %e, "Failed to check error budgets"),
+            }
+        }
+
         // Send digest notification
         if let Err(e) = self.notifier.send_digest(&stats).await {
             warn!(error = %e, "Failed to send digest notification");
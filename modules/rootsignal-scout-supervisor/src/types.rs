@@ -116,6 +116,18 @@ impl fmt::Display for IssueStatus {
     }
 }
 
+/// A high-severity tension that has gone unmet long enough to escalate.
+#[derive(Debug, Clone)]
+pub struct CoverageEscalation {
+    pub tension_id: Uuid,
+    pub title: String,
+    pub severity: String,
+    /// 0.0 (no responses) to 1.0 (fully covered).
+    pub coverage: f64,
+    pub unmet_days: i64,
+    pub what_would_help: Option<String>,
+}
+
 /// Stats from a supervisor run.
 #[derive(Debug, Default)]
 pub struct SupervisorStats {
@@ -128,16 +140,22 @@ pub struct SupervisorStats {
     pub sources_penalized: u64,
     pub sources_reset: u64,
     pub echoes_flagged: u64,
+    pub tensions_scored: u64,
+    pub tensions_escalated: u64,
+    pub tensions_deescalated: u64,
+    pub budgets_burned: u64,
 }
 
 impl fmt::Display for SupervisorStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "signals_reviewed={} passed={} rejected={} issues_created={} github_issue={} sources_penalized={} sources_reset={} echoes_flagged={} {}",
+            "signals_reviewed={} passed={} rejected={} issues_created={} github_issue={} sources_penalized={} sources_reset={} echoes_flagged={} tensions_scored={} tensions_escalated={} tensions_deescalated={} budgets_burned={} {}",
             self.signals_reviewed, self.signals_passed, self.signals_rejected,
             self.issues_created, self.github_issue_created,
-            self.sources_penalized, self.sources_reset, self.echoes_flagged, self.auto_fix,
+            self.sources_penalized, self.sources_reset, self.echoes_flagged,
+            self.tensions_scored, self.tensions_escalated, self.tensions_deescalated,
+            self.budgets_burned, self.auto_fix,
         )
     }
 }
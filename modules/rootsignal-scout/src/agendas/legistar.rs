@@ -0,0 +1,295 @@
+//! Legistar Web API client (`webapi.legistar.com/v1/{client}`).
+//!
+//! Meetings ("events") are listed by date; each has its agenda items
+//! ("event items") behind a second call. Only items tied to legislation (a
+//! "matter") are kept — roll call, approval of minutes and the like have none.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use rootsignal_common::{AgendaBody, AgendaItem, Meeting};
+
+use super::AgendaConnector;
+
+const API_BASE: &str = "https://webapi.legistar.com/v1";
+
+/// Meetings read per body per run.
+const MAX_MEETINGS_PER_BODY: usize = 10;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct LegistarEvent {
+    event_id: i64,
+    #[serde(default)]
+    event_body_name: Option<String>,
+    event_date: String,
+    #[serde(default)]
+    event_time: Option<String>,
+    #[serde(default)]
+    event_location: Option<String>,
+    #[serde(default)]
+    event_agenda_status_name: Option<String>,
+    #[serde(default)]
+    event_agenda_file: Option<String>,
+    #[serde(default)]
+    event_minutes_status_name: Option<String>,
+    #[serde(default, rename = "EventInSiteURL")]
+    event_in_site_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct LegistarEventItem {
+    event_item_id: i64,
+    #[serde(default)]
+    event_item_agenda_number: Option<String>,
+    #[serde(default)]
+    event_item_title: Option<String>,
+    #[serde(default)]
+    event_item_matter_id: Option<i64>,
+    #[serde(default)]
+    event_item_matter_name: Option<String>,
+    #[serde(default)]
+    event_item_matter_type: Option<String>,
+    #[serde(default)]
+    event_item_action_name: Option<String>,
+    #[serde(default)]
+    event_item_agenda_note: Option<String>,
+    #[serde(default)]
+    event_item_minutes_note: Option<String>,
+}
+
+pub struct LegistarClient {
+    client: reqwest::Client,
+}
+
+impl LegistarClient {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("Failed to build Legistar HTTP client"),
+        }
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, url: &str, params: &[(&str, String)]) -> Result<T> {
+        self.client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .context("Legistar request failed")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Failed to parse Legistar response")
+    }
+}
+
+impl Default for LegistarClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AgendaConnector for LegistarClient {
+    async fn meetings(&self, body: &AgendaBody, now: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        let from = now - chrono::Duration::days(body.lookback_days as i64);
+        let to = now + chrono::Duration::days(body.lookahead_days as i64);
+        let events: Vec<LegistarEvent> = self
+            .get(
+                &format!("{API_BASE}/{}/events", body.legistar_client),
+                &[
+                    ("$filter", events_filter(body, from, to)),
+                    ("$orderby", "EventDate".to_string()),
+                ],
+            )
+            .await?;
+
+        let tz = body.tz();
+        let mut meetings = Vec::new();
+        for event in events {
+            let Some(mut meeting) = to_meeting(&event, tz, now) else {
+                continue;
+            };
+            let items: Vec<LegistarEventItem> = match self
+                .get(
+                    &format!("{API_BASE}/{}/events/{}/eventitems", body.legistar_client, event.event_id),
+                    &[("AgendaNote", "1".to_string()), ("MinutesNote", "1".to_string())],
+                )
+                .await
+            {
+                Ok(items) => items,
+                Err(e) => {
+                    warn!(meeting = meeting.id.as_str(), error = %e, "Legistar agenda items fetch failed");
+                    continue;
+                }
+            };
+            meeting.items = items.iter().filter_map(to_agenda_item).collect();
+            meetings.push(meeting);
+            if meetings.len() >= MAX_MEETINGS_PER_BODY {
+                break;
+            }
+        }
+        info!(body = body.name.as_str(), meetings = meetings.len(), "legistar: fetched meetings");
+        Ok(meetings)
+    }
+}
+
+/// OData filter for the body's meetings between `from` and `to`.
+pub(crate) fn events_filter(body: &AgendaBody, from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let mut filter = format!(
+        "EventDate ge datetime'{}' and EventDate le datetime'{}'",
+        from.format("%Y-%m-%d"),
+        to.format("%Y-%m-%d")
+    );
+    if let Some(name) = &body.body_name {
+        // OData escapes a quote by doubling it.
+        filter.push_str(&format!(" and EventBodyName eq '{}'", name.replace('\'', "''")));
+    }
+    filter
+}
+
+/// A meeting worth reading: upcoming with a published agenda, or past with
+/// final minutes. Drafts change; they're read once they're final.
+pub(crate) fn to_meeting(event: &LegistarEvent, tz: Tz, now: DateTime<Utc>) -> Option<Meeting> {
+    let starts_at = meeting_start(&event.event_date, event.event_time.as_deref(), tz)?;
+    let is_final = |status: &Option<String>| status.as_deref().is_some_and(|s| s.eq_ignore_ascii_case("final"));
+    let minutes_published = is_final(&event.event_minutes_status_name);
+    let readable = if starts_at >= now {
+        is_final(&event.event_agenda_status_name) || event.event_agenda_file.is_some()
+    } else {
+        minutes_published
+    };
+    if !readable {
+        return None;
+    }
+
+    let url = event
+        .event_in_site_url
+        .clone()
+        .or_else(|| event.event_agenda_file.clone())?;
+    Some(Meeting {
+        id: event.event_id.to_string(),
+        body_name: event.event_body_name.clone().unwrap_or_default(),
+        starts_at,
+        location: event.event_location.clone().filter(|l| !l.trim().is_empty()),
+        url,
+        minutes_published,
+        items: Vec::new(),
+    })
+}
+
+/// Legistar publishes the date as midnight and the time separately, both local.
+fn meeting_start(date: &str, time: Option<&str>, tz: Tz) -> Option<DateTime<Utc>> {
+    let date = NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S")
+        .map(|dt| dt.date())
+        .or_else(|_| NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+        .ok()?;
+    let time = time
+        .and_then(|t| NaiveTime::parse_from_str(t.trim(), "%I:%M %p").ok())
+        .unwrap_or(NaiveTime::MIN);
+    tz.from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Substantive items only: those tied to a matter, with a title.
+pub(crate) fn to_agenda_item(item: &LegistarEventItem) -> Option<AgendaItem> {
+    item.event_item_matter_id?;
+    let clean = |s: &Option<String>| s.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string);
+    let title = clean(&item.event_item_matter_name).or_else(|| clean(&item.event_item_title))?;
+    Some(AgendaItem {
+        id: item.event_item_id.to_string(),
+        number: clean(&item.event_item_agenda_number),
+        title,
+        matter_type: clean(&item.event_item_matter_type),
+        action: clean(&item.event_item_action_name),
+        note: clean(&item.event_item_minutes_note).or_else(|| clean(&item.event_item_agenda_note)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn council() -> AgendaBody {
+        AgendaBody {
+            name: "Minneapolis City Council".to_string(),
+            legistar_client: "minneapolismn".to_string(),
+            body_name: Some("Committee of the Whole".to_string()),
+            timezone: "America/Chicago".to_string(),
+            location_name: "Minneapolis City Hall".to_string(),
+            latitude: 44.9773,
+            longitude: -93.2655,
+            lookahead_days: 14,
+            lookback_days: 14,
+        }
+    }
+
+    fn event(json: &str) -> LegistarEvent {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn filter_limits_dates_and_body() {
+        let from = Utc.with_ymd_and_hms(2026, 10, 3, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2026, 10, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            events_filter(&council(), from, to),
+            "EventDate ge datetime'2026-10-03' and EventDate le datetime'2026-10-31' \
+             and EventBodyName eq 'Committee of the Whole'"
+        );
+    }
+
+    #[test]
+    fn upcoming_meeting_starts_at_its_local_time() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let e = event(
+            r#"{"EventId": 5120, "EventBodyName": "City Council", "EventDate": "2026-10-22T00:00:00",
+                "EventTime": "9:30 AM", "EventLocation": "Room 317, City Hall",
+                "EventAgendaStatusName": "Final", "EventMinutesStatusName": "Draft",
+                "EventInSiteURL": "https://minneapolismn.legistar.com/MeetingDetail.aspx?ID=5120"}"#,
+        );
+
+        let meeting = to_meeting(&e, council().tz(), now).unwrap();
+        assert_eq!(meeting.starts_at, Utc.with_ymd_and_hms(2026, 10, 22, 14, 30, 0).unwrap());
+        assert_eq!(meeting.location.as_deref(), Some("Room 317, City Hall"));
+        assert!(!meeting.minutes_published);
+    }
+
+    #[test]
+    fn past_meetings_wait_for_final_minutes() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let e = event(
+            r#"{"EventId": 5101, "EventDate": "2026-10-09T00:00:00", "EventTime": "9:30 AM",
+                "EventAgendaStatusName": "Final", "EventMinutesStatusName": "Draft",
+                "EventInSiteURL": "https://minneapolismn.legistar.com/MeetingDetail.aspx?ID=5101"}"#,
+        );
+        assert!(to_meeting(&e, council().tz(), now).is_none());
+    }
+
+    #[test]
+    fn procedural_items_are_skipped() {
+        let items: Vec<LegistarEventItem> = serde_json::from_str(
+            r#"[{"EventItemId": 1, "EventItemTitle": "Roll Call", "EventItemMatterId": null},
+                {"EventItemId": 2, "EventItemAgendaNumber": "4.", "EventItemTitle": "Ordinance amending Title 20 ...",
+                 "EventItemMatterId": 9981, "EventItemMatterName": "Rezoning: 2800 E Lake St",
+                 "EventItemMatterType": "Ordinance", "EventItemActionName": "Adopted"}]"#,
+        )
+        .unwrap();
+
+        let parsed: Vec<AgendaItem> = items.iter().filter_map(to_agenda_item).collect();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].title, "Rezoning: 2800 E Lake St");
+        assert_eq!(parsed[0].action.as_deref(), Some("Adopted"));
+    }
+}
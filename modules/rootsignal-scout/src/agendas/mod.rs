@@ -0,0 +1,165 @@
+//! Government meeting agendas — council and commission items as Notices.
+//!
+//! An agenda item is an official announcement of a decision to come: a
+//! rezoning, a budget amendment, a new ordinance. Each region lists the
+//! bodies it follows (`RegionAgendas`); a connector reads their upcoming
+//! agendas and final minutes, and every substantive item becomes a Notice
+//! located where the body meets. Items on a high-impact topic (budget,
+//! zoning) get high severity, which puts them first in line for the
+//! investigator and the tension linker.
+
+pub mod legistar;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use rootsignal_common::{
+    AgendaBody, AgendaItem, GeoPoint, GeoPrecision, Meeting, Node, NodeMeta, NoticeNode, SensitivityLevel, Severity,
+};
+
+/// Longest agenda item title kept; Legistar titles can run to a paragraph.
+const TITLE_CHARS: usize = 140;
+
+/// Reads meetings from an agenda portal.
+#[async_trait]
+pub trait AgendaConnector: Send + Sync {
+    /// The body's meetings within its lookback/lookahead window that have a
+    /// published agenda (upcoming) or final minutes (past), with their items.
+    async fn meetings(&self, body: &AgendaBody, now: DateTime<Utc>) -> Result<Vec<Meeting>>;
+}
+
+/// Turn an agenda item into a Notice. `high_impact_topic` is the topic that
+/// flagged it, if any.
+pub fn item_to_notice(
+    body: &AgendaBody,
+    meeting: &Meeting,
+    item: &AgendaItem,
+    high_impact_topic: Option<&str>,
+    now: DateTime<Utc>,
+) -> Node {
+    let title = match item.title.char_indices().nth(TITLE_CHARS) {
+        Some((end, _)) => format!("{}…", item.title[..end].trim_end()),
+        None => item.title.clone(),
+    };
+    let when = meeting.starts_at.with_timezone(&body.tz()).format("%b %-d, %Y %-I:%M %p");
+    let mut summary = match (&item.action, meeting.minutes_published) {
+        (Some(action), true) => format!("{} action on {when}: {action}.", body.name),
+        _ => format!("On the {} agenda for {when}.", body.name),
+    };
+    if let Some(matter_type) = &item.matter_type {
+        summary.push_str(&format!(" {matter_type}."));
+    }
+    if let Some(note) = &item.note {
+        summary.push(' ');
+        summary.push_str(note);
+    }
+
+    let meta = NodeMeta {
+        id: Uuid::new_v4(),
+        title,
+        summary,
+        sensitivity: SensitivityLevel::General,
+        confidence: 0.0,
+        freshness_score: 1.0,
+        corroboration_count: 0,
+        about_location: Some(GeoPoint {
+            lat: body.latitude,
+            lng: body.longitude,
+            precision: GeoPrecision::Exact,
+        }),
+        about_location_name: Some(meeting.location.clone().unwrap_or_else(|| body.location_name.clone())),
+        from_location: None,
+        source_url: meeting.url.clone(),
+        extracted_at: now,
+        // Minutes report what happened at the meeting; an agenda is about the future.
+        content_date: meeting.minutes_published.then_some(meeting.starts_at),
+        last_confirmed_active: now,
+        source_diversity: 1,
+        external_ratio: 0.0,
+        cause_heat: 0.0,
+        implied_queries: Vec::new(),
+        channel_diversity: 1,
+        mentioned_actors: Vec::new(),
+        author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
+    };
+
+    Node::Notice(NoticeNode {
+        meta,
+        severity: if high_impact_topic.is_some() { Severity::High } else { Severity::Low },
+        category: Some(high_impact_topic.unwrap_or("policy").to_string()),
+        effective_date: Some(meeting.starts_at),
+        source_authority: Some(body.name.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn council() -> AgendaBody {
+        AgendaBody {
+            name: "Minneapolis City Council".to_string(),
+            legistar_client: "minneapolismn".to_string(),
+            body_name: None,
+            timezone: "America/Chicago".to_string(),
+            location_name: "Minneapolis City Hall".to_string(),
+            latitude: 44.9773,
+            longitude: -93.2655,
+            lookahead_days: 14,
+            lookback_days: 14,
+        }
+    }
+
+    fn meeting(minutes_published: bool) -> Meeting {
+        Meeting {
+            id: "5120".to_string(),
+            body_name: "City Council".to_string(),
+            starts_at: Utc.with_ymd_and_hms(2026, 10, 22, 14, 30, 0).unwrap(),
+            location: None,
+            url: "https://minneapolismn.legistar.com/MeetingDetail.aspx?ID=5120".to_string(),
+            minutes_published,
+            items: Vec::new(),
+        }
+    }
+
+    fn rezoning(action: Option<&str>) -> AgendaItem {
+        AgendaItem {
+            id: "77".to_string(),
+            number: Some("4.".to_string()),
+            title: "Rezoning: 2800 E Lake St".to_string(),
+            matter_type: Some("Ordinance".to_string()),
+            action: action.map(str::to_string),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn agenda_item_is_a_notice_at_the_meeting_venue() {
+        let Node::Notice(n) = item_to_notice(&council(), &meeting(false), &rezoning(None), Some("zoning"), Utc::now())
+        else {
+            panic!("agenda items are notices");
+        };
+        assert_eq!(n.meta.summary, "On the Minneapolis City Council agenda for Oct 22, 2026 9:30 AM. Ordinance.");
+        assert_eq!(n.severity, Severity::High);
+        assert_eq!(n.category.as_deref(), Some("zoning"));
+        assert_eq!(n.meta.about_location_name.as_deref(), Some("Minneapolis City Hall"));
+        assert!(n.meta.content_date.is_none());
+    }
+
+    #[test]
+    fn minutes_report_the_action_taken() {
+        let Node::Notice(n) = item_to_notice(&council(), &meeting(true), &rezoning(Some("Adopted")), None, Utc::now())
+        else {
+            panic!("agenda items are notices");
+        };
+        assert!(n.meta.summary.starts_with("Minneapolis City Council action on Oct 22, 2026 9:30 AM: Adopted."));
+        assert_eq!(n.severity, Severity::Low);
+        assert_eq!(n.meta.content_date, Some(meeting(true).starts_at));
+    }
+}
@@ -1,11 +1,16 @@
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 
 use ai_client::tool::{Tool, ToolDefinition};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use rootsignal_archive::Archive;
+use rootsignal_graph::GraphWriter;
+
+use crate::infra::embedder::TextEmbedder;
 
 pub(crate) struct WebSearchTool {
     pub(crate) archive: Arc<Archive>,
@@ -150,3 +155,376 @@ impl Tool for ReadPageTool {
         }
     }
 }
+
+// --- Graph tools ---
+
+const MAX_SIMILAR_SIGNALS: u32 = 5;
+const MAX_ACTOR_SIGNALS: u32 = 10;
+
+/// Caps how many graph lookups the agent tools may make in one run.
+/// Clones share the same counter.
+#[derive(Clone)]
+pub(crate) struct GraphCallBudget {
+    used: Arc<AtomicU32>,
+    max: u32,
+}
+
+impl GraphCallBudget {
+    pub(crate) fn new(max: u32) -> Self {
+        Self {
+            used: Arc::new(AtomicU32::new(0)),
+            max,
+        }
+    }
+
+    /// Claim one call. Returns false once the budget is spent.
+    pub(crate) fn try_take(&self) -> bool {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                (n < self.max).then_some(n + 1)
+            })
+            .is_ok()
+    }
+
+    pub(crate) fn used(&self) -> u32 {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.used() >= self.max
+    }
+}
+
+/// Read-only view of the graph for agent tools. It exposes lookups only, so
+/// nothing the agent asks for can write to the graph, and every lookup is
+/// charged against the shared call budget.
+#[derive(Clone)]
+pub(crate) struct GraphLookup {
+    writer: GraphWriter,
+    embedder: Arc<dyn TextEmbedder>,
+    budget: GraphCallBudget,
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+}
+
+impl GraphLookup {
+    pub(crate) fn new(
+        writer: GraphWriter,
+        embedder: Arc<dyn TextEmbedder>,
+        budget: GraphCallBudget,
+        bounds: (f64, f64, f64, f64),
+    ) -> Self {
+        let (min_lat, max_lat, min_lng, max_lng) = bounds;
+        Self {
+            writer,
+            embedder,
+            budget,
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
+        }
+    }
+
+    fn charge(&self) -> Result<(), ToolError> {
+        if self.budget.try_take() {
+            Ok(())
+        } else {
+            Err(ToolError(
+                "Graph lookup budget for this run is exhausted; continue without it.".to_string(),
+            ))
+        }
+    }
+
+    async fn similar_signals(&self, text: &str) -> Result<Vec<rootsignal_graph::SimilarSignal>, ToolError> {
+        self.charge()?;
+        let embedding = self
+            .embedder
+            .embed(text)
+            .await
+            .map_err(|e| ToolError(format!("Embedding failed: {e}")))?;
+        self.writer
+            .find_similar_signals(
+                &embedding,
+                MAX_SIMILAR_SIGNALS + 1,
+                self.min_lat,
+                self.max_lat,
+                self.min_lng,
+                self.max_lng,
+            )
+            .await
+            .map_err(|e| ToolError(format!("Graph lookup failed: {e}")))
+    }
+
+    async fn actor_history(&self, name: &str) -> Result<Option<rootsignal_graph::ActorHistory>, ToolError> {
+        self.charge()?;
+        self.writer
+            .get_actor_history(name, MAX_ACTOR_SIGNALS)
+            .await
+            .map_err(|e| ToolError(format!("Graph lookup failed: {e}")))
+    }
+
+    async fn situation_context(
+        &self,
+        signal_id: Uuid,
+    ) -> Result<Vec<rootsignal_graph::SituationContext>, ToolError> {
+        self.charge()?;
+        self.writer
+            .get_situation_context(signal_id)
+            .await
+            .map_err(|e| ToolError(format!("Graph lookup failed: {e}")))
+    }
+}
+
+pub(crate) struct FindSimilarSignalsTool {
+    pub(crate) graph: GraphLookup,
+    /// The signal under investigation, left out of its own results.
+    pub(crate) exclude_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct FindSimilarSignalsArgs {
+    pub(crate) query: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SimilarSignalItem {
+    pub(crate) id: String,
+    pub(crate) signal_type: String,
+    pub(crate) title: String,
+    pub(crate) summary: String,
+    pub(crate) source_url: String,
+    pub(crate) confidence: f32,
+    pub(crate) evidence_count: u32,
+    pub(crate) extracted_at: String,
+    pub(crate) similarity: f64,
+}
+
+#[async_trait]
+impl Tool for FindSimilarSignalsTool {
+    const NAME: &'static str = "find_similar_signals";
+    type Error = ToolError;
+    type Args = FindSimilarSignalsArgs;
+    type Output = Vec<SimilarSignalItem>;
+
+    async fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Find signals already in the knowledge graph for this region that are \
+                semantically similar to a description. Returns titles, sources, confidence and \
+                how much evidence each already has."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "A short description of the signal to look for"
+                    }
+                },
+                "required": ["query"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> std::result::Result<Self::Output, Self::Error> {
+        let matches = self.graph.similar_signals(&args.query).await?;
+        Ok(matches
+            .into_iter()
+            .filter(|m| Some(m.id) != self.exclude_id)
+            .take(MAX_SIMILAR_SIGNALS as usize)
+            .map(|m| SimilarSignalItem {
+                id: m.id.to_string(),
+                signal_type: m.node_type.to_string(),
+                title: m.title,
+                summary: m.summary,
+                source_url: m.source_url,
+                confidence: m.confidence,
+                evidence_count: m.evidence_count,
+                extracted_at: m.extracted_at,
+                similarity: m.similarity,
+            })
+            .collect())
+    }
+}
+
+pub(crate) struct ActorHistoryTool {
+    pub(crate) graph: GraphLookup,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ActorHistoryArgs {
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ActorHistoryOutput {
+    pub(crate) found: bool,
+    pub(crate) name: String,
+    pub(crate) actor_type: String,
+    pub(crate) description: String,
+    pub(crate) signal_count: u32,
+    pub(crate) first_seen: String,
+    pub(crate) last_active: String,
+    pub(crate) recent_signals: Vec<ActorSignalItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ActorSignalItem {
+    pub(crate) signal_type: String,
+    pub(crate) title: String,
+    pub(crate) role: String,
+    pub(crate) extracted_at: String,
+}
+
+#[async_trait]
+impl Tool for ActorHistoryTool {
+    const NAME: &'static str = "get_actor_history";
+    type Error = ToolError;
+    type Args = ActorHistoryArgs;
+    type Output = ActorHistoryOutput;
+
+    async fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Look up an organization or person in the knowledge graph by name. \
+                Returns what is known about them and the signals they recently appeared in."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "The actor's name, e.g. an organization"
+                    }
+                },
+                "required": ["name"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> std::result::Result<Self::Output, Self::Error> {
+        let Some(actor) = self.graph.actor_history(&args.name).await? else {
+            return Ok(ActorHistoryOutput {
+                found: false,
+                name: args.name,
+                actor_type: String::new(),
+                description: String::new(),
+                signal_count: 0,
+                first_seen: String::new(),
+                last_active: String::new(),
+                recent_signals: Vec::new(),
+            });
+        };
+        Ok(ActorHistoryOutput {
+            found: true,
+            name: actor.name,
+            actor_type: actor.actor_type,
+            description: actor.description,
+            signal_count: actor.signal_count,
+            first_seen: actor.first_seen,
+            last_active: actor.last_active,
+            recent_signals: actor
+                .recent_signals
+                .into_iter()
+                .map(|s| ActorSignalItem {
+                    signal_type: s.signal_type,
+                    title: s.title,
+                    role: s.role,
+                    extracted_at: s.extracted_at,
+                })
+                .collect(),
+        })
+    }
+}
+
+pub(crate) struct SituationContextTool {
+    pub(crate) graph: GraphLookup,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SituationContextArgs {
+    pub(crate) signal_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SituationContextItem {
+    pub(crate) headline: String,
+    pub(crate) lede: String,
+    pub(crate) arc: String,
+    pub(crate) temperature: f64,
+    pub(crate) signal_count: u32,
+    pub(crate) related_signal_titles: Vec<String>,
+}
+
+#[async_trait]
+impl Tool for SituationContextTool {
+    const NAME: &'static str = "get_situation_context";
+    type Error = ToolError;
+    type Args = SituationContextArgs;
+    type Output = Vec<SituationContextItem>;
+
+    async fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: Self::NAME.to_string(),
+            description: "Get the situations a signal in the knowledge graph belongs to, with \
+                their arc, temperature and other signals in them."
+                .to_string(),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "signal_id": {
+                        "type": "string",
+                        "description": "The signal's id (a UUID)"
+                    }
+                },
+                "required": ["signal_id"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> std::result::Result<Self::Output, Self::Error> {
+        let signal_id = Uuid::parse_str(args.signal_id.trim())
+            .map_err(|_| ToolError(format!("Not a signal id: {}", args.signal_id)))?;
+        let situations = self.graph.situation_context(signal_id).await?;
+        Ok(situations
+            .into_iter()
+            .map(|s| SituationContextItem {
+                headline: s.headline,
+                lede: s.lede,
+                arc: s.arc,
+                temperature: s.temperature,
+                signal_count: s.signal_count,
+                related_signal_titles: s.sibling_titles,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_call_budget_refuses_calls_past_the_cap() {
+        let budget = GraphCallBudget::new(2);
+
+        let taken: Vec<bool> = (0..3).map(|_| budget.try_take()).collect();
+
+        assert_eq!(taken, vec![true, true, false]);
+        assert_eq!(budget.used(), 2);
+        assert!(budget.is_exhausted());
+    }
+
+    #[test]
+    fn graph_call_budget_is_shared_between_clones() {
+        let budget = GraphCallBudget::new(1);
+        let clone = budget.clone();
+
+        assert!(clone.try_take());
+
+        assert!(!budget.try_take());
+    }
+}
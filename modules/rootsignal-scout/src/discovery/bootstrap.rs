@@ -1,15 +1,24 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use rootsignal_common::{canonical_value, PinNode, ScoutScope, DiscoveryMethod, SourceNode, SourceRole};
+use rootsignal_common::bootstrap_review;
+use rootsignal_common::{
+    canonical_value, BootstrapCandidate, CandidateScore, PinNode, ReviewStatus, ScoutScope, DiscoveryMethod, SourceNode,
+    SourceRole,
+};
 use rootsignal_graph::GraphWriter;
 
-use rootsignal_archive::Archive;
+use rootsignal_archive::{link_graph, Archive};
 
+use crate::discovery::query_ledger::{self, NewQuery, QueryLedger};
+
+/// How far back the link graph is read for domain authority.
+const AUTHORITY_WINDOW_DAYS: i64 = 180;
 
 /// Handles cold-start bootstrapping for a brand-new region.
 /// Generates seed search queries, performs a news sweep, and creates initial Source nodes.
@@ -18,6 +27,7 @@ pub struct Bootstrapper<'a> {
     archive: Arc<Archive>,
     anthropic_api_key: String,
     region: ScoutScope,
+    ledger: Option<QueryLedger>,
 }
 
 impl<'a> Bootstrapper<'a> {
@@ -32,10 +42,21 @@ impl<'a> Bootstrapper<'a> {
             archive,
             anthropic_api_key: anthropic_api_key.to_string(),
             region,
+            ledger: None,
         }
     }
 
+    /// Register seed queries in the query ledger so their yield is tracked.
+    pub fn with_ledger(mut self, ledger: QueryLedger) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
     /// Run the cold start bootstrap. Returns number of sources discovered.
+    ///
+    /// Every source is scored and recorded as a bootstrap candidate. Those
+    /// that pass `AUTO_APPROVE_SCORE` start active; the rest are created
+    /// inactive and wait for an admin to approve them.
     pub async fn run(&self) -> Result<u32> {
         info!(
             region = self.region.name.as_str(),
@@ -46,13 +67,16 @@ impl<'a> Bootstrapper<'a> {
         let queries = self.generate_seed_queries().await?;
         info!(count = queries.len(), "Generated seed queries");
 
-        // Step 2: Create WebQuery source nodes for each query
+        let authority = self.domain_authority().await;
         let mut sources_created = 0u32;
+        let mut auto_approved = 0u32;
+
+        // Step 2: Create WebQuery source nodes for each query
         for (query, role) in &queries {
             let cv = query.clone();
             let ck = canonical_value(&cv);
             let mut source = SourceNode::new(
-                ck,
+                ck.clone(),
                 cv,
                 None,
                 DiscoveryMethod::ColdStart,
@@ -60,23 +84,34 @@ impl<'a> Bootstrapper<'a> {
                 role.clone(),
                 None,
             );
-            match self.writer.upsert_source(&source).await {
-                Ok(_) => {
+            let label = source.canonical_value.clone();
+            match self.stage_source(&mut source, &authority).await {
+                Ok(approved) => {
                     sources_created += 1;
-                    self.create_pin_for_source(source.id).await;
+                    auto_approved += approved as u32;
+                    if let Some(ledger) = &self.ledger {
+                        let seed = NewQuery {
+                            canonical_key: ck,
+                            query: query.clone(),
+                            pattern: query_ledger::bootstrap_pattern(&role.to_string()),
+                            refined_from: None,
+                        };
+                        if let Err(e) = ledger.register(&seed).await {
+                            warn!(query = query.as_str(), error = %e, "Failed to register seed query (non-fatal)");
+                        }
+                    }
                 }
-                Err(e) => warn!(query = query.as_str(), error = %e, "Failed to create seed source"),
+                Err(e) => warn!(query = label.as_str(), error = %e, "Failed to create seed source"),
             }
         }
 
         // Step 3: Also create standard platform sources (including LLM-discovered subreddits)
         let platform_sources = self.generate_platform_sources().await;
-        for source in platform_sources {
-            let source_id = source.id;
-            match self.writer.upsert_source(&source).await {
-                Ok(_) => {
+        for mut source in platform_sources {
+            match self.stage_source(&mut source, &authority).await {
+                Ok(approved) => {
                     sources_created += 1;
-                    self.create_pin_for_source(source_id).await;
+                    auto_approved += approved as u32;
                 }
                 Err(e) => {
                     let label = source.url.as_deref().unwrap_or(&source.canonical_value);
@@ -85,10 +120,88 @@ impl<'a> Bootstrapper<'a> {
             }
         }
 
-        info!(sources_created, "Cold start bootstrap complete");
+        info!(
+            sources_created,
+            auto_approved,
+            pending_review = sources_created - auto_approved,
+            "Cold start bootstrap complete"
+        );
         Ok(sources_created)
     }
 
+    /// Score a source, create it (inactive unless it auto-approves) with a
+    /// pin at the region center, and record it for review. Returns whether it
+    /// was auto-approved.
+    async fn stage_source(&self, source: &mut SourceNode, authority: &HashMap<String, f64>) -> Result<bool> {
+        let region_name = &self.region.name;
+        let domain = source.url.as_deref().map(link_graph::domain_of);
+        let sample = match source.url.as_deref() {
+            Some(url) => self.sample_content(url).await,
+            None => None,
+        };
+        let locality_text = format!(
+            "{} {} {}",
+            source.url.as_deref().unwrap_or_default(),
+            source.canonical_value,
+            source.gap_context.as_deref().unwrap_or_default()
+        );
+        let score = CandidateScore {
+            domain_reputation: bootstrap_review::domain_reputation(
+                domain.as_deref(),
+                domain.as_ref().and_then(|d| authority.get(d).copied()),
+            ),
+            locality: bootstrap_review::locality_match(region_name, &locality_text),
+            content: sample.as_deref().map(|s| bootstrap_review::content_score(region_name, s)),
+        };
+        let status = score.status();
+        source.active = status == ReviewStatus::Approved;
+
+        self.writer.upsert_source(source).await?;
+        self.create_pin_for_source(source.id).await;
+        let candidate = BootstrapCandidate {
+            region: rootsignal_common::slugify(region_name),
+            canonical_key: source.canonical_key.clone(),
+            canonical_value: source.canonical_value.clone(),
+            url: source.url.clone(),
+            source_role: source.source_role,
+            score,
+            status,
+            sample_excerpt: sample.as_deref().map(bootstrap_review::sample_excerpt),
+            created_at: Utc::now(),
+            reviewed_at: None,
+        };
+        self.writer.record_bootstrap_candidate(&candidate).await?;
+        Ok(source.active)
+    }
+
+    /// Text of a page (or, for feeds, its item titles) to score a candidate
+    /// on. None when neither can be fetched.
+    async fn sample_content(&self, url: &str) -> Option<String> {
+        if let Ok(page) = self.archive.page(url).await {
+            return Some(page.markdown);
+        }
+        let feed = self.archive.feed(url).await.ok()?;
+        let titles: Vec<String> = feed.items.into_iter().filter_map(|item| item.title).collect();
+        Some(format!("{} {}", feed.title.unwrap_or_default(), titles.join(" ")))
+    }
+
+    /// Link-graph authority of each known domain, relative to the best-linked
+    /// one. Empty (no reputation boost) when it can't be loaded.
+    async fn domain_authority(&self) -> HashMap<String, f64> {
+        let scores = match self.archive.domain_scores(Utc::now() - Duration::days(AUTHORITY_WINDOW_DAYS)).await {
+            Ok(scores) => scores,
+            Err(e) => {
+                warn!(error = %e, "Failed to load domain scores, scoring bootstrap sources without them");
+                return HashMap::new();
+            }
+        };
+        let max = scores.iter().map(|s| s.authority).fold(0.0_f64, f64::max);
+        if max <= 0.0 {
+            return HashMap::new();
+        }
+        scores.into_iter().map(|s| (s.domain, s.authority / max)).collect()
+    }
+
     /// Create a pin at the region center for a source.
     async fn create_pin_for_source(&self, source_id: Uuid) {
         let pin = PinNode {
@@ -259,7 +372,7 @@ Return ONLY the terms, one per line. No numbering, no explanations."#
                 ),
                 SourceRole::Response,
             ),
-            // Site-scoped search: Serper will query `site:gofundme.com/f/ {region} {topic}`
+            // Site-scoped search: the search provider will query `site:gofundme.com/f/ {region} {topic}`
             make_query(
                 &format!("site:gofundme.com/f/ {}", region_name),
                 SourceRole::Response,
@@ -389,27 +502,22 @@ Do NOT include national wire services (AP, Reuters, NPR national, CNN, Fox News)
 Only include outlets that primarily cover {region_name} and its surrounding area.
 
 For each outlet, provide the name and homepage URL.
-Return as JSON array: [{{"name": "Outlet Name", "url": "https://..."}}]
-Maximum 8 outlets. Return ONLY the JSON array, no explanation."#
+Maximum 8 outlets."#
         );
 
         let claude =
             ai_client::claude::Claude::new(&self.anthropic_api_key, "claude-haiku-4-5-20251001");
 
-        let response = claude.complete(&prompt).await?;
-
-        let json_str = response
-            .trim()
-            .trim_start_matches("```json")
-            .trim_start_matches("```")
-            .trim_end_matches("```")
-            .trim();
-
-        let outlets: Vec<NewsOutlet> = serde_json::from_str(json_str)
-            .unwrap_or_else(|e| {
-                warn!(error = %e, "Failed to parse news outlet JSON");
+        let outlets = match claude
+            .extract::<NewsOutletList>("claude-haiku-4-5-20251001", "You list local news outlets.", &prompt)
+            .await
+        {
+            Ok(list) => list.outlets,
+            Err(e) => {
+                warn!(error = %e, "Failed to get news outlets");
                 Vec::new()
-            });
+            }
+        };
 
         let mut results = Vec::new();
 
@@ -489,7 +597,12 @@ fn extract_feed_links(html: &str, base_url: &str) -> Vec<String> {
     feeds
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, schemars::JsonSchema)]
+struct NewsOutletList {
+    outlets: Vec<NewsOutlet>,
+}
+
+#[derive(serde::Deserialize, schemars::JsonSchema)]
 struct NewsOutlet {
     name: String,
     url: String,
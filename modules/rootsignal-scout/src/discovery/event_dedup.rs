@@ -0,0 +1,142 @@
+//! Cross-source event deduplication for the gathering finder.
+//!
+//! Events found by web investigation are often already in the graph from
+//! structured sources (event platforms, calendars). Embedding dedup misses
+//! many of these because a listing and a news mention read differently, so
+//! dated gatherings are also matched against existing Gatherings by fuzzy
+//! title, venue and start time before a new node is created.
+
+use std::collections::HashSet;
+
+use rootsignal_graph::EventCandidate;
+use uuid::Uuid;
+
+/// How far either side of the discovered date an existing event may start.
+/// Discovered dates are day-only, so this spans the whole day plus timezone slop.
+pub const EVENT_WINDOW_HOURS: i64 = 36;
+
+/// Title similarity needed when the venues agree.
+const TITLE_MATCH_WITH_VENUE: f64 = 0.5;
+
+/// Title similarity needed when either side has no venue to compare.
+const TITLE_MATCH_WITHOUT_VENUE: f64 = 0.75;
+
+const STOPWORDS: &[&str] = &["a", "an", "and", "at", "for", "in", "of", "on", "the", "to", "with"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventMatch {
+    pub id: Uuid,
+    pub title_similarity: f64,
+    pub venue_matched: bool,
+}
+
+/// The best existing event this discovered gathering duplicates, if any.
+/// Candidates are assumed to already fall in the time window and region.
+/// A known venue on both sides that disagrees rules a candidate out.
+pub fn match_event(title: &str, venue: Option<&str>, candidates: &[EventCandidate]) -> Option<EventMatch> {
+    let venue = venue.filter(|v| !v.trim().is_empty());
+    candidates
+        .iter()
+        .filter_map(|c| {
+            let title_similarity = title_similarity(title, &c.title);
+            let venue_matched = match venue {
+                Some(v) if !c.venues.is_empty() => {
+                    if !c.venues.iter().any(|cv| venues_match(v, cv)) {
+                        return None;
+                    }
+                    true
+                }
+                _ => false,
+            };
+            let needed = if venue_matched { TITLE_MATCH_WITH_VENUE } else { TITLE_MATCH_WITHOUT_VENUE };
+            (title_similarity >= needed).then_some(EventMatch {
+                id: c.id,
+                title_similarity,
+                venue_matched,
+            })
+        })
+        .max_by(|a, b| a.title_similarity.total_cmp(&b.title_similarity))
+}
+
+/// Jaccard overlap of title words, ignoring case, punctuation and stopwords.
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let a = tokens(a);
+    let b = tokens(b);
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// Venues match when one's words contain the other's, so "Powderhorn Park"
+/// matches "Powderhorn Park Building" but not "Loring Park".
+fn venues_match(a: &str, b: &str) -> bool {
+    let a = tokens(a);
+    let b = tokens(b);
+    !a.is_empty() && !b.is_empty() && (a.is_subset(&b) || b.is_subset(&a))
+}
+
+fn tokens(s: &str) -> HashSet<String> {
+    s.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !STOPWORDS.contains(w))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(title: &str, venues: &[&str]) -> EventCandidate {
+        EventCandidate {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            source_url: "https://events.example.org/e/1".to_string(),
+            starts_at: None,
+            venues: venues.iter().map(|v| v.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn reworded_title_at_same_venue_matches() {
+        let existing = candidate("Candlelight Vigil for Peace", &["Powderhorn Park Building"]);
+
+        let m = match_event("Peace vigil (candlelight)", Some("Powderhorn Park"), &[existing.clone()]);
+
+        let m = m.expect("should match");
+        assert_eq!(m.id, existing.id);
+        assert!(m.venue_matched);
+    }
+
+    #[test]
+    fn same_title_at_different_venue_is_a_different_event() {
+        let existing = candidate("Community Cleanup", &["Loring Park"]);
+
+        let m = match_event("Community Cleanup", Some("Powderhorn Park"), &[existing]);
+
+        assert_eq!(m, None);
+    }
+
+    #[test]
+    fn without_venue_only_a_close_title_matches() {
+        let existing = candidate("Tenant Rights Workshop at Sabathani", &[]);
+
+        let close = match_event("Tenant rights workshop - Sabathani", None, &[existing.clone()]);
+        let loose = match_event("Tenant workshop", None, &[existing]);
+
+        assert!(close.is_some_and(|m| !m.venue_matched));
+        assert_eq!(loose, None);
+    }
+
+    #[test]
+    fn best_title_wins_among_candidates() {
+        let weak = candidate("Neighborhood potluck and clothing swap", &["Sabathani Center"]);
+        let strong = candidate("Neighborhood Potluck", &["Sabathani Community Center"]);
+
+        let m = match_event("Neighborhood potluck", Some("Sabathani"), &[weak, strong.clone()]);
+
+        assert_eq!(m.map(|m| m.id), Some(strong.id));
+    }
+}
@@ -10,19 +10,24 @@ use serde::Deserialize;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use rootsignal_common::error_budget::{FailureSample, OperationTally};
+use rootsignal_common::log_events::{failure_reason, InvestigationFailed};
 use rootsignal_common::{
-    canonical_value, AidNode, DiscoveryMethod, GatheringNode, GeoPoint, GeoPrecision, NeedNode, Node,
-    NodeMeta, NodeType, ScoutScope, SensitivityLevel, SourceNode, SourceRole, Urgency,
+    canonical_value, channel_type, AidNode, Decision, DecisionKind, DiscoveryMethod, EvidenceNode, GatheringNode, GeoPoint,
+    GeoPrecision, NeedNode, Node, NodeMeta, NodeType, ScoutScope, SensitivityLevel, SourceNode,
+    SourceRole, TrialState, Urgency,
 };
 use rootsignal_graph::{GatheringFinderTarget, GraphWriter, ResponseHeuristic};
 
 use rootsignal_archive::Archive;
 
 use crate::infra::embedder::TextEmbedder;
+use crate::memory::memo::RegionMemo;
 use crate::discovery::agent_tools::{ReadPageTool, WebSearchTool};
+use crate::discovery::event_dedup::{self, EVENT_WINDOW_HOURS};
 
 const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
-const MAX_GRAVITY_TARGETS_PER_RUN: usize = 5;
+pub const MAX_GRAVITY_TARGETS_PER_RUN: usize = 5;
 const MAX_TOOL_TURNS: usize = 10;
 const MAX_GATHERINGS_PER_TENSION: usize = 8;
 const MAX_FUTURE_QUERIES_PER_TENSION: usize = 3;
@@ -80,26 +85,49 @@ pub struct DiscoveredGathering {
 pub struct GatheringFinderStats {
     pub targets_found: u32,
     pub targets_investigated: u32,
+    /// Targets whose investigation errored, with the first few errors.
+    pub targets_failed: u32,
+    pub failure_samples: Vec<FailureSample>,
     pub targets_no_gravity: u32,
     pub gatherings_discovered: u32,
     pub gatherings_deduped: u32,
+    /// Dated gatherings checked against existing events.
+    pub events_checked: u32,
+    /// Of those, how many matched an existing event and corroborated it.
+    pub events_matched: u32,
     pub signals_created: u32,
     pub edges_created: u32,
     pub future_sources_created: u32,
 }
 
+impl GatheringFinderStats {
+    /// This run's attempts and failures, for error budgets.
+    pub fn tally(&self) -> OperationTally {
+        OperationTally::new(
+            "gathering_finder",
+            self.targets_investigated + self.targets_failed,
+            self.targets_failed,
+            self.failure_samples.clone(),
+        )
+    }
+}
+
 impl std::fmt::Display for GatheringFinderStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Gathering finder: {} targets found, {} investigated, \
+            "Gathering finder: {} targets found, {} investigated, {} failed, \
              {} no-gravity, {} gatherings discovered ({} deduped), \
+             {}/{} matched existing events, \
              {} signals created, {} edges, {} future sources",
             self.targets_found,
             self.targets_investigated,
+            self.targets_failed,
             self.targets_no_gravity,
             self.gatherings_discovered,
             self.gatherings_deduped,
+            self.events_matched,
+            self.events_checked,
             self.signals_created,
             self.edges_created,
             self.future_sources_created,
@@ -276,6 +304,10 @@ pub struct GatheringFinder<'a> {
     max_lng: f64,
     cancelled: Arc<AtomicBool>,
     run_id: String,
+    /// The region memo as a prompt block, appended to the investigation prompt.
+    region_memo: Option<String>,
+    /// Targets per run, at most `MAX_GRAVITY_TARGETS_PER_RUN`.
+    max_targets: usize,
 }
 
 impl<'a> GatheringFinder<'a> {
@@ -297,31 +329,44 @@ impl<'a> GatheringFinder<'a> {
                 visited_urls: None,
             });
 
-        let lat_delta = region.radius_km / 111.0;
-        let lng_delta = region.radius_km / (111.0 * region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
         let region_slug = region.name.clone();
         Self {
             writer,
             claude,
             embedder,
-            min_lat: region.center_lat - lat_delta,
-            max_lat: region.center_lat + lat_delta,
-            min_lng: region.center_lng - lng_delta,
-            max_lng: region.center_lng + lng_delta,
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
             region,
             region_slug,
             cancelled,
             run_id,
+            region_memo: None,
+            max_targets: MAX_GRAVITY_TARGETS_PER_RUN,
         }
     }
 
+    /// Cap targets this run, e.g. to what the budget allocated.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.max_targets = max_targets.min(MAX_GRAVITY_TARGETS_PER_RUN);
+        self
+    }
+
+    /// Append the region memo to the investigation system prompt.
+    pub fn with_region_memo(mut self, memo: &RegionMemo) -> Self {
+        self.region_memo = memo.prompt_block(&self.region.name);
+        self
+    }
+
     pub async fn run(&self) -> GatheringFinderStats {
         let mut stats = GatheringFinderStats::default();
 
         let targets = match self
             .writer
             .find_gathering_finder_targets(
-                MAX_GRAVITY_TARGETS_PER_RUN as u32,
+                self.max_targets as u32,
                 self.min_lat,
                 self.max_lat,
                 self.min_lng,
@@ -356,12 +401,16 @@ impl<'a> GatheringFinder<'a> {
                     found
                 }
                 Err(e) => {
-                    warn!(
-                        tension_id = %target.tension_id,
-                        title = target.title.as_str(),
-                        error = %e,
-                        "Gathering finder investigation failed"
-                    );
+                    stats.targets_failed += 1;
+                    let failed = InvestigationFailed {
+                        phase: "gathering_finder",
+                        target_id: target.tension_id,
+                        reason: failure_reason(&e),
+                        turns: MAX_TOOL_TURNS,
+                        error: format!("{e:#}"),
+                    };
+                    FailureSample::keep(&mut stats.failure_samples, failed.reason, failed.error.clone());
+                    failed.emit();
                     false
                 }
             };
@@ -401,14 +450,18 @@ impl<'a> GatheringFinder<'a> {
             .await
             .unwrap_or_default();
 
-        let system = investigation_system_prompt(&self.region.name);
+        let mut system = investigation_system_prompt(&self.region.name);
+        if let Some(memo) = &self.region_memo {
+            system.push_str("\n\n");
+            system.push_str(memo);
+        }
         let user = investigation_user_prompt(target, &existing);
 
         // Phase 1: Agentic investigation with web_search + read_page tools
         let reasoning = self
             .claude
             .prompt(&user)
-            .preamble(&system)
+            .cacheable_prefix(&system)
             .temperature(0.7)
             .multi_turn(MAX_TOOL_TURNS)
             .send()
@@ -517,45 +570,57 @@ impl<'a> GatheringFinder<'a> {
             _ => NodeType::Aid, // Default to Aid for unknown types
         };
 
-        // Check for duplicate (region-scoped)
-        let existing = self
-            .writer
-            .find_duplicate(
-                &embedding,
-                node_type,
-                0.85,
-                self.min_lat,
-                self.max_lat,
-                self.min_lng,
-                self.max_lng,
-            )
-            .await;
+        // Same event already ingested from another source? Corroborate it
+        // rather than creating a near-copy.
+        let event_match = match node_type {
+            NodeType::Gathering => self.match_existing_event(gathering, stats).await,
+            _ => None,
+        };
 
         let was_new;
-        let signal_id = match existing {
-            Ok(Some(dup)) => {
-                info!(
-                    existing_id = %dup.id,
-                    similarity = dup.similarity,
-                    title = gathering.title.as_str(),
-                    "Matched existing signal for gathering"
-                );
-                stats.gatherings_deduped += 1;
-                was_new = false;
+        let signal_id = if let Some(id) = event_match {
+            was_new = false;
+            id
+        } else {
+            // Check for duplicate (region-scoped)
+            let existing = self
+                .writer
+                .find_duplicate(
+                    &embedding,
+                    node_type,
+                    0.85,
+                    self.min_lat,
+                    self.max_lat,
+                    self.min_lng,
+                    self.max_lng,
+                )
+                .await;
+
+            match existing {
+                Ok(Some(dup)) => {
+                    info!(
+                        existing_id = %dup.id,
+                        similarity = dup.similarity,
+                        title = gathering.title.as_str(),
+                        "Matched existing signal for gathering"
+                    );
+                    stats.gatherings_deduped += 1;
+                    was_new = false;
 
-                // Touch the existing signal so it doesn't age out
-                if let Err(e) = self.writer.touch_signal_timestamp(dup.id).await {
-                    warn!(error = %e, "Failed to touch signal timestamp (non-fatal)");
-                }
+                    // Touch the existing signal so it doesn't age out
+                    if let Err(e) = self.writer.touch_signal_timestamp(dup.id).await {
+                        warn!(error = %e, "Failed to touch signal timestamp (non-fatal)");
+                    }
 
-                dup.id
-            }
-            _ => {
-                if let Err(ref e) = existing {
-                    warn!(error = %e, "Gathering dedup check failed, creating new");
+                    dup.id
+                }
+                _ => {
+                    if let Err(ref e) = existing {
+                        warn!(error = %e, "Gathering dedup check failed, creating new");
+                    }
+                    was_new = true;
+                    self.create_gathering_node(gathering).await?
                 }
-                was_new = true;
-                self.create_gathering_node(gathering).await?
             }
         };
 
@@ -627,6 +692,101 @@ impl<'a> GatheringFinder<'a> {
         Ok(())
     }
 
+    /// Match a dated gathering against events already in the graph by title,
+    /// venue and start time. On a match, corroborate the existing event with
+    /// this page as evidence and return its id. Lookup failures fall through
+    /// to the usual embedding dedup.
+    async fn match_existing_event(
+        &self,
+        gathering: &DiscoveredGathering,
+        stats: &mut GatheringFinderStats,
+    ) -> Option<Uuid> {
+        let starts_at = parse_event_date(gathering.event_date.as_deref())?;
+        stats.events_checked += 1;
+
+        let candidates = match self
+            .writer
+            .find_gatherings_near_time(
+                starts_at,
+                chrono::Duration::hours(EVENT_WINDOW_HOURS),
+                self.min_lat,
+                self.max_lat,
+                self.min_lng,
+                self.max_lng,
+            )
+            .await
+        {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Event dedup lookup failed, falling back to embedding dedup");
+                return None;
+            }
+        };
+
+        let Some(m) = event_dedup::match_event(&gathering.title, gathering.venue.as_deref(), &candidates) else {
+            info!(
+                title = gathering.title.as_str(),
+                candidates = candidates.len(),
+                "No existing event matched"
+            );
+            return None;
+        };
+
+        let now = Utc::now();
+        if let Err(e) = self.writer.corroborate(m.id, NodeType::Gathering, now, &[]).await {
+            warn!(existing_id = %m.id, error = %e, "Failed to corroborate matched event, falling back");
+            return None;
+        }
+        let decision = Decision::new(
+            DecisionKind::Merge,
+            m.id,
+            NodeType::Gathering,
+            "gathering_finder.event_dedup",
+            format!("corroborated by {}", gathering.url),
+        )
+        .with_inputs(&serde_json::json!({
+            "source_url": gathering.url,
+            "title": gathering.title,
+            "venue": gathering.venue,
+            "title_similarity": m.title_similarity,
+        }))
+        .with_rationale(format!(
+            "Same event: title similarity {:.2}{}",
+            m.title_similarity,
+            if m.venue_matched { ", venue matched" } else { "" }
+        ))
+        .with_run_id(&self.run_id);
+        if let Err(e) = self.writer.record_decision(&decision).await {
+            warn!(existing_id = %m.id, error = %e, "Failed to record merge decision");
+        }
+        let evidence = EvidenceNode {
+            id: Uuid::new_v4(),
+            source_url: gathering.url.clone(),
+            retrieved_at: now,
+            content_hash: String::new(),
+            snippet: Some(gathering.summary.clone()),
+            relevance: None,
+            evidence_confidence: None,
+            channel_type: Some(channel_type(&gathering.url)),
+            change_summary: None,
+            engagement_velocity: None,
+            verification: None,
+        };
+        if let Err(e) = self.writer.create_evidence(&evidence, m.id).await {
+            warn!(existing_id = %m.id, error = %e, "Failed to attach evidence to matched event (non-fatal)");
+        }
+
+        stats.events_matched += 1;
+        info!(
+            existing_id = %m.id,
+            title = gathering.title.as_str(),
+            title_similarity = m.title_similarity,
+            venue_matched = m.venue_matched,
+            "Gathering matches an existing event, corroborated"
+        );
+        Some(m.id)
+    }
+
     async fn create_gathering_node(&self, gathering: &DiscoveredGathering) -> Result<Uuid> {
         let now = Utc::now();
         let meta = NodeMeta {
@@ -655,15 +815,14 @@ impl<'a> GatheringFinder<'a> {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
 
         let node = match gathering.signal_type.to_lowercase().as_str() {
             "gathering" => {
-                let starts_at = gathering.event_date.as_deref().and_then(|d| {
-                    chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
-                        .ok()
-                        .map(|nd| nd.and_hms_opt(0, 0, 0).unwrap().and_utc())
-                });
+                let starts_at = parse_event_date(gathering.event_date.as_deref());
                 Node::Gathering(GatheringNode {
                     meta,
                     starts_at,
@@ -679,6 +838,7 @@ impl<'a> GatheringFinder<'a> {
                 what_needed: Some(gathering.summary.clone()),
                 action_url: Some(gathering.url.clone()),
                 goal: None,
+                fundraiser: None,
             }),
             _ => Node::Aid(AidNode {
                 meta,
@@ -715,17 +875,10 @@ impl<'a> GatheringFinder<'a> {
         explanation: &str,
         gathering_type: &str,
     ) -> Result<()> {
-        let lat_delta = self.region.radius_km / 111.0;
-        let lng_delta =
-            self.region.radius_km / (111.0 * self.region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = self.region.bounding_box();
         let active_tensions = self
             .writer
-            .get_active_tensions(
-                self.region.center_lat - lat_delta,
-                self.region.center_lat + lat_delta,
-                self.region.center_lng - lng_delta,
-                self.region.center_lng + lng_delta,
-            )
+            .get_active_tensions(min_lat, max_lat, min_lng, max_lng)
             .await?;
         if active_tensions.is_empty() {
             return Ok(());
@@ -804,6 +957,7 @@ impl<'a> GatheringFinder<'a> {
             quality_penalty: 1.0,
             source_role: SourceRole::Response,
             scrape_count: 0,
+            trial: TrialState::Probation,
         };
 
         self.writer.upsert_source(&source).await?;
@@ -819,6 +973,12 @@ impl<'a> GatheringFinder<'a> {
     }
 }
 
+/// Parse an ISO `YYYY-MM-DD` event date as midnight UTC.
+fn parse_event_date(date: Option<&str>) -> Option<chrono::DateTime<Utc>> {
+    let nd = chrono::NaiveDate::parse_from_str(date?, "%Y-%m-%d").ok()?;
+    Some(nd.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
 fn cosine_sim_f64(a: &[f64], b: &[f64]) -> f64 {
     crate::infra::util::cosine_similarity(a, b)
 }
@@ -910,9 +1070,13 @@ mod tests {
         let stats = GatheringFinderStats {
             targets_found: 3,
             targets_investigated: 2,
+            targets_failed: 0,
+            failure_samples: Vec::new(),
             targets_no_gravity: 1,
             gatherings_discovered: 5,
             gatherings_deduped: 1,
+            events_checked: 2,
+            events_matched: 1,
             signals_created: 4,
             edges_created: 6,
             future_sources_created: 3,
@@ -922,6 +1086,7 @@ mod tests {
         assert!(display.contains("2 investigated"));
         assert!(display.contains("1 no-gravity"));
         assert!(display.contains("5 gatherings discovered"));
+        assert!(display.contains("1/2 matched existing events"));
         assert!(display.contains("4 signals created"));
     }
 
@@ -940,13 +1105,11 @@ mod tests {
 
     #[test]
     fn event_date_parsing() {
-        let date_str = "2026-03-15";
-        let parsed = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d");
-        assert!(parsed.is_ok());
+        let parsed = parse_event_date(Some("2026-03-15"));
+        assert_eq!(parsed.map(|d| d.to_rfc3339()), Some("2026-03-15T00:00:00+00:00".to_string()));
 
-        let bad_str = "not-a-date";
-        let parsed = chrono::NaiveDate::parse_from_str(bad_str, "%Y-%m-%d");
-        assert!(parsed.is_err());
+        assert!(parse_event_date(Some("not-a-date")).is_none());
+        assert!(parse_event_date(None).is_none());
     }
 
     #[test]
@@ -956,6 +1119,7 @@ mod tests {
             center_lat: 44.9778,
             center_lng: -93.2650,
             radius_km: 30.0,
+            boundary: None,
         };
 
         let now = Utc::now();
@@ -985,6 +1149,9 @@ mod tests {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
 
         let node = Node::Gathering(GatheringNode {
@@ -0,0 +1,266 @@
+//! Targeted retrieval for situation information requests ("pull" mode).
+//!
+//! For each open request, hottest situation first: plan a few searches,
+//! read the top pages, and keep only excerpts that answer the question and
+//! appear verbatim on a page that was read. Answers are attached to the
+//! situation as pulled evidence; a request nothing answers is retried in a
+//! later run until it runs out of attempts.
+
+use std::collections::HashMap;
+
+use ai_client::claude::Claude;
+use anyhow::Result;
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::information_requests::{prioritize, pull_allowance};
+use rootsignal_common::{InformationRequest, PulledEvidence, ScoutScope};
+use rootsignal_graph::GraphWriter;
+
+use crate::pipeline::traits::ContentFetcher;
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
+
+const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
+const MAX_QUERIES_PER_REQUEST: usize = 3;
+const MAX_PAGES_PER_REQUEST: usize = 3;
+/// Page text shown to the judge, per page.
+const MAX_PAGE_CHARS: usize = 6_000;
+
+/// Cost of answering one request.
+pub const PULL_UNIT_CENTS: u64 = OperationCost::CLAUDE_HAIKU_INFORMATION_PULL
+    + OperationCost::SEARCH_INFORMATION_PULL
+    + OperationCost::CHROME_INFORMATION_PULL;
+
+const QUERY_PLANNING_SYSTEM: &str = "You plan web searches that answer one specific question about a local situation. \
+Return up to 3 short search queries, most promising first. Prefer queries that find primary sources: \
+official sites, agency announcements, local news. Include the place name. \
+Never search for private individuals.";
+
+const ANSWER_SYSTEM: &str = "You check whether web pages answer a question about a local situation. \
+For each page that answers it, quote the passage that does, copied exactly from the page, at most three sentences. \
+Do not paraphrase, combine pages or infer. If no page answers the question, return no excerpts.";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct PullQueries {
+    queries: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct PullAnswer {
+    #[serde(default)]
+    excerpts: Vec<Excerpt>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+struct Excerpt {
+    url: String,
+    snippet: String,
+}
+
+/// Stats from a pull run.
+#[derive(Debug, Default)]
+pub struct PullStats {
+    pub requests_open: u32,
+    pub requests_answered: u32,
+    pub requests_unanswered: u32,
+    pub requests_failed: u32,
+    pub evidence_attached: u32,
+}
+
+impl std::fmt::Display for PullStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Information pull: {} open, {} answered ({} excerpts), {} unanswered, {} failed",
+            self.requests_open, self.requests_answered, self.evidence_attached,
+            self.requests_unanswered, self.requests_failed,
+        )
+    }
+}
+
+pub struct InformationPuller<'a> {
+    writer: &'a GraphWriter,
+    fetcher: &'a dyn ContentFetcher,
+    claude: Claude,
+    region: String,
+}
+
+impl<'a> InformationPuller<'a> {
+    pub fn new(writer: &'a GraphWriter, fetcher: &'a dyn ContentFetcher, anthropic_api_key: &str, region: &str) -> Self {
+        Self {
+            writer,
+            fetcher,
+            claude: Claude::new(anthropic_api_key, HAIKU_MODEL),
+            region: region.to_string(),
+        }
+    }
+
+    /// Answer the scope's open requests, hottest situation first, within the
+    /// pull step's share of the remaining budget.
+    pub async fn run(&self, scope: &ScoutScope, budget: &BudgetTracker) -> PullStats {
+        let mut stats = PullStats::default();
+
+        let mut requests = match self.writer.open_information_requests(scope).await {
+            Ok(requests) => requests,
+            Err(e) => {
+                warn!(error = %e, "Failed to load open information requests");
+                return stats;
+            }
+        };
+        stats.requests_open = requests.len() as u32;
+        prioritize(&mut requests);
+
+        let remaining = budget.is_active().then(|| budget.remaining());
+        let allowance = pull_allowance(remaining, PULL_UNIT_CENTS);
+        if allowance < requests.len() {
+            info!(open = requests.len(), allowance, "Information pull limited by budget");
+        }
+
+        for request in requests.into_iter().take(allowance) {
+            budget.spend(PULL_UNIT_CENTS);
+            match self.pull(&request).await {
+                Ok(evidence) if evidence.is_empty() => {
+                    stats.requests_unanswered += 1;
+                    if let Err(e) = self.writer.record_unanswered_request(request.id).await {
+                        warn!(request_id = %request.id, error = %e, "Failed to record unanswered request");
+                    }
+                }
+                Ok(evidence) => match self.writer.attach_pulled_evidence(request.id, &evidence).await {
+                    Ok(()) => {
+                        stats.requests_answered += 1;
+                        stats.evidence_attached += evidence.len() as u32;
+                    }
+                    Err(e) => {
+                        stats.requests_failed += 1;
+                        warn!(request_id = %request.id, error = %e, "Failed to attach pulled evidence");
+                    }
+                },
+                Err(e) => {
+                    stats.requests_failed += 1;
+                    warn!(request_id = %request.id, error = %e, "Information pull failed");
+                }
+            }
+        }
+
+        stats
+    }
+
+    async fn pull(&self, request: &InformationRequest) -> Result<Vec<PulledEvidence>> {
+        // 1. Plan searches
+        let plan: PullQueries = self
+            .claude
+            .extract(
+                HAIKU_MODEL,
+                QUERY_PLANNING_SYSTEM,
+                format!(
+                    "Situation: {}\nQuestion: {}\nPlace: {}",
+                    request.situation_headline, request.question, self.region
+                ),
+            )
+            .await?;
+
+        // 2. Search, collecting distinct result URLs in rank order
+        let mut urls: Vec<String> = Vec::new();
+        for query in plan.queries.iter().take(MAX_QUERIES_PER_REQUEST) {
+            match self.fetcher.search(query).await {
+                Ok(search) => {
+                    for result in search.results {
+                        if !urls.contains(&result.url) {
+                            urls.push(result.url);
+                        }
+                    }
+                }
+                Err(e) => warn!(query, error = %e, "Information pull search failed"),
+            }
+        }
+
+        // 3. Read the top pages
+        let mut pages: HashMap<String, String> = HashMap::new();
+        for url in urls.into_iter().take(MAX_PAGES_PER_REQUEST) {
+            match self.fetcher.page(&url).await {
+                Ok(page) if !page.markdown.trim().is_empty() => {
+                    pages.insert(url, page.markdown.chars().take(MAX_PAGE_CHARS).collect());
+                }
+                Ok(_) => {}
+                Err(e) => warn!(url, error = %e, "Information pull page read failed"),
+            }
+        }
+        if pages.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // 4. Keep excerpts that answer the question
+        let pages_text = pages
+            .iter()
+            .map(|(url, text)| format!("--- Page ---\nURL: {url}\n{text}"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let answer: PullAnswer = self
+            .claude
+            .extract(
+                HAIKU_MODEL,
+                ANSWER_SYSTEM,
+                format!("Question: {}\nSituation: {}\n\n{pages_text}", request.question, request.situation_headline),
+            )
+            .await?;
+
+        let now = Utc::now();
+        Ok(grounded_excerpts(answer.excerpts, &pages)
+            .into_iter()
+            .map(|e| PulledEvidence {
+                id: Uuid::new_v4(),
+                request_id: request.id,
+                situation_id: request.situation_id,
+                url: e.url,
+                snippet: e.snippet,
+                retrieved_at: now,
+            })
+            .collect())
+    }
+}
+
+/// Excerpts from a page that was read whose text appears on that page,
+/// ignoring whitespace differences.
+fn grounded_excerpts(excerpts: Vec<Excerpt>, pages: &HashMap<String, String>) -> Vec<Excerpt> {
+    let squash = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+    excerpts
+        .into_iter()
+        .filter(|e| {
+            let snippet = squash(&e.snippet);
+            !snippet.is_empty() && pages.get(&e.url).is_some_and(|page| squash(page).contains(&snippet))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn excerpt(url: &str, snippet: &str) -> Excerpt {
+        Excerpt {
+            url: url.to_string(),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    #[test]
+    fn only_excerpts_quoted_from_pages_read_are_kept() {
+        let pages = HashMap::from([(
+            "https://city.gov/shelter".to_string(),
+            "The shelter is open\n  daily from 6pm to 8am.".to_string(),
+        )]);
+        let excerpts = vec![
+            excerpt("https://city.gov/shelter", "open daily from 6pm to 8am"),
+            excerpt("https://city.gov/shelter", "open around the clock"),
+            excerpt("https://elsewhere.com", "open daily from 6pm to 8am"),
+        ];
+
+        let kept = grounded_excerpts(excerpts, &pages);
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].url, "https://city.gov/shelter");
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use ai_client::claude::Claude;
+use ai_client::traits::{Agent, PromptBuilder};
 use anyhow::Result;
 use chrono::Utc;
 use schemars::JsonSchema;
@@ -9,25 +10,45 @@ use serde::{de, Deserialize};
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use rootsignal_common::{ScoutScope, EvidenceNode};
+use rootsignal_common::error_budget::{FailureSample, OperationTally};
+use rootsignal_common::investigation_queue;
+use rootsignal_common::investigations::{consulted_sources, investigation_question};
+use rootsignal_common::log_events::{failure_reason, InvestigationFailed};
+use rootsignal_common::{Decision, DecisionKind, EvidenceNode, Investigation, InvestigationFinding, ScoutScope};
 use rootsignal_graph::{EvidenceSummary, GraphWriter, InvestigationTarget};
 
 use rootsignal_archive::Archive;
 
+use crate::discovery::agent_tools::{
+    ActorHistoryTool, FindSimilarSignalsTool, GraphCallBudget, GraphLookup, SituationContextTool,
+};
+use crate::infra::embedder::TextEmbedder;
+use crate::scheduling::budget::OperationCost;
+
 const MAX_SEARCH_QUERIES_PER_RUN: usize = 15;
-const MAX_SIGNALS_INVESTIGATED: usize = 8;
+pub const MAX_SIGNALS_INVESTIGATED: usize = 8;
+/// Open queue entries considered per run.
+const QUEUE_SCAN_LIMIT: u32 = 500;
 const MAX_QUERIES_PER_SIGNAL: usize = 3;
+/// Graph tool calls shared by every investigation in a run.
+const MAX_GRAPH_CALLS_PER_RUN: u32 = 24;
+const MAX_GRAPH_TOOL_TURNS: usize = 4;
 
 pub struct Investigator<'a> {
     writer: &'a GraphWriter,
     archive: Arc<Archive>,
+    embedder: Arc<dyn TextEmbedder>,
+    anthropic_api_key: String,
     claude: Claude,
+    graph_budget: GraphCallBudget,
     region: String,
     min_lat: f64,
     max_lat: f64,
     min_lng: f64,
     max_lng: f64,
     cancelled: Arc<AtomicBool>,
+    /// Signals per run, at most `MAX_SIGNALS_INVESTIGATED`.
+    max_targets: usize,
 }
 
 /// Stats from an investigation run.
@@ -36,18 +57,93 @@ pub struct InvestigationStats {
     pub targets_found: u32,
     pub targets_investigated: u32,
     pub targets_failed: u32,
+    /// The first few investigation errors.
+    pub failure_samples: Vec<FailureSample>,
     pub evidence_created: u32,
     pub search_queries_used: u32,
     pub confidence_adjustments: u32,
+    /// Evidence demoted because its page and the signal's source cite each other.
+    pub circular_citations: u32,
+    /// Read-only graph tool calls made while consulting existing knowledge.
+    pub graph_lookups: u32,
+    pub reports_recorded: u32,
+    /// Queue entries left pending for later runs.
+    pub queue_depth: u32,
+}
+
+/// What one signal's investigation did, for its report.
+#[derive(Debug, Default)]
+struct SignalInvestigation {
+    graph_consulted: bool,
+    queries: Vec<String>,
+    sources: Vec<String>,
+    findings: Vec<InvestigationFinding>,
+    llm_calls: u32,
+    circular_citations: u32,
+}
+
+impl SignalInvestigation {
+    fn evidence_count(&self) -> u32 {
+        self.findings.len() as u32
+    }
+
+    fn cost_cents(&self) -> u32 {
+        (self.llm_calls as u64 * OperationCost::CLAUDE_HAIKU_INVESTIGATION
+            + self.queries.len() as u64 * OperationCost::SEARCH_INVESTIGATION) as u32
+    }
+
+    /// The steps taken, in order, as one readable line.
+    fn method(&self) -> String {
+        let mut steps = Vec::new();
+        if self.graph_consulted {
+            steps.push("consulted the graph for prior knowledge".to_string());
+        }
+        if self.queries.is_empty() {
+            steps.push("generated no search queries".to_string());
+        } else {
+            steps.push(format!(
+                "searched the web for {}",
+                self.queries.iter().map(|q| format!("\"{q}\"")).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        if !self.sources.is_empty() {
+            steps.push(format!("judged {} off-domain results for relevance", self.sources.len()));
+        }
+        if self.circular_citations > 0 {
+            steps.push(format!(
+                "demoted {} circular citation(s) to SUPPORTING",
+                self.circular_citations
+            ));
+        }
+        let mut method = steps.join("; ");
+        if let Some(first) = method.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+        method
+    }
+}
+
+impl InvestigationStats {
+    /// This run's attempts and failures, for error budgets.
+    pub fn tally(&self) -> OperationTally {
+        OperationTally::new(
+            "investigation",
+            self.targets_investigated + self.targets_failed,
+            self.targets_failed,
+            self.failure_samples.clone(),
+        )
+    }
 }
 
 impl std::fmt::Display for InvestigationStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Investigation: {} targets found, {} investigated, {} failed, {} evidence created, {} search queries, {} confidence adjustments",
+            "Investigation: {} targets found, {} investigated, {} failed, {} evidence created, {} search queries, {} confidence adjustments, {} circular citations, {} graph lookups, {} reports recorded, {} left queued",
             self.targets_found, self.targets_investigated, self.targets_failed,
             self.evidence_created, self.search_queries_used, self.confidence_adjustments,
+            self.circular_citations, self.graph_lookups, self.reports_recorded,
+            self.queue_depth,
         )
     }
 }
@@ -109,31 +205,48 @@ SUPPORTING = provides credibility context. \
 CONTRADICTING = evidence of inaccuracy. \
 Only include genuinely relevant results. Set confidence 0.0-1.0.";
 
+const GRAPH_CONSULTATION_SYSTEM: &str = "\
+You brief an investigator on what a community knowledge graph already knows before they search the web. \
+Use the tools to look for similar signals, the history of any organization named, and the situations the signal belongs to. \
+Only report what the tools returned; do not guess. \
+Reply with at most 8 short bullet points: prior signals and their evidence, actor track record, situation context. \
+If the graph knows nothing relevant, reply with exactly: NOTHING";
+
 const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
 
 impl<'a> Investigator<'a> {
     pub fn new(
         writer: &'a GraphWriter,
         archive: Arc<Archive>,
+        embedder: Arc<dyn TextEmbedder>,
         anthropic_api_key: &str,
         region: &ScoutScope,
         cancelled: Arc<AtomicBool>,
     ) -> Self {
-        let lat_delta = region.radius_km / 111.0;
-        let lng_delta = region.radius_km / (111.0 * region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
         Self {
             writer,
             archive,
+            embedder,
+            anthropic_api_key: anthropic_api_key.to_string(),
             claude: Claude::new(anthropic_api_key, HAIKU_MODEL),
+            graph_budget: GraphCallBudget::new(MAX_GRAPH_CALLS_PER_RUN),
             region: region.name.clone(),
-            min_lat: region.center_lat - lat_delta,
-            max_lat: region.center_lat + lat_delta,
-            min_lng: region.center_lng - lng_delta,
-            max_lng: region.center_lng + lng_delta,
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
             cancelled,
+            max_targets: MAX_SIGNALS_INVESTIGATED,
         }
     }
 
+    /// Cap signals investigated this run, e.g. to what the budget allocated.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.max_targets = max_targets.min(MAX_SIGNALS_INVESTIGATED);
+        self
+    }
+
     /// Run one investigation cycle. Non-fatal — individual failures are logged.
     pub async fn run(&self) -> InvestigationStats {
         let mut stats = InvestigationStats::default();
@@ -156,16 +269,35 @@ impl<'a> Investigator<'a> {
         };
 
         stats.targets_found = targets.len() as u32;
-        if targets.is_empty() {
-            info!("No investigation targets found");
+
+        // Queue what this run found, then take the best of everything queued
+        // (including targets earlier runs didn't get to).
+        if let Err(e) = self.writer.enqueue_investigations(&self.region, &targets).await {
+            warn!(error = %e, "Failed to queue investigation targets");
+        }
+        let queue = match self.writer.open_investigations(&self.region, QUEUE_SCAN_LIMIT).await {
+            Ok(q) => q,
+            Err(e) => {
+                warn!(error = %e, "Failed to load investigation queue");
+                return stats;
+            }
+        };
+        let selected = investigation_queue::dequeue(&queue, self.max_targets, Utc::now());
+        stats.queue_depth = queue.len().saturating_sub(selected.len()) as u32;
+        if selected.is_empty() {
+            info!("No investigation targets queued");
             return stats;
         }
+        let targets = match self.writer.claim_investigations(&selected).await {
+            Ok(t) => t,
+            Err(e) => {
+                warn!(error = %e, "Failed to claim investigation targets");
+                return stats;
+            }
+        };
+        info!(count = targets.len(), queue_depth = stats.queue_depth, "Investigation targets dequeued");
 
-        // Take up to MAX_SIGNALS_INVESTIGATED, respecting per-domain dedup
-        // (the Cypher already does per-domain dedup, but cap total count here)
-        let targets: Vec<_> = targets.into_iter().take(MAX_SIGNALS_INVESTIGATED).collect();
-        info!(count = targets.len(), "Investigation targets selected");
-
+        let mut started = 0;
         for target in &targets {
             if self.cancelled.load(Ordering::Relaxed) {
                 info!("Investigation cancelled");
@@ -175,9 +307,25 @@ impl<'a> Investigator<'a> {
                 info!("Search query budget exhausted, stopping investigation");
                 break;
             }
+            started += 1;
+
+            let confidence_before = match self
+                .writer
+                .get_signal_confidence(target.signal_id, target.node_type)
+                .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    warn!(signal_id = %target.signal_id, error = %e, "Failed to read signal confidence");
+                    stats.targets_failed += 1;
+                    let _ = self.writer.fail_investigation(target.signal_id, &e.to_string()).await;
+                    continue;
+                }
+            };
 
             match self.investigate_signal(target, &mut stats).await {
-                Ok(evidence_count) => {
+                Ok(report) => {
+                    let evidence_count = report.evidence_count();
                     stats.targets_investigated += 1;
                     stats.evidence_created += evidence_count;
                     info!(
@@ -189,18 +337,33 @@ impl<'a> Investigator<'a> {
                     );
 
                     // Revise confidence based on accumulated evidence
-                    if evidence_count > 0 {
-                        self.revise_confidence(target, &mut stats).await;
+                    let confidence_after = if evidence_count > 0 {
+                        self.revise_confidence(target, confidence_before, &mut stats)
+                            .await
+                    } else {
+                        confidence_before
+                    };
+
+                    self.record_report(target, report, confidence_before, confidence_after, &mut stats)
+                        .await;
+                    if let Err(e) = self.writer.complete_investigation(target.signal_id).await {
+                        warn!(signal_id = %target.signal_id, error = %e, "Failed to complete queue entry");
                     }
                 }
                 Err(e) => {
                     stats.targets_failed += 1;
-                    warn!(
-                        signal_id = %target.signal_id,
-                        title = target.title.as_str(),
-                        error = %e,
-                        "Investigation failed for signal"
-                    );
+                    if let Err(qe) = self.writer.fail_investigation(target.signal_id, &e.to_string()).await {
+                        warn!(signal_id = %target.signal_id, error = %qe, "Failed to record queue entry failure");
+                    }
+                    let failed = InvestigationFailed {
+                        phase: "investigation",
+                        target_id: target.signal_id,
+                        reason: failure_reason(&e),
+                        turns: MAX_GRAPH_TOOL_TURNS,
+                        error: format!("{e:#}"),
+                    };
+                    FailureSample::keep(&mut stats.failure_samples, failed.reason, failed.error.clone());
+                    failed.emit();
                 }
             }
 
@@ -214,14 +377,109 @@ impl<'a> Investigator<'a> {
             }
         }
 
+        // Return what this run claimed but didn't start to the queue.
+        let unstarted: Vec<Uuid> = targets[started..].iter().map(|t| t.signal_id).collect();
+        if !unstarted.is_empty() {
+            stats.queue_depth += unstarted.len() as u32;
+            if let Err(e) = self.writer.release_investigations(&unstarted).await {
+                warn!(error = %e, "Failed to release unstarted investigation targets");
+            }
+        }
+
+        stats.graph_lookups = self.graph_budget.used();
         stats
     }
 
+    /// Build an agent that can query the graph, but never write to it.
+    fn build_graph_agent(&self, target: &InvestigationTarget) -> Claude {
+        let graph = GraphLookup::new(
+            self.writer.clone(),
+            self.embedder.clone(),
+            self.graph_budget.clone(),
+            (self.min_lat, self.max_lat, self.min_lng, self.max_lng),
+        );
+        Claude::new(&self.anthropic_api_key, HAIKU_MODEL)
+            .tool(FindSimilarSignalsTool {
+                graph: graph.clone(),
+                exclude_id: Some(target.signal_id),
+            })
+            .tool(ActorHistoryTool {
+                graph: graph.clone(),
+            })
+            .tool(SituationContextTool { graph })
+    }
+
+    /// Ask the graph what it already knows about the target, so queries and
+    /// evidence evaluation build on it. Non-fatal — returns None on failure,
+    /// when the graph has nothing, or once the run's lookup budget is spent.
+    async fn consult_graph(&self, target: &InvestigationTarget) -> Option<String> {
+        if self.graph_budget.is_exhausted() {
+            return None;
+        }
+
+        let user = format!(
+            "Signal id: {}\nSignal type: {}\nTitle: {}\nSummary: {}\nSource URL: {}\nCity: {}",
+            target.signal_id, target.node_type, target.title, target.summary, target.source_url,
+            self.region,
+        );
+
+        match self
+            .build_graph_agent(target)
+            .prompt(&user)
+            .preamble(GRAPH_CONSULTATION_SYSTEM)
+            .temperature(0.0)
+            .multi_turn(MAX_GRAPH_TOOL_TURNS)
+            .send()
+            .await
+        {
+            Ok(briefing) => graph_briefing(&briefing),
+            Err(e) => {
+                warn!(signal_id = %target.signal_id, error = %e, "Graph consultation failed (non-fatal)");
+                None
+            }
+        }
+    }
+
+    /// Persist the investigation as an `Investigation` node. Non-fatal.
+    async fn record_report(
+        &self,
+        target: &InvestigationTarget,
+        report: SignalInvestigation,
+        confidence_before: f32,
+        confidence_after: f32,
+        stats: &mut InvestigationStats,
+    ) {
+        let investigation = Investigation {
+            id: Uuid::new_v4(),
+            signal_id: target.signal_id,
+            question: investigation_question(&target.node_type.to_string(), &target.title),
+            method: report.method(),
+            cost_cents: report.cost_cents(),
+            sources_consulted: consulted_sources(report.sources),
+            findings: report.findings,
+            confidence_before,
+            confidence_after,
+            created_at: Utc::now(),
+        };
+
+        match self.writer.record_investigation(&investigation).await {
+            Ok(true) => stats.reports_recorded += 1,
+            Ok(false) => {
+                warn!(signal_id = %target.signal_id, "Signal gone before its investigation report was stored");
+            }
+            Err(e) => {
+                warn!(signal_id = %target.signal_id, error = %e, "Failed to store investigation report (non-fatal)");
+            }
+        }
+    }
+
     async fn investigate_signal(
         &self,
         target: &InvestigationTarget,
         stats: &mut InvestigationStats,
-    ) -> Result<u32> {
+    ) -> Result<SignalInvestigation> {
+        let mut report = SignalInvestigation::default();
+
         // 1. Generate search queries via LLM
         let system_prompt = if target.is_sensitive {
             format!(
@@ -232,11 +490,20 @@ impl<'a> Investigator<'a> {
             QUERY_GENERATION_SYSTEM.to_string()
         };
 
-        let user_prompt = format!(
+        let graph_context = self.consult_graph(target).await;
+        report.graph_consulted = graph_context.is_some();
+
+        let mut user_prompt = format!(
             "Signal type: {}\nTitle: {}\nSummary: {}\nSource URL: {}\nCity: {}",
             target.node_type, target.title, target.summary, target.source_url, self.region,
         );
+        if let Some(context) = &graph_context {
+            user_prompt.push_str(&format!(
+                "\n\nAlready known from the graph (search for what this does not cover):\n{context}"
+            ));
+        }
 
+        report.llm_calls += 1;
         let queries: InvestigationQueries = self
             .claude
             .extract(HAIKU_MODEL, &system_prompt, &user_prompt)
@@ -248,7 +515,7 @@ impl<'a> Investigator<'a> {
             .take(MAX_QUERIES_PER_SIGNAL)
             .collect();
         if queries.is_empty() {
-            return Ok(0);
+            return Ok(report);
         }
 
         // 2. Execute web searches (budget-limited)
@@ -260,6 +527,7 @@ impl<'a> Investigator<'a> {
                 break;
             }
             stats.search_queries_used += 1;
+            report.queries.push(query.clone());
 
             match async {
                 let handle = self.archive.source(query).await.map_err(|e| anyhow::anyhow!("{e}"))?;
@@ -284,8 +552,9 @@ impl<'a> Investigator<'a> {
         }
 
         if all_results.is_empty() {
-            return Ok(0);
+            return Ok(report);
         }
+        report.sources = all_results.iter().map(|r| r.url.clone()).collect();
 
         // 3. LLM evaluates results
         let results_text: String = all_results
@@ -303,11 +572,15 @@ impl<'a> Investigator<'a> {
             .collect::<Vec<_>>()
             .join("\n\n");
 
-        let eval_user_prompt = format!(
+        let mut eval_user_prompt = format!(
             "Signal: {} — {}\n\nSearch results:\n{}",
             target.title, target.summary, results_text,
         );
+        if let Some(context) = &graph_context {
+            eval_user_prompt.push_str(&format!("\n\nAlready known from the graph:\n{context}"));
+        }
 
+        report.llm_calls += 1;
         let evaluation: EvidenceEvaluation = self
             .claude
             .extract(HAIKU_MODEL, EVIDENCE_EVALUATION_SYSTEM, &eval_user_prompt)
@@ -315,7 +588,7 @@ impl<'a> Investigator<'a> {
 
         // 4. Create EvidenceNodes for items with confidence >= 0.5
         let now = Utc::now();
-        let mut evidence_count = 0u32;
+        let circular_before = stats.circular_citations;
 
         for item in evaluation.evidence {
             if item.confidence < 0.5 {
@@ -323,16 +596,21 @@ impl<'a> Investigator<'a> {
             }
 
             let content_hash = format!("{:x}", content_hash(&item.source_url));
-            let relevance = item.relevance;
+            let relevance = self
+                .check_provenance(&target.source_url, &item.source_url, item.relevance, stats)
+                .await;
             let evidence = EvidenceNode {
                 id: Uuid::new_v4(),
                 source_url: item.source_url.clone(),
                 retrieved_at: now,
                 content_hash,
-                snippet: Some(item.snippet),
+                snippet: Some(item.snippet.clone()),
                 relevance: Some(relevance.clone()),
                 evidence_confidence: Some(item.confidence as f32),
                 channel_type: Some(rootsignal_common::channel_type(&item.source_url)),
+                change_summary: None,
+                engagement_velocity: None,
+                verification: None,
             };
 
             match self
@@ -341,7 +619,12 @@ impl<'a> Investigator<'a> {
                 .await
             {
                 Ok(()) => {
-                    evidence_count += 1;
+                    report.findings.push(InvestigationFinding {
+                        source_url: item.source_url.clone(),
+                        relevance: relevance.clone(),
+                        confidence: item.confidence as f32,
+                        snippet: item.snippet,
+                    });
                     info!(
                         signal_id = %target.signal_id,
                         evidence_url = item.source_url.as_str(),
@@ -361,15 +644,44 @@ impl<'a> Investigator<'a> {
             }
         }
 
-        Ok(evidence_count)
+        report.circular_citations = stats.circular_citations - circular_before;
+        Ok(report)
     }
 
-    /// Revise signal confidence based on accumulated evidence.
+    /// A page in a citation loop with the signal's own source only repeats
+    /// it, so DIRECT evidence from one is demoted to SUPPORTING.
+    async fn check_provenance(
+        &self,
+        source_url: &str,
+        evidence_url: &str,
+        relevance: String,
+        stats: &mut InvestigationStats,
+    ) -> String {
+        if relevance != "DIRECT" {
+            return relevance;
+        }
+        match self.archive.citation_loop(source_url, evidence_url).await {
+            Ok(true) => {
+                stats.circular_citations += 1;
+                info!(source_url, evidence_url, "Circular citation, evidence demoted to SUPPORTING");
+                "SUPPORTING".to_string()
+            }
+            Ok(false) => relevance,
+            Err(e) => {
+                warn!(evidence_url, error = %e, "Failed to check evidence provenance");
+                relevance
+            }
+        }
+    }
+
+    /// Revise signal confidence based on accumulated evidence. Returns the
+    /// confidence the signal ends up with.
     async fn revise_confidence(
         &self,
         target: &InvestigationTarget,
+        old_confidence: f32,
         stats: &mut InvestigationStats,
-    ) {
+    ) -> f32 {
         let evidence = match self
             .writer
             .get_evidence_summary(target.signal_id, target.node_type)
@@ -378,34 +690,22 @@ impl<'a> Investigator<'a> {
             Ok(e) => e,
             Err(e) => {
                 warn!(signal_id = %target.signal_id, error = %e, "Failed to get evidence summary for confidence revision");
-                return;
+                return old_confidence;
             }
         };
 
         if evidence.is_empty() {
-            return;
+            return old_confidence;
         }
 
         let adjustment = compute_confidence_adjustment(&evidence);
         if adjustment.abs() < f32::EPSILON {
-            return;
+            return old_confidence;
         }
 
-        let old_confidence = match self
-            .writer
-            .get_signal_confidence(target.signal_id, target.node_type)
-            .await
-        {
-            Ok(c) => c,
-            Err(e) => {
-                warn!(signal_id = %target.signal_id, error = %e, "Failed to read signal confidence");
-                return;
-            }
-        };
-
         let new_confidence = (old_confidence + adjustment).clamp(0.1, 1.0);
         if (new_confidence - old_confidence).abs() < f32::EPSILON {
-            return;
+            return old_confidence;
         }
 
         if let Err(e) = self
@@ -414,10 +714,23 @@ impl<'a> Investigator<'a> {
             .await
         {
             warn!(signal_id = %target.signal_id, error = %e, "Failed to update signal confidence");
-            return;
+            return old_confidence;
         }
 
         stats.confidence_adjustments += 1;
+        let counted: Vec<(&str, f32)> = evidence.iter().map(|e| (e.relevance.as_str(), e.confidence)).collect();
+        let decision = Decision::new(
+            DecisionKind::ConfidenceChange,
+            target.signal_id,
+            target.node_type,
+            "investigator",
+            format!("confidence {old_confidence:.2} -> {new_confidence:.2}"),
+        )
+        .with_inputs(&counted)
+        .with_rationale(format!("Adjusted by {adjustment:+.2} from {} pieces of evidence", evidence.len()));
+        if let Err(e) = self.writer.record_decision(&decision).await {
+            warn!(signal_id = %target.signal_id, error = %e, "Failed to record confidence decision");
+        }
         info!(
             signal_id = %target.signal_id,
             old_confidence,
@@ -426,6 +739,7 @@ impl<'a> Investigator<'a> {
             evidence_count = evidence.len(),
             "Signal confidence revised"
         );
+        new_confidence
     }
 }
 
@@ -458,6 +772,16 @@ pub fn compute_confidence_adjustment(evidence: &[EvidenceSummary]) -> f32 {
     direct_boost + supporting_boost - contradicting_penalty
 }
 
+/// Normalize the graph agent's reply; an empty or NOTHING reply means no context.
+fn graph_briefing(reply: &str) -> Option<String> {
+    let reply = reply.trim();
+    if reply.is_empty() || reply.eq_ignore_ascii_case("nothing") {
+        None
+    } else {
+        Some(reply.to_string())
+    }
+}
+
 /// Extract domain from a URL for same-domain filtering.
 fn extract_domain(url: &str) -> String {
     url::Url::parse(url)
@@ -484,6 +808,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn report_method_lists_steps_in_order() {
+        let report = SignalInvestigation {
+            graph_consulted: true,
+            queries: vec!["elm towers rent".to_string(), "elm towers tenants union".to_string()],
+            sources: vec!["https://news.example/a".to_string(); 4],
+            llm_calls: 3,
+            circular_citations: 1,
+            ..Default::default()
+        };
+
+        let method = report.method();
+
+        assert_eq!(
+            method,
+            "Consulted the graph for prior knowledge; searched the web for \"elm towers rent\", \
+             \"elm towers tenants union\"; judged 4 off-domain results for relevance; \
+             demoted 1 circular citation(s) to SUPPORTING"
+        );
+        assert_eq!(report.cost_cents(), 5);
+    }
+
+    #[test]
+    fn graph_briefing_treats_nothing_reply_as_no_context() {
+        assert_eq!(graph_briefing("  NOTHING\n"), None);
+        assert_eq!(graph_briefing(""), None);
+        assert_eq!(
+            graph_briefing("- Similar signal already has 2 DIRECT evidence\n"),
+            Some("- Similar signal already has 2 DIRECT evidence".to_string())
+        );
+    }
+
     #[test]
     fn confidence_adjustment_direct_evidence_boosts() {
         // 3 DIRECT at 0.8 → +0.05 * 3 = +0.15 (capped at 0.15)
@@ -1,7 +1,10 @@
 pub mod agent_tools;
 pub mod bootstrap;
+pub mod event_dedup;
 pub mod gathering_finder;
+pub mod information_puller;
 pub mod investigator;
+pub mod query_ledger;
 pub mod response_finder;
 pub mod source_finder;
 pub mod tension_linker;
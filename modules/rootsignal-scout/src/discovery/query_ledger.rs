@@ -0,0 +1,371 @@
+//! Discovery query performance ledger.
+//!
+//! Every web query discovery generates (bootstrap seeds, curiosity-engine
+//! queries, mechanical gap templates, signal expansion) is registered here
+//! with the pattern that produced it. After each run the ledger is credited
+//! with the signals each query got accepted into the graph. Past winners seed
+//! the curiosity engine's refinements, and patterns that consistently come up
+//! empty are retired so nothing generates them again.
+//!
+//! Retirements, refinements and skips are recorded in `query_decisions`, one
+//! row each, so they can be inspected after the fact.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::PgPool;
+
+/// Mechanical gap analysis: "<what would help> resources services <region>".
+pub const GAP_TEMPLATE_PATTERN: &str = "template:resources_services";
+/// Implied queries from extracted signals.
+pub const EXPANSION_PATTERN: &str = "expansion:implied";
+
+/// A pattern needs this many queries before it can be retired...
+const RETIRE_MIN_QUERIES: u32 = 3;
+/// ...and this many runs across them.
+const RETIRE_MIN_RUNS: u32 = 12;
+/// Accepted signals per run below which a pattern is unproductive.
+const RETIRE_MAX_YIELD: f64 = 0.05;
+/// A query needs this many runs before it can count as a winner.
+const WINNER_MIN_RUNS: u32 = 2;
+
+/// Pattern for a cold-start seed query, by the role it was generated for.
+pub fn bootstrap_pattern(role: &str) -> String {
+    format!("bootstrap:{role}")
+}
+
+/// Pattern for a curiosity-engine query of `gap_type`.
+pub fn curiosity_pattern(gap_type: &str) -> String {
+    format!("curiosity:{gap_type}")
+}
+
+/// Pattern for a curiosity-engine query that refines a past winner.
+pub fn refined_pattern(gap_type: &str) -> String {
+    format!("refined:{gap_type}")
+}
+
+/// A query as the ledger knows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryRecord {
+    pub canonical_key: String,
+    pub query: String,
+    pub pattern: String,
+    /// The winning query this one was seeded by.
+    pub refined_from: Option<String>,
+    pub runs: u32,
+    pub accepted_signals: u32,
+}
+
+impl QueryRecord {
+    /// Accepted signals per run.
+    pub fn yield_per_run(&self) -> f64 {
+        if self.runs == 0 {
+            0.0
+        } else {
+            self.accepted_signals as f64 / self.runs as f64
+        }
+    }
+}
+
+/// A newly generated query to register.
+#[derive(Debug, Clone)]
+pub struct NewQuery {
+    pub canonical_key: String,
+    pub query: String,
+    pub pattern: String,
+    pub refined_from: Option<String>,
+}
+
+/// Totals for one pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternStats {
+    pub pattern: String,
+    pub queries: u32,
+    pub runs: u32,
+    pub accepted_signals: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionKind {
+    /// The pattern stops generating queries.
+    RetirePattern,
+    /// A query was generated as a refinement of a past winner.
+    Refine,
+    /// A generated query was dropped because its pattern is retired.
+    SkipRetired,
+}
+
+impl DecisionKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::RetirePattern => "retire_pattern",
+            Self::Refine => "refine",
+            Self::SkipRetired => "skip_retired",
+        }
+    }
+}
+
+/// One recorded optimizer decision.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryDecision {
+    pub kind: DecisionKind,
+    pub pattern: String,
+    pub query: Option<String>,
+    pub reason: String,
+}
+
+/// Sum query records by pattern, in pattern order.
+pub fn pattern_stats(records: &[QueryRecord]) -> Vec<PatternStats> {
+    let mut by_pattern: BTreeMap<&str, PatternStats> = BTreeMap::new();
+    for r in records {
+        let stats = by_pattern.entry(r.pattern.as_str()).or_insert_with(|| PatternStats {
+            pattern: r.pattern.clone(),
+            queries: 0,
+            runs: 0,
+            accepted_signals: 0,
+        });
+        stats.queries += 1;
+        stats.runs += r.runs;
+        stats.accepted_signals += r.accepted_signals;
+    }
+    by_pattern.into_values().collect()
+}
+
+/// Patterns that have had a fair chance and still yield (almost) nothing.
+pub fn patterns_to_retire(stats: &[PatternStats], retired: &HashSet<String>) -> Vec<QueryDecision> {
+    stats
+        .iter()
+        .filter(|s| !retired.contains(&s.pattern))
+        .filter(|s| s.queries >= RETIRE_MIN_QUERIES && s.runs >= RETIRE_MIN_RUNS)
+        .filter(|s| (s.accepted_signals as f64 / s.runs as f64) < RETIRE_MAX_YIELD)
+        .map(|s| QueryDecision {
+            kind: DecisionKind::RetirePattern,
+            pattern: s.pattern.clone(),
+            query: None,
+            reason: format!(
+                "{} accepted signals from {} queries over {} runs",
+                s.accepted_signals, s.queries, s.runs
+            ),
+        })
+        .collect()
+}
+
+/// The `n` best-yielding queries that have run enough to judge.
+pub fn winning_queries(records: &[QueryRecord], n: usize) -> Vec<QueryRecord> {
+    let mut winners: Vec<&QueryRecord> = records
+        .iter()
+        .filter(|r| r.runs >= WINNER_MIN_RUNS && r.accepted_signals > 0)
+        .collect();
+    winners.sort_by(|a, b| {
+        b.yield_per_run()
+            .total_cmp(&a.yield_per_run())
+            .then(b.accepted_signals.cmp(&a.accepted_signals))
+    });
+    winners.into_iter().take(n).cloned().collect()
+}
+
+/// The ledger for one region, backed by Postgres.
+#[derive(Clone)]
+pub struct QueryLedger {
+    pool: PgPool,
+    region: String,
+    run_id: Option<String>,
+}
+
+impl QueryLedger {
+    pub fn new(pool: PgPool, region: &str) -> Self {
+        Self {
+            pool,
+            region: region.to_string(),
+            run_id: None,
+        }
+    }
+
+    /// Attribute recorded decisions to a scout run.
+    pub fn for_run(mut self, run_id: &str) -> Self {
+        self.run_id = Some(run_id.to_string());
+        self
+    }
+
+    /// Register a generated query. A query already in the ledger keeps its
+    /// original pattern and history.
+    pub async fn register(&self, query: &NewQuery) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO discovery_queries (region, canonical_key, query, pattern, refined_from)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (region, canonical_key) DO NOTHING
+            "#,
+        )
+        .bind(&self.region)
+        .bind(&query.canonical_key)
+        .bind(&query.query)
+        .bind(&query.pattern)
+        .bind(&query.refined_from)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Credit one run and its accepted signals to each ledger query that was
+    /// searched this run. Keys the ledger doesn't know are ignored.
+    pub async fn record_runs(&self, accepted: &HashMap<String, u32>) -> Result<()> {
+        if accepted.is_empty() {
+            return Ok(());
+        }
+        let (keys, counts): (Vec<String>, Vec<i32>) =
+            accepted.iter().map(|(k, n)| (k.clone(), *n as i32)).unzip();
+        sqlx::query(
+            r#"
+            UPDATE discovery_queries q
+            SET runs = q.runs + 1,
+                accepted_signals = q.accepted_signals + r.accepted,
+                last_run_at = now()
+            FROM UNNEST($2::text[], $3::int[]) AS r(canonical_key, accepted)
+            WHERE q.region = $1 AND q.canonical_key = r.canonical_key
+            "#,
+        )
+        .bind(&self.region)
+        .bind(&keys)
+        .bind(&counts)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Every query in the region's ledger.
+    pub async fn records(&self) -> Result<Vec<QueryRecord>> {
+        let rows = sqlx::query_as::<_, (String, String, String, Option<String>, i32, i32)>(
+            r#"
+            SELECT canonical_key, query, pattern, refined_from, runs, accepted_signals
+            FROM discovery_queries WHERE region = $1
+            "#,
+        )
+        .bind(&self.region)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| QueryRecord {
+                canonical_key: r.0,
+                query: r.1,
+                pattern: r.2,
+                refined_from: r.3,
+                runs: r.4.max(0) as u32,
+                accepted_signals: r.5.max(0) as u32,
+            })
+            .collect())
+    }
+
+    /// Patterns retired in this region.
+    pub async fn retired_patterns(&self) -> Result<HashSet<String>> {
+        let patterns = sqlx::query_scalar::<_, String>(
+            "SELECT DISTINCT pattern FROM query_decisions WHERE region = $1 AND decision = 'retire_pattern'",
+        )
+        .bind(&self.region)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(patterns.into_iter().collect())
+    }
+
+    pub async fn record_decision(&self, decision: &QueryDecision) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO query_decisions (region, run_id, decision, pattern, query, reason)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(&self.region)
+        .bind(&self.run_id)
+        .bind(decision.kind.as_str())
+        .bind(&decision.pattern)
+        .bind(&decision.query)
+        .bind(&decision.reason)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Retire the patterns that have proven unproductive. Returns the new
+    /// retirements, already recorded.
+    pub async fn review(&self) -> Result<Vec<QueryDecision>> {
+        let records = self.records().await?;
+        let retired = self.retired_patterns().await?;
+        let decisions = patterns_to_retire(&pattern_stats(&records), &retired);
+        for decision in &decisions {
+            self.record_decision(decision).await?;
+        }
+        Ok(decisions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(query: &str, pattern: &str, runs: u32, accepted: u32) -> QueryRecord {
+        QueryRecord {
+            canonical_key: query.to_string(),
+            query: query.to_string(),
+            pattern: pattern.to_string(),
+            refined_from: None,
+            runs,
+            accepted_signals: accepted,
+        }
+    }
+
+    #[test]
+    fn stats_sum_by_pattern() {
+        let records = vec![
+            record("a", "curiosity:novel_angle", 3, 0),
+            record("b", "curiosity:novel_angle", 2, 1),
+            record("c", GAP_TEMPLATE_PATTERN, 4, 6),
+        ];
+        let stats = pattern_stats(&records);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[0],
+            PatternStats { pattern: "curiosity:novel_angle".into(), queries: 2, runs: 5, accepted_signals: 1 }
+        );
+    }
+
+    #[test]
+    fn only_tried_and_unproductive_patterns_retire() {
+        let records = vec![
+            // Enough queries and runs, nothing accepted: retire.
+            record("a", "curiosity:novel_angle", 5, 0),
+            record("b", "curiosity:novel_angle", 5, 0),
+            record("c", "curiosity:novel_angle", 5, 0),
+            // Just as empty, but too few queries to judge.
+            record("d", "curiosity:signal_imbalance", 10, 0),
+            record("e", "curiosity:signal_imbalance", 10, 0),
+            // Productive.
+            record("f", GAP_TEMPLATE_PATTERN, 6, 4),
+            record("g", GAP_TEMPLATE_PATTERN, 6, 0),
+            record("h", GAP_TEMPLATE_PATTERN, 6, 0),
+        ];
+        let decisions = patterns_to_retire(&pattern_stats(&records), &HashSet::new());
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].kind, DecisionKind::RetirePattern);
+        assert_eq!(decisions[0].pattern, "curiosity:novel_angle");
+        assert_eq!(decisions[0].reason, "0 accepted signals from 3 queries over 15 runs");
+
+        let retired = HashSet::from(["curiosity:novel_angle".to_string()]);
+        assert!(patterns_to_retire(&pattern_stats(&records), &retired).is_empty());
+    }
+
+    #[test]
+    fn winners_rank_by_yield_per_run() {
+        let records = vec![
+            record("steady", "curiosity:unmet_tension", 10, 10),
+            record("sharp", "refined:unmet_tension", 2, 6),
+            record("untested", "curiosity:unmet_tension", 1, 9),
+            record("empty", GAP_TEMPLATE_PATTERN, 8, 0),
+        ];
+        let winners: Vec<String> = winning_queries(&records, 5).into_iter().map(|r| r.query).collect();
+        assert_eq!(winners, vec!["sharp", "steady"]);
+        assert_eq!(winning_queries(&records, 1).len(), 1);
+    }
+}
@@ -11,20 +11,23 @@ use serde::Deserialize;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use rootsignal_common::error_budget::{FailureSample, OperationTally};
+use rootsignal_common::log_events::{failure_reason, InvestigationFailed};
 use rootsignal_common::{
     canonical_value, AidNode, DiscoveryMethod, GatheringNode, GeoPoint, GeoPrecision, NeedNode, Node,
-    NodeMeta, NodeType, ScoutScope, SensitivityLevel, Severity, SourceNode, SourceRole, TensionNode, Urgency,
+    NodeMeta, NodeType, ScoutScope, SensitivityLevel, Severity, SourceNode, SourceRole, TensionNode, TrialState, Urgency,
 };
 use rootsignal_graph::{GraphWriter, ResponseFinderTarget, ResponseHeuristic, SituationBrief};
 
 use rootsignal_archive::Archive;
 
 use crate::infra::embedder::TextEmbedder;
+use crate::memory::memo::RegionMemo;
 use crate::pipeline::extractor::ResourceTag;
 use crate::discovery::agent_tools::{ReadPageTool, WebSearchTool};
 
 const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
-const MAX_RESPONSE_TARGETS_PER_RUN: usize = 5;
+pub const MAX_RESPONSE_TARGETS_PER_RUN: usize = 5;
 const MAX_TOOL_TURNS: usize = 10;
 const MAX_RESPONSES_PER_TENSION: usize = 8;
 const MAX_FUTURE_QUERIES_PER_TENSION: usize = 3;
@@ -91,6 +94,9 @@ pub struct EmergentTension {
 pub struct ResponseFinderStats {
     pub targets_found: u32,
     pub targets_investigated: u32,
+    /// Targets whose investigation errored, with the first few errors.
+    pub targets_failed: u32,
+    pub failure_samples: Vec<FailureSample>,
     pub responses_discovered: u32,
     pub responses_deduped: u32,
     pub signals_created: u32,
@@ -99,15 +105,28 @@ pub struct ResponseFinderStats {
     pub future_sources_created: u32,
 }
 
+impl ResponseFinderStats {
+    /// This run's attempts and failures, for error budgets.
+    pub fn tally(&self) -> OperationTally {
+        OperationTally::new(
+            "response_finder",
+            self.targets_investigated + self.targets_failed,
+            self.targets_failed,
+            self.failure_samples.clone(),
+        )
+    }
+}
+
 impl std::fmt::Display for ResponseFinderStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Response finder: {} targets found, {} investigated, \
+            "Response finder: {} targets found, {} investigated, {} failed, \
              {} responses discovered ({} deduped), {} signals created, \
              {} edges, {} emergent tensions, {} future sources",
             self.targets_found,
             self.targets_investigated,
+            self.targets_failed,
             self.responses_discovered,
             self.responses_deduped,
             self.signals_created,
@@ -186,6 +205,13 @@ fn investigation_user_prompt(
     if let Some(ref cat) = target.category {
         prompt.push_str(&format!("\nCategory: {cat}"));
     }
+    if target.escalated {
+        prompt.push_str(
+            "\n\nESCALATED: this tension has gone without meaningful responses for over a week. \
+             Look past the obvious — mutual aid networks, faith groups, tenant and neighborhood \
+             associations, city and county programs.",
+        );
+    }
 
     if !existing.is_empty() {
         prompt.push_str("\n\nEXISTING RESPONSES (hints about what categories exist):");
@@ -284,6 +310,10 @@ pub struct ResponseFinder<'a> {
     max_lng: f64,
     cancelled: Arc<AtomicBool>,
     run_id: String,
+    /// The region memo as a prompt block, appended to the investigation prompt.
+    region_memo: Option<String>,
+    /// Targets per run, at most `MAX_RESPONSE_TARGETS_PER_RUN`.
+    max_targets: usize,
 }
 
 impl<'a> ResponseFinder<'a> {
@@ -296,25 +326,38 @@ impl<'a> ResponseFinder<'a> {
         cancelled: Arc<AtomicBool>,
         run_id: String,
     ) -> Self {
-        let lat_delta = region.radius_km / 111.0;
-        let lng_delta = region.radius_km / (111.0 * region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
         let region_slug = region.name.clone();
         Self {
             writer,
             anthropic_api_key: anthropic_api_key.to_string(),
             archive,
             embedder,
-            min_lat: region.center_lat - lat_delta,
-            max_lat: region.center_lat + lat_delta,
-            min_lng: region.center_lng - lng_delta,
-            max_lng: region.center_lng + lng_delta,
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
             region,
             _region_slug: region_slug,
             cancelled,
             run_id,
+            region_memo: None,
+            max_targets: MAX_RESPONSE_TARGETS_PER_RUN,
         }
     }
 
+    /// Cap targets this run, e.g. to what the budget allocated.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.max_targets = max_targets.min(MAX_RESPONSE_TARGETS_PER_RUN);
+        self
+    }
+
+    /// Append the region memo to the investigation system prompt.
+    pub fn with_region_memo(mut self, memo: &RegionMemo) -> Self {
+        self.region_memo = memo.prompt_block(&self.region.name);
+        self
+    }
+
     /// Build a Claude agent with URL tracking for a single investigation.
     fn build_tracked_agent(&self) -> (Claude, Arc<Mutex<HashSet<String>>>) {
         let visited = Arc::new(Mutex::new(HashSet::new()));
@@ -335,7 +378,7 @@ impl<'a> ResponseFinder<'a> {
         let targets = match self
             .writer
             .find_response_finder_targets(
-                MAX_RESPONSE_TARGETS_PER_RUN as u32,
+                self.max_targets as u32,
                 self.min_lat,
                 self.max_lat,
                 self.min_lng,
@@ -378,12 +421,16 @@ impl<'a> ResponseFinder<'a> {
                     stats.targets_investigated += 1;
                 }
                 Err(e) => {
-                    warn!(
-                        tension_id = %target.tension_id,
-                        title = target.title.as_str(),
-                        error = %e,
-                        "Response scout investigation failed"
-                    );
+                    stats.targets_failed += 1;
+                    let failed = InvestigationFailed {
+                        phase: "response_finder",
+                        target_id: target.tension_id,
+                        reason: failure_reason(&e),
+                        turns: MAX_TOOL_TURNS,
+                        error: format!("{e:#}"),
+                    };
+                    FailureSample::keep(&mut stats.failure_samples, failed.reason, failed.error.clone());
+                    failed.emit();
                 }
             }
 
@@ -413,7 +460,11 @@ impl<'a> ResponseFinder<'a> {
             .await
             .unwrap_or_default();
 
-        let system = investigation_system_prompt(&self.region.name);
+        let mut system = investigation_system_prompt(&self.region.name);
+        if let Some(memo) = &self.region_memo {
+            system.push_str("\n\n");
+            system.push_str(memo);
+        }
         let user = investigation_user_prompt(target, &existing, situation_context);
 
         // Build a tracked agent for this investigation
@@ -422,7 +473,7 @@ impl<'a> ResponseFinder<'a> {
         // Phase 1: Agentic investigation with web_search + read_page tools
         let reasoning = claude
             .prompt(&user)
-            .preamble(&system)
+            .cacheable_prefix(&system)
             .temperature(0.7)
             .multi_turn(MAX_TOOL_TURNS)
             .send()
@@ -711,6 +762,9 @@ impl<'a> ResponseFinder<'a> {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
 
         let node = match response.signal_type.to_lowercase().as_str() {
@@ -735,6 +789,7 @@ impl<'a> ResponseFinder<'a> {
                 what_needed: Some(response.summary.clone()),
                 action_url: Some(response.url.clone()),
                 goal: None,
+                fundraiser: None,
             }),
             _ => Node::Aid(AidNode {
                 meta,
@@ -768,17 +823,10 @@ impl<'a> ResponseFinder<'a> {
         also_addresses: &[String],
         explanation: &str,
     ) -> Result<()> {
-        let lat_delta = self.region.radius_km / 111.0;
-        let lng_delta =
-            self.region.radius_km / (111.0 * self.region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = self.region.bounding_box();
         let active_tensions = self
             .writer
-            .get_active_tensions(
-                self.region.center_lat - lat_delta,
-                self.region.center_lat + lat_delta,
-                self.region.center_lng - lng_delta,
-                self.region.center_lng + lng_delta,
-            )
+            .get_active_tensions(min_lat, max_lat, min_lng, max_lng)
             .await?;
         if active_tensions.is_empty() {
             return Ok(());
@@ -890,6 +938,9 @@ impl<'a> ResponseFinder<'a> {
                 mentioned_actors: vec![],
                 implied_queries: vec![],
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity,
             category: Some(tension.category.clone()),
@@ -948,6 +999,7 @@ impl<'a> ResponseFinder<'a> {
             quality_penalty: 1.0,
             source_role: SourceRole::Response,
             scrape_count: 0,
+            trial: TrialState::Probation,
         };
 
         self.writer.upsert_source(&source).await?;
@@ -1036,6 +1088,8 @@ mod tests {
         let stats = ResponseFinderStats {
             targets_found: 5,
             targets_investigated: 4,
+            targets_failed: 1,
+            failure_samples: Vec::new(),
             responses_discovered: 12,
             responses_deduped: 3,
             signals_created: 9,
@@ -1051,6 +1105,18 @@ mod tests {
         assert!(display.contains("2 emergent tensions"));
     }
 
+    #[test]
+    fn failed_targets_count_as_attempts_in_the_tally() {
+        let mut stats = ResponseFinderStats { targets_investigated: 3, targets_failed: 1, ..Default::default() };
+        FailureSample::keep(&mut stats.failure_samples, "max_turns", "Max turns (10) exceeded");
+
+        let tally = stats.tally();
+
+        assert_eq!(tally.operation, "response_finder");
+        assert_eq!((tally.attempts, tally.failures), (4, 1));
+        assert_eq!(tally.samples[0].reason, "max_turns");
+    }
+
     #[test]
     fn cosine_similarity_works() {
         let a = vec![1.0, 0.0, 0.0];
@@ -1084,6 +1150,7 @@ mod tests {
             center_lat: 44.9778,
             center_lng: -93.2650,
             radius_km: 30.0,
+            boundary: None,
         };
 
         let now = Utc::now();
@@ -1113,6 +1180,9 @@ mod tests {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
 
         let node = Node::Aid(AidNode {
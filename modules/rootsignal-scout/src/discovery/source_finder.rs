@@ -10,11 +10,27 @@ use rootsignal_graph::{
     StoryBrief, TensionResponseShape, UnmetTension,
 };
 
+use rootsignal_archive::Archive;
+
+use crate::discovery::query_ledger::{
+    self, DecisionKind, NewQuery, QueryDecision, QueryLedger, QueryRecord, GAP_TEMPLATE_PATTERN,
+};
+use crate::memory::memo::RegionMemo;
 use crate::scheduling::budget::{BudgetTracker, OperationCost};
 
 const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
 const MAX_CURIOSITY_QUERIES: usize = 12;
 const MAX_DISCOVERY_DEPTH: u32 = 2;
+/// Past winners shown to the curiosity engine as seeds for refinement.
+const MAX_WINNING_QUERIES: usize = 5;
+
+/// Sources at or above this weight count as trusted linkers in the link graph.
+const TRUSTED_SOURCE_WEIGHT: f64 = 0.5;
+/// A domain needs links from this many trusted domains to become a source.
+const MIN_TRUSTED_LINKERS: u32 = 2;
+const MAX_LINK_GRAPH_SOURCES: usize = 5;
+/// How far back the link graph looks.
+const LINK_GRAPH_WINDOW_DAYS: i64 = 90;
 
 /// Stats from a discovery run.
 #[derive(Debug, Default)]
@@ -23,14 +39,20 @@ pub struct SourceFinderStats {
     pub link_sources: u32,
     pub gap_sources: u32,
     pub duplicates_skipped: u32,
+    /// Generated queries dropped because their pattern is retired.
+    pub retired_skipped: u32,
 }
 
 impl std::fmt::Display for SourceFinderStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Discovery: actors={}, links={}, gaps={}, skipped={}",
-            self.actor_sources, self.link_sources, self.gap_sources, self.duplicates_skipped
+            "Discovery: actors={}, links={}, gaps={}, skipped={}, retired={}",
+            self.actor_sources,
+            self.link_sources,
+            self.gap_sources,
+            self.duplicates_skipped,
+            self.retired_skipped
         )
     }
 }
@@ -51,6 +73,10 @@ pub struct DiscoveryBriefing {
     pub gap_type_stats: Vec<GapTypeStats>,
     pub extraction_yield: Vec<ExtractionYield>,
     pub response_shapes: Vec<TensionResponseShape>,
+    /// Best-yielding past queries, from the query ledger.
+    pub winning_queries: Vec<QueryRecord>,
+    /// Gap types whose curiosity queries have been retired as unproductive.
+    pub retired_gap_types: Vec<String>,
 }
 
 impl DiscoveryBriefing {
@@ -82,6 +108,9 @@ impl DiscoveryBriefing {
                     "   community attention: {} sources, {} corroborations, heat={:.1}\n",
                     t.source_diversity, t.corroboration_count, t.cause_heat,
                 ));
+                if t.escalated {
+                    out.push_str("   ESCALATED: unmet for over a week — find sources that could respond\n");
+                }
             }
             out.push('\n');
         }
@@ -291,6 +320,32 @@ impl DiscoveryBriefing {
             out.push('\n');
         }
 
+        // Query ledger
+        if !self.winning_queries.is_empty() {
+            out.push_str("## WINNING QUERIES (refine these)\n");
+            out.push_str(
+                "These past queries got the most signals accepted. Propose sharper variants —\n",
+            );
+            out.push_str(
+                "another neighborhood, program type or population — and set `refines` to the\n",
+            );
+            out.push_str("query you built on.\n");
+            for w in &self.winning_queries {
+                out.push_str(&format!(
+                    "- \"{}\" → {} accepted signals over {} runs\n",
+                    w.query, w.accepted_signals, w.runs,
+                ));
+            }
+            out.push('\n');
+        }
+        if !self.retired_gap_types.is_empty() {
+            out.push_str("## RETIRED STRATEGIES (do not use)\n");
+            out.push_str(&format!(
+                "Queries with these gap types have consistently produced no accepted signals: {}\n\n",
+                self.retired_gap_types.join(", "),
+            ));
+        }
+
         // Existing queries
         if !self.existing_queries.is_empty() {
             out.push_str("## EXISTING QUERIES (do not duplicate)\n");
@@ -335,6 +390,9 @@ pub struct DiscoveryQuery {
     pub gap_type: String,
     /// Related tension title, if applicable
     pub related_tension: Option<String>,
+    /// The winning query this refines, verbatim, if it builds on one
+    #[serde(default)]
+    pub refines: Option<String>,
 }
 
 /// Handle LLM returning queries as either a proper JSON array, a stringified JSON array, or null.
@@ -402,6 +460,7 @@ mutual aid requests, community forum posts, volunteer calls)\n\
          - Include \"{city_name}\" or neighborhood names — local results only\n\
          - Target organizations, programs, resources — not news articles about problems\n\
          - Avoid queries similar to ones that previously failed\n\
+         - Build on WINNING QUERIES when there are any; never use a RETIRED STRATEGY\n\
          - Be specific: \"affordable housing waitlist programs {city_name}\" not \"housing crisis\"\n\
          \n\
          ENGAGEMENT SIGNAL: Tensions with higher corroboration, source diversity, and\n\
@@ -452,6 +511,10 @@ pub struct SourceFinder<'a> {
     claude: Option<Claude>,
     budget: &'a BudgetTracker,
     embedder: Option<&'a dyn crate::infra::embedder::TextEmbedder>,
+    archive: Option<&'a Archive>,
+    ledger: Option<&'a QueryLedger>,
+    /// The region memo as a prompt block, shown to the curiosity engine.
+    region_memo: Option<String>,
 }
 
 /// Cosine similarity threshold for embedding-based query dedup.
@@ -477,6 +540,9 @@ impl<'a> SourceFinder<'a> {
             claude,
             budget,
             embedder: None,
+            archive: None,
+            ledger: None,
+            region_memo: None,
         }
     }
 
@@ -489,6 +555,25 @@ impl<'a> SourceFinder<'a> {
         self
     }
 
+    /// Set the archive whose link graph suggests domains trusted sources link to.
+    pub fn with_archive(mut self, archive: &'a Archive) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    /// Set the query ledger: generated queries are registered in it, past
+    /// winners seed the curiosity engine, and retired patterns are skipped.
+    pub fn with_ledger(mut self, ledger: &'a QueryLedger) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
+    /// Show the region memo to the curiosity engine.
+    pub fn with_region_memo(mut self, memo: &RegionMemo) -> Self {
+        self.region_memo = memo.prompt_block(&self.region_name);
+        self
+    }
+
     /// Run all discovery triggers. Returns stats and social topics for topic discovery.
     pub async fn run(&self) -> (SourceFinderStats, Vec<String>) {
         let mut stats = SourceFinderStats::default();
@@ -497,7 +582,10 @@ impl<'a> SourceFinder<'a> {
         // 1. Actor-mentioned sources — actors with domains/URLs that aren't tracked
         self.discover_from_actors(&mut stats).await;
 
-        // 2. LLM-driven curiosity engine (with mechanical fallback)
+        // 2. Link graph — domains trusted sources keep linking to
+        self.discover_from_link_graph(&mut stats).await;
+
+        // 3. LLM-driven curiosity engine (with mechanical fallback)
         self.discover_from_curiosity(&mut stats, &mut social_topics)
             .await;
 
@@ -623,6 +711,78 @@ impl<'a> SourceFinder<'a> {
         }
     }
 
+    /// Create sources for untracked domains that several trusted sources link to.
+    async fn discover_from_link_graph(&self, stats: &mut SourceFinderStats) {
+        let Some(archive) = self.archive else {
+            return;
+        };
+        let existing = match self.writer.get_active_sources().await {
+            Ok(s) => s,
+            Err(e) => {
+                warn!(error = %e, "Failed to get existing sources for link graph discovery");
+                return;
+            }
+        };
+        let trusted_urls: Vec<String> = existing
+            .iter()
+            .filter(|s| s.weight * s.quality_penalty >= TRUSTED_SOURCE_WEIGHT)
+            .filter_map(|s| s.url.clone())
+            .collect();
+        if trusted_urls.is_empty() {
+            return;
+        }
+        let existing_keys: HashSet<String> =
+            existing.iter().map(|s| s.canonical_key.clone()).collect();
+
+        let since = chrono::Utc::now() - chrono::Duration::days(LINK_GRAPH_WINDOW_DAYS);
+        let candidates = match archive.link_candidates(&trusted_urls, MIN_TRUSTED_LINKERS, since).await {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Failed to read link graph for discovery");
+                return;
+            }
+        };
+
+        let mut created = 0;
+        for candidate in candidates {
+            if created >= MAX_LINK_GRAPH_SOURCES {
+                break;
+            }
+            let url = format!("https://{}", candidate.domain);
+            let ck = canonical_value(&url);
+            if existing_keys.contains(&ck) {
+                stats.duplicates_skipped += 1;
+                continue;
+            }
+
+            let source = SourceNode::new(
+                ck.clone(),
+                ck,
+                Some(url.clone()),
+                DiscoveryMethod::LinkedFrom,
+                initial_weight_for_method(DiscoveryMethod::LinkedFrom, None),
+                SourceRole::Mixed,
+                Some(format!(
+                    "Linked from {} trusted sources (authority {:.2})",
+                    candidate.trusted_linkers, candidate.authority
+                )),
+            );
+            match self.writer.upsert_source(&source).await {
+                Ok(_) => {
+                    created += 1;
+                    stats.link_sources += 1;
+                    info!(
+                        url,
+                        trusted_linkers = candidate.trusted_linkers,
+                        authority = candidate.authority,
+                        "Discovered source from link graph"
+                    );
+                }
+                Err(e) => warn!(url, error = %e, "Failed to create link-graph source"),
+            }
+        }
+    }
+
     /// LLM-driven curiosity engine with mechanical fallback.
     async fn discover_from_curiosity(
         &self,
@@ -669,7 +829,11 @@ impl<'a> SourceFinder<'a> {
         // LLM call
         let formatted = briefing.format_prompt();
         let system = discovery_system_prompt(&self.region_name);
-        let user = discovery_user_prompt(&self.region_name, &formatted);
+        let mut user = discovery_user_prompt(&self.region_name, &formatted);
+        if let Some(memo) = &self.region_memo {
+            user.push_str("\n\n");
+            user.push_str(memo);
+        }
 
         let plan: DiscoveryPlan = match claude.extract(HAIKU_MODEL, &system, &user).await {
             Ok(p) => p,
@@ -695,6 +859,7 @@ impl<'a> SourceFinder<'a> {
         }
 
         // Create sources from plan
+        let retired = self.retired_patterns().await;
         let existing_queries: HashSet<String> = briefing
             .existing_queries
             .iter()
@@ -704,6 +869,30 @@ impl<'a> SourceFinder<'a> {
         for dq in plan.queries.into_iter().take(MAX_CURIOSITY_QUERIES) {
             let query_lower = dq.query.to_lowercase();
 
+            // Refinements must name a winner the briefing actually showed
+            let refined_from = dq.refines.as_deref().and_then(|refines| {
+                briefing
+                    .winning_queries
+                    .iter()
+                    .find(|w| w.query.eq_ignore_ascii_case(refines.trim()))
+                    .map(|w| w.canonical_key.clone())
+            });
+            let pattern = match refined_from {
+                Some(_) => query_ledger::refined_pattern(&dq.gap_type),
+                None => query_ledger::curiosity_pattern(&dq.gap_type),
+            };
+            if retired.contains(&pattern) {
+                stats.retired_skipped += 1;
+                self.record_decision(QueryDecision {
+                    kind: DecisionKind::SkipRetired,
+                    pattern,
+                    query: Some(dq.query.clone()),
+                    reason: "Curiosity engine proposed a query for a retired gap type".to_string(),
+                })
+                .await;
+                continue;
+            }
+
             // Dedup layer 1: substring overlap with existing queries
             let is_dup = existing_queries
                 .iter()
@@ -790,6 +979,22 @@ impl<'a> SourceFinder<'a> {
                         weight,
                         "LLM discovery: created query source"
                     );
+                    self.register_query(NewQuery {
+                        canonical_key: ck.clone(),
+                        query: dq.query.clone(),
+                        pattern: pattern.clone(),
+                        refined_from: refined_from.clone(),
+                    })
+                    .await;
+                    if let Some(winner) = refined_from {
+                        self.record_decision(QueryDecision {
+                            kind: DecisionKind::Refine,
+                            pattern,
+                            query: Some(dq.query.clone()),
+                            reason: format!("Refines \"{winner}\""),
+                        })
+                        .await;
+                    }
                     // Store query embedding so future runs can dedup against it
                     if let Some(embedder) = self.embedder {
                         if let Ok(embedding) = embedder.embed(&dq.query).await {
@@ -866,6 +1071,28 @@ impl<'a> SourceFinder<'a> {
             .map(|s| s.canonical_value.clone())
             .collect();
 
+        let (winning_queries, retired_gap_types) = match self.ledger {
+            Some(ledger) => {
+                let winners = match ledger.records().await {
+                    Ok(records) => query_ledger::winning_queries(&records, MAX_WINNING_QUERIES),
+                    Err(e) => {
+                        warn!(error = %e, "Failed to load query ledger (non-fatal)");
+                        Vec::new()
+                    }
+                };
+                let mut retired: Vec<String> = self
+                    .retired_patterns()
+                    .await
+                    .iter()
+                    .filter_map(|p| p.strip_prefix("curiosity:"))
+                    .map(str::to_string)
+                    .collect();
+                retired.sort();
+                (winners, retired)
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
         Ok(DiscoveryBriefing {
             tensions,
             stories,
@@ -878,9 +1105,38 @@ impl<'a> SourceFinder<'a> {
             gap_type_stats,
             extraction_yield,
             response_shapes,
+            winning_queries,
+            retired_gap_types,
+        })
+    }
+
+    /// Patterns retired in this region; none without a ledger.
+    async fn retired_patterns(&self) -> HashSet<String> {
+        let Some(ledger) = self.ledger else {
+            return HashSet::new();
+        };
+        ledger.retired_patterns().await.unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to load retired query patterns (non-fatal)");
+            HashSet::new()
         })
     }
 
+    async fn register_query(&self, query: NewQuery) {
+        if let Some(ledger) = self.ledger {
+            if let Err(e) = ledger.register(&query).await {
+                warn!(error = %e, query = query.query.as_str(), "Failed to register query in ledger (non-fatal)");
+            }
+        }
+    }
+
+    async fn record_decision(&self, decision: QueryDecision) {
+        if let Some(ledger) = self.ledger {
+            if let Err(e) = ledger.record_decision(&decision).await {
+                warn!(error = %e, pattern = decision.pattern.as_str(), "Failed to record query decision (non-fatal)");
+            }
+        }
+    }
+
     /// Mechanical template-based gap analysis — the original discovery method.
     /// Used as fallback when LLM is unavailable, budget is exhausted, or on cold start.
     ///
@@ -929,6 +1185,7 @@ impl<'a> SourceFinder<'a> {
             .map(|s| s.canonical_value.to_lowercase())
             .collect();
 
+        let template_retired = self.retired_patterns().await.contains(GAP_TEMPLATE_PATTERN);
         let mut gap_count = 0u32;
         const MAX_GAP_QUERIES: u32 = 5;
 
@@ -950,6 +1207,18 @@ impl<'a> SourceFinder<'a> {
                 continue;
             }
 
+            if template_retired {
+                stats.retired_skipped += 1;
+                self.record_decision(QueryDecision {
+                    kind: DecisionKind::SkipRetired,
+                    pattern: GAP_TEMPLATE_PATTERN.to_string(),
+                    query: Some(query),
+                    reason: "Gap template is retired".to_string(),
+                })
+                .await;
+                continue;
+            }
+
             let cv = query.clone();
             let ck = canonical_value(&cv);
 
@@ -975,6 +1244,13 @@ impl<'a> SourceFinder<'a> {
                         query = source.canonical_value.as_str(),
                         "Created gap analysis query"
                     );
+                    self.register_query(NewQuery {
+                        canonical_key: source.canonical_key.clone(),
+                        query,
+                        pattern: GAP_TEMPLATE_PATTERN.to_string(),
+                        refined_from: None,
+                    })
+                    .await;
                 }
                 Err(e) => warn!(error = %e, "Failed to create gap analysis source"),
             }
@@ -1010,6 +1286,8 @@ mod tests {
             corroboration_count: 0,
             source_diversity: 0,
             cause_heat: 0.0,
+            coverage: None,
+            escalated: false,
         }
     }
 
@@ -1031,6 +1309,8 @@ mod tests {
             corroboration_count,
             source_diversity,
             cause_heat,
+            coverage: None,
+            escalated: false,
         }
     }
 
@@ -1143,6 +1423,8 @@ mod tests {
             extraction_yield: vec![],
             situations: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         }
     }
 
@@ -1286,6 +1568,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         assert!(briefing.is_cold_start());
     }
@@ -1307,6 +1591,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         assert!(
             briefing.is_cold_start(),
@@ -1337,6 +1623,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         assert!(!briefing.is_cold_start());
     }
@@ -1374,6 +1662,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         let prompt = briefing.format_prompt();
         assert!(prompt.contains("affordable housing programs Minneapolis"));
@@ -1403,6 +1693,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         let prompt = briefing.format_prompt();
         assert!(prompt.contains("youth mentorship programs Minneapolis"));
@@ -1425,6 +1717,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         let prompt = briefing.format_prompt();
 
@@ -1460,6 +1754,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         let prompt = briefing.format_prompt();
 
@@ -1505,6 +1801,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         let prompt = briefing.format_prompt();
         assert!(
@@ -1575,6 +1873,17 @@ mod tests {
         assert_eq!(plan.queries.len(), 1);
     }
 
+    #[test]
+    fn discovery_plan_reads_refinements() {
+        let json = r#"{"queries": [
+            {"query": "Northside tenant union Minneapolis", "reasoning": "narrower", "gap_type": "unmet_tension", "related_tension": null, "refines": "tenant union Minneapolis"},
+            {"query": "test", "reasoning": "r", "gap_type": "novel_angle", "related_tension": null}
+        ]}"#;
+        let plan: DiscoveryPlan = serde_json::from_str(json).unwrap();
+        assert_eq!(plan.queries[0].refines.as_deref(), Some("tenant union Minneapolis"));
+        assert!(plan.queries[1].refines.is_none());
+    }
+
     // --- D. Source Creation from Plan ---
 
     #[test]
@@ -1601,6 +1910,7 @@ mod tests {
                 reasoning: "test".to_string(),
                 gap_type: "unmet_tension".to_string(),
                 related_tension: None,
+                refines: None,
             })
             .collect();
         assert_eq!(queries.into_iter().take(MAX_CURIOSITY_QUERIES).count(), MAX_CURIOSITY_QUERIES);
@@ -1768,6 +2078,8 @@ mod tests {
             gap_type_stats: vec![],
             extraction_yield: vec![],
             response_shapes: vec![],
+            winning_queries: vec![],
+            retired_gap_types: vec![],
         };
         assert!(
             briefing.is_cold_start(),
@@ -1850,6 +2162,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn briefing_seeds_refinements_from_winning_queries() {
+        let mut briefing = make_briefing();
+        briefing.winning_queries = vec![QueryRecord {
+            canonical_key: "tenant union Minneapolis".to_string(),
+            query: "tenant union Minneapolis".to_string(),
+            pattern: "curiosity:unmet_tension".to_string(),
+            refined_from: None,
+            runs: 4,
+            accepted_signals: 9,
+        }];
+        briefing.retired_gap_types = vec!["novel_angle".to_string()];
+        let prompt = briefing.format_prompt();
+        assert!(prompt.contains("## WINNING QUERIES (refine these)"));
+        assert!(prompt.contains("- \"tenant union Minneapolis\" → 9 accepted signals over 4 runs"));
+        assert!(prompt.contains("## RETIRED STRATEGIES (do not use)"));
+        assert!(prompt.contains("produced no accepted signals: novel_angle"));
+    }
+
+    #[test]
+    fn briefing_without_ledger_omits_ledger_sections() {
+        let prompt = make_briefing().format_prompt();
+        assert!(!prompt.contains("WINNING QUERIES"));
+        assert!(!prompt.contains("RETIRED STRATEGIES"));
+    }
+
     // --- G. Feedback Loop: Extraction Yield ---
 
     #[test]
@@ -10,6 +10,8 @@ use serde::Deserialize;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use rootsignal_common::error_budget::{FailureSample, OperationTally};
+use rootsignal_common::log_events::{failure_reason, InvestigationFailed};
 use rootsignal_common::{
     ScoutScope, GeoPoint, GeoPrecision, Node, NodeMeta, NodeType, SensitivityLevel, Severity,
     TensionNode,
@@ -22,7 +24,7 @@ use crate::infra::embedder::TextEmbedder;
 use super::agent_tools::{ReadPageTool, WebSearchTool};
 
 const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
-const MAX_TENSION_LINKER_TARGETS_PER_RUN: u32 = 10;
+pub const MAX_TENSION_LINKER_TARGETS_PER_RUN: u32 = 10;
 const MAX_TOOL_TURNS: usize = 8;
 const MAX_TENSIONS_PER_SIGNAL: usize = 3;
 
@@ -62,20 +64,36 @@ pub struct DiscoveredTension {
 pub struct TensionLinkerStats {
     pub targets_found: u32,
     pub targets_investigated: u32,
+    /// Targets whose investigation errored, with the first few errors.
+    pub targets_failed: u32,
+    pub failure_samples: Vec<FailureSample>,
     pub targets_skipped: u32,
     pub tensions_discovered: u32,
     pub tensions_deduplicated: u32,
     pub edges_created: u32,
 }
 
+impl TensionLinkerStats {
+    /// This run's attempts and failures, for error budgets.
+    pub fn tally(&self) -> OperationTally {
+        OperationTally::new(
+            "tension_linker",
+            self.targets_investigated + self.targets_failed,
+            self.targets_failed,
+            self.failure_samples.clone(),
+        )
+    }
+}
+
 impl std::fmt::Display for TensionLinkerStats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Tension linker: {} targets found, {} investigated, {} skipped, \
+            "Tension linker: {} targets found, {} investigated, {} failed, {} skipped, \
              {} tensions discovered ({} deduped), {} edges created",
             self.targets_found,
             self.targets_investigated,
+            self.targets_failed,
             self.targets_skipped,
             self.tensions_discovered,
             self.tensions_deduplicated,
@@ -178,6 +196,8 @@ pub struct TensionLinker<'a> {
     max_lng: f64,
     cancelled: Arc<AtomicBool>,
     run_id: String,
+    /// Targets per run, at most `MAX_TENSION_LINKER_TARGETS_PER_RUN`.
+    max_targets: usize,
 }
 
 impl<'a> TensionLinker<'a> {
@@ -199,30 +219,36 @@ impl<'a> TensionLinker<'a> {
                 visited_urls: None,
             });
 
-        let lat_delta = region.radius_km / 111.0;
-        let lng_delta = region.radius_km / (111.0 * region.center_lat.to_radians().cos());
+        let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
 
         Self {
             writer,
             claude,
             embedder,
-            min_lat: region.center_lat - lat_delta,
-            max_lat: region.center_lat + lat_delta,
-            min_lng: region.center_lng - lng_delta,
-            max_lng: region.center_lng + lng_delta,
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
             region,
             cancelled,
             run_id,
+            max_targets: MAX_TENSION_LINKER_TARGETS_PER_RUN as usize,
         }
     }
 
+    /// Cap targets this run, e.g. to what the budget allocated.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.max_targets = max_targets.min(MAX_TENSION_LINKER_TARGETS_PER_RUN as usize);
+        self
+    }
+
     pub async fn run(&self) -> TensionLinkerStats {
         let mut stats = TensionLinkerStats::default();
 
         let targets = match self
             .writer
             .find_tension_linker_targets(
-                MAX_TENSION_LINKER_TARGETS_PER_RUN,
+                self.max_targets as u32,
                 self.min_lat,
                 self.max_lat,
                 self.min_lng,
@@ -325,12 +351,16 @@ impl<'a> TensionLinker<'a> {
                     }
                 }
                 Err(e) => {
-                    warn!(
-                        signal_id = %target.signal_id,
-                        title = target.title.as_str(),
-                        error = %e,
-                        "Curiosity investigation failed"
-                    );
+                    stats.targets_failed += 1;
+                    let failed = InvestigationFailed {
+                        phase: "tension_linker",
+                        target_id: target.signal_id,
+                        reason: failure_reason(&e),
+                        turns: MAX_TOOL_TURNS,
+                        error: format!("{e:#}"),
+                    };
+                    FailureSample::keep(&mut stats.failure_samples, failed.reason, failed.error.clone());
+                    failed.emit();
                     TensionLinkerOutcome::Failed
                 }
             };
@@ -484,6 +514,9 @@ impl<'a> TensionLinker<'a> {
                 mentioned_actors: vec![],
                 implied_queries: vec![],
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity,
             category: Some(tension.category.clone()),
@@ -567,6 +600,7 @@ mod tests {
             center_lat: 44.9778,
             center_lng: -93.2650,
             radius_km: 30.0,
+            boundary: None,
         };
 
         let tension = DiscoveredTension {
@@ -617,6 +651,9 @@ mod tests {
                 mentioned_actors: vec![],
                 implied_queries: vec![],
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity,
             category: Some(tension.category.clone()),
@@ -649,6 +686,8 @@ mod tests {
         let stats = TensionLinkerStats {
             targets_found: 10,
             targets_investigated: 7,
+            targets_failed: 0,
+            failure_samples: Vec::new(),
             targets_skipped: 3,
             tensions_discovered: 5,
             tensions_deduplicated: 2,
@@ -0,0 +1,280 @@
+//! End-of-run production audit of new signals.
+//!
+//! While scraping, the run keeps a bounded random sample of the signals it
+//! creates along with an excerpt of the content each came from. At the end
+//! of the run an LLM judge reviews each sampled signal against its source,
+//! as far as the budget allows. Verdicts are stored on the signal node and in
+//! Postgres for the dashboard trend; a signal with a critical issue is
+//! rejected before the supervisor can publish it.
+
+use ai_client::claude::Claude;
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::signal_audit::{self, AuditIssue, AuditSeverity, AuditVerdict};
+use rootsignal_common::{Decision, DecisionKind, NodeType};
+use rootsignal_graph::GraphWriter;
+
+use crate::infra::run_log::{EventKind, RunLog};
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
+
+const SONNET_MODEL: &str = "claude-sonnet-4-5-20250929";
+/// Signals sampled for audit per run.
+pub const MAX_AUDITED_PER_RUN: usize = 10;
+/// Source content kept per sampled signal.
+const MAX_AUDIT_CONTENT_CHARS: usize = 8_000;
+
+/// A new signal and the content it was extracted from.
+#[derive(Debug, Clone)]
+pub struct AuditCandidate {
+    pub node_id: Uuid,
+    pub node_type: NodeType,
+    pub title: String,
+    pub summary: String,
+    pub source_url: String,
+    pub content: String,
+}
+
+impl AuditCandidate {
+    pub fn new(node_id: Uuid, node_type: NodeType, title: &str, summary: &str, source_url: &str, content: &str) -> Self {
+        Self {
+            node_id,
+            node_type,
+            title: title.to_string(),
+            summary: summary.to_string(),
+            source_url: source_url.to_string(),
+            content: content.chars().take(MAX_AUDIT_CONTENT_CHARS).collect(),
+        }
+    }
+}
+
+/// A uniform random sample of the run's new signals in bounded memory.
+/// Signal ids are random (v4), so keeping the `cap` smallest ids is a random
+/// sample however many signals the run creates.
+#[derive(Debug)]
+pub struct AuditSample {
+    cap: usize,
+    candidates: Vec<AuditCandidate>,
+}
+
+impl Default for AuditSample {
+    fn default() -> Self {
+        Self::new(MAX_AUDITED_PER_RUN)
+    }
+}
+
+impl AuditSample {
+    pub fn new(cap: usize) -> Self {
+        Self { cap, candidates: Vec::new() }
+    }
+
+    /// Consider a new signal. `candidate` is only built if it's kept.
+    pub fn offer(&mut self, node_id: Uuid, candidate: impl FnOnce() -> AuditCandidate) {
+        if self.candidates.len() < self.cap {
+            self.candidates.push(candidate());
+            return;
+        }
+        let Some((largest, _)) = self
+            .candidates
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, c)| c.node_id)
+        else {
+            return;
+        };
+        if node_id < self.candidates[largest].node_id {
+            self.candidates[largest] = candidate();
+        }
+    }
+
+    pub fn candidates(&self) -> &[AuditCandidate] {
+        &self.candidates
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct Judgement {
+    /// 0 (unusable) to 1 (faithful, specific and complete).
+    score: f64,
+    /// Every problem found; empty if none.
+    #[serde(default)]
+    issues: Vec<AuditIssue>,
+}
+
+const AUDIT_SYSTEM: &str = "You audit community signals extracted from web pages. Check the signal \
+against its source content: is it a real, specific, actionable signal of the stated type? Are its \
+title and summary faithful, with no invented dates, places, names or claims? Score it from 0 \
+(unusable) to 1 (faithful, specific and complete) and list every issue. Mark an issue critical only \
+if the signal should not be published: invented or misleading content, the wrong event or \
+organization, unsafe or private details, or content that is not a community signal at all.";
+
+fn audit_prompt(candidate: &AuditCandidate) -> String {
+    format!(
+        "## Signal\nType: {}\nTitle: {}\nSummary: {}\n\n## Source: {}\n{}",
+        candidate.node_type.to_string().to_lowercase(),
+        candidate.title,
+        candidate.summary,
+        candidate.source_url,
+        candidate.content
+    )
+}
+
+pub struct Auditor<'a> {
+    writer: &'a GraphWriter,
+    pool: &'a PgPool,
+    claude: Claude,
+    budget: &'a BudgetTracker,
+    region: &'a str,
+    run_id: &'a str,
+}
+
+impl<'a> Auditor<'a> {
+    pub fn new(
+        writer: &'a GraphWriter,
+        pool: &'a PgPool,
+        anthropic_api_key: &str,
+        budget: &'a BudgetTracker,
+        region: &'a str,
+        run_id: &'a str,
+    ) -> Self {
+        Self {
+            writer,
+            pool,
+            claude: Claude::new(anthropic_api_key, SONNET_MODEL),
+            budget,
+            region,
+            run_id,
+        }
+    }
+
+    /// Judge the sampled signals while budget lasts, recording each verdict
+    /// and suppressing critical ones.
+    pub async fn run(&self, sample: &AuditSample, run_log: &mut RunLog) {
+        let mut audited = 0;
+        let mut suppressed = 0;
+        for candidate in sample.candidates() {
+            if !self.budget.has_budget(OperationCost::CLAUDE_SONNET_AUDIT) {
+                info!(remaining = sample.candidates().len() - audited, "Skipping signal audit (budget exhausted)");
+                break;
+            }
+            self.budget.spend(OperationCost::CLAUDE_SONNET_AUDIT);
+            let verdict = match self.judge(candidate).await {
+                Ok(verdict) => verdict,
+                Err(e) => {
+                    warn!(signal = %candidate.node_id, error = %e, "Signal audit failed");
+                    continue;
+                }
+            };
+            audited += 1;
+
+            let critical = verdict.is_critical();
+            let labels = verdict.issues.iter().map(AuditIssue::label).collect();
+            let was_suppressed = match self
+                .writer
+                .record_audit_verdict(candidate.node_id, candidate.node_type, verdict.score, labels, critical)
+                .await
+            {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!(signal = %candidate.node_id, error = %e, "Failed to record audit verdict on signal");
+                    false
+                }
+            };
+            if was_suppressed {
+                suppressed += 1;
+                let critical_issues: Vec<String> = verdict
+                    .issues
+                    .iter()
+                    .filter(|i| i.severity == AuditSeverity::Critical)
+                    .map(AuditIssue::label)
+                    .collect();
+                let decision = Decision::new(
+                    DecisionKind::Suppression,
+                    candidate.node_id,
+                    candidate.node_type,
+                    "audit",
+                    "rejected before publication",
+                )
+                .with_inputs(&serde_json::json!({ "source_url": candidate.source_url, "score": verdict.score }))
+                .with_rationale(critical_issues.join("; "))
+                .with_run_id(self.run_id);
+                if let Err(e) = self.writer.record_decision(&decision).await {
+                    warn!(signal = %candidate.node_id, error = %e, "Failed to record suppression decision");
+                }
+            }
+            if let Err(e) = signal_audit::record(self.pool, self.run_id, self.region, &verdict, was_suppressed).await {
+                warn!(signal = %candidate.node_id, error = %e, "Failed to store audit verdict");
+            }
+            run_log.log(EventKind::SignalAudited {
+                node_id: candidate.node_id.to_string(),
+                signal_type: verdict.signal_type.clone(),
+                title: candidate.title.clone(),
+                score: verdict.score,
+                suppressed: was_suppressed,
+            });
+        }
+        if audited > 0 {
+            info!(audited, suppressed, "Signal audit complete");
+        }
+    }
+
+    async fn judge(&self, candidate: &AuditCandidate) -> Result<AuditVerdict> {
+        let judgement: Judgement = self
+            .claude
+            .extract(SONNET_MODEL, AUDIT_SYSTEM, &audit_prompt(candidate))
+            .await?;
+        Ok(AuditVerdict {
+            signal_id: candidate.node_id,
+            signal_type: candidate.node_type.to_string().to_lowercase(),
+            source_url: candidate.source_url.clone(),
+            score: judgement.score.clamp(0.0, 1.0),
+            issues: judgement.issues,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(node_id: Uuid) -> AuditCandidate {
+        AuditCandidate::new(node_id, NodeType::Aid, "Free groceries", "Food shelf open Mondays", "https://example.org", "page")
+    }
+
+    #[test]
+    fn sample_keeps_the_smallest_ids_up_to_its_cap() {
+        let ids: Vec<Uuid> = (1..=10u128).rev().map(Uuid::from_u128).collect();
+        let mut sample = AuditSample::new(3);
+
+        for id in &ids {
+            sample.offer(*id, || candidate(*id));
+        }
+
+        let mut kept: Vec<u128> = sample.candidates().iter().map(|c| c.node_id.as_u128()).collect();
+        kept.sort();
+        assert_eq!(kept, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn rejected_offers_never_build_a_candidate() {
+        let mut sample = AuditSample::new(1);
+        sample.offer(Uuid::from_u128(1), || candidate(Uuid::from_u128(1)));
+
+        sample.offer(Uuid::from_u128(2), || panic!("built a candidate the sample won't keep"));
+
+        assert_eq!(sample.candidates().len(), 1);
+    }
+
+    #[test]
+    fn candidate_content_is_truncated() {
+        let long = "x".repeat(MAX_AUDIT_CONTENT_CHARS + 500);
+
+        let c = AuditCandidate::new(Uuid::new_v4(), NodeType::Need, "t", "s", "u", &long);
+
+        assert_eq!(c.content.chars().count(), MAX_AUDIT_CONTENT_CHARS);
+    }
+}
@@ -0,0 +1,248 @@
+//! Census demographic context for situations.
+//!
+//! A region's ACS 5-year tract estimates are fetched from the Census API and
+//! joined to tract internal points from the Census Gazetteer. They change
+//! once a year, so they're cached on the region and refetched only when the
+//! cache is old or the region's configuration changes. After weaving, each
+//! situation in scope gets the context of the tracts its signals are in.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use rootsignal_common::{DemographicContext, RegionDemographics, ScoutScope, TractDemographics};
+use rootsignal_graph::GraphWriter;
+
+/// Cached tracts older than this are refetched.
+pub const CACHE_MAX_AGE_DAYS: i64 = 90;
+
+/// ACS variables, in request order: total population; poverty status universe
+/// and below poverty; occupied and renter-occupied housing units; households
+/// and the four limited-English-speaking household counts.
+const ACS_VARIABLES: &[&str] = &[
+    "B01003_001E",
+    "B17001_001E",
+    "B17001_002E",
+    "B25003_001E",
+    "B25003_003E",
+    "C16002_001E",
+    "C16002_004E",
+    "C16002_007E",
+    "C16002_010E",
+    "C16002_013E",
+];
+
+/// Source of tract estimates for a region.
+#[async_trait]
+pub trait CensusSource: Send + Sync {
+    async fn tracts(&self, config: &RegionDemographics) -> Result<Vec<TractDemographics>>;
+}
+
+pub struct AcsClient {
+    client: reqwest::Client,
+}
+
+impl AcsClient {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(60))
+                .build()
+                .expect("Failed to build Census HTTP client"),
+        }
+    }
+
+    async fn get(&self, url: &str) -> Result<String> {
+        Ok(self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context("Census request failed")?
+            .error_for_status()?
+            .text()
+            .await?)
+    }
+}
+
+impl Default for AcsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CensusSource for AcsClient {
+    async fn tracts(&self, config: &RegionDemographics) -> Result<Vec<TractDemographics>> {
+        let year = config.acs_year;
+        let gazetteer = self
+            .get(&format!(
+                "https://www2.census.gov/geo/docs/maps-data/data/gazetteer/{year}_Gazetteer/{year}_gaz_tracts_{}.txt",
+                config.state_fips
+            ))
+            .await?;
+        let points = parse_gazetteer(&gazetteer);
+
+        let mut tracts = Vec::new();
+        for county in &config.county_fips {
+            let body = self
+                .get(&format!(
+                    "https://api.census.gov/data/{year}/acs/acs5?get={}&for=tract:*&in=state:{}%20county:{county}",
+                    ACS_VARIABLES.join(","),
+                    config.state_fips
+                ))
+                .await?;
+            tracts.extend(parse_acs(&body, &points).context("Failed to parse ACS response")?);
+        }
+        info!(state = config.state_fips.as_str(), tracts = tracts.len(), year, "acs: fetched tract estimates");
+        Ok(tracts)
+    }
+}
+
+/// Tract internal points by GEOID from a tab-separated Gazetteer file.
+pub(crate) fn parse_gazetteer(body: &str) -> HashMap<String, (f64, f64)> {
+    let mut lines = body.lines();
+    let Some(header) = lines.next() else {
+        return HashMap::new();
+    };
+    let columns: Vec<&str> = header.split('\t').map(str::trim).collect();
+    let column = |name: &str| columns.iter().position(|c| *c == name);
+    let (Some(geoid), Some(lat), Some(lng)) = (column("GEOID"), column("INTPTLAT"), column("INTPTLONG")) else {
+        return HashMap::new();
+    };
+
+    lines
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+            let point = (fields.get(lat)?.parse().ok()?, fields.get(lng)?.parse().ok()?);
+            Some((fields.get(geoid)?.to_string(), point))
+        })
+        .collect()
+}
+
+/// Tracts from a Census API response (a header row, then one row per tract).
+/// Tracts without a Gazetteer point are dropped; suppressed estimates
+/// (negative sentinels) count as zero.
+pub(crate) fn parse_acs(body: &str, points: &HashMap<String, (f64, f64)>) -> Result<Vec<TractDemographics>> {
+    let rows: Vec<Vec<Option<String>>> = serde_json::from_str(body)?;
+    let Some((header, rows)) = rows.split_first() else {
+        return Ok(Vec::new());
+    };
+    let column = |name: &str| header.iter().position(|c| c.as_deref() == Some(name));
+    let (Some(state), Some(county), Some(tract)) = (column("state"), column("county"), column("tract")) else {
+        anyhow::bail!("ACS response has no state/county/tract columns");
+    };
+    let variables: Vec<usize> = ACS_VARIABLES
+        .iter()
+        .map(|v| column(v).with_context(|| format!("ACS response has no {v} column")))
+        .collect::<Result<_>>()?;
+
+    let mut tracts = Vec::new();
+    for row in rows {
+        let field = |i: usize| row.get(i).cloned().flatten().unwrap_or_default();
+        let geoid = format!("{}{}{}", field(state), field(county), field(tract));
+        let Some(&(lat, lng)) = points.get(&geoid) else {
+            continue;
+        };
+        let count = |v: usize| field(variables[v]).parse::<i64>().unwrap_or(0).clamp(0, u32::MAX as i64) as u32;
+        tracts.push(TractDemographics {
+            geoid,
+            lat,
+            lng,
+            population: count(0),
+            poverty_universe: count(1),
+            below_poverty: count(2),
+            households: count(5),
+            renter_households: count(4),
+            limited_english_households: count(6) + count(7) + count(8) + count(9),
+        });
+    }
+    Ok(tracts)
+}
+
+/// The region's tracts, from cache when it's fresh and matches `config`.
+async fn load_tracts(
+    source: &dyn CensusSource,
+    writer: &GraphWriter,
+    region_slug: &str,
+    config: &RegionDemographics,
+    now: DateTime<Utc>,
+) -> Result<Vec<TractDemographics>> {
+    let key = config.cache_key();
+    if let Some((cached_key, fetched_at, tracts)) = writer.get_cached_tracts(region_slug).await? {
+        if cached_key == key && now - fetched_at < chrono::Duration::days(CACHE_MAX_AGE_DAYS) && !tracts.is_empty() {
+            return Ok(tracts);
+        }
+    }
+    let tracts = source.tracts(config).await?;
+    writer.set_cached_tracts(region_slug, &key, &tracts).await?;
+    Ok(tracts)
+}
+
+/// Attach census context to every situation in scope. Returns the number of
+/// situations with context.
+pub async fn refresh_situation_demographics(
+    source: &dyn CensusSource,
+    writer: &GraphWriter,
+    region_slug: &str,
+    config: &RegionDemographics,
+    scope: &ScoutScope,
+    now: DateTime<Utc>,
+) -> u32 {
+    let tracts = match load_tracts(source, writer, region_slug, config, now).await {
+        Ok(tracts) => tracts,
+        Err(e) => {
+            warn!(error = %e, region = region_slug, "Failed to load census tracts");
+            return 0;
+        }
+    };
+    let footprints = match writer.situation_footprints(scope).await {
+        Ok(footprints) => footprints,
+        Err(e) => {
+            warn!(error = %e, "Failed to load situation footprints");
+            return 0;
+        }
+    };
+
+    let mut enriched = 0u32;
+    for (situation_id, points) in footprints {
+        let context = DemographicContext::for_footprint(&tracts, &points, config.acs_year);
+        enriched += context.is_some() as u32;
+        if let Err(e) = writer.set_situation_demographics(situation_id, context.as_ref()).await {
+            warn!(error = %e, %situation_id, "Failed to record situation demographics");
+        }
+    }
+    info!(region = region_slug, enriched, "Situations enriched with census context");
+    enriched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAZETTEER: &str = "USPS\tGEOID\tALAND\tAWATER\tALAND_SQMI\tAWATER_SQMI\tINTPTLAT\tINTPTLONG                                                                                                               \n\
+        MN\t27053107600\t1234\t0\t0.5\t0\t44.948000\t-93.262000\n\
+        MN\t27053108000\t1234\t0\t0.5\t0\t44.940000\t-93.250000\n";
+
+    #[test]
+    fn acs_rows_join_gazetteer_points_by_geoid() {
+        let acs = r#"[
+            ["B01003_001E","B17001_001E","B17001_002E","B25003_001E","B25003_003E","C16002_001E","C16002_004E","C16002_007E","C16002_010E","C16002_013E","state","county","tract"],
+            ["4210","4100","1025","1700","1190","1700","40","85","12","3","27","053","107600"],
+            ["3890","3800","-666666666","1500","420","1500","0","10","0","0","27","053","108000"],
+            ["100","90","10","40","20","40","0","0","0","0","27","053","999999"]
+        ]"#;
+
+        let tracts = parse_acs(acs, &parse_gazetteer(GAZETTEER)).unwrap();
+        assert_eq!(tracts.len(), 2, "tracts without a point are dropped");
+        assert_eq!(tracts[0].geoid, "27053107600");
+        assert_eq!((tracts[0].lat, tracts[0].lng), (44.948, -93.262));
+        assert_eq!(tracts[0].renter_households, 1190);
+        assert_eq!(tracts[0].limited_english_households, 140);
+        assert_eq!(tracts[1].below_poverty, 0, "suppressed estimates count as zero");
+    }
+}
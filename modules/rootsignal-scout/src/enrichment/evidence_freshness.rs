@@ -0,0 +1,189 @@
+//! Evidence freshness — re-check that evidence pages still say what they said.
+//!
+//! Evidence is written once, when a page is scraped or a search turns it up,
+//! and its page can change or disappear afterwards. This module re-fetches
+//! evidence source URLs on a cadence and records what it finds:
+//!
+//! - **Current** — the page hash matches, or the cited passage is still there.
+//! - **Changed** — neither; the page diff is kept with the evidence.
+//! - **Removed** — the page is gone (4xx) or renders empty.
+//! - **Stale** — the page couldn't be read for `STALE_AFTER_DAYS`.
+//!
+//! The graph rescales each supported signal's confidence with the result.
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use rootsignal_common::evidence_freshness::{MAX_DRIFT_DIFF_CHARS, RECHECK_INTERVAL_DAYS};
+use rootsignal_common::{
+    content_hash, ArchivedPage, Classify, ErrorClass, EvidenceFreshness, EvidenceRecheck, EvidenceVerification,
+};
+
+use crate::pipeline::page_diff::PageDiff;
+use crate::pipeline::traits::{ContentFetcher, SignalStore};
+
+/// Evidence pages re-fetched per run, so a backlog can't stall a sweep.
+pub const MAX_RECHECKS_PER_RUN: u32 = 50;
+
+/// Tally of an evidence re-check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FreshnessSummary {
+    pub checked: u32,
+    pub current: u32,
+    pub changed: u32,
+    pub removed: u32,
+    pub stale: u32,
+}
+
+/// Freshness of evidence whose page was read, with the diff when it changed.
+pub fn assess_page(evidence: &EvidenceRecheck, page: &ArchivedPage) -> (EvidenceFreshness, Option<String>) {
+    if page.markdown.trim().is_empty() {
+        return (EvidenceFreshness::Removed, None);
+    }
+    if format!("{:x}", content_hash(&page.markdown)) == evidence.content_hash {
+        return (EvidenceFreshness::Current, None);
+    }
+    let squash = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    if let Some(snippet) = evidence.snippet.as_deref().map(squash).filter(|s| !s.is_empty()) {
+        if squash(&page.markdown).contains(&snippet) {
+            return (EvidenceFreshness::Current, None);
+        }
+    }
+
+    let diff = match page.previous_markdown.as_deref().map(|previous| PageDiff::compute(previous, &page.markdown)) {
+        Some(Some(diff)) => diff.report(),
+        Some(None) => "Most of the page changed since it was last read".to_string(),
+        None => "The cited passage no longer appears on the page".to_string(),
+    };
+    (EvidenceFreshness::Changed, Some(diff.chars().take(MAX_DRIFT_DIFF_CHARS).collect()))
+}
+
+/// Freshness of evidence whose page couldn't be fetched; None when the
+/// failure says nothing yet and the check should be retried next run.
+pub fn assess_fetch_failure(evidence: &EvidenceRecheck, class: ErrorClass, now: DateTime<Utc>) -> Option<EvidenceFreshness> {
+    match class {
+        // 404/410/unsupported — the page is gone.
+        ErrorClass::User => Some(EvidenceFreshness::Removed),
+        ErrorClass::Transient | ErrorClass::Budget | ErrorClass::System => {
+            evidence.is_overdue(now).then_some(EvidenceFreshness::Stale)
+        }
+    }
+}
+
+/// Re-fetch evidence pages not checked within `RECHECK_INTERVAL_DAYS` and
+/// record their freshness.
+pub async fn recheck_evidence(
+    fetcher: &dyn ContentFetcher,
+    store: &dyn SignalStore,
+    now: DateTime<Utc>,
+) -> FreshnessSummary {
+    let checked_before = now - chrono::Duration::days(RECHECK_INTERVAL_DAYS);
+    let due = match store.evidence_due_for_recheck(checked_before, MAX_RECHECKS_PER_RUN).await {
+        Ok(due) => due,
+        Err(e) => {
+            warn!(error = %e, "Failed to list evidence due for recheck");
+            return FreshnessSummary::default();
+        }
+    };
+
+    let mut summary = FreshnessSummary::default();
+    for evidence in due {
+        let (freshness, drift_diff, read) = match fetcher.page(&evidence.source_url).await {
+            Ok(page) => {
+                let (freshness, diff) = assess_page(&evidence, &page);
+                (freshness, diff, true)
+            }
+            Err(e) => {
+                let class = e.class();
+                let Some(freshness) = assess_fetch_failure(&evidence, class, now) else {
+                    warn!(url = evidence.source_url.as_str(), error = %e, error_class = %class, "Evidence recheck fetch failed");
+                    continue;
+                };
+                (freshness, None, freshness == EvidenceFreshness::Removed)
+            }
+        };
+
+        let verification = EvidenceVerification {
+            freshness,
+            checked_at: now,
+            drift_diff,
+        };
+        if let Err(e) = store.record_evidence_check(&evidence, &verification, read).await {
+            warn!(url = evidence.source_url.as_str(), error = %e, "Failed to record evidence check");
+            continue;
+        }
+        summary.checked += 1;
+        match freshness {
+            EvidenceFreshness::Current => summary.current += 1,
+            EvidenceFreshness::Changed => summary.changed += 1,
+            EvidenceFreshness::Removed => summary.removed += 1,
+            EvidenceFreshness::Stale => summary.stale += 1,
+        }
+    }
+
+    if summary.checked > 0 {
+        info!(
+            checked = summary.checked,
+            current = summary.current,
+            changed = summary.changed,
+            removed = summary.removed,
+            stale = summary.stale,
+            "Rechecked evidence freshness"
+        );
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::archived_page;
+
+    fn recheck(markdown: &str, snippet: Option<&str>) -> EvidenceRecheck {
+        EvidenceRecheck {
+            evidence_id: uuid::Uuid::new_v4(),
+            source_url: "https://pantry.org/hours".to_string(),
+            content_hash: format!("{:x}", content_hash(markdown)),
+            snippet: snippet.map(str::to_string),
+            freshness: None,
+            verified_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn page_still_quoting_the_snippet_is_current_even_when_its_hash_changed() {
+        let evidence = recheck("Open Saturdays 9-12.", Some("open saturdays  9-12"));
+        let page = archived_page("https://pantry.org/hours", "# Hours\n\nOpen Saturdays 9-12.\n\nNew: volunteers wanted.");
+
+        assert_eq!(assess_page(&evidence, &page), (EvidenceFreshness::Current, None));
+    }
+
+    #[test]
+    fn rewritten_page_is_changed_with_its_diff() {
+        let before = "# Hours\n\nOpen Saturdays 9-12.\n\n# Location\n\nHoly Rosary basement.";
+        let evidence = recheck(before, Some("Open Saturdays 9-12."));
+        let mut page = archived_page(
+            "https://pantry.org/hours",
+            "# Hours\n\nClosed until further notice.\n\n# Location\n\nHoly Rosary basement.",
+        );
+        page.previous_markdown = Some(before.to_string());
+
+        let (freshness, diff) = assess_page(&evidence, &page);
+
+        assert_eq!(freshness, EvidenceFreshness::Changed);
+        let diff = diff.expect("diff kept");
+        assert!(diff.contains("Closed until further notice."));
+        assert!(diff.contains("Open Saturdays 9-12."));
+    }
+
+    #[test]
+    fn missing_page_is_removed_but_a_flaky_one_only_goes_stale_once_overdue() {
+        let now = Utc::now();
+        let mut evidence = recheck("Open Saturdays 9-12.", None);
+
+        assert_eq!(assess_fetch_failure(&evidence, ErrorClass::User, now), Some(EvidenceFreshness::Removed));
+        assert_eq!(assess_fetch_failure(&evidence, ErrorClass::Transient, now), None);
+        evidence.verified_at = now - chrono::Duration::days(31);
+        assert_eq!(assess_fetch_failure(&evidence, ErrorClass::Transient, now), Some(EvidenceFreshness::Stale));
+    }
+}
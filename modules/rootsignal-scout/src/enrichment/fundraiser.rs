@@ -0,0 +1,260 @@
+//! Fundraiser tracking — keep crowdfunding progress on Needs current.
+//!
+//! A Need is tied to a fundraiser when its action URL, source page, or any of
+//! its evidence is a campaign page (GoFundMe, Givebutter, GiveSendGo). The
+//! graph records that URL; this module re-reads the page on a cadence and
+//! writes goal / raised / donor counts back onto the Need.
+//!
+//! Campaign pages embed their state as JSON for client-side hydration, which
+//! is preferred. When it's missing (page layout changed, fetched without JS),
+//! the visible "$36,500 raised of $50K" line is parsed instead.
+
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use rootsignal_common::{fundraiser_platform, ArchivedPage, Fundraiser};
+
+use crate::pipeline::traits::{ContentFetcher, SignalStore};
+
+/// How long fundraiser progress stays fresh before the page is re-read.
+pub const REFRESH_INTERVAL_HOURS: i64 = 12;
+
+/// Fundraiser pages re-read per run, so a backlog can't stall a scout run.
+pub const MAX_REFRESH_PER_RUN: u32 = 25;
+
+static EMBEDDED_JSON: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?s)<script[^>]*(?:__NEXT_DATA__|application/json)[^>]*>(.*?)</script>"#).unwrap()
+});
+
+static INITIAL_STATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)window\.initialState\s*=\s*(\{.*?\});?\s*</script>").unwrap());
+
+static RAISED_OF_GOAL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)([$€£])\s?([\d.,]+)\s*([km])?\s*(?:[a-z]{3}\s+)?raised\s+of\s+[$€£]?\s?([\d.,]+)\s*([km])?")
+        .unwrap()
+});
+
+static DONATIONS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)([\d.,]+)\s*(k)?\s+(?:donations|donors)\b").unwrap());
+
+/// Re-read fundraiser pages whose progress is stale and record the result.
+///
+/// Returns the number of Needs whose progress was updated. Pages that can't
+/// be fetched are retried next run; pages that load but can't be parsed are
+/// marked checked so they wait out the cadence.
+pub async fn refresh_fundraisers(
+    fetcher: &dyn ContentFetcher,
+    store: &dyn SignalStore,
+    now: DateTime<Utc>,
+) -> u32 {
+    let checked_before = now - chrono::Duration::hours(REFRESH_INTERVAL_HOURS);
+    let due = match store.fundraisers_due_for_refresh(checked_before, MAX_REFRESH_PER_RUN).await {
+        Ok(due) => due,
+        Err(e) => {
+            warn!(error = %e, "Failed to list fundraisers due for refresh");
+            return 0;
+        }
+    };
+
+    let mut updated = 0;
+    for (need_id, url) in due {
+        let page = match fetcher.page(&url).await {
+            Ok(page) => page,
+            Err(e) => {
+                warn!(url, error = %e, "Fundraiser page fetch failed");
+                continue;
+            }
+        };
+        let progress = parse_fundraiser(&url, &page, now);
+        if progress.is_none() {
+            warn!(url, "No fundraiser progress found on page");
+        }
+        match store.update_fundraiser(need_id, now, progress.as_ref()).await {
+            Ok(()) if progress.is_some() => updated += 1,
+            Ok(()) => {}
+            Err(e) => warn!(url, error = %e, "Failed to record fundraiser progress"),
+        }
+    }
+
+    if updated > 0 {
+        info!(updated, "Refreshed fundraiser progress");
+    }
+    updated
+}
+
+/// Read a campaign's progress from its page: embedded JSON first, then the
+/// visible progress line.
+pub fn parse_fundraiser(url: &str, page: &ArchivedPage, now: DateTime<Utc>) -> Option<Fundraiser> {
+    let fields = embedded_progress(&page.raw_html).or_else(|| text_progress(&page.markdown))?;
+
+    Some(Fundraiser {
+        url: url.to_string(),
+        platform: fundraiser_platform(url).to_string(),
+        goal: fields.goal.filter(|g| *g > 0.0),
+        raised: fields.raised,
+        currency: fields.currency.unwrap_or_else(|| "USD".to_string()),
+        donor_count: fields.donor_count,
+        organizer: fields.organizer,
+        beneficiary: fields.beneficiary,
+        checked_at: now,
+    })
+}
+
+#[derive(Debug, Default)]
+struct ProgressFields {
+    raised: f64,
+    goal: Option<f64>,
+    currency: Option<String>,
+    donor_count: Option<u32>,
+    organizer: Option<String>,
+    beneficiary: Option<String>,
+}
+
+const RAISED_KEYS: &[&str] = &["currentAmount", "current_amount", "amountRaised", "amount_raised", "raised"];
+const GOAL_KEYS: &[&str] = &["goalAmount", "goal_amount", "goal"];
+const DONOR_KEYS: &[&str] = &["donationCount", "donationsCount", "donation_count", "donors_count", "donorCount"];
+
+fn embedded_progress(html: &str) -> Option<ProgressFields> {
+    EMBEDDED_JSON
+        .captures_iter(html)
+        .chain(INITIAL_STATE.captures_iter(html))
+        .filter_map(|c| serde_json::from_str::<Value>(c[1].trim()).ok())
+        .find_map(|json| find_campaign(&json))
+}
+
+/// Depth-first search for the object carrying the campaign's raised amount.
+fn find_campaign(value: &Value) -> Option<ProgressFields> {
+    match value {
+        Value::Object(map) => {
+            if let Some((raised, currency)) = RAISED_KEYS.iter().find_map(|k| map.get(*k).and_then(amount)) {
+                let goal = GOAL_KEYS.iter().find_map(|k| map.get(*k).and_then(amount));
+                return Some(ProgressFields {
+                    raised,
+                    currency: currency
+                        .or_else(|| goal.as_ref().and_then(|(_, c)| c.clone()))
+                        .or_else(|| string_field(map.get("currencyCode").or(map.get("currency")))),
+                    goal: goal.map(|(g, _)| g),
+                    donor_count: DONOR_KEYS
+                        .iter()
+                        .find_map(|k| map.get(*k).and_then(Value::as_u64))
+                        .map(|c| c as u32),
+                    organizer: person_name(map.get("organizer")).or_else(|| string_field(map.get("organizerName"))),
+                    beneficiary: person_name(map.get("beneficiary"))
+                        .or_else(|| string_field(map.get("beneficiaryName"))),
+                });
+            }
+            map.values().find_map(find_campaign)
+        }
+        Value::Array(items) => items.iter().find_map(find_campaign),
+        _ => None,
+    }
+}
+
+/// An amount as a bare number, a numeric string, or `{amount, currencyCode}`.
+fn amount(value: &Value) -> Option<(f64, Option<String>)> {
+    match value {
+        Value::Number(n) => n.as_f64().map(|a| (a, None)),
+        Value::String(s) => s.replace(',', "").parse().ok().map(|a| (a, None)),
+        Value::Object(map) => {
+            let (a, _) = amount(map.get("amount").or(map.get("value"))?)?;
+            Some((a, string_field(map.get("currencyCode").or(map.get("currency")))))
+        }
+        _ => None,
+    }
+}
+
+fn person_name(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::Object(map) => string_field(map.get("name").or(map.get("displayName"))),
+        other => string_field(Some(other)),
+    }
+}
+
+fn string_field(value: Option<&Value>) -> Option<String> {
+    value?.as_str().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+fn text_progress(text: &str) -> Option<ProgressFields> {
+    let caps = RAISED_OF_GOAL.captures(text)?;
+    let currency = match &caps[1] {
+        "€" => "EUR",
+        "£" => "GBP",
+        _ => "USD",
+    };
+    let donor_count = DONATIONS
+        .captures(text)
+        .and_then(|c| scaled(&c[1], c.get(2).map(|m| m.as_str())))
+        .map(|c| c as u32);
+
+    Some(ProgressFields {
+        raised: scaled(&caps[2], caps.get(3).map(|m| m.as_str()))?,
+        goal: scaled(&caps[4], caps.get(5).map(|m| m.as_str())),
+        currency: Some(currency.to_string()),
+        donor_count,
+        ..Default::default()
+    })
+}
+
+/// "36,500" → 36500, "1.2" + "k" → 1200.
+fn scaled(number: &str, suffix: Option<&str>) -> Option<f64> {
+    let n: f64 = number.trim_end_matches(['.', ',']).replace(',', "").parse().ok()?;
+    let multiplier = match suffix.map(str::to_lowercase).as_deref() {
+        Some("k") => 1_000.0,
+        Some("m") => 1_000_000.0,
+        _ => 1.0,
+    };
+    Some(n * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::archived_page;
+
+    const URL: &str = "https://www.gofundme.com/f/rebuild-lake-street-bakery";
+
+    #[test]
+    fn embedded_campaign_state_is_preferred() {
+        let html = r#"<html><script id="__NEXT_DATA__" type="application/json">
+            {"props":{"pageProps":{"fundraiser":{
+                "title":"Rebuild Lake Street Bakery",
+                "goalAmount":{"amount":50000,"currencyCode":"USD"},
+                "currentAmount":{"amount":36500,"currencyCode":"USD"},
+                "donationCount":412,
+                "organizer":{"name":"Marisol Vega"},
+                "beneficiary":{"name":"Lake Street Bakery"}
+            }}}}
+        </script><body>$1 raised of $2</body></html>"#;
+        let mut page = archived_page(URL, "");
+        page.raw_html = html.to_string();
+
+        let f = parse_fundraiser(URL, &page, Utc::now()).unwrap();
+        assert_eq!(f.raised, 36_500.0);
+        assert_eq!(f.goal, Some(50_000.0));
+        assert_eq!(f.donor_count, Some(412));
+        assert_eq!(f.organizer.as_deref(), Some("Marisol Vega"));
+        assert_eq!(f.beneficiary.as_deref(), Some("Lake Street Bakery"));
+        assert_eq!(f.percent_funded().map(|p| p.round()), Some(73.0));
+    }
+
+    #[test]
+    fn visible_progress_line_is_the_fallback() {
+        let page = archived_page(URL, "Rebuild Lake Street Bakery\n\n$36,500 raised of $50K goal\n\n1.2K donations");
+
+        let f = parse_fundraiser(URL, &page, Utc::now()).unwrap();
+        assert_eq!(f.raised, 36_500.0);
+        assert_eq!(f.goal, Some(50_000.0));
+        assert_eq!(f.donor_count, Some(1_200));
+        assert_eq!(f.currency, "USD");
+    }
+
+    #[test]
+    fn pages_without_progress_yield_nothing() {
+        let page = archived_page(URL, "This fundraiser is no longer accepting donations.");
+        assert!(parse_fundraiser(URL, &page, Utc::now()).is_none());
+    }
+}
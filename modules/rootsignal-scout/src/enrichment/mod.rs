@@ -1,4 +1,10 @@
 pub mod actor_extractor;
 pub mod actor_location;
+pub mod audit;
+pub mod demographics;
+pub mod evidence_freshness;
+pub mod fundraiser;
 pub mod link_promoter;
 pub mod quality;
+pub mod summary_variants;
+pub mod verification;
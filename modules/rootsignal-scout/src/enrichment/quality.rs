@@ -133,6 +133,9 @@ mod tests {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         }
     }
 
@@ -0,0 +1,289 @@
+//! Plain-language and translated summaries for high-visibility subjects.
+//!
+//! After situation weaving, the hottest situations and the most visible live
+//! signals in the region get a plain-language English summary (unless
+//! extraction already wrote one) and translations into the region's priority
+//! languages. One LLM call covers every language a subject needs. Variants
+//! are cached in Postgres against a hash of the English summary, so a
+//! subject is only regenerated when its summary changes or a language is
+//! added.
+
+use ai_client::claude::Claude;
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+use rootsignal_common::accessibility::accept_plain_summary;
+use rootsignal_common::summary_variants::{self, SummaryVariant, SOURCE_LANGUAGE};
+use rootsignal_graph::SummaryVariantSource;
+
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
+
+const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
+/// Situations considered per refresh, hottest first.
+pub const MAX_VARIANT_SITUATIONS: u32 = 10;
+/// Signals considered per refresh, most visible first.
+pub const MAX_VARIANT_SIGNALS: u32 = 30;
+/// Subjects regenerated per refresh, before the budget is considered.
+const MAX_GENERATIONS_PER_RUN: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GeneratedVariants {
+    /// The summary rewritten in plain English at a 6th-grade reading level,
+    /// or empty if not requested.
+    #[serde(default)]
+    plain_summary: String,
+    /// One entry per requested language.
+    #[serde(default)]
+    translations: Vec<Translation>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct Translation {
+    /// The language code exactly as requested, e.g. "es".
+    language: String,
+    /// The summary, translated faithfully.
+    summary: String,
+    /// The plain-language summary, translated and kept just as simple.
+    plain_summary: String,
+}
+
+const VARIANTS_SYSTEM: &str = "You rewrite public summaries of local community signals (events, \
+resources, needs, notices, community concerns) for residents who find newspaper English hard to read \
+or don't read English. Keep every fact, date, time, place and name exactly; add nothing. A plain \
+summary uses short sentences and everyday words a 6th grader knows. Translations must be natural and \
+faithful; keep names of people, organizations and places as they are.";
+
+fn variants_prompt(source: &SummaryVariantSource, languages: &[String]) -> String {
+    let wants_plain = languages.iter().any(|l| l == SOURCE_LANGUAGE);
+    let translations: Vec<&str> = languages
+        .iter()
+        .map(String::as_str)
+        .filter(|l| *l != SOURCE_LANGUAGE)
+        .collect();
+
+    let mut prompt = format!("## {}\nTitle: {}\nSummary: {}\n", source.kind, source.title, source.summary);
+    if let Some(plain) = &source.plain_summary {
+        prompt.push_str(&format!("Plain summary: {plain}\n"));
+    }
+    prompt.push_str("\n## Tasks\n");
+    if wants_plain {
+        prompt.push_str("- Write plain_summary: the summary in plain English.\n");
+    } else {
+        prompt.push_str("- Leave plain_summary empty.\n");
+    }
+    if translations.is_empty() {
+        prompt.push_str("- Leave translations empty.\n");
+    } else {
+        prompt.push_str(&format!(
+            "- Translate the summary and the plain summary into each of these languages (ISO 639 codes): {}.\n",
+            translations.join(", ")
+        ));
+    }
+    prompt
+}
+
+/// The variants worth storing from a generation: only requested languages,
+/// no empty translations, and a plain English summary only if it grades as
+/// plain. The English variant is stored even without one, so a summary the
+/// model can't simplify isn't retried every run.
+fn variants_from(
+    source: &SummaryVariantSource,
+    requested: &[String],
+    generated: GeneratedVariants,
+) -> Vec<SummaryVariant> {
+    let source_hash = summary_variants::source_hash(&source.summary);
+    let variant = |language: &str, summary: &str, plain_summary: Option<String>| SummaryVariant {
+        subject_id: source.id,
+        language: language.to_string(),
+        summary: summary.trim().to_string(),
+        plain_summary,
+        source_hash: source_hash.clone(),
+    };
+
+    let mut variants = Vec::new();
+    if requested.iter().any(|l| l == SOURCE_LANGUAGE) {
+        let plain = accept_plain_summary(Some(&generated.plain_summary));
+        variants.push(variant(SOURCE_LANGUAGE, &source.summary, plain));
+    }
+    for translation in generated.translations {
+        let Some(language) = summary_variants::primary_language(&translation.language) else {
+            continue;
+        };
+        let wanted = language != SOURCE_LANGUAGE && requested.contains(&language);
+        if !wanted || translation.summary.trim().is_empty() || variants.iter().any(|v| v.language == language) {
+            continue;
+        }
+        let plain = Some(translation.plain_summary.trim().to_string()).filter(|p| !p.is_empty());
+        variants.push(variant(&language, &translation.summary, plain));
+    }
+    variants
+}
+
+pub struct SummaryVariantGenerator<'a> {
+    pool: &'a PgPool,
+    claude: Claude,
+    budget: &'a BudgetTracker,
+    languages: Vec<String>,
+}
+
+impl<'a> SummaryVariantGenerator<'a> {
+    /// `languages` are the region's priority languages; English is always
+    /// covered by its plain-language variant.
+    pub fn new(pool: &'a PgPool, anthropic_api_key: &str, budget: &'a BudgetTracker, languages: &[String]) -> Self {
+        let mut priority: Vec<String> = Vec::new();
+        for language in languages.iter().filter_map(|l| summary_variants::primary_language(l)) {
+            if language != SOURCE_LANGUAGE && !priority.contains(&language) {
+                priority.push(language);
+            }
+        }
+        Self {
+            pool,
+            claude: Claude::new(anthropic_api_key, HAIKU_MODEL),
+            budget,
+            languages: priority,
+        }
+    }
+
+    /// Generate missing and stale variants for `sources`, most visible
+    /// first, while budget lasts. Returns the number of subjects refreshed.
+    pub async fn run(&self, sources: &[SummaryVariantSource]) -> usize {
+        if sources.is_empty() {
+            return 0;
+        }
+        let ids: Vec<_> = sources.iter().map(|s| s.id).collect();
+        let cached = match summary_variants::load(self.pool, &ids).await {
+            Ok(cached) => cached,
+            Err(e) => {
+                warn!(error = %e, "Failed to load cached summary variants");
+                return 0;
+            }
+        };
+
+        let mut refreshed = 0;
+        for source in sources {
+            let needed = summary_variants::languages_to_refresh(
+                cached.get(&source.id).map(Vec::as_slice).unwrap_or_default(),
+                &source.summary,
+                source.plain_summary.is_some(),
+                &self.languages,
+            );
+            if needed.is_empty() {
+                continue;
+            }
+            if refreshed >= MAX_GENERATIONS_PER_RUN
+                || !self.budget.has_budget(OperationCost::CLAUDE_HAIKU_SUMMARY_VARIANTS)
+            {
+                info!(refreshed, "Stopping summary variant generation (cap or budget reached)");
+                break;
+            }
+            self.budget.spend(OperationCost::CLAUDE_HAIKU_SUMMARY_VARIANTS);
+
+            let variants = match self.generate(source, &needed).await {
+                Ok(variants) => variants,
+                Err(e) => {
+                    warn!(subject = %source.id, error = %e, "Summary variant generation failed");
+                    continue;
+                }
+            };
+            for variant in &variants {
+                if let Err(e) = summary_variants::upsert(self.pool, &source.kind, variant).await {
+                    warn!(subject = %source.id, language = variant.language, error = %e, "Failed to store summary variant");
+                }
+            }
+            refreshed += 1;
+        }
+        if refreshed > 0 {
+            info!(refreshed, languages = self.languages.join(","), "Summary variants refreshed");
+        }
+        refreshed
+    }
+
+    async fn generate(&self, source: &SummaryVariantSource, languages: &[String]) -> Result<Vec<SummaryVariant>> {
+        let generated: GeneratedVariants = self
+            .claude
+            .extract(HAIKU_MODEL, VARIANTS_SYSTEM, &variants_prompt(source, languages))
+            .await?;
+        Ok(variants_from(source, languages, generated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn source(plain_summary: Option<&str>) -> SummaryVariantSource {
+        SummaryVariantSource {
+            id: Uuid::new_v4(),
+            kind: "signal".to_string(),
+            title: "Free tax help".to_string(),
+            summary: "Volunteers offer complimentary tax preparation assistance for qualifying households.".to_string(),
+            plain_summary: plain_summary.map(str::to_string),
+        }
+    }
+
+    fn translation(language: &str, summary: &str) -> Translation {
+        Translation {
+            language: language.to_string(),
+            summary: summary.to_string(),
+            plain_summary: format!("{summary} (plain)"),
+        }
+    }
+
+    fn langs(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn only_requested_non_empty_translations_are_kept() {
+        let source = source(Some("Get free help with your taxes."));
+        let generated = GeneratedVariants {
+            plain_summary: String::new(),
+            translations: vec![
+                translation("es-MX", "Voluntarios ofrecen ayuda gratuita con los impuestos."),
+                translation("so", "  "),
+                translation("fr", "Des bénévoles offrent de l'aide."),
+            ],
+        };
+
+        let variants = variants_from(&source, &langs(&["es", "so"]), generated);
+
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].language, "es");
+        assert_eq!(variants[0].source_hash, summary_variants::source_hash(&source.summary));
+    }
+
+    #[test]
+    fn plain_english_is_kept_only_if_it_reads_plainly() {
+        let source = source(None);
+        let plain = GeneratedVariants {
+            plain_summary: "You can get free help with your taxes.".to_string(),
+            translations: vec![],
+        };
+        let dense = GeneratedVariants {
+            plain_summary: "Complimentary professional preparation assistance is available to qualifying households."
+                .to_string(),
+            translations: vec![],
+        };
+
+        let kept = variants_from(&source, &langs(&["en"]), plain);
+        let dropped = variants_from(&source, &langs(&["en"]), dense);
+
+        assert_eq!(kept[0].plain_summary.as_deref(), Some("You can get free help with your taxes."));
+        assert_eq!(kept[0].summary, source.summary);
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].plain_summary, None);
+    }
+
+    #[test]
+    fn prompt_asks_only_for_what_is_missing() {
+        let prompt = variants_prompt(&source(Some("Get free help with your taxes.")), &langs(&["so", "hmn"]));
+
+        assert!(prompt.contains("Leave plain_summary empty"));
+        assert!(prompt.contains("(ISO 639 codes): so, hmn."));
+        assert!(prompt.contains("Plain summary: Get free help with your taxes."));
+    }
+}
@@ -0,0 +1,190 @@
+//! Re-verification of aging signals against their source pages.
+//!
+//! Signals are only refreshed when a scrape happens to re-encounter them, so
+//! signals from rarely-scraped sources drift toward the freshness cutoff even
+//! when they are still live. The sweep re-fetches each aging signal's source
+//! URL and decides whether the signal is still advertised there:
+//!
+//! - **Confirmed** — page still mentions the signal → `last_confirmed_active` bumped.
+//! - **Gone** — page no longer exists or no longer mentions it → signal expired.
+//! - **Inconclusive** — transient failure or empty render → left alone for next sweep.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use chrono::Utc;
+use tracing::{info, warn};
+
+use rootsignal_common::{Classify, Decision, DecisionKind, ErrorClass, ScoutScope};
+use rootsignal_graph::{GraphWriter, VerificationCandidate};
+
+use crate::pipeline::traits::ContentFetcher;
+
+/// Signals not confirmed for this many days are due for verification.
+pub const VERIFY_AFTER_DAYS: u32 = 7;
+
+/// Cap on signals checked per sweep, to bound fetch cost.
+pub const MAX_SIGNALS_PER_SWEEP: u32 = 100;
+
+/// Fraction of significant title words that must appear on the page.
+const TITLE_MATCH_THRESHOLD: f64 = 0.6;
+
+/// Outcome of checking one signal against its source page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityVerdict {
+    Confirmed,
+    Gone,
+    Inconclusive,
+}
+
+/// Per-region tally of a verification sweep.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationSummary {
+    pub checked: u32,
+    pub confirmed: u32,
+    pub expired: u32,
+    pub inconclusive: u32,
+}
+
+/// Decide whether `title` is still advertised on a page with `markdown` content.
+///
+/// Matches on significant words (≥4 chars) rather than the exact title, since
+/// extracted titles are paraphrased from the page.
+pub fn assess_activity(title: &str, markdown: &str) -> ActivityVerdict {
+    if markdown.trim().is_empty() {
+        return ActivityVerdict::Inconclusive;
+    }
+    let page = markdown.to_lowercase();
+    if page.contains(&title.trim().to_lowercase()) {
+        return ActivityVerdict::Confirmed;
+    }
+
+    let words: HashSet<String> = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 4)
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() {
+        return ActivityVerdict::Inconclusive;
+    }
+    let present = words.iter().filter(|w| page.contains(w.as_str())).count();
+    if present as f64 / words.len() as f64 >= TITLE_MATCH_THRESHOLD {
+        ActivityVerdict::Confirmed
+    } else {
+        ActivityVerdict::Gone
+    }
+}
+
+/// Verdict for a source page that could not be fetched.
+pub fn assess_fetch_failure(class: ErrorClass) -> ActivityVerdict {
+    match class {
+        // 404/410/unsupported — the page the signal came from is gone.
+        ErrorClass::User => ActivityVerdict::Gone,
+        ErrorClass::Transient | ErrorClass::Budget | ErrorClass::System => ActivityVerdict::Inconclusive,
+    }
+}
+
+/// Verify one signal by re-fetching its source URL.
+pub async fn verify_signal(fetcher: &dyn ContentFetcher, candidate: &VerificationCandidate) -> ActivityVerdict {
+    match fetcher.page(&candidate.source_url).await {
+        Ok(page) => assess_activity(&candidate.title, &page.markdown),
+        Err(e) => {
+            let class = e.class();
+            warn!(url = candidate.source_url.as_str(), error = %e, error_class = %class, "Verification fetch failed");
+            assess_fetch_failure(class)
+        }
+    }
+}
+
+/// Sweep aging signals in `scope`, refreshing confirmed ones and expiring gone ones.
+pub async fn run_verification_sweep(
+    writer: &GraphWriter,
+    fetcher: &dyn ContentFetcher,
+    scope: &ScoutScope,
+    older_than_days: u32,
+    limit: u32,
+) -> Result<VerificationSummary> {
+    let candidates = writer
+        .find_signals_due_for_verification(scope, older_than_days, limit)
+        .await?;
+    info!(region = scope.name.as_str(), candidates = candidates.len(), "Starting verification sweep");
+
+    let mut summary = VerificationSummary::default();
+    for candidate in &candidates {
+        summary.checked += 1;
+        match verify_signal(fetcher, candidate).await {
+            ActivityVerdict::Confirmed => {
+                writer.refresh_signal(candidate.id, candidate.node_type, Utc::now()).await?;
+                summary.confirmed += 1;
+            }
+            ActivityVerdict::Gone => {
+                if writer.expire_signal(candidate.id, candidate.node_type).await? {
+                    info!(
+                        signal_id = %candidate.id,
+                        title = candidate.title.as_str(),
+                        url = candidate.source_url.as_str(),
+                        "Signal no longer active at source, expired"
+                    );
+                    let decision = Decision::new(
+                        DecisionKind::Suppression,
+                        candidate.id,
+                        candidate.node_type,
+                        "verification",
+                        "expired",
+                    )
+                    .with_inputs(&serde_json::json!({ "source_url": candidate.source_url, "title": candidate.title }))
+                    .with_rationale("Source page no longer exists or no longer mentions the signal");
+                    if let Err(e) = writer.record_decision(&decision).await {
+                        warn!(signal_id = %candidate.id, error = %e, "Failed to record expiry decision");
+                    }
+                }
+                summary.expired += 1;
+            }
+            ActivityVerdict::Inconclusive => summary.inconclusive += 1,
+        }
+    }
+
+    info!(
+        region = scope.name.as_str(),
+        checked = summary.checked,
+        confirmed = summary.confirmed,
+        expired = summary.expired,
+        inconclusive = summary.inconclusive,
+        "Verification sweep complete"
+    );
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_title_on_page_is_confirmed() {
+        let page = "# Events\n\nFree Legal Clinic — every Tuesday at Sabathani Center";
+        assert_eq!(assess_activity("Free Legal Clinic", page), ActivityVerdict::Confirmed);
+    }
+
+    #[test]
+    fn paraphrased_title_with_most_words_present_is_confirmed() {
+        let page = "The Holy Rosary food pantry is open Saturdays 9-12.";
+        assert_eq!(assess_activity("Saturday Food Pantry at Holy Rosary", page), ActivityVerdict::Confirmed);
+    }
+
+    #[test]
+    fn page_that_no_longer_mentions_signal_is_gone() {
+        let page = "# Upcoming\n\nSpring plant sale, May 3rd.";
+        assert_eq!(assess_activity("Winter Coat Drive at Powderhorn Park", page), ActivityVerdict::Gone);
+    }
+
+    #[test]
+    fn empty_page_is_inconclusive() {
+        assert_eq!(assess_activity("Free Legal Clinic", "   "), ActivityVerdict::Inconclusive);
+    }
+
+    #[test]
+    fn missing_page_is_gone_but_transient_failure_is_inconclusive() {
+        assert_eq!(assess_fetch_failure(ErrorClass::User), ActivityVerdict::Gone);
+        assert_eq!(assess_fetch_failure(ErrorClass::Transient), ActivityVerdict::Inconclusive);
+    }
+}
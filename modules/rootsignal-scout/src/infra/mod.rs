@@ -1,3 +1,4 @@
 pub mod embedder;
+pub mod retention;
 pub mod run_log;
 pub mod util;
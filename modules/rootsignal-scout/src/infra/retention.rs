@@ -0,0 +1,68 @@
+//! The retention purge: applies every `RetentionPolicy` across the graph and
+//! Postgres, skipping legal holds, and records the result.
+//!
+//! Runs at the end of each supervisor pass; admins can also run it, or a dry
+//! run of it, from the API.
+
+use chrono::Utc;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::{DataClass, HeldTargets, PurgeCount, PurgeReport};
+use rootsignal_graph::{retention, GraphClient};
+
+use super::run_log;
+
+pub async fn run_purge(client: &GraphClient, pool: &PgPool, dry_run: bool) -> anyhow::Result<PurgeReport> {
+    let now = Utc::now();
+    let policies = retention::retention_policies(client).await?;
+    let holds = retention::active_legal_holds(client).await?;
+    let held = HeldTargets::from_holds(&holds);
+
+    let mut report = PurgeReport {
+        id: Uuid::new_v4(),
+        dry_run,
+        ran_at: now,
+        counts: Vec::new(),
+        errors: Vec::new(),
+    };
+    for policy in policies {
+        let cutoff = policy.cutoff(now);
+        let result = match policy.class {
+            DataClass::ArchiveContent => {
+                rootsignal_archive::retention::purge_content_before(pool, cutoff, &held.source_urls, dry_run)
+                    .await
+                    .map_err(anyhow::Error::from)
+            }
+            DataClass::EvidenceSnippets => retention::purge_evidence_snippets(client, cutoff, &held, dry_run)
+                .await
+                .map_err(anyhow::Error::from),
+            DataClass::ResolvedSituations => retention::purge_resolved_situations(client, cutoff, &held, dry_run)
+                .await
+                .map_err(anyhow::Error::from),
+            DataClass::ScoutRunLogs => run_log::purge_runs_before(pool, cutoff, dry_run).await.map(|n| (n, 0)),
+            DataClass::DecisionRecords => retention::purge_decision_records(client, cutoff, &held, dry_run)
+                .await
+                .map_err(anyhow::Error::from),
+        };
+        match result {
+            Ok((purged, held)) => report.counts.push(PurgeCount {
+                class: policy.class,
+                retain_days: policy.retain_days,
+                purged,
+                held,
+            }),
+            Err(e) => {
+                warn!(class = policy.class.as_str(), error = %e, "Retention purge failed");
+                report.errors.push((policy.class, e.to_string()));
+            }
+        }
+    }
+
+    if let Err(e) = retention::record_purge(client, &report).await {
+        warn!(error = %e, "Failed to record retention run");
+    }
+    info!("{report}");
+    Ok(report)
+}
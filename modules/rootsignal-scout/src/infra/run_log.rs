@@ -1,11 +1,15 @@
 //! Scout run log — persisted timeline of every action taken during a run.
 //!
 //! Each run produces a single row in the `scout_runs` Postgres table
-//! containing JSONB columns for stats and events.
+//! containing JSONB columns for stats, events and the feature flags the run
+//! used.
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use rootsignal_common::error_budget::{FailureSample, OperationTally};
+use rootsignal_common::feature_flags::FeatureFlags;
+use rootsignal_common::ErrorClass;
 use sqlx::PgPool;
 use tracing::info;
 
@@ -19,6 +23,7 @@ pub struct RunLog {
     pub run_id: String,
     pub region: String,
     pub started_at: DateTime<Utc>,
+    flags: FeatureFlags,
     events: Vec<RunEvent>,
     seq: u32,
 }
@@ -42,6 +47,11 @@ pub enum EventKind {
     Bootstrap {
         sources_created: u64,
     },
+    Backfill {
+        canonical_key: String,
+        signals_stored: u32,
+        success: bool,
+    },
     SearchQuery {
         query: String,
         provider: String,
@@ -53,11 +63,24 @@ pub enum EventKind {
         strategy: String,
         success: bool,
         content_bytes: usize,
+        /// Failure class when `success` is false.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error_class: Option<ErrorClass>,
     },
     ScrapeFeed {
         url: String,
         items: u32,
     },
+    /// One batch drained through the scrape work queue.
+    WorkQueueDrained {
+        lane: String,
+        items: u32,
+        extracted: u32,
+        peak_pending_extractions: u32,
+        backpressure_waits: u32,
+        avg_queue_wait_ms: u64,
+        avg_extract_ms: u64,
+    },
     SocialScrape {
         platform: String,
         identifier: String,
@@ -87,6 +110,27 @@ pub enum EventKind {
         matched_id: String,
         similarity: f64,
         action: String,
+        /// Empty in runs logged before it was recorded.
+        #[serde(default)]
+        source_url: String,
+    },
+    /// Matched a negative knowledge entry (`matched_id`) and was not stored.
+    SignalSuppressed {
+        signal_type: String,
+        title: String,
+        matched_id: String,
+        kind: String,
+        similarity: f64,
+        #[serde(default)]
+        source_url: String,
+    },
+    /// Reviewed by the end-of-run judge audit.
+    SignalAudited {
+        node_id: String,
+        signal_type: String,
+        title: String,
+        score: f64,
+        suppressed: bool,
     },
     SignalCorroborated {
         existing_id: String,
@@ -105,6 +149,21 @@ pub enum EventKind {
         spent_cents: u64,
         remaining_cents: u64,
     },
+    /// How synthesis split what was left of the budget between its phases.
+    SynthesisBudgetAllocated {
+        mode: String,
+        /// `None` when the budget is unlimited.
+        remaining_cents: Option<u64>,
+    },
+    /// One synthesis phase's share, and whether it ran or was skipped.
+    SynthesisPhaseAllocated {
+        phase: String,
+        priority: u32,
+        allocated_cents: Option<u64>,
+        /// Targets the share pays for; `None` for phases without targets.
+        max_targets: Option<u32>,
+        outcome: String,
+    },
 }
 
 impl RunLog {
@@ -113,11 +172,18 @@ impl RunLog {
             run_id,
             region,
             started_at: Utc::now(),
+            flags: FeatureFlags::default(),
             events: Vec::new(),
             seq: 0,
         }
     }
 
+    /// Record the feature flags the run resolved.
+    pub fn with_flags(mut self, flags: FeatureFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
     pub fn log(&mut self, kind: EventKind) {
         self.events.push(RunEvent {
             seq: self.seq,
@@ -127,15 +193,39 @@ impl RunLog {
         self.seq += 1;
     }
 
+    /// Events logged so far, in order.
+    pub fn events(&self) -> &[RunEvent] {
+        &self.events
+    }
+
+    /// The run's page fetches, for error budgets. Failed fetches are sampled
+    /// by URL and error class.
+    pub fn scrape_tally(&self, stats: &ScoutStats) -> OperationTally {
+        let mut samples = Vec::new();
+        for event in &self.events {
+            if let EventKind::ScrapeUrl { url, success: false, error_class, .. } = &event.kind {
+                let reason = error_class.map_or("unknown", |c| c.as_str());
+                FailureSample::keep(&mut samples, reason, url.clone());
+            }
+        }
+        OperationTally::new(
+            "scrape",
+            stats.urls_scraped + stats.urls_unchanged + stats.urls_failed,
+            stats.urls_failed,
+            samples,
+        )
+    }
+
     /// Serialize the run log and write to Postgres.
     pub async fn save_to_db(&self, pool: &PgPool, stats: &ScoutStats) -> Result<()> {
         let stats_json = serde_json::to_value(SerializedStats::from(stats))?;
         let events_json = serde_json::to_value(&self.events)?;
+        let flags_json = serde_json::to_value(self.flags.to_map())?;
 
         sqlx::query(
             r#"
-            INSERT INTO scout_runs (run_id, region, started_at, finished_at, stats, events)
-            VALUES ($1, $2, $3, now(), $4, $5)
+            INSERT INTO scout_runs (run_id, region, started_at, finished_at, stats, events, flags)
+            VALUES ($1, $2, $3, now(), $4, $5, $6)
             "#,
         )
         .bind(&self.run_id)
@@ -143,6 +233,7 @@ impl RunLog {
         .bind(self.started_at)
         .bind(&stats_json)
         .bind(&events_json)
+        .bind(&flags_json)
         .execute(pool)
         .await?;
 
@@ -151,6 +242,17 @@ impl RunLog {
     }
 }
 
+/// Delete run logs finished before `cutoff`. A dry run only counts.
+pub async fn purge_runs_before(pool: &PgPool, cutoff: DateTime<Utc>, dry_run: bool) -> Result<u64> {
+    let sql = if dry_run {
+        "SELECT count(*) FROM scout_runs WHERE finished_at < $1"
+    } else {
+        "WITH deleted AS (DELETE FROM scout_runs WHERE finished_at < $1 RETURNING 1) SELECT count(*) FROM deleted"
+    };
+    let count = sqlx::query_scalar::<_, i64>(sql).bind(cutoff).fetch_one(pool).await?;
+    Ok(count as u64)
+}
+
 // ---------------------------------------------------------------------------
 // Serialization wrappers
 // ---------------------------------------------------------------------------
@@ -162,6 +264,7 @@ struct SerializedStats {
     urls_failed: u32,
     signals_extracted: u32,
     signals_deduplicated: u32,
+    signals_suppressed: u32,
     signals_stored: u32,
     social_media_posts: u32,
     expansion_queries_collected: u32,
@@ -176,6 +279,7 @@ impl From<&ScoutStats> for SerializedStats {
             urls_failed: s.urls_failed,
             signals_extracted: s.signals_extracted,
             signals_deduplicated: s.signals_deduplicated,
+            signals_suppressed: s.signals_suppressed,
             signals_stored: s.signals_stored,
             social_media_posts: s.social_media_posts,
             expansion_queries_collected: s.expansion_queries_collected,
@@ -183,3 +287,36 @@ impl From<&ScoutStats> for SerializedStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrape_tally_samples_failed_fetches() {
+        let mut run_log = RunLog::new("run-1".to_string(), "Minneapolis".to_string());
+        let stats = ScoutStats { urls_scraped: 6, urls_unchanged: 2, urls_failed: 2, ..Default::default() };
+        run_log.log(EventKind::ScrapeUrl {
+            url: "https://a.example.org".to_string(),
+            strategy: "web".to_string(),
+            success: true,
+            content_bytes: 10,
+            error_class: None,
+        });
+        run_log.log(EventKind::ScrapeUrl {
+            url: "https://b.example.org".to_string(),
+            strategy: "web".to_string(),
+            success: false,
+            content_bytes: 0,
+            error_class: Some(ErrorClass::Transient),
+        });
+
+        let tally = run_log.scrape_tally(&stats);
+
+        assert_eq!((tally.attempts, tally.failures), (10, 2));
+        assert_eq!(tally.samples, vec![FailureSample {
+            reason: "transient".to_string(),
+            error: "https://b.example.org".to_string(),
+        }]);
+    }
+}
@@ -1,8 +1,12 @@
+pub mod agendas;
 pub mod discovery;
 pub mod enrichment;
 pub mod infra;
+pub mod memory;
+pub mod open_data;
 pub mod pipeline;
 pub mod scheduling;
+pub mod service_alerts;
 #[cfg(any(test, feature = "test-support"))]
 pub mod testing;
 pub mod workflows;
@@ -1,26 +1,19 @@
-use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
-
 use anyhow::{Context, Result};
 use clap::Parser;
-use serde::Serialize;
 use sqlx::postgres::PgPoolOptions;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
-use rootsignal_common::{Config, Node, NodeType, ScoutScope, SituationNode};
+use rootsignal_common::tenancy::DEFAULT_DATABASE;
+use rootsignal_common::{Config, ScoutScope, TenantRegistry};
 use rootsignal_graph::{
-    migrate::{backfill_source_canonical_keys, backfill_source_diversity, migrate},
-    query,
-    reader::{node_type_label, row_to_node},
-    GraphClient, GraphWriter, PublicGraphReader,
+    dump,
+    migrate::{backfill_source_canonical_keys, backfill_source_diversity},
+    GraphClient, GraphRouter,
 };
 
-use rootsignal_scout::infra::embedder::{Embedder, TextEmbedder};
-use rootsignal_scout::pipeline::extractor::{Extractor, SignalExtractor};
-use rootsignal_scout::pipeline::scrape_pipeline::ScrapePipeline;
-use rootsignal_scout::scheduling::budget::BudgetTracker;
-use rootsignal_scout::workflows::{create_archive, ScoutDeps};
+use rootsignal_scout::workflows::full_run::run_local;
+use rootsignal_scout::workflows::{blob_backend, ScoutDeps};
 
 #[derive(Parser)]
 #[command(about = "Run the Root Signal scout for a region")]
@@ -28,23 +21,34 @@ struct Cli {
     /// Region slug (e.g. "minneapolis"). Overrides REGION env var.
     region: Option<String>,
 
+    /// Run for a tenant from TENANTS_FILE: its database, region and budget.
+    #[arg(long)]
+    tenant: Option<String>,
+
     /// Dump raw graph data (situations + signals) as JSON to stdout instead of running the scout.
     #[arg(long)]
     dump: bool,
-}
 
-#[derive(Serialize)]
-struct DumpOutput {
-    region: String,
-    situations: Vec<SituationDump>,
-    ungrouped_signals: Vec<Node>,
-}
+    /// List pending dead-lettered workflow items instead of running the scout.
+    #[arg(long)]
+    dead_letters: bool,
+
+    /// Replay a dead-lettered item (by id) into its workflow via RESTATE_INGRESS_URL.
+    #[arg(long, value_name = "ID")]
+    replay: Option<uuid::Uuid>,
 
-#[derive(Serialize)]
-struct SituationDump {
-    #[serde(flatten)]
-    situation: SituationNode,
-    signals: Vec<Node>,
+    /// Move large archived text and transcripts out of Postgres rows into the
+    /// configured blob store (BLOB_STORE_PATH or S3_BUCKET) instead of running the scout.
+    #[arg(long)]
+    migrate_blobs: bool,
+
+    /// With --migrate-blobs, only report what would move.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Report blob store usage and the space saved by deduplication and compression.
+    #[arg(long)]
+    blob_stats: bool,
 }
 
 #[tokio::main]
@@ -62,61 +66,86 @@ async fn main() -> Result<()> {
     // Load config, with optional CLI region override
     let cli = Cli::parse();
     let mut config = Config::scout_from_env();
+    let database = match &cli.tenant {
+        Some(slug) => {
+            let registry = TenantRegistry::from_env()?.context("--tenant requires TENANTS_FILE")?;
+            let tenant = registry.get(slug).with_context(|| format!("Unknown tenant {slug}"))?;
+            config = tenant.config(&config);
+            info!(tenant = slug.as_str(), database = tenant.database.as_str(), "Running for tenant");
+            tenant.database.clone()
+        }
+        None => DEFAULT_DATABASE.to_string(),
+    };
     if let Some(region) = cli.region {
         config.region = region;
     }
 
-    // Connect to Neo4j
-    let client = GraphClient::connect(
+    if cli.dead_letters || cli.replay.is_some() {
+        return dead_letters(cli.replay).await;
+    }
+
+    // Connect to Neo4j, creating a tenant's database on its first run
+    if database != DEFAULT_DATABASE {
+        GraphClient::connect(&config.neo4j_uri, &config.neo4j_user, &config.neo4j_password.expose())
+            .await?
+            .create_database(&database)
+            .await?;
+    }
+    let client = GraphClient::connect_database(
         &config.neo4j_uri,
         &config.neo4j_user,
-        &config.neo4j_password,
+        &config.neo4j_password.expose(),
+        &database,
     )
     .await?;
+    let router = GraphRouter::from_config(client.clone(), &config);
 
     if cli.dump {
-        return dump_region(&client, &config.region).await;
+        return dump_region(&router.for_region(&config.region).await?, &config.region).await;
+    }
+
+    if cli.migrate_blobs {
+        return migrate_blobs(router, &config, cli.dry_run).await;
+    }
+
+    if cli.blob_stats {
+        return blob_stats(router, &config).await;
     }
 
     config.log_redacted();
 
-    // Run migrations
-    migrate(&client).await?;
+    // Run migrations on the default database and every routed region database
+    router.migrate_all().await?;
+    let region_client = router.for_region(&config.region).await?;
 
-    // Construct ScoutScope from env vars
+    // Construct ScoutScope from env vars: a boundary file if there is one,
+    // otherwise center + radius
     let region_name = config.region_name.as_deref().unwrap_or(&config.region);
-    let center_lat = config
-        .region_lat
-        .expect("REGION_LAT required");
-    let center_lng = config
-        .region_lng
-        .expect("REGION_LNG required");
-    let radius_km = config.region_radius_km.unwrap_or(30.0);
-
-    let region = ScoutScope {
-        center_lat,
-        center_lng,
-        radius_km,
-        name: region_name.to_string(),
+    let region = match config.region_boundary()? {
+        Some(boundary) => ScoutScope::from_boundary(region_name, boundary),
+        None => ScoutScope {
+            center_lat: config.region_lat.expect("REGION_LAT required"),
+            center_lng: config.region_lng.expect("REGION_LNG required"),
+            radius_km: config.region_radius_km.unwrap_or(30.0),
+            name: region_name.to_string(),
+            boundary: None,
+        },
     };
 
     info!(
         name = region.name.as_str(),
-        lat = center_lat,
-        lng = center_lng,
-        radius_km,
+        lat = region.center_lat,
+        lng = region.center_lng,
+        radius_km = region.radius_km,
+        bounded = region.boundary.is_some(),
         "Constructed ScoutScope from env vars"
     );
 
     // Backfill canonical keys on existing Source nodes (idempotent migration)
-    backfill_source_canonical_keys(&client).await?;
+    backfill_source_canonical_keys(&region_client).await?;
 
     // Backfill source diversity for existing signals (no entity mappings — domain fallback handles it)
-    backfill_source_diversity(&client, &[]).await?;
-
-    // Save region geo bounds before moving region into pipeline
-    let region_name_key = region.name.clone();
-    let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
+    backfill_source_diversity(&region_client, &[]).await?;
 
     // Connect to Postgres for the web archive
     let database_url = std::env::var("DATABASE_URL")
@@ -130,198 +159,124 @@ async fn main() -> Result<()> {
     // Build shared deps (same struct the Restate workflows use)
     let deps = ScoutDeps::builder()
         .graph_client(client.clone())
+        .graph_router(Some(router))
         .pg_pool(pool)
         .anthropic_api_key(config.anthropic_api_key.clone())
         .voyage_api_key(config.voyage_api_key.clone())
         .serper_api_key(config.serper_api_key.clone())
         .apify_api_key(config.apify_api_key.clone())
+        .apify_webhook_url(config.apify_webhook_url.clone())
+        .apify_webhook_secret(config.apify_webhook_secret.clone())
+        .tavily_api_key(config.tavily_api_key.clone())
+        .brave_search_api_key(config.brave_search_api_key.clone())
+        .searxng_url(config.searxng_url.clone())
+        .search_providers(config.search_providers.clone())
+        .search_daily_limits(config.search_daily_limits.clone())
         .daily_budget_cents(config.daily_budget_cents)
         .browserless_url(config.browserless_url.clone())
         .browserless_token(config.browserless_token.clone())
+        .source_credentials_key(config.source_credentials_key.clone())
+        .openai_api_key(config.openai_api_key.clone())
+        .whisper_bin(config.whisper_bin.clone())
+        .whisper_model(config.whisper_model.clone())
+        .eventbrite_token(config.eventbrite_token.clone())
+        .socrata_app_token(config.socrata_app_token.clone())
+        .blob_store_path(config.blob_store_path.clone())
+        .s3_endpoint(config.s3_endpoint.clone())
+        .s3_bucket(config.s3_bucket.clone())
+        .s3_region(config.s3_region.clone())
+        .s3_access_key_id(config.s3_access_key_id.clone())
+        .s3_secret_access_key(config.s3_secret_access_key.clone())
+        .s3_cold_storage_class(config.s3_cold_storage_class.clone())
+        .blob_cold_after_days(config.blob_cold_after_days)
+        .summary_languages(config.summary_languages.clone())
         .build();
 
-    let writer = GraphWriter::new(deps.graph_client.clone());
-
-    // Check if any task for this region is already running
-    if writer
-        .is_region_task_running(&region.name)
-        .await
-        .context("Failed to check running status")?
-    {
-        anyhow::bail!("Another scout run is in progress for {}", region.name);
-    }
-
-    let result = run_full_scout(&deps, region).await;
-
-    let stats = result?;
-    info!("Scout run complete. {stats}");
-
-    // Actor extraction — extract actors from signals that have none.
-    // Not yet part of any workflow, so it runs here post-run.
-    info!("Starting actor extraction...");
-    let sweep_stats = rootsignal_scout::enrichment::actor_extractor::run_actor_extraction(
-        &writer,
-        &client,
-        &config.anthropic_api_key,
-        &region_name_key,
-        min_lat,
-        max_lat,
-        min_lng,
-        max_lng,
-    )
-    .await;
-    info!("{sweep_stats}");
+    run_local(&deps, region).await?;
 
     Ok(())
 }
 
-/// Run a full scout cycle: scrape → synthesis → situation weaving → supervisor.
-///
-/// Delegates to the same functions that the Restate workflows use, avoiding duplication.
-async fn run_full_scout(
-    deps: &ScoutDeps,
-    region: ScoutScope,
-) -> Result<rootsignal_scout::pipeline::stats::ScoutStats> {
-    let extractor: Arc<dyn SignalExtractor> = Arc::new(Extractor::new(
-        &deps.anthropic_api_key,
-        region.name.as_str(),
-        region.center_lat,
-        region.center_lng,
-    ));
-    let embedder: Arc<dyn TextEmbedder> =
-        Arc::new(Embedder::new(&deps.voyage_api_key));
-    let archive = create_archive(deps);
-    let budget = BudgetTracker::new(deps.daily_budget_cents);
-    let cancelled = Arc::new(AtomicBool::new(false));
-    let run_id = uuid::Uuid::new_v4().to_string();
-    let writer = GraphWriter::new(deps.graph_client.clone());
-
-    // === Scrape pipeline ===
-    let pipeline = ScrapePipeline::new(
-        writer,
-        extractor,
-        embedder,
-        archive,
-        deps.anthropic_api_key.clone(),
-        region.clone(),
-        &budget,
-        cancelled,
-        run_id,
-        deps.pg_pool.clone(),
-    );
-    let stats = pipeline.run_all().await?;
+/// List pending dead letters, or replay one when `replay` is set.
+async fn dead_letters(replay: Option<uuid::Uuid>) -> Result<()> {
+    use rootsignal_common::dead_letter::{self, DeadLetterStatus};
 
-    let spent_so_far = budget.total_spent();
+    let database_url = std::env::var("DATABASE_URL").context("DATABASE_URL required")?;
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .context("Failed to connect to Postgres")?;
 
-    // === Synthesis (parallel finders + similarity edges) ===
-    let synthesis_result = rootsignal_scout::workflows::synthesis::run_synthesis_from_deps(
-        deps, &region, spent_so_far,
-    ).await?;
+    if let Some(id) = replay {
+        let ingress_url = std::env::var("RESTATE_INGRESS_URL").context("RESTATE_INGRESS_URL required")?;
+        let key = dead_letter::replay(&pool, &reqwest::Client::new(), &ingress_url, id).await?;
+        println!("Replayed {id} as {key}");
+        return Ok(());
+    }
+
+    let entries = dead_letter::list(&pool, Some(DeadLetterStatus::Pending), 200).await?;
+    if entries.is_empty() {
+        println!("No pending dead letters");
+    }
+    for dl in entries {
+        println!(
+            "{}  {}  {}  attempts={}  [{}] {}",
+            dl.id,
+            dl.workflow,
+            dl.item_key,
+            dl.attempts.len(),
+            dl.error_class,
+            dl.error,
+        );
+    }
+    Ok(())
+}
 
-    // === Situation weaving + source boost + curiosity re-investigation ===
-    let _weaver_result = rootsignal_scout::workflows::situation_weaver::run_situation_weaving_from_deps(
-        deps, &region, synthesis_result.spent_cents,
-    ).await?;
+async fn migrate_blobs(router: GraphRouter, config: &Config, dry_run: bool) -> Result<()> {
+    let deps = ScoutDeps::from_config(router, blob_pool().await?, config);
+    let report =
+        rootsignal_archive::blob::migrate_to_blob_store(&deps.pg_pool, blob_backend(&deps), 100, dry_run).await?;
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    println!("{verb} {} rows ({} bytes) to the blob store", report.rows, report.bytes);
+    Ok(())
+}
 
-    // === Supervisor (merge tensions, compute cause heat, detect beacons) ===
-    let _supervisor_result = rootsignal_scout::workflows::supervisor::run_supervisor_pipeline(
-        deps, &region,
-    ).await?;
+async fn blob_stats(router: GraphRouter, config: &Config) -> Result<()> {
+    let deps = ScoutDeps::from_config(router, blob_pool().await?, config);
+    let stats = rootsignal_archive::blob::storage_stats(&deps.pg_pool, blob_backend(&deps)).await?;
+    println!("{} blobs referenced by {} rows", stats.blobs, stats.references);
+    println!("  logical:    {:>14} bytes", stats.logical_bytes);
+    println!("  unique:     {:>14} bytes (dedup saved {})", stats.unique_bytes, stats.saved_by_dedup());
+    println!("  stored:     {:>14} bytes (compression saved {})", stats.stored_bytes, stats.saved_by_compression());
+    println!("  saved:      {:>13.1}%", stats.saved_ratio() * 100.0);
+    Ok(())
+}
 
-    Ok(stats)
+async fn blob_pool() -> Result<sqlx::PgPool> {
+    let database_url = std::env::var("DATABASE_URL").context("DATABASE_URL required")?;
+    PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .context("Failed to connect to Postgres")
 }
 
 /// Dump all situations and signals for a region as raw JSON to stdout.
 async fn dump_region(client: &GraphClient, region_slug: &str) -> Result<()> {
     // Construct geo bounds from env vars (same as main scout flow)
     let config = Config::scout_from_env();
-    let center_lat = config
-        .region_lat
-        .context("REGION_LAT required for dump")?;
-    let center_lng = config
-        .region_lng
-        .context("REGION_LNG required for dump")?;
-    let radius_km = config.region_radius_km.unwrap_or(30.0);
-
-    let scope = ScoutScope {
-        center_lat,
-        center_lng,
-        radius_km,
-        name: region_slug.to_string(),
-    };
-    let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
-
-    // Fetch all situations in the region's bounding box
-    let reader = PublicGraphReader::new(client.clone());
-    let situation_nodes = reader
-        .situations_in_bounds(min_lat, max_lat, min_lng, max_lng, 500, None)
-        .await?;
-
-    let mut situations: Vec<SituationDump> = Vec::new();
-    let mut grouped_signal_ids = std::collections::HashSet::new();
-
-    // For each situation, fetch its signals via EVIDENCES edges
-    for situation in situation_nodes {
-        let mut signals = Vec::new();
-        for nt in &[
-            NodeType::Gathering,
-            NodeType::Aid,
-            NodeType::Need,
-            NodeType::Notice,
-            NodeType::Tension,
-        ] {
-            let label = node_type_label(*nt);
-            let cypher = format!(
-                "MATCH (n:{label})-[:EVIDENCES]->(s:Situation {{id: $id}}) RETURN n ORDER BY n.confidence DESC"
-            );
-            let q = query(&cypher).param("id", situation.id.to_string());
-            let mut sig_stream = client.inner().execute(q).await?;
-            while let Some(row) = sig_stream.next().await? {
-                if let Some(node) = row_to_node(&row, *nt) {
-                    grouped_signal_ids.insert(node.id());
-                    signals.push(node);
-                }
-            }
-        }
-        situations.push(SituationDump { situation, signals });
-    }
-
-    // Fetch ungrouped signals (not evidencing any situation) within the bounding box
-    let mut ungrouped = Vec::new();
-    for nt in &[
-        NodeType::Gathering,
-        NodeType::Aid,
-        NodeType::Need,
-        NodeType::Notice,
-        NodeType::Tension,
-    ] {
-        let label = node_type_label(*nt);
-        let cypher = format!(
-            "MATCH (n:{label})
-             WHERE n.lat >= $min_lat AND n.lat <= $max_lat
-               AND n.lng >= $min_lng AND n.lng <= $max_lng
-               AND NOT (n)-[:EVIDENCES]->(:Situation)
-             RETURN n
-             ORDER BY n.confidence DESC"
-        );
-        let q = query(&cypher)
-            .param("min_lat", min_lat)
-            .param("max_lat", max_lat)
-            .param("min_lng", min_lng)
-            .param("max_lng", max_lng);
-        let mut stream = client.inner().execute(q).await?;
-        while let Some(row) = stream.next().await? {
-            if let Some(node) = row_to_node(&row, *nt) {
-                ungrouped.push(node);
-            }
-        }
-    }
-
-    let output = DumpOutput {
-        region: region_slug.to_string(),
-        situations,
-        ungrouped_signals: ungrouped,
+    let scope = match config.region_boundary()? {
+        Some(boundary) => ScoutScope::from_boundary(region_slug, boundary),
+        None => ScoutScope {
+            center_lat: config.region_lat.context("REGION_LAT required for dump")?,
+            center_lng: config.region_lng.context("REGION_LNG required for dump")?,
+            radius_km: config.region_radius_km.unwrap_or(30.0),
+            name: region_slug.to_string(),
+            boundary: None,
+        },
     };
+    let output = dump::dump_region(client, &scope).await?;
 
     println!("{}", serde_json::to_string_pretty(&output)?);
     Ok(())
@@ -0,0 +1,355 @@
+//! The region memo, its Postgres store, and the size-bounded renderer it is
+//! shown to prompts through.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+
+/// Characters of memo shown in a prompt.
+pub const MEMO_PROMPT_CHARS: usize = 3_000;
+/// Facts kept per section.
+pub const MAX_FACTS_PER_SECTION: usize = 12;
+/// Longest fact kept, in characters.
+const MAX_FACT_CHARS: usize = 200;
+
+// ---------------------------------------------------------------------------
+// MemoBuilder
+// ---------------------------------------------------------------------------
+
+/// Renders titled sections of one-line facts into a block of at most
+/// `max_chars` characters. Sections take turns claiming the budget, one line
+/// at a time, so a long section can't crowd out the rest; within a section
+/// lines are kept in the order given.
+pub struct MemoBuilder {
+    max_chars: usize,
+    sections: Vec<(String, Vec<String>)>,
+}
+
+impl MemoBuilder {
+    pub fn new(max_chars: usize) -> Self {
+        Self {
+            max_chars,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Add a section. Lines go in priority order; empty sections are left out.
+    pub fn section<I, S>(mut self, title: &str, lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let lines: Vec<String> = lines
+            .into_iter()
+            .map(Into::into)
+            .filter(|l: &String| !l.trim().is_empty())
+            .collect();
+        self.sections.push((title.to_string(), lines));
+        self
+    }
+
+    /// `## TITLE` followed by `- line`s, sections separated by a blank line.
+    pub fn build(&self) -> String {
+        let mut taken = vec![0usize; self.sections.len()];
+        let mut open: Vec<bool> = self.sections.iter().map(|(_, lines)| !lines.is_empty()).collect();
+        let mut used = 0;
+        let mut any_section = false;
+        while open.iter().any(|o| *o) {
+            for (i, (title, lines)) in self.sections.iter().enumerate() {
+                if !open[i] {
+                    continue;
+                }
+                // "\n- line"
+                let mut cost = lines[taken[i]].chars().count() + 3;
+                if taken[i] == 0 {
+                    // "## TITLE", plus the blank line separating it from the previous section
+                    cost += title.chars().count() + 3 + if any_section { 2 } else { 0 };
+                }
+                if used + cost > self.max_chars {
+                    open[i] = false;
+                    continue;
+                }
+                used += cost;
+                any_section = true;
+                taken[i] += 1;
+                if taken[i] == lines.len() {
+                    open[i] = false;
+                }
+            }
+        }
+
+        self.sections
+            .iter()
+            .zip(&taken)
+            .filter(|(_, n)| **n > 0)
+            .map(|((title, lines), n)| {
+                let mut block = format!("## {title}");
+                for line in &lines[..*n] {
+                    block.push_str("\n- ");
+                    block.push_str(line);
+                }
+                block
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RegionMemo
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoSection {
+    KeyActors,
+    RecurringVenues,
+    ActiveSituations,
+    KnownHoaxes,
+    StyleNotes,
+}
+
+impl MemoSection {
+    /// In the order they're shown to prompts.
+    pub const ALL: [MemoSection; 5] = [
+        MemoSection::KeyActors,
+        MemoSection::RecurringVenues,
+        MemoSection::ActiveSituations,
+        MemoSection::KnownHoaxes,
+        MemoSection::StyleNotes,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            MemoSection::KeyActors => "KEY ACTORS",
+            MemoSection::RecurringVenues => "RECURRING VENUES",
+            MemoSection::ActiveSituations => "ACTIVE SITUATIONS",
+            MemoSection::KnownHoaxes => "KNOWN HOAXES",
+            MemoSection::StyleNotes => "STYLE NOTES",
+        }
+    }
+
+    /// Days a fact may go unseen before it's dropped. Situations move fast;
+    /// organizations, places and hoaxes stick around.
+    pub fn stale_after_days(self) -> i64 {
+        match self {
+            MemoSection::ActiveSituations => 30,
+            MemoSection::KeyActors | MemoSection::RecurringVenues => 180,
+            MemoSection::KnownHoaxes | MemoSection::StyleNotes => 365,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoFact {
+    pub text: String,
+    /// The last run date the fact was confirmed.
+    pub last_seen: NaiveDate,
+}
+
+impl MemoFact {
+    pub fn new(text: &str, last_seen: NaiveDate) -> Self {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let text = match text.char_indices().nth(MAX_FACT_CHARS) {
+            Some((end, _)) => text[..end].to_string(),
+            None => text,
+        };
+        Self { text, last_seen }
+    }
+}
+
+/// What the scout remembers about a region between runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegionMemo {
+    pub key_actors: Vec<MemoFact>,
+    pub recurring_venues: Vec<MemoFact>,
+    pub active_situations: Vec<MemoFact>,
+    pub known_hoaxes: Vec<MemoFact>,
+    pub style_notes: Vec<MemoFact>,
+}
+
+impl RegionMemo {
+    pub fn facts(&self, section: MemoSection) -> &[MemoFact] {
+        match section {
+            MemoSection::KeyActors => &self.key_actors,
+            MemoSection::RecurringVenues => &self.recurring_venues,
+            MemoSection::ActiveSituations => &self.active_situations,
+            MemoSection::KnownHoaxes => &self.known_hoaxes,
+            MemoSection::StyleNotes => &self.style_notes,
+        }
+    }
+
+    pub fn facts_mut(&mut self, section: MemoSection) -> &mut Vec<MemoFact> {
+        match section {
+            MemoSection::KeyActors => &mut self.key_actors,
+            MemoSection::RecurringVenues => &mut self.recurring_venues,
+            MemoSection::ActiveSituations => &mut self.active_situations,
+            MemoSection::KnownHoaxes => &mut self.known_hoaxes,
+            MemoSection::StyleNotes => &mut self.style_notes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        MemoSection::ALL.iter().all(|s| self.facts(*s).is_empty())
+    }
+
+    /// Drop facts unseen for longer than their section allows and duplicates,
+    /// then keep the freshest facts per section.
+    pub fn prune(&mut self, today: NaiveDate) {
+        for section in MemoSection::ALL {
+            let facts = self.facts_mut(section);
+            facts.retain(|f| (today - f.last_seen).num_days() <= section.stale_after_days());
+            facts.sort_by_key(|f| std::cmp::Reverse(f.last_seen));
+            let mut seen = std::collections::HashSet::new();
+            facts.retain(|f| !f.text.is_empty() && seen.insert(f.text.to_lowercase()));
+            facts.truncate(MAX_FACTS_PER_SECTION);
+        }
+    }
+
+    /// The memo as prompt text, freshest facts first, within `max_chars`.
+    pub fn render(&self, max_chars: usize) -> String {
+        MemoSection::ALL
+            .iter()
+            .fold(MemoBuilder::new(max_chars), |builder, section| {
+                builder.section(section.title(), self.facts(*section).iter().map(|f| f.text.clone()))
+            })
+            .build()
+    }
+
+    /// The memo framed as background for an extractor or finder prompt.
+    /// `None` while the region has no memory yet.
+    pub fn prompt_block(&self, region_name: &str) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "## REGION MEMORY\n\
+             What earlier runs learned about {region_name}. Use it to recognize names, places and \
+             ongoing situations, and be skeptical of claims matching a known hoax. It is background, \
+             not evidence: never report something only because it appears here.\n\n{}",
+            self.render(MEMO_PROMPT_CHARS)
+        ))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RegionMemoStore
+// ---------------------------------------------------------------------------
+
+/// A region's memo in the `region_memos` table.
+pub struct RegionMemoStore {
+    pool: PgPool,
+    region: String,
+}
+
+impl RegionMemoStore {
+    pub fn new(pool: PgPool, region: &str) -> Self {
+        Self {
+            pool,
+            region: region.to_string(),
+        }
+    }
+
+    /// The region's memo; empty if it has none yet.
+    pub async fn load(&self) -> Result<RegionMemo> {
+        let memo = sqlx::query_scalar::<_, serde_json::Value>("SELECT memo FROM region_memos WHERE region = $1")
+            .bind(&self.region)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(match memo {
+            Some(v) => serde_json::from_value(v)?,
+            None => RegionMemo::default(),
+        })
+    }
+
+    /// Like `load`, but a run never fails for lack of memory.
+    pub async fn load_or_default(&self) -> RegionMemo {
+        self.load().await.unwrap_or_else(|e| {
+            warn!(error = %e, region = self.region.as_str(), "Failed to load region memo (non-fatal)");
+            RegionMemo::default()
+        })
+    }
+
+    pub async fn save(&self, memo: &RegionMemo, run_id: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO region_memos (region, memo, run_id, updated_at)
+            VALUES ($1, $2, $3, now())
+            ON CONFLICT (region) DO UPDATE
+            SET memo = EXCLUDED.memo, run_id = EXCLUDED.run_id, updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&self.region)
+        .bind(serde_json::to_value(memo)?)
+        .bind(run_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn builder_shares_budget_across_sections() {
+        let long: Vec<String> = (0..50).map(|i| format!("actor number {i} runs a food shelf")).collect();
+        let memo = MemoBuilder::new(300)
+            .section("KEY ACTORS", long)
+            .section("KNOWN HOAXES", ["Fake FEMA cash card texts"])
+            .section("EMPTY", Vec::<String>::new())
+            .build();
+
+        assert!(memo.chars().count() <= 300);
+        assert!(memo.starts_with("## KEY ACTORS\n- actor number 0 runs a food shelf"));
+        assert!(memo.contains("\n\n## KNOWN HOAXES\n- Fake FEMA cash card texts"));
+        assert!(!memo.contains("EMPTY"));
+    }
+
+    #[test]
+    fn builder_fills_budget_exactly() {
+        let memo = MemoBuilder::new(21).section("A", ["1234567890", "x"]).build();
+        assert_eq!(memo, "## A\n- 1234567890\n- x");
+        assert_eq!(MemoBuilder::new(20).section("A", ["1234567890", "x"]).build(), "## A\n- 1234567890");
+        assert_eq!(MemoBuilder::new(5).section("A", ["1234567890"]).build(), "");
+    }
+
+    #[test]
+    fn prune_drops_stale_and_duplicate_facts() {
+        let mut memo = RegionMemo {
+            active_situations: vec![
+                MemoFact::new("Lake Street rent strike", day(1)),
+                MemoFact::new("Encampment sweep at Near North", NaiveDate::from_ymd_opt(2026, 1, 2).unwrap()),
+            ],
+            key_actors: vec![
+                MemoFact::new("Northside Food Shelf", day(1)),
+                MemoFact::new("northside food shelf", day(20)),
+            ],
+            ..Default::default()
+        };
+        memo.prune(day(28));
+
+        assert_eq!(memo.active_situations, vec![MemoFact::new("Lake Street rent strike", day(1))]);
+        assert_eq!(memo.key_actors, vec![MemoFact::new("northside food shelf", day(20))]);
+    }
+
+    #[test]
+    fn empty_memo_has_no_prompt_block() {
+        assert!(RegionMemo::default().prompt_block("Minneapolis").is_none());
+
+        let memo = RegionMemo {
+            known_hoaxes: vec![MemoFact::new("Fake FEMA cash card texts", day(1))],
+            ..Default::default()
+        };
+        let block = memo.prompt_block("Minneapolis").unwrap();
+        assert!(block.starts_with("## REGION MEMORY\nWhat earlier runs learned about Minneapolis."));
+        assert!(block.ends_with("## KNOWN HOAXES\n- Fake FEMA cash card texts"));
+    }
+}
@@ -0,0 +1,10 @@
+//! Region memory — what the scout has learned about a region, carried across runs.
+//!
+//! Every run used to start context-free. Each region now keeps a memo of key
+//! actors, recurring venues, active situations, known hoaxes and style notes.
+//! It is loaded at the start of a run and injected into the extractor and
+//! finder prompts as background, then rewritten at the end of the run by an
+//! LLM that folds in what the run found and prunes stale facts.
+
+pub mod memo;
+pub mod updater;
@@ -0,0 +1,283 @@
+//! End-of-run memo update: an LLM folds what the run found into the region
+//! memo and drops facts that no longer hold.
+//!
+//! The LLM sees the current memo with numbered facts and a digest of the run
+//! (signals created and seen again, the situation landscape). It returns the
+//! next memo, marking which current fact each line carries over and whether
+//! the run confirmed it. Dates are assigned here, not by the LLM, and stale
+//! facts are pruned by `RegionMemo::prune` whatever the LLM kept.
+
+use anyhow::Result;
+use ai_client::claude::Claude;
+use chrono::{NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tracing::info;
+
+use rootsignal_graph::{GraphWriter, SituationBrief};
+
+use crate::infra::run_log::{EventKind, RunLog};
+use crate::memory::memo::{MemoFact, MemoSection, RegionMemo, RegionMemoStore, MAX_FACTS_PER_SECTION};
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
+
+const HAIKU_MODEL: &str = "claude-haiku-4-5-20251001";
+/// Signals from the run shown to the LLM.
+const MAX_DIGEST_SIGNALS: usize = 60;
+/// Situations from the landscape shown to the LLM.
+const MAX_DIGEST_SITUATIONS: u32 = 15;
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct MemoDraft {
+    #[serde(default)]
+    pub key_actors: Vec<DraftFact>,
+    #[serde(default)]
+    pub recurring_venues: Vec<DraftFact>,
+    #[serde(default)]
+    pub active_situations: Vec<DraftFact>,
+    #[serde(default)]
+    pub known_hoaxes: Vec<DraftFact>,
+    #[serde(default)]
+    pub style_notes: Vec<DraftFact>,
+}
+
+impl MemoDraft {
+    fn facts(&self, section: MemoSection) -> &[DraftFact] {
+        match section {
+            MemoSection::KeyActors => &self.key_actors,
+            MemoSection::RecurringVenues => &self.recurring_venues,
+            MemoSection::ActiveSituations => &self.active_situations,
+            MemoSection::KnownHoaxes => &self.known_hoaxes,
+            MemoSection::StyleNotes => &self.style_notes,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DraftFact {
+    /// One short line.
+    pub text: String,
+    /// Number of the current memo fact this keeps or rewrites; null for a new fact.
+    #[serde(default)]
+    pub carries: Option<u32>,
+    /// Whether this run's findings confirm the fact.
+    #[serde(default)]
+    pub seen_this_run: bool,
+}
+
+/// The memo's facts in prompt order; fact `n` in the prompt is `[n - 1]`.
+fn numbered(memo: &RegionMemo) -> Vec<&MemoFact> {
+    MemoSection::ALL.iter().flat_map(|s| memo.facts(*s)).collect()
+}
+
+/// Build the next memo from the LLM's draft. Carried facts keep their
+/// `last_seen` unless the run confirmed them; new and confirmed facts are
+/// dated `today`.
+pub fn apply_draft(memo: &RegionMemo, draft: &MemoDraft, today: NaiveDate) -> RegionMemo {
+    let current = numbered(memo);
+    let mut next = RegionMemo::default();
+    for section in MemoSection::ALL {
+        for fact in draft.facts(section) {
+            let carried = fact
+                .carries
+                .and_then(|n| current.get((n as usize).checked_sub(1)?));
+            let last_seen = match carried {
+                Some(f) if !fact.seen_this_run => f.last_seen,
+                _ => today,
+            };
+            next.facts_mut(section).push(MemoFact::new(&fact.text, last_seen));
+        }
+    }
+    next.prune(today);
+    next
+}
+
+fn memo_system_prompt(region_name: &str) -> String {
+    format!(
+        "You maintain the memory an intelligence scout keeps about {region_name} between runs.\n\
+         You receive the current memo, with numbered facts, and a digest of what this run found. \
+         Write the next memo:\n\
+         - key_actors: organizations, officials and groups that keep coming up, with a few words on what they do.\n\
+         - recurring_venues: places where things keep happening.\n\
+         - active_situations: situations still in motion.\n\
+         - known_hoaxes: rumors and scams that were debunked or keep circulating.\n\
+         - style_notes: how local sources write (neighborhood names, abbreviations, naming habits) that helps read them.\n\
+         \n\
+         Rules:\n\
+         - One short line per fact, at most {MAX_FACTS_PER_SECTION} per section.\n\
+         - To keep or reword a current fact, set `carries` to its number. Set `seen_this_run` only when the digest confirms it.\n\
+         - Add a new fact only when the digest supports it, with `carries` null.\n\
+         - Drop facts that are resolved, contradicted or no longer useful, and don't record one-off events.\n\
+         - Never record personal details about private individuals."
+    )
+}
+
+fn memo_user_prompt(memo: &RegionMemo, digest: &str) -> String {
+    let mut current = String::new();
+    let mut n = 0;
+    for section in MemoSection::ALL {
+        let facts = memo.facts(section);
+        if facts.is_empty() {
+            continue;
+        }
+        current.push_str(&format!("{}:\n", section.title()));
+        for fact in facts {
+            n += 1;
+            current.push_str(&format!("{n}. {} (last seen {})\n", fact.text, fact.last_seen));
+        }
+    }
+    if current.is_empty() {
+        current.push_str("(empty — this is the region's first memo)\n");
+    }
+    format!("## CURRENT MEMO\n{current}\n## THIS RUN\n{digest}")
+}
+
+/// What the run found: signals created and seen again, then the situation
+/// landscape. Empty when the run found nothing.
+fn run_digest(run_log: &RunLog, situations: &[SituationBrief]) -> String {
+    let signals: Vec<String> = run_log
+        .events()
+        .iter()
+        .filter_map(|e| match &e.kind {
+            EventKind::SignalCreated { signal_type, title, .. } => Some(format!("- {signal_type}: {title}")),
+            EventKind::SignalDeduplicated { signal_type, title, .. } => {
+                Some(format!("- {signal_type}: {title} (seen again)"))
+            }
+            _ => None,
+        })
+        .take(MAX_DIGEST_SIGNALS)
+        .collect();
+    if signals.is_empty() {
+        return String::new();
+    }
+
+    let mut digest = format!("Signals:\n{}\n", signals.join("\n"));
+    if !situations.is_empty() {
+        digest.push_str("\nSituations:\n");
+        for s in situations {
+            let place = s.location_name.as_deref().map(|l| format!(", {l}")).unwrap_or_default();
+            digest.push_str(&format!("- {} ({}, temperature {:.2}{place})\n", s.headline, s.arc, s.temperature));
+        }
+    }
+    digest
+}
+
+pub struct MemoUpdater<'a> {
+    writer: &'a GraphWriter,
+    claude: Claude,
+    budget: &'a BudgetTracker,
+    region_name: String,
+}
+
+impl<'a> MemoUpdater<'a> {
+    pub fn new(
+        writer: &'a GraphWriter,
+        anthropic_api_key: &str,
+        region_name: &str,
+        budget: &'a BudgetTracker,
+    ) -> Self {
+        Self {
+            writer,
+            claude: Claude::new(anthropic_api_key, HAIKU_MODEL),
+            budget,
+            region_name: region_name.to_string(),
+        }
+    }
+
+    /// Rewrite the region's memo from this run and save it. Runs that found
+    /// nothing leave the memo alone.
+    pub async fn update(&self, store: &RegionMemoStore, memo: &RegionMemo, run_log: &RunLog) -> Result<()> {
+        if !self.budget.has_budget(OperationCost::CLAUDE_HAIKU_REGION_MEMO) {
+            info!("Skipping region memo update (budget exhausted)");
+            return Ok(());
+        }
+        let situations = self
+            .writer
+            .get_situation_landscape(MAX_DIGEST_SITUATIONS)
+            .await
+            .unwrap_or_default();
+        let digest = run_digest(run_log, &situations);
+        if digest.is_empty() {
+            info!("No signals this run, region memo unchanged");
+            return Ok(());
+        }
+
+        let system = memo_system_prompt(&self.region_name);
+        let user = memo_user_prompt(memo, &digest);
+        let draft: MemoDraft = self.claude.extract(HAIKU_MODEL, &system, &user).await?;
+        self.budget.spend(OperationCost::CLAUDE_HAIKU_REGION_MEMO);
+
+        let next = apply_draft(memo, &draft, Utc::now().date_naive());
+        store.save(&next, &run_log.run_id).await?;
+        info!(
+            actors = next.key_actors.len(),
+            venues = next.recurring_venues.len(),
+            situations = next.active_situations.len(),
+            hoaxes = next.known_hoaxes.len(),
+            style_notes = next.style_notes.len(),
+            "Region memo updated"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn draft_fact(text: &str, carries: Option<u32>, seen_this_run: bool) -> DraftFact {
+        DraftFact {
+            text: text.to_string(),
+            carries,
+            seen_this_run,
+        }
+    }
+
+    fn memo() -> RegionMemo {
+        RegionMemo {
+            key_actors: vec![MemoFact::new("Northside Food Shelf runs Tuesday pantries", day(2))],
+            active_situations: vec![MemoFact::new("Lake Street rent strike", day(5))],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn carried_facts_keep_their_date_unless_confirmed() {
+        let draft = MemoDraft {
+            key_actors: vec![draft_fact("Northside Food Shelf runs Tuesday and Saturday pantries", Some(1), false)],
+            active_situations: vec![draft_fact("Lake Street rent strike enters third month", Some(2), true)],
+            known_hoaxes: vec![draft_fact("Fake FEMA cash card texts", None, false)],
+            ..Default::default()
+        };
+        let next = apply_draft(&memo(), &draft, day(20));
+
+        assert_eq!(next.key_actors[0].last_seen, day(2));
+        assert_eq!(next.key_actors[0].text, "Northside Food Shelf runs Tuesday and Saturday pantries");
+        assert_eq!(next.active_situations[0].last_seen, day(20));
+        assert_eq!(next.known_hoaxes[0].last_seen, day(20));
+    }
+
+    #[test]
+    fn stale_carried_fact_is_pruned_even_if_kept() {
+        let draft = MemoDraft {
+            active_situations: vec![draft_fact("Lake Street rent strike", Some(2), false)],
+            ..Default::default()
+        };
+        let next = apply_draft(&memo(), &draft, NaiveDate::from_ymd_opt(2026, 5, 1).unwrap());
+        assert!(next.active_situations.is_empty());
+    }
+
+    #[test]
+    fn user_prompt_numbers_facts_across_sections() {
+        let prompt = memo_user_prompt(&memo(), "Signals:\n- Gathering: Tenant meeting\n");
+        assert!(prompt.contains("KEY ACTORS:\n1. Northside Food Shelf runs Tuesday pantries (last seen 2026-03-02)"));
+        assert!(prompt.contains("ACTIVE SITUATIONS:\n2. Lake Street rent strike (last seen 2026-03-05)"));
+        assert!(prompt.ends_with("## THIS RUN\nSignals:\n- Gathering: Tenant meeting\n"));
+
+        let first = memo_user_prompt(&RegionMemo::default(), "");
+        assert!(first.contains("(empty — this is the region's first memo)"));
+    }
+}
@@ -0,0 +1,103 @@
+//! CKAN datastore client. `datastore_search` can sort but not range-filter,
+//! so the newest records are requested and the lookback window is applied
+//! by the caller.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::info;
+
+use rootsignal_common::OpenDataDataset;
+
+#[derive(Debug, Deserialize)]
+struct DatastoreResponse {
+    success: bool,
+    result: Option<DatastoreResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DatastoreResult {
+    #[serde(default)]
+    records: Vec<Value>,
+}
+
+pub struct CkanClient {
+    client: reqwest::Client,
+}
+
+impl CkanClient {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("Failed to build CKAN HTTP client"),
+        }
+    }
+
+    /// The newest `max_records` raw records of a datastore resource.
+    pub async fn fetch_rows(&self, dataset: &OpenDataDataset) -> Result<Vec<Value>> {
+        let url = format!(
+            "{}/api/3/action/datastore_search",
+            dataset.base_url.trim_end_matches('/')
+        );
+        let body = self
+            .client
+            .get(&url)
+            .query(&[
+                ("resource_id", dataset.dataset_id.clone()),
+                ("sort", format!("{} desc", dataset.fields.opened_at)),
+                ("limit", dataset.max_records.to_string()),
+            ])
+            .send()
+            .await
+            .context("CKAN request failed")?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        let rows = parse_records(&body)?;
+        info!(dataset = dataset.name.as_str(), rows = rows.len(), "ckan: fetched records");
+        Ok(rows)
+    }
+}
+
+impl Default for CkanClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Records from a `datastore_search` response.
+pub(crate) fn parse_records(body: &[u8]) -> Result<Vec<Value>> {
+    let resp: DatastoreResponse = serde_json::from_slice(body).context("Failed to parse CKAN response")?;
+    match resp.result {
+        Some(result) if resp.success => Ok(result.records),
+        _ => bail!("CKAN datastore_search was not successful"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_come_from_the_result_envelope() {
+        let body = br#"{"success": true, "result": {"total": 2, "records": [
+            {"_id": 1, "service_request_id": "101", "service_name": "Illegal Dumping"},
+            {"_id": 2, "service_request_id": "102", "service_name": "Heat Complaint"}
+        ]}}"#;
+
+        let records = parse_records(body).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1]["service_name"], "Heat Complaint");
+    }
+
+    #[test]
+    fn unsuccessful_responses_are_errors() {
+        let body = br#"{"success": false, "error": {"message": "Not found"}}"#;
+        assert!(parse_records(body).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+//! Open-data connectors — city service-request datasets (311) as a tension feed.
+//!
+//! 311 requests are ground truth: a resident reported a broken heater or an
+//! unsafe building at a specific place and time. Each region configures its
+//! datasets (`RegionOpenData`); a connector reads the newest records from
+//! the portal, the dataset's field mapping normalizes them, and its category
+//! rules decide which become Tensions or Notices. Everything else is dropped.
+
+pub mod ckan;
+pub mod socrata;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use rootsignal_common::{
+    GeoPoint, GeoPrecision, Node, NodeMeta, NoticeNode, OpenDataDataset, OpenDataFields, OpenDataRecord,
+    OpenDataSignal, PortalKind, SensitivityLevel, TensionNode,
+};
+
+use self::ckan::CkanClient;
+use self::socrata::SocrataClient;
+
+/// Reads records from an open-data portal.
+#[async_trait]
+pub trait OpenDataConnector: Send + Sync {
+    /// Records opened since `since`, newest first, at most `dataset.max_records`.
+    async fn fetch(&self, dataset: &OpenDataDataset, since: DateTime<Utc>) -> Result<Vec<OpenDataRecord>>;
+}
+
+/// Dispatches each dataset to the client for its portal.
+pub struct PortalConnector {
+    socrata: SocrataClient,
+    ckan: CkanClient,
+}
+
+impl PortalConnector {
+    /// `socrata_app_token` raises Socrata's anonymous rate limit; optional.
+    pub fn new(socrata_app_token: Option<String>) -> Self {
+        Self {
+            socrata: SocrataClient::new(socrata_app_token),
+            ckan: CkanClient::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl OpenDataConnector for PortalConnector {
+    async fn fetch(&self, dataset: &OpenDataDataset, since: DateTime<Utc>) -> Result<Vec<OpenDataRecord>> {
+        let rows = match dataset.portal {
+            PortalKind::Socrata => self.socrata.fetch_rows(dataset, since).await?,
+            PortalKind::Ckan => self.ckan.fetch_rows(dataset).await?,
+        };
+        // CKAN's datastore_search can't filter by date, so the window is applied here.
+        let mut records: Vec<OpenDataRecord> = rows
+            .iter()
+            .filter_map(|row| map_record(row, &dataset.fields))
+            .filter(|r| r.opened_at.is_none_or(|t| t >= since))
+            .collect();
+        records.truncate(dataset.max_records as usize);
+        Ok(records)
+    }
+}
+
+/// Normalize one raw row through the dataset's field mapping. Rows without
+/// an ID or category are dropped.
+pub fn map_record(row: &Value, fields: &OpenDataFields) -> Option<OpenDataRecord> {
+    let text = |column: Option<&String>| -> Option<String> {
+        let value = row.get(column?)?;
+        let s = match value {
+            Value::String(s) => s.trim().to_string(),
+            Value::Number(n) => n.to_string(),
+            _ => return None,
+        };
+        (!s.is_empty()).then_some(s)
+    };
+    let coord = |column: Option<&String>| text(column).and_then(|s| s.parse::<f64>().ok()).filter(|c| *c != 0.0);
+
+    Some(OpenDataRecord {
+        id: text(Some(&fields.id))?,
+        category: text(Some(&fields.category))?,
+        description: text(fields.description.as_ref()),
+        opened_at: text(Some(&fields.opened_at)).and_then(|s| parse_timestamp(&s)),
+        latitude: coord(fields.latitude.as_ref()),
+        longitude: coord(fields.longitude.as_ref()),
+        address: text(fields.address.as_ref()),
+    })
+}
+
+/// Portals publish RFC 3339, or "floating" local timestamps without an offset
+/// (Socrata), which are taken as UTC.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").ok().map(|n| n.and_utc()))
+        .or_else(|| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok().map(|n| n.and_utc()))
+}
+
+/// Turn a record into the signal its category maps to, or `None` when no
+/// category rule matches.
+pub fn record_to_node(dataset: &OpenDataDataset, record: &OpenDataRecord) -> Option<Node> {
+    let rule = dataset.rule_for(&record.category)?;
+    let now = Utc::now();
+    let title = match &record.address {
+        Some(address) => format!("{} near {address}", record.category),
+        None => record.category.clone(),
+    };
+    let summary = record
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("{} request reported to {}.", record.category, dataset.name));
+    let about_location = match (record.latitude, record.longitude) {
+        (Some(lat), Some(lng)) => Some(GeoPoint {
+            lat,
+            lng,
+            precision: GeoPrecision::Exact,
+        }),
+        _ => None,
+    };
+
+    let meta = NodeMeta {
+        id: Uuid::new_v4(),
+        title,
+        summary,
+        // A complaint ties a resident to an address; keep it to neighborhood
+        // precision. Official notices are public at full precision.
+        sensitivity: match rule.signal {
+            OpenDataSignal::Tension => SensitivityLevel::Elevated,
+            OpenDataSignal::Notice => SensitivityLevel::General,
+        },
+        confidence: 0.0,
+        freshness_score: 1.0,
+        corroboration_count: 0,
+        about_location,
+        about_location_name: record.address.clone(),
+        from_location: None,
+        source_url: dataset.record_url(&record.id),
+        extracted_at: now,
+        content_date: record.opened_at,
+        last_confirmed_active: now,
+        source_diversity: 1,
+        external_ratio: 0.0,
+        cause_heat: 0.0,
+        implied_queries: Vec::new(),
+        channel_diversity: 1,
+        mentioned_actors: Vec::new(),
+        author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
+    };
+
+    Some(match rule.signal {
+        OpenDataSignal::Tension => Node::Tension(TensionNode {
+            meta,
+            severity: rule.severity,
+            category: Some(record.category.clone()),
+            what_would_help: None,
+        }),
+        OpenDataSignal::Notice => Node::Notice(NoticeNode {
+            meta,
+            severity: rule.severity,
+            category: Some(record.category.clone()),
+            effective_date: record.opened_at,
+            source_authority: Some(dataset.name.clone()),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rootsignal_common::{CategoryRule, Severity};
+    use serde_json::json;
+
+    fn dataset() -> OpenDataDataset {
+        OpenDataDataset {
+            name: "Minneapolis 311".to_string(),
+            portal: PortalKind::Socrata,
+            base_url: "https://opendata.minneapolismn.gov".to_string(),
+            dataset_id: "abcd-1234".to_string(),
+            fields: OpenDataFields {
+                id: "case_id".to_string(),
+                category: "request_type".to_string(),
+                opened_at: "opened_date".to_string(),
+                description: Some("details".to_string()),
+                latitude: Some("lat".to_string()),
+                longitude: Some("lon".to_string()),
+                address: Some("address".to_string()),
+            },
+            categories: vec![
+                CategoryRule {
+                    contains: "no heat".to_string(),
+                    signal: OpenDataSignal::Tension,
+                    severity: Severity::High,
+                },
+                CategoryRule {
+                    contains: "water main".to_string(),
+                    signal: OpenDataSignal::Notice,
+                    severity: Severity::Medium,
+                },
+            ],
+            max_records: 50,
+            lookback_days: 7,
+        }
+    }
+
+    #[test]
+    fn socrata_row_maps_through_the_field_mapping() {
+        let row = json!({
+            "case_id": "SR-2026-104233",
+            "request_type": "Housing - No Heat",
+            "details": "Tenant reports no heat in building since Monday.",
+            "opened_date": "2026-10-12T14:03:00.000",
+            "lat": "44.9482",
+            "lon": -93.2620,
+            "address": "31XX Chicago Ave"
+        });
+
+        let record = map_record(&row, &dataset().fields).unwrap();
+        assert_eq!(record.id, "SR-2026-104233");
+        assert_eq!(record.opened_at.unwrap().to_rfc3339(), "2026-10-12T14:03:00+00:00");
+        assert_eq!(record.latitude, Some(44.9482));
+        assert_eq!(record.longitude, Some(-93.262));
+    }
+
+    #[test]
+    fn rows_without_a_category_are_dropped() {
+        let row = json!({"case_id": "SR-1", "request_type": "  "});
+        assert!(map_record(&row, &dataset().fields).is_none());
+    }
+
+    #[test]
+    fn category_rules_pick_the_signal_type() {
+        let ds = dataset();
+        let record = |category: &str| OpenDataRecord {
+            id: "SR-1".to_string(),
+            category: category.to_string(),
+            description: None,
+            opened_at: None,
+            latitude: Some(44.95),
+            longitude: Some(-93.26),
+            address: Some("31XX Chicago Ave".to_string()),
+        };
+
+        let Some(Node::Tension(t)) = record_to_node(&ds, &record("Housing - No Heat")) else {
+            panic!("no-heat complaints are tensions");
+        };
+        assert_eq!(t.meta.title, "Housing - No Heat near 31XX Chicago Ave");
+        assert_eq!(t.severity, Severity::High);
+        assert_eq!(t.meta.sensitivity, SensitivityLevel::Elevated);
+        assert_eq!(
+            t.meta.source_url,
+            "https://opendata.minneapolismn.gov/resource/abcd-1234.json?case_id=SR-1"
+        );
+
+        assert!(matches!(record_to_node(&ds, &record("Water Main Break")), Some(Node::Notice(_))));
+        assert!(record_to_node(&ds, &record("Parking Violation")).is_none());
+    }
+}
@@ -0,0 +1,99 @@
+//! Socrata (SODA 2.x) client. Datasets are queried with SoQL so the portal
+//! does the date filtering and ordering: only the newest records in the
+//! lookback window come back.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracing::info;
+
+use rootsignal_common::OpenDataDataset;
+
+pub struct SocrataClient {
+    client: reqwest::Client,
+    app_token: Option<String>,
+}
+
+impl SocrataClient {
+    pub fn new(app_token: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("Failed to build Socrata HTTP client"),
+            app_token: app_token.filter(|t| !t.is_empty()),
+        }
+    }
+
+    /// Raw rows opened since `since`, newest first.
+    pub async fn fetch_rows(&self, dataset: &OpenDataDataset, since: DateTime<Utc>) -> Result<Vec<Value>> {
+        let url = format!(
+            "{}/resource/{}.json",
+            dataset.base_url.trim_end_matches('/'),
+            dataset.dataset_id
+        );
+        let mut req = self.client.get(&url).query(&soql_params(dataset, since));
+        if let Some(token) = &self.app_token {
+            req = req.header("X-App-Token", token);
+        }
+
+        let rows: Vec<Value> = req
+            .send()
+            .await
+            .context("Socrata request failed")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Failed to parse Socrata rows")?;
+        info!(dataset = dataset.name.as_str(), rows = rows.len(), "socrata: fetched rows");
+        Ok(rows)
+    }
+}
+
+/// SoQL query parameters: rows opened since `since`, newest first.
+pub(crate) fn soql_params(dataset: &OpenDataDataset, since: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    let opened = &dataset.fields.opened_at;
+    vec![
+        // Socrata compares floating timestamps without an offset.
+        ("$where", format!("{opened} > '{}'", since.format("%Y-%m-%dT%H:%M:%S"))),
+        ("$order", format!("{opened} DESC")),
+        ("$limit", dataset.max_records.to_string()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rootsignal_common::{OpenDataFields, PortalKind};
+
+    #[test]
+    fn query_filters_and_orders_by_the_opened_column() {
+        let dataset = OpenDataDataset {
+            name: "Chicago 311".to_string(),
+            portal: PortalKind::Socrata,
+            base_url: "https://data.cityofchicago.org".to_string(),
+            dataset_id: "v6vf-nfxy".to_string(),
+            fields: OpenDataFields {
+                id: "sr_number".to_string(),
+                category: "sr_type".to_string(),
+                opened_at: "created_date".to_string(),
+                description: None,
+                latitude: None,
+                longitude: None,
+                address: None,
+            },
+            categories: Vec::new(),
+            max_records: 25,
+            lookback_days: 7,
+        };
+        let since = Utc.with_ymd_and_hms(2026, 10, 10, 0, 0, 0).unwrap();
+
+        let params = soql_params(&dataset, since);
+        assert_eq!(params[0], ("$where", "created_date > '2026-10-10T00:00:00'".to_string()));
+        assert_eq!(params[1], ("$order", "created_date DESC".to_string()));
+        assert_eq!(params[2], ("$limit", "25".to_string()));
+    }
+}
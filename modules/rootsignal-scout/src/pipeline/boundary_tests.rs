@@ -238,6 +238,71 @@ async fn same_title_extracted_twice_produces_one_signal() {
     assert!(store.has_signal_titled("Different Signal"));
 }
 
+#[tokio::test]
+async fn debunked_rumor_is_suppressed_not_stored() {
+    use rootsignal_common::negative_knowledge::{canonical_text, NegativeEntry, NegativeIndex, NegativeKind};
+
+    let fetcher = MockFetcher::new()
+        .on_page(
+            "https://forum.example/rumors",
+            archived_page("https://forum.example/rumors", "# FEMA is handing out $750 cash cards"),
+        );
+
+    let extractor = MockExtractor::new()
+        .on_url(
+            "https://forum.example/rumors",
+            crate::pipeline::extractor::ExtractionResult {
+                nodes: vec![
+                    tension_at("FEMA is handing out $750 cash cards!", 44.975, -93.270),
+                    tension_at("Shelter Beds Full Downtown", 44.975, -93.270),
+                ],
+                implied_queries: vec![],
+                resource_tags: Vec::new(),
+                signal_tags: Vec::new(),
+            },
+        );
+
+    let store = Arc::new(MockSignalStore::new());
+    let embedder = Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM));
+    let entry = NegativeEntry {
+        id: uuid::Uuid::new_v4(),
+        region: None,
+        kind: NegativeKind::Debunked,
+        text: "FEMA is handing out $750 cash cards".to_string(),
+        canonical_text: canonical_text("FEMA is handing out $750 cash cards"),
+        embedding: None,
+        reason: "Debunked by FEMA".to_string(),
+        added_by: "admin".to_string(),
+        hit_count: 0,
+        created_at: chrono::Utc::now(),
+        expires_at: None,
+        last_hit_at: None,
+    };
+    let entry_id = entry.id;
+
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        embedder,
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_negative_knowledge(NegativeIndex::new(vec![entry]));
+
+    let source = page_source("https://forum.example/rumors");
+    let sources: Vec<&SourceNode> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+    assert!(store.has_signal_titled("Shelter Beds Full Downtown"));
+    assert_eq!(ctx.stats.signals_suppressed, 1);
+    assert_eq!(ctx.negative_hits.get(&entry_id), Some(&1));
+}
+
 // NOTE: Tests `mentioned_actors_are_linked_to_their_signal` and
 // `same_actor_in_two_signals_appears_once_linked_to_both` were removed.
 // Mentioned actors no longer create Actor nodes — see
@@ -2619,6 +2684,814 @@ async fn social_signal_has_produced_by_edge() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// Newsletters — archived issues → extractor → stored signals
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn newsletter_issue_produces_signal_linked_to_its_source() {
+    let source = newsletter_source("news@ward6.org");
+    let fetcher = MockFetcher::new()
+        .on_posts(source.value(), vec![test_post("# Ward 6 Update\nTenant meeting Thursday")]);
+    let extractor = MockExtractor::new()
+        .on_url(source.value(), crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![tension_at("Tenant Meeting", 44.95, -93.26)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_newsletters(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+    assert!(store.signal_has_source("Tenant Meeting", source.id));
+}
+
+#[tokio::test]
+async fn newsletter_issues_received_before_last_scrape_are_not_reextracted() {
+    let mut source = newsletter_source("news@ward6.org");
+    source.last_scraped = Some(Utc::now());
+    let mut old_issue = test_post("Last month's update");
+    old_issue.fetched_at = Utc::now() - chrono::Duration::days(30);
+    let fetcher = MockFetcher::new().on_posts(source.value(), vec![old_issue]);
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_newsletters(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 0);
+    assert!(ctx.stats.extraction_failures.is_empty(), "old issue should never reach the extractor");
+}
+
+#[tokio::test]
+async fn backfill_reads_issues_from_before_the_last_scrape_within_its_age() {
+    let mut source = newsletter_source("news@ward6.org");
+    source.last_scraped = Some(Utc::now());
+    let mut last_month = test_post("# Ward 6 Update\nTenant meeting Thursday");
+    last_month.fetched_at = Utc::now() - chrono::Duration::days(30);
+    last_month.published_at = Some(last_month.fetched_at);
+    let mut two_years_ago = test_post("# Ward 6 Update\nBudget hearing");
+    two_years_ago.published_at = Some(Utc::now() - chrono::Duration::days(730));
+    let fetcher = Arc::new(MockFetcher::new().on_posts(source.value(), vec![last_month]));
+    let stale_fetcher = Arc::new(MockFetcher::new().on_posts(source.value(), vec![two_years_ago]));
+    let extractor = MockExtractor::new()
+        .on_url(source.value(), crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![tension_at("Tenant Meeting", 44.95, -93.26)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+    let store = Arc::new(MockSignalStore::new());
+    let phase = |fetcher: Arc<MockFetcher>, extractor: MockExtractor| {
+        ScrapePhase::new(
+            store.clone(),
+            Arc::new(extractor),
+            Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+            fetcher,
+            mpls_region(),
+            "test-run".to_string(),
+        )
+        .for_backfill("backfill-run".to_string(), rootsignal_common::BackfillDepth::default())
+    };
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut stale_ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase(fetcher, extractor).run_newsletters(&sources, &mut ctx, &mut log).await;
+    phase(stale_fetcher, MockExtractor::new()).run_newsletters(&sources, &mut stale_ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+    assert!(store.signal_has_source("Tenant Meeting", source.id));
+    assert!(stale_ctx.stats.extraction_failures.is_empty(), "issues past the backfill's age are never extracted");
+}
+
+// ---------------------------------------------------------------------------
+// Video channels — transcripts → extractor → time-coded signals
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn council_meeting_signal_links_to_the_minute_it_was_said() {
+    let source = social_source("https://www.youtube.com/@MplsCouncil");
+    let budget_discussion = "Routine budget line items. ".repeat(450);
+    let video = test_video("abc123", &[
+        (5.0, budget_discussion.as_str()),
+        (754.0, "Residents asked for a warming shelter on Lake Street"),
+    ]);
+    let fetcher = MockFetcher::new().on_videos(source.value(), vec![video]);
+    let extractor = MockExtractor::new()
+        .on_url("https://www.youtube.com/watch?v=abc123&t=720s", crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![tension_at("Warming Shelter Request", 44.95, -93.26)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_videos(&sources, &mut ctx, &mut log).await;
+
+    let signal = store.signal_by_title("Warming Shelter Request").expect("signal stored");
+    assert_eq!(signal.source_url, "https://www.youtube.com/watch?v=abc123&t=720s");
+    assert!(store.signal_has_source("Warming Shelter Request", source.id));
+}
+
+#[tokio::test]
+async fn videos_archived_before_last_scrape_are_not_reextracted() {
+    let mut source = social_source("https://www.youtube.com/@MplsCouncil");
+    source.last_scraped = Some(Utc::now());
+    let mut old_video = test_video("old456", &[(0.0, "Last month's meeting")]);
+    old_video.fetched_at = Utc::now() - chrono::Duration::days(30);
+    let fetcher = MockFetcher::new().on_videos(source.value(), vec![old_video]);
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_videos(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 0);
+    assert!(ctx.stats.extraction_failures.is_empty(), "old video should never reach the extractor");
+}
+
+#[tokio::test]
+async fn podcast_signal_cites_the_episode_it_came_from() {
+    let source = social_source("https://feeds.buzzsprout.com/northside.rss");
+    let episode = test_episode("https://northside.fm/12", &[
+        (0.0, "Welcome back to Northside Talks"),
+        (95.0, "Tenants on Penn Ave say they are starting a rent strike"),
+    ]);
+    let fetcher = MockFetcher::new().on_episodes(source.value(), vec![episode]);
+    let extractor = MockExtractor::new()
+        .on_url("https://northside.fm/12", crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![tension_at("Penn Ave Rent Strike", 44.99, -93.30)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_podcasts(&sources, &mut ctx, &mut log).await;
+
+    let signal = store.signal_by_title("Penn Ave Rent Strike").expect("signal stored");
+    assert_eq!(signal.source_url, "https://northside.fm/12");
+    assert!(store.signal_has_source("Penn Ave Rent Strike", source.id));
+}
+
+#[tokio::test]
+async fn eventbrite_event_becomes_gathering_authored_by_its_organizer() {
+    let source = social_source("https://www.eventbrite.com/o/northside-tenants-union-31415926535");
+    let event = test_listed_event(
+        "Tenant rights clinic",
+        "https://www.eventbrite.com/e/tenant-rights-clinic-tickets-771234",
+        "Northside Tenants Union",
+        chrono::Duration::days(4),
+    );
+    let fetcher = MockFetcher::new().on_events(source.value(), vec![event]);
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_events(&sources, &mut ctx, &mut log).await;
+
+    let signal = store.signal_by_title("Tenant rights clinic").expect("event stored");
+    assert_eq!(signal.node_type, rootsignal_common::NodeType::Gathering);
+    assert!(signal.confidence >= 0.9, "structured events are high confidence");
+    assert!(signal.about_location.is_some());
+    assert!(store.actor_linked_to_signal("Northside Tenants Union", "Tenant rights clinic"));
+    assert!(store.actor_has_source("Northside Tenants Union", source.id));
+    assert!(ctx.stats.extraction_failures.is_empty(), "listed events skip the extractor");
+}
+
+#[tokio::test]
+async fn events_that_already_ended_are_not_stored() {
+    let source = social_source("https://www.facebook.com/NorthsideTenants/events");
+    let past = test_listed_event(
+        "Last month's potluck",
+        "https://www.facebook.com/events/5550002",
+        "Northside Tenants",
+        -chrono::Duration::days(30),
+    );
+    let fetcher = MockFetcher::new().on_events(source.value(), vec![past]);
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_events(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Open data (311) — boundary tests
+//
+// MockOpenData → run_open_data (the organ) → MockSignalStore
+// Mapped service requests become Tensions/Notices; requests about an issue
+// already in the graph corroborate it instead.
+// ---------------------------------------------------------------------------
+
+const MPLS_311: &str = "Minneapolis 311";
+
+fn open_data_phase(store: Arc<MockSignalStore>, embedder: FixedEmbedder, connector: MockOpenData) -> ScrapePhase {
+    ScrapePhase::new(
+        store,
+        Arc::new(MockExtractor::new()),
+        Arc::new(embedder),
+        Arc::new(MockFetcher::new()),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_open_data(Arc::new(connector))
+}
+
+#[tokio::test]
+async fn mapped_311_requests_become_tensions_and_unmapped_ones_are_dropped() {
+    let connector = MockOpenData::new().on_dataset(
+        MPLS_311,
+        vec![
+            test_open_data_record("SR-1", "Housing - No Heat", "3100 Chicago Ave", 44.9482, -93.2620),
+            test_open_data_record("SR-2", "Parking Violation", "3100 Chicago Ave", 44.9482, -93.2620),
+        ],
+    );
+    let store = Arc::new(MockSignalStore::new());
+    let phase = open_data_phase(store.clone(), FixedEmbedder::new(TEST_EMBEDDING_DIM), connector);
+    let datasets = vec![test_open_data_dataset(MPLS_311)];
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_open_data(&datasets, &mut ctx, &mut log).await;
+    // Overlapping lookback windows see the same request again.
+    phase.run_open_data(&datasets, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+    let signal = store
+        .signal_by_title("Housing - No Heat near 3100 Chicago Ave")
+        .expect("mapped request stored");
+    assert_eq!(signal.node_type, rootsignal_common::NodeType::Tension);
+    assert_eq!(
+        signal.source_url,
+        "https://opendata.minneapolismn.gov/resource/abcd-1234.json?case_id=SR-1"
+    );
+    assert!(signal.about_location.is_some());
+}
+
+#[tokio::test]
+async fn request_about_an_extracted_tension_corroborates_it() {
+    // An article's wording never matches the record's closely (~0.88 here),
+    // below the region-wide cross-source threshold.
+    let record_vec = vec![1.0; TEST_EMBEDDING_DIM];
+    let mut same_issue = record_vec.clone();
+    same_issue[50..].fill(0.0);
+    let embedder = FixedEmbedder::new(TEST_EMBEDDING_DIM).on_text(
+        "Housing - No Heat near 3100 Chicago Ave Housing - No Heat request reported to Minneapolis 311.",
+        record_vec,
+    );
+    let store = Arc::new(MockSignalStore::new().with_vector_dedup());
+    store
+        .create_node(
+            &tension_at("Tenants at 3100 Chicago Ave without heat", 44.9483, -93.2621),
+            &same_issue,
+            "test",
+            "earlier-run",
+        )
+        .await
+        .unwrap();
+    let connector = MockOpenData::new().on_dataset(
+        MPLS_311,
+        vec![test_open_data_record("SR-1", "Housing - No Heat", "3100 Chicago Ave", 44.9482, -93.2620)],
+    );
+    let phase = open_data_phase(store.clone(), embedder, connector);
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase
+        .run_open_data(&[test_open_data_dataset(MPLS_311)], &mut ctx, &mut log)
+        .await;
+
+    assert_eq!(store.signals_created(), 1, "no second tension for the same issue");
+    assert_eq!(store.corroborations_for("Tenants at 3100 Chicago Ave without heat"), 1);
+    assert_eq!(store.evidence_count_for_title("Tenants at 3100 Chicago Ave without heat"), 1);
+    assert_eq!(ctx.stats.signals_deduplicated, 1);
+}
+
+#[tokio::test]
+async fn similar_tension_across_town_is_not_the_same_issue() {
+    // Similar enough to be the same issue nearby (~0.88), not enough to
+    // corroborate region-wide.
+    let record_vec = vec![1.0; TEST_EMBEDDING_DIM];
+    let mut similar = record_vec.clone();
+    similar[50..].fill(0.0);
+    let embedder = FixedEmbedder::new(TEST_EMBEDDING_DIM).on_text(
+        "Housing - No Heat near 3100 Chicago Ave Housing - No Heat request reported to Minneapolis 311.",
+        record_vec,
+    );
+    let store = Arc::new(MockSignalStore::new().with_vector_dedup());
+    store
+        .create_node(&tension_at("No heat in North Loop lofts", 44.9890, -93.2760), &similar, "test", "earlier-run")
+        .await
+        .unwrap();
+    let connector = MockOpenData::new().on_dataset(
+        MPLS_311,
+        vec![test_open_data_record("SR-1", "Housing - No Heat", "3100 Chicago Ave", 44.9482, -93.2620)],
+    );
+    let phase = open_data_phase(store.clone(), embedder, connector);
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase
+        .run_open_data(&[test_open_data_dataset(MPLS_311)], &mut ctx, &mut log)
+        .await;
+
+    assert!(store.has_signal_titled("Housing - No Heat near 3100 Chicago Ave"));
+    assert_eq!(store.corroborations_for("No heat in North Loop lofts"), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Meeting agendas — boundary tests
+//
+// MockAgendas → run_agendas (the organ) → MockSignalStore
+// Substantive agenda items become Notices located where the body meets.
+// ---------------------------------------------------------------------------
+
+const COUNCIL: &str = "Minneapolis City Council";
+const MEETING_URL: &str = "https://minneapolismn.legistar.com/MeetingDetail.aspx?ID=5120";
+
+fn agenda_phase(store: Arc<MockSignalStore>, connector: MockAgendas) -> ScrapePhase {
+    ScrapePhase::new(
+        store,
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(MockFetcher::new()),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_agendas(Arc::new(connector))
+}
+
+fn council_agendas() -> rootsignal_common::RegionAgendas {
+    rootsignal_common::RegionAgendas {
+        bodies: vec![test_agenda_body(COUNCIL)],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn agenda_items_become_notices_citing_their_meeting() {
+    let meeting = test_meeting(
+        MEETING_URL,
+        chrono::Duration::days(5),
+        &["Rezoning: 2800 E Lake St", "Street lighting maintenance contract"],
+    );
+    let store = Arc::new(MockSignalStore::new());
+    let phase = agenda_phase(store.clone(), MockAgendas::new().on_body(COUNCIL, vec![meeting]));
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_agendas(&council_agendas(), &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 2);
+    let rezoning = store.signal_by_title("Rezoning: 2800 E Lake St").expect("item stored");
+    assert_eq!(rezoning.node_type, rootsignal_common::NodeType::Notice);
+    assert_eq!(rezoning.source_url, MEETING_URL);
+    assert!(rezoning.about_location.is_some(), "located at the meeting venue");
+    assert!(rezoning.confidence >= 0.5, "eligible for the tension linker");
+    assert!(ctx.stats.extraction_failures.is_empty(), "agenda items skip the extractor");
+}
+
+#[tokio::test]
+async fn item_seen_again_in_later_run_is_not_duplicated() {
+    let meeting = test_meeting(MEETING_URL, chrono::Duration::days(5), &["Rezoning: 2800 E Lake St"]);
+    let store = Arc::new(MockSignalStore::new());
+    let phase = agenda_phase(store.clone(), MockAgendas::new().on_body(COUNCIL, vec![meeting]));
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_agendas(&council_agendas(), &mut ctx, &mut log).await;
+    phase.run_agendas(&council_agendas(), &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+}
+
+#[tokio::test]
+async fn unreachable_agenda_portal_does_not_crash() {
+    let store = Arc::new(MockSignalStore::new());
+    let phase = agenda_phase(store.clone(), MockAgendas::new());
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_agendas(&council_agendas(), &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Service alerts — boundary tests
+//
+// MockServiceAlerts → run_service_alerts (the organ) → MockSignalStore
+// Route cuts and school closures become signals tagged with what they affect.
+// ---------------------------------------------------------------------------
+
+use rootsignal_common::{AffectedEntity, AlertEffect};
+
+const METRO_TRANSIT: &str = "Metro Transit";
+const MPS: &str = "Minneapolis Public Schools";
+
+fn alerts_phase(store: Arc<MockSignalStore>, connector: MockServiceAlerts) -> ScrapePhase {
+    ScrapePhase::new(
+        store,
+        Arc::new(MockExtractor::new()),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(MockFetcher::new()),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_service_alerts(Arc::new(connector))
+}
+
+fn alert_feeds() -> rootsignal_common::RegionServiceAlerts {
+    rootsignal_common::RegionServiceAlerts {
+        transit_agencies: vec![test_transit_agency(METRO_TRANSIT)],
+        school_districts: vec![test_school_district(MPS, &["Lincoln Elementary"])],
+    }
+}
+
+#[tokio::test]
+async fn route_suspension_is_linked_to_signals_about_the_route() {
+    let store = Arc::new(MockSignalStore::new());
+    for title in ["Riders stranded as Route 21 stops running", "Route 210 adds weekend trips"] {
+        store
+            .create_node(&tension(title), &[0.5; TEST_EMBEDDING_DIM], "test", "earlier-run")
+            .await
+            .unwrap();
+    }
+    let connector = MockServiceAlerts::new()
+        .on_feed(
+            METRO_TRANSIT,
+            vec![test_service_alert(
+                "https://www.metrotransit.org/alerts/4412",
+                "Route 21 suspended on Lake St",
+                AlertEffect::Closure,
+                vec![AffectedEntity::Route("21".to_string())],
+            )],
+        )
+        .on_feed(MPS, Vec::new());
+    let phase = alerts_phase(store.clone(), connector);
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_service_alerts(&alert_feeds(), &mut ctx, &mut log).await;
+
+    let alert = store.signal_by_title("Route 21 suspended on Lake St").expect("alert stored");
+    assert_eq!(alert.node_type, rootsignal_common::NodeType::Tension);
+    assert!(store.has_tag("Route 21 suspended on Lake St", "route-21"));
+    assert!(store.has_tag("Riders stranded as Route 21 stops running", "route-21"));
+    assert!(!store.has_tag("Route 210 adds weekend trips", "route-21"), "a different route");
+}
+
+#[tokio::test]
+async fn school_closure_is_tagged_with_the_school_and_delays_are_notices() {
+    let store = Arc::new(MockSignalStore::new());
+    let connector = MockServiceAlerts::new().on_feed(METRO_TRANSIT, Vec::new()).on_feed(
+        MPS,
+        vec![
+            test_service_alert(
+                "https://mpls.k12.mn.us/news/a1",
+                "Lincoln Elementary closed Monday",
+                AlertEffect::Closure,
+                vec![AffectedEntity::School("Lincoln Elementary".to_string())],
+            ),
+            test_service_alert(
+                "https://mpls.k12.mn.us/news/a2",
+                "Two-hour late start district-wide",
+                AlertEffect::Delay,
+                vec![AffectedEntity::School(MPS.to_string())],
+            ),
+        ],
+    );
+    let phase = alerts_phase(store.clone(), connector);
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_service_alerts(&alert_feeds(), &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 2);
+    assert!(store.has_tag("Lincoln Elementary closed Monday", "school-lincoln-elementary"));
+    let late_start = store.signal_by_title("Two-hour late start district-wide").expect("alert stored");
+    assert_eq!(late_start.node_type, rootsignal_common::NodeType::Notice);
+    assert!(store.has_tag("Two-hour late start district-wide", "school-minneapolis-public-schools"));
+}
+
+#[tokio::test]
+async fn unreachable_transit_feed_does_not_stop_school_alerts() {
+    let store = Arc::new(MockSignalStore::new());
+    let connector = MockServiceAlerts::new().on_feed(
+        MPS,
+        vec![test_service_alert(
+            "https://mpls.k12.mn.us/news/a1",
+            "Lincoln Elementary closed Monday",
+            AlertEffect::Closure,
+            vec![AffectedEntity::School("Lincoln Elementary".to_string())],
+        )],
+    );
+    let phase = alerts_phase(store.clone(), connector);
+    let mut ctx = RunContext::new(&[]);
+    let mut log = run_log();
+
+    phase.run_service_alerts(&alert_feeds(), &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 1);
+}
+
+// ---------------------------------------------------------------------------
+// Registry entity linking — boundary tests
+//
+// MockExtractor → run_web (the organ) → MockSignalStore
+// Extracted signals get MENTIONS / LOCATED_IN edges to the region's registry.
+// ---------------------------------------------------------------------------
+
+use rootsignal_common::{EntityGeometry, EntityLink, RegionRegistry, RegistryEntity, RegistryKind};
+
+const TRANSIT_NEWS: &str = "https://southsidepride.com/route-21";
+
+fn registry_entity(kind: RegistryKind, name: &str, geometry: Option<EntityGeometry>) -> RegistryEntity {
+    RegistryEntity {
+        kind,
+        name: name.to_string(),
+        aliases: Vec::new(),
+        geometry,
+    }
+}
+
+fn mpls_registry() -> RegionRegistry {
+    RegionRegistry {
+        entities: vec![
+            registry_entity(RegistryKind::Route, "21", None),
+            registry_entity(
+                RegistryKind::Ward,
+                "9",
+                Some(EntityGeometry::Polygon {
+                    ring: vec![[44.93, -93.28], [44.93, -93.24], [44.96, -93.24], [44.96, -93.28]],
+                }),
+            ),
+            registry_entity(RegistryKind::Park, "Powderhorn Park", None),
+        ],
+    }
+}
+
+async fn scrape_with_registry(store: Arc<MockSignalStore>, signal: rootsignal_common::Node) {
+    let fetcher = MockFetcher::new().on_page(TRANSIT_NEWS, archived_page(TRANSIT_NEWS, "# Route 21 riders stranded"));
+    let extractor = MockExtractor::new().on_url(
+        TRANSIT_NEWS,
+        crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![signal],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        },
+    );
+    let phase = ScrapePhase::new(
+        store,
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_registry(mpls_registry());
+    let source = page_source(TRANSIT_NEWS);
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&[&source], &mut ctx, &mut log).await;
+}
+
+#[tokio::test]
+async fn extracted_signal_links_to_the_route_it_names_and_the_ward_it_is_in() {
+    let title = "Riders stranded as Route 21 stops running on Lake St";
+    let store = Arc::new(MockSignalStore::new());
+
+    scrape_with_registry(store.clone(), tension_at(title, 44.948, -93.262)).await;
+
+    assert!(store.has_entity_link(title, "minneapolis/route-21", EntityLink::Mentions));
+    assert!(store.has_entity_link(title, "minneapolis/ward-9", EntityLink::LocatedIn));
+    assert!(!store.has_entity_link(title, "minneapolis/park-powderhorn-park", EntityLink::Mentions));
+}
+
+#[tokio::test]
+async fn signal_outside_every_ward_is_linked_by_mention_only() {
+    let title = "Powderhorn Park cleanup needs volunteers";
+    let store = Arc::new(MockSignalStore::new());
+
+    scrape_with_registry(store.clone(), tension_at(title, 44.99, -93.262)).await;
+
+    assert!(store.has_entity_link(title, "minneapolis/park-powderhorn-park", EntityLink::Mentions));
+    assert!(!store.has_entity_link(title, "minneapolis/ward-9", EntityLink::LocatedIn));
+}
+
+// ---------------------------------------------------------------------------
+// Fundraiser tracking — boundary tests
+//
+// MOCK → refresh_fundraisers (the organ) → OUTPUT
+// Needs tied to a campaign page get its progress written back onto them.
+// ---------------------------------------------------------------------------
+
+use crate::enrichment::fundraiser::refresh_fundraisers;
+
+const FUNDRAISER_URL: &str = "https://www.gofundme.com/f/rebuild-lake-street-bakery";
+
+fn fundraiser_page() -> rootsignal_common::ArchivedPage {
+    archived_page(FUNDRAISER_URL, "Rebuild Lake Street Bakery\n\n$36,500 raised of $50,000 goal\n\n412 donations")
+}
+
+#[tokio::test]
+async fn need_scraped_from_fundraiser_page_gets_its_progress() {
+    let fetcher = Arc::new(MockFetcher::new().on_page(FUNDRAISER_URL, fundraiser_page()));
+    let extractor = MockExtractor::new().on_url(
+        FUNDRAISER_URL,
+        crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![need_at("Rebuild Lake Street Bakery", 44.9483, -93.2350)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        },
+    );
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        fetcher.clone(),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let source = page_source(FUNDRAISER_URL);
+    let sources: Vec<&SourceNode> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    let updated = refresh_fundraisers(&*fetcher, &*store, chrono::Utc::now()).await;
+
+    assert_eq!(updated, 1);
+    let progress = store.fundraiser_for("Rebuild Lake Street Bakery").expect("progress recorded");
+    assert_eq!(progress.raised, 36_500.0);
+    assert_eq!(progress.donor_count, Some(412));
+    assert_eq!(progress.percent_funded().map(|p| p.round()), Some(73.0));
+}
+
+#[tokio::test]
+async fn fundraiser_cited_as_evidence_ties_the_need_to_the_campaign() {
+    let store = MockSignalStore::new();
+    let id = store.create_node(&need("Help the Vega family"), &[0.0], "test", "test-run").await.unwrap();
+    let evidence = rootsignal_common::EvidenceNode {
+        id: uuid::Uuid::new_v4(),
+        source_url: FUNDRAISER_URL.to_string(),
+        retrieved_at: chrono::Utc::now(),
+        content_hash: "abc".to_string(),
+        snippet: None,
+        relevance: Some("SUPPORTING".to_string()),
+        evidence_confidence: Some(0.8),
+        channel_type: None,
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
+    };
+
+    store.create_evidence(&evidence, id).await.unwrap();
+
+    assert_eq!(store.fundraiser_url_for("Help the Vega family").as_deref(), Some(FUNDRAISER_URL));
+}
+
+#[tokio::test]
+async fn fresh_fundraiser_progress_is_not_refetched_until_the_cadence_passes() {
+    let fetcher = MockFetcher::new().on_page(FUNDRAISER_URL, fundraiser_page());
+    let store = MockSignalStore::new();
+    let mut node = need("Rebuild Lake Street Bakery");
+    if let rootsignal_common::Node::Need(n) = &mut node {
+        n.action_url = Some(FUNDRAISER_URL.to_string());
+    }
+    store.create_node(&node, &[0.0], "test", "test-run").await.unwrap();
+    let now = chrono::Utc::now();
+
+    assert_eq!(refresh_fundraisers(&fetcher, &store, now).await, 1);
+    assert_eq!(refresh_fundraisers(&fetcher, &store, now + chrono::Duration::hours(1)).await, 0);
+    assert_eq!(refresh_fundraisers(&fetcher, &store, now + chrono::Duration::hours(13)).await, 1);
+}
+
+// ---------------------------------------------------------------------------
+// Evidence freshness — boundary tests
+//
+// MOCK → recheck_evidence (the organ) → OUTPUT
+// Evidence pages are re-fetched on a cadence; drift lowers signal confidence.
+// Uses the pantry page from the diff-aware re-extraction tests below.
+// ---------------------------------------------------------------------------
+
+use crate::enrichment::evidence_freshness::recheck_evidence;
+
+#[tokio::test]
+async fn rewritten_evidence_page_is_marked_changed_and_lowers_signal_confidence() {
+    let (store, _) = run_pantry_scrape(archived_page(PANTRY_URL, PANTRY_BEFORE)).await;
+    let before = store.signal_by_title("Northside Pantry Saturday hours").expect("signal stored").confidence;
+    let rewritten = rescraped_pantry_page(&PANTRY_BEFORE.replace("Open Tuesdays, 10am to 2pm.", "Closed for renovation."));
+    let fetcher = MockFetcher::new().on_page(PANTRY_URL, rewritten);
+
+    let summary = recheck_evidence(&fetcher, &*store, chrono::Utc::now() + chrono::Duration::days(8)).await;
+
+    assert_eq!(summary.changed, 1);
+    let verifications = store.evidence_verifications_for_title("Northside Pantry Saturday hours");
+    assert_eq!(verifications[0].freshness, rootsignal_common::EvidenceFreshness::Changed);
+    assert!(verifications[0].drift_diff.as_deref().unwrap_or_default().contains("Closed for renovation"));
+    let after = store.signal_by_title("Northside Pantry Saturday hours").unwrap().confidence;
+    assert!(after < before);
+}
+
+#[tokio::test]
+async fn removed_evidence_page_that_comes_back_restores_signal_confidence() {
+    let (store, _) = run_pantry_scrape(archived_page(PANTRY_URL, PANTRY_BEFORE)).await;
+    let before = store.signal_by_title("Northside Pantry Saturday hours").unwrap().confidence;
+    let now = chrono::Utc::now();
+    let fetcher = MockFetcher::new()
+        .on_page(PANTRY_URL, archived_page(PANTRY_URL, PANTRY_BEFORE))
+        .fail_page_once(PANTRY_URL, "HTTP 404 Not Found");
+
+    let removed = recheck_evidence(&fetcher, &*store, now + chrono::Duration::days(8)).await;
+    let too_soon = recheck_evidence(&fetcher, &*store, now + chrono::Duration::days(9)).await;
+    let restored = recheck_evidence(&fetcher, &*store, now + chrono::Duration::days(16)).await;
+
+    assert_eq!((removed.removed, too_soon.checked, restored.current), (1, 0, 1));
+    let after = store.signal_by_title("Northside Pantry Saturday hours").unwrap().confidence;
+    assert!((after - before).abs() < 1e-6);
+}
+
 // ---------------------------------------------------------------------------
 // Actor location enrichment — boundary tests
 //
@@ -3584,3 +4457,69 @@ async fn actor_bio_location_corroborated_by_signal_wins() {
         "bio location corroborated by signal should win"
     );
 }
+
+// ---------------------------------------------------------------------------
+// Diff-aware re-extraction
+// ---------------------------------------------------------------------------
+
+const PANTRY_URL: &str = "https://northsidepantry.org/hours";
+
+const PANTRY_BEFORE: &str = "# Northside Pantry\n\nFree groceries for north Minneapolis families.\n\n\
+    ## Hours\n\nOpen Tuesdays, 10am to 2pm.\n\n## Donations\n\nWe accept canned goods.\n\n\
+    ## Volunteer\n\nShifts are posted monthly.";
+
+fn rescraped_pantry_page(markdown: &str) -> rootsignal_common::ArchivedPage {
+    let mut page = archived_page(PANTRY_URL, markdown);
+    page.previous_markdown = Some(PANTRY_BEFORE.to_string());
+    page
+}
+
+async fn run_pantry_scrape(page: rootsignal_common::ArchivedPage) -> (Arc<MockSignalStore>, RunContext) {
+    let fetcher = Arc::new(MockFetcher::new().on_page(PANTRY_URL, page));
+    let extractor = MockExtractor::new().on_url(
+        PANTRY_URL,
+        crate::pipeline::extractor::ExtractionResult {
+            nodes: vec![aid_at("Northside Pantry Saturday hours", 44.99, -93.29)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        },
+    );
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        fetcher,
+        mpls_region(),
+        "test-run".to_string(),
+    );
+    let source = page_source(PANTRY_URL);
+    let sources: Vec<&SourceNode> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+    (store, ctx)
+}
+
+#[tokio::test]
+async fn changed_page_records_what_changed_on_the_evidence() {
+    let after = PANTRY_BEFORE.replace("Open Tuesdays, 10am to 2pm.", "Open Tuesdays and Saturdays, 10am to 2pm.");
+    let (store, ctx) = run_pantry_scrape(rescraped_pantry_page(&after)).await;
+
+    assert!(store.has_signal_titled("Northside Pantry Saturday hours"));
+    assert_eq!(
+        store.change_summary_for_title("Northside Pantry Saturday hours").as_deref(),
+        Some("Updated \"Hours\": 1 block added, 1 removed")
+    );
+    assert_eq!(ctx.stats.urls_diff_extracted, 1);
+}
+
+#[tokio::test]
+async fn reformatted_page_is_not_re_extracted() {
+    let after = PANTRY_BEFORE.replace("Free groceries for", "Free  groceries\nfor");
+    let (store, ctx) = run_pantry_scrape(rescraped_pantry_page(&after)).await;
+
+    assert_eq!(store.signals_created(), 0);
+    assert_eq!(ctx.stats.urls_unchanged, 1);
+}
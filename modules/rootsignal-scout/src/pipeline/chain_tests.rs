@@ -7,10 +7,12 @@
 use std::sync::Arc;
 
 use rootsignal_common::types::ActorContext;
-use rootsignal_common::canonical_value;
+use rootsignal_common::{canonical_value, DecisionKind};
 
 use crate::pipeline::extractor::ExtractionResult;
 use crate::pipeline::scrape_phase::{RunContext, ScrapePhase};
+use crate::pipeline::stats::ExtractionFailureKind;
+use crate::pipeline::work_queue::WorkQueueConfig;
 use crate::testing::*;
 
 // ---------------------------------------------------------------------------
@@ -287,6 +289,12 @@ async fn same_event_from_three_sites_produces_one_signal_with_two_corroborations
         2,
         "two corroborations (first creates, second and third corroborate)"
     );
+
+    // Each corroboration is recorded as a merge decision on the signal
+    assert_eq!(
+        store.decisions_for("Community Garden Cleanup"),
+        vec![DecisionKind::Merge, DecisionKind::Merge]
+    );
 }
 
 // ---------------------------------------------------------------------------
@@ -1036,3 +1044,181 @@ async fn flywheel_scrape_then_enrich_updates_actor_location() {
         "actor should be placed in Phillips (2 Phillips vs 1 Powderhorn)"
     );
 }
+
+// ---------------------------------------------------------------------------
+// Chain Test: Failure classification drives scrape retries
+//
+// transient fetch failure → retried once → signal stored.
+// unsupported-content failure → not retried → URL counted as failed.
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn transient_fetch_failure_is_retried_and_page_still_produces_signal() {
+    let url = "https://localorg.org/resources";
+
+    let fetcher = MockFetcher::new()
+        .fail_page_once(url, "Fetch failed: connection reset by peer")
+        .on_page(url, archived_page(url, "Free legal clinic every Tuesday at Sabathani Center..."));
+
+    let extractor = MockExtractor::new()
+        .on_url(url, ExtractionResult {
+            nodes: vec![tension_at("Free Legal Clinic at Sabathani", 44.9341, -93.2619)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+
+    let source = page_source(url);
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    assert!(store.has_signal_titled("Free Legal Clinic at Sabathani"));
+    assert_eq!(ctx.stats.urls_failed, 0);
+}
+
+#[tokio::test]
+async fn unsupported_content_failure_is_not_retried() {
+    let url = "https://localorg.org/flyer.pdf";
+
+    // Page is registered, so a retry would succeed — it must not happen.
+    let fetcher = MockFetcher::new()
+        .fail_page_once(url, "Content type not supported for this source: application/pdf")
+        .on_page(url, archived_page(url, "Community flyer"));
+
+    let extractor = MockExtractor::new()
+        .on_url(url, ExtractionResult {
+            nodes: vec![tension_at("Should Not Be Extracted", 44.9341, -93.2619)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+
+    let source = page_source(url);
+    let sources: Vec<&_> = vec![&source];
+    let mut ctx = RunContext::new(&[source.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    assert_eq!(store.signals_created(), 0);
+    assert_eq!(ctx.stats.urls_failed, 1);
+}
+
+// ---------------------------------------------------------------------------
+// Chain Test: Extraction isolation
+//
+// one page's extractor panics / hangs → recorded as an ExtractionFailure,
+// sibling page in the same batch still produces its signal.
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn panicking_extraction_is_recorded_and_sibling_page_still_produces_signal() {
+    let bad_url = "https://localorg.org/broken";
+    let good_url = "https://localorg.org/pantry";
+
+    let fetcher = MockFetcher::new()
+        .on_page(bad_url, archived_page(bad_url, "<<garbled>>"))
+        .on_page(good_url, archived_page(good_url, "Food pantry open Saturdays at Holy Rosary..."));
+
+    let extractor = MockExtractor::new()
+        .panic_on(bad_url)
+        .on_url(good_url, ExtractionResult {
+            nodes: vec![tension_at("Holy Rosary Food Pantry", 44.9488, -93.2471)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    );
+
+    let bad = page_source(bad_url);
+    let good = page_source(good_url);
+    let sources: Vec<&_> = vec![&bad, &good];
+    let mut ctx = RunContext::new(&[bad.clone(), good.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    assert!(store.has_signal_titled("Holy Rosary Food Pantry"));
+    assert_eq!(ctx.stats.extraction_failures.len(), 1);
+    let failure = &ctx.stats.extraction_failures[0];
+    assert_eq!(failure.url, bad_url);
+    assert_eq!(failure.kind, ExtractionFailureKind::Panic);
+    assert_eq!(ctx.stats.urls_failed, 1);
+}
+
+#[tokio::test]
+async fn stalled_extraction_times_out_without_blocking_the_batch() {
+    let slow_url = "https://localorg.org/slow";
+    let good_url = "https://localorg.org/clinic";
+
+    let fetcher = MockFetcher::new()
+        .on_page(slow_url, archived_page(slow_url, "Very long page..."))
+        .on_page(good_url, archived_page(good_url, "Free flu shots at Cedar Riverside clinic..."));
+
+    let extractor = MockExtractor::new()
+        .stall_on(slow_url)
+        .on_url(good_url, ExtractionResult {
+            nodes: vec![tension_at("Cedar Riverside Flu Shots", 44.9689, -93.2476)],
+            implied_queries: vec![],
+            resource_tags: Vec::new(),
+            signal_tags: Vec::new(),
+        });
+
+    let store = Arc::new(MockSignalStore::new());
+    let phase = ScrapePhase::new(
+        store.clone(),
+        Arc::new(extractor),
+        Arc::new(FixedEmbedder::new(TEST_EMBEDDING_DIM)),
+        Arc::new(fetcher),
+        mpls_region(),
+        "test-run".to_string(),
+    )
+    .with_queue_config(WorkQueueConfig {
+        extract_timeout: std::time::Duration::from_millis(50),
+        ..Default::default()
+    });
+
+    let slow = page_source(slow_url);
+    let good = page_source(good_url);
+    let sources: Vec<&_> = vec![&slow, &good];
+    let mut ctx = RunContext::new(&[slow.clone(), good.clone()]);
+    let mut log = run_log();
+
+    phase.run_web(&sources, &mut ctx, &mut log).await;
+
+    assert!(store.has_signal_titled("Cedar Riverside Flu Shots"));
+    assert_eq!(ctx.stats.extraction_failures.len(), 1);
+    assert_eq!(ctx.stats.extraction_failures[0].kind, ExtractionFailureKind::Timeout);
+}
@@ -0,0 +1,199 @@
+//! Comment-thread expansion for social posts.
+//!
+//! The actionable details of a post (the address, the time, the needs list)
+//! are often in its comments, posted by the organizer or asked for by
+//! neighbors. For a few high-signal posts per account, the top-level comments
+//! are fetched and appended under the post, each marked with its author, so
+//! extraction sees the whole thread. Depth (top-level only), comments per post
+//! and posts per account are all capped to bound scraper cost.
+
+use std::collections::HashMap;
+
+use futures::future::join_all;
+use rootsignal_common::types::{Post, PostComment};
+use tracing::{info, warn};
+
+use super::traits::ContentFetcher;
+
+/// Posts per account whose comments are fetched.
+pub const MAX_EXPANDED_POSTS_PER_ACCOUNT: usize = 3;
+/// Top-level comments fetched per post.
+pub const MAX_COMMENTS_PER_POST: u32 = 15;
+/// Comments a post needs before its thread is worth a fetch.
+const MIN_COMMENTS_FOR_EXPANSION: i64 = 3;
+/// Characters kept per comment.
+const MAX_COMMENT_CHARS: usize = 500;
+
+/// Phrases pointing readers to the comments for the details.
+const COMMENT_CUES: &[&str] = &[
+    "in the comments",
+    "in comments",
+    "see comments",
+    "check comments",
+    "details below",
+    "info below",
+    "link below",
+    "comment below",
+];
+
+fn engagement_count(post: &Post, key: &str) -> i64 {
+    post.engagement
+        .as_ref()
+        .and_then(|e| e.get(key))
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0)
+}
+
+fn points_to_comments(post: &Post) -> bool {
+    let text = post.text.as_deref().unwrap_or_default().to_lowercase();
+    COMMENT_CUES.iter().any(|cue| text.contains(cue))
+}
+
+/// Permalinks of the posts whose comments are worth fetching, best first:
+/// posts people are talking about, or that send readers to the comments.
+pub fn posts_to_expand(posts: &[Post]) -> Vec<&str> {
+    let mut candidates: Vec<(i64, &str)> = posts
+        .iter()
+        .filter_map(|p| {
+            let permalink = p.permalink.as_deref()?;
+            let comments = engagement_count(p, "comments");
+            let cued = points_to_comments(p) && comments > 0;
+            if comments < MIN_COMMENTS_FOR_EXPANSION && !cued {
+                return None;
+            }
+            let score = comments * 2 + engagement_count(p, "likes") + if cued { 1_000 } else { 0 };
+            Some((score, permalink))
+        })
+        .collect();
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.0));
+    candidates
+        .into_iter()
+        .take(MAX_EXPANDED_POSTS_PER_ACCOUNT)
+        .map(|(_, permalink)| permalink)
+        .collect()
+}
+
+/// A post's comments as extraction context, each attributed to its author so
+/// commenters' words aren't read as the poster's.
+pub fn format_thread(post_author: Option<&str>, comments: &[PostComment]) -> String {
+    let mut thread = String::from(
+        "Top-level comments on this post. They may add details (address, time, what's needed) \
+         to the post above; commenters other than the post author speak only for themselves.",
+    );
+    for (i, comment) in comments.iter().enumerate() {
+        let author = comment.author.as_deref().unwrap_or("unknown");
+        let role = if post_author.is_some_and(|a| a.eq_ignore_ascii_case(author)) {
+            " (post author)"
+        } else {
+            ""
+        };
+        let text: String = comment.text.chars().take(MAX_COMMENT_CHARS).collect();
+        thread.push_str(&format!("\n[Comment {} by @{author}{role}]\n{}", i + 1, text.trim()));
+    }
+    thread
+}
+
+/// Fetch and format the comment threads of an account's high-signal posts,
+/// keyed by post permalink. Posts whose comments can't be fetched are left
+/// as they are.
+pub async fn expand(fetcher: &dyn ContentFetcher, posts: &[Post]) -> HashMap<String, String> {
+    let targets = posts_to_expand(posts);
+    if targets.is_empty() {
+        return HashMap::new();
+    }
+    let fetched = join_all(
+        targets
+            .iter()
+            .map(|url| fetcher.comments(url, MAX_COMMENTS_PER_POST)),
+    )
+    .await;
+
+    let mut threads = HashMap::new();
+    for (url, result) in targets.into_iter().zip(fetched) {
+        match result {
+            Ok(comments) if !comments.is_empty() => {
+                let post_author = posts
+                    .iter()
+                    .find(|p| p.permalink.as_deref() == Some(url))
+                    .and_then(|p| p.author.as_deref());
+                info!(post_url = url, comments = comments.len(), "Expanded comment thread");
+                threads.insert(url.to_string(), format_thread(post_author, &comments));
+            }
+            Ok(_) => {}
+            Err(e) => warn!(post_url = url, error = %e, "Failed to fetch comment thread"),
+        }
+    }
+    threads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{test_post, MockFetcher};
+
+    fn post(permalink: &str, comments: i64, likes: i64, text: &str) -> Post {
+        let mut p = test_post(text);
+        p.permalink = Some(permalink.to_string());
+        p.engagement = Some(serde_json::json!({ "likes": likes, "comments": comments }));
+        p
+    }
+
+    fn comment(author: &str, text: &str) -> PostComment {
+        PostComment {
+            author: Some(author.to_string()),
+            text: text.to_string(),
+            published_at: None,
+        }
+    }
+
+    #[test]
+    fn busiest_threads_are_expanded_up_to_the_cap() {
+        let posts = vec![
+            post("https://ig/p/quiet", 1, 500, "Nice day"),
+            post("https://ig/p/a", 10, 0, "Food drive"),
+            post("https://ig/p/b", 40, 0, "Rent strike"),
+            post("https://ig/p/c", 5, 100, "Cleanup"),
+            post("https://ig/p/d", 4, 0, "Book swap"),
+        ];
+
+        let expanded = posts_to_expand(&posts);
+
+        assert_eq!(expanded, vec!["https://ig/p/c", "https://ig/p/b", "https://ig/p/a"]);
+    }
+
+    #[test]
+    fn posts_pointing_to_comments_are_expanded_first() {
+        let posts = vec![
+            post("https://ig/p/busy", 50, 50, "Big turnout"),
+            post("https://ig/p/cued", 1, 0, "Supply drive Saturday, address in the comments!"),
+        ];
+
+        let expanded = posts_to_expand(&posts);
+
+        assert_eq!(expanded, vec!["https://ig/p/cued", "https://ig/p/busy"]);
+    }
+
+    #[test]
+    fn thread_attributes_each_comment_and_marks_the_post_author() {
+        let comments = vec![
+            comment("mutualaidmpls", "It's at 2400 Chicago Ave, 10am"),
+            comment("neighbor", "Do you need diapers?"),
+        ];
+
+        let thread = format_thread(Some("MutualAidMpls"), &comments);
+
+        assert!(thread.contains("[Comment 1 by @mutualaidmpls (post author)]\nIt's at 2400 Chicago Ave, 10am"));
+        assert!(thread.contains("[Comment 2 by @neighbor]\nDo you need diapers?"));
+    }
+
+    #[tokio::test]
+    async fn failed_fetches_leave_posts_unexpanded() {
+        let posts = vec![post("https://ig/p/a", 10, 0, "Food drive"), post("https://ig/p/b", 9, 0, "Fridge")];
+        let fetcher = MockFetcher::new().on_comments("https://ig/p/a", vec![comment("org", "Open 9-5")]);
+
+        let threads = expand(&fetcher, &posts).await;
+
+        assert_eq!(threads.len(), 1);
+        assert!(threads["https://ig/p/a"].contains("Open 9-5"));
+    }
+}
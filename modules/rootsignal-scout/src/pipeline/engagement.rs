@@ -0,0 +1,106 @@
+//! Engagement velocity for social content.
+//!
+//! The archive measures each post's velocity (weighted interactions per hour)
+//! when it fetches it. Fast-moving posts are extracted first, and the signals
+//! drawn from them start with a confidence boost: a post with 500 shares in an
+//! hour says more about what's happening than one with 2 likes.
+
+use std::collections::HashMap;
+
+use rootsignal_common::types::Post;
+use rootsignal_common::Node;
+
+/// Velocity at which a post starts to count as fast-moving.
+const FAST_VELOCITY: f64 = 10.0;
+/// Boost at `FAST_VELOCITY`, added again per tenfold increase.
+const BOOST_PER_DECADE: f32 = 0.05;
+const MAX_BOOST: f32 = 0.15;
+
+/// Order posts fastest first; posts without a velocity keep their order at the end.
+pub fn sort_by_velocity(posts: &mut [Post]) {
+    posts.sort_by(|a, b| {
+        let va = a.engagement_velocity.unwrap_or(f64::NEG_INFINITY);
+        let vb = b.engagement_velocity.unwrap_or(f64::NEG_INFINITY);
+        vb.total_cmp(&va)
+    });
+}
+
+/// Post permalinks to their velocities.
+pub fn velocities_by_permalink(posts: &[Post]) -> HashMap<String, f64> {
+    posts
+        .iter()
+        .filter_map(|p| Some((p.permalink.clone()?, p.engagement_velocity?)))
+        .collect()
+}
+
+/// Velocity of the post each node was extracted from, keyed by node id.
+/// Nodes the extractor didn't attribute to a specific post get none.
+pub fn signal_velocities(nodes: &[Node], by_permalink: &HashMap<String, f64>) -> HashMap<uuid::Uuid, f64> {
+    nodes
+        .iter()
+        .filter_map(|n| {
+            let meta = n.meta()?;
+            Some((meta.id, *by_permalink.get(&meta.source_url)?))
+        })
+        .collect()
+}
+
+/// Confidence for a signal from content moving at `velocity`: a log-scaled
+/// boost from `FAST_VELOCITY` up, capped at `MAX_BOOST`.
+pub fn boosted_confidence(confidence: f32, velocity: f64) -> f32 {
+    if velocity < FAST_VELOCITY {
+        return confidence;
+    }
+    let decades = (velocity / FAST_VELOCITY).log10() as f32;
+    let boost = (BOOST_PER_DECADE * (1.0 + decades)).min(MAX_BOOST);
+    (confidence + boost).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{gathering, test_post};
+
+    fn post(permalink: &str, velocity: Option<f64>) -> Post {
+        let mut p = test_post(permalink);
+        p.permalink = Some(permalink.to_string());
+        p.engagement_velocity = velocity;
+        p
+    }
+
+    fn gathering_from(source_url: &str) -> Node {
+        let mut node = gathering("Rally");
+        node.meta_mut().unwrap().source_url = source_url.to_string();
+        node
+    }
+
+    #[test]
+    fn fastest_posts_come_first_and_unmeasured_last() {
+        let mut posts = vec![post("slow", Some(0.5)), post("unknown", None), post("viral", Some(1500.0))];
+
+        sort_by_velocity(&mut posts);
+
+        let order: Vec<_> = posts.iter().map(|p| p.permalink.as_deref().unwrap()).collect();
+        assert_eq!(order, vec!["viral", "slow", "unknown"]);
+    }
+
+    #[test]
+    fn boost_grows_with_velocity_and_is_capped() {
+        assert_eq!(boosted_confidence(0.6, 2.0), 0.6);
+        assert!((boosted_confidence(0.6, 10.0) - 0.65).abs() < 1e-6);
+        assert!((boosted_confidence(0.6, 100.0) - 0.70).abs() < 1e-6);
+        assert!((boosted_confidence(0.6, 1_000_000.0) - 0.75).abs() < 1e-6);
+        assert_eq!(boosted_confidence(0.95, 1_000_000.0), 1.0);
+    }
+
+    #[test]
+    fn signals_take_the_velocity_of_their_post() {
+        let posts = vec![post("https://x.com/a/status/1", Some(300.0)), post("https://x.com/a/status/2", None)];
+        let nodes = vec![gathering_from("https://x.com/a/status/1"), gathering_from("https://x.com/a")];
+
+        let velocities = signal_velocities(&nodes, &velocities_by_permalink(&posts));
+
+        assert_eq!(velocities.len(), 1);
+        assert_eq!(velocities[&nodes[0].meta().unwrap().id], 300.0);
+    }
+}
@@ -10,6 +10,7 @@ use tracing::{info, warn};
 use rootsignal_common::{canonical_value, DiscoveryMethod, SourceNode};
 use rootsignal_graph::GraphWriter;
 
+use crate::discovery::query_ledger::{NewQuery, QueryLedger, EXPANSION_PATTERN};
 use crate::infra::embedder::TextEmbedder;
 use crate::infra::run_log::{EventKind, RunLog};
 use crate::pipeline::scrape_phase::RunContext;
@@ -26,6 +27,7 @@ pub(crate) struct Expansion<'a> {
     writer: &'a GraphWriter,
     embedder: &'a dyn TextEmbedder,
     region_slug: &'a str,
+    ledger: Option<&'a QueryLedger>,
 }
 
 impl<'a> Expansion<'a> {
@@ -38,9 +40,16 @@ impl<'a> Expansion<'a> {
             writer,
             embedder,
             region_slug,
+            ledger: None,
         }
     }
 
+    /// Register created expansion queries in the query ledger.
+    pub fn with_ledger(mut self, ledger: &'a QueryLedger) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
     /// Run the expansion stage:
     /// 1. Collect deferred expansion queries (from recently linked signals)
     /// 2. Deduplicate against existing WebQuery sources (Jaccard + embedding)
@@ -138,6 +147,17 @@ impl<'a> Expansion<'a> {
                         query: query_text.clone(),
                     });
                     created += 1;
+                    if let Some(ledger) = self.ledger {
+                        let expansion = NewQuery {
+                            canonical_key: ck.clone(),
+                            query: query_text.clone(),
+                            pattern: EXPANSION_PATTERN.to_string(),
+                            refined_from: None,
+                        };
+                        if let Err(e) = ledger.register(&expansion).await {
+                            warn!(error = %e, "Failed to register expansion query (non-fatal)");
+                        }
+                    }
                     // Store embedding for future dedup
                     if let Ok(embedding) = self.embedder.embed(query_text).await {
                         if let Err(e) = self.writer.set_query_embedding(&ck, &embedding).await {
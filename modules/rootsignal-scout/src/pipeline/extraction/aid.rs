@@ -0,0 +1,27 @@
+use rootsignal_common::{AidNode, Node, NodeMeta};
+
+use super::ExtractionPlugin;
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub struct AidPlugin;
+
+impl ExtractionPlugin for AidPlugin {
+    fn signal_type(&self) -> &'static str {
+        "aid"
+    }
+
+    fn definition(&self) -> &'static str {
+        "- **Aid**: A free resource, service, or program that people in need can access — food shelves,
+  legal clinics, shelter beds, mutual aid funds, habitat restoration programs. Must be free or
+  publicly available. A business offering paid services is NOT Aid. Has availability and contact info."
+    }
+
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, source_url: &str) -> Node {
+        Node::Aid(AidNode {
+            meta,
+            action_url: signal.action_url.clone().unwrap_or_else(|| source_url.to_string()),
+            availability: signal.availability.clone(),
+            is_ongoing: signal.is_ongoing.unwrap_or(true),
+        })
+    }
+}
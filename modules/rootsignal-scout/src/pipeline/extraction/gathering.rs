@@ -0,0 +1,71 @@
+use rootsignal_common::{GatheringNode, Node, NodeMeta};
+
+use super::{parse_time, ExtractionPlugin};
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub struct GatheringPlugin;
+
+impl ExtractionPlugin for GatheringPlugin {
+    fn signal_type(&self) -> &'static str {
+        "gathering"
+    }
+
+    fn definition(&self) -> &'static str {
+        "- **Gathering**: People coming together in response to a community need or tension — town halls,
+  cleanups, vigils, mutual aid distributions, workshops, solidarity actions. Has time, location,
+  and who's organizing. A press conference or product launch is NOT a Gathering."
+    }
+
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, source_url: &str) -> Node {
+        Node::Gathering(GatheringNode {
+            meta,
+            starts_at: parse_time(signal.starts_at.as_deref()),
+            ends_at: parse_time(signal.ends_at.as_deref()),
+            action_url: signal.action_url.clone().unwrap_or_else(|| source_url.to_string()),
+            organizer: signal.organizer.clone(),
+            is_recurring: signal.is_recurring.unwrap_or(false),
+        })
+    }
+
+    /// An end before the start is a misread time (often a date without a
+    /// year); the start is the one readers act on, so the end is dropped.
+    fn post_process(&self, node: &mut Node) {
+        if let Node::Gathering(gathering) = node {
+            if let (Some(starts_at), Some(ends_at)) = (gathering.starts_at, gathering.ends_at) {
+                if ends_at < starts_at {
+                    gathering.ends_at = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::extractor::ExtractionResponse;
+    use crate::testing::test_meta;
+
+    #[test]
+    fn gathering_ending_before_it_starts_loses_its_end_time() {
+        let response: ExtractionResponse = serde_json::from_str(
+            r#"{"signals": [{
+                "signal_type": "gathering",
+                "title": "Lake Nokomis cleanup",
+                "summary": "Bring gloves",
+                "sensitivity": "general",
+                "starts_at": "2026-04-18T14:00:00Z",
+                "ends_at": "2025-04-18T16:00:00Z"
+            }]}"#,
+        )
+        .unwrap();
+
+        let mut node = GatheringPlugin.build(test_meta("https://example.org"), &response.signals[0], "https://example.org");
+        GatheringPlugin.post_process(&mut node);
+
+        let Node::Gathering(gathering) = node else { panic!("expected a gathering") };
+        assert!(gathering.starts_at.is_some());
+        assert_eq!(gathering.ends_at, None);
+        assert_eq!(gathering.action_url, "https://example.org");
+    }
+}
@@ -0,0 +1,263 @@
+//! Per-node-type extraction plugins.
+//!
+//! Each signal type the extractor knows is an [`ExtractionPlugin`]: its
+//! definition and field notes for the prompt, a validator, how it builds a
+//! node from an extracted signal, and a post-processor. The plugins live in an
+//! [`ExtractionRegistry`], which composes the prompt's signal-type sections
+//! and, narrowed with [`ExtractionRegistry::for_role`], decides which types a
+//! source of a given role is asked for. A new signal type is a new plugin
+//! registered here; the core extractor doesn't change. Fields only a newer
+//! type uses come back in the signal's `details` and are described by the
+//! plugin's `fields`.
+
+mod aid;
+mod gathering;
+mod need;
+mod notice;
+mod tension;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use rootsignal_common::{Node, NodeMeta, Severity, SourceRole};
+
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub use aid::AidPlugin;
+pub use gathering::GatheringPlugin;
+pub use need::NeedPlugin;
+pub use notice::NoticePlugin;
+pub use tension::TensionPlugin;
+
+/// Where a signal type's definition sits in the prompt's ranking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalTier {
+    /// Tensions and the responses to them, listed first.
+    Primary,
+    /// Context for those, e.g. official notices.
+    Context,
+}
+
+pub trait ExtractionPlugin: Send + Sync {
+    /// The `signal_type` the model returns for this node type.
+    fn signal_type(&self) -> &'static str;
+
+    fn tier(&self) -> SignalTier {
+        SignalTier::Primary
+    }
+
+    /// Roles of the sources this type is extracted from.
+    fn roles(&self) -> &'static [SourceRole] {
+        &SourceRole::ALL
+    }
+
+    /// The type's entry in the prompt's "Signal Types" list.
+    fn definition(&self) -> &'static str;
+
+    /// A prompt section describing the type's own fields, if it has any.
+    fn fields(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Why a signal of this type should be dropped, if it should.
+    fn validate(&self, _signal: &ExtractedSignal) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// The node for a validated signal. `source_url` is the signal's own
+    /// source URL, falling back to the page's.
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, source_url: &str) -> Node;
+
+    /// Adjust the built node, e.g. to correct fields the model gets wrong.
+    fn post_process(&self, _node: &mut Node) {}
+}
+
+/// The signal types the extractor knows, in prompt order.
+#[derive(Clone)]
+pub struct ExtractionRegistry {
+    plugins: Vec<Arc<dyn ExtractionPlugin>>,
+}
+
+impl Default for ExtractionRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl ExtractionRegistry {
+    /// A registry with no signal types.
+    pub fn empty() -> Self {
+        Self { plugins: Vec::new() }
+    }
+
+    /// Tension, Aid, Need, Gathering and Notice.
+    pub fn builtin() -> Self {
+        Self::empty()
+            .register(TensionPlugin)
+            .register(AidPlugin)
+            .register(NeedPlugin)
+            .register(GatheringPlugin)
+            .register(NoticePlugin)
+    }
+
+    /// Add a signal type, replacing any registered plugin for the same type.
+    pub fn register(mut self, plugin: impl ExtractionPlugin + 'static) -> Self {
+        let plugin: Arc<dyn ExtractionPlugin> = Arc::new(plugin);
+        match self.plugins.iter_mut().find(|p| p.signal_type() == plugin.signal_type()) {
+            Some(existing) => *existing = plugin,
+            None => self.plugins.push(plugin),
+        }
+        self
+    }
+
+    /// The signal types extracted from sources with `role`.
+    pub fn for_role(&self, role: SourceRole) -> Self {
+        Self {
+            plugins: self.plugins.iter().filter(|p| p.roles().contains(&role)).cloned().collect(),
+        }
+    }
+
+    pub fn plugin(&self, signal_type: &str) -> Option<&dyn ExtractionPlugin> {
+        self.plugins.iter().find(|p| p.signal_type() == signal_type).map(|p| p.as_ref())
+    }
+
+    pub fn signal_types(&self) -> Vec<&'static str> {
+        self.plugins.iter().map(|p| p.signal_type()).collect()
+    }
+
+    /// Fill a prompt template's `{primary_types}`, `{context_types}` and
+    /// `{type_fields}` placeholders from the registered types.
+    pub fn compose_prompt(&self, template: &str) -> String {
+        let definitions = |tier: SignalTier| {
+            self.plugins
+                .iter()
+                .filter(|p| p.tier() == tier)
+                .map(|p| p.definition())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let fields: Vec<&str> = self.plugins.iter().filter_map(|p| p.fields()).collect();
+        template
+            .replace("{primary_types}", &definitions(SignalTier::Primary))
+            .replace("{context_types}", &definitions(SignalTier::Context))
+            .replace("{type_fields}", &fields.join("\n\n"))
+    }
+}
+
+/// An RFC 3339 timestamp from the model, if it gave a valid one.
+pub(crate) fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// A severity from the model, medium when missing or unrecognized.
+pub(crate) fn parse_severity(value: Option<&str>) -> Severity {
+    match value {
+        Some("high") => Severity::High,
+        Some("critical") => Severity::Critical,
+        Some("low") => Severity::Low,
+        _ => Severity::Medium,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::extractor::{extraction_result, ExtractionResponse};
+
+    /// A council vote, kept as a policy notice, from the sources that carry it.
+    struct DecisionPlugin;
+
+    impl ExtractionPlugin for DecisionPlugin {
+        fn signal_type(&self) -> &'static str {
+            "decision"
+        }
+
+        fn tier(&self) -> SignalTier {
+            SignalTier::Context
+        }
+
+        fn roles(&self) -> &'static [SourceRole] {
+            &[SourceRole::Tension, SourceRole::Mixed]
+        }
+
+        fn definition(&self) -> &'static str {
+            "- **Decision**: A vote by a public body."
+        }
+
+        fn fields(&self) -> Option<&'static str> {
+            Some("## Decision Fields\n- details.vote: the tally, e.g. \"7-2\"")
+        }
+
+        fn validate(&self, signal: &ExtractedSignal) -> Result<(), String> {
+            match signal.details.get("vote") {
+                Some(_) => Ok(()),
+                None => Err("decision without a vote".to_string()),
+            }
+        }
+
+        fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, _source_url: &str) -> Node {
+            Node::Notice(rootsignal_common::NoticeNode {
+                meta,
+                severity: Severity::Medium,
+                category: Some("policy".to_string()),
+                effective_date: None,
+                source_authority: signal.source_authority.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn registered_type_is_composed_into_the_prompt_only_for_its_roles() {
+        let registry = ExtractionRegistry::builtin().register(DecisionPlugin);
+        let template = "{primary_types}\n--\n{context_types}\n--\n{type_fields}";
+
+        let council = registry.for_role(SourceRole::Tension).compose_prompt(template);
+        let nonprofit = registry.for_role(SourceRole::Response).compose_prompt(template);
+
+        assert!(council.contains("- **Notice**: An official advisory or policy change. Has source authority and effective date.\n- **Decision**: A vote by a public body.\n--"));
+        assert!(council.ends_with("## Decision Fields\n- details.vote: the tally, e.g. \"7-2\""));
+        assert!(!nonprofit.contains("Decision"));
+        assert_eq!(
+            registry.for_role(SourceRole::Response).signal_types(),
+            ["tension", "aid", "need", "gathering", "notice"]
+        );
+    }
+
+    fn council_minutes() -> ExtractionResponse {
+        serde_json::from_str(
+            r#"{"signals": [
+                {"signal_type": "decision", "title": "Council adopts rent stabilization", "summary": "Passed 7-2", "sensitivity": "general", "details": {"vote": "7-2"}},
+                {"signal_type": "decision", "title": "Council discusses parking", "summary": "No vote taken", "sensitivity": "general"},
+                {"signal_type": "rumor", "title": "Mayor may resign", "summary": "Heard at the meeting", "sensitivity": "general"}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn registered_type_becomes_a_node_built_by_its_plugin() {
+        let registry = ExtractionRegistry::builtin().register(DecisionPlugin);
+
+        let result = extraction_result(&registry, SourceRole::Tension, council_minutes(), "https://example.org/minutes");
+
+        assert_eq!(result.nodes.len(), 1);
+        let Node::Notice(notice) = &result.nodes[0] else { panic!("expected a notice") };
+        assert_eq!(notice.meta.title, "Council adopts rent stabilization");
+        assert_eq!(notice.category.as_deref(), Some("policy"));
+    }
+
+    #[test]
+    fn unregistered_types_and_signals_failing_validation_are_dropped() {
+        let registry = ExtractionRegistry::builtin().register(DecisionPlugin);
+
+        let nonprofit = extraction_result(&registry, SourceRole::Response, council_minutes(), "https://example.org/minutes");
+        let without_plugin =
+            extraction_result(&ExtractionRegistry::builtin(), SourceRole::Tension, council_minutes(), "https://example.org/minutes");
+
+        assert!(nonprofit.nodes.is_empty());
+        assert!(without_plugin.nodes.is_empty());
+    }
+}
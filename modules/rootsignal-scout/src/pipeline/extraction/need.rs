@@ -0,0 +1,33 @@
+use rootsignal_common::{NeedNode, Node, NodeMeta, Urgency};
+
+use super::ExtractionPlugin;
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub struct NeedPlugin;
+
+impl ExtractionPlugin for NeedPlugin {
+    fn signal_type(&self) -> &'static str {
+        "need"
+    }
+
+    fn definition(&self) -> &'static str {
+        "- **Need**: Someone directly expressing what they need and how you can help — fundraisers, volunteer drives, donation requests, mutual aid calls, petitions. The content must come from or speak for the person/group who has the need. Must include: (1) a specific need, (2) a way to respond (donate link, signup, contact info). A journalist reporting that communities need help is a Tension, not a Need."
+    }
+
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, _source_url: &str) -> Node {
+        let urgency = match signal.urgency.as_deref() {
+            Some("high") => Urgency::High,
+            Some("critical") => Urgency::Critical,
+            Some("low") => Urgency::Low,
+            _ => Urgency::Medium,
+        };
+        Node::Need(NeedNode {
+            meta,
+            urgency,
+            what_needed: signal.what_needed.clone(),
+            action_url: signal.action_url.clone(),
+            goal: signal.goal.clone(),
+            fundraiser: None,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use rootsignal_common::{Node, NodeMeta, NoticeNode};
+
+use super::{parse_severity, parse_time, ExtractionPlugin, SignalTier};
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub struct NoticePlugin;
+
+impl ExtractionPlugin for NoticePlugin {
+    fn signal_type(&self) -> &'static str {
+        "notice"
+    }
+
+    fn tier(&self) -> SignalTier {
+        SignalTier::Context
+    }
+
+    fn definition(&self) -> &'static str {
+        "- **Notice**: An official advisory or policy change. Has source authority and effective date."
+    }
+
+    fn fields(&self) -> Option<&'static str> {
+        Some(
+            r#"## Notice Fields
+- severity: "low", "medium", "high", "critical"
+- category: "psa", "policy", "advisory", "enforcement", "health"
+- effective_date: ISO 8601 when the notice takes effect
+- source_authority: The official body issuing it"#,
+        )
+    }
+
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, _source_url: &str) -> Node {
+        Node::Notice(NoticeNode {
+            meta,
+            severity: parse_severity(signal.severity.as_deref()),
+            category: signal.category.clone(),
+            effective_date: parse_time(signal.effective_date.as_deref()),
+            source_authority: signal.source_authority.clone(),
+        })
+    }
+}
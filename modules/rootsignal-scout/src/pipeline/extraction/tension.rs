@@ -0,0 +1,34 @@
+use rootsignal_common::{Node, NodeMeta, TensionNode};
+
+use super::{parse_severity, ExtractionPlugin};
+use crate::pipeline::extractor::ExtractedSignal;
+
+pub struct TensionPlugin;
+
+impl ExtractionPlugin for TensionPlugin {
+    fn signal_type(&self) -> &'static str {
+        "tension"
+    }
+
+    fn definition(&self) -> &'static str {
+        "- **Tension**: A community conflict, systemic problem, or ecological misalignment. Has severity and what would help. NOT the narrative itself — the underlying structural issue."
+    }
+
+    fn fields(&self) -> Option<&'static str> {
+        Some(
+            r#"## Tension Fields
+- severity: "low", "medium", "high", "critical"
+- category: One of: {tension_cats}. These are guidance, not constraints — propose a new category if none fit.
+- what_would_help: What response would address this tension (e.g. "affordable housing policy", "community oversight board")"#,
+        )
+    }
+
+    fn build(&self, meta: NodeMeta, signal: &ExtractedSignal, _source_url: &str) -> Node {
+        Node::Tension(TensionNode {
+            meta,
+            severity: parse_severity(signal.severity.as_deref()),
+            category: signal.category.clone(),
+            what_would_help: signal.what_would_help.clone(),
+        })
+    }
+}
@@ -1,4 +1,9 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use ai_client::claude::Claude;
+use ai_client::traits::{Agent, OutputBuilder, PromptBuilder};
+use ai_client::AiError;
 use anyhow::Result;
 use chrono::Utc;
 use schemars::JsonSchema;
@@ -6,18 +11,25 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use rootsignal_common::{
-    AidNode, GatheringNode, GeoPoint, GeoPrecision, NeedNode, Node, NodeMeta, NoticeNode,
-    SensitivityLevel, Severity, TensionNode, Urgency,
-};
+use rootsignal_common::{Classify, ErrorClass, GeoPoint, GeoPrecision, Node, NodeMeta, SensitivityLevel, SourceRole};
+use rootsignal_common::accessibility::accept_plain_summary;
+
+use crate::memory::memo::RegionMemo;
+use crate::pipeline::extraction::{parse_time, ExtractionRegistry};
+use crate::pipeline::stats::{ExtractionFailure, ExtractionFailureKind};
 
 /// What the LLM returns for each extracted signal.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExtractedSignal {
-    /// Signal type: "gathering", "aid", "need", or "notice"
+    /// Signal type: "tension", "aid", "need", "gathering", "notice", or
+    /// another type registered with the extraction registry
     pub signal_type: String,
     pub title: String,
     pub summary: String,
+    /// The summary for a general reader: one or two short sentences, common
+    /// words, no jargon, at about a 6th-grade reading level.
+    #[serde(default)]
+    pub plain_summary: Option<String>,
     /// "general", "elevated", or "sensitive"
     pub sensitivity: String,
     /// Latitude if location can be determined
@@ -83,6 +95,10 @@ pub struct ExtractedSignal {
     /// For social posts: the account holder. For org pages: the organization.
     /// For news: the journalist or publication.
     pub author_actor: Option<String>,
+    /// Fields specific to signal types whose fields aren't listed above, as
+    /// described in the prompt's section for that type.
+    #[serde(default)]
+    pub details: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A resource capability extracted from a signal.
@@ -146,50 +162,150 @@ pub struct ExtractionResult {
 #[async_trait::async_trait]
 pub trait SignalExtractor: Send + Sync {
     async fn extract(&self, content: &str, source_url: &str) -> Result<ExtractionResult>;
+
+    /// Extract from a source with a known role, asking only for the signal
+    /// types registered for that role. Extractors without per-role prompts
+    /// extract as usual.
+    async fn extract_for_role(&self, content: &str, source_url: &str, _role: SourceRole) -> Result<ExtractionResult> {
+        self.extract(content, source_url).await
+    }
+}
+
+/// Run one extraction in its own task so a hang or panic affects only this item.
+///
+/// The task is aborted if it exceeds `timeout`. Errors, timeouts, and panics all
+/// come back as an [`ExtractionFailure`] for the caller to record.
+pub async fn extract_isolated(
+    extractor: Arc<dyn SignalExtractor>,
+    content: String,
+    source_url: String,
+    role: SourceRole,
+    timeout: Duration,
+) -> std::result::Result<ExtractionResult, ExtractionFailure> {
+    let url = source_url.clone();
+    let failure = |kind, class, message: String| ExtractionFailure {
+        url: url.clone(),
+        kind,
+        class,
+        message,
+    };
+
+    let mut task = tokio::spawn(async move { extractor.extract_for_role(&content, &source_url, role).await });
+    match tokio::time::timeout(timeout, &mut task).await {
+        Ok(Ok(Ok(result))) => Ok(result),
+        Ok(Ok(Err(e))) => Err(failure(ExtractionFailureKind::Error, e.class(), e.to_string())),
+        Ok(Err(join_err)) => {
+            let message = if join_err.is_panic() {
+                let payload = join_err.into_panic();
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "extractor panicked".to_string())
+            } else {
+                join_err.to_string()
+            };
+            Err(failure(ExtractionFailureKind::Panic, ErrorClass::System, message))
+        }
+        Err(_) => {
+            task.abort();
+            Err(failure(
+                ExtractionFailureKind::Timeout,
+                ErrorClass::Transient,
+                format!("no result after {}s", timeout.as_secs()),
+            ))
+        }
+    }
+}
+
+/// Titles the model gives a signal when the page itself failed to load.
+const JUNK_TITLES: &[&str] = &["unable to extract", "page not found", "error loading"];
+
+/// Abort a streamed extraction whose first signal is a junk title: the page
+/// failed to load and nothing after it is worth the output tokens.
+fn junk_first_signal(partial: &str) -> Option<String> {
+    let after_key = &partial[partial.find("\"title\"")? + "\"title\"".len()..];
+    let value = after_key.trim_start().strip_prefix(':')?.trim_start().strip_prefix('"')?;
+    let title = value.split('"').next().unwrap_or(value).to_lowercase();
+    JUNK_TITLES
+        .iter()
+        .find(|junk| title.contains(*junk))
+        .map(|_| format!("first signal is junk: {title:?}"))
 }
 
 pub struct Extractor {
     claude: Claude,
-    system_prompt: String,
+    /// The system prompt for each source role.
+    system_prompts: Vec<(SourceRole, String)>,
+    registry: ExtractionRegistry,
 }
 
 impl Extractor {
-    pub fn new(
-        anthropic_api_key: &str,
-        city_name: &str,
-        default_lat: f64,
-        default_lng: f64,
-    ) -> Self {
-        Self::with_tag_vocabulary(anthropic_api_key, city_name, default_lat, default_lng, &[])
+    pub fn new(anthropic_api_key: &str, city_name: &str) -> Self {
+        Self::with_tag_vocabulary(anthropic_api_key, city_name, &[])
+    }
+
+    pub fn with_tag_vocabulary(anthropic_api_key: &str, city_name: &str, tag_vocabulary: &[String]) -> Self {
+        Self::with_template(
+            anthropic_api_key,
+            DEFAULT_PROMPT_TEMPLATE,
+            city_name,
+            tag_vocabulary,
+            ExtractionRegistry::builtin(),
+        )
     }
 
-    pub fn with_tag_vocabulary(
+    /// Create an extractor from a prompt template, with each source role's
+    /// signal types from `registry` composed into its prompt.
+    pub fn with_template(
         anthropic_api_key: &str,
+        template: &str,
         city_name: &str,
-        default_lat: f64,
-        default_lng: f64,
         tag_vocabulary: &[String],
+        registry: ExtractionRegistry,
     ) -> Self {
-        let claude = Claude::new(anthropic_api_key, "claude-haiku-4-5-20251001");
-        let system_prompt =
-            build_system_prompt(city_name, default_lat, default_lng, tag_vocabulary);
+        let system_prompts = SourceRole::ALL
+            .iter()
+            .map(|&role| (role, render_prompt(template, city_name, tag_vocabulary, &registry.for_role(role))))
+            .collect();
         Self {
-            claude,
-            system_prompt,
+            claude: Claude::new(anthropic_api_key, "claude-haiku-4-5-20251001"),
+            system_prompts,
+            registry,
+        }
+    }
+
+    /// Append the region memo to the system prompt as background.
+    pub fn with_region_memo(mut self, memo: &RegionMemo, region_name: &str) -> Self {
+        if let Some(block) = memo.prompt_block(region_name) {
+            for (_, prompt) in &mut self.system_prompts {
+                prompt.push_str("\n\n");
+                prompt.push_str(&block);
+            }
         }
+        self
     }
 
-    /// Create an extractor with a pre-built system prompt (for genome-driven evolution).
+    /// Create an extractor with a pre-built system prompt (for genome-driven
+    /// evolution), used for every source role.
     pub fn with_system_prompt(anthropic_api_key: &str, system_prompt: String) -> Self {
-        let claude = Claude::new(anthropic_api_key, "claude-haiku-4-5-20251001");
         Self {
-            claude,
-            system_prompt,
+            claude: Claude::new(anthropic_api_key, "claude-haiku-4-5-20251001"),
+            system_prompts: SourceRole::ALL.iter().map(|&role| (role, system_prompt.clone())).collect(),
+            registry: ExtractionRegistry::builtin(),
         }
     }
 
+    fn system_prompt(&self, role: SourceRole) -> &str {
+        self.system_prompts
+            .iter()
+            .find(|(r, _)| *r == role)
+            .map(|(_, prompt)| prompt.as_str())
+            .unwrap_or_default()
+    }
+
     /// Extract signals from page content (internal implementation).
-    async fn extract_impl(&self, content: &str, source_url: &str) -> Result<ExtractionResult> {
+    async fn extract_impl(&self, content: &str, source_url: &str, role: SourceRole) -> Result<ExtractionResult> {
         // Truncate content to avoid token limits
         let content = if content.len() > 30_000 {
             let mut end = 30_000;
@@ -205,279 +321,221 @@ impl Extractor {
             "Extract all signals from this web page.\n\nSource URL: {source_url}\n\n---\n\n{content}"
         );
 
-        let response: ExtractionResponse = self
+        // The system prompt is identical across every page in a run, so it is
+        // cached and only the page content is billed at the full input rate.
+        let response = self
             .claude
-            .extract(
-                "claude-haiku-4-5-20251001",
-                &self.system_prompt,
-                &user_prompt,
-            )
-            .await?;
-
-        // Collect implied queries before converting to nodes
-        let implied_queries: Vec<String> = response
-            .signals
-            .iter()
-            .flat_map(|s| s.implied_queries.iter().cloned())
-            .collect();
+            .prompt(user_prompt)
+            .cacheable_prefix(self.system_prompt(role))
+            .output::<ExtractionResponse>()
+            .send_streaming(&junk_first_signal)
+            .await;
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => match e.downcast_ref::<AiError>() {
+                Some(AiError::Aborted { reason, partial }) => {
+                    warn!(source_url, reason, partial, "Extraction aborted early");
+                    return Ok(ExtractionResult::default());
+                }
+                _ => return Err(e),
+            },
+        };
+
+        let result = extraction_result(&self.registry, role, response, source_url);
+        info!(
+            source_url,
+            count = result.nodes.len(),
+            implied_queries = result.implied_queries.len(),
+            "Extracted signals"
+        );
+        Ok(result)
+    }
+}
 
-        let now = Utc::now();
-        let mut nodes = Vec::new();
-        let mut resource_tags: Vec<(Uuid, Vec<ResourceTag>)> = Vec::new();
-        let mut signal_tags: Vec<(Uuid, Vec<String>)> = Vec::new();
+/// Turn the model's response into nodes: each signal goes to the plugin
+/// registered for its type and `role`, which validates, builds and
+/// post-processes it. Junk, second-hand, unregistered and invalid signals
+/// are dropped.
+pub(crate) fn extraction_result(
+    registry: &ExtractionRegistry,
+    role: SourceRole,
+    response: ExtractionResponse,
+    source_url: &str,
+) -> ExtractionResult {
+    // Collect implied queries before converting to nodes
+    let implied_queries: Vec<String> = response
+        .signals
+        .iter()
+        .flat_map(|s| s.implied_queries.iter().cloned())
+        .collect();
+
+    let registry = registry.for_role(role);
+    let now = Utc::now();
+    let mut nodes = Vec::new();
+    let mut resource_tags: Vec<(Uuid, Vec<ResourceTag>)> = Vec::new();
+    let mut signal_tags: Vec<(Uuid, Vec<String>)> = Vec::new();
+
+    for signal in response.signals {
+        // Skip junk signals from extraction failures
+        let title_lower = signal.title.to_lowercase();
+        if JUNK_TITLES.iter().any(|junk| title_lower.contains(junk))
+        {
+            warn!(
+                source_url,
+                title = signal.title,
+                "Filtered junk signal from extraction"
+            );
+            continue;
+        }
 
-        for signal in response.signals {
-            // Skip junk signals from extraction failures
-            let title_lower = signal.title.to_lowercase();
-            if ["unable to extract", "page not found", "error loading"]
-                .iter()
-                .any(|junk| title_lower.contains(junk))
-            {
-                warn!(
-                    source_url,
-                    title = signal.title,
-                    "Filtered junk signal from extraction"
-                );
-                continue;
-            }
+        // Drop signals flagged as not first-hand (political commentary, not personally affected)
+        if signal.is_firsthand == Some(false) {
+            info!(
+                source_url,
+                title = signal.title,
+                "Dropped non-first-hand signal"
+            );
+            continue;
+        }
+
+        let Some(plugin) = registry.plugin(&signal.signal_type) else {
+            warn!(
+                signal_type = signal.signal_type,
+                title = signal.title,
+                "Unknown signal type, skipping"
+            );
+            continue;
+        };
+        if let Err(reason) = plugin.validate(&signal) {
+            info!(
+                source_url,
+                title = signal.title,
+                reason,
+                "Dropped signal that failed validation"
+            );
+            continue;
+        }
 
-            // Drop signals flagged as not first-hand (political commentary, not personally affected)
-            if signal.is_firsthand == Some(false) {
-                info!(
-                    source_url,
-                    title = signal.title,
-                    "Dropped non-first-hand signal"
-                );
-                continue;
+        let sensitivity = match signal.sensitivity.as_str() {
+            "sensitive" => SensitivityLevel::Sensitive,
+            "elevated" => SensitivityLevel::Elevated,
+            _ => SensitivityLevel::General,
+        };
+
+        let location = match (signal.latitude, signal.longitude) {
+            (Some(lat), Some(lng)) => {
+                let precision = match signal.geo_precision.as_deref() {
+                    Some("exact") => GeoPrecision::Exact,
+                    Some("neighborhood") => GeoPrecision::Neighborhood,
+                    _ => GeoPrecision::Approximate,
+                };
+                Some(GeoPoint {
+                    lat,
+                    lng,
+                    precision,
+                })
             }
+            _ => None,
+        };
 
-            let sensitivity = match signal.sensitivity.as_str() {
-                "sensitive" => SensitivityLevel::Sensitive,
-                "elevated" => SensitivityLevel::Elevated,
-                _ => SensitivityLevel::General,
-            };
+        let mentioned_actors = signal.mentioned_actors.clone().unwrap_or_default();
 
-            let location = match (signal.latitude, signal.longitude) {
-                (Some(lat), Some(lng)) => {
-                    let precision = match signal.geo_precision.as_deref() {
-                        Some("exact") => GeoPrecision::Exact,
-                        Some("neighborhood") => GeoPrecision::Neighborhood,
-                        _ => GeoPrecision::Approximate,
-                    };
-                    Some(GeoPoint {
-                        lat,
-                        lng,
-                        precision,
-                    })
-                }
-                _ => None,
-            };
+        // Use the LLM-returned source_url when present (specific post URL),
+        // falling back to the page-level source_url.
+        let effective_source_url = signal
+            .source_url
+            .as_deref()
+            .filter(|u| !u.is_empty())
+            .unwrap_or(source_url)
+            .to_string();
 
-            let mentioned_actors = signal.mentioned_actors.unwrap_or_default();
-
-            // Use the LLM-returned source_url when present (specific post URL),
-            // falling back to the page-level source_url.
-            let effective_source_url = signal
-                .source_url
-                .as_deref()
-                .filter(|u| !u.is_empty())
-                .unwrap_or(source_url)
-                .to_string();
-
-            let content_date = signal
-                .content_date
-                .as_deref()
-                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-
-            let node_id = Uuid::new_v4();
-            let meta = NodeMeta {
-                id: node_id,
-                title: signal.title.clone(),
-                summary: signal.summary.clone(),
-                sensitivity,
-                confidence: 0.0,      // Will be computed by QualityScorer
-                freshness_score: 1.0, // Fresh at extraction time
-                corroboration_count: 0,
-                about_location: location,
-                about_location_name: signal.location_name.clone(),
-                from_location: None,
-                source_url: effective_source_url.clone(),
-                extracted_at: now,
-                content_date,
-                last_confirmed_active: now,
-                source_diversity: 1,
-                external_ratio: 0.0,
-                cause_heat: 0.0,
-                channel_diversity: 1,
-                mentioned_actors,
-                implied_queries: signal.implied_queries.clone(),
-                author_actor: signal.author_actor.clone(),
-            };
+        let content_date = parse_time(signal.content_date.as_deref());
 
-            let node = match signal.signal_type.as_str() {
-                "gathering" => {
-                    let starts_at = signal
-                        .starts_at
-                        .as_deref()
-                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                        .map(|dt| dt.with_timezone(&Utc));
-                    let ends_at = signal
-                        .ends_at
-                        .as_deref()
-                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                        .map(|dt| dt.with_timezone(&Utc));
-
-                    Node::Gathering(GatheringNode {
-                        meta,
-                        starts_at,
-                        ends_at,
-                        action_url: signal.action_url.unwrap_or(effective_source_url),
-                        organizer: signal.organizer,
-                        is_recurring: signal.is_recurring.unwrap_or(false),
-                    })
-                }
-                "aid" => Node::Aid(AidNode {
-                    meta,
-                    action_url: signal.action_url.unwrap_or(effective_source_url),
-                    availability: signal.availability,
-                    is_ongoing: signal.is_ongoing.unwrap_or(true),
-                }),
-                "need" => {
-                    let urgency = match signal.urgency.as_deref() {
-                        Some("high") => Urgency::High,
-                        Some("critical") => Urgency::Critical,
-                        Some("low") => Urgency::Low,
-                        _ => Urgency::Medium,
-                    };
-                    Node::Need(NeedNode {
-                        meta,
-                        urgency,
-                        what_needed: signal.what_needed,
-                        action_url: signal.action_url,
-                        goal: signal.goal,
-                    })
-                }
-                "notice" => {
-                    let severity = match signal.severity.as_deref() {
-                        Some("high") => Severity::High,
-                        Some("critical") => Severity::Critical,
-                        Some("low") => Severity::Low,
-                        _ => Severity::Medium,
-                    };
-                    let effective_date = signal
-                        .effective_date
-                        .as_deref()
-                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                        .map(|dt| dt.with_timezone(&Utc));
-
-                    Node::Notice(NoticeNode {
-                        meta,
-                        severity,
-                        category: signal.category,
-                        effective_date,
-                        source_authority: signal.source_authority,
-                    })
-                }
-                "tension" => {
-                    let severity = match signal.severity.as_deref() {
-                        Some("high") => Severity::High,
-                        Some("critical") => Severity::Critical,
-                        Some("low") => Severity::Low,
-                        _ => Severity::Medium,
-                    };
-                    Node::Tension(TensionNode {
-                        meta,
-                        severity,
-                        category: signal.category.clone(),
-                        what_would_help: signal.what_would_help.clone(),
-                    })
-                }
-                other => {
-                    warn!(
-                        signal_type = other,
-                        title = signal.title,
-                        "Unknown signal type, skipping"
-                    );
-                    continue;
-                }
-            };
+        let node_id = Uuid::new_v4();
+        let meta = NodeMeta {
+            id: node_id,
+            title: signal.title.clone(),
+            summary: signal.summary.clone(),
+            sensitivity,
+            confidence: 0.0,      // Will be computed by QualityScorer
+            freshness_score: 1.0, // Fresh at extraction time
+            corroboration_count: 0,
+            about_location: location,
+            about_location_name: signal.location_name.clone(),
+            from_location: None,
+            source_url: effective_source_url.clone(),
+            extracted_at: now,
+            content_date,
+            last_confirmed_active: now,
+            source_diversity: 1,
+            external_ratio: 0.0,
+            cause_heat: 0.0,
+            channel_diversity: 1,
+            mentioned_actors,
+            implied_queries: signal.implied_queries.clone(),
+            author_actor: signal.author_actor.clone(),
+            field_visibility: Default::default(),
+            alt_text: None, // generated by the writer
+            plain_summary: accept_plain_summary(signal.plain_summary.as_deref()),
+        };
 
-            // Collect resource tags for this signal
-            if !signal.resources.is_empty() {
-                resource_tags.push((node_id, signal.resources.clone()));
-            }
+        let mut node = plugin.build(meta, &signal, &effective_source_url);
+        plugin.post_process(&mut node);
 
-            // Collect thematic tags for this signal (slugify each tag)
-            if !signal.tags.is_empty() {
-                let slugified: Vec<String> = signal
-                    .tags
-                    .iter()
-                    .map(|t| rootsignal_common::slugify(t))
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                if !slugified.is_empty() {
-                    signal_tags.push((node_id, slugified));
-                }
-            }
+        // Collect resource tags for this signal
+        if !signal.resources.is_empty() {
+            resource_tags.push((node_id, signal.resources.clone()));
+        }
 
-            nodes.push(node);
+        // Collect thematic tags for this signal (slugify each tag)
+        if !signal.tags.is_empty() {
+            let slugified: Vec<String> = signal
+                .tags
+                .iter()
+                .map(|t| rootsignal_common::slugify(t))
+                .filter(|s| !s.is_empty())
+                .collect();
+            if !slugified.is_empty() {
+                signal_tags.push((node_id, slugified));
+            }
         }
 
-        info!(
-            source_url,
-            count = nodes.len(),
-            implied_queries = implied_queries.len(),
-            "Extracted signals"
-        );
-        Ok(ExtractionResult {
-            nodes,
-            implied_queries,
-            resource_tags,
-            signal_tags,
-        })
+        nodes.push(node);
+    }
+
+    ExtractionResult {
+        nodes,
+        implied_queries,
+        resource_tags,
+        signal_tags,
     }
 }
 
+
 #[async_trait::async_trait]
 impl SignalExtractor for Extractor {
     async fn extract(&self, content: &str, source_url: &str) -> Result<ExtractionResult> {
-        self.extract_impl(content, source_url).await
+        self.extract_impl(content, source_url, SourceRole::Mixed).await
+    }
+
+    async fn extract_for_role(&self, content: &str, source_url: &str, role: SourceRole) -> Result<ExtractionResult> {
+        self.extract_impl(content, source_url, role).await
     }
 }
 
-pub fn build_system_prompt(
-    city_name: &str,
-    _default_lat: f64,
-    _default_lng: f64,
-    tag_vocabulary: &[String],
-) -> String {
-    let today = Utc::now().format("%Y-%m-%d").to_string();
-    let tension_cats = crate::infra::util::TENSION_CATEGORIES;
-    let tag_vocab_section = if tag_vocabulary.is_empty() {
-        String::new()
-    } else {
-        format!(
-            "**Existing tag vocabulary** (prefer these when they fit; only invent new tags when no existing tag matches):\n`{}`\n\n",
-            tag_vocabulary.join("`, `")
-        )
-    };
-    format!(
-        r#"You are a signal extractor for {city_name}.
+/// The built-in extraction prompt. `{city_name}`, `{today}`,
+/// `{tension_cats}` and `{tag_vocab_section}` are filled in by
+/// [`render_prompt`], and `{primary_types}`, `{context_types}` and
+/// `{type_fields}` from the extraction registry; registry prompts use the
+/// same placeholders.
+pub const DEFAULT_PROMPT_TEMPLATE: &str = r#"You are a signal extractor for {city_name}.
 
 Your job: find real problems and the people addressing them. The most valuable signal is a TENSION (something out of alignment in community or ecological life) paired with RESPONSES (the gives, needs, events, and notices that address it). A food shelf addressing a food desert, a cleanup responding to pollution, a legal aid hotline responding to enforcement activity — these tension-response pairs are what gets people engaged in real-world problems.
 
 ## Signal Types (ranked by value)
 
 **Highest — Tension + Response pairs:**
-- **Tension**: A community conflict, systemic problem, or ecological misalignment. Has severity and what would help. NOT the narrative itself — the underlying structural issue.
-- **Aid**: A free resource, service, or program that people in need can access — food shelves,
-  legal clinics, shelter beds, mutual aid funds, habitat restoration programs. Must be free or
-  publicly available. A business offering paid services is NOT Aid. Has availability and contact info.
-- **Need**: Someone directly expressing what they need and how you can help — fundraisers, volunteer drives, donation requests, mutual aid calls, petitions. The content must come from or speak for the person/group who has the need. Must include: (1) a specific need, (2) a way to respond (donate link, signup, contact info). A journalist reporting that communities need help is a Tension, not a Need.
-- **Gathering**: People coming together in response to a community need or tension — town halls,
-  cleanups, vigils, mutual aid distributions, workshops, solidarity actions. Has time, location,
-  and who's organizing. A press conference or product launch is NOT a Gathering.
+{primary_types}
 
 **Also valuable — standalone responses with an implicit tension:**
 - A "feed people on Sundays" program implies food insecurity. Extract it as an Aid even without an explicit tension on the page.
@@ -487,7 +545,7 @@ Your job: find real problems and the people addressing them. The most valuable s
 - Community calendar events, recurring worship services, social gatherings. Still extract these, but they matter less than signals that point to a real problem someone can help with.
 
 **Context signals:**
-- **Notice**: An official advisory or policy change. Has source authority and effective date.
+{context_types}
 
 If content doesn't map to one of these types, return an empty signals array.
 
@@ -530,16 +588,11 @@ Do NOT classify news reportage as a Need based on the urgency of the topic alone
 - is_ongoing: true for ongoing services
 - is_recurring: true for recurring events
 
-## Notice Fields
-- severity: "low", "medium", "high", "critical"
-- category: "psa", "policy", "advisory", "enforcement", "health"
-- effective_date: ISO 8601 when the notice takes effect
-- source_authority: The official body issuing it
+{type_fields}
 
-## Tension Fields
-- severity: "low", "medium", "high", "critical"
-- category: One of: {tension_cats}. These are guidance, not constraints — propose a new category if none fit.
-- what_would_help: What response would address this tension (e.g. "affordable housing policy", "community oversight board")
+## Plain-Language Summary
+- plain_summary: the summary rewritten so anyone can read it: one or two short sentences, common words, no jargon or acronyms (about a 6th-grade reading level)
+- Keep every fact a reader needs to act (what, when, where, how to help); don't add anything
 
 ## Source URL
 - When extracting from multiple posts (e.g. "--- Post 1 (https://...) ---"), set source_url to the specific post URL the signal came from
@@ -582,10 +635,10 @@ This enables matching: "I have a car" finds all orgs needing drivers; "I need fo
 `reliable-internet`, `financial-donation`, `skilled-trade`, `administrative`
 
 **Examples:**
-- A volunteer driver program → resources: [{{slug: "vehicle", role: "requires", confidence: 0.95}}]
-- A bilingual legal clinic → resources: [{{slug: "legal-expertise", role: "offers", confidence: 0.9}}, {{slug: "bilingual-spanish", role: "offers", confidence: 0.85}}]
-- Food shelf → resources: [{{slug: "food", role: "offers", confidence: 0.95, context: "emergency groceries, Mon-Fri 9-5"}}]
-- Court date transport needing Spanish speakers → resources: [{{slug: "vehicle", role: "requires", confidence: 0.9}}, {{slug: "bilingual-spanish", role: "prefers", confidence: 0.7}}]
+- A volunteer driver program → resources: [{slug: "vehicle", role: "requires", confidence: 0.95}]
+- A bilingual legal clinic → resources: [{slug: "legal-expertise", role: "offers", confidence: 0.9}, {slug: "bilingual-spanish", role: "offers", confidence: 0.85}]
+- Food shelf → resources: [{slug: "food", role: "offers", confidence: 0.95, context: "emergency groceries, Mon-Fri 9-5"}]
+- Court date transport needing Spanish speakers → resources: [{slug: "vehicle", role: "requires", confidence: 0.9}, {slug: "bilingual-spanish", role: "prefers", confidence: 0.7}]
 
 Only include resources when the capability is clear from the content. Omit the resources array for signals with no resource semantics (e.g. Notices, Tensions).
 
@@ -633,14 +686,75 @@ services, and events — not news articles.
 
 DO NOT provide implied_queries for routine community gatherings (farmers markets,
 worship services, recurring social gatherings) that have no tension connection.
-Return an empty array for these."#
-    )
+Return an empty array for these."#;
+
+pub fn build_system_prompt(
+    city_name: &str,
+    _default_lat: f64,
+    _default_lng: f64,
+    tag_vocabulary: &[String],
+) -> String {
+    render_prompt(DEFAULT_PROMPT_TEMPLATE, city_name, tag_vocabulary, &ExtractionRegistry::builtin())
+}
+
+/// Fill in a prompt template's placeholders for a region, with the signal
+/// types in `registry`.
+pub fn render_prompt(
+    template: &str,
+    city_name: &str,
+    tag_vocabulary: &[String],
+    registry: &ExtractionRegistry,
+) -> String {
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+    let tag_vocab_section = if tag_vocabulary.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "**Existing tag vocabulary** (prefer these when they fit; only invent new tags when no existing tag matches):\n`{}`\n\n",
+            tag_vocabulary.join("`, `")
+        )
+    };
+    registry
+        .compose_prompt(template)
+        .replace("{city_name}", city_name)
+        .replace("{today}", &today)
+        .replace("{tension_cats}", crate::infra::util::TENSION_CATEGORIES)
+        .replace("{tag_vocab_section}", &tag_vocab_section)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn region_memo_is_appended_to_system_prompt() {
+        use crate::memory::memo::MemoFact;
+
+        let memo = RegionMemo {
+            known_hoaxes: vec![MemoFact::new("Fake FEMA cash card texts", Utc::now().date_naive())],
+            ..Default::default()
+        };
+        let extractor = Extractor::new("test-key", "Minneapolis")
+            .with_region_memo(&memo, "Minneapolis");
+        assert!(extractor
+            .system_prompt(SourceRole::Tension)
+            .ends_with("## KNOWN HOAXES\n- Fake FEMA cash card texts"));
+
+        let bare = Extractor::new("test-key", "Minneapolis")
+            .with_region_memo(&RegionMemo::default(), "Minneapolis");
+        assert!(!bare.system_prompt(SourceRole::Mixed).contains("REGION MEMORY"));
+    }
+
+    #[test]
+    fn streamed_extraction_aborts_once_the_first_title_is_junk() {
+        let junk = r#"{"signals": [{"signal_type": "notice", "title": "Page not found"#;
+        let real = r#"{"signals": [{"signal_type": "aid", "title": "Free groceries at"#;
+
+        assert!(junk_first_signal(junk).is_some());
+        assert_eq!(junk_first_signal(real), None);
+        assert_eq!(junk_first_signal(r#"{"signals": [{"signal_type": "aid", "ti"#), None);
+    }
+
     #[test]
     fn system_prompt_includes_tension() {
         let prompt = build_system_prompt("Minneapolis", 44.9778, -93.2650, &[]);
@@ -656,13 +770,13 @@ mod tests {
 
     #[test]
     fn tension_type_constructs_node() {
-        // The tension match arm now constructs a TensionNode instead of skipping.
         // This test verifies the ExtractedSignal has the what_would_help field
-        // and that TensionNode can be constructed with all fields.
+        // the tension plugin builds a TensionNode from.
         let signal = ExtractedSignal {
             signal_type: "tension".to_string(),
             title: "Housing crisis".to_string(),
             summary: "Rent increases displacing families".to_string(),
+            plain_summary: None,
             sensitivity: "elevated".to_string(),
             latitude: None,
             longitude: None,
@@ -691,6 +805,7 @@ mod tests {
             tags: vec![],
             is_firsthand: None,
             author_actor: None,
+            details: Default::default(),
         };
 
         assert_eq!(signal.signal_type, "tension");
@@ -743,6 +858,9 @@ mod tests {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
         let aid = AidNode {
             meta,
@@ -778,6 +896,9 @@ mod tests {
             mentioned_actors: vec![],
             implied_queries: vec![],
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
         let need = NeedNode {
             meta,
@@ -785,6 +906,7 @@ mod tests {
             what_needed: None,
             action_url: None,
             goal: None,
+            fundraiser: None,
         };
         assert!(need.what_needed.is_none());
     }
@@ -0,0 +1,283 @@
+//! Flyer reading for social posts.
+//!
+//! Much civic information is posted as an image — an event flyer, a
+//! screenshot of an announcement — with little or no caption. A post's image
+//! attachments are read with a vision model for event and resource details,
+//! and what it finds is merged under the caption before extraction. Images the
+//! archive has already transcribed are reused for free; vision reads are
+//! charged per image as their own operation class, capped per account and by
+//! the allowance the run grants.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use ai_client::VisionAgent;
+use futures::future::join_all;
+use rootsignal_common::types::{ArchiveFile, Post};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::traits::ContentFetcher;
+use crate::scheduling::budget::OperationCost;
+
+pub const VISION_MODEL: &str = "claude-sonnet-4-5-20250929";
+
+/// Vision reads per account per scrape.
+pub const MAX_FLYERS_PER_ACCOUNT: usize = 4;
+/// Vision reads per run, before the budget is considered.
+pub const MAX_FLYER_READS_PER_RUN: u32 = 60;
+/// Images read per post (carousels often put the details on a later slide).
+const MAX_IMAGES_PER_POST: usize = 2;
+/// Captions shorter than this are read first: the details are likely in the image.
+const SHORT_CAPTION_CHARS: usize = 80;
+/// Largest image the vision model accepts.
+const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+/// Image types the vision model accepts.
+const VISION_MIME_TYPES: &[&str] = &["image/jpeg", "image/png", "image/gif", "image/webp"];
+/// The model's answer when an image carries nothing actionable.
+const NO_DETAILS: &str = "NONE";
+
+const FLYER_PROMPT: &str = "This image was posted to a community social media account. \
+If it is a flyer, poster, or announcement, transcribe every detail someone would need to act on it: \
+what it is, who is organizing it, date and time, address or location, cost, how to sign up or get help, \
+what is needed or offered, contact information, and any deadlines. Keep the wording from the image; \
+don't add anything that isn't shown. If the image has no such details (a photo, a meme, decorative text), \
+answer exactly NONE.";
+
+fn is_readable_image(file: &ArchiveFile) -> bool {
+    VISION_MIME_TYPES.contains(&file.mime_type.as_str())
+}
+
+fn transcribed_text(file: &ArchiveFile) -> Option<&str> {
+    file.text.as_deref().map(str::trim).filter(|t| !t.is_empty())
+}
+
+/// Image attachments worth a vision read, best first: images on posts with
+/// little caption, then the rest, each group in feed order, capped at
+/// `MAX_FLYERS_PER_ACCOUNT`. Images the archive already transcribed are
+/// left out.
+pub fn images_to_read(posts: &[Post]) -> Vec<(Uuid, &ArchiveFile)> {
+    let mut candidates: Vec<(bool, Uuid, &ArchiveFile)> = posts
+        .iter()
+        .flat_map(|p| {
+            let short_caption = p.text.as_deref().unwrap_or_default().trim().chars().count() < SHORT_CAPTION_CHARS;
+            p.attachments
+                .iter()
+                .filter(|f| is_readable_image(f))
+                .take(MAX_IMAGES_PER_POST)
+                .filter(|f| transcribed_text(f).is_none())
+                .map(move |f| (short_caption, p.id, f))
+        })
+        .collect();
+    candidates.sort_by_key(|c| !c.0);
+    candidates
+        .into_iter()
+        .take(MAX_FLYERS_PER_ACCOUNT)
+        .map(|(_, post_id, file)| (post_id, file))
+        .collect()
+}
+
+/// Image text as extraction context, placed under the post's caption.
+pub fn format_image_text(texts: &[String]) -> String {
+    let mut block = String::from("Text and details read from images attached to this post:");
+    for (i, text) in texts.iter().enumerate() {
+        block.push_str(&format!("\n[Image {}]\n{}", i + 1, text.trim()));
+    }
+    block
+}
+
+/// Reads flyer images with a vision model, within an allowance of reads
+/// granted by the run budget. The run charges itself for the reads with
+/// `take_uncharged_cost`.
+pub struct FlyerReader {
+    vision: Arc<dyn VisionAgent>,
+    allowance: u32,
+    reads: AtomicU32,
+    uncharged: AtomicU32,
+}
+
+impl FlyerReader {
+    pub fn new(vision: Arc<dyn VisionAgent>, allowance: u32) -> Self {
+        Self {
+            vision,
+            allowance,
+            reads: AtomicU32::new(0),
+            uncharged: AtomicU32::new(0),
+        }
+    }
+
+    /// Claim one read from the allowance.
+    fn reserve(&self) -> bool {
+        self.reads
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |r| (r < self.allowance).then_some(r + 1))
+            .is_ok()
+    }
+
+    /// Vision reads made so far this run.
+    pub fn reads(&self) -> u32 {
+        self.reads.load(Ordering::Relaxed)
+    }
+
+    /// Cost in cents of the reads made since the last call.
+    pub fn take_uncharged_cost(&self) -> u64 {
+        self.uncharged.swap(0, Ordering::Relaxed) as u64 * OperationCost::CLAUDE_SONNET_FLYER_READ
+    }
+
+    async fn read_image(&self, fetcher: &dyn ContentFetcher, file: &ArchiveFile) -> Option<String> {
+        let bytes = match fetcher.media(&file.url).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!(url = file.url, error = %e, "Failed to download post image");
+                return None;
+            }
+        };
+        if bytes.len() > MAX_IMAGE_BYTES || !self.reserve() {
+            return None;
+        }
+        self.uncharged.fetch_add(1, Ordering::Relaxed);
+        match self.vision.describe_image(&bytes, &file.mime_type, FLYER_PROMPT).await {
+            Ok(text) if text.trim() != NO_DETAILS && !text.trim().is_empty() => Some(text),
+            Ok(_) => None,
+            Err(e) => {
+                warn!(url = file.url, error = %e, "Failed to read post image");
+                None
+            }
+        }
+    }
+
+    /// Image text for an account's posts, formatted for extraction and keyed
+    /// by post id. Uses the archive's transcriptions where they exist and
+    /// vision reads for the most promising of the rest.
+    pub async fn read(&self, fetcher: &dyn ContentFetcher, posts: &[Post]) -> HashMap<Uuid, String> {
+        let mut texts: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for post in posts {
+            for file in post.attachments.iter().filter(|f| is_readable_image(f)).take(MAX_IMAGES_PER_POST) {
+                if let Some(text) = transcribed_text(file) {
+                    texts.entry(post.id).or_default().push(text.to_string());
+                }
+            }
+        }
+
+        let targets = images_to_read(posts);
+        let read = join_all(targets.iter().map(|(_, file)| self.read_image(fetcher, file))).await;
+        let mut flyers = 0;
+        for ((post_id, _), text) in targets.into_iter().zip(read) {
+            if let Some(text) = text {
+                texts.entry(post_id).or_default().push(text);
+                flyers += 1;
+            }
+        }
+        if flyers > 0 {
+            info!(flyers, "Read flyer images");
+        }
+
+        texts
+            .into_iter()
+            .map(|(post_id, texts)| (post_id, format_image_text(&texts)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{test_post, MockFetcher};
+    use anyhow::Result;
+    use async_trait::async_trait;
+
+    struct MockVision {
+        answers: HashMap<usize, String>,
+    }
+
+    #[async_trait]
+    impl VisionAgent for MockVision {
+        async fn describe_image(&self, bytes: &[u8], _mime_type: &str, _prompt: &str) -> Result<String> {
+            self.answers
+                .get(&bytes.len())
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("MockVision: no answer for image"))
+        }
+    }
+
+    /// Answers keyed by image size, so each registered image gets its own.
+    fn vision(answers: &[(usize, &str)]) -> Arc<dyn VisionAgent> {
+        Arc::new(MockVision {
+            answers: answers.iter().map(|(len, a)| (*len, a.to_string())).collect(),
+        })
+    }
+
+    fn image(url: &str, mime_type: &str, text: Option<&str>) -> ArchiveFile {
+        ArchiveFile {
+            id: Uuid::new_v4(),
+            url: url.to_string(),
+            content_hash: String::new(),
+            fetched_at: chrono::Utc::now(),
+            title: None,
+            mime_type: mime_type.to_string(),
+            duration: None,
+            page_count: None,
+            text: text.map(str::to_string),
+            text_language: None,
+        }
+    }
+
+    fn post(caption: &str, attachments: Vec<ArchiveFile>) -> Post {
+        let mut p = test_post(caption);
+        p.attachments = attachments;
+        p
+    }
+
+    #[test]
+    fn images_on_uncaptioned_posts_are_read_first_up_to_the_cap() {
+        let long_caption = "Join us this weekend for our annual neighborhood cleanup, bring gloves and a friend, snacks provided!";
+        let posts = vec![
+            post(long_caption, vec![image("https://cdn/long.jpg", "image/jpeg", None)]),
+            post("", vec![image("https://cdn/video.mp4", "video/mp4", None)]),
+            post("👇", vec![image("https://cdn/a.jpg", "image/jpeg", None), image("https://cdn/b.png", "image/png", None), image("https://cdn/c.png", "image/png", None)]),
+            post("", vec![image("https://cdn/done.jpg", "image/jpeg", Some("Food shelf open Tue")), image("https://cdn/d.jpg", "image/jpeg", None)]),
+        ];
+
+        let urls: Vec<_> = images_to_read(&posts).iter().map(|(_, f)| f.url.as_str()).collect();
+
+        assert_eq!(urls, vec!["https://cdn/a.jpg", "https://cdn/b.png", "https://cdn/d.jpg", "https://cdn/long.jpg"]);
+    }
+
+    #[tokio::test]
+    async fn flyer_details_and_archive_transcriptions_are_merged_per_post() {
+        let flyer = post("", vec![image("https://cdn/flyer.jpg", "image/jpeg", None)]);
+        let meme = post("lol", vec![image("https://cdn/meme.jpg", "image/jpeg", None)]);
+        let known = post("", vec![image("https://cdn/known.jpg", "image/jpeg", Some("Rent clinic, Thu 6pm"))]);
+        let fetcher = MockFetcher::new()
+            .on_media("https://cdn/flyer.jpg", &[0; 3])
+            .on_media("https://cdn/meme.jpg", &[0; 4]);
+        let reader = FlyerReader::new(vision(&[(3, "Coat drive Sat 10am, 2400 Chicago Ave"), (4, "NONE")]), 10);
+
+        let texts = reader.read(&fetcher, &[flyer.clone(), meme.clone(), known.clone()]).await;
+
+        assert!(texts[&flyer.id].contains("[Image 1]\nCoat drive Sat 10am, 2400 Chicago Ave"));
+        assert!(texts[&known.id].contains("Rent clinic, Thu 6pm"));
+        assert!(!texts.contains_key(&meme.id));
+    }
+
+    #[tokio::test]
+    async fn reads_stop_at_the_allowance_and_are_charged_once() {
+        let posts = vec![
+            post("", vec![image("https://cdn/1.jpg", "image/jpeg", None)]),
+            post("", vec![image("https://cdn/2.jpg", "image/jpeg", None)]),
+            post("", vec![image("https://cdn/3.jpg", "image/jpeg", None)]),
+        ];
+        let fetcher = MockFetcher::new()
+            .on_media("https://cdn/1.jpg", &[0; 5])
+            .on_media("https://cdn/2.jpg", &[0; 5])
+            .on_media("https://cdn/3.jpg", &[0; 5]);
+        let reader = FlyerReader::new(vision(&[(5, "Vigil at City Hall, 7pm")]), 2);
+
+        let texts = reader.read(&fetcher, &posts).await;
+
+        assert_eq!(texts.len(), 2);
+        assert_eq!(reader.reads(), 2);
+        assert_eq!(reader.take_uncharged_cost(), 2 * OperationCost::CLAUDE_SONNET_FLYER_READ);
+        assert_eq!(reader.take_uncharged_cost(), 0);
+    }
+}
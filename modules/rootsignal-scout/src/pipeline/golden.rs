@@ -0,0 +1,270 @@
+//! Golden outputs for the extraction prompt.
+//!
+//! A curated set of inputs, each with an approved extraction output on disk.
+//! Fresh outputs are compared by signal type and by embedding similarity of
+//! title and summary, so rewording passes but a lost, added or retyped signal
+//! does not. A changed output is written as pending and has to be approved
+//! before it replaces the golden.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use rootsignal_common::{Node, TextEmbedder};
+
+use crate::infra::util::cosine_similarity;
+
+/// Default similarity a fresh signal needs to its golden counterpart.
+pub const DEFAULT_THRESHOLD: f64 = 0.85;
+
+/// One curated input, listed in `cases.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenCase {
+    pub name: String,
+    /// File under the fixtures directory.
+    pub fixture: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenSignal {
+    pub signal_type: String,
+    pub title: String,
+    pub summary: String,
+}
+
+impl GoldenSignal {
+    pub fn from_node(node: &Node) -> Self {
+        Self {
+            signal_type: node.node_type().to_string().to_lowercase(),
+            title: node.title().to_string(),
+            summary: node.meta().map(|m| m.summary.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// The text that is embedded for comparison.
+    pub fn text(&self) -> String {
+        format!("{}. {}", self.title, self.summary)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoldenOutput {
+    pub signals: Vec<GoldenSignal>,
+}
+
+/// How a fresh output lines up with the approved one.
+#[derive(Debug, Default)]
+pub struct GoldenReport {
+    /// (golden index, fresh index, similarity)
+    pub matched: Vec<(usize, usize, f64)>,
+    /// Golden signals with no fresh signal of the same type close enough.
+    pub missing: Vec<usize>,
+    /// Fresh signals that matched nothing in the golden.
+    pub unexpected: Vec<usize>,
+}
+
+impl GoldenReport {
+    pub fn passed(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+/// Pair golden and fresh signals of the same type, most similar first, and
+/// keep pairs at or above `threshold`. Embeddings are in signal order.
+pub fn compare(
+    golden: &GoldenOutput,
+    fresh: &GoldenOutput,
+    golden_embeddings: &[Vec<f64>],
+    fresh_embeddings: &[Vec<f64>],
+    threshold: f64,
+) -> GoldenReport {
+    let mut pairs = Vec::new();
+    for (g, gs) in golden.signals.iter().enumerate() {
+        for (f, fs) in fresh.signals.iter().enumerate() {
+            if gs.signal_type != fs.signal_type {
+                continue;
+            }
+            let sim = cosine_similarity(&golden_embeddings[g], &fresh_embeddings[f]);
+            if sim >= threshold {
+                pairs.push((g, f, sim));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    let mut golden_used = vec![false; golden.signals.len()];
+    let mut fresh_used = vec![false; fresh.signals.len()];
+    let mut report = GoldenReport::default();
+    for (g, f, sim) in pairs {
+        if golden_used[g] || fresh_used[f] {
+            continue;
+        }
+        golden_used[g] = true;
+        fresh_used[f] = true;
+        report.matched.push((g, f, sim));
+    }
+    report.missing = (0..golden.signals.len()).filter(|&g| !golden_used[g]).collect();
+    report.unexpected = (0..fresh.signals.len()).filter(|&f| !fresh_used[f]).collect();
+    report
+}
+
+/// Embed both outputs and compare them.
+pub async fn compare_embedded(
+    embedder: &dyn TextEmbedder,
+    golden: &GoldenOutput,
+    fresh: &GoldenOutput,
+    threshold: f64,
+) -> Result<GoldenReport> {
+    let embed = |output: &GoldenOutput| {
+        let texts: Vec<String> = output.signals.iter().map(GoldenSignal::text).collect();
+        async move {
+            if texts.is_empty() {
+                return Ok(Vec::new());
+            }
+            let vectors = embedder.embed_batch(texts).await?;
+            Ok::<_, anyhow::Error>(
+                vectors
+                    .into_iter()
+                    .map(|v| v.into_iter().map(f64::from).collect())
+                    .collect::<Vec<Vec<f64>>>(),
+            )
+        }
+    };
+    let golden_embeddings = embed(golden).await?;
+    let fresh_embeddings = embed(fresh).await?;
+    Ok(compare(golden, fresh, &golden_embeddings, &fresh_embeddings, threshold))
+}
+
+/// The golden directory: `cases.json`, `approved/` and `pending/`.
+pub struct GoldenSet {
+    root: PathBuf,
+}
+
+impl GoldenSet {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn cases(&self) -> Result<Vec<GoldenCase>> {
+        let path = self.root.join("cases.json");
+        let json = std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn approved_path(&self, name: &str) -> PathBuf {
+        self.root.join("approved").join(format!("{name}.json"))
+    }
+
+    fn pending_path(&self, name: &str) -> PathBuf {
+        self.root.join("pending").join(format!("{name}.json"))
+    }
+
+    /// The approved output, or `None` if the case has never been approved.
+    pub fn approved(&self, name: &str) -> Result<Option<GoldenOutput>> {
+        read_output(&self.approved_path(name))
+    }
+
+    /// Record a fresh output that needs review.
+    pub fn write_pending(&self, name: &str, output: &GoldenOutput) -> Result<PathBuf> {
+        let path = self.pending_path(name);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, serde_json::to_string_pretty(output)? + "\n")?;
+        Ok(path)
+    }
+
+    /// Drop a stale pending output once the case passes again.
+    pub fn clear_pending(&self, name: &str) -> Result<()> {
+        let path = self.pending_path(name);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Names of cases with a pending output.
+    pub fn pending(&self) -> Result<Vec<String>> {
+        let dir = self.root.join("pending");
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut names: Vec<String> = std::fs::read_dir(dir)?
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let path = e.path();
+                (path.extension()? == "json").then(|| path.file_stem()?.to_str().map(str::to_string))?
+            })
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// Promote a pending output to the approved golden.
+    pub fn approve(&self, name: &str) -> Result<()> {
+        let pending = self.pending_path(name);
+        anyhow::ensure!(pending.exists(), "no pending output for golden {name:?}");
+        let approved = self.approved_path(name);
+        std::fs::create_dir_all(approved.parent().unwrap())?;
+        std::fs::rename(pending, approved)?;
+        Ok(())
+    }
+}
+
+fn read_output(path: &Path) -> Result<Option<GoldenOutput>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let json = std::fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(signal_type: &str, title: &str) -> GoldenSignal {
+        GoldenSignal {
+            signal_type: signal_type.to_string(),
+            title: title.to_string(),
+            summary: String::new(),
+        }
+    }
+
+    #[test]
+    fn reworded_signal_matches_but_retyped_one_does_not() {
+        let golden = GoldenOutput {
+            signals: vec![signal("gathering", "Spring planting day"), signal("aid", "Free seedlings")],
+        };
+        let fresh = GoldenOutput {
+            signals: vec![signal("gathering", "Planting day this spring"), signal("need", "Free seedlings")],
+        };
+        let golden_embeddings = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let fresh_embeddings = vec![vec![0.95, 0.1], vec![0.0, 1.0]];
+
+        let report = compare(&golden, &fresh, &golden_embeddings, &fresh_embeddings, DEFAULT_THRESHOLD);
+
+        assert_eq!(report.matched.iter().map(|m| (m.0, m.1)).collect::<Vec<_>>(), vec![(0, 0)]);
+        assert_eq!(report.missing, vec![1]);
+        assert_eq!(report.unexpected, vec![1]);
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn pending_output_replaces_golden_only_when_approved() {
+        let root = std::env::temp_dir().join(format!("golden-{}", uuid::Uuid::new_v4()));
+        let set = GoldenSet::new(&root);
+        let output = GoldenOutput { signals: vec![signal("aid", "Food shelf open Saturdays")] };
+
+        set.write_pending("food_shelf", &output).unwrap();
+        let before = set.approved("food_shelf").unwrap();
+        set.approve("food_shelf").unwrap();
+        let after = set.approved("food_shelf").unwrap();
+        let pending = set.pending().unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(before, None);
+        assert_eq!(after, Some(output));
+        assert!(pending.is_empty());
+    }
+}
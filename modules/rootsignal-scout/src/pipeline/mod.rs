@@ -1,10 +1,18 @@
+pub mod comment_threads;
+pub mod engagement;
 pub mod expansion;
+pub mod extraction;
 pub mod extractor;
+pub mod flyers;
+pub mod golden;
 pub mod news_scanner;
+pub mod page_diff;
 pub mod scrape_phase;
 pub mod scrape_pipeline;
+pub mod shadow;
 pub mod stats;
 pub mod traits;
+pub mod work_queue;
 #[cfg(test)]
 pub mod simweb_adapter;
 #[cfg(test)]
@@ -1,20 +1,71 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::Result;
 use rootsignal_common::Node;
+use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 use rootsignal_graph::beacon::BeaconCandidate;
-use rootsignal_graph::GraphWriter;
+use rootsignal_graph::{GraphWriter, SituationBrief};
 
 use rootsignal_archive::Archive;
 
 use crate::pipeline::extractor::{Extractor, SignalExtractor};
-use crate::scheduling::budget::BudgetTracker;
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
 
-/// Hardcoded seed list of global/national RSS feeds for Driver B.
-const NEWS_FEEDS: &[&str] = &[
+/// How close a news source is to the regions we cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Locality {
+    /// Covers one region.
+    Local(String),
+    /// A beat (housing, climate, labor...) across places.
+    Topical,
+    /// National or international desk, including wire services.
+    National,
+}
+
+/// Reliability tier: how much a source's articles are worth scanning.
+/// Tier 1 is original local reporting; tier 3 is national and wire copy,
+/// scanned only when it touches a region's topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SourceTier {
+    One,
+    Two,
+    Three,
+}
+
+impl SourceTier {
+    pub const ALL: [SourceTier; 3] = [SourceTier::One, SourceTier::Two, SourceTier::Three];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+        }
+    }
+
+    /// Base priority; topic relevance adds to it but never lifts an
+    /// article past a better tier.
+    fn base_priority(&self) -> f64 {
+        match self {
+            Self::One => 2.0,
+            Self::Two => 1.0,
+            Self::Three => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewsFeed {
+    pub url: String,
+    pub locality: Locality,
+    pub tier: SourceTier,
+}
+
+/// National and international feeds.
+const NATIONAL_FEEDS: &[&str] = &[
     // Wire services
     "https://feeds.apnews.com/rss/apf-topnews",
     "https://www.reuters.com/rssFeed/topNews",
@@ -25,6 +76,10 @@ const NEWS_FEEDS: &[&str] = &[
     "https://feeds.bbci.co.uk/news/rss.xml",
     "https://www.aljazeera.com/xml/rss/all.xml",
     "https://www.theguardian.com/us-news/rss",
+];
+
+/// Beat publications covering the issues signals are about.
+const TOPICAL_FEEDS: &[&str] = &[
     // Topic: housing
     "https://www.curbed.com/rss/index.xml",
     "https://shelterforce.org/feed/",
@@ -43,12 +98,174 @@ const NEWS_FEEDS: &[&str] = &[
     "https://www.labornotes.org/rss.xml",
 ];
 
-/// News scanner that fetches global RSS feeds, extracts signals, and stores them.
+/// Local newsrooms by region slug.
+const LOCAL_FEEDS: &[(&str, &str)] = &[
+    ("twincities", "https://sahanjournal.com/feed/"),
+    ("twincities", "https://minnesotareformer.com/feed/"),
+    ("twincities", "https://www.minnpost.com/feed/"),
+    ("twincities", "https://racketmn.com/rss/"),
+    ("twincities", "https://www.startribune.com/local/index.rss2"),
+];
+
+/// Situations a region's topic targets are drawn from.
+pub const MAX_TARGET_SITUATIONS: u32 = 15;
+/// Terms kept per region.
+const MAX_TOPIC_TERMS: usize = 25;
+/// Hottest situations searched directly per region each scan.
+const TOPIC_QUERIES_PER_REGION: usize = 3;
+const TOPIC_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "by", "for", "from", "in", "into", "is", "it", "new", "of", "on",
+    "over", "the", "to", "with",
+];
+
+/// The seed feeds, classified.
+pub fn seed_feeds() -> Vec<NewsFeed> {
+    let local = LOCAL_FEEDS.iter().map(|(region, url)| NewsFeed {
+        url: url.to_string(),
+        locality: Locality::Local(region.to_string()),
+        tier: SourceTier::One,
+    });
+    let topical = TOPICAL_FEEDS.iter().map(|url| NewsFeed {
+        url: url.to_string(),
+        locality: Locality::Topical,
+        tier: SourceTier::Two,
+    });
+    let national = NATIONAL_FEEDS.iter().map(|url| NewsFeed {
+        url: url.to_string(),
+        locality: Locality::National,
+        tier: SourceTier::Three,
+    });
+    local.chain(topical).chain(national).collect()
+}
+
+/// Regions with local feeds.
+pub fn local_feed_regions() -> Vec<String> {
+    let mut regions: Vec<String> = LOCAL_FEEDS.iter().map(|(region, _)| region.to_string()).collect();
+    regions.dedup();
+    regions
+}
+
+/// What a region's news scan looks for, derived from its active situations.
+#[derive(Debug, Clone, Default)]
+pub struct TopicTargets {
+    pub region: String,
+    /// Headline words weighted by the temperature of the situations using them.
+    terms: Vec<(String, f64)>,
+    /// Hottest situation headlines, searched as news queries.
+    queries: Vec<String>,
+}
+
+impl TopicTargets {
+    pub fn from_situations(region: &str, situations: &[SituationBrief]) -> Self {
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for situation in situations {
+            let text = format!("{} {}", situation.headline, situation.location_name.as_deref().unwrap_or_default());
+            for term in topic_words(&text) {
+                *weights.entry(term).or_default() += situation.temperature.max(0.0);
+            }
+        }
+        let mut terms: Vec<(String, f64)> = weights.into_iter().filter(|(_, w)| *w > 0.0).collect();
+        terms.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        terms.truncate(MAX_TOPIC_TERMS);
+
+        let mut hottest: Vec<&SituationBrief> = situations.iter().collect();
+        hottest.sort_by(|a, b| b.temperature.total_cmp(&a.temperature));
+        Self {
+            region: region.to_string(),
+            terms,
+            queries: hottest
+                .into_iter()
+                .take(TOPIC_QUERIES_PER_REGION)
+                .map(|s| s.headline.clone())
+                .collect(),
+        }
+    }
+
+    /// How strongly `text` touches this region's topics, 0 to 1: the share
+    /// of the heaviest term's weight its matching terms add up to.
+    pub fn relevance(&self, text: &str) -> f64 {
+        let Some(max) = self.terms.first().map(|(_, w)| *w) else {
+            return 0.0;
+        };
+        let words: HashSet<String> = topic_words(text).collect();
+        let matched: f64 = self.terms.iter().filter(|(t, _)| words.contains(t)).map(|(_, w)| w).sum();
+        (matched / max).min(1.0)
+    }
+
+    /// Search feeds for the region's hottest situations.
+    pub fn query_feeds(&self) -> Vec<NewsFeed> {
+        self.queries
+            .iter()
+            .filter_map(|q| url::Url::parse_with_params("https://news.google.com/rss/search", &[("q", q.as_str())]).ok())
+            .map(|url| NewsFeed {
+                url: url.to_string(),
+                locality: Locality::Local(self.region.clone()),
+                tier: SourceTier::Two,
+            })
+            .collect()
+    }
+}
+
+fn topic_words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|w| w.len() > 2 && !TOPIC_STOPWORDS.contains(&w.as_str()))
+}
+
+/// Scan priority for an article, or `None` if it isn't worth the budget:
+/// tier-3 articles must touch some region's topics.
+pub fn article_priority(feed: &NewsFeed, title: Option<&str>, targets: &[TopicTargets]) -> Option<f64> {
+    let title = title.unwrap_or_default();
+    let relevance = targets
+        .iter()
+        .filter(|t| match &feed.locality {
+            Locality::Local(region) => &t.region == region,
+            Locality::Topical | Locality::National => true,
+        })
+        .map(|t| t.relevance(title))
+        .fold(0.0, f64::max);
+    if feed.tier == SourceTier::Three && relevance == 0.0 {
+        return None;
+    }
+    Some(feed.tier.base_priority() + relevance)
+}
+
+/// What one tier's sources produced in a scan.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TierYield {
+    pub tier: u8,
+    pub articles_found: u32,
+    /// Tier-3 articles that touched no region topic.
+    pub articles_skipped: u32,
+    pub articles_scanned: u32,
+    pub signals_extracted: u32,
+    pub beacon_candidates: u32,
+}
+
+/// Outcome of a news scan.
+#[derive(Debug, Clone, Default)]
+pub struct NewsScanReport {
+    pub articles_scanned: u32,
+    pub beacons_created: u32,
+    pub by_tier: Vec<TierYield>,
+}
+
+struct Article {
+    url: String,
+    tier: SourceTier,
+    priority: f64,
+}
+
+/// News scanner that fetches RSS feeds, extracts signals, and turns located
+/// problems into beacon tasks. Local, topic-relevant articles are scanned
+/// first so national noise gets only what budget is left.
 pub struct NewsScanner {
     archive: Arc<Archive>,
     extractor: Box<dyn SignalExtractor>,
     writer: GraphWriter,
     budget: BudgetTracker,
+    feeds: Vec<NewsFeed>,
+    targets: Vec<TopicTargets>,
 }
 
 impl NewsScanner {
@@ -59,81 +276,87 @@ impl NewsScanner {
         daily_budget_cents: u64,
     ) -> Self {
         // Use a generic "Global" scope for extraction — no region bias
-        let extractor = Box::new(Extractor::new(
-            anthropic_api_key,
-            "Global",
-            0.0,
-            0.0,
-        ));
+        let extractor = Box::new(Extractor::new(anthropic_api_key, "Global"));
 
         Self {
             archive,
             extractor,
             writer,
             budget: BudgetTracker::new(daily_budget_cents),
+            feeds: seed_feeds(),
+            targets: Vec::new(),
         }
     }
 
+    /// Target a region's topics, and search for its hottest situations.
+    pub fn with_topic_targets(mut self, targets: TopicTargets) -> Self {
+        self.feeds.extend(targets.query_feeds());
+        self.targets.push(targets);
+        self
+    }
+
     /// Scan all news feeds, extract signals, and create beacon tasks for hot areas.
-    /// Returns (articles_scanned, beacons_created).
-    pub async fn scan(&self) -> Result<(u32, u32)> {
-        info!(feeds = NEWS_FEEDS.len(), "Starting news scan");
-
-        // 1. Fetch all feeds
-        let mut all_urls: Vec<(String, Option<String>)> = Vec::new();
-        for feed_url in NEWS_FEEDS {
-            let feed_result = async {
-                let feed = self.archive.feed(feed_url).await.map_err(|e| anyhow::anyhow!("{e}"))?;
-                Ok::<_, anyhow::Error>(
-                    feed.items
-                        .into_iter()
-                        .map(|i| (i.url, i.title))
-                        .collect::<Vec<_>>(),
-                )
-            }
-            .await;
-            match feed_result {
-                Ok(items) => {
-                    for (url, title) in items {
-                        all_urls.push((url, title));
-                    }
-                }
+    pub async fn scan(&self) -> Result<NewsScanReport> {
+        info!(feeds = self.feeds.len(), regions = self.targets.len(), "Starting news scan");
+        let mut by_tier: HashMap<SourceTier, TierYield> = SourceTier::ALL
+            .into_iter()
+            .map(|t| (t, TierYield { tier: t.number(), ..Default::default() }))
+            .collect();
+
+        // 1. Fetch all feeds, ranking each article
+        let mut seen = HashSet::new();
+        let mut articles: Vec<Article> = Vec::new();
+        for feed in &self.feeds {
+            let items = match self.archive.feed(&feed.url).await {
+                Ok(feed) => feed.items,
                 Err(e) => {
-                    warn!(feed = feed_url, error = %e, "Failed to fetch feed");
+                    warn!(feed = feed.url.as_str(), error = %e, "Failed to fetch feed");
+                    continue;
+                }
+            };
+            for item in items {
+                if !seen.insert(item.url.clone()) {
+                    continue;
+                }
+                let tier = by_tier.get_mut(&feed.tier).expect("every tier is present");
+                tier.articles_found += 1;
+                match article_priority(feed, item.title.as_deref(), &self.targets) {
+                    Some(priority) => articles.push(Article {
+                        url: item.url,
+                        tier: feed.tier,
+                        priority,
+                    }),
+                    None => tier.articles_skipped += 1,
                 }
             }
         }
+        articles.sort_by(|a, b| b.priority.total_cmp(&a.priority));
 
-        info!(articles = all_urls.len(), "Collected articles from feeds");
+        info!(articles = articles.len(), "Collected articles from feeds");
 
         // 2. Dedup against existing sources in the graph
-        let mut seen = HashSet::new();
-        let mut new_urls: Vec<(String, Option<String>)> = Vec::new();
-        for (url, title) in all_urls {
-            if seen.insert(url.clone()) {
-                // Check if source already exists in graph
-                let exists = self
-                    .writer
-                    .source_exists(&url)
-                    .await
-                    .unwrap_or(false);
-                if !exists {
-                    new_urls.push((url, title));
-                }
+        let mut new_articles = Vec::new();
+        for article in articles {
+            let exists = self.writer.source_exists(&article.url).await.unwrap_or(false);
+            if !exists {
+                new_articles.push(article);
             }
         }
 
-        info!(new_articles = new_urls.len(), "New articles after dedup");
+        info!(new_articles = new_articles.len(), "New articles after dedup");
 
-        // 3. Process each new article — collect beacon candidates instead of storing signals
+        // 3. Process each new article, best first — collect beacon candidates instead of storing signals
         let mut beacon_candidates: Vec<BeaconCandidate> = Vec::new();
         let mut articles_scanned = 0u32;
+        let article_cost = OperationCost::CHROME_SCRAPE + OperationCost::CLAUDE_HAIKU_EXTRACTION;
 
-        for (url, _title) in &new_urls {
-            if self.budget.is_active() && !self.budget.has_budget(5) {
+        for article in &new_articles {
+            let url = &article.url;
+            if !self.budget.has_budget(article_cost) {
                 info!("Budget exhausted, stopping news scan");
                 break;
             }
+            self.budget.spend(article_cost);
 
             // Scrape
             let content = match async {
@@ -163,6 +386,9 @@ impl NewsScanner {
             };
 
             articles_scanned += 1;
+            let tier = by_tier.get_mut(&article.tier).expect("every tier is present");
+            tier.articles_scanned += 1;
+            tier.signals_extracted += extraction.nodes.len() as u32;
 
             // Collect beacon candidates from Tension/Need nodes with locations.
             // Only these signal types indicate problems worth investigating.
@@ -171,6 +397,7 @@ impl NewsScanner {
                 if matches!(node, Node::Tension(_) | Node::Need(_)) {
                     if let Some(meta) = node.meta() {
                         if let Some(loc) = &meta.about_location {
+                            tier.beacon_candidates += 1;
                             beacon_candidates.push(BeaconCandidate {
                                 lat: loc.lat,
                                 lng: loc.lng,
@@ -191,11 +418,116 @@ impl NewsScanner {
         )
         .await?;
 
+        let mut by_tier: Vec<TierYield> = by_tier.into_values().collect();
+        by_tier.sort_by_key(|t| t.tier);
+        for t in &by_tier {
+            info!(
+                tier = t.tier,
+                found = t.articles_found,
+                skipped = t.articles_skipped,
+                scanned = t.articles_scanned,
+                signals = t.signals_extracted,
+                beacon_candidates = t.beacon_candidates,
+                "News tier yield"
+            );
+        }
         info!(
             articles_scanned,
             beacons_created,
             "News scan complete"
         );
-        Ok((articles_scanned, beacons_created))
+        Ok(NewsScanReport {
+            articles_scanned,
+            beacons_created,
+            by_tier,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn situation(headline: &str, temperature: f64) -> SituationBrief {
+        SituationBrief {
+            headline: headline.to_string(),
+            arc: "developing".to_string(),
+            temperature,
+            clarity: "clear".to_string(),
+            signal_count: 5,
+            tension_count: 1,
+            dispatch_count: 2,
+            location_name: None,
+            sensitivity: "general".to_string(),
+        }
+    }
+
+    fn feed(locality: Locality, tier: SourceTier) -> NewsFeed {
+        NewsFeed {
+            url: "https://example.org/feed".to_string(),
+            locality,
+            tier,
+        }
+    }
+
+    #[test]
+    fn topic_relevance_follows_situation_temperature() {
+        let targets = TopicTargets::from_situations(
+            "twincities",
+            &[situation("Phillips eviction wave", 0.9), situation("Lake Street bus detour", 0.2)],
+        );
+
+        let hot = targets.relevance("Eviction filings surge in Phillips");
+        let cool = targets.relevance("Bus detour extended");
+        let none = targets.relevance("Senate passes budget");
+
+        assert_eq!(hot, 1.0);
+        assert!(cool > 0.0 && cool < hot, "{cool}");
+        assert_eq!(none, 0.0);
+    }
+
+    #[test]
+    fn untargeted_national_articles_are_skipped_and_local_ones_lead() {
+        let targets = vec![TopicTargets::from_situations("twincities", &[situation("Phillips eviction wave", 0.9)])];
+        let local = feed(Locality::Local("twincities".to_string()), SourceTier::One);
+        let national = feed(Locality::National, SourceTier::Three);
+
+        let local_untargeted = article_priority(&local, Some("School board meets"), &targets).unwrap();
+        let national_targeted = article_priority(&national, Some("Eviction crisis reaches Phillips"), &targets).unwrap();
+
+        assert!(article_priority(&national, Some("Senate passes budget"), &targets).is_none());
+        assert!(local_untargeted > national_targeted);
+    }
+
+    #[test]
+    fn local_articles_only_count_their_own_region_topics() {
+        let targets = vec![
+            TopicTargets::from_situations("twincities", &[situation("Phillips eviction wave", 0.9)]),
+            TopicTargets::from_situations("denver", &[situation("Colfax shelter closure", 0.9)]),
+        ];
+        let twin_cities = feed(Locality::Local("twincities".to_string()), SourceTier::One);
+
+        let priority = article_priority(&twin_cities, Some("Colfax shelter closure"), &targets).unwrap();
+
+        assert_eq!(priority, SourceTier::One.base_priority());
+    }
+
+    #[test]
+    fn hottest_situations_become_local_query_feeds() {
+        let targets = TopicTargets::from_situations(
+            "twincities",
+            &[
+                situation("Cool topic", 0.1),
+                situation("Phillips eviction wave", 0.9),
+                situation("Bus detour", 0.5),
+                situation("Park cleanup", 0.3),
+            ],
+        );
+
+        let feeds = targets.query_feeds();
+
+        assert_eq!(feeds.len(), TOPIC_QUERIES_PER_REGION);
+        assert!(feeds[0].url.contains("q=Phillips+eviction+wave"), "{}", feeds[0].url);
+        assert!(feeds.iter().all(|f| f.tier == SourceTier::Two && f.locality == Locality::Local("twincities".to_string())));
     }
 }
@@ -0,0 +1,260 @@
+//! Block-level diffs between two versions of a page, for diff-aware re-extraction.
+//!
+//! Pages are split into blocks (paragraphs, lists, tables, headings) at blank
+//! lines and compared after collapsing whitespace and case, so re-rendering
+//! noise isn't a change. Changed blocks go to the extractor with their section
+//! heading and the unchanged block before them as context; removed blocks are
+//! listed so closures and cancellations still read as changes.
+
+use std::collections::HashMap;
+
+/// Past this share of changed blocks the page is re-extracted whole.
+const MAX_CHANGED_SHARE: f64 = 0.5;
+/// Removed blocks shown to the extractor.
+const MAX_REMOVED_BLOCKS: usize = 5;
+/// Section headings named in a summary.
+const MAX_SUMMARY_SECTIONS: usize = 3;
+
+struct Block<'a> {
+    text: &'a str,
+    /// The heading of the section the block is in; a heading is its own section.
+    heading: Option<&'a str>,
+    normalized: String,
+}
+
+/// What changed between two versions of a page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageDiff {
+    /// Changed blocks with their context, in page order; gaps are marked `...`.
+    excerpts: Vec<String>,
+    removed_blocks: Vec<String>,
+    /// Headings of the sections with changes, in page order.
+    sections: Vec<String>,
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl PageDiff {
+    /// Diff `previous` against `current`. `None` when there's nothing to
+    /// compare against or so much changed that the whole page should be
+    /// re-extracted.
+    pub fn compute(previous: &str, current: &str) -> Option<Self> {
+        let before = blocks(previous);
+        let after = blocks(current);
+        if before.is_empty() || after.is_empty() {
+            return None;
+        }
+
+        let mut unmatched: HashMap<&str, usize> = HashMap::new();
+        for block in &before {
+            *unmatched.entry(block.normalized.as_str()).or_default() += 1;
+        }
+        let changed: Vec<bool> = after
+            .iter()
+            .map(|block| match unmatched.get_mut(block.normalized.as_str()) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .collect();
+
+        let added = changed.iter().filter(|c| **c).count();
+        let removed_blocks: Vec<&Block> = {
+            let mut remaining = unmatched;
+            before
+                .iter()
+                .filter(|block| match remaining.get_mut(block.normalized.as_str()) {
+                    Some(n) if *n > 0 => {
+                        *n -= 1;
+                        true
+                    }
+                    _ => false,
+                })
+                .collect()
+        };
+        let removed = removed_blocks.len();
+
+        let share = (added + removed) as f64 / (before.len() + after.len()) as f64;
+        if share > MAX_CHANGED_SHARE {
+            return None;
+        }
+
+        let mut excerpts = Vec::new();
+        let mut sections: Vec<String> = Vec::new();
+        let mut shown = vec![false; after.len()];
+        for (i, block) in after.iter().enumerate() {
+            if !changed[i] {
+                continue;
+            }
+            let mut context = Vec::new();
+            if i > 0 && !shown[i - 1] {
+                if !excerpts.is_empty() {
+                    excerpts.push("...".to_string());
+                }
+                // Name the section unless the context block already is its heading.
+                if let Some(heading) = block.heading.filter(|h| *h != after[i - 1].text && *h != block.text) {
+                    context.push(heading.to_string());
+                }
+                context.push(after[i - 1].text.to_string());
+                shown[i - 1] = true;
+            }
+            excerpts.extend(context);
+            excerpts.push(block.text.to_string());
+            shown[i] = true;
+            note_section(&mut sections, block.heading);
+        }
+        for block in &removed_blocks {
+            note_section(&mut sections, block.heading);
+        }
+
+        Some(Self {
+            excerpts,
+            removed_blocks: removed_blocks.iter().take(MAX_REMOVED_BLOCKS).map(|b| b.text.to_string()).collect(),
+            sections,
+            added,
+            removed,
+        })
+    }
+
+    /// Whether the page is the same once rendering noise is ignored.
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.removed == 0
+    }
+
+    /// One line for evidence, e.g. `Updated "Hours", "Donations": 2 blocks added, 1 removed`.
+    pub fn summary(&self) -> String {
+        let counts = format!(
+            "{} block{} added, {} removed",
+            self.added,
+            if self.added == 1 { "" } else { "s" },
+            self.removed
+        );
+        if self.sections.is_empty() {
+            return format!("Updated: {counts}");
+        }
+        let mut names: Vec<String> =
+            self.sections.iter().take(MAX_SUMMARY_SECTIONS).map(|s| format!("\"{s}\"")).collect();
+        if self.sections.len() > MAX_SUMMARY_SECTIONS {
+            names.push(format!("{} more", self.sections.len() - MAX_SUMMARY_SECTIONS));
+        }
+        format!("Updated {}: {counts}", names.join(", "))
+    }
+
+    /// The diff as kept on evidence: the summary, then what changed and
+    /// what was removed.
+    pub fn report(&self) -> String {
+        let mut report = self.summary();
+        if !self.excerpts.is_empty() {
+            report.push_str("\n\nCHANGED:\n\n");
+            report.push_str(&self.excerpts.join("\n\n"));
+        }
+        if !self.removed_blocks.is_empty() {
+            report.push_str("\n\nREMOVED:\n\n");
+            report.push_str(&self.removed_blocks.join("\n\n"));
+        }
+        report
+    }
+
+    /// The text the extractor sees instead of the whole page.
+    pub fn extraction_input(&self) -> String {
+        let mut input = String::from(
+            "PAGE UPDATE: signals on this page were already extracted from an earlier version. \
+            Only what changed since then is shown, with the preceding block for context. \
+            Extract signals from the changes; a removed block may mean something ended, \
+            closed or was cancelled.\n\nCHANGED:\n\n",
+        );
+        if self.excerpts.is_empty() {
+            input.push_str("(nothing added)");
+        } else {
+            input.push_str(&self.excerpts.join("\n\n"));
+        }
+        if !self.removed_blocks.is_empty() {
+            input.push_str("\n\nREMOVED:\n\n");
+            input.push_str(&self.removed_blocks.join("\n\n"));
+        }
+        input
+    }
+}
+
+fn note_section(sections: &mut Vec<String>, heading: Option<&str>) {
+    if let Some(heading) = heading.map(heading_title) {
+        if !sections.contains(&heading) {
+            sections.push(heading);
+        }
+    }
+}
+
+fn heading_title(heading: &str) -> String {
+    heading.trim_start_matches('#').trim().to_string()
+}
+
+fn is_heading(text: &str) -> bool {
+    text.starts_with('#') && !text.contains('\n')
+}
+
+/// Split markdown into blank-line separated blocks, tagging each with its
+/// section heading.
+fn blocks(markdown: &str) -> Vec<Block<'_>> {
+    let mut heading = None;
+    markdown
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(|text| {
+            if is_heading(text) {
+                heading = Some(text);
+            }
+            Block {
+                text,
+                heading,
+                normalized: text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BEFORE: &str = "# Northside Food Shelf\n\nWe serve families across the north side.\n\n\
+        ## Hours\n\nOpen Tuesday and Thursday, 10am to 2pm.\n\n\
+        ## Donations\n\nWe accept canned goods and diapers.\n\n\
+        ## Volunteer\n\nShifts are posted monthly.";
+
+    #[test]
+    fn changed_block_comes_with_its_section_and_context() {
+        let after = BEFORE.replace("Thursday, 10am to 2pm", "Thursday and Saturday, 10am to 4pm");
+        let diff = PageDiff::compute(BEFORE, &after).unwrap();
+        assert_eq!((diff.added, diff.removed), (1, 1));
+        assert_eq!(diff.summary(), "Updated \"Hours\": 1 block added, 1 removed");
+
+        let input = diff.extraction_input();
+        assert!(input.contains("## Hours\n\nOpen Tuesday and Thursday and Saturday, 10am to 4pm."));
+        assert!(input.contains("REMOVED:\n\nOpen Tuesday and Thursday, 10am to 2pm."));
+        assert!(!input.contains("canned goods"), "unchanged sections are left out");
+    }
+
+    #[test]
+    fn rendering_noise_is_not_a_change() {
+        let after = BEFORE.replace("We serve families", "We  serve\nFamilies");
+        let diff = PageDiff::compute(BEFORE, &after).unwrap();
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn added_section_is_named() {
+        let after = format!("{BEFORE}\n\n## Winter Coat Drive\n\nDrop off coats through February.");
+        let diff = PageDiff::compute(BEFORE, &after).unwrap();
+        assert_eq!(diff.summary(), "Updated \"Winter Coat Drive\": 2 blocks added, 0 removed");
+        assert!(diff.extraction_input().contains("Shifts are posted monthly.\n\n## Winter Coat Drive"));
+    }
+
+    #[test]
+    fn rewritten_page_is_extracted_whole() {
+        assert!(PageDiff::compute(BEFORE, "# Page moved\n\nSee our new site.").is_none());
+        assert!(PageDiff::compute("", BEFORE).is_none());
+    }
+}
@@ -11,22 +11,33 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use futures::stream::{self, StreamExt};
 use tracing::{info, warn};
 use uuid::Uuid;
 
 use rootsignal_common::{
-    canonical_value, channel_type, is_web_query, scraping_strategy, ActorNode, ActorType, ActorContext, ScoutScope,
-    DiscoveryMethod, EvidenceNode, Node, NodeType, Post, ScrapingStrategy,
-    SocialPlatform, SourceNode, SourceRole,
+    canonical_value, channel_type, is_web_query, scraping_strategy, ActorNode, ActorType, ActorContext, BackfillDepth, ScoutScope,
+    ArchivedPage, Classify, Decision, DecisionKind, DiscoveryMethod, EntityLink, ErrorClass, EvidenceNode, GatheringNode, GeoPoint, GeoPrecision, ListedEvent,
+    LongVideo, Node, NodeMeta, NodeType, OpenDataDataset, PodcastEpisode, RegionAgendas, RegionRegistry, RegionServiceAlerts,
+    RegistryEntity, Post, ScrapingStrategy, SensitivityLevel, ServiceAlert, SocialPlatform, SourceNode, SourceRole, TranscriptSegment,
 };
+use rootsignal_common::negative_knowledge::NegativeIndex;
+
+use crate::enrichment::audit::{AuditCandidate, AuditSample};
 use crate::enrichment::link_promoter;
 use crate::infra::embedder::TextEmbedder;
-use crate::pipeline::extractor::{ResourceTag, SignalExtractor};
+use crate::pipeline::comment_threads;
+use crate::pipeline::flyers::FlyerReader;
+use crate::pipeline::engagement;
+use crate::pipeline::extractor::{extract_isolated, ResourceTag, SignalExtractor};
+use crate::pipeline::page_diff::PageDiff;
 use crate::enrichment::quality;
 use crate::infra::run_log::{EventKind, RunLog};
-use crate::pipeline::stats::ScoutStats;
+use crate::pipeline::stats::{ExtractionFailure, ScoutStats};
+use crate::pipeline::work_queue::{Fetched, Lane, LaneMetrics, WorkPriority, WorkQueue, WorkQueueConfig};
 use crate::infra::util::{content_hash, sanitize_url};
+use crate::agendas::{item_to_notice, AgendaConnector};
+use crate::open_data::{record_to_node, OpenDataConnector};
+use crate::service_alerts::{alert_to_node, AlertOrigin, ServiceAlertConnector};
 
 // ---------------------------------------------------------------------------
 // CollectedLink — a discovered outbound link with its provenance
@@ -60,8 +71,19 @@ pub(crate) struct RunContext {
     pub actor_contexts: HashMap<String, ActorContext>,
     /// RSS/Atom pub_date keyed by article URL, used as fallback content_date.
     pub url_to_pub_date: HashMap<String, DateTime<Utc>>,
+    /// What changed on a re-scraped page, keyed by URL, recorded on its evidence.
+    pub url_to_change_summary: HashMap<String, String>,
+    /// Engagement velocity of the post each social signal came from, keyed by
+    /// extraction-time node id: boosts its confidence and is recorded on its evidence.
+    pub signal_engagement_velocity: HashMap<Uuid, f64>,
     /// Links collected during scraping, carrying the discovering source's coordinates.
     pub collected_links: Vec<CollectedLink>,
+    /// Canonical keys of explicitly requested (pinned) sources — scraped ahead of the queue.
+    pub breaking_keys: HashSet<String>,
+    /// Extractions suppressed by each negative knowledge entry this run.
+    pub negative_hits: HashMap<Uuid, u32>,
+    /// New signals sampled for the end-of-run judge audit.
+    pub audit_sample: AuditSample,
 }
 
 impl RunContext {
@@ -84,7 +106,12 @@ impl RunContext {
             query_api_errors: HashSet::new(),
             actor_contexts: HashMap::new(),
             url_to_pub_date: HashMap::new(),
+            url_to_change_summary: HashMap::new(),
+            signal_engagement_velocity: HashMap::new(),
             collected_links: Vec::new(),
+            breaking_keys: HashSet::new(),
+            negative_hits: HashMap::new(),
+            audit_sample: AuditSample::default(),
         }
     }
 
@@ -170,9 +197,28 @@ enum ScrapeOutcome {
         nodes: Vec<Node>,
         resource_tags: Vec<(Uuid, Vec<ResourceTag>)>,
         signal_tags: Vec<(Uuid, Vec<String>)>,
+        /// Set when only the changes since the last fetch were extracted.
+        change_summary: Option<String>,
     },
     Unchanged,
-    Failed,
+    Failed(ErrorClass),
+    ExtractionFailed(ExtractionFailure),
+}
+
+/// Backoff before the single retry of a transient page fetch failure.
+const TRANSIENT_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Fetch a page, retrying once if the failure is classified as transient.
+/// Permanent, user, and budget failures are returned immediately.
+async fn fetch_page_with_retry(fetcher: &dyn super::traits::ContentFetcher, url: &str) -> Result<ArchivedPage> {
+    match fetcher.page(url).await {
+        Err(e) if e.is_retryable() => {
+            warn!(url, error = %e, "Transient scrape failure, retrying once");
+            tokio::time::sleep(TRANSIENT_RETRY_DELAY).await;
+            fetcher.page(url).await
+        }
+        result => result,
+    }
 }
 
 /// Normalize a title for dedup comparison: lowercase and trim.
@@ -192,10 +238,194 @@ pub(crate) fn batch_title_dedup(nodes: Vec<Node>) -> Vec<Node> {
 
 /// Returns true if this scraping strategy represents an "owned" source — one
 /// where the author of the content is the account holder, not an aggregator.
-/// Social accounts and dedicated web pages are owned; RSS feeds and web
-/// queries aggregate content from many authors.
+/// Social accounts, video channels, podcasts, newsletters, event organizer
+/// listings and dedicated web pages are owned; RSS feeds and web queries
+/// aggregate content from many authors.
 pub(crate) fn is_owned_source(strategy: &ScrapingStrategy) -> bool {
-    matches!(strategy, ScrapingStrategy::Social(_))
+    matches!(
+        strategy,
+        ScrapingStrategy::Social(_)
+            | ScrapingStrategy::Video
+            | ScrapingStrategy::Podcast
+            | ScrapingStrategy::Newsletter
+            | ScrapingStrategy::Events
+    )
+}
+
+/// Most recent posts read per social account per run.
+const SOCIAL_POST_LIMIT: u32 = 20;
+
+/// Most recent newsletter issues read per source per run.
+const NEWSLETTER_ISSUE_LIMIT: u32 = 10;
+
+/// Most recent videos read per channel per run.
+const VIDEO_LIMIT: u32 = 5;
+
+/// Most recent episodes read per podcast per run.
+const PODCAST_EPISODE_LIMIT: u32 = 3;
+
+/// Upcoming events read per event listing per run.
+const EVENT_LISTING_LIMIT: u32 = 25;
+
+/// Confidence floor for events read from an event platform's structured
+/// fields. Online events have no location, which quality scoring penalizes,
+/// but their times and links are as reliable as any in-person event's.
+const LISTED_EVENT_CONFIDENCE: f32 = 0.9;
+
+/// Longest summary taken from an event description.
+const EVENT_SUMMARY_CHARS: usize = 280;
+
+/// How near an existing signal must be to an open-data record to be the
+/// same issue. 311 records are geocoded to the block; an extracted signal
+/// about the same building is rarely farther off.
+const OPEN_DATA_DEDUP_RADIUS_KM: f64 = 0.5;
+
+/// Similarity at which an open-data record corroborates a nearby existing
+/// signal rather than creating its own. Lower than cross-source dedup
+/// because the record's terse category text never reads like an article,
+/// and the radius already confines matches to the same block.
+const OPEN_DATA_DEDUP_THRESHOLD: f64 = 0.80;
+
+/// How far back a service alert looks for signals mentioning the route or
+/// school it affects, and how many it links.
+const ALERT_LINK_LOOKBACK_DAYS: i64 = 30;
+const ALERT_LINK_LIMIT: u32 = 50;
+
+/// Transcript characters per extraction call. A two-hour council meeting
+/// becomes a handful of chunks, each linking to the minute it starts at.
+const TRANSCRIPT_CHUNK_CHARS: usize = 12_000;
+
+/// A transcribed video or podcast episode, ready for extraction.
+struct Recording<'a> {
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    transcript: &'a [TranscriptSegment],
+    /// Fallback content date for undated signals.
+    published_at: DateTime<Utc>,
+}
+
+/// A window of a video transcript, sized for one extraction call.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TranscriptChunk {
+    pub start_secs: f64,
+    /// Transcript lines, each prefixed with its `[h:mm:ss]` timestamp.
+    pub text: String,
+}
+
+/// Split a transcript into chunks of at most `max_chars` (a single overlong
+/// line still gets a chunk of its own). Lines keep their timestamps so the
+/// extractor can see when things were said.
+pub(crate) fn chunk_transcript(segments: &[TranscriptSegment], max_chars: usize) -> Vec<TranscriptChunk> {
+    let mut chunks = Vec::new();
+    let mut current: Option<TranscriptChunk> = None;
+
+    for segment in segments {
+        let line = format!("[{}] {}\n", format_timestamp(segment.start_secs), segment.text.trim());
+        match current.as_mut() {
+            Some(chunk) if chunk.text.len() + line.len() <= max_chars => chunk.text.push_str(&line),
+            _ => {
+                chunks.extend(current.take());
+                current = Some(TranscriptChunk {
+                    start_secs: segment.start_secs,
+                    text: line,
+                });
+            }
+        }
+    }
+    chunks.extend(current);
+    chunks
+}
+
+/// `h:mm:ss` (or `m:ss` under an hour), as shown on the video player.
+fn format_timestamp(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    let (h, m, s) = (total / 3600, (total % 3600) / 60, total % 60);
+    if h > 0 {
+        format!("{h}:{m:02}:{s:02}")
+    } else {
+        format!("{m}:{s:02}")
+    }
+}
+
+/// Deep link into a video at the minute containing `secs`, via the `t` query
+/// parameter (`watch?v=abc&t=720s`).
+pub(crate) fn timestamped_url(permalink: &str, secs: f64) -> String {
+    let minute_start = (secs.max(0.0) as u64 / 60) * 60;
+    let Ok(mut url) = url::Url::parse(permalink) else {
+        return permalink.to_string();
+    };
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != "t")
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("t", &format!("{minute_start}s"));
+    url.to_string()
+}
+
+/// Map a structured event listing straight to a Gathering. Signals cite the
+/// listing they were found on; `action_url` is where to sign up. The
+/// organizer becomes the author actor.
+pub(crate) fn listed_event_to_gathering(event: &ListedEvent, is_recurring: bool) -> Node {
+    let now = Utc::now();
+    let summary = event
+        .description
+        .as_deref()
+        .and_then(|d| d.lines().map(str::trim).find(|l| !l.is_empty()))
+        .map(|first| match first.char_indices().nth(EVENT_SUMMARY_CHARS) {
+            Some((end, _)) => format!("{}…", first[..end].trim_end()),
+            None => first.to_string(),
+        })
+        .unwrap_or_else(|| event.title.clone());
+    let about_location = match (event.latitude, event.longitude) {
+        (Some(lat), Some(lng)) => Some(GeoPoint {
+            lat,
+            lng,
+            precision: GeoPrecision::Exact,
+        }),
+        _ => None,
+    };
+    let about_location_name = match (&event.venue_name, &event.venue_address) {
+        (Some(name), Some(address)) => Some(format!("{name}, {address}")),
+        (name, address) => name.clone().or_else(|| address.clone()),
+    };
+
+    Node::Gathering(GatheringNode {
+        meta: NodeMeta {
+            id: Uuid::new_v4(),
+            title: event.title.clone(),
+            summary,
+            sensitivity: SensitivityLevel::General,
+            confidence: LISTED_EVENT_CONFIDENCE,
+            freshness_score: 1.0,
+            corroboration_count: 0,
+            about_location,
+            about_location_name,
+            from_location: None,
+            source_url: event.permalink.clone(),
+            extracted_at: now,
+            content_date: None,
+            last_confirmed_active: now,
+            source_diversity: 1,
+            external_ratio: 0.0,
+            cause_heat: 0.0,
+            implied_queries: Vec::new(),
+            channel_diversity: 1,
+            mentioned_actors: Vec::new(),
+            author_actor: event.organizer_name.clone(),
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
+        },
+        starts_at: event.starts_at,
+        ends_at: event.ends_at,
+        action_url: event.ticket_url.clone().unwrap_or_else(|| event.permalink.clone()),
+        organizer: event.organizer_name.clone(),
+        is_recurring,
+    })
 }
 
 /// Scores quality, populates from/about locations, and removes Evidence nodes.
@@ -207,11 +437,16 @@ pub(crate) fn score_and_filter(
     url: &str,
     actor_ctx: Option<&ActorContext>,
 ) -> Vec<Node> {
-    // 1. Score quality and stamp source URL
+    // 1. Score quality and stamp source URL. Event platform listings are
+    // structured data, not LLM output, so they keep a confidence floor.
+    let confidence_floor = match scraping_strategy(url) {
+        ScrapingStrategy::Events => LISTED_EVENT_CONFIDENCE,
+        _ => 0.0,
+    };
     for node in &mut nodes {
         let q = quality::score(node);
         if let Some(meta) = node.meta_mut() {
-            meta.confidence = q.confidence;
+            meta.confidence = q.confidence.max(confidence_floor);
             meta.source_url = url.to_string();
         }
     }
@@ -335,6 +570,23 @@ pub(crate) fn dedup_verdict(
     DedupVerdict::Create
 }
 
+/// Fold one drained batch into the run's queue metrics and log it.
+fn record_queue_metrics(ctx: &mut RunContext, run_log: &mut RunLog, lane: Lane, drained: &LaneMetrics) {
+    if drained.items == 0 {
+        return;
+    }
+    ctx.stats.queue_metrics.record(lane, drained);
+    run_log.log(EventKind::WorkQueueDrained {
+        lane: lane.as_str().to_string(),
+        items: drained.items,
+        extracted: drained.extracted,
+        peak_pending_extractions: drained.peak_pending_extractions as u32,
+        backpressure_waits: drained.backpressure_waits,
+        avg_queue_wait_ms: drained.avg_queue_wait_ms(),
+        avg_extract_ms: drained.avg_extract_ms(),
+    });
+}
+
 // ---------------------------------------------------------------------------
 // ScrapePhase — the core scrape-extract-store-dedup pipeline
 // ---------------------------------------------------------------------------
@@ -346,6 +598,18 @@ pub(crate) struct ScrapePhase {
     fetcher: Arc<dyn super::traits::ContentFetcher>,
     region: ScoutScope,
     run_id: String,
+    queue: WorkQueue,
+    open_data: Option<Arc<dyn OpenDataConnector>>,
+    agendas: Option<Arc<dyn AgendaConnector>>,
+    service_alerts: Option<Arc<dyn ServiceAlertConnector>>,
+    registry: RegionRegistry,
+    negative: NegativeIndex,
+    flyers: Option<Arc<FlyerReader>>,
+    /// Where duplicates of this region's signals are looked for: its own
+    /// bounding box, widened by `with_overlapping_regions`.
+    dedup_bounds: (f64, f64, f64, f64),
+    /// Set on a backfill's phase: read this deep into each source's history.
+    backfill: Option<BackfillDepth>,
 }
 
 impl ScrapePhase {
@@ -358,15 +622,135 @@ impl ScrapePhase {
         run_id: String,
     ) -> Self {
         Self {
+            dedup_bounds: region.bounding_box(),
             store,
             extractor,
             embedder,
             fetcher,
             region,
             run_id,
+            queue: WorkQueue::new(WorkQueueConfig::default()),
+            open_data: None,
+            agendas: None,
+            service_alerts: None,
+            registry: RegionRegistry::default(),
+            negative: NegativeIndex::default(),
+            flyers: None,
+            backfill: None,
+        }
+    }
+
+    /// A phase for backfilling sources of this phase's region: same stores,
+    /// registry and dedup, but reading up to `depth` of each source's history
+    /// and storing signals under `run_id`. Connectors and flyer reads are
+    /// left out.
+    pub fn for_backfill(&self, run_id: String, depth: BackfillDepth) -> Self {
+        Self {
+            store: self.store.clone(),
+            extractor: self.extractor.clone(),
+            embedder: self.embedder.clone(),
+            fetcher: self.fetcher.clone(),
+            region: self.region.clone(),
+            run_id,
+            queue: WorkQueue::new(self.queue.config().clone()),
+            open_data: None,
+            agendas: None,
+            service_alerts: None,
+            registry: self.registry.clone(),
+            negative: self.negative.clone(),
+            flyers: None,
+            dedup_bounds: self.dedup_bounds,
+            backfill: Some(depth),
         }
     }
 
+    /// How many items to read from a source that regularly yields `regular`.
+    fn item_limit(&self, regular: u32) -> u32 {
+        self.backfill.map_or(regular, |depth| depth.max_items)
+    }
+
+    /// Whether an item is worth reading: new since the source was last
+    /// scraped, or within the backfill's reach on a backfill.
+    fn is_fresh(&self, source: &SourceNode, fetched_at: DateTime<Utc>, published_at: Option<DateTime<Utc>>) -> bool {
+        match self.backfill {
+            Some(depth) => depth.keeps(published_at, Utc::now()),
+            None => source.last_scraped.is_none_or(|t| fetched_at > t),
+        }
+    }
+
+    /// Override the fetch/extract concurrency and backpressure limits.
+    pub fn with_queue_config(mut self, config: WorkQueueConfig) -> Self {
+        self.queue = WorkQueue::new(config);
+        self
+    }
+
+    /// Connector for the region's open-data datasets. Without one,
+    /// `run_open_data` reads nothing.
+    pub fn with_open_data(mut self, connector: Arc<dyn OpenDataConnector>) -> Self {
+        self.open_data = Some(connector);
+        self
+    }
+
+    /// Connector for the region's meeting agendas. Without one,
+    /// `run_agendas` reads nothing.
+    pub fn with_agendas(mut self, connector: Arc<dyn AgendaConnector>) -> Self {
+        self.agendas = Some(connector);
+        self
+    }
+
+    /// Connector for the region's transit and school alert feeds. Without
+    /// one, `run_service_alerts` reads nothing.
+    pub fn with_service_alerts(mut self, connector: Arc<dyn ServiceAlertConnector>) -> Self {
+        self.service_alerts = Some(connector);
+        self
+    }
+
+    /// The region's schools, routes, wards and parks. Stored signals are
+    /// linked to the ones they mention or lie within.
+    pub fn with_registry(mut self, registry: RegionRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Debunked, spam and known-duplicate content to suppress instead of
+    /// storing. Without it, nothing is suppressed.
+    pub fn with_negative_knowledge(mut self, index: NegativeIndex) -> Self {
+        self.negative = index;
+        self
+    }
+
+    /// Other regions' scopes. Duplicates are also looked for in the ones that
+    /// overlap this region, so a signal both regions' scouts find is stored
+    /// once and shared rather than stored twice.
+    pub fn with_overlapping_regions(mut self, others: &[ScoutScope]) -> Self {
+        self.dedup_bounds = rootsignal_common::region_membership::overlap_bounds(&self.region, others);
+        self
+    }
+
+    /// Reader for flyer images attached to social posts. Without one, only
+    /// captions (and image text the archive already has) are extracted.
+    pub fn with_flyer_reader(mut self, reader: Arc<FlyerReader>) -> Self {
+        self.flyers = Some(reader);
+        self
+    }
+
+    /// Cost of the flyer reads made since the last call, for the run budget.
+    pub fn take_flyer_cost(&self) -> u64 {
+        self.flyers.as_ref().map_or(0, |f| f.take_uncharged_cost())
+    }
+
+    /// Dispatch priority for each source, keyed by canonical_key.
+    fn url_priorities(&self, sources: &[&SourceNode], ctx: &RunContext) -> HashMap<String, WorkPriority> {
+        let now = Utc::now();
+        sources
+            .iter()
+            .map(|s| {
+                let breaking = ctx.breaking_keys.contains(&s.canonical_key);
+                (s.canonical_key.clone(), WorkPriority::for_source(s, breaking, now))
+            })
+            .collect()
+    }
+
     /// Scrape a set of web sources: resolve queries → URLs, scrape pages, extract signals, store results.
     /// Used by both Phase A (tension/mixed sources) and Phase B (response/discovery sources).
     ///
@@ -404,27 +788,40 @@ impl ScrapePhase {
                 "Resolving web search queries..."
             );
             let fetcher = self.fetcher.clone();
+            let now = Utc::now();
             let query_inputs: Vec<_> = api_queries
                 .iter()
-                .map(|source| (source.canonical_key.clone(), source.canonical_value.clone()))
+                .map(|source| {
+                    let breaking = ctx.breaking_keys.contains(&source.canonical_key);
+                    (
+                        WorkPriority::for_source(source, breaking, now),
+                        (source.canonical_key.clone(), source.canonical_value.clone()),
+                    )
+                })
                 .collect();
-            let search_results: Vec<_> = stream::iter(query_inputs.into_iter().map(|(canonical_key, query_str)| {
-                let fetcher = fetcher.clone();
-                async move {
-                    let result = fetcher.search(&query_str).await;
-                    (canonical_key, query_str, result)
-                }
-            }))
-            .buffer_unordered(5)
-            .collect()
-            .await;
+            let (search_results, lane_metrics) = self
+                .queue
+                .run(
+                    Lane::Search,
+                    query_inputs,
+                    |(canonical_key, query_str)| {
+                        let fetcher = fetcher.clone();
+                        async move {
+                            let result = fetcher.search(&query_str).await;
+                            Fetched::<(), _>::Done((canonical_key, query_str, result))
+                        }
+                    },
+                    |()| async { unreachable!("search lane is fetch-only") },
+                )
+                .await;
+            record_queue_metrics(ctx, run_log, Lane::Search, &lane_metrics);
 
             for (canonical_key, query_str, result) in search_results {
                 match result {
                     Ok(archived) => {
                         run_log.log(EventKind::SearchQuery {
                             query: query_str.clone(),
-                            provider: "serper".to_string(),
+                            provider: archived.provider.clone().unwrap_or_else(|| "unknown".to_string()),
                             result_count: archived.results.len() as u32,
                             canonical_key: canonical_key.clone(),
                         });
@@ -497,7 +894,12 @@ impl ScrapePhase {
                 if let Some(ref feed_url) = source.url {
                     let feed_result = self.fetcher.feed(feed_url).await;
                     match feed_result {
-                        Ok(archived) => {
+                        Ok(mut archived) => {
+                            if let Some(depth) = self.backfill {
+                                let now = Utc::now();
+                                archived.items.retain(|item| depth.keeps(item.pub_date, now));
+                                archived.items.truncate(depth.max_items as usize);
+                            }
                             run_log.log(EventKind::ScrapeFeed {
                                 url: feed_url.clone(),
                                 items: archived.items.len() as u32,
@@ -548,73 +950,126 @@ impl ScrapePhase {
             return;
         }
 
-        // Scrape + extract in parallel
+        // Scrape + extract through the bounded work queue
+        let url_priorities = self.url_priorities(sources, ctx);
+        let source_roles: HashMap<&str, SourceRole> =
+            sources.iter().map(|s| (s.canonical_key.as_str(), s.source_role)).collect();
+        let url_roles: Arc<HashMap<String, SourceRole>> = Arc::new(
+            phase_urls
+                .iter()
+                .filter_map(|url| {
+                    let clean_url = sanitize_url(url);
+                    let role = *source_roles.get(ctx.url_to_canonical_key.get(&clean_url)?.as_str())?;
+                    Some((clean_url, role))
+                })
+                .collect(),
+        );
+        let items: Vec<_> = phase_urls
+            .into_iter()
+            .map(|url| {
+                let priority = ctx
+                    .url_to_canonical_key
+                    .get(&sanitize_url(&url))
+                    .and_then(|ck| url_priorities.get(ck))
+                    .copied()
+                    .unwrap_or_else(WorkPriority::unknown);
+                (priority, url)
+            })
+            .collect();
         let fetcher = self.fetcher.clone();
         let store = self.store.clone();
         let extractor = self.extractor.clone();
-        let pipeline_results: Vec<_> = stream::iter(phase_urls.into_iter().map(|url| {
-            let fetcher = fetcher.clone();
-            let store = store.clone();
-            let extractor = extractor.clone();
-            async move {
-                let clean_url = sanitize_url(&url);
+        let extract_timeout = self.queue.config().extract_timeout;
+        let (pipeline_results, lane_metrics) = self
+            .queue
+            .run(
+                Lane::Web,
+                items,
+                |url| {
+                    let fetcher = fetcher.clone();
+                    let store = store.clone();
+                    async move {
+                        let clean_url = sanitize_url(&url);
+
+                        let (content, page_links, previous) = match fetch_page_with_retry(fetcher.as_ref(), &url).await {
+                            Ok(p) if !p.markdown.is_empty() => (p.markdown, p.links, p.previous_markdown),
+                            // Rendered fine but nothing to extract — refetching won't change that.
+                            Ok(p) => return Fetched::Done((clean_url, ScrapeOutcome::Failed(ErrorClass::User), p.links)),
+                            Err(e) => {
+                                let class = e.class();
+                                warn!(url, error = %e, error_class = %class, "Scrape failed");
+                                return Fetched::Done((clean_url, ScrapeOutcome::Failed(class), Vec::new()));
+                            }
+                        };
 
-                let (content, page_links) = match fetcher.page(&url).await {
-                    Ok(p) if !p.markdown.is_empty() => (p.markdown, p.links),
-                    Ok(p) => return (clean_url, ScrapeOutcome::Failed, p.links),
-                    Err(e) => {
-                        warn!(url, error = %e, "Scrape failed");
-                        return (clean_url, ScrapeOutcome::Failed, Vec::new());
-                    }
-                };
+                        let hash = format!("{:x}", content_hash(&content));
+                        match store.content_already_processed(&hash, &clean_url).await {
+                            Ok(true) => {
+                                info!(url = clean_url.as_str(), "Content unchanged, skipping extraction");
+                                return Fetched::Done((clean_url, ScrapeOutcome::Unchanged, page_links));
+                            }
+                            Ok(false) => {}
+                            Err(e) => {
+                                warn!(url = clean_url.as_str(), error = %e, "Hash check failed, proceeding with extraction");
+                            }
+                        }
 
-                let hash = format!("{:x}", content_hash(&content));
-                match store.content_already_processed(&hash, &clean_url).await {
-                    Ok(true) => {
-                        info!(url = clean_url.as_str(), "Content unchanged, skipping extraction");
-                        return (clean_url, ScrapeOutcome::Unchanged, page_links);
-                    }
-                    Ok(false) => {}
-                    Err(e) => {
-                        warn!(url = clean_url.as_str(), error = %e, "Hash check failed, proceeding with extraction");
+                        // Re-scraped page: extract only what changed since the last fetch.
+                        let diff = previous.as_deref().and_then(|previous| PageDiff::compute(previous, &content));
+                        if diff.as_ref().is_some_and(PageDiff::is_empty) {
+                            info!(url = clean_url.as_str(), "Only formatting changed, skipping extraction");
+                            return Fetched::Done((clean_url, ScrapeOutcome::Unchanged, page_links));
+                        }
+                        Fetched::Extract((clean_url, content, page_links, diff))
                     }
-                }
-
-                // Prepend first-hand filter for web search/feed sources
-                let filtered_content = format!(
-                    "FIRST-HAND FILTER (applies to this content):\n\
-                    This content comes from web search results, which may contain \
-                    political commentary from people not directly involved. Apply strict filtering:\n\n\
-                    For each potential signal, assess: Is this person describing something happening \
-                    to them, their family, their community, or their neighborhood? Or are they \
-                    asking for help? If yes, mark is_firsthand: true. If this is political commentary \
-                    from someone not personally affected — regardless of viewpoint — mark \
-                    is_firsthand: false.\n\n\
-                    Only extract signals where is_firsthand is true. Reject the rest.\n\n\
-                    {content}"
-                );
+                },
+                |(clean_url, content, page_links, diff): (String, String, Vec<String>, Option<PageDiff>)| {
+                    let extractor = extractor.clone();
+                    let role = url_roles.get(&clean_url).copied().unwrap_or_default();
+                    async move {
+                        let extraction_input = match &diff {
+                            Some(diff) => {
+                                info!(url = clean_url.as_str(), change = %diff.summary(), "Extracting page changes only");
+                                diff.extraction_input()
+                            }
+                            None => content.clone(),
+                        };
+                        // Prepend first-hand filter for web search/feed sources
+                        let filtered_content = format!(
+                            "FIRST-HAND FILTER (applies to this content):\n\
+                            This content comes from web search results, which may contain \
+                            political commentary from people not directly involved. Apply strict filtering:\n\n\
+                            For each potential signal, assess: Is this person describing something happening \
+                            to them, their family, their community, or their neighborhood? Or are they \
+                            asking for help? If yes, mark is_firsthand: true. If this is political commentary \
+                            from someone not personally affected — regardless of viewpoint — mark \
+                            is_firsthand: false.\n\n\
+                            Only extract signals where is_firsthand is true. Reject the rest.\n\n\
+                            {extraction_input}"
+                        );
 
-                match extractor.extract(&filtered_content, &clean_url).await {
-                    Ok(result) => (
-                        clean_url,
-                        ScrapeOutcome::New {
-                            content,
-                            nodes: result.nodes,
-                            resource_tags: result.resource_tags,
-                            signal_tags: result.signal_tags,
-                        },
-                        page_links,
-                    ),
-                    Err(e) => {
-                        warn!(url = clean_url.as_str(), error = %e, "Extraction failed");
-                        (clean_url, ScrapeOutcome::Failed, page_links)
+                        match extract_isolated(extractor, filtered_content, clean_url.clone(), role, extract_timeout).await {
+                            Ok(result) => (
+                                clean_url,
+                                ScrapeOutcome::New {
+                                    content,
+                                    nodes: result.nodes,
+                                    resource_tags: result.resource_tags,
+                                    signal_tags: result.signal_tags,
+                                    change_summary: diff.map(|d| d.summary()),
+                                },
+                                page_links,
+                            ),
+                            Err(failure) => {
+                                warn!(url = clean_url.as_str(), error = %failure, "Extraction failed");
+                                (clean_url, ScrapeOutcome::ExtractionFailed(failure), page_links)
+                            }
+                        }
                     }
-                }
-            }
-        }))
-        .buffer_unordered(6)
-        .collect()
-        .await;
+                },
+            )
+            .await;
+        record_queue_metrics(ctx, run_log, Lane::Web, &lane_metrics);
 
         // Process results
         let now = Utc::now();
@@ -644,12 +1099,14 @@ impl ScrapePhase {
                     mut nodes,
                     resource_tags,
                     signal_tags,
+                    change_summary,
                 } => {
                     run_log.log(EventKind::ScrapeUrl {
                         url: url.clone(),
                         strategy: "web".to_string(),
                         success: true,
                         content_bytes: content.len(),
+                        error_class: None,
                     });
 
                     // Count implied queries for logging
@@ -683,6 +1140,11 @@ impl ScrapePhase {
                         }
                     }
 
+                    if let Some(summary) = change_summary {
+                        ctx.stats.urls_diff_extracted += 1;
+                        ctx.url_to_change_summary.insert(url.clone(), summary);
+                    }
+
                     let source_id = ck_to_source_id.get(&ck).copied();
                     let signal_count_before = ctx.stats.signals_stored;
                     match self
@@ -722,14 +1184,26 @@ impl ScrapePhase {
                     ctx.stats.urls_unchanged += 1;
                     ctx.source_signal_counts.entry(ck).or_default();
                 }
-                ScrapeOutcome::Failed => {
+                ScrapeOutcome::Failed(class) => {
+                    run_log.log(EventKind::ScrapeUrl {
+                        url: url.clone(),
+                        strategy: "web".to_string(),
+                        success: false,
+                        content_bytes: 0,
+                        error_class: Some(class),
+                    });
+                    ctx.stats.urls_failed += 1;
+                }
+                ScrapeOutcome::ExtractionFailed(failure) => {
                     run_log.log(EventKind::ScrapeUrl {
                         url: url.clone(),
                         strategy: "web".to_string(),
                         success: false,
                         content_bytes: 0,
+                        error_class: Some(failure.class),
                     });
                     ctx.stats.urls_failed += 1;
+                    ctx.stats.extraction_failures.push(failure);
                 }
             }
         }
@@ -748,7 +1222,8 @@ impl ScrapePhase {
             usize,
             Vec<String>,
             Option<DateTime<Utc>>, // most recent published_at for content_date fallback
-        )>; // (canonical_key, source_url, platform, combined_text, nodes, resource_tags, signal_tags, post_count, mentions, newest_published_at)
+            HashMap<String, f64>,  // engagement velocity by post permalink
+        )>; // (canonical_key, source_url, platform, combined_text, nodes, resource_tags, signal_tags, post_count, mentions, newest_published_at, velocities)
 
         // Build uniform list of (canonical_key, source_url, platform, fetch_identifier) from SourceNodes
         struct SocialEntry {
@@ -878,12 +1353,20 @@ impl ScrapePhase {
             Only extract signals where is_firsthand is true. Reject the rest.\n\n";
 
         // Collect all futures into a single Vec<Pin<Box<...>>> so types unify
-        let mut futures: Vec<Pin<Box<dyn Future<Output = SocialResult> + Send>>> =
-            Vec::new();
+        type SocialFuture = Pin<Box<dyn Future<Output = SocialResult> + Send>>;
+        let mut futures: Vec<(WorkPriority, SocialFuture)> = Vec::new();
+        let priorities = self.url_priorities(social_sources, ctx);
+        let roles: HashMap<&str, SourceRole> =
+            social_sources.iter().map(|s| (s.canonical_key.as_str(), s.source_role)).collect();
 
         let fetcher = self.fetcher.clone();
         let extractor = self.extractor.clone();
+        let extract_timeout = self.queue.config().extract_timeout;
+        let extraction_failures: Arc<std::sync::Mutex<Vec<ExtractionFailure>>> = Arc::default();
+        let post_limit = self.item_limit(SOCIAL_POST_LIMIT);
+        let backfill = self.backfill;
         for (canonical_key, source_url, account) in &accounts {
+            let role = roles.get(canonical_key.as_str()).copied().unwrap_or_default();
             let canonical_key = canonical_key.clone();
             let source_url = source_url.clone();
             let platform = account.platform;
@@ -896,18 +1379,32 @@ impl ScrapePhase {
             };
             let fetcher = fetcher.clone();
             let extractor = extractor.clone();
+            let flyers = self.flyers.clone();
             let identifier = account.identifier.clone();
-
-            futures.push(Box::pin(async move {
-                let posts = match fetcher.posts(&identifier, 20).await {
+            let extraction_failures = extraction_failures.clone();
+            let priority = priorities
+                .get(&canonical_key)
+                .copied()
+                .unwrap_or_else(WorkPriority::unknown);
+
+            futures.push((priority, Box::pin(async move {
+                let mut posts = match fetcher.posts(&identifier, post_limit).await {
                     Ok(posts) => posts,
                     Err(e) => {
                         warn!(source_url, error = %e, "Social media scrape failed");
                         return None;
                     }
                 };
+                if let Some(depth) = backfill {
+                    let now = Utc::now();
+                    posts.retain(|p| depth.keeps(p.published_at, now));
+                }
                 let post_count = posts.len();
 
+                // Fast-moving posts go first, ahead of any content truncation
+                engagement::sort_by_velocity(&mut posts);
+                let velocities = engagement::velocities_by_permalink(&posts);
+
                 // Find the most recent published_at for content_date fallback
                 let newest_published_at = posts.iter()
                     .filter_map(|p| p.published_at)
@@ -918,17 +1415,36 @@ impl ScrapePhase {
                     .flat_map(|p| p.mentions.iter().cloned())
                     .collect();
 
-                // Format a post header including the specific post URL when available.
+                // Pull in the comment threads of high-signal posts
+                let threads = comment_threads::expand(fetcher.as_ref(), &posts).await;
+
+                // Read the details off flyer images, which often carry no caption
+                let image_texts = match &flyers {
+                    Some(reader) => reader.read(fetcher.as_ref(), &posts).await,
+                    None => HashMap::new(),
+                };
+
+                // Format a post header including the specific post URL when available,
+                // followed by text read from its images and its comment thread if expanded.
                 let post_header = |i: usize, p: &Post| -> String {
                     let text = p.text.as_deref().unwrap_or("");
-                    match &p.permalink {
+                    let mut block = match &p.permalink {
                         Some(url) => format!("--- Post {} ({}) ---\n{}", i + 1, url, text),
                         None => format!("--- Post {} ---\n{}", i + 1, text),
+                    };
+                    if let Some(image_text) = image_texts.get(&p.id) {
+                        block.push_str("\n\n");
+                        block.push_str(image_text);
+                    }
+                    if let Some(thread) = p.permalink.as_ref().and_then(|url| threads.get(url)) {
+                        block.push_str("\n\n");
+                        block.push_str(thread);
                     }
+                    block
                 };
 
-                if is_reddit {
-                    // Reddit: batch posts 10 at a time for extraction
+                if is_reddit || backfill.is_some() {
+                    // Reddit, and any backfill's deep history: batch posts 10 at a time for extraction
                     let batches: Vec<_> = posts.chunks(10).collect();
                     let mut all_nodes = Vec::new();
                     let mut all_resource_tags = Vec::new();
@@ -952,14 +1468,15 @@ impl ScrapePhase {
                             combined_text = format!("{prefix}{combined_text}");
                         }
                         combined_all.push_str(&combined_text);
-                        match extractor.extract(&combined_text, &source_url).await {
+                        match extract_isolated(extractor.clone(), combined_text, source_url.clone(), role, extract_timeout).await {
                             Ok(result) => {
                                 all_nodes.extend(result.nodes);
                                 all_resource_tags.extend(result.resource_tags);
                                 all_signal_tags.extend(result.signal_tags);
                             }
-                            Err(e) => {
-                                warn!(source_url, error = %e, "Reddit extraction failed");
+                            Err(failure) => {
+                                warn!(source_url, error = %failure, "Reddit extraction failed");
+                                extraction_failures.lock().unwrap().push(failure);
                             }
                         }
                     }
@@ -978,6 +1495,7 @@ impl ScrapePhase {
                         post_count,
                         source_mentions,
                         newest_published_at,
+                        velocities,
                     ))
                 } else {
                     // Instagram/Facebook/Twitter/TikTok: combine all posts then extract
@@ -997,10 +1515,19 @@ impl ScrapePhase {
                     } else if let Some(ref prefix) = firsthand_prefix {
                         combined_text = format!("{prefix}{combined_text}");
                     }
-                    let result = match extractor.extract(&combined_text, &source_url).await {
+                    let result = match extract_isolated(
+                        extractor.clone(),
+                        combined_text.clone(),
+                        source_url.clone(),
+                        role,
+                        extract_timeout,
+                    )
+                    .await
+                    {
                         Ok(r) => r,
-                        Err(e) => {
-                            warn!(source_url, error = %e, "Social extraction failed");
+                        Err(failure) => {
+                            warn!(source_url, error = %failure, "Social extraction failed");
+                            extraction_failures.lock().unwrap().push(failure);
                             return None;
                         }
                     };
@@ -1016,68 +1543,771 @@ impl ScrapePhase {
                         post_count,
                         source_mentions,
                         newest_published_at,
+                        velocities,
                     ))
                 }
-            }));
+            })));
+        }
+
+        // Social fetch and extraction are fused per account, so the whole unit runs in the fetch stage.
+        let (results, lane_metrics) = self
+            .queue
+            .run(
+                Lane::Social,
+                futures,
+                |fut| async move { Fetched::<(), _>::Done(fut.await) },
+                |()| async { unreachable!("social lane extracts inside the fetch stage") },
+            )
+            .await;
+        record_queue_metrics(ctx, run_log, Lane::Social, &lane_metrics);
+        ctx.stats
+            .extraction_failures
+            .append(&mut extraction_failures.lock().unwrap());
+
+        let known_urls = ctx.known_urls();
+        let promotion_config = link_promoter::PromotionConfig::default();
+        let ck_to_source_id: HashMap<String, Uuid> = social_sources
+            .iter()
+            .map(|s| (s.canonical_key.clone(), s.id))
+            .collect();
+        for result in results.into_iter().flatten() {
+            let (
+                canonical_key,
+                source_url,
+                result_platform,
+                combined_text,
+                mut nodes,
+                resource_tags,
+                signal_tags,
+                post_count,
+                mentions,
+                newest_published_at,
+                velocities,
+            ) = result;
+
+            // Apply social published_at as fallback content_date when LLM didn't extract one
+            if let Some(pub_at) = newest_published_at {
+                for node in &mut nodes {
+                    if let Some(meta) = node.meta_mut() {
+                        if meta.content_date.is_none() {
+                            meta.content_date = Some(pub_at);
+                        }
+                    }
+                }
+            }
+
+            ctx.signal_engagement_velocity
+                .extend(engagement::signal_velocities(&nodes, &velocities));
+
+            // Accumulate mentions as URLs for promotion (capped per source)
+            for handle in mentions.into_iter().take(promotion_config.max_per_source) {
+                let mention_url = link_promoter::platform_url(&result_platform, &handle);
+                ctx.collected_links.push(CollectedLink {
+                    url: mention_url,
+                    discovered_on: source_url.clone(),
+                });
+            }
+
+            run_log.log(EventKind::SocialScrape {
+                platform: "social".to_string(),
+                identifier: source_url.clone(),
+                post_count: post_count as u32,
+            });
+
+            run_log.log(EventKind::LlmExtraction {
+                source_url: source_url.clone(),
+                content_chars: combined_text.len(),
+                signals_extracted: nodes.len() as u32,
+                implied_queries: 0,
+            });
+
+            // Collect implied queries from Tension/Need social signals
+            for node in &nodes {
+                if matches!(node.node_type(), NodeType::Tension | NodeType::Need) {
+                    if let Some(meta) = node.meta() {
+                        ctx.expansion_queries
+                            .extend(meta.implied_queries.iter().cloned());
+                    }
+                }
+            }
+            ctx.stats.social_media_posts += post_count as u32;
+            let source_id = ck_to_source_id.get(&canonical_key).copied();
+            let signal_count_before = ctx.stats.signals_stored;
+            if let Err(e) = self
+                .store_signals(
+                    &source_url,
+                    &combined_text,
+                    nodes,
+                    resource_tags,
+                    signal_tags,
+                    ctx,
+                    &known_urls,
+                    run_log,
+                    source_id,
+                )
+                .await
+            {
+                warn!(source_url = source_url.as_str(), error = %e, "Failed to store social media signals");
+            }
+            let produced = ctx.stats.signals_stored - signal_count_before;
+            *ctx.source_signal_counts
+                .entry(canonical_key)
+                .or_default() += produced;
+        }
+    }
+
+    /// Extract signals from newsletter issues received since each source was
+    /// last scraped. Issues are archived by the inbound email webhook, so the
+    /// fetcher only reads them back — nothing goes over the network.
+    pub async fn run_newsletters(&self, newsletter_sources: &[&SourceNode], ctx: &mut RunContext, run_log: &mut RunLog) {
+        info!(newsletters = newsletter_sources.len(), "Reading newsletter issues...");
+        let extract_timeout = self.queue.config().extract_timeout;
+        let known_urls = ctx.known_urls();
+
+        for source in newsletter_sources {
+            let source_value = source.value().to_string();
+            ctx.source_signal_counts
+                .entry(source.canonical_key.clone())
+                .or_default();
+
+            let issues = match self.fetcher.posts(&source_value, self.item_limit(NEWSLETTER_ISSUE_LIMIT)).await {
+                Ok(issues) => issues,
+                Err(e) => {
+                    warn!(source = source_value.as_str(), error = %e, "Failed to read newsletter issues");
+                    continue;
+                }
+            };
+            let fresh: Vec<&Post> = issues
+                .iter()
+                .filter(|p| self.is_fresh(source, p.fetched_at, p.published_at))
+                .filter(|p| p.text.as_deref().is_some_and(|t| !t.trim().is_empty()))
+                .collect();
+            run_log.log(EventKind::SocialScrape {
+                platform: "newsletter".to_string(),
+                identifier: source_value.clone(),
+                post_count: fresh.len() as u32,
+            });
+
+            for issue in fresh {
+                let content = issue.text.clone().unwrap_or_default();
+                let result = match extract_isolated(
+                    self.extractor.clone(),
+                    content.clone(),
+                    source_value.clone(),
+                    source.source_role,
+                    extract_timeout,
+                )
+                .await
+                {
+                    Ok(r) => r,
+                    Err(failure) => {
+                        warn!(source = source_value.as_str(), error = %failure, "Newsletter extraction failed");
+                        ctx.stats.extraction_failures.push(failure);
+                        continue;
+                    }
+                };
+
+                let mut nodes = result.nodes;
+                // The send date is the best fallback for undated announcements.
+                let sent_at = issue.published_at.unwrap_or(issue.fetched_at);
+                for node in &mut nodes {
+                    if let Some(meta) = node.meta_mut() {
+                        if meta.content_date.is_none() {
+                            meta.content_date = Some(sent_at);
+                        }
+                    }
+                    if matches!(node.node_type(), NodeType::Tension | NodeType::Need) {
+                        if let Some(meta) = node.meta() {
+                            ctx.expansion_queries
+                                .extend(meta.implied_queries.iter().cloned());
+                        }
+                    }
+                }
+
+                run_log.log(EventKind::LlmExtraction {
+                    source_url: source_value.clone(),
+                    content_chars: content.len(),
+                    signals_extracted: nodes.len() as u32,
+                    implied_queries: 0,
+                });
+
+                let signal_count_before = ctx.stats.signals_stored;
+                if let Err(e) = self
+                    .store_signals(
+                        &source_value,
+                        &content,
+                        nodes,
+                        result.resource_tags,
+                        result.signal_tags,
+                        ctx,
+                        &known_urls,
+                        run_log,
+                        Some(source.id),
+                    )
+                    .await
+                {
+                    warn!(source = source_value.as_str(), error = %e, "Failed to store newsletter signals");
+                }
+                let produced = ctx.stats.signals_stored - signal_count_before;
+                *ctx.source_signal_counts
+                    .entry(source.canonical_key.clone())
+                    .or_default() += produced;
+            }
+        }
+    }
+
+    /// Extract signals from videos published on each channel since it was last
+    /// scraped. Transcripts are chunked; every signal's source URL deep-links
+    /// to the minute of the video its chunk starts at.
+    pub async fn run_videos(&self, video_sources: &[&SourceNode], ctx: &mut RunContext, run_log: &mut RunLog) {
+        info!(channels = video_sources.len(), "Fetching video transcripts...");
+        let known_urls = ctx.known_urls();
+
+        for source in video_sources {
+            let source_value = source.value().to_string();
+            ctx.source_signal_counts
+                .entry(source.canonical_key.clone())
+                .or_default();
+
+            let videos = match self.fetcher.videos(&source_value, self.item_limit(VIDEO_LIMIT)).await {
+                Ok(videos) => videos,
+                Err(e) => {
+                    warn!(source = source_value.as_str(), error = %e, "Video channel fetch failed");
+                    continue;
+                }
+            };
+            let fresh: Vec<&LongVideo> = videos
+                .iter()
+                .filter(|v| self.is_fresh(source, v.fetched_at, v.published_at))
+                .collect();
+            run_log.log(EventKind::SocialScrape {
+                platform: "youtube".to_string(),
+                identifier: source_value.clone(),
+                post_count: fresh.len() as u32,
+            });
+
+            for video in fresh {
+                let permalink = video.permalink.clone().unwrap_or_else(|| source_value.clone());
+                let recording = Recording {
+                    title: video.title.as_deref(),
+                    description: video.text.as_deref(),
+                    transcript: &video.transcript,
+                    published_at: video.published_at.unwrap_or(video.fetched_at),
+                };
+                self.extract_recording(source, recording, |secs| timestamped_url(&permalink, secs), ctx, &known_urls, run_log)
+                    .await;
+            }
+        }
+    }
+
+    /// Extract signals from podcast episodes published since each feed was
+    /// last scraped. Signals cite the episode itself (its page, or the audio
+    /// file when the feed links no page), whichever chunk they came from.
+    pub async fn run_podcasts(&self, podcast_sources: &[&SourceNode], ctx: &mut RunContext, run_log: &mut RunLog) {
+        info!(podcasts = podcast_sources.len(), "Fetching podcast transcripts...");
+        let known_urls = ctx.known_urls();
+
+        for source in podcast_sources {
+            let source_value = source.value().to_string();
+            ctx.source_signal_counts
+                .entry(source.canonical_key.clone())
+                .or_default();
+
+            let episodes = match self.fetcher.episodes(&source_value, self.item_limit(PODCAST_EPISODE_LIMIT)).await {
+                Ok(episodes) => episodes,
+                Err(e) => {
+                    warn!(source = source_value.as_str(), error = %e, "Podcast feed fetch failed");
+                    continue;
+                }
+            };
+            let fresh: Vec<&PodcastEpisode> = episodes
+                .iter()
+                .filter(|e| self.is_fresh(source, e.fetched_at, e.published_at))
+                .collect();
+            run_log.log(EventKind::SocialScrape {
+                platform: "podcast".to_string(),
+                identifier: source_value.clone(),
+                post_count: fresh.len() as u32,
+            });
+
+            for episode in fresh {
+                let recording = Recording {
+                    title: episode.title.as_deref(),
+                    description: episode.description.as_deref(),
+                    transcript: &episode.transcript,
+                    published_at: episode.published_at.unwrap_or(episode.fetched_at),
+                };
+                self.extract_recording(source, recording, |_| episode.permalink.clone(), ctx, &known_urls, run_log)
+                    .await;
+            }
+        }
+    }
+
+    /// Read upcoming events from each event platform listing and store them
+    /// as Gatherings without LLM extraction. An event listed again on a later
+    /// run refreshes the existing Gathering instead of creating another.
+    pub async fn run_events(&self, event_sources: &[&SourceNode], ctx: &mut RunContext, run_log: &mut RunLog) {
+        info!(listings = event_sources.len(), "Fetching event listings...");
+        let known_urls = ctx.known_urls();
+        let now = Utc::now();
+
+        for source in event_sources {
+            let source_value = source.value().to_string();
+            ctx.source_signal_counts
+                .entry(source.canonical_key.clone())
+                .or_default();
+
+            let events = match self.fetcher.events(&source_value, EVENT_LISTING_LIMIT).await {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!(source = source_value.as_str(), error = %e, "Event listing fetch failed");
+                    continue;
+                }
+            };
+            let upcoming: Vec<&ListedEvent> = events
+                .iter()
+                .filter(|e| e.ends_at.or(e.starts_at).is_none_or(|t| t >= now))
+                .collect();
+            run_log.log(EventKind::SocialScrape {
+                platform: "events".to_string(),
+                identifier: source_value.clone(),
+                post_count: upcoming.len() as u32,
+            });
+
+            // A title listed more than once (weekly clinics, monthly meetings) is a
+            // series; URL title dedup keeps only its next occurrence.
+            let mut title_counts: HashMap<String, u32> = HashMap::new();
+            for event in &upcoming {
+                *title_counts.entry(normalize_title(&event.title)).or_default() += 1;
+            }
+
+            for event in upcoming {
+                let is_recurring = title_counts[&normalize_title(&event.title)] > 1;
+                let node = listed_event_to_gathering(event, is_recurring);
+                let content = match &event.description {
+                    Some(description) => format!("{}\n\n{description}", event.title),
+                    None => event.title.clone(),
+                };
+
+                let signal_count_before = ctx.stats.signals_stored;
+                if let Err(e) = self
+                    .store_signals(
+                        &source_value,
+                        &content,
+                        vec![node],
+                        Vec::new(),
+                        Vec::new(),
+                        ctx,
+                        &known_urls,
+                        run_log,
+                        Some(source.id),
+                    )
+                    .await
+                {
+                    warn!(source = source_value.as_str(), error = %e, "Failed to store listed event");
+                }
+                let produced = ctx.stats.signals_stored - signal_count_before;
+                *ctx.source_signal_counts
+                    .entry(source.canonical_key.clone())
+                    .or_default() += produced;
+            }
+        }
+    }
+
+    /// Ingest recent records from the region's open-data datasets (311
+    /// service requests) as Tensions and Notices, per each dataset's category
+    /// rules. A record about an issue already in the graph — an extracted
+    /// tension about the same building — corroborates that signal instead of
+    /// creating a second one. Records already ingested are caught by URL
+    /// title dedup, so overlapping lookback windows are harmless.
+    pub async fn run_open_data(&self, datasets: &[OpenDataDataset], ctx: &mut RunContext, run_log: &mut RunLog) {
+        let Some(connector) = &self.open_data else {
+            return;
+        };
+        info!(datasets = datasets.len(), "Fetching open-data records...");
+        let known_urls = ctx.known_urls();
+        let now = Utc::now();
+
+        for dataset in datasets {
+            let since = now - chrono::Duration::days(dataset.lookback_days as i64);
+            let records = match connector.fetch(dataset, since).await {
+                Ok(records) => records,
+                Err(e) => {
+                    warn!(dataset = dataset.name.as_str(), error = %e, "Open-data fetch failed");
+                    continue;
+                }
+            };
+            run_log.log(EventKind::SocialScrape {
+                platform: "open_data".to_string(),
+                identifier: dataset.name.clone(),
+                post_count: records.len() as u32,
+            });
+
+            for record in &records {
+                let Some(node) = record_to_node(dataset, record) else {
+                    continue;
+                };
+                let url = node.meta().map(|m| m.source_url.clone()).unwrap_or_default();
+                let content = node.meta().map(|m| m.summary.clone()).unwrap_or_default();
+
+                match self.corroborate_nearby(&node, &url, &content, ctx, run_log).await {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(e) => warn!(url = url.as_str(), error = %e, "Open-data dedup check failed"),
+                }
+
+                if let Err(e) = self
+                    .store_signals(
+                        &url,
+                        &content,
+                        vec![node],
+                        Vec::new(),
+                        Vec::new(),
+                        ctx,
+                        &known_urls,
+                        run_log,
+                        None,
+                    )
+                    .await
+                {
+                    warn!(url = url.as_str(), error = %e, "Failed to store open-data record");
+                }
+            }
+        }
+    }
+
+    /// Read the agendas and minutes of the region's meeting bodies and store
+    /// each substantive item as a Notice citing its meeting. An item seen
+    /// again (agenda, then minutes) refreshes the existing Notice; the same
+    /// matter on another body's agenda corroborates it.
+    pub async fn run_agendas(&self, agendas: &RegionAgendas, ctx: &mut RunContext, run_log: &mut RunLog) {
+        let Some(connector) = &self.agendas else {
+            return;
+        };
+        info!(bodies = agendas.bodies.len(), "Fetching meeting agendas...");
+        let known_urls = ctx.known_urls();
+        let now = Utc::now();
+
+        for body in &agendas.bodies {
+            let meetings = match connector.meetings(body, now).await {
+                Ok(meetings) => meetings,
+                Err(e) => {
+                    warn!(body = body.name.as_str(), error = %e, "Agenda fetch failed");
+                    continue;
+                }
+            };
+            run_log.log(EventKind::SocialScrape {
+                platform: "legistar".to_string(),
+                identifier: body.name.clone(),
+                post_count: meetings.iter().map(|m| m.items.len() as u32).sum(),
+            });
+
+            for meeting in &meetings {
+                // One item per call: items of a meeting share a URL, and a shared
+                // content snippet would make them look like duplicates of each other.
+                for item in &meeting.items {
+                    let topic = agendas.high_impact_topic(item);
+                    let node = item_to_notice(body, meeting, item, topic, now);
+                    let content = node.meta().map(|m| m.summary.clone()).unwrap_or_default();
+                    if let Err(e) = self
+                        .store_signals(
+                            &meeting.url,
+                            &content,
+                            vec![node],
+                            Vec::new(),
+                            Vec::new(),
+                            ctx,
+                            &known_urls,
+                            run_log,
+                            None,
+                        )
+                        .await
+                    {
+                        warn!(url = meeting.url.as_str(), error = %e, "Failed to store agenda item");
+                    }
+                }
+            }
         }
+    }
 
-        let results: Vec<_> = stream::iter(futures).buffer_unordered(10).collect().await;
-
+    /// Read the region's transit and school district alert feeds and store
+    /// each alert as a Tension or Notice tagged with the routes and schools it
+    /// affects. Recent signals mentioning those routes and schools get the
+    /// same tags, linking a closure to what the community is saying about it.
+    pub async fn run_service_alerts(&self, feeds: &RegionServiceAlerts, ctx: &mut RunContext, run_log: &mut RunLog) {
+        let Some(connector) = &self.service_alerts else {
+            return;
+        };
+        info!(
+            agencies = feeds.transit_agencies.len(),
+            districts = feeds.school_districts.len(),
+            "Fetching service alerts..."
+        );
         let known_urls = ctx.known_urls();
-        let promotion_config = link_promoter::PromotionConfig::default();
-        let ck_to_source_id: HashMap<String, Uuid> = social_sources
-            .iter()
-            .map(|s| (s.canonical_key.clone(), s.id))
-            .collect();
-        for result in results.into_iter().flatten() {
-            let (
-                canonical_key,
-                source_url,
-                result_platform,
-                combined_text,
-                mut nodes,
-                resource_tags,
-                signal_tags,
-                post_count,
-                mentions,
-                newest_published_at,
-            ) = result;
+        let now = Utc::now();
 
-            // Apply social published_at as fallback content_date when LLM didn't extract one
-            if let Some(pub_at) = newest_published_at {
-                for node in &mut nodes {
-                    if let Some(meta) = node.meta_mut() {
-                        if meta.content_date.is_none() {
-                            meta.content_date = Some(pub_at);
-                        }
+        for agency in &feeds.transit_agencies {
+            match connector.transit_alerts(agency, now).await {
+                Ok(alerts) => {
+                    run_log.log(EventKind::SocialScrape {
+                        platform: "gtfs_rt".to_string(),
+                        identifier: agency.name.clone(),
+                        post_count: alerts.len() as u32,
+                    });
+                    self.store_alerts(&AlertOrigin::from(agency), &alerts, now, ctx, &known_urls, run_log)
+                        .await;
+                }
+                Err(e) => warn!(agency = agency.name.as_str(), error = %e, "Transit alert fetch failed"),
+            }
+        }
+        for district in &feeds.school_districts {
+            match connector.school_alerts(district, now).await {
+                Ok(alerts) => {
+                    run_log.log(EventKind::SocialScrape {
+                        platform: "school_feed".to_string(),
+                        identifier: district.name.clone(),
+                        post_count: alerts.len() as u32,
+                    });
+                    self.store_alerts(&AlertOrigin::from(district), &alerts, now, ctx, &known_urls, run_log)
+                        .await;
+                }
+                Err(e) => warn!(district = district.name.as_str(), error = %e, "School alert fetch failed"),
+            }
+        }
+    }
+
+    /// Store alerts one per call (a feed's alerts can share a URL) and tag
+    /// signals mentioning their affected entities.
+    async fn store_alerts(
+        &self,
+        origin: &AlertOrigin<'_>,
+        alerts: &[ServiceAlert],
+        now: DateTime<Utc>,
+        ctx: &mut RunContext,
+        known_urls: &HashSet<String>,
+        run_log: &mut RunLog,
+    ) {
+        for alert in alerts {
+            let node = alert_to_node(origin, alert, now);
+            let Some(meta) = node.meta() else {
+                continue;
+            };
+            let (url, content) = (meta.source_url.clone(), meta.summary.clone());
+            let slugs: Vec<String> = alert.affected.iter().map(|e| e.tag_slug()).collect();
+            let signal_tags = vec![(meta.id, slugs)];
+            if let Err(e) = self
+                .store_signals(&url, &content, vec![node], Vec::new(), signal_tags, ctx, known_urls, run_log, None)
+                .await
+            {
+                warn!(url = url.as_str(), error = %e, "Failed to store service alert");
+                continue;
+            }
+
+            let since = now - chrono::Duration::days(ALERT_LINK_LOOKBACK_DAYS);
+            for entity in &alert.affected {
+                let ids = match self
+                    .store
+                    .find_signals_mentioning(&entity.mention(), since, ALERT_LINK_LIMIT)
+                    .await
+                {
+                    Ok(ids) => ids,
+                    Err(e) => {
+                        warn!(entity = entity.mention().as_str(), error = %e, "Mention lookup failed");
+                        continue;
+                    }
+                };
+                let slug = [entity.tag_slug()];
+                for id in ids {
+                    if let Err(e) = self.store.batch_tag_signals(id, &slug).await {
+                        warn!(signal_id = %id, error = %e, "Failed to link signal to service alert entity");
                     }
                 }
             }
+        }
+    }
 
-            // Accumulate mentions as URLs for promotion (capped per source)
-            for handle in mentions.into_iter().take(promotion_config.max_per_source) {
-                let mention_url = link_promoter::platform_url(&result_platform, &handle);
-                ctx.collected_links.push(CollectedLink {
-                    url: mention_url,
-                    discovered_on: source_url.clone(),
-                });
+    /// Link a stored signal to the registry entities its title or summary
+    /// mentions and those whose boundary contains its location.
+    async fn link_registry_entities(&self, signal_id: Uuid, node: &Node) {
+        if self.registry.is_empty() {
+            return;
+        }
+        let Some(meta) = node.meta() else {
+            return;
+        };
+        let text = format!("{} {}", meta.title, meta.summary);
+        let mut links: Vec<(&RegistryEntity, EntityLink)> = self
+            .registry
+            .mentioned_in(&text)
+            .into_iter()
+            .map(|e| (e, EntityLink::Mentions))
+            .collect();
+        if let Some(loc) = meta.about_location {
+            links.extend(
+                self.registry
+                    .containing(loc.lat, loc.lng)
+                    .into_iter()
+                    .map(|e| (e, EntityLink::LocatedIn)),
+            );
+        }
+
+        let region_slug = rootsignal_common::slugify(&self.region.name);
+        for (entity, link) in links {
+            if let Err(e) = self
+                .store
+                .link_signal_to_entity(signal_id, &entity.node_id(&region_slug), link)
+                .await
+            {
+                warn!(error = %e, entity = entity.key().as_str(), "Registry entity link failed (non-fatal)");
             }
+        }
+    }
 
-            run_log.log(EventKind::SocialScrape {
-                platform: "social".to_string(),
-                identifier: source_url.clone(),
-                post_count: post_count as u32,
-            });
+    /// Record a decision under this run. Failing to record never fails the scrape.
+    async fn record_decision(&self, decision: Decision) {
+        let decision = decision.with_run_id(&self.run_id);
+        if let Err(e) = self.store.record_decision(&decision).await {
+            warn!(signal = %decision.subject_id, error = %e, "Failed to record decision");
+        }
+    }
 
-            run_log.log(EventKind::LlmExtraction {
-                source_url: source_url.clone(),
-                content_chars: combined_text.len(),
-                signals_extracted: nodes.len() as u32,
-                implied_queries: 0,
-            });
+    /// Corroborate an existing signal of the same type near `node`'s location
+    /// that describes the same issue. Returns whether one was found. Records
+    /// without coordinates are left to `store_signals`' region-wide dedup.
+    async fn corroborate_nearby(
+        &self,
+        node: &Node,
+        url: &str,
+        content: &str,
+        ctx: &mut RunContext,
+        run_log: &mut RunLog,
+    ) -> Result<bool> {
+        let Some(location) = node.meta().and_then(|m| m.about_location) else {
+            return Ok(false);
+        };
+        let url = sanitize_url(url);
+        let embedding = self
+            .embedder
+            .embed(&format!("{} {}", node.title(), content))
+            .await?;
+
+        let lat_delta = OPEN_DATA_DEDUP_RADIUS_KM / 111.0;
+        let lng_delta = OPEN_DATA_DEDUP_RADIUS_KM / (111.0 * location.lat.to_radians().cos());
+        let Some(dup) = self
+            .store
+            .find_duplicate(
+                &embedding,
+                node.node_type(),
+                OPEN_DATA_DEDUP_THRESHOLD,
+                location.lat - lat_delta,
+                location.lat + lat_delta,
+                location.lng - lng_delta,
+                location.lng + lng_delta,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+        // The record's own signal from an earlier run: URL title dedup refreshes it.
+        if sanitize_url(&dup.source_url) == url {
+            return Ok(false);
+        }
 
-            // Collect implied queries from Tension/Need social signals
-            for node in &nodes {
+        let now = Utc::now();
+        run_log.log(EventKind::SignalCorroborated {
+            existing_id: dup.id.to_string(),
+            signal_type: format!("{}", dup.node_type),
+            new_source_url: url.clone(),
+            similarity: dup.similarity,
+        });
+        info!(
+            existing_id = %dup.id,
+            similarity = dup.similarity,
+            title = node.title(),
+            "Open-data record matches a nearby signal, corroborating"
+        );
+        self.store.corroborate(dup.id, dup.node_type, now, &[]).await?;
+        self.record_decision(
+            Decision::new(DecisionKind::Merge, dup.id, dup.node_type, "scraper.open_data", format!("corroborated by {url}"))
+                .with_inputs(&serde_json::json!({ "source_url": url, "title": node.title(), "similarity": dup.similarity }))
+                .with_rationale(format!("Open-data record near the signal at similarity {:.3}", dup.similarity)),
+        )
+        .await;
+        let evidence = EvidenceNode {
+            id: Uuid::new_v4(),
+            source_url: url.clone(),
+            retrieved_at: now,
+            content_hash: format!("{:x}", content_hash(content)),
+            snippet: Some(content.to_string()),
+            relevance: None,
+            evidence_confidence: None,
+            channel_type: Some(channel_type(&url)),
+            change_summary: None,
+            engagement_velocity: None,
+            verification: None,
+        };
+        self.store.create_evidence(&evidence, dup.id).await?;
+        ctx.stats.signals_extracted += 1;
+        ctx.stats.signals_deduplicated += 1;
+        Ok(true)
+    }
+
+    /// Chunk a recording's transcript, extract each chunk, and store the
+    /// signals under `chunk_url(chunk_start_secs)`. A recording without a
+    /// transcript is extracted from its title and description alone.
+    async fn extract_recording(
+        &self,
+        source: &SourceNode,
+        recording: Recording<'_>,
+        chunk_url: impl Fn(f64) -> String,
+        ctx: &mut RunContext,
+        known_urls: &HashSet<String>,
+        run_log: &mut RunLog,
+    ) {
+        let extract_timeout = self.queue.config().extract_timeout;
+        let header = match (recording.title, recording.description) {
+            (Some(title), Some(description)) => format!("# {title}\n\n{description}\n\n"),
+            (Some(title), None) => format!("# {title}\n\n"),
+            (None, Some(description)) => format!("{description}\n\n"),
+            (None, None) => String::new(),
+        };
+
+        let chunks = if recording.transcript.is_empty() {
+            if header.trim().is_empty() {
+                return;
+            }
+            vec![TranscriptChunk { start_secs: 0.0, text: String::new() }]
+        } else {
+            chunk_transcript(recording.transcript, TRANSCRIPT_CHUNK_CHARS)
+        };
+
+        for chunk in chunks {
+            let url = chunk_url(chunk.start_secs);
+            let content = format!("{header}{}", chunk.text);
+            let result = match extract_isolated(
+                self.extractor.clone(),
+                content.clone(),
+                url.clone(),
+                source.source_role,
+                extract_timeout,
+            )
+            .await
+            {
+                Ok(r) => r,
+                Err(failure) => {
+                    warn!(url = url.as_str(), error = %failure, "Transcript extraction failed");
+                    ctx.stats.extraction_failures.push(failure);
+                    continue;
+                }
+            };
+
+            let mut nodes = result.nodes;
+            for node in &mut nodes {
+                if let Some(meta) = node.meta_mut() {
+                    if meta.content_date.is_none() {
+                        meta.content_date = Some(recording.published_at);
+                    }
+                }
                 if matches!(node.node_type(), NodeType::Tension | NodeType::Need) {
                     if let Some(meta) = node.meta() {
                         ctx.expansion_queries
@@ -1085,28 +2315,34 @@ impl ScrapePhase {
                     }
                 }
             }
-            ctx.stats.social_media_posts += post_count as u32;
-            let source_id = ck_to_source_id.get(&canonical_key).copied();
+
+            run_log.log(EventKind::LlmExtraction {
+                source_url: url.clone(),
+                content_chars: content.len(),
+                signals_extracted: nodes.len() as u32,
+                implied_queries: 0,
+            });
+
             let signal_count_before = ctx.stats.signals_stored;
             if let Err(e) = self
                 .store_signals(
-                    &source_url,
-                    &combined_text,
+                    &url,
+                    &content,
                     nodes,
-                    resource_tags,
-                    signal_tags,
+                    result.resource_tags,
+                    result.signal_tags,
                     ctx,
-                    &known_urls,
+                    known_urls,
                     run_log,
-                    source_id,
+                    Some(source.id),
                 )
                 .await
             {
-                warn!(source_url = source_url.as_str(), error = %e, "Failed to store social media signals");
+                warn!(url = url.as_str(), error = %e, "Failed to store transcript signals");
             }
             let produced = ctx.stats.signals_stored - signal_count_before;
             *ctx.source_signal_counts
-                .entry(canonical_key)
+                .entry(source.canonical_key.clone())
                 .or_default() += produced;
         }
     }
@@ -1245,10 +2481,19 @@ impl ScrapePhase {
                 }
 
                 // Extract signals via LLM
-                let result = match self.extractor.extract(&combined_text, &source_url).await {
+                let result = match extract_isolated(
+                    self.extractor.clone(),
+                    combined_text.clone(),
+                    source_url.clone(),
+                    SourceRole::default(),
+                    self.queue.config().extract_timeout,
+                )
+                .await
+                {
                     Ok(r) => r,
-                    Err(e) => {
-                        warn!(username, platform = platform_name, error = %e, "Discovery extraction failed");
+                    Err(failure) => {
+                        warn!(username, platform = platform_name, error = %failure, "Discovery extraction failed");
+                        ctx.stats.extraction_failures.push(failure);
                         continue;
                     }
                 };
@@ -1335,7 +2580,7 @@ impl ScrapePhase {
         }
 
         // Site-scoped search: find WebQuery sources with `site:` prefix,
-        // search Serper for each topic, scrape + extract results.
+        // web-search each topic, scrape + extract results.
         let site_sources: Vec<&SourceNode> = existing_sources
             .iter()
             .filter(|s| {
@@ -1380,14 +2625,22 @@ impl ScrapePhase {
                     }
                     let content = page.markdown;
 
-                    let extracted =
-                        match self.extractor.extract(&content, &result.url).await {
-                            Ok(r) => r,
-                            Err(e) => {
-                                warn!(url = result.url, error = %e, "Site-scoped extraction failed");
-                                continue;
-                            }
-                        };
+                    let extracted = match extract_isolated(
+                        self.extractor.clone(),
+                        content.clone(),
+                        result.url.clone(),
+                        SourceRole::default(),
+                        self.queue.config().extract_timeout,
+                    )
+                    .await
+                    {
+                        Ok(r) => r,
+                        Err(failure) => {
+                            warn!(url = result.url, error = %failure, "Site-scoped extraction failed");
+                            ctx.stats.extraction_failures.push(failure);
+                            continue;
+                        }
+                    };
 
                     if extracted.nodes.is_empty() {
                         continue;
@@ -1424,6 +2677,48 @@ impl ScrapePhase {
     // store_signals — multi-layer dedup + graph storage (private)
     // -----------------------------------------------------------------------
 
+    /// Whether `node` matches negative knowledge: by title, or by embedding
+    /// once it has one. A match is logged and counted against its entry.
+    fn is_known_negative(
+        &self,
+        node: &Node,
+        embedding: Option<&[f32]>,
+        url: &str,
+        ctx: &mut RunContext,
+        run_log: &mut RunLog,
+    ) -> bool {
+        if self.negative.is_empty() {
+            return false;
+        }
+        let now = Utc::now();
+        let hit = match embedding {
+            Some(embedding) => self.negative.check_embedding(embedding, now),
+            None => self.negative.check_text(node.title(), now),
+        };
+        let Some(hit) = hit else {
+            return false;
+        };
+        run_log.log(EventKind::SignalSuppressed {
+            signal_type: format!("{}", node.node_type()),
+            title: node.title().to_string(),
+            matched_id: hit.entry_id.to_string(),
+            kind: hit.kind.as_str().to_string(),
+            similarity: hit.similarity,
+            source_url: url.to_string(),
+        });
+        info!(
+            entry_id = %hit.entry_id,
+            kind = hit.kind.as_str(),
+            similarity = hit.similarity,
+            title = node.title(),
+            source = url,
+            "Matches negative knowledge, suppressing"
+        );
+        *ctx.negative_hits.entry(hit.entry_id).or_default() += 1;
+        ctx.stats.signals_suppressed += 1;
+        true
+    }
+
     async fn store_signals(
         &self,
         url: &str,
@@ -1438,6 +2733,7 @@ impl ScrapePhase {
     ) -> Result<()> {
         let url = sanitize_url(url);
         ctx.stats.signals_extracted += nodes.len() as u32;
+        let change_summary = ctx.url_to_change_summary.get(&url).cloned();
 
         // Build lookup map from node ID → resource tags
         let resource_map: HashMap<Uuid, Vec<ResourceTag>> = resource_tags.into_iter().collect();
@@ -1454,16 +2750,44 @@ impl ScrapePhase {
             .get(&url)
             .cloned()
             .unwrap_or_else(|| url.clone());
-        let actor_ctx = ctx.actor_contexts.get(&ck_for_fallback);
-        let nodes = score_and_filter(nodes, &url, actor_ctx);
+        let actor_ctx = ctx.actor_contexts.get(&ck_for_fallback).cloned();
+        let mut nodes = score_and_filter(nodes, &url, actor_ctx.as_ref());
 
         if nodes.is_empty() {
             return Ok(());
         }
 
+        // Fast-moving social content starts with more confidence
+        let velocities: HashMap<Uuid, f64> = nodes
+            .iter()
+            .filter_map(|n| n.meta().map(|m| m.id))
+            .filter_map(|id| Some((id, ctx.signal_engagement_velocity.remove(&id)?)))
+            .collect();
+        let mut boosted_from: HashMap<Uuid, f32> = HashMap::new();
+        for node in &mut nodes {
+            if let Some(meta) = node.meta_mut() {
+                if let Some(&velocity) = velocities.get(&meta.id) {
+                    let boosted = engagement::boosted_confidence(meta.confidence, velocity);
+                    if boosted > meta.confidence {
+                        boosted_from.insert(meta.id, meta.confidence);
+                    }
+                    meta.confidence = boosted;
+                }
+            }
+        }
+
         // --- Layer 1: Within-batch dedup by (normalized_title, node_type) ---
         let nodes = batch_title_dedup(nodes);
 
+        // --- Negative knowledge: titles of debunked, spam or duplicate content ---
+        let nodes: Vec<_> = nodes
+            .into_iter()
+            .filter(|n| !self.is_known_negative(n, None, &url, ctx, run_log))
+            .collect();
+        if nodes.is_empty() {
+            return Ok(());
+        }
+
         // --- Layer 2: URL-based title dedup against existing database ---
         let existing_titles: HashSet<String> = self
             .store
@@ -1532,6 +2856,12 @@ impl ScrapePhase {
                     self.store
                         .corroborate(existing_id, existing_type, now, &entity_mappings)
                         .await?;
+                    self.record_decision(
+                        Decision::new(DecisionKind::Merge, existing_id, existing_type, "scraper.dedup", format!("corroborated by {url}"))
+                            .with_inputs(&serde_json::json!({ "source_url": url, "title": node.title() }))
+                            .with_rationale("Same title and type as this signal, from a different source"),
+                    )
+                    .await;
                     let evidence = EvidenceNode {
                         id: Uuid::new_v4(),
                         source_url: url.clone(),
@@ -1541,6 +2871,9 @@ impl ScrapePhase {
                         relevance: None,
                         evidence_confidence: None,
                         channel_type: Some(channel_type(&url)),
+                        change_summary: change_summary.clone(),
+                        engagement_velocity: node.meta().and_then(|m| velocities.get(&m.id)).copied(),
+                        verification: None,
                     };
                     self.store
                         .create_evidence(&evidence, existing_id)
@@ -1554,6 +2887,7 @@ impl ScrapePhase {
                         matched_id: existing_id.to_string(),
                         similarity,
                         action: "refresh".to_string(),
+                        source_url: url.clone(),
                     });
                     info!(
                         existing_id = %existing_id,
@@ -1573,6 +2907,9 @@ impl ScrapePhase {
                         relevance: None,
                         evidence_confidence: None,
                         channel_type: Some(channel_type(&url)),
+                        change_summary: change_summary.clone(),
+                        engagement_velocity: node.meta().and_then(|m| velocities.get(&m.id)).copied(),
+                        verification: None,
                     };
                     self.store
                         .create_evidence(&evidence, existing_id)
@@ -1660,24 +2997,18 @@ impl ScrapePhase {
                 NodeType::Evidence => continue,
             };
 
+            if self.is_known_negative(&node, Some(&embedding), &url, ctx, run_log) {
+                continue;
+            }
+
             // 3a: Check in-memory cache first (catches cross-batch dupes not yet indexed)
             let cache_hit = ctx.embed_cache.find_match(&embedding, 0.85);
 
-            // 3b: Check graph index (catches dupes from previous runs, region-scoped)
-            let lat_delta = self.region.radius_km / 111.0;
-            let lng_delta = self.region.radius_km
-                / (111.0 * self.region.center_lat.to_radians().cos());
+            // 3b: Check graph index (catches dupes from previous runs, in this and overlapping regions)
+            let (min_lat, max_lat, min_lng, max_lng) = self.dedup_bounds;
             let graph_hit = match self
                 .store
-                .find_duplicate(
-                    &embedding,
-                    node_type,
-                    0.85,
-                    self.region.center_lat - lat_delta,
-                    self.region.center_lat + lat_delta,
-                    self.region.center_lng - lng_delta,
-                    self.region.center_lng + lng_delta,
-                )
+                .find_duplicate(&embedding, node_type, 0.85, min_lat, max_lat, min_lng, max_lng)
                 .await
             {
                 Ok(Some(dup)) => {
@@ -1703,6 +3034,7 @@ impl ScrapePhase {
                         matched_id: existing_id.to_string(),
                         similarity,
                         action: "refresh".to_string(),
+                        source_url: url.clone(),
                     });
                     info!(
                         existing_id = %existing_id,
@@ -1723,6 +3055,9 @@ impl ScrapePhase {
                         relevance: None,
                         evidence_confidence: None,
                         channel_type: Some(channel_type(&url)),
+                        change_summary: change_summary.clone(),
+                        engagement_velocity: node.meta().and_then(|m| velocities.get(&m.id)).copied(),
+                        verification: None,
                     };
                     self.store.create_evidence(&evidence, existing_id).await?;
                     // Update embed cache if verdict came from graph
@@ -1752,6 +3087,12 @@ impl ScrapePhase {
                     self.store
                         .corroborate(existing_id, existing_type, now, &entity_mappings)
                         .await?;
+                    self.record_decision(
+                        Decision::new(DecisionKind::Merge, existing_id, existing_type, "scraper.dedup", format!("corroborated by {url}"))
+                            .with_inputs(&serde_json::json!({ "source_url": url, "title": node.title(), "similarity": similarity }))
+                            .with_rationale(format!("Cross-source duplicate at similarity {similarity:.3} ({source_layer} match)")),
+                    )
+                    .await;
                     let evidence = EvidenceNode {
                         id: Uuid::new_v4(),
                         source_url: url.clone(),
@@ -1761,6 +3102,9 @@ impl ScrapePhase {
                         relevance: None,
                         evidence_confidence: None,
                         channel_type: Some(channel_type(&url)),
+                        change_summary: change_summary.clone(),
+                        engagement_velocity: node.meta().and_then(|m| velocities.get(&m.id)).copied(),
+                        verification: None,
                     };
                     self.store.create_evidence(&evidence, existing_id).await?;
                     // Update embed cache if verdict came from graph
@@ -1777,6 +3121,21 @@ impl ScrapePhase {
 
             // Create new node
             let node_id = self.store.create_node(&node, &embedding, "scraper", &self.run_id).await?;
+            if let Some((meta, &before)) = node.meta().and_then(|m| Some((m, boosted_from.get(&m.id)?))) {
+                let velocity = velocities.get(&meta.id).copied().unwrap_or_default();
+                self.record_decision(
+                    Decision::new(
+                        DecisionKind::Boost,
+                        node_id,
+                        node_type,
+                        "scraper.engagement",
+                        format!("confidence {before:.2} -> {:.2}", meta.confidence),
+                    )
+                    .with_inputs(&serde_json::json!({ "source_url": url, "velocity": velocity, "confidence": before }))
+                    .with_rationale(format!("Source post is spreading fast ({velocity:.1} interactions/hour)")),
+                )
+                .await;
+            }
 
             run_log.log(EventKind::SignalCreated {
                 node_id: node_id.to_string(),
@@ -1785,6 +3144,10 @@ impl ScrapePhase {
                 confidence: node.meta().map(|m| m.confidence as f64).unwrap_or(0.0),
                 source_url: url.clone(),
             });
+            ctx.audit_sample.offer(node_id, || {
+                let summary = node.meta().map(|m| m.summary.as_str()).unwrap_or_default();
+                AuditCandidate::new(node_id, node_type, node.title(), summary, &url, content)
+            });
 
             // Add to in-memory cache so subsequent batches can find it immediately
             ctx.embed_cache
@@ -1799,6 +3162,9 @@ impl ScrapePhase {
                 relevance: None,
                 evidence_confidence: None,
                 channel_type: Some(channel_type(&url)),
+                change_summary: change_summary.clone(),
+                engagement_velocity: node.meta().and_then(|m| velocities.get(&m.id)).copied(),
+                verification: None,
             };
             self.store.create_evidence(&evidence, node_id).await?;
 
@@ -1837,7 +3203,7 @@ impl ScrapePhase {
                                         location_lat: None,
                                         location_lng: None,
                                         location_name: None,
-                                        discovery_depth: actor_ctx.map(|ac| ac.discovery_depth + 1).unwrap_or(0),
+                                        discovery_depth: actor_ctx.as_ref().map(|ac| ac.discovery_depth + 1).unwrap_or(0),
                                     };
                                     match self.store.upsert_actor(&actor).await {
                                         Ok(_) => {
@@ -1952,6 +3318,9 @@ impl ScrapePhase {
                 }
             }
 
+            // Link to registry entities (MENTIONS / LOCATED_IN edges)
+            self.link_registry_entities(node_id, &node).await;
+
             // Update stats
             ctx.stats.signals_stored += 1;
             ctx.stats.by_type[type_idx] += 1;
@@ -2046,6 +3415,9 @@ mod tests {
                 channel_diversity: 1,
                 mentioned_actors: Vec::new(),
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity: Severity::Medium,
             category: None,
@@ -2077,11 +3449,15 @@ mod tests {
                 channel_diversity: 1,
                 mentioned_actors: Vec::new(),
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             urgency: Urgency::Medium,
             what_needed: None,
             action_url: None,
             goal: None,
+            fundraiser: None,
         })
     }
 
@@ -2423,6 +3799,9 @@ mod tests {
                 channel_diversity: 1,
                 mentioned_actors: Vec::new(),
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity: Severity::Medium,
             category: None,
@@ -2472,6 +3851,9 @@ mod tests {
             relevance: None,
             evidence_confidence: None,
             channel_type: None,
+            change_summary: None,
+            engagement_velocity: None,
+            verification: None,
         });
         let nodes = vec![
             tension_at("Real signal", 44.95, -93.27),
@@ -2506,6 +3888,9 @@ mod tests {
                 channel_diversity: 1,
                 mentioned_actors: Vec::new(),
                 author_actor: None,
+                field_visibility: Default::default(),
+                alt_text: None,
+                plain_summary: None,
             },
             severity: Severity::Medium,
             category: None,
@@ -2629,6 +4014,9 @@ mod tests {
             relevance: None,
             evidence_confidence: None,
             channel_type: None,
+            change_summary: None,
+            engagement_velocity: None,
+            verification: None,
         });
         let nodes = vec![tension("Real Signal"), evidence];
         let result = score_and_filter(nodes, URL_A, None);
@@ -2655,6 +4043,104 @@ mod tests {
         assert!(is_owned_source(&ScrapingStrategy::Social(SocialPlatform::Twitter)));
     }
 
+    #[test]
+    fn video_channels_and_podcasts_are_owned_sources() {
+        assert!(is_owned_source(&ScrapingStrategy::Video));
+        assert!(is_owned_source(&ScrapingStrategy::Podcast));
+    }
+
+    #[test]
+    fn listed_event_maps_to_gathering_at_its_venue_with_ticket_link() {
+        let mut event = crate::testing::test_listed_event(
+            "Tenant rights clinic",
+            "https://www.eventbrite.com/e/771234",
+            "Northside Tenants Union",
+            chrono::Duration::days(3),
+        );
+        event.ticket_url = Some("https://northsidetenants.org/rsvp".to_string());
+
+        let node = listed_event_to_gathering(&event, false);
+
+        let Node::Gathering(g) = &node else { panic!("expected Gathering") };
+        assert_eq!(g.starts_at, event.starts_at);
+        assert_eq!(g.action_url, "https://northsidetenants.org/rsvp");
+        assert_eq!(g.organizer.as_deref(), Some("Northside Tenants Union"));
+        assert_eq!(g.meta.author_actor.as_deref(), Some("Northside Tenants Union"));
+        let loc = g.meta.about_location.expect("venue coordinates");
+        assert_eq!(loc.precision, GeoPrecision::Exact);
+        assert_eq!(
+            g.meta.about_location_name.as_deref(),
+            Some("Sumner Library, 611 Van White Memorial Blvd, Minneapolis, MN")
+        );
+    }
+
+    #[test]
+    fn online_listed_event_keeps_high_confidence_without_a_location() {
+        let mut event = crate::testing::test_listed_event(
+            "Budget hearing watch party",
+            "https://www.facebook.com/events/5550001",
+            "Ward 5 Neighbors",
+            chrono::Duration::days(1),
+        );
+        event.is_online = true;
+        event.latitude = None;
+        event.longitude = None;
+        event.venue_name = None;
+        event.venue_address = None;
+
+        let nodes = vec![listed_event_to_gathering(&event, false)];
+        let result = score_and_filter(nodes, "https://www.facebook.com/ward5neighbors/events", None);
+
+        assert!(result[0].meta().unwrap().about_location.is_none());
+        assert!(result[0].meta().unwrap().confidence >= LISTED_EVENT_CONFIDENCE);
+    }
+
+    #[test]
+    fn event_listings_are_owned_sources() {
+        assert!(is_owned_source(&ScrapingStrategy::Events));
+    }
+
+    // --- transcript chunking tests ---
+
+    fn segment(start_secs: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment { start_secs, text: text.to_string() }
+    }
+
+    #[test]
+    fn transcript_chunks_start_at_their_first_line_and_keep_timestamps() {
+        let segments = vec![
+            segment(5.0, "Call to order"),
+            segment(65.0, "Public comment opens"),
+            segment(3725.0, "Motion to approve the shelter contract"),
+        ];
+
+        let chunks = chunk_transcript(&segments, 50);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].start_secs, 5.0);
+        assert_eq!(chunks[0].text, "[0:05] Call to order\n[1:05] Public comment opens\n");
+        assert_eq!(chunks[1].start_secs, 3725.0);
+        assert!(chunks[1].text.starts_with("[1:02:05] Motion to approve"));
+    }
+
+    #[test]
+    fn overlong_transcript_line_still_gets_its_own_chunk() {
+        let long = "word ".repeat(40);
+        let chunks = chunk_transcript(&[segment(0.0, &long), segment(30.0, "next")], 20);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].text, "[0:30] next\n");
+    }
+
+    #[test]
+    fn deep_link_points_at_the_minute_and_replaces_existing_offset() {
+        assert_eq!(
+            timestamped_url("https://www.youtube.com/watch?v=abc123&t=5s", 754.2),
+            "https://www.youtube.com/watch?v=abc123&t=720s"
+        );
+        assert_eq!(timestamped_url("https://youtu.be/abc123", 59.0), "https://youtu.be/abc123?t=0s");
+    }
+
     #[test]
     fn is_owned_source_web_page_returns_false() {
         assert!(!is_owned_source(&ScrapingStrategy::WebPage));
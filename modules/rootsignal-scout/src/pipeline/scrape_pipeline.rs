@@ -4,7 +4,7 @@
 //! each phase as an async method. Used by both the Restate ScrapeWorkflow
 //! and the legacy CLI binary.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -14,23 +14,36 @@ use sqlx::PgPool;
 use tracing::{info, warn};
 
 use rootsignal_common::{
-    is_web_query, scraping_strategy, ScoutScope, DiscoveryMethod, ScrapingStrategy, SourceNode,
+    is_web_query, scraping_strategy, ActorNode, ScoutScope, DiscoveryMethod, OpenDataDataset, RegionAgendas, RegionRegistry, RegionServiceAlerts, ReviewStatus, ScrapingStrategy, SourceNode,
 };
+use rootsignal_common::error_budget;
+use rootsignal_common::feature_flags::FeatureFlags;
+use rootsignal_common::negative_knowledge::{self, NegativeIndex};
 use rootsignal_graph::GraphWriter;
 
+use ai_client::Claude;
 use rootsignal_archive::Archive;
 
-use crate::scheduling::budget::BudgetTracker;
+use crate::scheduling::budget::{BudgetTracker, OperationCost};
 use crate::infra::embedder::TextEmbedder;
 use crate::pipeline::extractor::SignalExtractor;
+use crate::pipeline::flyers::{self, FlyerReader};
 use crate::pipeline::expansion::Expansion;
 use crate::enrichment::link_promoter::{self, PromotionConfig};
 use crate::scheduling::metrics::Metrics;
 use crate::infra::run_log::{EventKind, RunLog};
 use crate::pipeline::scrape_phase::{RunContext, ScrapePhase};
 use crate::pipeline::stats::ScoutStats;
+use crate::pipeline::work_queue::WorkQueueConfig;
+use crate::discovery::query_ledger::QueryLedger;
 use crate::discovery::source_finder::SourceFinderStats;
 use crate::infra::util::sanitize_url;
+use crate::memory::memo::{RegionMemo, RegionMemoStore};
+use crate::enrichment::audit::Auditor;
+use crate::memory::updater::MemoUpdater;
+use crate::agendas::legistar::LegistarClient;
+use crate::service_alerts::AlertFeedsClient;
+use crate::open_data::{OpenDataConnector, PortalConnector};
 
 pub(crate) fn check_cancelled_flag(cancelled: &AtomicBool) -> Result<()> {
     if cancelled.load(Ordering::Relaxed) {
@@ -53,6 +66,9 @@ pub struct ScrapePipeline<'a> {
     cancelled: Arc<AtomicBool>,
     run_id: String,
     pg_pool: PgPool,
+    open_data: Arc<dyn OpenDataConnector>,
+    region_memo: RegionMemo,
+    flags: FeatureFlags,
 }
 
 /// Phase 2 outputs that flow into subsequent phases.
@@ -62,6 +78,14 @@ pub(crate) struct ScheduledRun {
     tension_phase_keys: HashSet<String>,
     response_phase_keys: HashSet<String>,
     scheduled_keys: HashSet<String>,
+    /// Region calendar blocked this run (quiet hours / blackout): scrape nothing.
+    calendar_blocked: bool,
+    /// The region's open-data (311) datasets.
+    open_data: Vec<OpenDataDataset>,
+    /// The meeting bodies the region follows.
+    agendas: RegionAgendas,
+    /// The transit agencies and school districts the region follows.
+    service_alerts: RegionServiceAlerts,
     phase: ScrapePhase,
     consumed_pin_ids: Vec<uuid::Uuid>,
 }
@@ -90,9 +114,31 @@ impl<'a> ScrapePipeline<'a> {
             cancelled,
             run_id,
             pg_pool,
+            open_data: Arc::new(PortalConnector::new(None)),
+            region_memo: RegionMemo::default(),
+            flags: FeatureFlags::default(),
         }
     }
 
+    /// Set the region memo loaded at the start of the run. Discovery sees it,
+    /// and `finalize` rewrites it from what the run found.
+    pub fn with_region_memo(mut self, memo: RegionMemo) -> Self {
+        self.region_memo = memo;
+        self
+    }
+
+    /// Set the feature flags resolved for this run's region.
+    pub fn with_flags(mut self, flags: FeatureFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Replace the open-data connector (e.g. to pass a Socrata app token).
+    pub fn with_open_data(mut self, connector: Arc<dyn OpenDataConnector>) -> Self {
+        self.open_data = connector;
+        self
+    }
+
     /// Remove stale signals from the graph.
     pub async fn reap_expired_signals(&self, run_log: &mut RunLog) {
         info!("Reaping expired signals...");
@@ -145,15 +191,28 @@ impl<'a> ScrapePipeline<'a> {
             }
         };
 
-        // Self-heal: if region has zero sources, re-run the cold-start bootstrapper.
-        if all_sources.is_empty() {
+        // Self-heal: if region has zero sources, re-run the cold-start bootstrapper —
+        // unless an earlier bootstrap's sources are still waiting for review.
+        let awaiting_review = if all_sources.is_empty() {
+            self.writer
+                .bootstrap_candidates(&rootsignal_common::slugify(&self.region.name), Some(ReviewStatus::Pending))
+                .await
+                .map(|pending| pending.len())
+                .unwrap_or_default()
+        } else {
+            0
+        };
+        if awaiting_review > 0 {
+            info!(pending = awaiting_review, "No active sources; bootstrap sources are awaiting review");
+        } else if all_sources.is_empty() {
             info!("No sources found — running cold-start bootstrap");
             let bootstrapper = crate::discovery::bootstrap::Bootstrapper::new(
                 &self.writer,
                 self.archive.clone(),
                 &self.anthropic_api_key,
                 self.region.clone(),
-            );
+            )
+            .with_ledger(self.query_ledger());
             match bootstrapper.run().await {
                 Ok(n) => {
                     run_log.log(EventKind::Bootstrap { sources_created: n as u64 });
@@ -171,19 +230,26 @@ impl<'a> ScrapePipeline<'a> {
                 .unwrap_or_default();
         }
 
-        // Actor discovery — if no actors in region, discover from web pages
+        // Actor discovery — if no actors in region, discover from web pages.
+        // The query is by bounding box; a region boundary narrows it further.
         let (min_lat, max_lat, min_lng, max_lng) = self.region.bounding_box();
-        let actors_in_region = self.writer
+        let in_region = |actor: &ActorNode| match (actor.location_lat, actor.location_lng) {
+            (Some(lat), Some(lng)) => self.region.contains(lat, lng),
+            _ => false,
+        };
+        let mut actors_in_region = self.writer
             .find_actors_in_region(min_lat, max_lat, min_lng, max_lng)
             .await
             .unwrap_or_default();
+        actors_in_region.retain(|(actor, _)| in_region(actor));
 
         // Actor sources — inject known actor accounts with elevated priority
         let actor_pairs = match self.writer
             .find_actors_in_region(min_lat, max_lat, min_lng, max_lng)
             .await
         {
-            Ok(pairs) => {
+            Ok(mut pairs) => {
+                pairs.retain(|(actor, _)| in_region(actor));
                 let actor_count = pairs.len();
                 let source_count: usize = pairs.iter().map(|(_, s)| s.len()).sum();
                 if actor_count > 0 {
@@ -223,6 +289,7 @@ impl<'a> ScrapePipeline<'a> {
         // Pin consumption — add pin sources to the pool
         let existing_keys: HashSet<String> =
             all_sources.iter().map(|s| s.canonical_key.clone()).collect();
+        let mut pinned_keys: HashSet<String> = HashSet::new();
         let consumed_pin_ids = match self.writer
             .find_pins_in_region(min_lat, max_lat, min_lng, max_lng)
             .await
@@ -230,6 +297,7 @@ impl<'a> ScrapePipeline<'a> {
             Ok(pins) => {
                 let mut ids = Vec::new();
                 for (pin, source) in pins {
+                    pinned_keys.insert(source.canonical_key.clone());
                     if !existing_keys.contains(&source.canonical_key) {
                         all_sources.push(source);
                     }
@@ -247,7 +315,70 @@ impl<'a> ScrapePipeline<'a> {
         };
 
         let now_schedule = Utc::now();
-        let scheduler = crate::scheduling::scheduler::SourceScheduler::new();
+        let calendar = match self
+            .writer
+            .get_region_calendar(&rootsignal_common::slugify(&self.region.name))
+            .await
+        {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(error = %e, "Failed to load region calendar, scheduling without it");
+                None
+            }
+        };
+        let open_data = match self
+            .writer
+            .get_region_open_data(&rootsignal_common::slugify(&self.region.name))
+            .await
+        {
+            Ok(o) => o.map(|o| o.datasets).unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, "Failed to load region open-data datasets, continuing without");
+                Vec::new()
+            }
+        };
+        let agendas = match self
+            .writer
+            .get_region_agendas(&rootsignal_common::slugify(&self.region.name))
+            .await
+        {
+            Ok(a) => a.unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, "Failed to load region agendas, continuing without");
+                RegionAgendas::default()
+            }
+        };
+        let service_alerts = match self
+            .writer
+            .get_region_service_alerts(&rootsignal_common::slugify(&self.region.name))
+            .await
+        {
+            Ok(a) => a.unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, "Failed to load region service alerts, continuing without");
+                RegionServiceAlerts::default()
+            }
+        };
+        let registry = match self
+            .writer
+            .get_region_registry(&rootsignal_common::slugify(&self.region.name))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!(error = %e, "Failed to load region registry, continuing without entity links");
+                RegionRegistry::default()
+            }
+        };
+        let region_slug = rootsignal_common::slugify(&self.region.name);
+        let overlapping_regions: Vec<ScoutScope> = match self.writer.register_region_scope(&region_slug, &self.region).await {
+            Ok(scopes) => scopes.into_iter().filter(|(slug, _)| *slug != region_slug).map(|(_, scope)| scope).collect(),
+            Err(e) => {
+                warn!(error = %e, "Failed to register region scope, deduplicating within this region only");
+                Vec::new()
+            }
+        };
+        let scheduler = crate::scheduling::scheduler::SourceScheduler::new().with_calendar(calendar);
         let schedule = scheduler.schedule(&all_sources, now_schedule);
         let scheduled_keys: HashSet<String> = schedule
             .scheduled
@@ -291,6 +422,7 @@ impl<'a> ScrapePipeline<'a> {
 
         // Create shared run context and scrape phase
         let mut ctx = RunContext::new(&all_sources);
+        ctx.breaking_keys = pinned_keys;
 
         // Populate actor contexts for location fallback during extraction
         for (actor, sources) in &actor_pairs {
@@ -315,7 +447,18 @@ impl<'a> ScrapePipeline<'a> {
             self.archive.clone() as Arc<dyn crate::pipeline::traits::ContentFetcher>,
             self.region.clone(),
             self.run_id.clone(),
-        );
+        )
+        .with_queue_config(WorkQueueConfig::from_env())
+        .with_open_data(self.open_data.clone())
+        .with_agendas(Arc::new(LegistarClient::new()))
+        .with_service_alerts(Arc::new(AlertFeedsClient::new()))
+        .with_registry(registry)
+        .with_negative_knowledge(self.load_negative_knowledge().await)
+        .with_overlapping_regions(&overlapping_regions)
+        .with_flyer_reader(Arc::new(FlyerReader::new(
+            Arc::new(Claude::new(&self.anthropic_api_key, flyers::VISION_MODEL)),
+            self.flyer_allowance(),
+        )));
 
         let run = ScheduledRun {
             all_sources,
@@ -323,6 +466,10 @@ impl<'a> ScrapePipeline<'a> {
             tension_phase_keys,
             response_phase_keys,
             scheduled_keys,
+            calendar_blocked: schedule.blocked.is_some(),
+            open_data,
+            agendas,
+            service_alerts,
             phase,
             consumed_pin_ids,
         };
@@ -330,9 +477,92 @@ impl<'a> ScrapePipeline<'a> {
         Ok((run, ctx))
     }
 
+    /// Flyer reads the run can pay for, up to the per-run cap.
+    fn flyer_allowance(&self) -> u32 {
+        if !self.budget.is_active() {
+            return flyers::MAX_FLYER_READS_PER_RUN;
+        }
+        let affordable = self.budget.remaining() / OperationCost::CLAUDE_SONNET_FLYER_READ;
+        affordable.min(flyers::MAX_FLYER_READS_PER_RUN as u64) as u32
+    }
+
+    /// The region's active negative knowledge. Entries without an embedding
+    /// yet are embedded here and saved, so each is embedded once.
+    async fn load_negative_knowledge(&self) -> NegativeIndex {
+        let entries = match negative_knowledge::active(&self.pg_pool, &self.region.name).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(error = %e, "Failed to load negative knowledge (non-fatal)");
+                return NegativeIndex::default();
+            }
+        };
+        let mut index = NegativeIndex::new(entries);
+        let (ids, texts): (Vec<uuid::Uuid>, Vec<String>) = index.missing_embeddings().into_iter().unzip();
+        if !texts.is_empty() {
+            match self.embedder.embed_batch(texts).await {
+                Ok(embeddings) => {
+                    for (id, embedding) in ids.into_iter().zip(embeddings) {
+                        if let Err(e) = negative_knowledge::set_embedding(&self.pg_pool, id, &embedding).await {
+                            warn!(error = %e, entry_id = %id, "Failed to save negative knowledge embedding");
+                        }
+                        index.set_embedding(id, embedding);
+                    }
+                }
+                Err(e) => warn!(error = %e, "Failed to embed negative knowledge, matching titles only"),
+            }
+        }
+        if !index.is_empty() {
+            info!(entries = index.len(), "Negative knowledge loaded");
+        }
+        index
+    }
+
+    /// Re-read fundraiser pages behind Needs whose progress has gone stale.
+    pub async fn refresh_fundraisers(&self) {
+        crate::enrichment::fundraiser::refresh_fundraisers(
+            &*self.archive,
+            &self.writer as &dyn crate::pipeline::traits::SignalStore,
+            Utc::now(),
+        )
+        .await;
+    }
+
+    /// The region's discovery query ledger, attributing decisions to this run.
+    fn query_ledger(&self) -> QueryLedger {
+        QueryLedger::new(self.pg_pool.clone(), &self.region.name).for_run(&self.run_id)
+    }
+
+    /// Credit this run's accepted signals to the ledger's queries, then retire
+    /// query patterns that have proven unproductive.
+    async fn review_queries(&self, ctx: &RunContext) {
+        let ledger = self.query_ledger();
+        let accepted: HashMap<String, u32> = ctx
+            .source_signal_counts
+            .iter()
+            .filter(|(key, _)| !ctx.query_api_errors.contains(*key))
+            .map(|(key, n)| (key.clone(), *n))
+            .collect();
+        if let Err(e) = ledger.record_runs(&accepted).await {
+            warn!(error = %e, "Failed to update query ledger");
+            return;
+        }
+        match ledger.review().await {
+            Ok(retired) => {
+                for d in &retired {
+                    info!(pattern = d.pattern.as_str(), reason = d.reason.as_str(), "Retired query pattern");
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to review query patterns"),
+        }
+    }
+
     /// Promote any links collected during scraping into new SourceNodes.
-    /// Clears the collected_links buffer after processing.
+    /// Clears the collected_links buffer after processing. Without the
+    /// `link_mining` flag the links are dropped.
     async fn promote_collected_links(&self, ctx: &mut RunContext) {
+        if !self.flags.enable_link_mining {
+            ctx.collected_links.clear();
+        }
         if ctx.collected_links.is_empty() {
             return;
         }
@@ -377,6 +607,71 @@ impl<'a> ScrapePipeline<'a> {
             .collect();
         if !phase_a_social.is_empty() {
             run.phase.run_social(&phase_a_social, ctx, run_log).await;
+            self.budget.spend(run.phase.take_flyer_cost());
+        }
+
+        // Phase A newsletters: issues already delivered by the inbound webhook
+        let phase_a_newsletters: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Newsletter)
+                    && run.tension_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_a_newsletters.is_empty() {
+            run.phase.run_newsletters(&phase_a_newsletters, ctx, run_log).await;
+        }
+
+        // Phase A video channels: transcripts of recently published videos
+        let phase_a_videos: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Video)
+                    && run.tension_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_a_videos.is_empty() {
+            run.phase.run_videos(&phase_a_videos, ctx, run_log).await;
+        }
+
+        // Phase A podcasts: transcription is costly, so only what the budget allows
+        let phase_a_podcasts: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Podcast)
+                    && run.tension_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        let phase_a_podcasts = self.budget.admit(phase_a_podcasts, OperationCost::AUDIO_TRANSCRIPTION);
+        if !phase_a_podcasts.is_empty() {
+            run.phase.run_podcasts(&phase_a_podcasts, ctx, run_log).await;
+        }
+
+        // Phase A event listings: structured events, no LLM extraction
+        let phase_a_events: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Events)
+                    && run.tension_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_a_events.is_empty() {
+            run.phase.run_events(&phase_a_events, ctx, run_log).await;
+        }
+
+        // Phase A open data: 311 service requests, no LLM extraction
+        if !run.calendar_blocked && !run.open_data.is_empty() {
+            run.phase.run_open_data(&run.open_data, ctx, run_log).await;
+        }
+
+        // Phase A meeting agendas: council items announce decisions to come
+        if !run.calendar_blocked && !run.agendas.bodies.is_empty() {
+            run.phase.run_agendas(&run.agendas, ctx, run_log).await;
+        }
+
+        // Phase A service alerts: route cuts and school closures strain neighborhoods today
+        if !run.calendar_blocked && !run.service_alerts.is_empty() {
+            run.phase.run_service_alerts(&run.service_alerts, ctx, run_log).await;
         }
 
         self.promote_collected_links(ctx).await;
@@ -386,6 +681,7 @@ impl<'a> ScrapePipeline<'a> {
     /// Returns discovery stats and social topics discovered for later topic-based searching.
     pub(crate) async fn discover_mid_run_sources(&self) -> (SourceFinderStats, Vec<String>) {
         info!("=== Mid-Run Discovery ===");
+        let ledger = self.query_ledger();
         let discoverer = crate::discovery::source_finder::SourceFinder::new(
             &self.writer,
             &self.region.name,
@@ -393,9 +689,12 @@ impl<'a> ScrapePipeline<'a> {
             Some(&self.anthropic_api_key),
             self.budget,
         )
-        .with_embedder(&*self.embedder);
+        .with_embedder(&*self.embedder)
+        .with_archive(&self.archive)
+        .with_ledger(&ledger)
+        .with_region_memo(&self.region_memo);
         let (stats, social_topics) = discoverer.run().await;
-        if stats.actor_sources + stats.gap_sources > 0 {
+        if stats.actor_sources + stats.link_sources + stats.gap_sources > 0 {
             info!("{stats}");
         }
         (stats, social_topics)
@@ -430,7 +729,9 @@ impl<'a> ScrapePipeline<'a> {
             .iter()
             .filter(|s| {
                 run.response_phase_keys.contains(&s.canonical_key)
-                    || (s.last_scraped.is_none() && !run.scheduled_keys.contains(&s.canonical_key))
+                    || (!run.calendar_blocked
+                        && s.last_scraped.is_none()
+                        && !run.scheduled_keys.contains(&s.canonical_key))
             })
             .collect();
 
@@ -461,6 +762,56 @@ impl<'a> ScrapePipeline<'a> {
             .collect();
         if !phase_b_social.is_empty() {
             run.phase.run_social(&phase_b_social, ctx, run_log).await;
+            self.budget.spend(run.phase.take_flyer_cost());
+        }
+
+        // Phase B newsletters
+        let phase_b_newsletters: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Newsletter)
+                    && run.response_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_b_newsletters.is_empty() {
+            run.phase.run_newsletters(&phase_b_newsletters, ctx, run_log).await;
+        }
+
+        // Phase B video channels: transcripts of recently published videos
+        let phase_b_videos: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Video)
+                    && run.response_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_b_videos.is_empty() {
+            run.phase.run_videos(&phase_b_videos, ctx, run_log).await;
+        }
+
+        // Phase B podcasts: transcription is costly, so only what the budget allows
+        let phase_b_podcasts: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Podcast)
+                    && run.response_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        let phase_b_podcasts = self.budget.admit(phase_b_podcasts, OperationCost::AUDIO_TRANSCRIPTION);
+        if !phase_b_podcasts.is_empty() {
+            run.phase.run_podcasts(&phase_b_podcasts, ctx, run_log).await;
+        }
+
+        // Phase B event listings: structured events, no LLM extraction
+        let phase_b_events: Vec<&SourceNode> = run.scheduled_sources
+            .iter()
+            .filter(|s| {
+                matches!(scraping_strategy(s.value()), ScrapingStrategy::Events)
+                    && run.response_phase_keys.contains(&s.canonical_key)
+            })
+            .collect();
+        if !phase_b_events.is_empty() {
+            run.phase.run_events(&phase_b_events, ctx, run_log).await;
         }
 
         self.promote_collected_links(ctx).await;
@@ -471,19 +822,81 @@ impl<'a> ScrapePipeline<'a> {
         // Merge expansion-derived social topics with LLM-generated topics
         let mut all_social_topics = social_topics;
         all_social_topics.extend(ctx.social_expansion_topics.drain(..));
-        run.phase
-            .discover_from_topics(&all_social_topics, ctx, run_log)
-            .await;
-
-        self.promote_collected_links(ctx).await;
+        if self.flags.enable_topic_discovery {
+            run.phase
+                .discover_from_topics(&all_social_topics, ctx, run_log)
+                .await;
+            self.promote_collected_links(ctx).await;
+        }
 
         Ok(())
     }
 
+    /// Run the region's requested backfills the budget can pay for. Each is a
+    /// scrape of one source with its own scout run id, reading deep into the
+    /// source's history; its signals are then marked historical. Backfills
+    /// the budget can't cover wait for a later run.
+    pub(crate) async fn run_backfills(&self, run: &ScheduledRun, run_log: &mut RunLog) {
+        let requested = match self.writer.requested_backfills(&rootsignal_common::slugify(&self.region.name)).await {
+            Ok(requested) => requested,
+            Err(e) => {
+                warn!(error = %e, "Failed to load requested backfills, skipping");
+                return;
+            }
+        };
+        if requested.is_empty() || run.calendar_blocked {
+            return;
+        }
+        info!(requested = requested.len(), "=== Backfills ===");
+
+        for backfill in requested {
+            let key = backfill.canonical_key.as_str();
+            let Some(source) = run.all_sources.iter().find(|s| s.canonical_key == key) else {
+                self.finish_backfill(key, "", Some("source is not active in this region"), run_log).await;
+                continue;
+            };
+            let strategy = scraping_strategy(source.value());
+            let Some(cost) = OperationCost::backfill(&strategy, backfill.depth.max_items) else {
+                self.finish_backfill(key, "", Some("source has no history to backfill"), run_log).await;
+                continue;
+            };
+            if !self.budget.has_budget(cost) {
+                info!(canonical_key = key, cost, "Backfill deferred, budget can't cover it this run");
+                continue;
+            }
+            self.budget.spend(cost);
+
+            let backfill_run_id = uuid::Uuid::new_v4().to_string();
+            let phase = run.phase.for_backfill(backfill_run_id.clone(), backfill.depth);
+            let mut ctx = RunContext::new(&run.all_sources);
+            let sources = [source];
+            match strategy {
+                ScrapingStrategy::Social(_) => phase.run_social(&sources, &mut ctx, run_log).await,
+                ScrapingStrategy::Newsletter => phase.run_newsletters(&sources, &mut ctx, run_log).await,
+                ScrapingStrategy::Video => phase.run_videos(&sources, &mut ctx, run_log).await,
+                ScrapingStrategy::Podcast => phase.run_podcasts(&sources, &mut ctx, run_log).await,
+                _ => phase.run_web(&sources, &mut ctx, run_log).await,
+            }
+            self.finish_backfill(key, &backfill_run_id, None, run_log).await;
+        }
+    }
+
+    async fn finish_backfill(&self, canonical_key: &str, backfill_run_id: &str, error: Option<&str>, run_log: &mut RunLog) {
+        match self.writer.complete_backfill(canonical_key, backfill_run_id, error).await {
+            Ok(signals_stored) => run_log.log(EventKind::Backfill {
+                canonical_key: canonical_key.to_string(),
+                signals_stored,
+                success: error.is_none(),
+            }),
+            Err(e) => warn!(canonical_key, error = %e, "Failed to record backfill outcome"),
+        }
+    }
+
     /// Record source metrics, update weights/cadence, deactivate dead sources.
     pub(crate) async fn update_source_metrics(&self, run: &ScheduledRun, ctx: &RunContext) {
         let metrics = Metrics::new(&self.writer, &self.region.name);
         metrics.update(&run.all_sources, ctx, Utc::now()).await;
+        self.review_queries(ctx).await;
 
         // Log budget status before compute-heavy phases
         self.budget.log_status();
@@ -497,12 +910,16 @@ impl<'a> ScrapePipeline<'a> {
         run_log: &mut RunLog,
     ) -> Result<()> {
         // Signal Expansion — create sources from implied queries
-        let expansion = Expansion::new(
-            &self.writer,
-            &*self.embedder,
-            &self.region.name,
-        );
-        expansion.run(ctx, run_log).await;
+        let ledger = self.query_ledger();
+        if self.flags.enable_signal_expansion {
+            let expansion = Expansion::new(
+                &self.writer,
+                &*self.embedder,
+                &self.region.name,
+            )
+            .with_ledger(&ledger);
+            expansion.run(ctx, run_log).await;
+        }
 
         check_cancelled_flag(&self.cancelled)?;
 
@@ -514,12 +931,15 @@ impl<'a> ScrapePipeline<'a> {
             Some(&self.anthropic_api_key),
             self.budget,
         )
-        .with_embedder(&*self.embedder);
+        .with_embedder(&*self.embedder)
+        .with_archive(&self.archive)
+        .with_ledger(&ledger)
+        .with_region_memo(&self.region_memo);
         let (end_discovery_stats, end_social_topics) = end_discoverer.run().await;
-        if end_discovery_stats.actor_sources + end_discovery_stats.gap_sources > 0 {
+        if end_discovery_stats.actor_sources + end_discovery_stats.link_sources + end_discovery_stats.gap_sources > 0 {
             info!("{end_discovery_stats}");
         }
-        if !end_social_topics.is_empty() {
+        if !end_social_topics.is_empty() && self.flags.enable_topic_discovery {
             info!(count = end_social_topics.len(), "Consuming end-of-run social topics");
             run.phase.discover_from_topics(&end_social_topics, ctx, run_log).await;
             self.promote_collected_links(ctx).await;
@@ -528,8 +948,33 @@ impl<'a> ScrapePipeline<'a> {
         Ok(())
     }
 
-    /// Save run log and return final stats.
+    /// Fold what this run found into the region memo for the next run.
+    async fn update_region_memo(&self, run_log: &RunLog) {
+        let store = RegionMemoStore::new(self.pg_pool.clone(), &self.region.name);
+        let updater = MemoUpdater::new(&self.writer, &self.anthropic_api_key, &self.region.name, self.budget);
+        if let Err(e) = updater.update(&store, &self.region_memo, run_log).await {
+            warn!(error = %e, "Failed to update region memo (non-fatal)");
+        }
+    }
+
+    /// Audit a sample of the run's new signals, update the region memo,
+    /// count negative knowledge hits, save the run log and its failure tally and
+    /// return final stats.
     pub(crate) async fn finalize(&self, ctx: RunContext, mut run_log: RunLog) -> ScoutStats {
+        Auditor::new(
+            &self.writer,
+            &self.pg_pool,
+            &self.anthropic_api_key,
+            self.budget,
+            &self.region.name,
+            &self.run_id,
+        )
+        .run(&ctx.audit_sample, &mut run_log)
+        .await;
+        self.update_region_memo(&run_log).await;
+        if let Err(e) = negative_knowledge::record_hits(&self.pg_pool, &ctx.negative_hits).await {
+            warn!(error = %e, "Failed to record negative knowledge hits (non-fatal)");
+        }
         run_log.log(EventKind::BudgetCheckpoint {
             spent_cents: self.budget.total_spent(),
             remaining_cents: self.budget.remaining(),
@@ -537,6 +982,10 @@ impl<'a> ScrapePipeline<'a> {
         if let Err(e) = run_log.save_to_db(&self.pg_pool, &ctx.stats).await {
             warn!(error = %e, "Failed to save scout run log");
         }
+        let tally = run_log.scrape_tally(&ctx.stats);
+        if let Err(e) = error_budget::record_run(&self.pg_pool, &self.run_id, &self.region.name, &[tally]).await {
+            warn!(error = %e, "Failed to record scrape failure tally");
+        }
 
         info!("{}", ctx.stats);
         ctx.stats
@@ -544,7 +993,7 @@ impl<'a> ScrapePipeline<'a> {
 
     /// Run all phases in sequence.
     pub async fn run_all(self) -> Result<ScoutStats> {
-        let mut run_log = RunLog::new(self.run_id.clone(), self.region.name.clone());
+        let mut run_log = RunLog::new(self.run_id.clone(), self.region.name.clone()).with_flags(self.flags);
 
         self.reap_expired_signals(&mut run_log).await;
 
@@ -558,6 +1007,9 @@ impl<'a> ScrapePipeline<'a> {
 
         self.scrape_response_sources(&run, social_topics, &mut ctx, &mut run_log).await?;
 
+        self.run_backfills(&run, &mut run_log).await;
+        check_cancelled_flag(&self.cancelled)?;
+
         // Delete consumed pins now that their sources have been scraped
         if !run.consumed_pin_ids.is_empty() {
             match self.writer.delete_pins(&run.consumed_pin_ids).await {
@@ -569,6 +1021,8 @@ impl<'a> ScrapePipeline<'a> {
         // Enrich actor locations from signal mode before metrics/expansion
         run.phase.enrich_actors().await;
 
+        self.refresh_fundraisers().await;
+
         self.update_source_metrics(&run, &ctx).await;
         check_cancelled_flag(&self.cancelled)?;
 
@@ -0,0 +1,376 @@
+//! Shadow-mode extraction for candidate prompts.
+//!
+//! When a region has a shadow candidate in the prompt registry, the run's
+//! extractor is wrapped in a [`ShadowExtractor`]: on sampled URLs it also runs
+//! the candidate and records both outputs to the shadow store. Only the
+//! production result flows on to the graph. [`evaluate`] later has an LLM judge
+//! the samples and saves the comparison report that gates promotion.
+
+use std::sync::Arc;
+
+use ai_client::claude::Claude;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use rootsignal_common::extraction_prompts::{
+    self, NewSample, PromptStatus, ShadowReport, ShadowSample, ShadowSignal,
+};
+use rootsignal_common::{ScoutScope, SourceRole};
+
+use crate::memory::memo::RegionMemo;
+use crate::pipeline::extraction::ExtractionRegistry;
+use crate::pipeline::extractor::{ExtractionResult, Extractor, SignalExtractor};
+
+const SONNET_MODEL: &str = "claude-sonnet-4-5-20250929";
+/// Samples judged per evaluation; the rest wait for the next one.
+const MAX_JUDGED_PER_EVALUATION: usize = 60;
+
+/// Where shadow samples go.
+#[async_trait]
+pub trait ShadowSink: Send + Sync {
+    async fn record(&self, sample: NewSample<'_>) -> Result<()>;
+}
+
+pub struct PgShadowSink {
+    pool: PgPool,
+}
+
+impl PgShadowSink {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShadowSink for PgShadowSink {
+    async fn record(&self, sample: NewSample<'_>) -> Result<()> {
+        extraction_prompts::record_sample(&self.pool, &sample).await?;
+        Ok(())
+    }
+}
+
+/// Runs a candidate extractor beside production on sampled URLs.
+pub struct ShadowExtractor {
+    production: Arc<dyn SignalExtractor>,
+    candidate: Arc<dyn SignalExtractor>,
+    prompt_id: Uuid,
+    sample_rate: f64,
+    run_id: String,
+    region: String,
+    sink: Arc<dyn ShadowSink>,
+}
+
+impl ShadowExtractor {
+    pub fn new(
+        production: Arc<dyn SignalExtractor>,
+        candidate: Arc<dyn SignalExtractor>,
+        prompt_id: Uuid,
+        sample_rate: f64,
+        sink: Arc<dyn ShadowSink>,
+    ) -> Self {
+        Self {
+            production,
+            candidate,
+            prompt_id,
+            sample_rate,
+            run_id: String::new(),
+            region: String::new(),
+            sink,
+        }
+    }
+
+    /// Tag recorded samples with the run and region they came from.
+    pub fn with_run(mut self, run_id: &str, region: &str) -> Self {
+        self.run_id = run_id.to_string();
+        self.region = region.to_string();
+        self
+    }
+}
+
+#[async_trait]
+impl SignalExtractor for ShadowExtractor {
+    async fn extract(&self, content: &str, source_url: &str) -> Result<ExtractionResult> {
+        self.extract_for_role(content, source_url, SourceRole::Mixed).await
+    }
+
+    async fn extract_for_role(&self, content: &str, source_url: &str, role: SourceRole) -> Result<ExtractionResult> {
+        if !extraction_prompts::is_sampled(source_url, self.sample_rate) {
+            return self.production.extract_for_role(content, source_url, role).await;
+        }
+
+        let (production, candidate) = tokio::join!(
+            self.production.extract_for_role(content, source_url, role),
+            self.candidate.extract_for_role(content, source_url, role),
+        );
+        let production = production?;
+        match candidate {
+            Ok(candidate) => {
+                let sample = NewSample {
+                    prompt_id: self.prompt_id,
+                    run_id: &self.run_id,
+                    region: &self.region,
+                    source_url,
+                    content,
+                    production: &shadow_signals(&production),
+                    candidate: &shadow_signals(&candidate),
+                };
+                if let Err(e) = self.sink.record(sample).await {
+                    warn!(url = source_url, error = %e, "Failed to record shadow sample");
+                }
+            }
+            Err(e) => warn!(url = source_url, error = %e, "Shadow candidate extraction failed"),
+        }
+        Ok(production)
+    }
+}
+
+fn shadow_signals(result: &ExtractionResult) -> Vec<ShadowSignal> {
+    result
+        .nodes
+        .iter()
+        .filter_map(|node| {
+            let meta = node.meta()?;
+            Some(ShadowSignal {
+                signal_type: node.node_type().to_string().to_lowercase(),
+                title: meta.title.clone(),
+                summary: meta.summary.clone(),
+            })
+        })
+        .collect()
+}
+
+/// The extractor for a run in `scope`: the registry's live prompt if there is
+/// one, else the built-in prompt, shadowed by any candidate under evaluation.
+/// If the registry can't be read, the run uses the built-in prompt unshadowed.
+pub async fn region_extractor(
+    pool: &PgPool,
+    anthropic_api_key: &str,
+    scope: &ScoutScope,
+    memo: &RegionMemo,
+    run_id: &str,
+) -> Arc<dyn SignalExtractor> {
+    let prompt_extractor = |template: &str| {
+        Extractor::with_template(anthropic_api_key, template, &scope.name, &[], ExtractionRegistry::builtin())
+            .with_region_memo(memo, &scope.name)
+    };
+
+    let live = match extraction_prompts::active_for(pool, &scope.name, PromptStatus::Live).await {
+        Ok(live) => live,
+        Err(e) => {
+            warn!(region = scope.name.as_str(), error = %e, "Failed to load live extraction prompt, using built-in");
+            None
+        }
+    };
+    let production: Arc<dyn SignalExtractor> = match &live {
+        Some(prompt) => {
+            info!(region = scope.name.as_str(), prompt = prompt.name.as_str(), "Using registry extraction prompt");
+            Arc::new(prompt_extractor(&prompt.template))
+        }
+        None => Arc::new(
+            Extractor::new(anthropic_api_key, &scope.name)
+                .with_region_memo(memo, &scope.name),
+        ),
+    };
+
+    let shadow = match extraction_prompts::active_for(pool, &scope.name, PromptStatus::Shadow).await {
+        Ok(shadow) => shadow,
+        Err(e) => {
+            warn!(region = scope.name.as_str(), error = %e, "Failed to load shadow extraction prompt");
+            None
+        }
+    };
+    match shadow {
+        Some(candidate) => {
+            info!(
+                region = scope.name.as_str(),
+                prompt = candidate.name.as_str(),
+                sample_rate = candidate.sample_rate,
+                "Shadowing candidate extraction prompt"
+            );
+            Arc::new(
+                ShadowExtractor::new(
+                    production,
+                    Arc::new(prompt_extractor(&candidate.template)),
+                    candidate.id,
+                    candidate.sample_rate,
+                    Arc::new(PgShadowSink::new(pool.clone())),
+                )
+                .with_run(run_id, &scope.name),
+            )
+        }
+        None => production,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Judging
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct JudgeScores {
+    /// 1 (poor) to 5 (excellent) for extraction A.
+    a: u8,
+    /// 1 (poor) to 5 (excellent) for extraction B.
+    b: u8,
+}
+
+const JUDGE_SYSTEM: &str = "You compare two extractions of community signals from the same page. \
+A good extraction captures every real, specific, actionable signal on the page with the right type, \
+an accurate title and a faithful summary, and invents nothing. Score each extraction from 1 (poor) \
+to 5 (excellent), judging only against the page content.";
+
+fn judge_prompt(sample: &ShadowSample, a: &[ShadowSignal], b: &[ShadowSignal]) -> String {
+    let list = |signals: &[ShadowSignal]| {
+        if signals.is_empty() {
+            return "(no signals)".to_string();
+        }
+        signals
+            .iter()
+            .map(|s| format!("- [{}] {} — {}", s.signal_type, s.title, s.summary))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "Source: {}\n\n## Page content\n{}\n\n## Extraction A\n{}\n\n## Extraction B\n{}",
+        sample.source_url,
+        sample.content,
+        list(a),
+        list(b)
+    )
+}
+
+/// Judge-scored (production, candidate) for one sample. Which extraction is
+/// shown first alternates with `flip` so the judge's position bias evens out.
+async fn judge(claude: &Claude, sample: &ShadowSample, flip: bool) -> Result<(f64, f64)> {
+    let (a, b) = if flip {
+        (&sample.candidate, &sample.production)
+    } else {
+        (&sample.production, &sample.candidate)
+    };
+    let scores: JudgeScores = claude
+        .extract(SONNET_MODEL, JUDGE_SYSTEM, &judge_prompt(sample, a, b))
+        .await?;
+    let (a, b) = (scores.a.clamp(1, 5) as f64, scores.b.clamp(1, 5) as f64);
+    Ok(if flip { (b, a) } else { (a, b) })
+}
+
+/// Judge a candidate's unjudged samples, then build and save its report.
+pub async fn evaluate(pool: &PgPool, anthropic_api_key: &str, prompt_id: Uuid) -> Result<ShadowReport> {
+    let mut samples = extraction_prompts::samples(pool, prompt_id).await?;
+    let claude = Claude::new(anthropic_api_key, SONNET_MODEL);
+
+    let mut judged = 0;
+    for (i, sample) in samples.iter_mut().enumerate() {
+        if sample.candidate_score.is_some() || judged >= MAX_JUDGED_PER_EVALUATION {
+            continue;
+        }
+        match judge(&claude, sample, i % 2 == 1).await {
+            Ok((production, candidate)) => {
+                extraction_prompts::set_scores(pool, sample.id, production, candidate).await?;
+                sample.production_score = Some(production);
+                sample.candidate_score = Some(candidate);
+                judged += 1;
+            }
+            Err(e) => warn!(sample = %sample.id, error = %e, "Failed to judge shadow sample"),
+        }
+    }
+
+    let report = ShadowReport::build(&samples, Utc::now());
+    extraction_prompts::save_report(pool, prompt_id, &report).await?;
+    info!(
+        prompt = %prompt_id,
+        samples = report.samples,
+        newly_judged = judged,
+        yield_ratio = ?report.yield_ratio,
+        agreement = report.agreement,
+        "Shadow report saved"
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::testing::{aid, need, MockExtractor};
+
+    /// URL, production signals, candidate signals.
+    type Recorded = (String, Vec<ShadowSignal>, Vec<ShadowSignal>);
+
+    #[derive(Default)]
+    struct MemorySink {
+        samples: Mutex<Vec<Recorded>>,
+    }
+
+    #[async_trait]
+    impl ShadowSink for MemorySink {
+        async fn record(&self, sample: NewSample<'_>) -> Result<()> {
+            self.samples.lock().unwrap().push((
+                sample.source_url.to_string(),
+                sample.production.to_vec(),
+                sample.candidate.to_vec(),
+            ));
+            Ok(())
+        }
+    }
+
+    fn result(nodes: Vec<rootsignal_common::Node>) -> ExtractionResult {
+        ExtractionResult {
+            nodes,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn sampled_content_records_both_outputs_but_returns_production() {
+        let production = MockExtractor::new().with_default(result(vec![need("Winter coats needed")]));
+        let candidate = MockExtractor::new().with_default(result(vec![
+            need("Winter coats needed"),
+            aid("Free coat drive"),
+        ]));
+        let sink = Arc::new(MemorySink::default());
+        let shadow = ShadowExtractor::new(Arc::new(production), Arc::new(candidate), Uuid::new_v4(), 1.0, sink.clone());
+
+        let out = shadow.extract("page", "https://example.org/coats").await.unwrap();
+
+        assert_eq!(out.nodes.len(), 1);
+        let samples = sink.samples.lock().unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].1.len(), 1);
+        assert_eq!(samples[0].2.len(), 2);
+        assert_eq!(samples[0].2[1].signal_type, "aid");
+    }
+
+    #[tokio::test]
+    async fn failing_candidate_never_affects_production() {
+        let production = MockExtractor::new().with_default(result(vec![need("Winter coats needed")]));
+        let candidate = MockExtractor::new();
+        let sink = Arc::new(MemorySink::default());
+        let shadow = ShadowExtractor::new(Arc::new(production), Arc::new(candidate), Uuid::new_v4(), 1.0, sink.clone());
+
+        let out = shadow.extract("page", "https://example.org/coats").await.unwrap();
+
+        assert_eq!(out.nodes.len(), 1);
+        assert!(sink.samples.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unsampled_content_skips_the_candidate() {
+        let production = MockExtractor::new().with_default(result(vec![need("Winter coats needed")]));
+        let candidate = MockExtractor::new().panic_on("https://example.org/coats");
+        let sink = Arc::new(MemorySink::default());
+        let shadow = ShadowExtractor::new(Arc::new(production), Arc::new(candidate), Uuid::new_v4(), 1e-9, sink.clone());
+
+        let out = shadow.extract("page", "https://example.org/coats").await.unwrap();
+
+        assert_eq!(out.nodes.len(), 1);
+        assert!(sink.samples.lock().unwrap().is_empty());
+    }
+}
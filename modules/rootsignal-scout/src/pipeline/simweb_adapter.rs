@@ -7,7 +7,7 @@ use chrono::Utc;
 use uuid::Uuid;
 
 use rootsignal_common::types::{
-    ArchivedFeed, ArchivedPage, ArchivedSearchResults, Post, SearchResult,
+    ArchivedFeed, ArchivedPage, ArchivedSearchResults, ListedEvent, LongVideo, PodcastEpisode, Post, PostComment, SearchResult,
 };
 use simweb::SimulatedWeb;
 
@@ -36,6 +36,7 @@ impl ContentFetcher for SimulatedWeb {
             markdown: sim.content,
             title: None,
             links,
+            previous_markdown: None,
         })
     }
 
@@ -67,11 +68,32 @@ impl ContentFetcher for SimulatedWeb {
                 hashtags: Vec::new(),
                 media_type: None,
                 platform_id: None,
+                engagement_velocity: None,
                 attachments: Vec::new(),
             })
             .collect())
     }
 
+    async fn comments(&self, _post_url: &str, _limit: u32) -> Result<Vec<PostComment>> {
+        Err(anyhow!("SimulatedWeb does not support comment threads"))
+    }
+
+    async fn media(&self, _url: &str) -> Result<Vec<u8>> {
+        Err(anyhow!("SimulatedWeb does not serve media files"))
+    }
+
+    async fn videos(&self, _url: &str, _limit: u32) -> Result<Vec<LongVideo>> {
+        Err(anyhow!("SimulatedWeb does not support video channels"))
+    }
+
+    async fn episodes(&self, _url: &str, _limit: u32) -> Result<Vec<PodcastEpisode>> {
+        Err(anyhow!("SimulatedWeb does not support podcasts"))
+    }
+
+    async fn events(&self, _url: &str, _limit: u32) -> Result<Vec<ListedEvent>> {
+        Err(anyhow!("SimulatedWeb does not support event platforms"))
+    }
+
     async fn search(&self, query: &str) -> Result<ArchivedSearchResults> {
         let sim_results = self.search(query, 10).await?;
         Ok(to_archived_search(query, &sim_results))
@@ -106,6 +128,7 @@ impl ContentFetcher for SimulatedWeb {
                 hashtags: Vec::new(),
                 media_type: None,
                 platform_id: None,
+                engagement_velocity: None,
                 attachments: Vec::new(),
             })
             .collect())
@@ -143,6 +166,7 @@ fn to_archived_search(query: &str, sim_results: &[simweb::SimSearchResult]) -> A
                 snippet: r.snippet.clone(),
             })
             .collect(),
+        provider: Some("simweb".to_string()),
     }
 }
 
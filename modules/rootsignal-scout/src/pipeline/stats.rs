@@ -1,11 +1,55 @@
+use rootsignal_common::ErrorClass;
+
+use crate::pipeline::work_queue::{Lane, QueueMetrics};
+
+/// Why a single item's extraction was abandoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractionFailureKind {
+    /// The extractor returned an error (LLM refusal, malformed output, API failure).
+    Error,
+    /// The extractor did not finish within the per-item timeout.
+    Timeout,
+    /// The extractor panicked.
+    Panic,
+}
+
+impl ExtractionFailureKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Timeout => "timeout",
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// A failed extraction, recorded instead of aborting the batch.
+#[derive(Debug, Clone)]
+pub struct ExtractionFailure {
+    pub url: String,
+    pub kind: ExtractionFailureKind,
+    pub class: ErrorClass,
+    pub message: String,
+}
+
+impl std::fmt::Display for ExtractionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}", self.kind.as_str(), self.class, self.message)
+    }
+}
+
 /// Stats from a scout run.
 #[derive(Debug, Default)]
 pub struct ScoutStats {
     pub urls_scraped: u32,
     pub urls_unchanged: u32,
+    /// Re-scraped pages where only the changes were extracted.
+    pub urls_diff_extracted: u32,
     pub urls_failed: u32,
     pub signals_extracted: u32,
     pub signals_deduplicated: u32,
+    /// Matched negative knowledge (debunked, spam, known duplicate) and dropped.
+    pub signals_suppressed: u32,
     pub signals_stored: u32,
     pub by_type: [u32; 5], // Gathering, Aid, Need, Notice, Tension
     pub fresh_7d: u32,
@@ -18,6 +62,8 @@ pub struct ScoutStats {
     pub expansion_sources_created: u32,
     pub expansion_deferred_expanded: u32,
     pub expansion_social_topics_queued: u32,
+    pub queue_metrics: QueueMetrics,
+    pub extraction_failures: Vec<ExtractionFailure>,
 }
 
 impl std::fmt::Display for ScoutStats {
@@ -25,12 +71,14 @@ impl std::fmt::Display for ScoutStats {
         writeln!(f, "\n=== Scout Run Complete ===")?;
         writeln!(f, "URLs scraped:       {}", self.urls_scraped)?;
         writeln!(f, "URLs unchanged:     {}", self.urls_unchanged)?;
+        writeln!(f, "URLs diff-extracted:{}", self.urls_diff_extracted)?;
         writeln!(f, "URLs failed:        {}", self.urls_failed)?;
         writeln!(f, "Social media posts: {}", self.social_media_posts)?;
         writeln!(f, "Discovery posts:    {}", self.discovery_posts_found)?;
         writeln!(f, "Accounts discovered:{}", self.discovery_accounts_found)?;
         writeln!(f, "Signals extracted:  {}", self.signals_extracted)?;
         writeln!(f, "Signals deduped:    {}", self.signals_deduplicated)?;
+        writeln!(f, "Signals suppressed: {}", self.signals_suppressed)?;
         writeln!(f, "Signals stored:     {}", self.signals_stored)?;
         writeln!(f, "\nBy type:")?;
         writeln!(f, "  Gathering: {}", self.by_type[0])?;
@@ -79,6 +127,34 @@ impl std::fmt::Display for ScoutStats {
                 )?;
             }
         }
+        if !self.extraction_failures.is_empty() {
+            let count = |kind| self.extraction_failures.iter().filter(|e| e.kind == kind).count();
+            writeln!(
+                f,
+                "\nExtraction failures: {} ({} errors, {} timeouts, {} panics)",
+                self.extraction_failures.len(),
+                count(ExtractionFailureKind::Error),
+                count(ExtractionFailureKind::Timeout),
+                count(ExtractionFailureKind::Panic)
+            )?;
+        }
+        if !self.queue_metrics.lanes.is_empty() {
+            writeln!(f, "\nWork queue:")?;
+            for lane in [Lane::Search, Lane::Web, Lane::Social] {
+                if let Some(m) = self.queue_metrics.lane(lane) {
+                    writeln!(
+                        f,
+                        "  {:<7} {} items, peak pending {}, {} backpressure waits, avg wait {}ms, avg extract {}ms",
+                        lane.as_str(),
+                        m.items,
+                        m.peak_pending_extractions,
+                        m.backpressure_waits,
+                        m.avg_queue_wait_ms(),
+                        m.avg_extract_ms()
+                    )?;
+                }
+            }
+        }
         Ok(())
     }
 }
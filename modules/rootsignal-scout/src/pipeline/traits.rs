@@ -14,10 +14,10 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use rootsignal_common::types::{
-    ActorNode, ArchivedFeed, ArchivedPage, ArchivedSearchResults, EvidenceNode, Node, NodeType,
-    Post, SourceNode,
+    ActorNode, ArchivedFeed, ArchivedPage, ArchivedSearchResults, EvidenceNode, Fundraiser, ListedEvent,
+    LongVideo, Node, NodeType, PodcastEpisode, Post, PostComment, SourceNode,
 };
-use rootsignal_common::EntityMappingOwned;
+use rootsignal_common::{Decision, EntityLink, EntityMappingOwned, EvidenceRecheck, EvidenceVerification};
 use rootsignal_graph::DuplicateMatch;
 
 // ---------------------------------------------------------------------------
@@ -35,7 +35,22 @@ pub trait ContentFetcher: Send + Sync {
     /// Fetch social media posts for an account.
     async fn posts(&self, identifier: &str, limit: u32) -> Result<Vec<Post>>;
 
-    /// Run a web search query (Serper).
+    /// Fetch top-level comments on one social media post.
+    async fn comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>>;
+
+    /// Download a post's media file (e.g. an attached image).
+    async fn media(&self, url: &str) -> Result<Vec<u8>>;
+
+    /// Fetch recent videos, with timed transcripts, from a video channel.
+    async fn videos(&self, url: &str, limit: u32) -> Result<Vec<LongVideo>>;
+
+    /// Fetch recent episodes, with transcripts, from a podcast feed.
+    async fn episodes(&self, url: &str, limit: u32) -> Result<Vec<PodcastEpisode>>;
+
+    /// Fetch upcoming events from an event platform listing.
+    async fn events(&self, url: &str, limit: u32) -> Result<Vec<ListedEvent>>;
+
+    /// Run a web search query (Serper, with fallback providers).
     async fn search(&self, query: &str) -> Result<ArchivedSearchResults>;
 
     /// Search social platforms by topic keywords. Absorbs the
@@ -70,6 +85,26 @@ impl ContentFetcher for rootsignal_archive::Archive {
         Ok(self.posts(identifier, limit).await?)
     }
 
+    async fn comments(&self, post_url: &str, limit: u32) -> Result<Vec<PostComment>> {
+        Ok(self.comments(post_url, limit).await?)
+    }
+
+    async fn media(&self, url: &str) -> Result<Vec<u8>> {
+        Ok(self.media(url).await?)
+    }
+
+    async fn videos(&self, url: &str, limit: u32) -> Result<Vec<LongVideo>> {
+        Ok(self.videos(url, limit).await?)
+    }
+
+    async fn episodes(&self, url: &str, limit: u32) -> Result<Vec<PodcastEpisode>> {
+        Ok(self.episodes(url, limit).await?)
+    }
+
+    async fn events(&self, url: &str, limit: u32) -> Result<Vec<ListedEvent>> {
+        Ok(self.events(url, limit).await?)
+    }
+
     async fn search(&self, query: &str) -> Result<ArchivedSearchResults> {
         Ok(self.search(query).await?)
     }
@@ -142,6 +177,9 @@ pub trait SignalStore: Send + Sync {
         entity_mappings: &[EntityMappingOwned],
     ) -> Result<()>;
 
+    /// Record an automated decision about a signal (merge, boost, ...).
+    async fn record_decision(&self, decision: &Decision) -> Result<()>;
+
     // --- Dedup queries ---
 
     /// Return titles of existing signals from a given source URL.
@@ -188,6 +226,9 @@ pub trait SignalStore: Send + Sync {
     /// Link a signal to its source (PRODUCED_BY edge).
     async fn link_signal_to_source(&self, signal_id: Uuid, source_id: Uuid) -> Result<()>;
 
+    /// Link a signal to a registry entity (MENTIONS or LOCATED_IN edge).
+    async fn link_signal_to_entity(&self, signal_id: Uuid, entity_id: &str, link: EntityLink) -> Result<()>;
+
     /// Find an actor by entity_id (URL-based identity).
     async fn find_actor_by_entity_id(&self, entity_id: &str) -> Result<Option<Uuid>>;
 
@@ -240,6 +281,15 @@ pub trait SignalStore: Send + Sync {
     /// Batch-create Tag nodes and TAGGED edges for a signal.
     async fn batch_tag_signals(&self, signal_id: Uuid, tag_slugs: &[String]) -> Result<()>;
 
+    /// Signals extracted since `since` whose title or summary mentions
+    /// `phrase` as whole words, newest first.
+    async fn find_signals_mentioning(
+        &self,
+        phrase: &str,
+        since: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>>;
+
     // --- Actor location enrichment ---
 
     /// Get signal location observations for an actor (authored signals with about_location).
@@ -260,6 +310,43 @@ pub trait SignalStore: Send + Sync {
 
     /// List all actors with their linked sources.
     async fn list_all_actors(&self) -> Result<Vec<(ActorNode, Vec<SourceNode>)>>;
+
+    // --- Fundraiser tracking ---
+
+    /// Needs backed by a fundraiser not checked since `checked_before`.
+    /// Returns (need_id, fundraiser_url) tuples, least recently checked first.
+    async fn fundraisers_due_for_refresh(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<(Uuid, String)>>;
+
+    /// Record a fundraiser check; `progress` is `None` when the page couldn't be read.
+    async fn update_fundraiser(
+        &self,
+        need_id: Uuid,
+        checked_at: DateTime<Utc>,
+        progress: Option<&Fundraiser>,
+    ) -> Result<()>;
+
+    // --- Evidence freshness ---
+
+    /// Evidence whose page hasn't been checked since `checked_before`,
+    /// least recently checked first.
+    async fn evidence_due_for_recheck(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<EvidenceRecheck>>;
+
+    /// Record an evidence re-check and rescale the supported signal's
+    /// confidence; `read` is false when the page couldn't be fetched.
+    async fn record_evidence_check(
+        &self,
+        evidence: &EvidenceRecheck,
+        verification: &EvidenceVerification,
+        read: bool,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -309,6 +396,10 @@ impl SignalStore for rootsignal_graph::GraphWriter {
         Ok(self.corroborate(id, node_type, now, entity_mappings).await?)
     }
 
+    async fn record_decision(&self, decision: &Decision) -> Result<()> {
+        Ok(self.record_decision(decision).await?)
+    }
+
     async fn existing_titles_for_url(&self, url: &str) -> Result<Vec<String>> {
         Ok(self.existing_titles_for_url(url).await?)
     }
@@ -360,6 +451,10 @@ impl SignalStore for rootsignal_graph::GraphWriter {
         Ok(self.link_signal_to_source(signal_id, source_id).await?)
     }
 
+    async fn link_signal_to_entity(&self, signal_id: Uuid, entity_id: &str, link: EntityLink) -> Result<()> {
+        Ok(self.link_signal_to_entity(signal_id, entity_id, link).await?)
+    }
+
     async fn find_actor_by_entity_id(&self, entity_id: &str) -> Result<Option<Uuid>> {
         Ok(self.find_actor_by_entity_id(entity_id).await?)
     }
@@ -424,6 +519,15 @@ impl SignalStore for rootsignal_graph::GraphWriter {
         Ok(self.batch_tag_signals(signal_id, tag_slugs).await?)
     }
 
+    async fn find_signals_mentioning(
+        &self,
+        phrase: &str,
+        since: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>> {
+        Ok(self.find_signals_mentioning(phrase, since, limit).await?)
+    }
+
     async fn get_signals_for_actor(
         &self,
         actor_id: Uuid,
@@ -444,4 +548,38 @@ impl SignalStore for rootsignal_graph::GraphWriter {
     async fn list_all_actors(&self) -> Result<Vec<(ActorNode, Vec<SourceNode>)>> {
         Ok(self.list_all_actors().await?)
     }
+
+    async fn fundraisers_due_for_refresh(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<(Uuid, String)>> {
+        Ok(self.fundraisers_due_for_refresh(checked_before, limit).await?)
+    }
+
+    async fn update_fundraiser(
+        &self,
+        need_id: Uuid,
+        checked_at: DateTime<Utc>,
+        progress: Option<&Fundraiser>,
+    ) -> Result<()> {
+        Ok(self.update_fundraiser(need_id, checked_at, progress).await?)
+    }
+
+    async fn evidence_due_for_recheck(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<EvidenceRecheck>> {
+        Ok(self.evidence_due_for_recheck(checked_before, limit).await?)
+    }
+
+    async fn record_evidence_check(
+        &self,
+        evidence: &EvidenceRecheck,
+        verification: &EvidenceVerification,
+        read: bool,
+    ) -> Result<()> {
+        Ok(self.record_evidence_check(evidence, verification, read).await?)
+    }
 }
@@ -0,0 +1,461 @@
+//! Bounded, priority-ordered work queue for the scrape pipeline.
+//!
+//! Items flow through two stages: **fetch** (network-bound: page, search,
+//! social API) and **extract** (LLM-bound). Each lane (web, social, search)
+//! has its own fetch concurrency limit. Fetched items wait in a bounded
+//! hand-off buffer for extraction; when extraction lags, the buffer fills and
+//! fetchers block instead of piling content up in memory (backpressure).
+//!
+//! Items are dispatched highest-priority first: breaking (pinned) sources,
+//! then the most overdue relative to cadence, then by weight.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use tokio::sync::mpsc;
+
+use rootsignal_common::SourceNode;
+
+use crate::scheduling::scheduler::cadence_hours_for_weight;
+
+// ---------------------------------------------------------------------------
+// Priority
+// ---------------------------------------------------------------------------
+
+/// Dispatch priority for a work item. Higher sorts first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorkPriority {
+    /// Explicitly requested (pinned) — jumps the queue.
+    pub breaking: bool,
+    /// Hours past the source's cadence. Never-scraped sources count as very overdue.
+    pub overdue_hours: f64,
+    /// Effective source weight (weight × quality penalty).
+    pub weight: f64,
+}
+
+impl WorkPriority {
+    pub fn for_source(source: &SourceNode, breaking: bool, now: DateTime<Utc>) -> Self {
+        let cadence = source
+            .cadence_hours
+            .map(f64::from)
+            .unwrap_or_else(|| cadence_hours_for_weight(source.weight) as f64);
+        let overdue_hours = match source.last_scraped {
+            Some(last) => (now - last).num_minutes() as f64 / 60.0 - cadence,
+            None => f64::MAX,
+        };
+        Self {
+            breaking,
+            overdue_hours,
+            weight: source.weight * source.quality_penalty,
+        }
+    }
+
+    /// Priority for work with no known source (e.g. ad hoc URLs).
+    pub fn unknown() -> Self {
+        Self {
+            breaking: false,
+            overdue_hours: 0.0,
+            weight: 0.0,
+        }
+    }
+}
+
+impl Eq for WorkPriority {}
+
+impl PartialOrd for WorkPriority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WorkPriority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.breaking
+            .cmp(&other.breaking)
+            .then(self.overdue_hours.total_cmp(&other.overdue_hours))
+            .then(self.weight.total_cmp(&other.weight))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Config
+// ---------------------------------------------------------------------------
+
+/// Which fetch concurrency pool an item draws from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lane {
+    Web,
+    Social,
+    Search,
+}
+
+impl Lane {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Lane::Web => "web",
+            Lane::Social => "social",
+            Lane::Search => "search",
+        }
+    }
+}
+
+/// Throughput knobs for the scrape pipeline.
+#[derive(Debug, Clone)]
+pub struct WorkQueueConfig {
+    pub web_fetch_concurrency: usize,
+    pub social_fetch_concurrency: usize,
+    pub search_fetch_concurrency: usize,
+    /// Concurrent LLM extractions.
+    pub extract_concurrency: usize,
+    /// Fetched items allowed to wait for extraction before fetchers block.
+    pub max_pending_extractions: usize,
+    /// Per-item extraction deadline; the item is recorded as failed past it.
+    pub extract_timeout: Duration,
+}
+
+impl Default for WorkQueueConfig {
+    fn default() -> Self {
+        Self {
+            web_fetch_concurrency: 6,
+            social_fetch_concurrency: 10,
+            search_fetch_concurrency: 5,
+            extract_concurrency: 6,
+            max_pending_extractions: 12,
+            extract_timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+impl WorkQueueConfig {
+    /// Defaults overridden by `SCRAPE_WEB_CONCURRENCY`, `SCRAPE_SOCIAL_CONCURRENCY`,
+    /// `SCRAPE_SEARCH_CONCURRENCY`, `SCRAPE_EXTRACT_CONCURRENCY`, `SCRAPE_MAX_PENDING_EXTRACTIONS`,
+    /// `SCRAPE_EXTRACT_TIMEOUT_SECS`.
+    pub fn from_env() -> Self {
+        let var = |key: &str, default: usize| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|&n| n > 0)
+                .unwrap_or(default)
+        };
+        let d = Self::default();
+        Self {
+            web_fetch_concurrency: var("SCRAPE_WEB_CONCURRENCY", d.web_fetch_concurrency),
+            social_fetch_concurrency: var("SCRAPE_SOCIAL_CONCURRENCY", d.social_fetch_concurrency),
+            search_fetch_concurrency: var("SCRAPE_SEARCH_CONCURRENCY", d.search_fetch_concurrency),
+            extract_concurrency: var("SCRAPE_EXTRACT_CONCURRENCY", d.extract_concurrency),
+            max_pending_extractions: var("SCRAPE_MAX_PENDING_EXTRACTIONS", d.max_pending_extractions),
+            extract_timeout: Duration::from_secs(
+                var("SCRAPE_EXTRACT_TIMEOUT_SECS", d.extract_timeout.as_secs() as usize) as u64,
+            ),
+        }
+    }
+
+    fn fetch_concurrency(&self, lane: Lane) -> usize {
+        match lane {
+            Lane::Web => self.web_fetch_concurrency,
+            Lane::Social => self.social_fetch_concurrency,
+            Lane::Search => self.search_fetch_concurrency,
+        }
+        .max(1)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Metrics
+// ---------------------------------------------------------------------------
+
+/// Queue depth and latency for one lane, accumulated across the run.
+#[derive(Debug, Clone, Default)]
+pub struct LaneMetrics {
+    pub items: u32,
+    pub extracted: u32,
+    /// Largest number of fetched items waiting for extraction at once.
+    pub peak_pending_extractions: usize,
+    /// Times a fetcher found the hand-off buffer full and had to wait.
+    pub backpressure_waits: u32,
+    /// Time from enqueue until fetch started.
+    pub total_queue_wait: Duration,
+    pub total_fetch_time: Duration,
+    /// Time from fetch completion until extraction started.
+    pub total_extract_wait: Duration,
+    pub total_extract_time: Duration,
+}
+
+impl LaneMetrics {
+    fn avg_ms(total: Duration, n: u32) -> u64 {
+        if n == 0 {
+            0
+        } else {
+            (total.as_millis() / n as u128) as u64
+        }
+    }
+
+    pub fn avg_queue_wait_ms(&self) -> u64 {
+        Self::avg_ms(self.total_queue_wait, self.items)
+    }
+
+    pub fn avg_fetch_ms(&self) -> u64 {
+        Self::avg_ms(self.total_fetch_time, self.items)
+    }
+
+    pub fn avg_extract_wait_ms(&self) -> u64 {
+        Self::avg_ms(self.total_extract_wait, self.extracted)
+    }
+
+    pub fn avg_extract_ms(&self) -> u64 {
+        Self::avg_ms(self.total_extract_time, self.extracted)
+    }
+
+    fn merge(&mut self, other: &LaneMetrics) {
+        self.items += other.items;
+        self.extracted += other.extracted;
+        self.peak_pending_extractions = self.peak_pending_extractions.max(other.peak_pending_extractions);
+        self.backpressure_waits += other.backpressure_waits;
+        self.total_queue_wait += other.total_queue_wait;
+        self.total_fetch_time += other.total_fetch_time;
+        self.total_extract_wait += other.total_extract_wait;
+        self.total_extract_time += other.total_extract_time;
+    }
+}
+
+/// Per-lane queue metrics for a whole run.
+#[derive(Debug, Clone, Default)]
+pub struct QueueMetrics {
+    pub lanes: HashMap<Lane, LaneMetrics>,
+}
+
+impl QueueMetrics {
+    pub fn record(&mut self, lane: Lane, drained: &LaneMetrics) {
+        self.lanes.entry(lane).or_default().merge(drained);
+    }
+
+    pub fn lane(&self, lane: Lane) -> Option<&LaneMetrics> {
+        self.lanes.get(&lane)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Queue
+// ---------------------------------------------------------------------------
+
+/// Result of the fetch stage: either hand off to extraction, or finish early
+/// (fetch failed, content unchanged, nothing to extract).
+pub enum Fetched<M, O> {
+    Extract(M),
+    Done(O),
+}
+
+/// Runs batches of work through the fetch → extract stages.
+pub struct WorkQueue {
+    config: WorkQueueConfig,
+}
+
+impl WorkQueue {
+    pub fn new(config: WorkQueueConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &WorkQueueConfig {
+        &self.config
+    }
+
+    /// Drain `items` through `fetch` then `extract`, highest priority first.
+    /// Returns outputs (in completion order) and the lane's metrics for this batch.
+    pub async fn run<I, M, O, F, FFut, E, EFut>(
+        &self,
+        lane: Lane,
+        mut items: Vec<(WorkPriority, I)>,
+        fetch: F,
+        extract: E,
+    ) -> (Vec<O>, LaneMetrics)
+    where
+        F: Fn(I) -> FFut,
+        FFut: Future<Output = Fetched<M, O>>,
+        E: Fn(M) -> EFut,
+        EFut: Future<Output = O>,
+    {
+        // Stable sort keeps insertion order among equal priorities.
+        items.sort_by_key(|(priority, _)| Reverse(*priority));
+
+        let enqueued_at = Instant::now();
+        let metrics = Arc::new(Mutex::new(LaneMetrics {
+            items: items.len() as u32,
+            ..Default::default()
+        }));
+        let early_outputs: Mutex<Vec<O>> = Mutex::new(Vec::new());
+        let capacity = self.config.max_pending_extractions.max(1);
+        let (tx, mut rx) = mpsc::channel::<(M, Instant)>(capacity);
+
+        let producer = {
+            let metrics = metrics.clone();
+            let fetch = &fetch;
+            let early_outputs = &early_outputs;
+            async move {
+                let tx = &tx;
+                stream::iter(items)
+                    .for_each_concurrent(self.config.fetch_concurrency(lane), |(_, item)| {
+                        let metrics = metrics.clone();
+                        async move {
+                            let started = Instant::now();
+                            let fetched = fetch(item).await;
+                            {
+                                let mut m = metrics.lock().unwrap();
+                                m.total_queue_wait += started - enqueued_at;
+                                m.total_fetch_time += started.elapsed();
+                            }
+                            match fetched {
+                                Fetched::Extract(payload) => {
+                                    if tx.capacity() == 0 {
+                                        metrics.lock().unwrap().backpressure_waits += 1;
+                                    }
+                                    // Receiver only closes after the producer finishes.
+                                    let _ = tx.send((payload, Instant::now())).await;
+                                    let pending = tx.max_capacity() - tx.capacity();
+                                    let mut m = metrics.lock().unwrap();
+                                    m.peak_pending_extractions = m.peak_pending_extractions.max(pending);
+                                }
+                                Fetched::Done(output) => early_outputs.lock().unwrap().push(output),
+                            }
+                        }
+                    })
+                    .await;
+                // `tx` dropped here, closing the channel so the consumer drains and ends.
+            }
+        };
+
+        let consumer = {
+            let metrics = metrics.clone();
+            let extract = &extract;
+            let pending = stream::poll_fn(move |cx| rx.poll_recv(cx));
+            pending
+                .map(move |(payload, handed_off)| {
+                    let metrics = metrics.clone();
+                    async move {
+                        let started = Instant::now();
+                        let output = extract(payload).await;
+                        let mut m = metrics.lock().unwrap();
+                        m.extracted += 1;
+                        m.total_extract_wait += started - handed_off;
+                        m.total_extract_time += started.elapsed();
+                        output
+                    }
+                })
+                .buffer_unordered(self.config.extract_concurrency.max(1))
+                .collect::<Vec<O>>()
+        };
+
+        let ((), mut outputs) = tokio::join!(producer, consumer);
+        outputs.extend(early_outputs.into_inner().unwrap());
+        let lane_metrics = metrics.lock().unwrap().clone();
+        (outputs, lane_metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    fn priority(breaking: bool, overdue_hours: f64, weight: f64) -> WorkPriority {
+        WorkPriority {
+            breaking,
+            overdue_hours,
+            weight,
+        }
+    }
+
+    #[tokio::test]
+    async fn breaking_then_most_overdue_items_are_fetched_first() {
+        let queue = WorkQueue::new(WorkQueueConfig {
+            web_fetch_concurrency: 1,
+            ..Default::default()
+        });
+        let order = Mutex::new(Vec::new());
+        let items = vec![
+            (priority(false, 2.0, 0.9), "routine"),
+            (priority(false, 48.0, 0.1), "overdue"),
+            (priority(true, 0.0, 0.1), "pinned"),
+        ];
+
+        queue
+            .run(
+                Lane::Web,
+                items,
+                |name| {
+                    order.lock().unwrap().push(name);
+                    async move { Fetched::<(), &str>::Done(name) }
+                },
+                |_| async { "" },
+            )
+            .await;
+
+        assert_eq!(*order.lock().unwrap(), vec!["pinned", "overdue", "routine"]);
+    }
+
+    #[tokio::test]
+    async fn slow_extraction_applies_backpressure_to_fetchers() {
+        let queue = WorkQueue::new(WorkQueueConfig {
+            web_fetch_concurrency: 4,
+            extract_concurrency: 1,
+            max_pending_extractions: 1,
+            ..Default::default()
+        });
+        let in_flight = AtomicUsize::new(0);
+        let items: Vec<_> = (0..6).map(|i| (priority(false, 0.0, 0.5), i)).collect();
+
+        let (outputs, metrics) = queue
+            .run(
+                Lane::Web,
+                items,
+                |i| async move { Fetched::<u32, u32>::Extract(i) },
+                |i| {
+                    let in_flight = &in_flight;
+                    async move {
+                        let now = in_flight.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+                        assert_eq!(now, 1, "extraction concurrency must stay bounded");
+                        tokio::time::sleep(Duration::from_millis(5)).await;
+                        in_flight.fetch_sub(1, AtomicOrdering::SeqCst);
+                        i * 10
+                    }
+                },
+            )
+            .await;
+
+        assert_eq!(outputs.len(), 6);
+        assert_eq!(metrics.extracted, 6);
+        assert!(metrics.backpressure_waits > 0, "fetchers should have blocked on a full buffer");
+        assert!(metrics.peak_pending_extractions <= 1);
+    }
+
+    #[tokio::test]
+    async fn items_finished_at_fetch_skip_extraction() {
+        let queue = WorkQueue::new(WorkQueueConfig::default());
+        let items = vec![(priority(false, 0.0, 0.5), 1), (priority(false, 0.0, 0.5), 2)];
+
+        let (mut outputs, metrics) = queue
+            .run(
+                Lane::Social,
+                items,
+                |i| async move {
+                    if i == 1 {
+                        Fetched::Done("unchanged")
+                    } else {
+                        Fetched::Extract(i)
+                    }
+                },
+                |_| async { "extracted" },
+            )
+            .await;
+
+        outputs.sort();
+        assert_eq!(outputs, vec!["extracted", "unchanged"]);
+        assert_eq!(metrics.items, 2);
+        assert_eq!(metrics.extracted, 1);
+    }
+}
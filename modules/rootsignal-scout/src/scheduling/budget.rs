@@ -1,6 +1,8 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 use tracing::{info, warn};
 
+use rootsignal_common::ScrapingStrategy;
+
 /// Tracks spend against a daily budget limit.
 /// Thread-safe via atomic operations for concurrent scraping.
 pub struct BudgetTracker {
@@ -17,6 +19,7 @@ impl OperationCost {
     pub const SEARCH_QUERY: u64 = 1;
     pub const CHROME_SCRAPE: u64 = 1; // ~0.5 but round up
     pub const APIFY_SOCIAL: u64 = 1;
+    pub const AUDIO_TRANSCRIPTION: u64 = 20; // per episode: ~45 min of Whisper + several extraction chunks
     pub const CLAUDE_HAIKU_EXTRACTION: u64 = 1; // ~0.2 but round up
     pub const VOYAGE_EMBEDDING: u64 = 1; // ~0.01 per batch, round up
     pub const CLAUDE_HAIKU_SYNTHESIS: u64 = 1; // ~0.5 per story
@@ -31,9 +34,33 @@ impl OperationCost {
     pub const SEARCH_RESPONSE_FINDER: u64 = 5; // per tension: up to 5 searches
     pub const CHROME_RESPONSE_FINDER: u64 = 3; // per tension: page reads
     pub const CLAUDE_HAIKU_ACTOR_EXTRACTOR: u64 = 1; // per batch: actor extraction from signal text
+    pub const CLAUDE_HAIKU_REGION_MEMO: u64 = 1; // per run: end-of-run memo update
     pub const CLAUDE_HAIKU_GATHERING_FINDER: u64 = 3; // per tension: investigation + extraction (may terminate early)
     pub const SEARCH_GATHERING_FINDER: u64 = 5; // per tension: early termination uses ~2-3
     pub const CHROME_GATHERING_FINDER: u64 = 3; // per tension: page reads
+    pub const CLAUDE_SONNET_AUDIT: u64 = 2; // per signal: judge review against its source content
+    pub const CLAUDE_SONNET_FLYER_READ: u64 = 1; // per image: ~1.6k image tokens + a short transcription
+    pub const CLAUDE_HAIKU_SUMMARY_VARIANTS: u64 = 1; // per subject: plain English + every priority language in one call
+    pub const CLAUDE_HAIKU_INFORMATION_PULL: u64 = 2; // per request: query planning + answer check
+    pub const SEARCH_INFORMATION_PULL: u64 = 3; // per request: up to 3 searches
+    pub const CHROME_INFORMATION_PULL: u64 = 3; // per request: up to 3 page reads
+    pub const BACKFILL_ITEM: u64 = 1; // per historical post, issue or article: fetch + share of a batched extraction
+
+    /// Most a backfill of `max_items` items can cost for a source scraped
+    /// with `strategy`. None when the source has no history to backfill
+    /// (single pages, search queries, upcoming-event listings).
+    pub fn backfill(strategy: &ScrapingStrategy, max_items: u32) -> Option<u64> {
+        let per_item = match strategy {
+            ScrapingStrategy::Podcast => Self::AUDIO_TRANSCRIPTION,
+            ScrapingStrategy::Video => Self::CLAUDE_HAIKU_EXTRACTION * 3, // a few transcript chunks each
+            ScrapingStrategy::Social(_) | ScrapingStrategy::Rss | ScrapingStrategy::Newsletter => Self::BACKFILL_ITEM,
+            ScrapingStrategy::WebQuery
+            | ScrapingStrategy::WebPage
+            | ScrapingStrategy::HtmlListing { .. }
+            | ScrapingStrategy::Events => return None,
+        };
+        Some(per_item * max_items as u64)
+    }
 }
 
 impl BudgetTracker {
@@ -74,6 +101,26 @@ impl BudgetTracker {
         true
     }
 
+    /// Admit items in order while each can be paid for at `cost_cents`,
+    /// charging for every admitted item. Used for expensive per-item work
+    /// (e.g. audio transcription) where the whole batch may not fit.
+    pub fn admit<T>(&self, items: Vec<T>, cost_cents: u64) -> Vec<T> {
+        let total = items.len();
+        let admitted: Vec<T> = items
+            .into_iter()
+            .take_while(|_| self.has_budget(cost_cents) && self.spend(cost_cents))
+            .collect();
+        if admitted.len() < total {
+            warn!(
+                admitted = admitted.len(),
+                skipped = total - admitted.len(),
+                cost_cents,
+                "Budget exhausted, skipping remaining items"
+            );
+        }
+        admitted
+    }
+
     /// Total spent this run.
     pub fn total_spent(&self) -> u64 {
         self.spent_cents.load(Ordering::Relaxed)
@@ -129,6 +176,28 @@ mod tests {
         assert_eq!(tracker.remaining(), 50);
     }
 
+    #[test]
+    fn expensive_items_are_admitted_only_while_budget_lasts() {
+        let tracker = BudgetTracker::new(50);
+        tracker.spend(5);
+
+        let admitted = tracker.admit(vec!["a", "b", "c"], OperationCost::AUDIO_TRANSCRIPTION);
+
+        assert_eq!(admitted, vec!["a", "b"]);
+        assert_eq!(tracker.total_spent(), 45);
+    }
+
+    #[test]
+    fn backfill_cost_scales_with_depth_and_skips_sources_without_history() {
+        let social = OperationCost::backfill(&ScrapingStrategy::Social(rootsignal_common::SocialPlatform::Instagram), 100);
+        let podcast = OperationCost::backfill(&ScrapingStrategy::Podcast, 10);
+        let page = OperationCost::backfill(&ScrapingStrategy::WebPage, 100);
+
+        assert_eq!(social, Some(100 * OperationCost::BACKFILL_ITEM));
+        assert_eq!(podcast, Some(10 * OperationCost::AUDIO_TRANSCRIPTION));
+        assert_eq!(page, None);
+    }
+
     #[test]
     fn budget_exceeded_returns_false() {
         let tracker = BudgetTracker::new(100);
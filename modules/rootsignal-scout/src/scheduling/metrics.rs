@@ -2,7 +2,7 @@
 //!
 //! After scraping completes, this stage records per-source scrape metrics,
 //! recomputes weights based on signal production history, updates cadences,
-//! and deactivates dead sources/queries.
+//! ends source trials, and deactivates dead sources/queries.
 
 use chrono::{DateTime, Utc};
 use tracing::{info, warn};
@@ -105,6 +105,21 @@ impl<'a> Metrics<'a> {
             }
         }
 
+        // End finished trials: graduate corroborated probation sources, deactivate empty ones
+        match self.writer.apply_source_trials().await {
+            Ok(transitions) => {
+                for t in &transitions {
+                    info!(
+                        canonical_key = t.canonical_key.as_str(),
+                        to = t.to.as_str(),
+                        reason = t.reason.as_str(),
+                        "Source trial ended"
+                    );
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to apply source trials"),
+        }
+
         // Deactivate dead sources (10+ consecutive empty runs, non-curated/human only)
         match self
             .writer
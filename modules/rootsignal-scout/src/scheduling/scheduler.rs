@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 use tracing::info;
 
-use rootsignal_common::{is_web_query, DiscoveryMethod, SourceNode, SourceRole};
+use rootsignal_common::source_trial::{TRIAL_MIN_CADENCE_HOURS, TRIAL_SCRAPE_SHARE};
+use rootsignal_common::{is_web_query, CalendarBlock, DiscoveryMethod, RegionCalendar, SourceNode, SourceRole, TrialState};
 
 /// Determines which sources to scrape this run based on weight, cadence, and exploration policy.
 pub struct SourceScheduler {
@@ -13,6 +14,8 @@ pub struct SourceScheduler {
     exploration_weight_threshold: f64,
     /// Minimum days since last scrape before a low-weight source is eligible for exploration.
     exploration_min_stale_days: i64,
+    /// Region calendar: quiet hours and blackouts block scheduling, bursts shorten cadence.
+    calendar: Option<RegionCalendar>,
 }
 
 /// Result of scheduling: which sources to scrape and why.
@@ -27,6 +30,8 @@ pub struct ScheduleResult {
     pub tension_phase: Vec<String>,
     /// Convenience partition: canonical keys of sources with role=Response (Phase B).
     pub response_phase: Vec<String>,
+    /// Set when the region calendar blocked this run; nothing is scheduled.
+    pub blocked: Option<CalendarBlock>,
 }
 
 pub struct ScheduledSource {
@@ -50,11 +55,29 @@ impl SourceScheduler {
             exploration_ratio: 0.10,
             exploration_weight_threshold: 0.3,
             exploration_min_stale_days: 5,
+            calendar: None,
         }
     }
 
+    pub fn with_calendar(mut self, calendar: Option<RegionCalendar>) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
     /// Schedule sources for this run. Returns which to scrape and which to skip.
     pub fn schedule(&self, sources: &[SourceNode], now: DateTime<Utc>) -> ScheduleResult {
+        if let Some(block) = self.calendar.as_ref().and_then(|c| c.blocked_at(now)) {
+            info!(%block, sources = sources.len(), "Region calendar blocks scheduling");
+            return ScheduleResult {
+                scheduled: Vec::new(),
+                exploration: Vec::new(),
+                skipped: sources.len(),
+                tension_phase: Vec::new(),
+                response_phase: Vec::new(),
+                blocked: Some(block),
+            };
+        }
+
         let mut scheduled = Vec::new();
         let mut exploration_candidates = Vec::new();
         let mut skipped = 0usize;
@@ -98,7 +121,7 @@ impl SourceScheduler {
             b_stale.cmp(&a_stale) // most stale first
         });
 
-        let exploration: Vec<ScheduledSource> = exploration_candidates
+        let mut exploration: Vec<ScheduledSource> = exploration_candidates
             .into_iter()
             .take(exploration_slots)
             .map(|s| ScheduledSource {
@@ -106,6 +129,7 @@ impl SourceScheduler {
                 reason: ScheduleReason::Exploration,
             })
             .collect();
+        skipped += cap_probation(&mut scheduled, &mut exploration, sources);
 
         let exploration_picked = exploration.len();
         if exploration_picked > 0 {
@@ -146,6 +170,7 @@ impl SourceScheduler {
             skipped,
             tension_phase,
             response_phase,
+            blocked: None,
         }
     }
 
@@ -156,12 +181,40 @@ impl SourceScheduler {
             None => return true, // Never scraped — always due
         };
 
-        let cadence_hours = source
+        let hours_since = (now - last).num_minutes() as f64 / 60.0;
+        hours_since >= self.effective_cadence_hours(source, now)
+    }
+
+    /// Source cadence scaled by any burst window active at `now`.
+    fn effective_cadence_hours(&self, source: &SourceNode, now: DateTime<Utc>) -> f64 {
+        let mut cadence_hours = source
             .cadence_hours
             .unwrap_or_else(|| cadence_hours_for_weight(source.weight));
-        let hours_since = (now - last).num_hours();
+        if source.trial == TrialState::Probation {
+            cadence_hours = cadence_hours.max(TRIAL_MIN_CADENCE_HOURS);
+        }
+        let factor = self.calendar.as_ref().map_or(1.0, |c| c.cadence_factor(now));
+        f64::from(cadence_hours) * factor
+    }
 
-        hours_since >= cadence_hours as i64
+    /// When a source will next be scheduled: cadence due time pushed past any
+    /// quiet hours or blackout. `None` for dormant sources.
+    pub fn next_run_at(&self, source: &SourceNode, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let due = match source.last_scraped {
+            None => now,
+            Some(last) => {
+                let cadence = self.effective_cadence_hours(source, now);
+                if cadence >= f64::from(u32::MAX) {
+                    return None;
+                }
+                last.checked_add_signed(chrono::Duration::minutes((cadence * 60.0) as i64))?
+                    .max(now)
+            }
+        };
+        Some(match &self.calendar {
+            Some(c) => c.next_allowed(due),
+            None => due,
+        })
     }
 
     /// Check if a source is eligible for exploration sampling.
@@ -176,6 +229,39 @@ impl SourceScheduler {
     }
 }
 
+/// Drop probation sources beyond `TRIAL_SCRAPE_SHARE` of the run's scrapes
+/// (always allowing one), keeping the never-scraped and stalest. Returns how
+/// many were dropped.
+fn cap_probation(
+    scheduled: &mut Vec<ScheduledSource>,
+    exploration: &mut Vec<ScheduledSource>,
+    sources: &[SourceNode],
+) -> usize {
+    let on_probation: HashMap<&str, Option<DateTime<Utc>>> = sources
+        .iter()
+        .filter(|s| s.trial == TrialState::Probation)
+        .map(|s| (s.canonical_key.as_str(), s.last_scraped))
+        .collect();
+    let mut picked: Vec<(&str, Option<DateTime<Utc>>)> = scheduled
+        .iter()
+        .chain(exploration.iter())
+        .filter_map(|s| on_probation.get_key_value(s.canonical_key.as_str()).map(|(k, t)| (*k, *t)))
+        .collect();
+    let total = scheduled.len() + exploration.len();
+    let allowed = ((total as f64 * TRIAL_SCRAPE_SHARE).floor() as usize).max(1);
+    if picked.len() <= allowed {
+        return 0;
+    }
+
+    // None (never scraped) sorts first, then oldest
+    picked.sort_by_key(|(_, last_scraped)| *last_scraped);
+    let dropped: HashSet<String> = picked[allowed..].iter().map(|(k, _)| k.to_string()).collect();
+    scheduled.retain(|s| !dropped.contains(&s.canonical_key));
+    exploration.retain(|s| !dropped.contains(&s.canonical_key));
+    info!(dropped = dropped.len(), allowed, "Probation sources capped for this run");
+    dropped.len()
+}
+
 /// Map weight to scrape cadence in hours.
 pub fn cadence_hours_for_weight(weight: f64) -> u32 {
     if weight > 0.8 {
@@ -534,6 +620,7 @@ mod tests {
             quality_penalty: 1.0,
             source_role: SourceRole::default(),
             scrape_count: 0,
+            trial: TrialState::Established,
         }
     }
 
@@ -562,6 +649,96 @@ mod tests {
         assert_eq!(result.scheduled.len(), 0);
     }
 
+    #[test]
+    fn probation_sources_are_scraped_less_often_and_capped_per_run() {
+        let scheduler = SourceScheduler::new();
+        let now = Utc::now();
+        let mut sources: Vec<SourceNode> = (0..8)
+            .map(|_| make_source(0.9, Some(now - Duration::hours(7))))
+            .collect();
+        let mut fresh_trials: Vec<SourceNode> = (0..5)
+            .map(|_| SourceNode { trial: TrialState::Probation, ..make_source(0.5, None) })
+            .collect();
+        fresh_trials[0].last_scraped = Some(now - Duration::hours(7));
+        fresh_trials[0].weight = 0.9;
+        let stalest_new = fresh_trials[1].canonical_key.clone();
+        sources.extend(fresh_trials);
+
+        let result = scheduler.schedule(&sources, now);
+
+        // 12 due (the trial scraped 7h ago waits out its longer cadence);
+        // probation gets floor(12 * 0.2) = 2 of them, so 2 of the 4 new trials wait
+        let trial_keys: Vec<&str> = result
+            .scheduled
+            .iter()
+            .map(|s| s.canonical_key.as_str())
+            .filter(|k| sources.iter().any(|s| s.canonical_key == *k && s.trial == TrialState::Probation))
+            .collect();
+        assert_eq!(result.scheduled.len(), 10);
+        assert_eq!(trial_keys.len(), 2);
+        assert!(trial_keys.contains(&stalest_new.as_str()));
+        assert_eq!(result.skipped, 2 + 1);
+    }
+
+    fn quiet_1_to_5_chicago() -> RegionCalendar {
+        RegionCalendar {
+            timezone: "America/Chicago".to_string(),
+            quiet_hours: Some(rootsignal_common::QuietHours { start_hour: 1, end_hour: 5 }),
+            ..Default::default()
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn quiet_hours_schedule_nothing_even_when_sources_are_due() {
+        let scheduler = SourceScheduler::new().with_calendar(Some(quiet_1_to_5_chicago()));
+        let now = at("2026-06-10T08:00:00Z"); // 03:00 CDT
+        let sources = vec![make_source(0.9, None), make_source(0.9, Some(now - Duration::days(2)))];
+
+        let result = scheduler.schedule(&sources, now);
+
+        assert!(result.scheduled.is_empty());
+        assert_eq!(result.skipped, 2);
+        assert_eq!(result.blocked, Some(CalendarBlock::QuietHours));
+    }
+
+    #[test]
+    fn burst_window_makes_source_due_sooner() {
+        let now = at("2026-11-03T18:00:00Z");
+        let calendar = RegionCalendar {
+            bursts: vec![rootsignal_common::BurstWindow {
+                starts_at: now - Duration::hours(6),
+                ends_at: now + Duration::hours(18),
+                reason: "election day".to_string(),
+                cadence_factor: 0.25,
+            }],
+            ..Default::default()
+        };
+        // Weight 0.6 → 24h cadence; 8h since last scrape is only due under the burst.
+        let sources = vec![make_source(0.6, Some(now - Duration::hours(8)))];
+
+        let normal = SourceScheduler::new().schedule(&sources, now);
+        let burst = SourceScheduler::new().with_calendar(Some(calendar)).schedule(&sources, now);
+
+        assert!(normal.scheduled.is_empty());
+        assert_eq!(burst.scheduled.len(), 1);
+    }
+
+    #[test]
+    fn next_run_preview_is_pushed_past_quiet_hours() {
+        let scheduler = SourceScheduler::new().with_calendar(Some(quiet_1_to_5_chicago()));
+        let now = at("2026-06-10T03:00:00Z"); // 22:00 CDT
+        let source = make_source(0.9, Some(at("2026-06-10T01:30:00Z")));
+
+        let next = scheduler.next_run_at(&source, now);
+
+        // Due 07:30 UTC = 02:30 CDT, inside quiet hours → 05:00 CDT = 10:00 UTC
+        assert_eq!(next, Some(at("2026-06-10T10:00:00Z")));
+    }
+
     #[test]
     fn low_weight_source_scraped_every_7_days() {
         let scheduler = SourceScheduler::new();
@@ -816,6 +993,7 @@ mod tests {
             quality_penalty: 1.0,
             source_role: SourceRole::Response,
             scrape_count: 0,
+            trial: TrialState::Established,
         }
     }
 
@@ -0,0 +1,229 @@
+//! GTFS-realtime service alerts.
+//!
+//! Only the parts of `gtfs-realtime.proto` an alert needs are declared; prost
+//! skips the rest of the feed (trip updates, vehicle positions) unread.
+
+use chrono::{DateTime, TimeZone, Utc};
+use prost::Message;
+
+use rootsignal_common::{AffectedEntity, AlertEffect, ServiceAlert, TransitAgency};
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct FeedMessage {
+    #[prost(message, repeated, tag = "2")]
+    pub entity: Vec<FeedEntity>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct FeedEntity {
+    #[prost(string, optional, tag = "1")]
+    pub id: Option<String>,
+    #[prost(bool, optional, tag = "2")]
+    pub is_deleted: Option<bool>,
+    #[prost(message, optional, tag = "5")]
+    pub alert: Option<Alert>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct Alert {
+    #[prost(message, repeated, tag = "1")]
+    pub active_period: Vec<TimeRange>,
+    #[prost(message, repeated, tag = "5")]
+    pub informed_entity: Vec<EntitySelector>,
+    #[prost(int32, optional, tag = "7")]
+    pub effect: Option<i32>,
+    #[prost(message, optional, tag = "8")]
+    pub url: Option<TranslatedString>,
+    #[prost(message, optional, tag = "10")]
+    pub header_text: Option<TranslatedString>,
+    #[prost(message, optional, tag = "11")]
+    pub description_text: Option<TranslatedString>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct TimeRange {
+    #[prost(uint64, optional, tag = "1")]
+    pub start: Option<u64>,
+    #[prost(uint64, optional, tag = "2")]
+    pub end: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct EntitySelector {
+    #[prost(string, optional, tag = "1")]
+    pub agency_id: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub route_id: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct TranslatedString {
+    #[prost(message, repeated, tag = "1")]
+    pub translation: Vec<Translation>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct Translation {
+    #[prost(string, optional, tag = "1")]
+    pub text: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub language: Option<String>,
+}
+
+impl TranslatedString {
+    /// English text, or the untagged translation, or the first one.
+    fn text(&self) -> Option<String> {
+        let lang = |t: &&Translation| t.language.as_deref().map(|l| l.to_ascii_lowercase());
+        self.translation
+            .iter()
+            .find(|t| lang(t).is_some_and(|l| l == "en" || l.starts_with("en-")))
+            .or_else(|| self.translation.iter().find(|t| lang(t).is_none()))
+            .or_else(|| self.translation.first())
+            .and_then(|t| t.text.as_deref())
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// GTFS-RT `Alert.Effect` values.
+fn effect(value: Option<i32>) -> AlertEffect {
+    match value {
+        Some(1) => AlertEffect::Closure,        // NO_SERVICE
+        Some(2) => AlertEffect::ReducedService, // REDUCED_SERVICE
+        Some(3) => AlertEffect::Delay,          // SIGNIFICANT_DELAYS
+        Some(4) => AlertEffect::Detour,         // DETOUR
+        _ => AlertEffect::Other,
+    }
+}
+
+fn timestamp(secs: Option<u64>) -> Option<DateTime<Utc>> {
+    secs.filter(|s| *s > 0)
+        .and_then(|s| Utc.timestamp_opt(s as i64, 0).single())
+}
+
+/// Alerts in a GTFS-RT feed that are still active at `now` and touch a route
+/// the agency follows. Alerts naming no route (system-wide notices) are kept
+/// only when the agency follows every route.
+pub(crate) fn parse_alerts(body: &[u8], agency: &TransitAgency, now: DateTime<Utc>) -> anyhow::Result<Vec<ServiceAlert>> {
+    let feed = FeedMessage::decode(body)?;
+    let mut alerts = Vec::new();
+    for entity in feed.entity {
+        if entity.is_deleted == Some(true) {
+            continue;
+        }
+        let (Some(id), Some(alert)) = (entity.id, entity.alert) else {
+            continue;
+        };
+        let Some(title) = alert.header_text.as_ref().and_then(TranslatedString::text) else {
+            continue;
+        };
+
+        let active_from = alert.active_period.iter().filter_map(|p| timestamp(p.start)).min();
+        // An open-ended period keeps the alert active.
+        let active_until = if alert.active_period.iter().any(|p| timestamp(p.end).is_none()) {
+            None
+        } else {
+            alert.active_period.iter().filter_map(|p| timestamp(p.end)).max()
+        };
+        if active_until.is_some_and(|until| until < now) {
+            continue;
+        }
+
+        let mut affected: Vec<AffectedEntity> = Vec::new();
+        for route in alert.informed_entity.iter().filter_map(|e| e.route_id.as_deref()) {
+            let entity = AffectedEntity::Route(route.to_string());
+            if agency.follows_route(route) && !affected.contains(&entity) {
+                affected.push(entity);
+            }
+        }
+        if affected.is_empty() && !agency.routes.is_empty() {
+            continue;
+        }
+
+        alerts.push(ServiceAlert {
+            id,
+            title,
+            description: alert.description_text.as_ref().and_then(TranslatedString::text),
+            url: alert.url.as_ref().and_then(TranslatedString::text),
+            effect: effect(alert.effect),
+            affected,
+            active_from,
+            active_until,
+        });
+    }
+    Ok(alerts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> Option<TranslatedString> {
+        Some(TranslatedString {
+            translation: vec![Translation {
+                text: Some(s.to_string()),
+                language: Some("en".to_string()),
+            }],
+        })
+    }
+
+    fn alert(id: &str, routes: &[&str], effect: i32, end: Option<u64>) -> FeedEntity {
+        FeedEntity {
+            id: Some(id.to_string()),
+            is_deleted: None,
+            alert: Some(Alert {
+                active_period: vec![TimeRange { start: Some(1_792_000_000), end }],
+                informed_entity: routes
+                    .iter()
+                    .map(|r| EntitySelector {
+                        agency_id: Some("MT".to_string()),
+                        route_id: Some(r.to_string()),
+                    })
+                    .collect(),
+                effect: Some(effect),
+                url: None,
+                header_text: text(&format!("Alert {id}")),
+                description_text: text("Buses will not serve Lake St."),
+            }),
+        }
+    }
+
+    fn agency(routes: &[&str]) -> TransitAgency {
+        TransitAgency {
+            name: "Metro Transit".to_string(),
+            alerts_url: "https://svc.metrotransit.org/mtgtfs/alerts.pb".to_string(),
+            routes: routes.iter().map(|r| r.to_string()).collect(),
+            location_name: "Minneapolis".to_string(),
+            latitude: 44.9778,
+            longitude: -93.265,
+        }
+    }
+
+    #[test]
+    fn decodes_alerts_for_followed_routes() {
+        let feed = FeedMessage {
+            entity: vec![
+                alert("suspended", &["21", "14"], 1, None),
+                alert("detour", &["14"], 4, None),
+            ],
+        };
+        let now = Utc.timestamp_opt(1_792_100_000, 0).unwrap();
+
+        let alerts = parse_alerts(&feed.encode_to_vec(), &agency(&["21"]), now).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].title, "Alert suspended");
+        assert_eq!(alerts[0].effect, AlertEffect::Closure);
+        assert_eq!(alerts[0].affected, vec![AffectedEntity::Route("21".to_string())]);
+        assert_eq!(alerts[0].description.as_deref(), Some("Buses will not serve Lake St."));
+    }
+
+    #[test]
+    fn expired_alerts_are_dropped() {
+        let feed = FeedMessage {
+            entity: vec![alert("over", &["21"], 3, Some(1_792_050_000))],
+        };
+        let now = Utc.timestamp_opt(1_792_100_000, 0).unwrap();
+        assert!(parse_alerts(&feed.encode_to_vec(), &agency(&[]), now).unwrap().is_empty());
+    }
+}
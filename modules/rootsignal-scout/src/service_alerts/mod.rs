@@ -0,0 +1,252 @@
+//! Service alerts — transit route cuts and school closures as signals.
+//!
+//! Each region lists the transit agencies and school districts it follows
+//! (`RegionServiceAlerts`). A connector reads their feeds; every alert names
+//! the routes or schools it affects. Alerts that take service away (no
+//! service, a closed school, reduced service) become Tensions; detours,
+//! delays and the rest become Notices. Affected entities are stored as tags
+//! ("route-21") that are also put on recent signals mentioning the entity,
+//! so a closure and the community's reaction to it end up linked.
+
+pub mod gtfs_rt;
+pub mod schools;
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_common::{
+    AlertEffect, GeoPoint, GeoPrecision, Node, NodeMeta, NoticeNode, SchoolDistrict, SensitivityLevel, ServiceAlert,
+    Severity, TensionNode, TransitAgency,
+};
+
+/// Reads alerts from transit and school district feeds.
+#[async_trait]
+pub trait ServiceAlertConnector: Send + Sync {
+    /// The agency's alerts still active at `now` on routes it follows.
+    async fn transit_alerts(&self, agency: &TransitAgency, now: DateTime<Utc>) -> Result<Vec<ServiceAlert>>;
+
+    /// The district's recent announcements that are alerts.
+    async fn school_alerts(&self, district: &SchoolDistrict, now: DateTime<Utc>) -> Result<Vec<ServiceAlert>>;
+}
+
+pub struct AlertFeedsClient {
+    client: reqwest::Client,
+}
+
+impl AlertFeedsClient {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("Failed to build service alert HTTP client"),
+        }
+    }
+
+    async fn get(&self, url: &str) -> Result<bytes::Bytes> {
+        Ok(self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context("Service alert feed request failed")?
+            .error_for_status()?
+            .bytes()
+            .await?)
+    }
+}
+
+impl Default for AlertFeedsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ServiceAlertConnector for AlertFeedsClient {
+    async fn transit_alerts(&self, agency: &TransitAgency, now: DateTime<Utc>) -> Result<Vec<ServiceAlert>> {
+        let body = self.get(&agency.alerts_url).await?;
+        let alerts = gtfs_rt::parse_alerts(&body, agency, now).context("Failed to decode GTFS-RT alerts")?;
+        info!(agency = agency.name.as_str(), alerts = alerts.len(), "gtfs-rt: fetched alerts");
+        Ok(alerts)
+    }
+
+    async fn school_alerts(&self, district: &SchoolDistrict, now: DateTime<Utc>) -> Result<Vec<ServiceAlert>> {
+        let body = self.get(&district.feed_url).await?;
+        let alerts = schools::parse_announcements(&body, district, now)?;
+        info!(district = district.name.as_str(), alerts = alerts.len(), "schools: fetched alerts");
+        Ok(alerts)
+    }
+}
+
+/// Where an alert came from: the agency or district, its feed, and where its
+/// alerts are located.
+pub struct AlertOrigin<'a> {
+    pub authority: &'a str,
+    pub category: &'static str,
+    pub feed_url: &'a str,
+    pub location_name: &'a str,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl<'a> From<&'a TransitAgency> for AlertOrigin<'a> {
+    fn from(agency: &'a TransitAgency) -> Self {
+        Self {
+            authority: &agency.name,
+            category: "transit",
+            feed_url: &agency.alerts_url,
+            location_name: &agency.location_name,
+            latitude: agency.latitude,
+            longitude: agency.longitude,
+        }
+    }
+}
+
+impl<'a> From<&'a SchoolDistrict> for AlertOrigin<'a> {
+    fn from(district: &'a SchoolDistrict) -> Self {
+        Self {
+            authority: &district.name,
+            category: "education",
+            feed_url: &district.feed_url,
+            location_name: &district.location_name,
+            latitude: district.latitude,
+            longitude: district.longitude,
+        }
+    }
+}
+
+/// The alert's own page, or its feed addressed by alert id. The feed's query
+/// string is dropped: agencies put API keys there.
+pub fn alert_url(origin: &AlertOrigin, alert: &ServiceAlert) -> String {
+    if let Some(url) = &alert.url {
+        return url.clone();
+    }
+    match url::Url::parse(origin.feed_url) {
+        Ok(mut url) => {
+            url.set_query(None);
+            url.set_fragment(Some(&alert.id));
+            url.to_string()
+        }
+        Err(_) => format!("{}#{}", origin.feed_url, alert.id),
+    }
+}
+
+/// Turn an alert into a Tension (service taken away) or a Notice.
+pub fn alert_to_node(origin: &AlertOrigin, alert: &ServiceAlert, now: DateTime<Utc>) -> Node {
+    let mut summary = alert.description.clone().unwrap_or_else(|| alert.title.clone());
+    if !alert.affected.is_empty() {
+        let names: Vec<String> = alert.affected.iter().map(|e| e.mention()).collect();
+        summary.push_str(&format!(" Affects {}.", names.join(", ")));
+    }
+
+    let meta = NodeMeta {
+        id: Uuid::new_v4(),
+        title: alert.title.clone(),
+        summary,
+        sensitivity: SensitivityLevel::General,
+        confidence: 0.0,
+        freshness_score: 1.0,
+        corroboration_count: 0,
+        about_location: Some(GeoPoint {
+            lat: origin.latitude,
+            lng: origin.longitude,
+            precision: GeoPrecision::Approximate,
+        }),
+        about_location_name: Some(origin.location_name.to_string()),
+        from_location: None,
+        source_url: alert_url(origin, alert),
+        extracted_at: now,
+        content_date: alert.active_from,
+        last_confirmed_active: now,
+        source_diversity: 1,
+        external_ratio: 0.0,
+        cause_heat: 0.0,
+        implied_queries: Vec::new(),
+        channel_diversity: 1,
+        mentioned_actors: Vec::new(),
+        author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
+    };
+    let category = Some(origin.category.to_string());
+
+    match alert.effect {
+        AlertEffect::Closure | AlertEffect::ReducedService => Node::Tension(TensionNode {
+            meta,
+            severity: if alert.effect == AlertEffect::Closure { Severity::High } else { Severity::Medium },
+            category,
+            what_would_help: None,
+        }),
+        AlertEffect::Detour | AlertEffect::Delay | AlertEffect::Other => Node::Notice(NoticeNode {
+            meta,
+            severity: if alert.effect == AlertEffect::Other { Severity::Low } else { Severity::Medium },
+            category,
+            effective_date: alert.active_from,
+            source_authority: Some(origin.authority.to_string()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rootsignal_common::AffectedEntity;
+
+    fn agency() -> TransitAgency {
+        TransitAgency {
+            name: "Metro Transit".to_string(),
+            alerts_url: "https://svc.metrotransit.org/mtgtfs/alerts.pb?api_key=secret".to_string(),
+            routes: Vec::new(),
+            location_name: "Minneapolis".to_string(),
+            latitude: 44.9778,
+            longitude: -93.265,
+        }
+    }
+
+    fn alert(effect: AlertEffect) -> ServiceAlert {
+        ServiceAlert {
+            id: "4412".to_string(),
+            title: "Route 21 suspended on Lake St".to_string(),
+            description: Some("Buses will not serve Lake St between Hiawatha and Chicago.".to_string()),
+            url: None,
+            effect,
+            affected: vec![AffectedEntity::Route("21".to_string())],
+            active_from: None,
+            active_until: None,
+        }
+    }
+
+    #[test]
+    fn suspended_route_is_a_tension() {
+        let node = alert_to_node(&AlertOrigin::from(&agency()), &alert(AlertEffect::Closure), Utc::now());
+        let Node::Tension(t) = node else {
+            panic!("service cuts are tensions");
+        };
+        assert_eq!(t.severity, Severity::High);
+        assert_eq!(t.category.as_deref(), Some("transit"));
+        assert!(t.meta.summary.ends_with("Affects Route 21."));
+    }
+
+    #[test]
+    fn detour_is_a_notice_from_the_agency() {
+        let node = alert_to_node(&AlertOrigin::from(&agency()), &alert(AlertEffect::Detour), Utc::now());
+        let Node::Notice(n) = node else {
+            panic!("detours are notices");
+        };
+        assert_eq!(n.severity, Severity::Medium);
+        assert_eq!(n.source_authority.as_deref(), Some("Metro Transit"));
+    }
+
+    #[test]
+    fn feed_api_keys_stay_out_of_source_urls() {
+        let url = alert_url(&AlertOrigin::from(&agency()), &alert(AlertEffect::Delay));
+        assert_eq!(url, "https://svc.metrotransit.org/mtgtfs/alerts.pb#4412");
+    }
+}
@@ -0,0 +1,145 @@
+//! School district announcement feeds (RSS/Atom).
+//!
+//! Districts post closures next to lunch menus and board recaps; only
+//! announcements using one of the district's alert keywords are read. The
+//! schools an announcement names are its affected entities — none named
+//! means the whole district.
+
+use std::sync::LazyLock;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+
+use rootsignal_common::{AffectedEntity, AlertEffect, SchoolDistrict, ServiceAlert};
+
+/// Announcements older than this are past news.
+const MAX_AGE_DAYS: i64 = 7;
+
+static TAGS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// Most disruptive effect first: "closed, with e-learning" is a closure.
+const EFFECT_KEYWORDS: &[(AlertEffect, &[&str])] = &[
+    (AlertEffect::Closure, &["closed", "closure", "cancel", "no school"]),
+    (AlertEffect::ReducedService, &["early dismissal", "e-learning", "distance learning"]),
+    (AlertEffect::Delay, &["delayed start", "late start", "delay"]),
+];
+
+/// Alert announcements in a district feed published within the last week.
+pub(crate) fn parse_announcements(
+    body: &[u8],
+    district: &SchoolDistrict,
+    now: DateTime<Utc>,
+) -> Result<Vec<ServiceAlert>> {
+    let feed = feed_rs::parser::parse(body).context("Failed to parse school district feed")?;
+    let cutoff = now - chrono::Duration::days(MAX_AGE_DAYS);
+
+    let mut alerts = Vec::new();
+    for entry in feed.entries {
+        let published = entry.published.or(entry.updated);
+        if published.is_some_and(|p| p < cutoff) {
+            continue;
+        }
+        let Some(title) = entry.title.map(|t| t.content.trim().to_string()).filter(|t| !t.is_empty()) else {
+            continue;
+        };
+        let description = entry
+            .summary
+            .map(|s| s.content)
+            .or_else(|| entry.content.and_then(|c| c.body))
+            .map(|html| TAGS.replace_all(&html, " ").split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|d| !d.is_empty());
+
+        let text = format!("{title} {}", description.as_deref().unwrap_or("")).to_lowercase();
+        if !district.alert_keywords.iter().any(|k| text.contains(&k.to_lowercase())) {
+            continue;
+        }
+
+        let mut affected: Vec<AffectedEntity> = district
+            .schools
+            .iter()
+            .filter(|school| mentions(&text, school))
+            .map(|school| AffectedEntity::School(school.clone()))
+            .collect();
+        if affected.is_empty() {
+            affected.push(AffectedEntity::School(district.name.clone()));
+        }
+
+        alerts.push(ServiceAlert {
+            id: entry.id,
+            title,
+            description,
+            url: entry.links.first().map(|l| l.href.clone()),
+            effect: effect(&text),
+            affected,
+            active_from: published,
+            active_until: None,
+        });
+    }
+    Ok(alerts)
+}
+
+/// Whether lowercased `text` names `school` as whole words.
+fn mentions(text: &str, school: &str) -> bool {
+    let pattern = format!(r"\b{}\b", regex::escape(&school.trim().to_lowercase()));
+    Regex::new(&pattern).is_ok_and(|re| re.is_match(text))
+}
+
+fn effect(text: &str) -> AlertEffect {
+    EFFECT_KEYWORDS
+        .iter()
+        .find(|(_, words)| words.iter().any(|w| text.contains(w)))
+        .map(|(effect, _)| *effect)
+        .unwrap_or(AlertEffect::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rootsignal_common::service_alerts::default_school_alert_keywords;
+
+    fn district() -> SchoolDistrict {
+        SchoolDistrict {
+            name: "Minneapolis Public Schools".to_string(),
+            feed_url: "https://mpls.k12.mn.us/news.rss".to_string(),
+            schools: vec!["Lincoln Elementary".to_string(), "South High".to_string()],
+            alert_keywords: default_school_alert_keywords(),
+            location_name: "Davis Center".to_string(),
+            latitude: 44.9847,
+            longitude: -93.2837,
+        }
+    }
+
+    const FEED: &str = r#"<?xml version="1.0"?>
+        <rss version="2.0"><channel><title>MPS News</title>
+          <item><guid>a1</guid><title>Lincoln Elementary closed Monday</title>
+            <description>&lt;p&gt;Lincoln Elementary will be &lt;b&gt;closed&lt;/b&gt; due to a boiler failure.&lt;/p&gt;</description>
+            <link>https://mpls.k12.mn.us/news/a1</link><pubDate>Thu, 15 Oct 2026 18:00:00 GMT</pubDate></item>
+          <item><guid>a2</guid><title>Two-hour late start district-wide</title>
+            <link>https://mpls.k12.mn.us/news/a2</link><pubDate>Fri, 16 Oct 2026 11:00:00 GMT</pubDate></item>
+          <item><guid>a3</guid><title>October lunch menu</title>
+            <link>https://mpls.k12.mn.us/news/a3</link><pubDate>Fri, 16 Oct 2026 12:00:00 GMT</pubDate></item>
+          <item><guid>a4</guid><title>South High closed for flooding</title>
+            <link>https://mpls.k12.mn.us/news/a4</link><pubDate>Mon, 21 Sep 2026 12:00:00 GMT</pubDate></item>
+        </channel></rss>"#;
+
+    #[test]
+    fn recent_closure_announcements_name_their_schools() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let alerts = parse_announcements(FEED.as_bytes(), &district(), now).unwrap();
+
+        assert_eq!(alerts.len(), 2, "lunch menu and month-old closure are not alerts");
+        assert_eq!(alerts[0].effect, AlertEffect::Closure);
+        assert_eq!(alerts[0].affected, vec![AffectedEntity::School("Lincoln Elementary".to_string())]);
+        assert_eq!(
+            alerts[0].description.as_deref(),
+            Some("Lincoln Elementary will be closed due to a boiler failure.")
+        );
+        assert_eq!(alerts[1].effect, AlertEffect::Delay);
+        assert_eq!(
+            alerts[1].affected,
+            vec![AffectedEntity::School("Minneapolis Public Schools".to_string())]
+        );
+    }
+}
@@ -5,6 +5,9 @@
 // - MockSignalStore (SignalStore) — stateful in-memory graph
 // - FixedEmbedder (TextEmbedder) — deterministic hash-based vectors
 // - MockExtractor (SignalExtractor) — HashMap-based URL→ExtractionResult
+// Plus MockOpenData (OpenDataConnector), MockAgendas (AgendaConnector) and
+// MockServiceAlerts (ServiceAlertConnector) for the region's open-data
+// datasets, meeting bodies and transit/school alert feeds.
 //
 // Plus test helpers for constructing ScoutScope, SourceNode, NodeMeta etc.
 
@@ -17,14 +20,20 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use rootsignal_common::types::{
-    ActorNode, ArchivedFeed, ArchivedPage, ArchivedSearchResults, EvidenceNode, Node, NodeType,
-    Post, ScoutScope, SourceNode,
+    ActorNode, ArchivedFeed, ArchivedPage, ArchivedSearchResults, EvidenceNode, Fundraiser, ListedEvent,
+    LongVideo, Node, NodeType, PodcastEpisode, Post, PostComment, ScoutScope, SourceNode, TranscriptSegment,
+};
+use rootsignal_common::{
+    canonical_value, is_fundraiser_url, AgendaBody, Decision, DecisionKind, EntityLink, AgendaItem, CategoryRule, EntityMappingOwned, EvidenceRecheck, EvidenceVerification, OpenDataDataset, OpenDataFields,
+    Meeting, OpenDataRecord, OpenDataSignal, PortalKind, SchoolDistrict, ServiceAlert, Severity, TransitAgency,
 };
-use rootsignal_common::{canonical_value, EntityMappingOwned};
 use rootsignal_graph::DuplicateMatch;
 
+use crate::agendas::AgendaConnector;
+use crate::open_data::OpenDataConnector;
 use crate::pipeline::extractor::{ExtractionResult, SignalExtractor};
 use crate::pipeline::traits::{ContentFetcher, SignalStore};
+use crate::service_alerts::ServiceAlertConnector;
 
 // ---------------------------------------------------------------------------
 // Test constants
@@ -47,11 +56,18 @@ pub const DALLAS: (f64, f64) = (32.7767, -96.7970);
 // ---------------------------------------------------------------------------
 
 /// HashMap-based content fetcher. Returns `Err` for unregistered URLs.
-/// Builder pattern: `.on_page()`, `.on_search()`, `.on_posts()`, `.on_feed()`.
+/// Builder pattern: `.on_page()`, `.on_search()`, `.on_posts()`, `.on_feed()`, `.on_videos()`,
+/// `.on_episodes()`, `.on_events()`.
 pub struct MockFetcher {
     pages: HashMap<String, ArchivedPage>,
+    page_failures: Mutex<HashMap<String, Vec<String>>>,
     feeds: HashMap<String, ArchivedFeed>,
     posts: HashMap<String, Vec<Post>>,
+    comments: HashMap<String, Vec<PostComment>>,
+    media: HashMap<String, Vec<u8>>,
+    videos: HashMap<String, Vec<LongVideo>>,
+    episodes: HashMap<String, Vec<PodcastEpisode>>,
+    events: HashMap<String, Vec<ListedEvent>>,
     searches: HashMap<String, ArchivedSearchResults>,
     topic_searches: HashMap<String, Vec<Post>>,
     site_searches: HashMap<String, ArchivedSearchResults>,
@@ -61,8 +77,14 @@ impl MockFetcher {
     pub fn new() -> Self {
         Self {
             pages: HashMap::new(),
+            page_failures: Mutex::new(HashMap::new()),
             feeds: HashMap::new(),
             posts: HashMap::new(),
+            comments: HashMap::new(),
+            media: HashMap::new(),
+            videos: HashMap::new(),
+            episodes: HashMap::new(),
+            events: HashMap::new(),
             searches: HashMap::new(),
             topic_searches: HashMap::new(),
             site_searches: HashMap::new(),
@@ -74,6 +96,18 @@ impl MockFetcher {
         self
     }
 
+    /// Fail the next fetch of `url` with `error` before serving the registered page.
+    /// Call repeatedly to queue several failures.
+    pub fn fail_page_once(self, url: &str, error: &str) -> Self {
+        self.page_failures
+            .lock()
+            .unwrap()
+            .entry(url.to_string())
+            .or_default()
+            .push(error.to_string());
+        self
+    }
+
     #[allow(dead_code)] // scaffolding for future feed scrape tests
     pub fn on_feed(mut self, url: &str, feed: ArchivedFeed) -> Self {
         self.feeds.insert(url.to_string(), feed);
@@ -85,6 +119,31 @@ impl MockFetcher {
         self
     }
 
+    pub fn on_comments(mut self, post_url: &str, comments: Vec<PostComment>) -> Self {
+        self.comments.insert(post_url.to_string(), comments);
+        self
+    }
+
+    pub fn on_media(mut self, url: &str, bytes: &[u8]) -> Self {
+        self.media.insert(url.to_string(), bytes.to_vec());
+        self
+    }
+
+    pub fn on_videos(mut self, url: &str, videos: Vec<LongVideo>) -> Self {
+        self.videos.insert(url.to_string(), videos);
+        self
+    }
+
+    pub fn on_episodes(mut self, url: &str, episodes: Vec<PodcastEpisode>) -> Self {
+        self.episodes.insert(url.to_string(), episodes);
+        self
+    }
+
+    pub fn on_events(mut self, url: &str, events: Vec<ListedEvent>) -> Self {
+        self.events.insert(url.to_string(), events);
+        self
+    }
+
     pub fn on_search(mut self, query: &str, results: ArchivedSearchResults) -> Self {
         self.searches.insert(query.to_string(), results);
         self
@@ -105,6 +164,11 @@ impl MockFetcher {
 #[async_trait]
 impl ContentFetcher for MockFetcher {
     async fn page(&self, url: &str) -> Result<ArchivedPage> {
+        if let Some(queued) = self.page_failures.lock().unwrap().get_mut(url) {
+            if !queued.is_empty() {
+                bail!("{}", queued.remove(0));
+            }
+        }
         self.pages
             .get(url)
             .cloned()
@@ -125,6 +189,41 @@ impl ContentFetcher for MockFetcher {
             .ok_or_else(|| anyhow::anyhow!("MockFetcher: no posts registered for {identifier}"))
     }
 
+    async fn comments(&self, post_url: &str, _limit: u32) -> Result<Vec<PostComment>> {
+        self.comments
+            .get(post_url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("MockFetcher: no comments registered for {post_url}"))
+    }
+
+    async fn media(&self, url: &str) -> Result<Vec<u8>> {
+        self.media
+            .get(url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("MockFetcher: no media registered for {url}"))
+    }
+
+    async fn videos(&self, url: &str, _limit: u32) -> Result<Vec<LongVideo>> {
+        self.videos
+            .get(url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("MockFetcher: no videos registered for {url}"))
+    }
+
+    async fn episodes(&self, url: &str, _limit: u32) -> Result<Vec<PodcastEpisode>> {
+        self.episodes
+            .get(url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("MockFetcher: no episodes registered for {url}"))
+    }
+
+    async fn events(&self, url: &str, _limit: u32) -> Result<Vec<ListedEvent>> {
+        self.events
+            .get(url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("MockFetcher: no events registered for {url}"))
+    }
+
     async fn search(&self, query: &str) -> Result<ArchivedSearchResults> {
         self.searches
             .get(query)
@@ -165,6 +264,7 @@ impl ContentFetcher for MockFetcher {
 pub struct StoredSignal {
     pub id: Uuid,
     pub title: String,
+    pub summary: String,
     pub node_type: NodeType,
     pub source_url: String,
     pub corroboration_count: u32,
@@ -175,6 +275,10 @@ pub struct StoredSignal {
     pub about_location_name: Option<String>,
     pub confidence: f32,
     pub extracted_at: DateTime<Utc>,
+    /// Crowdfunding page behind a Need, if any.
+    pub fundraiser_url: Option<String>,
+    pub fundraiser: Option<Fundraiser>,
+    pub fundraiser_checked_at: Option<DateTime<Utc>>,
 }
 
 /// Actor-signal link in the mock graph.
@@ -202,13 +306,20 @@ struct MockSignalStoreInner {
     tags: HashMap<Uuid, Vec<String>>,
     blocked: HashSet<String>,
     processed_hashes: HashSet<(String, String)>,
+    /// evidence id → when its page was last read by a re-check
+    evidence_verified_at: HashMap<Uuid, DateTime<Utc>>,
     fail_on_create: bool,
+    /// Answer `find_duplicate` by cosine similarity over stored embeddings.
+    vector_dedup: bool,
     /// (actor_id, source_id) — HAS_SOURCE edges
     actor_sources: Vec<(Uuid, Uuid)>,
     /// (signal_id, source_id) — PRODUCED_BY edges
     signal_sources: Vec<(Uuid, Uuid)>,
+    /// (signal_id, registry entity id, link) — MENTIONS / LOCATED_IN edges
+    entity_links: Vec<(Uuid, String, EntityLink)>,
     /// entity_id → actor_id for find_actor_by_entity_id lookups
     actor_by_entity_id: HashMap<String, Uuid>,
+    decisions: Vec<Decision>,
 }
 
 /// Stateful in-memory graph mock. Thread-safe via interior Mutex.
@@ -234,10 +345,14 @@ impl MockSignalStore {
                 tags: HashMap::new(),
                 blocked: HashSet::new(),
                 processed_hashes: HashSet::new(),
+                evidence_verified_at: HashMap::new(),
                 fail_on_create: false,
+                vector_dedup: false,
                 actor_sources: Vec::new(),
                 signal_sources: Vec::new(),
+                entity_links: Vec::new(),
                 actor_by_entity_id: HashMap::new(),
+                decisions: Vec::new(),
             }),
         }
     }
@@ -248,6 +363,13 @@ impl MockSignalStore {
         self
     }
 
+    /// Make `find_duplicate` compare embeddings of stored signals of the same
+    /// type located inside the bounding box, like the graph's vector index.
+    pub fn with_vector_dedup(self) -> Self {
+        self.inner.lock().unwrap().vector_dedup = true;
+        self
+    }
+
     /// Pre-populate a blocked URL pattern.
     pub fn block_url(self, pattern: &str) -> Self {
         self.inner.lock().unwrap().blocked.insert(pattern.to_string());
@@ -347,6 +469,37 @@ impl MockSignalStore {
         }
     }
 
+    /// The latest re-check of each piece of evidence on the signal with this title.
+    pub fn evidence_verifications_for_title(&self, title: &str) -> Vec<rootsignal_common::EvidenceVerification> {
+        let Some(id) = self.signal_by_title(title).map(|s| s.id) else {
+            return Vec::new();
+        };
+        let inner = self.inner.lock().unwrap();
+        inner
+            .evidence
+            .iter()
+            .filter(|(sid, _)| *sid == id)
+            .filter_map(|(_, ev)| ev.verification.clone())
+            .collect()
+    }
+
+    /// The change summary on the latest evidence for a signal, if any.
+    pub fn change_summary_for_title(&self, title: &str) -> Option<String> {
+        let inner = self.inner.lock().unwrap();
+        let normalized = title.trim().to_lowercase();
+        let signal_id = inner
+            .signals
+            .values()
+            .find(|s| s.title.trim().to_lowercase() == normalized)?
+            .id;
+        inner
+            .evidence
+            .iter()
+            .rev()
+            .find(|(id, _)| *id == signal_id)
+            .and_then(|(_, ev)| ev.change_summary.clone())
+    }
+
     pub fn sources_promoted(&self) -> usize {
         self.inner.lock().unwrap().sources.len()
     }
@@ -496,6 +649,16 @@ impl MockSignalStore {
             .any(|(aid, sid)| *aid == actor_id && *sid == source_id)
     }
 
+    /// Latest fundraiser progress recorded on the signal with this title.
+    pub fn fundraiser_for(&self, signal_title: &str) -> Option<Fundraiser> {
+        self.signal_by_title(signal_title).and_then(|s| s.fundraiser)
+    }
+
+    /// The fundraiser URL tracked on the signal with this title, if any.
+    pub fn fundraiser_url_for(&self, signal_title: &str) -> Option<String> {
+        self.signal_by_title(signal_title).and_then(|s| s.fundraiser_url)
+    }
+
     pub fn signal_has_source(&self, signal_title: &str, source_id: Uuid) -> bool {
         let inner = self.inner.lock().unwrap();
         let normalized = signal_title.trim().to_lowercase();
@@ -513,6 +676,42 @@ impl MockSignalStore {
             .any(|(sid, src)| *sid == signal_id && *src == source_id)
     }
 
+    pub fn has_entity_link(&self, signal_title: &str, entity_id: &str, link: EntityLink) -> bool {
+        let inner = self.inner.lock().unwrap();
+        let normalized = signal_title.trim().to_lowercase();
+        let Some(signal_id) = inner
+            .signals
+            .values()
+            .find(|s| s.title.trim().to_lowercase() == normalized)
+            .map(|s| s.id)
+        else {
+            return false;
+        };
+        inner
+            .entity_links
+            .iter()
+            .any(|(sid, eid, l)| *sid == signal_id && eid == entity_id && *l == link)
+    }
+
+    /// Kinds of the decisions recorded about the signal with this title, oldest first.
+    pub fn decisions_for(&self, signal_title: &str) -> Vec<DecisionKind> {
+        let inner = self.inner.lock().unwrap();
+        let normalized = signal_title.trim().to_lowercase();
+        let Some(signal_id) = inner
+            .signals
+            .values()
+            .find(|s| s.title.trim().to_lowercase() == normalized)
+            .map(|s| s.id)
+        else {
+            return Vec::new();
+        };
+        inner
+            .decisions
+            .iter()
+            .filter(|d| d.subject_id == signal_id)
+            .map(|d| d.kind)
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -556,6 +755,7 @@ impl SignalStore for MockSignalStore {
         let stored = StoredSignal {
             id,
             title: title.clone(),
+            summary: meta.map(|m| m.summary.clone()).unwrap_or_default(),
             node_type,
             source_url: source_url.clone(),
             corroboration_count: 0,
@@ -566,6 +766,12 @@ impl SignalStore for MockSignalStore {
             about_location_name: meta.and_then(|m| m.about_location_name.clone()),
             confidence: meta.map(|m| m.confidence).unwrap_or(0.0),
             extracted_at: meta.map(|m| m.extracted_at).unwrap_or_else(Utc::now),
+            fundraiser_url: match node {
+                Node::Need(n) => n.fundraiser_url().map(str::to_string),
+                _ => None,
+            },
+            fundraiser: None,
+            fundraiser_checked_at: None,
         };
         inner.signals.insert(id, stored);
         inner
@@ -581,6 +787,13 @@ impl SignalStore for MockSignalStore {
 
     async fn create_evidence(&self, evidence: &EvidenceNode, signal_id: Uuid) -> Result<()> {
         let mut inner = self.inner.lock().unwrap();
+        if is_fundraiser_url(&evidence.source_url) {
+            if let Some(signal) = inner.signals.get_mut(&signal_id) {
+                if signal.node_type == NodeType::Need && signal.fundraiser_url.is_none() {
+                    signal.fundraiser_url = Some(evidence.source_url.clone());
+                }
+            }
+        }
         inner.evidence.push((signal_id, evidence.clone()));
         // Track hash+url as processed
         inner
@@ -616,6 +829,11 @@ impl SignalStore for MockSignalStore {
         Ok(())
     }
 
+    async fn record_decision(&self, decision: &Decision) -> Result<()> {
+        self.inner.lock().unwrap().decisions.push(decision.clone());
+        Ok(())
+    }
+
     async fn existing_titles_for_url(&self, url: &str) -> Result<Vec<String>> {
         let inner = self.inner.lock().unwrap();
         Ok(inner
@@ -647,18 +865,40 @@ impl SignalStore for MockSignalStore {
 
     async fn find_duplicate(
         &self,
-        _embedding: &[f32],
-        _primary_type: NodeType,
-        _threshold: f64,
-        _min_lat: f64,
-        _max_lat: f64,
-        _min_lng: f64,
-        _max_lng: f64,
+        embedding: &[f32],
+        primary_type: NodeType,
+        threshold: f64,
+        min_lat: f64,
+        max_lat: f64,
+        min_lng: f64,
+        max_lng: f64,
     ) -> Result<Option<DuplicateMatch>> {
         // MockSignalStore doesn't do vector similarity by default.
         // Chain tests that need dedup behavior should pre-populate via create_node
-        // and rely on title-based dedup (find_by_titles_and_types).
-        Ok(None)
+        // and rely on title-based dedup (find_by_titles_and_types), or opt in
+        // with `with_vector_dedup`.
+        let inner = self.inner.lock().unwrap();
+        if !inner.vector_dedup {
+            return Ok(None);
+        }
+        let best = inner
+            .signals
+            .values()
+            .filter(|s| s.node_type == primary_type)
+            .filter(|s| {
+                s.about_location.is_some_and(|p| {
+                    (min_lat..=max_lat).contains(&p.lat) && (min_lng..=max_lng).contains(&p.lng)
+                })
+            })
+            .map(|s| (s, cosine_similarity(embedding, &s.embedding)))
+            .filter(|(_, sim)| *sim >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        Ok(best.map(|(s, similarity)| DuplicateMatch {
+            id: s.id,
+            node_type: s.node_type,
+            source_url: s.source_url.clone(),
+            similarity,
+        }))
     }
 
     async fn find_actor_by_name(&self, name: &str) -> Result<Option<Uuid>> {
@@ -707,6 +947,12 @@ impl SignalStore for MockSignalStore {
         Ok(())
     }
 
+    async fn link_signal_to_entity(&self, signal_id: Uuid, entity_id: &str, link: EntityLink) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entity_links.push((signal_id, entity_id.to_string(), link));
+        Ok(())
+    }
+
     async fn find_actor_by_entity_id(&self, entity_id: &str) -> Result<Option<Uuid>> {
         let inner = self.inner.lock().unwrap();
         Ok(inner.actor_by_entity_id.get(entity_id).copied())
@@ -792,6 +1038,24 @@ impl SignalStore for MockSignalStore {
         Ok(())
     }
 
+    async fn find_signals_mentioning(
+        &self,
+        phrase: &str,
+        since: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>> {
+        let pattern = regex::Regex::new(&format!(r"(?i)\b{}\b", regex::escape(phrase.trim())))?;
+        let inner = self.inner.lock().unwrap();
+        let mut matches: Vec<&StoredSignal> = inner
+            .signals
+            .values()
+            .filter(|s| s.extracted_at >= since)
+            .filter(|s| pattern.is_match(&s.title) || pattern.is_match(&s.summary))
+            .collect();
+        matches.sort_by_key(|s| std::cmp::Reverse(s.extracted_at));
+        Ok(matches.iter().take(limit as usize).map(|s| s.id).collect())
+    }
+
     async fn get_signals_for_actor(
         &self,
         actor_id: Uuid,
@@ -838,6 +1102,97 @@ impl SignalStore for MockSignalStore {
             .map(|a| (a.clone(), Vec::new()))
             .collect())
     }
+
+    async fn fundraisers_due_for_refresh(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<(Uuid, String)>> {
+        let inner = self.inner.lock().unwrap();
+        let mut due: Vec<&StoredSignal> = inner
+            .signals
+            .values()
+            .filter(|s| s.fundraiser_url.is_some())
+            .filter(|s| s.fundraiser_checked_at.is_none_or(|t| t < checked_before))
+            .collect();
+        due.sort_by_key(|s| s.fundraiser_checked_at);
+        Ok(due
+            .into_iter()
+            .take(limit as usize)
+            .map(|s| (s.id, s.fundraiser_url.clone().unwrap_or_default()))
+            .collect())
+    }
+
+    async fn update_fundraiser(
+        &self,
+        need_id: Uuid,
+        checked_at: DateTime<Utc>,
+        progress: Option<&Fundraiser>,
+    ) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(signal) = inner.signals.get_mut(&need_id) {
+            signal.fundraiser_checked_at = Some(checked_at);
+            if let Some(progress) = progress {
+                signal.fundraiser = Some(progress.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn evidence_due_for_recheck(
+        &self,
+        checked_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<EvidenceRecheck>> {
+        let inner = self.inner.lock().unwrap();
+        let last_checked = |ev: &EvidenceNode| ev.verification.as_ref().map_or(ev.retrieved_at, |v| v.checked_at);
+        let mut due: Vec<&EvidenceNode> = inner
+            .evidence
+            .iter()
+            .map(|(_, ev)| ev)
+            .filter(|ev| ev.channel_type != Some(rootsignal_common::ChannelType::Social))
+            .filter(|ev| last_checked(ev) < checked_before)
+            .collect();
+        due.sort_by_key(|ev| last_checked(ev));
+        Ok(due
+            .into_iter()
+            .take(limit as usize)
+            .map(|ev| EvidenceRecheck {
+                evidence_id: ev.id,
+                source_url: ev.source_url.clone(),
+                content_hash: ev.content_hash.clone(),
+                snippet: ev.snippet.clone(),
+                freshness: ev.verification.as_ref().map(|v| v.freshness),
+                verified_at: inner.evidence_verified_at.get(&ev.id).copied().unwrap_or(ev.retrieved_at),
+            })
+            .collect())
+    }
+
+    async fn record_evidence_check(
+        &self,
+        evidence: &EvidenceRecheck,
+        verification: &EvidenceVerification,
+        read: bool,
+    ) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if read {
+            inner.evidence_verified_at.insert(evidence.evidence_id, verification.checked_at);
+        }
+        let Some((signal_id, ev)) = inner.evidence.iter_mut().find(|(_, ev)| ev.id == evidence.evidence_id) else {
+            return Ok(());
+        };
+        let kept_diff = ev.verification.as_ref().and_then(|v| v.drift_diff.clone());
+        ev.verification = Some(EvidenceVerification {
+            drift_diff: if read { verification.drift_diff.clone() } else { kept_diff },
+            ..verification.clone()
+        });
+        let signal_id = *signal_id;
+        if let Some(signal) = inner.signals.get_mut(&signal_id) {
+            let adjusted = signal.confidence as f64 * verification.freshness.confidence_adjustment(evidence.freshness);
+            signal.confidence = adjusted.min(1.0) as f32;
+        }
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -913,6 +1268,140 @@ impl rootsignal_common::TextEmbedder for FixedEmbedder {
     }
 }
 
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    (dot / (norm_a * norm_b)) as f64
+}
+
+// ---------------------------------------------------------------------------
+// MockOpenData
+// ---------------------------------------------------------------------------
+
+/// Dataset-name-keyed open-data connector. Returns `Err` for unregistered
+/// datasets; records are returned as registered, without date filtering.
+pub struct MockOpenData {
+    records: HashMap<String, Vec<OpenDataRecord>>,
+}
+
+impl MockOpenData {
+    pub fn new() -> Self {
+        Self {
+            records: HashMap::new(),
+        }
+    }
+
+    pub fn on_dataset(mut self, name: &str, records: Vec<OpenDataRecord>) -> Self {
+        self.records.insert(name.to_string(), records);
+        self
+    }
+}
+
+impl Default for MockOpenData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OpenDataConnector for MockOpenData {
+    async fn fetch(&self, dataset: &OpenDataDataset, _since: DateTime<Utc>) -> Result<Vec<OpenDataRecord>> {
+        match self.records.get(&dataset.name) {
+            Some(records) => Ok(records.clone()),
+            None => bail!("MockOpenData: no records for dataset {}", dataset.name),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MockAgendas
+// ---------------------------------------------------------------------------
+
+/// Body-name-keyed agenda connector. Returns `Err` for unregistered bodies.
+pub struct MockAgendas {
+    meetings: HashMap<String, Vec<Meeting>>,
+}
+
+impl MockAgendas {
+    pub fn new() -> Self {
+        Self {
+            meetings: HashMap::new(),
+        }
+    }
+
+    pub fn on_body(mut self, name: &str, meetings: Vec<Meeting>) -> Self {
+        self.meetings.insert(name.to_string(), meetings);
+        self
+    }
+}
+
+impl Default for MockAgendas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AgendaConnector for MockAgendas {
+    async fn meetings(&self, body: &AgendaBody, _now: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        match self.meetings.get(&body.name) {
+            Some(meetings) => Ok(meetings.clone()),
+            None => bail!("MockAgendas: no meetings for body {}", body.name),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MockServiceAlerts
+// ---------------------------------------------------------------------------
+
+/// Feed-name-keyed service alert connector (agency or district name).
+/// Returns `Err` for unregistered feeds.
+pub struct MockServiceAlerts {
+    alerts: HashMap<String, Vec<ServiceAlert>>,
+}
+
+impl MockServiceAlerts {
+    pub fn new() -> Self {
+        Self {
+            alerts: HashMap::new(),
+        }
+    }
+
+    pub fn on_feed(mut self, name: &str, alerts: Vec<ServiceAlert>) -> Self {
+        self.alerts.insert(name.to_string(), alerts);
+        self
+    }
+
+    fn get(&self, name: &str) -> Result<Vec<ServiceAlert>> {
+        match self.alerts.get(name) {
+            Some(alerts) => Ok(alerts.clone()),
+            None => bail!("MockServiceAlerts: no alerts for feed {name}"),
+        }
+    }
+}
+
+impl Default for MockServiceAlerts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ServiceAlertConnector for MockServiceAlerts {
+    async fn transit_alerts(&self, agency: &TransitAgency, _now: DateTime<Utc>) -> Result<Vec<ServiceAlert>> {
+        self.get(&agency.name)
+    }
+
+    async fn school_alerts(&self, district: &SchoolDistrict, _now: DateTime<Utc>) -> Result<Vec<ServiceAlert>> {
+        self.get(&district.name)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // MockExtractor
 // ---------------------------------------------------------------------------
@@ -922,6 +1411,10 @@ pub struct MockExtractor {
     results: HashMap<String, ExtractionResult>,
     /// Fallback result for any unregistered URL (optional).
     default_result: Option<ExtractionResult>,
+    /// URLs whose extraction panics.
+    panics: HashSet<String>,
+    /// URLs whose extraction never finishes.
+    stalls: HashSet<String>,
 }
 
 impl MockExtractor {
@@ -929,9 +1422,23 @@ impl MockExtractor {
         Self {
             results: HashMap::new(),
             default_result: None,
+            panics: HashSet::new(),
+            stalls: HashSet::new(),
         }
     }
 
+    /// Panic when extracting this URL.
+    pub fn panic_on(mut self, url: &str) -> Self {
+        self.panics.insert(url.to_string());
+        self
+    }
+
+    /// Hang indefinitely when extracting this URL.
+    pub fn stall_on(mut self, url: &str) -> Self {
+        self.stalls.insert(url.to_string());
+        self
+    }
+
     /// Register a URL→ExtractionResult mapping.
     pub fn on_url(mut self, url: &str, result: ExtractionResult) -> Self {
         self.results.insert(url.to_string(), result);
@@ -948,6 +1455,12 @@ impl MockExtractor {
 #[async_trait]
 impl SignalExtractor for MockExtractor {
     async fn extract(&self, _content: &str, source_url: &str) -> Result<ExtractionResult> {
+        if self.panics.contains(source_url) {
+            panic!("MockExtractor: malformed response for {source_url}");
+        }
+        if self.stalls.contains(source_url) {
+            std::future::pending::<()>().await;
+        }
         if let Some(result) = self.results.get(source_url) {
             return Ok(ExtractionResult {
                 nodes: result.nodes.clone(),
@@ -999,6 +1512,9 @@ pub fn tension(title: &str) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         severity: Severity::Medium,
         category: None,
@@ -1034,6 +1550,9 @@ pub fn tension_at(title: &str, lat: f64, lng: f64) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         severity: Severity::Medium,
         category: None,
@@ -1068,11 +1587,15 @@ pub fn need(title: &str) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         urgency: Urgency::Medium,
         what_needed: None,
         action_url: None,
         goal: None,
+        fundraiser: None,
     })
 }
 
@@ -1104,11 +1627,15 @@ pub fn need_at(title: &str, lat: f64, lng: f64) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         urgency: Urgency::Medium,
         what_needed: None,
         action_url: None,
         goal: None,
+        fundraiser: None,
     })
 }
 
@@ -1139,6 +1666,9 @@ pub fn gathering(title: &str) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         starts_at: None,
         ends_at: None,
@@ -1176,6 +1706,9 @@ pub fn gathering_at(title: &str, lat: f64, lng: f64) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         starts_at: None,
         ends_at: None,
@@ -1212,6 +1745,9 @@ pub fn aid(title: &str) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         action_url: String::new(),
         availability: None,
@@ -1247,6 +1783,9 @@ pub fn aid_at(title: &str, lat: f64, lng: f64) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         action_url: String::new(),
         availability: None,
@@ -1281,6 +1820,9 @@ pub fn notice(title: &str) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         severity: Severity::Medium,
         category: None,
@@ -1317,6 +1859,9 @@ pub fn notice_at(title: &str, lat: f64, lng: f64) -> Node {
             channel_diversity: 1,
             mentioned_actors: Vec::new(),
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         },
         severity: Severity::Medium,
         category: None,
@@ -1332,6 +1877,7 @@ pub fn mpls_region() -> ScoutScope {
         center_lng: -93.2650,
         radius_km: 15.0,
         name: "Minneapolis".to_string(),
+        boundary: None,
     }
 }
 
@@ -1374,6 +1920,221 @@ pub fn social_source(url: &str) -> SourceNode {
     )
 }
 
+/// Create a newsletter SourceNode keyed by sender address.
+pub fn newsletter_source(sender: &str) -> SourceNode {
+    let value = rootsignal_common::newsletter_source_value(sender);
+    SourceNode::new(
+        value.clone(),
+        value,
+        None,
+        rootsignal_common::DiscoveryMethod::HumanSubmission,
+        1.0,
+        rootsignal_common::SourceRole::Mixed,
+        None,
+    )
+}
+
+/// Create a minimal LongVideo with a timed transcript of `(start_secs, text)` lines.
+pub fn test_video(video_id: &str, transcript: &[(f64, &str)]) -> LongVideo {
+    LongVideo {
+        id: Uuid::new_v4(),
+        source_id: Uuid::new_v4(),
+        fetched_at: Utc::now(),
+        content_hash: String::new(),
+        text: None,
+        engagement: None,
+        published_at: None,
+        permalink: Some(format!("https://www.youtube.com/watch?v={video_id}")),
+        attachments: Vec::new(),
+        title: Some(format!("Video {video_id}")),
+        platform_id: Some(video_id.to_string()),
+        transcript: transcript
+            .iter()
+            .map(|(start_secs, text)| TranscriptSegment {
+                start_secs: *start_secs,
+                text: text.to_string(),
+            })
+            .collect(),
+    }
+}
+
+/// Create a minimal PodcastEpisode with a timed transcript of `(start_secs, text)` lines.
+pub fn test_episode(permalink: &str, transcript: &[(f64, &str)]) -> PodcastEpisode {
+    PodcastEpisode {
+        id: Uuid::new_v4(),
+        source_id: Uuid::new_v4(),
+        fetched_at: Utc::now(),
+        content_hash: String::new(),
+        guid: permalink.to_string(),
+        title: Some("Test episode".to_string()),
+        description: None,
+        published_at: None,
+        permalink: permalink.to_string(),
+        audio_url: format!("{permalink}.mp3"),
+        duration_secs: None,
+        transcript: transcript
+            .iter()
+            .map(|(start_secs, text)| TranscriptSegment {
+                start_secs: *start_secs,
+                text: text.to_string(),
+            })
+            .collect(),
+    }
+}
+
+/// Create a ListedEvent at a venue with coordinates, starting `starts_in` from now.
+pub fn test_listed_event(title: &str, permalink: &str, organizer: &str, starts_in: chrono::Duration) -> ListedEvent {
+    let starts_at = Utc::now() + starts_in;
+    ListedEvent {
+        id: Uuid::new_v4(),
+        source_id: Uuid::new_v4(),
+        fetched_at: Utc::now(),
+        content_hash: String::new(),
+        platform_id: permalink.to_string(),
+        title: title.to_string(),
+        description: Some(format!("{title}. All welcome.")),
+        starts_at: Some(starts_at),
+        ends_at: Some(starts_at + chrono::Duration::hours(2)),
+        venue_name: Some("Sumner Library".to_string()),
+        venue_address: Some("611 Van White Memorial Blvd, Minneapolis, MN".to_string()),
+        latitude: Some(44.9829),
+        longitude: Some(-93.2901),
+        is_online: false,
+        organizer_name: Some(organizer.to_string()),
+        organizer_url: None,
+        ticket_url: None,
+        permalink: permalink.to_string(),
+    }
+}
+
+/// A Socrata 311 dataset whose "No Heat" requests become High-severity
+/// Tensions and "Water Main" requests Medium-severity Notices.
+pub fn test_open_data_dataset(name: &str) -> OpenDataDataset {
+    OpenDataDataset {
+        name: name.to_string(),
+        portal: PortalKind::Socrata,
+        base_url: "https://opendata.minneapolismn.gov".to_string(),
+        dataset_id: "abcd-1234".to_string(),
+        fields: OpenDataFields {
+            id: "case_id".to_string(),
+            category: "request_type".to_string(),
+            opened_at: "opened_date".to_string(),
+            description: Some("details".to_string()),
+            latitude: Some("lat".to_string()),
+            longitude: Some("lon".to_string()),
+            address: Some("address".to_string()),
+        },
+        categories: vec![
+            CategoryRule {
+                contains: "no heat".to_string(),
+                signal: OpenDataSignal::Tension,
+                severity: Severity::High,
+            },
+            CategoryRule {
+                contains: "water main".to_string(),
+                signal: OpenDataSignal::Notice,
+                severity: Severity::Medium,
+            },
+        ],
+        max_records: 50,
+        lookback_days: 7,
+    }
+}
+
+/// Create a 311 record opened an hour ago at `(lat, lng)`.
+pub fn test_open_data_record(id: &str, category: &str, address: &str, lat: f64, lng: f64) -> OpenDataRecord {
+    OpenDataRecord {
+        id: id.to_string(),
+        category: category.to_string(),
+        description: None,
+        opened_at: Some(Utc::now() - chrono::Duration::hours(1)),
+        latitude: Some(lat),
+        longitude: Some(lng),
+        address: Some(address.to_string()),
+    }
+}
+
+/// A Legistar body meeting at Minneapolis City Hall.
+pub fn test_agenda_body(name: &str) -> AgendaBody {
+    AgendaBody {
+        name: name.to_string(),
+        legistar_client: "minneapolismn".to_string(),
+        body_name: None,
+        timezone: "America/Chicago".to_string(),
+        location_name: "Minneapolis City Hall".to_string(),
+        latitude: 44.9773,
+        longitude: -93.2655,
+        lookahead_days: 14,
+        lookback_days: 14,
+    }
+}
+
+/// A meeting `starts_in` from now with one item per title.
+pub fn test_meeting(url: &str, starts_in: chrono::Duration, titles: &[&str]) -> Meeting {
+    Meeting {
+        id: url.to_string(),
+        body_name: "City Council".to_string(),
+        starts_at: Utc::now() + starts_in,
+        location: None,
+        url: url.to_string(),
+        minutes_published: starts_in < chrono::Duration::zero(),
+        items: titles
+            .iter()
+            .enumerate()
+            .map(|(i, title)| AgendaItem {
+                id: i.to_string(),
+                number: Some(format!("{}.", i + 1)),
+                title: title.to_string(),
+                matter_type: Some("Ordinance".to_string()),
+                action: None,
+                note: None,
+            })
+            .collect(),
+    }
+}
+
+pub fn test_transit_agency(name: &str) -> TransitAgency {
+    TransitAgency {
+        name: name.to_string(),
+        alerts_url: "https://svc.metrotransit.org/mtgtfs/alerts.pb".to_string(),
+        routes: Vec::new(),
+        location_name: "Minneapolis".to_string(),
+        latitude: 44.9778,
+        longitude: -93.265,
+    }
+}
+
+pub fn test_school_district(name: &str, schools: &[&str]) -> SchoolDistrict {
+    SchoolDistrict {
+        name: name.to_string(),
+        feed_url: "https://mpls.k12.mn.us/news.rss".to_string(),
+        schools: schools.iter().map(|s| s.to_string()).collect(),
+        alert_keywords: rootsignal_common::service_alerts::default_school_alert_keywords(),
+        location_name: "Davis Center".to_string(),
+        latitude: 44.9847,
+        longitude: -93.2837,
+    }
+}
+
+/// An active alert linking to `url` and affecting `affected`.
+pub fn test_service_alert(
+    url: &str,
+    title: &str,
+    effect: rootsignal_common::AlertEffect,
+    affected: Vec<rootsignal_common::AffectedEntity>,
+) -> ServiceAlert {
+    ServiceAlert {
+        id: url.to_string(),
+        title: title.to_string(),
+        description: None,
+        url: Some(url.to_string()),
+        effect,
+        affected,
+        active_from: Some(Utc::now()),
+        active_until: None,
+    }
+}
+
 /// Create a minimal Post for testing social scrape.
 pub fn test_post(text: &str) -> Post {
     Post {
@@ -1391,6 +2152,7 @@ pub fn test_post(text: &str) -> Post {
         hashtags: Vec::new(),
         media_type: None,
         platform_id: None,
+        engagement_velocity: None,
         attachments: Vec::new(),
     }
 }
@@ -1419,6 +2181,9 @@ pub fn test_meta(source_url: &str) -> rootsignal_common::NodeMeta {
         channel_diversity: 1,
         mentioned_actors: Vec::new(),
         author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
     }
 }
 
@@ -1438,6 +2203,7 @@ pub fn archived_page(url: &str, markdown: &str) -> ArchivedPage {
         markdown: markdown.to_string(),
         title: None,
         links: Vec::new(),
+        previous_markdown: None,
     }
 }
 
@@ -1462,6 +2228,7 @@ pub fn search_results(query: &str, urls: &[&str]) -> ArchivedSearchResults {
                 snippet: String::new(),
             })
             .collect(),
+        provider: None,
     }
 }
 
@@ -11,6 +11,7 @@ use tracing::info;
 use rootsignal_graph::GraphWriter;
 
 use super::types::{BootstrapResult, EmptyRequest, TaskRequest};
+use crate::discovery::query_ledger::QueryLedger;
 use super::{create_archive, ScoutDeps};
 
 #[restate_sdk::workflow]
@@ -64,19 +65,26 @@ impl BootstrapWorkflow for BootstrapWorkflowImpl {
 
         ctx.set("status", "Starting bootstrap...".to_string());
         let archive = create_archive(&self.deps);
-        let api_key = self.deps.anthropic_api_key.clone();
+        let api_key = self.deps.anthropic_api_key.expose();
         let scope = req.scope.clone();
 
-        let graph_client = self.deps.graph_client.clone();
+        let ledger = QueryLedger::new(self.deps.pg_pool.clone(), &scope.name);
+
+        let deps = self.deps.clone();
         let sources_created = match ctx
             .run(|| async {
-                let writer = GraphWriter::new(graph_client);
+                let graph = deps
+                    .region_graph(&scope.name)
+                    .await
+                    .map_err(|e| -> HandlerError { TerminalError::new(e.to_string()).into() })?;
+                let writer = GraphWriter::new(graph);
                 let bootstrapper = crate::discovery::bootstrap::Bootstrapper::new(
                     &writer,
                     archive,
                     &api_key,
                     scope,
-                );
+                )
+                .with_ledger(ledger);
                 bootstrapper
                     .run()
                     .await
@@ -86,6 +94,8 @@ impl BootstrapWorkflow for BootstrapWorkflowImpl {
         {
             Ok(v) => v,
             Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "BootstrapWorkflow", &task_id, &req, &e.to_string()).await;
                 super::write_task_phase_status(&self.deps, &task_id, "idle").await;
                 return Err(e.into());
             }
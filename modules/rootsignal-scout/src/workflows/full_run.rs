@@ -3,15 +3,28 @@
 //! Orchestrator that calls all phase workflows in sequence:
 //! Bootstrap → Scrape → Synthesis → SituationWeaver → Supervisor
 //!
-//! Budget flows as `spent_cents` between workflows.
+//! Budget flows as `spent_cents` between workflows. `run_local` runs the
+//! same phases in-process, for the CLIs.
 
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+use anyhow::Context;
 use restate_sdk::prelude::*;
 use tracing::info;
 
+use rootsignal_common::{ScoutScope, Secret};
+use rootsignal_graph::GraphWriter;
+
 use super::types::*;
-use super::ScoutDeps;
+use super::{create_archive, ScoutDeps};
+use crate::infra::embedder::{Embedder, TextEmbedder};
+use crate::memory::memo::RegionMemoStore;
+use crate::open_data::PortalConnector;
+use crate::pipeline::scrape_pipeline::ScrapePipeline;
+use crate::pipeline::shadow::region_extractor;
+use crate::pipeline::stats::ScoutStats;
+use crate::scheduling::budget::BudgetTracker;
 
 #[restate_sdk::workflow]
 #[name = "FullScoutRunWorkflow"]
@@ -94,7 +107,7 @@ impl FullScoutRunWorkflow for FullScoutRunWorkflowImpl {
 
         // 4. Situation Weaving
         ctx.set("status", WorkflowPhase::SituationWeaving.to_string());
-        let _weaver_result: SituationWeaverResult = ctx
+        let weaver_result: SituationWeaverResult = ctx
             .workflow_client::<super::situation_weaver::SituationWeaverWorkflowClient>(&sub_key)
             .run(BudgetedTaskRequest {
                 task_id: task_id.clone(),
@@ -103,15 +116,17 @@ impl FullScoutRunWorkflow for FullScoutRunWorkflowImpl {
             })
             .call()
             .await?;
+        spent_cents = weaver_result.spent_cents;
         info!("Situation weaving phase complete");
 
         // 5. Supervisor
         ctx.set("status", WorkflowPhase::Supervisor.to_string());
         let supervisor_result: SupervisorResult = ctx
             .workflow_client::<super::supervisor::SupervisorWorkflowClient>(&sub_key)
-            .run(TaskRequest {
+            .run(BudgetedTaskRequest {
                 task_id: task_id.clone(),
                 scope: scope.clone(),
+                spent_cents,
             })
             .call()
             .await?;
@@ -138,3 +153,99 @@ impl FullScoutRunWorkflow for FullScoutRunWorkflowImpl {
         super::read_workflow_status(&ctx).await
     }
 }
+
+/// Run a full scout cycle in-process: scrape → synthesis → situation
+/// weaving → supervisor, then actor extraction. Calls the same functions
+/// the workflows do. Fails if a run is already in progress for the region.
+pub async fn run_local(deps: &ScoutDeps, region: ScoutScope) -> anyhow::Result<ScoutStats> {
+    if GraphWriter::new(deps.graph_client.clone())
+        .is_region_task_running(&region.name)
+        .await
+        .context("Failed to check running status")?
+    {
+        anyhow::bail!("Another scout run is in progress for {}", region.name);
+    }
+
+    let region_name = region.name.clone();
+    let (min_lat, max_lat, min_lng, max_lng) = region.bounding_box();
+    let stats = run_phases(deps, region).await?;
+    info!("Scout run complete. {stats}");
+
+    // Actor extraction — extract actors from signals that have none.
+    // Not yet part of any workflow, so it runs here post-run.
+    info!("Starting actor extraction...");
+    let region_client = deps.region_graph(&region_name).await?;
+    let writer = GraphWriter::new(region_client.clone());
+    let sweep_stats = crate::enrichment::actor_extractor::run_actor_extraction(
+        &writer,
+        &region_client,
+        &deps.anthropic_api_key.expose(),
+        &region_name,
+        min_lat,
+        max_lat,
+        min_lng,
+        max_lng,
+    )
+    .await;
+    info!("{sweep_stats}");
+
+    Ok(stats)
+}
+
+async fn run_phases(deps: &ScoutDeps, region: ScoutScope) -> anyhow::Result<ScoutStats> {
+    let region_memo = RegionMemoStore::new(deps.pg_pool.clone(), &region.name)
+        .load_or_default()
+        .await;
+    let flags = rootsignal_common::feature_flags::load_or_default(&deps.pg_pool, &region.name).await;
+    let run_id = uuid::Uuid::new_v4().to_string();
+    let extractor = region_extractor(
+        &deps.pg_pool,
+        &deps.anthropic_api_key.expose(),
+        &region,
+        &region_memo,
+        &run_id,
+    )
+    .await;
+    let embedder: Arc<dyn TextEmbedder> =
+        Arc::new(Embedder::new(&deps.voyage_api_key.expose()));
+    let archive = create_archive(deps);
+    let budget = BudgetTracker::new(deps.daily_budget_cents);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let writer = GraphWriter::new(deps.region_graph(&region.name).await?);
+
+    // === Scrape pipeline ===
+    let pipeline = ScrapePipeline::new(
+        writer,
+        extractor,
+        embedder,
+        archive,
+        deps.anthropic_api_key.expose(),
+        region.clone(),
+        &budget,
+        cancelled,
+        run_id,
+        deps.pg_pool.clone(),
+    )
+    .with_open_data(Arc::new(PortalConnector::new(
+        deps.socrata_app_token.as_ref().map(Secret::expose),
+    )))
+    .with_region_memo(region_memo)
+    .with_flags(flags);
+    let stats = pipeline.run_all().await?;
+
+    let spent_so_far = budget.total_spent();
+
+    // === Synthesis (parallel finders + similarity edges) ===
+    let synthesis_result = super::synthesis::run_synthesis_from_deps(deps, &region, spent_so_far).await?;
+
+    // === Situation weaving + source boost + curiosity re-investigation ===
+    let weaver_result = super::situation_weaver::run_situation_weaving_from_deps(
+        deps, &region, synthesis_result.spent_cents,
+    ).await?;
+
+    // === Supervisor (merge tensions, compute cause heat, detect beacons) ===
+    let _supervisor_result =
+        super::supervisor::run_supervisor_pipeline(deps, &region, weaver_result.spent_cents).await?;
+
+    Ok(stats)
+}
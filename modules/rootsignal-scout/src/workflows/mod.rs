@@ -9,20 +9,28 @@
 pub mod bootstrap;
 pub mod full_run;
 pub mod news_scanner;
+pub mod onboarding;
 pub mod scrape;
 pub mod situation_weaver;
 pub mod supervisor;
 pub mod synthesis;
 pub mod types;
+pub mod verification;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use restate_sdk::prelude::*;
-use rootsignal_archive::{Archive, ArchiveConfig, PageBackend, RestateDispatcher};
-use rootsignal_graph::GraphClient;
+use rootsignal_archive::{
+    Archive, ArchiveConfig, BlobBackend, PageBackend, RestateDispatcher, SearchBackend, SearchProviderConfig,
+    TranscriptionBackend,
+};
+use rootsignal_common::Secret;
+use rootsignal_graph::{GraphClient, GraphRouter};
 use sqlx::PgPool;
 use typed_builder::TypedBuilder;
 
+pub use rootsignal_common::restate::read_workflow_status;
+
 /// Shared dependency container for all scout workflows.
 ///
 /// Mirrors mntogether's `ServerDeps` pattern. Holds long-lived, cloneable
@@ -30,45 +38,150 @@ use typed_builder::TypedBuilder;
 /// constructed from these deps at the start of each workflow invocation.
 #[derive(Clone, TypedBuilder)]
 pub struct ScoutDeps {
+    /// The default database: scout tasks and their status.
     pub graph_client: GraphClient,
+    /// Routes each region's data to its database; unset = everything in `graph_client`.
+    #[builder(default)]
+    pub graph_router: Option<GraphRouter>,
     pub pg_pool: PgPool,
-    pub anthropic_api_key: String,
-    pub voyage_api_key: String,
-    pub serper_api_key: String,
+    pub anthropic_api_key: Secret,
+    pub voyage_api_key: Secret,
+    pub serper_api_key: Secret,
+    #[builder(default)]
+    pub apify_api_key: Secret,
+    /// The API's Apify webhook receiver; with the secret, runs report
+    /// completion by webhook instead of being long-polled.
+    #[builder(default)]
+    pub apify_webhook_url: Option<String>,
+    #[builder(default)]
+    pub apify_webhook_secret: Option<Secret>,
+    #[builder(default)]
+    pub tavily_api_key: Option<Secret>,
+    #[builder(default)]
+    pub brave_search_api_key: Option<Secret>,
+    #[builder(default)]
+    pub searxng_url: Option<String>,
+    /// Search provider fallback order; empty = Serper, then every other configured provider.
     #[builder(default)]
-    pub apify_api_key: String,
+    pub search_providers: Vec<String>,
+    /// Queries per UTC day per search provider; unlisted providers are unlimited.
+    #[builder(default)]
+    pub search_daily_limits: HashMap<String, u32>,
     pub daily_budget_cents: u64,
     #[builder(default)]
     pub browserless_url: Option<String>,
     #[builder(default)]
-    pub browserless_token: Option<String>,
+    pub browserless_token: Option<Secret>,
+    #[builder(default)]
+    pub source_credentials_key: Option<Secret>,
+    #[builder(default)]
+    pub openai_api_key: Option<Secret>,
+    #[builder(default)]
+    pub whisper_bin: Option<String>,
+    #[builder(default = "base".to_string())]
+    pub whisper_model: String,
+    #[builder(default)]
+    pub eventbrite_token: Option<Secret>,
+    #[builder(default)]
+    pub socrata_app_token: Option<Secret>,
+    #[builder(default)]
+    pub blob_store_path: Option<String>,
+    #[builder(default)]
+    pub s3_endpoint: Option<String>,
+    #[builder(default)]
+    pub s3_bucket: Option<String>,
+    #[builder(default = "us-east-1".to_string())]
+    pub s3_region: String,
+    #[builder(default)]
+    pub s3_access_key_id: Option<Secret>,
+    #[builder(default)]
+    pub s3_secret_access_key: Option<Secret>,
+    #[builder(default = "GLACIER_IR".to_string())]
+    pub s3_cold_storage_class: String,
+    /// Days before archived blobs move to cold storage; 0 = never.
+    #[builder(default = 30)]
+    pub blob_cold_after_days: u32,
     #[builder(default = 50)]
     pub max_web_queries_per_run: usize,
+    /// Languages public summaries are translated into; empty = plain-language English only.
+    #[builder(default)]
+    pub summary_languages: Vec<String>,
     #[builder(default)]
     pub restate_ingress_url: Option<String>,
+    /// Report what the scheduled retention purge would delete without deleting.
+    #[builder(default)]
+    pub retention_dry_run: bool,
 }
 
 impl ScoutDeps {
     /// Convenience constructor from Config — keeps API-side construction clean.
     pub fn from_config(
-        graph_client: GraphClient,
+        graph_router: GraphRouter,
         pg_pool: PgPool,
         config: &rootsignal_common::Config,
     ) -> Self {
         Self::builder()
-            .graph_client(graph_client)
+            .graph_client(graph_router.default_client().clone())
+            .graph_router(Some(graph_router))
             .pg_pool(pg_pool)
             .anthropic_api_key(config.anthropic_api_key.clone())
             .voyage_api_key(config.voyage_api_key.clone())
             .serper_api_key(config.serper_api_key.clone())
             .apify_api_key(config.apify_api_key.clone())
+            .apify_webhook_url(config.apify_webhook_url.clone())
+            .apify_webhook_secret(config.apify_webhook_secret.clone())
+            .tavily_api_key(config.tavily_api_key.clone())
+            .brave_search_api_key(config.brave_search_api_key.clone())
+            .searxng_url(config.searxng_url.clone())
+            .search_providers(config.search_providers.clone())
+            .search_daily_limits(config.search_daily_limits.clone())
             .daily_budget_cents(config.daily_budget_cents)
             .browserless_url(config.browserless_url.clone())
             .browserless_token(config.browserless_token.clone())
+            .source_credentials_key(config.source_credentials_key.clone())
+            .openai_api_key(config.openai_api_key.clone())
+            .whisper_bin(config.whisper_bin.clone())
+            .whisper_model(config.whisper_model.clone())
+            .eventbrite_token(config.eventbrite_token.clone())
+            .socrata_app_token(config.socrata_app_token.clone())
+            .blob_store_path(config.blob_store_path.clone())
+            .s3_endpoint(config.s3_endpoint.clone())
+            .s3_bucket(config.s3_bucket.clone())
+            .s3_region(config.s3_region.clone())
+            .s3_access_key_id(config.s3_access_key_id.clone())
+            .s3_secret_access_key(config.s3_secret_access_key.clone())
+            .s3_cold_storage_class(config.s3_cold_storage_class.clone())
+            .blob_cold_after_days(config.blob_cold_after_days)
             .max_web_queries_per_run(config.max_web_queries_per_run)
+            .summary_languages(config.summary_languages.clone())
             .restate_ingress_url(std::env::var("RESTATE_INGRESS_URL").ok().filter(|s| !s.is_empty()))
+            .retention_dry_run(std::env::var("RETENTION_DRY_RUN").is_ok_and(|v| v == "true" || v == "1"))
             .build()
     }
+
+    /// The graph holding a region's signals, sources and settings.
+    pub async fn region_graph(&self, region: &str) -> anyhow::Result<GraphClient> {
+        match &self.graph_router {
+            Some(router) => Ok(router.for_region(region).await?),
+            None => Ok(self.graph_client.clone()),
+        }
+    }
+
+    /// Re-resolve API keys if `error` looks like a rejected credential, so the
+    /// next invocation (or Restate retry) picks up a rotated key.
+    pub fn rotate_secrets_on_auth_failure(&self, error: &str) {
+        for secret in [
+            &self.anthropic_api_key,
+            &self.voyage_api_key,
+            &self.serper_api_key,
+            &self.apify_api_key,
+        ] {
+            secret.rotate_on_auth_failure(error);
+        }
+        for secret in [&self.tavily_api_key, &self.brave_search_api_key].into_iter().flatten() {
+            secret.rotate_on_auth_failure(error);
+        }
+    }
 }
 
 /// Create an `Archive` from the shared deps.
@@ -79,16 +192,24 @@ pub fn create_archive(deps: &ScoutDeps) -> Arc<Archive> {
         page_backend: match deps.browserless_url {
             Some(ref url) => PageBackend::Browserless {
                 base_url: url.clone(),
-                token: deps.browserless_token.clone(),
+                token: deps.browserless_token.as_ref().map(Secret::expose),
             },
             None => PageBackend::Chrome,
         },
-        serper_api_key: deps.serper_api_key.clone(),
-        apify_api_key: if deps.apify_api_key.is_empty() {
-            None
-        } else {
-            Some(deps.apify_api_key.clone())
+        search_providers: search_providers(deps),
+        apify_api_key: Some(deps.apify_api_key.expose()).filter(|k| !k.is_empty()),
+        apify_webhook_url: apify_webhook_url(deps),
+        credentials_key: deps.source_credentials_key.as_ref().map(Secret::expose),
+        transcription: match (&deps.whisper_bin, &deps.openai_api_key) {
+            (Some(bin), _) => TranscriptionBackend::LocalWhisper {
+                bin: bin.clone(),
+                model: deps.whisper_model.clone(),
+            },
+            (None, Some(key)) => TranscriptionBackend::WhisperApi { api_key: key.expose() },
+            (None, None) => TranscriptionBackend::None,
         },
+        eventbrite_token: deps.eventbrite_token.as_ref().map(Secret::expose),
+        blob_backend: blob_backend(deps),
     };
 
     let dispatcher = deps.restate_ingress_url.as_ref().map(|url| {
@@ -98,10 +219,81 @@ pub fn create_archive(deps: &ScoutDeps) -> Arc<Archive> {
     Arc::new(Archive::new(deps.pg_pool.clone(), archive_config, dispatcher))
 }
 
+/// The Apify webhook receiver URL with its secret, when both are configured.
+fn apify_webhook_url(deps: &ScoutDeps) -> Option<String> {
+    let url = deps.apify_webhook_url.as_deref()?;
+    let secret = deps.apify_webhook_secret.as_ref()?.expose();
+    let separator = if url.contains('?') { '&' } else { '?' };
+    Some(format!("{url}{separator}key={secret}"))
+}
+
+/// Web search providers in fallback order: `SEARCH_PROVIDERS` if set, else
+/// Serper followed by every other provider that has a key or URL.
+pub fn search_providers(deps: &ScoutDeps) -> Vec<SearchProviderConfig> {
+    const DEFAULT_ORDER: [&str; 4] = ["serper", "tavily", "brave", "searxng"];
+    let order: Vec<&str> = if deps.search_providers.is_empty() {
+        DEFAULT_ORDER.to_vec()
+    } else {
+        deps.search_providers.iter().map(String::as_str).collect()
+    };
+
+    order
+        .into_iter()
+        .filter_map(|name| {
+            let backend = match name {
+                "serper" => SearchBackend::Serper { api_key: deps.serper_api_key.expose() },
+                "tavily" => SearchBackend::Tavily { api_key: deps.tavily_api_key.as_ref()?.expose() },
+                "brave" => SearchBackend::Brave { api_key: deps.brave_search_api_key.as_ref()?.expose() },
+                "searxng" => SearchBackend::Searxng { base_url: deps.searxng_url.clone()? },
+                other => {
+                    tracing::warn!(provider = other, "Unknown search provider in SEARCH_PROVIDERS, ignoring");
+                    return None;
+                }
+            };
+            Some(SearchProviderConfig {
+                backend,
+                daily_limit: deps.search_daily_limits.get(name).copied(),
+            })
+        })
+        .collect()
+}
+
+/// The archive's blob store: S3 if a bucket is configured, else a local
+/// directory, else Postgres only.
+pub fn blob_backend(deps: &ScoutDeps) -> BlobBackend {
+    match (&deps.s3_bucket, &deps.blob_store_path) {
+        (Some(bucket), _) => BlobBackend::S3 {
+            endpoint: deps.s3_endpoint.clone(),
+            bucket: bucket.clone(),
+            region: deps.s3_region.clone(),
+            access_key_id: deps.s3_access_key_id.as_ref().map(Secret::expose).unwrap_or_default(),
+            secret_access_key: deps.s3_secret_access_key.as_ref().map(Secret::expose).unwrap_or_default(),
+            cold_storage_class: deps.s3_cold_storage_class.clone(),
+        },
+        (None, Some(path)) => BlobBackend::Filesystem { root: path.into() },
+        (None, None) => BlobBackend::Postgres,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Workflow helpers — shared across all workflows
 // ---------------------------------------------------------------------------
 
+/// Persist a terminally failed workflow request to the dead-letter queue so it
+/// can be replayed later instead of being dropped.
+pub async fn dead_letter(
+    deps: &ScoutDeps,
+    workflow: &str,
+    item_key: &str,
+    payload: &impl serde::Serialize,
+    error: &str,
+) {
+    match rootsignal_common::dead_letter::record(&deps.pg_pool, workflow, item_key, payload, error).await {
+        Ok(id) => tracing::info!(%id, workflow, item_key, "Failed work item dead-lettered"),
+        Err(e) => tracing::warn!(%e, workflow, item_key, "Failed to dead-letter work item"),
+    }
+}
+
 /// Write phase status to the ScoutTask node.
 /// Called by individual workflows to persist completion status for the admin UI.
 pub async fn write_task_phase_status(deps: &ScoutDeps, task_id: &str, status: &str) {
@@ -111,50 +303,3 @@ pub async fn write_task_phase_status(deps: &ScoutDeps, task_id: &str, status: &s
     }
 }
 
-/// Read the `"status"` key from Restate workflow state. Returns `"pending"` if unset.
-///
-/// Every workflow exposes a `get_status` shared handler with identical logic;
-/// this extracts the common body so each handler is a one-liner.
-pub async fn read_workflow_status(ctx: &SharedWorkflowContext<'_>) -> Result<String, HandlerError> {
-    Ok(ctx
-        .get::<String>("status")
-        .await?
-        .unwrap_or_else(|| "pending".to_string()))
-}
-
-
-// ---------------------------------------------------------------------------
-// Restate serde bridge macros (from mntogether)
-// ---------------------------------------------------------------------------
-
-/// Implement Restate SDK serialization traits for types that already have serde derives.
-///
-/// Bridges `serde::{Serialize, Deserialize}` to Restate's custom serialization traits
-/// without needing the `Json<>` wrapper.
-#[macro_export]
-macro_rules! impl_restate_serde {
-    ($type:ty) => {
-        impl restate_sdk::serde::Serialize for $type {
-            type Error = serde_json::Error;
-
-            fn serialize(&self) -> Result<bytes::Bytes, Self::Error> {
-                serde_json::to_vec(self).map(bytes::Bytes::from)
-            }
-        }
-
-        impl restate_sdk::serde::Deserialize for $type {
-            type Error = serde_json::Error;
-
-            fn deserialize(bytes: &mut bytes::Bytes) -> Result<Self, Self::Error> {
-                serde_json::from_slice(bytes)
-            }
-        }
-
-        impl restate_sdk::serde::WithContentType for $type {
-            fn content_type() -> &'static str {
-                "application/json"
-            }
-        }
-    };
-}
-
@@ -6,10 +6,12 @@
 use std::sync::Arc;
 
 use restate_sdk::prelude::*;
-use tracing::info;
+use tracing::{info, warn};
 
 use rootsignal_graph::GraphWriter;
 
+use crate::pipeline::news_scanner::{NewsScanner, TopicTargets, MAX_TARGET_SITUATIONS};
+
 use super::types::{EmptyRequest, NewsScanResult};
 use super::ScoutDeps;
 
@@ -41,13 +43,20 @@ impl NewsScanWorkflow for NewsScanWorkflowImpl {
 
         let deps = self.deps.clone();
 
-        let result = ctx
+        let result = match ctx
             .run(|| async {
                 run_news_scan_from_deps(&deps)
                     .await
                     .map_err(|e| -> HandlerError { TerminalError::new(e.to_string()).into() })
             })
-            .await?;
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                super::dead_letter(&self.deps, "NewsScanWorkflow", "global", &EmptyRequest, &e.to_string()).await;
+                return Err(e.into());
+            }
+        };
 
         ctx.set(
             "status",
@@ -79,17 +88,35 @@ pub async fn run_news_scan_from_deps(deps: &ScoutDeps) -> anyhow::Result<NewsSca
     let archive = super::create_archive(deps);
     let writer = GraphWriter::new(deps.graph_client.clone());
 
-    let scanner = crate::pipeline::news_scanner::NewsScanner::new(
+    let mut scanner = NewsScanner::new(
         archive,
-        &deps.anthropic_api_key,
+        &deps.anthropic_api_key.expose(),
         writer,
         deps.daily_budget_cents,
     );
 
-    let (articles_scanned, beacons_created) = scanner.scan().await?;
+    // Target each region's active situations
+    for region in crate::pipeline::news_scanner::local_feed_regions() {
+        let situations = match deps.region_graph(&region).await {
+            Ok(client) => GraphWriter::new(client)
+                .get_situation_landscape(MAX_TARGET_SITUATIONS)
+                .await
+                .map_err(anyhow::Error::from),
+            Err(e) => Err(e),
+        };
+        match situations {
+            Ok(situations) => {
+                scanner = scanner.with_topic_targets(TopicTargets::from_situations(&region, &situations));
+            }
+            Err(e) => warn!(region, error = %e, "Failed to load situations for news topic targets"),
+        }
+    }
+
+    let report = scanner.scan().await?;
 
     Ok(NewsScanResult {
-        articles_scanned,
-        beacons_created,
+        articles_scanned: report.articles_scanned,
+        beacons_created: report.beacons_created,
+        by_tier: report.by_tier,
     })
 }
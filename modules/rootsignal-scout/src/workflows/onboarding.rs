@@ -0,0 +1,283 @@
+//! Restate durable workflow for onboarding a new region.
+//!
+//! Runs `BootstrapWorkflow` for the region's task, seeds a paused schedule
+//! and the region's synthesis budget, dry-runs a sample of the approved
+//! bootstrap sources, and activates the region if its readiness checklist
+//! passes. Progress is saved on the region's onboarding record at each stage.
+
+use std::sync::Arc;
+
+use restate_sdk::prelude::*;
+use tracing::{info, warn};
+
+use rootsignal_common::onboarding::{DEFAULT_SCHEDULE_CRON, DRY_RUN_SAMPLE};
+use rootsignal_common::synthesis_budget::{self, AllocationMode};
+use rootsignal_common::{
+    scraping_strategy, slugify, DryRunReport, OnboardingStage, RegionOnboarding, RegionSchedule, ReviewStatus,
+    ScrapingStrategy,
+};
+use rootsignal_graph::{GraphClient, GraphWriter};
+
+use super::types::{BootstrapResult, EmptyRequest, OnboardingRequest, OnboardingResult, TaskRequest};
+use super::{create_archive, ScoutDeps};
+use crate::pipeline::traits::ContentFetcher;
+
+#[restate_sdk::workflow]
+#[name = "RegionOnboardingWorkflow"]
+pub trait RegionOnboardingWorkflow {
+    async fn run(req: OnboardingRequest) -> Result<OnboardingResult, HandlerError>;
+    #[shared]
+    async fn get_status(req: EmptyRequest) -> Result<String, HandlerError>;
+}
+
+pub struct RegionOnboardingWorkflowImpl {
+    deps: Arc<ScoutDeps>,
+}
+
+impl RegionOnboardingWorkflowImpl {
+    pub fn with_deps(deps: Arc<ScoutDeps>) -> Self {
+        Self { deps }
+    }
+}
+
+impl RegionOnboardingWorkflow for RegionOnboardingWorkflowImpl {
+    async fn run(
+        &self,
+        ctx: WorkflowContext<'_>,
+        req: OnboardingRequest,
+    ) -> Result<OnboardingResult, HandlerError> {
+        let slug = slugify(&req.scope.name);
+        let sub_key = format!("{slug}-onboarding-{}", ctx.key());
+
+        // 1. Bootstrap
+        ctx.set("status", "Bootstrapping sources...".to_string());
+        let deps = self.deps.clone();
+        ctx.run(|| async {
+            save_progress(&deps, &req, OnboardingStage::Bootstrapping, "Bootstrapping sources", None).await;
+            Ok(())
+        })
+        .await?;
+
+        let bootstrap: Result<BootstrapResult, _> = ctx
+            .workflow_client::<super::bootstrap::BootstrapWorkflowClient>(&sub_key)
+            .run(TaskRequest {
+                task_id: req.task_id.clone(),
+                scope: req.scope.clone(),
+            })
+            .call()
+            .await;
+        let sources_created = match bootstrap {
+            Ok(result) => result.sources_created,
+            Err(e) => {
+                let error = e.to_string();
+                ctx.run(|| async {
+                    save_progress(&deps, &req, OnboardingStage::Failed, "Bootstrap failed", Some(error.clone())).await;
+                    Ok(())
+                })
+                .await?;
+                return Err(e.into());
+            }
+        };
+        info!(region = slug.as_str(), sources_created, "Onboarding bootstrap complete");
+
+        // 2. Seed, dry run and check readiness
+        ctx.set("status", format!("Bootstrap created {sources_created} sources; checking readiness..."));
+        let result = match ctx
+            .run(|| async {
+                finish_onboarding(&deps, &req, sources_created)
+                    .await
+                    .map_err(|e| -> HandlerError { TerminalError::new(e.to_string()).into() })
+            })
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "RegionOnboardingWorkflow", &req.task_id, &req, &e.to_string()).await;
+                let error = Some(e.to_string());
+                save_progress(&self.deps, &req, OnboardingStage::Failed, "Onboarding failed", error).await;
+                return Err(e.into());
+            }
+        };
+
+        let status = if result.active { "Region active" } else { "Awaiting bootstrap review" };
+        ctx.set("status", status.to_string());
+        info!(region = slug.as_str(), active = result.active, "RegionOnboardingWorkflow complete");
+
+        Ok(result)
+    }
+
+    async fn get_status(
+        &self,
+        ctx: SharedWorkflowContext<'_>,
+        _req: EmptyRequest,
+    ) -> Result<String, HandlerError> {
+        super::read_workflow_status(&ctx).await
+    }
+}
+
+/// The region's onboarding record, or a fresh one for the request.
+async fn load_onboarding(client: &GraphClient, req: &OnboardingRequest) -> RegionOnboarding {
+    let slug = slugify(&req.scope.name);
+    match rootsignal_graph::onboarding::onboarding(client, &slug).await {
+        Ok(Some(onboarding)) => onboarding,
+        Ok(None) => RegionOnboarding::new(&slug, &req.scope.name, &req.task_id, &req.timezone),
+        Err(e) => {
+            warn!(region = slug.as_str(), error = %e, "Failed to load onboarding record");
+            RegionOnboarding::new(&slug, &req.scope.name, &req.task_id, &req.timezone)
+        }
+    }
+}
+
+async fn save(client: &GraphClient, onboarding: &RegionOnboarding) {
+    if let Err(e) = rootsignal_graph::onboarding::save_onboarding(client, onboarding).await {
+        warn!(region = onboarding.region.as_str(), error = %e, "Failed to save onboarding progress");
+    }
+}
+
+async fn save_progress(
+    deps: &ScoutDeps,
+    req: &OnboardingRequest,
+    stage: OnboardingStage,
+    progress: &str,
+    error: Option<String>,
+) {
+    let mut onboarding = load_onboarding(&deps.graph_client, req).await;
+    onboarding.advance(stage, progress);
+    onboarding.error = error;
+    save(&deps.graph_client, &onboarding).await;
+}
+
+async fn finish_onboarding(
+    deps: &ScoutDeps,
+    req: &OnboardingRequest,
+    sources_created: u32,
+) -> anyhow::Result<OnboardingResult> {
+    let slug = slugify(&req.scope.name);
+    let writer = GraphWriter::new(deps.graph_client.clone());
+    let mut onboarding = load_onboarding(&deps.graph_client, req).await;
+
+    // Seed a paused schedule (keeping one set up by hand) and the synthesis budget
+    onboarding.advance(
+        OnboardingStage::Seeding,
+        format!("Bootstrap created {sources_created} sources; seeding schedule and budget"),
+    );
+    save(&deps.graph_client, &onboarding).await;
+    if writer.get_region_schedule(&slug).await?.is_none() {
+        let schedule = RegionSchedule {
+            task_id: req.task_id.clone(),
+            cron: DEFAULT_SCHEDULE_CRON.to_string(),
+            timezone: req.timezone.clone(),
+            paused: true,
+            last_run_at: None,
+            updated_at: chrono::Utc::now(),
+        };
+        schedule.validate().map_err(anyhow::Error::msg)?;
+        writer.set_region_schedule(&slug, &schedule).await?;
+    }
+    let overrides = synthesis_budget::list_overrides(&deps.pg_pool, Some(&req.scope.name)).await?;
+    if !overrides.iter().any(|o| o.region.is_some()) {
+        let region = Some(req.scope.name.as_str());
+        synthesis_budget::set_mode(&deps.pg_pool, AllocationMode::default(), region, "onboarding").await?;
+    }
+
+    // Dry run: fetch a sample of the approved sources, storing nothing
+    onboarding.advance(OnboardingStage::DryRun, "Fetching a sample of approved sources");
+    save(&deps.graph_client, &onboarding).await;
+    let region_graph = deps.region_graph(&req.scope.name).await?;
+    let approved = rootsignal_graph::bootstrap_review::bootstrap_candidates(
+        &region_graph,
+        &slug,
+        Some(ReviewStatus::Approved),
+    )
+    .await?;
+    let values: Vec<String> = approved.iter().map(|c| c.canonical_value.clone()).collect();
+    let archive = create_archive(deps);
+    onboarding.dry_run = Some(dry_run(archive.as_ref(), &values).await);
+
+    // Readiness checklist
+    let active = onboarding.evaluate(approved.len() as u32);
+    if active {
+        writer.set_region_schedule_paused(&slug, false).await?;
+    }
+    onboarding.error = None;
+    save(&deps.graph_client, &onboarding).await;
+
+    Ok(OnboardingResult { sources_created, active })
+}
+
+/// Re-evaluate a region's readiness checklist, e.g. after its bootstrap
+/// sources were reviewed, and activate it if it now passes. Returns None if
+/// the region wasn't onboarded through `createRegion`.
+pub async fn recheck_readiness(
+    client: &GraphClient,
+    region_graph: &GraphClient,
+    region: &str,
+) -> anyhow::Result<Option<RegionOnboarding>> {
+    let Some(mut onboarding) = rootsignal_graph::onboarding::onboarding(client, region).await? else {
+        return Ok(None);
+    };
+    let approved =
+        rootsignal_graph::bootstrap_review::bootstrap_candidates(region_graph, region, Some(ReviewStatus::Approved))
+            .await?;
+    if onboarding.evaluate(approved.len() as u32) {
+        GraphWriter::new(client.clone()).set_region_schedule_paused(region, false).await?;
+    }
+    rootsignal_graph::onboarding::save_onboarding(client, &onboarding).await?;
+    Ok(Some(onboarding))
+}
+
+/// Fetch up to `DRY_RUN_SAMPLE` of the sources that are read as pages or
+/// feeds. Searches, social accounts and other sources fetched through
+/// platform APIs aren't sampled.
+pub async fn dry_run(fetcher: &dyn ContentFetcher, source_values: &[String]) -> DryRunReport {
+    let mut report = DryRunReport { fetched: 0, failed: 0 };
+    let sampled = source_values.iter().filter_map(|value| {
+        let is_feed = match scraping_strategy(value) {
+            ScrapingStrategy::WebPage | ScrapingStrategy::HtmlListing { .. } => false,
+            ScrapingStrategy::Rss | ScrapingStrategy::Podcast => true,
+            _ => return None,
+        };
+        Some((value, is_feed))
+    });
+    for (url, is_feed) in sampled.take(DRY_RUN_SAMPLE) {
+        let fetched = if is_feed {
+            fetcher.feed(url).await.map(|_| ())
+        } else {
+            fetcher.page(url).await.map(|_| ())
+        };
+        match fetched {
+            Ok(()) => report.fetched += 1,
+            Err(e) => {
+                warn!(url = url.as_str(), error = %e, "Onboarding dry-run fetch failed");
+                report.failed += 1;
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{archived_page, MockFetcher};
+
+    #[tokio::test]
+    async fn dry_run_fetches_pages_and_skips_searches_and_social_accounts() {
+        let fetcher = MockFetcher::new()
+            .on_page("https://northfieldfoodshelf.org", archived_page("https://northfieldfoodshelf.org", "# Hours"))
+            .on_page("https://ci.northfield.mn.us/news", archived_page("https://ci.northfield.mn.us/news", "# News"));
+        let sources = [
+            "https://northfieldfoodshelf.org",
+            "https://ci.northfield.mn.us/news",
+            "https://gone.example.org",
+            "northfield mn volunteer opportunities",
+            "https://www.instagram.com/northfieldmutualaid",
+        ]
+        .map(String::from);
+
+        let report = dry_run(&fetcher, &sources).await;
+
+        assert_eq!(report, DryRunReport { fetched: 2, failed: 1 });
+    }
+}
@@ -79,6 +79,8 @@ impl ScrapeWorkflow for ScrapeWorkflowImpl {
         {
             Ok(v) => v,
             Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "ScrapeWorkflow", &task_id, &req, &e.to_string()).await;
                 super::write_task_phase_status(&self.deps, &task_id, "idle").await;
                 return Err(e.into());
             }
@@ -115,41 +117,54 @@ async fn run_scrape_from_deps(
     deps: &ScoutDeps,
     scope: &rootsignal_common::ScoutScope,
 ) -> anyhow::Result<ScrapeResult> {
-    let writer = GraphWriter::new(deps.graph_client.clone());
-    let extractor: Arc<dyn crate::pipeline::extractor::SignalExtractor> =
-        Arc::new(crate::pipeline::extractor::Extractor::new(
-            &deps.anthropic_api_key,
-            scope.name.as_str(),
-            scope.center_lat,
-            scope.center_lng,
-        ));
+    let graph = deps.region_graph(&scope.name).await?;
+    let writer = GraphWriter::new(graph.clone());
+    let region_memo = crate::memory::memo::RegionMemoStore::new(deps.pg_pool.clone(), &scope.name)
+        .load_or_default()
+        .await;
+    let flags = rootsignal_common::feature_flags::load_or_default(&deps.pg_pool, &scope.name).await;
+    let run_id = uuid::Uuid::new_v4().to_string();
+    let extractor = crate::pipeline::shadow::region_extractor(
+        &deps.pg_pool,
+        &deps.anthropic_api_key.expose(),
+        scope,
+        &region_memo,
+        &run_id,
+    )
+    .await;
     let embedder: Arc<dyn crate::infra::embedder::TextEmbedder> =
-        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key));
+        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key.expose()));
     let region_slug = rootsignal_common::slugify(&scope.name);
     let archive = create_archive(deps);
     let budget = crate::scheduling::budget::BudgetTracker::new(deps.daily_budget_cents);
-    let run_id = uuid::Uuid::new_v4().to_string();
 
     let pipeline = crate::pipeline::scrape_pipeline::ScrapePipeline::new(
         writer,
         extractor,
         embedder,
         archive,
-        deps.anthropic_api_key.clone(),
+        deps.anthropic_api_key.expose(),
         scope.clone(),
         &budget,
         Arc::new(AtomicBool::new(false)),
         run_id.clone(),
         deps.pg_pool.clone(),
-    );
+    )
+    .with_open_data(Arc::new(crate::open_data::PortalConnector::new(
+        deps.socrata_app_token.as_ref().map(rootsignal_common::Secret::expose),
+    )))
+    .with_region_memo(region_memo)
+    .with_flags(flags);
 
-    let mut run_log = crate::infra::run_log::RunLog::new(run_id, scope.name.clone());
+    let mut run_log = crate::infra::run_log::RunLog::new(run_id, scope.name.clone()).with_flags(flags);
 
     pipeline.reap_expired_signals(&mut run_log).await;
     let (run, mut ctx) = pipeline.load_and_schedule_sources(&mut run_log).await?;
     pipeline.scrape_tension_sources(&run, &mut ctx, &mut run_log).await;
     let (_, social_topics) = pipeline.discover_mid_run_sources().await;
     pipeline.scrape_response_sources(&run, social_topics, &mut ctx, &mut run_log).await?;
+    pipeline.run_backfills(&run, &mut run_log).await;
+    pipeline.refresh_fundraisers().await;
     pipeline.update_source_metrics(&run, &ctx).await;
     pipeline.expand_and_discover(&run, &mut ctx, &mut run_log).await?;
 
@@ -1,7 +1,9 @@
 //! Restate durable workflow for situation weaving.
 //!
-//! Runs situation weaving (assigns signals to living situations),
-//! source boost for hot situations, and curiosity-triggered re-investigation.
+//! Runs situation weaving (assigns signals to living situations), topic
+//! category normalization, escalation risk scoring, source boost for hot
+//! situations, curiosity-triggered re-investigation, and targeted pulls that
+//! answer situations' information requests.
 
 use std::sync::Arc;
 
@@ -77,6 +79,8 @@ impl SituationWeaverWorkflow for SituationWeaverWorkflowImpl {
         {
             Ok(v) => v,
             Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "SituationWeaverWorkflow", &task_id, &req, &e.to_string()).await;
                 super::write_task_phase_status(&self.deps, &task_id, "idle").await;
                 return Err(e.into());
             }
@@ -104,9 +108,10 @@ pub async fn run_situation_weaving_from_deps(
     scope: &rootsignal_common::ScoutScope,
     spent_cents: u64,
 ) -> anyhow::Result<SituationWeaverResult> {
-    let writer = GraphWriter::new(deps.graph_client.clone());
+    let graph = deps.region_graph(&scope.name).await?;
+    let writer = GraphWriter::new(graph.clone());
     let embedder: Arc<dyn crate::infra::embedder::TextEmbedder> =
-        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key));
+        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key.expose()));
     let budget = BudgetTracker::new_with_spent(deps.daily_budget_cents, spent_cents);
     let run_id = uuid::Uuid::new_v4().to_string();
 
@@ -115,8 +120,8 @@ pub async fn run_situation_weaving_from_deps(
     // ================================================================
     info!("Starting situation weaving...");
     let situation_weaver = rootsignal_graph::SituationWeaver::new(
-        deps.graph_client.clone(),
-        &deps.anthropic_api_key,
+        graph.clone(),
+        &deps.anthropic_api_key.expose(),
         Arc::clone(&embedder),
         scope.clone(),
     );
@@ -133,6 +138,99 @@ pub async fn run_situation_weaving_from_deps(
         }
     };
 
+    // ================================================================
+    // Topic taxonomy (free-text categories mapped to canonical ones)
+    // ================================================================
+    if let Err(e) = rootsignal_graph::taxonomy::normalize_categories(&graph, embedder.as_ref()).await {
+        warn!(error = %e, "Category normalization failed (non-fatal)");
+    }
+
+    // ================================================================
+    // Political geography (situations re-tagged with the wards/districts
+    // their centroids now fall in)
+    // ================================================================
+    let region_slug = rootsignal_common::slugify(&scope.name);
+    match writer.get_region_registry(&region_slug).await {
+        Ok(registry) if registry.areas().next().is_some() => {
+            if let Err(e) = writer.link_located_in(&region_slug, &registry).await {
+                warn!(error = %e, "Failed to link situations to districts");
+            }
+        }
+        Ok(_) => {}
+        Err(e) => warn!(error = %e, "Failed to load region registry"),
+    }
+
+    // ================================================================
+    // Census context (who lives where each situation is unfolding)
+    // ================================================================
+    match writer.get_region_demographics(&region_slug).await {
+        Ok(Some(config)) => {
+            crate::enrichment::demographics::refresh_situation_demographics(
+                &crate::enrichment::demographics::AcsClient::new(),
+                &writer,
+                &region_slug,
+                &config,
+                scope,
+                chrono::Utc::now(),
+            )
+            .await;
+        }
+        Ok(None) => {}
+        Err(e) => warn!(error = %e, "Failed to load region demographics"),
+    }
+
+    // ================================================================
+    // Trends (weekly counts per category and neighborhood)
+    // ================================================================
+    if let Err(e) = rootsignal_graph::trends::refresh_trends(
+        &graph,
+        &region_slug,
+        scope.bounding_box(),
+        chrono::Utc::now(),
+    )
+    .await
+    {
+        warn!(error = %e, "Trend detection failed (non-fatal)");
+    }
+
+    // ================================================================
+    // Escalation risk (after census/trend context, before source boost)
+    // ================================================================
+    if let Err(e) = rootsignal_graph::escalation::refresh_escalation_risk(
+        &graph,
+        scope.bounding_box(),
+        chrono::Utc::now(),
+    )
+    .await
+    {
+        warn!(error = %e, "Escalation risk refresh failed (non-fatal)");
+    }
+
+    // ================================================================
+    // Summary variants (plain-language and translated summaries of the
+    // most visible situations and signals)
+    // ================================================================
+    match writer
+        .get_summary_variant_sources(
+            scope.bounding_box(),
+            crate::enrichment::summary_variants::MAX_VARIANT_SITUATIONS,
+            crate::enrichment::summary_variants::MAX_VARIANT_SIGNALS,
+        )
+        .await
+    {
+        Ok(sources) => {
+            crate::enrichment::summary_variants::SummaryVariantGenerator::new(
+                &deps.pg_pool,
+                &deps.anthropic_api_key.expose(),
+                &budget,
+                &deps.summary_languages,
+            )
+            .run(&sources)
+            .await;
+        }
+        Err(e) => warn!(error = %e, "Failed to load summary variant sources"),
+    }
+
     // ================================================================
     // Situation-driven source boost
     // ================================================================
@@ -178,6 +276,21 @@ pub async fn run_situation_weaving_from_deps(
         Err(e) => warn!(error = %e, "Failed to trigger situation curiosity"),
     }
 
+    // ================================================================
+    // Situation information requests (targeted pull)
+    // ================================================================
+    let archive = super::create_archive(deps);
+    let puller = crate::discovery::information_puller::InformationPuller::new(
+        &writer,
+        archive.as_ref(),
+        &deps.anthropic_api_key.expose(),
+        &scope.name,
+    );
+    let pull_stats = puller.run(scope, &budget).await;
+    if pull_stats.requests_open > 0 {
+        info!("{pull_stats}");
+    }
+
     Ok(SituationWeaverResult {
         situations_woven: weaver_stats.situations_created + weaver_stats.situations_updated,
         spent_cents: budget.total_spent(),
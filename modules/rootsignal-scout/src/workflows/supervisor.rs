@@ -1,22 +1,26 @@
 //! Restate durable workflow for the supervisor.
 //!
 //! Wraps post-run cleanup: `Supervisor::run()` + `merge_duplicate_tensions`
-//! + `compute_cause_heat`.
+//! + `compute_cause_heat` + region membership + story energy + the retention purge
+//! + region stats and the run's methodology record.
 
 use std::sync::Arc;
 
 use restate_sdk::prelude::*;
 use tracing::{info, warn};
 
+use rootsignal_common::extraction_prompts::{self, PromptStatus};
+use rootsignal_common::region_stats::BUILTIN_PROMPT_VERSION;
+use rootsignal_common::RunMethodology;
 use rootsignal_graph::GraphWriter;
 
-use super::types::{EmptyRequest, TaskRequest, SupervisorResult};
+use super::types::{BudgetedTaskRequest, EmptyRequest, SupervisorResult};
 use super::ScoutDeps;
 
 #[restate_sdk::workflow]
 #[name = "SupervisorWorkflow"]
 pub trait SupervisorWorkflow {
-    async fn run(req: TaskRequest) -> Result<SupervisorResult, HandlerError>;
+    async fn run(req: BudgetedTaskRequest) -> Result<SupervisorResult, HandlerError>;
     #[shared]
     async fn get_status(req: EmptyRequest) -> Result<String, HandlerError>;
 }
@@ -35,7 +39,7 @@ impl SupervisorWorkflow for SupervisorWorkflowImpl {
     async fn run(
         &self,
         ctx: WorkflowContext<'_>,
-        req: TaskRequest,
+        req: BudgetedTaskRequest,
     ) -> Result<SupervisorResult, HandlerError> {
         let task_id = req.task_id.clone();
 
@@ -63,10 +67,11 @@ impl SupervisorWorkflow for SupervisorWorkflowImpl {
 
         let deps = self.deps.clone();
         let scope = req.scope.clone();
+        let spent_cents = req.spent_cents;
 
         let result = match ctx
             .run(|| async {
-                run_supervisor_pipeline(&deps, &scope)
+                run_supervisor_pipeline(&deps, &scope, spent_cents)
                     .await
                     .map_err(|e| -> HandlerError { TerminalError::new(e.to_string()).into() })
             })
@@ -74,6 +79,8 @@ impl SupervisorWorkflow for SupervisorWorkflowImpl {
         {
             Ok(v) => v,
             Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "SupervisorWorkflow", &task_id, &req, &e.to_string()).await;
                 super::write_task_phase_status(&self.deps, &task_id, "idle").await;
                 return Err(e.into());
             }
@@ -102,8 +109,10 @@ impl SupervisorWorkflow for SupervisorWorkflowImpl {
 pub async fn run_supervisor_pipeline(
     deps: &ScoutDeps,
     scope: &rootsignal_common::ScoutScope,
+    spent_cents: u64,
 ) -> anyhow::Result<SupervisorResult> {
-    let writer = GraphWriter::new(deps.graph_client.clone());
+    let graph = deps.region_graph(&scope.name).await?;
+    let writer = GraphWriter::new(graph.clone());
     let (min_lat, max_lat, min_lng, max_lng) = scope.bounding_box();
 
     // 1. Run supervisor checks
@@ -111,11 +120,12 @@ pub async fn run_supervisor_pipeline(
         Box::new(rootsignal_scout_supervisor::notify::noop::NoopBackend);
 
     let supervisor = rootsignal_scout_supervisor::supervisor::Supervisor::new(
-        deps.graph_client.clone(),
+        graph.clone(),
         scope.clone(),
-        deps.anthropic_api_key.clone(),
+        deps.anthropic_api_key.expose(),
         notifier,
-    );
+    )
+    .with_schedule_graph(deps.graph_client.clone());
 
     let issues_found = match supervisor.run().await {
         Ok(stats) => {
@@ -139,27 +149,94 @@ pub async fn run_supervisor_pipeline(
     }
 
     // 3. Compute cause heat
-    match rootsignal_graph::cause_heat::compute_cause_heat(
-        &deps.graph_client,
-        0.7,
-        min_lat,
-        max_lat,
-        min_lng,
-        max_lng,
-    )
-    .await
+    match rootsignal_graph::cause_heat::compute_cause_heat(&graph, 0.7, scope).await
     {
         Ok(_) => info!("Cause heat computed"),
         Err(e) => warn!(error = %e, "Failed to compute cause heat"),
     }
 
-    // 4. Detect beacons (geographic signal clusters → new ScoutTasks)
-    match rootsignal_graph::beacon::detect_beacons(&deps.graph_client, &writer).await {
+    // 3b. Assign region membership, sharing signals with overlapping regions
+    let region_slug = rootsignal_common::slugify(&scope.name);
+    match writer.register_region_scope(&region_slug, scope).await {
+        Ok(scopes) => {
+            if let Err(e) = rootsignal_graph::region_membership::assign_signal_regions(&graph, &region_slug, &scopes).await {
+                warn!(error = %e, "Failed to assign signal regions");
+            }
+        }
+        Err(e) => warn!(error = %e, "Failed to register region scope"),
+    }
+
+    // 4. Recompute story energy (after cause heat, one of its inputs) with the region's weights
+    let weights = match rootsignal_graph::story_energy::region_energy_weights(
+        &graph,
+        &rootsignal_common::slugify(&scope.name),
+    )
+    .await
+    {
+        Ok(w) => w.unwrap_or_default(),
+        Err(e) => {
+            warn!(error = %e, "Failed to load region energy weights, using defaults");
+            rootsignal_common::StoryEnergyWeights::default()
+        }
+    };
+    let bbox = rootsignal_common::BoundingBox { min_lat, max_lat, min_lng, max_lng };
+    match rootsignal_graph::story_energy::recompute_story_energy(&graph, &weights, Some(&bbox)).await {
+        Ok(stories) => info!(stories, "Story energy recomputed"),
+        Err(e) => warn!(error = %e, "Failed to recompute story energy"),
+    }
+
+    // 5. Detect beacons (geographic signal clusters → new ScoutTasks)
+    match rootsignal_graph::beacon::detect_beacons(&graph, &writer).await {
         Ok(tasks) if !tasks.is_empty() => info!(count = tasks.len(), "Beacon tasks created"),
         Ok(_) => {}
         Err(e) => warn!(error = %e, "Beacon detection failed"),
     }
 
+    // 6. Retention purge (policies and legal holds are global, not per region)
+    if let Err(e) =
+        crate::infra::retention::run_purge(&graph, &deps.pg_pool, deps.retention_dry_run).await
+    {
+        warn!(error = %e, "Retention purge failed");
+    }
+
+    // 7. Blob lifecycle: cold storage for old blobs, deletion of unreferenced ones
+    if let Err(e) = rootsignal_archive::blob::apply_lifecycle(
+        &deps.pg_pool,
+        super::blob_backend(deps),
+        deps.blob_cold_after_days,
+        deps.retention_dry_run,
+    )
+    .await
+    {
+        warn!(error = %e, "Blob lifecycle failed");
+    }
+
+    // 8. Materialize dashboard stats now that the run's changes are in
+    if let Err(e) =
+        rootsignal_graph::region_stats::refresh_region_stats(&graph, &rootsignal_common::slugify(&scope.name)).await
+    {
+        warn!(error = %e, "Failed to materialize region stats");
+    }
+
+    // 9. Record what the run spent and which extraction prompt it used
+    let prompt = match extraction_prompts::active_for(&deps.pg_pool, &scope.name, PromptStatus::Live).await {
+        Ok(prompt) => prompt,
+        Err(e) => {
+            warn!(error = %e, "Failed to load live extraction prompt");
+            None
+        }
+    };
+    let run = RunMethodology {
+        finished_at: chrono::Utc::now(),
+        spent_cents,
+        budget_cents: deps.daily_budget_cents,
+        prompt_version: prompt.as_ref().map_or(BUILTIN_PROMPT_VERSION.to_string(), |p| p.name.clone()),
+        prompt_live_since: prompt.and_then(|p| p.promoted_at),
+    };
+    if let Err(e) = rootsignal_graph::region_stats::record_run_methodology(&graph, &region_slug, &run).await {
+        warn!(error = %e, "Failed to record run methodology");
+    }
+
     Ok(SupervisorResult {
         issues_found: issues_found as u32,
     })
@@ -1,7 +1,11 @@
 //! Restate durable workflow for synthesis.
 //!
 //! Runs similarity edges + parallel finders (response mapping, tension linker,
-//! response finder, gathering finder, investigation).
+//! response finder, gathering finder, investigation). The budget left after
+//! scraping is allocated between the finders by priority (see
+//! `rootsignal_common::synthesis_budget`), and the allocation is saved as
+//! the synthesis run's log. Each finder's failures are recorded for the
+//! supervisor's error budgets.
 
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -11,6 +15,17 @@ use tracing::{info, warn};
 
 use rootsignal_graph::{GraphWriter, SimilarityBuilder};
 
+use rootsignal_common::error_budget;
+use rootsignal_common::synthesis_budget::{
+    self, allocate, PhaseAllocation, PhaseDemand, PhaseOutcome, SynthesisBudgetPolicy, SynthesisPhase,
+};
+
+use crate::discovery::gathering_finder::MAX_GRAVITY_TARGETS_PER_RUN;
+use crate::discovery::investigator::MAX_SIGNALS_INVESTIGATED;
+use crate::discovery::response_finder::MAX_RESPONSE_TARGETS_PER_RUN;
+use crate::discovery::tension_linker::MAX_TENSION_LINKER_TARGETS_PER_RUN;
+use crate::infra::run_log::{EventKind, RunLog};
+use crate::pipeline::stats::ScoutStats;
 use crate::scheduling::budget::{BudgetTracker, OperationCost};
 
 use super::types::{BudgetedTaskRequest, EmptyRequest, SynthesisResult};
@@ -81,6 +96,8 @@ impl SynthesisWorkflow for SynthesisWorkflowImpl {
         {
             Ok(v) => v,
             Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                super::dead_letter(&self.deps, "SynthesisWorkflow", &task_id, &req, &e.to_string()).await;
                 super::write_task_phase_status(&self.deps, &task_id, "idle").await;
                 return Err(e.into());
             }
@@ -108,39 +125,54 @@ pub async fn run_synthesis_from_deps(
     scope: &rootsignal_common::ScoutScope,
     spent_cents: u64,
 ) -> anyhow::Result<SynthesisResult> {
-    let writer = GraphWriter::new(deps.graph_client.clone());
+    let graph = deps.region_graph(&scope.name).await?;
+    let writer = GraphWriter::new(graph.clone());
     let embedder: Arc<dyn crate::infra::embedder::TextEmbedder> =
-        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key));
+        Arc::new(crate::infra::embedder::Embedder::new(&deps.voyage_api_key.expose()));
     let archive = create_archive(deps);
     let budget = BudgetTracker::new_with_spent(deps.daily_budget_cents, spent_cents);
     let cancelled = Arc::new(AtomicBool::new(false));
     let run_id = uuid::Uuid::new_v4().to_string();
+    let region_memo = crate::memory::memo::RegionMemoStore::new(deps.pg_pool.clone(), &scope.name)
+        .load_or_default()
+        .await;
 
     // Parallel synthesis — similarity edges + finders run concurrently.
     // Finders don't read SIMILAR_TO edges; only StoryWeaver does (runs after).
     info!("Starting parallel synthesis (similarity edges, response mapping, tension linker, response finder, gathering finder, investigation)...");
 
-    let run_response_mapping = budget
-        .has_budget(OperationCost::CLAUDE_HAIKU_SYNTHESIS * 10);
-    let run_tension_linker = budget.has_budget(
-        OperationCost::CLAUDE_HAIKU_TENSION_LINKER + OperationCost::SEARCH_TENSION_LINKER,
-    );
-    let run_response_finder = budget.has_budget(
-        OperationCost::CLAUDE_HAIKU_RESPONSE_FINDER + OperationCost::SEARCH_RESPONSE_FINDER,
-    );
-    let run_gathering_finder = budget.has_budget(
-        OperationCost::CLAUDE_HAIKU_GATHERING_FINDER + OperationCost::SEARCH_GATHERING_FINDER,
-    );
-    let run_investigation = budget.has_budget(
-        OperationCost::CLAUDE_HAIKU_INVESTIGATION + OperationCost::SEARCH_INVESTIGATION,
-    );
+    let policy = synthesis_budget::load_or_default(&deps.pg_pool, &scope.name).await;
+    let allocations = allocate_phases(&budget, &policy);
+    log_allocations(deps, &run_id, &scope.name, &budget, &policy, &allocations).await;
+    let allocation = |phase: SynthesisPhase| {
+        allocations
+            .iter()
+            .find(|a| a.phase == phase)
+            .filter(|a| a.outcome == PhaseOutcome::Run)
+    };
+    let skip_reason = |phase: SynthesisPhase| {
+        allocations
+            .iter()
+            .find(|a| a.phase == phase)
+            .map_or("not allocated", |a| a.outcome.as_str())
+    };
+
+    let run_response_mapping = allocation(SynthesisPhase::ResponseMapping).is_some();
+    let tension_linker_targets = allocation(SynthesisPhase::TensionLinker)
+        .map(|a| a.units(TENSION_LINKER_UNIT_CENTS, MAX_TENSION_LINKER_TARGETS_PER_RUN as usize));
+    let response_finder_targets = allocation(SynthesisPhase::ResponseFinder)
+        .map(|a| a.units(RESPONSE_FINDER_UNIT_CENTS, MAX_RESPONSE_TARGETS_PER_RUN));
+    let gathering_finder_targets = allocation(SynthesisPhase::GatheringFinder)
+        .map(|a| a.units(GATHERING_FINDER_UNIT_CENTS, MAX_GRAVITY_TARGETS_PER_RUN));
+    let investigation_targets = allocation(SynthesisPhase::Investigation)
+        .map(|a| a.units(INVESTIGATION_UNIT_CENTS, MAX_SIGNALS_INVESTIGATED));
 
     let run_id_owned = run_id.to_string();
 
     let (sim_result, rm_result, tl_result, rf_result, gf_result, inv_result) = tokio::join!(
         async {
             info!("Building similarity edges...");
-            let similarity = SimilarityBuilder::new(deps.graph_client.clone());
+            let similarity = SimilarityBuilder::new(graph.clone());
             similarity.clear_edges().await.unwrap_or_else(|e| {
                 warn!(error = %e, "Failed to clear similarity edges");
                 0
@@ -154,8 +186,8 @@ pub async fn run_synthesis_from_deps(
             if run_response_mapping {
                 info!("Starting response mapping...");
                 let response_mapper = rootsignal_graph::response::ResponseMapper::new(
-                    deps.graph_client.clone(),
-                    &deps.anthropic_api_key,
+                    graph.clone(),
+                    &deps.anthropic_api_key.expose(),
                     scope.center_lat,
                     scope.center_lng,
                     scope.radius_km,
@@ -164,87 +196,195 @@ pub async fn run_synthesis_from_deps(
                     Ok(rm_stats) => info!("{rm_stats}"),
                     Err(e) => warn!(error = %e, "Response mapping failed (non-fatal)"),
                 }
-            } else if budget.is_active() {
-                info!("Skipping response mapping (budget exhausted)");
+            } else {
+                info!(reason = skip_reason(SynthesisPhase::ResponseMapping), "Skipping response mapping");
             }
         },
         async {
-            if run_tension_linker {
+            if let Some(max_targets) = tension_linker_targets {
                 info!("Starting tension linker...");
                 let tension_linker = crate::discovery::tension_linker::TensionLinker::new(
                     &writer,
                     archive.clone(),
                     &*embedder,
-                    &deps.anthropic_api_key,
+                    &deps.anthropic_api_key.expose(),
                     scope.clone(),
                     cancelled.clone(),
                     run_id_owned.clone(),
-                );
+                )
+                .with_max_targets(max_targets);
                 let tl_stats = tension_linker.run().await;
                 info!("{tl_stats}");
-            } else if budget.is_active() {
-                info!("Skipping tension linker (budget exhausted)");
+                Some(tl_stats.tally())
+            } else {
+                info!(reason = skip_reason(SynthesisPhase::TensionLinker), "Skipping tension linker");
+                None
             }
         },
         async {
-            if run_response_finder {
+            if let Some(max_targets) = response_finder_targets {
                 info!("Starting response finder...");
                 let response_finder = crate::discovery::response_finder::ResponseFinder::new(
                     &writer,
                     archive.clone(),
                     &*embedder,
-                    &deps.anthropic_api_key,
+                    &deps.anthropic_api_key.expose(),
                     scope.clone(),
                     cancelled.clone(),
                     run_id_owned.clone(),
-                );
+                )
+                .with_region_memo(&region_memo)
+                .with_max_targets(max_targets);
                 let rf_stats = response_finder.run().await;
                 info!("{rf_stats}");
-            } else if budget.is_active() {
-                info!("Skipping response finder (budget exhausted)");
+                Some(rf_stats.tally())
+            } else {
+                info!(reason = skip_reason(SynthesisPhase::ResponseFinder), "Skipping response finder");
+                None
             }
         },
         async {
-            if run_gathering_finder {
+            if let Some(max_targets) = gathering_finder_targets {
                 info!("Starting gathering finder...");
                 let gathering_finder = crate::discovery::gathering_finder::GatheringFinder::new(
                     &writer,
                     archive.clone(),
                     &*embedder,
-                    &deps.anthropic_api_key,
+                    &deps.anthropic_api_key.expose(),
                     scope.clone(),
                     cancelled.clone(),
                     run_id_owned.clone(),
-                );
+                )
+                .with_region_memo(&region_memo)
+                .with_max_targets(max_targets);
                 let gf_stats = gathering_finder.run().await;
                 info!("{gf_stats}");
-            } else if budget.is_active() {
-                info!("Skipping gathering finder (budget exhausted)");
+                Some(gf_stats.tally())
+            } else {
+                info!(reason = skip_reason(SynthesisPhase::GatheringFinder), "Skipping gathering finder");
+                None
             }
         },
         async {
-            if run_investigation {
+            if let Some(max_targets) = investigation_targets {
                 info!("Starting investigation phase...");
                 let investigator = crate::discovery::investigator::Investigator::new(
                     &writer,
                     archive.clone(),
-                    &deps.anthropic_api_key,
+                    embedder.clone(),
+                    &deps.anthropic_api_key.expose(),
                     scope,
                     cancelled.clone(),
-                );
+                )
+                .with_max_targets(max_targets);
                 let investigation_stats = investigator.run().await;
                 info!("{investigation_stats}");
-            } else if budget.is_active() {
-                info!("Skipping investigation (budget exhausted)");
+                Some(investigation_stats.tally())
+            } else {
+                info!(reason = skip_reason(SynthesisPhase::Investigation), "Skipping investigation");
+                None
             }
         },
     );
 
-    let _ = (sim_result, rm_result, tl_result, rf_result, gf_result, inv_result);
+    let _ = (sim_result, rm_result);
 
     info!("Parallel synthesis complete");
 
+    let tallies: Vec<_> = [tl_result, rf_result, gf_result, inv_result].into_iter().flatten().collect();
+    if let Err(e) = error_budget::record_run(&deps.pg_pool, &run_id, &scope.name, &tallies).await {
+        warn!(error = %e, "Failed to record synthesis failure tallies");
+    }
+
     Ok(SynthesisResult {
         spent_cents: budget.total_spent(),
     })
 }
+
+/// Cost of one response mapping pass, which has no targets to scale.
+const RESPONSE_MAPPING_CENTS: u64 = OperationCost::CLAUDE_HAIKU_SYNTHESIS * 10;
+/// Cost of one target for each finder.
+const TENSION_LINKER_UNIT_CENTS: u64 = OperationCost::CLAUDE_HAIKU_TENSION_LINKER
+    + OperationCost::SEARCH_TENSION_LINKER
+    + OperationCost::CHROME_TENSION_LINKER;
+const RESPONSE_FINDER_UNIT_CENTS: u64 = OperationCost::CLAUDE_HAIKU_RESPONSE_FINDER
+    + OperationCost::SEARCH_RESPONSE_FINDER
+    + OperationCost::CHROME_RESPONSE_FINDER;
+const GATHERING_FINDER_UNIT_CENTS: u64 = OperationCost::CLAUDE_HAIKU_GATHERING_FINDER
+    + OperationCost::SEARCH_GATHERING_FINDER
+    + OperationCost::CHROME_GATHERING_FINDER;
+const INVESTIGATION_UNIT_CENTS: u64 =
+    OperationCost::CLAUDE_HAIKU_INVESTIGATION + OperationCost::SEARCH_INVESTIGATION;
+
+fn allocate_phases(budget: &BudgetTracker, policy: &SynthesisBudgetPolicy) -> Vec<PhaseAllocation> {
+    let targeted = |phase, unit_cents: u64, max_targets: usize| PhaseDemand {
+        phase,
+        unit_cents,
+        full_cents: unit_cents * max_targets as u64,
+    };
+    let demands = [
+        PhaseDemand {
+            phase: SynthesisPhase::ResponseMapping,
+            unit_cents: RESPONSE_MAPPING_CENTS,
+            full_cents: RESPONSE_MAPPING_CENTS,
+        },
+        targeted(
+            SynthesisPhase::TensionLinker,
+            TENSION_LINKER_UNIT_CENTS,
+            MAX_TENSION_LINKER_TARGETS_PER_RUN as usize,
+        ),
+        targeted(SynthesisPhase::ResponseFinder, RESPONSE_FINDER_UNIT_CENTS, MAX_RESPONSE_TARGETS_PER_RUN),
+        targeted(SynthesisPhase::GatheringFinder, GATHERING_FINDER_UNIT_CENTS, MAX_GRAVITY_TARGETS_PER_RUN),
+        targeted(SynthesisPhase::Investigation, INVESTIGATION_UNIT_CENTS, MAX_SIGNALS_INVESTIGATED),
+    ];
+    let remaining = budget.is_active().then(|| budget.remaining());
+    allocate(policy, remaining, &demands)
+}
+
+/// Record the allocation on a run log of its own, so runs can be compared
+/// later by what was funded and what was skipped.
+async fn log_allocations(
+    deps: &ScoutDeps,
+    run_id: &str,
+    region: &str,
+    budget: &BudgetTracker,
+    policy: &SynthesisBudgetPolicy,
+    allocations: &[PhaseAllocation],
+) {
+    let mut run_log = RunLog::new(run_id.to_string(), region.to_string());
+    let remaining_cents = budget.is_active().then(|| budget.remaining());
+    run_log.log(EventKind::SynthesisBudgetAllocated {
+        mode: policy.mode.as_str().to_string(),
+        remaining_cents,
+    });
+    for a in allocations {
+        let unit_cents = match a.phase {
+            SynthesisPhase::ResponseMapping => None,
+            SynthesisPhase::TensionLinker => Some((TENSION_LINKER_UNIT_CENTS, MAX_TENSION_LINKER_TARGETS_PER_RUN as usize)),
+            SynthesisPhase::ResponseFinder => Some((RESPONSE_FINDER_UNIT_CENTS, MAX_RESPONSE_TARGETS_PER_RUN)),
+            SynthesisPhase::GatheringFinder => Some((GATHERING_FINDER_UNIT_CENTS, MAX_GRAVITY_TARGETS_PER_RUN)),
+            SynthesisPhase::Investigation => Some((INVESTIGATION_UNIT_CENTS, MAX_SIGNALS_INVESTIGATED)),
+        };
+        let max_targets = unit_cents
+            .filter(|_| a.outcome == PhaseOutcome::Run)
+            .map(|(unit, max)| a.units(unit, max) as u32);
+        info!(
+            phase = a.phase.as_str(),
+            priority = a.priority,
+            allocated_cents = ?a.allocated_cents,
+            max_targets = ?max_targets,
+            outcome = a.outcome.as_str(),
+            "Synthesis phase allocated"
+        );
+        run_log.log(EventKind::SynthesisPhaseAllocated {
+            phase: a.phase.as_str().to_string(),
+            priority: a.priority,
+            allocated_cents: a.allocated_cents,
+            max_targets,
+            outcome: a.outcome.as_str().to_string(),
+        });
+    }
+    if let Err(e) = run_log.save_to_db(&deps.pg_pool, &ScoutStats::default()).await {
+        warn!(error = %e, "Failed to save synthesis run log");
+    }
+}
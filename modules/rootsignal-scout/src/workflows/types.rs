@@ -61,9 +61,29 @@ pub struct BudgetedTaskRequest {
     pub spent_cents: u64,
 }
 
-/// Empty request for `get_status` shared handlers.
+/// Input for the signal verification sweep.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EmptyRequest;
+pub struct VerificationRequest {
+    pub scope: ScoutScope,
+    /// Re-schedule another sweep this many hours after this one finishes.
+    #[serde(default)]
+    pub repeat_every_hours: Option<u32>,
+    /// How many sweeps have run in this chain; used to key the next one.
+    #[serde(default)]
+    pub generation: u32,
+}
+
+/// Input for onboarding a new region.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnboardingRequest {
+    pub task_id: String,
+    pub scope: ScoutScope,
+    /// IANA timezone the seeded schedule runs in.
+    pub timezone: String,
+}
+
+/// Empty request for `get_status` shared handlers.
+pub use rootsignal_common::restate::EmptyRequest;
 
 // ---------------------------------------------------------------------------
 // Results
@@ -101,6 +121,25 @@ pub struct SupervisorResult {
 pub struct NewsScanResult {
     pub articles_scanned: u32,
     pub beacons_created: u32,
+    /// Yield per source tier, best tier first.
+    #[serde(default)]
+    pub by_tier: Vec<crate::pipeline::news_scanner::TierYield>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationResult {
+    pub region: String,
+    pub checked: u32,
+    pub confirmed: u32,
+    pub expired: u32,
+    pub inconclusive: u32,
+    /// Evidence pages re-checked, and how many had changed or were removed.
+    #[serde(default)]
+    pub evidence_checked: u32,
+    #[serde(default)]
+    pub evidence_changed: u32,
+    #[serde(default)]
+    pub evidence_removed: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,17 +150,27 @@ pub struct FullRunResult {
     pub issues_found: u32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnboardingResult {
+    pub sources_created: u32,
+    /// Whether the region passed its readiness checklist and went active.
+    pub active: bool,
+}
+
 // ---------------------------------------------------------------------------
 // Restate serde impls
 // ---------------------------------------------------------------------------
 
-crate::impl_restate_serde!(TaskRequest);
-crate::impl_restate_serde!(BudgetedTaskRequest);
-crate::impl_restate_serde!(EmptyRequest);
-crate::impl_restate_serde!(BootstrapResult);
-crate::impl_restate_serde!(ScrapeResult);
-crate::impl_restate_serde!(SynthesisResult);
-crate::impl_restate_serde!(SituationWeaverResult);
-crate::impl_restate_serde!(SupervisorResult);
-crate::impl_restate_serde!(NewsScanResult);
-crate::impl_restate_serde!(FullRunResult);
+rootsignal_common::impl_restate_serde!(TaskRequest);
+rootsignal_common::impl_restate_serde!(BudgetedTaskRequest);
+rootsignal_common::impl_restate_serde!(BootstrapResult);
+rootsignal_common::impl_restate_serde!(ScrapeResult);
+rootsignal_common::impl_restate_serde!(SynthesisResult);
+rootsignal_common::impl_restate_serde!(SituationWeaverResult);
+rootsignal_common::impl_restate_serde!(SupervisorResult);
+rootsignal_common::impl_restate_serde!(NewsScanResult);
+rootsignal_common::impl_restate_serde!(FullRunResult);
+rootsignal_common::impl_restate_serde!(VerificationRequest);
+rootsignal_common::impl_restate_serde!(VerificationResult);
+rootsignal_common::impl_restate_serde!(OnboardingRequest);
+rootsignal_common::impl_restate_serde!(OnboardingResult);
@@ -0,0 +1,140 @@
+//! Restate durable workflow for signal verification sweeps.
+//!
+//! Re-fetches the source pages of aging signals in a region, refreshing the
+//! ones still advertised and expiring the ones that are gone, then re-checks
+//! evidence pages for drift. With
+//! `repeat_every_hours` set, each sweep schedules the next one.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use restate_sdk::prelude::*;
+use tracing::info;
+
+use rootsignal_graph::GraphWriter;
+
+use crate::enrichment::evidence_freshness::recheck_evidence;
+use crate::enrichment::verification::{run_verification_sweep, MAX_SIGNALS_PER_SWEEP, VERIFY_AFTER_DAYS};
+
+use super::types::{EmptyRequest, VerificationRequest, VerificationResult};
+use super::ScoutDeps;
+
+#[restate_sdk::workflow]
+#[name = "VerificationWorkflow"]
+pub trait VerificationWorkflow {
+    async fn run(req: VerificationRequest) -> Result<VerificationResult, HandlerError>;
+    #[shared]
+    async fn get_status(req: EmptyRequest) -> Result<String, HandlerError>;
+}
+
+pub struct VerificationWorkflowImpl {
+    deps: Arc<ScoutDeps>,
+}
+
+impl VerificationWorkflowImpl {
+    pub fn with_deps(deps: Arc<ScoutDeps>) -> Self {
+        Self { deps }
+    }
+}
+
+impl VerificationWorkflow for VerificationWorkflowImpl {
+    async fn run(
+        &self,
+        ctx: WorkflowContext<'_>,
+        req: VerificationRequest,
+    ) -> Result<VerificationResult, HandlerError> {
+        ctx.set("status", format!("Verifying signals in {}...", req.scope.name));
+
+        let deps = self.deps.clone();
+        let scope = req.scope.clone();
+
+        let result = match ctx
+            .run(|| async {
+                run_verification_from_deps(&deps, &scope)
+                    .await
+                    .map_err(|e| -> HandlerError { TerminalError::new(e.to_string()).into() })
+            })
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                self.deps.rotate_secrets_on_auth_failure(&e.to_string());
+                let item_key = rootsignal_common::slugify(&req.scope.name);
+                super::dead_letter(&self.deps, "VerificationWorkflow", &item_key, &req, &e.to_string()).await;
+                return Err(e.into());
+            }
+        };
+
+        // Workflows are one-shot per key, so the next sweep gets a fresh one.
+        if let Some(hours) = req.repeat_every_hours.filter(|h| *h > 0) {
+            let root_key = ctx.key().split('+').next().unwrap_or_default().to_string();
+            let generation = req.generation + 1;
+            ctx.workflow_client::<VerificationWorkflowClient>(format!("{root_key}+{generation}"))
+                .run(VerificationRequest {
+                    generation,
+                    ..req.clone()
+                })
+                .send_after(Duration::from_secs(u64::from(hours) * 3600));
+        }
+
+        ctx.set(
+            "status",
+            format!(
+                "Verification complete: {} checked, {} confirmed, {} expired",
+                result.checked, result.confirmed, result.expired
+            ),
+        );
+        info!(
+            region = result.region.as_str(),
+            checked = result.checked,
+            confirmed = result.confirmed,
+            expired = result.expired,
+            inconclusive = result.inconclusive,
+            evidence_checked = result.evidence_checked,
+            evidence_changed = result.evidence_changed,
+            evidence_removed = result.evidence_removed,
+            "VerificationWorkflow complete"
+        );
+
+        Ok(result)
+    }
+
+    async fn get_status(
+        &self,
+        ctx: SharedWorkflowContext<'_>,
+        _req: EmptyRequest,
+    ) -> Result<String, HandlerError> {
+        super::read_workflow_status(&ctx).await
+    }
+}
+
+/// Run a verification sweep using shared deps. Usable from both Restate and CLI.
+pub async fn run_verification_from_deps(
+    deps: &ScoutDeps,
+    scope: &rootsignal_common::ScoutScope,
+) -> anyhow::Result<VerificationResult> {
+    let graph = deps.region_graph(&scope.name).await?;
+    let archive = super::create_archive(deps);
+    let writer = GraphWriter::new(graph.clone());
+
+    let summary = run_verification_sweep(
+        &writer,
+        archive.as_ref(),
+        scope,
+        VERIFY_AFTER_DAYS,
+        MAX_SIGNALS_PER_SWEEP,
+    )
+    .await?;
+    let freshness = recheck_evidence(archive.as_ref(), &writer, chrono::Utc::now()).await;
+
+    Ok(VerificationResult {
+        region: scope.name.clone(),
+        checked: summary.checked,
+        confirmed: summary.confirmed,
+        expired: summary.expired,
+        inconclusive: summary.inconclusive,
+        evidence_checked: freshness.checked,
+        evidence_changed: freshness.changed,
+        evidence_removed: freshness.removed,
+    })
+}
@@ -67,7 +67,7 @@ async fn load_or_record(
     // Record mode: call LLM
     let api_key = std::env::var("ANTHROPIC_API_KEY")
         .expect("ANTHROPIC_API_KEY required to record extraction snapshots");
-    let extractor = Extractor::new(&api_key, "Minneapolis", 44.9778, -93.2650);
+    let extractor = Extractor::new(&api_key, "Minneapolis");
     let result = extractor.extract(content, url).await.unwrap();
 
     // We need the raw ExtractionResponse for the snapshot. Re-extract to get it.
@@ -152,6 +152,9 @@ fn convert_response(response: &ExtractionResponse, _source_url: &str) -> Extract
             channel_diversity: 1,
             mentioned_actors,
             author_actor: None,
+            field_visibility: Default::default(),
+            alt_text: None,
+            plain_summary: None,
         };
 
         let node = match signal.signal_type.as_str() {
@@ -197,6 +200,7 @@ fn convert_response(response: &ExtractionResponse, _source_url: &str) -> Extract
                 what_needed: signal.what_needed.clone(),
                 action_url: signal.action_url.clone(),
                 goal: signal.goal.clone(),
+                fundraiser: None,
             }),
             "notice" => Node::Notice(NoticeNode {
                 meta,
@@ -304,6 +308,7 @@ fn extraction_result_to_response(result: &ExtractionResult) -> ExtractionRespons
                     signal_type: "unknown".into(),
                     title: String::new(),
                     summary: String::new(),
+                    plain_summary: None,
                     sensitivity: "general".into(),
                     latitude: None, longitude: None, geo_precision: None,
                     location_name: None, starts_at: None, ends_at: None,
@@ -315,6 +320,7 @@ fn extraction_result_to_response(result: &ExtractionResult) -> ExtractionRespons
                     implied_queries: vec![], resources: vec![], tags: vec![],
                     is_firsthand: None,
                     author_actor: None,
+                    details: Default::default(),
                 },
             };
 
@@ -328,6 +334,7 @@ fn extraction_result_to_response(result: &ExtractionResult) -> ExtractionRespons
                 signal_type: signal_type.into(),
                 title: meta.title.clone(),
                 summary: meta.summary.clone(),
+                plain_summary: meta.plain_summary.clone(),
                 sensitivity: sensitivity.into(),
                 latitude: meta.about_location.map(|l| l.lat),
                 longitude: meta.about_location.map(|l| l.lng),
@@ -364,6 +371,7 @@ fn extraction_result_to_response(result: &ExtractionResult) -> ExtractionRespons
                 tags: vec![],
                 is_firsthand: None,
                 author_actor: None,
+                details: Default::default(),
             }
         })
         .collect();
@@ -0,0 +1,107 @@
+//! Golden outputs for the extraction prompt.
+//!
+//! Each case in `tests/golden/cases.json` is extracted live and compared with
+//! its approved output in `tests/golden/approved/`, by signal type and by
+//! embedding similarity. A changed output is written to `tests/golden/pending/`
+//! and the test fails until it is reviewed and approved.
+//!
+//! - Check:   `cargo test -p rootsignal-scout --test golden_test -- --ignored`
+//! - Approve: `GOLDEN_APPROVE=all` (or `=name,name`) with the same command
+//!
+//! Needs ANTHROPIC_API_KEY and VOYAGE_API_KEY. `GOLDEN_FILTER` limits the
+//! cases by name; `GOLDEN_THRESHOLD` overrides the similarity threshold.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use rootsignal_scout::infra::embedder::Embedder;
+use rootsignal_scout::pipeline::extractor::{Extractor, SignalExtractor};
+use rootsignal_scout::pipeline::golden::{
+    compare_embedded, GoldenOutput, GoldenSet, GoldenSignal, DEFAULT_THRESHOLD,
+};
+
+fn golden_set() -> GoldenSet {
+    GoldenSet::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden"))
+}
+
+fn fixture(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {e}", path.display()))
+}
+
+fn approve(set: &GoldenSet, which: &str) {
+    let names = if which == "all" {
+        set.pending().unwrap()
+    } else {
+        which.split(',').map(|s| s.trim().to_string()).collect()
+    };
+    for name in names {
+        set.approve(&name).unwrap();
+        println!("approved {name}");
+    }
+}
+
+fn describe(out: &mut String, label: &str, signal: &GoldenSignal) {
+    let _ = writeln!(out, "    {label} [{}] {}", signal.signal_type, signal.title);
+}
+
+#[tokio::test]
+#[ignore] // Live LLM and embedding calls
+async fn extraction_matches_approved_goldens() {
+    let set = golden_set();
+    if let Ok(which) = std::env::var("GOLDEN_APPROVE") {
+        approve(&set, &which);
+        return;
+    }
+
+    let api_key = std::env::var("ANTHROPIC_API_KEY").expect("ANTHROPIC_API_KEY required");
+    let voyage_key = std::env::var("VOYAGE_API_KEY").expect("VOYAGE_API_KEY required");
+    let extractor = Extractor::new(&api_key, "Minneapolis");
+    let embedder = Embedder::new(&voyage_key);
+    let threshold = std::env::var("GOLDEN_THRESHOLD")
+        .ok()
+        .and_then(|t| t.parse().ok())
+        .unwrap_or(DEFAULT_THRESHOLD);
+    let filter = std::env::var("GOLDEN_FILTER").ok();
+
+    let mut failures = String::new();
+    for case in set.cases().unwrap() {
+        if filter.as_deref().is_some_and(|f| !case.name.contains(f)) {
+            continue;
+        }
+        let result = extractor.extract(&fixture(&case.fixture), &case.url).await.unwrap();
+        let fresh = GoldenOutput {
+            signals: result.nodes.iter().map(GoldenSignal::from_node).collect(),
+        };
+
+        let Some(golden) = set.approved(&case.name).unwrap() else {
+            let path = set.write_pending(&case.name, &fresh).unwrap();
+            let _ = writeln!(failures, "{}: no approved golden, review {}", case.name, path.display());
+            continue;
+        };
+        let report = compare_embedded(&embedder, &golden, &fresh, threshold).await.unwrap();
+        if report.passed() {
+            set.clear_pending(&case.name).unwrap();
+            println!("ok {} ({} signals)", case.name, report.matched.len());
+            continue;
+        }
+
+        let path = set.write_pending(&case.name, &fresh).unwrap();
+        let _ = writeln!(failures, "{}: output changed, review {}", case.name, path.display());
+        for &i in &report.missing {
+            describe(&mut failures, "-", &golden.signals[i]);
+        }
+        for &i in &report.unexpected {
+            describe(&mut failures, "+", &fresh.signals[i]);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Golden outputs changed:\n{failures}\nApprove with GOLDEN_APPROVE=<name,...|all> once reviewed."
+    );
+}
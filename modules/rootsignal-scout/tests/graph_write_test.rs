@@ -55,6 +55,9 @@ fn test_meta(title: &str) -> NodeMeta {
         channel_diversity: 1,
         mentioned_actors: vec![],
         author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
     }
 }
 
@@ -74,6 +77,9 @@ fn make_evidence(source_url: &str, content: &str) -> EvidenceNode {
         relevance: Some("primary".into()),
         evidence_confidence: Some(0.9),
         channel_type: Some(ChannelType::Press),
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
     }
 }
 
@@ -42,6 +42,9 @@ fn test_meta() -> NodeMeta {
         channel_diversity: 1,
         mentioned_actors: vec![],
         author_actor: None,
+        field_visibility: Default::default(),
+        alt_text: None,
+        plain_summary: None,
     }
 }
 
@@ -368,6 +371,7 @@ fn need_with_url_is_actionable_without_timing() {
         what_needed: Some("Winter coats".into()),
         action_url: Some("https://donate.example.com".into()),
         goal: Some("500 coats".into()),
+        fundraiser: None,
     });
 
     let q = quality::score(&node);
@@ -391,6 +395,7 @@ fn need_without_url_not_actionable() {
         what_needed: Some("Emergency housing".into()),
         action_url: None,
         goal: None,
+        fundraiser: None,
     });
 
     let q = quality::score(&node);
@@ -0,0 +1,360 @@
+//! What a demo region contains before anything is generated: its places,
+//! the organizations publishing about it, and the themes its signals and
+//! situations are about. Built procedurally or from a simweb world.
+
+use rootsignal_common::{slugify, ActorType, NodeType, SourceRole};
+
+#[derive(Debug, Clone)]
+pub struct Place {
+    pub name: String,
+    pub lat: f64,
+    pub lng: f64,
+}
+
+/// Publishes signals; becomes a Source and an Actor.
+#[derive(Debug, Clone)]
+pub struct Organization {
+    pub name: String,
+    pub url: String,
+    pub actor_type: ActorType,
+    pub role: SourceRole,
+}
+
+/// A local issue: one situation, its stories, and the signals about it.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub category: String,
+    pub situation: String,
+    pub lede: String,
+    /// Signal titles by type; `{place}` becomes the neighborhood's name.
+    pub titles: Vec<(NodeType, String)>,
+}
+
+/// A signal that must appear as written, e.g. a simweb ground-truth fact.
+#[derive(Debug, Clone)]
+pub struct FactSeed {
+    pub node_type: NodeType,
+    pub title: String,
+    pub source_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    pub name: String,
+    pub center_lat: f64,
+    pub center_lng: f64,
+    pub places: Vec<Place>,
+    pub organizations: Vec<Organization>,
+    pub themes: Vec<Theme>,
+    pub facts: Vec<FactSeed>,
+}
+
+const KM_PER_DEGREE_LAT: f64 = 111.0;
+
+impl Blueprint {
+    /// Minneapolis–St. Paul, with real neighborhoods.
+    pub fn twin_cities() -> Self {
+        let place = |name: &str, lat, lng| Place { name: name.to_string(), lat, lng };
+        let mut blueprint = Self::procedural("Twin Cities", 44.9778, -93.2650);
+        blueprint.places = vec![
+            place("Powderhorn", 44.9486, -93.2570),
+            place("Phillips", 44.9555, -93.2620),
+            place("Cedar-Riverside", 44.9690, -93.2470),
+            place("Northeast", 45.0000, -93.2560),
+            place("North Minneapolis", 45.0050, -93.3000),
+            place("Longfellow", 44.9410, -93.2220),
+            place("Frogtown", 44.9590, -93.1300),
+            place("Midway", 44.9560, -93.1670),
+            place("East Side", 44.9720, -93.0550),
+            place("West Side", 44.9300, -93.0800),
+        ];
+        blueprint
+    }
+
+    /// A region named `name` around a center, with generic neighborhoods.
+    pub fn procedural(name: &str, center_lat: f64, center_lng: f64) -> Self {
+        let names = ["Downtown", "North End", "East Side", "West Side", "Riverside", "Southside", "Old Town", "University District"];
+        Self {
+            name: name.to_string(),
+            center_lat,
+            center_lng,
+            places: ring_places(&names, center_lat, center_lng),
+            organizations: organizations_for(name),
+            themes: default_themes(),
+            facts: Vec::new(),
+        }
+    }
+
+    /// A region shaped by a simweb world: its geography, sites, social
+    /// profiles and topics, with every fact seeded as a signal.
+    pub fn from_world(world: &simweb::World) -> Self {
+        let geo = &world.geography;
+        let mut blueprint = Self::procedural(&geo.name, geo.center_lat, geo.center_lng);
+        if !geo.local_terms.is_empty() {
+            let names: Vec<&str> = geo.local_terms.iter().map(String::as_str).collect();
+            blueprint.places = ring_places(&names, geo.center_lat, geo.center_lng);
+        }
+
+        let sites = world.sites.iter().map(|site| Organization {
+            name: name_from_url(&site.url),
+            url: site.url.clone(),
+            actor_type: if site.kind.to_lowercase().contains("gov") {
+                ActorType::GovernmentBody
+            } else {
+                ActorType::Organization
+            },
+            role: role_for_kind(&site.kind),
+        });
+        let profiles = world.social_profiles.iter().map(|profile| Organization {
+            name: profile.identifier.trim_start_matches(['@', '/']).to_string(),
+            url: social_url(&profile.platform, &profile.identifier),
+            actor_type: ActorType::Organization,
+            role: SourceRole::Mixed,
+        });
+        let organizations: Vec<_> = sites.chain(profiles).collect();
+        if !organizations.is_empty() {
+            blueprint.organizations = organizations;
+        }
+
+        if !world.topics.is_empty() {
+            blueprint.themes = world.topics.iter().map(|t| Theme::for_topic(t)).collect();
+        }
+        blueprint.facts = world
+            .facts
+            .iter()
+            .map(|fact| FactSeed {
+                node_type: node_type_for(&format!("{} {}", fact.category, fact.text)),
+                title: fact.text.clone(),
+                source_url: fact.referenced_by.first().cloned(),
+            })
+            .collect();
+        blueprint
+    }
+}
+
+impl Theme {
+    /// A theme about any topic, from generic titles.
+    pub fn for_topic(topic: &str) -> Self {
+        let titles = [
+            (NodeType::Tension, "Residents in {place} raise concerns about {topic}"),
+            (NodeType::Tension, "{topic} worsening in {place}, neighbors say"),
+            (NodeType::Need, "Volunteers needed to help with {topic} in {place}"),
+            (NodeType::Need, "Donations sought for {topic} response"),
+            (NodeType::Aid, "Free help with {topic} at {place} community center"),
+            (NodeType::Aid, "Drop-in resource hours on {topic}"),
+            (NodeType::Gathering, "Community meeting on {topic} in {place}"),
+            (NodeType::Gathering, "Neighbors organize around {topic}"),
+            (NodeType::Notice, "City update on {topic}"),
+        ];
+        Self {
+            category: slugify(topic),
+            situation: format!("{} in {{place}}", capitalize(topic)),
+            lede: format!("Residents, organizations and the city are responding to {topic}."),
+            titles: titles
+                .into_iter()
+                .map(|(t, title)| (t, title.replace("{topic}", topic)))
+                .map(|(t, title)| (t, capitalize(&title)))
+                .collect(),
+        }
+    }
+}
+
+fn default_themes() -> Vec<Theme> {
+    let theme = |category: &str, situation: &str, lede: &str, titles: &[(NodeType, &str)]| Theme {
+        category: category.to_string(),
+        situation: situation.to_string(),
+        lede: lede.to_string(),
+        titles: titles.iter().map(|(t, s)| (*t, s.to_string())).collect(),
+    };
+    use NodeType::*;
+    vec![
+        theme(
+            "housing",
+            "Rent increases displacing {place} renters",
+            "Tenants report steep renewals and no-fault notices; tenant groups and legal aid are responding.",
+            &[
+                (Tension, "Tenants in {place} facing 20% rent increases at renewal"),
+                (Tension, "No-fault eviction notices rising in {place} buildings"),
+                (Need, "Tenant union looking for door-knocking volunteers in {place}"),
+                (Need, "Emergency rent assistance fund running low"),
+                (Aid, "Free tenant rights clinic every Tuesday"),
+                (Aid, "Legal aid walk-in hours for eviction defense"),
+                (Gathering, "Tenant union meeting at {place} library"),
+                (Gathering, "Know-your-rights workshop for renters"),
+                (Notice, "City council to vote on renter protections ordinance"),
+            ],
+        ),
+        theme(
+            "food",
+            "Food access gaps in {place}",
+            "A grocery closure left {place} without a full-service store; food shelves and mutual aid groups are filling in.",
+            &[
+                (Tension, "Grocery store closure leaves {place} without fresh food"),
+                (Tension, "Food shelf lines doubled since spring"),
+                (Need, "Food shelf needs drivers for Saturday deliveries"),
+                (Need, "Community fridge in {place} needs restocking"),
+                (Aid, "Free produce market Thursdays in {place}"),
+                (Aid, "Food shelf extends evening hours"),
+                (Gathering, "Community potluck and seed swap in {place}"),
+                (Notice, "SNAP benefit changes take effect next month"),
+            ],
+        ),
+        theme(
+            "winter-shelter",
+            "Shelter beds full as temperatures drop",
+            "Overnight shelters are at capacity; warming centers and outreach teams are stretched.",
+            &[
+                (Tension, "Shelters turning people away on cold nights"),
+                (Tension, "Encampment near {place} grows as shelters fill"),
+                (Need, "Winter coats and sleeping bags needed"),
+                (Need, "Outreach team looking for overnight volunteers"),
+                (Aid, "Warming center open overnight in {place}"),
+                (Aid, "Free winter gear distribution this weekend"),
+                (Gathering, "Volunteer orientation for street outreach"),
+                (Notice, "Snow emergency declared — parking restrictions in effect"),
+                (Notice, "County opens overflow shelter"),
+            ],
+        ),
+        theme(
+            "transit",
+            "Bus cuts stranding {place} riders",
+            "Route reductions have lengthened commutes; riders are organizing and neighbors are offering rides.",
+            &[
+                (Tension, "Route cuts leave {place} riders waiting 40 minutes"),
+                (Need, "Drivers needed for medical appointment rides"),
+                (Aid, "Free bike repair clinic in {place}"),
+                (Gathering, "Transit riders union meeting"),
+                (Notice, "Bus route detour for {place} street reconstruction"),
+                (Notice, "Transit agency proposes new service changes"),
+            ],
+        ),
+        theme(
+            "youth",
+            "After-school gaps for {place} youth",
+            "Program closures left many kids without after-school options; volunteers and libraries are stepping in.",
+            &[
+                (Tension, "After-school program closure leaves {place} families scrambling"),
+                (Need, "Tutors needed for after-school homework help"),
+                (Need, "Youth soccer league needs coaches"),
+                (Aid, "Free summer meals for kids at {place} parks"),
+                (Aid, "Library teen space open late on weekdays"),
+                (Gathering, "Youth open mic night in {place}"),
+                (Notice, "Park board extends rec center hours"),
+            ],
+        ),
+    ]
+}
+
+fn organizations_for(region: &str) -> Vec<Organization> {
+    let slug = slugify(region);
+    let org = |name: String, url: String, actor_type, role| Organization { name, url, actor_type, role };
+    use SourceRole::*;
+    vec![
+        org(format!("{region} Food Shelf"), format!("https://{slug}-foodshelf.example.org/news"), ActorType::Organization, Response),
+        org(format!("{region} Tenants Union"), format!("https://{slug}-tenants.example.org/updates"), ActorType::Coalition, Mixed),
+        org(format!("{region} Legal Aid"), format!("https://{slug}-legalaid.example.org/clinics"), ActorType::Organization, Response),
+        org(format!("City of {region}"), format!("https://{slug}.example.gov/news"), ActorType::GovernmentBody, Mixed),
+        org(format!("{region} Public Library"), format!("https://{slug}-library.example.org/events"), ActorType::GovernmentBody, Response),
+        org(format!("{region} Mutual Aid"), format!("https://www.instagram.com/{}mutualaid", slug.replace('-', "")), ActorType::Coalition, Mixed),
+        org(format!("{region} Youth Alliance"), format!("https://{slug}-youth.example.org/programs"), ActorType::Organization, Response),
+        org(format!("{region} Transit Riders"), format!("https://{slug}-riders.example.org/blog"), ActorType::Coalition, Tension),
+        org(format!("{region} Community Journal"), format!("https://{slug}-journal.example.com/local"), ActorType::Organization, Tension),
+        org(format!("r/{}", slug.replace('-', "")), format!("https://www.reddit.com/r/{}", slug.replace('-', "")), ActorType::Organization, Tension),
+    ]
+}
+
+/// `names` spread on a ring a few kilometres around the center.
+fn ring_places(names: &[&str], center_lat: f64, center_lng: f64) -> Vec<Place> {
+    let km_per_degree_lng = KM_PER_DEGREE_LAT * center_lat.to_radians().cos();
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let bearing = i as f64 / names.len() as f64 * std::f64::consts::TAU;
+            let distance_km = if i == 0 { 0.0 } else { 3.0 + (i % 3) as f64 * 2.0 };
+            Place {
+                name: name.to_string(),
+                lat: center_lat + distance_km * bearing.cos() / KM_PER_DEGREE_LAT,
+                lng: center_lng + distance_km * bearing.sin() / km_per_degree_lng,
+            }
+        })
+        .collect()
+}
+
+/// The signal type a fact reads as.
+fn node_type_for(text: &str) -> NodeType {
+    let text = text.to_lowercase();
+    let has = |words: &[&str]| words.iter().any(|w| text.contains(w));
+    if has(&["event", "gathering", "meeting", "rally", "workshop", "potluck", "festival"]) {
+        NodeType::Gathering
+    } else if has(&["volunteer", "donat", "needed", "needs", "ask"]) {
+        NodeType::Need
+    } else if has(&["closure", "closed", "advisory", "ordinance", "policy", "notice", "detour"]) {
+        NodeType::Notice
+    } else if has(&["tension", "conflict", "complain", "concern", "shortage", "evict", "crisis"]) {
+        NodeType::Tension
+    } else {
+        NodeType::Aid
+    }
+}
+
+fn role_for_kind(kind: &str) -> SourceRole {
+    let kind = kind.to_lowercase();
+    if ["news", "forum", "blog"].iter().any(|k| kind.contains(k)) {
+        SourceRole::Tension
+    } else if ["org", "nonprofit", "gov", "calendar", "church", "library"].iter().any(|k| kind.contains(k)) {
+        SourceRole::Response
+    } else {
+        SourceRole::Mixed
+    }
+}
+
+fn social_url(platform: &str, identifier: &str) -> String {
+    let id = identifier.trim_start_matches('@');
+    match platform.to_lowercase().as_str() {
+        "reddit" => format!("https://www.reddit.com/r/{}", id.trim_start_matches("r/")),
+        "facebook" => format!("https://www.facebook.com/{id}"),
+        "tiktok" => format!("https://www.tiktok.com/@{id}"),
+        _ => format!("https://www.instagram.com/{id}"),
+    }
+}
+
+/// "https://www.northside-food-shelf.org/about" → "Northside Food Shelf".
+fn name_from_url(url: &str) -> String {
+    let host = url
+        .split("://")
+        .nth(1)
+        .unwrap_or(url)
+        .split('/')
+        .next()
+        .unwrap_or(url)
+        .trim_start_matches("www.");
+    let stem = host.split('.').next().unwrap_or(host);
+    stem.split(['-', '_']).filter(|w| !w.is_empty()).map(capitalize).collect::<Vec<_>>().join(" ")
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn facts_read_as_the_signal_type_they_describe() {
+        assert_eq!(node_type_for("event: Potluck at the park on Saturday"), NodeType::Gathering);
+        assert_eq!(node_type_for("resource: Food shelf needs volunteers"), NodeType::Need);
+        assert_eq!(node_type_for("Library closed for repairs until June"), NodeType::Notice);
+        assert_eq!(node_type_for("Free tax prep at the community center"), NodeType::Aid);
+    }
+
+    #[test]
+    fn site_names_come_from_their_domain() {
+        assert_eq!(name_from_url("https://www.northside-food-shelf.org/about"), "Northside Food Shelf");
+    }
+}
@@ -0,0 +1,145 @@
+//! Seeds a plausible synthetic region for demos and development: sources,
+//! actors, signals of every type, stories and situations with dispatches,
+//! written through `GraphWriter` like real scout output.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+use rootsignal_common::Config;
+use rootsignal_graph::{GraphClient, GraphRouter};
+
+mod blueprint;
+mod plan;
+mod write;
+
+use blueprint::Blueprint;
+
+#[derive(Parser)]
+#[command(about = "Seed a synthetic demo region into the graph")]
+struct Cli {
+    /// Region whose database to seed (see REGION_DATABASES).
+    #[arg(long, env = "REGION", default_value = "twincities")]
+    region: String,
+
+    /// Build a procedural region around this center instead of the Twin Cities.
+    #[arg(long, requires = "lng", allow_negative_numbers = true)]
+    lat: Option<f64>,
+    #[arg(long, requires = "lat", allow_negative_numbers = true)]
+    lng: Option<f64>,
+
+    /// Display name for a procedural region.
+    #[arg(long, default_value = "Demo City")]
+    name: String,
+
+    /// Shape the region from a simweb world JSON file.
+    #[arg(long, conflicts_with_all = ["lat", "generate_world"])]
+    world: Option<PathBuf>,
+
+    /// Generate a fresh simweb world to shape the region (needs ANTHROPIC_API_KEY).
+    #[arg(long, conflicts_with = "lat")]
+    generate_world: bool,
+
+    /// Signals to generate, on top of one per world fact.
+    #[arg(long, default_value_t = 250)]
+    signals: usize,
+
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Remove previously seeded demo data first.
+    #[arg(long)]
+    wipe: bool,
+
+    /// Remove previously seeded demo data and stop.
+    #[arg(long)]
+    wipe_only: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env().add_directive("rootsignal=info".parse()?))
+        .init();
+    dotenv_load();
+    let cli = Cli::parse();
+
+    let client = region_client(&cli.region).await?;
+    if cli.wipe || cli.wipe_only {
+        let removed = write::wipe(&client).await?;
+        info!(removed, "Previous demo data wiped");
+        if cli.wipe_only {
+            return Ok(());
+        }
+    }
+
+    let blueprint = blueprint(&cli).await?;
+    let plan = plan::plan(&blueprint, cli.signals, &mut StdRng::seed_from_u64(cli.seed));
+    let signals = write::write(&client, &plan).await?;
+    info!(
+        region = blueprint.name.as_str(),
+        signals,
+        stories = plan.stories.len(),
+        situations = plan.situations.len(),
+        organizations = plan.actors.len(),
+        "Demo region seeded"
+    );
+    Ok(())
+}
+
+async fn blueprint(cli: &Cli) -> Result<Blueprint> {
+    if let Some(path) = &cli.world {
+        let raw = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let world: simweb::World =
+            serde_json::from_str(&raw).with_context(|| format!("Invalid simweb world {}", path.display()))?;
+        return Ok(Blueprint::from_world(&world));
+    }
+    if cli.generate_world {
+        let api_key = std::env::var("ANTHROPIC_API_KEY").context("--generate-world needs ANTHROPIC_API_KEY")?;
+        let world = simweb::generate_random_world(&api_key).await?;
+        info!(world = world.name.as_str(), "Generated simweb world");
+        return Ok(Blueprint::from_world(&world));
+    }
+    Ok(match (cli.lat, cli.lng) {
+        (Some(lat), Some(lng)) => Blueprint::procedural(&cli.name, lat, lng),
+        _ => Blueprint::twin_cities(),
+    })
+}
+
+/// The graph holding `region`'s data, as the API sees it.
+async fn region_client(region: &str) -> Result<GraphClient> {
+    let config = Config::web_from_env();
+    let client =
+        GraphClient::connect(&config.neo4j_uri, &config.neo4j_user, &config.neo4j_password.expose()).await?;
+    let router = GraphRouter::from_config(client, &config);
+    router.migrate_all().await?;
+    Ok(router.for_region(region).await?)
+}
+
+/// Load .env from workspace root (doesn't override existing env vars).
+fn dotenv_load() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(".env");
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                if std::env::var(key.trim()).is_err() {
+                    std::env::set_var(key.trim(), value.trim());
+                }
+            }
+        }
+    }
+}
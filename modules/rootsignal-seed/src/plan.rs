@@ -0,0 +1,425 @@
+//! Everything a demo region will hold, decided before anything is written:
+//! sources and actors for its organizations, signals clustered around each
+//! theme's neighborhood, stories grouping them, and a situation per theme
+//! whose dispatches cite the signals behind it.
+
+use chrono::{DateTime, Duration, Utc};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use uuid::Uuid;
+
+use rootsignal_common::{
+    canonical_value, slugify, ActorNode, AidNode, ChannelType, Clarity, DiscoveryMethod, DispatchNode, DispatchType,
+    EvidenceNode, FieldVisibility, GatheringNode, GeoPoint, GeoPrecision, NeedNode, Node, NodeMeta, NodeType,
+    NoticeNode, SensitivityLevel, Severity, SituationArc, SituationNode, SourceNode, StoryNode, TensionNode, Urgency,
+};
+
+use crate::blueprint::{Blueprint, Organization, Place};
+
+/// Signals per story within a theme.
+const STORY_SIZE: usize = 6;
+/// Signals cited by each dispatch.
+const CITES_PER_DISPATCH: usize = 3;
+const DISPATCHES_PER_SITUATION: usize = 3;
+const KM_PER_DEGREE_LAT: f64 = 111.0;
+
+pub struct PlannedSignal {
+    pub node: Node,
+    pub evidence: EvidenceNode,
+    /// Index into `Plan::actors` of the author.
+    pub author: usize,
+    pub theme: usize,
+}
+
+pub struct PlannedStory {
+    pub story: StoryNode,
+    pub signals: Vec<Uuid>,
+}
+
+pub struct PlannedSituation {
+    pub situation: SituationNode,
+    /// Evidencing signals and their labels.
+    pub signals: Vec<(Uuid, NodeType)>,
+    pub dispatches: Vec<DispatchNode>,
+}
+
+pub struct Plan {
+    /// Parallel to `actors`: each organization's source and actor.
+    pub sources: Vec<SourceNode>,
+    pub actors: Vec<ActorNode>,
+    pub signals: Vec<PlannedSignal>,
+    pub stories: Vec<PlannedStory>,
+    pub situations: Vec<PlannedSituation>,
+}
+
+/// Plan `signals` signals for `blueprint`, plus one per blueprint fact.
+pub fn plan(blueprint: &Blueprint, signals: usize, rng: &mut StdRng) -> Plan {
+    let now = Utc::now();
+    let sources = blueprint.organizations.iter().map(source_for).collect();
+    let actors = blueprint.organizations.iter().map(|org| actor_for(org, blueprint, now)).collect();
+
+    let theme_count = blueprint.themes.len().max(1);
+    let home = |theme: usize| &blueprint.places[theme % blueprint.places.len()];
+    let mut planned = Vec::with_capacity(signals + blueprint.facts.len());
+    for i in 0..signals {
+        let theme_index = i % theme_count;
+        let theme = &blueprint.themes[theme_index];
+        let (node_type, title) = &theme.titles[(i / theme_count) % theme.titles.len()];
+        let place = if rng.random_bool(0.6) { home(theme_index) } else { blueprint.places.choose(rng).unwrap() };
+        let author = rng.random_range(0..blueprint.organizations.len());
+        let title = title.replace("{place}", &place.name);
+        planned.push(signal(*node_type, title, None, place, &blueprint.organizations[author], author, theme_index, now, rng));
+    }
+    for (i, fact) in blueprint.facts.iter().enumerate() {
+        let theme_index = i % theme_count;
+        let author = rng.random_range(0..blueprint.organizations.len());
+        let place = home(theme_index);
+        planned.push(signal(
+            fact.node_type,
+            fact.title.clone(),
+            fact.source_url.clone(),
+            place,
+            &blueprint.organizations[author],
+            author,
+            theme_index,
+            now,
+            rng,
+        ));
+    }
+
+    let mut stories = Vec::new();
+    let mut situations = Vec::new();
+    for (theme_index, theme) in blueprint.themes.iter().enumerate() {
+        let mut members: Vec<&PlannedSignal> = planned.iter().filter(|s| s.theme == theme_index).collect();
+        if members.is_empty() {
+            continue;
+        }
+        members.sort_by_key(|s| s.node.meta().map(|m| m.extracted_at));
+        for group in members.chunks(STORY_SIZE) {
+            stories.push(PlannedStory {
+                story: story_for(group, theme, stories.len()),
+                signals: group.iter().filter_map(|s| s.node.meta().map(|m| m.id)).collect(),
+            });
+        }
+        let headline = theme.situation.replace("{place}", &home(theme_index).name);
+        situations.push(situation_for(&members, headline, theme, &home(theme_index).name, theme_index, now));
+    }
+
+    Plan { sources, actors, signals: planned, stories, situations }
+}
+
+fn source_for(org: &Organization) -> SourceNode {
+    let key = canonical_value(&org.url);
+    let mut source =
+        SourceNode::new(key.clone(), key, Some(org.url.clone()), DiscoveryMethod::Curated, 0.6, org.role, None);
+    source.cadence_hours = Some(24);
+    source
+}
+
+fn actor_for(org: &Organization, blueprint: &Blueprint, now: DateTime<Utc>) -> ActorNode {
+    let social = is_social(&org.url);
+    let domain = org.url.split("://").nth(1).and_then(|rest| rest.split('/').next()).unwrap_or_default();
+    ActorNode {
+        id: Uuid::new_v4(),
+        name: org.name.clone(),
+        actor_type: org.actor_type,
+        entity_id: slugify(&org.name),
+        domains: if social { Vec::new() } else { vec![domain.to_string()] },
+        social_urls: if social { vec![org.url.clone()] } else { Vec::new() },
+        description: format!("{} serving {}.", org.name, blueprint.name),
+        signal_count: 0,
+        first_seen: now - Duration::days(90),
+        last_active: now,
+        typical_roles: vec!["authored".to_string()],
+        bio: None,
+        location_lat: Some(blueprint.center_lat),
+        location_lng: Some(blueprint.center_lng),
+        location_name: Some(blueprint.name.clone()),
+        discovery_depth: 0,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn signal(
+    node_type: NodeType,
+    title: String,
+    source_url: Option<String>,
+    place: &Place,
+    org: &Organization,
+    author: usize,
+    theme: usize,
+    now: DateTime<Utc>,
+    rng: &mut StdRng,
+) -> PlannedSignal {
+    let extracted_at = now - Duration::minutes(rng.random_range(0..60 * 24 * 21));
+    let (lat, lng) = near(place, 0.8, rng);
+    let source_url = source_url.unwrap_or_else(|| format!("{}/{}", org.url.trim_end_matches('/'), slugify(&title)));
+    let summary = format!("{title}. Shared by {}.", org.name);
+    let meta = NodeMeta {
+        id: Uuid::new_v4(),
+        title,
+        summary: summary.clone(),
+        sensitivity: SensitivityLevel::General,
+        confidence: rng.random_range(0.6..0.95),
+        freshness_score: rng.random_range(0.5..1.0),
+        corroboration_count: rng.random_range(0..4),
+        about_location: Some(GeoPoint { lat, lng, precision: GeoPrecision::Neighborhood }),
+        about_location_name: Some(place.name.clone()),
+        from_location: None,
+        source_url: source_url.clone(),
+        extracted_at,
+        content_date: Some(extracted_at),
+        last_confirmed_active: extracted_at,
+        source_diversity: rng.random_range(1..4),
+        external_ratio: rng.random_range(0.0..0.6),
+        cause_heat: rng.random_range(0.0..1.0),
+        implied_queries: Vec::new(),
+        channel_diversity: rng.random_range(1..3),
+        mentioned_actors: Vec::new(),
+        author_actor: Some(org.name.clone()),
+        field_visibility: FieldVisibility::default(),
+        alt_text: None,
+        plain_summary: None,
+    };
+    let evidence = EvidenceNode {
+        id: Uuid::new_v4(),
+        source_url: source_url.clone(),
+        retrieved_at: extracted_at,
+        content_hash: Uuid::new_v4().simple().to_string(),
+        snippet: Some(summary),
+        relevance: Some("direct".to_string()),
+        evidence_confidence: Some(meta.confidence),
+        channel_type: Some(if is_social(&org.url) { ChannelType::Social } else { ChannelType::CommunityMedia }),
+        change_summary: None,
+        engagement_velocity: None,
+        verification: None,
+    };
+    let severity = *[Severity::Low, Severity::Medium, Severity::High].choose(rng).unwrap();
+    let node = match node_type {
+        NodeType::Gathering => Node::Gathering(GatheringNode {
+            meta,
+            starts_at: Some(now + Duration::hours(rng.random_range(2..24 * 14))),
+            ends_at: None,
+            action_url: source_url,
+            organizer: Some(org.name.clone()),
+            is_recurring: rng.random_bool(0.3),
+        }),
+        NodeType::Aid => Node::Aid(AidNode {
+            meta,
+            action_url: source_url,
+            availability: Some(["Weekdays 9–5", "Tuesdays 5–8pm", "Saturdays 10–2"].choose(rng).unwrap().to_string()),
+            is_ongoing: rng.random_bool(0.6),
+        }),
+        NodeType::Need => Node::Need(NeedNode {
+            meta,
+            urgency: *[Urgency::Low, Urgency::Medium, Urgency::High].choose(rng).unwrap(),
+            what_needed: Some("Volunteers and donations".to_string()),
+            action_url: Some(source_url),
+            goal: None,
+            fundraiser: None,
+        }),
+        NodeType::Notice => Node::Notice(NoticeNode {
+            meta,
+            severity,
+            category: None,
+            effective_date: Some(extracted_at + Duration::days(rng.random_range(0..14))),
+            source_authority: Some(org.name.clone()),
+        }),
+        NodeType::Tension | NodeType::Evidence => Node::Tension(TensionNode {
+            meta,
+            severity,
+            category: None,
+            what_would_help: Some("More capacity and neighbors who show up".to_string()),
+        }),
+    };
+    PlannedSignal { node, evidence, author, theme }
+}
+
+fn story_for(group: &[&PlannedSignal], theme: &crate::blueprint::Theme, index: usize) -> StoryNode {
+    let metas: Vec<&NodeMeta> = group.iter().filter_map(|s| s.node.meta()).collect();
+    let points: Vec<&GeoPoint> = metas.iter().filter_map(|m| m.about_location.as_ref()).collect();
+    let count_of = |t: NodeType| group.iter().filter(|s| s.node.node_type() == t).count() as u32;
+    let count = group.len() as u32;
+    let sources = group.iter().map(|s| s.author).collect::<std::collections::HashSet<_>>().len() as u32;
+    let headline = group
+        .iter()
+        .find(|s| s.node.node_type() == NodeType::Tension)
+        .or(group.first())
+        .and_then(|s| s.node.meta())
+        .map(|m| m.title.clone())
+        .unwrap_or_default();
+    StoryNode {
+        id: Uuid::new_v4(),
+        headline,
+        summary: theme.lede.clone(),
+        signal_count: count,
+        first_seen: metas.iter().map(|m| m.extracted_at).min().unwrap_or_else(Utc::now),
+        last_updated: metas.iter().map(|m| m.extracted_at).max().unwrap_or_else(Utc::now),
+        velocity: (index % 5) as f64 / 5.0,
+        energy: 0.3 + (index % 7) as f64 / 10.0,
+        centroid_lat: centroid(&points, |p| p.lat),
+        centroid_lng: centroid(&points, |p| p.lng),
+        dominant_type: group.first().map(|s| s.node.node_type().to_string()).unwrap_or_default(),
+        sensitivity: "general".to_string(),
+        source_count: sources,
+        entity_count: sources,
+        type_diversity: [NodeType::Gathering, NodeType::Aid, NodeType::Need, NodeType::Notice, NodeType::Tension]
+            .into_iter()
+            .filter(|t| count_of(*t) > 0)
+            .count() as u32,
+        source_domains: Vec::new(),
+        corroboration_depth: 1,
+        status: if sources > 1 { "confirmed" } else { "emerging" }.to_string(),
+        arc: None,
+        category: Some(theme.category.clone()),
+        lede: Some(theme.lede.clone()),
+        narrative: None,
+        action_guidance: None,
+        cause_heat: 0.5,
+        ask_count: count_of(NodeType::Need),
+        give_count: count_of(NodeType::Aid),
+        event_count: count_of(NodeType::Gathering),
+        drawn_to_count: 0,
+        gap_score: count_of(NodeType::Need) as i32 - count_of(NodeType::Aid) as i32,
+        gap_velocity: 0.0,
+        channel_diversity: 1,
+        energy_components: None,
+    }
+}
+
+fn situation_for(
+    members: &[&PlannedSignal],
+    headline: String,
+    theme: &crate::blueprint::Theme,
+    place: &str,
+    index: usize,
+    now: DateTime<Utc>,
+) -> PlannedSituation {
+    let id = Uuid::new_v4();
+    let metas: Vec<&NodeMeta> = members.iter().filter_map(|s| s.node.meta()).collect();
+    let points: Vec<&GeoPoint> = metas.iter().filter_map(|m| m.about_location.as_ref()).collect();
+    let tensions = members.iter().filter(|s| s.node.node_type() == NodeType::Tension).count() as u32;
+    let arcs = [SituationArc::Active, SituationArc::Developing, SituationArc::Emerging, SituationArc::Active, SituationArc::Cooling];
+    let heat = 0.35 + (index % 5) as f64 * 0.12;
+
+    // Early dispatches cite the earliest signals, later ones the newest.
+    let phases = members.chunks(members.len().div_ceil(DISPATCHES_PER_SITUATION).max(1));
+    let dispatches: Vec<DispatchNode> = phases
+        .enumerate()
+        .map(|(i, phase)| {
+            let cited: Vec<&NodeMeta> = phase.iter().filter_map(|s| s.node.meta()).take(CITES_PER_DISPATCH).collect();
+            let body = cited.iter().map(|m| format!("{} [signal:{}].", m.title, m.id)).collect::<Vec<_>>().join(" ");
+            DispatchNode {
+                id: Uuid::new_v4(),
+                situation_id: id,
+                body,
+                signal_ids: cited.iter().map(|m| m.id).collect(),
+                created_at: cited.iter().map(|m| m.extracted_at).max().unwrap_or(now),
+                dispatch_type: if i == 0 { DispatchType::Emergence } else { DispatchType::Update },
+                supersedes: None,
+                flagged_for_review: false,
+                flag_reason: None,
+                fidelity_score: None,
+            }
+        })
+        .collect();
+
+    PlannedSituation {
+        situation: SituationNode {
+            id,
+            headline,
+            lede: theme.lede.replace("{place}", place),
+            arc: arcs[index % arcs.len()],
+            temperature: heat,
+            tension_heat: heat,
+            entity_velocity: 0.4,
+            amplification: 0.3,
+            response_coverage: 0.5,
+            clarity_need: 0.3,
+            clarity: Clarity::Sharpening,
+            centroid_lat: centroid(&points, |p| p.lat),
+            centroid_lng: centroid(&points, |p| p.lng),
+            location_name: Some(place.to_string()),
+            structured_state: "{}".to_string(),
+            signal_count: members.len() as u32,
+            tension_count: tensions,
+            dispatch_count: dispatches.len() as u32,
+            first_seen: metas.iter().map(|m| m.extracted_at).min().unwrap_or(now),
+            last_updated: metas.iter().map(|m| m.extracted_at).max().unwrap_or(now),
+            sensitivity: SensitivityLevel::General,
+            category: Some(theme.category.clone()),
+            demographics: None,
+            escalation: None,
+            alt_text: None,
+            plain_summary: None,
+        },
+        signals: members.iter().filter_map(|s| s.node.meta().map(|m| (m.id, s.node.node_type()))).collect(),
+        dispatches,
+    }
+}
+
+/// A point within `radius_km` of `place`.
+fn near(place: &Place, radius_km: f64, rng: &mut StdRng) -> (f64, f64) {
+    let distance = radius_km * rng.random::<f64>().sqrt();
+    let bearing = rng.random_range(0.0..std::f64::consts::TAU);
+    let km_per_degree_lng = KM_PER_DEGREE_LAT * place.lat.to_radians().cos();
+    (
+        place.lat + distance * bearing.cos() / KM_PER_DEGREE_LAT,
+        place.lng + distance * bearing.sin() / km_per_degree_lng,
+    )
+}
+
+fn centroid(points: &[&GeoPoint], f: fn(&GeoPoint) -> f64) -> Option<f64> {
+    (!points.is_empty()).then(|| points.iter().map(|p| f(p)).sum::<f64>() / points.len() as f64)
+}
+
+fn is_social(url: &str) -> bool {
+    ["instagram.com", "facebook.com", "reddit.com", "tiktok.com"].iter().any(|d| url.contains(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn demo_region_has_every_signal_type() {
+        let blueprint = Blueprint::twin_cities();
+
+        let plan = plan(&blueprint, 100, &mut StdRng::seed_from_u64(1));
+
+        for t in [NodeType::Gathering, NodeType::Aid, NodeType::Need, NodeType::Notice, NodeType::Tension] {
+            assert!(plan.signals.iter().any(|s| s.node.node_type() == t), "no {t} signals");
+        }
+        assert_eq!(plan.situations.len(), blueprint.themes.len());
+    }
+
+    #[test]
+    fn dispatches_cite_only_their_situations_signals() {
+        let plan = plan(&Blueprint::twin_cities(), 60, &mut StdRng::seed_from_u64(2));
+
+        for planned in &plan.situations {
+            assert_eq!(planned.dispatches[0].dispatch_type, DispatchType::Emergence);
+            for dispatch in &planned.dispatches {
+                for id in &dispatch.signal_ids {
+                    assert!(planned.signals.iter().any(|(s, _)| s == id));
+                    assert!(dispatch.body.contains(&format!("[signal:{id}]")));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signals_cluster_around_the_region() {
+        let blueprint = Blueprint::procedural("Riverton", 40.0, -105.0);
+
+        let plan = plan(&blueprint, 50, &mut StdRng::seed_from_u64(3));
+
+        for meta in plan.signals.iter().filter_map(|s| s.node.meta()) {
+            let point = meta.about_location.as_ref().unwrap();
+            assert!((point.lat - 40.0).abs() < 0.15 && (point.lng + 105.0).abs() < 0.15);
+        }
+    }
+}
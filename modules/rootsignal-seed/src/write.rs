@@ -0,0 +1,121 @@
+//! Writes a plan through the same `GraphWriter` calls the scout and weaver
+//! use, then publishes it so the API serves it without review.
+//!
+//! Everything created is marked `created_by: "demo-seed"` so `wipe` can
+//! remove it without touching real data.
+
+use tracing::info;
+use uuid::Uuid;
+
+use rootsignal_graph::reader::node_type_label;
+use rootsignal_graph::{query, GraphClient, GraphWriter};
+
+use crate::plan::Plan;
+
+pub const SEED_CREATOR: &str = "demo-seed";
+
+/// Write `plan`, returning the number of signals created.
+pub async fn write(client: &GraphClient, plan: &Plan) -> anyhow::Result<usize> {
+    let writer = GraphWriter::new(client.clone());
+    let run_id = format!("demo-seed-{}", Uuid::new_v4());
+
+    // Sources and actors merge on their keys, so ones already in the graph
+    // are reused and left untagged.
+    let mut actor_ids = Vec::with_capacity(plan.actors.len());
+    for (source, actor) in plan.sources.iter().zip(&plan.actors) {
+        writer.upsert_source(source).await?;
+        writer.upsert_actor(actor).await?;
+        tag("Source", source.id, client).await?;
+        tag("Actor", actor.id, client).await?;
+        let actor_id = writer.find_actor_by_entity_id(&actor.entity_id).await?.unwrap_or(actor.id);
+        let q = query(
+            "MATCH (a:Actor {id: $actor_id})
+             MATCH (s:Source {canonical_key: $canonical_key})
+             MERGE (a)-[:HAS_SOURCE]->(s)",
+        )
+        .param("actor_id", actor_id.to_string())
+        .param("canonical_key", source.canonical_key.as_str());
+        client.inner().run(q).await?;
+        actor_ids.push(actor_id);
+    }
+    info!(organizations = actor_ids.len(), "Sources and actors seeded");
+
+    for (i, planned) in plan.signals.iter().enumerate() {
+        let id = writer.create_node(&planned.node, &[], SEED_CREATOR, &run_id).await?;
+        writer.create_evidence(&planned.evidence, id).await?;
+        writer.link_actor_to_signal(actor_ids[planned.author], id, "authored").await?;
+        if (i + 1).is_multiple_of(100) {
+            info!(written = i + 1, total = plan.signals.len(), "Seeding signals");
+        }
+    }
+    let q = query(
+        "MATCH (n {created_by: $creator})-[:SOURCED_FROM]->(ev:Evidence)
+         SET ev.created_by = $creator",
+    )
+    .param("creator", SEED_CREATOR);
+    client.inner().run(q).await?;
+    info!(signals = plan.signals.len(), "Signals seeded");
+
+    for planned in &plan.stories {
+        writer.create_story(&planned.story).await?;
+        for signal_id in &planned.signals {
+            writer.link_signal_to_story(planned.story.id, *signal_id).await?;
+        }
+        tag("Story", planned.story.id, client).await?;
+    }
+    info!(stories = plan.stories.len(), "Stories seeded");
+
+    for planned in &plan.situations {
+        let situation_id = writer.create_situation(&planned.situation, &[], &[]).await?;
+        tag("Situation", situation_id, client).await?;
+        for (signal_id, node_type) in &planned.signals {
+            writer.merge_evidence_edge(signal_id, node_type_label(*node_type), &situation_id, 0.9).await?;
+        }
+        for dispatch in &planned.dispatches {
+            let dispatch_id = writer.create_dispatch(dispatch).await?;
+            writer.merge_cites_edges(&dispatch_id, &dispatch.signal_ids).await?;
+            tag("Dispatch", dispatch_id, client).await?;
+        }
+    }
+    info!(situations = plan.situations.len(), "Situations seeded");
+
+    let q = query(
+        "MATCH (n {created_by: $creator})
+         WHERE n.review_status = 'staged'
+         SET n.review_status = 'live'",
+    )
+    .param("creator", SEED_CREATOR);
+    client.inner().run(q).await?;
+    Ok(plan.signals.len())
+}
+
+async fn tag(label: &str, id: Uuid, client: &GraphClient) -> anyhow::Result<()> {
+    let q = query(&format!("MATCH (n:{label} {{id: $id}}) SET n.created_by = $creator"))
+        .param("id", id.to_string())
+        .param("creator", SEED_CREATOR);
+    client.inner().run(q).await?;
+    Ok(())
+}
+
+/// Delete everything a previous seed created. Returns the number of nodes removed.
+pub async fn wipe(client: &GraphClient) -> anyhow::Result<u64> {
+    let mut removed = 0u64;
+    loop {
+        let q = query(
+            "MATCH (n {created_by: $creator})
+             WITH n LIMIT 5000
+             DETACH DELETE n
+             RETURN count(*) AS removed",
+        )
+        .param("creator", SEED_CREATOR);
+        let mut stream = client.inner().execute(q).await?;
+        let batch = match stream.next().await? {
+            Some(row) => row.get::<i64>("removed").unwrap_or(0) as u64,
+            None => 0,
+        };
+        if batch == 0 {
+            return Ok(removed);
+        }
+        removed += batch;
+    }
+}
@@ -1,10 +1,12 @@
 import { Routes, Route } from "react-router";
 import { SearchPage } from "@/pages/SearchPage";
+import { TransparencyPage } from "@/pages/TransparencyPage";
 
 export default function App() {
   return (
     <Routes>
       <Route path="/" element={<SearchPage />} />
+      <Route path="/transparency/:region" element={<TransparencyPage />} />
     </Routes>
   );
 }
@@ -0,0 +1,123 @@
+import { useState } from "react";
+import { useMutation } from "@apollo/client";
+import { REQUEST_CORRECTION } from "@/graphql/mutations";
+import { SIGNAL_DETAIL } from "@/graphql/queries";
+
+interface Correction {
+  id: string;
+  kind: string;
+  status: string;
+  resolutionNote?: string | null;
+}
+
+interface SignalCorrectionsProps {
+  signalId: string;
+  corrections: Correction[];
+}
+
+// Duplicates are reported through the API with the other signal's id.
+const KINDS: { value: string; label: string }[] = [
+  { value: "OUTDATED", label: "Outdated — moved, closed, or over" },
+  { value: "WRONG_LOCATION", label: "Wrong location" },
+  { value: "NOT_TRUE", label: "Not true" },
+  { value: "HARMFUL", label: "Harmful — puts someone at risk" },
+];
+
+const KIND_LABELS: Record<string, string> = {
+  OUTDATED: "Outdated",
+  WRONG_LOCATION: "Wrong location",
+  DUPLICATE: "Duplicate",
+  NOT_TRUE: "Not true",
+  HARMFUL: "Harmful",
+};
+
+const STATUS_LABELS: Record<string, string> = {
+  OPEN: "Under review",
+  ACCEPTED: "Corrected",
+  DISMISSED: "Reviewed, no change",
+};
+
+export function SignalCorrections({ signalId, corrections }: SignalCorrectionsProps) {
+  const [open, setOpen] = useState(false);
+  const [kind, setKind] = useState(KINDS[0].value);
+  const [details, setDetails] = useState("");
+  const [requestCorrection, { loading, error, data }] = useMutation(REQUEST_CORRECTION, {
+    refetchQueries: [{ query: SIGNAL_DETAIL, variables: { id: signalId } }],
+  });
+
+  const submit = () => {
+    requestCorrection({
+      variables: { signalId, kind, details: details.trim() || null },
+    }).then(() => {
+      setOpen(false);
+      setDetails("");
+    }).catch(() => {});
+  };
+
+  return (
+    <div className="space-y-2">
+      {corrections.length > 0 && (
+        <div className="rounded-lg border border-orange-500/40 bg-orange-500/10 p-3 space-y-1">
+          <p className="text-xs font-medium text-foreground">Reported problems</p>
+          {corrections.map((c) => (
+            <div key={c.id} className="text-xs text-muted-foreground">
+              {KIND_LABELS[c.kind] ?? c.kind} · {STATUS_LABELS[c.status] ?? c.status}
+              {c.resolutionNote && <span> — {c.resolutionNote}</span>}
+            </div>
+          ))}
+        </div>
+      )}
+
+      {data && !open && (
+        <p className="text-xs text-muted-foreground">Thanks — your report is in the review queue.</p>
+      )}
+
+      {!open ? (
+        <button
+          onClick={() => setOpen(true)}
+          className="text-xs text-muted-foreground underline hover:text-foreground"
+        >
+          Report a problem
+        </button>
+      ) : (
+        <div className="rounded-lg border border-border p-3 space-y-2">
+          <select
+            value={kind}
+            onChange={(e) => setKind(e.target.value)}
+            className="w-full rounded border border-border bg-background px-2 py-1 text-sm"
+          >
+            {KINDS.map((k) => (
+              <option key={k.value} value={k.value}>
+                {k.label}
+              </option>
+            ))}
+          </select>
+          <textarea
+            value={details}
+            onChange={(e) => setDetails(e.target.value)}
+            maxLength={1000}
+            rows={3}
+            placeholder="What's wrong? (optional — please leave out names, phone numbers and addresses)"
+            className="w-full rounded border border-border bg-background px-2 py-1 text-sm"
+          />
+          {error && <p className="text-xs text-red-400">{error.message}</p>}
+          <div className="flex gap-2">
+            <button
+              onClick={submit}
+              disabled={loading}
+              className="rounded bg-primary px-3 py-1 text-xs font-medium text-primary-foreground disabled:opacity-50"
+            >
+              {loading ? "Sending…" : "Send report"}
+            </button>
+            <button
+              onClick={() => setOpen(false)}
+              className="rounded px-3 py-1 text-xs text-muted-foreground hover:text-foreground"
+            >
+              Cancel
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -1,12 +1,19 @@
 import { useQuery } from "@apollo/client";
 import { SIGNAL_DETAIL } from "@/graphql/queries";
 import { LinkPreview } from "@/components/LinkPreview";
+import { SignalCorrections } from "@/components/SignalCorrections";
 
 interface SignalDetailProps {
   signalId: string;
   onBack: () => void;
 }
 
+const FRESHNESS_LABELS: Record<string, string> = {
+  CHANGED: "Source page has changed",
+  REMOVED: "Source page removed",
+  STALE: "Not re-checked recently",
+};
+
 export function SignalDetail({ signalId, onBack }: SignalDetailProps) {
   const { data, loading } = useQuery(SIGNAL_DETAIL, {
     variables: { id: signalId },
@@ -71,6 +78,11 @@ export function SignalDetail({ signalId, onBack }: SignalDetailProps) {
               <div className="mt-2 space-y-2">
                 {signal.evidence.map((ev: Record<string, string>, i: number) => (
                   <div key={i} className="rounded border border-border p-2 text-xs">
+                    {ev.freshness && ev.freshness !== "CURRENT" && (
+                      <p className="mb-1 text-[10px] font-medium uppercase text-muted-foreground">
+                        {FRESHNESS_LABELS[ev.freshness] ?? ev.freshness.toLowerCase()}
+                      </p>
+                    )}
                     {ev.snippet && (
                       <p className="text-muted-foreground mb-1">{ev.snippet}</p>
                     )}
@@ -80,6 +92,8 @@ export function SignalDetail({ signalId, onBack }: SignalDetailProps) {
               </div>
             </details>
           )}
+
+          <SignalCorrections signalId={signalId} corrections={signal.corrections ?? []} />
         </div>
       )}
     </div>
@@ -24,10 +24,19 @@ export function SituationCard({ situation, isSelected, onClick }: SituationCardP
   const arc = (situation.arc as string) ?? "";
   const signalCount = (situation.signalCount as number) ?? 0;
   const locationName = situation.locationName as string | null;
+  const accessibility = situation.accessibility as
+    | { ariaLabel: string }
+    | undefined;
+  // The lede in the browser's language when the API has a translation
+  const localized = situation.localizedSummary as
+    | { language: string; summary: string }
+    | undefined;
+  const lede = localized?.summary ?? (situation.lede as string | undefined);
 
   return (
     <button
       onClick={onClick}
+      aria-label={accessibility?.ariaLabel}
       className={cn(
         "w-full text-left px-4 py-3 border-b border-border transition-colors hover:bg-card",
         isSelected && "bg-card border-l-2 border-l-primary",
@@ -49,9 +58,9 @@ export function SituationCard({ situation, isSelected, onClick }: SituationCardP
       <h3 className="text-sm font-medium text-foreground line-clamp-2">
         {situation.headline as string}
       </h3>
-      {typeof situation.lede === "string" && situation.lede && (
-        <p className="mt-1 text-xs text-muted-foreground line-clamp-2">
-          {situation.lede}
+      {lede && (
+        <p lang={localized?.language} className="mt-1 text-xs text-muted-foreground line-clamp-2">
+          {lede}
         </p>
       )}
     </button>
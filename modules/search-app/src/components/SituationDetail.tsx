@@ -1,5 +1,6 @@
 import { useQuery } from "@apollo/client";
 import { SITUATION_DETAIL } from "@/graphql/queries";
+import { SituationFollow } from "@/components/SituationFollow";
 
 interface SituationDetailProps {
   situationId: string;
@@ -87,6 +88,8 @@ export function SituationDetail({ situationId, onBack }: SituationDetailProps) {
             </div>
           </div>
 
+          <SituationFollow situationId={situationId} />
+
           {/* Dispatch thread */}
           {situation.dispatches?.length > 0 && (
             <div>
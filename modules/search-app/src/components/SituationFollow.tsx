@@ -0,0 +1,55 @@
+import { useState } from "react";
+import { useMutation } from "@apollo/client";
+import { SUBSCRIBE_TO_SITUATION } from "@/graphql/mutations";
+
+interface SituationFollowProps {
+  situationId: string;
+}
+
+export function SituationFollow({ situationId }: SituationFollowProps) {
+  const [email, setEmail] = useState("");
+  const [subscribe, { loading, error, data }] = useMutation(SUBSCRIBE_TO_SITUATION);
+  const result = data?.subscribeToSituation;
+  const feedUrl = `${import.meta.env.VITE_API_URL ?? ""}/situations/${situationId}/feed.atom`;
+
+  const submit = () => {
+    subscribe({ variables: { situationId, email: email.trim() } }).catch(() => {});
+  };
+
+  return (
+    <div className="rounded-lg border border-border p-3 space-y-2">
+      <div className="flex items-center justify-between">
+        <p className="text-xs font-medium text-foreground">Follow this situation</p>
+        <a href={feedUrl} className="text-xs text-muted-foreground underline hover:text-foreground">
+          Atom feed
+        </a>
+      </div>
+      {result?.success ? (
+        <p className="text-xs text-muted-foreground">
+          {result.message === "Already subscribed"
+            ? "You're already following this situation."
+            : "Check your email and follow the link to confirm. We'll only email you after that."}
+        </p>
+      ) : (
+        <div className="flex gap-2">
+          <input
+            type="email"
+            value={email}
+            onChange={(e) => setEmail(e.target.value)}
+            placeholder="you@example.com"
+            className="flex-1 rounded border border-border bg-background px-2 py-1 text-sm"
+          />
+          <button
+            onClick={submit}
+            disabled={loading || !email.trim()}
+            className="rounded bg-primary px-3 py-1 text-xs font-medium text-primary-foreground disabled:opacity-50"
+          >
+            {loading ? "Saving…" : "Email me"}
+          </button>
+        </div>
+      )}
+      {error && <p className="text-xs text-red-400">{error.message}</p>}
+      {result && !result.success && <p className="text-xs text-red-400">{result.message}</p>}
+    </div>
+  );
+}
@@ -15,3 +15,27 @@ export const RECORD_DEMAND = gql`
     )
   }
 `;
+
+export const REQUEST_CORRECTION = gql`
+  mutation RequestCorrection(
+    $signalId: UUID!
+    $kind: CorrectionKind!
+    $details: String
+  ) {
+    requestCorrection(signalId: $signalId, kind: $kind, details: $details) {
+      id
+      kind
+      status
+      resolutionNote
+    }
+  }
+`;
+
+export const SUBSCRIBE_TO_SITUATION = gql`
+  mutation SubscribeToSituation($situationId: UUID!, $email: String!) {
+    subscribeToSituation(situationId: $situationId, email: $email) {
+      success
+      message
+    }
+  }
+`;
@@ -295,8 +295,9 @@ export const SIGNAL_DETAIL = gql`
         endsAt
         organizer
         isRecurring
-        evidence { sourceUrl snippet relevance }
+        evidence { sourceUrl snippet relevance freshness }
         story { id headline }
+        corrections { id kind status resolutionNote }
       }
       ... on GqlAidSignal {
         id
@@ -311,8 +312,9 @@ export const SIGNAL_DETAIL = gql`
         sourceUrl
         availability
         isOngoing
-        evidence { sourceUrl snippet relevance }
+        evidence { sourceUrl snippet relevance freshness }
         story { id headline }
+        corrections { id kind status resolutionNote }
       }
       ... on GqlNeedSignal {
         id
@@ -328,8 +330,9 @@ export const SIGNAL_DETAIL = gql`
         urgency
         whatNeeded
         goal
-        evidence { sourceUrl snippet relevance }
+        evidence { sourceUrl snippet relevance freshness }
         story { id headline }
+        corrections { id kind status resolutionNote }
       }
       ... on GqlNoticeSignal {
         id
@@ -344,8 +347,9 @@ export const SIGNAL_DETAIL = gql`
         sourceUrl
         severity
         category
-        evidence { sourceUrl snippet relevance }
+        evidence { sourceUrl snippet relevance freshness }
         story { id headline }
+        corrections { id kind status resolutionNote }
       }
       ... on GqlTensionSignal {
         id
@@ -361,8 +365,9 @@ export const SIGNAL_DETAIL = gql`
         severity
         category
         whatWouldHelp
-        evidence { sourceUrl snippet relevance }
+        evidence { sourceUrl snippet relevance freshness }
         story { id headline }
+        corrections { id kind status resolutionNote }
       }
     }
   }
@@ -398,6 +403,8 @@ export const SITUATIONS_IN_BOUNDS = gql`
       locationName
       clarity
       category
+      accessibility { ariaLabel }
+      localizedSummary { language summary }
     }
   }
 `;
@@ -457,3 +464,25 @@ export const SITUATIONS = gql`
     }
   }
 `;
+
+export const REGION_TRANSPARENCY = gql`
+  query RegionTransparency($region: String!) {
+    regionTransparency(region: $region) {
+      computedAt
+      activeSources
+      sourceComposition { label count }
+      extractionAcceptanceRate
+      correctionCounts { label count }
+      suppressionCounts { label count }
+      suppressedOther
+      suppressedTotal
+      lastRun {
+        finishedAt
+        spentCents
+        budgetCents
+        promptVersion
+        promptLiveSince
+      }
+    }
+  }
+`;
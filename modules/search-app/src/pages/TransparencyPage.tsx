@@ -0,0 +1,117 @@
+import { useQuery } from "@apollo/client";
+import { useParams } from "react-router";
+import { REGION_TRANSPARENCY } from "@/graphql/queries";
+
+interface LabelCount {
+  label: string;
+  count: number;
+}
+
+const formatLabel = (label: string) => label.replace(/^[a-z_]+\//, "").replace(/_/g, " ");
+
+const formatDollars = (cents: number) => `$${(cents / 100).toFixed(2)}`;
+
+interface CountListProps {
+  title: string;
+  counts: LabelCount[];
+  empty: string;
+  footer?: string;
+}
+
+function CountList({ title, counts, empty, footer }: CountListProps) {
+  return (
+    <section className="rounded-lg border border-border p-4">
+      <h2 className="text-sm font-medium mb-2">{title}</h2>
+      {counts.length === 0 ? (
+        <p className="text-xs text-muted-foreground">{empty}</p>
+      ) : (
+        <ul className="space-y-1 text-sm">
+          {counts.map((c) => (
+            <li key={c.label} className="flex justify-between">
+              <span className="capitalize">{formatLabel(c.label)}</span>
+              <span className="text-muted-foreground">{c.count}</span>
+            </li>
+          ))}
+        </ul>
+      )}
+      {footer && <p className="mt-2 text-xs text-muted-foreground">{footer}</p>}
+    </section>
+  );
+}
+
+export function TransparencyPage() {
+  const { region = "" } = useParams();
+  const { data, loading, error } = useQuery(REGION_TRANSPARENCY, { variables: { region } });
+
+  if (loading) return <p className="p-8 text-sm text-muted-foreground">Loading...</p>;
+  if (error) return <p className="p-8 text-sm text-red-400">Error: {error.message}</p>;
+
+  const t = data?.regionTransparency;
+  if (!t) {
+    return <p className="p-8 text-sm text-muted-foreground">No methodology stats for this region yet.</p>;
+  }
+
+  const acceptance = t.extractionAcceptanceRate as number | null;
+  const run = t.lastRun;
+
+  return (
+    <div className="mx-auto max-w-3xl p-6 space-y-4">
+      <header>
+        <h1 className="text-xl font-semibold">How this map is made</h1>
+        <p className="text-xs text-muted-foreground mt-1">
+          Machine-generated stats for {region}, updated {new Date(t.computedAt as string).toLocaleString()}.
+        </p>
+      </header>
+
+      <div className="grid grid-cols-1 sm:grid-cols-3 gap-4">
+        <section className="rounded-lg border border-border p-4">
+          <h2 className="text-xs text-muted-foreground">Active sources</h2>
+          <p className="text-2xl font-semibold">{t.activeSources}</p>
+        </section>
+        <section className="rounded-lg border border-border p-4">
+          <h2 className="text-xs text-muted-foreground">Extractions kept</h2>
+          <p className="text-2xl font-semibold">
+            {acceptance == null ? "—" : `${Math.round(acceptance * 100)}%`}
+          </p>
+        </section>
+        <section className="rounded-lg border border-border p-4">
+          <h2 className="text-xs text-muted-foreground">Held back for safety</h2>
+          <p className="text-2xl font-semibold">{t.suppressedTotal ?? "Too few to report"}</p>
+        </section>
+      </div>
+
+      <CountList title="Where signals come from" counts={t.sourceComposition} empty="No active sources." />
+      <CountList title="Correction requests" counts={t.correctionCounts} empty="No corrections requested." />
+      <CountList
+        title="Held back for safety, by reason"
+        counts={t.suppressionCounts}
+        empty="Counts are too small to report by reason."
+        footer={
+          t.suppressedOther > 0
+            ? `${t.suppressedOther} more across reasons too small to report on their own.`
+            : undefined
+        }
+      />
+
+      <section className="rounded-lg border border-border p-4 text-sm space-y-1">
+        <h2 className="font-medium mb-2">Latest run</h2>
+        {run ? (
+          <>
+            <p>
+              Finished {new Date(run.finishedAt as string).toLocaleString()}, spending{" "}
+              {formatDollars(run.spentCents)}
+              {run.budgetCents > 0 && ` of a ${formatDollars(run.budgetCents)} daily budget`}.
+            </p>
+            <p>
+              Extraction prompt: <span className="font-mono">{run.promptVersion}</span>
+              {run.promptLiveSince &&
+                ` (in use since ${new Date(run.promptLiveSince as string).toLocaleDateString()})`}
+            </p>
+          </>
+        ) : (
+          <p className="text-muted-foreground">No runs recorded yet.</p>
+        )}
+      </section>
+    </div>
+  );
+}
@@ -4,6 +4,7 @@
 //! find tensions in the world, then find the needs/gives/events that address them.
 
 use std::future::Future;
+use std::path::PathBuf;
 
 use anyhow::{anyhow, Result};
 use serde::Deserialize;
@@ -11,18 +12,28 @@ use tracing::{info, warn};
 
 use ai_client::Claude;
 
-use crate::fitness::{is_improvement, score_genome};
+use crate::fitness::{
+    attribute, is_improvement, score_genome, FitnessAttribution, RegressionPolicy, ScenarioRegression,
+};
 use crate::genome::{ScenarioScore, ScoutGenome};
+use crate::history::ScoreHistory;
 use crate::improve::Improver;
 use crate::judge::Verdict;
 use crate::scenario_gym::ScenarioGym;
 
 const SONNET_MODEL: &str = "claude-sonnet-4-20250514";
 
+/// Judge issues per failing scenario included in the mutation prompt.
+const MAX_CITED_ISSUES: usize = 3;
+
 /// Configuration for an evolution run.
 pub struct EvolutionConfig {
     pub max_generations: u32,
     pub mutations_per_generation: u32,
+    /// A mutant that regresses a protected scenario past this never becomes champion.
+    pub regression_policy: RegressionPolicy,
+    /// Where champions' per-scenario scores are kept across runs.
+    pub history_path: Option<PathBuf>,
 }
 
 impl Default for EvolutionConfig {
@@ -30,6 +41,8 @@ impl Default for EvolutionConfig {
         Self {
             max_generations: 3,
             mutations_per_generation: 2,
+            regression_policy: RegressionPolicy::default(),
+            history_path: None,
         }
     }
 }
@@ -39,6 +52,11 @@ pub struct EvolutionResult {
     pub champion: ScoutGenome,
     pub history: Vec<ScoutGenome>,
     pub scenarios_promoted: usize,
+    /// The baseline-to-champion change, by scenario.
+    pub attribution: FitnessAttribution,
+    /// Protected scenarios the champion scores worse on than the previous
+    /// run's champion did. Empty without a history.
+    pub history_regressions: Vec<ScenarioRegression>,
 }
 
 /// Audit report summary (passed to the evolver from the test harness).
@@ -143,6 +161,20 @@ impl Evolver {
                 );
 
                 if is_improvement(&fitness, champion.fitness.as_ref().unwrap()) {
+                    let regressions = config
+                        .regression_policy
+                        .regressions(champion_scores.as_deref().unwrap_or_default(), &fitness.scenario_scores);
+                    if !regressions.is_empty() {
+                        for r in &regressions {
+                            warn!(
+                                scenario = r.name.as_str(),
+                                before = r.before,
+                                after = r.after,
+                                "Mutant regresses a protected scenario, keeping champion"
+                            );
+                        }
+                        continue;
+                    }
                     info!(
                         old_fitness = champion.fitness.as_ref().unwrap().total,
                         new_fitness = fitness.total,
@@ -190,10 +222,36 @@ impl Evolver {
             }
         }
 
+        let champion_fitness = champion.fitness.as_ref().unwrap();
+        let attribution = attribute(&baseline_fitness, champion_fitness);
+        info!(report = %attribution, "Evolution fitness attribution");
+
+        let mut history_regressions = Vec::new();
+        if let Some(path) = &config.history_path {
+            let mut scores = ScoreHistory::load(path)?;
+            if let Some(previous) = scores.latest() {
+                history_regressions = config
+                    .regression_policy
+                    .regressions(&previous.scenario_scores, &champion_fitness.scenario_scores);
+                for r in &history_regressions {
+                    warn!(
+                        scenario = r.name.as_str(),
+                        before = r.before,
+                        after = r.after,
+                        "Champion regresses a protected scenario since the last run"
+                    );
+                }
+            }
+            scores.record(&champion);
+            scores.save(path)?;
+        }
+
         Ok(EvolutionResult {
             champion,
             history,
             scenarios_promoted,
+            attribution,
+            history_regressions,
         })
     }
 
@@ -215,12 +273,18 @@ impl Evolver {
                 .await
                 .map_err(|e| anyhow!("Failed to evaluate scenario '{}': {}", scenario.name, e))?;
 
+            let mut issues = std::collections::BTreeMap::new();
+            for issue in &verdict.issues {
+                *issues.entry(issue.category.clone()).or_insert(0) += 1;
+            }
             scores.push(ScenarioScore {
                 name: scenario.name.clone(),
                 verdict_pass: verdict.pass,
                 verdict_score: verdict.score,
                 audit_passed: audit.passed,
                 audit_total: audit.total,
+                issues,
+                findings: verdict.issues.clone(),
             });
         }
 
@@ -317,10 +381,22 @@ fn collect_failures(genome: &ScoutGenome) -> String {
             } else {
                 ""
             };
-            format!(
+            let mut line = format!(
                 "- {}{}: verdict={} (score={:.2}), audit={}/{} passed",
                 s.name, gap_hint, s.verdict_pass, s.verdict_score, s.audit_passed, s.audit_total,
-            )
+            );
+            // The judge's cited issues show the exact content the prompt got wrong.
+            for issue in s.findings.iter().take(MAX_CITED_ISSUES) {
+                let cites: Vec<String> = issue.citations.iter().map(ToString::to_string).collect();
+                line.push_str(&format!(
+                    "\n    [{:?}] {}: {} (cites {})",
+                    issue.severity,
+                    issue.category,
+                    issue.description,
+                    cites.join("; ")
+                ));
+            }
+            line
         })
         .collect();
 
@@ -343,6 +419,7 @@ fn collect_test_failures(genome: &ScoutGenome) -> Vec<crate::improve::TestFailur
             verdict_pass: s.verdict_pass,
             verdict_score: s.verdict_score,
             verdict_reasoning: format!("score={:.2}", s.verdict_score),
+            issues: s.findings.clone(),
             audit_failures: if s.audit_passed < s.audit_total {
                 vec![format!(
                     "{}/{} audit checks passed",
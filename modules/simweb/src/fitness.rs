@@ -1,4 +1,9 @@
-//! Fitness scoring for genome evaluation.
+//! Fitness scoring for genome evaluation, and the per-scenario view of it:
+//! which scenarios a change helped or hurt, and whether any protected
+//! scenario regressed past its allowance.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 
 use chrono::Utc;
 
@@ -75,6 +80,169 @@ pub fn is_improvement(mutant: &FitnessScore, champion: &FitnessScore) -> bool {
     mutant.total > champion.total && mutant.regressions == 0
 }
 
+/// How far a protected scenario's fitness may drop before a genome is
+/// rejected, however much it gains elsewhere.
+#[derive(Debug, Clone)]
+pub struct RegressionPolicy {
+    /// Largest allowed drop, as a fraction of the scenario's previous fitness.
+    pub max_drop: f64,
+    /// Scenarios the limit applies to; `None` protects every scenario.
+    pub protected: Option<BTreeSet<String>>,
+}
+
+impl Default for RegressionPolicy {
+    fn default() -> Self {
+        Self {
+            max_drop: 0.1,
+            protected: None,
+        }
+    }
+}
+
+/// A protected scenario whose fitness dropped more than the policy allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioRegression {
+    pub name: String,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl ScenarioRegression {
+    /// Drop as a fraction of `before`.
+    pub fn drop(&self) -> f64 {
+        (self.before - self.after) / self.before
+    }
+}
+
+impl RegressionPolicy {
+    fn protects(&self, name: &str) -> bool {
+        self.protected.as_ref().is_none_or(|p| p.contains(name))
+    }
+
+    /// Protected scenarios in `after` that dropped too far from `before`.
+    /// Scenarios missing from either side are skipped.
+    pub fn regressions(&self, before: &[ScenarioScore], after: &[ScenarioScore]) -> Vec<ScenarioRegression> {
+        after
+            .iter()
+            .filter(|s| self.protects(&s.name))
+            .filter_map(|s| {
+                let base = before.iter().find(|b| b.name == s.name)?;
+                let regression = ScenarioRegression {
+                    name: s.name.clone(),
+                    before: base.fitness(),
+                    after: s.fitness(),
+                };
+                (regression.before > 0.0 && regression.drop() > self.max_drop).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// One scenario's share of a change in total fitness.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioDelta {
+    pub name: String,
+    /// `None` when the scenario wasn't evaluated on that side.
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+    /// Change in the scenario's contribution to the total.
+    pub contribution: f64,
+    /// Change in judge issue counts by category; unchanged categories omitted.
+    pub issues: BTreeMap<String, i64>,
+}
+
+/// A change in total fitness, broken down by scenario.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitnessAttribution {
+    pub total_before: f64,
+    pub total_after: f64,
+    /// Largest contribution change first.
+    pub scenarios: Vec<ScenarioDelta>,
+}
+
+/// A scenario's fitness, its contribution to the total and its issues.
+fn share<'a>(scores: &'a [ScenarioScore], name: &str) -> Option<(f64, f64, &'a BTreeMap<String, u32>)> {
+    scores
+        .iter()
+        .find(|s| s.name == name)
+        .map(|s| (s.fitness(), s.fitness() / scores.len() as f64, &s.issues))
+}
+
+/// Attribute the change from `before` to `after` to scenarios and judge
+/// issue categories. Contributions are before the regression penalty, so
+/// they sum to the raw change when both sides ran the same scenarios.
+pub fn attribute(before: &FitnessScore, after: &FitnessScore) -> FitnessAttribution {
+    let names: BTreeSet<&str> = before
+        .scenario_scores
+        .iter()
+        .chain(&after.scenario_scores)
+        .map(|s| s.name.as_str())
+        .collect();
+
+    let empty = BTreeMap::new();
+    let mut scenarios: Vec<ScenarioDelta> = names
+        .into_iter()
+        .map(|name| {
+            let old = share(&before.scenario_scores, name);
+            let new = share(&after.scenario_scores, name);
+            let old_issues = old.map_or(&empty, |o| o.2);
+            let new_issues = new.map_or(&empty, |n| n.2);
+            let issues = old_issues
+                .keys()
+                .chain(new_issues.keys())
+                .map(|category| {
+                    let count = |m: &BTreeMap<String, u32>| m.get(category).copied().unwrap_or(0) as i64;
+                    (category.clone(), count(new_issues) - count(old_issues))
+                })
+                .filter(|(_, delta)| *delta != 0)
+                .collect();
+            ScenarioDelta {
+                name: name.to_string(),
+                before: old.map(|o| o.0),
+                after: new.map(|n| n.0),
+                contribution: new.map_or(0.0, |n| n.1) - old.map_or(0.0, |o| o.1),
+                issues,
+            }
+        })
+        .collect();
+    scenarios.sort_by(|a, b| b.contribution.abs().total_cmp(&a.contribution.abs()));
+
+    FitnessAttribution {
+        total_before: before.total,
+        total_after: after.total,
+        scenarios,
+    }
+}
+
+impl fmt::Display for FitnessAttribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Fitness {:.3} -> {:.3} ({:+.3})",
+            self.total_before,
+            self.total_after,
+            self.total_after - self.total_before
+        )?;
+        let score = |s: Option<f64>| s.map_or("-".to_string(), |s| format!("{s:.2}"));
+        for s in &self.scenarios {
+            write!(
+                f,
+                "  {:+.3}  {}: {} -> {}",
+                s.contribution,
+                s.name,
+                score(s.before),
+                score(s.after)
+            )?;
+            if !s.issues.is_empty() {
+                let issues: Vec<String> = s.issues.iter().map(|(c, d)| format!("{c} {d:+}")).collect();
+                write!(f, " [issues: {}]", issues.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,9 +260,15 @@ mod tests {
             verdict_score: score,
             audit_passed,
             audit_total,
+            issues: BTreeMap::new(),
+            findings: vec![],
         }
     }
 
+    fn fitness(scores: Vec<ScenarioScore>) -> FitnessScore {
+        score_genome(&scores, None)
+    }
+
     #[test]
     fn empty_scores_yield_zero() {
         let fitness = score_genome(&[], None);
@@ -154,4 +328,38 @@ mod tests {
         };
         assert!(is_improvement(&clean_improvement, &champion));
     }
+
+    #[test]
+    fn protected_scenario_drop_past_allowance_is_a_regression() {
+        let champion = vec![make_score("rural", true, 0.8, 5, 5), make_score("urban", true, 0.5, 5, 5)];
+        let mutant = vec![make_score("rural", true, 0.6, 5, 5), make_score("urban", true, 1.0, 5, 5)];
+        let only_urban = RegressionPolicy {
+            protected: Some(["urban".to_string()].into()),
+            ..Default::default()
+        };
+
+        let regressions = RegressionPolicy::default().regressions(&champion, &mutant);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "rural");
+        assert!((regressions[0].drop() - 0.14 / 0.86).abs() < 0.001);
+        assert!(only_urban.regressions(&champion, &mutant).is_empty());
+    }
+
+    #[test]
+    fn attribution_names_the_scenarios_and_issues_behind_a_change() {
+        let mut hallucinating = make_score("rural", false, 0.4, 5, 5);
+        hallucinating.issues.insert("hallucination".to_string(), 2);
+        let before = fitness(vec![make_score("rural", true, 0.8, 5, 5), make_score("urban", true, 0.6, 5, 5)]);
+        let after = fitness(vec![hallucinating, make_score("urban", true, 0.7, 5, 5)]);
+
+        let attribution = attribute(&before, &after);
+
+        let rural = &attribution.scenarios[0];
+        assert_eq!(rural.name, "rural");
+        assert!((rural.contribution - (-0.7 * 0.4 / 2.0)).abs() < 0.001);
+        assert_eq!(rural.issues.get("hallucination"), Some(&2));
+        let sum: f64 = attribution.scenarios.iter().map(|s| s.contribution).sum();
+        assert!((sum - (after.total - before.total)).abs() < 0.001);
+    }
 }
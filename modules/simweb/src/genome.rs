@@ -1,9 +1,13 @@
 //! ScoutGenome — treats scout's prompts as a mutable genome for evolution.
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::judge::Issue;
+
 /// A genome representing a scout's prompt configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutGenome {
@@ -39,6 +43,24 @@ pub struct ScenarioScore {
     pub verdict_score: f32,
     pub audit_passed: usize,
     pub audit_total: usize,
+    /// Judge issues by category, e.g. `hallucination`.
+    #[serde(default)]
+    pub issues: BTreeMap<String, u32>,
+    /// The judge's verified issues, with their citations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<Issue>,
+}
+
+impl ScenarioScore {
+    /// The scenario's own fitness, on the same 0.7/0.3 weighting as the total.
+    pub fn fitness(&self) -> f64 {
+        let audit = if self.audit_total == 0 {
+            1.0
+        } else {
+            self.audit_passed as f64 / self.audit_total as f64
+        };
+        0.7 * self.verdict_score as f64 + 0.3 * audit
+    }
 }
 
 impl ScoutGenome {
@@ -0,0 +1,115 @@
+//! Score history — each evolution run's champion, scored per scenario, kept
+//! on disk so later runs can tell which scenarios moved.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::genome::{ScenarioScore, ScoutGenome};
+
+/// Runs kept; older ones are dropped on record.
+const KEEP_RUNS: usize = 100;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreHistory {
+    /// Oldest first.
+    pub runs: Vec<HistoryRun>,
+}
+
+/// One evolution run's champion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRun {
+    pub recorded_at: DateTime<Utc>,
+    pub champion_id: String,
+    pub generation: u32,
+    pub total: f64,
+    pub scenario_scores: Vec<ScenarioScore>,
+}
+
+impl ScoreHistory {
+    /// Load from `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Record an evaluated champion. Unevaluated genomes are ignored.
+    pub fn record(&mut self, champion: &ScoutGenome) {
+        let Some(fitness) = &champion.fitness else {
+            return;
+        };
+        self.runs.push(HistoryRun {
+            recorded_at: Utc::now(),
+            champion_id: champion.id.clone(),
+            generation: champion.generation,
+            total: fitness.total,
+            scenario_scores: fitness.scenario_scores.clone(),
+        });
+        let excess = self.runs.len().saturating_sub(KEEP_RUNS);
+        self.runs.drain(..excess);
+    }
+
+    pub fn latest(&self) -> Option<&HistoryRun> {
+        self.runs.last()
+    }
+
+    /// A scenario's fitness in each run that evaluated it, oldest first.
+    pub fn scenario_trend(&self, name: &str) -> Vec<(DateTime<Utc>, f64)> {
+        self.runs
+            .iter()
+            .filter_map(|run| {
+                let score = run.scenario_scores.iter().find(|s| s.name == name)?;
+                Some((run.recorded_at, score.fitness()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fitness::score_genome;
+
+    fn champion(rural_score: f32) -> ScoutGenome {
+        let mut genome = ScoutGenome::baseline("prompt".to_string(), "disc".to_string());
+        let scores = vec![ScenarioScore {
+            name: "rural".to_string(),
+            verdict_pass: true,
+            verdict_score: rural_score,
+            audit_passed: 1,
+            audit_total: 1,
+            issues: Default::default(),
+            findings: vec![],
+        }];
+        genome.fitness = Some(score_genome(&scores, None));
+        genome
+    }
+
+    #[test]
+    fn history_survives_a_save_and_tracks_each_scenario() {
+        let path = std::env::temp_dir().join(format!("simweb-history-{}.json", uuid::Uuid::new_v4()));
+        let mut history = ScoreHistory::load(&path).unwrap();
+        history.record(&champion(0.5));
+        history.record(&champion(0.9));
+        history.save(&path).unwrap();
+
+        let reloaded = ScoreHistory::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let trend: Vec<f64> = reloaded.scenario_trend("rural").into_iter().map(|(_, f)| f).collect();
+        assert_eq!(trend.len(), 2);
+        assert!((trend[1] - (0.7 * 0.9 + 0.3)).abs() < 0.001);
+    }
+}
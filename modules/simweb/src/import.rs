@@ -0,0 +1,430 @@
+//! World import — derive a World from a real region dump.
+//!
+//! Reads the JSON written by `rootsignal dump` (only the fields it needs, so
+//! simweb stays free of rootsignal types). Each signal's source URL becomes
+//! a site or a social profile, each signal and situation a fact. Emails,
+//! phone numbers and @handles are scrubbed from text, social profiles get
+//! pseudonyms, URLs lose their query strings, and sizes are capped so the
+//! world stays a prompt-sized description.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::judge::JudgeCriteria;
+use crate::world::{Fact, Geography, Site, SocialProfile, World};
+
+/// A region dump, as written by `rootsignal dump`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegionDump {
+    pub region: String,
+    #[serde(default)]
+    pub situations: Vec<DumpSituation>,
+    #[serde(default)]
+    pub ungrouped_signals: Vec<DumpSignal>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DumpSituation {
+    pub headline: String,
+    #[serde(default)]
+    pub lede: String,
+    pub category: Option<String>,
+    pub centroid_lat: Option<f64>,
+    pub centroid_lng: Option<f64>,
+    #[serde(default)]
+    pub signals: Vec<DumpSignal>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DumpSignal {
+    pub node_type: String,
+    pub meta: DumpSignalMeta,
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DumpSignalMeta {
+    pub title: String,
+    #[serde(default)]
+    pub summary: String,
+    pub source_url: String,
+    pub about_location: Option<DumpPoint>,
+    pub extracted_at: Option<DateTime<Utc>>,
+    pub content_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct DumpPoint {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+/// Size caps and the geography a dump doesn't carry.
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    pub max_sites: usize,
+    pub max_social_profiles: usize,
+    pub max_facts: usize,
+    /// Signals summarized into each site's or profile's description.
+    pub signals_per_source: usize,
+    pub max_description_chars: usize,
+    pub state_or_region: String,
+    pub country: String,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            max_sites: 40,
+            max_social_profiles: 15,
+            max_facts: 100,
+            signals_per_source: 5,
+            max_description_chars: 600,
+            state_or_region: String::new(),
+            country: "US".to_string(),
+        }
+    }
+}
+
+/// (host suffix, platform, URL prefix for pseudonymous profiles)
+const SOCIAL_HOSTS: [(&str, &str, &str); 6] = [
+    ("instagram.com", "instagram", "https://www.instagram.com/"),
+    ("facebook.com", "facebook", "https://www.facebook.com/"),
+    ("x.com", "x", "https://x.com/"),
+    ("twitter.com", "x", "https://x.com/"),
+    ("tiktok.com", "tiktok", "https://www.tiktok.com/@"),
+    ("reddit.com", "reddit", "https://www.reddit.com/r/"),
+];
+
+/// Signals sharing a source, with the situation each belongs to.
+struct Source<'a> {
+    signals: Vec<(&'a DumpSignal, Option<&'a DumpSituation>)>,
+}
+
+/// Convert a dump into a world.
+pub fn import_world(dump: &RegionDump, options: &ImportOptions) -> World {
+    let all_signals: Vec<(&DumpSignal, Option<&DumpSituation>)> = dump
+        .situations
+        .iter()
+        .flat_map(|s| s.signals.iter().map(move |n| (n, Some(s))))
+        .chain(dump.ungrouped_signals.iter().map(|n| (n, None)))
+        .collect();
+
+    let mut sources: BTreeMap<String, Source> = BTreeMap::new();
+    for &(signal, situation) in &all_signals {
+        let url = strip_query(&signal.meta.source_url);
+        if url.is_empty() {
+            continue;
+        }
+        sources.entry(url).or_insert(Source { signals: Vec::new() }).signals.push((signal, situation));
+    }
+    // Busiest sources first; ties by URL so imports are reproducible.
+    let mut ranked: Vec<(String, Source)> = sources.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.signals.len().cmp(&a.1.signals.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let mut sites = Vec::new();
+    let mut social_profiles = Vec::new();
+    // Source URL to the URL facts reference it by.
+    let mut references: BTreeMap<String, String> = BTreeMap::new();
+    for (url, source) in &ranked {
+        match social_platform(url) {
+            Some((platform, prefix)) => {
+                if social_profiles.len() >= options.max_social_profiles {
+                    continue;
+                }
+                let identifier = format!("{platform}_profile_{}", social_profiles.len() + 1);
+                references.insert(url.clone(), format!("{prefix}{identifier}"));
+                social_profiles.push(SocialProfile {
+                    platform: platform.to_string(),
+                    identifier,
+                    persona: describe(&format!("A {platform} account that posted about"), source, options),
+                    post_count: source.signals.len() as u32,
+                });
+            }
+            None => {
+                if sites.len() >= options.max_sites {
+                    continue;
+                }
+                references.insert(url.clone(), url.clone());
+                sites.push(Site {
+                    url: url.clone(),
+                    kind: site_kind(url).to_string(),
+                    content_description: describe(&format!("Page on {}, covering", host(url)), source, options),
+                    published: source.signals.iter().filter_map(|(s, _)| signal_date(s)).max(),
+                    links_to: Vec::new(),
+                });
+            }
+        }
+    }
+
+    let mut facts = Vec::new();
+    for situation in &dump.situations {
+        let referenced_by: BTreeSet<String> = situation
+            .signals
+            .iter()
+            .filter_map(|s| references.get(&strip_query(&s.meta.source_url)).cloned())
+            .collect();
+        if !referenced_by.is_empty() {
+            facts.push(Fact {
+                text: truncate(&scrub(&join_text(&situation.headline, &situation.lede)), 400),
+                referenced_by: referenced_by.into_iter().collect(),
+                category: "situation".to_string(),
+            });
+        }
+    }
+    for (signal, _) in &all_signals {
+        let Some(reference) = references.get(&strip_query(&signal.meta.source_url)) else {
+            continue;
+        };
+        facts.push(Fact {
+            text: truncate(&scrub(&join_text(&signal.meta.title, &signal.meta.summary)), 400),
+            referenced_by: vec![reference.clone()],
+            category: signal.category.clone().unwrap_or_else(|| signal.node_type.to_lowercase()),
+        });
+    }
+    facts.truncate(options.max_facts);
+
+    let topics: BTreeSet<String> = dump
+        .situations
+        .iter()
+        .filter_map(|s| s.category.clone())
+        .chain(all_signals.iter().filter_map(|(s, _)| s.category.clone()))
+        .filter(|c| !c.is_empty())
+        .collect();
+
+    let (center_lat, center_lng) = center(dump, &all_signals);
+    World {
+        name: format!("Imported {}", dump.region),
+        description: format!(
+            "Derived from a real {} region dump: {} sites, {} social profiles and {} facts from {} signals \
+             in {} situations. Contact details are scrubbed and social profiles pseudonymized.",
+            dump.region,
+            sites.len(),
+            social_profiles.len(),
+            facts.len(),
+            all_signals.len(),
+            dump.situations.len(),
+        ),
+        facts,
+        sites,
+        social_profiles,
+        topics: topics.into_iter().take(20).collect(),
+        geography: Geography {
+            name: dump.region.clone(),
+            state_or_region: options.state_or_region.clone(),
+            country: options.country.clone(),
+            local_terms: vec![dump.region.clone()],
+            center_lat,
+            center_lng,
+        },
+    }
+}
+
+/// Judge criteria for an imported world: the real signals are the facts,
+/// so the agent should find them without inventing more.
+pub fn imported_criteria(world: &World) -> JudgeCriteria {
+    JudgeCriteria {
+        checks: vec![
+            format!(
+                "Signals should correspond to the world's {} facts; signals matching no fact are hallucinations.",
+                world.facts.len()
+            ),
+            "Each signal should be traceable to a site or social profile in the world.".to_string(),
+            "Signals drawn from facts in the same situation should be recognized as related.".to_string(),
+        ],
+        pass_threshold: 0.6,
+        critical_categories: vec!["hallucination".to_string()],
+    }
+}
+
+fn describe(lead: &str, source: &Source, options: &ImportOptions) -> String {
+    let titles: Vec<String> = source
+        .signals
+        .iter()
+        .take(options.signals_per_source)
+        .map(|(s, _)| join_text(&s.meta.title, &s.meta.summary))
+        .collect();
+    truncate(&scrub(&format!("{lead}: {}", titles.join("; "))), options.max_description_chars)
+}
+
+fn join_text(title: &str, detail: &str) -> String {
+    if detail.is_empty() {
+        title.to_string()
+    } else {
+        format!("{title} — {detail}")
+    }
+}
+
+fn signal_date(signal: &DumpSignal) -> Option<NaiveDate> {
+    signal.meta.content_date.or(signal.meta.extracted_at).map(|d| d.date_naive())
+}
+
+fn center(dump: &RegionDump, signals: &[(&DumpSignal, Option<&DumpSituation>)]) -> (f64, f64) {
+    let mut points: Vec<(f64, f64)> =
+        signals.iter().filter_map(|(s, _)| s.meta.about_location).map(|p| (p.lat, p.lng)).collect();
+    if points.is_empty() {
+        points = dump.situations.iter().filter_map(|s| Some((s.centroid_lat?, s.centroid_lng?))).collect();
+    }
+    if points.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = points.len() as f64;
+    (points.iter().map(|p| p.0).sum::<f64>() / n, points.iter().map(|p| p.1).sum::<f64>() / n)
+}
+
+fn strip_query(url: &str) -> String {
+    url.split(['?', '#']).next().unwrap_or_default().trim().to_string()
+}
+
+fn host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split('/').next().unwrap_or_default()
+}
+
+fn social_platform(url: &str) -> Option<(&'static str, &'static str)> {
+    let host = host(url).to_ascii_lowercase();
+    SOCIAL_HOSTS
+        .iter()
+        .find(|(suffix, _, _)| host == *suffix || host.ends_with(&format!(".{suffix}")))
+        .map(|&(_, platform, prefix)| (platform, prefix))
+}
+
+fn site_kind(url: &str) -> &'static str {
+    let host = host(url).to_ascii_lowercase();
+    if host.ends_with(".gov") || host.ends_with(".us") {
+        "government"
+    } else if host.ends_with(".edu") {
+        "school"
+    } else if host.ends_with(".org") {
+        "nonprofit"
+    } else {
+        "website"
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        format!("{}…", s.chars().take(max.saturating_sub(1)).collect::<String>())
+    }
+}
+
+/// Replace emails, phone numbers and @handles.
+pub fn scrub(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(' ')
+        .map(|word| {
+            let core = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '@');
+            if core.is_empty() || !core.contains('@') {
+                return word.to_string();
+            }
+            let replacement = match core.split_once('@') {
+                Some(("", _)) => "[handle]",
+                Some((_, domain)) if domain.contains('.') => "[email]",
+                _ => return word.to_string(),
+            };
+            word.replacen(core, replacement, 1)
+        })
+        .collect();
+    scrub_phones(&words.join(" "))
+}
+
+/// Runs of digits and phone punctuation holding 10 to 15 digits.
+fn scrub_phones(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let is_phone_char = |c: char| c.is_ascii_digit() || "()-. +".contains(c);
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let starts_run = c.is_ascii_digit() || c == '(' || c == '+';
+        if !starts_run || (i > 0 && chars[i - 1].is_alphanumeric()) {
+            out.push(c);
+            i += 1;
+            continue;
+        }
+        let mut end = i;
+        while end < chars.len() && is_phone_char(chars[end]) {
+            end += 1;
+        }
+        // Leave trailing separators outside the number.
+        while end > i && !chars[end - 1].is_ascii_digit() {
+            end -= 1;
+        }
+        let digits = chars[i..end].iter().filter(|c| c.is_ascii_digit()).count();
+        if (10..=15).contains(&digits) {
+            out.push_str("[phone]");
+        } else {
+            out.extend(&chars[i..end.max(i + 1)]);
+        }
+        i = end.max(i + 1);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(title: &str, source_url: &str) -> serde_json::Value {
+        serde_json::json!({
+            "node_type": "Need",
+            "meta": {
+                "title": title,
+                "summary": "",
+                "source_url": source_url,
+                "about_location": {"lat": 44.9, "lng": -93.2, "precision": "exact"},
+                "extracted_at": "2026-03-02T12:00:00Z",
+            },
+        })
+    }
+
+    #[test]
+    fn contact_details_are_scrubbed() {
+        let text = "Call (612) 555-0199 or email jo.smith@example.org, DM @jsmith. Room 201, 2026 drive.";
+
+        let scrubbed = scrub(text);
+
+        assert_eq!(scrubbed, "Call [phone] or email [email], DM [handle]. Room 201, 2026 drive.");
+    }
+
+    #[test]
+    fn sources_become_capped_sites_and_pseudonymous_profiles() {
+        let dump: RegionDump = serde_json::from_value(serde_json::json!({
+            "region": "Minneapolis",
+            "situations": [{
+                "headline": "Food shelf shortages",
+                "lede": "Shelves are running low.",
+                "category": "food",
+                "signals": [
+                    signal("Pantry needs cereal", "https://pantry.org/news?utm=x"),
+                    signal("Pantry needs milk", "https://pantry.org/news"),
+                ],
+            }],
+            "ungrouped_signals": [
+                signal("Coat drive", "https://www.instagram.com/jane.doe.mpls/"),
+                signal("Bus detour", "https://metrotransit.org/detours"),
+            ],
+        }))
+        .unwrap();
+        let options = ImportOptions { max_sites: 1, ..Default::default() };
+
+        let world = import_world(&dump, &options);
+
+        assert_eq!(world.sites.len(), 1);
+        assert_eq!(world.sites[0].url, "https://pantry.org/news");
+        assert_eq!(world.sites[0].kind, "nonprofit");
+        assert_eq!(world.social_profiles[0].identifier, "instagram_profile_1");
+        assert!(!serde_json::to_string(&world).unwrap().contains("jane.doe"));
+        // The dropped site's signal has nothing to reference, so no fact.
+        let facts: Vec<&str> = world.facts.iter().map(|f| f.text.as_str()).collect();
+        assert_eq!(
+            facts,
+            ["Food shelf shortages — Shelves are running low.", "Pantry needs cereal", "Pantry needs milk", "Coat drive"]
+        );
+        assert_eq!(world.topics, ["food"]);
+    }
+}
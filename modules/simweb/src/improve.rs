@@ -4,7 +4,7 @@ use ai_client::Claude;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::judge::JudgeCriteria;
+use crate::judge::{Citation, Issue, JudgeCriteria};
 use crate::world::World;
 
 const SONNET_MODEL: &str = "claude-sonnet-4-20250514";
@@ -17,6 +17,8 @@ pub struct TestFailure {
     pub verdict_score: f32,
     pub verdict_reasoning: String,
     pub audit_failures: Vec<String>,
+    /// The judge's issues, citing the content each is about.
+    pub issues: Vec<Issue>,
 }
 
 /// A blind spot identified from test failures.
@@ -41,6 +43,9 @@ pub struct PromptFix {
     pub target: String,
     pub issue: String,
     pub suggestion: String,
+    /// Failing content the fix addresses, copied from the failures' issues.
+    #[serde(default)]
+    pub citations: Vec<Citation>,
 }
 
 /// Complete improvement report from analyzing test failures.
@@ -77,7 +82,8 @@ impl Improver {
             serde_json::to_string_pretty(&failures).map_err(|e| anyhow!("serialize: {e}"))?;
 
         // Step 1: Identify blind spots
-        let analysis = self.identify_blind_spots(&failures_json).await?;
+        let mut analysis = self.identify_blind_spots(&failures_json).await?;
+        keep_failure_citations(&mut analysis.prompt_suggestions, &failures);
 
         // Step 2: Generate adversarial scenarios for each blind spot
         let mut scenarios = Vec::new();
@@ -154,7 +160,10 @@ impl Improver {
                \"blind_spots\": [{{\"category\": \"string\", \"description\": \"string\", \
                \"source\": \"scenario name\", \"severity\": \"High|Medium|Low\"}}],\n  \
                \"prompt_suggestions\": [{{\"target\": \"extractor|judge|etc\", \
-               \"issue\": \"string\", \"suggestion\": \"string\"}}]\n}}"
+               \"issue\": \"string\", \"suggestion\": \"string\", \
+               \"citations\": [copied verbatim from the failures' issues]}}]\n}}\n\n\
+             Each prompt suggestion should cite the failing content it addresses, copying \
+             citations exactly from the issues above."
         );
 
         let response = self.claude.chat_completion(system, &user).await?;
@@ -198,6 +207,19 @@ impl Improver {
     }
 }
 
+/// Drop citations on `fixes` that don't appear in any failure's issues, so a
+/// fix only ever points at content the judge actually flagged.
+fn keep_failure_citations(fixes: &mut [PromptFix], failures: &[TestFailure]) {
+    let known: Vec<&Citation> = failures
+        .iter()
+        .flat_map(|f| &f.issues)
+        .flat_map(|i| &i.citations)
+        .collect();
+    for fix in fixes {
+        fix.citations.retain(|c| known.contains(&c));
+    }
+}
+
 #[derive(Deserialize)]
 struct AnalysisResponse {
     blind_spots: Vec<BlindSpot>,
@@ -213,3 +235,41 @@ fn strip_code_fence(s: &str) -> &str {
         .unwrap_or(s);
     s.trim()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::judge::{CitationKind, Severity};
+
+    fn citation(kind: CitationKind, reference: &str) -> Citation {
+        Citation { kind, reference: reference.to_string(), quote: String::new() }
+    }
+
+    #[test]
+    fn prompt_fixes_keep_only_citations_the_judge_made() {
+        let flagged = citation(CitationKind::Fact, "F2");
+        let failures = vec![TestFailure {
+            scenario_name: "rural".to_string(),
+            verdict_pass: false,
+            verdict_score: 0.5,
+            verdict_reasoning: String::new(),
+            audit_failures: vec![],
+            issues: vec![Issue {
+                severity: Severity::Critical,
+                category: "missed_tension".to_string(),
+                description: "Food shelf shortage missed".to_string(),
+                citations: vec![flagged.clone()],
+            }],
+        }];
+        let mut fixes = vec![PromptFix {
+            target: "extractor".to_string(),
+            issue: "misses shortages".to_string(),
+            suggestion: "look for supply language".to_string(),
+            citations: vec![flagged.clone(), citation(CitationKind::Fact, "F9")],
+        }];
+
+        keep_failure_citations(&mut fixes, &failures);
+
+        assert_eq!(fixes[0].citations, [flagged]);
+    }
+}
@@ -2,8 +2,9 @@
 
 use ai_client::Claude;
 use anyhow::{anyhow, Result};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
+use tracing::info;
 
 use crate::prompt;
 use crate::world::World;
@@ -18,24 +19,72 @@ pub struct JudgeCriteria {
     pub critical_categories: Vec<String>,
 }
 
+/// Score deductions the judge prompt prescribes, refunded for rejected issues.
+const CRITICAL_DEDUCTION: f32 = 0.25;
+const WARNING_DEDUCTION: f32 = 0.10;
+
 /// The judge's evaluation of agent output.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Verdict {
     pub pass: bool,
     pub score: f32,
     pub reasoning: String,
     pub issues: Vec<Issue>,
+    /// Issues the verifier dropped for lacking a valid citation.
+    #[serde(default)]
+    #[schemars(skip)]
+    pub rejected_issues: Vec<Issue>,
 }
 
 /// A single issue found during evaluation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Issue {
     pub severity: Severity,
     pub category: String,
     pub description: String,
+    /// The content the issue is about. Issues need at least one valid citation.
+    #[serde(default)]
+    pub citations: Vec<Citation>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// What an issue points at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Citation {
+    pub kind: CitationKind,
+    /// A site URL, `platform:identifier` for a social profile's posts, or
+    /// `F<n>` for the n-th fact. Empty for agent output.
+    #[serde(default)]
+    pub reference: String,
+    /// The offending text, verbatim. Required for agent output.
+    #[serde(default)]
+    pub quote: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationKind {
+    Page,
+    Post,
+    Fact,
+    Output,
+}
+
+impl std::fmt::Display for Citation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            CitationKind::Output => write!(f, "output \"{}\"", self.quote),
+            kind => {
+                write!(f, "{kind:?} {}", self.reference)?;
+                if !self.quote.is_empty() {
+                    write!(f, " \"{}\"", self.quote)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum Severity {
     Critical,
     Warning,
@@ -55,8 +104,14 @@ impl std::fmt::Display for Verdict {
                     "  [{:?}] {}: {}",
                     issue.severity, issue.category, issue.description
                 )?;
+                for citation in &issue.citations {
+                    writeln!(f, "      cites {citation}")?;
+                }
             }
         }
+        if !self.rejected_issues.is_empty() {
+            writeln!(f, "Rejected {} uncited issues", self.rejected_issues.len())?;
+        }
         Ok(())
     }
 }
@@ -89,15 +144,16 @@ impl Judge {
             "Judge evaluating"
         );
 
-        let response = self.claude.chat_completion(system, &user).await?;
-
-        let verdict = parse_verdict(&response, criteria)?;
+        let mut verdict: Verdict = self.claude.extract(SONNET_MODEL, system, &user).await?;
+        verify_citations(&mut verdict, world, agent_output);
+        apply_criteria(&mut verdict, criteria);
 
         info!(
             world = world.name,
             pass = verdict.pass,
             score = verdict.score,
             issues = verdict.issues.len(),
+            rejected = verdict.rejected_issues.len(),
             "Judge verdict"
         );
 
@@ -105,19 +161,55 @@ impl Judge {
     }
 }
 
-fn parse_verdict(response: &str, criteria: &JudgeCriteria) -> Result<Verdict> {
-    let json_str = response.trim();
-    let json_str = json_str
-        .strip_prefix("```json")
-        .or_else(|| json_str.strip_prefix("```"))
-        .and_then(|s| s.strip_suffix("```"))
-        .unwrap_or(json_str);
+/// Whether `citation` points at something that exists: a site or social
+/// profile in the world, one of its facts, or text in the agent output.
+fn citation_is_valid(citation: &Citation, world: &World, agent_output: &str) -> bool {
+    let reference = citation.reference.trim();
+    match citation.kind {
+        CitationKind::Page => world
+            .sites
+            .iter()
+            .any(|s| s.url.trim_end_matches('/') == reference.trim_end_matches('/')),
+        CitationKind::Post => reference.split_once(':').is_some_and(|(platform, identifier)| {
+            let identifier = identifier.trim().trim_start_matches('@');
+            world
+                .social_profiles
+                .iter()
+                .any(|p| p.platform.eq_ignore_ascii_case(platform.trim()) && p.identifier == identifier)
+        }),
+        CitationKind::Fact => reference
+            .strip_prefix(['F', 'f'])
+            .and_then(|n| n.parse::<usize>().ok())
+            .is_some_and(|n| (1..=world.facts.len()).contains(&n)),
+        CitationKind::Output => {
+            let normalize = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+            let quote = normalize(&citation.quote);
+            !quote.is_empty() && normalize(agent_output).contains(&quote)
+        }
+    }
+}
 
-    let mut verdict: Verdict = serde_json::from_str(json_str).map_err(|e| {
-        warn!(error = %e, response = response, "Failed to parse judge response");
-        anyhow!("Failed to parse judge verdict: {e}")
-    })?;
+/// Drop issues with no valid citation, keeping them in `rejected_issues`,
+/// and refund their score deductions. Invalid citations on kept issues are
+/// removed.
+pub fn verify_citations(verdict: &mut Verdict, world: &World, agent_output: &str) {
+    for mut issue in std::mem::take(&mut verdict.issues) {
+        issue.citations.retain(|c| citation_is_valid(c, world, agent_output));
+        if !issue.citations.is_empty() {
+            verdict.issues.push(issue);
+            continue;
+        }
+        verdict.score += match issue.severity {
+            Severity::Critical => CRITICAL_DEDUCTION,
+            Severity::Warning => WARNING_DEDUCTION,
+            Severity::Info => 0.0,
+        };
+        verdict.rejected_issues.push(issue);
+    }
+    verdict.score = verdict.score.min(1.0);
+}
 
+fn apply_criteria(verdict: &mut Verdict, criteria: &JudgeCriteria) {
     // Override pass/fail based on threshold (don't trust the LLM's boolean)
     verdict.pass = verdict.score >= criteria.pass_threshold;
 
@@ -134,8 +226,6 @@ fn parse_verdict(response: &str, criteria: &JudgeCriteria) -> Result<Verdict> {
             verdict.pass = false;
         }
     }
-
-    Ok(verdict)
 }
 
 /// Generate a random World using Sonnet (for Tier 3 random discovery tests).
@@ -164,3 +254,68 @@ pub async fn generate_random_world(api_key: &str) -> Result<World> {
     );
     Ok(world)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Fact, Geography, Site};
+
+    fn world() -> World {
+        World {
+            name: "test".to_string(),
+            description: String::new(),
+            facts: vec![Fact {
+                text: "Food shelf needs volunteers".to_string(),
+                referenced_by: vec!["https://foodshelf.org".to_string()],
+                category: "need".to_string(),
+            }],
+            sites: vec![Site {
+                url: "https://foodshelf.org".to_string(),
+                kind: "nonprofit".to_string(),
+                content_description: String::new(),
+                published: None,
+                links_to: vec![],
+            }],
+            social_profiles: vec![],
+            topics: vec![],
+            geography: Geography {
+                name: "Town".to_string(),
+                state_or_region: String::new(),
+                country: "US".to_string(),
+                local_terms: vec![],
+                center_lat: 0.0,
+                center_lng: 0.0,
+            },
+        }
+    }
+
+    fn issue(severity: Severity, citations: Vec<Citation>) -> Issue {
+        Issue { severity, category: "c".to_string(), description: "d".to_string(), citations }
+    }
+
+    fn cite(kind: CitationKind, reference: &str, quote: &str) -> Citation {
+        Citation { kind, reference: reference.to_string(), quote: quote.to_string() }
+    }
+
+    #[test]
+    fn uncited_issues_are_rejected_and_refunded() {
+        let mut verdict = Verdict {
+            pass: false,
+            score: 0.4,
+            reasoning: String::new(),
+            issues: vec![
+                issue(Severity::Critical, vec![cite(CitationKind::Fact, "F1", "")]),
+                issue(Severity::Critical, vec![]),
+                issue(Severity::Warning, vec![cite(CitationKind::Page, "https://elsewhere.org", "")]),
+                issue(Severity::Warning, vec![cite(CitationKind::Output, "", "Free  concert SATURDAY")]),
+            ],
+            rejected_issues: vec![],
+        };
+
+        verify_citations(&mut verdict, &world(), "Signals:\n- Free concert Saturday at the park");
+
+        assert_eq!(verdict.issues.len(), 2);
+        assert_eq!(verdict.rejected_issues.len(), 2);
+        assert!((verdict.score - 0.75).abs() < 0.001);
+    }
+}
@@ -6,7 +6,9 @@
 pub mod evolve;
 pub mod fitness;
 pub mod genome;
+pub mod history;
 pub mod improve;
+pub mod import;
 pub mod judge;
 pub mod prompt;
 pub mod scenario_gym;
@@ -16,12 +18,20 @@ pub mod types;
 pub mod world;
 
 pub use evolve::{AuditSummary, EvolutionConfig, EvolutionResult, Evolver};
-pub use fitness::{is_improvement, score_genome};
+pub use fitness::{
+    attribute, is_improvement, score_genome, FitnessAttribution, RegressionPolicy, ScenarioDelta,
+    ScenarioRegression,
+};
 pub use genome::{FitnessScore, ScenarioScore, ScoutGenome};
+pub use history::{HistoryRun, ScoreHistory};
 pub use improve::{
     BlindSpot, BlindSpotSeverity, ImprovementReport, Improver, PromptFix, TestFailure,
 };
-pub use judge::{generate_random_world, Issue, Judge, JudgeCriteria, Severity, Verdict};
+pub use import::{import_world, imported_criteria, ImportOptions};
+pub use judge::{
+    generate_random_world, verify_citations, Citation, CitationKind, Issue, Judge, JudgeCriteria, Severity,
+    Verdict,
+};
 pub use scenario_gym::{ScenarioEntry, ScenarioGym, ScenarioSource};
 pub use sim::SimulatedWeb;
 pub use types::{SimPage, SimPost, SimSearchResult};
@@ -198,6 +198,15 @@ SCORING:
 - Info issues: no score impact
 - Minimum score: 0.0
 
+CITATIONS:
+Every issue must cite the content it is about. Issues without a valid citation are discarded.
+- {"kind": "fact", "reference": "F3"} — a ground-truth fact, by its number
+- {"kind": "page", "reference": "<site url>", "quote": "..."} — a site, exactly as listed
+- {"kind": "post", "reference": "<platform>:<identifier>", "quote": "..."} — a social profile's posts
+- {"kind": "output", "quote": "..."} — text copied verbatim from scout's output
+A missed signal cites the fact or source it should have come from; a hallucinated or wrong
+signal quotes scout's output.
+
 Return JSON:
 {
   "pass": true/false,
@@ -207,7 +216,8 @@ Return JSON:
     {
       "severity": "Critical|Warning|Info",
       "category": "string",
-      "description": "string"
+      "description": "string",
+      "citations": [{"kind": "fact|page|post|output", "reference": "string", "quote": "string"}]
     }
   ]
 }"#
@@ -218,7 +228,8 @@ pub fn judge_user(world: &World, criteria_checks: &[String], agent_output: &str)
     let facts_list = world
         .facts
         .iter()
-        .map(|f| format!("- [{}] {}", f.category, f.text))
+        .enumerate()
+        .map(|(i, f)| format!("- F{} [{}] {}", i + 1, f.category, f.text))
         .collect::<Vec<_>>()
         .join("\n");
 
@@ -276,7 +287,7 @@ Geography: {geo_name}, {state}, {country}
         profiles = world
             .social_profiles
             .iter()
-            .map(|p| format!("- {} @{}: {}", p.platform, p.identifier, p.persona))
+            .map(|p| format!("- {}:{}: {}", p.platform, p.identifier, p.persona))
             .collect::<Vec<_>>()
             .join("\n"),
         checks_list = checks_list,
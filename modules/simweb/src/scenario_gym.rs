@@ -23,6 +23,11 @@ pub enum ScenarioSource {
         blind_spot: String,
         promoted_at: DateTime<Utc>,
     },
+    /// Derived from a real region dump by `import::import_world`.
+    Imported {
+        region: String,
+        imported_at: DateTime<Utc>,
+    },
 }
 
 /// Persisted format for generated scenarios.
@@ -33,6 +38,9 @@ struct PersistedScenario {
     criteria: JudgeCriteria,
     blind_spot: String,
     promoted_at: DateTime<Utc>,
+    /// Set for imported scenarios; `blind_spot` is empty for those.
+    #[serde(default)]
+    imported_from: Option<String>,
 }
 
 /// Collection of scenarios that grows over time as adversarial scenarios are promoted.
@@ -89,27 +97,14 @@ impl ScenarioGym {
         blind_spot: String,
     ) -> anyhow::Result<()> {
         let promoted_at = Utc::now();
-
-        // Persist to disk if we have a directory
-        if let Some(dir) = &self.generated_dir {
-            std::fs::create_dir_all(dir)?;
-            let filename = format!(
-                "{}_{}.json",
-                name.to_lowercase().replace(' ', "_"),
-                promoted_at.format("%Y%m%d_%H%M%S")
-            );
-            let path = dir.join(filename);
-            let persisted = PersistedScenario {
-                name: name.clone(),
-                world: world.clone(),
-                criteria: criteria.clone(),
-                blind_spot: blind_spot.clone(),
-                promoted_at,
-            };
-            let json = serde_json::to_string_pretty(&persisted)?;
-            std::fs::write(&path, json)?;
-            tracing::info!(path = %path.display(), "Promoted scenario to gym");
-        }
+        self.persist(PersistedScenario {
+            name: name.clone(),
+            world: world.clone(),
+            criteria: criteria.clone(),
+            blind_spot: blind_spot.clone(),
+            promoted_at,
+            imported_from: None,
+        })?;
 
         self.entries.push(ScenarioEntry {
             name,
@@ -124,6 +119,55 @@ impl ScenarioGym {
         Ok(())
     }
 
+    /// Add a world imported from a region dump and persist it to disk.
+    pub fn add_imported(
+        &mut self,
+        name: String,
+        world: World,
+        criteria: JudgeCriteria,
+        region: String,
+    ) -> anyhow::Result<()> {
+        let imported_at = Utc::now();
+        self.persist(PersistedScenario {
+            name: name.clone(),
+            world: world.clone(),
+            criteria: criteria.clone(),
+            blind_spot: String::new(),
+            promoted_at: imported_at,
+            imported_from: Some(region.clone()),
+        })?;
+
+        self.entries.push(ScenarioEntry {
+            name,
+            world,
+            criteria,
+            source: ScenarioSource::Imported {
+                region,
+                imported_at,
+            },
+        });
+
+        Ok(())
+    }
+
+    /// Write a scenario to the gym's directory, if it has one.
+    fn persist(&self, scenario: PersistedScenario) -> anyhow::Result<()> {
+        let Some(dir) = &self.generated_dir else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(dir)?;
+        let filename = format!(
+            "{}_{}.json",
+            scenario.name.to_lowercase().replace(' ', "_"),
+            scenario.promoted_at.format("%Y%m%d_%H%M%S")
+        );
+        let path = dir.join(filename);
+        let json = serde_json::to_string_pretty(&scenario)?;
+        std::fs::write(&path, json)?;
+        tracing::info!(path = %path.display(), "Saved scenario to gym");
+        Ok(())
+    }
+
     /// All scenarios in the gym.
     pub fn scenarios(&self) -> &[ScenarioEntry] {
         &self.entries
@@ -144,19 +188,34 @@ impl ScenarioGym {
             .filter(|e| matches!(e.source, ScenarioSource::Generated { .. }))
             .count()
     }
+
+    /// Number of scenarios imported from region dumps.
+    pub fn imported_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.source, ScenarioSource::Imported { .. }))
+            .count()
+    }
 }
 
 fn load_generated_scenario(path: &Path) -> anyhow::Result<ScenarioEntry> {
     let data = std::fs::read_to_string(path)?;
     let persisted: PersistedScenario = serde_json::from_str(&data)?;
+    let source = match persisted.imported_from {
+        Some(region) => ScenarioSource::Imported {
+            region,
+            imported_at: persisted.promoted_at,
+        },
+        None => ScenarioSource::Generated {
+            blind_spot: persisted.blind_spot,
+            promoted_at: persisted.promoted_at,
+        },
+    };
     Ok(ScenarioEntry {
         name: persisted.name,
         world: persisted.world,
         criteria: persisted.criteria,
-        source: ScenarioSource::Generated {
-            blind_spot: persisted.blind_spot,
-            promoted_at: persisted.promoted_at,
-        },
+        source,
     })
 }
 
@@ -220,4 +279,23 @@ mod tests {
         assert_eq!(gym.scenarios().len(), 1);
         assert_eq!(gym.generated_count(), 1);
     }
+
+    #[test]
+    fn imported_scenarios_reload_as_imported() {
+        let dir = std::env::temp_dir().join(format!("simweb-gym-{}", uuid::Uuid::new_v4()));
+        let mut gym = ScenarioGym::load(vec![], &dir);
+        gym.add_imported(
+            "imported minneapolis".to_string(),
+            test_world(),
+            test_criteria(),
+            "Minneapolis".to_string(),
+        )
+        .unwrap();
+
+        let reloaded = ScenarioGym::load(vec![], &dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(reloaded.imported_count(), 1);
+        assert_eq!(reloaded.generated_count(), 0);
+    }
 }